@@ -148,6 +148,7 @@ pub(super) fn process_command_word(
                     Ok(rank) => {
                         if (0..5).contains(&rank) {
                             model.judgerank = rank;
+                            model.judgerank_fraction = 0.0;
                             model.judgerank_type = JudgeRankType::BmsRank;
                         } else {
                             return Some(DecodeLog::new(
@@ -169,10 +170,10 @@ pub(super) fn process_command_word(
         CmdDef {
             name: "DEFEXRANK",
             handler: |model, arg| {
-                match arg.parse::<i32>() {
+                match arg.parse::<f64>() {
                     Ok(rank) => {
-                        if rank >= 1 {
-                            model.judgerank = rank;
+                        if rank >= 1.0 && rank.is_finite() {
+                            model.set_judgerank_exact(rank);
                             model.judgerank_type = JudgeRankType::BmsDefexrank;
                         } else {
                             return Some(DecodeLog::new(
@@ -1517,6 +1517,17 @@ mod tests {
         assert_eq!(model.judgerank_type, JudgeRankType::BmsDefexrank);
     }
 
+    #[test]
+    fn decode_fractional_defexrank() {
+        let mut decoder = BMSDecoder::new();
+        let data = make_bms_bytes(&["#BPM 120", "#DEFEXRANK 87.5"]);
+        let model = decoder.decode_bytes(&data, false, None).unwrap();
+        assert_eq!(model.judgerank, 87);
+        assert_eq!(model.judgerank_fraction, 0.5);
+        assert_eq!(model.judgerank_exact(), 87.5);
+        assert_eq!(model.judgerank_type, JudgeRankType::BmsDefexrank);
+    }
+
     #[test]
     fn decode_genre() {
         let mut decoder = BMSDecoder::new();
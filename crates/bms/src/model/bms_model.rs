@@ -70,6 +70,8 @@ pub struct BMSModel {
     pub playlevel: String,
     pub difficulty: i32,
     pub judgerank: i32,
+    /// Fractional part of the judge rank (e.g. `#DEFEXRANK 87.5`), in `[0, 1)`
+    pub judgerank_fraction: f64,
     pub judgerank_type: JudgeRankType,
     pub total: f64,
     pub total_type: TotalType,
@@ -111,6 +113,7 @@ impl BMSModel {
             playlevel: String::new(),
             difficulty: 0,
             judgerank: 2,
+            judgerank_fraction: 0.0,
             judgerank_type: JudgeRankType::BmsRank,
             total: 100.0,
             total_type: TotalType::Bmson,
@@ -234,6 +237,18 @@ impl BMSModel {
             .unwrap_or(LnType::LongNote)
     }
 
+    /// Judge rank including its fractional part.
+    pub fn judgerank_exact(&self) -> f64 {
+        self.judgerank as f64 + self.judgerank_fraction
+    }
+
+    /// Set the judge rank, splitting it into `judgerank` and `judgerank_fraction`.
+    pub fn set_judgerank_exact(&mut self, judgerank: f64) {
+        let whole = judgerank.floor();
+        self.judgerank = whole as i32;
+        self.judgerank_fraction = judgerank - whole;
+    }
+
     pub fn total_notes(&self) -> i32 {
         crate::model::bms_model_utils::total_notes(self)
     }
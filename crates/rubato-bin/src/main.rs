@@ -21,6 +21,7 @@ use rubato::core::bms_player_mode::BMSPlayerMode;
 use rubato::core::config::DisplayMode;
 use rubato::core::main_controller::MainController;
 use rubato::core::version;
+use rubato::external::result_screenshot::ResultScreenshotInfo;
 use rubato::render::egui_integration::EguiIntegration;
 use rubato::render::gpu_context::GpuContext;
 use rubato::render::gpu_texture_manager::GpuTextureManager;
@@ -55,6 +56,79 @@ struct Args {
     /// Direct play mode
     #[arg(short = 's', long)]
     play: bool,
+
+    /// Autoplay BMS_FILE without a window or audio device and print the final score as JSON
+    #[arg(long, requires = "bms_path", conflicts_with_all = ["practice", "replay", "play"])]
+    headless: bool,
+
+    /// Give up a headless run after this many seconds
+    #[arg(
+        long,
+        value_name = "SECS",
+        default_value_t = 600,
+        requires = "headless"
+    )]
+    headless_timeout: u64,
+
+    /// Directory for config, databases and player data
+    #[arg(long, value_name = "DIR", global = true)]
+    data_dir: Option<PathBuf>,
+
+    /// Keep all data beside the executable (same as placing portable.txt there)
+    #[arg(long, global = true)]
+    portable: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Scan the configured BMS folders into the song database
+    Scan {
+        /// Rebuild every entry instead of only new and changed files
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Print a JSON summary of a chart: metadata, hashes, note counts and density
+    Analyze {
+        /// Chart to analyze
+        #[arg(value_name = "BMS_FILE")]
+        bms_path: PathBuf,
+
+        /// LN mode used to decode undefined long notes (0 LN, 1 CN, 2 HCN)
+        #[arg(long, value_name = "MODE", default_value_t = 0)]
+        lnmode: i32,
+    },
+
+    /// Re-judge a saved replay and check it against the stored score
+    VerifyReplay {
+        /// BMS file the replay was recorded on
+        #[arg(value_name = "BMS_FILE")]
+        bms_path: PathBuf,
+
+        /// Replay slot (1-4)
+        #[arg(short = 'r', long, value_name = "NUM", default_value_t = 1)]
+        replay: u8,
+    },
+
+    /// Write every stored score with its song title as CSV or JSON
+    ExportScores {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+        format: ExportFormat,
+
+        /// Write to a file instead of stdout
+        #[arg(short = 'o', long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum ExportFormat {
+    Csv,
+    Json,
 }
 
 fn main() -> Result<()> {
@@ -63,6 +137,9 @@ fn main() -> Result<()> {
     #[cfg(feature = "dhat-heap")]
     let _profiler = dhat::Profiler::new_heap();
 
+    // Profiling spans (no-op without the `profiling` feature)
+    rubato::profiling::init();
+
     let mut args = Args::parse();
 
     // Canonicalize BMS path before any CWD change so relative paths resolve
@@ -73,6 +150,22 @@ fn main() -> Result<()> {
     {
         args.bms_path = Some(abs);
     }
+    if let Some(Command::VerifyReplay { bms_path, .. } | Command::Analyze { bms_path, .. }) =
+        args.command.as_mut()
+        && bms_path.is_relative()
+        && let Ok(abs) = bms_path.canonicalize()
+    {
+        *bms_path = abs;
+    }
+    if let Some(Command::ExportScores {
+        output: Some(output),
+        ..
+    }) = args.command.as_mut()
+        && output.is_relative()
+        && let Ok(cwd) = std::env::current_dir()
+    {
+        *output = cwd.join(&output);
+    }
 
     // Determine player mode from arguments
     // Java: MainLoader.main() parses -a, -p, -r, -r1..r4, -s flags
@@ -98,21 +191,45 @@ fn main() -> Result<()> {
 
     // Java: if (Files.exists(Config.configpath) && (bmsPath != null || auto != null))
     let config_exists = {
-        let cwd = std::env::current_dir().unwrap_or_default();
-        match rubato::skin::config::resolve_config_dir(&cwd) {
-            Some(config_dir) => {
-                // Anchor CWD to the resolved config root so all relative paths
-                // (songpath, skinpath, etc.) resolve correctly when launched
-                // from a subdirectory.
-                if let Err(e) = std::env::set_current_dir(&config_dir) {
-                    warn!("Failed to set CWD to config dir {:?}: {}", config_dir, e);
-                }
-                true
-            }
-            None => false,
+        let data_dir = rubato::core::data_dir::resolve_data_dir(
+            &rubato::core::data_dir::DataDirOptions::from_env(args.data_dir.clone(), args.portable),
+        );
+        info!(
+            "Data directory: {} ({:?})",
+            data_dir.root.display(),
+            data_dir.source
+        );
+        // Anchor CWD to the data directory so all relative paths in Config
+        // (songpath, skinpath, etc.) resolve inside it.
+        if let Err(e) = std::fs::create_dir_all(&data_dir.root)
+            .and_then(|()| std::env::set_current_dir(&data_dir.root))
+        {
+            warn!("Failed to use data dir {:?}: {}", data_dir.root, e);
         }
+        data_dir.has_config()
     };
 
+    if let Some(command) = args.command {
+        if !config_exists && !matches!(command, Command::Analyze { .. }) {
+            anyhow::bail!("No config found; run from the rubato directory");
+        }
+        return match command {
+            Command::Scan { all } => {
+                subsystem_init::init_song_database_with_options(all);
+                Ok(())
+            }
+            Command::Analyze { bms_path, lnmode } => analyze(&bms_path, lnmode),
+            Command::VerifyReplay { bms_path, replay } => verify_replay(&bms_path, replay),
+            Command::ExportScores { format, output } => export_scores(format, output),
+        };
+    }
+
+    if args.headless
+        && let Some(bms_path) = args.bms_path
+    {
+        return headless(bms_path, Duration::from_secs(args.headless_timeout));
+    }
+
     if config_exists && (args.bms_path.is_some() || player_mode.is_some()) {
         play(args.bms_path, player_mode)?;
     } else {
@@ -124,6 +241,100 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Print the chart summary as JSON.
+fn analyze(bms_path: &std::path::Path, lnmode: i32) -> Result<()> {
+    let analysis = rubato::song::chart_analysis::analyze_chart(bms_path, lnmode)?;
+    println!("{}", serde_json::to_string_pretty(&analysis)?);
+    Ok(())
+}
+
+/// Export all scores of the configured player to `output` or stdout.
+fn export_scores(format: ExportFormat, output: Option<PathBuf>) -> Result<()> {
+    use rubato::core::score_export;
+    use std::io::Write;
+
+    let mut config = rubato::core::config::Config::read().unwrap_or_default();
+    rubato::skin::validatable::Validatable::validate(&mut config);
+    let rows = score_export::read_library_scores(&config)?;
+    let mut out: Box<dyn Write> = match output {
+        Some(ref path) => Box::new(std::io::BufWriter::new(std::fs::File::create(path)?)),
+        None => Box::new(std::io::stdout().lock()),
+    };
+    match format {
+        ExportFormat::Csv => score_export::write_csv(&rows, &mut out)?,
+        ExportFormat::Json => {
+            serde_json::to_writer_pretty(&mut out, &rows)?;
+            writeln!(out)?;
+        }
+    }
+    out.flush()?;
+    info!("Exported {} scores", rows.len());
+    Ok(())
+}
+
+/// Re-run a saved replay through the judge and report whether it matches the stored score.
+///
+/// Exits with an error when the replay is tampered or stale.
+fn verify_replay(bms_path: &std::path::Path, slot: u8) -> Result<()> {
+    use rubato::core::main_loader::MainLoader;
+
+    let (config, player, _) = MainLoader::start();
+    let index = i32::from(slot.clamp(1, 4)) - 1;
+    let result =
+        rubato::play::replay_verifier::verify_replay_file(&config, &player, bms_path, index)?;
+    let counts = &result.recomputed.judge_counts;
+    println!(
+        "EX score {} / max combo {} / BP {}",
+        result.recomputed.exscore(),
+        result.recomputed.maxcombo,
+        result.recomputed.minbp
+    );
+    println!(
+        "PG {} / GR {} / GD {} / BD {} / PR {} / MS {}",
+        counts.epg + counts.lpg,
+        counts.egr + counts.lgr,
+        counts.egd + counts.lgd,
+        counts.ebd + counts.lbd,
+        counts.epr + counts.lpr,
+        counts.ems + counts.lms
+    );
+    if result.is_valid() {
+        println!("Replay {} matches the stored score", slot);
+        return Ok(());
+    }
+    for m in &result.mismatches {
+        println!("{}: stored {}, replay {}", m.field, m.stored, m.recomputed);
+    }
+    anyhow::bail!("Replay {} does not match the stored score", slot)
+}
+
+/// Autoplay a chart through the state machine without window or audio device.
+///
+/// The song database is not scanned; the chart is loaded straight from
+/// `bms_path`. Logs go to stderr, so stdout only carries the score JSON.
+fn headless(bms_path: PathBuf, timeout: Duration) -> Result<()> {
+    use rubato::core::headless_runner::{HeadlessOptions, run_headless};
+    use rubato::core::main_loader::MainLoader;
+
+    let mut main_controller = MainLoader::play(
+        Some(bms_path),
+        Some(BMSPlayerMode::AUTOPLAY),
+        true,
+        None,
+        None,
+        false,
+    )?;
+    subsystem_init::init_song_information_database(&mut main_controller);
+
+    let options = HeadlessOptions {
+        timeout,
+        ..Default::default()
+    };
+    let score = run_headless(&mut main_controller, &options)?;
+    println!("{}", serde_json::to_string_pretty(&score)?);
+    Ok(())
+}
+
 /// Java: MainLoader.start(Stage) → opens the launcher/configuration UI.
 ///
 /// Delegates to MainLoader::start() for Config/PlayerConfig loading,
@@ -178,8 +389,33 @@ fn launch() -> Result<()> {
 /// EventLoop + wgpu context for the render loop.
 fn play(bms_path: Option<PathBuf>, player_mode: Option<BMSPlayerMode>) -> Result<()> {
     use rubato::core::main_loader::MainLoader;
+    use rubato::core::single_instance::{self, Acquired, InstanceRequest};
+
+    // Hand the chart to an instance that is already running instead of
+    // opening a second window.
+    let request = bms_path
+        .clone()
+        .map_or(InstanceRequest::Focus, InstanceRequest::Open);
+    let instance_lock = match single_instance::acquire(&single_instance::endpoint(), &request) {
+        Ok(Acquired::Primary(lock)) => Some(lock),
+        Ok(Acquired::Forwarded) => {
+            info!("Already running; handed the request to the running instance");
+            return Ok(());
+        }
+        Err(e) => {
+            warn!("Single-instance guard unavailable: {}", e);
+            None
+        }
+    };
 
-    subsystem_init::init_song_database();
+    // Open the databases while the window and first state come up.
+    let pending_databases = subsystem_init::open_databases_async();
+
+    // Save the play in progress on a panic; the next launch offers to record it.
+    let recovery_path = std::env::current_dir()
+        .unwrap_or_default()
+        .join(rubato::play::crash_recovery::RECOVERY_PATH);
+    rubato::play::crash_recovery::install_panic_hook(recovery_path);
 
     // Initialize shared key state BEFORE MainController creation.
     // BMSPlayerInputProcessor::new() captures the global SharedKeyState at construction
@@ -192,9 +428,13 @@ fn play(bms_path: Option<PathBuf>, player_mode: Option<BMSPlayerMode>) -> Result
     // Java: MainLoader.play() handles config, illegal songs, player config, and controller creation.
     // It sets config.windowWidth/Height from resolution before creating MainController.
     let mut main_controller = MainLoader::play(bms_path, player_mode, true, None, None, false)?;
+    if let Some(lock) = instance_lock {
+        main_controller.set_instance_lock(lock);
+    }
+    rubato::i18n::set_locale(&main_controller.config().locale);
 
+    main_controller.set_pending_databases(pending_databases);
     subsystem_init::init_audio_driver(&mut main_controller)?;
-    subsystem_init::init_song_information_database(&mut main_controller);
 
     let _listener_handles = subsystem_init::init_state_listeners(&mut main_controller);
     subsystem_init::init_ir_config(&mut main_controller);
@@ -207,6 +447,7 @@ fn play(bms_path: Option<PathBuf>, player_mode: Option<BMSPlayerMode>) -> Result
         main_controller.config().clone(),
         main_controller.command_queue().clone(),
     );
+    main_controller.set_imgui(Box::new(rubato::modmenu::imgui_renderer::ImGuiRenderer));
 
     // Extract window config from the controller's Config
     // Java: these were set by MainLoader.play() → config.setWindowWidth/Height
@@ -303,6 +544,12 @@ impl ApplicationHandler for RubatoApp {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         // Populate monitor cache for VideoConfigurationView
         rubato::platform::update_monitors_from_winit(event_loop);
+        // Let the launcher offer these modes on its next start
+        if let Err(e) = rubato::platform::save_display_mode_cache(std::path::Path::new(
+            rubato::platform::DISPLAY_MODE_CACHE,
+        )) {
+            warn!("Failed to save display mode cache: {}", e);
+        }
 
         // Sync display mode cache to core MainLoader
         {
@@ -553,19 +800,24 @@ impl RubatoApp {
                 );
 
                 // Create GPU texture manager for skin image rendering
-                let texture_manager = GpuTextureManager::new(
+                let mut texture_manager = GpuTextureManager::new(
                     &gpu.device,
                     &gpu.queue,
                     &sprite_pipeline.texture_layout,
                     &sprite_pipeline.sampler_nearest,
                     &sprite_pipeline.sampler_linear,
                 );
+                texture_manager
+                    .set_memory_budget(self.controller.config().render.texture_budget_bytes());
                 self.texture_manager = Some(texture_manager);
                 self.sprite_pipeline = Some(sprite_pipeline);
 
                 // Initialize egui integration
                 // Java: ImGui.createContext() + imGuiGl3.init() + imGuiGlfw.init()
                 let egui_integration = EguiIntegration::new(&gpu.device, gpu.surface_format());
+                // egui's built-in fonts have no CJK glyphs; IME input in the
+                // search field is drawn with the configured system font.
+                egui_integration.add_fallback_font(&self.controller.config().paths.systemfontpath);
                 let egui_state = egui_winit::State::new(
                     egui_integration.ctx.clone(),
                     egui::ViewportId::ROOT,
@@ -686,11 +938,22 @@ impl RubatoApp {
                     window.set_fullscreen(Some(winit::window::Fullscreen::Borderless(monitor)));
                 }
             }
+            if rubato::core::window_command::take_focus_request() {
+                window.set_minimized(false);
+                window.focus_window();
+            }
             let screenshot_requested = rubato::core::window_command::take_screenshot_request();
+            let result_screenshot = self.controller.take_result_screenshot();
 
             let full_output = self.run_egui_frame(&window);
 
-            self.submit_gpu_frame(&gpu, &window, full_output, screenshot_requested);
+            self.submit_gpu_frame(
+                &gpu,
+                &window,
+                full_output,
+                screenshot_requested,
+                result_screenshot,
+            );
         })) {
             Ok(()) => {}
             Err(payload) => {
@@ -702,11 +965,13 @@ impl RubatoApp {
         // Put gpu back
         self.gpu = Some(gpu);
 
+        rubato::profiling::finish_frame();
         window.request_redraw();
     }
 
     /// Run the egui frame: gather input, render UI overlay, return output.
     fn run_egui_frame(&mut self, window: &Window) -> Option<egui::FullOutput> {
+        rubato::profile_scope!("egui_frame");
         // Gather diagnostic info before egui frame (avoids borrow conflicts)
         let diag_state_type = self.controller.current_state_type();
         let diag_has_skin = self
@@ -714,6 +979,7 @@ impl RubatoApp {
             .current_state()
             .map(|s| s.main_state_data().skin.is_some())
             .unwrap_or(false);
+        let starting_up = self.controller.is_starting_up();
         let (Some(egui_state), Some(egui_integration)) =
             (&mut self.egui_state, &self.egui_integration)
         else {
@@ -721,7 +987,11 @@ impl RubatoApp {
         };
 
         // Java: ImGuiRenderer.start() → ImGuiRenderer.render() → ImGuiRenderer.end()
-        let raw_input = egui_state.take_egui_input(window);
+        let mut raw_input = egui_state.take_egui_input(window);
+        // Mod menu navigation from lane keys, queued by the main loop
+        raw_input
+            .events
+            .extend(rubato::modmenu::controller_navigation::take_events());
         let full_output = egui_integration.ctx.run(raw_input, |ctx| {
             rubato::modmenu::imgui_renderer::ImGuiRenderer::render_ui(ctx);
 
@@ -757,6 +1027,18 @@ impl RubatoApp {
                             );
                         });
                 });
+
+            // Loading indicator while the databases and select skin load
+            if starting_up {
+                egui::Area::new(egui::Id::new("startup_loading"))
+                    .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+                    .show(ctx, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.colored_label(egui::Color32::WHITE, "Loading...");
+                        });
+                    });
+            }
         });
         egui_state.handle_platform_output(window, full_output.platform_output.clone());
         Some(full_output)
@@ -769,7 +1051,9 @@ impl RubatoApp {
         window: &Window,
         full_output: Option<egui::FullOutput>,
         screenshot_requested: bool,
+        result_screenshot: Option<ResultScreenshotInfo>,
     ) {
+        rubato::profile_scope!("submit_gpu_frame");
         // wgpu render pass: clear screen, sprite batch flush, egui overlay, present
         match gpu.current_texture() {
             Ok(output) => {
@@ -855,8 +1139,13 @@ impl RubatoApp {
                 gpu.queue.submit(std::iter::once(encoder.finish()));
 
                 // Capture screenshot after render pass, before present
-                if screenshot_requested {
-                    self.capture_screenshot(gpu, &output.texture);
+                if screenshot_requested || result_screenshot.is_some() {
+                    self.capture_screenshot(
+                        gpu,
+                        &output.texture,
+                        screenshot_requested,
+                        result_screenshot,
+                    );
                 }
 
                 output.present();
@@ -944,7 +1233,15 @@ impl RubatoApp {
 
     /// Capture the rendered frame and save as a PNG screenshot.
     /// Must be called after the render pass with the rendered texture.
-    fn capture_screenshot(&self, gpu: &GpuContext, texture: &wgpu::Texture) {
+    /// `manual` saves the hotkey screenshot; `result_screenshot` saves the
+    /// automatic result screenshot stamped with its metadata.
+    fn capture_screenshot(
+        &self,
+        gpu: &GpuContext,
+        texture: &wgpu::Texture,
+        manual: bool,
+        result_screenshot: Option<ResultScreenshotInfo>,
+    ) {
         let Some(ref surface_config) = gpu.surface_config else {
             warn!("Cannot capture screenshot: no surface config");
             return;
@@ -1027,6 +1324,18 @@ impl RubatoApp {
                     }
                 }
 
+                if let Some(shot) = result_screenshot {
+                    let frame = if manual {
+                        rgba.clone()
+                    } else {
+                        std::mem::take(&mut rgba)
+                    };
+                    self.save_result_screenshot(frame, width, height, &shot);
+                }
+                if !manual {
+                    return;
+                }
+
                 // Save as PNG
                 let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
                 let path = format!("screenshot_{}.png", timestamp);
@@ -1048,6 +1357,38 @@ impl RubatoApp {
         }
     }
 
+    /// Stamp and save an automatic result screenshot, then share it when configured.
+    fn save_result_screenshot(
+        &self,
+        rgba: Vec<u8>,
+        width: u32,
+        height: u32,
+        shot: &ResultScreenshotInfo,
+    ) {
+        let config = &self.controller.config().integration;
+        match rubato::external::result_screenshot::save_result_screenshot(
+            rgba, width, height, shot, config,
+        ) {
+            Ok(path) => {
+                info!("Result screenshot saved: {}", path.display());
+                if shot.social_share {
+                    let accounts = rubato::external::social_share::SocialAccounts::from_config(
+                        config,
+                        self.controller.player_config(),
+                    );
+                    let text =
+                        rubato::external::social_share::share_text(&config.social_share_text, shot);
+                    rubato::external::social_share::share_result_in_background(
+                        accounts, text, &path,
+                    );
+                } else if config.result_screenshot_share {
+                    self.post_screenshot_actions(&path.to_string_lossy());
+                }
+            }
+            Err(e) => warn!("Failed to save result screenshot: {:#}", e),
+        }
+    }
+
     /// Run post-screenshot actions: clipboard copy and webhook send if configured.
     fn post_screenshot_actions(&self, path: &str) {
         let config = self.controller.config();
@@ -1168,6 +1509,32 @@ impl rubato::http_download_submitter::HttpDownloadSubmitter for HttpDownloadProc
     fn submit_md5_task(&self, md5: &str, task_name: &str) {
         self.0.submit_md5_task(md5, task_name);
     }
+
+    fn submit_url_task(&self, url: &str, hash: &str, task_name: &str) {
+        self.0.submit_url_task(url, hash, task_name);
+    }
+
+    fn submit_md5_task_in_table(&self, md5: &str, task_name: &str, table: Option<&str>) {
+        self.0.submit_md5_task_in_table(md5, task_name, table);
+    }
+
+    fn submit_url_task_in_table(
+        &self,
+        url: &str,
+        hash: &str,
+        task_name: &str,
+        table: Option<&str>,
+    ) {
+        self.0.submit_url_task_in_table(url, hash, task_name, table);
+    }
+
+    fn library_revision(&self) -> u64 {
+        self.0.library_revision()
+    }
+
+    fn set_downloads_suspended(&self, suspended: bool) {
+        self.0.set_downloads_suspended(suspended);
+    }
 }
 
 /// Spawn a child process and wait for it with an optional timeout.
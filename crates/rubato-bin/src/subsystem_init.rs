@@ -8,23 +8,11 @@ use std::sync::Arc;
 use anyhow::Result;
 use log::{info, warn};
 
-use rubato::core::main_controller::MainController;
+use rubato::core::main_controller::{MainController, StartupDatabases};
 use rubato::ir_resend_service::IrResendService;
 
 use crate::{HttpDownloadProcessorWrapper, SongDbMainControllerRef, SongDbMusicDatabaseAdapter};
 
-/// Initialize the song database from config paths.
-/// Must be called before MainLoader::play() which calls take_score_database_accessor().
-pub(crate) fn init_song_database() {
-    init_song_database_impl(false, true);
-}
-
-/// Initialize the song database with explicit update_all flag.
-/// Called from the launcher for Load All BMS / Load Diff BMS actions.
-pub(crate) fn init_song_database_with_options(update_all: bool) {
-    init_song_database_impl(update_all, false);
-}
-
 /// Initialize the song information database on MainController.
 ///
 /// The select screen reads main BPM and density data through MainControllerAccess,
@@ -50,9 +38,56 @@ pub(crate) fn init_song_information_database(controller: &mut MainController) {
     }
 }
 
-fn init_song_database_impl(update_all: bool, set_accessor: bool) {
+/// Open the song and song information databases on a background thread and
+/// check for illegal songs, so a slow disk does not hold up the window.
+/// The controller installs them and enters the first state once ready.
+pub(crate) fn open_databases_async() -> std::thread::JoinHandle<StartupDatabases> {
+    std::thread::spawn(|| {
+        use rubato::core::config::Config;
+        use rubato::core::main_loader::MainLoader;
+        use rubato::skin::validatable::Validatable;
+        use rubato::song::song_information_accessor::SongInformationAccessor;
+        use rubato::song::sqlite_song_database_accessor::SQLiteSongDatabaseAccessor;
+
+        let mut config = Config::read().unwrap_or_default();
+        config.validate();
+        let mut databases = StartupDatabases::default();
+        match SQLiteSongDatabaseAccessor::new(&config.paths.songpath, &config.paths.bmsroot) {
+            Ok(accessor) => {
+                databases.illegal_songs = MainLoader::check_illegal_songs_in(&accessor);
+                info!("Song database initialized: {}", &config.paths.songpath);
+                databases.songdb = Some(Box::new(accessor));
+            }
+            Err(e) => {
+                warn!(
+                    "Song database init failed: {}. Continuing without song DB.",
+                    e
+                );
+            }
+        }
+        match SongInformationAccessor::new(&config.paths.songinfopath) {
+            Ok(db) => {
+                info!(
+                    "Song information database initialized: {}",
+                    config.paths.songinfopath
+                );
+                databases.infodb = Some(Box::new(db));
+            }
+            Err(e) => {
+                warn!(
+                    "Song information database init failed: {}. Continuing without song info DB.",
+                    e
+                );
+            }
+        }
+        databases
+    })
+}
+
+/// Initialize the song database with explicit update_all flag.
+/// Called from the launcher for Load All BMS / Load Diff BMS actions.
+pub(crate) fn init_song_database_with_options(update_all: bool) {
     use rubato::core::config::Config;
-    use rubato::core::main_loader::MainLoader;
     use rubato::skin::validatable::Validatable;
 
     let mut config = Config::read().unwrap_or_default();
@@ -65,15 +100,9 @@ fn init_song_database_impl(update_all: bool, set_accessor: bool) {
         &config.paths.bmsroot,
     ) {
         Ok(accessor) => {
-            // Known limitation: runs a full scan on every launch. A background or
-            // on-demand scan would reduce startup time for large libraries.
-            // Java: MainLoader calls updateSongDatas() before creating the controller.
             info!("Scanning BMS files from configured paths...");
             accessor.update_song_datas(None, &config.paths.bmsroot, update_all, false, None);
             info!("Song database initialized: {}", &config.paths.songpath);
-            if set_accessor {
-                MainLoader::set_score_database_accessor(Box::new(accessor));
-            }
         }
         Err(e) => {
             warn!(
@@ -157,19 +186,42 @@ pub(crate) fn init_state_listeners(controller: &mut MainController) -> Vec<Box<d
         )
     };
     if use_discord_rpc {
-        let (sender, listener) = rubato::external::discord_listener::DiscordListener::new();
+        let (sender, listener) = rubato::external::discord_listener::DiscordListener::new(
+            cfg_clone.integration.discord_hide_song_details,
+        );
         controller.add_event_sender(sender);
         handles.push(Box::new(listener));
     }
     if use_obs_ws {
         let obs_client = rubato::external::obs::obs_ws_client::ObsWsClient::new(&cfg_clone);
-        let (sender, listener) = rubato::external::obs::obs_listener::ObsListener::new(cfg_clone);
+        let (sender, listener) =
+            rubato::external::obs::obs_listener::ObsListener::new(cfg_clone.clone());
         controller.add_event_sender(sender);
         handles.push(Box::new(listener));
         if let Ok(client) = obs_client {
             controller.set_obs_client(Box::new(client));
         }
     }
+    if cfg_clone.integration.overlay_server {
+        let port = cfg_clone
+            .integration
+            .overlay_server_port
+            .clamp(0, u16::MAX as i32) as u16;
+        match rubato::external::overlay_server::OverlayServer::start(port) {
+            Ok(server) => controller.set_overlay_server(server),
+            Err(e) => log::warn!("Failed to start overlay server: {}", e),
+        }
+    }
+    if cfg_clone.integration.state_push_server {
+        let port = cfg_clone
+            .integration
+            .state_push_port
+            .clamp(0, u16::MAX as i32) as u16;
+        match rubato::stream::state_push_server::StatePushServer::start(port) {
+            Ok(server) => controller.set_state_push_server(server),
+            Err(e) => log::warn!("Failed to start state push server: {}", e),
+        }
+    }
     handles
 }
 
@@ -267,13 +319,10 @@ fn init_http_download_processor(
     controller: &mut MainController,
     config: &rubato::core::config::Config,
 ) {
-    // Look up download source by config.network.download_source, fall back to default
+    // Look up download source by config.network.download_source, fall back to the
+    // highest priority one
     let source_meta = rubato::song::md_processor::http_download_processor::DOWNLOAD_SOURCES
-        .get(&config.network.download_source)
-        .copied()
-        .unwrap_or_else(|| {
-            rubato::song::md_processor::http_download_processor::HttpDownloadProcessor::default_download_source()
-        });
+        .primary(&config.network.download_source);
     let http_download_source: Arc<
         dyn rubato::song::md_processor::http_download_source::HttpDownloadSource,
     > = Arc::from(source_meta.build(config));
@@ -305,6 +354,17 @@ fn init_http_download_processor(
                     config.network.download_directory.clone(),
                 ),
             );
+            processor.set_max_parallel_downloads(
+                config.network.max_concurrent_downloads.max(1) as usize,
+            );
+            processor.set_speed_limit(config.network.download_speed_limit.max(0) as u64 * 1024);
+            processor.set_placement_rules(rubato::song::md_processor::placement::PlacementRules {
+                subfolder: config.network.download_placement.clone(),
+                sanitize_names: config.network.sanitize_download_names,
+            });
+            if config.network.mirror_fallback {
+                processor.set_fallback_sources(build_fallback_sources(config, source_meta.name()));
+            }
 
             // Java: DownloadTaskState.initialize(httpDownloadProcessor)
             rubato::song::md_processor::download_task_state::DownloadTaskState::initialize();
@@ -330,6 +390,23 @@ fn init_http_download_processor(
     }
 }
 
+/// Every download source except `primary`, highest priority first, for mirror
+/// fallback. The override url only applies to the primary source, so mirrors are
+/// built with their own urls.
+fn build_fallback_sources(
+    config: &rubato::core::config::Config,
+    primary: &str,
+) -> Vec<Arc<dyn rubato::song::md_processor::http_download_source::HttpDownloadSource>> {
+    let mut mirror_config = config.clone();
+    mirror_config.network.override_download_url.clear();
+    rubato::song::md_processor::http_download_processor::DOWNLOAD_SOURCES
+        .entries()
+        .iter()
+        .filter(|meta| meta.name() != primary)
+        .map(|meta| Arc::from(meta.build(&mirror_config)))
+        .collect()
+}
+
 /// Initialize the stream controller for request-mode song streaming.
 ///
 /// Java: MainController.initializeStates() lines 561-564:
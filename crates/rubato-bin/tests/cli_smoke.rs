@@ -220,3 +220,82 @@ fn reexec_child_inherits_working_directory() {
     );
     assert_normal_exit_or_live_gui_run(&output);
 }
+
+#[test]
+fn headless_requires_bms_file() {
+    let output = rubato_bin()
+        .arg("--headless")
+        .output()
+        .expect("failed to execute binary");
+
+    assert!(
+        !output.status.success(),
+        "expected --headless without BMS_FILE to be rejected"
+    );
+}
+
+/// Autoplay a short chart headlessly and check the score JSON on stdout.
+/// Needs no display or audio device; the chart plays in real time.
+#[test]
+fn headless_autoplay_prints_score_json() {
+    let tmp = tempfile::TempDir::new().expect("failed to create tempdir");
+    let chart =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test-bms/minimal_7k.bms");
+
+    let result = run_with_timeout(
+        rubato_bin()
+            .arg("--headless")
+            .arg("--data-dir")
+            .arg(tmp.path())
+            .arg(chart),
+        Duration::from_secs(120),
+    );
+
+    assert!(!result.timed_out, "headless run did not finish");
+    assert!(
+        result.output.status.success(),
+        "headless run failed: {}",
+        String::from_utf8_lossy(&result.output.stderr)
+    );
+    let score: serde_json::Value =
+        serde_json::from_slice(&result.output.stdout).expect("stdout should be score JSON");
+    assert!(score["epg"].as_i64().unwrap_or(0) > 0, "{score}");
+}
+
+#[test]
+fn analyze_prints_chart_summary_json() {
+    let chart =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test-bms/minimal_7k.bms");
+
+    let output = rubato_bin()
+        .arg("analyze")
+        .arg(chart)
+        .output()
+        .expect("failed to execute binary");
+
+    assert!(output.status.success(), "analyze failed");
+    let analysis: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be JSON");
+    assert_eq!(analysis["mode"], "beat-7k");
+    assert_eq!(analysis["totalNotes"], 12);
+}
+
+#[test]
+fn export_scores_writes_csv_header_for_empty_library() {
+    let tmp = tempfile::TempDir::new().expect("failed to create tempdir");
+    write_minimal_config(tmp.path());
+    let out = tmp.path().join("scores.csv");
+
+    let output = rubato_bin()
+        .arg("--data-dir")
+        .arg(tmp.path())
+        .arg("export-scores")
+        .arg("-o")
+        .arg(&out)
+        .output()
+        .expect("failed to execute binary");
+
+    assert!(output.status.success(), "export-scores failed");
+    let csv = std::fs::read_to_string(&out).expect("export file should exist");
+    assert!(csv.starts_with("sha256,title,"), "{csv}");
+}
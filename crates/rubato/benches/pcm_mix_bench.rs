@@ -0,0 +1,137 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+
+use rubato::audio::pcm_mix;
+
+const SAMPLE_RATE: usize = 44100;
+const CHANNELS: usize = 2;
+
+/// Half a second of stereo keysound with a little variation per sound.
+fn make_keysound(seed: usize) -> Vec<i16> {
+    (0..SAMPLE_RATE / 2 * CHANNELS)
+        .map(|i| (((i * 31 + seed * 977) % 65536) as i32 - 32768) as i16)
+        .collect()
+}
+
+/// The bounds-checked per-sample loop the chart renderer used before.
+fn mix_i16_scalar(samples: &[i16], dst_start: usize, mix_buffer: &mut [f32]) {
+    let mut dst_index = dst_start;
+    for &sample in samples {
+        if dst_index >= mix_buffer.len() {
+            break;
+        }
+        mix_buffer[dst_index] += sample as f32 / 32768.0;
+        dst_index += 1;
+    }
+}
+
+/// The per-sample division resampler `FloatPCM` used before.
+fn resample_scalar(src: &[f32], channels: usize, src_rate: i64, dst_rate: i64) -> Vec<f32> {
+    let len = ((src.len() as i64 / channels as i64) * dst_rate / src_rate) as usize * channels;
+    let mut dst = vec![0f32; len];
+    for i in 0..(len / channels) as i64 {
+        let position = i * src_rate / dst_rate;
+        let modv = (i * src_rate) % dst_rate;
+        for j in 0..channels as i64 {
+            let base = (position * channels as i64 + j) as usize;
+            let next = ((position + 1) * channels as i64 + j) as usize;
+            dst[(i * channels as i64 + j) as usize] = if modv != 0 && next < src.len() {
+                (src[base] * (dst_rate - modv) as f32 + src[next] * modv as f32) / dst_rate as f32
+            } else {
+                src.get(base).copied().unwrap_or(0.0)
+            };
+        }
+    }
+    dst
+}
+
+/// Overlapping keysounds, staggered by 10ms, mixed into one second of output.
+fn bench_mix(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pcm_mix");
+    for voices in [16, 64, 128] {
+        let sounds: Vec<Vec<i16>> = (0..voices).map(make_keysound).collect();
+        let offset = |voice: usize| voice * SAMPLE_RATE / 100 * CHANNELS % (SAMPLE_RATE / 2);
+        let mut buffer = vec![0f32; SAMPLE_RATE * CHANNELS];
+        group.bench_with_input(BenchmarkId::new("scalar", voices), &voices, |b, _| {
+            b.iter(|| {
+                buffer.fill(0.0);
+                for (voice, sound) in sounds.iter().enumerate() {
+                    mix_i16_scalar(sound, offset(voice), &mut buffer);
+                }
+                black_box(&buffer);
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("chunked", voices), &voices, |b, _| {
+            b.iter(|| {
+                buffer.fill(0.0);
+                for (voice, sound) in sounds.iter().enumerate() {
+                    pcm_mix::mix_i16(&mut buffer[offset(voice)..], sound, 1.0 / 32768.0);
+                }
+                black_box(&buffer);
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_convert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pcm_convert");
+    let mix: Vec<f32> = (0..SAMPLE_RATE * CHANNELS)
+        .map(|i| (i % 400) as f32 / 100.0 - 2.0)
+        .collect();
+    let mut shorts = vec![0i16; mix.len()];
+    group.bench_function("f32_to_i16/scalar", |b| {
+        b.iter(|| {
+            for (d, s) in shorts.iter_mut().zip(&mix) {
+                *d = ((s * 0.5).clamp(-1.0, 1.0) * 32767.0) as i16;
+            }
+            black_box(&shorts);
+        })
+    });
+    group.bench_function("f32_to_i16/chunked", |b| {
+        b.iter(|| {
+            pcm_mix::f32_to_i16(&mix, &mut shorts, 0.5);
+            black_box(&shorts);
+        })
+    });
+
+    let mono: Vec<f32> = mix[..SAMPLE_RATE].to_vec();
+    let mut stereo = vec![0f32; SAMPLE_RATE * 2];
+    group.bench_function("mono_to_stereo/scalar", |b| {
+        b.iter(|| {
+            for i in 0..stereo.len() / 2 {
+                for j in 0..2 {
+                    stereo[i * 2 + j] = mono[i];
+                }
+            }
+            black_box(&stereo);
+        })
+    });
+    group.bench_function("mono_to_stereo/chunked", |b| {
+        b.iter(|| {
+            pcm_mix::spread_first_channel(&mono, 1, &mut stereo, 2);
+            black_box(&stereo);
+        })
+    });
+    group.finish();
+}
+
+fn bench_resample(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pcm_resample");
+    let src: Vec<f32> = (0..SAMPLE_RATE * CHANNELS)
+        .map(|i| ((i * 37) % 101) as f32 / 50.0 - 1.0)
+        .collect();
+    group.bench_function("44100_to_48000/scalar", |b| {
+        b.iter(|| black_box(resample_scalar(&src, CHANNELS, 44100, 48000)))
+    });
+    group.bench_function("44100_to_48000/incremental", |b| {
+        b.iter(|| {
+            let mut dst = vec![0f32; 48000 * CHANNELS];
+            pcm_mix::resample_linear(&src, CHANNELS, 44100, 48000, &mut dst);
+            black_box(dst)
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_mix, bench_convert, bench_resample);
+criterion_main!(benches);
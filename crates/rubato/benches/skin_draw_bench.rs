@@ -0,0 +1,200 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+use rubato::render::pixmap::{Pixmap, PixmapFormat};
+use rubato::render::sprite_batch::SpriteBatch;
+use rubato::render::texture::{Texture, TextureRegion};
+use rubato::skin::main_state::MainState;
+use rubato::skin::skin::Skin;
+use rubato::skin::skin_drawable::SkinDrawable;
+use rubato::skin::skin_header::SkinHeader;
+use rubato::skin::skin_image::SkinImage;
+use rubato::skin::skin_object::DestinationParams;
+use rubato::skin::skin_render_context::SkinRenderContext;
+use rubato::skin::skin_timer::Timer;
+use rubato::skin::timer_access::TimerAccess;
+use rubato::skin::timer_id::TimerId;
+
+/// Counts heap allocations so each mode can report allocations per frame.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+struct BenchState {
+    timer: Timer,
+    prepare_fps: i32,
+}
+
+impl TimerAccess for BenchState {
+    fn now_time(&self) -> i64 {
+        self.timer.now_time()
+    }
+    fn now_micro_time(&self) -> i64 {
+        self.timer.now_micro_time()
+    }
+    fn micro_timer(&self, timer_id: TimerId) -> i64 {
+        self.timer.micro_timer(timer_id)
+    }
+    fn timer(&self, timer_id: TimerId) -> i64 {
+        self.timer.timer(timer_id)
+    }
+    fn now_time_for(&self, timer_id: TimerId) -> i64 {
+        self.timer.now_time_for(timer_id)
+    }
+    fn is_timer_on(&self, timer_id: TimerId) -> bool {
+        self.timer.is_timer_on(timer_id)
+    }
+}
+
+impl SkinRenderContext for BenchState {
+    fn prepare_fps(&self) -> i32 {
+        self.prepare_fps
+    }
+}
+
+impl MainState for BenchState {}
+
+/// Build a skin of `count` images drawn from a handful of pixmap textures.
+/// Every `animated_every`-th image moves each frame; the rest are static,
+/// like the frames, panels and labels that make up most of a play skin.
+fn make_heavy_skin(count: usize, animated_every: usize) -> Skin {
+    let textures: Vec<Texture> = (0..8)
+        .map(|_| Texture::from_pixmap(&Pixmap::new(64, 64, PixmapFormat::RGBA8888)))
+        .collect();
+    let mut skin = Skin::new(SkinHeader::new());
+    for i in 0..count {
+        let mut image =
+            SkinImage::new_with_single(TextureRegion::from_texture(textures[i % 8].clone()));
+        let x = (i % 64) as f32 * 20.0;
+        let y = (i / 64) as f32 * 10.0;
+        let keyframes: &[(i64, f32)] = if i % animated_every == 0 {
+            &[(0, 0.0), (1000, 100.0)]
+        } else {
+            &[(0, 0.0)]
+        };
+        for &(time, dx) in keyframes {
+            image.data.set_destination_with_int_timer_ops(
+                &DestinationParams {
+                    time,
+                    x: x + dx,
+                    y,
+                    w: 16.0,
+                    h: 8.0,
+                    acc: 0,
+                    a: 255,
+                    r: 255,
+                    g: 255,
+                    b: 255,
+                    blend: if i % 5 == 0 { 2 } else { 0 },
+                    filter: 0,
+                    angle: if i % 7 == 0 { 15 } else { 0 },
+                    center: 0,
+                    loop_val: 0,
+                },
+                0,
+                &[0],
+            );
+        }
+        skin.add(Box::new(image));
+    }
+    skin
+}
+
+struct Frame {
+    skin: Skin,
+    batch: SpriteBatch,
+    state: BenchState,
+}
+
+impl Frame {
+    /// `prepare_fps` 0 evaluates every property each frame; 1 evaluates them
+    /// once per second, leaving mostly the draw path to measure.
+    fn new(count: usize, retained: bool, prepare_fps: i32) -> Self {
+        let mut skin = make_heavy_skin(count, 10);
+        skin.set_draw_retention(retained);
+        let state = BenchState {
+            timer: Timer::default(),
+            prepare_fps,
+        };
+        skin.prepare(&state);
+        Self {
+            skin,
+            batch: SpriteBatch::new(),
+            state,
+        }
+    }
+
+    fn draw(&mut self) {
+        self.state.timer.now_micro_time += 16_667;
+        self.state.timer.now_time = (self.state.timer.now_micro_time / 1000) % 1000;
+        self.batch.flush();
+        self.skin.swap_sprite_batch(&mut self.batch);
+        self.skin.draw_all_objects(&self.state);
+        self.skin.swap_sprite_batch(&mut self.batch);
+    }
+}
+
+fn report_allocations(count: usize) {
+    for retained in [false, true] {
+        let mut frame = Frame::new(count, retained, 0);
+        // Warm up buffers and recordings before counting.
+        for _ in 0..4 {
+            frame.draw();
+        }
+        let frames = 100;
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        for _ in 0..frames {
+            frame.draw();
+        }
+        let per_frame = (ALLOCATIONS.load(Ordering::Relaxed) - before) / frames;
+        eprintln!(
+            "skin_frame/{}/{}: {} allocations per frame",
+            if retained { "retained" } else { "immediate" },
+            count,
+            per_frame
+        );
+    }
+}
+
+fn bench_skin_draw(c: &mut Criterion) {
+    for (group_name, prepare_fps) in [("skin_draw", 1), ("skin_frame", 0)] {
+        let mut group = c.benchmark_group(group_name);
+        for count in [1000, 4000] {
+            if prepare_fps == 0 {
+                report_allocations(count);
+            }
+            for retained in [false, true] {
+                let name = if retained { "retained" } else { "immediate" };
+                let mut frame = Frame::new(count, retained, prepare_fps);
+                group.bench_with_input(BenchmarkId::new(name, count), &count, |b, _| {
+                    b.iter(|| frame.draw())
+                });
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_skin_draw);
+criterion_main!(benches);
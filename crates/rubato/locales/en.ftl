@@ -0,0 +1,587 @@
+# English UI messages. Every message here must also exist in ja.ftl.
+
+## language
+language-label = Language
+language-system = System
+
+## launcher
+launcher-player = Player:
+launcher-mode = Mode
+launcher-start = Start
+launcher-load-all-bms = Load All BMS
+launcher-load-diff-bms = Load Diff BMS
+launcher-import-score = Import Score
+launcher-exit = Exit
+launcher-import-beatoraja-settings = Import beatoraja Settings
+launcher-import-beatoraja-prompt = Import its BMS folders, tables, key bindings, gauge, hi-speed, IR accounts and skin selections?
+launcher-import = Import
+launcher-not-now = Not Now
+launcher-whats-new = What's New
+launcher-ok = OK
+launcher-chart-details = Chart Details
+launcher-display-mode = Display Mode:
+launcher-resolution = Resolution:
+launcher-window-size = Window Size:
+launcher-custom = Custom
+launcher-vsync = VSync:
+launcher-max-fps = Max FPS:
+launcher-1000hz-input-polling = 1000Hz Input Polling:
+launcher-1000hz-judge-thread = 1000Hz Judge Thread:
+launcher-texture-budget = Texture Budget (MB):
+launcher-driver = Driver:
+launcher-device = Device:
+launcher-refresh = Refresh
+launcher-audio-buffer = Audio Buffer:
+launcher-max-simultaneous = Max Simultaneous:
+launcher-sample-rate = Sample Rate:
+launcher-auto = Auto
+launcher-freq-option = Freq Option:
+launcher-unprocessed = Unprocessed
+launcher-frequency = Frequency
+launcher-fast-forward = Fast Forward:
+launcher-system-volume = System Volume:
+launcher-key-volume = Key Volume:
+launcher-bg-volume = BG Volume:
+launcher-normalize-volume = Normalize Volume:
+launcher-loop-result-sound = Loop Result Sound:
+launcher-loop-course-result-sound = Loop Course Result Sound:
+launcher-play-test-sound = Play Test Sound
+launcher-input-configuration = Input Configuration
+launcher-keyboard = Keyboard
+launcher-duration = Duration:
+launcher-latency-offset = Latency Offset (ms):
+launcher-debounce = Debounce
+launcher-debounce-window = Re-press Window (ms, 0 = off):
+launcher-name = Name:
+launcher-jkoc-hack = JKOC Hack:
+launcher-analog-scratch = Analog Scratch:
+launcher-axis-threshold = Axis Threshold (%):
+launcher-axis-hysteresis = Axis Hysteresis (%):
+launcher-analog-threshold = Analog Threshold:
+launcher-analog-mode = Analog Mode:
+launcher-sensitivity-ticks = Sensitivity (ticks):
+launcher-deadzone-ticks = Deadzone (ticks):
+launcher-active-duration = Active Duration (ms):
+launcher-mouse-scratch = Mouse Scratch
+launcher-enable = Enable:
+launcher-modmenu-combo = Mod Menu Button Combination
+launcher-modmenu-combo-hint = Hold these buttons together to open or close the mod menu without a keyboard.
+launcher-time-threshold = Time Threshold:
+launcher-distance = Distance:
+launcher-mode-label = Mode:
+launcher-bms-folders = BMS Folders
+launcher-folder = Folder
+launcher-charts = Charts
+launcher-scan = Scan
+launcher-remove = Remove
+launcher-add-bms-folder = Add BMS folder...
+launcher-scan-new-changed = Scan New/Changed
+launcher-rescan-all = Rescan All
+launcher-watch-folders = Scan folders changed while the game runs
+launcher-scan-completed = Scan completed.
+launcher-skin-configuration = Skin Configuration
+launcher-cache-skin-image = Cache Skin Image (CIM)
+launcher-category = Category:
+launcher-skin = Skin:
+launcher-no-skins-found = (no skins found)
+launcher-play-options = Play Options
+launcher-hispeed = HiSpeed:
+launcher-configured-per-play-mode = (configured per play mode)
+launcher-target = Target:
+launcher-ghost-key-beams = Replay ghost key beams:
+launcher-accessibility = Accessibility
+launcher-color-palette = Color palette:
+launcher-color-palette-skin = Skin colors
+launcher-color-palette-red-green = Red-green safe (protanopia/deuteranopia)
+launcher-color-palette-blue-yellow = Blue-yellow safe (tritanopia)
+launcher-high-contrast-notes = High-contrast notes:
+launcher-other-settings = Other Settings
+launcher-clipboard-screenshot = Clipboard Screenshot
+launcher-result-screenshot = Result Screenshot
+launcher-capture-automatically = Capture automatically:
+launcher-screenshot-folder = Folder:
+launcher-clipboard-webhook = Clipboard / webhook:
+launcher-social-sharing = Social Sharing (F7 on the result screen)
+launcher-mastodon-instance = Mastodon instance:
+launcher-mastodon-access-token = Mastodon access token:
+launcher-misskey-instance = Misskey instance:
+launcher-misskey-access-token = Misskey access token:
+launcher-post-text = Post text:
+launcher-ipfs-url = IPFS URL:
+launcher-http-download = HTTP Download
+launcher-download-source = Download Source:
+launcher-default-url = Default URL:
+launcher-override-url = Override URL:
+launcher-parallel-downloads = Parallel Downloads:
+launcher-mirror-fallback = Mirror Fallback:
+launcher-place-downloads-in = Place Downloads In:
+launcher-sanitize-folder-names = Sanitize Folder Names:
+launcher-speed-limit = Speed Limit (KiB/s, 0 = off):
+launcher-only-download-outside-play = Only Download Outside Play:
+launcher-diagnostics = Diagnostics
+launcher-metrics-dump = Metrics Dump:
+launcher-metrics-dump-hint = metrics.json (empty to disable)
+launcher-rivals = Rivals
+launcher-no-imported-rivals = No imported rivals.
+launcher-import-rival-file = Import...
+launcher-rival-import-hint = score.db or JSON score export; an existing name is replaced
+launcher-refresh-all = Refresh All
+launcher-internet-ranking = Internet Ranking
+launcher-no-ir-configurations = No IR configurations.
+launcher-add-ir-configuration = Add IR Configuration
+launcher-ir-slot = IR Slot:
+launcher-enabled = Enabled:
+launcher-log-in-at-startup = Log in at startup
+launcher-ir-name = IR Name:
+launcher-home = Home:
+launcher-user-id = User ID:
+launcher-password = Password:
+launcher-send-mode = Send Mode:
+launcher-import-rival = Import Rival:
+launcher-ir-import-score = Import Score:
+launcher-test-login = Test Login
+launcher-stream-configuration = Stream Configuration
+launcher-enable-request = Enable Request:
+launcher-notify-request = Notify Request:
+launcher-max-request-count = Max Request Count:
+launcher-control-token = Control Token:
+launcher-control-token-hint = Lets bots send !!ctl <token> skip | top <sha256> | autoplay
+launcher-now-playing-file = Now Playing File
+launcher-path = Path:
+launcher-now-playing-hint = nowplaying.txt or nowplaying.json (empty to disable)
+launcher-overlay-server = Overlay Server (restart required)
+launcher-port = Port:
+launcher-browser-source = Browser Source:
+launcher-state-push = State Push WebSocket (restart required)
+launcher-websocket-url = WebSocket URL:
+launcher-enable-discord-rich-presence = Enable Discord Rich Presence
+launcher-discord-hide-song = Hide song title and artist
+launcher-webhook = Webhook
+launcher-send-on = Send On:
+launcher-bot-name = Bot Name:
+launcher-avatar-url = Avatar URL:
+launcher-conditions = Conditions:
+launcher-personal-best-only = Personal best only
+launcher-clears-only = Clears only
+launcher-minimum-rank = Minimum Rank:
+launcher-embed-template = Embed Template:
+launcher-embed-template-hint = JSON file with an "embed" object; leave empty for the default
+launcher-webhook-urls = Webhook URLs:
+launcher-add = Add
+launcher-tab-video = Video
+launcher-tab-audio = Audio
+launcher-tab-input = Input
+launcher-tab-key-binding = Key Binding
+launcher-tab-folders = Folders
+launcher-tab-tables = Tables
+launcher-tab-skin = Skin
+launcher-tab-option = Option
+launcher-tab-other = Other
+launcher-tab-stream = Stream
+launcher-beatoraja-found = A beatoraja installation was found at { $path }.
+launcher-import-failed = Import failed: { $error }
+launcher-scan-progress = Scanning: { $processed }/{ $total } processed, { $new } new
+launcher-scan-failed = Scan failed: { $error }
+launcher-display-modes-detected = Display: { $width } x { $height } ({ $count } fullscreen modes detected)
+launcher-display-modes-pending = Display: { $width } x { $height } (fullscreen modes are detected on the first game start)
+launcher-resolution-unsupported = { $resolution } (unsupported)
+launcher-display-clamped = Does not fit this display; will be saved as { $resolution } with a { $width } x { $height } window
+launcher-audio-test-buffer = { $frames } frames ({ $ms } ms)
+launcher-audio-test-unknown = unknown
+launcher-audio-test-latency = { $ms } ms
+launcher-audio-test-not-reported = not reported
+launcher-audio-test-result = { $device }: { $rate } Hz, buffer { $buffer }, output latency { $latency }
+launcher-audio-test-failed = Audio test failed: { $error }
+launcher-latency-tuning = Latency tuning
+launcher-latency-tuning-hint = Connect the output to the default input with a loopback cable, or hold a microphone near the speakers, then measure.
+launcher-period-count = Period count
+launcher-period-count-hint = Number of device buffers queued ahead of playback
+launcher-latency-buffered = Buffered
+launcher-latency-stored = Stored output latency
+launcher-measure-latency = Measure Round Trip
+launcher-latency-result = { $device }: heard { $heard }/{ $played } clicks, round trip { $round_trip } ms, output latency { $output } ms
+launcher-latency-recommended = Recommended audio offset: { $offset } ms
+launcher-latency-apply = Apply
+launcher-latency-apply-hint = Store these buffer settings and the measured latency, and use it as the audio offset
+launcher-controller = Controller { $number } ({ $number }P)
+launcher-select-bms-folder = Select BMS folder
+launcher-none = None
+launcher-twitter-consumer-key = Twitter consumer key:
+launcher-twitter-consumer-secret = Twitter consumer secret:
+launcher-twitter-access-token = Twitter access token:
+launcher-twitter-access-token-secret = Twitter access token secret:
+launcher-select-rival-score-file = Select the rival's score file
+launcher-rival-imported = Imported { $name }
+launcher-rivals-refreshed = Refreshed { $count } rivals
+launcher-ir-logged-in = Logged in as { $name } (ID { $id })
+launcher-ir-logged-in-rank = Logged in as { $name } (ID { $id }, rank { $rank })
+launcher-ir-login-succeeded = Login succeeded: { $message }
+launcher-ir-login-failed = Login failed: { $message }
+
+## update
+update-updates = Updates
+update-check-on-start = Check for updates when the launcher opens
+update-check-now = Check Now
+update-no-build-for-this-platform = No build for this platform
+update-release-page = Release page
+update-check-failed = Update check failed: { $error }
+update-up-to-date = Up to date ({ $version })
+update-available = { $name } is available
+update-download = Download { $name } ({ $size } MB)
+update-saved = Saved to { $path }. Close rubato and extract it over this installation.
+update-download-failed = Download failed: { $error }
+
+## keybind
+keybind-key-binding = Key Binding
+keybind-instructions = Click a row, then press the key, button, MIDI note or MIDI CC to assign.
+keybind-key = Key
+keybind-keyboard = Keyboard
+keybind-controller = Controller
+keybind-clear = Clear
+keybind-midi-velocity-threshold = MIDI Velocity Threshold:
+keybind-midi-channel = MIDI Channel:
+keybind-midi-channel-hint = -1 listens on every channel
+keybind-press-input = Press a key, button or MIDI note (Esc to cancel)
+keybind-assigned-key = Assigned key { $key }
+keybind-assigned-button = Assigned { $device } { $button }
+keybind-no-free-slot = No free controller slot for { $device }
+keybind-assigned-midi = Assigned MIDI { $input }
+keyconfig-press-key = Press a key (Esc to cancel)
+keyconfig-press-button = Press a controller button or move an axis
+keyconfig-play-midi = Play a MIDI note or move a CC
+keyconfig-assigned-hotkey = Assigned { $key } to { $hotkey }
+keyconfig-reserved = { $key } is reserved
+
+## downloads
+downloads-task = Task
+downloads-progress = Progress
+downloads-speed = Speed
+downloads-eta = ETA
+downloads-op = Op
+downloads-cancel = Cancel
+downloads-password = Password
+downloads-extract = Extract
+downloads-download-tasks = Download Tasks
+downloads-on-hold = Downloads are on hold until play ends
+downloads-empty = No Download Task. Try selecting missing bms to submit new task!
+downloads-running = Running
+downloads-expired = Expired
+downloads-sources = Sources
+downloads-source = Source
+downloads-success = Success
+downloads-failed = Failed
+downloads-last-error = Last Error
+downloads-pause = Pause
+downloads-resume = Resume
+downloads-retry = Retry
+downloads-running-count = Running: { $count }
+downloads-expired-count = Expired: { $count }
+downloads-failures = { $failures } ({ $broken } broken)
+
+## rate
+rate-rate-modifier = Rate Modifier
+rate-controls = Controls
+rate-rate-enabled = Rate Enabled
+rate-description = Modifies the chart playback rate to be faster or slower by a given percent.
+rate-reset = Reset
+rate-enabled-help = When enabled positive rate scores save locally without a lamp, negative rate scores save as assisted plays.
+
+## modmenu
+modmenu-controller-navigation = Controller navigation
+modmenu-controller-navigation-help = Key 1 / scratch up: previous item. Key 3 / scratch down: next item. Key 2 / key 4: change the value. Key 5: select. Key 7: close the menu.
+modmenu-show-rate-modifier-window = Show Rate Modifier Window
+modmenu-show-random-trainer-window = Show Random Trainer Window
+modmenu-show-judge-trainer-window = Show Judge Trainer Window
+modmenu-show-practice-window = Show Practice Window
+modmenu-show-skin-configuration-window = Show Skin Configuration Window
+modmenu-show-skin-widget-manager-window = Show Skin Widget Manager Window
+modmenu-show-song-manager-window = Show Song Manager Window
+modmenu-show-download-tasks-window = Show Download Tasks Window
+modmenu-show-performance-monitor-window = Show Performance Monitor Window
+modmenu-show-profiler-window = Show Profiler Window
+modmenu-show-input-diagnostics-window = Show Input Diagnostics Window
+modmenu-show-ir-status-window = Show IR Status Window
+modmenu-show-notification-history-window = Show Notification History Window
+modmenu-show-misc-setting-window = Show Misc Setting Window
+modmenu-endless-dream-debug-information = Endless Dream Debug Information
+modmenu-commit-hash = Commit hash: { $hash }
+modmenu-build-time = Build time: { $time }
+
+## input-diag
+input-diag-input-diagnostics = Input Diagnostics
+input-diag-polling-interval = Polling interval: ---
+input-diag-press-a-key = Press a key to see its activity
+input-diag-key = Key
+input-diag-state = State
+input-diag-presses = Presses
+input-diag-device = Device
+input-diag-last-event = Last event
+input-diag-on = ON
+input-diag-off = off
+input-diag-key-chatter = Key chatter
+input-diag-no-chatter-detected = No chatter detected
+input-diag-suppressed = Suppressed
+input-diag-reset = Reset
+input-diag-polling-interval-stats = Polling interval: { $average } ms avg / { $max } ms max
+input-diag-scratch-rate = Scratch rate: { $rate } /s
+input-diag-ms-ago = { $ms } ms ago
+input-diag-keyboard = Keyboard
+input-diag-controller = Controller
+input-diag-key-chatter-help = Presses ignored because they came within the debounce window after a release. Set the window per play mode in the launcher.
+
+## stats
+stats-statistics = Statistics
+stats-play-time = Play time
+stats-play-count = Play count
+stats-clear-count = Clear count
+stats-notes-hit = Notes hit
+stats-notes-per-day = Notes per day
+stats-lamps-per-level = Lamps per table level
+stats-score-trend = Recent score improvements
+stats-no-data = No data
+stats-day-notes = { $date }: { $notes } notes, { $plays } plays, { $time } played
+
+## ir-status
+ir-status-ir-status = IR Status
+ir-status-not-connected = Not connected to any IR
+ir-status-ir = IR
+ir-status-player = Player
+ir-status-home = Home
+ir-status-retry-now = Retry now
+ir-status-pending = Pending submissions: { $count }
+ir-status-last-submission = Last submission: { $submission }
+ir-status-submission-none = none
+ir-status-sent = sent
+ir-status-failed = failed
+ir-status-failed-reason = failed ({ $reason })
+ir-status-submission = { $title } to { $ir } { $outcome }, { $age }
+age-seconds = { $count }s ago
+age-minutes = { $count }m ago
+age-hours = { $count }h ago
+
+## judge-trainer
+judge-trainer-judge-trainer = Judge Trainer
+judge-trainer-override-judge = Override chart's judge
+judge-trainer-judge = judge
+judge-trainer-judge-windows-default = Judge windows: chart default
+judge-trainer-judge-windows-scaled = Judge windows: x{ $scale } (score is not saved)
+
+## misc
+misc-misc-settings = Misc Settings
+misc-notification-positions = Notification Positions
+misc-play-mode = Play Mode
+misc-enable-lift = Enable Lift
+misc-enable-hidden = Enable Hidden
+misc-enable-lane-cover = Enable Lane Cover
+misc-enable-constant = Enable Constant
+misc-switch = Switch
+misc-reload-list = Reload list
+misc-player-profile = Player Profile
+misc-lift = Lift
+misc-hidden = Hidden
+misc-lane-cover = Lane Cover
+misc-fade-in-time = Fade-in Time (ms)
+
+## notifications
+notifications-notifications = Notifications
+notifications-info = Info
+notifications-success = Success
+notifications-warning = Warning
+notifications-error = Error
+notifications-clear = Clear
+notifications-no-notifications = No notifications
+
+## perf
+perf-performance-monitor = Performance Monitor
+perf-frames = Frames
+perf-no-frame-data = No frame data
+perf-audio-judge = Audio / Judge
+perf-audio-callback-load-none = Audio callback load: N/A
+perf-judge-latency-none = Judge latency: N/A
+perf-watch = Watch
+perf-no-watch-data = No watch data
+perf-name = Name
+perf-avg-ms = Avg (ms)
+perf-std-ms = Std (ms)
+perf-events = Events
+perf-filter-threshold-ms = Filter threshold (ms):
+perf-sort-by-duration = Sort by duration
+perf-no-event-data = No event data
+perf-average = Average
+perf-low-1 = 1% low
+perf-low-01 = 0.1% low
+perf-audio-underruns = Audio underruns: { $count }
+perf-audio-callback-load = Audio callback load: avg { $average }% / max { $max }%
+perf-judge-latency = Judge latency: avg { $average } ms / max { $max } ms
+
+## practice
+practice-practice = Practice
+practice-not-active = Start a chart in practice mode to edit its settings.
+practice-applies-next-run = Range and rate apply from the next run.
+practice-start = Start
+practice-end = End
+practice-loop-section = Loop section
+practice-freeze-gauge = Freeze gauge
+practice-show-invisible-notes = Show invisible notes
+practice-rate-percent = rate %
+practice-freeze-gauge-help = The gauge stays at its start value, so the run never fails.
+
+## profiler
+profiler-profiler = Profiler
+profiler-pause = Pause
+profiler-slowest-recent-frame = Slowest recent frame
+profiler-clear = Clear
+profiler-no-profiling-data = No profiling data
+
+## random-trainer
+random-trainer-random-trainer = Random Trainer
+random-trainer-controls = Controls
+random-trainer-trainer-enabled = Trainer Enabled
+random-trainer-track-current-random = Track Current Random
+random-trainer-black-white-random-select = Black/White Random Select
+random-trainer-mirror = Mirror
+random-trainer-shift-left = Shift Left
+random-trainer-shift-right = Shift Right
+random-trainer-candidates = Candidates
+random-trainer-generate = Generate
+random-trainer-locked-none = Locked: none
+random-trainer-lock-current = Lock current
+random-trainer-unlock = Unlock
+random-trainer-locked = Locked: { $order } (RANDOM)
+
+## skin-menu
+skin-menu-skin = Skin
+skin-menu-open = Open
+skin-menu-live-editing = Live Editing
+skin-menu-reset-prompt = Reset current skin's settings to default
+skin-menu-are-you-sure = ARE YOU SURE?
+skin-menu-click-outside-to-close = (click outside popup to close)
+skin-menu-freeze-timers = Freeze timers
+skin-menu-no-skin-loaded = No skin loaded
+skin-menu-other = Other
+skin-menu-save = Save
+skin-menu-reset = Reset
+skin-menu-confirm = Confirm
+skin-menu-random = Random
+
+## widgets
+widgets-skin-widgets = Skin Widgets
+widgets-no-skin-is-loaded = No skin is loaded
+widgets-skinwidgets = SkinWidgets
+widgets-history = History
+widgets-undo = Undo
+widgets-show-position = Show Position
+widgets-export = Export
+widgets-save = Save
+widgets-reset = Reset
+widgets-columns = Columns
+widgets-toggle = Toggle
+widgets-edit = Edit
+widgets-edit-skin-widget = Edit Skin Widget
+widgets-submit = Submit
+widgets-move = Move
+widgets-no-history = No history
+widgets-description = Description
+
+## song-manager
+song-manager-song-manager = Song Manager
+song-manager-not-a-selectable-song = Not a selectable song
+song-manager-sort-by-last-played = Sort by last played
+song-manager-open-folder = Open folder
+song-manager-rescan-folder = Rescan folder
+song-manager-remove-from-db = Remove from DB
+song-manager-tag = Tag
+song-manager-save = Save
+song-manager-diagnostics = Diagnostics
+song-manager-user-metadata = My metadata
+song-manager-display-title = Display title
+song-manager-artist-romanized = Artist (romanized)
+song-manager-tags = Tags
+song-manager-notes = Notes
+song-manager-song = Song: { $name }
+song-manager-last-played = Last played: { $time }
+song-manager-favorite-song = Favorite song
+song-manager-favorite-chart = Favorite chart
+song-manager-hide-song = Hide song
+song-manager-hide-chart = Hide chart
+song-manager-duplicates = Duplicate charts
+song-manager-find-duplicates = Find duplicates
+song-manager-no-duplicates = No chart is stored under more than one path
+song-manager-keep-hide-others = Keep, hide others
+song-manager-keep-delete-others = Keep, delete others
+
+## toast
+toast-success = Success
+toast-warning = Warning
+toast-error = Error
+toast-info = Info
+
+## notify
+notify-song-load-failed = Failed to load BMS: the song was not found or has errors
+notify-course-songs-missing = Failed to load course: some songs were not found
+notify-random-course-songs-missing = Failed to load random course: some songs were not found
+notify-course-stages-missing = Some stages are missing. Download them to start the course
+notify-course-pending = { $course } starts when all stages are downloaded
+notify-course-ready = All stages of { $course } are ready. Starting the course
+notify-random-select-empty = No { $filter } in this folder
+notify-library-scan-started = Scanning library in the background
+notify-chart-queued = { $name } opens when you return to song select
+notify-table-updating = Updating table: { $name }
+notify-config-reload-failed = Config reload failed: { $error }
+notify-config-reloaded = Config reloaded: { $settings }
+notify-config-restart-required = Restart required to apply: { $settings } (not applied)
+notify-ir-connects-on-restart = { $ir } connects on next restart
+notify-ir-sent = IR score sent: { $title } ({ $ir })
+notify-ir-send-failed = IR score submission failed: { $message } ({ $ir })
+notify-open-ranking = Open ranking
+notify-crash-interrupted = { $title } was interrupted by a crash (EX score { $exscore })
+notify-crash-save-score = Save score
+notify-recovered-score-saved = Recovered score saved: { $title }
+notify-recovered-score-failed = Failed to save recovered score: { $error }
+notify-chart-offset = Chart offset: { $offset } ms
+notify-url-copied = Copied URL to clipboard.
+notify-md5-copied = MD5 hash copied: { $hash }
+notify-sha256-copied = SHA256 hash copied: { $hash }
+notify-menu-text-copied = Copied highlighted menu text: { $text }
+notify-http-download-disabled = HTTP download is not enabled
+notify-bms-search-not-found = { $title } was not found on BMS Search
+notify-bms-search-failed = BMS Search lookup failed: { $error }
+notify-bms-search-no-direct-download = { $title } has no direct download; opening its page
+notify-already-submitted = Already submitted
+notify-download-song-not-found = Cannot find the specified song on { $source }
+notify-download-source-error = { $source } returned a severe error: { $error }
+notify-download-submitted = New download task [{ $name }] submitted
+notify-download-failed = Failed downloading from { $source }: { $error }
+notify-download-mirror-fallback = { $source } failed for { $name }, trying { $mirror }
+notify-download-password-required = { $name } needs a password to extract
+notify-extract-failed = Failed extracting { $path }: { $error }
+notify-download-extracted = Downloaded and extracted. Rebuilding the download directory
+notify-download-added = { $name } added to library
+notify-open-folder = Open folder
+notify-archive-delete-failed = Failed to delete the archive file automatically
+notify-request-skipped = Skipped request { $title }
+notify-request-accepted = Accepted request { $title }
+notify-request-duplicate = { $title } has already been added
+notify-request-added = Added { $title } to the stream request list
+notify-request-song-missing = The requested song is not in the collection
+notify-requests-autoplay = Requests will be played with autoplay
+notify-requests-normal = Requests will be played normally
+notify-no-social-accounts = No social accounts are configured
+notify-share-posted = Posted result to { $service }
+notify-share-failed = Failed to post result to { $service }: { $error }
+notify-obs-replay-saved = OBS: Replay buffer saved ({ $reason })
+notify-obs-recording-kept = OBS: Recording will be kept.
+notify-obs-status = OBS: { $message }.
+notify-screenshot-saved = Screenshot saved: { $path }
+notify-screenshot-clipboard = Screenshot saved to the clipboard
+notify-webhook-http-error = Unexpected HTTP response code when sending webhook: { $status }
+notify-webhook-template-ignored = Webhook template ignored: { $error }
+notify-lr2ir-empty-response = LR2IR returned an empty response.
+notify-lr2ir-ghost-parse-failed = Could not parse the ghost data response from LR2IR.
+notify-lr2ir-score-parse-failed = Failed to get score data from LR2IR: XML parse error
+notify-lr2ir-unsupported-random = Unsupported random option: { $option }
+notify-lr2ir-http-error = Failed to send request to LR2IR: HTTP error code: { $status }
+notify-lr2ir-request-failed = Failed to send request to LR2IR: { $error }
+notify-ghost-load-failed = Failed to load ghost data.
+notify-widget-changes-copied = Copied changes to clipboard
+notify-widget-move-no-origin = Cannot submit the move result because there's no original position
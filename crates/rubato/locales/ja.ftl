@@ -0,0 +1,587 @@
+# 日本語の UI メッセージ。en.ftl と同じメッセージをすべて定義すること。
+
+## language
+language-label = 言語
+language-system = システム
+
+## launcher
+launcher-player = プレイヤー:
+launcher-mode = モード
+launcher-start = 起動
+launcher-load-all-bms = 全 BMS を読み込み
+launcher-load-diff-bms = 差分 BMS を読み込み
+launcher-import-score = スコアをインポート
+launcher-exit = 終了
+launcher-import-beatoraja-settings = beatoraja の設定をインポート
+launcher-import-beatoraja-prompt = BMS フォルダ、難易度表、キー割り当て、ゲージ、ハイスピード、IR アカウント、スキンの選択をインポートしますか?
+launcher-import = インポート
+launcher-not-now = 後で
+launcher-whats-new = 更新内容
+launcher-ok = OK
+launcher-chart-details = 譜面の詳細
+launcher-display-mode = 表示モード:
+launcher-resolution = 解像度:
+launcher-window-size = ウィンドウサイズ:
+launcher-custom = カスタム
+launcher-vsync = 垂直同期:
+launcher-max-fps = 最大 FPS:
+launcher-1000hz-input-polling = 1000Hz 入力ポーリング:
+launcher-1000hz-judge-thread = 1000Hz 判定スレッド:
+launcher-texture-budget = テクスチャ上限 (MB):
+launcher-driver = ドライバ:
+launcher-device = デバイス:
+launcher-refresh = 更新
+launcher-audio-buffer = オーディオバッファ:
+launcher-max-simultaneous = 最大同時発音数:
+launcher-sample-rate = サンプルレート:
+launcher-auto = 自動
+launcher-freq-option = 速度変更方式:
+launcher-unprocessed = 無処理
+launcher-frequency = 周波数
+launcher-fast-forward = 早送り:
+launcher-system-volume = システム音量:
+launcher-key-volume = キー音量:
+launcher-bg-volume = BG 音量:
+launcher-normalize-volume = 音量を正規化:
+launcher-loop-result-sound = リザルト音をループ:
+launcher-loop-course-result-sound = コースリザルト音をループ:
+launcher-play-test-sound = テスト音を再生
+launcher-input-configuration = 入力設定
+launcher-keyboard = キーボード
+launcher-duration = 持続時間:
+launcher-latency-offset = 遅延補正 (ms):
+launcher-debounce = チャタリング対策
+launcher-debounce-window = 再押下無視時間 (ms、0 = 無効):
+launcher-name = 名前:
+launcher-jkoc-hack = JKOC ハック:
+launcher-analog-scratch = アナログ皿:
+launcher-axis-threshold = 軸のしきい値 (%):
+launcher-axis-hysteresis = 軸のヒステリシス (%):
+launcher-analog-threshold = アナログしきい値:
+launcher-analog-mode = アナログモード:
+launcher-sensitivity-ticks = 感度 (tick):
+launcher-deadzone-ticks = デッドゾーン (tick):
+launcher-active-duration = 有効時間 (ms):
+launcher-mouse-scratch = マウス皿
+launcher-enable = 有効:
+launcher-modmenu-combo = MODメニューのボタン組み合わせ
+launcher-modmenu-combo-hint = これらのボタンを同時に押すと、キーボードなしでMODメニューを開閉します。
+launcher-time-threshold = 時間しきい値:
+launcher-distance = 距離:
+launcher-mode-label = モード:
+launcher-bms-folders = BMS フォルダ
+launcher-folder = フォルダ
+launcher-charts = 譜面数
+launcher-scan = スキャン
+launcher-remove = 削除
+launcher-add-bms-folder = BMS フォルダを追加...
+launcher-scan-new-changed = 新規・変更分をスキャン
+launcher-rescan-all = すべて再スキャン
+launcher-watch-folders = ゲーム中に変更されたフォルダをスキャン
+launcher-scan-completed = スキャンが完了しました。
+launcher-skin-configuration = スキン設定
+launcher-cache-skin-image = スキン画像をキャッシュ (CIM)
+launcher-category = カテゴリ:
+launcher-skin = スキン:
+launcher-no-skins-found = (スキンが見つかりません)
+launcher-play-options = プレイオプション
+launcher-hispeed = ハイスピード:
+launcher-configured-per-play-mode = (プレイモードごとに設定)
+launcher-target = ターゲット:
+launcher-ghost-key-beams = リプレイゴーストのキービーム:
+launcher-accessibility = アクセシビリティ
+launcher-color-palette = カラーパレット:
+launcher-color-palette-skin = スキンの色
+launcher-color-palette-red-green = 赤緑色覚向け (P型/D型)
+launcher-color-palette-blue-yellow = 青黄色覚向け (T型)
+launcher-high-contrast-notes = ハイコントラストノーツ:
+launcher-other-settings = その他の設定
+launcher-clipboard-screenshot = クリップボードにスクリーンショット
+launcher-result-screenshot = リザルトのスクリーンショット
+launcher-capture-automatically = 自動で撮影:
+launcher-screenshot-folder = フォルダ:
+launcher-clipboard-webhook = クリップボード / Webhook:
+launcher-social-sharing = SNS 共有 (リザルト画面で F7)
+launcher-mastodon-instance = Mastodon インスタンス:
+launcher-mastodon-access-token = Mastodon アクセストークン:
+launcher-misskey-instance = Misskey インスタンス:
+launcher-misskey-access-token = Misskey アクセストークン:
+launcher-post-text = 投稿文:
+launcher-ipfs-url = IPFS URL:
+launcher-http-download = HTTP ダウンロード
+launcher-download-source = ダウンロード元:
+launcher-default-url = 既定の URL:
+launcher-override-url = 上書き URL:
+launcher-parallel-downloads = 同時ダウンロード数:
+launcher-mirror-fallback = ミラーへのフォールバック:
+launcher-place-downloads-in = ダウンロード先:
+launcher-sanitize-folder-names = フォルダ名を整形:
+launcher-speed-limit = 速度制限 (KiB/s、0 = 無効):
+launcher-only-download-outside-play = プレイ中はダウンロードしない:
+launcher-diagnostics = 診断
+launcher-metrics-dump = メトリクス出力:
+launcher-metrics-dump-hint = metrics.json (空欄で無効)
+launcher-rivals = ライバル
+launcher-no-imported-rivals = インポートしたライバルはいません。
+launcher-import-rival-file = インポート...
+launcher-rival-import-hint = score.db または JSON スコア出力。同じ名前のライバルは置き換えられます
+launcher-refresh-all = すべて更新
+launcher-internet-ranking = インターネットランキング
+launcher-no-ir-configurations = IR が設定されていません。
+launcher-add-ir-configuration = IR 設定を追加
+launcher-ir-slot = IR スロット:
+launcher-enabled = 有効:
+launcher-log-in-at-startup = 起動時にログイン
+launcher-ir-name = IR 名:
+launcher-home = ホーム:
+launcher-user-id = ユーザー ID:
+launcher-password = パスワード:
+launcher-send-mode = 送信モード:
+launcher-import-rival = ライバルをインポート:
+launcher-ir-import-score = スコアをインポート:
+launcher-test-login = ログインをテスト
+launcher-stream-configuration = 配信設定
+launcher-enable-request = リクエストを有効化:
+launcher-notify-request = リクエストを通知:
+launcher-max-request-count = 最大リクエスト数:
+launcher-control-token = 操作トークン:
+launcher-control-token-hint = ボットから !!ctl <token> skip | top <sha256> | autoplay を送信できます
+launcher-now-playing-file = 再生中ファイル
+launcher-path = パス:
+launcher-now-playing-hint = nowplaying.txt または nowplaying.json (空欄で無効)
+launcher-overlay-server = オーバーレイサーバー (再起動が必要)
+launcher-port = ポート:
+launcher-browser-source = ブラウザソース:
+launcher-state-push = 状態配信 WebSocket (再起動が必要)
+launcher-websocket-url = WebSocket URL:
+launcher-enable-discord-rich-presence = Discord Rich Presence を有効化
+launcher-discord-hide-song = 曲名とアーティストを隠す
+launcher-webhook = Webhook
+launcher-send-on = 送信タイミング:
+launcher-bot-name = ボット名:
+launcher-avatar-url = アバター URL:
+launcher-conditions = 条件:
+launcher-personal-best-only = 自己ベストのみ
+launcher-clears-only = クリアのみ
+launcher-minimum-rank = 最低ランク:
+launcher-embed-template = Embed テンプレート:
+launcher-embed-template-hint = "embed" オブジェクトを含む JSON ファイル。空欄で既定のテンプレート
+launcher-webhook-urls = Webhook URL:
+launcher-add = 追加
+launcher-tab-video = 映像
+launcher-tab-audio = 音声
+launcher-tab-input = 入力
+launcher-tab-key-binding = キー割り当て
+launcher-tab-folders = フォルダ
+launcher-tab-tables = 難易度表
+launcher-tab-skin = スキン
+launcher-tab-option = オプション
+launcher-tab-other = その他
+launcher-tab-stream = 配信
+launcher-beatoraja-found = { $path } に beatoraja が見つかりました。
+launcher-import-failed = インポートに失敗しました: { $error }
+launcher-scan-progress = スキャン中: { $processed }/{ $total } 件処理、新規 { $new } 件
+launcher-scan-failed = スキャンに失敗しました: { $error }
+launcher-display-modes-detected = ディスプレイ: { $width } x { $height } (フルスクリーンモード { $count } 件を検出)
+launcher-display-modes-pending = ディスプレイ: { $width } x { $height } (フルスクリーンモードは初回のゲーム起動時に検出されます)
+launcher-resolution-unsupported = { $resolution } (非対応)
+launcher-display-clamped = このディスプレイに収まりません。{ $resolution }、ウィンドウ { $width } x { $height } で保存されます
+launcher-audio-test-buffer = { $frames } フレーム ({ $ms } ms)
+launcher-audio-test-unknown = 不明
+launcher-audio-test-latency = { $ms } ms
+launcher-audio-test-not-reported = 取得できません
+launcher-audio-test-result = { $device }: { $rate } Hz、バッファ { $buffer }、出力遅延 { $latency }
+launcher-audio-test-failed = オーディオテストに失敗しました: { $error }
+launcher-latency-tuning = レイテンシ調整
+launcher-latency-tuning-hint = ループバックケーブルで出力を既定の入力に接続するか、スピーカーの近くにマイクを置いてから測定してください。
+launcher-period-count = ピリオド数
+launcher-period-count-hint = 再生前に確保するデバイスバッファの数
+launcher-latency-buffered = バッファ量
+launcher-latency-stored = 保存済みの出力レイテンシ
+launcher-measure-latency = 往復レイテンシを測定
+launcher-latency-result = { $device }: クリック { $heard }/{ $played } 検出, 往復 { $round_trip } ms, 出力レイテンシ { $output } ms
+launcher-latency-recommended = 推奨オーディオオフセット: { $offset } ms
+launcher-latency-apply = 適用
+launcher-latency-apply-hint = このバッファ設定と測定したレイテンシを保存し、オーディオオフセットに設定します
+launcher-controller = コントローラー { $number } ({ $number }P)
+launcher-select-bms-folder = BMS フォルダを選択
+launcher-none = なし
+launcher-twitter-consumer-key = Twitter コンシューマーキー:
+launcher-twitter-consumer-secret = Twitter コンシューマーシークレット:
+launcher-twitter-access-token = Twitter アクセストークン:
+launcher-twitter-access-token-secret = Twitter アクセストークンシークレット:
+launcher-select-rival-score-file = ライバルのスコアファイルを選択
+launcher-rival-imported = { $name } をインポートしました
+launcher-rivals-refreshed = ライバル { $count } 人を更新しました
+launcher-ir-logged-in = { $name } (ID { $id }) としてログインしました
+launcher-ir-logged-in-rank = { $name } (ID { $id }、ランク { $rank }) としてログインしました
+launcher-ir-login-succeeded = ログインに成功しました: { $message }
+launcher-ir-login-failed = ログインに失敗しました: { $message }
+
+## update
+update-updates = アップデート
+update-check-on-start = ランチャー起動時にアップデートを確認
+update-check-now = 今すぐ確認
+update-no-build-for-this-platform = このプラットフォーム向けのビルドはありません
+update-release-page = リリースページ
+update-check-failed = アップデートの確認に失敗しました: { $error }
+update-up-to-date = 最新です ({ $version })
+update-available = { $name } が利用可能です
+update-download = { $name } をダウンロード ({ $size } MB)
+update-saved = { $path } に保存しました。rubato を終了し、このインストール先に上書き展開してください。
+update-download-failed = ダウンロードに失敗しました: { $error }
+
+## keybind
+keybind-key-binding = キー割り当て
+keybind-instructions = 行をクリックしてから、割り当てるキー、ボタン、MIDI ノートまたは MIDI CC を入力してください。
+keybind-key = キー
+keybind-keyboard = キーボード
+keybind-controller = コントローラー
+keybind-clear = クリア
+keybind-midi-velocity-threshold = MIDI ベロシティしきい値:
+keybind-midi-channel = MIDI チャンネル:
+keybind-midi-channel-hint = -1 で全チャンネルを受信
+keybind-press-input = キー、ボタンまたは MIDI ノートを入力 (Esc でキャンセル)
+keybind-assigned-key = キー { $key } を割り当てました
+keybind-assigned-button = { $device } { $button } を割り当てました
+keybind-no-free-slot = { $device } に空きコントローラースロットがありません
+keybind-assigned-midi = MIDI { $input } を割り当てました
+keyconfig-press-key = キーを押してください (Esc でキャンセル)
+keyconfig-press-button = コントローラーのボタンを押すか軸を動かしてください
+keyconfig-play-midi = MIDI ノートを鳴らすか CC を動かしてください
+keyconfig-assigned-hotkey = { $key } を { $hotkey } に割り当てました
+keyconfig-reserved = { $key } は予約されています
+
+## downloads
+downloads-task = タスク
+downloads-progress = 進捗
+downloads-speed = 速度
+downloads-eta = 残り時間
+downloads-op = 操作
+downloads-cancel = キャンセル
+downloads-password = パスワード
+downloads-extract = 展開
+downloads-download-tasks = ダウンロードタスク
+downloads-on-hold = プレイ終了までダウンロードを保留しています
+downloads-empty = ダウンロードタスクはありません。未所持の BMS を選択するとタスクを追加できます!
+downloads-running = 実行中
+downloads-expired = 終了
+downloads-sources = ダウンロード元
+downloads-source = ダウンロード元
+downloads-success = 成功
+downloads-failed = 失敗
+downloads-last-error = 最後のエラー
+downloads-pause = 一時停止
+downloads-resume = 再開
+downloads-retry = 再試行
+downloads-running-count = 実行中: { $count }
+downloads-expired-count = 終了: { $count }
+downloads-failures = { $failures } (破損 { $broken })
+
+## rate
+rate-rate-modifier = 再生速度変更
+rate-controls = 操作
+rate-rate-enabled = 速度変更を有効化
+rate-description = 譜面の再生速度を指定した割合だけ速く、または遅くします。
+rate-reset = リセット
+rate-enabled-help = 有効にすると、速度を上げたスコアはランプなしでローカルに保存され、速度を下げたスコアはアシストプレイとして保存されます。
+
+## modmenu
+modmenu-controller-navigation = コントローラー操作
+modmenu-controller-navigation-help = 1鍵 / 皿上: 前の項目。3鍵 / 皿下: 次の項目。2鍵 / 4鍵: 値を変更。5鍵: 決定。7鍵: メニューを閉じる。
+modmenu-show-rate-modifier-window = 再生速度変更ウィンドウを表示
+modmenu-show-random-trainer-window = ランダムトレーナーウィンドウを表示
+modmenu-show-judge-trainer-window = 判定トレーナーウィンドウを表示
+modmenu-show-practice-window = 練習ウィンドウを表示
+modmenu-show-skin-configuration-window = スキン設定ウィンドウを表示
+modmenu-show-skin-widget-manager-window = スキンウィジェット管理ウィンドウを表示
+modmenu-show-song-manager-window = 楽曲管理ウィンドウを表示
+modmenu-show-download-tasks-window = ダウンロードタスクウィンドウを表示
+modmenu-show-performance-monitor-window = パフォーマンスモニターウィンドウを表示
+modmenu-show-profiler-window = プロファイラーウィンドウを表示
+modmenu-show-input-diagnostics-window = 入力診断ウィンドウを表示
+modmenu-show-ir-status-window = IR 状態ウィンドウを表示
+modmenu-show-notification-history-window = 通知履歴ウィンドウを表示
+modmenu-show-misc-setting-window = その他の設定ウィンドウを表示
+modmenu-endless-dream-debug-information = Endless Dream デバッグ情報
+modmenu-commit-hash = コミットハッシュ: { $hash }
+modmenu-build-time = ビルド日時: { $time }
+
+## input-diag
+input-diag-input-diagnostics = 入力診断
+input-diag-polling-interval = ポーリング間隔: ---
+input-diag-press-a-key = キーを押すと入力状況が表示されます
+input-diag-key = キー
+input-diag-state = 状態
+input-diag-presses = 押下回数
+input-diag-device = デバイス
+input-diag-last-event = 最終入力
+input-diag-on = ON
+input-diag-off = off
+input-diag-key-chatter = キーチャタリング
+input-diag-no-chatter-detected = チャタリングは検出されていません
+input-diag-suppressed = 無視した回数
+input-diag-reset = リセット
+input-diag-polling-interval-stats = ポーリング間隔: 平均 { $average } ms / 最大 { $max } ms
+input-diag-scratch-rate = 皿の回転数: { $rate } /s
+input-diag-ms-ago = { $ms } ms 前
+input-diag-keyboard = キーボード
+input-diag-controller = コントローラー
+input-diag-key-chatter-help = 離した直後のチャタリング対策時間内に入った押下は無視されます。時間はランチャーでプレイモードごとに設定できます。
+
+## stats
+stats-statistics = 統計
+stats-play-time = プレイ時間
+stats-play-count = プレイ回数
+stats-clear-count = クリア回数
+stats-notes-hit = 叩いたノーツ数
+stats-notes-per-day = 日別ノーツ数
+stats-lamps-per-level = 難易度表レベル別ランプ
+stats-score-trend = 最近のスコア更新
+stats-no-data = データなし
+stats-day-notes = { $date }: { $notes } ノーツ, { $plays } プレイ, プレイ時間 { $time }
+
+## ir-status
+ir-status-ir-status = IR 状態
+ir-status-not-connected = どの IR にも接続していません
+ir-status-ir = IR
+ir-status-player = プレイヤー
+ir-status-home = ホーム
+ir-status-retry-now = 今すぐ再送
+ir-status-pending = 未送信のスコア: { $count }
+ir-status-last-submission = 最後の送信: { $submission }
+ir-status-submission-none = なし
+ir-status-sent = 送信済み
+ir-status-failed = 失敗
+ir-status-failed-reason = 失敗 ({ $reason })
+ir-status-submission = { $title } → { $ir } { $outcome }、{ $age }
+age-seconds = { $count } 秒前
+age-minutes = { $count } 分前
+age-hours = { $count } 時間前
+
+## judge-trainer
+judge-trainer-judge-trainer = 判定トレーナー
+judge-trainer-override-judge = 譜面の判定を上書き
+judge-trainer-judge = 判定
+judge-trainer-judge-windows-default = 判定幅: 譜面の既定値
+judge-trainer-judge-windows-scaled = 判定幅: x{ $scale } (スコアは保存されません)
+
+## misc
+misc-misc-settings = その他の設定
+misc-notification-positions = 通知の位置
+misc-play-mode = プレイモード
+misc-enable-lift = リフトを有効化
+misc-enable-hidden = HIDDEN を有効化
+misc-enable-lane-cover = レーンカバーを有効化
+misc-enable-constant = CONSTANT を有効化
+misc-switch = 切り替え
+misc-reload-list = 一覧を再読み込み
+misc-player-profile = プレイヤープロファイル
+misc-lift = リフト
+misc-hidden = HIDDEN
+misc-lane-cover = レーンカバー
+misc-fade-in-time = フェードイン時間 (ms)
+
+## notifications
+notifications-notifications = 通知
+notifications-info = 情報
+notifications-success = 成功
+notifications-warning = 警告
+notifications-error = エラー
+notifications-clear = クリア
+notifications-no-notifications = 通知はありません
+
+## perf
+perf-performance-monitor = パフォーマンスモニター
+perf-frames = フレーム
+perf-no-frame-data = フレームデータがありません
+perf-audio-judge = 音声 / 判定
+perf-audio-callback-load-none = 音声コールバック負荷: N/A
+perf-judge-latency-none = 判定遅延: N/A
+perf-watch = 計測
+perf-no-watch-data = 計測データがありません
+perf-name = 名前
+perf-avg-ms = 平均 (ms)
+perf-std-ms = 標準偏差 (ms)
+perf-events = イベント
+perf-filter-threshold-ms = 表示するしきい値 (ms):
+perf-sort-by-duration = 所要時間順に並べる
+perf-no-event-data = イベントデータがありません
+perf-average = 平均
+perf-low-1 = 下位 1%
+perf-low-01 = 下位 0.1%
+perf-audio-underruns = 音声アンダーラン: { $count }
+perf-audio-callback-load = 音声コールバック負荷: 平均 { $average }% / 最大 { $max }%
+perf-judge-latency = 判定遅延: 平均 { $average } ms / 最大 { $max } ms
+
+## practice
+practice-practice = 練習
+practice-not-active = 練習モードで譜面を開始すると設定を編集できます。
+practice-applies-next-run = 範囲と速度は次のプレイから反映されます。
+practice-start = 開始
+practice-end = 終了
+practice-loop-section = 区間をループ
+practice-freeze-gauge = ゲージを固定
+practice-show-invisible-notes = 不可視ノーツを表示
+practice-rate-percent = 速度 %
+practice-freeze-gauge-help = ゲージが開始時の値のまま変わらないため、途中で落ちることはありません。
+
+## profiler
+profiler-profiler = プロファイラー
+profiler-pause = 一時停止
+profiler-slowest-recent-frame = 最近で最も遅いフレーム
+profiler-clear = クリア
+profiler-no-profiling-data = プロファイルデータがありません
+
+## random-trainer
+random-trainer-random-trainer = ランダムトレーナー
+random-trainer-controls = 操作
+random-trainer-trainer-enabled = トレーナーを有効化
+random-trainer-track-current-random = 現在のランダムを追跡
+random-trainer-black-white-random-select = 白鍵/黒鍵ランダム選択
+random-trainer-mirror = ミラー
+random-trainer-shift-left = 左にずらす
+random-trainer-shift-right = 右にずらす
+random-trainer-candidates = 候補
+random-trainer-generate = 生成
+random-trainer-locked-none = 固定: なし
+random-trainer-lock-current = 現在の配置で固定
+random-trainer-unlock = 固定を解除
+random-trainer-locked = 固定: { $order } (RANDOM)
+
+## skin-menu
+skin-menu-skin = スキン
+skin-menu-open = 開く
+skin-menu-live-editing = ライブ編集
+skin-menu-reset-prompt = 現在のスキンの設定を既定値に戻します
+skin-menu-are-you-sure = よろしいですか?
+skin-menu-click-outside-to-close = (外側をクリックして閉じる)
+skin-menu-freeze-timers = タイマーを停止
+skin-menu-no-skin-loaded = スキンが読み込まれていません
+skin-menu-other = その他
+skin-menu-save = 保存
+skin-menu-reset = リセット
+skin-menu-confirm = 確定
+skin-menu-random = ランダム
+
+## widgets
+widgets-skin-widgets = スキンウィジェット
+widgets-no-skin-is-loaded = スキンが読み込まれていません
+widgets-skinwidgets = ウィジェット
+widgets-history = 履歴
+widgets-undo = 元に戻す
+widgets-show-position = 位置を表示
+widgets-export = エクスポート
+widgets-save = 保存
+widgets-reset = リセット
+widgets-columns = 列
+widgets-toggle = 切り替え
+widgets-edit = 編集
+widgets-edit-skin-widget = スキンウィジェットを編集
+widgets-submit = 決定
+widgets-move = 移動
+widgets-no-history = 履歴はありません
+widgets-description = 説明
+
+## song-manager
+song-manager-song-manager = 楽曲管理
+song-manager-not-a-selectable-song = 選択できる楽曲ではありません
+song-manager-sort-by-last-played = 最終プレイ順に並べる
+song-manager-open-folder = フォルダを開く
+song-manager-rescan-folder = フォルダを再スキャン
+song-manager-remove-from-db = DB から削除
+song-manager-tag = タグ
+song-manager-save = 保存
+song-manager-diagnostics = 診断
+song-manager-user-metadata = マイメタデータ
+song-manager-display-title = 表示タイトル
+song-manager-artist-romanized = アーティスト (ローマ字)
+song-manager-tags = タグ一覧
+song-manager-notes = メモ
+song-manager-song = 楽曲: { $name }
+song-manager-last-played = 最終プレイ: { $time }
+song-manager-favorite-song = お気に入りの曲
+song-manager-favorite-chart = お気に入りの譜面
+song-manager-hide-song = 曲を隠す
+song-manager-hide-chart = 譜面を隠す
+song-manager-duplicates = 重複譜面
+song-manager-find-duplicates = 重複を検索
+song-manager-no-duplicates = 複数の場所にある譜面はありません
+song-manager-keep-hide-others = これを残して他を隠す
+song-manager-keep-delete-others = これを残して他を削除
+
+## toast
+toast-success = 成功
+toast-warning = 警告
+toast-error = エラー
+toast-info = 情報
+
+## notify
+notify-song-load-failed = BMS の読み込みに失敗しました: 曲が見つからないか、エラーがあります
+notify-course-songs-missing = コースの読み込みに失敗しました: 見つからない曲があります
+notify-random-course-songs-missing = ランダムコースの読み込みに失敗しました: 見つからない曲があります
+notify-course-stages-missing = 足りないステージがあります。ダウンロードするとコースを開始できます
+notify-course-pending = { $course } は全ステージのダウンロード後に開始します
+notify-course-ready = { $course } の全ステージがそろいました。コースを開始します
+notify-random-select-empty = このフォルダに { $filter } はありません
+notify-library-scan-started = バックグラウンドでライブラリをスキャンしています
+notify-chart-queued = 選曲画面に戻ると { $name } が開きます
+notify-table-updating = 難易度表を更新中: { $name }
+notify-config-reload-failed = 設定の再読み込みに失敗しました: { $error }
+notify-config-reloaded = 設定を再読み込みしました: { $settings }
+notify-config-restart-required = 反映には再起動が必要です: { $settings } (未反映)
+notify-ir-connects-on-restart = { $ir } には次回の起動時に接続します
+notify-ir-sent = IR スコア送信完了: { $title } ({ $ir })
+notify-ir-send-failed = IR スコア送信失敗: { $message } ({ $ir })
+notify-open-ranking = ランキングを開く
+notify-crash-interrupted = { $title } のプレイはクラッシュで中断されました (EX スコア { $exscore })
+notify-crash-save-score = スコアを保存
+notify-recovered-score-saved = 復元したスコアを保存しました: { $title }
+notify-recovered-score-failed = 復元したスコアの保存に失敗しました: { $error }
+notify-chart-offset = 譜面オフセット: { $offset } ms
+notify-url-copied = URL をクリップボードにコピーしました。
+notify-md5-copied = MD5 ハッシュをコピーしました: { $hash }
+notify-sha256-copied = SHA256 ハッシュをコピーしました: { $hash }
+notify-menu-text-copied = 選択中のメニューテキストをコピーしました: { $text }
+notify-http-download-disabled = HTTP ダウンロードが有効になっていません
+notify-bms-search-not-found = { $title } は BMS Search に見つかりませんでした
+notify-bms-search-failed = BMS Search の検索に失敗しました: { $error }
+notify-bms-search-no-direct-download = { $title } は直接ダウンロードできないため、ページを開きます
+notify-already-submitted = 追加済みです
+notify-download-song-not-found = { $source } に指定の曲が見つかりません
+notify-download-source-error = { $source } で重大なエラーが発生しました: { $error }
+notify-download-submitted = ダウンロードタスク [{ $name }] を追加しました
+notify-download-failed = { $source } からのダウンロードに失敗しました: { $error }
+notify-download-mirror-fallback = { $source } で { $name } の取得に失敗したため、{ $mirror } を試します
+notify-download-password-required = { $name } の展開にはパスワードが必要です
+notify-extract-failed = { $path } の展開に失敗しました: { $error }
+notify-download-extracted = ダウンロードと展開が完了しました。ダウンロードフォルダを再構築します
+notify-download-added = { $name } をライブラリに追加しました
+notify-open-folder = フォルダを開く
+notify-archive-delete-failed = アーカイブファイルを自動で削除できませんでした
+notify-request-skipped = リクエスト { $title } をスキップしました
+notify-request-accepted = リクエスト { $title } を受け付けました
+notify-request-duplicate = { $title } はすでに追加されています
+notify-request-added = { $title } を配信リクエスト一覧に追加しました
+notify-request-song-missing = リクエストされた曲はコレクションにありません
+notify-requests-autoplay = リクエスト曲はオートプレイで再生します
+notify-requests-normal = リクエスト曲は通常どおりプレイします
+notify-no-social-accounts = SNS アカウントが設定されていません
+notify-share-posted = リザルトを { $service } に投稿しました
+notify-share-failed = { $service } へのリザルト投稿に失敗しました: { $error }
+notify-obs-replay-saved = OBS: リプレイバッファを保存しました ({ $reason })
+notify-obs-recording-kept = OBS: 録画を残します。
+notify-obs-status = OBS: { $message }。
+notify-screenshot-saved = スクリーンショットを保存しました: { $path }
+notify-screenshot-clipboard = スクリーンショットをクリップボードに保存しました
+notify-webhook-http-error = Webhook 送信時に想定外の HTTP レスポンスコードが返されました: { $status }
+notify-webhook-template-ignored = Webhook テンプレートを無視しました: { $error }
+notify-lr2ir-empty-response = LR2IR から空の応答が返されました。
+notify-lr2ir-ghost-parse-failed = LR2IR のゴーストデータを解析できませんでした。
+notify-lr2ir-score-parse-failed = LR2IR からスコアデータを取得できませんでした: XML の解析エラー
+notify-lr2ir-unsupported-random = 対応していないランダムオプションです: { $option }
+notify-lr2ir-http-error = LR2IR へのリクエストに失敗しました: HTTP エラーコード { $status }
+notify-lr2ir-request-failed = LR2IR へのリクエストに失敗しました: { $error }
+notify-ghost-load-failed = ゴーストデータの読み込みに失敗しました。
+notify-widget-changes-copied = 変更内容をクリップボードにコピーしました
+notify-widget-move-no-origin = 元の位置がないため移動結果を確定できません
@@ -43,6 +43,12 @@ pub trait AudioDriver {
         true
     }
 
+    /// Oldest unread audio callback load (processing time / time allotted), if
+    /// the output backend reports it. Call until `None` to drain.
+    fn pop_callback_load(&mut self) -> Option<f32> {
+        None
+    }
+
     /// Preload a sound file into the path sound cache without playing it.
     /// Call during state `create()` for known system sounds to avoid blocking
     /// I/O on first `play_path()`.
@@ -73,6 +79,53 @@ pub trait AudioDriver {
     fn dispose(&mut self);
 }
 
+/// What `AudioDriver::set_model` loads for a chart: its location, wav definitions,
+/// volume and the (wav id, start, duration) slice of every sounding note.
+/// Two models with equal sets share the same loaded keysounds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeysoundSet {
+    path: Option<String>,
+    wavmap: Vec<String>,
+    volwav: i32,
+    slices: Vec<(i32, i64, i64)>,
+}
+
+impl KeysoundSet {
+    pub fn of(model: &BMSModel) -> Self {
+        let mut slices = Vec::new();
+        let mut add = |n: &Note| {
+            if n.wav() >= 0 {
+                slices.push((n.wav(), n.micro_starttime(), n.micro_duration()));
+            }
+        };
+        let lanes = model.mode().map(|m| m.key()).unwrap_or(0);
+        for tl in &model.timelines {
+            for i in 0..lanes {
+                if let Some(n) = tl.note(i) {
+                    add(n);
+                    for ln in n.layered_notes() {
+                        add(ln);
+                    }
+                }
+                if let Some(hn) = tl.hidden_note(i) {
+                    add(hn);
+                }
+            }
+            for n in tl.back_ground_notes() {
+                add(n);
+            }
+        }
+        slices.sort_unstable();
+        slices.dedup();
+        Self {
+            path: model.path(),
+            wavmap: model.wavmap.clone(),
+            volwav: model.volwav,
+            slices,
+        }
+    }
+}
+
 /// Get all supported audio file paths for the given path.
 ///
 /// Tries the original path and alternate extensions (.wav, .flac, .ogg, .mp3).
@@ -143,6 +196,17 @@ pub fn is_bms_resource_path_safe(resource_name: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn keysound_set_depends_on_wav_definitions() {
+        let mut model = BMSModel::new();
+        model.wavmap = vec!["kick.wav".to_string(), "snare.wav".to_string()];
+        let set = KeysoundSet::of(&model);
+        assert_eq!(set, KeysoundSet::of(&model.clone()));
+
+        model.wavmap[1] = "hat.wav".to_string();
+        assert_ne!(set, KeysoundSet::of(&model));
+    }
+
     #[test]
     fn bms_resource_path_allows_simple_filename() {
         assert!(is_bms_resource_path_safe("kick.wav"));
@@ -109,6 +109,11 @@ impl AudioSystem {
         delegate!(self, poll_loading(), noop: true)
     }
 
+    /// Oldest unread audio callback load, if the output backend reports it.
+    pub fn pop_callback_load(&mut self) -> Option<f32> {
+        delegate!(self, pop_callback_load())
+    }
+
     /// Preload a sound file into the path sound cache without playing it.
     pub fn preload_path(&mut self, path: &str) {
         delegate!(self, preload_path(path));
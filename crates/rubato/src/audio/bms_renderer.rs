@@ -7,6 +7,14 @@ use log::{info, trace, warn};
 
 use crate::audio::audio_driver;
 use crate::audio::pcm::PCM;
+use crate::audio::pcm_mix;
+
+/// The `len` samples of `samples` from `start`, cut short at its end.
+fn pcm_window<T>(samples: &[T], start: usize, len: i32) -> &[T] {
+    let start = start.min(samples.len());
+    let end = start.saturating_add(len.max(0) as usize).min(samples.len());
+    &samples[start..end]
+}
 
 /// Convert f32 samples (normalized [-1.0, 1.0]) to i16 samples.
 ///
@@ -36,6 +44,16 @@ pub struct RenderResult {
     pub duration_ms: i64,
 }
 
+impl RenderResult {
+    /// Interleaved 16-bit samples decoded from `pcm_data`.
+    pub fn samples(&self) -> Vec<i16> {
+        self.pcm_data
+            .chunks_exact(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]))
+            .collect()
+    }
+}
+
 impl BMSRenderer {
     pub fn new(sample_rate: i32, channels: i32) -> Self {
         BMSRenderer {
@@ -126,16 +144,11 @@ impl BMSRenderer {
             }
         }
 
-        // Float -> Int16 with -6dB headroom
+        // Float -> Int16 with -6dB headroom to try to alleviate clipping
+        let mut shorts = vec![0i16; mix_buffer.len()];
+        pcm_mix::f32_to_i16(&mix_buffer, &mut shorts, 0.5);
         let mut output_buffer = Vec::with_capacity(buffer_size);
-        for sample in &mix_buffer {
-            let mut s = *sample;
-            // -6dB headroom to try to alleviate clipping
-            s *= 0.5f32;
-
-            s = s.clamp(-1.0f32, 1.0f32);
-
-            let short_val = (s * 32767.0f32) as i16;
+        for short_val in shorts {
             output_buffer.extend_from_slice(&short_val.to_le_bytes());
         }
 
@@ -210,84 +223,55 @@ impl BMSRenderer {
         }
     }
 
-    /// Note: if pcm.start is negative, `as usize` wraps to a huge value and the
-    /// `src_index >= samples.len()` guard immediately exits the loop (silent no-op).
-    /// This matches Java's behavior where negative offsets produce no audio.
+    /// Destination range of a PCM starting at `start_sample` frames. Mixing
+    /// is a silent no-op for a negative start or PCM offset, as in Java.
+    fn mix_range(&self, pcm_start: i32, start_sample: i64) -> Option<(usize, usize)> {
+        let dst_start = start_sample * self.channels as i64;
+        let src_start = usize::try_from(pcm_start).ok()?;
+        let dst_start = usize::try_from(dst_start).ok()?;
+        Some((src_start, dst_start))
+    }
+
     fn mix_short_pcm(
         &self,
         pcm: &crate::audio::short_pcm::ShortPCM,
         start_sample: i64,
         mix_buffer: &mut [f32],
     ) {
-        let samples = &pcm.sample;
-        let mut src_index = pcm.start as usize;
-        let dst_start = start_sample * self.channels as i64;
-        if dst_start < 0 {
+        let Some((src_start, dst_start)) = self.mix_range(pcm.start, start_sample) else {
             return;
-        }
-        let mut dst_index = dst_start as usize;
-        let len = pcm.len as usize;
-
-        for _i in 0..len {
-            if dst_index >= mix_buffer.len() || src_index >= samples.len() {
-                break;
-            }
-            mix_buffer[dst_index] += samples[src_index] as f32 / 32768.0;
-            src_index += 1;
-            dst_index += 1;
-        }
+        };
+        let src = pcm_window(&pcm.sample, src_start, pcm.len);
+        let dst = mix_buffer.get_mut(dst_start..).unwrap_or_default();
+        pcm_mix::mix_i16(dst, src, 1.0 / 32768.0);
     }
 
-    /// See `mix_short_pcm` note: negative pcm.start wraps to huge usize and exits immediately.
     fn mix_float_pcm(
         &self,
         pcm: &crate::audio::float_pcm::FloatPCM,
         start_sample: i64,
         mix_buffer: &mut [f32],
     ) {
-        let samples = &pcm.sample;
-        let mut src_index = pcm.start as usize;
-        let dst_start = start_sample * self.channels as i64;
-        if dst_start < 0 {
+        let Some((src_start, dst_start)) = self.mix_range(pcm.start, start_sample) else {
             return;
-        }
-        let mut dst_index = dst_start as usize;
-        let len = pcm.len as usize;
-
-        for _i in 0..len {
-            if dst_index >= mix_buffer.len() || src_index >= samples.len() {
-                break;
-            }
-            mix_buffer[dst_index] += samples[src_index];
-            src_index += 1;
-            dst_index += 1;
-        }
+        };
+        let src = pcm_window(&pcm.sample, src_start, pcm.len);
+        let dst = mix_buffer.get_mut(dst_start..).unwrap_or_default();
+        pcm_mix::mix_f32(dst, src, 1.0);
     }
 
-    /// See `mix_short_pcm` note: negative pcm.start wraps to huge usize and exits immediately.
     fn mix_byte_pcm(
         &self,
         pcm: &crate::audio::byte_pcm::BytePCM,
         start_sample: i64,
         mix_buffer: &mut [f32],
     ) {
-        let samples = &pcm.sample;
-        let mut src_index = pcm.start as usize;
-        let dst_start = start_sample * self.channels as i64;
-        if dst_start < 0 {
+        let Some((src_start, dst_start)) = self.mix_range(pcm.start, start_sample) else {
             return;
-        }
-        let mut dst_index = dst_start as usize;
-        let len = pcm.len as usize;
-
-        for _i in 0..len {
-            if dst_index >= mix_buffer.len() || src_index >= samples.len() {
-                break;
-            }
-            mix_buffer[dst_index] += samples[src_index] as f32 / 128.0;
-            src_index += 1;
-            dst_index += 1;
-        }
+        };
+        let src = pcm_window(&pcm.sample, src_start, pcm.len);
+        let dst = mix_buffer.get_mut(dst_start..).unwrap_or_default();
+        pcm_mix::mix_u8(dst, src, 1.0 / 128.0);
     }
 
     fn load_wav_files(&self, model: &BMSModel) -> HashMap<i32, PCM> {
@@ -353,6 +337,20 @@ impl BMSRenderer {
 mod tests {
     use super::*;
 
+    #[test]
+    fn render_result_samples_decode_little_endian() {
+        let result = RenderResult {
+            pcm_data: [0i16, 1, -1, i16::MAX, i16::MIN]
+                .iter()
+                .flat_map(|s| s.to_le_bytes())
+                .collect(),
+            sample_rate: 44100,
+            channels: 1,
+            duration_ms: 0,
+        };
+        assert_eq!(result.samples(), vec![0, 1, -1, i16::MAX, i16::MIN]);
+    }
+
     #[test]
     fn buffer_size_try_from_rejects_negative() {
         // On any platform, a negative i64 value should fail usize::try_from.
@@ -48,9 +48,7 @@ impl FloatPCM {
         match loader.bits_per_sample {
             8 => {
                 let mut s = vec![0f32; bytes];
-                for i in 0..s.len() {
-                    s[i] = (pcm[i] as f32 - 128.0) / 128.0;
-                }
+                crate::audio::pcm_mix::u8_to_f32(pcm, &mut s);
                 sample = s;
             }
             16 => {
@@ -154,34 +152,13 @@ impl FloatPCM {
             / self.sample_rate as i64)
             * self.channels as i64) as usize;
         let mut samples = vec![0f32; new_len];
-
-        for i in 0i64..(samples.len() as i64 / self.channels as i64) {
-            let position = i * self.sample_rate as i64 / sample as i64;
-            let modv = (i * self.sample_rate as i64) % sample as i64;
-            for j in 0..self.channels {
-                if modv != 0
-                    && (((position + 1) * self.channels as i64 + j as i64) as usize)
-                        < self.sample.len()
-                {
-                    let sample1 =
-                        self.sample[(position * self.channels as i64 + j as i64) as usize];
-                    let sample2 =
-                        self.sample[((position + 1) * self.channels as i64 + j as i64) as usize];
-                    samples[(i * self.channels as i64 + j as i64) as usize] =
-                        (sample1 * (sample as i64 - modv) as f32 + sample2 * modv as f32)
-                            / sample as f32;
-                } else {
-                    let idx = (position * self.channels as i64 + j as i64) as usize;
-                    samples[(i * self.channels as i64 + j as i64) as usize] =
-                        if idx < self.sample.len() {
-                            self.sample[idx]
-                        } else {
-                            0.0
-                        };
-                }
-            }
-        }
-
+        crate::audio::pcm_mix::resample_linear(
+            &self.sample,
+            self.channels as usize,
+            self.sample_rate as u32,
+            sample as u32,
+            &mut samples,
+        );
         samples
     }
 
@@ -199,12 +176,12 @@ impl FloatPCM {
         let mut samples =
             vec![0f32; self.sample.len() * channels as usize / self.channels as usize];
 
-        for i in 0i64..(samples.len() as i64 / channels as i64) {
-            for j in 0..channels {
-                samples[(i * channels as i64 + j as i64) as usize] =
-                    self.sample[(i * self.channels as i64) as usize];
-            }
-        }
+        crate::audio::pcm_mix::spread_first_channel(
+            &self.sample,
+            self.channels as usize,
+            &mut samples,
+            channels as usize,
+        );
         FloatPCM::new(
             channels,
             self.sample_rate,
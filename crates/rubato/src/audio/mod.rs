@@ -13,6 +13,7 @@ pub mod gdx_audio_device_driver;
 pub mod gdx_sound_driver;
 pub mod ms_adpcm_decoder;
 pub mod pcm;
+pub mod pcm_mix;
 pub mod port_audio_driver;
 pub mod recording_audio_driver;
 pub mod shared_recording_audio_driver;
@@ -0,0 +1,270 @@
+//! Inner loops for mixing and converting PCM sample buffers.
+//!
+//! The loops walk the buffers in fixed chunks of `LANES` samples over
+//! pre-sliced, equal-length inputs, so they carry no per-sample bounds
+//! checks and the compiler turns each chunk into vector instructions
+//! (SSE2/AVX on x86_64, NEON on aarch64). Results match the scalar loops
+//! they replace sample for sample.
+
+/// Samples processed per chunk. Eight f32 fill one AVX register or two
+/// SSE/NEON registers.
+pub const LANES: usize = 8;
+
+/// Add `src * gain` to `dst` over the shorter of the two buffers.
+pub fn mix_f32(dst: &mut [f32], src: &[f32], gain: f32) {
+    let len = dst.len().min(src.len());
+    let (dst, src) = (&mut dst[..len], &src[..len]);
+    let mut dst_chunks = dst.chunks_exact_mut(LANES);
+    let mut src_chunks = src.chunks_exact(LANES);
+    for (d, s) in (&mut dst_chunks).zip(&mut src_chunks) {
+        for i in 0..LANES {
+            d[i] += s[i] * gain;
+        }
+    }
+    for (d, s) in dst_chunks
+        .into_remainder()
+        .iter_mut()
+        .zip(src_chunks.remainder())
+    {
+        *d += s * gain;
+    }
+}
+
+/// Add 16-bit samples scaled by `gain` to `dst`.
+pub fn mix_i16(dst: &mut [f32], src: &[i16], gain: f32) {
+    let len = dst.len().min(src.len());
+    let (dst, src) = (&mut dst[..len], &src[..len]);
+    let mut dst_chunks = dst.chunks_exact_mut(LANES);
+    let mut src_chunks = src.chunks_exact(LANES);
+    for (d, s) in (&mut dst_chunks).zip(&mut src_chunks) {
+        for i in 0..LANES {
+            d[i] += s[i] as f32 * gain;
+        }
+    }
+    for (d, s) in dst_chunks
+        .into_remainder()
+        .iter_mut()
+        .zip(src_chunks.remainder())
+    {
+        *d += *s as f32 * gain;
+    }
+}
+
+/// Add 8-bit samples scaled by `gain` to `dst`.
+pub fn mix_u8(dst: &mut [f32], src: &[u8], gain: f32) {
+    let len = dst.len().min(src.len());
+    let (dst, src) = (&mut dst[..len], &src[..len]);
+    let mut dst_chunks = dst.chunks_exact_mut(LANES);
+    let mut src_chunks = src.chunks_exact(LANES);
+    for (d, s) in (&mut dst_chunks).zip(&mut src_chunks) {
+        for i in 0..LANES {
+            d[i] += s[i] as f32 * gain;
+        }
+    }
+    for (d, s) in dst_chunks
+        .into_remainder()
+        .iter_mut()
+        .zip(src_chunks.remainder())
+    {
+        *d += *s as f32 * gain;
+    }
+}
+
+/// Convert f32 samples to 16-bit after scaling by `gain`, clipping to the
+/// 16-bit range.
+pub fn f32_to_i16(src: &[f32], dst: &mut [i16], gain: f32) {
+    let len = dst.len().min(src.len());
+    let (dst, src) = (&mut dst[..len], &src[..len]);
+    let mut dst_chunks = dst.chunks_exact_mut(LANES);
+    let mut src_chunks = src.chunks_exact(LANES);
+    for (d, s) in (&mut dst_chunks).zip(&mut src_chunks) {
+        for i in 0..LANES {
+            d[i] = ((s[i] * gain).clamp(-1.0, 1.0) * 32767.0) as i16;
+        }
+    }
+    for (d, s) in dst_chunks
+        .into_remainder()
+        .iter_mut()
+        .zip(src_chunks.remainder())
+    {
+        *d = ((s * gain).clamp(-1.0, 1.0) * 32767.0) as i16;
+    }
+}
+
+/// Convert unsigned 8-bit samples to f32 as `(sample - 128) / 128`.
+pub fn u8_to_f32(src: &[u8], dst: &mut [f32]) {
+    let len = dst.len().min(src.len());
+    let (dst, src) = (&mut dst[..len], &src[..len]);
+    let mut dst_chunks = dst.chunks_exact_mut(LANES);
+    let mut src_chunks = src.chunks_exact(LANES);
+    for (d, s) in (&mut dst_chunks).zip(&mut src_chunks) {
+        for i in 0..LANES {
+            d[i] = (s[i] as f32 - 128.0) / 128.0;
+        }
+    }
+    for (d, s) in dst_chunks
+        .into_remainder()
+        .iter_mut()
+        .zip(src_chunks.remainder())
+    {
+        *d = (*s as f32 - 128.0) / 128.0;
+    }
+}
+
+/// Write the first channel of each `src_channels`-wide frame of `src` to
+/// every channel of the `dst_channels`-wide frames of `dst`, the channel
+/// conversion the PCM types use. Mono to stereo is the common case and
+/// gets its own loop.
+pub fn spread_first_channel<T: Copy>(
+    src: &[T],
+    src_channels: usize,
+    dst: &mut [T],
+    dst_channels: usize,
+) {
+    if src_channels == 0 || dst_channels == 0 {
+        return;
+    }
+    let frames = (dst.len() / dst_channels).min(src.len().div_ceil(src_channels));
+    if src_channels == 1 && dst_channels == 2 {
+        for (d, s) in dst[..frames * 2].chunks_exact_mut(2).zip(&src[..frames]) {
+            d[0] = *s;
+            d[1] = *s;
+        }
+        return;
+    }
+    for (d, s) in dst[..frames * dst_channels]
+        .chunks_exact_mut(dst_channels)
+        .zip(src.chunks(src_channels))
+    {
+        d.fill(s[0]);
+    }
+}
+
+/// Linear interpolation resampling of interleaved f32 samples from
+/// `src_rate` to `dst_rate`, filling all of `dst`.
+///
+/// Output frame `i` reads source frame `i * src_rate / dst_rate` and blends
+/// in the next frame by the division remainder, the same arithmetic as
+/// `FloatPCM`'s resampler, but stepping the position incrementally instead
+/// of dividing for every sample.
+pub fn resample_linear(
+    src: &[f32],
+    channels: usize,
+    src_rate: u32,
+    dst_rate: u32,
+    dst: &mut [f32],
+) {
+    if channels == 0 || src_rate == 0 || dst_rate == 0 {
+        return;
+    }
+    let src_rate = src_rate as u64;
+    let dst_rate = dst_rate as u64;
+    let scale = dst_rate as f32;
+    let mut position = 0usize;
+    let mut remainder = 0u64;
+    for frame in dst.chunks_exact_mut(channels) {
+        let base = position * channels;
+        let next = base + channels;
+        let w1 = (dst_rate - remainder) as f32;
+        let w2 = remainder as f32;
+        if remainder != 0 && next + channels <= src.len() {
+            let (a, b) = (&src[base..next], &src[next..next + channels]);
+            for j in 0..channels {
+                frame[j] = (a[j] * w1 + b[j] * w2) / scale;
+            }
+        } else {
+            // Last frames, where the next source frame is missing or partial
+            for (j, out) in frame.iter_mut().enumerate() {
+                *out = if remainder != 0 && next + j < src.len() {
+                    (src[base + j] * w1 + src[next + j] * w2) / scale
+                } else {
+                    src.get(base + j).copied().unwrap_or(0.0)
+                };
+            }
+        }
+        remainder += src_rate;
+        position += (remainder / dst_rate) as usize;
+        remainder %= dst_rate;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mix_kernels_match_scalar_loops() {
+        let src_f: Vec<f32> = (0..37).map(|i| i as f32 * 0.01 - 0.2).collect();
+        let src_s: Vec<i16> = (0..37).map(|i| (i * 977 - 16000) as i16).collect();
+        let src_b: Vec<u8> = (0..37).map(|i| (i * 7) as u8).collect();
+        let mut dst = vec![0.25f32; 40];
+        let mut expected = dst.clone();
+
+        mix_f32(&mut dst, &src_f, 0.5);
+        mix_i16(&mut dst, &src_s, 1.0 / 32768.0);
+        mix_u8(&mut dst, &src_b, 1.0 / 128.0);
+        for i in 0..37 {
+            expected[i] += src_f[i] * 0.5;
+            expected[i] += src_s[i] as f32 / 32768.0;
+            expected[i] += src_b[i] as f32 / 128.0;
+        }
+        assert_eq!(dst, expected);
+    }
+
+    #[test]
+    fn conversions_match_scalar_loops() {
+        let floats: Vec<f32> = (0..21).map(|i| i as f32 * 0.15 - 1.5).collect();
+        let mut out = vec![0i16; 21];
+        f32_to_i16(&floats, &mut out, 0.5);
+        for (o, f) in out.iter().zip(&floats) {
+            assert_eq!(*o, ((f * 0.5).clamp(-1.0, 1.0) * 32767.0) as i16);
+        }
+
+        let bytes: Vec<u8> = (0..19).map(|i| (i * 13) as u8).collect();
+        let mut out = vec![0.0; 19];
+        u8_to_f32(&bytes, &mut out);
+        for (o, b) in out.iter().zip(&bytes) {
+            assert_eq!(*o, (*b as f32 - 128.0) / 128.0);
+        }
+    }
+
+    #[test]
+    fn spread_first_channel_converts_channel_counts() {
+        let mut stereo = vec![0; 6];
+        spread_first_channel(&[1, 2, 3], 1, &mut stereo, 2);
+        assert_eq!(stereo, [1, 1, 2, 2, 3, 3]);
+
+        let mut mono = vec![0; 3];
+        spread_first_channel(&[1, 9, 2, 9, 3, 9], 2, &mut mono, 1);
+        assert_eq!(mono, [1, 2, 3]);
+    }
+
+    #[test]
+    fn resample_linear_matches_per_sample_division() {
+        let channels = 2usize;
+        let src: Vec<f32> = (0..200)
+            .map(|i| ((i * 37) % 101) as f32 / 50.0 - 1.0)
+            .collect();
+        for (src_rate, dst_rate) in [(44100u32, 48000u32), (48000, 44100), (22050, 44100)] {
+            let frames = src.len() / channels * dst_rate as usize / src_rate as usize;
+            let mut dst = vec![0.0; frames * channels];
+            resample_linear(&src, channels, src_rate, dst_rate, &mut dst);
+
+            for i in 0..frames as i64 {
+                let position = i * src_rate as i64 / dst_rate as i64;
+                let modv = (i * src_rate as i64) % dst_rate as i64;
+                for j in 0..channels as i64 {
+                    let base = (position * channels as i64 + j) as usize;
+                    let next = ((position + 1) * channels as i64 + j) as usize;
+                    let expected = if modv != 0 && next < src.len() {
+                        (src[base] * (dst_rate as i64 - modv) as f32 + src[next] * modv as f32)
+                            / dst_rate as f32
+                    } else {
+                        src.get(base).copied().unwrap_or(0.0)
+                    };
+                    assert_eq!(dst[(i * channels as i64 + j) as usize], expected);
+                }
+            }
+        }
+    }
+}
@@ -411,6 +411,10 @@ impl AudioDriver for PortAudioDriver {
         }
     }
 
+    fn pop_callback_load(&mut self) -> Option<f32> {
+        self.manager.backend_mut().pop_cpu_usage()
+    }
+
     fn preload_path(&mut self, path: &str) {
         if path.is_empty() || self.path_sound_cache.contains_key(path) {
             return;
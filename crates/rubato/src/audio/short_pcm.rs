@@ -194,12 +194,12 @@ impl ShortPCM {
         let mut samples =
             vec![0i16; self.sample.len() * channels as usize / self.channels as usize];
 
-        for i in 0i64..(samples.len() as i64 / channels as i64) {
-            for j in 0..channels {
-                samples[(i * channels as i64 + j as i64) as usize] =
-                    self.sample[(i * self.channels as i64) as usize];
-            }
-        }
+        crate::audio::pcm_mix::spread_first_channel(
+            &self.sample,
+            self.channels as usize,
+            &mut samples,
+            channels as usize,
+        );
         ShortPCM::new(
             channels,
             self.sample_rate,
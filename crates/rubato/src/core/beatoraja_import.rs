@@ -0,0 +1,254 @@
+//! Import settings from an existing beatoraja installation.
+//!
+//! beatoraja writes `config_sys.json` (older versions: `config.json`) and
+//! `player/<id>/config_player.json` in the same JSON layout that Config and
+//! PlayerConfig already read, so the import is mostly a matter of reading
+//! those files and re-anchoring paths that are relative to the beatoraja
+//! directory. Database and cache locations (songdata.db, table/, player/)
+//! stay on brs's own defaults.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+
+use crate::core::config::Config;
+use crate::core::player_config::{PlayerConfig, read_all_player_id};
+use crate::core::validatable::Validatable;
+
+/// Settings read from a beatoraja installation.
+#[derive(Clone, Debug)]
+pub struct BeatorajaImport {
+    /// beatoraja installation directory.
+    pub root: PathBuf,
+    /// System config with BMS roots made absolute.
+    pub config: Config,
+    /// Player profiles with skin paths made absolute.
+    pub players: Vec<PlayerConfig>,
+}
+
+impl BeatorajaImport {
+    /// Merge the imported system config into `current`, keeping brs's own
+    /// database, cache and resource paths.
+    pub fn merge_config(&self, current: &Config) -> Config {
+        let mut config = self.config.clone();
+        config.last_booted_version = current.last_booted_version.clone();
+        let bmsroot = config.paths.bmsroot.clone();
+        let table_url = config.paths.table_url.clone();
+        let available_url = config.paths.available_url.clone();
+        config.paths = current.paths.clone();
+        config.paths.bmsroot = bmsroot;
+        config.paths.table_url = table_url;
+        config.paths.available_url = available_url;
+        config.network.download_directory = current.network.download_directory.clone();
+        if !self
+            .players
+            .iter()
+            .any(|p| p.id.as_deref() == config.playername.as_deref())
+        {
+            config.playername = self.players.first().and_then(|p| p.id.clone());
+        }
+        config.validate();
+        config
+    }
+}
+
+/// Whether `dir` looks like a beatoraja installation.
+pub fn is_beatoraja_install(dir: &Path) -> bool {
+    let has_config = dir.join("config_sys.json").is_file() || dir.join("config.json").is_file();
+    has_config && (dir.join("beatoraja.jar").is_file() || dir.join("player").is_dir())
+}
+
+/// Directories where a beatoraja installation is commonly found relative to `cwd`.
+///
+/// `BEATORAJA_DIR` takes precedence, followed by `beatoraja*` directories next
+/// to the working directory and in the user's home directory.
+pub fn beatoraja_install_candidates(cwd: &Path) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    if let Some(dir) = std::env::var_os("BEATORAJA_DIR") {
+        candidates.push(PathBuf::from(dir));
+    }
+    let home = std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(PathBuf::from);
+    for parent in cwd.parent().into_iter().chain(home.as_deref()) {
+        let Ok(entries) = std::fs::read_dir(parent) else {
+            continue;
+        };
+        let mut dirs: Vec<PathBuf> = entries
+            .flatten()
+            .filter(|e| {
+                e.file_name()
+                    .to_str()
+                    .is_some_and(|name| name.to_ascii_lowercase().starts_with("beatoraja"))
+            })
+            .map(|e| e.path())
+            .filter(|path| path.as_path() != cwd)
+            .collect();
+        dirs.sort();
+        candidates.extend(dirs);
+    }
+    candidates
+}
+
+/// First candidate directory that is a beatoraja installation.
+pub fn detect_beatoraja_install(candidates: &[PathBuf]) -> Option<PathBuf> {
+    candidates
+        .iter()
+        .find(|dir| is_beatoraja_install(dir))
+        .cloned()
+}
+
+/// Read the system config and all player profiles of the beatoraja installation at `root`.
+pub fn read_beatoraja_install(root: &Path) -> Result<BeatorajaImport> {
+    if !is_beatoraja_install(root) {
+        bail!("{} is not a beatoraja installation", root.display());
+    }
+    let configpath = [root.join("config_sys.json"), root.join("config.json")]
+        .into_iter()
+        .find(|p| p.is_file())
+        .context("beatoraja config not found")?;
+    let data = std::fs::read_to_string(&configpath)
+        .with_context(|| format!("failed to read {}", configpath.display()))?;
+    let mut config = Config::from_json(&data)
+        .with_context(|| format!("failed to parse {}", configpath.display()))?;
+    config.paths.bmsroot = config
+        .paths
+        .bmsroot
+        .iter()
+        .map(|path| rebase_path(root, path))
+        .collect();
+
+    let playerdir = root.join(&config.paths.playerpath);
+    let playerpath = playerdir.to_string_lossy();
+    let mut ids = read_all_player_id(&playerpath);
+    ids.sort();
+    let mut players = Vec::new();
+    for id in ids {
+        match PlayerConfig::read_player_config(&playerpath, &id) {
+            Ok(mut player) => {
+                rebase_skin_paths(root, &mut player);
+                players.push(player);
+            }
+            Err(e) => log::warn!("Skipping beatoraja player {}: {}", id, e),
+        }
+    }
+
+    Ok(BeatorajaImport {
+        root: root.to_path_buf(),
+        config,
+        players,
+    })
+}
+
+/// Make a beatoraja-relative path absolute when it exists under `root`.
+fn rebase_path(root: &Path, path: &str) -> String {
+    let p = Path::new(path);
+    if p.is_relative() && root.join(p).exists() {
+        root.join(p).to_string_lossy().to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+fn rebase_skin_paths(root: &Path, player: &mut PlayerConfig) {
+    let skins = player
+        .skin
+        .iter_mut()
+        .flatten()
+        .chain(player.skin_history.iter_mut());
+    for skin in skins {
+        if let Some(path) = skin.path.as_mut() {
+            *path = rebase_path(root, path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, contents: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    fn make_install(root: &Path) {
+        std::fs::create_dir_all(root.join("bms")).unwrap();
+        write(&root.join("skin/play/play7.luaskin"), "");
+        write(
+            &root.join("config_sys.json"),
+            r#"{"playername":"dj","bmsroot":["bms","/abs/songs"],"tableURL":["https://example.com/table.html"],"songpath":"beatoraja.db","windowWidth":1920,"windowHeight":1080}"#,
+        );
+        write(
+            &root.join("player/dj/config_player.json"),
+            r#"{"name":"DJ","gauge":3,"irconfig":[{"irname":"LR2IR","userid":"u","password":"p"}],"skin":[{"path":"skin/play/play7.luaskin"}],"mode7":{"playconfig":{"hispeed":3.5}}}"#,
+        );
+    }
+
+    #[test]
+    fn read_beatoraja_install_maps_config_and_players() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        make_install(root);
+
+        let import = read_beatoraja_install(root).unwrap();
+
+        assert_eq!(
+            import.config.paths.bmsroot,
+            vec![
+                root.join("bms").to_string_lossy().to_string(),
+                "/abs/songs".to_string()
+            ]
+        );
+        assert_eq!(import.config.display.window_width, 1920);
+        assert_eq!(import.players.len(), 1);
+        let player = &import.players[0];
+        assert_eq!(player.id.as_deref(), Some("dj"));
+        assert_eq!(player.play_settings.gauge, 3);
+        assert_eq!(player.mode7.playconfig.hispeed, 3.5);
+        assert_eq!(player.irconfig.len(), 1);
+        assert_eq!(
+            player.skin[0].as_ref().and_then(|s| s.path.clone()),
+            Some(
+                root.join("skin/play/play7.luaskin")
+                    .to_string_lossy()
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn merge_config_keeps_local_database_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        make_install(dir.path());
+        let import = read_beatoraja_install(dir.path()).unwrap();
+
+        let current = Config::default();
+        let merged = import.merge_config(&current);
+
+        assert_eq!(merged.paths.songpath, current.paths.songpath);
+        assert_eq!(merged.paths.bmsroot, import.config.paths.bmsroot);
+        assert_eq!(
+            merged.paths.table_url,
+            vec!["https://example.com/table.html".to_string()]
+        );
+        assert_eq!(merged.playername.as_deref(), Some("dj"));
+        assert_eq!(merged.display.window_width, 1920);
+    }
+
+    #[test]
+    fn detect_beatoraja_install_requires_config_and_players() {
+        let dir = tempfile::tempdir().unwrap();
+        let empty = dir.path().join("empty");
+        std::fs::create_dir_all(&empty).unwrap();
+        let install = dir.path().join("beatoraja0.8");
+        make_install(&install);
+
+        assert!(!is_beatoraja_install(&empty));
+        assert_eq!(
+            detect_beatoraja_install(&[empty, install.clone()]),
+            Some(install)
+        );
+        assert!(read_beatoraja_install(dir.path()).is_err());
+    }
+}
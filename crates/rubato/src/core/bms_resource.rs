@@ -86,39 +86,20 @@ impl BMSResource {
         self.banner = None;
         self.banner_pix = None;
         if let Some(parent) = f.parent() {
-            if !model.stagefile.is_empty()
-                && crate::audio::audio_driver::is_bms_resource_path_safe(&model.stagefile)
-            {
-                let stagefile_path = parent.join(&model.stagefile);
-                if let Some(pix) =
-                    PixmapResourcePool::load_picture(&stagefile_path.to_string_lossy())
-                {
-                    let tex = Texture::from_pixmap(&pix);
-                    self.stagefile = Some(TextureRegion::from_texture(tex));
-                }
-            }
-
-            if !model.backbmp.is_empty()
-                && crate::audio::audio_driver::is_bms_resource_path_safe(&model.backbmp)
-            {
-                let backbmp_path = parent.join(&model.backbmp);
-                if let Some(pix) = PixmapResourcePool::load_picture(&backbmp_path.to_string_lossy())
-                {
-                    let tex = Texture::from_pixmap(&pix);
-                    self.backbmp = Some(TextureRegion::from_texture(tex));
-                }
-            }
-
-            if !model.banner.is_empty()
-                && crate::audio::audio_driver::is_bms_resource_path_safe(&model.banner)
-            {
-                let banner_path = parent.join(&model.banner);
-                if let Some(pix) = PixmapResourcePool::load_picture(&banner_path.to_string_lossy())
-                {
-                    let tex = Texture::from_pixmap(&pix);
-                    self.banner = Some(TextureRegion::from_texture(tex));
-                }
-            }
+            // The three images decode independently; load them side by side.
+            let (stagefile, backbmp, banner) = thread::scope(|scope| {
+                let stagefile = scope.spawn(|| load_resource_image(parent, &model.stagefile));
+                let backbmp = scope.spawn(|| load_resource_image(parent, &model.backbmp));
+                let banner = load_resource_image(parent, &model.banner);
+                (
+                    stagefile.join().unwrap_or(None),
+                    backbmp.join().unwrap_or(None),
+                    banner,
+                )
+            });
+            self.stagefile = stagefile;
+            self.backbmp = backbmp;
+            self.banner = banner;
         }
 
         // Clean up finished loader threads
@@ -235,6 +216,15 @@ impl BMSResource {
     }
 }
 
+/// Decode a #STAGEFILE/#BACKBMP/#BANNER image next to the chart.
+pub(crate) fn load_resource_image(parent: &Path, name: &str) -> Option<TextureRegion> {
+    if name.is_empty() || !crate::audio::audio_driver::is_bms_resource_path_safe(name) {
+        return None;
+    }
+    let pix = PixmapResourcePool::load_picture(&parent.join(name).to_string_lossy())?;
+    Some(TextureRegion::from_texture(Texture::from_pixmap(&pix)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -371,6 +361,33 @@ mod tests {
         assert_eq!(bb.region_height, 256);
     }
 
+    #[test]
+    fn test_set_bms_file_loads_all_resource_images() {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, w, h) in [
+            ("stage.png", 64, 48),
+            ("back.png", 32, 32),
+            ("banner.png", 30, 8),
+        ] {
+            image::RgbaImage::new(w, h)
+                .save(dir.path().join(name))
+                .unwrap();
+        }
+        let bms_path = dir.path().join("test.bms");
+        std::fs::write(&bms_path, "").unwrap();
+
+        let mut model = BMSModel::default();
+        model.stagefile = "stage.png".to_string();
+        model.backbmp = "back.png".to_string();
+        model.banner = "banner.png".to_string();
+
+        let mut res = make_bms_resource();
+        res.set_bms_file(&model, &bms_path, &Config::default(), &BMSPlayerMode::PLAY);
+        assert_eq!(res.stagefile().unwrap().region_width, 64);
+        assert_eq!(res.backbmp().unwrap().region_width, 32);
+        assert_eq!(res.banner().unwrap().region_width, 30);
+    }
+
     #[test]
     fn test_set_bms_file_missing_image_leaves_none() {
         let dir = tempfile::tempdir().unwrap();
@@ -0,0 +1,266 @@
+use bms::model::mode::Mode;
+
+use crate::input::bm_controller_input_processor::BMKeys;
+use crate::input::bms_player_input_device::BMSPlayerInputDevice;
+use crate::input::bms_player_input_processor::BMSPlayerInputProcessor;
+use crate::input::keyboard_input_processor::ControlKeys;
+use crate::skin::hotkey_config::Hotkey;
+use crate::skin::player_config::PlayerConfig;
+use crate::tr;
+
+use super::KeyConfiguration;
+use super::constants::{KEYSA, MODE};
+
+/// Keyboard key codes scanned for a press while capturing.
+const KEYCODE_COUNT: i32 = 256;
+
+/// Device whose bindings are shown and captured.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeyConfigPage {
+    #[default]
+    Keyboard,
+    Controller,
+    Midi,
+    /// Function hotkeys used during play (keyboard only).
+    Hotkeys,
+}
+
+impl KeyConfigPage {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Keyboard => Self::Controller,
+            Self::Controller => Self::Midi,
+            Self::Midi => Self::Hotkeys,
+            Self::Hotkeys => Self::Keyboard,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Keyboard => "KEYBOARD",
+            Self::Controller => "CONTROLLER",
+            Self::Midi => "MIDI",
+            Self::Hotkeys => "HOTKEYS",
+        }
+    }
+}
+
+impl KeyConfiguration {
+    /// Chart mode configured by a layout index.
+    pub fn layout_mode(mode_index: usize) -> Mode {
+        match mode_index {
+            0 => Mode::BEAT_5K,
+            2 => Mode::POPN_9K,
+            3 => Mode::BEAT_10K,
+            4 => Mode::BEAT_14K,
+            5 => Mode::KEYBOARD_24K,
+            6 => Mode::KEYBOARD_24K_DOUBLE,
+            _ => Mode::BEAT_7K,
+        }
+    }
+
+    pub fn page(&self) -> KeyConfigPage {
+        self.page
+    }
+
+    /// Number of rows on the selected page.
+    pub fn row_count(&self) -> usize {
+        match self.page {
+            KeyConfigPage::Hotkeys => Hotkey::ALL.len(),
+            _ => KEYSA[self.mode].len(),
+        }
+    }
+
+    /// Binding of the selected page at `index`, or "---" when unassigned.
+    /// On the hotkey page `index` is the `Hotkey::index()`.
+    pub fn page_assign_label(&self, player: &PlayerConfig, index: i32) -> String {
+        let pmc = player.play_config_ref(Self::layout_mode(self.mode));
+        let label = match self.page {
+            KeyConfigPage::Keyboard => {
+                let keycode = Self::keyboard_key_assign(&pmc.keyboard, index);
+                (keycode >= 0).then(|| Self::keyboard_key_name(keycode).to_string())
+            }
+            KeyConfigPage::Controller => {
+                let buttons: Vec<String> = (0..pmc.controller.len())
+                    .filter_map(|device| {
+                        let button = Self::controller_key_assign(&pmc.controller, device, index);
+                        (button >= 0)
+                            .then(|| format!("{}P {}", device + 1, BMKeys::to_string(button)))
+                    })
+                    .collect();
+                (!buttons.is_empty()).then(|| buttons.join(", "))
+            }
+            KeyConfigPage::Midi => match index {
+                -1 => pmc.midi.start,
+                -2 => pmc.midi.select,
+                i if i >= 0 => pmc.midi.keys.get(i as usize).copied().flatten(),
+                _ => None,
+            }
+            .map(|input| input.to_string()),
+            KeyConfigPage::Hotkeys => Hotkey::ALL
+                .get(index as usize)
+                .map(|&hotkey| player.hotkeys.key(hotkey))
+                .filter(|&keycode| keycode >= 0)
+                .map(|keycode| Self::keyboard_key_name(keycode).to_string()),
+        };
+        label.unwrap_or_else(|| "---".to_string())
+    }
+
+    /// Navigate, switch mode/page, and capture presses on the selected page.
+    /// Returns true when the screen should close.
+    pub(super) fn process_input(
+        &mut self,
+        input: &mut BMSPlayerInputProcessor,
+        player: &mut PlayerConfig,
+    ) -> bool {
+        if self.keyinput {
+            if input.is_control_key_pressed(ControlKeys::Escape) {
+                self.keyinput = false;
+                self.status.clear();
+            } else {
+                self.capture(input, player);
+            }
+            return false;
+        }
+
+        let rows = self.row_count();
+        if input.is_control_key_pressed(ControlKeys::Up) {
+            self.cursorpos = (self.cursorpos + rows - 1) % rows;
+        }
+        if input.is_control_key_pressed(ControlKeys::Down) {
+            self.cursorpos = (self.cursorpos + 1) % rows;
+        }
+        if input.is_control_key_pressed(ControlKeys::Left) {
+            self.set_mode((self.mode + MODE.len() - 1) % MODE.len());
+        }
+        if input.is_control_key_pressed(ControlKeys::Right) {
+            self.set_mode((self.mode + 1) % MODE.len());
+        }
+        if input.is_control_key_pressed(ControlKeys::Insert) {
+            self.page = self.page.next();
+            self.cursorpos = self.cursorpos.min(self.row_count() - 1);
+            self.status.clear();
+        }
+        if input.is_control_key_pressed(ControlKeys::Del) {
+            if self.page == KeyConfigPage::Hotkeys {
+                player.hotkeys.set_key(Hotkey::ALL[self.cursorpos], -1);
+            } else {
+                let pmc = player.play_config(Self::layout_mode(self.mode));
+                Self::delete_key_assign(pmc, KEYSA[self.mode][self.cursorpos]);
+            }
+        }
+        if input.is_control_key_pressed(ControlKeys::Enter) {
+            self.start_capture(input);
+        }
+        input.is_control_key_pressed(ControlKeys::Escape)
+    }
+
+    fn set_mode(&mut self, mode: usize) {
+        self.mode = mode;
+        self.cursorpos = self.cursorpos.min(self.row_count() - 1);
+    }
+
+    fn start_capture(&mut self, input: &mut BMSPlayerInputProcessor) {
+        self.set_key_assign_mode(self.cursorpos);
+        let key_state = input.shared_key_state().clone();
+        self.held_keys = (0..KEYCODE_COUNT)
+            .map(|code| key_state.is_key_pressed(code))
+            .collect();
+        for controller in input.bm_input_processor_mut() {
+            controller.clear();
+        }
+        input.midi_input_processor_mut().clear_last_pressed_key();
+        self.status = match self.page {
+            KeyConfigPage::Keyboard | KeyConfigPage::Hotkeys => tr!("keyconfig-press-key"),
+            KeyConfigPage::Controller => tr!("keyconfig-press-button"),
+            KeyConfigPage::Midi => tr!("keyconfig-play-midi"),
+        };
+    }
+
+    /// First keyboard key pressed since capture started.
+    fn captured_keyboard_key(&mut self, input: &BMSPlayerInputProcessor) -> Option<i32> {
+        let key_state = input.shared_key_state().clone();
+        let mut pressed = None;
+        for code in 0..KEYCODE_COUNT {
+            let down = key_state.is_key_pressed(code);
+            let was_down = std::mem::replace(&mut self.held_keys[code as usize], down);
+            if down && !was_down && pressed.is_none() {
+                pressed = Some(code);
+            }
+        }
+        pressed
+    }
+
+    fn capture(&mut self, input: &mut BMSPlayerInputProcessor, player: &mut PlayerConfig) {
+        if self.page == KeyConfigPage::Hotkeys {
+            let Some(code) = self.captured_keyboard_key(input) else {
+                return;
+            };
+            let hotkey = Hotkey::ALL[self.cursorpos];
+            player.hotkeys.set_key(hotkey, code);
+            self.finish_capture(tr!(
+                "keyconfig-assigned-hotkey",
+                key = Self::keyboard_key_name(code),
+                hotkey = hotkey.label()
+            ));
+            return;
+        }
+
+        let index = KEYSA[self.mode][self.cursorpos];
+        let pmc = player.play_config(Self::layout_mode(self.mode));
+        match self.page {
+            KeyConfigPage::Keyboard => {
+                let Some(code) = self.captured_keyboard_key(input) else {
+                    return;
+                };
+                if input.keyboard_input_processor().is_reserved_key(code) {
+                    self.status = tr!("keyconfig-reserved", key = Self::keyboard_key_name(code));
+                    return;
+                }
+                Self::set_keyboard_key_assign(pmc, index, code, false);
+                self.finish_capture(tr!(
+                    "keybind-assigned-key",
+                    key = Self::keyboard_key_name(code)
+                ));
+            }
+            KeyConfigPage::Controller => {
+                let Some((name, button)) =
+                    input
+                        .bm_input_processor_mut()
+                        .iter_mut()
+                        .find_map(|controller| {
+                            let button = controller.last_pressed_button();
+                            controller.clear();
+                            (button >= 0).then(|| (controller.name().to_string(), button))
+                        })
+                else {
+                    return;
+                };
+                if Self::claim_controller_key_assign(pmc, index, &name, button) {
+                    self.finish_capture(tr!(
+                        "keybind-assigned-button",
+                        device = name.as_str(),
+                        button = BMKeys::to_string(button)
+                    ));
+                } else {
+                    self.status = tr!("keybind-no-free-slot", device = name.as_str());
+                }
+            }
+            KeyConfigPage::Midi => {
+                let midi = input.midi_input_processor_mut();
+                let Some(pressed) = midi.last_pressed_key() else {
+                    return;
+                };
+                midi.clear_last_pressed_key();
+                Self::set_midi_key_assign(pmc, index, Some(pressed));
+                self.finish_capture(tr!("keybind-assigned-midi", input = pressed.to_string()));
+            }
+            KeyConfigPage::Hotkeys => {}
+        }
+    }
+
+    fn finish_capture(&mut self, status: String) {
+        self.keyinput = false;
+        self.status = status;
+    }
+}
@@ -1,13 +1,20 @@
+mod capture;
 mod constants;
 mod gdx_key_name;
 mod mutators;
 #[cfg(test)]
 mod tests;
+mod view;
+
+pub use capture::KeyConfigPage;
+pub use view::KeyConfigView;
 
 use crate::skin::play_mode_config::{
     ControllerConfig, KeyboardConfig, MidiConfig, MidiInput, MouseScratchConfig,
 };
 
+use bms::model::mode::Mode;
+
 use crate::core::app_context::GameContext;
 use crate::core::main_controller::MainController;
 use crate::core::main_state::{MainState, MainStateData, MainStateType, StateTransition};
@@ -19,9 +26,9 @@ use gdx_key_name::gdx_key_name;
 // Key configuration screen.
 // Translated from Java: KeyConfiguration extends MainState
 //
-// This is heavily dependent on libGDX UI (SpriteBatch, BitmapFont, ShapeDrawer, etc.)
-// and input processing (BMSPlayerInputProcessor, BMControllerInputProcessor, MidiInputProcessor).
-// Most rendering and input methods are stubbed pending Phase 5+ graphics integration.
+// Java draws this screen with libGDX (SpriteBatch, BitmapFont, ShapeDrawer). Here
+// the state captures keyboard, controller and MIDI presses through the game's
+// BMSPlayerInputProcessor and the bindings are drawn as an egui overlay.
 
 pub struct KeyConfiguration {
     state_data: MainStateData,
@@ -30,8 +37,14 @@ pub struct KeyConfiguration {
     keyinput: bool,
     mode: usize,
     _deletepressed: bool,
-    // References to input processors and config are Phase 5+ types
-    // egui rendering deferred to Phase 9 launcher
+    /// Device whose bindings are shown and captured.
+    page: KeyConfigPage,
+    /// Keyboard keys held when capture started, so only new presses are taken.
+    held_keys: Vec<bool>,
+    status: String,
+    /// Controller enabled flags before the screen enabled every pad for capture.
+    restore_enabled: Option<Vec<bool>>,
+    exit: bool,
 }
 
 impl KeyConfiguration {
@@ -43,6 +56,11 @@ impl KeyConfiguration {
             keyinput: false,
             mode: 0,
             _deletepressed: false,
+            page: KeyConfigPage::default(),
+            held_keys: Vec::new(),
+            status: String::new(),
+            restore_enabled: None,
+            exit: false,
         }
     }
 
@@ -96,6 +114,33 @@ impl KeyConfiguration {
         KEYSA[self.mode]
     }
 
+    /// Layout index (into the mode/key tables) used for a chart mode.
+    pub fn mode_index(mode: Mode) -> usize {
+        match mode {
+            Mode::BEAT_5K => 0,
+            Mode::POPN_5K | Mode::POPN_9K => 2,
+            Mode::BEAT_10K => 3,
+            Mode::BEAT_14K => 4,
+            Mode::KEYBOARD_24K => 5,
+            Mode::KEYBOARD_24K_DOUBLE => 6,
+            _ => 1,
+        }
+    }
+
+    /// Row labels paired with their key index (-1: START, -2: SELECT) for a layout.
+    pub fn key_layout(mode_index: usize) -> impl Iterator<Item = (&'static str, i32)> {
+        let mode_index = mode_index.min(MODE.len() - 1);
+        KEYS[mode_index]
+            .iter()
+            .copied()
+            .zip(KEYSA[mode_index].iter().copied())
+    }
+
+    /// Display name of a keyboard key code.
+    pub fn keyboard_key_name(keycode: i32) -> &'static str {
+        gdx_key_name(keycode)
+    }
+
     pub fn dispose_resources(&mut self) {
         // Java disposes BitmapFont (LibGDX GPU texture). In Rust, font resources
         // (GlyphAtlas/SpriteBatch) are owned by the render pipeline and dropped automatically.
@@ -221,15 +266,12 @@ impl MainState for KeyConfiguration {
     }
 
     fn input(&mut self) {
-        // Process key assignment input.
-        // In Java, listens for keyboard/controller/MIDI input and assigns
-        // the pressed key to the currently selected slot.
-        // In Rust, input is available via BMSPlayerInputProcessor from beatoraja-input.
-        // Key assignment: when a key is pressed, store its keycode in the current
-        // PlayModeConfig's keyboard/controller/midi config for the active lane.
+        // Key assignment needs the input processor and player config, see
+        // input_with_game_context().
     }
 
     fn dispose(&mut self) {
+        Self::publish_view(None);
         self.dispose_resources();
         // Call default trait dispose for skin cleanup
         let data = self.main_state_data_mut();
@@ -239,12 +281,49 @@ impl MainState for KeyConfiguration {
         data.skin = None;
     }
 
-    fn render_with_game_context(&mut self, _ctx: &mut GameContext) -> StateTransition {
+    fn render_with_game_context(&mut self, ctx: &mut GameContext) -> StateTransition {
         self.render();
-        StateTransition::Continue
+        if !self.exit {
+            return StateTransition::Continue;
+        }
+        self.exit = false;
+        if let (Some(input), Some(enabled)) = (ctx.input.as_mut(), self.restore_enabled.take()) {
+            for (controller, enabled) in input.bm_input_processor_mut().iter_mut().zip(enabled) {
+                controller.enabled = enabled;
+            }
+        }
+        Self::publish_view(None);
+        ctx.save_config();
+        StateTransition::ChangeTo(MainStateType::MusicSelect)
     }
 
-    fn input_with_game_context(&mut self, _ctx: &mut GameContext) {
-        self.input();
+    fn input_with_game_context(&mut self, ctx: &mut GameContext) {
+        let Some(input) = ctx.input.as_mut() else {
+            return;
+        };
+        if self.restore_enabled.is_none() {
+            // Unbound pads are polled too, so any connected controller can be assigned
+            let controllers = input.bm_input_processor_mut();
+            self.restore_enabled = Some(controllers.iter().map(|c| c.enabled).collect());
+            for controller in controllers {
+                controller.enabled = true;
+            }
+            if let Some(mode) = ctx.player.mode {
+                self.mode = Self::mode_index(mode);
+            }
+        }
+
+        if self.process_input(input, &mut ctx.player) {
+            self.exit = true;
+        }
+        input.set_hotkey_config(&ctx.player.hotkeys);
+
+        let controllers = input
+            .bm_input_processor()
+            .iter()
+            .map(|c| c.name().to_string())
+            .collect();
+        let midi_ports = input.midi_input_processor().port_names().to_vec();
+        Self::publish_view(Some(self.build_view(&ctx.player, controllers, midi_ports)));
     }
 }
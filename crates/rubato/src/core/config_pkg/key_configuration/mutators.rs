@@ -130,6 +130,27 @@ impl KeyConfiguration {
         }
     }
 
+    /// Binds a controller button to `index` on the config named after the device.
+    ///
+    /// A device without a matching config claims the first unnamed one, the same
+    /// way `BMSPlayerInputProcessor::set_controller_config` does at play time.
+    /// Returns false when every config already belongs to another device.
+    pub fn claim_controller_key_assign(
+        pmc: &mut PlayModeConfig,
+        index: i32,
+        controller_name: &str,
+        last_pressed_button: i32,
+    ) -> bool {
+        if !pmc.controller.iter().any(|c| c.name == controller_name) {
+            match pmc.controller.iter_mut().find(|c| c.name.is_empty()) {
+                Some(config) => config.name = controller_name.to_string(),
+                None => return false,
+            }
+        }
+        Self::set_controller_key_assign(pmc, index, controller_name, last_pressed_button);
+        true
+    }
+
     /// Assigns a MIDI key at the given index.
     ///
     /// Java: KeyConfiguration.setMidiKeyAssign(int index)
@@ -1,10 +1,12 @@
-use super::KeyConfiguration;
 use super::gdx_key_name::gdx_key_name;
+use super::{KeyConfigPage, KeyConfiguration};
 use crate::core::main_state::MainStateData;
 use crate::core::timer_manager::TimerManager;
+use crate::skin::hotkey_config::Hotkey;
 use crate::skin::play_mode_config::{
     KeyboardConfig, MidiConfig, MidiInput, MidiInputType, PlayModeConfig,
 };
+use crate::skin::player_config::PlayerConfig;
 use bms::model::mode::Mode;
 
 /// Creates a PlayModeConfig for 7K mode (mode index 1 in KEYSA).
@@ -21,6 +23,11 @@ fn make_kc(mode: usize) -> KeyConfiguration {
         keyinput: false,
         mode,
         _deletepressed: false,
+        page: KeyConfigPage::default(),
+        held_keys: Vec::new(),
+        status: String::new(),
+        restore_enabled: None,
+        exit: false,
     }
 }
 
@@ -450,3 +457,101 @@ fn test_get_key_assign_unassigned_key() {
     let keys = vec![-1; 9]; // All unassigned
     assert_eq!(kc.key_assign(0, &keys), "---");
 }
+
+#[test]
+fn test_key_layout_follows_chart_mode() {
+    let layout: Vec<_> =
+        KeyConfiguration::key_layout(KeyConfiguration::mode_index(Mode::POPN_9K)).collect();
+    assert_eq!(layout.len(), 11);
+    assert_eq!(layout[8], ("9 KEY", 8));
+    assert_eq!(layout[10], ("SELECT", -2));
+    assert_eq!(KeyConfiguration::mode_index(Mode::BEAT_7K), 1);
+    assert_eq!(KeyConfiguration::keyboard_key_name(29), "A");
+}
+
+#[test]
+fn test_layout_mode_round_trips_mode_index() {
+    for mode_index in 0..7 {
+        let mode = KeyConfiguration::layout_mode(mode_index);
+        assert_eq!(KeyConfiguration::mode_index(mode), mode_index);
+    }
+}
+
+#[test]
+fn test_key_config_page_cycles() {
+    let page = KeyConfigPage::default();
+    assert_eq!(page, KeyConfigPage::Keyboard);
+    assert_eq!(page.next(), KeyConfigPage::Controller);
+    assert_eq!(page.next().next(), KeyConfigPage::Midi);
+    assert_eq!(page.next().next().next(), KeyConfigPage::Hotkeys);
+    assert_eq!(page.next().next().next().next(), KeyConfigPage::Keyboard);
+}
+
+#[test]
+fn test_page_assign_label_per_device() {
+    let mut player = PlayerConfig::default();
+    let mut kc = make_kc(1);
+    let keycode = player.mode7.keyboard.keys[0];
+    assert_eq!(
+        kc.page_assign_label(&player, 0),
+        KeyConfiguration::keyboard_key_name(keycode)
+    );
+
+    kc.page = KeyConfigPage::Controller;
+    assert_eq!(kc.page_assign_label(&player, 0), "---");
+    let button = crate::input::bm_controller_input_processor::BMKeys::BUTTON_4;
+    player.mode7.controller[0].keys[0] = button;
+    assert_eq!(
+        kc.page_assign_label(&player, 0),
+        format!(
+            "1P {}",
+            crate::input::bm_controller_input_processor::BMKeys::to_string(button)
+        )
+    );
+
+    kc.page = KeyConfigPage::Midi;
+    player.mode7.midi.start = None;
+    assert_eq!(kc.page_assign_label(&player, -1), "---");
+    let note = MidiInput {
+        input_type: MidiInputType::NOTE,
+        value: 60,
+    };
+    player.mode7.midi.start = Some(note);
+    assert_eq!(kc.page_assign_label(&player, -1), note.to_string());
+
+    kc.page = KeyConfigPage::Hotkeys;
+    assert_eq!(
+        kc.page_assign_label(&player, Hotkey::Close.index() as i32),
+        KeyConfiguration::keyboard_key_name(player.hotkeys.close)
+    );
+    assert_eq!(
+        kc.page_assign_label(&player, Hotkey::QuickRetry.index() as i32),
+        "---"
+    );
+}
+
+#[test]
+fn test_build_view_lists_layout_rows() {
+    let player = PlayerConfig::default();
+    let mut kc = make_kc(1);
+    kc.cursorpos = 2;
+    kc.page = KeyConfigPage::Midi;
+    let view = kc.build_view(&player, vec!["pad".to_string()], Vec::new());
+    assert_eq!(view.mode_name, kc.mode_name());
+    assert_eq!(view.page, KeyConfigPage::Midi);
+    assert_eq!(view.rows.len(), kc.keys().len());
+    assert_eq!(view.rows[0].0, kc.keys()[0]);
+    assert_eq!(view.cursor, 2);
+    assert_eq!(view.controllers, ["pad"]);
+}
+
+#[test]
+fn test_build_view_lists_hotkeys_on_hotkey_page() {
+    let player = PlayerConfig::default();
+    let mut kc = make_kc(1);
+    kc.page = KeyConfigPage::Hotkeys;
+    assert_eq!(kc.row_count(), Hotkey::ALL.len());
+    let view = kc.build_view(&player, Vec::new(), Vec::new());
+    assert_eq!(view.rows.len(), Hotkey::ALL.len());
+    assert_eq!(view.rows[1].0, Hotkey::Close.label());
+}
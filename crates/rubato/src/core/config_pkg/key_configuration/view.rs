@@ -0,0 +1,118 @@
+use std::sync::Mutex;
+
+use crate::skin::hotkey_config::Hotkey;
+use crate::skin::player_config::PlayerConfig;
+use crate::skin::sync_utils::lock_or_recover;
+
+use super::KeyConfiguration;
+use super::capture::KeyConfigPage;
+use super::constants::{KEYS, KEYSA};
+
+/// What the key configuration overlay shows this frame.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeyConfigView {
+    pub mode_name: String,
+    pub page: KeyConfigPage,
+    /// Row label and binding on the selected page.
+    pub rows: Vec<(String, String)>,
+    pub cursor: usize,
+    pub capturing: bool,
+    pub controllers: Vec<String>,
+    pub midi_ports: Vec<String>,
+    pub status: String,
+}
+
+/// Published by the Config state on the main thread, drawn from the egui frame.
+static KEY_CONFIG_VIEW: Mutex<Option<KeyConfigView>> = Mutex::new(None);
+
+impl KeyConfiguration {
+    pub fn build_view(
+        &self,
+        player: &PlayerConfig,
+        controllers: Vec<String>,
+        midi_ports: Vec<String>,
+    ) -> KeyConfigView {
+        let rows = if self.page == KeyConfigPage::Hotkeys {
+            Hotkey::ALL
+                .iter()
+                .map(|hotkey| {
+                    let binding = self.page_assign_label(player, hotkey.index() as i32);
+                    (hotkey.label().to_string(), binding)
+                })
+                .collect()
+        } else {
+            KEYS[self.mode]
+                .iter()
+                .zip(KEYSA[self.mode].iter())
+                .map(|(label, &index)| (label.to_string(), self.page_assign_label(player, index)))
+                .collect()
+        };
+        KeyConfigView {
+            mode_name: self.mode_name().to_string(),
+            page: self.page,
+            rows,
+            cursor: self.cursorpos,
+            capturing: self.keyinput,
+            controllers,
+            midi_ports,
+            status: self.status.clone(),
+        }
+    }
+
+    pub(super) fn publish_view(view: Option<KeyConfigView>) {
+        *lock_or_recover(&KEY_CONFIG_VIEW) = view;
+    }
+
+    /// Draw the key configuration screen while the Config state is active.
+    /// Called from the egui frame via static dispatch.
+    pub fn render_egui(ctx: &egui::Context) {
+        let Some(view) = lock_or_recover(&KEY_CONFIG_VIEW).clone() else {
+            return;
+        };
+        egui::Window::new("Key Configuration")
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.label(format!("{}  <  {}  >", view.page.label(), view.mode_name));
+                ui.label(
+                    "Up/Down: select  Left/Right: mode  Insert: device  \
+                     Enter: assign  Del: clear  Esc: back",
+                );
+                ui.separator();
+                egui::Grid::new("key_config_grid")
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for (i, (label, binding)) in view.rows.iter().enumerate() {
+                            let selected = i == view.cursor;
+                            let binding = if selected && view.capturing {
+                                "...".to_string()
+                            } else {
+                                binding.clone()
+                            };
+                            ui.add(egui::SelectableLabel::new(selected, label));
+                            ui.label(binding);
+                            ui.end_row();
+                        }
+                    });
+                ui.separator();
+                let devices = match view.page {
+                    KeyConfigPage::Keyboard | KeyConfigPage::Hotkeys => None,
+                    KeyConfigPage::Controller => Some(&view.controllers),
+                    KeyConfigPage::Midi => Some(&view.midi_ports),
+                };
+                match devices {
+                    Some(devices) if devices.is_empty() => {
+                        ui.label("No devices detected");
+                    }
+                    Some(devices) => {
+                        ui.label(format!("Detected: {}", devices.join(", ")));
+                    }
+                    None => {}
+                }
+                if !view.status.is_empty() {
+                    ui.label(&view.status);
+                }
+            });
+    }
+}
@@ -0,0 +1,317 @@
+//! Config hot-reload.
+//!
+//! Polls `config_sys.json` and the current player's `config_player.json` for
+//! modifications while the game is running. Changes that can take effect
+//! without re-initialising anything (volumes, skin selection, IR send/import
+//! options and IR enablement) are applied to the live config; any other
+//! change is reported back so the caller can ask for a restart.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use anyhow::{Context, Result};
+
+use crate::core::config::Config;
+use crate::core::player_config::PlayerConfig;
+use crate::core::validatable::Validatable;
+
+/// Minimum interval between file modification checks.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Watches the config files by modification time.
+pub struct ConfigFileWatcher {
+    config_path: PathBuf,
+    player_path: PathBuf,
+    config_modified: Option<SystemTime>,
+    player_modified: Option<SystemTime>,
+    last_poll: Instant,
+}
+
+impl ConfigFileWatcher {
+    /// Watch `config_sys.json` in `config_dir` and the config of player `playerid`.
+    pub fn new(config_dir: &Path, playerpath: &str, playerid: &str) -> Self {
+        let config_path = config_dir.join("config_sys.json");
+        let player_path = Path::new(playerpath)
+            .join(playerid)
+            .join("config_player.json");
+        Self {
+            config_modified: modified(&config_path),
+            player_modified: modified(&player_path),
+            config_path,
+            player_path,
+            last_poll: Instant::now(),
+        }
+    }
+
+    /// Returns true when either file changed since the last call.
+    ///
+    /// Checks the file system at most once per [`POLL_INTERVAL`].
+    pub fn poll(&mut self) -> bool {
+        if self.last_poll.elapsed() < POLL_INTERVAL {
+            return false;
+        }
+        self.last_poll = Instant::now();
+        self.check()
+    }
+
+    fn check(&mut self) -> bool {
+        let config_modified = modified(&self.config_path);
+        let player_modified = modified(&self.player_path);
+        let changed =
+            config_modified != self.config_modified || player_modified != self.player_modified;
+        self.config_modified = config_modified;
+        self.player_modified = player_modified;
+        changed
+    }
+
+    /// Parse both files. Missing files yield `None`.
+    pub fn read(&self) -> Result<(Option<Config>, Option<PlayerConfig>)> {
+        let config = read_json(&self.config_path, Config::from_json)?.map(|mut c| {
+            c.validate();
+            c
+        });
+        let player = read_json(&self.player_path, PlayerConfig::from_json)?.map(|mut p| {
+            p.validate();
+            p
+        });
+        Ok((config, player))
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn read_json<T>(path: &Path, parse: fn(&str) -> Result<T>) -> Result<Option<T>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let data = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let value = parse(&data).with_context(|| format!("failed to parse {}", path.display()))?;
+    Ok(Some(value))
+}
+
+/// What a reload did.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReloadOutcome {
+    /// Labels of the live-applied change groups ("volume", "skin", "IR").
+    pub applied: Vec<&'static str>,
+    /// JSON keys of changes that need a restart; they were not applied.
+    pub restart_required: Vec<String>,
+}
+
+impl ReloadOutcome {
+    pub fn is_empty(&self) -> bool {
+        self.applied.is_empty() && self.restart_required.is_empty()
+    }
+}
+
+/// Apply the live-reloadable part of `new_config` / `new_player` and report the rest.
+pub fn apply_config_reload(
+    config: &mut Config,
+    player: &mut PlayerConfig,
+    new_config: Option<&Config>,
+    new_player: Option<&PlayerConfig>,
+) -> ReloadOutcome {
+    let mut outcome = ReloadOutcome::default();
+
+    if let Some(new_config) = new_config {
+        let mut probe = new_config.clone();
+        if let (Some(audio), Some(new_audio)) = (config.audio.as_mut(), probe.audio.as_mut()) {
+            let volumes = (
+                new_audio.systemvolume,
+                new_audio.keyvolume,
+                new_audio.bgvolume,
+            );
+            if volumes != (audio.systemvolume, audio.keyvolume, audio.bgvolume) {
+                (audio.systemvolume, audio.keyvolume, audio.bgvolume) = volumes;
+                outcome.applied.push("volume");
+            }
+            new_audio.systemvolume = audio.systemvolume;
+            new_audio.keyvolume = audio.keyvolume;
+            new_audio.bgvolume = audio.bgvolume;
+        }
+        outcome
+            .restart_required
+            .extend(changed_keys(&*config, &probe));
+    }
+
+    if let Some(new_player) = new_player {
+        let mut probe = new_player.clone();
+        probe.id = player.id.clone();
+        if !json_eq(&probe.skin, &player.skin)
+            || !json_eq(&probe.skin_history, &player.skin_history)
+        {
+            player.skin = probe.skin.clone();
+            player.skin_history = probe.skin_history.clone();
+            outcome.applied.push("skin");
+        }
+        let mut ir_changed = false;
+        for new_ir in probe.irconfig.iter_mut().flatten() {
+            let Some(ir) = player
+                .irconfig
+                .iter_mut()
+                .flatten()
+                .find(|ir| ir.irname == new_ir.irname)
+            else {
+                continue;
+            };
+            let flags = (
+                new_ir.enabled,
+                new_ir.irsend,
+                new_ir.importrival,
+                new_ir.importscore,
+            );
+            if flags != (ir.enabled, ir.irsend, ir.importrival, ir.importscore) {
+                (ir.enabled, ir.irsend, ir.importrival, ir.importscore) = flags;
+                ir_changed = true;
+            }
+        }
+        if ir_changed {
+            outcome.applied.push("IR");
+        }
+        // Compare the remaining fields; safe ones now match the live config
+        for new_ir in probe.irconfig.iter_mut().flatten() {
+            if let Some(ir) = player
+                .irconfig
+                .iter()
+                .flatten()
+                .find(|ir| ir.irname == new_ir.irname)
+            {
+                new_ir.enabled = ir.enabled;
+                new_ir.irsend = ir.irsend;
+                new_ir.importrival = ir.importrival;
+                new_ir.importscore = ir.importscore;
+            }
+        }
+        outcome
+            .restart_required
+            .extend(changed_keys(&*player, &probe));
+    }
+
+    outcome
+}
+
+fn json_eq<T: serde::Serialize>(a: &T, b: &T) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
+
+/// Top-level JSON keys whose values differ between `a` and `b`.
+fn changed_keys<T: serde::Serialize>(a: &T, b: &T) -> Vec<String> {
+    let (Ok(serde_json::Value::Object(a)), Ok(serde_json::Value::Object(b))) =
+        (serde_json::to_value(a), serde_json::to_value(b))
+    else {
+        return Vec::new();
+    };
+    let mut keys: Vec<String> = a
+        .iter()
+        .filter(|(key, value)| b.get(*key) != Some(*value))
+        .map(|(key, _)| key.clone())
+        .chain(b.keys().filter(|key| !a.contains_key(*key)).cloned())
+        .collect();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::audio_config::AudioConfig;
+    use crate::core::ir_config::IRConfig;
+    use crate::core::skin_config::SkinConfig;
+
+    fn config() -> Config {
+        Config {
+            audio: Some(AudioConfig::default()),
+            ..Default::default()
+        }
+    }
+
+    fn player() -> PlayerConfig {
+        PlayerConfig {
+            id: Some("player1".to_string()),
+            irconfig: vec![Some(IRConfig {
+                irname: "TestIR".to_string(),
+                ..Default::default()
+            })],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unchanged_files_report_nothing() {
+        let (mut c, mut p) = (config(), player());
+        let outcome = apply_config_reload(&mut c, &mut p, Some(&config()), Some(&player()));
+        assert!(outcome.is_empty(), "{:?}", outcome);
+    }
+
+    #[test]
+    fn safe_changes_are_applied_live() {
+        let (mut c, mut p) = (config(), player());
+        let mut new_config = config();
+        new_config.audio.as_mut().unwrap().keyvolume = 0.25;
+        let mut new_player = player();
+        new_player.skin = vec![Some(SkinConfig::new_with_path("skin/other.luaskin"))];
+        let ir = new_player.irconfig[0].as_mut().unwrap();
+        ir.enabled = false;
+        ir.irsend = 2;
+
+        let outcome = apply_config_reload(&mut c, &mut p, Some(&new_config), Some(&new_player));
+
+        assert_eq!(outcome.applied, vec!["volume", "skin", "IR"]);
+        assert!(outcome.restart_required.is_empty());
+        assert_eq!(c.audio.as_ref().unwrap().keyvolume, 0.25);
+        assert_eq!(
+            p.skin[0].as_ref().and_then(|s| s.path()),
+            Some("skin/other.luaskin")
+        );
+        let ir = p.irconfig[0].as_ref().unwrap();
+        assert!(!ir.enabled);
+        assert_eq!(ir.irsend, 2);
+    }
+
+    #[test]
+    fn restart_only_changes_are_reported_not_applied() {
+        let (mut c, mut p) = (config(), player());
+        let mut new_config = config();
+        new_config.display.window_width = 1920;
+        new_config.audio.as_mut().unwrap().bgvolume = 0.5;
+        let mut new_player = player();
+        new_player.play_settings.gauge = 3;
+
+        let outcome = apply_config_reload(&mut c, &mut p, Some(&new_config), Some(&new_player));
+
+        assert_eq!(outcome.applied, vec!["volume"]);
+        assert_eq!(outcome.restart_required, vec!["windowWidth", "gauge"]);
+        assert_eq!(
+            c.display.window_width,
+            Config::default().display.window_width
+        );
+        assert_eq!(
+            p.play_settings.gauge,
+            PlayerConfig::default().play_settings.gauge
+        );
+    }
+
+    #[test]
+    fn watcher_detects_modification() {
+        let dir = tempfile::tempdir().unwrap();
+        let playerpath = dir.path().join("player");
+        std::fs::create_dir_all(playerpath.join("p1")).unwrap();
+        let mut watcher = ConfigFileWatcher::new(dir.path(), &playerpath.to_string_lossy(), "p1");
+        assert!(!watcher.check());
+
+        std::fs::write(dir.path().join("config_sys.json"), "{}").unwrap();
+        assert!(watcher.check());
+        assert!(!watcher.check());
+        let (config, player) = watcher.read().unwrap();
+        assert!(config.is_some());
+        assert!(player.is_none());
+
+        std::fs::write(playerpath.join("p1/config_player.json"), "{").unwrap();
+        assert!(watcher.check());
+        assert!(watcher.read().is_err());
+    }
+}
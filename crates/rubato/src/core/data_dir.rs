@@ -0,0 +1,202 @@
+//! Data directory resolution.
+//!
+//! All config, database and cache paths in Config are relative and resolved
+//! against the working directory, so the binary picks a data directory at
+//! startup and changes into it. The directory is chosen as follows:
+//!
+//! 1. `--data-dir` on the command line.
+//! 2. Portable mode (`--portable`, or a `portable.txt` file beside the
+//!    executable): the executable's directory.
+//! 3. An existing config in the working directory or one of its ancestors,
+//!    so installs that predate this lookup keep working.
+//! 4. The platform data directory (`$XDG_DATA_HOME/rubato`,
+//!    `%APPDATA%\rubato`, `~/Library/Application Support/rubato`).
+//! 5. The working directory.
+
+use std::path::{Path, PathBuf};
+
+use crate::core::config::resolve_config_dir;
+
+/// Marker file that enables portable mode when placed beside the executable.
+pub const PORTABLE_MARKER: &str = "portable.txt";
+
+const APP_DIR_NAME: &str = "rubato";
+
+/// Why a data directory was chosen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataDirSource {
+    CommandLine,
+    Portable,
+    ExistingConfig,
+    Platform,
+    WorkingDirectory,
+}
+
+/// Resolved data directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataDir {
+    pub root: PathBuf,
+    pub source: DataDirSource,
+}
+
+impl DataDir {
+    /// Whether the directory already holds a config file.
+    pub fn has_config(&self) -> bool {
+        self.root.join("config_sys.json").exists() || self.root.join("config.json").exists()
+    }
+}
+
+/// Inputs for [`resolve_data_dir`], gathered from the command line and environment.
+#[derive(Clone, Debug, Default)]
+pub struct DataDirOptions {
+    /// `--data-dir` override.
+    pub data_dir: Option<PathBuf>,
+    /// `--portable` flag.
+    pub portable: bool,
+    /// Directory containing the executable.
+    pub exe_dir: Option<PathBuf>,
+    /// Working directory at startup.
+    pub cwd: PathBuf,
+    /// Platform data directory (see [`platform_data_dir`]).
+    pub platform_dir: Option<PathBuf>,
+}
+
+impl DataDirOptions {
+    /// Options for the running process with the given command-line overrides.
+    pub fn from_env(data_dir: Option<PathBuf>, portable: bool) -> Self {
+        Self {
+            data_dir,
+            portable,
+            exe_dir: std::env::current_exe()
+                .ok()
+                .and_then(|exe| exe.parent().map(Path::to_path_buf)),
+            cwd: std::env::current_dir().unwrap_or_default(),
+            platform_dir: platform_data_dir(),
+        }
+    }
+}
+
+/// Pick the data directory according to the order described in the module docs.
+pub fn resolve_data_dir(options: &DataDirOptions) -> DataDir {
+    if let Some(dir) = &options.data_dir {
+        return DataDir {
+            root: options.cwd.join(dir),
+            source: DataDirSource::CommandLine,
+        };
+    }
+    if let Some(exe_dir) = &options.exe_dir
+        && (options.portable || exe_dir.join(PORTABLE_MARKER).exists())
+    {
+        return DataDir {
+            root: exe_dir.clone(),
+            source: DataDirSource::Portable,
+        };
+    }
+    if let Some(dir) = resolve_config_dir(&options.cwd) {
+        return DataDir {
+            root: dir,
+            source: DataDirSource::ExistingConfig,
+        };
+    }
+    if let Some(dir) = &options.platform_dir {
+        return DataDir {
+            root: dir.clone(),
+            source: DataDirSource::Platform,
+        };
+    }
+    DataDir {
+        root: options.cwd.clone(),
+        source: DataDirSource::WorkingDirectory,
+    }
+}
+
+/// Per-user application data directory for this platform, if it can be determined.
+pub fn platform_data_dir() -> Option<PathBuf> {
+    let env_dir = |name: &str| {
+        std::env::var_os(name)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+    let base = if cfg!(target_os = "windows") {
+        env_dir("APPDATA")?
+    } else if cfg!(target_os = "macos") {
+        env_dir("HOME")?.join("Library/Application Support")
+    } else {
+        env_dir("XDG_DATA_HOME").or_else(|| env_dir("HOME").map(|h| h.join(".local/share")))?
+    };
+    Some(base.join(APP_DIR_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(cwd: &Path) -> DataDirOptions {
+        DataDirOptions {
+            cwd: cwd.to_path_buf(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn command_line_override_wins() {
+        let dir = tempfile::tempdir().unwrap();
+        let exe_dir = dir.path().join("bin");
+        std::fs::create_dir_all(&exe_dir).unwrap();
+        std::fs::write(exe_dir.join(PORTABLE_MARKER), "").unwrap();
+        let opts = DataDirOptions {
+            data_dir: Some(PathBuf::from("data")),
+            exe_dir: Some(exe_dir),
+            ..options(dir.path())
+        };
+
+        let resolved = resolve_data_dir(&opts);
+
+        assert_eq!(resolved.root, dir.path().join("data"));
+        assert_eq!(resolved.source, DataDirSource::CommandLine);
+    }
+
+    #[test]
+    fn portable_marker_uses_executable_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let exe_dir = dir.path().join("bin");
+        std::fs::create_dir_all(&exe_dir).unwrap();
+        let mut opts = DataDirOptions {
+            exe_dir: Some(exe_dir.clone()),
+            platform_dir: Some(dir.path().join("platform")),
+            ..options(dir.path())
+        };
+        assert_eq!(resolve_data_dir(&opts).source, DataDirSource::Platform);
+
+        std::fs::write(exe_dir.join(PORTABLE_MARKER), "").unwrap();
+        assert_eq!(
+            resolve_data_dir(&opts),
+            DataDir {
+                root: exe_dir.clone(),
+                source: DataDirSource::Portable,
+            }
+        );
+
+        std::fs::remove_file(exe_dir.join(PORTABLE_MARKER)).unwrap();
+        opts.portable = true;
+        assert_eq!(resolve_data_dir(&opts).source, DataDirSource::Portable);
+    }
+
+    #[test]
+    fn existing_config_takes_precedence_over_platform_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("config_sys.json"), "{}").unwrap();
+        let cwd = dir.path().join("sub");
+        std::fs::create_dir_all(&cwd).unwrap();
+        let opts = DataDirOptions {
+            platform_dir: Some(dir.path().join("platform")),
+            ..options(&cwd)
+        };
+
+        let resolved = resolve_data_dir(&opts);
+
+        assert_eq!(resolved.source, DataDirSource::ExistingConfig);
+        assert_eq!(resolved.root, dir.path().canonicalize().unwrap());
+        assert!(resolved.has_config());
+    }
+}
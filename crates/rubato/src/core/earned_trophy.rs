@@ -0,0 +1,198 @@
+//! Course trophies and class certifications kept in the player's score
+//! database, so they survive course edits and score resets.
+
+use crate::core::clear_type::ClearType;
+use crate::core::course_data::{CourseData, TrophyData};
+use crate::core::score_data::ScoreData;
+
+/// The best a player has done on one course.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EarnedTrophy {
+    /// Course hash: the chart SHA-256s concatenated, as for course scores.
+    pub hash: String,
+    /// Course name when the trophy was earned.
+    pub course: String,
+    /// Name of the best trophy reached (`goldmedal`, ...).
+    pub trophy: Option<String>,
+    /// A class course was cleared: the player holds this dan.
+    pub certified: bool,
+    /// Unix time the record last improved.
+    pub date: i64,
+}
+
+impl EarnedTrophy {
+    /// Hash a course is recorded under.
+    pub fn course_hash(course: &CourseData) -> String {
+        course.hash.iter().map(|s| s.file.sha256.as_str()).collect()
+    }
+
+    /// What a course result earns, or `None` when it earns nothing.
+    pub fn evaluate(course: &CourseData, score: &ScoreData, date: i64) -> Option<EarnedTrophy> {
+        let trophy = course
+            .trophy
+            .iter()
+            .rfind(|t| qualified(score, t))
+            .map(|t| t.name().to_string());
+        let certified = course.is_class_course() && score.clear > ClearType::Failed.id();
+        if trophy.is_none() && !certified {
+            return None;
+        }
+        Some(EarnedTrophy {
+            hash: Self::course_hash(course),
+            course: course.name().to_string(),
+            trophy,
+            certified,
+            date,
+        })
+    }
+
+    /// Combine with the stored record `old`, keeping the better trophy (by
+    /// its position in the course's trophy list) and any certification.
+    /// Returns whether `self` improves on `old`; if not, `self` becomes `old`.
+    pub fn merge(&mut self, old: &EarnedTrophy, course: &CourseData) -> bool {
+        let rank = |name: &Option<String>| {
+            name.as_deref()
+                .and_then(|n| course.trophy.iter().position(|t| t.name() == n))
+        };
+        let better_trophy = rank(&self.trophy) > rank(&old.trophy);
+        let new_certification = self.certified && !old.certified;
+        if !better_trophy {
+            self.trophy = old.trophy.clone();
+        }
+        self.certified |= old.certified;
+        if !better_trophy && !new_certification {
+            self.date = old.date;
+            return false;
+        }
+        true
+    }
+}
+
+/// Whether `score` meets a trophy's miss rate and score rate.
+pub fn qualified(score: &ScoreData, trophy: &TrophyData) -> bool {
+    score.notes != 0
+        && trophy.missrate >= score.minbp as f32 * 100.0 / score.notes as f32
+        && trophy.scorerate <= score.exscore() as f32 * 100.0 / (score.notes as f32 * 2.0)
+}
+
+/// Dan shown as the player's rank: the most recently earned certification.
+pub fn current_dan<'a>(
+    trophies: impl IntoIterator<Item = &'a EarnedTrophy>,
+) -> Option<&'a EarnedTrophy> {
+    trophies
+        .into_iter()
+        .filter(|t| t.certified)
+        .max_by_key(|t| t.date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::course_data::CourseDataConstraint;
+    use crate::skin::SongData;
+
+    fn course(class: bool) -> CourseData {
+        let mut song = SongData::default();
+        song.file.sha256 = "a".repeat(64);
+        CourseData {
+            name: Some("sl5".to_string()),
+            hash: vec![song.clone(), song],
+            constraint: if class {
+                vec![CourseDataConstraint::Class]
+            } else {
+                Vec::new()
+            },
+            trophy: vec![
+                TrophyData::new("bronzemedal".to_string(), 7.5, 55.0),
+                TrophyData::new("silvermedal".to_string(), 5.0, 70.0),
+                TrophyData::new("goldmedal".to_string(), 2.5, 85.0),
+            ],
+            release: true,
+        }
+    }
+
+    fn score(clear: ClearType, rate: i32, minbp: i32) -> ScoreData {
+        let mut s = ScoreData {
+            clear: clear.id(),
+            notes: 100,
+            minbp,
+            ..Default::default()
+        };
+        s.judge_counts.epg = rate;
+        s
+    }
+
+    #[test]
+    fn evaluate_picks_best_trophy_and_certification() {
+        let earned = EarnedTrophy::evaluate(&course(true), &score(ClearType::Hard, 75, 4), 10)
+            .expect("earned");
+        assert_eq!(earned.hash.len(), 128);
+        assert_eq!(earned.trophy.as_deref(), Some("silvermedal"));
+        assert!(earned.certified);
+
+        let failed = EarnedTrophy::evaluate(&course(true), &score(ClearType::Failed, 75, 4), 10)
+            .expect("trophy without clear");
+        assert!(!failed.certified);
+
+        assert!(
+            EarnedTrophy::evaluate(&course(false), &score(ClearType::Normal, 10, 50), 10).is_none()
+        );
+    }
+
+    #[test]
+    fn merge_keeps_the_better_record() {
+        let c = course(true);
+        let old = EarnedTrophy {
+            trophy: Some("goldmedal".to_string()),
+            certified: false,
+            date: 1,
+            ..Default::default()
+        };
+        let mut worse = EarnedTrophy {
+            trophy: Some("bronzemedal".to_string()),
+            date: 2,
+            ..Default::default()
+        };
+        assert!(!worse.merge(&old, &c));
+        assert_eq!(worse.trophy.as_deref(), Some("goldmedal"));
+        assert_eq!(worse.date, 1);
+
+        let mut certified = EarnedTrophy {
+            certified: true,
+            date: 3,
+            ..Default::default()
+        };
+        assert!(certified.merge(&old, &c));
+        assert_eq!(certified.trophy.as_deref(), Some("goldmedal"));
+        assert_eq!(certified.date, 3);
+    }
+
+    #[test]
+    fn current_dan_is_latest_certification() {
+        let trophies = [
+            EarnedTrophy {
+                course: "sl5".to_string(),
+                certified: true,
+                date: 5,
+                ..Default::default()
+            },
+            EarnedTrophy {
+                course: "sl6".to_string(),
+                certified: true,
+                date: 9,
+                ..Default::default()
+            },
+            EarnedTrophy {
+                course: "gold".to_string(),
+                trophy: Some("goldmedal".to_string()),
+                date: 12,
+                ..Default::default()
+            },
+        ];
+        assert_eq!(
+            current_dan(&trophies).map(|t| t.course.as_str()),
+            Some("sl6")
+        );
+        assert!(current_dan(&[] as &[EarnedTrophy]).is_none());
+    }
+}
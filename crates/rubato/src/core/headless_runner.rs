@@ -0,0 +1,92 @@
+//! Headless simulation of the state machine.
+//!
+//! Drives `MainController` frame by frame without a window, GPU or audio
+//! device until the result screen is reached, then hands back the score the
+//! play state left in `PlayerResource`. Used by `rubato --headless` for CI
+//! runs and chart batch validation. Playback still advances in real time, so
+//! a run takes about as long as the chart plus the decide screen.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, bail};
+
+use crate::audio::audio_system::AudioSystem;
+use crate::audio::recording_audio_driver::RecordingAudioDriver;
+use crate::core::main_controller::MainController;
+use crate::core::main_state::MainStateType;
+use crate::core::score_data::ScoreData;
+
+/// Options for [`run_headless`].
+#[derive(Clone, Debug)]
+pub struct HeadlessOptions {
+    /// Give up when the result screen is not reached within this time.
+    pub timeout: Duration,
+    /// Sleep between frames. Zero renders as fast as possible.
+    pub frame_interval: Duration,
+}
+
+impl Default for HeadlessOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(600),
+            frame_interval: Duration::from_millis(1),
+        }
+    }
+}
+
+/// Run `controller` until the result screen and return the final score.
+///
+/// Autoplay runs report the score kept apart from the saved one.
+///
+/// A recording audio driver stands in for the sound device when none was
+/// set. The controller is disposed before returning, also on failure.
+pub fn run_headless(
+    controller: &mut MainController,
+    options: &HeadlessOptions,
+) -> Result<ScoreData> {
+    if controller.audio_processor().is_none() {
+        controller.set_audio_driver(AudioSystem::Recording(RecordingAudioDriver::new()));
+    }
+    controller.create();
+    let result = run_until_result(controller, options);
+    controller.dispose();
+    result
+}
+
+fn run_until_result(
+    controller: &mut MainController,
+    options: &HeadlessOptions,
+) -> Result<ScoreData> {
+    let start = Instant::now();
+    loop {
+        if controller.current_state_type() == Some(MainStateType::Result) {
+            // The result state takes the resource over from the controller.
+            let score = controller
+                .current_state()
+                .and_then(|s| s.player_resource())
+                .or(controller.player_resource())
+                .and_then(|r| r.score_data().or(r.autoplay_score_data()));
+            return match score {
+                Some(score) => Ok(score.clone()),
+                None => bail!("result screen reached without a score"),
+            };
+        }
+        if controller.is_exit_requested() {
+            bail!(
+                "exited in {:?} before reaching the result screen",
+                controller.current_state_type()
+            );
+        }
+        if start.elapsed() > options.timeout {
+            bail!(
+                "timed out after {:?} in {:?}",
+                options.timeout,
+                controller.current_state_type()
+            );
+        }
+        controller.render();
+        if !options.frame_interval.is_zero() {
+            std::thread::sleep(options.frame_interval);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::tr;
 
 impl MainController {
     pub fn new(
@@ -103,6 +104,23 @@ impl MainController {
             state_event_log: None,
             decide_skin_cache: None,
             preloaded_play_skin: None,
+            pending_databases: None,
+            preloaded_select_skin: None,
+            preloaded_keysounds: None,
+            config_watcher: None,
+            play_timer_on: false,
+            result_screenshot_taken: false,
+            pending_result_screenshot: None,
+            overlay_result_recorded: false,
+            last_frame_at: None,
+            crash_snapshot_at: None,
+            library_scanner: crate::song::library_scan::LibraryScanner::new(),
+            library_watcher: None,
+            pending_fade: None,
+            config_checked_at: None,
+            saved_config_json: None,
+            instance_lock: None,
+            pending_charts: std::collections::VecDeque::new(),
         }
     }
 
@@ -235,6 +253,21 @@ impl MainController {
         self.ctx.integration.obs_client = Some(client);
     }
 
+    pub fn set_overlay_server(&mut self, server: crate::external::overlay_server::OverlayServer) {
+        self.ctx.integration.overlay_server = Some(server);
+    }
+
+    pub fn overlay_server(&self) -> Option<&crate::external::overlay_server::OverlayServer> {
+        self.ctx.integration.overlay_server.as_ref()
+    }
+
+    pub fn set_state_push_server(
+        &mut self,
+        server: crate::stream::state_push_server::StatePushServer,
+    ) {
+        self.ctx.integration.state_push_server = Some(server);
+    }
+
     pub fn save_last_recording(&self, reason: &str) {
         if let Some(ref client) = self.ctx.integration.obs_client {
             client.save_last_recording(reason);
@@ -335,22 +368,483 @@ impl MainController {
                 .unwrap_or(ScreenType::Other);
             let state_type = current.state_type();
 
-            let song_info = self.resource.as_ref().and_then(|r| r.songdata()).map(|sd| {
-                crate::skin::app_event::SongInfo {
-                    title: sd.metadata.title.clone(),
-                    subtitle: sd.metadata.subtitle.clone(),
-                    artist: sd.metadata.artist.clone(),
-                    mode: sd.chart.mode,
-                }
+            // Decide and the result screens hold the resource while they run.
+            let resource = self.resource.as_ref().or_else(|| current.player_resource());
+            let song_info =
+                resource
+                    .and_then(|r| r.songdata())
+                    .map(|sd| crate::skin::app_event::SongInfo {
+                        title: sd.metadata.title.clone(),
+                        subtitle: sd.metadata.subtitle.clone(),
+                        artist: sd.metadata.artist.clone(),
+                        mode: sd.chart.mode,
+                        difficulty: sd.chart.difficulty,
+                        level: sd.chart.level,
+                        length_ms: sd.chart.length as i64,
+                    });
+
+            let is_result = matches!(
+                screen_type,
+                ScreenType::MusicResult | ScreenType::CourseResult
+            );
+            let gauge_type = resource.map(|r| match r.groove_gauge() {
+                Some(gauge) if is_result => gauge.gauge_type(),
+                _ => r.player_config().play_settings.gauge,
             });
+            let (clear_type, previous_clear_type) = if is_result {
+                let clear = resource.and_then(|r| {
+                    if screen_type == ScreenType::CourseResult {
+                        r.course_score_data()
+                    } else {
+                        r.score_data()
+                    }
+                    .map(|score| score.clear)
+                });
+                (clear, current.previous_clear_type())
+            } else {
+                (None, None)
+            };
+            let timer = &current.main_state_data().timer;
+            let play_elapsed_ms = (screen_type == ScreenType::BMSPlayer
+                && timer.is_timer_on(TIMER_PLAY))
+            .then(|| timer.now_time_for_id(TIMER_PLAY));
 
             let data = crate::skin::app_event::StateChangedData {
                 screen_type,
                 state_type,
                 status,
                 song_info,
+                gauge_type,
+                clear_type,
+                previous_clear_type,
+                play_elapsed_ms,
             };
             self.broadcast_app_event(crate::skin::app_event::AppEvent::StateChanged(data));
         }
     }
+
+    /// Re-broadcast `StateChanged` when TIMER_PLAY starts, so listeners can
+    /// show the elapsed and remaining play time.
+    pub(super) fn poll_play_timer(&mut self) {
+        let play_timer_on = self.current.as_ref().is_some_and(|current| {
+            current.state_type() == Some(MainStateType::Play)
+                && current.main_state_data().timer.is_timer_on(TIMER_PLAY)
+        });
+        if play_timer_on != self.play_timer_on {
+            self.play_timer_on = play_timer_on;
+            if play_timer_on {
+                self.broadcast_state_changed(0);
+            }
+        }
+    }
+
+    /// Schedule the automatic result screenshot once the result screen has
+    /// been shown for `RESULT_SCREENSHOT_DELAY_MS`.
+    pub(super) fn poll_result_screenshot(&mut self) {
+        use crate::external::result_screenshot::{
+            RESULT_SCREENSHOT_DELAY_MS, ResultScreenshotInfo,
+        };
+
+        let Some(ref current) = self.current else {
+            return;
+        };
+        let course = match current.state_type() {
+            Some(MainStateType::Result) => false,
+            Some(MainStateType::CourseResult) => true,
+            _ => {
+                self.result_screenshot_taken = false;
+                return;
+            }
+        };
+        if self.result_screenshot_taken
+            || !self.ctx.config.integration.result_screenshot
+            || current.main_state_data().timer.now_time() < RESULT_SCREENSHOT_DELAY_MS
+        {
+            return;
+        }
+        self.result_screenshot_taken = true;
+        let resource = self.resource.as_ref().or_else(|| current.player_resource());
+        self.pending_result_screenshot =
+            resource.and_then(|r| ResultScreenshotInfo::from_resource(r, course));
+    }
+
+    /// Publish the current screen, song and score to the stream overlay server,
+    /// the state push server and the now-playing file, recording each result
+    /// screen once in the overlay's recent results list.
+    pub(super) fn poll_overlay(&mut self) {
+        use crate::external::now_playing::NowPlayingWriter;
+        use crate::external::overlay_server::{
+            OverlayBest, OverlayLive, OverlayResult, OverlaySong,
+        };
+        use crate::external::result_screenshot::ResultScreenshotInfo;
+        use crate::external::screen_shot_exporter;
+
+        let path = &self.ctx.config.integration.now_playing_path;
+        let integration = &mut self.ctx.integration;
+        if path.is_empty() {
+            integration.now_playing = None;
+        } else if integration
+            .now_playing
+            .as_ref()
+            .is_none_or(|writer| writer.path() != std::path::Path::new(path))
+        {
+            integration.now_playing = Some(NowPlayingWriter::new(path));
+        }
+        if integration.overlay_server.is_none()
+            && integration.state_push_server.is_none()
+            && integration.now_playing.is_none()
+        {
+            return;
+        }
+        let Some(ref current) = self.current else {
+            return;
+        };
+        let state_type = current.state_type();
+        let resource = self.resource.as_ref().or_else(|| current.player_resource());
+        let song = resource.and_then(|r| r.songdata()).map(|sd| OverlaySong {
+            title: sd.metadata.title.clone(),
+            subtitle: sd.metadata.subtitle.clone(),
+            artist: sd.metadata.artist.clone(),
+            genre: sd.metadata.genre.clone(),
+            mode: sd.chart.mode,
+            level: sd.chart.level,
+            difficulty: sd.chart.difficulty,
+            minbpm: sd.chart.minbpm,
+            maxbpm: sd.chart.maxbpm,
+            notes: sd.chart.notes,
+        });
+        let live_from = |score: &crate::skin::score_data::ScoreData,
+                         rate: f32,
+                         combo: i32,
+                         gauge: Option<f32>,
+                         clear: Option<String>| OverlayLive {
+            exscore: score.exscore(),
+            max_score: score.notes * 2,
+            rate: rate * 100.0,
+            pgreat: score.judge_count_total(0),
+            great: score.judge_count_total(1),
+            good: score.judge_count_total(2),
+            bad: score.judge_count_total(3),
+            poor: score.judge_count_total(4),
+            miss: score.judge_count_total(5),
+            combo,
+            maxcombo: score.maxcombo,
+            gauge,
+            clear,
+        };
+        let course = state_type == Some(MainStateType::CourseResult);
+        let live = match state_type {
+            Some(MainStateType::Play) => {
+                let property = &current.main_state_data().score;
+                property.score_data().map(|score| {
+                    live_from(
+                        score,
+                        property.nowrate,
+                        current.now_combo().unwrap_or(0),
+                        current.groove_gauge_value(),
+                        None,
+                    )
+                })
+            }
+            Some(MainStateType::Result) | Some(MainStateType::CourseResult) => resource
+                .and_then(|r| {
+                    if course {
+                        r.course_score_data()
+                    } else {
+                        r.score_data()
+                    }
+                })
+                .map(|score| {
+                    let max = score.notes * 2;
+                    let rate = if max > 0 {
+                        score.exscore() as f32 / max as f32
+                    } else {
+                        0.0
+                    };
+                    let clear = screen_shot_exporter::clear_name(score.clear).to_string();
+                    live_from(score, rate, 0, None, Some(clear))
+                }),
+            _ => None,
+        };
+        let screen = state_type
+            .map(|t| t.obs_key().to_lowercase())
+            .unwrap_or_default();
+        if let Some(ref mut writer) = self.ctx.integration.now_playing {
+            let best = current.best_score().map(|score| OverlayBest::from(&score));
+            writer.update(&screen, song.as_ref(), live.as_ref(), best.as_ref());
+        }
+        if let Some(ref server) = self.ctx.integration.state_push_server {
+            server.update(&screen, song.clone(), live.clone());
+        }
+        let Some(ref server) = self.ctx.integration.overlay_server else {
+            return;
+        };
+        server.update(&screen, song, live);
+
+        if !matches!(
+            state_type,
+            Some(MainStateType::Result) | Some(MainStateType::CourseResult)
+        ) {
+            self.overlay_result_recorded = false;
+            return;
+        }
+        if self.overlay_result_recorded {
+            return;
+        }
+        if let Some(info) = resource.and_then(|r| ResultScreenshotInfo::from_resource(r, course)) {
+            server.record_result(OverlayResult::from(&info));
+            self.overlay_result_recorded = true;
+        }
+    }
+
+    /// Hand command lines sent by state push clients (e.g. `!!req <sha256>`) to
+    /// the stream controller.
+    pub(super) fn poll_stream_commands(&mut self) {
+        let integration = &mut self.ctx.integration;
+        let Some(ref server) = integration.state_push_server else {
+            return;
+        };
+        let lines = server.take_received();
+        let Some(ref mut controller) = integration.stream_controller else {
+            return;
+        };
+        for line in &lines {
+            controller.dispatch_line(line);
+        }
+    }
+
+    /// Publish key chatter counts and live input statistics to the input
+    /// diagnostics window while it is shown.
+    pub(super) fn poll_input_diagnostics(&mut self) {
+        use crate::modmenu::imgui_renderer::ImGuiRenderer;
+        use crate::modmenu::input_diagnostics_menu::{InputDiagnosticsMenu, InputStats};
+
+        if !ImGuiRenderer::is_input_diagnostics_shown() {
+            return;
+        }
+        let Some(ref mut input) = self.ctx.input else {
+            return;
+        };
+        if InputDiagnosticsMenu::take_reset_request() {
+            input.reset_chatter_counts();
+            input.reset_diagnostics();
+        }
+        InputDiagnosticsMenu::update_chatter_counts(input.chatter_counts());
+
+        let mode = self
+            .ctx
+            .player
+            .mode
+            .unwrap_or(bms::model::mode::Mode::BEAT_7K);
+        let diagnostics = input.diagnostics();
+        let now = crate::skin::monotonic_clock::monotonic_micros();
+        InputDiagnosticsMenu::update_input_stats(InputStats {
+            keys: diagnostics
+                .keys()
+                .iter()
+                .enumerate()
+                .filter(|(_, activity)| activity.last_event.is_some())
+                .map(|(key, activity)| (key, *activity))
+                .collect(),
+            now,
+            scratch_rate: diagnostics.press_rate(now, |key| mode.is_scratch_key(key as i32)),
+            poll_interval: diagnostics.poll_interval(),
+        });
+    }
+
+    /// Hand the current state's skin to the skin widget manager, which lists
+    /// its widgets and applies edits and saved overrides.
+    pub(super) fn poll_skin_widgets(&mut self) {
+        use crate::modmenu::imgui_renderer::ImGuiRenderer;
+        use crate::modmenu::skin_widget_manager::SkinWidgetManager;
+
+        let Some(ref mut current) = self.current else {
+            return;
+        };
+        if let Some(skin) = current.main_state_data_mut().skin.as_deref_mut() {
+            SkinWidgetManager::sync_skin(skin, ImGuiRenderer::is_skin_widget_manager_shown());
+        }
+    }
+
+    /// Keep the crash recovery snapshot of the play in progress current,
+    /// refreshing it once per `CRASH_SNAPSHOT_INTERVAL` while TIMER_PLAY is on.
+    pub(super) fn poll_crash_recovery(&mut self) {
+        use crate::play::crash_recovery;
+
+        if !self.play_timer_on {
+            self.crash_snapshot_at = None;
+            if crash_recovery::is_active() {
+                crash_recovery::clear();
+            }
+            return;
+        }
+        if self
+            .crash_snapshot_at
+            .is_some_and(|at| at.elapsed() < CRASH_SNAPSHOT_INTERVAL)
+        {
+            return;
+        }
+        self.crash_snapshot_at = Some(Instant::now());
+        let Some(mut snapshot) = self.current.as_ref().and_then(|c| c.crash_snapshot()) else {
+            return;
+        };
+        PlayerResource::append_keylog_to_replay(&self.ctx.input, &mut snapshot.replay);
+        crash_recovery::update(snapshot);
+    }
+
+    /// Start queued library scans and queue the folders the watcher reports.
+    ///
+    /// Scans wait while a chart loads or plays (Decide and Play), since chart
+    /// decoding competes with playback for CPU time.
+    pub(super) fn poll_library_scan(&mut self) {
+        use crate::song::library_scan::{LibraryFolderWatcher, WATCH_INTERVAL};
+
+        let config = &self.ctx.config;
+        let watch = config.select.watch_library
+            && !config.paths.bmsroot.is_empty()
+            && self.ctx.db.songdb.is_some();
+        if !watch {
+            self.library_watcher = None;
+        } else if self
+            .library_watcher
+            .as_ref()
+            .is_none_or(|w| w.roots() != config.paths.bmsroot.as_slice())
+        {
+            self.library_watcher = Some(LibraryFolderWatcher::spawn(
+                &config.paths.bmsroot,
+                WATCH_INTERVAL,
+            ));
+        }
+        if let Some(ref watcher) = self.library_watcher {
+            for path in watcher.take_changes() {
+                log::info!("BMS folder changed: {}", path);
+                self.library_scanner.request(Some(path), false);
+            }
+        }
+        if !matches!(
+            self.current_state_type(),
+            Some(MainStateType::Decide | MainStateType::Play)
+        ) {
+            self.library_scanner.poll(&self.ctx.config);
+        }
+    }
+
+    /// Keep the single-instance guard so later launches hand their charts over.
+    pub fn set_instance_lock(&mut self, lock: crate::core::single_instance::InstanceLock) {
+        self.instance_lock = Some(lock);
+    }
+
+    /// Queue charts handed over by later launches and open the next one
+    /// once song select can take it.
+    pub(super) fn poll_instance_requests(&mut self) {
+        use crate::core::single_instance::InstanceRequest;
+        use crate::modmenu::imgui_notify::ImGuiNotify;
+
+        if let Some(ref lock) = self.instance_lock {
+            for request in lock.take_requests() {
+                crate::core::window_command::request_focus();
+                let InstanceRequest::Open(path) = request else {
+                    continue;
+                };
+                if self.current_state_type() != Some(MainStateType::MusicSelect) {
+                    let name = path.file_name().unwrap_or_default().to_string_lossy();
+                    ImGuiNotify::info(&tr!("notify-chart-queued", name = name.to_string()));
+                }
+                self.pending_charts.push_back(path);
+            }
+        }
+        if self.pending_fade.is_some()
+            || self.current_state_type() != Some(MainStateType::MusicSelect)
+        {
+            return;
+        }
+        let Some(path) = self.pending_charts.front() else {
+            return;
+        };
+        if let Some(ref mut current) = self.current
+            && current.open_chart_file(path)
+        {
+            self.pending_charts.pop_front();
+        }
+    }
+
+    /// Drive the fade shared by all states: TIMER_STARTINPUT turns on once the
+    /// skin's input delay has passed, and a change deferred by
+    /// `request_state_change` happens once the skin's fade-out has elapsed.
+    pub(super) fn poll_state_fade(&mut self) {
+        use crate::skin::skin_property::{TIMER_FADEOUT, TIMER_STARTINPUT};
+
+        let Some(ref mut current) = self.current else {
+            self.pending_fade = None;
+            return;
+        };
+        let st = current.state_type();
+        let data = current.main_state_data_mut();
+        let (input, fadeout) = data
+            .skin
+            .as_ref()
+            .map_or((0, 0), |s| (s.input() as i64, s.fadeout() as i64));
+        // Play arms TIMER_STARTINPUT itself when the chart is ready.
+        if st != Some(MainStateType::Play) && data.skin.is_some() && data.timer.now_time() > input {
+            data.timer.switch_timer(TIMER_STARTINPUT, true);
+        }
+        let Some(state) = self.pending_fade else {
+            return;
+        };
+        if data.timer.now_time_for_id(TIMER_FADEOUT) > fadeout {
+            self.change_state(state);
+        }
+    }
+
+    /// Write the runtime metrics report to the configured dump file at its interval.
+    pub(super) fn poll_metrics_dump(&mut self) {
+        use crate::external::runtime_metrics::MetricsDumper;
+
+        let path = &self.ctx.config.integration.metrics_dump_path;
+        let integration = &mut self.ctx.integration;
+        if path.is_empty() {
+            integration.metrics_dump = None;
+            return;
+        }
+        let dumper = match integration.metrics_dump {
+            Some(ref mut dumper) if dumper.path() == std::path::Path::new(path) => dumper,
+            _ => integration.metrics_dump.insert(MetricsDumper::new(path)),
+        };
+        dumper.poll(PerformanceMetrics::get());
+    }
+
+    /// Capture the result screen on the next frame and post it to the configured
+    /// social accounts (result share hotkey).
+    pub(super) fn request_result_share(&mut self) {
+        use crate::external::result_screenshot::ResultScreenshotInfo;
+        use crate::external::social_share::SocialAccounts;
+
+        let Some(ref current) = self.current else {
+            return;
+        };
+        let course = match current.state_type() {
+            Some(MainStateType::Result) => false,
+            Some(MainStateType::CourseResult) => true,
+            _ => return,
+        };
+        if SocialAccounts::from_config(&self.ctx.config.integration, &self.ctx.player).is_empty() {
+            ImGuiNotify::warning(&tr!("notify-no-social-accounts"));
+            return;
+        }
+        let resource = self.resource.as_ref().or_else(|| current.player_resource());
+        self.pending_result_screenshot = resource
+            .and_then(|r| ResultScreenshotInfo::from_resource(r, course))
+            .map(|info| ResultScreenshotInfo {
+                social_share: true,
+                ..info
+            });
+    }
+
+    /// Take the scheduled result screenshot, if any. The app shell captures
+    /// the next rendered frame and stamps it with the returned metadata.
+    pub fn take_result_screenshot(
+        &mut self,
+    ) -> Option<crate::external::result_screenshot::ResultScreenshotInfo> {
+        self.pending_result_screenshot.take()
+    }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use crate::modmenu::controller_navigation::NavigationInput;
 
 impl MainController {
     /// Main create lifecycle method.
@@ -55,12 +56,35 @@ impl MainController {
         self.initialize_states();
         self.update_state_references();
 
+        // Rivals downloaded from IR or imported from score files
+        self.ctx.db.rivals.load_local_rivals();
+
         // Input polling: done synchronously in render().
         // Java spawns a thread that calls input.poll() once per millisecond,
         // but in Rust, poll() requires &mut self. The synchronous approach in
         // render() provides equivalent functionality for single-threaded rendering.
 
-        // Enter initial state based on bmsfile
+        // With the databases still opening, render() enters the first state
+        // once they are ready and shows a loading indicator until then.
+        if self.pending_databases.is_some() {
+            self.start_select_skin_preload();
+        } else {
+            self.enter_initial_state();
+        }
+
+        self.trigger_ln_warning();
+        self.set_target_list();
+        crate::play::crash_recovery::offer_recovery(&self.ctx.config);
+
+        self.ctx.lifecycle.last_config_save = Instant::now();
+        self.start_config_watcher();
+
+        info!("Initialization time (ms): {}", t.elapsed().as_millis());
+    }
+
+    /// Enter the first state: play `bmsfile` when one was given, otherwise
+    /// the select screen.
+    pub(super) fn enter_initial_state(&mut self) {
         if self.bmsfile.is_some() {
             // Java: if(resource.setBMSFile(bmsfile, auto)) changeState(PLAY)
             //       else { changeState(CONFIG); exit(); }
@@ -78,15 +102,10 @@ impl MainController {
                 self.exit();
             }
         } else {
+            // Scan for new and changed charts while the select screen is shown.
+            self.update_song("");
             self.change_state(MainStateType::MusicSelect);
         }
-
-        self.trigger_ln_warning();
-        self.set_target_list();
-
-        self.ctx.lifecycle.last_config_save = Instant::now();
-
-        info!("Initialization time (ms): {}", t.elapsed().as_millis());
     }
 
     /// Main render lifecycle method -- called every frame.
@@ -114,9 +133,13 @@ impl MainController {
     /// }
     /// ```
     pub fn render(&mut self) {
+        crate::profile_scope!("MainController::render");
         // timer.update()
         self.ctx.timer.update();
 
+        self.poll_config_reload();
+        self.poll_startup();
+
         // GL clear is handled by wgpu render pass in main.rs
 
         // Notify current state of media load status from PlayerResource.
@@ -134,7 +157,11 @@ impl MainController {
 
         // Poll background keysound loading (non-blocking check each frame)
         if let Some(ref mut audio) = self.ctx.audio {
+            crate::profile_scope!("AudioSystem::poll_loading");
             audio.poll_loading();
+            while let Some(load) = audio.pop_callback_load() {
+                PerformanceMetrics::get().submit_audio_callback_load(load as f64);
+            }
         }
 
         // Push gradual loading progress to the current state each frame.
@@ -149,6 +176,7 @@ impl MainController {
         // current.render() -- take the state out to avoid borrow conflict
         // between `self.current` and `self.ctx`.
         if let Some(mut current) = self.current.take() {
+            crate::profile_scope!("MainState::render");
             // Move PlayerResource into ctx so states using render_with_game_context
             // can access it via ctx.resource without a separate accessor.
             self.ctx.resource = self.resource.take();
@@ -175,6 +203,7 @@ impl MainController {
         if let Some(ref mut current) = self.current
             && let Some(ref mut audio) = self.ctx.audio
         {
+            crate::profile_scope!("MainState::sync_audio");
             current.sync_audio(audio);
         }
 
@@ -206,6 +235,7 @@ impl MainController {
 
                 if current.main_state_data().skin.is_some() {
                     if let Some(ref mut s) = sprite {
+                        crate::profile_scope!("MainState::render_skin");
                         current.render_skin(s);
                     }
                 } else {
@@ -236,6 +266,17 @@ impl MainController {
         }
         self.sprite = sprite;
 
+        self.poll_play_timer();
+        self.poll_result_screenshot();
+        self.poll_overlay();
+        self.poll_stream_commands();
+        self.poll_input_diagnostics();
+        self.poll_skin_widgets();
+        self.poll_crash_recovery();
+        self.poll_library_scan();
+        self.poll_instance_requests();
+        self.poll_state_fade();
+
         // Stage update/draw skipped (no scene2d equivalent yet)
 
         // FPS display (Phase 22+: requires system font)
@@ -263,7 +304,7 @@ impl MainController {
         // State change (destroys current state)
         let has_state_change = pending_change.is_some();
         if let Some(state_type) = pending_change {
-            self.change_state(state_type);
+            self.request_state_change(state_type);
         }
 
         // Emit OutboxDrained event when state changes were processed.
@@ -330,8 +371,14 @@ impl MainController {
         self.background_threads = remaining;
 
         self.periodic_config_save();
+        self.poll_config_changes();
 
+        let now = std::time::Instant::now();
+        if let Some(prev) = self.last_frame_at.replace(now) {
+            PerformanceMetrics::get().record_duration(WATCH_FRAME_TIME, now - prev);
+        }
         PerformanceMetrics::get().commit();
+        self.poll_metrics_dump();
 
         // ImGui rendering is handled by egui in main.rs
 
@@ -348,6 +395,7 @@ impl MainController {
                 .as_millis() as i64,
         };
         if time > self.ctx.lifecycle.prevtime {
+            crate::profile_scope!("MainState::input");
             self.ctx.lifecycle.prevtime = time;
             // Poll input (Java: done in a separate thread, Rust: done synchronously).
             // Polling inside the time gate ensures no intermediate key transitions
@@ -355,6 +403,7 @@ impl MainController {
             if let Some(ref mut input) = self.ctx.input {
                 input.poll();
             }
+            self.update_modmenu_navigation();
             if let Some(ref input) = self.ctx.input
                 && let Some(ref mut current) = self.current
             {
@@ -420,6 +469,7 @@ impl MainController {
             }
 
             // KeyCommand handlers (Java: MainController.render() lines 727-819)
+            let mut share_result = false;
             if let Some(ref mut input) = self.ctx.input {
                 // FPS display toggle
                 if input.is_activated(KeyCommand::ShowFps) {
@@ -443,6 +493,9 @@ impl MainController {
                     log::info!("Screenshot requested");
                 }
 
+                // Result sharing
+                share_result = input.is_activated(KeyCommand::ShareResult);
+
                 // Mod menu toggle
                 if input.is_activated(KeyCommand::ToggleModMenu)
                     && let Some(ref mut imgui) = self.ctx.integration.imgui
@@ -450,6 +503,41 @@ impl MainController {
                     imgui.toggle_menu();
                 }
             }
+            if share_result {
+                self.request_result_share();
+            }
+        }
+    }
+
+    /// Toggle the mod menu with the configured button combination and, while it
+    /// is open, turn lane key presses into menu navigation. Presses used for
+    /// navigation are hidden from the current state.
+    fn update_modmenu_navigation(&mut self) {
+        let (Some(input), Some(imgui)) = (&mut self.ctx.input, &mut self.ctx.integration.imgui)
+        else {
+            return;
+        };
+        let mut nav_input = NavigationInput {
+            start: input.start_pressed(),
+            select: input.is_select_pressed(),
+            ..Default::default()
+        };
+        for (key, held) in nav_input.keys.iter_mut().enumerate() {
+            *held = input.key_state(key as i32);
+        }
+        let update = self.ctx.integration.modmenu_navigation.update(
+            &nav_input,
+            &self.ctx.player.modmenu_combo,
+            imgui.is_menu_shown(),
+        );
+        if update.toggle {
+            imgui.toggle_menu();
+        }
+        for action in update.actions {
+            imgui.navigate(action);
+        }
+        for key in update.consumed_keys {
+            input.reset_key_changed_time(key as i32);
         }
     }
 
@@ -478,6 +566,8 @@ impl MainController {
         // Drop the preloaded play skin thread handle. Dropping the JoinHandle
         // detaches the background thread (intentional: "drop handle, don't join").
         self.preloaded_play_skin = None;
+        self.pending_databases = None;
+        self.preloaded_select_skin = None;
 
         // Dispose current state
         if let Some(ref mut current) = self.current {
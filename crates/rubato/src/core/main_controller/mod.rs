@@ -19,7 +19,7 @@ pub(crate) use crate::core::ir_config::IRConfig;
 pub(crate) use crate::core::main_state::{MainStateType, StateTransition};
 #[allow(deprecated)]
 pub(crate) use crate::core::main_state_listener::MainStateListener;
-pub(crate) use crate::core::performance_metrics::PerformanceMetrics;
+pub(crate) use crate::core::performance_metrics::{PerformanceMetrics, WATCH_FRAME_TIME};
 pub(crate) use crate::core::play_data_accessor::PlayDataAccessor;
 pub(crate) use crate::core::player_config::PlayerConfig;
 pub(crate) use crate::core::player_resource::PlayerResource;
@@ -29,6 +29,7 @@ pub(crate) use crate::core::system_sound_manager::SystemSoundManager;
 pub(crate) use crate::core::timer_manager::TimerManager;
 pub(crate) use crate::core::version;
 pub(crate) use crate::game_screen::GameScreen;
+pub(crate) use crate::skin::skin_property::TIMER_PLAY;
 
 /// Function pointer type for creating concrete state instances.
 ///
@@ -77,12 +78,20 @@ pub trait StateReferencesCallback: Send {
     );
 }
 
+pub use startup::StartupDatabases;
+
 /// Re-export SkinOffset from rubato-types (single source of truth for the runtime type).
 pub use crate::skin::skin_offset::SkinOffset;
 
 /// SkinProperty constants
 pub const OFFSET_MAX: usize = 255;
 
+/// How often the crash recovery snapshot is refreshed during play.
+const CRASH_SNAPSHOT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// How often the system config is checked for runtime changes (see `poll_config_changes`).
+const CONFIG_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
 /// IRStatus - holds IR connection state
 pub struct IRStatus {
     pub config: IRConfig,
@@ -192,12 +201,18 @@ pub struct DatabaseState {
 #[derive(Default)]
 pub struct IntegrationState {
     pub imgui: Option<Box<dyn crate::imgui_access::ImGuiAccess>>,
+    /// Mod menu toggle combination and navigation from lane keys.
+    pub modmenu_navigation: crate::modmenu::controller_navigation::ControllerNavigation,
     pub ir_resend_service: Option<Box<dyn crate::ir_resend_service::IrResendService>>,
     pub obs_client: Option<Box<dyn crate::obs_access::ObsAccess>>,
     pub download: Option<Box<dyn crate::music_download_access::MusicDownloadAccess>>,
     pub http_download_processor:
         Option<std::sync::Arc<dyn crate::http_download_submitter::HttpDownloadSubmitter>>,
     pub stream_controller: Option<Box<dyn crate::stream_controller_access::StreamControllerAccess>>,
+    pub overlay_server: Option<crate::external::overlay_server::OverlayServer>,
+    pub state_push_server: Option<crate::stream::state_push_server::StatePushServer>,
+    pub now_playing: Option<crate::external::now_playing::NowPlayingWriter>,
+    pub metrics_dump: Option<crate::external::runtime_metrics::MetricsDumper>,
 }
 
 /// MainController - root class of the application
@@ -264,6 +279,48 @@ pub struct MainController {
         i32,
         std::thread::JoinHandle<Option<crate::skin::types::skin::Skin>>,
     )>,
+
+    /// Thread opening the song and song information databases at startup.
+    /// The first state is entered once it finishes (see `poll_startup`).
+    pending_databases: Option<std::thread::JoinHandle<StartupDatabases>>,
+
+    /// Background thread parsing the select skin while the databases open.
+    preloaded_select_skin: Option<std::thread::JoinHandle<Option<crate::skin::types::skin::Skin>>>,
+
+    /// Keysounds loaded for the chart on the decide screen, so entering Play
+    /// with the same set skips the reload.
+    preloaded_keysounds: Option<crate::audio::audio_driver::KeysoundSet>,
+
+    /// Watches config files for hot-reload. Created in create().
+    config_watcher: Option<crate::core::config_reload::ConfigFileWatcher>,
+
+    /// Whether TIMER_PLAY was on at the last frame (see `poll_play_timer`).
+    play_timer_on: bool,
+
+    /// Whether the current result screen has been captured (see `poll_result_screenshot`).
+    result_screenshot_taken: bool,
+    /// Automatic result screenshot waiting for the app shell to capture the frame.
+    pending_result_screenshot: Option<crate::external::result_screenshot::ResultScreenshotInfo>,
+    /// Whether the current result screen has been sent to the overlay server (see `poll_overlay`).
+    overlay_result_recorded: bool,
+    /// Start of the previous frame, for the frame time metric.
+    last_frame_at: Option<std::time::Instant>,
+    /// When the crash recovery snapshot was last taken (see `poll_crash_recovery`).
+    crash_snapshot_at: Option<std::time::Instant>,
+    /// Song database updates running off the render thread (see `poll_library_scan`).
+    library_scanner: crate::song::library_scan::LibraryScanner,
+    /// Requests scans of BMS folders changed on disk when `watchLibrary` is on.
+    library_watcher: Option<crate::song::library_scan::LibraryFolderWatcher>,
+    /// State change waiting for the current skin's fade-out (see `poll_state_fade`).
+    pending_fade: Option<MainStateType>,
+    /// When the system config was last checked for changes (see `poll_config_changes`).
+    config_checked_at: Option<std::time::Instant>,
+    /// Config as last written or loaded, serialized; `None` until the first check.
+    saved_config_json: Option<String>,
+    /// Guard that makes this the only running instance (see `poll_instance_requests`).
+    instance_lock: Option<crate::core::single_instance::InstanceLock>,
+    /// Charts handed over by later launches, opened once song select can take them.
+    pending_charts: std::collections::VecDeque<std::path::PathBuf>,
 }
 
 /// Offset count (SkinProperty.OFFSET_MAX + 1)
@@ -301,6 +358,7 @@ pub struct DownloadMessageThread {
 
 mod accessors;
 mod lifecycle;
+mod startup;
 mod state_creation;
 mod state_machine;
 mod trait_impls;
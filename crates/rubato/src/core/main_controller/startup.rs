@@ -0,0 +1,105 @@
+use super::*;
+
+use crate::skin::types::skin::Skin;
+
+/// Databases opened on a background thread while the window comes up.
+#[derive(Default)]
+pub struct StartupDatabases {
+    pub songdb: Option<Box<dyn SongDatabaseAccessorTrait>>,
+    pub infodb: Option<Box<dyn SongInformationDb>>,
+    /// Illegal songs found in the song database. Startup stops when non-zero.
+    pub illegal_songs: usize,
+}
+
+impl MainController {
+    /// Hand over the thread opening the databases. `create()` then shows the
+    /// loading indicator instead of entering the first state, and `render()`
+    /// enters it once the databases are ready.
+    pub fn set_pending_databases(&mut self, handle: std::thread::JoinHandle<StartupDatabases>) {
+        self.pending_databases = Some(handle);
+    }
+
+    /// Whether the databases or the select skin are still loading.
+    pub fn is_starting_up(&self) -> bool {
+        self.pending_databases.is_some()
+    }
+
+    /// Parse the select skin in the background while the databases open.
+    /// Lua skins read the live state while loading, so they are left to the
+    /// selector.
+    pub(super) fn start_select_skin_preload(&mut self) {
+        if self.bmsfile.is_some() {
+            return;
+        }
+        let skin_type_id = crate::skin::skin_type::SkinType::MusicSelect.id();
+        let is_lua = crate::skin::skin_loader::skin_path_from_player_config(
+            self.player_config(),
+            skin_type_id,
+        )
+        .is_some_and(|path| path.ends_with(".luaskin"));
+        if is_lua {
+            return;
+        }
+        let config = self.config().clone();
+        let player_config = self.player_config().clone();
+        self.preloaded_select_skin = Some(std::thread::spawn(move || {
+            crate::skin::skin_loader::load_skin_from_config(&config, &player_config, skin_type_id)
+        }));
+    }
+
+    /// Take the preloaded select skin if it finished loading.
+    pub(super) fn take_preloaded_select_skin(&mut self) -> Option<Skin> {
+        let handle = self.preloaded_select_skin.take()?;
+        if handle.is_finished() {
+            handle.join().ok().flatten()
+        } else {
+            log::info!("Preloaded select skin not ready yet; loading synchronously");
+            None
+        }
+    }
+
+    /// Install the databases and enter the first state once the startup
+    /// threads finish. The select skin preload is waited for too so the
+    /// selector does not parse the same skin a second time.
+    pub(super) fn poll_startup(&mut self) {
+        let ready = self
+            .pending_databases
+            .as_ref()
+            .is_some_and(|handle| handle.is_finished())
+            && self
+                .preloaded_select_skin
+                .as_ref()
+                .is_none_or(|handle| handle.is_finished());
+        if !ready {
+            return;
+        }
+        let Some(handle) = self.pending_databases.take() else {
+            return;
+        };
+        let databases = handle.join().unwrap_or_else(|_| {
+            log::error!("Database startup thread panicked");
+            StartupDatabases::default()
+        });
+        if let Some(songdb) = databases.songdb {
+            self.set_song_database(songdb);
+        }
+        if let Some(infodb) = databases.infodb
+            && self.info_database().is_none()
+        {
+            self.set_info_database(infodb);
+        }
+
+        if databases.illegal_songs > 0 {
+            log::error!(
+                "Detected {} illegal BMS songs. Remove them, update song database and restart.",
+                databases.illegal_songs
+            );
+            self.preloaded_select_skin = None;
+            self.exit();
+            return;
+        }
+
+        info!("Databases ready; entering the first state");
+        self.enter_initial_state();
+    }
+}
@@ -21,6 +21,7 @@ use crate::result::PlayerResource as ResultPlayerResource;
 use crate::result::course_result::CourseResult;
 use crate::result::music_result::MusicResult;
 use crate::select::music_selector::MusicSelector;
+use crate::skin::player_config::{GHOST_REPLAY1, GHOST_SCORE};
 use crate::skin::score_data::ScoreData;
 use crate::state_factory::shared_selector::SharedMusicSelectorState;
 use crate::state_factory::wire_selector_dependencies;
@@ -83,12 +84,17 @@ impl MainController {
         &mut self,
         state_type: MainStateType,
     ) -> Option<StateCreateResult> {
+        crate::profile_scope!("MainController::create_state");
         match state_type {
             MainStateType::MusicSelect => {
                 // Java: selector = new MusicSelector(this, songUpdated);
                 // If a shared selector exists (created for StreamController), use it
                 // so stream request bars appear in the select screen.
+                let preloaded_skin = self.take_preloaded_select_skin();
                 if let Some(arc) = self.shared_music_selector() {
+                    if let Some(skin) = preloaded_skin {
+                        crate::skin::sync_utils::lock_or_recover(arc).set_preloaded_skin(skin);
+                    }
                     let wrapper = SharedMusicSelectorState::new(Arc::clone(arc));
                     return Some(StateCreateResult {
                         state: GameScreen::SharedSelect(Box::new(wrapper)),
@@ -113,6 +119,9 @@ impl MainController {
                 wire_selector_dependencies(&mut selector, self);
                 selector.config = self.player_config().clone();
                 selector.app_config = config;
+                if let Some(skin) = preloaded_skin {
+                    selector.set_preloaded_skin(skin);
+                }
                 Some(StateCreateResult {
                     state: GameScreen::Select(Box::new(selector)),
                     target_score: None,
@@ -269,6 +278,11 @@ impl MainController {
                     player.set_previous_gauge_values(gauge_log.clone());
                 }
 
+                // Wire the timing offset saved for this chart
+                if let Some(pda) = self.play_data_accessor() {
+                    player.set_chart_offset(pda.read_chart_offset(&model.sha256));
+                }
+
                 // Wire guide SE from player config
                 player.set_guide_se(self.player_config().display_settings.is_guide_se);
 
@@ -323,7 +337,12 @@ impl MainController {
                 // BMSPlayer stores freq_on/force_no_ir_send; these flow to PlayerResource
                 // via ScoreHandoff when the play session ends.
                 {
-                    let freq = crate::modmenu::freq_trainer_menu::FreqTrainerMenu::get_freq();
+                    use crate::modmenu::freq_trainer_menu::FreqTrainerMenu;
+                    let freq = if FreqTrainerMenu::is_freq_trainer_enabled() {
+                        FreqTrainerMenu::get_freq()
+                    } else {
+                        100
+                    };
                     let is_play_mode =
                         player.play_mode().mode == crate::core::bms_player_mode::Mode::Play;
                     let freq_option = self
@@ -340,6 +359,28 @@ impl MainController {
                 let sha256 = &model.sha256;
                 let has_ln = model.contains_undefined_long_note();
                 let db_score = self.read_score_data_by_hash(sha256, has_ln, lnmode);
+
+                // Ghost pacemaker from the stored score or a replay slot
+                let ghost_source = self.player_config().play_settings.ghost_source;
+                let ghost_replay = if ghost_source >= GHOST_REPLAY1 {
+                    self.read_replay_data_by_hash(
+                        sha256,
+                        has_ln,
+                        lnmode,
+                        ghost_source - GHOST_REPLAY1,
+                    )
+                } else {
+                    None
+                };
+                let ghost = if ghost_source == GHOST_SCORE {
+                    db_score.as_ref().and_then(|s| s.decode_ghost())
+                } else {
+                    ghost_replay.as_ref().map(|rd| rd.ghost.clone())
+                };
+                player.set_ghost(ghost);
+                if self.player_config().display_settings.ghost_key_beam {
+                    player.set_ghost_key_beams(ghost_replay.map(|rd| rd.keylog));
+                }
                 player.set_db_score(db_score);
 
                 // Java: resource.getRivalScoreData()
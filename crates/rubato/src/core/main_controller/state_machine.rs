@@ -1,6 +1,33 @@
 use super::*;
 
 impl MainController {
+    /// Change state once the current skin has faded out.
+    ///
+    /// States that run their own fade (Decide, Play, Result) request the change
+    /// after TIMER_FADEOUT has elapsed, so it happens immediately. For the
+    /// others TIMER_FADEOUT is turned on here and `poll_state_fade` completes
+    /// the change after `skin.fadeout()` ms. Skins without a fade-out change
+    /// immediately. While a fade runs, further requests are ignored.
+    pub fn request_state_change(&mut self, state: MainStateType) {
+        use crate::skin::skin_property::TIMER_FADEOUT;
+
+        if self.pending_fade.is_some() {
+            return;
+        }
+        let Some(ref mut current) = self.current else {
+            self.change_state(state);
+            return;
+        };
+        let data = current.main_state_data_mut();
+        let fadeout = data.skin.as_ref().map_or(0, |s| s.fadeout());
+        if fadeout <= 0 || data.timer.is_timer_on(TIMER_FADEOUT) {
+            self.change_state(state);
+            return;
+        }
+        data.timer.set_timer_on(TIMER_FADEOUT);
+        self.pending_fade = Some(state);
+    }
+
     /// Change to the specified state type.
     ///
     /// Translated from: MainController.changeState(MainStateType)
@@ -24,6 +51,8 @@ impl MainController {
     /// }
     /// ```
     pub fn change_state(&mut self, state: MainStateType) {
+        self.pending_fade = None;
+
         // Emit transition start event
         let from_state = self.current_state_type();
         self.emit_state_event(crate::skin::state_event::StateEvent::TransitionStart {
@@ -146,7 +175,32 @@ impl MainController {
     /// current.prepare();
     /// updateMainStateListener(0);
     /// ```
+    /// Load keysounds from the BMS model of `new_state` into the audio driver.
+    /// Java: audio.setModel(model) is called during resource loading in BMSPlayer;
+    /// in Rust the audio driver is owned by MainController. Decide starts the
+    /// load early; Play skips it when its (pattern-modified) chart needs the
+    /// same keysounds.
+    fn load_state_keysounds(&mut self, new_state: &crate::game_screen::GameScreen) {
+        let preloaded_keysounds = self.preloaded_keysounds.take();
+        if let Some(model) = new_state.bms_model()
+            && let Some(ref mut audio) = self.ctx.audio
+        {
+            let keysounds = crate::audio::audio_driver::KeysoundSet::of(model);
+            if new_state.state_type() == Some(MainStateType::Play)
+                && preloaded_keysounds.as_ref() == Some(&keysounds)
+            {
+                log::info!("Keysounds already loaded on the decide screen");
+            } else {
+                audio.set_model(model);
+            }
+            if new_state.state_type() == Some(MainStateType::Decide) {
+                self.preloaded_keysounds = Some(keysounds);
+            }
+        }
+    }
+
     fn transition_to_state(&mut self, mut new_state: crate::game_screen::GameScreen) {
+        crate::profile_scope!("MainController::transition_to_state");
         // Prune finished background threads before the transition so their Arc
         // references to shared resources (DB handles, IR caches, etc.) are released
         // before the old state shuts down and the new state is created.
@@ -162,6 +216,9 @@ impl MainController {
         }
         self.background_threads = remaining;
 
+        // Hold or release downloads before the new state starts loading
+        self.schedule_downloads(new_state.state_type());
+
         // Shutdown the old state BEFORE creating the new one (matching Java order).
         // This frees GPU resources (textures, skins) and flushes audio before the
         // new state loads its own resources, preventing resource contention.
@@ -258,6 +315,13 @@ impl MainController {
             false
         };
 
+        // Decide's chart is already in the resource: start decoding keysounds
+        // before create() so they load while the skin and BGA images do.
+        let is_decide = new_state.state_type() == Some(MainStateType::Decide);
+        if is_decide {
+            self.load_state_keysounds(&new_state);
+        }
+
         // Create the new state
         new_state.create();
 
@@ -280,6 +344,8 @@ impl MainController {
             {
                 input.set_enable(true);
                 input.set_play_config(self.ctx.player.play_config(mode));
+                input.set_hotkey_config(&self.ctx.player.hotkeys);
+                input.set_audio_offset(self.ctx.player.judge_settings.audio_offset);
             }
             if let Some(ref mut audio) = self.ctx.audio {
                 if effects.guide_se {
@@ -310,14 +376,9 @@ impl MainController {
             }
         }
 
-        // Load keysounds from the BMS model into the audio driver.
-        // Java: audio.setModel(model) is called during resource loading in BMSPlayer;
-        // in Rust the audio driver is owned by MainController, so we call it here
-        // after create() has set up the model.
-        if let Some(model) = new_state.bms_model()
-            && let Some(ref mut audio) = self.ctx.audio
-        {
-            audio.set_model(model);
+        // Play sets up its (pattern-modified) model in create().
+        if !is_decide {
+            self.load_state_keysounds(&new_state);
         }
 
         // Register BMS resource images (stagefile=100, backbmp=101, banner=102) into
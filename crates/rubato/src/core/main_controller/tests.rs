@@ -1656,6 +1656,37 @@ fn test_event_sender_receives_state_changed_on_transition() {
     );
 }
 
+#[test]
+fn test_event_sender_receives_state_changed_when_play_timer_starts() {
+    let mut mc = make_test_controller();
+    let (tx, rx) = std::sync::mpsc::sync_channel(256);
+    mc.add_event_sender(tx);
+    mc.change_state(MainStateType::Play);
+    while rx.try_recv().is_ok() {}
+
+    mc.poll_play_timer();
+    assert!(rx.try_recv().is_err(), "no event before TIMER_PLAY");
+
+    mc.current
+        .as_mut()
+        .unwrap()
+        .main_state_data_mut()
+        .timer
+        .set_timer_on(TIMER_PLAY);
+    mc.poll_play_timer();
+    mc.poll_play_timer();
+
+    let state_changed: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok())
+        .filter_map(|event| match event {
+            crate::skin::app_event::AppEvent::StateChanged(data) => Some(data),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(state_changed.len(), 1);
+    assert_eq!(state_changed[0].screen_type, ScreenType::BMSPlayer);
+    assert!(state_changed[0].play_elapsed_ms.is_some());
+}
+
 #[test]
 fn test_event_sender_no_state_changed_without_current_state() {
     let mut mc = make_test_controller();
@@ -1796,6 +1827,29 @@ fn test_save_config_writes_config_sys_json() {
     );
 }
 
+#[test]
+fn test_runtime_config_change_is_written() {
+    let _lock = CWD_MUTEX.lock().expect("mutex poisoned");
+    let dir = tempfile::tempdir().unwrap();
+    let _cwd = CurrentDirGuard::set(dir.path());
+    let config_path = dir.path().join("config_sys.json");
+
+    let mut mc = make_test_controller();
+    mc.poll_config_changes();
+    assert!(
+        !config_path.exists(),
+        "unchanged config should not be written"
+    );
+
+    mc.ctx.config.display.window_width = 1600;
+    mc.config_checked_at = None;
+    mc.poll_config_changes();
+
+    let contents = std::fs::read_to_string(&config_path).unwrap();
+    let written: Config = serde_json::from_str(&contents).unwrap();
+    assert_eq!(written.display.window_width, 1600);
+}
+
 #[test]
 fn test_save_config_writes_player_config_json() {
     let _lock = CWD_MUTEX.lock().expect("mutex poisoned");
@@ -2002,6 +2056,7 @@ fn make_handoff(
 ) -> crate::score_handoff::ScoreHandoff {
     crate::score_handoff::ScoreHandoff {
         score_data: None,
+        autoplay_score: None,
         combo: 0,
         maxcombo: 0,
         gauge: vec![],
@@ -2009,10 +2064,12 @@ fn make_handoff(
         assist,
         freq_on,
         force_no_ir_send,
+        judge_trainer_on: false,
         replay_data: None,
         updated_model: None,
         recent_judges: Vec::new(),
         recent_judges_index: 0,
+        judge_log: Vec::new(),
     }
 }
 
@@ -2067,6 +2124,28 @@ fn test_handoff_update_score_true_when_assist_zero() {
     );
 }
 
+#[test]
+fn test_handoff_update_score_false_for_judge_trainer_run() {
+    let mut mc = make_test_controller();
+    mc.restore_player_resource(PlayerResource::new(
+        Config::default(),
+        PlayerConfig::default(),
+    ));
+
+    let mut handoff = make_handoff(0, false, true);
+    handoff.judge_trainer_on = true;
+    mc.current = Some(GameScreen::Mock(Box::new(HandoffTestState::new(handoff))));
+
+    mc.render();
+
+    let res = mc.resource.as_ref().unwrap();
+    assert!(
+        !res.update_score,
+        "update_score must be false for judge trainer runs"
+    );
+    assert!(!res.update_course_score);
+}
+
 #[test]
 fn test_handoff_transfers_freq_on_and_force_no_ir_send() {
     let mut mc = make_test_controller();
@@ -2862,3 +2941,602 @@ fn test_transition_without_bms_images_does_not_register() {
         regs
     );
 }
+
+#[test]
+fn test_result_screenshot_scheduled_after_delay_when_enabled() {
+    use crate::external::result_screenshot::RESULT_SCREENSHOT_DELAY_MS;
+
+    let mut mc = make_test_controller();
+    mc.ctx.config.integration.result_screenshot = true;
+    let mut resource = PlayerResource::new(Config::default(), PlayerConfig::default());
+    let mut score = crate::core::score_data::ScoreData::default();
+    score.notes = 1000;
+    score.judge_counts.epg = 800;
+    score.clear = 6;
+    resource.set_score_data(score);
+    mc.resource = Some(resource);
+
+    mc.change_state(MainStateType::Result);
+    mc.poll_result_screenshot();
+    assert!(
+        mc.take_result_screenshot().is_none(),
+        "not before the delay"
+    );
+
+    let set_now = |mc: &mut MainController, ms: i64| {
+        mc.current
+            .as_mut()
+            .unwrap()
+            .main_state_data_mut()
+            .timer
+            .set_now_micro_time(ms * 1000);
+    };
+    set_now(&mut mc, RESULT_SCREENSHOT_DELAY_MS);
+    mc.poll_result_screenshot();
+    let info = mc.take_result_screenshot().expect("scheduled");
+    assert_eq!(info.exscore, 1600);
+    assert_eq!(info.max_score, 2000);
+    assert_eq!(info.clear_name(), "HARD CLEAR");
+
+    // Only once per result screen.
+    mc.poll_result_screenshot();
+    assert!(mc.take_result_screenshot().is_none());
+
+    // A new result screen is captured again.
+    mc.change_state(MainStateType::MusicSelect);
+    mc.poll_result_screenshot();
+    mc.change_state(MainStateType::Result);
+    set_now(&mut mc, RESULT_SCREENSHOT_DELAY_MS);
+    mc.poll_result_screenshot();
+    assert!(mc.take_result_screenshot().is_some());
+}
+
+#[test]
+fn test_result_screenshot_not_scheduled_when_disabled() {
+    let mut mc = make_test_controller();
+    let mut resource = PlayerResource::new(Config::default(), PlayerConfig::default());
+    resource.set_score_data(crate::core::score_data::ScoreData::default());
+    mc.resource = Some(resource);
+
+    mc.change_state(MainStateType::Result);
+    mc.current
+        .as_mut()
+        .unwrap()
+        .main_state_data_mut()
+        .timer
+        .set_now_micro_time(10_000_000);
+    mc.poll_result_screenshot();
+    assert!(mc.take_result_screenshot().is_none());
+}
+
+#[test]
+fn test_result_share_requires_result_screen_and_accounts() {
+    let mut mc = make_test_controller();
+    let mut resource = PlayerResource::new(Config::default(), PlayerConfig::default());
+    let mut score = crate::core::score_data::ScoreData::default();
+    score.notes = 1000;
+    resource.set_score_data(score);
+    mc.resource = Some(resource);
+
+    mc.change_state(MainStateType::Result);
+    mc.request_result_share();
+    assert!(
+        mc.take_result_screenshot().is_none(),
+        "no accounts configured"
+    );
+
+    mc.ctx.config.integration.misskey_instance = "misskey.example".to_string();
+    mc.ctx.config.integration.misskey_access_token = "token".to_string();
+    mc.request_result_share();
+    let info = mc
+        .take_result_screenshot()
+        .expect("share capture scheduled");
+    assert!(info.social_share);
+
+    mc.change_state(MainStateType::MusicSelect);
+    mc.request_result_share();
+    assert!(mc.take_result_screenshot().is_none());
+}
+
+#[test]
+fn test_overlay_records_each_result_screen_once() {
+    use crate::external::overlay_server::OverlayServer;
+
+    let mut mc = make_test_controller();
+    mc.set_overlay_server(
+        OverlayServer::bind(([127, 0, 0, 1], 0).into()).expect("bind overlay server"),
+    );
+    let mut resource = PlayerResource::new(Config::default(), PlayerConfig::default());
+    let mut score = crate::core::score_data::ScoreData::default();
+    score.notes = 500;
+    resource.set_score_data(score);
+    mc.resource = Some(resource);
+
+    mc.change_state(MainStateType::Result);
+    mc.poll_overlay();
+    mc.poll_overlay();
+    let snapshot = mc.overlay_server().unwrap().snapshot();
+    assert_eq!(snapshot.screen, "result");
+    assert_eq!(snapshot.results.len(), 1);
+    let live = snapshot.live.expect("result score published");
+    assert_eq!(live.max_score, 1000);
+    assert_eq!(live.gauge, None);
+
+    mc.change_state(MainStateType::MusicSelect);
+    mc.poll_overlay();
+    let snapshot = mc.overlay_server().unwrap().snapshot();
+    assert_eq!(snapshot.screen, "musicselect");
+    assert!(snapshot.live.is_none());
+
+    mc.change_state(MainStateType::Result);
+    mc.poll_overlay();
+    assert_eq!(mc.overlay_server().unwrap().snapshot().results.len(), 2);
+}
+
+#[test]
+fn test_now_playing_file_follows_config_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nowplaying.json");
+    let mut mc = make_test_controller();
+    let mut resource = PlayerResource::new(Config::default(), PlayerConfig::default());
+    let mut score = crate::core::score_data::ScoreData::default();
+    score.notes = 500;
+    resource.set_score_data(score);
+    mc.resource = Some(resource);
+
+    mc.change_state(MainStateType::Result);
+    mc.poll_overlay();
+    assert!(!path.exists(), "disabled while the path is empty");
+
+    mc.ctx.config.integration.now_playing_path = path.to_string_lossy().into_owned();
+    mc.poll_overlay();
+    let value: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(value["screen"], "result");
+    assert_eq!(value["live"]["max_score"], 1000);
+    assert_eq!(value["live"]["clear"], "NO PLAY");
+
+    mc.ctx.config.integration.now_playing_path.clear();
+    mc.poll_overlay();
+    assert!(mc.ctx.integration.now_playing.is_none());
+}
+
+#[test]
+fn test_metrics_dump_follows_config_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("metrics.json");
+    let mut mc = make_test_controller();
+
+    mc.poll_metrics_dump();
+    assert!(mc.ctx.integration.metrics_dump.is_none());
+
+    mc.ctx.config.integration.metrics_dump_path = path.to_string_lossy().into_owned();
+    mc.poll_metrics_dump();
+    let value: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert!(value["timings"].is_object());
+    assert!(value["values"].is_object());
+
+    mc.ctx.config.integration.metrics_dump_path.clear();
+    mc.poll_metrics_dump();
+    assert!(mc.ctx.integration.metrics_dump.is_none());
+}
+
+#[derive(Default)]
+struct SuspensionRecorder {
+    suspended: Mutex<Vec<bool>>,
+}
+
+impl crate::http_download_submitter::HttpDownloadSubmitter for Arc<SuspensionRecorder> {
+    fn submit_md5_task(&self, _md5: &str, _task_name: &str) {}
+
+    fn submit_url_task(&self, _url: &str, _hash: &str, _task_name: &str) {}
+
+    fn set_downloads_suspended(&self, suspended: bool) {
+        self.suspended.lock().unwrap().push(suspended);
+    }
+}
+
+#[test]
+fn test_downloads_held_during_play_when_configured() {
+    let recorder = Arc::new(SuspensionRecorder::default());
+    let mut mc = make_test_controller();
+    mc.set_http_download_processor(Box::new(recorder.clone()));
+
+    mc.change_state(MainStateType::Play);
+    mc.change_state(MainStateType::Result);
+    assert_eq!(*recorder.suspended.lock().unwrap(), [false, false]);
+
+    recorder.suspended.lock().unwrap().clear();
+    mc.ctx.config.network.download_only_when_idle = true;
+    mc.change_state(MainStateType::Decide);
+    mc.change_state(MainStateType::Play);
+    mc.change_state(MainStateType::Result);
+    mc.change_state(MainStateType::MusicSelect);
+    assert_eq!(
+        *recorder.suspended.lock().unwrap(),
+        [true, true, false, false]
+    );
+}
+
+/// Walks select -> decide -> play -> result, leaving a score behind in play.
+struct AdvancingState {
+    state_data: MainStateData,
+    state_type: MainStateType,
+}
+
+impl MainState for AdvancingState {
+    fn state_type(&self) -> Option<MainStateType> {
+        Some(self.state_type)
+    }
+    fn main_state_data(&self) -> &MainStateData {
+        &self.state_data
+    }
+    fn main_state_data_mut(&mut self) -> &mut MainStateData {
+        &mut self.state_data
+    }
+    fn create(&mut self) {}
+    fn render(&mut self) {}
+
+    fn render_with_game_context(&mut self, ctx: &mut GameContext) -> StateTransition {
+        match self.state_type {
+            MainStateType::MusicSelect => StateTransition::ChangeTo(MainStateType::Decide),
+            MainStateType::Decide => StateTransition::ChangeTo(MainStateType::Play),
+            MainStateType::Play => {
+                let mut score = crate::core::score_data::ScoreData {
+                    maxcombo: 3,
+                    ..Default::default()
+                };
+                score.judge_counts.epg = 3;
+                if let Some(ref mut resource) = ctx.resource {
+                    resource.set_score_data(score);
+                }
+                StateTransition::ChangeTo(MainStateType::Result)
+            }
+            _ => StateTransition::Continue,
+        }
+    }
+}
+
+fn advancing_creator() -> StateCreator {
+    Box::new(
+        |state_type: MainStateType, _controller: &mut MainController| {
+            Some(StateCreateResult {
+                state: GameScreen::Mock(Box::new(AdvancingState {
+                    state_data: MainStateData::new(TimerManager::new()),
+                    state_type,
+                })),
+                target_score: None,
+            })
+        },
+    )
+}
+
+#[test]
+fn test_headless_run_returns_score_at_result() {
+    use crate::core::headless_runner::{HeadlessOptions, run_headless};
+    use std::time::Duration;
+
+    let _lock = CWD_MUTEX.lock().expect("mutex poisoned");
+    let dir = tempfile::tempdir().unwrap();
+    let _cwd = CurrentDirGuard::set(dir.path());
+
+    let mut mc = make_test_controller();
+    mc.set_state_factory(advancing_creator());
+    let options = HeadlessOptions {
+        frame_interval: Duration::ZERO,
+        ..Default::default()
+    };
+
+    let score = run_headless(&mut mc, &options).unwrap();
+
+    assert_eq!(score.judge_counts.epg, 3);
+    assert_eq!(score.maxcombo, 3);
+    assert!(
+        mc.current_state().is_none(),
+        "controller should be disposed"
+    );
+}
+
+#[test]
+fn test_headless_run_times_out_without_result() {
+    use crate::core::headless_runner::{HeadlessOptions, run_headless};
+    use std::time::Duration;
+
+    let _lock = CWD_MUTEX.lock().expect("mutex poisoned");
+    let dir = tempfile::tempdir().unwrap();
+    let _cwd = CurrentDirGuard::set(dir.path());
+
+    let mut mc = make_test_controller();
+    let options = HeadlessOptions {
+        timeout: Duration::from_millis(50),
+        frame_interval: Duration::ZERO,
+    };
+
+    let err = run_headless(&mut mc, &options).unwrap_err();
+
+    assert!(err.to_string().contains("timed out"), "{err}");
+}
+
+#[test]
+fn test_update_song_queues_background_scan() {
+    let mut mc = make_test_controller();
+    mc.update_song("");
+    assert!(
+        !mc.library_scanner.has_queued(),
+        "no scan without a song database"
+    );
+
+    mc.set_song_database(Box::new(
+        crate::select::null_song_database_accessor::NullSongDatabaseAccessor,
+    ));
+    mc.ctx.config.paths.bmsroot.clear();
+    mc.update_song("");
+    assert!(
+        !mc.library_scanner.has_queued(),
+        "no scan without BMS roots"
+    );
+
+    mc.ctx.config.paths.bmsroot = vec!["songs".to_string()];
+    mc.update_song("");
+    assert!(mc.library_scanner.has_queued());
+    assert!(!mc.library_scanner.is_running(), "scans start from render");
+}
+
+// --- Shared state fade ---
+
+/// Skin with the fade timings the controller reads.
+struct FadeSkin {
+    input: i32,
+    fadeout: i32,
+}
+
+impl SkinDrawable for FadeSkin {
+    fn draw_all_objects_timed(
+        &mut self,
+        _ctx: &mut dyn crate::skin::skin_render_context::SkinRenderContext,
+    ) {
+    }
+    fn update_custom_objects_timed(
+        &mut self,
+        _ctx: &mut dyn crate::skin::skin_render_context::SkinRenderContext,
+    ) {
+    }
+    fn mouse_pressed_at(
+        &mut self,
+        _ctx: &mut dyn crate::skin::skin_render_context::SkinRenderContext,
+        _button: i32,
+        _x: i32,
+        _y: i32,
+    ) {
+    }
+    fn mouse_dragged_at(
+        &mut self,
+        _ctx: &mut dyn crate::skin::skin_render_context::SkinRenderContext,
+        _button: i32,
+        _x: i32,
+        _y: i32,
+    ) {
+    }
+    fn prepare_skin(&mut self, _state_type: Option<crate::skin::main_state_type::MainStateType>) {}
+    fn dispose_skin(&mut self) {}
+    fn fadeout(&self) -> i32 {
+        self.fadeout
+    }
+    fn input(&self) -> i32 {
+        self.input
+    }
+    fn scene(&self) -> i32 {
+        0
+    }
+    fn get_width(&self) -> f32 {
+        1280.0
+    }
+    fn get_height(&self) -> f32 {
+        720.0
+    }
+    fn swap_sprite_batch(&mut self, _batch: &mut SpriteBatch) {}
+}
+
+fn controller_in_select_with_skin(input: i32, fadeout: i32) -> MainController {
+    let mut mc = make_test_controller();
+    mc.change_state(MainStateType::MusicSelect);
+    let data = mc.current_state_mut().unwrap().main_state_data_mut();
+    data.skin = Some(Box::new(FadeSkin { input, fadeout }));
+    data.timer.set_now_micro_time(0);
+    mc
+}
+
+fn current_timer(mc: &MainController) -> &TimerManager {
+    &mc.current_state().unwrap().main_state_data().timer
+}
+
+#[test]
+fn test_state_change_waits_for_skin_fadeout() {
+    use crate::skin::skin_property::TIMER_FADEOUT;
+
+    let mut mc = controller_in_select_with_skin(0, 500);
+    mc.request_state_change(MainStateType::Config);
+    assert_eq!(mc.current_state_type(), Some(MainStateType::MusicSelect));
+    assert!(current_timer(&mc).is_timer_on(TIMER_FADEOUT));
+
+    // A second request during the fade does not replace the first.
+    mc.request_state_change(MainStateType::SkinConfig);
+
+    mc.current_state_mut()
+        .unwrap()
+        .main_state_data_mut()
+        .timer
+        .set_now_micro_time(400_000);
+    mc.poll_state_fade();
+    assert_eq!(mc.current_state_type(), Some(MainStateType::MusicSelect));
+
+    mc.current_state_mut()
+        .unwrap()
+        .main_state_data_mut()
+        .timer
+        .set_now_micro_time(501_000);
+    mc.poll_state_fade();
+    assert_eq!(mc.current_state_type(), Some(MainStateType::Config));
+    assert!(mc.pending_fade.is_none());
+}
+
+#[test]
+fn test_state_change_is_immediate_without_fadeout_or_after_own_fade() {
+    use crate::skin::skin_property::TIMER_FADEOUT;
+
+    let mut mc = controller_in_select_with_skin(0, 0);
+    mc.request_state_change(MainStateType::Config);
+    assert_eq!(mc.current_state_type(), Some(MainStateType::Config));
+
+    // States that ran their own fade request the change with TIMER_FADEOUT on.
+    let mut mc = controller_in_select_with_skin(0, 500);
+    mc.current_state_mut()
+        .unwrap()
+        .main_state_data_mut()
+        .timer
+        .set_timer_on(TIMER_FADEOUT);
+    mc.request_state_change(MainStateType::Config);
+    assert_eq!(mc.current_state_type(), Some(MainStateType::Config));
+}
+
+#[test]
+fn test_start_input_turns_on_after_skin_input_delay() {
+    use crate::skin::skin_property::TIMER_STARTINPUT;
+
+    let mut mc = controller_in_select_with_skin(300, 0);
+    mc.poll_state_fade();
+    assert!(!current_timer(&mc).is_timer_on(TIMER_STARTINPUT));
+
+    mc.current_state_mut()
+        .unwrap()
+        .main_state_data_mut()
+        .timer
+        .set_now_micro_time(301_000);
+    mc.poll_state_fade();
+    assert!(current_timer(&mc).is_timer_on(TIMER_STARTINPUT));
+}
+
+// --- Charts handed over by another launch ---
+
+/// Select state that records the charts it is asked to open.
+struct ChartOpeningState {
+    state_data: MainStateData,
+    opened: Arc<Mutex<Vec<std::path::PathBuf>>>,
+}
+
+impl MainState for ChartOpeningState {
+    fn state_type(&self) -> Option<MainStateType> {
+        Some(MainStateType::MusicSelect)
+    }
+
+    fn main_state_data(&self) -> &MainStateData {
+        &self.state_data
+    }
+
+    fn main_state_data_mut(&mut self) -> &mut MainStateData {
+        &mut self.state_data
+    }
+
+    fn create(&mut self) {}
+
+    fn render(&mut self) {}
+
+    fn open_chart_file(&mut self, path: &std::path::Path) -> bool {
+        self.opened.lock().unwrap().push(path.to_path_buf());
+        true
+    }
+}
+
+#[test]
+fn test_handed_over_chart_waits_for_song_select() {
+    let opened = Arc::new(Mutex::new(Vec::new()));
+    let mut mc = make_test_controller();
+    {
+        let opened = Arc::clone(&opened);
+        mc.set_state_factory(Box::new(move |state_type, _controller| {
+            let state = if state_type == MainStateType::MusicSelect {
+                GameScreen::Mock(Box::new(ChartOpeningState {
+                    state_data: MainStateData::new(TimerManager::new()),
+                    opened: Arc::clone(&opened),
+                }))
+            } else {
+                GameScreen::Mock(Box::new(TestState::new(state_type)))
+            };
+            Some(StateCreateResult {
+                state,
+                target_score: None,
+            })
+        }));
+    }
+    let chart = std::path::PathBuf::from("/songs/chart.bms");
+    mc.change_state(MainStateType::Result);
+    mc.pending_charts.push_back(chart.clone());
+
+    mc.poll_instance_requests();
+    assert!(opened.lock().unwrap().is_empty());
+
+    mc.change_state(MainStateType::MusicSelect);
+    mc.poll_instance_requests();
+    assert_eq!(*opened.lock().unwrap(), vec![chart]);
+    assert!(mc.pending_charts.is_empty());
+}
+
+// --- Databases opened in the background at startup ---
+
+/// Render frames until the startup threads have been picked up.
+fn render_until_started(mc: &mut MainController) {
+    for _ in 0..500 {
+        mc.render();
+        if !mc.is_starting_up() {
+            return;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    panic!("startup did not finish");
+}
+
+#[test]
+fn test_pending_databases_defer_first_state() {
+    let (tx, rx) = std::sync::mpsc::channel::<()>();
+    let mut mc = make_test_controller();
+    mc.set_pending_databases(std::thread::spawn(move || {
+        rx.recv().ok();
+        StartupDatabases {
+            songdb: Some(Box::new(
+                crate::select::null_song_database_accessor::NullSongDatabaseAccessor,
+            )),
+            ..Default::default()
+        }
+    }));
+    mc.create();
+    mc.render();
+
+    assert!(mc.is_starting_up());
+    assert!(mc.current_state().is_none());
+    assert!(mc.song_database().is_none());
+
+    tx.send(()).unwrap();
+    render_until_started(&mut mc);
+
+    assert_eq!(mc.current_state_type(), Some(MainStateType::MusicSelect));
+    assert!(mc.song_database().is_some());
+}
+
+#[test]
+fn test_illegal_songs_stop_startup() {
+    let _lock = CWD_MUTEX.lock().expect("mutex poisoned");
+    let dir = tempfile::tempdir().unwrap();
+    let _cwd = CurrentDirGuard::set(dir.path());
+
+    let mut mc = make_test_controller();
+    mc.set_pending_databases(std::thread::spawn(|| StartupDatabases {
+        illegal_songs: 1,
+        ..Default::default()
+    }));
+    mc.create();
+    render_until_started(&mut mc);
+
+    assert!(mc.is_exit_requested());
+    assert!(mc.current_state().is_none());
+}
@@ -37,4 +37,18 @@ impl MainController {
             .as_ref()
             .and_then(|pda| pda.read_score_data_by_hash(hash, ln, lnmode))
     }
+
+    pub fn read_replay_data_by_hash(
+        &self,
+        hash: &str,
+        ln: bool,
+        lnmode: i32,
+        index: i32,
+    ) -> Option<crate::skin::replay_data::ReplayData> {
+        self.ctx
+            .db
+            .playdata
+            .as_ref()
+            .and_then(|pda| pda.read_replay_data(hash, ln, lnmode, index))
+    }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use crate::tr;
 
 impl MainController {
     /// Notify all state listeners of a state change.
@@ -111,6 +112,16 @@ impl MainController {
         self.ctx.integration.http_download_processor.clone()
     }
 
+    /// With `downloadOnlyWhenIdle`, hold downloads while a chart loads or plays
+    /// (Decide and Play) and let them continue on any other screen.
+    pub(super) fn schedule_downloads(&self, state: Option<MainStateType>) {
+        if let Some(ref processor) = self.ctx.integration.http_download_processor {
+            let busy = matches!(state, Some(MainStateType::Decide | MainStateType::Play));
+            processor
+                .set_downloads_suspended(self.ctx.config.network.download_only_when_idle && busy);
+        }
+    }
+
     pub fn set_http_download_processor(
         &mut self,
         processor: Box<dyn crate::http_download_submitter::HttpDownloadSubmitter>,
@@ -122,7 +133,6 @@ impl MainController {
     ///
     /// Translated from: MainController.updateSong(String)
     /// In Java, spawns SongUpdateThread calling songdb.updateSongDatas().
-    /// Requires SongDatabaseAccessor trait to expose update_song_datas() -- deferred.
     pub fn update_song(&mut self, path: &str) {
         self.update_song_with_flag(path, false);
     }
@@ -130,6 +140,10 @@ impl MainController {
     /// Start song database update with parent-when-missing flag.
     ///
     /// Translated from: MainController.updateSong(String, boolean)
+    ///
+    /// The update is queued on the library scanner, which runs it on a
+    /// background thread and lets the select screen reload its bars as
+    /// folders are committed. An empty `path` updates every BMS root.
     pub fn update_song_with_flag(&mut self, path: &str, update_parent_when_missing: bool) {
         log::info!(
             "updating folder : {}, update parent when missing : {}",
@@ -140,26 +154,27 @@ impl MainController {
                 "no"
             }
         );
+        if self.ctx.db.songdb.is_none() {
+            return;
+        }
+        if self.ctx.config.paths.bmsroot.is_empty() {
+            log::warn!("No BMS root folders registered");
+            return;
+        }
         let update_path = if path.is_empty() {
             None
         } else {
             Some(path.to_string())
         };
-        let bmsroot = self.ctx.config.paths.bmsroot.to_vec();
-        if let Some(ref songdb) = self.ctx.db.songdb {
-            // Spawn on a background thread to avoid blocking the main/render loop.
-            // Java: SongUpdateThread.
-            let songdb = std::sync::Arc::clone(songdb);
-            let handle = std::thread::spawn(move || {
-                songdb.update_song_datas(
-                    update_path.as_deref(),
-                    &bmsroot,
-                    false,
-                    update_parent_when_missing,
-                );
-            });
-            self.background_threads.push(handle);
-        }
+        self.library_scanner
+            .request(update_path, update_parent_when_missing);
+    }
+
+    /// Progress and revision of the background library scans.
+    pub fn library_scan_status(
+        &self,
+    ) -> std::sync::Arc<crate::song::library_scan::LibraryScanStatus> {
+        self.library_scanner.status()
     }
 
     pub fn get_version() -> &'static str {
@@ -549,12 +564,46 @@ impl MainController {
         self.save_config();
     }
 
+    /// Write the system config soon after it changes at runtime (resolution,
+    /// audio, folders, external toggles) rather than waiting for the periodic
+    /// save or exit. Checked once per `CONFIG_CHECK_INTERVAL`, outside play.
+    pub(super) fn poll_config_changes(&mut self) {
+        if self.current_state_type() == Some(MainStateType::Play)
+            || self
+                .config_checked_at
+                .is_some_and(|at| at.elapsed() < CONFIG_CHECK_INTERVAL)
+        {
+            return;
+        }
+        self.config_checked_at = Some(Instant::now());
+        let json = match serde_json::to_string_pretty(&self.ctx.config) {
+            Ok(json) => json,
+            Err(e) => {
+                log::warn!("Failed to serialize config: {}", e);
+                return;
+            }
+        };
+        let Some(saved) = self.saved_config_json.replace(json) else {
+            return;
+        };
+        if self.saved_config_json.as_ref() == Some(&saved) {
+            return;
+        }
+        match Config::write(&self.ctx.config) {
+            Ok(()) => info!("Config changes saved"),
+            Err(e) => log::error!("Failed to write config: {}", e),
+        }
+    }
+
     /// Update difficulty table data in a background thread.
     ///
     /// Translated from: MainController.updateTable(TableBar)
     pub fn update_table(&mut self, source: Box<dyn crate::table_update_source::TableUpdateSource>) {
         let name = source.source_name();
-        crate::imgui_notify::ImGuiNotify::info(&format!("updating table : {name}"));
+        crate::imgui_notify::ImGuiNotify::info(&tr!(
+            "notify-table-updating",
+            name = name.to_string()
+        ));
         let handle = std::thread::spawn(move || {
             source.refresh();
         });
@@ -578,6 +627,109 @@ impl MainController {
         }
         crate::imgui_notify::ImGuiNotify::info(message);
     }
+
+    /// Start watching the config files for hot-reload.
+    pub(super) fn start_config_watcher(&mut self) {
+        let Ok(config_dir) = std::env::current_dir() else {
+            return;
+        };
+        let playerid = self
+            .ctx
+            .player
+            .id
+            .clone()
+            .or_else(|| self.ctx.config.playername.clone())
+            .unwrap_or_default();
+        self.config_watcher = Some(crate::core::config_reload::ConfigFileWatcher::new(
+            &config_dir,
+            &self.ctx.config.paths.playerpath,
+            &playerid,
+        ));
+    }
+
+    /// Apply safe config file changes made while running.
+    ///
+    /// Volumes, skin selection and IR options are applied live; other changes
+    /// are left untouched and reported as requiring a restart.
+    pub(super) fn poll_config_reload(&mut self) {
+        use crate::modmenu::imgui_notify::ImGuiNotify;
+
+        let Some(watcher) = self.config_watcher.as_mut() else {
+            return;
+        };
+        if !watcher.poll() {
+            return;
+        }
+        let (new_config, new_player) = match watcher.read() {
+            Ok(files) => files,
+            Err(e) => {
+                ImGuiNotify::error(&tr!(
+                    "notify-config-reload-failed",
+                    error = format!("{:#}", e)
+                ));
+                return;
+            }
+        };
+        let outcome = crate::core::config_reload::apply_config_reload(
+            &mut self.ctx.config,
+            &mut self.ctx.player,
+            new_config.as_ref(),
+            new_player.as_ref(),
+        );
+        // Take the reloaded config as the saved state, so edits that need
+        // a restart are not overwritten by `poll_config_changes`.
+        self.saved_config_json = None;
+        if outcome.applied.contains(&"IR") {
+            self.sync_ir_status_with_player_config();
+        }
+        if !outcome.applied.is_empty() {
+            info!("Config reloaded: {}", outcome.applied.join(", "));
+            ImGuiNotify::success(&tr!(
+                "notify-config-reloaded",
+                settings = outcome.applied.join(", ")
+            ));
+        }
+        if !outcome.restart_required.is_empty() {
+            ImGuiNotify::warning(&tr!(
+                "notify-config-restart-required",
+                settings = outcome.restart_required.join(", ")
+            ));
+        }
+    }
+
+    /// Copy IR options from the player config onto the live IR statuses and
+    /// drop connections whose IR was disabled.
+    fn sync_ir_status_with_player_config(&mut self) {
+        let irconfig = &self.ctx.player.irconfig;
+        self.ctx.db.ir.retain_mut(|status| {
+            let Some(ir) = irconfig
+                .iter()
+                .flatten()
+                .find(|ir| ir.irname == status.config.irname)
+            else {
+                return true;
+            };
+            status.config.enabled = ir.enabled;
+            status.config.irsend = ir.irsend;
+            status.config.importrival = ir.importrival;
+            status.config.importscore = ir.importscore;
+            ir.enabled
+        });
+        for ir in irconfig.iter().flatten().filter(|ir| ir.enabled) {
+            if !self
+                .ctx
+                .db
+                .ir
+                .iter()
+                .any(|status| status.config.irname == ir.irname)
+            {
+                crate::modmenu::imgui_notify::ImGuiNotify::warning(&tr!(
+                    "notify-ir-connects-on-restart",
+                    ir = ir.irname.to_string()
+                ));
+            }
+        }
+    }
 }
 
 #[cfg(test)]
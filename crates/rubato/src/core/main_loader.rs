@@ -9,7 +9,7 @@ use crate::skin::validatable::Validatable;
 use crate::song_database_accessor::SongDatabaseAccessor as SongDatabaseAccessorTrait;
 
 use crate::core::bms_player_mode::BMSPlayerMode;
-use crate::core::config::Config;
+use crate::core::config::{Config, DisplayMode};
 use crate::core::main_controller::MainController;
 use crate::core::player_config::PlayerConfig;
 use crate::core::version;
@@ -190,10 +190,15 @@ impl MainLoader {
         //        final int h = config.getResolution().height;
         //        config.setWindowWidth(w);
         //        config.setWindowHeight(h);
-        let w = config.display.resolution.width();
-        let h = config.display.resolution.height();
-        config.display.window_width = w;
-        config.display.window_height = h;
+        // A custom window size (useResolution off) is kept in windowed mode.
+        if config.display.use_resolution
+            || !matches!(config.display.displaymode, DisplayMode::WINDOW)
+        {
+            let w = config.display.resolution.width();
+            let h = config.display.resolution.height();
+            config.display.window_width = w;
+            config.display.window_height = h;
+        }
 
         // Java: MainController main = new MainController(bmsPath, config, player, playerMode, songUpdated)
         let mut main = MainController::new(bms_path, config, player, player_mode, song_updated);
@@ -249,13 +254,21 @@ impl MainLoader {
     fn check_illegal_songs() {
         let guard = lock_or_recover(Self::songdb_lock());
         if let Some(ref songdb) = *guard {
-            // SongUtils.illegalsongs = ["notme"]
-            let illegal_hashes: Vec<String> = vec!["notme".to_string()];
-            let songs = songdb.song_datas_by_hashes(&illegal_hashes);
-            for song in &songs {
-                Self::put_illegal_song(&song.file.sha256);
-            }
+            Self::check_illegal_songs_in(songdb.as_ref());
+        }
+    }
+
+    /// Record the illegal songs found in `songdb` and return how many there
+    /// were. Used when the database is opened off the main thread instead of
+    /// through the global accessor.
+    pub fn check_illegal_songs_in(songdb: &dyn SongDatabaseAccessorTrait) -> usize {
+        // SongUtils.illegalsongs = ["notme"]
+        let illegal_hashes: Vec<String> = vec!["notme".to_string()];
+        let songs = songdb.song_datas_by_hashes(&illegal_hashes);
+        for song in &songs {
+            Self::put_illegal_song(&song.file.sha256);
         }
+        songs.len()
     }
 
     pub fn version_checker() -> &'static Mutex<Option<Box<dyn VersionChecker>>> {
@@ -615,6 +628,35 @@ mod tests {
         assert_eq!(cfg.display.window_height, Resolution::FULLHD.height());
     }
 
+    #[test]
+    fn test_play_keeps_custom_window_size_in_window_mode() {
+        let _lock = TEST_LOCK.lock().unwrap();
+        use crate::core::resolution::Resolution;
+
+        MainLoader::clear_illegal_songs();
+        MainLoader::clear_score_database_accessor();
+
+        let mut config = Config::default();
+        config.display.resolution = Resolution::FULLHD;
+        config.display.use_resolution = false;
+        config.display.window_width = 1600;
+        config.display.window_height = 900;
+
+        let controller = MainLoader::play(
+            None,
+            None,
+            true,
+            Some(config),
+            Some(PlayerConfig::default()),
+            false,
+        )
+        .unwrap();
+
+        let cfg = controller.config();
+        assert_eq!(cfg.display.window_width, 1600);
+        assert_eq!(cfg.display.window_height, 900);
+    }
+
     #[test]
     fn test_play_with_songdb_passes_to_controller() {
         let _lock = TEST_LOCK.lock().unwrap();
@@ -217,6 +217,17 @@ pub trait MainState {
         None
     }
 
+    /// Returns the current combo, or None outside of play.
+    fn now_combo(&self) -> Option<i32> {
+        None
+    }
+
+    /// Returns the personal best for the selected or playing chart, as it was
+    /// before the current play, or None if the screen has no chart.
+    fn best_score(&self) -> Option<crate::core::score_data::ScoreData> {
+        None
+    }
+
     fn get_image(&self, _imageid: i32) -> Option<crate::render::texture::TextureRegion> {
         // Default no-op: concrete states override to return TextureRegion from PlayerResource.
         // Skin rendering uses the skin crate's MainState trait (separate from this trait).
@@ -276,9 +287,9 @@ pub trait MainState {
     /// Update gradual loading progress values each frame.
     /// Called by MainController with audio driver progress and whether BGA is enabled.
     /// BMSPlayer reads its own BGA progress from the BGAProcessor it owns.
-    /// Only BMSPlayer uses this for the skin property ID 165 loading bar.
+    /// BMSPlayer and MusicDecide use this for the skin property ID 165 loading bar.
     fn update_loading_progress(&mut self, _audio_progress: f32, _bga_on: bool) {
-        // Default no-op — only BMSPlayer uses this.
+        // Default no-op.
     }
 
     /// Receive an updated PlayConfig pushed from MainController after modmenu changes.
@@ -329,6 +340,32 @@ pub trait MainState {
     fn bms_model(&self) -> Option<&bms::model::bms_model::BMSModel> {
         None
     }
+
+    /// Borrow the PlayerResource this state holds until `take_player_resource`.
+    ///
+    /// Used by MainController to snapshot song and score data for
+    /// `AppEvent::StateChanged` while the resource is owned by the state.
+    fn player_resource(&self) -> Option<&crate::core::player_resource::PlayerResource> {
+        None
+    }
+
+    /// Clear type of the best score recorded before the play shown by this state.
+    fn previous_clear_type(&self) -> Option<i32> {
+        None
+    }
+
+    /// Snapshot of the play in progress, saved by the panic hook on a crash.
+    fn crash_snapshot(&self) -> Option<crate::play::crash_recovery::PlaySnapshot> {
+        None
+    }
+
+    /// Open a chart handed over by another launch.
+    ///
+    /// Returns false when this state cannot open it now; the controller keeps
+    /// it queued until one can.
+    fn open_chart_file(&mut self, _path: &std::path::Path) -> bool {
+        false
+    }
 }
 
 // Re-exported from rubato-render (canonical location)
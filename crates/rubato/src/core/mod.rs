@@ -8,6 +8,8 @@ pub mod validatable;
 // Config types
 pub mod audio_config;
 pub mod config;
+pub mod config_reload;
+pub mod data_dir;
 pub mod ir_config;
 pub mod play_config;
 pub mod play_mode_config;
@@ -21,6 +23,7 @@ pub mod version;
 
 // Data models
 pub mod course_data;
+pub mod earned_trophy;
 pub mod player_data;
 pub mod player_information;
 pub mod random_course_data;
@@ -36,6 +39,7 @@ pub mod app_context;
 pub mod bms_resource;
 pub mod command;
 pub mod disposable_object;
+pub mod headless_runner;
 pub mod main_controller;
 pub mod main_loader;
 pub mod main_state;
@@ -45,6 +49,8 @@ pub mod performance_metrics;
 pub mod pixmap_resource_pool;
 pub mod player_resource;
 pub mod resource_pool;
+pub mod score_export;
+pub mod single_instance;
 pub mod sprite_batch_helper;
 pub mod system_sound_manager;
 pub mod timer_manager;
@@ -64,6 +70,9 @@ pub mod table_data_accessor;
 // Config subpackage
 pub mod config_pkg;
 
+// Settings import from an existing beatoraja installation
+pub mod beatoraja_import;
+
 // Pattern modifiers (merged from beatoraja-pattern crate)
 pub mod pattern;
 
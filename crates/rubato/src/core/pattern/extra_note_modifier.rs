@@ -1,11 +1,19 @@
 use bms::model::bms_model::BMSModel;
-use bms::model::note::Note;
+use bms::model::note::{Note, TYPE_CHARGENOTE, TYPE_HELLCHARGENOTE, TYPE_LONGNOTE};
+use bms::model::time_line::TimeLine;
 
 use crate::core::pattern::pattern_modifier::{AssistLevel, PatternModifier, PatternModifierBase};
 
+/// Moves background notes onto free lanes.
+///
+/// `note_type` selects what is inserted: 0 inserts normal notes, while
+/// `TYPE_LONGNOTE` / `TYPE_CHARGENOTE` / `TYPE_HELLCHARGENOTE` insert long notes whose
+/// end is placed on the following timeline when that lane is still free there.
+/// Lanes covered by a long note body are never used, and the per-timeline depth is
+/// scaled down in sections that are already dense.
 pub struct ExtraNoteModifier {
     pub base: PatternModifierBase,
-    _note_type: i32,
+    note_type: i32,
     depth: i32,
     scratch: bool,
 }
@@ -14,11 +22,63 @@ impl ExtraNoteModifier {
     pub fn new(note_type: i32, depth: i32, scratch: bool) -> Self {
         ExtraNoteModifier {
             base: PatternModifierBase::new(),
-            _note_type: note_type,
+            note_type,
             depth,
             scratch,
         }
     }
+
+    fn long_note_type(&self) -> Option<i32> {
+        match self.note_type {
+            TYPE_LONGNOTE | TYPE_CHARGENOTE | TYPE_HELLCHARGENOTE => Some(self.note_type),
+            _ => None,
+        }
+    }
+
+    /// For every timeline, which lanes lie inside a long note (start through end).
+    fn long_note_bodies(timelines: &[TimeLine], mode_key: usize) -> Vec<Vec<bool>> {
+        let mut bodies = vec![vec![false; mode_key]; timelines.len()];
+        let mut active = vec![false; mode_key];
+        for (tl, body) in timelines.iter().zip(bodies.iter_mut()) {
+            for key in 0..mode_key {
+                if let Some(n) = tl.note(key as i32)
+                    && n.is_long()
+                {
+                    body[key] = true;
+                    active[key] = !n.is_end();
+                } else {
+                    body[key] = active[key];
+                }
+            }
+        }
+        bodies
+    }
+
+    /// Depth to use in each timeline: `depth` scaled by the share of lanes left free
+    /// on average by the timelines of the same section.
+    fn scaled_depths(&self, timelines: &[TimeLine], mode_key: usize) -> Vec<i32> {
+        let mut sections: Vec<(i64, usize, usize)> = Vec::new();
+        for tl in timelines {
+            let section = tl.section().floor() as i64;
+            let notes = (0..mode_key as i32)
+                .filter(|&key| tl.note(key).is_some_and(|n| !n.is_mine()))
+                .count();
+            match sections.last_mut() {
+                Some((s, count, total)) if *s == section => {
+                    *count += 1;
+                    *total += notes;
+                }
+                _ => sections.push((section, 1, notes)),
+            }
+        }
+        let mut depths = Vec::with_capacity(timelines.len());
+        for &(_, count, total) in &sections {
+            let free = 1.0 - total as f64 / (count * mode_key) as f64;
+            let depth = (self.depth as f64 * free).ceil() as i32;
+            depths.extend(std::iter::repeat_n(depth.clamp(0, self.depth), count));
+        }
+        depths
+    }
 }
 
 impl PatternModifier for ExtraNoteModifier {
@@ -28,65 +88,89 @@ impl PatternModifier for ExtraNoteModifier {
         if mode_key == 0 {
             return;
         }
+        let mode_key = mode_key as usize;
         let scratch = self.scratch;
+        let ln_type = self.long_note_type();
 
         let mode = model.mode().copied();
         let timelines = &mut model.timelines;
-        let mut lns = vec![false; mode_key as usize];
-        let mut blank = vec![false; mode_key as usize];
-        let mut lastnote: Vec<Option<Note>> = vec![None; mode_key as usize];
+        let mut ln_body = Self::long_note_bodies(timelines, mode_key);
+        let depths = self.scaled_depths(timelines, mode_key);
+        let mut blank = vec![false; mode_key];
+        let mut lastnote: Vec<Option<Note>> = vec![None; mode_key];
         let mut lastoffset = 0usize;
+        let mut inserted_ln = false;
 
-        for tl in timelines.iter_mut() {
-            for key in 0..mode_key as usize {
-                let note = tl.note(key as i32);
-                if let Some(n) = note
-                    && n.is_long()
-                {
-                    lns[key] = !n.is_end();
-                }
+        for index in 0..timelines.len() {
+            let tl = &timelines[index];
+            for key in 0..mode_key {
                 let is_scratch = mode
                     .as_ref()
                     .map(|m| m.is_scratch_key(key as i32))
                     .unwrap_or(false);
-                blank[key] = !lns[key] && tl.note(key as i32).is_none() && (scratch || !is_scratch);
+                blank[key] = !ln_body[index][key]
+                    && tl.note(key as i32).is_none()
+                    && tl.hidden_note(key as i32).is_none()
+                    && (scratch || !is_scratch);
             }
 
-            for _d in 0..self.depth {
-                if !tl.back_ground_notes().is_empty() {
-                    let note = tl.back_ground_notes()[0].clone();
-
-                    let mut offset = lastoffset;
-                    for _j in 1..mode_key as usize {
-                        if let Some(ref ln) = lastnote[offset]
-                            && ln.wav() == note.wav()
-                        {
-                            break;
-                        }
-                        offset = (offset + 1) % mode_key as usize;
-                    }
-                    lastoffset = offset;
-
-                    let mut placed = false;
-                    let mut key = offset % mode_key as usize;
-                    for _j in 0..mode_key as usize {
-                        if blank[key] {
-                            lastnote[key] = Some(note.clone());
-                            tl.set_note(key as i32, Some(note.clone()));
-                            tl.remove_back_ground_note(0);
-                            assist = AssistLevel::Assist;
-                            placed = true;
-                            break;
-                        }
-                        key = (key + 1) % mode_key as usize;
-                    }
-                    if !placed {
+            for _d in 0..depths[index] {
+                if timelines[index].back_ground_notes().is_empty() {
+                    break;
+                }
+                let note = timelines[index].back_ground_notes()[0].clone();
+
+                let mut offset = lastoffset;
+                for _j in 1..mode_key {
+                    if let Some(ref ln) = lastnote[offset]
+                        && ln.wav() == note.wav()
+                    {
                         break;
                     }
+                    offset = (offset + 1) % mode_key;
+                }
+                lastoffset = offset;
+
+                let Some(key) = (0..mode_key)
+                    .map(|j| (offset + j) % mode_key)
+                    .find(|&k| blank[k])
+                else {
+                    break;
+                };
+                blank[key] = false;
+                lastnote[key] = Some(note.clone());
+                timelines[index].remove_back_ground_note(0);
+                assist = AssistLevel::Assist;
+
+                // A long note needs the same lane to be free on the next timeline for its end.
+                let end_index = index + 1;
+                let can_end = end_index < timelines.len()
+                    && !ln_body[end_index][key]
+                    && !timelines[end_index].exist_note_at(key as i32)
+                    && timelines[end_index].hidden_note(key as i32).is_none();
+                match ln_type {
+                    Some(t) if can_end => {
+                        let mut start = Note::new_long_with_start_duration(
+                            note.wav(),
+                            note.micro_starttime(),
+                            note.micro_duration(),
+                        );
+                        start.set_long_note_type(t);
+                        let mut end = Note::new_long(-2);
+                        end.set_end(true);
+                        timelines[index].set_note(key as i32, Some(start));
+                        timelines[end_index].set_note(key as i32, Some(end));
+                        ln_body[end_index][key] = true;
+                        inserted_ln = true;
+                    }
+                    _ => timelines[index].set_note(key as i32, Some(note)),
                 }
             }
         }
 
+        if inserted_ln {
+            model.resolve_long_note_pairs();
+        }
         self.base.assist = assist;
     }
 
@@ -257,4 +341,81 @@ mod tests {
         }
         assert_eq!(placed_count, 2, "depth=2 should place exactly 2 notes");
     }
+
+    // -- Long note awareness --
+
+    #[test]
+    fn extra_note_modifier_skips_lanes_inside_long_notes() {
+        let mode = Mode::BEAT_7K;
+        let mut tl0 = TimeLine::new(0.0, 0, 8);
+        tl0.set_note(0, Some(Note::new_long(1)));
+        let mut tl1 = TimeLine::new(0.5, 500_000, 8);
+        tl1.add_back_ground_note(Note::new_normal(9));
+        let mut tl2 = TimeLine::new(0.9, 900_000, 8);
+        let mut end = Note::new_long(-2);
+        end.set_end(true);
+        tl2.set_note(0, Some(end));
+
+        let mut model = make_test_model(&mode, vec![tl0, tl1, tl2]);
+        model.resolve_long_note_pairs();
+
+        let mut modifier = ExtraNoteModifier::new(0, 1, false);
+        modifier.modify(&mut model);
+
+        let tl = &model.timelines[1];
+        assert!(tl.note(0).is_none(), "lane 0 is inside an LN body");
+        assert_eq!(tl.note(1).map(|n| n.wav()), Some(9));
+    }
+
+    #[test]
+    fn extra_note_modifier_inserts_charge_note_with_end() {
+        let mode = Mode::BEAT_7K;
+        let mut tl0 = TimeLine::new(0.0, 0, 8);
+        tl0.add_back_ground_note(Note::new_normal(4));
+        let tl1 = TimeLine::new(0.5, 500_000, 8);
+        let mut model = make_test_model(&mode, vec![tl0, tl1]);
+
+        let mut modifier = ExtraNoteModifier::new(TYPE_CHARGENOTE, 1, false);
+        modifier.modify(&mut model);
+
+        let start = model.timelines[0].note(0).expect("inserted note");
+        assert!(start.is_long() && !start.is_end());
+        assert_eq!(start.long_note_type(), TYPE_CHARGENOTE);
+        assert_eq!(start.pair(), Some(1));
+        let end = model.timelines[1].note(0).expect("inserted CN end");
+        assert!(end.is_end());
+        assert_eq!(end.pair(), Some(0));
+    }
+
+    #[test]
+    fn extra_note_modifier_falls_back_to_normal_note_without_room_for_end() {
+        let mode = Mode::BEAT_7K;
+        let mut tl = TimeLine::new(0.0, 0, 8);
+        tl.add_back_ground_note(Note::new_normal(4));
+        let mut model = make_test_model(&mode, vec![tl]);
+
+        let mut modifier = ExtraNoteModifier::new(TYPE_CHARGENOTE, 1, false);
+        modifier.modify(&mut model);
+
+        assert!(model.timelines[0].note(0).is_some_and(|n| n.is_normal()));
+    }
+
+    #[test]
+    fn extra_note_modifier_scales_depth_in_dense_sections() {
+        let mode = Mode::BEAT_7K;
+        let mut tl = TimeLine::new(0.0, 0, 8);
+        for lane in 0..6 {
+            tl.set_note(lane, Some(Note::new_normal(1)));
+        }
+        for wav in 10..14 {
+            tl.add_back_ground_note(Note::new_normal(wav));
+        }
+        let mut model = make_test_model(&mode, vec![tl]);
+
+        // 6 of 8 lanes used -> depth 4 scaled to ceil(4 * 0.25) = 1
+        let mut modifier = ExtraNoteModifier::new(0, 4, true);
+        modifier.modify(&mut model);
+
+        assert_eq!(model.timelines[0].back_ground_notes().len(), 3);
+    }
 }
@@ -0,0 +1,139 @@
+use crate::core::pattern::java_random::JavaRandom;
+use crate::core::pattern::pattern_modifier::{AssistLevel, PatternModifier, PatternModifierBase};
+use bms::model::bms_model::BMSModel;
+use bms::model::mode::Mode;
+
+use super::{get_random_pattern_impl, lane_shuffle_modify};
+
+/// Number of playable keys per player on KEYBOARD-24K (two octaves, C to B).
+const KEYBOARD_KEYS: usize = 24;
+const OCTAVE: usize = 12;
+
+/// Semitone positions of the black keys within an octave starting at C.
+const BLACK_KEYS: [usize; 5] = [1, 3, 6, 8, 10];
+
+/// Lane shuffles that respect the piano layout of KEYBOARD-24K / 24K-DOUBLE.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyboardShuffle {
+    /// Mirror each octave around D, so white keys stay white and black keys stay black.
+    OctaveMirror,
+    /// Transpose by a random number of whole tones, wrapping within the two octaves.
+    WholeToneRotate,
+    /// Swap every black key with the white key directly below it (E and B stay).
+    WhiteBlackSwap,
+}
+
+// ---- KeyboardShuffleModifier ----
+
+pub struct KeyboardShuffleModifier {
+    pub base: PatternModifierBase,
+    pub shuffle: KeyboardShuffle,
+    pub show_shuffle_pattern: bool,
+    random: Vec<i32>,
+}
+
+impl KeyboardShuffleModifier {
+    pub fn new(player: i32, shuffle: KeyboardShuffle) -> Self {
+        let mut base = PatternModifierBase::with_player(player);
+        base.assist = AssistLevel::None;
+        KeyboardShuffleModifier {
+            base,
+            shuffle,
+            show_shuffle_pattern: false,
+            random: Vec::new(),
+        }
+    }
+
+    /// Build the lane map for the given keyboard shuffle. `keys` are the player's
+    /// non-scratch lanes in pitch order; anything other than 24 keys is left as identity.
+    pub fn make_random(
+        shuffle: KeyboardShuffle,
+        keys: &[i32],
+        model: &BMSModel,
+        seed: i64,
+    ) -> Vec<i32> {
+        let mode_key = model.mode().map(|m| m.key()).unwrap_or(0);
+        let mut result: Vec<i32> = (0..mode_key).collect();
+        if keys.len() != KEYBOARD_KEYS {
+            return result;
+        }
+        let shift = match shuffle {
+            KeyboardShuffle::WholeToneRotate => {
+                let mut rand = JavaRandom::new(seed);
+                (rand.next_int_bounded((KEYBOARD_KEYS / 2) as i32 - 1) + 1) as usize * 2
+            }
+            _ => 0,
+        };
+        for (i, &key) in keys.iter().enumerate() {
+            let pitch = i % OCTAVE;
+            let src = match shuffle {
+                KeyboardShuffle::OctaveMirror => i - pitch + (OCTAVE + 4 - pitch) % OCTAVE,
+                KeyboardShuffle::WholeToneRotate => (i + shift) % KEYBOARD_KEYS,
+                KeyboardShuffle::WhiteBlackSwap => {
+                    if BLACK_KEYS.contains(&pitch) {
+                        i - 1
+                    } else if BLACK_KEYS.contains(&(pitch + 1)) {
+                        i + 1
+                    } else {
+                        i
+                    }
+                }
+            };
+            result[key as usize] = keys[src];
+        }
+        result
+    }
+
+    pub fn is_to_display(&self) -> bool {
+        self.show_shuffle_pattern
+    }
+
+    pub fn random_pattern(&self, mode: &Mode) -> Vec<i32> {
+        get_random_pattern_impl(
+            &self.random,
+            self.show_shuffle_pattern,
+            false,
+            self.base.player,
+            mode,
+        )
+    }
+}
+
+impl PatternModifier for KeyboardShuffleModifier {
+    fn modify(&mut self, model: &mut BMSModel) {
+        let shuffle = self.shuffle;
+        self.random = lane_shuffle_modify(&mut self.base, model, false, false, |keys, m, seed| {
+            Self::make_random(shuffle, keys, m, seed)
+        });
+    }
+
+    fn assist_level(&self) -> AssistLevel {
+        self.base.assist
+    }
+
+    fn set_assist_level(&mut self, assist: AssistLevel) {
+        self.base.assist = assist;
+    }
+
+    fn get_seed(&self) -> i64 {
+        self.base.seed
+    }
+
+    fn set_seed(&mut self, seed: i64) {
+        if seed >= 0 {
+            self.base.seed = seed;
+        }
+    }
+
+    fn player(&self) -> i32 {
+        self.base.player
+    }
+
+    fn is_lane_shuffle_to_display(&self) -> bool {
+        self.show_shuffle_pattern
+    }
+
+    fn get_lane_shuffle_random_pattern(&self, mode: &Mode) -> Option<Vec<i32>> {
+        Some(self.random_pattern(mode))
+    }
+}
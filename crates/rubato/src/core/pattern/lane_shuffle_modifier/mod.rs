@@ -342,6 +342,8 @@ pub struct LaneRandomShuffleModifier {
     pub is_scratch_lane_modify: bool,
     pub show_shuffle_pattern: bool,
     random: Vec<i32>,
+    /// Fixed permutation (0-based source lane per key) used instead of the seed
+    lane_order: Option<Vec<i32>>,
 }
 
 impl LaneRandomShuffleModifier {
@@ -357,9 +359,38 @@ impl LaneRandomShuffleModifier {
             is_scratch_lane_modify,
             show_shuffle_pattern: true,
             random: Vec::new(),
+            lane_order: None,
         }
     }
 
+    /// RANDOM with a fixed lane order in random trainer notation ("3142576":
+    /// key 1 takes the notes of lane 3, and so on).
+    ///
+    /// Returns None when the order is not a permutation of 1..=n.
+    pub fn with_lane_order(player: i32, lane_order: &str) -> Option<Self> {
+        let order: Vec<i32> = lane_order
+            .chars()
+            .map(|c| c.to_digit(10).map(|d| d as i32 - 1))
+            .collect::<Option<_>>()?;
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        if !sorted.iter().copied().eq(0..order.len() as i32) {
+            return None;
+        }
+        let mut modifier = Self::new(player, false);
+        modifier.lane_order = Some(order);
+        Some(modifier)
+    }
+
+    fn fixed_random(keys: &[i32], model: &BMSModel, order: &[i32]) -> Vec<i32> {
+        let mode_key = model.mode().map(|m| m.key()).unwrap_or(0);
+        let mut result: Vec<i32> = (0..mode_key).collect();
+        for (&key, &src) in keys.iter().zip(order) {
+            result[key as usize] = keys[src as usize];
+        }
+        result
+    }
+
     pub fn make_random(keys: &[i32], model: &BMSModel, seed: i64) -> Vec<i32> {
         let mut rand = JavaRandom::new(seed);
         let mut l: Vec<i32> = keys.to_vec();
@@ -390,12 +421,16 @@ impl LaneRandomShuffleModifier {
 
 impl PatternModifier for LaneRandomShuffleModifier {
     fn modify(&mut self, model: &mut BMSModel) {
+        let lane_order = self.lane_order.clone();
         self.random = lane_shuffle_modify(
             &mut self.base,
             model,
             self.is_scratch_lane_modify,
             true,
-            Self::make_random,
+            |keys, model, seed| match lane_order {
+                Some(order) if order.len() == keys.len() => Self::fixed_random(keys, model, &order),
+                _ => Self::make_random(keys, model, seed),
+            },
         );
     }
 
@@ -432,6 +467,8 @@ impl PatternModifier for LaneRandomShuffleModifier {
 
 mod advanced;
 pub use advanced::*;
+mod keyboard;
+pub use keyboard::*;
 
 #[cfg(test)]
 mod tests {
@@ -607,6 +644,31 @@ mod tests {
         assert_eq!(result[7], 7);
     }
 
+    #[test]
+    fn random_modifier_with_lane_order_applies_fixed_permutation() {
+        let mode = Mode::BEAT_7K;
+        let mut tl = TimeLine::new(0.0, 0, 8);
+        tl.set_note(2, Some(Note::new_normal(1)));
+        tl.set_note(7, Some(Note::new_normal(2)));
+        let mut model = make_test_model(&mode, vec![tl]);
+
+        let mut modifier = LaneRandomShuffleModifier::with_lane_order(0, "3124567").unwrap();
+        modifier.modify(&mut model);
+
+        assert!(model.timelines[0].note(0).is_some());
+        assert!(model.timelines[0].note(2).is_none());
+        // Scratch is untouched
+        assert!(model.timelines[0].note(7).is_some());
+        assert_eq!(modifier.random_pattern(&mode)[..7], [2, 0, 1, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn random_modifier_with_lane_order_rejects_non_permutation() {
+        assert!(LaneRandomShuffleModifier::with_lane_order(0, "1123456").is_none());
+        assert!(LaneRandomShuffleModifier::with_lane_order(0, "12a4567").is_none());
+        assert!(LaneRandomShuffleModifier::with_lane_order(0, "2345678").is_none());
+    }
+
     // -- LaneRotateShuffleModifier --
 
     #[test]
@@ -790,6 +852,89 @@ mod tests {
         // All values out of range -> all fall back to identity.
         assert_eq!(model.timelines[0].note(0).unwrap().wav(), 99);
     }
+
+    // -- KeyboardShuffleModifier --
+
+    fn keyboard_map(shuffle: KeyboardShuffle, mode: Mode, player: i32, seed: i64) -> Vec<i32> {
+        let model = make_test_model(&mode, vec![TimeLine::new(0.0, 0, mode.key())]);
+        let keys = PatternModifierBase::keys_static(&mode, player, false);
+        KeyboardShuffleModifier::make_random(shuffle, &keys, &model, seed)
+    }
+
+    fn is_black(lane: i32) -> bool {
+        [1, 3, 6, 8, 10].contains(&(lane % 12))
+    }
+
+    #[test]
+    fn keyboard_octave_mirror_preserves_key_colors() {
+        let result = keyboard_map(KeyboardShuffle::OctaveMirror, Mode::KEYBOARD_24K, 0, 0);
+        // C <-> E, F <-> B within each octave, D and G# fixed
+        assert_eq!(&result[..12], &[4, 3, 2, 1, 0, 11, 10, 9, 8, 7, 6, 5]);
+        assert_eq!(result[12], 16);
+        for lane in 0..24 {
+            assert_eq!(is_black(lane), is_black(result[lane as usize]));
+        }
+        // Scratch lanes untouched
+        assert_eq!(&result[24..], &[24, 25]);
+    }
+
+    #[test]
+    fn keyboard_whole_tone_rotate_shifts_by_even_interval() {
+        for seed in 0..20 {
+            let result = keyboard_map(
+                KeyboardShuffle::WholeToneRotate,
+                Mode::KEYBOARD_24K,
+                0,
+                seed,
+            );
+            let shift = (result[0] + 24) % 24;
+            assert!(shift > 0 && shift % 2 == 0, "seed {seed}: shift {shift}");
+            for lane in 0..24 {
+                assert_eq!(result[lane as usize], (lane + shift) % 24);
+            }
+            assert_eq!(&result[24..], &[24, 25]);
+        }
+    }
+
+    #[test]
+    fn keyboard_white_black_swap_is_an_involution() {
+        let result = keyboard_map(KeyboardShuffle::WhiteBlackSwap, Mode::KEYBOARD_24K, 0, 0);
+        assert_eq!(&result[..12], &[1, 0, 3, 2, 4, 6, 5, 8, 7, 10, 9, 11]);
+        for lane in 0..26 {
+            assert_eq!(result[result[lane] as usize], lane as i32);
+        }
+    }
+
+    #[test]
+    fn keyboard_shuffle_second_player_uses_own_lanes() {
+        let result = keyboard_map(
+            KeyboardShuffle::OctaveMirror,
+            Mode::KEYBOARD_24K_DOUBLE,
+            1,
+            0,
+        );
+        assert_eq!(result.len(), 52);
+        assert_eq!((0..26).collect::<Vec<i32>>(), result[..26].to_vec());
+        assert_eq!(result[26], 30);
+        assert_eq!(&result[50..], &[50, 51]);
+    }
+
+    #[test]
+    fn keyboard_shuffle_modifier_moves_notes() {
+        let mode = Mode::KEYBOARD_24K;
+        let mut tl = TimeLine::new(0.0, 0, mode.key());
+        tl.set_note(0, Some(Note::new_normal(10)));
+        tl.set_note(24, Some(Note::new_normal(20)));
+        let mut model = make_test_model(&mode, vec![tl]);
+
+        let mut modifier = KeyboardShuffleModifier::new(0, KeyboardShuffle::WhiteBlackSwap);
+        modifier.modify(&mut model);
+
+        assert_eq!(model.timelines[0].note(1).unwrap().wav(), 10);
+        assert!(model.timelines[0].note(0).is_none());
+        assert_eq!(model.timelines[0].note(24).unwrap().wav(), 20);
+        assert_eq!(modifier.assist_level(), AssistLevel::None);
+    }
 }
 
 #[cfg(test)]
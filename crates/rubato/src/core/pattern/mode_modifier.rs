@@ -37,10 +37,103 @@ impl ModeModifier {
     }
 }
 
+impl ModeModifier {
+    /// Re-lays notes for conversions driven by a static [`LaneFold`] table.
+    ///
+    /// Long notes keep their destination lane until their end. Notes that find no
+    /// free destination are moved to the background so their keysounds still play;
+    /// dropping notes this way raises the assist level to `Assist`.
+    fn fold_lanes(&mut self, model: &mut BMSModel, fold: &LaneFold) {
+        let before_lanes = self.before_mode.key() as usize;
+        let lanes = self.after_mode.key() as usize;
+        // Source lane feeding each destination while a long note is held there
+        let mut pinned: Vec<Option<usize>> = vec![None; lanes];
+        // Source lanes whose long note start was moved to the background
+        let mut dropped_ln = vec![false; before_lanes];
+        let mut dropped = false;
+
+        for tl in model.timelines.iter_mut() {
+            let mut notes: Vec<Option<Note>> =
+                (0..before_lanes).map(|i| tl.take_note(i as i32)).collect();
+            let mut hnotes: Vec<Option<Note>> = (0..before_lanes)
+                .map(|i| tl.take_hidden_note(i as i32))
+                .collect();
+            tl.set_lane_count(lanes as i32);
+
+            for (src, note) in notes.iter_mut().enumerate() {
+                if dropped_ln[src] && note.as_ref().is_some_and(|n| n.is_long() && n.is_end()) {
+                    *note = None;
+                    dropped_ln[src] = false;
+                }
+            }
+
+            let mut source = pinned.clone();
+            for (dst, &src) in fold.primary.iter().enumerate() {
+                if source[dst].is_none() && src >= 0 && notes[src as usize].is_some() {
+                    source[dst] = Some(src as usize);
+                }
+            }
+            for &(src, dsts) in fold.fallback {
+                let src = src as usize;
+                if notes[src].is_none() || source.contains(&Some(src)) {
+                    continue;
+                }
+                if let Some(&dst) = dsts.iter().find(|&&d| source[d as usize].is_none()) {
+                    source[dst as usize] = Some(src);
+                }
+            }
+
+            for dst in 0..lanes {
+                match source[dst] {
+                    Some(src) => {
+                        let note = notes[src].take();
+                        if let Some(n) = note.as_ref().filter(|n| n.is_long()) {
+                            pinned[dst] = if n.is_end() { None } else { Some(src) };
+                        }
+                        tl.set_note(dst as i32, note);
+                        tl.set_hidden_note(dst as i32, hnotes[src].take());
+                    }
+                    None => {
+                        if let Some(&src) = fold.primary.get(dst).filter(|&&s| s >= 0) {
+                            tl.set_hidden_note(dst as i32, hnotes[src as usize].take());
+                        }
+                    }
+                }
+            }
+
+            for (src, note) in notes.into_iter().enumerate() {
+                let Some(note) = note else { continue };
+                if note.is_mine() || (note.is_long() && note.is_end()) {
+                    continue;
+                }
+                if note.is_long() {
+                    dropped_ln[src] = true;
+                }
+                tl.add_back_ground_note(Note::new_normal_with_start_duration(
+                    note.wav(),
+                    note.micro_starttime(),
+                    note.micro_duration(),
+                ));
+                dropped = true;
+            }
+        }
+
+        model.set_mode(self.after_mode);
+        model.resolve_long_note_pairs();
+        if dropped {
+            self.base.assist = AssistLevel::Assist;
+        }
+    }
+}
+
 impl PatternModifier for ModeModifier {
     fn modify(&mut self, model: &mut BMSModel) {
-        model.set_mode(self.after_mode);
         let algorithm = Algorithm::get(&self.before_mode, &self.after_mode);
+        if let Some(fold) = algorithm.and_then(|a| a.lane_fold()) {
+            self.fold_lanes(model, fold);
+            return;
+        }
+        model.set_mode(self.after_mode);
         let lanes = self.after_mode.key() as usize;
         let mut ln = vec![-1i32; lanes];
         let mut last_note_time = vec![-100i64; lanes];
@@ -161,17 +254,69 @@ impl PatternModifier for ModeModifier {
 
 // keys_static is defined in lane_shuffle_modifier.rs
 
-#[derive(Clone, Copy)]
+/// Static lane mapping for conversions that reduce or pad the key count.
+struct LaneFold {
+    /// Preferred source lane for each destination lane (`-1`: none).
+    primary: &'static [i32],
+    /// Source lanes without a primary destination, with the destinations they may
+    /// take (in order) when those are free in the current timeline.
+    fallback: &'static [(i32, &'static [i32])],
+}
+
+/// 9K -> 7K: buttons 2-8 become keys 1-7 and button 1 the scratch. Button 9 takes
+/// key 7 when it is free, otherwise the scratch.
+const NINE_TO_SEVEN: LaneFold = LaneFold {
+    primary: &[1, 2, 3, 4, 5, 6, 7, 0],
+    fallback: &[(8, &[6, 7])],
+};
+
+/// 14K -> 7K: the 1P side is kept as is and every 2P lane (scratch included) merges
+/// onto the matching 1P lane when it is free.
+const FOURTEEN_TO_SEVEN: LaneFold = LaneFold {
+    primary: &[0, 1, 2, 3, 4, 5, 6, 7],
+    fallback: &[
+        (8, &[0]),
+        (9, &[1]),
+        (10, &[2]),
+        (11, &[3]),
+        (12, &[4]),
+        (13, &[5]),
+        (14, &[6]),
+        (15, &[7]),
+    ],
+};
+
+/// 5K -> 7K: keys 1-5 and the scratch keep their positions; keys 6 and 7 stay empty.
+const FIVE_TO_SEVEN: LaneFold = LaneFold {
+    primary: &[0, 1, 2, 3, 4, -1, -1, 5],
+    fallback: &[],
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Algorithm {
     SevenToNine,
+    NineToSeven,
+    FourteenToSeven,
+    FiveToSeven,
 }
 
 impl Algorithm {
     fn get(before_mode: &Mode, after_mode: &Mode) -> Option<Algorithm> {
-        if *before_mode == Mode::BEAT_7K && *after_mode == Mode::POPN_9K {
-            Some(Algorithm::SevenToNine)
-        } else {
-            None
+        match (*before_mode, *after_mode) {
+            (Mode::BEAT_7K, Mode::POPN_9K) => Some(Algorithm::SevenToNine),
+            (Mode::POPN_9K, Mode::BEAT_7K) => Some(Algorithm::NineToSeven),
+            (Mode::BEAT_14K, Mode::BEAT_7K) => Some(Algorithm::FourteenToSeven),
+            (Mode::BEAT_5K, Mode::BEAT_7K) => Some(Algorithm::FiveToSeven),
+            _ => None,
+        }
+    }
+
+    fn lane_fold(&self) -> Option<&'static LaneFold> {
+        match self {
+            Algorithm::SevenToNine => None,
+            Algorithm::NineToSeven => Some(&NINE_TO_SEVEN),
+            Algorithm::FourteenToSeven => Some(&FOURTEEN_TO_SEVEN),
+            Algorithm::FiveToSeven => Some(&FIVE_TO_SEVEN),
         }
     }
 
@@ -250,6 +395,10 @@ impl Algorithm {
 
                 result
             }
+            // Table-driven conversions are laid out by ModeModifier::fold_lanes
+            Algorithm::NineToSeven | Algorithm::FourteenToSeven | Algorithm::FiveToSeven => {
+                params._keys.to_vec()
+            }
         }
     }
 }
@@ -277,8 +426,19 @@ mod tests {
     }
 
     #[test]
-    fn algorithm_get_9k_to_7k_returns_none() {
-        assert!(Algorithm::get(&Mode::POPN_9K, &Mode::BEAT_7K).is_none());
+    fn algorithm_get_conversions_to_7k() {
+        assert_eq!(
+            Algorithm::get(&Mode::POPN_9K, &Mode::BEAT_7K),
+            Some(Algorithm::NineToSeven)
+        );
+        assert_eq!(
+            Algorithm::get(&Mode::BEAT_14K, &Mode::BEAT_7K),
+            Some(Algorithm::FourteenToSeven)
+        );
+        assert_eq!(
+            Algorithm::get(&Mode::BEAT_5K, &Mode::BEAT_7K),
+            Some(Algorithm::FiveToSeven)
+        );
     }
 
     #[test]
@@ -288,6 +448,107 @@ mod tests {
         assert!(Algorithm::get(&Mode::BEAT_7K, &Mode::BEAT_14K).is_none());
     }
 
+    #[test]
+    fn lane_fold_tables_cover_destination_lanes() {
+        for alg in [
+            Algorithm::NineToSeven,
+            Algorithm::FourteenToSeven,
+            Algorithm::FiveToSeven,
+        ] {
+            let fold = alg.lane_fold().unwrap();
+            assert_eq!(fold.primary.len(), Mode::BEAT_7K.key() as usize, "{alg:?}");
+        }
+        assert!(Algorithm::SevenToNine.lane_fold().is_none());
+    }
+
+    // -- Table-driven conversions --
+
+    fn wavs(tl: &TimeLine) -> Vec<i32> {
+        (0..tl.lane_count())
+            .map(|lane| tl.note(lane).map(|n| n.wav()).unwrap_or(0))
+            .collect()
+    }
+
+    #[test]
+    fn modify_9k_to_7k_folds_outer_buttons() {
+        let mut tl0 = TimeLine::new(0.0, 0, 9);
+        for lane in 0..9 {
+            tl0.set_note(lane, Some(Note::new_normal(lane + 1)));
+        }
+        let mut tl1 = TimeLine::new(1.0, 1_000_000, 9);
+        tl1.set_note(8, Some(Note::new_normal(9)));
+        let mut model = make_test_model(&Mode::POPN_9K, vec![tl0, tl1]);
+
+        let mut modifier = ModeModifier::new(Mode::POPN_9K, Mode::BEAT_7K, PlayerConfig::default());
+        modifier.modify(&mut model);
+
+        assert_eq!(model.mode(), Some(&Mode::BEAT_7K));
+        // All lanes taken: button 9 goes to the background
+        assert_eq!(wavs(&model.timelines[0]), vec![2, 3, 4, 5, 6, 7, 8, 1]);
+        assert_eq!(model.timelines[0].back_ground_notes()[0].wav(), 9);
+        // Button 9 alone lands on key 7
+        assert_eq!(wavs(&model.timelines[1]), vec![0, 0, 0, 0, 0, 0, 9, 0]);
+        assert_eq!(modifier.assist_level(), AssistLevel::Assist);
+    }
+
+    #[test]
+    fn modify_14k_to_7k_merges_sides() {
+        let mut tl = TimeLine::new(0.0, 0, 16);
+        tl.set_note(0, Some(Note::new_normal(1)));
+        tl.set_note(9, Some(Note::new_normal(10)));
+        tl.set_note(15, Some(Note::new_normal(16)));
+        let mut model = make_test_model(&Mode::BEAT_14K, vec![tl]);
+
+        let mut modifier =
+            ModeModifier::new(Mode::BEAT_14K, Mode::BEAT_7K, PlayerConfig::default());
+        modifier.modify(&mut model);
+
+        assert_eq!(wavs(&model.timelines[0]), vec![1, 10, 0, 0, 0, 0, 0, 16]);
+        assert!(model.timelines[0].back_ground_notes().is_empty());
+        assert_eq!(modifier.assist_level(), AssistLevel::LightAssist);
+    }
+
+    #[test]
+    fn modify_14k_to_7k_keeps_long_note_lane_until_end() {
+        let mut tl0 = TimeLine::new(0.0, 0, 16);
+        tl0.set_note(8, Some(Note::new_long(1)));
+        let mut tl1 = TimeLine::new(1.0, 1_000_000, 16);
+        tl1.set_note(0, Some(Note::new_normal(2)));
+        let mut tl2 = TimeLine::new(2.0, 2_000_000, 16);
+        let mut end = Note::new_long(-2);
+        end.set_end(true);
+        tl2.set_note(8, Some(end));
+        let mut model = make_test_model(&Mode::BEAT_14K, vec![tl0, tl1, tl2]);
+        model.resolve_long_note_pairs();
+
+        let mut modifier =
+            ModeModifier::new(Mode::BEAT_14K, Mode::BEAT_7K, PlayerConfig::default());
+        modifier.modify(&mut model);
+
+        let tls = &model.timelines;
+        assert!(tls[0].note(0).is_some_and(|n| n.is_long()));
+        // Lane 0 is held by the 2P long note, so the 1P note is moved to the background
+        assert!(tls[1].note(0).is_none());
+        assert_eq!(tls[1].back_ground_notes()[0].wav(), 2);
+        assert!(tls[2].note(0).is_some_and(|n| n.is_end()));
+        assert_eq!(tls[0].note(0).unwrap().pair(), Some(2));
+    }
+
+    #[test]
+    fn modify_5k_to_7k_pads_keys() {
+        let mut tl = TimeLine::new(0.0, 0, 6);
+        for lane in 0..6 {
+            tl.set_note(lane, Some(Note::new_normal(lane + 1)));
+        }
+        let mut model = make_test_model(&Mode::BEAT_5K, vec![tl]);
+
+        let mut modifier = ModeModifier::new(Mode::BEAT_5K, Mode::BEAT_7K, PlayerConfig::default());
+        modifier.modify(&mut model);
+
+        assert_eq!(wavs(&model.timelines[0]), vec![1, 2, 3, 4, 5, 0, 0, 6]);
+        assert_eq!(modifier.assist_level(), AssistLevel::LightAssist);
+    }
+
     // -- Algorithm::SevenToNine.modify with various patterns --
 
     #[test]
@@ -3,6 +3,7 @@ use bms::model::bms_model::BMSModel;
 use bms::model::mode::Mode;
 
 use crate::core::pattern::pattern_modifier::{AssistLevel, PatternModifier, PatternModifierBase};
+use crate::core::pattern::pattern_modify_log::PatternModifyLog;
 use crate::core::pattern::random::Random;
 use crate::core::pattern::randomizer::Randomizer;
 
@@ -10,15 +11,20 @@ pub struct NoteShuffleModifier {
     pub base: PatternModifierBase,
     randomizer: Randomizer,
     is_scratch_lane_modify: bool,
+    log: Vec<PatternModifyLog>,
 }
 
 impl NoteShuffleModifier {
     pub fn new(r: Random, player: i32, mode: &Mode, config: &PlayerConfig) -> Self {
         let randomizer = Randomizer::create_with_side(r, player, mode, config);
         NoteShuffleModifier {
-            base: PatternModifierBase::with_player(player),
+            base: PatternModifierBase {
+                assist: randomizer.assist_level(),
+                ..PatternModifierBase::with_player(player)
+            },
             randomizer,
             is_scratch_lane_modify: r.is_scratch_lane_modify(),
+            log: Vec::new(),
         }
     }
 }
@@ -31,10 +37,13 @@ impl PatternModifier for NoteShuffleModifier {
             None => return,
         };
         self.randomizer.set_modify_lanes(&keys);
+        self.log.clear();
         let timelines = &mut model.timelines;
         for tl in timelines.iter_mut() {
             if tl.exist_note() || tl.exist_hidden_note() {
-                self.randomizer.permutate(tl);
+                let permutation = self.randomizer.permutate(tl);
+                self.log
+                    .push(PatternModifyLog::new(tl.section(), permutation));
             }
         }
         self.base.assist = self.randomizer.assist_level();
@@ -61,6 +70,10 @@ impl PatternModifier for NoteShuffleModifier {
     fn player(&self) -> i32 {
         self.base.player
     }
+
+    fn pattern_modify_log(&self) -> &[PatternModifyLog] {
+        &self.log
+    }
 }
 
 #[cfg(test)]
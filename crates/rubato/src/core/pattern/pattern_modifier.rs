@@ -39,6 +39,12 @@ pub trait PatternModifier {
         None
     }
 
+    /// Per-timeslice lane permutations applied by the last `modify()` call.
+    /// Note-unit shuffles override this so replays can reproduce the exact arrangement.
+    fn pattern_modify_log(&self) -> &[PatternModifyLog] {
+        &[]
+    }
+
     fn keys(&self, mode: &Mode, player: i32, contains_scratch: bool) -> Vec<i32> {
         if player >= mode.player() {
             return Vec::new();
@@ -138,6 +144,60 @@ impl PatternModifier for IdentityModifier {
     }
 }
 
+/// Re-applies a recorded pattern modify log instead of re-rolling a note shuffle.
+///
+/// Used when playing back a replay that carries `ReplayData::pattern`, so the
+/// arrangement matches the original play even if the randomizer or its settings
+/// have changed since.
+pub struct PatternLogModifier {
+    pub base: PatternModifierBase,
+    log: Vec<PatternModifyLog>,
+}
+
+impl PatternLogModifier {
+    pub fn new(player: i32, log: Vec<PatternModifyLog>, assist: AssistLevel) -> Self {
+        PatternLogModifier {
+            base: PatternModifierBase {
+                assist,
+                ..PatternModifierBase::with_player(player)
+            },
+            log,
+        }
+    }
+}
+
+impl PatternModifier for PatternLogModifier {
+    fn modify(&mut self, model: &mut BMSModel) {
+        apply_modify_log(model, &self.log);
+    }
+
+    fn assist_level(&self) -> AssistLevel {
+        self.base.assist
+    }
+
+    fn set_assist_level(&mut self, assist: AssistLevel) {
+        self.base.assist = assist;
+    }
+
+    fn get_seed(&self) -> i64 {
+        self.base.seed
+    }
+
+    fn set_seed(&mut self, seed: i64) {
+        if seed >= 0 {
+            self.base.seed = seed;
+        }
+    }
+
+    fn player(&self) -> i32 {
+        self.base.player
+    }
+
+    fn pattern_modify_log(&self) -> &[PatternModifyLog] {
+        &self.log
+    }
+}
+
 /// Apply pattern modify log to a model
 pub fn apply_modify_log(model: &mut BMSModel, log: &[PatternModifyLog]) {
     let mode_key = model.mode().map(|m| m.key()).unwrap_or(0);
@@ -181,7 +241,10 @@ pub fn create_pattern_modifier(
     mode: &Mode,
     config: &PlayerConfig,
 ) -> Box<dyn PatternModifier> {
-    let chart_option = Random::from_id(id, mode);
+    let chart_option = match Random::from_id(id, mode) {
+        Random::SRandom if config.play_settings.sran_plus => Random::SRandomPlus,
+        r => r,
+    };
     match chart_option {
         Random::Identity => Box::new(IdentityModifier::new()),
         Random::Mirror => Box::new(LaneMirrorShuffleModifier::new(player, false)),
@@ -194,6 +257,18 @@ pub fn create_pattern_modifier(
         Random::RandomPlayable => Box::new(LanePlayableRandomShuffleModifier::new(player, false)),
         Random::Flip => Box::new(PlayerFlipModifier::new()),
         Random::Battle => Box::new(PlayerBattleModifier::new()),
+        Random::KeyboardOctaveMirror => Box::new(KeyboardShuffleModifier::new(
+            player,
+            KeyboardShuffle::OctaveMirror,
+        )),
+        Random::KeyboardWholeToneRotate => Box::new(KeyboardShuffleModifier::new(
+            player,
+            KeyboardShuffle::WholeToneRotate,
+        )),
+        Random::KeyboardWhiteBlackSwap => Box::new(KeyboardShuffleModifier::new(
+            player,
+            KeyboardShuffle::WhiteBlackSwap,
+        )),
         _ => match chart_option.unit() {
             RandomUnit::Note => {
                 Box::new(NoteShuffleModifier::new(chart_option, player, mode, config))
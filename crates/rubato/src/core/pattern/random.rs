@@ -29,6 +29,16 @@ pub enum Random {
     SRandomPlayable,
     Flip,
     Battle,
+    /// S-RANDOM that avoids re-using the lanes of the immediately preceding
+    /// timeslice. Not part of the selectable option lists; `create_pattern_modifier`
+    /// substitutes it for `SRandom` when `PlaySettings::sran_plus` is enabled.
+    SRandomPlus,
+    /// Per-octave mirror that keeps white and black keys in place (KEYBOARD modes only).
+    KeyboardOctaveMirror,
+    /// Whole-tone transposition within the two octaves (KEYBOARD modes only).
+    KeyboardWholeToneRotate,
+    /// Black keys swapped with the white key below them (KEYBOARD modes only).
+    KeyboardWhiteBlackSwap,
 }
 
 impl Random {
@@ -53,6 +63,10 @@ impl Random {
             Random::SRandomPlayable => RandomUnit::Note,
             Random::Flip => RandomUnit::Player,
             Random::Battle => RandomUnit::Player,
+            Random::SRandomPlus => RandomUnit::Note,
+            Random::KeyboardOctaveMirror => RandomUnit::Lane,
+            Random::KeyboardWholeToneRotate => RandomUnit::Lane,
+            Random::KeyboardWhiteBlackSwap => RandomUnit::Lane,
         }
     }
 
@@ -77,6 +91,10 @@ impl Random {
             Random::SRandomPlayable => true,
             Random::Flip => true,
             Random::Battle => true,
+            Random::SRandomPlus => false,
+            Random::KeyboardOctaveMirror => false,
+            Random::KeyboardWholeToneRotate => false,
+            Random::KeyboardWhiteBlackSwap => false,
         }
     }
 
@@ -110,6 +128,23 @@ impl Random {
         ]
     }
 
+    /// KEYBOARD-24K options. MIRROR / RANDOM / ROTATE break the piano layout, so
+    /// their slots are taken by the keyboard-aware lane shuffles.
+    pub fn option_keyboard() -> &'static [Random] {
+        &[
+            Random::Identity,
+            Random::KeyboardOctaveMirror,
+            Random::KeyboardWhiteBlackSwap,
+            Random::KeyboardWholeToneRotate,
+            Random::SRandom,
+            Random::Spiral,
+            Random::HRandom,
+            Random::AllScr,
+            Random::RandomEx,
+            Random::SRandomEx,
+        ]
+    }
+
     pub fn option_double() -> &'static [Random] {
         &[Random::Identity, Random::Flip]
     }
@@ -121,6 +156,7 @@ impl Random {
     pub fn from_id(id: i32, mode: &Mode) -> Random {
         let randoms = match mode {
             Mode::POPN_5K | Mode::POPN_9K => Random::option_pms(),
+            Mode::KEYBOARD_24K | Mode::KEYBOARD_24K_DOUBLE => Random::option_keyboard(),
             _ => Random::option_general(),
         };
         if id >= 0 && (id as usize) < randoms.len() {
@@ -169,6 +205,9 @@ mod tests {
             Random::RotateEx,
             Random::Cross,
             Random::RandomPlayable,
+            Random::KeyboardOctaveMirror,
+            Random::KeyboardWholeToneRotate,
+            Random::KeyboardWhiteBlackSwap,
         ];
         for r in lane_randoms {
             assert_eq!(r.unit(), RandomUnit::Lane, "{:?} should have Lane unit", r);
@@ -186,6 +225,7 @@ mod tests {
             Random::Converge,
             Random::SRandomNoThreshold,
             Random::SRandomPlayable,
+            Random::SRandomPlus,
         ];
         for r in note_randoms {
             assert_eq!(r.unit(), RandomUnit::Note, "{:?} should have Note unit", r);
@@ -212,6 +252,10 @@ mod tests {
             Random::HRandom,
             Random::Cross,
             Random::SRandomNoThreshold,
+            Random::SRandomPlus,
+            Random::KeyboardOctaveMirror,
+            Random::KeyboardWholeToneRotate,
+            Random::KeyboardWhiteBlackSwap,
         ];
         for r in non_scratch {
             assert!(
@@ -314,6 +358,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_random_uses_keyboard_options_for_24k() {
+        assert_eq!(
+            Random::from_id(1, &Mode::KEYBOARD_24K),
+            Random::KeyboardOctaveMirror
+        );
+        assert_eq!(
+            Random::from_id(3, &Mode::KEYBOARD_24K_DOUBLE),
+            Random::KeyboardWholeToneRotate
+        );
+        assert_eq!(Random::option_keyboard().len(), 10);
+    }
+
     #[test]
     fn get_random_all_general_ids_valid() {
         for i in 0..10 {
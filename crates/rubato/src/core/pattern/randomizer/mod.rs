@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
 use crate::core::pattern::java_random::JavaRandom;
 use crate::core::pattern::pattern_modifier::AssistLevel;
@@ -246,6 +246,58 @@ impl TimeBasedRandomizerState {
     }
 }
 
+// ---- DensityThreshold ----
+
+/// Width of the trailing window used to measure note density, in milliseconds.
+pub const DENSITY_WINDOW: i64 = 1000;
+
+/// H-RANDOM threshold derived per timeline from the local BPM and recent note density.
+///
+/// The threshold is one 16th note at the timeline's BPM, capped by the configured
+/// threshold and by the average per-lane note interval over the last
+/// [`DENSITY_WINDOW`], so dense passages are not forced entirely into inferior lanes.
+pub struct DensityThreshold {
+    pub max_threshold: i64,
+    recent_notes: VecDeque<i64>,
+}
+
+impl DensityThreshold {
+    pub fn new(max_threshold: i64) -> Self {
+        DensityThreshold {
+            max_threshold,
+            recent_notes: VecDeque::new(),
+        }
+    }
+
+    pub fn threshold_at(&mut self, tl: &TimeLine, lanes: usize) -> i64 {
+        let time = tl.milli_time();
+        while self
+            .recent_notes
+            .front()
+            .is_some_and(|&t| time - t >= DENSITY_WINDOW)
+        {
+            self.recent_notes.pop_front();
+        }
+        let mut threshold = self.max_threshold;
+        if tl.bpm > 0.0 {
+            threshold = threshold.min((15000.0 / tl.bpm).ceil() as i64);
+        }
+        if !self.recent_notes.is_empty() && lanes > 0 {
+            threshold =
+                threshold.min(DENSITY_WINDOW * lanes as i64 / self.recent_notes.len() as i64);
+        }
+        threshold.max(0)
+    }
+
+    pub fn record(&mut self, tl: &TimeLine, lanes: &[i32]) {
+        for &lane in lanes {
+            if tl.note(lane).is_some_and(|n| !n.is_mine()) {
+                self.recent_notes.push_back(tl.milli_time());
+            }
+        }
+    }
+}
+
 // ---- Randomizer enum ----
 
 pub enum Randomizer {
@@ -254,6 +306,7 @@ pub enum Randomizer {
     AllScratch(AllScratchRandomizer),
     NoMurioshi(NoMurioshiRandomizer),
     Converge(ConvergeRandomizer),
+    SRandomPlus(SRandomPlusRandomizer),
 }
 
 impl Randomizer {
@@ -282,9 +335,10 @@ impl Randomizer {
                 threshold_millis,
                 threshold_millis * 2,
             )),
-            Random::HRandom => {
-                Randomizer::SRandom(SRandomizer::new(threshold_millis, AssistLevel::LightAssist))
-            }
+            Random::HRandom => Randomizer::SRandom(SRandomizer::new_density_aware(
+                threshold_millis,
+                AssistLevel::LightAssist,
+            )),
             Random::Spiral => Randomizer::Spiral(SpiralRandomizer::new()),
             Random::SRandom => {
                 Randomizer::SRandom(SRandomizer::new(SRAN_THRESHOLD, AssistLevel::None))
@@ -298,6 +352,9 @@ impl Randomizer {
             Random::SRandomPlayable => {
                 Randomizer::NoMurioshi(NoMurioshiRandomizer::new(threshold_millis))
             }
+            Random::SRandomPlus => Randomizer::SRandomPlus(SRandomPlusRandomizer::new(
+                (config.play_settings.sran_plus_min_gap as i64).max(SRAN_THRESHOLD),
+            )),
             other => {
                 log::warn!(
                     "Unhandled Random variant {:?} for Randomizer, using SRandom as fallback",
@@ -318,6 +375,7 @@ impl Randomizer {
             Randomizer::AllScratch(r) => &r.base,
             Randomizer::NoMurioshi(r) => &r.base,
             Randomizer::Converge(r) => &r.base,
+            Randomizer::SRandomPlus(r) => &r.base,
         }
     }
 
@@ -328,6 +386,7 @@ impl Randomizer {
             Randomizer::AllScratch(r) => &mut r.base,
             Randomizer::NoMurioshi(r) => &mut r.base,
             Randomizer::Converge(r) => &mut r.base,
+            Randomizer::SRandomPlus(r) => &mut r.base,
         }
     }
 
@@ -389,6 +448,11 @@ impl Randomizer {
                     r.renda_count.insert(lane, 0);
                 }
             }
+            Randomizer::SRandomPlus(r) => {
+                r.base.set_modify_lanes(lanes);
+                r.time_state.init_lanes(lanes);
+                r.previous_lanes.clear();
+            }
         }
     }
 
@@ -407,6 +471,7 @@ impl Randomizer {
             Randomizer::AllScratch(r) => r.permutate(tl),
             Randomizer::NoMurioshi(r) => r.permutate(tl),
             Randomizer::Converge(r) => r.permutate(tl),
+            Randomizer::SRandomPlus(r) => r.permutate(tl),
         }
     }
 }
@@ -912,6 +977,65 @@ mod tests {
         assert_eq!(sr.time_state.threshold, DEFAULT_HRAN_THRESHOLD);
     }
 
+    // -- DensityThreshold --
+
+    fn density_tl(time_ms: i64, bpm: f64, lanes: &[i32]) -> TimeLine {
+        let mut tl = TimeLine::new(0.0, time_ms * 1000, 8);
+        tl.bpm = bpm;
+        for &lane in lanes {
+            tl.set_note(lane, Some(Note::new_normal(1)));
+        }
+        tl
+    }
+
+    #[test]
+    fn hrandom_is_density_aware() {
+        let config = PlayerConfig::default();
+        let r = Randomizer::create(Random::HRandom, &Mode::BEAT_7K, &config);
+        let sr = r.as_srandom().unwrap();
+        assert_eq!(
+            sr.density.as_ref().map(|d| d.max_threshold),
+            Some(sr.time_state.threshold)
+        );
+        let srandom = Randomizer::create(Random::SRandom, &Mode::BEAT_7K, &config);
+        assert!(srandom.as_srandom().unwrap().density.is_none());
+    }
+
+    #[test]
+    fn density_threshold_follows_local_bpm() {
+        let mut d = DensityThreshold::new(125);
+        // 16th at 200 BPM = 75ms, below the configured 125ms
+        assert_eq!(d.threshold_at(&density_tl(0, 200.0, &[]), 7), 75);
+        // 16th at 100 BPM = 150ms, capped by the configured threshold
+        assert_eq!(d.threshold_at(&density_tl(0, 100.0, &[]), 7), 125);
+        // No BPM information falls back to the configured threshold
+        assert_eq!(d.threshold_at(&density_tl(0, 0.0, &[]), 7), 125);
+    }
+
+    #[test]
+    fn density_threshold_relaxes_in_dense_passages() {
+        let mut d = DensityThreshold::new(125);
+        // 7 lanes, 70 notes within the last second -> 100ms average per lane
+        for i in 0..10 {
+            d.record(
+                &density_tl(i * 50, 120.0, &[0, 1, 2, 3, 4, 5, 6]),
+                &[0, 1, 2, 3, 4, 5, 6],
+            );
+        }
+        assert_eq!(d.threshold_at(&density_tl(500, 120.0, &[]), 7), 100);
+        // Once the window has passed, only the BPM threshold applies
+        assert_eq!(d.threshold_at(&density_tl(2000, 120.0, &[]), 7), 125);
+    }
+
+    #[test]
+    fn density_threshold_ignores_mines() {
+        let mut d = DensityThreshold::new(125);
+        let mut tl = density_tl(0, 120.0, &[]);
+        tl.set_note(0, Some(Note::new_mine(1, 10.0)));
+        d.record(&tl, &[0]);
+        assert_eq!(d.threshold_at(&density_tl(10, 120.0, &[]), 1), 125);
+    }
+
     #[test]
     fn as_srandom_returns_none_for_non_srandom_variants() {
         let config = PlayerConfig::default();
@@ -6,7 +6,7 @@ use bms::model::mode::Mode;
 use bms::model::note::Note;
 use bms::model::time_line::TimeLine;
 
-use super::{RandomizerBase, TimeBasedRandomizerState};
+use super::{DensityThreshold, RandomizerBase, TimeBasedRandomizerState};
 use crate::core::pattern::java_random::JavaRandom;
 use crate::core::pattern::pattern_modifier::AssistLevel;
 
@@ -15,6 +15,8 @@ use crate::core::pattern::pattern_modifier::AssistLevel;
 pub struct SRandomizer {
     pub base: RandomizerBase,
     pub time_state: TimeBasedRandomizerState,
+    /// When set (H-RANDOM), `time_state.threshold` is recomputed for every timeline.
+    pub density: Option<DensityThreshold>,
 }
 
 impl SRandomizer {
@@ -24,10 +26,23 @@ impl SRandomizer {
         SRandomizer {
             base,
             time_state: TimeBasedRandomizerState::new(threshold),
+            density: None,
         }
     }
 
+    /// H-RANDOM variant: `max_threshold` is the configured threshold, lowered per
+    /// timeline by [`DensityThreshold`].
+    pub fn new_density_aware(max_threshold: i64, assist: AssistLevel) -> Self {
+        let mut r = Self::new(max_threshold, assist);
+        r.density = Some(DensityThreshold::new(max_threshold));
+        r
+    }
+
     pub fn permutate(&mut self, tl: &mut TimeLine) -> Vec<i32> {
+        if let Some(density) = self.density.as_mut() {
+            self.time_state.threshold = density.threshold_at(tl, self.base.modify_lanes.len());
+            density.record(tl, &self.base.modify_lanes);
+        }
         let mut changeable = self.base.changeable_lane.clone();
         let mut assignable = self.base.assignable_lane.clone();
         let random_map = {
@@ -723,6 +738,65 @@ impl ConvergeRandomizer {
     }
 }
 
+// ---- SRandomPlusRandomizer ----
+
+/// SRAN+: S-RANDOM that keeps the lanes assigned in the immediately preceding
+/// timeslice out of the candidate set whenever another lane is available, so
+/// consecutive slices never stack into vertical jacks unless the chart forces it.
+pub struct SRandomPlusRandomizer {
+    pub base: RandomizerBase,
+    pub time_state: TimeBasedRandomizerState,
+    pub(super) previous_lanes: Vec<i32>,
+}
+
+impl SRandomPlusRandomizer {
+    pub fn new(min_gap: i64) -> Self {
+        let mut base = RandomizerBase::new();
+        base.assist = AssistLevel::LightAssist;
+        SRandomPlusRandomizer {
+            base,
+            time_state: TimeBasedRandomizerState::new(min_gap),
+            previous_lanes: Vec::new(),
+        }
+    }
+
+    pub fn permutate(&mut self, tl: &mut TimeLine) -> Vec<i32> {
+        let time_state = &mut self.time_state;
+        let previous_lanes = &mut self.previous_lanes;
+        self.base
+            .permutate(tl, &mut |tl, changeable, assignable, random| {
+                let prev = previous_lanes.clone();
+                let mut select_fn = |lane: &[i32], rng: &mut JavaRandom| -> usize {
+                    let fresh: Vec<usize> = (0..lane.len())
+                        .filter(|&i| !prev.contains(&lane[i]))
+                        .collect();
+                    if fresh.is_empty() {
+                        rng.next_int_bounded(lane.len() as i32) as usize
+                    } else {
+                        fresh[rng.next_int_bounded(fresh.len() as i32) as usize]
+                    }
+                };
+                let random_map = time_state.time_based_shuffle(
+                    tl,
+                    changeable,
+                    assignable,
+                    random,
+                    &mut select_fn,
+                );
+                time_state.update_note_time(tl, &random_map);
+
+                let mut assigned: Vec<i32> = random_map
+                    .iter()
+                    .filter(|(k, _)| tl.note(**k).is_some_and(|n| !n.is_mine()))
+                    .map(|(_, &v)| v)
+                    .collect();
+                assigned.sort_unstable();
+                *previous_lanes = assigned;
+                random_map
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1551,4 +1625,37 @@ mod tests {
             lane_usage
         );
     }
+
+    #[test]
+    fn srandom_plus_never_reuses_previous_slice_lane() {
+        let mut r = SRandomPlusRandomizer::new(0);
+        r.base.set_mode(Mode::BEAT_7K);
+        r.base.set_modify_lanes(&[0, 1, 2, 3, 4, 5, 6]);
+        r.time_state.init_lanes(&[0, 1, 2, 3, 4, 5, 6]);
+        r.base.set_random_seed(7);
+
+        let mut prev: Vec<i32> = Vec::new();
+        for t in 0..50 {
+            // Two notes per slice, 1ms apart: plain S-RANDOM with a zero
+            // threshold would freely stack these into jacks.
+            let mut tl = TimeLine::new(0.0, (t + 1) * 1_000, 8);
+            tl.set_note(0, Some(Note::new_normal(t as i32 * 10 + 1)));
+            tl.set_note(1, Some(Note::new_normal(t as i32 * 10 + 2)));
+            let perm = r.permutate(&mut tl);
+            assert_valid_permutation(&perm, 8, &[0, 1, 2, 3, 4, 5, 6]);
+
+            let lanes: Vec<i32> = (0..7).filter(|&l| tl.note(l).is_some()).collect();
+            assert_eq!(lanes.len(), 2);
+            for lane in &lanes {
+                assert!(
+                    !prev.contains(lane),
+                    "slice {} reused lane {} from previous slice {:?}",
+                    t,
+                    lane,
+                    prev
+                );
+            }
+            prev = lanes;
+        }
+    }
 }
@@ -3,7 +3,24 @@ use std::cell::RefCell;
 use std::collections::{HashMap, VecDeque};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Mutex, OnceLock};
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+/// Interval between rendered frames.
+pub const WATCH_FRAME_TIME: &str = "frame";
+/// Delay between a key event and the judge update that processes it.
+pub const WATCH_JUDGE_LATENCY: &str = "judge.latency";
+/// Song database queries.
+pub const WATCH_SONG_DB_QUERY: &str = "db.song.query";
+/// Score database queries.
+pub const WATCH_SCORE_DB_QUERY: &str = "db.score.query";
+/// Audio callback processing time / time allotted (1.0 = about to underrun).
+pub const SAMPLE_AUDIO_CALLBACK_LOAD: &str = "audio.callback_load";
+
+/// Callback load at which the audio output is counted as an underrun.
+pub const AUDIO_UNDERRUN_LOAD: f64 = 1.0;
+
+/// How long watch records and samples are kept (see `commit`).
+pub const RETENTION_NANOS: i64 = 3_000_000_000;
 
 static INSTANCE: OnceLock<PerformanceMetrics> = OnceLock::new();
 
@@ -18,6 +35,10 @@ pub struct PerformanceMetrics {
     pub event_results: Mutex<Vec<EventResult>>,
     /// Watch records keyed by name
     watch_records: Mutex<HashMap<String, VecDeque<(i64, i64)>>>,
+    /// Non-duration samples (time, value) keyed by name
+    sample_records: Mutex<HashMap<String, VecDeque<(i64, f64)>>>,
+    /// Audio callbacks that ran over their time budget since startup
+    audio_underruns: AtomicU64,
     /// Base instant for timing
     base_instant: Instant,
 }
@@ -40,6 +61,8 @@ impl PerformanceMetrics {
         Self {
             event_results: Mutex::new(Vec::new()),
             watch_records: Mutex::new(HashMap::new()),
+            sample_records: Mutex::new(HashMap::new()),
+            audio_underruns: AtomicU64::new(0),
             base_instant: Instant::now(),
         }
     }
@@ -83,10 +106,39 @@ impl PerformanceMetrics {
         deque.push_back((time, duration));
     }
 
+    /// Record a duration measured elsewhere as a watch result ending now.
+    pub fn record_duration(&self, name: &str, duration: Duration) {
+        let duration = duration.as_nanos() as i64;
+        self.submit_watch_result(name, self.nanos() - duration, duration);
+    }
+
+    /// Record a non-duration value (e.g. a load ratio).
+    pub fn submit_sample(&self, name: &str, value: f64) {
+        let time = self.nanos();
+        let mut records = lock_or_recover(&self.sample_records);
+        records
+            .entry(name.to_string())
+            .or_default()
+            .push_back((time, value));
+    }
+
+    /// Record an audio callback load, counting an underrun when it is over budget.
+    pub fn submit_audio_callback_load(&self, load: f64) {
+        if load >= AUDIO_UNDERRUN_LOAD {
+            self.audio_underruns.fetch_add(1, Ordering::Relaxed);
+        }
+        self.submit_sample(SAMPLE_AUDIO_CALLBACK_LOAD, load);
+    }
+
+    /// Number of audio callbacks that ran over their time budget since startup.
+    pub fn audio_underruns(&self) -> u64 {
+        self.audio_underruns.load(Ordering::Relaxed)
+    }
+
     /// Drop measurements older than 3 seconds
     pub fn commit(&self) {
         let now = self.nanos();
-        let keep = now - 3_000_000_000;
+        let keep = now - RETENTION_NANOS;
         let mut records = lock_or_recover(&self.watch_records);
         for (_k, v) in records.iter_mut() {
             while let Some(&(time, _)) = v.front() {
@@ -97,6 +149,14 @@ impl PerformanceMetrics {
                 }
             }
         }
+        drop(records);
+        let mut samples = lock_or_recover(&self.sample_records);
+        for v in samples.values_mut() {
+            while v.front().is_some_and(|&(time, _)| time < keep) {
+                v.pop_front();
+            }
+        }
+        drop(samples);
 
         // Evict old event results to prevent unbounded Vec growth over
         // multi-hour sessions. Use the same 3-second window as watch_records.
@@ -113,6 +173,28 @@ impl PerformanceMetrics {
         let records = lock_or_recover(&self.watch_records);
         records.get(name).cloned()
     }
+
+    pub fn sample_names(&self) -> Vec<String> {
+        let records = lock_or_recover(&self.sample_records);
+        records.keys().cloned().collect()
+    }
+
+    pub fn get_sample_records(&self, name: &str) -> Option<VecDeque<(i64, f64)>> {
+        let records = lock_or_recover(&self.sample_records);
+        records.get(name).cloned()
+    }
+}
+
+/// Average of the largest `fraction` of `values` (at least one value), e.g. the
+/// 1% low frame time with `fraction` 0.01.
+pub fn slowest_average(values: &[f64], fraction: f64) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable_by(|a, b| b.total_cmp(a));
+    let count = ((sorted.len() as f64 * fraction).ceil() as usize).clamp(1, sorted.len());
+    Some(sorted[..count].iter().sum::<f64>() / count as f64)
 }
 
 /// EventBlock - RAII block for measuring event duration
@@ -209,6 +291,50 @@ mod tests {
         assert_eq!(results[0].name, "recent_event");
     }
 
+    #[test]
+    fn commit_evicts_old_samples_and_watch_records() {
+        let metrics = PerformanceMetrics::new();
+        let now = metrics.nanos();
+        metrics.submit_watch_result("w", now - 4_000_000_000, 10);
+        metrics.record_duration("w", Duration::from_millis(2));
+        metrics.submit_sample("s", 0.5);
+        lock_or_recover(&metrics.sample_records)
+            .get_mut("s")
+            .unwrap()
+            .push_front((now - 4_000_000_000, 0.9));
+
+        metrics.commit();
+
+        let watch = metrics.get_watch_records("w").unwrap();
+        assert_eq!(watch.len(), 1);
+        assert_eq!(watch[0].1, 2_000_000);
+        let samples = metrics.get_sample_records("s").unwrap();
+        assert_eq!(samples.iter().map(|s| s.1).collect::<Vec<_>>(), vec![0.5]);
+    }
+
+    #[test]
+    fn slowest_average_takes_the_worst_share() {
+        assert_eq!(slowest_average(&[], 0.01), None);
+        let mut values: Vec<f64> = vec![1.0; 198];
+        values.extend([10.0, 20.0]);
+        assert_eq!(slowest_average(&values, 0.01), Some(15.0));
+        // At least one value is always taken
+        assert_eq!(slowest_average(&values, 0.001), Some(20.0));
+    }
+
+    #[test]
+    fn audio_callback_load_over_budget_counts_underruns() {
+        let metrics = PerformanceMetrics::new();
+        metrics.submit_audio_callback_load(0.4);
+        metrics.submit_audio_callback_load(1.2);
+        metrics.submit_audio_callback_load(AUDIO_UNDERRUN_LOAD);
+        assert_eq!(metrics.audio_underruns(), 2);
+        let samples = metrics
+            .get_sample_records(SAMPLE_AUDIO_CALLBACK_LOAD)
+            .unwrap();
+        assert_eq!(samples.len(), 3);
+    }
+
     /// commit() should not remove events within the 3-second window.
     #[test]
     fn commit_keeps_recent_event_results() {
@@ -3,7 +3,7 @@ use std::path::Path;
 
 use sha2::{Digest, Sha256};
 
-use crate::core::score_data_log_database_accessor::ScoreDataLogDatabaseAccessor;
+use crate::core::score_data_log_database_accessor::{PlayLog, ScoreDataLogDatabaseAccessor};
 use crate::core::score_database_accessor::{ScoreDataCollector, ScoreDatabaseAccessor, SongData};
 use crate::core::score_log_database_accessor::{ScoreLog, ScoreLogDatabaseAccessor};
 use crate::skin::clear_type::ClearType;
@@ -110,6 +110,39 @@ impl PlayDataAccessor {
         self.scoredb.as_ref()?.player_data()
     }
 
+    /// The `count` most recent daily player data rows (cumulative), newest
+    /// first. Every row when `count` is 0.
+    pub fn read_player_datas(&self, count: i32) -> Vec<PlayerData> {
+        self.scoredb
+            .as_ref()
+            .map_or_else(Vec::new, |db| db.player_datas(count))
+    }
+
+    /// The `count` most recent score improvements, newest first.
+    pub fn read_score_logs(&self, count: i32) -> Vec<ScoreLog> {
+        self.scorelogdb
+            .as_ref()
+            .map_or_else(Vec::new, |db| db.score_logs(count))
+    }
+
+    /// The `count` most recent plays of a chart, newest first.
+    pub fn read_play_logs(&self, hash: &str, ln: bool, lnmode: i32, count: i32) -> Vec<PlayLog> {
+        self.scoredatalogdb.as_ref().map_or_else(Vec::new, |db| {
+            db.play_logs(hash, if ln { lnmode } else { 0 }, count)
+        })
+    }
+
+    /// Timing offset (ms) saved for a chart, 0 when none is set.
+    pub fn read_chart_offset(&self, hash: &str) -> i32 {
+        self.scoredb.as_ref().map_or(0, |db| db.chart_offset(hash))
+    }
+
+    pub fn write_chart_offset(&self, hash: &str, offset: i32) {
+        if let Some(scoredb) = &self.scoredb {
+            scoredb.set_chart_offset(hash, offset);
+        }
+    }
+
     pub fn read_today_player_data(&self) -> Option<PlayerData> {
         let scoredb = self.scoredb.as_ref()?;
         let mut pd = scoredb.player_datas(2);
@@ -301,6 +334,8 @@ impl PlayDataAccessor {
             newscore_copy.clearcount = score.clearcount;
             newscore_copy.scorehash = self.get_score_hash(&newscore_copy).unwrap_or_default();
             scoredatalogdb.set_score_data_log(&newscore_copy);
+            newscore_copy.sha256 = hash.to_string();
+            scoredatalogdb.set_play_log(&newscore_copy);
         }
 
         // Play time calculation (seconds)
@@ -530,6 +565,34 @@ impl PlayDataAccessor {
         }
     }
 
+    /// Shift replays in slots `0..count - 1` down by one slot, dropping the
+    /// replay in the last slot, so that slot 0 is free for a new replay.
+    pub fn rotate_replay_data(
+        &self,
+        hash: &str,
+        ln: bool,
+        lnmode: i32,
+        count: i32,
+    ) -> anyhow::Result<()> {
+        for index in (1..count).rev() {
+            // fs::rename does not replace an existing file on every platform
+            self.delete_replay_data(hash, ln, lnmode, index);
+            if !self.exists_replay_data(hash, ln, lnmode, index - 1) {
+                continue;
+            }
+            let from = format!(
+                "{}.brd",
+                self.get_replay_data_file_path(hash, ln, lnmode, index - 1)
+            );
+            let to = format!(
+                "{}.brd",
+                self.get_replay_data_file_path(hash, ln, lnmode, index)
+            );
+            fs::rename(&from, &to)?;
+        }
+        Ok(())
+    }
+
     pub(super) fn get_replay_data_file_path(
         &self,
         hash: &str,
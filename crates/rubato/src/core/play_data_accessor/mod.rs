@@ -991,6 +991,33 @@ mod tests {
     // Replay data file path (single model)
     // ========================================================================
 
+    #[test]
+    fn test_rotate_replay_data_shifts_slots_down() {
+        let dir = tempfile::tempdir().unwrap();
+        let accessor = create_test_accessor(dir.path());
+        for (index, exscore) in [(0, 100), (1, 200), (3, 400)] {
+            let mut rd = crate::skin::replay_data::ReplayData {
+                exscore,
+                ..Default::default()
+            };
+            accessor
+                .write_replay_data(&mut rd, "hash", false, 0, index)
+                .unwrap();
+        }
+
+        accessor.rotate_replay_data("hash", false, 0, 4).unwrap();
+
+        let exscore = |index| {
+            accessor
+                .read_replay_data("hash", false, 0, index)
+                .map(|rd| rd.exscore)
+        };
+        assert_eq!(exscore(0), None);
+        assert_eq!(exscore(1), Some(100));
+        assert_eq!(exscore(2), Some(200));
+        assert_eq!(exscore(3), None, "empty slot 3 shifted into the last slot");
+    }
+
     #[test]
     fn test_replay_data_file_path_basic() {
         let accessor = PlayDataAccessor {
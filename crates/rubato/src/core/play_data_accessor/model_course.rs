@@ -2,7 +2,9 @@ use std::path::Path;
 
 use bms::model::bms_model::BMSModel;
 
-use crate::skin::course_data::CourseDataConstraint;
+use crate::core::earned_trophy::EarnedTrophy;
+use crate::core::score_data_log_database_accessor::PlayLog;
+use crate::skin::course_data::{CourseData, CourseDataConstraint};
 use crate::skin::replay_data::ReplayData;
 use crate::skin::score_data::ScoreData;
 
@@ -15,6 +17,12 @@ impl PlayDataAccessor {
         self.read_score_data_by_hash(hash, ln, lnmode)
     }
 
+    /// The `count` most recent plays of a BMSModel, newest first.
+    pub fn read_play_logs_model(&self, model: &BMSModel, lnmode: i32, count: i32) -> Vec<PlayLog> {
+        let ln = model.contains_undefined_long_note();
+        self.read_play_logs(&model.sha256, ln, lnmode, count)
+    }
+
     /// Write score data for a single BMSModel (delegates to write_score_data).
     pub fn write_score_data_model(
         &self,
@@ -68,6 +76,17 @@ impl PlayDataAccessor {
         self.write_replay_data(rd, &model.sha256, ln, lnmode, index)
     }
 
+    /// Shift replay slots of a single BMSModel down by one to free slot 0.
+    pub fn rotate_replay_data_model(
+        &self,
+        model: &BMSModel,
+        lnmode: i32,
+        count: i32,
+    ) -> anyhow::Result<()> {
+        let ln = model.contains_undefined_long_note();
+        self.rotate_replay_data(&model.sha256, ln, lnmode, count)
+    }
+
     /// Delete score data for a single BMSModel.
     pub fn delete_score_data_model(&self, model: &BMSModel, lnmode: i32) {
         self.delete_score_data(&model.sha256, model.contains_undefined_long_note(), lnmode);
@@ -121,6 +140,37 @@ impl PlayDataAccessor {
         self.write_score_data_for_course(newscore, &ctx);
     }
 
+    /// Record the trophy and class certification a course result earned.
+    /// Returns the stored record when it improved on the previous one.
+    pub fn write_earned_trophy(
+        &self,
+        course: &CourseData,
+        models: &[BMSModel],
+        newscore: &ScoreData,
+    ) -> Option<EarnedTrophy> {
+        let scoredb = self.scoredb.as_ref()?;
+        let date = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64;
+        let mut earned = EarnedTrophy::evaluate(course, newscore, date)?;
+        earned.hash = Self::course_hash(models);
+        if let Some(old) = scoredb.earned_trophy(&earned.hash)
+            && !earned.merge(&old, course)
+        {
+            return None;
+        }
+        scoredb.set_earned_trophy(&earned);
+        Some(earned)
+    }
+
+    pub fn read_earned_trophies(&self) -> Vec<EarnedTrophy> {
+        self.scoredb
+            .as_ref()
+            .map(|db| db.earned_trophies())
+            .unwrap_or_default()
+    }
+
     /// Check if replay data exists for a course.
     pub fn exists_replay_data_course(
         &self,
@@ -36,6 +36,8 @@ pub struct PlayerResource {
     orgmode: Option<bms::model::mode::Mode>,
     /// Player data
     pub playerdata: PlayerData,
+    /// Player data gained today
+    pub today_playerdata: PlayerData,
     /// Config reference
     config: Config,
     /// Player config reference
@@ -46,6 +48,8 @@ pub struct PlayerResource {
     bmsresource: Option<BMSResource>,
     /// Score
     score: Option<ScoreData>,
+    /// Score of the last autoplay run. Never saved; read by headless runs.
+    autoplay_score: Option<ScoreData>,
     /// Rival score
     rscore: Option<ScoreData>,
     /// Target score
@@ -78,6 +82,8 @@ pub struct PlayerResource {
     coursegauge: Vec<Vec<FloatArray>>,
     /// Course replay data
     course_replay: Vec<ReplayData>,
+    /// Score of each finished course stage
+    course_stage_scores: Vec<ScoreData>,
     /// Course score
     cscore: Option<ScoreData>,
     /// Combo count (for course play carry-over)
@@ -103,11 +109,15 @@ pub struct PlayerResource {
     /// BGA processor for reuse across plays.
     /// Java: BMSResource holds BGAProcessor, reused via PlayerResource.getBGAManager().
     bga: Option<Arc<Mutex<crate::play::bga::bga_processor::BGAProcessor>>>,
+    /// BGA images decoded on the decide screen, consumed by the next play.
+    bga_preload: Option<Arc<crate::play::bga::bga_image_preloader::BgaImagePreload>>,
     /// Recent judge timing offsets (milliseconds), transferred from play session
     /// via ScoreHandoff for result screen visualizers.
     recent_judges: Vec<i64>,
     /// Write index for recent_judges circular buffer.
     recent_judges_index: usize,
+    /// Hit offsets (us) of every PGREAT to BAD judge of the last play, from ScoreHandoff.
+    judge_log: Vec<i64>,
 }
 
 impl PlayerResource {
@@ -119,11 +129,13 @@ impl PlayerResource {
             songdata: None,
             orgmode: None,
             playerdata: PlayerData::new(),
+            today_playerdata: PlayerData::new(),
             config,
             pconfig,
             mode: None,
             bmsresource,
             score: None,
+            autoplay_score: None,
             rscore: None,
             tscore: None,
             ranking: None,
@@ -140,6 +152,7 @@ impl PlayerResource {
             courseindex: 0,
             coursegauge: Vec::new(),
             course_replay: Vec::new(),
+            course_stage_scores: Vec::new(),
             cscore: None,
             combo: 0,
             maxcombo: 0,
@@ -152,8 +165,10 @@ impl PlayerResource {
             freq_string: None,
             force_no_ir_send: false,
             bga: None,
+            bga_preload: None,
             recent_judges: Vec::new(),
             recent_judges_index: 0,
+            judge_log: Vec::new(),
         }
     }
 
@@ -162,11 +177,13 @@ impl PlayerResource {
         self.courseindex = 0;
         self.cscore = None;
         self.score = None;
+        self.autoplay_score = None;
         // rscore is intentionally not cleared (commented out in Java)
         self.tscore = None;
         self.gauge = None;
         self.course_replay.clear();
         self.coursegauge.clear();
+        self.course_stage_scores.clear();
         self.combo = 0;
         self.maxcombo = 0;
         self.bms_paths = None;
@@ -316,6 +333,10 @@ impl PlayerResource {
         self.score = Some(score);
     }
 
+    pub fn autoplay_score_data(&self) -> Option<&ScoreData> {
+        self.autoplay_score.as_ref()
+    }
+
     pub fn rival_score_data(&self) -> Option<&ScoreData> {
         self.rscore.as_ref()
     }
@@ -348,6 +369,19 @@ impl PlayerResource {
         self.bga = Some(bga);
     }
 
+    pub fn bga_preload(
+        &self,
+    ) -> Option<&Arc<crate::play::bga::bga_image_preloader::BgaImagePreload>> {
+        self.bga_preload.as_ref()
+    }
+
+    pub fn set_bga_preload(
+        &mut self,
+        preload: Option<Arc<crate::play::bga::bga_image_preloader::BgaImagePreload>>,
+    ) {
+        self.bga_preload = preload;
+    }
+
     pub fn set_course_bms_files(&mut self, files: &[PathBuf]) -> bool {
         let lnmode = self.pconfig.play_settings.lnmode;
         let mut models = Vec::with_capacity(files.len());
@@ -413,16 +447,7 @@ impl PlayerResource {
         }
         self.songdata = None;
         // Load the next course chart (Java: setBMSFile(Paths.get(course[courseindex].getPath()), mode))
-        let path = self
-            .course
-            .as_ref()
-            .and_then(|models| models.get(self.courseindex))
-            .and_then(|model| model.path());
-        let mode = self.mode;
-        if let (Some(path_str), Some(mode)) = (path, mode) {
-            return self.set_bms_file(Path::new(&path_str), mode);
-        }
-        false
+        self.load_course_stage(self.courseindex)
     }
 
     pub fn course_index(&self) -> usize {
@@ -445,6 +470,21 @@ impl PlayerResource {
         self.groove_gauge = Some(groove_gauge);
     }
 
+    /// Final gauge value of the last stage, read from the gauge log like Java's
+    /// `getGauge()[type].get(size - 1)`. A failed or quit stage pads its log with
+    /// zeros, so this is 0 even when the live gauge was still above it.
+    /// Falls back to the live gauge value when no log was stored.
+    pub fn last_gauge_value(&self) -> f32 {
+        let Some(groove_gauge) = self.groove_gauge.as_ref() else {
+            return 0.0;
+        };
+        self.gauge
+            .as_ref()
+            .and_then(|log| log.get(groove_gauge.gauge_type() as usize))
+            .and_then(|log| log.last().copied())
+            .unwrap_or_else(|| groove_gauge.value())
+    }
+
     pub fn replay_data(&self) -> Option<&ReplayData> {
         self.replay.as_ref()
     }
@@ -497,6 +537,56 @@ impl PlayerResource {
         self.coursegauge.push(gauge);
     }
 
+    pub fn course_stage_scores(&self) -> &[ScoreData] {
+        &self.course_stage_scores
+    }
+
+    pub fn add_course_stage_score(&mut self, score: ScoreData) {
+        self.course_stage_scores.push(score);
+    }
+
+    /// Rewind the course to its first stage, dropping everything carried over
+    /// from the finished run.
+    pub fn restart_course(&mut self) -> bool {
+        self.courseindex = 0;
+        self.cscore = None;
+        self.course_replay.clear();
+        self.coursegauge.clear();
+        self.course_stage_scores.clear();
+        self.combo = 0;
+        self.maxcombo = 0;
+        self.songdata = None;
+        self.load_course_stage(0)
+    }
+
+    /// Leave course mode and load a single stage of the course as a normal chart.
+    pub fn retry_course_stage(&mut self, index: usize) -> bool {
+        let Some(path) = self
+            .course
+            .as_ref()
+            .and_then(|models| models.get(index))
+            .and_then(|model| model.path())
+        else {
+            return false;
+        };
+        let mode = self.mode;
+        self.clear();
+        self.coursedata = None;
+        mode.is_some_and(|mode| self.set_bms_file(Path::new(&path), mode))
+    }
+
+    fn load_course_stage(&mut self, index: usize) -> bool {
+        let path = self
+            .course
+            .as_ref()
+            .and_then(|models| models.get(index))
+            .and_then(|model| model.path());
+        match (path, self.mode) {
+            (Some(path), Some(mode)) => self.set_bms_file(Path::new(&path), mode),
+            _ => false,
+        }
+    }
+
     pub fn dispose(&mut self) {
         if let Some(mut bmsresource) = self.bmsresource.take() {
             bmsresource.dispose();
@@ -544,6 +634,27 @@ impl PlayerResource {
         &self.playerdata
     }
 
+    pub fn today_player_data(&self) -> &PlayerData {
+        &self.today_playerdata
+    }
+
+    /// Re-read the cumulative and today's player data from the score database.
+    /// Keeps the cumulative data when the database has none.
+    pub fn reload_player_data(
+        &mut self,
+        accessor: &crate::core::play_data_accessor::PlayDataAccessor,
+    ) {
+        if let Some(pd) = accessor.read_player_data() {
+            self.playerdata = pd;
+        }
+        // The newest row only holds today's plays once one was saved today
+        let today = crate::core::score_database_accessor::local_midnight_timestamp();
+        self.today_playerdata = accessor
+            .read_today_player_data()
+            .filter(|pd| pd.date == today)
+            .unwrap_or_default();
+    }
+
     pub fn chart_option(&self) -> Option<&ReplayData> {
         self.chart_option.as_ref()
     }
@@ -653,6 +764,14 @@ impl PlayerResource {
         self.recent_judges = judges;
     }
 
+    pub fn judge_log(&self) -> &[i64] {
+        &self.judge_log
+    }
+
+    pub fn set_judge_log(&mut self, judge_log: Vec<i64>) {
+        self.judge_log = judge_log;
+    }
+
     /// Apply a score handoff from BMSPlayer to this resource.
     ///
     /// Encapsulates the logic that was previously in lifecycle.rs outbox drain.
@@ -665,6 +784,7 @@ impl PlayerResource {
         if let Some(score) = handoff.score_data {
             self.set_score_data(score);
         }
+        self.autoplay_score = handoff.autoplay_score;
         self.combo = handoff.combo;
         self.maxcombo = handoff.maxcombo;
         self.set_gauge(handoff.gauge);
@@ -673,9 +793,10 @@ impl PlayerResource {
         }
         self.assist = handoff.assist;
         // Java: resource.setUpdateScore(assist == 0)
-        self.update_score = handoff.assist == 0;
+        let update_score = handoff.assist == 0 && !handoff.judge_trainer_on;
+        self.update_score = update_score;
         // Java: resource.setUpdateCourseScore(resource.isUpdateCourseScore() && assist == 0)
-        self.update_course_score = self.update_course_score && (handoff.assist == 0);
+        self.update_course_score = self.update_course_score && update_score;
         self.freq_on = handoff.freq_on;
         self.force_no_ir_send = handoff.force_no_ir_send;
 
@@ -694,6 +815,7 @@ impl PlayerResource {
 
         // Transfer recent judge offsets for result screen visualizers.
         self.set_recent_judges(handoff.recent_judges_index, handoff.recent_judges);
+        self.judge_log = handoff.judge_log;
     }
 
     /// Append recorded key input log from BMSPlayerInputProcessor to replay data.
@@ -989,6 +1111,14 @@ impl MediaAccess for PlayerResource {
         self.playerdata = player_data;
     }
 
+    fn today_player_data(&self) -> Option<&crate::skin::player_data::PlayerData> {
+        Some(&self.today_playerdata)
+    }
+
+    fn set_today_player_data(&mut self, player_data: crate::skin::player_data::PlayerData) {
+        self.today_playerdata = player_data;
+    }
+
     fn set_bms_banner_raw(&mut self, data: Option<(i32, i32, Vec<u8>)>) {
         if let Some(res) = &mut self.bmsresource {
             let pixmap = data.map(|(w, h, d)| Pixmap::from_rgba_data(w, h, d));
@@ -1201,4 +1331,87 @@ mod tests {
             "should have loaded exactly 1 course model"
         );
     }
+
+    fn course_resource() -> PlayerResource {
+        let mut resource = PlayerResource::new(Config::default(), PlayerConfig::default());
+        let dir = test_bms_dir();
+        let files = vec![dir.join("minimal_7k.bms"), dir.join("7key_test.bms")];
+        assert!(resource.set_course_bms_files(&files));
+        assert!(resource.set_bms_file(&files[0], BMSPlayerMode::PLAY));
+        assert!(resource.next_course());
+        resource.set_course_score_data(ScoreData::default());
+        resource.add_course_gauge(vec![vec![80.0]]);
+        resource.add_course_stage_score(ScoreData::default());
+        resource.maxcombo = 42;
+        resource
+    }
+
+    #[test]
+    fn restart_course_rewinds_to_first_stage() {
+        let mut resource = course_resource();
+
+        assert!(resource.restart_course());
+
+        assert_eq!(resource.course_index(), 0);
+        assert!(resource.course_score_data().is_none());
+        assert!(resource.course_gauge().is_empty());
+        assert!(resource.course_stage_scores().is_empty());
+        assert_eq!(resource.maxcombo, 0);
+        assert_eq!(resource.course_bms_models().map(Vec::len), Some(2));
+        assert!(
+            resource
+                .bms_model()
+                .and_then(|m| m.path())
+                .is_some_and(|p| p.ends_with("minimal_7k.bms"))
+        );
+    }
+
+    #[test]
+    fn retry_course_stage_loads_stage_as_single_chart() {
+        let mut resource = course_resource();
+        resource.set_course_data(CourseData::default());
+
+        assert!(resource.retry_course_stage(1));
+
+        assert!(resource.course_bms_models().is_none());
+        assert!(resource.course_data().is_none());
+        assert!(resource.course_gauge().is_empty());
+        assert!(
+            resource
+                .bms_model()
+                .and_then(|m| m.path())
+                .is_some_and(|p| p.ends_with("7key_test.bms"))
+        );
+        assert!(!resource.retry_course_stage(0));
+    }
+
+    #[test]
+    fn reload_player_data_reads_totals_and_today() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.paths.playerpath = dir.path().to_string_lossy().to_string();
+        config.playername = Some("test".to_string());
+        let accessor = crate::core::play_data_accessor::PlayDataAccessor::new(&config);
+        let conn = accessor.scoredb().unwrap().connection();
+        conn.execute(
+            "INSERT INTO player (date, playcount, clear, epg, playtime) VALUES (?1, ?2, ?3, ?4, ?5)",
+            rusqlite::params![1000, 10, 4, 500, 3600],
+        )
+        .unwrap();
+
+        let mut resource = PlayerResource::new(Config::default(), PlayerConfig::default());
+        let mut score = ScoreData::default();
+        score.judge_counts.epg = 300;
+        score.clear = crate::skin::clear_type::ClearType::Normal.id();
+        accessor.update_player_data(&score, 120);
+        resource.reload_player_data(&accessor);
+
+        assert_eq!(resource.player_data().playcount, 11);
+        assert_eq!(resource.player_data().epg, 800);
+        let today = resource.today_player_data();
+        assert_eq!(today.playcount, 1);
+        assert_eq!(today.clear, 1);
+        assert_eq!(today.epg, 300);
+        assert_eq!(today.playtime, 120);
+    }
 }
@@ -1,8 +1,12 @@
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
+use std::collections::HashSet;
+
 use crate::core::course_data::{CourseData, CourseDataConstraint, TrophyData};
+use crate::core::player_config::SelectSettings;
 use crate::core::random_stage_data::RandomStageData;
+use crate::core::table_data::TableData;
 use crate::skin::SongData;
 
 /// Random course data - selects songs by SQL query results
@@ -19,6 +23,51 @@ pub struct RandomCourseData {
 impl RandomCourseData {
     pub const EMPTY: &'static [RandomCourseData] = &[];
 
+    /// Name of the course generated from the player's select settings.
+    pub const GENERATED_NAME: &'static str = "RANDOM COURSE";
+
+    /// The course described by the random course select settings, or `None`
+    /// when the generated course is turned off.
+    ///
+    /// The level range is spread over the stages in ascending order, so a
+    /// 4-stage course over levels 9-12 plays one chart of each level.
+    pub fn from_settings(settings: &SelectSettings) -> Option<RandomCourseData> {
+        let stages = settings.random_course_stages;
+        if stages <= 0 {
+            return None;
+        }
+        let table = Some(settings.random_course_table.trim())
+            .filter(|t| !t.is_empty())
+            .map(str::to_string);
+        let stage = (0..stages)
+            .map(|i| {
+                let (level_min, level_max) = stage_level_range(
+                    settings.random_course_level_min,
+                    settings.random_course_level_max,
+                    stages,
+                    i,
+                );
+                RandomStageData {
+                    title: None,
+                    sql: None,
+                    table: table.clone(),
+                    level_min,
+                    level_max,
+                }
+            })
+            .collect();
+        let mut rconstraint = vec![RandomCourseDataConstraint::Distinct];
+        if settings.random_course_unplayed {
+            rconstraint.push(RandomCourseDataConstraint::Unplayed);
+        }
+        Some(RandomCourseData {
+            name: Some(Self::GENERATED_NAME.to_string()),
+            stage,
+            rconstraint,
+            ..Default::default()
+        })
+    }
+
     pub fn name(&self) -> &str {
         self.name.as_deref().unwrap_or("")
     }
@@ -49,11 +98,13 @@ impl RandomCourseData {
     }
 
     /// Run the full lottery: query DB for each stage's SQL, then pick random songs.
+    /// Stages naming a table only draw charts listed in that table's folders.
     ///
     /// Java: RandomCourseData.lotterySongDatas(MainController)
     pub fn lottery_song_datas(
         &mut self,
         songdb: &dyn crate::song_database_accessor::SongDatabaseAccessor,
+        tables: &[&TableData],
         score_db_path: &str,
         scorelog_db_path: &str,
         info_db_path: Option<&str>,
@@ -61,18 +112,24 @@ impl RandomCourseData {
         let is_distinct = self
             .rconstraint
             .contains(&RandomCourseDataConstraint::Distinct);
+        let is_unplayed = self
+            .rconstraint
+            .contains(&RandomCourseDataConstraint::Unplayed);
         let stage_count = self.stage.len();
         let mut results: Vec<Option<SongData>> = vec![None; stage_count];
         let mut lots: Vec<SongData> = Vec::new();
 
         for (i, stage) in self.stage.iter().enumerate() {
-            let sql_opt = stage.sql.as_deref().filter(|s| !s.is_empty());
-            if sql_opt.is_none() && i > 0 {
+            if !stage.has_filter() && i > 0 {
                 Self::lottery_song_data(&mut results, i, &lots, is_distinct);
                 continue;
             }
-            let sql = sql_opt.unwrap_or("1");
-            lots = songdb.song_datas_by_sql(sql, score_db_path, scorelog_db_path, info_db_path);
+            let sql = stage.where_clause(is_unplayed);
+            lots = songdb.song_datas_by_sql(&sql, score_db_path, scorelog_db_path, info_db_path);
+            if let Some(table) = stage.table.as_deref() {
+                let hashes = table_stage_hashes(tables, table, stage);
+                lots.retain(|s| hashes.contains(&s.file.sha256) || hashes.contains(&s.file.md5));
+            }
             Self::lottery_song_data(&mut results, i, &lots, is_distinct);
         }
 
@@ -124,28 +181,73 @@ impl RandomCourseData {
     }
 }
 
+/// Level bounds of stage `index` when `min..=max` is spread over `stages`
+/// stages. A zero bound is open and applies unchanged to every stage.
+fn stage_level_range(min: i32, max: i32, stages: i32, index: i32) -> (Option<i32>, Option<i32>) {
+    if min > 0 && max >= min {
+        let span = max - min + 1;
+        let lo = min + span * index / stages;
+        let hi = (min + span * (index + 1) / stages - 1).max(lo);
+        (Some(lo), Some(hi))
+    } else {
+        (Some(min).filter(|&l| l > 0), Some(max).filter(|&l| l > 0))
+    }
+}
+
+/// Level of a table folder, read from the number its name ends with
+/// (`★12`, `sl3`, `12`).
+fn folder_level(name: &str) -> Option<i32> {
+    let prefix = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    name[prefix.len()..].parse().ok()
+}
+
+/// Hashes of the charts in the folders of table `table` whose level is in
+/// the stage's range.
+fn table_stage_hashes(
+    tables: &[&TableData],
+    table: &str,
+    stage: &RandomStageData,
+) -> HashSet<String> {
+    let ranged = stage.level_min.is_some() || stage.level_max.is_some();
+    tables
+        .iter()
+        .filter(|t| t.name == table)
+        .flat_map(|t| &t.folder)
+        .filter(|f| !ranged || folder_level(f.name()).is_some_and(|l| stage.contains_level(l)))
+        .flat_map(|f| &f.songs)
+        .flat_map(|s| [&s.file.sha256, &s.file.md5])
+        .filter(|h| !h.is_empty())
+        .cloned()
+        .collect()
+}
+
 /// Random course data constraint
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RandomCourseDataConstraint {
     Distinct,
+    /// Only draw charts without a score.
+    Unplayed,
 }
 
 impl RandomCourseDataConstraint {
     pub fn name_str(&self) -> &str {
         match self {
             RandomCourseDataConstraint::Distinct => "distinct",
+            RandomCourseDataConstraint::Unplayed => "unplayed",
         }
     }
 
     pub fn constraint_type(&self) -> i32 {
         match self {
             RandomCourseDataConstraint::Distinct => 0,
+            RandomCourseDataConstraint::Unplayed => 1,
         }
     }
 
     pub fn value(name: &str) -> Option<RandomCourseDataConstraint> {
         match name {
             "distinct" => Some(RandomCourseDataConstraint::Distinct),
+            "unplayed" => Some(RandomCourseDataConstraint::Unplayed),
             _ => None,
         }
     }
@@ -180,4 +282,123 @@ mod tests {
         // If duplicate check works, it should pick "B"
         assert_eq!(song_datas[2].as_ref().unwrap().file.sha256, "B");
     }
+
+    #[test]
+    fn level_range_is_spread_ascending_over_stages() {
+        let ranges: Vec<_> = (0..4).map(|i| stage_level_range(9, 12, 4, i)).collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (Some(9), Some(9)),
+                (Some(10), Some(10)),
+                (Some(11), Some(11)),
+                (Some(12), Some(12)),
+            ]
+        );
+        // Fewer levels than stages repeats levels instead of leaving gaps.
+        let ranges: Vec<_> = (0..4).map(|i| stage_level_range(10, 11, 4, i)).collect();
+        assert_eq!(ranges[0], (Some(10), Some(10)));
+        assert_eq!(ranges[3], (Some(11), Some(11)));
+        // Open bounds apply unchanged.
+        assert_eq!(stage_level_range(0, 5, 3, 2), (None, Some(5)));
+        assert_eq!(stage_level_range(7, 0, 3, 0), (Some(7), None));
+    }
+
+    #[test]
+    fn where_clause_combines_sql_level_and_unplayed() {
+        let stage = RandomStageData {
+            sql: Some("song.mode = 7".to_string()),
+            level_min: Some(10),
+            level_max: Some(12),
+            ..Default::default()
+        };
+        assert_eq!(
+            stage.where_clause(true),
+            "(song.mode = 7) AND song.level >= 10 AND song.level <= 12 AND score.sha256 IS NULL"
+        );
+        assert_eq!(RandomStageData::default().where_clause(false), "1");
+
+        // Table stages match levels against the table folders instead.
+        let table_stage = RandomStageData {
+            table: Some("Insane".to_string()),
+            level_min: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(table_stage.where_clause(false), "1");
+        assert!(table_stage.has_filter());
+        assert!(!RandomStageData::default().has_filter());
+    }
+
+    #[test]
+    fn table_stage_hashes_match_folder_levels() {
+        use crate::core::table_data::TableFolder;
+        let mut md5_only = SongData::default();
+        md5_only.file.md5 = "m12".to_string();
+        let table = TableData {
+            name: "Insane".to_string(),
+            folder: vec![
+                TableFolder {
+                    name: Some("★11".to_string()),
+                    songs: vec![make_song("s11")],
+                },
+                TableFolder {
+                    name: Some("★12".to_string()),
+                    songs: vec![make_song("s12"), md5_only],
+                },
+                TableFolder {
+                    name: Some("★???".to_string()),
+                    songs: vec![make_song("unknown")],
+                },
+            ],
+            ..Default::default()
+        };
+        let stage = RandomStageData {
+            table: Some("Insane".to_string()),
+            level_min: Some(12),
+            level_max: Some(12),
+            ..Default::default()
+        };
+        let hashes = table_stage_hashes(&[&table], "Insane", &stage);
+        let mut hashes: Vec<_> = hashes.into_iter().collect();
+        hashes.sort();
+        assert_eq!(hashes, vec!["m12", "s12"]);
+
+        let whole_table = RandomStageData {
+            table: Some("Insane".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            table_stage_hashes(&[&table], "Insane", &whole_table).len(),
+            4
+        );
+        assert!(table_stage_hashes(&[&table], "Normal", &whole_table).is_empty());
+    }
+
+    #[test]
+    fn from_settings_builds_generated_course() {
+        let mut settings = SelectSettings::default();
+        assert!(RandomCourseData::from_settings(&settings).is_none());
+
+        settings.random_course_stages = 3;
+        settings.random_course_table = " Insane ".to_string();
+        settings.random_course_level_min = 1;
+        settings.random_course_level_max = 3;
+        settings.random_course_unplayed = true;
+        let course = RandomCourseData::from_settings(&settings).unwrap();
+        assert_eq!(course.name(), RandomCourseData::GENERATED_NAME);
+        assert_eq!(course.stage.len(), 3);
+        assert_eq!(course.stage[0].table.as_deref(), Some("Insane"));
+        assert_eq!(course.stage[2].level_min, Some(3));
+        assert_eq!(
+            course.rconstraint,
+            vec![
+                RandomCourseDataConstraint::Distinct,
+                RandomCourseDataConstraint::Unplayed
+            ]
+        );
+        assert_eq!(
+            RandomCourseDataConstraint::value("unplayed"),
+            Some(RandomCourseDataConstraint::Unplayed)
+        );
+    }
 }
@@ -5,4 +5,54 @@ use serde::{Deserialize, Serialize};
 pub struct RandomStageData {
     pub title: Option<String>,
     pub sql: Option<String>,
+    /// Name of the difficulty table the stage draws from.
+    pub table: Option<String>,
+    /// Level range of the stage. Matched against the table folder level when
+    /// `table` is set, otherwise against the chart's own level.
+    #[serde(rename = "levelMin")]
+    pub level_min: Option<i32>,
+    #[serde(rename = "levelMax")]
+    pub level_max: Option<i32>,
+}
+
+impl RandomStageData {
+    /// Whether the stage narrows the candidates at all. Stages without a
+    /// filter reuse the candidates of the previous stage.
+    pub fn has_filter(&self) -> bool {
+        self.sql.as_deref().is_some_and(|s| !s.is_empty())
+            || self.table.is_some()
+            || self.level_min.is_some()
+            || self.level_max.is_some()
+    }
+
+    /// WHERE clause for the song query. Levels are only matched here when
+    /// the stage has no table; table levels are matched after the query.
+    pub fn where_clause(&self, unplayed: bool) -> String {
+        let mut conditions: Vec<String> = Vec::new();
+        if let Some(sql) = self.sql.as_deref().filter(|s| !s.is_empty()) {
+            conditions.push(format!("({})", sql));
+        }
+        if self.table.is_none() {
+            if let Some(min) = self.level_min {
+                conditions.push(format!("song.level >= {}", min));
+            }
+            if let Some(max) = self.level_max {
+                conditions.push(format!("song.level <= {}", max));
+            }
+        }
+        if unplayed {
+            conditions.push("score.sha256 IS NULL".to_string());
+        }
+        if conditions.is_empty() {
+            "1".to_string()
+        } else {
+            conditions.join(" AND ")
+        }
+    }
+
+    /// Whether `level` is inside the stage's level range.
+    pub fn contains_level(&self, level: i32) -> bool {
+        self.level_min.is_none_or(|min| level >= min)
+            && self.level_max.is_none_or(|max| level <= max)
+    }
 }
@@ -180,19 +180,13 @@ mod tests {
 
         let mut rd = ReplayData::new();
         rd.pattern = Some(vec![
-            PatternModifyLog {
-                old_lane: 2,
-                new_lane: 0,
-            },
-            PatternModifyLog {
-                old_lane: 3,
-                new_lane: 1,
-            },
+            PatternModifyLog::new(0.0, vec![2, 0, 1]),
+            PatternModifyLog::new(1.0, vec![0, 2, 1]),
         ]);
 
         assert_eq!(rd.pattern.as_ref().unwrap().len(), 2);
-        assert_eq!(rd.pattern.as_ref().unwrap()[0].old_lane, 2);
-        assert_eq!(rd.pattern.as_ref().unwrap()[0].new_lane, 0);
+        assert_eq!(rd.pattern.as_ref().unwrap()[0].section, 0.0);
+        assert_eq!(rd.pattern.as_ref().unwrap()[0].modify, Some(vec![2, 0, 1]));
     }
 
     #[test]
@@ -6,6 +6,9 @@ use crate::skin::player_information::PlayerInformation;
 use crate::core::main_controller::MainController;
 use crate::core::score_data_importer::ScoreDataImporter;
 use crate::core::score_database_accessor::ScoreDatabaseAccessor;
+use crate::external::score_data_importer::{
+    RIVAL_DIR, ScoreDataImporter as ExternalScoreDataImporter,
+};
 
 /// Rival data accessor.
 /// Translated from Java: RivalDataAccessor
@@ -13,6 +16,8 @@ use crate::core::score_database_accessor::ScoreDatabaseAccessor;
 pub struct RivalDataAccessor {
     rivals: Vec<PlayerInformation>,
     rivalcaches: Vec<ScoreDataCache>,
+    /// Score database backing each rival, parallel to `rivals`.
+    rivalpaths: Vec<String>,
 }
 
 impl RivalDataAccessor {
@@ -20,6 +25,7 @@ impl RivalDataAccessor {
         Self {
             rivals: Vec::new(),
             rivalcaches: Vec::new(),
+            rivalpaths: Vec::new(),
         }
     }
 
@@ -42,6 +48,66 @@ impl RivalDataAccessor {
         self.rivals.len()
     }
 
+    /// Path of the score database backing the rival at `index`.
+    pub fn rival_score_db_path(&self, index: usize) -> Option<&str> {
+        self.rivalpaths.get(index).map(String::as_str)
+    }
+
+    /// Load the rivals stored in the `rival/` directory (IR downloads and
+    /// imported score files) without contacting the IR.
+    pub fn load_local_rivals(&mut self) {
+        self.rivals.clear();
+        self.rivalcaches.clear();
+        self.rivalpaths.clear();
+        for (info, path) in Self::scan_rival_dir(Path::new(RIVAL_DIR), |_| false) {
+            self.push_rival(info, path);
+        }
+    }
+
+    /// Import a rival's score file as `name` and reload the local rivals.
+    /// Importing an existing name again refreshes that rival's scores.
+    pub fn import_rival(&mut self, source: &Path, name: &str) -> anyhow::Result<()> {
+        ExternalScoreDataImporter::import_rival(source, name, Path::new(RIVAL_DIR))?;
+        self.load_local_rivals();
+        Ok(())
+    }
+
+    fn push_rival(&mut self, info: PlayerInformation, path: String) {
+        self.rivalcaches
+            .push(Self::create_score_cache_for_db(&path));
+        self.rivals.push(info);
+        self.rivalpaths.push(path);
+    }
+
+    /// Rival databases in `dir` whose file name is not `skip`ped, in file name order.
+    fn scan_rival_dir(dir: &Path, skip: impl Fn(&str) -> bool) -> Vec<(PlayerInformation, String)> {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        let mut paths: Vec<_> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "db"))
+            .collect();
+        paths.sort();
+
+        let mut rivals = Vec::new();
+        for path in paths {
+            let file_name = path.file_name().and_then(|f| f.to_str()).unwrap_or("");
+            if skip(file_name) {
+                continue;
+            }
+            let path_str = path.to_string_lossy().to_string();
+            if let Ok(scoredb) = ScoreDatabaseAccessor::new(&path_str)
+                && let Some(info) = scoredb.information()
+            {
+                log::info!("Local rival score loaded: {}", info.name());
+                rivals.push((info, path_str));
+            }
+        }
+        rivals
+    }
+
     /// Update rival data from IR.
     /// Translates: RivalDataAccessor.update(MainController)
     pub fn update(&mut self, main: &mut MainController) {
@@ -103,13 +169,12 @@ impl RivalDataAccessor {
         let should_import_rivals = provider.should_import_rivals();
 
         let mut rivals = Vec::new();
-        let mut rivalcaches = Vec::new();
 
         if should_import_rivals {
             match provider.fetch_rival_list() {
                 Ok(rival_list) => {
                     // Create rival/ directory if needed
-                    let rival_dir = Path::new("rival");
+                    let rival_dir = Path::new(RIVAL_DIR);
                     if !rival_dir.exists()
                         && let Err(e) = std::fs::create_dir_all(rival_dir)
                     {
@@ -118,7 +183,7 @@ impl RivalDataAccessor {
 
                     for rival_info in &rival_list {
                         let info = rival_info.to_player_information();
-                        let db_path = format!("rival/{}{}.db", ir_name, rival_info.id);
+                        let db_path = format!("{}/{}{}.db", RIVAL_DIR, ir_name, rival_info.id);
 
                         // Fetch rival scores in background thread
                         let rival_info_clone = rival_info.clone();
@@ -158,10 +223,7 @@ impl RivalDataAccessor {
                             }
                         }
 
-                        // Create cache backed by the rival score database
-                        let cache = Self::create_score_cache_for_db(&db_path);
-                        rivals.push(info);
-                        rivalcaches.push(cache);
+                        rivals.push((info, db_path));
                     }
                 }
                 Err(e) => {
@@ -171,44 +233,24 @@ impl RivalDataAccessor {
         }
 
         // Step 3: Scan rival/ directory for existing .db files not in IR list
-        let rival_dir = Path::new("rival");
-        if rival_dir.exists()
-            && let Ok(entries) = std::fs::read_dir(rival_dir)
-        {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if path.extension().is_some_and(|ext| ext == "db") {
-                    let file_name = path.file_name().and_then(|f| f.to_str()).unwrap_or("");
-
-                    // Skip if already loaded from IR
-                    let already_loaded = rivals.iter().any(|info| {
-                        let expected =
-                            format!("{}{}.db", ir_name, info.id.as_deref().unwrap_or(""));
-                        file_name == expected
-                    });
-                    if already_loaded {
-                        continue;
-                    }
+        let loaded: Vec<String> = rivals
+            .iter()
+            .map(|(info, _)| format!("{}{}.db", ir_name, info.id.as_deref().unwrap_or("")))
+            .collect();
+        rivals.extend(Self::scan_rival_dir(Path::new(RIVAL_DIR), |file_name| {
+            loaded.iter().any(|expected| expected == file_name)
+        }));
 
-                    let path_str = path.to_string_lossy().to_string();
-                    if let Ok(scoredb) = ScoreDatabaseAccessor::new(&path_str)
-                        && let Some(info) = scoredb.information()
-                    {
-                        let cache = Self::create_score_cache_for_db(&path_str);
-                        log::info!("Local rival score loaded: {}", info.name());
-                        rivals.push(info);
-                        rivalcaches.push(cache);
-                    }
-                }
-            }
+        self.rivals.clear();
+        self.rivalcaches.clear();
+        self.rivalpaths.clear();
+        for (info, path) in rivals {
+            self.push_rival(info, path);
         }
-
-        self.rivals = rivals;
-        self.rivalcaches = rivalcaches;
     }
 
     /// Create a ScoreDataCache backed by a score database file.
-    fn create_score_cache_for_db(db_path: &str) -> ScoreDataCache {
+    pub fn create_score_cache_for_db(db_path: &str) -> ScoreDataCache {
         let db_path_single = db_path.to_string();
         let db_path_multi = db_path.to_string();
 
@@ -241,3 +283,48 @@ impl RivalDataAccessor {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skin::score_data::ScoreData;
+
+    #[test]
+    fn scan_rival_dir_loads_imported_rivals_with_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("score.db");
+        let db = ScoreDatabaseAccessor::new(&source.to_string_lossy()).unwrap();
+        db.create_table().unwrap();
+        let mut score = ScoreData {
+            sha256: "aaa".to_string(),
+            notes: 100,
+            ..Default::default()
+        };
+        score.judge_counts.epg = 10;
+        db.set_score_data(&score);
+        drop(db);
+        let rival_dir = dir.path().join("rival");
+        ExternalScoreDataImporter::import_rival(&source, "Bob", &rival_dir).unwrap();
+        ExternalScoreDataImporter::import_rival(&source, "Alice", &rival_dir).unwrap();
+
+        let mut accessor = RivalDataAccessor::new();
+        for (info, path) in RivalDataAccessor::scan_rival_dir(&rival_dir, |_| false) {
+            accessor.push_rival(info, path);
+        }
+
+        assert_eq!(accessor.rival_count(), 2);
+        assert_eq!(accessor.rival_information(0).unwrap().name(), "Alice");
+        assert!(
+            accessor
+                .rival_score_db_path(0)
+                .unwrap()
+                .ends_with("import_Alice.db")
+        );
+        assert_eq!(accessor.rival_information(1).unwrap().name(), "Bob");
+        assert!(accessor.rival_score_db_path(2).is_none());
+
+        let skipped = RivalDataAccessor::scan_rival_dir(&rival_dir, |f| f == "import_Bob.db");
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].0.name(), "Alice");
+    }
+}
@@ -16,40 +16,53 @@ impl ScoreDataLogDatabaseAccessor {
         conn.execute_batch("PRAGMA journal_mode = WAL; PRAGMA synchronous = NORMAL;")?;
         conn.pragma_update(None, "cache_size", 2000)?;
 
-        let tables = vec![Table::new(
-            "scoredatalog",
-            vec![
-                Column::with_pk("sha256", "TEXT", 1, 1),
-                Column::with_pk("mode", "INTEGER", 0, 1),
-                Column::new("clear", "INTEGER"),
-                Column::new("epg", "INTEGER"),
-                Column::new("lpg", "INTEGER"),
-                Column::new("egr", "INTEGER"),
-                Column::new("lgr", "INTEGER"),
-                Column::new("egd", "INTEGER"),
-                Column::new("lgd", "INTEGER"),
-                Column::new("ebd", "INTEGER"),
-                Column::new("lbd", "INTEGER"),
-                Column::new("epr", "INTEGER"),
-                Column::new("lpr", "INTEGER"),
-                Column::new("ems", "INTEGER"),
-                Column::new("lms", "INTEGER"),
-                Column::new("notes", "INTEGER"),
-                Column::new("combo", "INTEGER"),
-                Column::new("minbp", "INTEGER"),
-                Column::with_default("avgjudge", "INTEGER", 1, 0, &i32::MAX.to_string()),
-                Column::new("playcount", "INTEGER"),
-                Column::new("clearcount", "INTEGER"),
-                Column::new("trophy", "TEXT"),
-                Column::new("ghost", "TEXT"),
-                Column::new("option", "INTEGER"),
-                Column::new("seed", "INTEGER"),
-                Column::new("random", "INTEGER"),
-                Column::new("date", "INTEGER"),
-                Column::new("state", "INTEGER"),
-                Column::new("scorehash", "TEXT"),
-            ],
-        )];
+        let tables = vec![
+            Table::new(
+                "scoredatalog",
+                vec![
+                    Column::with_pk("sha256", "TEXT", 1, 1),
+                    Column::with_pk("mode", "INTEGER", 0, 1),
+                    Column::new("clear", "INTEGER"),
+                    Column::new("epg", "INTEGER"),
+                    Column::new("lpg", "INTEGER"),
+                    Column::new("egr", "INTEGER"),
+                    Column::new("lgr", "INTEGER"),
+                    Column::new("egd", "INTEGER"),
+                    Column::new("lgd", "INTEGER"),
+                    Column::new("ebd", "INTEGER"),
+                    Column::new("lbd", "INTEGER"),
+                    Column::new("epr", "INTEGER"),
+                    Column::new("lpr", "INTEGER"),
+                    Column::new("ems", "INTEGER"),
+                    Column::new("lms", "INTEGER"),
+                    Column::new("notes", "INTEGER"),
+                    Column::new("combo", "INTEGER"),
+                    Column::new("minbp", "INTEGER"),
+                    Column::with_default("avgjudge", "INTEGER", 1, 0, &i32::MAX.to_string()),
+                    Column::new("playcount", "INTEGER"),
+                    Column::new("clearcount", "INTEGER"),
+                    Column::new("trophy", "TEXT"),
+                    Column::new("ghost", "TEXT"),
+                    Column::new("option", "INTEGER"),
+                    Column::new("seed", "INTEGER"),
+                    Column::new("random", "INTEGER"),
+                    Column::new("date", "INTEGER"),
+                    Column::new("state", "INTEGER"),
+                    Column::new("scorehash", "TEXT"),
+                ],
+            ),
+            Table::new(
+                "playlog",
+                vec![
+                    Column::with_pk("sha256", "TEXT", 1, 0),
+                    Column::new("mode", "INTEGER"),
+                    Column::new("clear", "INTEGER"),
+                    Column::new("exscore", "INTEGER"),
+                    Column::new("minbp", "INTEGER"),
+                    Column::new("date", "INTEGER"),
+                ],
+            ),
+        ];
 
         let base = SQLiteDatabaseAccessor::new(tables);
         base.validate(&conn)?;
@@ -78,11 +91,66 @@ impl ScoreDataLogDatabaseAccessor {
         }
     }
 
+    /// Record one play. Unlike `scoredatalog`, which keeps the last play of
+    /// each chart, `playlog` keeps every play.
+    pub fn set_play_log(&self, score: &ScoreData) {
+        if let Err(e) = self
+            .base
+            .insert_with_values(&self.conn, "playlog", &|col_name| match col_name {
+                "sha256" => rusqlite::types::Value::Text(score.sha256.clone()),
+                "mode" => rusqlite::types::Value::Integer(score.mode as i64),
+                "clear" => rusqlite::types::Value::Integer(score.clear as i64),
+                "exscore" => rusqlite::types::Value::Integer(score.exscore() as i64),
+                "minbp" => rusqlite::types::Value::Integer(score.minbp as i64),
+                "date" => rusqlite::types::Value::Integer(score.date),
+                _ => rusqlite::types::Value::Null,
+            })
+        {
+            log::error!("Exception setting play log: {}", e);
+        }
+    }
+
+    /// The `count` most recent plays of a chart, newest first.
+    pub fn play_logs(&self, sha256: &str, mode: i32, count: i32) -> Vec<PlayLog> {
+        match self
+            .conn
+            .prepare(
+                "SELECT * FROM playlog WHERE sha256 = ?1 AND mode = ?2 \
+                 ORDER BY date DESC, rowid DESC LIMIT ?3",
+            )
+            .and_then(|mut stmt| {
+                stmt.query_map(rusqlite::params![sha256, mode, count], |row| {
+                    Ok(PlayLog {
+                        clear: row.get("clear").unwrap_or(0),
+                        exscore: row.get("exscore").unwrap_or(0),
+                        minbp: row.get("minbp").unwrap_or(0),
+                        date: row.get("date").unwrap_or(0),
+                    })
+                })
+                .map(|rows| rows.filter_map(|r| r.ok()).collect::<Vec<_>>())
+            }) {
+            Ok(logs) => logs,
+            Err(e) => {
+                log::error!("Exception getting play logs: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
     pub fn connection(&self) -> &Connection {
         &self.conn
     }
 }
 
+/// One play of a chart.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PlayLog {
+    pub clear: i32,
+    pub exscore: i32,
+    pub minbp: i32,
+    pub date: i64,
+}
+
 fn score_data_to_value(score: &ScoreData, col_name: &str) -> rusqlite::types::Value {
     match col_name {
         "sha256" => rusqlite::types::Value::Text(score.sha256.clone()),
@@ -126,7 +194,33 @@ fn score_data_to_value(score: &ScoreData, col_name: &str) -> rusqlite::types::Va
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::skin::score_data::TimingStats;
+    use crate::skin::score_data::{JudgeCounts, TimingStats};
+
+    #[test]
+    fn play_logs_keep_every_play_newest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("scoredatalog.db");
+        let accessor = ScoreDataLogDatabaseAccessor::new(db_path.to_str().unwrap()).unwrap();
+        let play = |sha256: &str, epg: i32, date: i64| ScoreData {
+            sha256: sha256.to_string(),
+            judge_counts: JudgeCounts {
+                epg,
+                ..Default::default()
+            },
+            date,
+            ..Default::default()
+        };
+        accessor.set_play_log(&play("a", 100, 10));
+        accessor.set_play_log(&play("a", 150, 20));
+        accessor.set_play_log(&play("a", 120, 20));
+        accessor.set_play_log(&play("b", 500, 30));
+
+        let logs = accessor.play_logs("a", 0, 10);
+        let scores: Vec<i32> = logs.iter().map(|log| log.exscore).collect();
+        assert_eq!(scores, vec![240, 300, 200]);
+        assert_eq!(accessor.play_logs("a", 0, 1).len(), 1);
+        assert!(accessor.play_logs("a", 1, 10).is_empty());
+    }
 
     #[test]
     fn score_data_log_avgjudge_sentinel_normalized_to_i32_max() {
@@ -120,7 +120,7 @@ pub(super) fn score_data_to_value(score: &ScoreData, col_name: &str) -> rusqlite
 /// - Ambiguous time (clocks fall back): picks the earlier of the two.
 /// - Non-existent time (clocks spring forward): falls back to the current local time's
 ///   start-of-day in UTC.
-pub(super) fn local_midnight_timestamp() -> i64 {
+pub(crate) fn local_midnight_timestamp() -> i64 {
     let naive_midnight = chrono::Local::now()
         .date_naive()
         .and_hms_opt(0, 0, 0)
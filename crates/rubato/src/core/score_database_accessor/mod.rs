@@ -8,6 +8,7 @@ use crate::core::sqlite_database_accessor::{Column, SQLiteDatabaseAccessor, Tabl
 use crate::skin::player_data::PlayerData;
 use crate::skin::score_data::ScoreData;
 
+pub(crate) use helpers::local_midnight_timestamp;
 use helpers::player_data_to_value;
 
 // Re-export SongData from rubato_types for use by other accessors
@@ -99,6 +100,23 @@ impl ScoreDatabaseAccessor {
                     Column::new("scorehash", "TEXT"),
                 ],
             ),
+            Table::new(
+                "trophy",
+                vec![
+                    Column::with_pk("hash", "TEXT", 1, 1),
+                    Column::new("name", "TEXT"),
+                    Column::new("trophy", "TEXT"),
+                    Column::new("certified", "INTEGER"),
+                    Column::new("date", "INTEGER"),
+                ],
+            ),
+            Table::new(
+                "chartoffset",
+                vec![
+                    Column::with_pk("sha256", "TEXT", 1, 1),
+                    Column::new("offset", "INTEGER"),
+                ],
+            ),
         ];
 
         let base = SQLiteDatabaseAccessor::new(tables);
@@ -107,7 +125,7 @@ impl ScoreDatabaseAccessor {
     }
 
     pub fn create_table(&self) -> anyhow::Result<()> {
-        self.base.validate(&self.conn)?;
+        self.base.migrate(&self.conn)?;
         if self.player_datas(1).is_empty() {
             let pd = PlayerData::default();
             self.base
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use crate::core::earned_trophy::EarnedTrophy;
 use crate::skin::player_data::PlayerData;
 use crate::skin::player_information::PlayerInformation;
 use crate::skin::score_data::ScoreData;
@@ -156,4 +157,40 @@ impl ScoreDatabaseAccessor {
             log::error!("Exception updating score: {}", e);
         }
     }
+
+    pub fn set_earned_trophy(&self, trophy: &EarnedTrophy) {
+        if let Err(e) = self
+            .base
+            .insert_with_values(&self.conn, "trophy", &|col_name| match col_name {
+                "hash" => rusqlite::types::Value::Text(trophy.hash.clone()),
+                "name" => rusqlite::types::Value::Text(trophy.course.clone()),
+                "trophy" => rusqlite::types::Value::Text(trophy.trophy.clone().unwrap_or_default()),
+                "certified" => rusqlite::types::Value::Integer(trophy.certified as i64),
+                "date" => rusqlite::types::Value::Integer(trophy.date),
+                _ => rusqlite::types::Value::Null,
+            })
+        {
+            log::error!("Exception updating trophy: {}", e);
+        }
+    }
+
+    /// Store the timing offset (ms) of a chart. Offset 0 removes the record.
+    pub fn set_chart_offset(&self, sha256: &str, offset: i32) {
+        let result = if offset == 0 {
+            self.conn
+                .execute("DELETE FROM chartoffset WHERE sha256 = ?1", [sha256])
+                .map(|_| ())
+                .map_err(anyhow::Error::from)
+        } else {
+            self.base
+                .insert_with_values(&self.conn, "chartoffset", &|col_name| match col_name {
+                    "sha256" => rusqlite::types::Value::Text(sha256.to_string()),
+                    "offset" => rusqlite::types::Value::Integer(offset as i64),
+                    _ => rusqlite::types::Value::Null,
+                })
+        };
+        if let Err(e) = result {
+            log::error!("Exception updating chart offset: {}", e);
+        }
+    }
 }
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::core::earned_trophy::EarnedTrophy;
+use crate::core::performance_metrics::{PerformanceMetrics, WATCH_SCORE_DB_QUERY};
 use crate::skin::player_data::PlayerData;
 use crate::skin::player_information::PlayerInformation;
 use crate::skin::score_data::ScoreData;
@@ -9,6 +11,22 @@ use super::helpers::{row_to_player_data, row_to_score_data};
 use super::{LOAD_CHUNK_SIZE, ScoreDataCollector, ScoreDatabaseAccessor, SongData};
 
 impl ScoreDatabaseAccessor {
+    /// Read every valid score from the score database at `path` without
+    /// modifying it (no schema validation, no journal mode change).
+    pub fn read_score_file(path: &str) -> anyhow::Result<Vec<ScoreData>> {
+        let conn = rusqlite::Connection::open_with_flags(
+            path,
+            rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY | rusqlite::OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?;
+        let mut stmt = conn.prepare("SELECT * FROM score")?;
+        let scores = stmt
+            .query_map([], |row| Ok(row_to_score_data(row)))?
+            .filter_map(|r| r.ok())
+            .filter_map(|mut s| if s.validate() { Some(s) } else { None })
+            .collect();
+        Ok(scores)
+    }
+
     pub fn information(&self) -> Option<PlayerInformation> {
         match self
             .conn
@@ -38,6 +56,7 @@ impl ScoreDatabaseAccessor {
     }
 
     pub fn score_data(&self, hash: &str, mode: i32) -> Option<ScoreData> {
+        let _watch = PerformanceMetrics::get().watch(WATCH_SCORE_DB_QUERY);
         match self
             .conn
             .prepare("SELECT * FROM score WHERE sha256 = ?1 AND mode = ?2")
@@ -77,6 +96,7 @@ impl ScoreDatabaseAccessor {
         songs: &[SongData],
         lnmode: i32,
     ) {
+        let _watch = PerformanceMetrics::get().watch(WATCH_SCORE_DB_QUERY);
         let mut str_buf = String::with_capacity(songs.len() * 68);
         self.get_score_datas_inner(collector, songs, lnmode, &mut str_buf, true);
         str_buf.clear();
@@ -153,6 +173,7 @@ impl ScoreDatabaseAccessor {
     // local access. Parameterization would require a significant refactor of the folder filter
     // system. Same pattern as the Java original (beatoraja).
     pub fn score_datas(&self, sql: &str) -> Option<Vec<ScoreData>> {
+        let _watch = PerformanceMetrics::get().watch(WATCH_SCORE_DB_QUERY);
         match self
             .conn
             .prepare(&format!("SELECT * FROM score WHERE {}", sql))
@@ -208,4 +229,49 @@ impl ScoreDatabaseAccessor {
             }
         }
     }
+
+    /// Every earned course trophy and class certification.
+    pub fn earned_trophies(&self) -> Vec<EarnedTrophy> {
+        match self
+            .conn
+            .prepare("SELECT hash, name, trophy, certified, date FROM trophy")
+            .and_then(|mut stmt| {
+                stmt.query_map([], |row| {
+                    Ok(EarnedTrophy {
+                        hash: row.get(0)?,
+                        course: row.get::<_, Option<String>>(1)?.unwrap_or_default(),
+                        trophy: row.get::<_, Option<String>>(2)?.filter(|t| !t.is_empty()),
+                        certified: row.get::<_, Option<i32>>(3)?.unwrap_or(0) != 0,
+                        date: row.get::<_, Option<i64>>(4)?.unwrap_or(0),
+                    })
+                })
+                .map(|rows| rows.filter_map(|r| r.ok()).collect::<Vec<_>>())
+            }) {
+            Ok(trophies) => trophies,
+            Err(e) => {
+                log::error!("Exception getting trophies: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    pub fn earned_trophy(&self, hash: &str) -> Option<EarnedTrophy> {
+        self.earned_trophies().into_iter().find(|t| t.hash == hash)
+    }
+
+    /// Timing offset (ms) of a chart, 0 when none is set.
+    pub fn chart_offset(&self, sha256: &str) -> i32 {
+        match self.conn.query_row(
+            "SELECT offset FROM chartoffset WHERE sha256 = ?1",
+            [sha256],
+            |row| row.get::<_, Option<i32>>(0),
+        ) {
+            Ok(offset) => offset.unwrap_or(0),
+            Err(rusqlite::Error::QueryReturnedNoRows) => 0,
+            Err(e) => {
+                log::error!("Exception getting chart offset: {}", e);
+                0
+            }
+        }
+    }
 }
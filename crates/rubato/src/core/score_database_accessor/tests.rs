@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use rusqlite::Connection;
 
+use crate::core::earned_trophy::EarnedTrophy;
 use crate::core::sqlite_database_accessor::{Column, SQLiteDatabaseAccessor, Table};
 use crate::skin::player_data::PlayerData;
 use crate::skin::score_data::ScoreData;
@@ -453,6 +454,23 @@ fn memory_accessor() -> ScoreDatabaseAccessor {
                 Column::new("scorehash", "TEXT"),
             ],
         ),
+        Table::new(
+            "trophy",
+            vec![
+                Column::with_pk("hash", "TEXT", 1, 1),
+                Column::new("name", "TEXT"),
+                Column::new("trophy", "TEXT"),
+                Column::new("certified", "INTEGER"),
+                Column::new("date", "INTEGER"),
+            ],
+        ),
+        Table::new(
+            "chartoffset",
+            vec![
+                Column::with_pk("sha256", "TEXT", 1, 1),
+                Column::new("offset", "INTEGER"),
+            ],
+        ),
     ];
 
     let base = SQLiteDatabaseAccessor::new(tables);
@@ -841,3 +859,68 @@ fn score_data_no_match_returns_none() {
         "score_data must return None for nonexistent hash"
     );
 }
+
+#[test]
+fn read_score_file_reads_valid_scores() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("score.db");
+    let accessor = ScoreDatabaseAccessor::new(db_path.to_str().unwrap()).unwrap();
+    accessor.create_table().expect("create table");
+    let mut valid = ScoreData {
+        sha256: "valid".to_string(),
+        notes: 100,
+        clear: 4,
+        ..Default::default()
+    };
+    valid.judge_counts.epg = 50;
+    let invalid = ScoreData {
+        sha256: "invalid".to_string(),
+        notes: 0,
+        ..Default::default()
+    };
+    accessor.set_score_data_batch(&[&valid, &invalid]);
+    drop(accessor);
+
+    let scores = ScoreDatabaseAccessor::read_score_file(db_path.to_str().unwrap()).unwrap();
+    assert_eq!(scores.len(), 1);
+    assert_eq!(scores[0].sha256, "valid");
+    assert_eq!(scores[0].exscore(), 100);
+
+    assert!(
+        ScoreDatabaseAccessor::read_score_file(dir.path().join("missing.db").to_str().unwrap())
+            .is_err()
+    );
+}
+
+#[test]
+fn earned_trophy_roundtrip_replaces_by_hash() {
+    let accessor = memory_accessor();
+    assert!(accessor.earned_trophies().is_empty());
+    let mut trophy = EarnedTrophy {
+        hash: "ab".repeat(64),
+        course: "sl5".to_string(),
+        trophy: None,
+        certified: true,
+        date: 100,
+    };
+    accessor.set_earned_trophy(&trophy);
+    assert_eq!(accessor.earned_trophy(&trophy.hash), Some(trophy.clone()));
+
+    trophy.trophy = Some("goldmedal".to_string());
+    trophy.date = 200;
+    accessor.set_earned_trophy(&trophy);
+    assert_eq!(accessor.earned_trophies(), vec![trophy]);
+}
+
+#[test]
+fn chart_offset_roundtrip_and_reset() {
+    let accessor = memory_accessor();
+    let sha256 = "cd".repeat(32);
+    assert_eq!(accessor.chart_offset(&sha256), 0);
+    accessor.set_chart_offset(&sha256, -15);
+    assert_eq!(accessor.chart_offset(&sha256), -15);
+    accessor.set_chart_offset(&sha256, 20);
+    assert_eq!(accessor.chart_offset(&sha256), 20);
+    accessor.set_chart_offset(&sha256, 0);
+    assert_eq!(accessor.chart_offset(&sha256), 0);
+}
@@ -0,0 +1,189 @@
+//! Score export for `rubato export-scores`.
+//!
+//! Joins the score database with song titles and writes one row per score,
+//! as CSV or JSON, so a library can be inspected from scripts.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::core::clear_type::ClearType;
+use crate::core::config::Config;
+use crate::core::play_data_accessor::PlayDataAccessor;
+use crate::core::score_data::ScoreData;
+use crate::skin::song_data::SongData;
+use crate::song::song_database_accessor::SongDatabaseAccessor;
+use crate::song::sqlite_song_database_accessor::SQLiteSongDatabaseAccessor;
+
+/// One exported score row.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedScore {
+    pub sha256: String,
+    /// Empty when the chart is not in the song database.
+    pub title: String,
+    pub artist: String,
+    pub lnmode: i32,
+    pub clear: String,
+    pub exscore: i32,
+    pub maxcombo: i32,
+    pub minbp: i32,
+    pub notes: i32,
+    pub playcount: i32,
+    pub clearcount: i32,
+    /// Unix time in seconds of the last update.
+    pub date: i64,
+}
+
+impl ExportedScore {
+    pub fn new(score: &ScoreData, song: Option<&SongData>) -> Self {
+        Self {
+            sha256: score.sha256.clone(),
+            title: song.map(|s| s.metadata.title.clone()).unwrap_or_default(),
+            artist: song.map(|s| s.metadata.artist.clone()).unwrap_or_default(),
+            lnmode: score.mode,
+            clear: format!("{:?}", ClearType::clear_type_by_id(score.clear)),
+            exscore: score.exscore(),
+            maxcombo: score.maxcombo,
+            minbp: score.minbp,
+            notes: score.notes,
+            playcount: score.playcount,
+            clearcount: score.clearcount,
+            date: score.date,
+        }
+    }
+}
+
+/// Pair every score with its song, sorted by title then LN mode.
+pub fn export_scores(scores: &[ScoreData], songs: &[SongData]) -> Vec<ExportedScore> {
+    let by_hash: HashMap<&str, &SongData> = songs
+        .iter()
+        .map(|song| (song.file.sha256.as_str(), song))
+        .collect();
+    let mut rows: Vec<ExportedScore> = scores
+        .iter()
+        .map(|score| ExportedScore::new(score, by_hash.get(score.sha256.as_str()).copied()))
+        .collect();
+    rows.sort_by(|a, b| (&a.title, &a.sha256, a.lnmode).cmp(&(&b.title, &b.sha256, b.lnmode)));
+    rows
+}
+
+/// Read every score of the configured player, with titles from the song database.
+///
+/// Scores are still exported without titles when the song database cannot
+/// be opened.
+pub fn read_library_scores(config: &Config) -> Result<Vec<ExportedScore>> {
+    let scores = PlayDataAccessor::new(config)
+        .read_score_datas_sql("1 = 1")
+        .context("score database is not available")?;
+    let hashes: Vec<String> = scores.iter().map(|s| s.sha256.clone()).collect();
+    let songs = match SQLiteSongDatabaseAccessor::new(&config.paths.songpath, &config.paths.bmsroot)
+    {
+        Ok(db) => db.song_datas_by_hashes(&hashes),
+        Err(e) => {
+            log::warn!("Song database unavailable, exporting without titles: {}", e);
+            Vec::new()
+        }
+    };
+    Ok(export_scores(&scores, &songs))
+}
+
+const CSV_HEADER: &str =
+    "sha256,title,artist,lnmode,clear,exscore,maxcombo,minbp,notes,playcount,clearcount,date";
+
+/// Write `rows` as CSV with a header line.
+pub fn write_csv<W: Write>(rows: &[ExportedScore], mut out: W) -> io::Result<()> {
+    writeln!(out, "{}", CSV_HEADER)?;
+    for row in rows {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{},{},{},{}",
+            row.sha256,
+            csv_field(&row.title),
+            csv_field(&row.artist),
+            row.lnmode,
+            row.clear,
+            row.exscore,
+            row.maxcombo,
+            row.minbp,
+            row.notes,
+            row.playcount,
+            row.clearcount,
+            row.date
+        )?;
+    }
+    Ok(())
+}
+
+/// Quote a field when it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(sha256: &str, clear: ClearType) -> ScoreData {
+        let mut score = ScoreData {
+            sha256: sha256.to_string(),
+            clear: clear.id(),
+            maxcombo: 10,
+            minbp: 1,
+            ..Default::default()
+        };
+        score.judge_counts.epg = 4;
+        score.judge_counts.lgr = 2;
+        score
+    }
+
+    fn song(sha256: &str, title: &str) -> SongData {
+        let mut song = SongData::new();
+        song.file.sha256 = sha256.to_string();
+        song.metadata.set_title(title.to_string());
+        song
+    }
+
+    #[test]
+    fn export_scores_joins_titles_and_sorts() {
+        let scores = [score("b", ClearType::Hard), score("a", ClearType::Failed)];
+        let songs = [song("a", "Zeta"), song("b", "Alpha")];
+
+        let rows = export_scores(&scores, &songs);
+
+        assert_eq!(rows[0].title, "Alpha");
+        assert_eq!(rows[0].clear, "Hard");
+        assert_eq!(rows[0].exscore, 10);
+        assert_eq!(rows[1].title, "Zeta");
+        assert_eq!(rows[1].clear, "Failed");
+
+        let orphan = export_scores(&[score("c", ClearType::Easy)], &songs);
+        assert_eq!(orphan[0].title, "");
+    }
+
+    #[test]
+    fn write_csv_quotes_fields_that_need_it() {
+        let rows = export_scores(
+            &[score("a", ClearType::Max)],
+            &[song("a", "Say \"hi\", world")],
+        );
+        let mut out = Vec::new();
+
+        write_csv(&rows, &mut out).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let mut lines = text.lines();
+        assert_eq!(lines.next(), Some(CSV_HEADER));
+        assert_eq!(
+            lines.next(),
+            Some("a,\"Say \"\"hi\"\", world\",,0,Max,10,10,1,0,0,0,0")
+        );
+        assert_eq!(lines.next(), None);
+    }
+}
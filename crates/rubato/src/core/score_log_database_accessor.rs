@@ -65,6 +65,37 @@ impl ScoreLogDatabaseAccessor {
         }
     }
 
+    /// The `count` most recent score improvements, newest first.
+    pub fn score_logs(&self, count: i32) -> Vec<ScoreLog> {
+        match self
+            .conn
+            .prepare("SELECT * FROM scorelog ORDER BY date DESC, rowid DESC LIMIT ?1")
+            .and_then(|mut stmt| {
+                stmt.query_map([count], |row| {
+                    Ok(ScoreLog {
+                        sha256: row.get("sha256").ok(),
+                        mode: row.get("mode").unwrap_or(0),
+                        clear: row.get("clear").unwrap_or(0),
+                        oldclear: row.get("oldclear").unwrap_or(0),
+                        score: row.get("score").unwrap_or(0),
+                        oldscore: row.get("oldscore").unwrap_or(0),
+                        combo: row.get("combo").unwrap_or(0),
+                        oldcombo: row.get("oldcombo").unwrap_or(0),
+                        minbp: row.get("minbp").unwrap_or(0),
+                        oldminbp: row.get("oldminbp").unwrap_or(0),
+                        date: row.get("date").unwrap_or(0),
+                    })
+                })
+                .map(|rows| rows.filter_map(|r| r.ok()).collect::<Vec<_>>())
+            }) {
+            Ok(logs) => logs,
+            Err(e) => {
+                log::error!("Exception getting score logs: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
     pub fn connection(&self) -> &Connection {
         &self.conn
     }
@@ -149,4 +180,24 @@ mod tests {
             synchronous
         );
     }
+
+    #[test]
+    fn score_logs_are_newest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("test_scorelog.db");
+        let accessor = ScoreLogDatabaseAccessor::new(db_path.to_str().unwrap()).unwrap();
+        for (hash, score, date) in [("a", 100, 10), ("b", 200, 30), ("c", 300, 20)] {
+            let mut log = ScoreLog::new();
+            log.set_sha256(hash);
+            log.score = score;
+            log.date = date;
+            accessor.set_score_log(&log);
+        }
+
+        let logs = accessor.score_logs(2);
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].sha256(), Some("b"));
+        assert_eq!(logs[0].score, 200);
+        assert_eq!(logs[1].sha256(), Some("c"));
+    }
 }
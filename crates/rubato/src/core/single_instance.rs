@@ -0,0 +1,238 @@
+//! Single-instance guard with chart handoff.
+//!
+//! The first instance listens on a local socket. A later launch connects to
+//! it, forwards its `--bms` path and exits, so the running instance opens the
+//! chart instead of a second window coming up.
+
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[cfg(not(unix))]
+use std::net::{TcpListener as Listener, TcpStream as Stream};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener as Listener, UnixStream as Stream};
+
+use crate::skin::sync_utils::lock_or_recover;
+
+/// Environment variable that overrides the instance socket path.
+#[cfg(unix)]
+pub const INSTANCE_SOCKET_ENV: &str = "RUBATO_INSTANCE_SOCKET";
+
+#[cfg(unix)]
+const SOCKET_FILE_NAME: &str = "rubato.sock";
+
+/// Loopback port the first instance listens on where Unix sockets are unavailable.
+#[cfg(not(unix))]
+const INSTANCE_PORT: u16 = 47_819;
+
+/// How long the first instance waits for a connected launch to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Where the first instance listens: a socket path on Unix, a loopback address elsewhere.
+#[cfg(unix)]
+pub type Endpoint = PathBuf;
+#[cfg(not(unix))]
+pub type Endpoint = std::net::SocketAddr;
+
+/// `$RUBATO_INSTANCE_SOCKET`, else `rubato.sock` in `$XDG_RUNTIME_DIR`, else
+/// in the temp directory.
+#[cfg(unix)]
+pub fn endpoint() -> Endpoint {
+    if let Some(path) = std::env::var_os(INSTANCE_SOCKET_ENV).filter(|p| !p.is_empty()) {
+        return PathBuf::from(path);
+    }
+    std::env::var_os("XDG_RUNTIME_DIR")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(std::env::temp_dir)
+        .join(SOCKET_FILE_NAME)
+}
+
+#[cfg(not(unix))]
+pub fn endpoint() -> Endpoint {
+    std::net::SocketAddr::from(([127, 0, 0, 1], INSTANCE_PORT))
+}
+
+/// What a later launch asks the running instance to do.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InstanceRequest {
+    /// Open the chart at this path.
+    Open(PathBuf),
+    /// Only bring the window to the front.
+    Focus,
+}
+
+impl InstanceRequest {
+    fn to_line(&self) -> String {
+        match self {
+            InstanceRequest::Open(path) => format!("open {}\n", path.display()),
+            InstanceRequest::Focus => "focus\n".to_string(),
+        }
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end_matches('\r');
+        if line == "focus" {
+            return Some(InstanceRequest::Focus);
+        }
+        line.strip_prefix("open ")
+            .filter(|path| !path.is_empty())
+            .map(|path| InstanceRequest::Open(PathBuf::from(path)))
+    }
+}
+
+/// Outcome of [`acquire`].
+pub enum Acquired {
+    /// This is the only instance; keep the lock for as long as it runs.
+    Primary(InstanceLock),
+    /// Another instance is running and took the request.
+    Forwarded,
+}
+
+/// Become the running instance, or hand `request` to the one already running.
+pub fn acquire(endpoint: &Endpoint, request: &InstanceRequest) -> std::io::Result<Acquired> {
+    if let Ok(mut stream) = Stream::connect(endpoint) {
+        stream.write_all(request.to_line().as_bytes())?;
+        return Ok(Acquired::Forwarded);
+    }
+    InstanceLock::bind(endpoint).map(Acquired::Primary)
+}
+
+/// Held by the running instance. Collects requests from later launches on a
+/// background thread; dropping it stops listening.
+pub struct InstanceLock {
+    endpoint: Endpoint,
+    requests: Arc<Mutex<Vec<InstanceRequest>>>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl InstanceLock {
+    fn bind(endpoint: &Endpoint) -> std::io::Result<Self> {
+        // A socket file left behind by a crashed instance: nobody answered
+        // the connect in `acquire`, so it is stale.
+        #[cfg(unix)]
+        if endpoint.exists() {
+            std::fs::remove_file(endpoint)?;
+        }
+        let listener = Listener::bind(endpoint)?;
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+        {
+            let requests = Arc::clone(&requests);
+            let shutdown = Arc::clone(&shutdown);
+            std::thread::Builder::new()
+                .name("instance-lock".to_string())
+                .spawn(move || Self::serve(&listener, &requests, &shutdown))?;
+        }
+        Ok(Self {
+            endpoint: endpoint.clone(),
+            requests,
+            shutdown,
+        })
+    }
+
+    fn serve(listener: &Listener, requests: &Mutex<Vec<InstanceRequest>>, shutdown: &AtomicBool) {
+        for stream in listener.incoming() {
+            if shutdown.load(Ordering::SeqCst) {
+                break;
+            }
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    log::warn!("Instance lock accept failed: {}", e);
+                    continue;
+                }
+            };
+            // A launch that connects but never writes must not stall the loop.
+            let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+            for line in BufReader::new(stream).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                match InstanceRequest::parse(&line) {
+                    Some(request) => {
+                        log::info!("Request from another launch: {:?}", request);
+                        lock_or_recover(requests).push(request);
+                    }
+                    None => log::warn!("Ignoring unknown instance request: {}", line),
+                }
+            }
+        }
+    }
+
+    /// Requests received since the last call.
+    pub fn take_requests(&self) -> Vec<InstanceRequest> {
+        std::mem::take(&mut *lock_or_recover(&self.requests))
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        // Wake the accept loop so the detached thread sees the flag and exits.
+        self.shutdown.store(true, Ordering::SeqCst);
+        let _ = Stream::connect(&self.endpoint);
+        #[cfg(unix)]
+        let _ = std::fs::remove_file(&self.endpoint);
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn wait_for_requests(lock: &InstanceLock) -> Vec<InstanceRequest> {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            let requests = lock.take_requests();
+            if !requests.is_empty() || Instant::now() > deadline {
+                return requests;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn second_launch_forwards_its_chart() {
+        let dir = tempfile::tempdir().unwrap();
+        let endpoint = dir.path().join(SOCKET_FILE_NAME);
+        let Acquired::Primary(lock) = acquire(&endpoint, &InstanceRequest::Focus).unwrap() else {
+            panic!("first launch should hold the lock");
+        };
+
+        let chart = PathBuf::from("/songs/a b/chart.bms");
+        let second = acquire(&endpoint, &InstanceRequest::Open(chart.clone())).unwrap();
+        assert!(matches!(second, Acquired::Forwarded));
+        assert_eq!(wait_for_requests(&lock), vec![InstanceRequest::Open(chart)]);
+
+        drop(lock);
+        assert!(!endpoint.exists(), "socket file should be removed");
+    }
+
+    #[test]
+    fn stale_socket_file_is_replaced() {
+        let dir = tempfile::tempdir().unwrap();
+        let endpoint = dir.path().join(SOCKET_FILE_NAME);
+        drop(Listener::bind(&endpoint).unwrap());
+        assert!(endpoint.exists());
+
+        let acquired = acquire(&endpoint, &InstanceRequest::Focus).unwrap();
+        assert!(matches!(acquired, Acquired::Primary(_)));
+    }
+
+    #[test]
+    fn request_lines_round_trip() {
+        for request in [
+            InstanceRequest::Focus,
+            InstanceRequest::Open(PathBuf::from("/songs/chart.bms")),
+        ] {
+            let line = request.to_line();
+            assert_eq!(InstanceRequest::parse(line.trim_end()), Some(request));
+        }
+        assert_eq!(InstanceRequest::parse("open "), None);
+        assert_eq!(InstanceRequest::parse("quit"), None);
+    }
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use rusqlite::{Connection, params};
 
 // SQLite column definition
@@ -191,6 +193,40 @@ impl SQLiteDatabaseAccessor {
         Ok(())
     }
 
+    /// Whether `validate` would change an existing database: add a column,
+    /// or a table next to existing ones. Creating a new database is not an
+    /// upgrade.
+    pub fn needs_upgrade(&self, conn: &Connection) -> anyhow::Result<bool> {
+        let mut any_exists = false;
+        let mut any_missing = false;
+        for table in &self.tables {
+            let existing = table_columns(conn, table.name())?;
+            if existing.is_empty() {
+                any_missing = true;
+                continue;
+            }
+            any_exists = true;
+            if table
+                .columns()
+                .iter()
+                .any(|column| !existing.iter().any(|name| name == column.name()))
+            {
+                return Ok(true);
+            }
+        }
+        Ok(any_exists && any_missing)
+    }
+
+    /// Bring the schema up to date like `validate`. Upgrading an existing
+    /// database first backs it up with `backup_database` and runs in one
+    /// transaction, rolled back on failure.
+    pub fn migrate(&self, conn: &Connection) -> anyhow::Result<()> {
+        if !self.needs_upgrade(conn)? {
+            return self.validate(conn);
+        }
+        upgrade_with_backup(conn, || self.validate(conn))
+    }
+
     /// Insert or replace a row using column values provided by a closure.
     /// The closure maps column name -> rusqlite Value.
     pub fn insert_with_values(
@@ -245,3 +281,138 @@ impl SQLiteDatabaseAccessor {
         &self.tables
     }
 }
+
+fn table_columns(conn: &Connection, table: &str) -> anyhow::Result<Vec<String>> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info('{}');", table))?;
+    let rows = stmt.query_map([], |row| row.get::<_, String>(1))?;
+    Ok(rows.filter_map(|r| r.ok()).collect())
+}
+
+/// Copy the database of `conn` to `<file>.<timestamp>.bak` beside it.
+/// None for in-memory databases.
+pub fn backup_database(conn: &Connection) -> anyhow::Result<Option<PathBuf>> {
+    let Some(path) = conn.path().filter(|p| !p.is_empty()) else {
+        return Ok(None);
+    };
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let backup = PathBuf::from(format!("{path}.{stamp}.bak"));
+    conn.execute("VACUUM INTO ?1", [backup.to_string_lossy()])
+        .map_err(|e| anyhow::anyhow!("Failed to back up {path}: {e}"))?;
+    log::info!(
+        "Backed up {} to {} before a schema upgrade",
+        path,
+        backup.display()
+    );
+    Ok(Some(backup))
+}
+
+/// Run the schema upgrade `upgrade` after backing up the database, in one
+/// immediate transaction. On failure the transaction is rolled back and the
+/// error names the backup, so the user can restore it by hand.
+pub fn upgrade_with_backup(
+    conn: &Connection,
+    upgrade: impl FnOnce() -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let backup = backup_database(conn)?;
+    conn.execute_batch("BEGIN IMMEDIATE")?;
+    match upgrade() {
+        Ok(()) => {
+            conn.execute_batch("COMMIT")?;
+            Ok(())
+        }
+        Err(e) => {
+            let _ = conn.execute_batch("ROLLBACK");
+            Err(match backup {
+                Some(backup) => e.context(format!(
+                    "Schema upgrade failed and was rolled back; backup kept at {}",
+                    backup.display()
+                )),
+                None => e.context("Schema upgrade failed and was rolled back"),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_with(dir: &tempfile::TempDir, sql: &str) -> Connection {
+        let conn = Connection::open(dir.path().join("test.db")).unwrap();
+        conn.execute_batch(sql).unwrap();
+        conn
+    }
+
+    fn backups(dir: &tempfile::TempDir) -> Vec<PathBuf> {
+        std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == "bak"))
+            .collect()
+    }
+
+    #[test]
+    fn new_and_current_databases_are_not_backed_up() {
+        let dir = tempfile::tempdir().unwrap();
+        let conn = open_with(&dir, "");
+        let base = SQLiteDatabaseAccessor::new(vec![Table::new(
+            "player",
+            vec![Column::new("name", "TEXT")],
+        )]);
+        assert!(!base.needs_upgrade(&conn).unwrap());
+        base.migrate(&conn).unwrap();
+        assert!(!base.needs_upgrade(&conn).unwrap());
+        base.migrate(&conn).unwrap();
+        assert!(backups(&dir).is_empty());
+    }
+
+    #[test]
+    fn upgrade_backs_up_the_old_schema_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let conn = open_with(
+            &dir,
+            "CREATE TABLE player (name TEXT); INSERT INTO player VALUES ('p1');",
+        );
+        let base = SQLiteDatabaseAccessor::new(vec![Table::new(
+            "player",
+            vec![Column::new("name", "TEXT"), Column::new("clear", "INTEGER")],
+        )]);
+        assert!(base.needs_upgrade(&conn).unwrap());
+        base.migrate(&conn).unwrap();
+
+        assert_eq!(table_columns(&conn, "player").unwrap(), ["name", "clear"]);
+        let backups = backups(&dir);
+        assert_eq!(backups.len(), 1);
+        let old = Connection::open(&backups[0]).unwrap();
+        assert_eq!(table_columns(&old, "player").unwrap(), ["name"]);
+        let name: String = old
+            .query_row("SELECT name FROM player", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(name, "p1");
+    }
+
+    #[test]
+    fn failed_upgrade_is_rolled_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let conn = open_with(
+            &dir,
+            "CREATE TABLE a (x TEXT); CREATE TABLE b (x TEXT); INSERT INTO b VALUES ('row');",
+        );
+        // SQLite cannot add a NOT NULL column without a default to a non-empty table
+        let base = SQLiteDatabaseAccessor::new(vec![
+            Table::new(
+                "a",
+                vec![Column::new("x", "TEXT"), Column::new("y", "TEXT")],
+            ),
+            Table::new(
+                "b",
+                vec![Column::new("x", "TEXT"), Column::with_pk("y", "TEXT", 1, 0)],
+            ),
+        ]);
+        let err = base.migrate(&conn).unwrap_err();
+
+        assert!(format!("{err}").contains("backup kept at"), "{err}");
+        assert_eq!(table_columns(&conn, "a").unwrap(), ["x"]);
+        assert_eq!(backups(&dir).len(), 1);
+    }
+}
@@ -1,12 +1,18 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use anyhow::{Context, Result, bail};
 use sha2::{Digest, Sha256};
 
 use crate::core::table_data::TableData;
-use crate::core::table_data_bridge::difficulty_table_to_table_data;
+use crate::core::table_data_bridge::{
+    difficulty_table_to_table_data, table_data_to_difficulty_table,
+};
 use crate::core::validatable::Validatable;
 
+/// File name prefix of tables created locally with the table editor.
+pub const CUSTOM_TABLE_PREFIX: &str = "custom_";
+
 /// Table data accessor.
 /// Translated from Java: TableDataAccessor
 pub struct TableDataAccessor {
@@ -169,6 +175,54 @@ impl TableDataAccessor {
         TableData::read_from_path(&path)
     }
 
+    /// Path a locally created table named `name` is stored under.
+    pub fn custom_table_path(&self, name: &str) -> PathBuf {
+        PathBuf::from(&self.tabledir).join(format!(
+            "{}{}.bmt",
+            CUSTOM_TABLE_PREFIX,
+            Self::get_file_name(name)
+        ))
+    }
+
+    /// Locally created tables as (path, table name), sorted by name.
+    pub fn custom_tables(&self) -> Vec<(PathBuf, String)> {
+        let Ok(entries) = fs::read_dir(&self.tabledir) else {
+            return Vec::new();
+        };
+        let mut tables: Vec<(PathBuf, String)> = entries
+            .flatten()
+            .filter(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                name.starts_with(CUSTOM_TABLE_PREFIX) && name.ends_with(".bmt")
+            })
+            .filter_map(|entry| {
+                let path = entry.path();
+                TableData::read_from_path(&path).map(|td| (path, td.name))
+            })
+            .collect();
+        tables.sort_by(|a, b| a.1.cmp(&b.1));
+        tables
+    }
+
+    /// Export a table as `header.json` and `data.json` in the standard
+    /// difficulty table format, ready to be published under `dir`.
+    pub fn export_json(td: &TableData, dir: &Path) -> Result<()> {
+        if td.tag.is_empty() {
+            bail!("table symbol is empty");
+        }
+        fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create directory: {}", dir.display()))?;
+        let header = dir.join("header.json");
+        let data = dir.join("data.json");
+        let mut dt = table_data_to_difficulty_table(td);
+        bms::table::difficulty_table_parser::DifficultyTableParser::new()
+            .encode_json_table_data(&mut dt, &header, &data);
+        if !header.exists() || !data.exists() {
+            bail!("failed to write table JSON to {}", dir.display());
+        }
+        Ok(())
+    }
+
     fn get_file_name(name: &str) -> String {
         let mut hasher = Sha256::new();
         hasher.update(name.as_bytes());
@@ -419,6 +473,56 @@ mod tests {
         assert_eq!(read_back.unwrap().name, "Custom Table");
     }
 
+    #[test]
+    fn test_custom_table_store_list_and_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let accessor = TableDataAccessor::new(dir.path().to_str().unwrap());
+
+        let td = TableData {
+            name: "My Table".to_string(),
+            tag: "M".to_string(),
+            folder: vec![TableFolder {
+                name: Some("M3".to_string()),
+                songs: vec![{
+                    let mut s = crate::skin::SongData::new();
+                    s.file.sha256 = "b".repeat(64);
+                    s.metadata.title = "Owned Song".to_string();
+                    s
+                }],
+            }],
+            ..Default::default()
+        };
+        let path = accessor.custom_table_path(&td.name);
+        TableData::write_to_path(&path, &td).unwrap();
+        accessor.write_with_filename(&mut td.clone(), "other.bmt");
+
+        assert_eq!(
+            accessor.custom_tables(),
+            vec![(path.clone(), "My Table".to_string())]
+        );
+
+        let out = dir.path().join("export");
+        TableDataAccessor::export_json(&td, &out).unwrap();
+        let parser = bms::table::difficulty_table_parser::DifficultyTableParser::new();
+        let mut dt = bms::table::difficulty_table::DifficultyTable::new();
+        parser
+            .decode_json_table_header_from_file(&mut dt, &out.join("header.json"))
+            .unwrap();
+        assert_eq!(dt.table.name(), Some("My Table"));
+        assert_eq!(dt.table.data_url, vec!["data.json".to_string()]);
+        parser
+            .decode_json_table_data_from_file(&mut dt, &out.join("data.json"))
+            .unwrap();
+        assert_eq!(dt.elements().len(), 1);
+        assert_eq!(dt.elements()[0].level, "3");
+
+        let unnamed = TableData {
+            tag: String::new(),
+            ..td
+        };
+        assert!(TableDataAccessor::export_json(&unnamed, &out).is_err());
+    }
+
     #[test]
     fn test_read_local_table_names() {
         let dir = tempfile::tempdir().unwrap();
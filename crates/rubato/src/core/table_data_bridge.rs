@@ -2,7 +2,7 @@
 //!
 //! Translates: TableDataAccessor.toSongData (Java)
 //! Converts BmsTableElement / DifficultyTableElement → SongData,
-//! and DifficultyTable → TableData.
+//! and DifficultyTable → TableData (and back, for exporting local tables).
 
 use bms::model::mode::Mode;
 use bms::table::bms_table_element::BmsTableElement;
@@ -160,6 +160,83 @@ pub fn difficulty_table_to_table_data(dt: &DifficultyTable, url: &str) -> TableD
     }
 }
 
+/// Convert a SongData to a BmsTableElement for table export.
+fn song_data_to_bms_table_element(song: &SongData) -> BmsTableElement {
+    let mut te = BmsTableElement::new();
+    if !song.file.md5.is_empty() {
+        te.set_md5(&song.file.md5);
+    }
+    if !song.file.sha256.is_empty() {
+        te.set_sha256(&song.file.sha256);
+    }
+    te.set_title(&song.metadata.full_title());
+    if !song.metadata.artist.is_empty() {
+        te.set_artist(&song.metadata.artist);
+    }
+    if let Some(url) = song.url.as_deref() {
+        te.set_url(url);
+    }
+    te
+}
+
+/// Convert a beatoraja-types CourseData to a bms-table Course.
+fn course_data_to_course(cd: &CourseData) -> Course {
+    let mut course = Course::new();
+    course.set_name(cd.name());
+    course.charts = cd.hash.iter().map(song_data_to_bms_table_element).collect();
+    course.constraint = cd
+        .constraint
+        .iter()
+        .map(|c| c.name_str().to_string())
+        .collect();
+    course.trophy = cd
+        .trophy
+        .iter()
+        .map(|t| {
+            let mut trophy = Trophy::new();
+            trophy.set_name(t.name());
+            trophy.missrate = t.missrate as f64;
+            trophy.scorerate = t.scorerate as f64;
+            trophy
+        })
+        .collect();
+    course
+}
+
+/// Convert a TableData to a DifficultyTable.
+///
+/// Inverse of [`difficulty_table_to_table_data`]: one level per folder, with
+/// the table tag stripped from folder names to recover the level.
+pub fn table_data_to_difficulty_table(td: &TableData) -> DifficultyTable {
+    let mut dt = DifficultyTable::new();
+    dt.table.set_name(&td.name);
+    dt.table.set_id(&td.tag);
+    dt.table.set_tag(&td.tag);
+
+    let mut levels: Vec<String> = Vec::with_capacity(td.folder.len());
+    for folder in &td.folder {
+        let level = folder
+            .name()
+            .strip_prefix(td.tag.as_str())
+            .filter(|lv| !td.tag.is_empty() && !lv.is_empty())
+            .unwrap_or(folder.name())
+            .to_string();
+        for song in &folder.songs {
+            let mut dte = DifficultyTableElement::new();
+            dte.element = song_data_to_bms_table_element(song);
+            dte.set_level(Some(&level));
+            dt.table.add_element(dte);
+        }
+        levels.push(level);
+    }
+    dt.set_level_description(&levels);
+
+    if !td.course.is_empty() {
+        dt.course = vec![td.course.iter().map(course_data_to_course).collect()];
+    }
+    dt
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -498,4 +575,44 @@ mod tests {
         assert_eq!(cd.constraint[0], CourseDataConstraint::Mirror);
         assert_eq!(cd.constraint[1], CourseDataConstraint::GaugeLr2);
     }
+
+    #[test]
+    fn test_table_data_to_difficulty_table_round_trip() {
+        let mut song = SongData::new();
+        song.metadata.title = "Song A".to_string();
+        song.file.md5 = "aaaa".to_string();
+        let mut course = CourseData::default();
+        course.set_name("Dan 1".to_string());
+        course.hash = vec![song.clone()];
+        course.constraint = vec![CourseDataConstraint::Class];
+        let td = TableData {
+            name: "My Table".to_string(),
+            tag: "\u{2606}".to_string(),
+            folder: vec![
+                TableFolder {
+                    name: Some("\u{2606}1".to_string()),
+                    songs: vec![song],
+                },
+                TableFolder {
+                    name: Some("extra".to_string()),
+                    songs: Vec::new(),
+                },
+            ],
+            course: vec![course],
+            ..Default::default()
+        };
+
+        let dt = table_data_to_difficulty_table(&td);
+        assert_eq!(dt.table.id(), Some("\u{2606}"));
+        assert_eq!(dt.level_description(), vec!["1", "extra"]);
+        assert_eq!(dt.elements().len(), 1);
+        assert_eq!(dt.elements()[0].level, "1");
+        assert_eq!(dt.course()[0][0].constraint(), &["grade"]);
+
+        let back = difficulty_table_to_table_data(&dt, "");
+        assert_eq!(back.name, "My Table");
+        assert_eq!(back.folder[0].name(), "\u{2606}1");
+        assert_eq!(back.folder[0].songs[0].file.md5, "aaaa");
+        assert_eq!(back.course[0].name(), "Dan 1");
+    }
 }
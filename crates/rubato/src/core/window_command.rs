@@ -5,6 +5,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 
 static FULLSCREEN_TOGGLE: AtomicBool = AtomicBool::new(false);
 static SCREENSHOT_REQUEST: AtomicBool = AtomicBool::new(false);
+static FOCUS_REQUEST: AtomicBool = AtomicBool::new(false);
 
 /// Request a fullscreen toggle (called by MainController on F4 press).
 pub fn request_fullscreen_toggle() {
@@ -27,3 +28,15 @@ pub fn request_screenshot() {
 pub fn take_screenshot_request() -> bool {
     SCREENSHOT_REQUEST.swap(false, Ordering::AcqRel)
 }
+
+/// Request that the window be brought to the front (called by MainController
+/// when another launch hands over a request).
+pub fn request_focus() {
+    FOCUS_REQUEST.store(true, Ordering::Release);
+}
+
+/// Consume the focus request (called by the app shell).
+/// Returns true if focus was requested since the last call.
+pub fn take_focus_request() -> bool {
+    FOCUS_REQUEST.swap(false, Ordering::AcqRel)
+}
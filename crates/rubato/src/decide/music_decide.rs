@@ -7,12 +7,16 @@ use crate::core::system_sound_manager::SoundType;
 use crate::core::timer_manager::TimerManager;
 use crate::skin::property_snapshot::PropertySnapshot;
 use crate::skin::skin_action_queue::SkinActionQueue;
-use crate::skin::skin_property::{TIMER_FADEOUT, TIMER_STARTINPUT};
+use crate::skin::skin_property::{
+    FLOAT_LOADING_PROGRESS, NUMBER_LOADING_PROGRESS, OPTION_LOADED, OPTION_NOW_LOADING,
+    STRING_LOADING_FILE, TIMER_FADEOUT, TIMER_STARTINPUT,
+};
 use crate::skin::skin_type::SkinType;
 use crate::skin::timer_id::TimerId;
 
 use super::ControlKeys;
 use crate::core::player_resource::PlayerResource as CorePlayerResource;
+use crate::play::bga::bga_image_preloader::BgaImagePreloader;
 use crate::skin::player_resource_access::{ConfigAccess, PlayerStateAccess};
 
 /// Render context adapter for decide screen skin rendering.
@@ -1148,6 +1152,12 @@ pub struct MusicDecide {
     pending_audio_path_stops: Vec<String>,
     /// Outbox: pending audio config update.
     pending_audio_config: Option<crate::skin::audio_config::AudioConfig>,
+    /// Background decoding of the chart's BGA images, started in create().
+    bga_preloader: Option<BgaImagePreloader>,
+    /// Keysound loading progress (0.0-1.0), pushed by MainController each frame.
+    audio_progress: f32,
+    /// Decide key pressed before loading finished; fade out once it does.
+    decide_requested: bool,
 }
 
 impl MusicDecide {
@@ -1172,11 +1182,51 @@ impl MusicDecide {
             pending_audio_path_plays: Vec::new(),
             pending_audio_path_stops: Vec::new(),
             pending_audio_config: None,
+            bga_preloader: None,
+            audio_progress: 1.0,
+            decide_requested: false,
         }
     }
 }
 
 impl MusicDecide {
+    /// Combined keysound and BGA loading progress (0.0-1.0).
+    fn loading_progress(&self) -> f32 {
+        match self.bga_preloader {
+            Some(ref bga) => (self.audio_progress + bga.preload().progress()) / 2.0,
+            None => self.audio_progress,
+        }
+    }
+
+    fn is_loaded(&self) -> bool {
+        self.audio_progress >= 1.0
+            && self
+                .bga_preloader
+                .as_ref()
+                .is_none_or(|bga| bga.preload().is_finished())
+    }
+
+    /// Name of the file being loaded, empty when only keysounds are left.
+    fn loading_file(&self) -> String {
+        self.bga_preloader
+            .as_ref()
+            .map(|bga| bga.preload().current_file())
+            .unwrap_or_default()
+    }
+
+    /// Whether the screen should start fading out into Play.
+    /// Waits for loading and the minimum duration; the maximum duration
+    /// (when set) moves on regardless and leaves the rest to Play's preload.
+    fn should_start_fadeout(&self, nowtime: i64, scene_time: i64) -> bool {
+        let max_duration = self.config.select.decide_max_duration as i64;
+        if max_duration > 0 && nowtime > max_duration {
+            return true;
+        }
+        let min_duration = self.config.select.decide_min_duration as i64;
+        self.is_loaded()
+            && (self.decide_requested || (nowtime > scene_time && nowtime >= min_duration))
+    }
+
     /// Build a PropertySnapshot capturing all raw data needed for skin rendering.
     fn build_snapshot(&self, timer: &TimerManager) -> PropertySnapshot {
         let mut s = PropertySnapshot::new();
@@ -1238,11 +1288,23 @@ impl MusicDecide {
 
         // Player / course data
         s.player_data = Some(*self.resource.player_data());
+        s.today_player_data = Some(*self.resource.today_player_data());
         s.is_course_mode = self.resource.course_data().is_some();
         s.course_index = self.resource.course_index();
         s.course_song_count = self.resource.course_data().map_or(0, |cd| cd.hash.len());
         s.is_update_score = self.resource.is_update_score();
 
+        // Loading state
+        let progress = self.loading_progress();
+        let loaded = self.is_loaded();
+        s.integers
+            .insert(NUMBER_LOADING_PROGRESS, (progress * 100.0) as i32);
+        s.floats.insert(FLOAT_LOADING_PROGRESS, progress);
+        s.booleans.insert(OPTION_NOW_LOADING, !loaded);
+        s.booleans.insert(OPTION_LOADED, loaded);
+        s.strings.insert(STRING_LOADING_FILE, self.loading_file());
+        s.is_media_load_finished = loaded;
+
         // Offsets
         s.offsets = self.data.offsets.clone();
 
@@ -1343,6 +1405,22 @@ impl MainState for MusicDecide {
     fn create(&mut self) {
         self.cancel = false;
 
+        // Decode BGA images while the skin loads and the screen is shown;
+        // keysounds are started by MainController through bms_model() before
+        // create(), so all three overlap.
+        self.decide_requested = false;
+        self.bga_preloader = None;
+        self.resource.set_bga_preload(None);
+        if self.resource.is_bga_on()
+            && let Some(model) = self.resource.bms_model()
+        {
+            let preloader =
+                BgaImagePreloader::start(model, self.config.render.texture_budget_bytes());
+            self.resource
+                .set_bga_preload(Some(std::sync::Arc::clone(preloader.preload())));
+            self.bga_preloader = Some(preloader);
+        }
+
         // loadSkin(SkinType.DECIDE)
         // Skip loading if a cached skin was injected by MainController.
         if self.data.skin.is_none() {
@@ -1489,7 +1567,7 @@ impl MainState for MusicDecide {
                     MainStateType::Play
                 });
             }
-        } else if nowtime > scene_time {
+        } else if self.should_start_fadeout(nowtime, scene_time) {
             self.data.timer.set_timer_on(TIMER_FADEOUT);
         }
     }
@@ -1532,7 +1610,7 @@ impl MainState for MusicDecide {
                     MainStateType::Play
                 });
             }
-        } else if nowtime > scene_time {
+        } else if self.should_start_fadeout(nowtime, scene_time) {
             self.data.timer.set_timer_on(TIMER_FADEOUT);
         }
 
@@ -1560,12 +1638,19 @@ impl MainState for MusicDecide {
                 .unwrap_or(false)
                 || (snapshot.start_pressed && snapshot.select_pressed);
             if decide {
-                self.data.timer.set_timer_on(TIMER_FADEOUT);
+                if self.is_loaded() {
+                    self.data.timer.set_timer_on(TIMER_FADEOUT);
+                } else {
+                    self.decide_requested = true;
+                }
             }
             if cancel {
                 self.cancel = true;
                 ctx.set_global_pitch(1f32);
                 self.data.timer.set_timer_on(TIMER_FADEOUT);
+                // Stop decoding BGA for a chart that will not be played
+                self.bga_preloader = None;
+                self.resource.set_bga_preload(None);
             }
         }
     }
@@ -1602,6 +1687,18 @@ impl MainState for MusicDecide {
         self.data.skin = None;
     }
 
+    fn update_loading_progress(&mut self, audio_progress: f32, _bga_on: bool) {
+        self.audio_progress = audio_progress;
+    }
+
+    fn bms_model(&self) -> Option<&bms::model::bms_model::BMSModel> {
+        self.resource.bms_model()
+    }
+
+    fn player_resource(&self) -> Option<&CorePlayerResource> {
+        Some(&self.resource)
+    }
+
     fn take_player_resource(&mut self) -> Option<CorePlayerResource> {
         // Replace with a default resource; the taken resource is returned to MainController.
         let old = std::mem::replace(
@@ -2040,6 +2137,64 @@ mod tests {
         assert!(decide.cancel);
     }
 
+    #[test]
+    fn test_render_waits_for_loading_before_fadeout() {
+        let mut decide = make_decide();
+        decide.data.skin = Some(Box::new(MockSkin::with_values(0, -1, 0)));
+        decide.update_loading_progress(0.5, false);
+        decide.render();
+        assert!(!decide.data.timer.is_timer_on(TIMER_FADEOUT));
+
+        decide.update_loading_progress(1.0, false);
+        decide.render();
+        assert!(decide.data.timer.is_timer_on(TIMER_FADEOUT));
+    }
+
+    #[test]
+    fn test_render_honors_min_and_max_decide_duration() {
+        let mut decide = make_decide();
+        decide.config.select.decide_min_duration = 2000;
+        decide.config.select.decide_max_duration = 5000;
+        decide.data.skin = Some(Box::new(MockSkin::with_values(0, -1, 0)));
+        decide.data.timer.set_now_micro_time(1_000_000);
+        decide.render();
+        assert!(!decide.data.timer.is_timer_on(TIMER_FADEOUT));
+
+        // Loading is still running past the maximum: move on anyway
+        decide.update_loading_progress(0.2, false);
+        decide.data.timer.set_now_micro_time(5_001_000);
+        decide.render();
+        assert!(decide.data.timer.is_timer_on(TIMER_FADEOUT));
+    }
+
+    #[test]
+    fn test_input_decide_key_while_loading_waits_for_loading() {
+        let mut decide = make_decide();
+        let mut ctx = make_game_context();
+        decide.data.skin = Some(Box::new(MockSkin::with_values(0, i32::MAX, 0)));
+        decide.data.timer.set_timer_on(TIMER_STARTINPUT);
+        decide.update_loading_progress(0.5, false);
+        let mut snapshot = crate::input::input_snapshot::InputSnapshot::default();
+        snapshot.key_state[0] = true;
+        decide.input_snapshot = Some(snapshot);
+        decide.input_with_game_context(&mut ctx);
+        assert!(!decide.data.timer.is_timer_on(TIMER_FADEOUT));
+
+        decide.update_loading_progress(1.0, false);
+        decide.render();
+        assert!(decide.data.timer.is_timer_on(TIMER_FADEOUT));
+    }
+
+    #[test]
+    fn test_snapshot_exposes_loading_progress() {
+        let mut decide = make_decide();
+        decide.update_loading_progress(0.25, false);
+        let snapshot = decide.build_snapshot(&decide.data.timer);
+        assert_eq!(snapshot.integers.get(&NUMBER_LOADING_PROGRESS), Some(&25));
+        assert_eq!(snapshot.booleans.get(&OPTION_NOW_LOADING), Some(&true));
+        assert!(!snapshot.is_media_load_finished);
+    }
+
     #[test]
     fn test_input_with_game_context_during_fadeout_no_action() {
         let mut decide = make_decide();
@@ -70,6 +70,43 @@ pub struct BMSSearchAccessor {
 static API_STRING: &str =
     "https://api.bmssearch.net/v1/bmses/search?orderBy=PUBLISHED&orderDirection=DESC&limit=20";
 
+static API_BASE: &str = "https://api.bmssearch.net/v1";
+
+static SITE_BASE: &str = "https://bmssearch.net";
+
+/// The package a chart hash belongs to, as resolved by BMS Search.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BMSSearchPackage {
+    pub title: String,
+    /// The package's page on bmssearch.net.
+    pub page_url: String,
+    /// First download link listed for the package, if any.
+    pub download_url: Option<String>,
+}
+
+impl BMSSearchPackage {
+    fn from_element(element: &BMSSearchElement) -> Option<Self> {
+        let id = element.id.as_deref().filter(|id| !id.is_empty())?;
+        let download_url = element
+            .downloads
+            .iter()
+            .flatten()
+            .filter_map(|d| d.url.as_deref())
+            .find(|url| url.starts_with("http://") || url.starts_with("https://"))
+            .map(str::to_string);
+        Some(Self {
+            title: element.title.clone().unwrap_or_default(),
+            page_url: format!("{}/bmses/{}", SITE_BASE, id),
+            download_url,
+        })
+    }
+
+    /// The url to send the player to: the direct download when known, else the package page.
+    pub fn browser_url(&self) -> &str {
+        self.download_url.as_deref().unwrap_or(&self.page_url)
+    }
+}
+
 impl BMSSearchAccessor {
     pub fn new(tabledir: &str) -> Self {
         Self {
@@ -158,6 +195,44 @@ impl BMSSearchAccessor {
         let patterns: Vec<BMSPatterns> = serde_json::from_slice(&bytes)?;
         Ok(patterns)
     }
+
+    /// Resolve a chart hash (md5 or sha256) to the package containing it.
+    /// Returns None when BMS Search does not know the chart.
+    pub fn resolve_package(hash: &str) -> anyhow::Result<Option<BMSSearchPackage>> {
+        const MAX_RESPONSE_BYTES: u64 = 1024 * 1024; // 1 MB
+
+        if hash.is_empty() || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            bail!("Invalid chart hash: {}", hash);
+        }
+        let client = Self::http_client()?;
+        let response = client
+            .get(format!("{}/patterns/{}", API_BASE, hash))
+            .send()?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response = response.error_for_status()?;
+        let bytes = read_response_bytes_limited(response, MAX_RESPONSE_BYTES)?;
+        let Some(bms_id) = Self::parse_pattern_bms_id(&bytes)? else {
+            return Ok(None);
+        };
+
+        let response = client
+            .get(format!("{}/bmses/{}", API_BASE, bms_id))
+            .send()?
+            .error_for_status()?;
+        let bytes = read_response_bytes_limited(response, MAX_RESPONSE_BYTES)?;
+        let element: BMSSearchElement = serde_json::from_slice(&bytes)?;
+        Ok(BMSSearchPackage::from_element(&element))
+    }
+
+    fn parse_pattern_bms_id(bytes: &[u8]) -> anyhow::Result<Option<String>> {
+        let pattern: BMSPatterns = serde_json::from_slice(bytes)?;
+        Ok(pattern
+            .bms
+            .and_then(|bms| bms.id)
+            .filter(|id| !id.is_empty()))
+    }
 }
 
 impl TableAccessor for BMSSearchAccessor {
@@ -203,6 +278,16 @@ pub struct Downloads {
 pub struct BMSPatterns {
     #[serde(default)]
     pub file: Option<BMSPatternsFile>,
+    /// The package the pattern belongs to.
+    #[serde(default)]
+    pub bms: Option<BMSPatternsBms>,
+}
+
+/// Package reference inside a pattern
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct BMSPatternsBms {
+    #[serde(default)]
+    pub id: Option<String>,
 }
 
 /// BMS patterns file with hash
@@ -211,3 +296,59 @@ pub struct BMSPatternsFile {
     #[serde(default, rename = "hashMd5")]
     pub hash_md5: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pattern_bms_id_reads_package_reference() {
+        let json = br#"{"file":{"hashMd5":"abc"},"bms":{"id":"pkg-1","title":"Song"}}"#;
+        assert_eq!(
+            BMSSearchAccessor::parse_pattern_bms_id(json).unwrap(),
+            Some("pkg-1".to_string())
+        );
+        assert_eq!(
+            BMSSearchAccessor::parse_pattern_bms_id(br#"{"file":{}}"#).unwrap(),
+            None
+        );
+        assert!(BMSSearchAccessor::parse_pattern_bms_id(b"not json").is_err());
+    }
+
+    #[test]
+    fn package_prefers_first_http_download() {
+        let element: BMSSearchElement = serde_json::from_str(
+            r#"{"id":"pkg-1","title":"Song","downloads":[
+                {"url":"magnet:?xt=urn"},
+                {"url":"https://example.com/song.zip","description":"main"}
+            ]}"#,
+        )
+        .unwrap();
+        let package = BMSSearchPackage::from_element(&element).unwrap();
+        assert_eq!(package.title, "Song");
+        assert_eq!(package.page_url, "https://bmssearch.net/bmses/pkg-1");
+        assert_eq!(
+            package.download_url.as_deref(),
+            Some("https://example.com/song.zip")
+        );
+        assert_eq!(package.browser_url(), "https://example.com/song.zip");
+    }
+
+    #[test]
+    fn package_without_downloads_falls_back_to_page() {
+        let element: BMSSearchElement =
+            serde_json::from_str(r#"{"id":"pkg-2","title":"Song"}"#).unwrap();
+        let package = BMSSearchPackage::from_element(&element).unwrap();
+        assert_eq!(package.download_url, None);
+        assert_eq!(package.browser_url(), "https://bmssearch.net/bmses/pkg-2");
+
+        let no_id: BMSSearchElement = serde_json::from_str(r#"{"title":"Song"}"#).unwrap();
+        assert!(BMSSearchPackage::from_element(&no_id).is_none());
+    }
+
+    #[test]
+    fn resolve_package_rejects_non_hash_input() {
+        assert!(BMSSearchAccessor::resolve_package("").is_err());
+        assert!(BMSSearchAccessor::resolve_package("../bmses").is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::external::discord_rpc::rich_presence::{RichPresence, RichPresenceData};
 
-use crate::skin::app_event::{AppEvent, StateChangedData};
+use crate::skin::app_event::{AppEvent, SongInfo, StateChangedData};
 use crate::skin::screen_type::ScreenType;
 
 static APPLICATION_ID: &str = "1054234988167561277";
@@ -34,8 +34,10 @@ impl DiscordListener {
     ///
     /// The caller should register `app_event_sender` with `MainController::add_event_sender()`.
     /// The listener must be kept alive (not dropped) for the background threads to run.
-    pub fn new() -> (std::sync::mpsc::SyncSender<AppEvent>, Self) {
-        match Self::try_connect() {
+    ///
+    /// `hide_song_details` mirrors `IntegrationConfig::discord_hide_song_details`.
+    pub fn new(hide_song_details: bool) -> (std::sync::mpsc::SyncSender<AppEvent>, Self) {
+        match Self::try_connect(hide_song_details) {
             Ok((app_tx, listener)) => {
                 log::info!("Discord RPC Ready!");
                 (app_tx, listener)
@@ -56,7 +58,9 @@ impl DiscordListener {
         }
     }
 
-    fn try_connect() -> anyhow::Result<(std::sync::mpsc::SyncSender<AppEvent>, Self)> {
+    fn try_connect(
+        hide_song_details: bool,
+    ) -> anyhow::Result<(std::sync::mpsc::SyncSender<AppEvent>, Self)> {
         let mut rp = RichPresence::new(APPLICATION_ID.to_string());
         rp.connect()?;
 
@@ -74,7 +78,7 @@ impl DiscordListener {
         let bridge_handle = std::thread::Builder::new()
             .name("discord-bridge".to_string())
             .spawn(move || {
-                Self::bridge_loop(app_rx, ipc_tx_clone);
+                Self::bridge_loop(app_rx, ipc_tx_clone, hide_song_details);
             })?;
 
         Ok((
@@ -92,6 +96,7 @@ impl DiscordListener {
     fn bridge_loop(
         rx: std::sync::mpsc::Receiver<AppEvent>,
         ipc_tx: std::sync::mpsc::SyncSender<DiscordCommand>,
+        hide_song_details: bool,
     ) {
         let mut start_timestamp: i64 = 0;
         let mut last_screen_type: Option<ScreenType> = None;
@@ -99,9 +104,12 @@ impl DiscordListener {
         loop {
             match rx.recv() {
                 Ok(AppEvent::StateChanged(data)) => {
-                    if let Some(rp_data) =
-                        Self::build_presence(&data, &mut start_timestamp, &mut last_screen_type)
-                    {
+                    if let Some(rp_data) = Self::build_presence(
+                        &data,
+                        &mut start_timestamp,
+                        &mut last_screen_type,
+                        hide_song_details,
+                    ) {
                         let _ = ipc_tx.try_send(DiscordCommand::Update(Box::new(rp_data)));
                     }
                 }
@@ -119,25 +127,30 @@ impl DiscordListener {
 
     /// Build a `RichPresenceData` from a `StateChangedData` snapshot.
     /// Returns `None` if the screen type is not relevant for Discord display.
+    ///
+    /// With `hide_song_details` the title, artist and chart difficulty are
+    /// left out; the screen, key mode, gauge and clear are still shown.
     fn build_presence(
         data: &StateChangedData,
         start_timestamp: &mut i64,
         last_screen_type: &mut Option<ScreenType>,
+        hide_song_details: bool,
     ) -> Option<RichPresenceData> {
         let screen_type = data.screen_type;
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as i64;
 
         // Capture start_timestamp once when the activity (screen) changes
         if *last_screen_type != Some(screen_type) {
             *last_screen_type = Some(screen_type);
-            *start_timestamp = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs() as i64;
+            *start_timestamp = now / 1000;
         }
 
-        let mut rp_data = RichPresenceData::new()
-            .set_start_timestamp(*start_timestamp)
-            .set_large_image("bms".to_string(), String::new());
+        let mut rp_data = RichPresenceData::new().set_large_image("bms".to_string(), String::new());
+        let song_info = data.song_info.as_ref();
+        let details = song_info.filter(|_| !hide_song_details).map(song_details);
 
         match screen_type {
             ScreenType::MusicSelector => {
@@ -147,26 +160,49 @@ impl DiscordListener {
                 rp_data = rp_data.set_state("Decide Screen".to_string());
             }
             ScreenType::BMSPlayer => {
-                if let Some(ref song_info) = data.song_info {
-                    let full_title = if song_info.subtitle.is_empty() {
-                        song_info.title.clone()
-                    } else {
-                        format!("{} {}", song_info.title, song_info.subtitle)
-                    };
-                    rp_data = rp_data.set_details(format!("{} / {}", full_title, song_info.artist));
-                    rp_data = rp_data.set_state(format!("Playing: {}Keys", song_info.mode));
+                if let Some(song_info) = song_info {
+                    let mut state = format!("Playing: {}Keys", song_info.mode);
+                    if !hide_song_details && let Some(chart) = chart_label(song_info) {
+                        state = format!("{} {}", state, chart);
+                    }
+                    if let Some(gauge) = data.gauge_type.and_then(gauge_name) {
+                        state = format!("{} / {}", state, gauge);
+                    }
+                    rp_data = rp_data.set_state(state);
+                    if let Some(details) = details {
+                        rp_data = rp_data.set_details(details);
+                    }
+                    // Count from the first note once TIMER_PLAY is running.
+                    if let Some(elapsed) = data.play_elapsed_ms {
+                        let start = now - elapsed;
+                        rp_data = rp_data.set_start_timestamp(start / 1000);
+                        if song_info.length_ms > 0 {
+                            rp_data =
+                                rp_data.set_end_timestamp((start + song_info.length_ms) / 1000);
+                        }
+                        return Some(rp_data);
+                    }
                 }
             }
-            ScreenType::MusicResult => {
-                rp_data = rp_data.set_state("Result Screen".to_string());
-            }
-            ScreenType::CourseResult => {
-                rp_data = rp_data.set_state("Course Result Screen".to_string());
+            ScreenType::MusicResult | ScreenType::CourseResult => {
+                let label = if screen_type == ScreenType::MusicResult {
+                    "Result Screen"
+                } else {
+                    "Course Result Screen"
+                };
+                let state = match data.clear_type.and_then(clear_name) {
+                    Some(clear) => format!("{}: {}", label, clear),
+                    None => label.to_string(),
+                };
+                rp_data = rp_data.set_state(state);
+                if let Some(details) = details {
+                    rp_data = rp_data.set_details(details);
+                }
             }
             _ => {}
         }
 
-        Some(rp_data)
+        Some(rp_data.set_start_timestamp(*start_timestamp))
     }
 
     /// IPC thread loop: receives commands and performs Discord IPC.
@@ -232,7 +268,7 @@ impl DiscordListener {
 
 impl Default for DiscordListener {
     fn default() -> Self {
-        Self::new().1
+        Self::new(false).1
     }
 }
 
@@ -242,6 +278,70 @@ impl Drop for DiscordListener {
     }
 }
 
+/// "Title Subtitle / Artist".
+fn song_details(song_info: &SongInfo) -> String {
+    let full_title = if song_info.subtitle.is_empty() {
+        song_info.title.clone()
+    } else {
+        format!("{} {}", song_info.title, song_info.subtitle)
+    };
+    format!("{} / {}", full_title, song_info.artist)
+}
+
+/// Difficulty and level, e.g. "ANOTHER 12". `None` when neither is known.
+fn chart_label(song_info: &SongInfo) -> Option<String> {
+    let difficulty = match song_info.difficulty {
+        1 => Some("BEGINNER"),
+        2 => Some("NORMAL"),
+        3 => Some("HYPER"),
+        4 => Some("ANOTHER"),
+        5 => Some("INSANE"),
+        _ => None,
+    };
+    match (difficulty, song_info.level) {
+        (Some(difficulty), level) if level > 0 => Some(format!("{} {}", difficulty, level)),
+        (Some(difficulty), _) => Some(difficulty.to_string()),
+        (None, level) if level > 0 => Some(format!("Lv.{}", level)),
+        (None, _) => None,
+    }
+}
+
+fn gauge_name(gauge_type: i32) -> Option<&'static str> {
+    const NAMES: [&str; 9] = [
+        "ASSIST EASY",
+        "EASY",
+        "NORMAL",
+        "HARD",
+        "EX-HARD",
+        "HAZARD",
+        "GRADE",
+        "EX GRADE",
+        "EXHARD GRADE",
+    ];
+    usize::try_from(gauge_type)
+        .ok()
+        .and_then(|i| NAMES.get(i).copied())
+}
+
+fn clear_name(clear_type: i32) -> Option<&'static str> {
+    const NAMES: [&str; 11] = [
+        "NO PLAY",
+        "FAILED",
+        "ASSIST EASY CLEAR",
+        "LIGHT ASSIST EASY CLEAR",
+        "EASY CLEAR",
+        "CLEAR",
+        "HARD CLEAR",
+        "EXHARD CLEAR",
+        "FULL COMBO",
+        "PERFECT",
+        "MAX",
+    ];
+    usize::try_from(clear_type)
+        .ok()
+        .and_then(|i| NAMES.get(i).copied())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -363,9 +463,13 @@ mod tests {
             state_type: Some(crate::skin::main_state_type::MainStateType::MusicSelect),
             status: 0,
             song_info: None,
+            gauge_type: None,
+            clear_type: None,
+            previous_clear_type: None,
+            play_elapsed_ms: None,
         };
 
-        let result = DiscordListener::build_presence(&data, &mut start_ts, &mut last_screen);
+        let result = DiscordListener::build_presence(&data, &mut start_ts, &mut last_screen, false);
         assert!(result.is_some());
         assert!(start_ts > 0);
         assert_eq!(last_screen, Some(ScreenType::MusicSelector));
@@ -454,10 +558,117 @@ mod tests {
                 subtitle: "".to_string(),
                 artist: "Test Artist".to_string(),
                 mode: 7,
+                difficulty: 0,
+                level: 0,
+                length_ms: 0,
             }),
+            gauge_type: None,
+            clear_type: None,
+            previous_clear_type: None,
+            play_elapsed_ms: None,
         };
 
-        let result = DiscordListener::build_presence(&data, &mut start_ts, &mut last_screen);
+        let result = DiscordListener::build_presence(&data, &mut start_ts, &mut last_screen, false);
         assert!(result.is_some());
     }
+
+    fn play_data(play_elapsed_ms: Option<i64>) -> StateChangedData {
+        StateChangedData {
+            screen_type: ScreenType::BMSPlayer,
+            state_type: Some(crate::skin::main_state_type::MainStateType::Play),
+            status: 0,
+            song_info: Some(SongInfo {
+                title: "Test Song".to_string(),
+                subtitle: "[Another]".to_string(),
+                artist: "Test Artist".to_string(),
+                mode: 7,
+                difficulty: 4,
+                level: 12,
+                length_ms: 120_000,
+            }),
+            gauge_type: Some(3),
+            clear_type: None,
+            previous_clear_type: None,
+            play_elapsed_ms,
+        }
+    }
+
+    #[test]
+    fn test_build_presence_play_shows_chart_gauge_and_remaining_time() {
+        let (mut start_ts, mut last_screen) = (0i64, None);
+
+        let rp = DiscordListener::build_presence(
+            &play_data(Some(30_000)),
+            &mut start_ts,
+            &mut last_screen,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            rp.details.as_deref(),
+            Some("Test Song [Another] / Test Artist")
+        );
+        assert_eq!(
+            rp.state.as_deref(),
+            Some("Playing: 7Keys ANOTHER 12 / HARD")
+        );
+        let timestamps = rp.timestamps.unwrap();
+        let (start, end) = (timestamps.start.unwrap(), timestamps.end.unwrap());
+        assert_eq!(end - start, 120);
+        assert!((start_ts - start - 30).abs() <= 1);
+    }
+
+    #[test]
+    fn test_build_presence_before_timer_play_has_no_end_time() {
+        let (mut start_ts, mut last_screen) = (0i64, None);
+
+        let rp = DiscordListener::build_presence(
+            &play_data(None),
+            &mut start_ts,
+            &mut last_screen,
+            false,
+        )
+        .unwrap();
+
+        let timestamps = rp.timestamps.unwrap();
+        assert_eq!(timestamps.start, Some(start_ts));
+        assert_eq!(timestamps.end, None);
+    }
+
+    #[test]
+    fn test_build_presence_hides_song_details() {
+        let (mut start_ts, mut last_screen) = (0i64, None);
+
+        let rp = DiscordListener::build_presence(
+            &play_data(Some(0)),
+            &mut start_ts,
+            &mut last_screen,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(rp.details, None);
+        assert_eq!(rp.state.as_deref(), Some("Playing: 7Keys / HARD"));
+    }
+
+    #[test]
+    fn test_build_presence_result_shows_clear() {
+        let (mut start_ts, mut last_screen) = (0i64, None);
+        let data = StateChangedData {
+            screen_type: ScreenType::MusicResult,
+            state_type: Some(crate::skin::main_state_type::MainStateType::Result),
+            clear_type: Some(6),
+            ..play_data(None)
+        };
+
+        let rp =
+            DiscordListener::build_presence(&data, &mut start_ts, &mut last_screen, false).unwrap();
+
+        assert_eq!(rp.state.as_deref(), Some("Result Screen: HARD CLEAR"));
+        assert_eq!(
+            rp.details.as_deref(),
+            Some("Test Song [Another] / Test Artist")
+        );
+    }
 }
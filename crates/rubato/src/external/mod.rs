@@ -23,6 +23,18 @@ pub mod bms_search_accessor;
 // Discord Rich Presence listener
 pub mod discord_listener;
 
+// Now-playing file for OBS text sources
+pub mod now_playing;
+
+// Local HTTP API for stream overlays
+pub mod overlay_server;
+
+// Runtime metrics report (frame time, audio load, judge latency, DB timings)
+pub mod runtime_metrics;
+
+// Automatic result screenshots with metadata overlay
+pub mod result_screenshot;
+
 // Score data import from LR2
 pub mod score_data_importer;
 
@@ -31,6 +43,7 @@ pub mod screen_shot_exporter;
 
 // Screenshot file exporter
 pub mod screen_shot_file_exporter;
+pub mod social_share;
 
 // Webhook handler for Discord webhooks
 pub mod webhook_handler;
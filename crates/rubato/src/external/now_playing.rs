@@ -0,0 +1,314 @@
+// Now-playing file for OBS text sources.
+//
+// Writes the current song and live score to a file that streaming software can
+// read: JSON when the path ends in `.json`, plain text lines otherwise. The JSON
+// form carries the full game state (screen, song, live score and lamp, personal
+// best) for tools that can't use the pipe, socket or WebSocket integrations. The
+// file is rewritten immediately on screen changes and at most once per
+// `WRITE_INTERVAL` otherwise, so the score updates during play without hitting
+// the disk every frame.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::external::overlay_server::{OverlayBest, OverlayLive, OverlaySong};
+
+/// Minimum time between writes while the screen stays the same.
+pub const WRITE_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Serialize)]
+struct NowPlaying<'a> {
+    screen: &'a str,
+    song: Option<&'a OverlaySong>,
+    live: Option<&'a OverlayLive>,
+    best: Option<&'a OverlayBest>,
+}
+
+pub struct NowPlayingWriter {
+    path: PathBuf,
+    screen: Option<String>,
+    contents: Option<String>,
+    last_write: Option<Instant>,
+    failing: bool,
+}
+
+impl NowPlayingWriter {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            screen: None,
+            contents: None,
+            last_write: None,
+            failing: false,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Rewrite the file if the screen changed, or the interval elapsed and the
+    /// contents differ. Returns whether the file was written.
+    pub fn update(
+        &mut self,
+        screen: &str,
+        song: Option<&OverlaySong>,
+        live: Option<&OverlayLive>,
+        best: Option<&OverlayBest>,
+    ) -> bool {
+        self.update_at(Instant::now(), screen, song, live, best)
+    }
+
+    fn update_at(
+        &mut self,
+        now: Instant,
+        screen: &str,
+        song: Option<&OverlaySong>,
+        live: Option<&OverlayLive>,
+        best: Option<&OverlayBest>,
+    ) -> bool {
+        let screen_changed = self.screen.as_deref() != Some(screen);
+        if !screen_changed
+            && self
+                .last_write
+                .is_some_and(|last| now.duration_since(last) < WRITE_INTERVAL)
+        {
+            return false;
+        }
+        self.last_write = Some(now);
+        self.screen = Some(screen.to_string());
+
+        let contents = if is_json_path(&self.path) {
+            render_json(screen, song, live, best)
+        } else {
+            render_text(song, live)
+        };
+        if self.contents.as_deref() == Some(contents.as_str()) {
+            return false;
+        }
+        match write_replace(&self.path, &contents) {
+            Ok(()) => {
+                self.contents = Some(contents);
+                self.failing = false;
+                true
+            }
+            Err(e) => {
+                if !self.failing {
+                    log::warn!("Failed to write now-playing file: {:#}", e);
+                }
+                self.failing = true;
+                false
+            }
+        }
+    }
+}
+
+fn is_json_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+}
+
+fn render_json(
+    screen: &str,
+    song: Option<&OverlaySong>,
+    live: Option<&OverlayLive>,
+    best: Option<&OverlayBest>,
+) -> String {
+    serde_json::to_string_pretty(&NowPlaying {
+        screen,
+        song,
+        live,
+        best,
+    })
+    .unwrap_or_default()
+}
+
+/// Title, artist, chart and score on separate lines; empty when no song is loaded.
+fn render_text(song: Option<&OverlaySong>, live: Option<&OverlayLive>) -> String {
+    let Some(song) = song else {
+        return String::new();
+    };
+    let mut lines = vec![if song.subtitle.is_empty() {
+        song.title.clone()
+    } else {
+        format!("{} {}", song.title, song.subtitle)
+    }];
+    lines.push(song.artist.clone());
+    lines.push(chart_label(song));
+    if let Some(live) = live {
+        lines.push(format!(
+            "EX SCORE {} / {} ({:.2}%)",
+            live.exscore, live.max_score, live.rate
+        ));
+    }
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+/// e.g. "7K ANOTHER 12".
+fn chart_label(song: &OverlaySong) -> String {
+    let difficulty = match song.difficulty {
+        1 => "BEGINNER",
+        2 => "NORMAL",
+        3 => "HYPER",
+        4 => "ANOTHER",
+        5 => "INSANE",
+        _ => "",
+    };
+    [
+        (song.mode > 0).then(|| format!("{}K", song.mode)),
+        (!difficulty.is_empty()).then(|| difficulty.to_string()),
+        (song.level > 0).then(|| song.level.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+/// Write through a temporary file so readers never see a half-written file.
+pub(crate) fn write_replace(path: &Path, contents: &str) -> Result<()> {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    let temp = PathBuf::from(temp);
+    fs::write(&temp, contents).with_context(|| format!("writing {}", temp.display()))?;
+    fs::rename(&temp, path).with_context(|| format!("replacing {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn song() -> OverlaySong {
+        OverlaySong {
+            title: "Title".to_string(),
+            subtitle: "[SPA]".to_string(),
+            artist: "Artist".to_string(),
+            mode: 7,
+            level: 12,
+            difficulty: 4,
+            ..Default::default()
+        }
+    }
+
+    fn live(exscore: i32) -> OverlayLive {
+        OverlayLive {
+            exscore,
+            max_score: 2000,
+            rate: 88.5,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn text_lists_song_chart_and_score() {
+        assert_eq!(
+            render_text(Some(&song()), Some(&live(1234))),
+            "Title [SPA]\nArtist\n7K ANOTHER 12\nEX SCORE 1234 / 2000 (88.50%)\n"
+        );
+        assert_eq!(
+            render_text(Some(&song()), None),
+            "Title [SPA]\nArtist\n7K ANOTHER 12\n"
+        );
+        assert_eq!(render_text(None, Some(&live(1))), "");
+    }
+
+    #[test]
+    fn chart_label_skips_unknown_parts() {
+        let song = OverlaySong {
+            mode: 14,
+            ..Default::default()
+        };
+        assert_eq!(chart_label(&song), "14K");
+        assert_eq!(chart_label(&OverlaySong::default()), "");
+    }
+
+    #[test]
+    fn json_path_writes_the_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nowplaying.JSON");
+        let mut writer = NowPlayingWriter::new(&path);
+        let best = OverlayBest {
+            clear: "HARD CLEAR".to_string(),
+            exscore: 1500,
+            ..Default::default()
+        };
+        let result = OverlayLive {
+            clear: Some("EX HARD CLEAR".to_string()),
+            ..live(10)
+        };
+        assert!(writer.update("result", Some(&song()), Some(&result), Some(&best)));
+
+        let value: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(value["screen"], "result");
+        assert_eq!(value["song"]["title"], "Title");
+        assert_eq!(value["live"]["exscore"], 10);
+        assert_eq!(value["live"]["clear"], "EX HARD CLEAR");
+        assert_eq!(value["best"]["clear"], "HARD CLEAR");
+        assert_eq!(value["best"]["exscore"], 1500);
+        assert!(!dir.path().join("nowplaying.JSON.tmp").exists());
+    }
+
+    #[test]
+    fn writes_are_throttled_within_a_screen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nowplaying.txt");
+        let mut writer = NowPlayingWriter::new(&path);
+        let start = Instant::now();
+
+        assert!(writer.update_at(start, "play", Some(&song()), Some(&live(10)), None));
+        assert!(!writer.update_at(
+            start + Duration::from_millis(100),
+            "play",
+            Some(&song()),
+            Some(&live(20)),
+            None
+        ));
+        assert!(fs::read_to_string(&path).unwrap().contains("EX SCORE 10 "));
+
+        let later = start + WRITE_INTERVAL;
+        assert!(writer.update_at(later, "play", Some(&song()), Some(&live(20)), None));
+        assert!(fs::read_to_string(&path).unwrap().contains("EX SCORE 20 "));
+        // Unchanged contents are not rewritten.
+        assert!(!writer.update_at(
+            later + WRITE_INTERVAL,
+            "play",
+            Some(&song()),
+            Some(&live(20)),
+            None
+        ));
+    }
+
+    #[test]
+    fn screen_change_writes_immediately() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nowplaying.txt");
+        let mut writer = NowPlayingWriter::new(&path);
+        let start = Instant::now();
+
+        assert!(writer.update_at(start, "play", Some(&song()), Some(&live(10)), None));
+        assert!(writer.update_at(start, "result", Some(&song()), Some(&live(30)), None));
+        assert!(fs::read_to_string(&path).unwrap().contains("EX SCORE 30 "));
+        assert!(writer.update_at(start, "musicselect", None, None, None));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "");
+    }
+
+    #[test]
+    fn write_failure_is_retried() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing").join("nowplaying.txt");
+        let mut writer = NowPlayingWriter::new(&path);
+        let start = Instant::now();
+
+        assert!(!writer.update_at(start, "play", Some(&song()), None, None));
+        fs::create_dir(dir.path().join("missing")).unwrap();
+        assert!(writer.update_at(start + WRITE_INTERVAL, "play", Some(&song()), None, None));
+        assert!(path.exists());
+    }
+}
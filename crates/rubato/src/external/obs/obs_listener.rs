@@ -3,14 +3,16 @@ use std::time::Duration;
 
 use log::warn;
 
-use crate::core::config::Config;
+use crate::core::config::{Config, ObsConfig};
 use crate::core::main_state::MainStateType;
 use crate::skin::app_event::{AppEvent, StateChangedData};
+use crate::skin::clear_type::ClearType;
 use crate::skin::screen_type::ScreenType;
 
-use super::lock_or_recover;
 use super::obs_ws_client::ObsWsClient;
 use super::{ACTION_NONE, SCENE_NONE};
+use super::{ImGuiNotify, lock_or_recover};
+use crate::tr;
 
 /// ObsListener - scene/recording control via OBS WebSocket.
 ///
@@ -133,7 +135,14 @@ impl ObsListener {
         if current_state_type == MainStateType::Play
             && *last_state_type == Some(MainStateType::Play)
         {
-            Self::trigger_replay_static(config, obs_client, scheduled_stop_task);
+            if data.play_elapsed_ms.is_some() {
+                // Same play, TIMER_PLAY just started
+                if config.obs.obs_ws_rec_on_play {
+                    Self::start_play_recording(obs_client, scheduled_stop_task);
+                }
+            } else {
+                Self::trigger_replay_static(config, obs_client, scheduled_stop_task);
+            }
         } else if Some(current_state_type) != *last_state_type {
             Self::trigger_state_change_by_type_static(
                 current_state_type,
@@ -141,6 +150,20 @@ impl ObsListener {
                 obs_client,
                 scheduled_stop_task,
             );
+            if *last_state_type == Some(MainStateType::Play) && config.obs.obs_ws_rec_on_play {
+                Self::schedule_stop_record(
+                    obs_client,
+                    config.obs.obs_ws_rec_stop_wait,
+                    scheduled_stop_task,
+                );
+            }
+            if let Some(reason) = replay_buffer_trigger(&config.obs, data)
+                && let Some(client) = obs_client
+                && client.is_connected()
+            {
+                client.save_replay_buffer();
+                ImGuiNotify::info(&tr!("notify-obs-replay-saved", reason = reason.to_string()));
+            }
         }
 
         *last_state_type = Some(current_state_type);
@@ -311,30 +334,60 @@ impl ObsListener {
             && action != ACTION_NONE
         {
             if action == "StopRecord" {
-                let delay = config.obs.obs_ws_rec_stop_wait;
                 if stop_record_now {
                     return;
                 }
-                let Some(runtime_handle) = client.runtime_handle() else {
-                    return;
-                };
-                let runtime_handle = runtime_handle.clone();
-                let client_clone = Arc::clone(client);
-                let stop_task_clone = Arc::clone(scheduled_stop_task);
-                let handle = runtime_handle.spawn(async move {
-                    tokio::time::sleep(Duration::from_millis(delay.max(0) as u64)).await;
-                    client_clone.request_stop_record();
-                    let mut guard = lock_or_recover(&stop_task_clone);
-                    *guard = None;
-                });
-                let mut guard = lock_or_recover(scheduled_stop_task);
-                *guard = Some(handle);
+                Self::schedule_stop_record(
+                    obs_client,
+                    config.obs.obs_ws_rec_stop_wait,
+                    scheduled_stop_task,
+                );
             } else {
                 client.send_request(action);
             }
         }
     }
 
+    /// Stop recording after `delay` ms unless a later state change cancels it.
+    fn schedule_stop_record(
+        obs_client: &Option<Arc<ObsWsClient>>,
+        delay: i32,
+        scheduled_stop_task: &Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    ) {
+        let Some(client) = obs_client else {
+            return;
+        };
+        let Some(runtime_handle) = client.runtime_handle() else {
+            return;
+        };
+        let runtime_handle = runtime_handle.clone();
+        let client_clone = Arc::clone(client);
+        let stop_task_clone = Arc::clone(scheduled_stop_task);
+        let handle = runtime_handle.spawn(async move {
+            tokio::time::sleep(Duration::from_millis(delay.max(0) as u64)).await;
+            client_clone.request_stop_record();
+            let mut guard = lock_or_recover(&stop_task_clone);
+            *guard = None;
+        });
+        let mut guard = lock_or_recover(scheduled_stop_task);
+        if let Some(previous) = guard.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Start a fresh recording for the play whose notes just started.
+    fn start_play_recording(
+        obs_client: &Option<Arc<ObsWsClient>>,
+        scheduled_stop_task: &Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    ) {
+        let Some(client) = obs_client else {
+            return;
+        };
+        Self::cancel_scheduled_stop_impl(scheduled_stop_task);
+        // Splits off a recording still running from the previous play.
+        client.restart_recording();
+    }
+
     fn close_impl(
         scheduled_stop_task: &Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
         obs_client: &Option<Arc<ObsWsClient>>,
@@ -374,6 +427,27 @@ impl ObsListener {
     }
 }
 
+/// Why the replay buffer should be saved for this state change, if at all.
+fn replay_buffer_trigger(obs: &ObsConfig, data: &StateChangedData) -> Option<&'static str> {
+    if !matches!(
+        data.screen_type,
+        ScreenType::MusicResult | ScreenType::CourseResult
+    ) {
+        return None;
+    }
+    let clear = data.clear_type?;
+    if obs.obs_ws_replay_on_full_combo && ClearType::clear_type_by_id(clear).is_full_combo() {
+        return Some("full combo");
+    }
+    if obs.obs_ws_replay_on_new_lamp
+        && clear > ClearType::Failed.id()
+        && clear > data.previous_clear_type.unwrap_or(ClearType::NoPlay.id())
+    {
+        return Some("new lamp");
+    }
+    None
+}
+
 impl Drop for ObsListener {
     fn drop(&mut self) {
         self.close();
@@ -551,6 +625,10 @@ mod tests {
             state_type: Some(MainStateType::Play),
             status: 0,
             song_info: None,
+            gauge_type: None,
+            clear_type: None,
+            previous_clear_type: None,
+            play_elapsed_ms: None,
         };
         // Should not panic with obs_client=None
         ObsListener::handle_state_changed(
@@ -573,6 +651,10 @@ mod tests {
             state_type: Some(MainStateType::SkinConfig),
             status: 0,
             song_info: None,
+            gauge_type: None,
+            clear_type: None,
+            previous_clear_type: None,
+            play_elapsed_ms: None,
         };
         ObsListener::handle_state_changed(
             &data,
@@ -583,4 +665,52 @@ mod tests {
         );
         assert!(last_state.is_none());
     }
+
+    fn result_data(clear: i32, previous: i32) -> StateChangedData {
+        StateChangedData {
+            screen_type: ScreenType::MusicResult,
+            state_type: Some(MainStateType::Result),
+            status: 0,
+            song_info: None,
+            gauge_type: None,
+            clear_type: Some(clear),
+            previous_clear_type: Some(previous),
+            play_elapsed_ms: None,
+        }
+    }
+
+    #[test]
+    fn replay_buffer_trigger_follows_config() {
+        let fc = result_data(ClearType::FullCombo.id(), ClearType::Max.id());
+        let lamp = result_data(ClearType::Hard.id(), ClearType::Normal.id());
+        let off = ObsConfig::default();
+        assert_eq!(replay_buffer_trigger(&off, &fc), None);
+        assert_eq!(replay_buffer_trigger(&off, &lamp), None);
+
+        let on = ObsConfig {
+            obs_ws_replay_on_full_combo: true,
+            obs_ws_replay_on_new_lamp: true,
+            ..Default::default()
+        };
+        assert_eq!(replay_buffer_trigger(&on, &fc), Some("full combo"));
+        assert_eq!(replay_buffer_trigger(&on, &lamp), Some("new lamp"));
+        let same_lamp = result_data(ClearType::Hard.id(), ClearType::Hard.id());
+        assert_eq!(replay_buffer_trigger(&on, &same_lamp), None);
+        let failed = result_data(ClearType::Failed.id(), ClearType::NoPlay.id());
+        assert_eq!(replay_buffer_trigger(&on, &failed), None);
+    }
+
+    #[test]
+    fn replay_buffer_trigger_ignores_non_result_screens() {
+        let on = ObsConfig {
+            obs_ws_replay_on_full_combo: true,
+            ..Default::default()
+        };
+        let data = StateChangedData {
+            screen_type: ScreenType::BMSPlayer,
+            state_type: Some(MainStateType::Play),
+            ..result_data(ClearType::Max.id(), ClearType::NoPlay.id())
+        };
+        assert_eq!(replay_buffer_trigger(&on, &data), None);
+    }
 }
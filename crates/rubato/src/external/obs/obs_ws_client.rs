@@ -21,6 +21,7 @@ use crate::core::config::Config;
 use crate::imgui_notify::ImGuiNotify;
 
 use super::lock_or_recover;
+use crate::tr;
 
 /// ObsRecordingMode - recording mode enum
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -600,7 +601,10 @@ impl ObsWsClient {
                     }
 
                     if !notify_message.is_empty() {
-                        ImGuiNotify::info(&format!("OBS: {}.", notify_message));
+                        ImGuiNotify::info(&tr!(
+                            "notify-obs-status",
+                            message = notify_message.to_string()
+                        ));
                     }
 
                     let record_handler = {
@@ -943,7 +947,7 @@ impl ObsWsClient {
         // Set flag under the same lock guard to avoid TOCTOU race.
         guard.save_requested = true;
         drop(guard);
-        ImGuiNotify::info("OBS: Recording will be kept.");
+        ImGuiNotify::info(&tr!("notify-obs-recording-kept"));
     }
 
     pub fn set_scene(&self, scene_name: &str) {
@@ -1009,6 +1013,11 @@ impl ObsWsClient {
         }
     }
 
+    /// Save the replay buffer. The replay buffer must be running in OBS.
+    pub fn save_replay_buffer(&self) {
+        self.send_request("SaveReplayBuffer");
+    }
+
     pub fn restart_recording(&self) {
         let mut guard = lock_or_recover(&self.inner);
         if !(guard.is_connected && guard.is_identified && !guard.is_reconnecting)
@@ -0,0 +1,426 @@
+// Local HTTP API for stream overlays (browser sources).
+//
+// Serves the current song, live score/gauge and recent results as JSON, plus a
+// minimal HTML overlay that polls them. Listens on localhost only. Also serves
+// the runtime metrics report at `/api/metrics`.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::core::performance_metrics::PerformanceMetrics;
+use crate::external::result_screenshot::ResultScreenshotInfo;
+use crate::external::runtime_metrics::MetricsReport;
+use crate::external::screen_shot_exporter;
+use crate::skin::score_data::ScoreData;
+use crate::skin::sync_utils::lock_or_recover;
+
+/// Port used when `IntegrationConfig::overlay_server_port` is 0.
+pub const DEFAULT_OVERLAY_PORT: u16 = 5768;
+
+/// Number of results kept for `/api/results`.
+pub const RECENT_RESULT_COUNT: usize = 10;
+
+/// Minimal browser-source overlay: polls `/api/state` and shows the song, live score and gauge.
+const OVERLAY_HTML: &str = r##"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>rubato overlay</title>
+<style>
+body { margin: 0; font-family: sans-serif; color: #fff; background: transparent;
+       text-shadow: 0 0 4px #000, 0 0 2px #000; }
+#panel { padding: 12px 16px; }
+#title { font-size: 28px; font-weight: bold; }
+#sub { font-size: 18px; opacity: 0.85; }
+#score { font-size: 22px; margin-top: 6px; }
+#judges { font-size: 16px; opacity: 0.85; }
+#gauge { height: 8px; margin-top: 6px; background: rgba(255,255,255,0.25); width: 320px; }
+#gauge div { height: 100%; background: #f44; width: 0; }
+</style>
+</head>
+<body>
+<div id="panel">
+  <div id="title"></div>
+  <div id="sub"></div>
+  <div id="score"></div>
+  <div id="judges"></div>
+  <div id="gauge"><div></div></div>
+</div>
+<script>
+const text = (id, value) => { document.getElementById(id).textContent = value; };
+async function refresh() {
+  try {
+    const state = await (await fetch("/api/state", { cache: "no-store" })).json();
+    const song = state.song, live = state.live;
+    text("title", song ? song.title + (song.subtitle ? " " + song.subtitle : "") : "");
+    text("sub", song ? song.artist + " / " + song.mode + "K Lv." + song.level : state.screen);
+    text("score", live ? "EX SCORE " + live.exscore + " (" + live.rate.toFixed(2) + "%)" : "");
+    text("judges", live ? "PG " + live.pgreat + " GR " + live.great + " GD " + live.good
+      + " BD " + live.bad + " PR " + live.poor + " / MAX COMBO " + live.maxcombo : "");
+    const gauge = live && live.gauge !== null ? live.gauge : 0;
+    document.querySelector("#gauge div").style.width = Math.max(0, Math.min(100, gauge)) + "%";
+  } catch (e) {
+    text("sub", "rubato is not running");
+  }
+}
+setInterval(refresh, 500);
+refresh();
+</script>
+</body>
+</html>
+"##;
+
+/// Everything the overlay endpoints expose.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct OverlaySnapshot {
+    /// Current screen (`musicselect`, `decide`, `play`, `result`, `courseresult`, ...).
+    pub screen: String,
+    pub song: Option<OverlaySong>,
+    /// Present while playing and on the result screens.
+    pub live: Option<OverlayLive>,
+    /// Most recent first.
+    pub results: Vec<OverlayResult>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct OverlaySong {
+    pub title: String,
+    pub subtitle: String,
+    pub artist: String,
+    pub genre: String,
+    pub mode: i32,
+    pub level: i32,
+    pub difficulty: i32,
+    pub minbpm: i32,
+    pub maxbpm: i32,
+    pub notes: i32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct OverlayLive {
+    pub exscore: i32,
+    pub max_score: i32,
+    /// EX score rate of the notes played so far, in percent.
+    pub rate: f32,
+    pub pgreat: i32,
+    pub great: i32,
+    pub good: i32,
+    pub bad: i32,
+    pub poor: i32,
+    pub miss: i32,
+    /// Current combo while playing.
+    pub combo: i32,
+    pub maxcombo: i32,
+    /// Gauge value while playing.
+    pub gauge: Option<f32>,
+    /// Clear lamp of the finished play, on the result screens.
+    pub clear: Option<String>,
+}
+
+/// Personal best for the chart, as stored before the current play.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct OverlayBest {
+    pub clear: String,
+    pub exscore: i32,
+    pub max_score: i32,
+    pub minbp: i32,
+    pub maxcombo: i32,
+    pub playcount: i32,
+}
+
+impl From<&ScoreData> for OverlayBest {
+    fn from(score: &ScoreData) -> Self {
+        Self {
+            clear: screen_shot_exporter::clear_name(score.clear).to_string(),
+            exscore: score.exscore(),
+            max_score: score.notes * 2,
+            minbp: score.minbp,
+            maxcombo: score.maxcombo,
+            playcount: score.playcount,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct OverlayResult {
+    pub title: String,
+    pub exscore: i32,
+    pub max_score: i32,
+    pub clear: String,
+    pub rank: String,
+    /// RFC 3339 local time.
+    pub date: String,
+}
+
+impl From<&ResultScreenshotInfo> for OverlayResult {
+    fn from(info: &ResultScreenshotInfo) -> Self {
+        Self {
+            title: info.title.clone(),
+            exscore: info.exscore,
+            max_score: info.max_score,
+            clear: info.clear_name().to_string(),
+            rank: info.rank_name().to_string(),
+            date: info.date.to_rfc3339(),
+        }
+    }
+}
+
+/// HTTP server thread plus the snapshot it serves. Stops when dropped.
+pub struct OverlayServer {
+    snapshot: Arc<Mutex<OverlaySnapshot>>,
+    addr: SocketAddr,
+    shutdown: Arc<AtomicBool>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl OverlayServer {
+    /// Bind `127.0.0.1:port` (0 picks `DEFAULT_OVERLAY_PORT`) and start serving.
+    pub fn start(port: u16) -> anyhow::Result<Self> {
+        let port = if port == 0 {
+            DEFAULT_OVERLAY_PORT
+        } else {
+            port
+        };
+        Self::bind(SocketAddr::from(([127, 0, 0, 1], port)))
+    }
+
+    pub(crate) fn bind(addr: SocketAddr) -> anyhow::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let snapshot = Arc::new(Mutex::new(OverlaySnapshot::default()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let snapshot = snapshot.clone();
+            let shutdown = shutdown.clone();
+            std::thread::Builder::new()
+                .name("overlay-server".to_string())
+                .spawn(move || serve(listener, snapshot, shutdown))?
+        };
+        log::info!("Overlay server listening on http://{}", addr);
+        Ok(Self {
+            snapshot,
+            addr,
+            shutdown,
+            thread: Some(thread),
+        })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Replace the screen, song and live data, keeping the recent results.
+    pub fn update(&self, screen: &str, song: Option<OverlaySong>, live: Option<OverlayLive>) {
+        let mut snapshot = lock_or_recover(&self.snapshot);
+        snapshot.screen = screen.to_string();
+        snapshot.song = song;
+        snapshot.live = live;
+    }
+
+    /// Add a finished play to the front of the recent results.
+    pub fn record_result(&self, result: OverlayResult) {
+        let mut snapshot = lock_or_recover(&self.snapshot);
+        snapshot.results.insert(0, result);
+        snapshot.results.truncate(RECENT_RESULT_COUNT);
+    }
+
+    pub fn snapshot(&self) -> OverlaySnapshot {
+        lock_or_recover(&self.snapshot).clone()
+    }
+}
+
+impl Drop for OverlayServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Release);
+        // Wake the blocking accept() so the thread sees the flag.
+        let _ = TcpStream::connect_timeout(&self.addr, Duration::from_millis(200));
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn serve(listener: TcpListener, snapshot: Arc<Mutex<OverlaySnapshot>>, shutdown: Arc<AtomicBool>) {
+    for stream in listener.incoming() {
+        if shutdown.load(Ordering::Acquire) {
+            break;
+        }
+        let Ok(stream) = stream else {
+            continue;
+        };
+        if let Err(e) = handle_connection(stream, &snapshot) {
+            log::debug!("Overlay request failed: {}", e);
+        }
+    }
+}
+
+fn handle_connection(
+    mut stream: TcpStream,
+    snapshot: &Mutex<OverlaySnapshot>,
+) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(2)))?;
+    stream.set_write_timeout(Some(Duration::from_secs(2)))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Drain the headers; the overlay API has no request bodies.
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let path = parts.next().unwrap_or("/");
+    let (status, content_type, body) = if method == "GET" {
+        let snapshot = lock_or_recover(snapshot).clone();
+        route(path, &snapshot)
+    } else {
+        (
+            "405 Method Not Allowed",
+            "text/plain",
+            "method not allowed".to_string(),
+        )
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}; charset=utf-8\r\nContent-Length: {}\r\nAccess-Control-Allow-Origin: *\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    )?;
+    stream.write_all(body.as_bytes())?;
+    stream.flush()
+}
+
+/// Map a request path to `(status, content type, body)`.
+fn route(path: &str, snapshot: &OverlaySnapshot) -> (&'static str, &'static str, String) {
+    let path = path.split('?').next().unwrap_or(path);
+    let json = |value: serde_json::Result<String>| match value {
+        Ok(body) => ("200 OK", "application/json", body),
+        Err(e) => (
+            "500 Internal Server Error",
+            "text/plain",
+            format!("serialization failed: {}", e),
+        ),
+    };
+    match path {
+        "/" | "/overlay" => ("200 OK", "text/html", OVERLAY_HTML.to_string()),
+        "/api/state" => json(serde_json::to_string(snapshot)),
+        "/api/song" => json(serde_json::to_string(&snapshot.song)),
+        "/api/live" => json(serde_json::to_string(&snapshot.live)),
+        "/api/results" => json(serde_json::to_string(&snapshot.results)),
+        "/api/metrics" => json(serde_json::to_string(&MetricsReport::collect(
+            PerformanceMetrics::get(),
+        ))),
+        _ => ("404 Not Found", "text/plain", "not found".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn get(addr: SocketAddr, path: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    fn body(response: &str) -> &str {
+        response.split_once("\r\n\r\n").unwrap().1
+    }
+
+    fn result(title: &str) -> OverlayResult {
+        OverlayResult {
+            title: title.to_string(),
+            exscore: 1500,
+            max_score: 2000,
+            clear: "HARD CLEAR".to_string(),
+            rank: "A".to_string(),
+            date: "2026-03-09T21:05:07+09:00".to_string(),
+        }
+    }
+
+    #[test]
+    fn serves_song_live_and_results_as_json() {
+        let server = OverlayServer::bind(SocketAddr::from(([127, 0, 0, 1], 0))).unwrap();
+        server.update(
+            "play",
+            Some(OverlaySong {
+                title: "Song".to_string(),
+                mode: 7,
+                ..Default::default()
+            }),
+            Some(OverlayLive {
+                exscore: 120,
+                gauge: Some(80.0),
+                ..Default::default()
+            }),
+        );
+        server.record_result(result("Previous"));
+
+        let response = get(server.addr(), "/api/state");
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("Access-Control-Allow-Origin: *"));
+        let state: serde_json::Value = serde_json::from_str(body(&response)).unwrap();
+        assert_eq!(state["screen"], "play");
+        assert_eq!(state["song"]["title"], "Song");
+        assert_eq!(state["live"]["exscore"], 120);
+        assert_eq!(state["results"][0]["title"], "Previous");
+
+        let live: serde_json::Value =
+            serde_json::from_str(body(&get(server.addr(), "/api/live?t=1"))).unwrap();
+        assert_eq!(live["gauge"], 80.0);
+    }
+
+    #[test]
+    fn serves_overlay_page_and_rejects_unknown_requests() {
+        let server = OverlayServer::bind(SocketAddr::from(([127, 0, 0, 1], 0))).unwrap();
+        let page = get(server.addr(), "/");
+        assert!(page.contains("Content-Type: text/html"));
+        assert!(body(&page).contains("/api/state"));
+        assert!(get(server.addr(), "/nope").starts_with("HTTP/1.1 404"));
+        let metrics: serde_json::Value =
+            serde_json::from_str(body(&get(server.addr(), "/api/metrics"))).unwrap();
+        assert!(metrics["timings"].is_object());
+
+        let mut stream = TcpStream::connect(server.addr()).unwrap();
+        write!(stream, "POST /api/state HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 405"));
+    }
+
+    #[test]
+    fn recent_results_are_capped_newest_first() {
+        let server = OverlayServer::bind(SocketAddr::from(([127, 0, 0, 1], 0))).unwrap();
+        for i in 0..RECENT_RESULT_COUNT + 2 {
+            server.record_result(result(&format!("Song {}", i)));
+        }
+        server.update("musicselect", None, None);
+        let snapshot = server.snapshot();
+        assert_eq!(snapshot.results.len(), RECENT_RESULT_COUNT);
+        assert_eq!(
+            snapshot.results[0].title,
+            format!("Song {}", RECENT_RESULT_COUNT + 1)
+        );
+        assert_eq!(snapshot.screen, "musicselect");
+    }
+
+    #[test]
+    fn drop_stops_the_server() {
+        let server = OverlayServer::bind(SocketAddr::from(([127, 0, 0, 1], 0))).unwrap();
+        let addr = server.addr();
+        drop(server);
+        assert!(TcpListener::bind(addr).is_ok(), "port should be released");
+    }
+}
@@ -0,0 +1,308 @@
+// Automatic result screenshots stamped with the play's metadata.
+
+use std::path::{Path, PathBuf};
+
+use ab_glyph::{Font, FontVec, PxScale, ScaleFont};
+use anyhow::Context;
+use image::RgbaImage;
+
+use crate::core::config::IntegrationConfig;
+use crate::core::player_resource::PlayerResource;
+use crate::external::screen_shot_exporter;
+
+/// Time on the result screen before the capture, so skin fade-ins have finished.
+pub const RESULT_SCREENSHOT_DELAY_MS: i64 = 1500;
+
+/// Folder used when `IntegrationConfig::result_screenshot_dir` is empty.
+pub const DEFAULT_RESULT_SCREENSHOT_DIR: &str = "screenshot/result";
+
+/// Fonts tried for the overlay, the bundled default skin font first.
+const OVERLAY_FONT_PATHS: &[&str] = &[
+    "skin/default/VL-Gothic-Regular.ttf",
+    #[cfg(target_os = "linux")]
+    "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+    #[cfg(target_os = "macos")]
+    "/System/Library/Fonts/Supplemental/Arial.ttf",
+    #[cfg(target_os = "windows")]
+    "C:\\Windows\\Fonts\\msgothic.ttc",
+];
+
+/// Metadata of a finished play, captured when the result screenshot is scheduled.
+#[derive(Debug, Clone)]
+pub struct ResultScreenshotInfo {
+    pub title: String,
+    pub exscore: i32,
+    pub max_score: i32,
+    /// Clear type id (`ClearType::id`).
+    pub clear: i32,
+    pub date: chrono::DateTime<chrono::Local>,
+    /// Post the saved screenshot to the configured social accounts (share hotkey).
+    pub social_share: bool,
+}
+
+impl ResultScreenshotInfo {
+    /// Snapshot the song (or course) and score held by `resource`.
+    /// Returns None when no score has been recorded.
+    pub fn from_resource(resource: &PlayerResource, course: bool) -> Option<Self> {
+        let score = if course {
+            resource.course_score_data()
+        } else {
+            resource.score_data()
+        }?;
+        let title = match resource.course_data() {
+            Some(course_data) if course => course_data.name().to_string(),
+            _ => resource
+                .songdata()
+                .map(|sd| sd.metadata.full_title())
+                .unwrap_or_default(),
+        };
+        Some(Self {
+            title,
+            exscore: score.exscore(),
+            max_score: score.notes * 2,
+            clear: score.clear,
+            date: chrono::Local::now(),
+            social_share: false,
+        })
+    }
+
+    pub fn clear_name(&self) -> &'static str {
+        screen_shot_exporter::clear_name(self.clear)
+    }
+
+    pub fn rank_name(&self) -> &'static str {
+        screen_shot_exporter::rank_name(self.exscore, self.max_score)
+    }
+
+    /// The two overlay lines: the title, then lamp, rank, score and date.
+    pub fn overlay_lines(&self) -> [String; 2] {
+        let rate = if self.max_score > 0 {
+            self.exscore as f64 * 100.0 / self.max_score as f64
+        } else {
+            0.0
+        };
+        [
+            self.title.clone(),
+            format!(
+                "{}  {}  EX SCORE {}/{} ({:.2}%)  {}",
+                self.clear_name(),
+                self.rank_name(),
+                self.exscore,
+                self.max_score,
+                rate,
+                self.date.format("%Y-%m-%d %H:%M")
+            ),
+        ]
+    }
+
+    /// Path below the screenshot folder: `YYYY-MM/YYYYMMDD_HHMMSS_<title>_<clear>_<rank>.png`.
+    pub fn relative_path(&self) -> PathBuf {
+        let name = format!(
+            "{}_{}_{}_{}.png",
+            self.date.format("%Y%m%d_%H%M%S"),
+            sanitize_file_name(&self.title),
+            self.clear_name(),
+            self.rank_name()
+        );
+        Path::new(&self.date.format("%Y-%m").to_string()).join(name)
+    }
+}
+
+/// Replace characters that are not allowed in file names with full-width equivalents,
+/// matching the manual screenshot naming.
+fn sanitize_file_name(name: &str) -> String {
+    name.replace('\\', "\u{FFE5}")
+        .replace('/', "\u{FF0F}")
+        .replace(':', "\u{FF1A}")
+        .replace('*', "\u{FF0A}")
+        .replace('?', "\u{FF1F}")
+        .replace('"', "\u{201D}")
+        .replace('<', "\u{FF1C}")
+        .replace('>', "\u{FF1E}")
+        .replace('|', "\u{FF5C}")
+        .replace('\t', " ")
+}
+
+/// Load the first available overlay font.
+pub fn load_overlay_font() -> Option<FontVec> {
+    OVERLAY_FONT_PATHS.iter().find_map(|path| {
+        std::fs::read(path)
+            .ok()
+            .and_then(|data| FontVec::try_from_vec(data).ok())
+    })
+}
+
+/// Darken a band along the bottom of `image` and write the overlay lines into it.
+pub fn stamp_overlay(image: &mut RgbaImage, info: &ResultScreenshotInfo, font: &FontVec) {
+    let (width, height) = image.dimensions();
+    let size = (height as f32 / 32.0).max(12.0);
+    let line_height = size * 1.25;
+    let band_top = height.saturating_sub((line_height * 2.0 + size * 0.5) as u32);
+
+    for y in band_top..height {
+        for x in 0..width {
+            let pixel = image.get_pixel_mut(x, y);
+            for c in &mut pixel.0[..3] {
+                *c /= 3;
+            }
+        }
+    }
+
+    let scaled = font.as_scaled(PxScale::from(size));
+    for (i, line) in info.overlay_lines().iter().enumerate() {
+        let baseline = band_top as f32 + size * 0.25 + line_height * i as f32 + scaled.ascent();
+        draw_text(image, &scaled, line, size * 0.5, baseline);
+    }
+}
+
+fn draw_text<F: Font, SF: ScaleFont<F>>(
+    image: &mut RgbaImage,
+    font: &SF,
+    text: &str,
+    x: f32,
+    baseline: f32,
+) {
+    let (width, height) = image.dimensions();
+    let mut caret = x;
+    let mut prev = None;
+    for ch in text.chars() {
+        let id = font.glyph_id(ch);
+        if let Some(prev) = prev {
+            caret += font.kern(prev, id);
+        }
+        let glyph = id.with_scale_and_position(font.scale(), ab_glyph::point(caret, baseline));
+        caret += font.h_advance(id);
+        prev = Some(id);
+        let Some(outline) = font.outline_glyph(glyph) else {
+            continue;
+        };
+        let bounds = outline.px_bounds();
+        outline.draw(|gx, gy, coverage| {
+            let px = bounds.min.x as i64 + gx as i64;
+            let py = bounds.min.y as i64 + gy as i64;
+            if px < 0 || py < 0 || px >= width as i64 || py >= height as i64 {
+                return;
+            }
+            let pixel = image.get_pixel_mut(px as u32, py as u32);
+            for c in &mut pixel.0[..3] {
+                *c = (*c as f32 + (255.0 - *c as f32) * coverage.min(1.0)) as u8;
+            }
+        });
+    }
+}
+
+/// Stamp and save a captured result frame; returns the written path.
+///
+/// `rgba` is the frame as tightly packed RGBA rows. The overlay is skipped when
+/// no font can be loaded.
+pub fn save_result_screenshot(
+    rgba: Vec<u8>,
+    width: u32,
+    height: u32,
+    info: &ResultScreenshotInfo,
+    config: &IntegrationConfig,
+) -> anyhow::Result<PathBuf> {
+    let mut image = RgbaImage::from_raw(width, height, rgba)
+        .context("screenshot data does not match the frame size")?;
+    match load_overlay_font() {
+        Some(font) => stamp_overlay(&mut image, info, &font),
+        None => log::warn!("No font found for the result screenshot overlay"),
+    }
+
+    let dir = if config.result_screenshot_dir.trim().is_empty() {
+        DEFAULT_RESULT_SCREENSHOT_DIR
+    } else {
+        config.result_screenshot_dir.trim()
+    };
+    let path = Path::new(dir).join(info.relative_path());
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    image
+        .save(&path)
+        .with_context(|| format!("failed to save {}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn info() -> ResultScreenshotInfo {
+        ResultScreenshotInfo {
+            title: "Song <Another>".to_string(),
+            exscore: 1500,
+            max_score: 2000,
+            clear: 6,
+            date: chrono::Local
+                .with_ymd_and_hms(2026, 3, 9, 21, 5, 7)
+                .unwrap(),
+            social_share: false,
+        }
+    }
+
+    fn test_font() -> FontVec {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../skin/default/VL-Gothic-Regular.ttf");
+        FontVec::try_from_vec(std::fs::read(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn overlay_lines_show_lamp_rank_score_and_date() {
+        let [title, details] = info().overlay_lines();
+        assert_eq!(title, "Song <Another>");
+        assert_eq!(
+            details,
+            "HARD CLEAR  A  EX SCORE 1500/2000 (75.00%)  2026-03-09 21:05"
+        );
+    }
+
+    #[test]
+    fn relative_path_is_grouped_by_month_and_sanitized() {
+        assert_eq!(
+            info().relative_path(),
+            Path::new("2026-03")
+                .join("20260309_210507_Song \u{FF1C}Another\u{FF1E}_HARD CLEAR_A.png")
+        );
+    }
+
+    #[test]
+    fn from_resource_requires_a_score() {
+        let resource = PlayerResource::new(Default::default(), Default::default());
+        assert!(ResultScreenshotInfo::from_resource(&resource, false).is_none());
+        assert!(ResultScreenshotInfo::from_resource(&resource, true).is_none());
+    }
+
+    #[test]
+    fn stamp_overlay_only_touches_the_bottom_band() {
+        let mut image = RgbaImage::from_pixel(320, 240, image::Rgba([90, 90, 90, 255]));
+        stamp_overlay(&mut image, &info(), &test_font());
+
+        assert_eq!(image.get_pixel(10, 10).0, [90, 90, 90, 255]);
+        let band: Vec<_> = (0..320).map(|x| image.get_pixel(x, 230).0).collect();
+        assert!(band.iter().any(|p| p[0] < 90), "band should be darkened");
+        let text_rows = (200..240).flat_map(|y| (0..320).map(move |x| (x, y)));
+        assert!(
+            text_rows
+                .into_iter()
+                .any(|(x, y)| image.get_pixel(x, y).0[0] > 90),
+            "text should be drawn brighter than the band"
+        );
+    }
+
+    #[test]
+    fn save_result_screenshot_writes_into_configured_folder() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = IntegrationConfig {
+            result_screenshot_dir: dir.path().to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        let path = save_result_screenshot(vec![0; 64 * 48 * 4], 64, 48, &info(), &config).unwrap();
+        assert_eq!(path, dir.path().join(info().relative_path()));
+        assert!(path.is_file());
+
+        assert!(save_result_screenshot(vec![0; 10], 64, 48, &info(), &config).is_err());
+    }
+}
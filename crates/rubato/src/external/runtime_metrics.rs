@@ -0,0 +1,221 @@
+// Runtime metrics report for diagnosing performance on user machines.
+//
+// Summarizes the recent `PerformanceMetrics` watch records (frame time, judge
+// latency, DB queries, ...) and samples (audio callback load) as JSON. Served
+// by the overlay server at `/api/metrics` and optionally dumped to a file at a
+// fixed interval.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::core::performance_metrics::{PerformanceMetrics, RETENTION_NANOS};
+use crate::external::now_playing::write_replace;
+
+/// Interval between metrics dumps.
+pub const DUMP_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct MetricsReport {
+    /// RFC 3339 local time.
+    pub generated_at: String,
+    /// Length of the window the summaries cover.
+    pub window_secs: f64,
+    /// Durations in milliseconds, keyed by watch name.
+    pub timings: BTreeMap<String, TimingSummary>,
+    /// Raw values, keyed by sample name.
+    pub values: BTreeMap<String, ValueSummary>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct TimingSummary {
+    pub count: usize,
+    pub avg_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ValueSummary {
+    pub count: usize,
+    pub avg: f64,
+    pub max: f64,
+}
+
+impl MetricsReport {
+    /// Summarize everything `metrics` currently retains. Empty series are skipped.
+    pub fn collect(metrics: &PerformanceMetrics) -> Self {
+        let timings = metrics
+            .watch_names()
+            .into_iter()
+            .filter_map(|name| {
+                let records = metrics.get_watch_records(&name)?;
+                let durations: Vec<f64> = records
+                    .iter()
+                    .map(|&(_, nanos)| nanos as f64 / 1_000_000.0)
+                    .collect();
+                Some((name, TimingSummary::from_values(durations)?))
+            })
+            .collect();
+        let values = metrics
+            .sample_names()
+            .into_iter()
+            .filter_map(|name| {
+                let records = metrics.get_sample_records(&name)?;
+                let values: Vec<f64> = records.iter().map(|&(_, value)| value).collect();
+                Some((name, ValueSummary::from_values(&values)?))
+            })
+            .collect();
+        Self {
+            generated_at: chrono::Local::now().to_rfc3339(),
+            window_secs: RETENTION_NANOS as f64 / 1_000_000_000.0,
+            timings,
+            values,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+impl TimingSummary {
+    fn from_values(mut values: Vec<f64>) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(f64::total_cmp);
+        let count = values.len();
+        let p95_index = ((count as f64 * 0.95).ceil() as usize).clamp(1, count) - 1;
+        Some(Self {
+            count,
+            avg_ms: values.iter().sum::<f64>() / count as f64,
+            p95_ms: values[p95_index],
+            max_ms: values[count - 1],
+        })
+    }
+}
+
+impl ValueSummary {
+    fn from_values(values: &[f64]) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        Some(Self {
+            count: values.len(),
+            avg: values.iter().sum::<f64>() / values.len() as f64,
+            max: values.iter().copied().fold(f64::MIN, f64::max),
+        })
+    }
+}
+
+/// Writes a `MetricsReport` to a file every `DUMP_INTERVAL`.
+pub struct MetricsDumper {
+    path: PathBuf,
+    last_dump: Option<Instant>,
+    failing: bool,
+}
+
+impl MetricsDumper {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            last_dump: None,
+            failing: false,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Dump the current report if the interval elapsed. Returns whether the file was written.
+    pub fn poll(&mut self, metrics: &PerformanceMetrics) -> bool {
+        self.poll_at(Instant::now(), metrics)
+    }
+
+    fn poll_at(&mut self, now: Instant, metrics: &PerformanceMetrics) -> bool {
+        if self
+            .last_dump
+            .is_some_and(|last| now.duration_since(last) < DUMP_INTERVAL)
+        {
+            return false;
+        }
+        self.last_dump = Some(now);
+        match write_replace(&self.path, &MetricsReport::collect(metrics).to_json()) {
+            Ok(()) => {
+                self.failing = false;
+                true
+            }
+            Err(e) => {
+                if !self.failing {
+                    log::warn!("Failed to write metrics dump: {:#}", e);
+                }
+                self.failing = true;
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timing_summary_reports_avg_p95_and_max() {
+        let summary = TimingSummary::from_values((1..=100).map(f64::from).collect()).unwrap();
+        assert_eq!(summary.count, 100);
+        assert_eq!(summary.avg_ms, 50.5);
+        assert_eq!(summary.p95_ms, 95.0);
+        assert_eq!(summary.max_ms, 100.0);
+
+        let single = TimingSummary::from_values(vec![4.0]).unwrap();
+        assert_eq!((single.p95_ms, single.max_ms), (4.0, 4.0));
+        assert!(TimingSummary::from_values(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn value_summary_reports_avg_and_max() {
+        let summary = ValueSummary::from_values(&[0.25, 0.75, 0.5]).unwrap();
+        assert_eq!(summary.count, 3);
+        assert_eq!(summary.avg, 0.5);
+        assert_eq!(summary.max, 0.75);
+        assert!(ValueSummary::from_values(&[]).is_none());
+    }
+
+    #[test]
+    fn collect_includes_watches_and_samples() {
+        let metrics = PerformanceMetrics::get();
+        metrics.record_duration("test.runtime_metrics.watch", Duration::from_millis(3));
+        metrics.submit_sample("test.runtime_metrics.sample", 0.4);
+
+        let report = MetricsReport::collect(metrics);
+        let timing = &report.timings["test.runtime_metrics.watch"];
+        assert!(timing.count >= 1);
+        assert!(timing.max_ms >= 3.0);
+        assert!(report.values["test.runtime_metrics.sample"].max >= 0.4);
+        assert_eq!(report.window_secs, 3.0);
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert!(json["timings"]["test.runtime_metrics.watch"]["p95_ms"].is_number());
+    }
+
+    #[test]
+    fn dumper_writes_once_per_interval() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("metrics.json");
+        let mut dumper = MetricsDumper::new(&path);
+        let metrics = PerformanceMetrics::get();
+        let start = Instant::now();
+
+        assert!(dumper.poll_at(start, metrics));
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert!(json["timings"].is_object());
+        assert!(!dumper.poll_at(start + Duration::from_secs(1), metrics));
+        assert!(dumper.poll_at(start + DUMP_INTERVAL, metrics));
+    }
+}
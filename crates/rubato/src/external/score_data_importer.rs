@@ -1,6 +1,16 @@
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
 
 use crate::external::{ScoreData, ScoreDatabaseAccessor, SongDatabaseAccessor};
+use crate::skin::player_information::PlayerInformation;
+
+/// Directory holding rival score databases, read by `RivalDataAccessor`.
+pub const RIVAL_DIR: &str = "rival";
+
+/// File name prefix of rivals imported from score files (IR rivals use the IR name).
+const IMPORTED_RIVAL_PREFIX: &str = "import_";
 
 /// Score data importer.
 /// Translated from Java: ScoreDataImporter
@@ -144,6 +154,115 @@ impl ScoreDataImporter {
         log::info!("Score import complete - imported count: {}", result.len());
     }
 
+    /// Import a rival's scores as `name` into `rival_dir`, replacing an earlier
+    /// import of the same name.
+    ///
+    /// `source` is a score database (`score.db`, or another rival database) or
+    /// a JSON array of score data. The source path is kept as the rival's id so
+    /// [`Self::refresh_rivals`] can import it again.
+    pub fn import_rival(source: &Path, name: &str, rival_dir: &Path) -> anyhow::Result<PathBuf> {
+        let name = name.trim();
+        anyhow::ensure!(!name.is_empty(), "rival name is empty");
+        let scores = Self::read_rival_scores(source)?;
+        anyhow::ensure!(
+            !scores.is_empty(),
+            "no scores found in {}",
+            source.display()
+        );
+
+        std::fs::create_dir_all(rival_dir)
+            .with_context(|| format!("failed to create {}", rival_dir.display()))?;
+        let path = rival_dir.join(format!(
+            "{}{}.db",
+            IMPORTED_RIVAL_PREFIX,
+            sanitize_rival_name(name)
+        ));
+        // Build into a fresh file and swap it in, so a failed refresh keeps the old scores.
+        let tmp = path.with_extension("db.tmp");
+        let _ = std::fs::remove_file(&tmp);
+        {
+            let tmp_str = tmp.to_string_lossy();
+            let scoredb = ScoreDatabaseAccessor::new(&tmp_str)?;
+            scoredb.create_table()?;
+            let source_id = std::path::absolute(source).unwrap_or_else(|_| source.to_path_buf());
+            scoredb.set_information(&PlayerInformation {
+                id: Some(source_id.to_string_lossy().into_owned()),
+                name: Some(name.to_string()),
+                rank: None,
+            });
+            let refs: Vec<&ScoreData> = scores.iter().collect();
+            scoredb.set_score_data_batch(&refs);
+        }
+        std::fs::rename(&tmp, &path)
+            .with_context(|| format!("failed to replace {}", path.display()))?;
+        log::info!(
+            "Rival {} imported from {}: {} scores",
+            name,
+            source.display(),
+            scores.len()
+        );
+        Ok(path)
+    }
+
+    /// Rivals previously imported into `rival_dir`, with their database paths.
+    pub fn imported_rivals(rival_dir: &Path) -> Vec<(PathBuf, PlayerInformation)> {
+        let Ok(entries) = std::fs::read_dir(rival_dir) else {
+            return Vec::new();
+        };
+        let mut rivals: Vec<_> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension().is_some_and(|ext| ext == "db")
+                    && path
+                        .file_name()
+                        .and_then(|f| f.to_str())
+                        .is_some_and(|f| f.starts_with(IMPORTED_RIVAL_PREFIX))
+            })
+            .filter_map(|path| {
+                let info = ScoreDatabaseAccessor::new(&path.to_string_lossy())
+                    .ok()?
+                    .information()?;
+                Some((path, info))
+            })
+            .collect();
+        rivals.sort_by(|a, b| a.0.cmp(&b.0));
+        rivals
+    }
+
+    /// Import every rival in `rival_dir` again from the file it was imported from.
+    /// Returns the number of rivals refreshed; failures are logged and skipped.
+    pub fn refresh_rivals(rival_dir: &Path) -> usize {
+        let mut refreshed = 0;
+        for (_, info) in Self::imported_rivals(rival_dir) {
+            let source = PathBuf::from(info.id.as_deref().unwrap_or_default());
+            match Self::import_rival(&source, info.name(), rival_dir) {
+                Ok(_) => refreshed += 1,
+                Err(e) => log::warn!("Failed to refresh rival {}: {:#}", info.name(), e),
+            }
+        }
+        refreshed
+    }
+
+    fn read_rival_scores(source: &Path) -> anyhow::Result<Vec<ScoreData>> {
+        anyhow::ensure!(source.is_file(), "{} does not exist", source.display());
+        if source
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+        {
+            let data = std::fs::read_to_string(source)
+                .with_context(|| format!("failed to read {}", source.display()))?;
+            let mut scores: Vec<ScoreData> = serde_json::from_str(&data)
+                .with_context(|| format!("failed to parse {}", source.display()))?;
+            use crate::skin::validatable::Validatable;
+            scores.retain_mut(|s| s.validate());
+            Ok(scores)
+        } else {
+            ScoreDatabaseAccessor::read_score_file(&source.to_string_lossy())
+                .with_context(|| format!("failed to read scores from {}", source.display()))
+        }
+    }
+
     /// Clamp an i64 value from external data to i32 range, preventing silent wrapping.
     fn clamp_nonneg_i64_to_i32(val: i64) -> i32 {
         val.clamp(0, i32::MAX as i64) as i32
@@ -184,6 +303,17 @@ impl ScoreDataImporter {
     }
 }
 
+/// Characters that are unsafe in file names are replaced with `_`.
+fn sanitize_rival_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '\\' | '/' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -231,4 +361,121 @@ mod tests {
             i32::MAX
         );
     }
+
+    fn score(sha256: &str, exscore_half: i32, clear: i32) -> ScoreData {
+        let mut sd = ScoreData {
+            sha256: sha256.to_string(),
+            notes: 1000,
+            clear,
+            ..Default::default()
+        };
+        sd.judge_counts.epg = exscore_half;
+        sd
+    }
+
+    fn write_score_db(path: &Path, scores: &[ScoreData]) {
+        let db = ScoreDatabaseAccessor::new(&path.to_string_lossy()).unwrap();
+        db.create_table().unwrap();
+        let refs: Vec<&ScoreData> = scores.iter().collect();
+        db.set_score_data_batch(&refs);
+    }
+
+    fn rival_scores(path: &Path, sha256: &str) -> Option<ScoreData> {
+        ScoreDatabaseAccessor::new(&path.to_string_lossy())
+            .unwrap()
+            .score_data(sha256, 0)
+    }
+
+    #[test]
+    fn import_rival_from_score_db_stores_named_rival() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("score.db");
+        write_score_db(&source, &[score("aaa", 400, 5), score("bbb", 300, 6)]);
+        let rival_dir = dir.path().join("rival");
+
+        let path = ScoreDataImporter::import_rival(&source, "Alice", &rival_dir).unwrap();
+
+        assert_eq!(path, rival_dir.join("import_Alice.db"));
+        assert_eq!(rival_scores(&path, "aaa").unwrap().exscore(), 800);
+        assert_eq!(rival_scores(&path, "bbb").unwrap().clear, 6);
+        let rivals = ScoreDataImporter::imported_rivals(&rival_dir);
+        assert_eq!(rivals.len(), 1);
+        assert_eq!(rivals[0].1.name(), "Alice");
+        assert!(rivals[0].1.id.as_deref().unwrap().ends_with("score.db"));
+        assert!(!path.with_extension("db.tmp").exists());
+    }
+
+    #[test]
+    fn import_rival_from_json_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("scores.json");
+        let mut invalid = score("bad", 1, 0);
+        invalid.notes = 0;
+        std::fs::write(
+            &source,
+            serde_json::to_string(&vec![score("aaa", 100, 4), invalid]).unwrap(),
+        )
+        .unwrap();
+
+        let path =
+            ScoreDataImporter::import_rival(&source, "Bob", &dir.path().join("rival")).unwrap();
+        assert_eq!(rival_scores(&path, "aaa").unwrap().exscore(), 200);
+        assert!(rival_scores(&path, "bad").is_none());
+    }
+
+    #[test]
+    fn reimport_replaces_scores_and_refresh_rereads_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("score.db");
+        write_score_db(&source, &[score("aaa", 400, 5), score("old", 100, 1)]);
+        let rival_dir = dir.path().join("rival");
+        let path = ScoreDataImporter::import_rival(&source, "Alice", &rival_dir).unwrap();
+
+        std::fs::remove_file(&source).unwrap();
+        write_score_db(&source, &[score("aaa", 450, 7)]);
+        assert_eq!(ScoreDataImporter::refresh_rivals(&rival_dir), 1);
+
+        let aaa = rival_scores(&path, "aaa").unwrap();
+        assert_eq!(aaa.exscore(), 900);
+        assert_eq!(aaa.clear, 7);
+        assert!(
+            rival_scores(&path, "old").is_none(),
+            "old scores are replaced"
+        );
+    }
+
+    #[test]
+    fn failed_refresh_keeps_previous_scores() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("score.db");
+        write_score_db(&source, &[score("aaa", 400, 5)]);
+        let rival_dir = dir.path().join("rival");
+        let path = ScoreDataImporter::import_rival(&source, "Alice", &rival_dir).unwrap();
+
+        std::fs::remove_file(&source).unwrap();
+        assert_eq!(ScoreDataImporter::refresh_rivals(&rival_dir), 0);
+        assert_eq!(rival_scores(&path, "aaa").unwrap().exscore(), 800);
+    }
+
+    #[test]
+    fn import_rival_rejects_bad_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let rival_dir = dir.path().join("rival");
+        let missing = dir.path().join("missing.db");
+        assert!(ScoreDataImporter::import_rival(&missing, "Alice", &rival_dir).is_err());
+
+        let source = dir.path().join("score.db");
+        write_score_db(&source, &[score("aaa", 400, 5)]);
+        assert!(ScoreDataImporter::import_rival(&source, "  ", &rival_dir).is_err());
+
+        let empty = dir.path().join("empty.db");
+        write_score_db(&empty, &[]);
+        assert!(ScoreDataImporter::import_rival(&empty, "Carol", &rival_dir).is_err());
+    }
+
+    #[test]
+    fn sanitize_rival_name_replaces_path_characters() {
+        assert_eq!(sanitize_rival_name("a/b\\c:d"), "a_b_c_d");
+        assert_eq!(sanitize_rival_name("ライバル"), "ライバル");
+    }
 }
@@ -1,3 +1,4 @@
+use crate::core::config::WEBHOOK_DJ_LEVELS;
 use crate::external::{
     BooleanPropertyFactory, IntegerPropertyFactory, MainState, NUMBER_CLEAR, OPTION_RESULT_A_1P,
     OPTION_RESULT_AA_1P, OPTION_RESULT_AAA_1P, OPTION_RESULT_B_1P, OPTION_RESULT_C_1P,
@@ -13,7 +14,13 @@ pub trait ScreenShotExporter {
 /// Returns the clear type name string for the current state.
 /// Translated from Java: ScreenShotExporter.getClearTypeName (static default method)
 pub fn clear_type_name(current_state: &MainState) -> String {
-    let clear_type_name: [&str; 11] = [
+    let clear = IntegerPropertyFactory::integer_property(NUMBER_CLEAR).get(current_state);
+    clear_name(clear).to_string()
+}
+
+/// Display name of a clear type id (`ClearType::id`), or `""` when out of range.
+pub fn clear_name(clear: i32) -> &'static str {
+    const CLEAR_TYPE_NAME: [&str; 11] = [
         "NO PLAY",
         "FAILED",
         "ASSIST EASY CLEAR",
@@ -26,29 +33,31 @@ pub fn clear_type_name(current_state: &MainState) -> String {
         "PERFECT",
         "MAX",
     ];
-
-    let clear = IntegerPropertyFactory::integer_property(NUMBER_CLEAR).get(current_state);
-    if clear >= 0 && (clear as usize) < clear_type_name.len() {
-        return clear_type_name[clear as usize].to_string();
-    }
-
-    String::new()
+    usize::try_from(clear)
+        .ok()
+        .and_then(|i| CLEAR_TYPE_NAME.get(i))
+        .copied()
+        .unwrap_or("")
 }
 
 /// Returns the clear type colour as an integer for the current state.
 /// Translated from Java: ScreenShotExporter.getClearTypeColour (static default method)
 pub fn clear_type_colour(current_state: &MainState) -> i32 {
-    let clear_type_rgb: [&str; 11] = [
+    let clear = IntegerPropertyFactory::integer_property(NUMBER_CLEAR).get(current_state);
+    clear_colour(clear)
+}
+
+/// RGB colour of a clear type id, or 0 when out of range.
+pub fn clear_colour(clear: i32) -> i32 {
+    const CLEAR_TYPE_RGB: [&str; 11] = [
         "7F7F7F", "8A0000", "9F39CF", "C467D5", "00D70F", "229AFF", "FDFDFD", "FFDB00", "78FFF7",
         "A7F583", "F0F0FF",
     ];
-
-    let clear = IntegerPropertyFactory::integer_property(NUMBER_CLEAR).get(current_state);
-    if clear >= 0 && (clear as usize) < clear_type_rgb.len() {
-        return i32::from_str_radix(clear_type_rgb[clear as usize], 16).unwrap_or(0);
-    }
-
-    0
+    usize::try_from(clear)
+        .ok()
+        .and_then(|i| CLEAR_TYPE_RGB.get(i))
+        .and_then(|rgb| i32::from_str_radix(rgb, 16).ok())
+        .unwrap_or(0)
 }
 
 /// Returns the rank type name string for the current state.
@@ -74,3 +83,44 @@ pub fn rank_type_name(current_state: &MainState) -> String {
     }
     rank_type_name
 }
+
+/// Index into [`WEBHOOK_DJ_LEVELS`] for an EX score: AAA from 8/9 of the
+/// maximum, AA from 7/9 and so on down to F.
+pub fn dj_level(exscore: i32, max_score: i32) -> usize {
+    if max_score <= 0 {
+        return 0;
+    }
+    let ninths = (exscore.max(0) as i64 * 9 / max_score as i64) as usize;
+    ninths.saturating_sub(1).min(WEBHOOK_DJ_LEVELS.len() - 1)
+}
+
+/// DJ LEVEL name for an EX score, computed from the score rather than skin options.
+pub fn rank_name(exscore: i32, max_score: i32) -> &'static str {
+    WEBHOOK_DJ_LEVELS[dj_level(exscore, max_score)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clear_name_and_colour_by_id() {
+        assert_eq!(clear_name(0), "NO PLAY");
+        assert_eq!(clear_name(6), "HARD CLEAR");
+        assert_eq!(clear_name(10), "MAX");
+        assert_eq!(clear_name(11), "");
+        assert_eq!(clear_name(-1), "");
+        assert_eq!(clear_colour(7), 0xFFDB00);
+        assert_eq!(clear_colour(99), 0);
+    }
+
+    #[test]
+    fn rank_name_uses_ninths_of_max_score() {
+        assert_eq!(rank_name(0, 900), "F");
+        assert_eq!(rank_name(200, 900), "E");
+        assert_eq!(rank_name(699, 900), "A");
+        assert_eq!(rank_name(700, 900), "AA");
+        assert_eq!(rank_name(900, 900), "AAA");
+        assert_eq!(rank_name(10, 0), "F");
+    }
+}
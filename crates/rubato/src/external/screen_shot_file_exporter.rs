@@ -5,6 +5,7 @@ use crate::external::{
     NUMBER_PLAYLEVEL, Pixmap, PixmapIO, STRING_FULLTITLE, STRING_TABLE_LEVEL, ScreenType,
     StringPropertyFactory,
 };
+use crate::tr;
 
 /// ScreenShotFileExporter - saves screenshots to file and optionally copies to clipboard / sends webhook.
 /// Translated from Java: ScreenShotFileExporter implements ScreenShotExporter
@@ -97,7 +98,10 @@ impl ScreenShotExporter for ScreenShotFileExporter {
             PixmapIO::write_png(&path, &pixmap);
             log::info!("Screenshot saved: {}", path);
             pixmap.dispose();
-            ImGuiNotify::info_with_dismiss(&format!("Screen shot saved: {}", path), 2000);
+            ImGuiNotify::info_with_dismiss(
+                &tr!("notify-screenshot-saved", path = path.to_string()),
+                2000,
+            );
 
             self.send_clipboard(current_state, &path);
             self.send_webhook(current_state, &path);
@@ -136,7 +140,7 @@ impl ScreenShotFileExporter {
         match ClipboardHelper::copy_image_to_clipboard(path) {
             Ok(()) => {
                 log::info!("Screenshot saved: Clipboard");
-                ImGuiNotify::info_with_dismiss("Screen shot saved : Clipboard", 2000);
+                ImGuiNotify::info_with_dismiss(&tr!("notify-screenshot-clipboard"), 2000);
             }
             Err(e) => {
                 log::error!("Clipboard copy error: {}", e);
@@ -157,6 +161,12 @@ impl ScreenShotFileExporter {
             return;
         }
 
+        let handler = WebhookHandler::new();
+        if !handler.should_send(current_state) {
+            log::info!("Webhook skipped: result does not meet the configured conditions");
+            return;
+        }
+
         // Extract all data from current_state before spawning the background thread,
         // since MainState is not Send.
         let webhook_urls: Vec<String> = current_state
@@ -166,7 +176,6 @@ impl ScreenShotFileExporter {
             .webhook_url
             .to_vec();
 
-        let handler = WebhookHandler::new();
         let payload = match (|| -> Result<String, Box<dyn std::error::Error>> {
             let p = handler.create_webhook_payload(current_state);
             Ok(serde_json::to_string(&p)?)
@@ -0,0 +1,580 @@
+// Posting result summaries with the result screenshot to Mastodon, Misskey and Twitter.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Context;
+use base64::Engine;
+use serde::Deserialize;
+use sha1::{Digest, Sha1};
+
+use crate::core::config::IntegrationConfig;
+use crate::core::player_config::PlayerConfig;
+use crate::external::ImGuiNotify;
+use crate::external::result_screenshot::ResultScreenshotInfo;
+use crate::tr;
+
+/// Post text used when `IntegrationConfig::social_share_text` is empty.
+pub const DEFAULT_SHARE_TEXT: &str =
+    "{title}\n{clear} {rank} EX SCORE {exscore}/{maxscore} ({rate}%)\n#rubato";
+
+const TWITTER_MEDIA_UPLOAD_URL: &str = "https://upload.twitter.com/1.1/media/upload.json";
+const TWITTER_TWEET_URL: &str = "https://api.twitter.com/2/tweets";
+
+static HTTP_CLIENT: std::sync::OnceLock<reqwest::blocking::Client> = std::sync::OnceLock::new();
+
+fn get_http_client() -> &'static reqwest::blocking::Client {
+    HTTP_CLIENT.get_or_init(|| {
+        reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(60))
+            .build()
+            .unwrap_or_else(|_| reqwest::blocking::Client::new())
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SocialService {
+    Mastodon,
+    Misskey,
+    Twitter,
+}
+
+impl SocialService {
+    pub fn name(self) -> &'static str {
+        match self {
+            SocialService::Mastodon => "Mastodon",
+            SocialService::Misskey => "Misskey",
+            SocialService::Twitter => "Twitter",
+        }
+    }
+}
+
+/// Twitter OAuth 1.0a user credentials.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TwitterCredentials {
+    pub consumer_key: String,
+    pub consumer_secret: String,
+    pub access_token: String,
+    pub access_token_secret: String,
+}
+
+/// The accounts a result is posted to. Services without complete credentials are None.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SocialAccounts {
+    /// Instance base url and access token.
+    pub mastodon: Option<(String, String)>,
+    /// Instance base url and access token.
+    pub misskey: Option<(String, String)>,
+    pub twitter: Option<TwitterCredentials>,
+}
+
+impl SocialAccounts {
+    /// Fediverse accounts come from the integration settings, Twitter from the
+    /// player's `twitter*` keys.
+    pub fn from_config(integration: &IntegrationConfig, player: &PlayerConfig) -> Self {
+        let account = |instance: &str, token: &str| {
+            let instance = instance_url(instance)?;
+            let token = token.trim();
+            (!token.is_empty()).then(|| (instance, token.to_string()))
+        };
+        let key = |value: Option<&str>| {
+            value
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        };
+        let twitter = match (
+            key(player.twitter_consumer_key()),
+            key(player.twitter_consumer_secret()),
+            key(player.twitter_access_token()),
+            key(player.twitter_access_token_secret()),
+        ) {
+            (Some(consumer_key), Some(consumer_secret), Some(access_token), Some(secret)) => {
+                Some(TwitterCredentials {
+                    consumer_key,
+                    consumer_secret,
+                    access_token,
+                    access_token_secret: secret,
+                })
+            }
+            _ => None,
+        };
+        Self {
+            mastodon: account(
+                &integration.mastodon_instance,
+                &integration.mastodon_access_token,
+            ),
+            misskey: account(
+                &integration.misskey_instance,
+                &integration.misskey_access_token,
+            ),
+            twitter,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mastodon.is_none() && self.misskey.is_none() && self.twitter.is_none()
+    }
+}
+
+/// Normalize an instance given as `example.social` or `https://example.social/`.
+fn instance_url(instance: &str) -> Option<String> {
+    let instance = instance.trim().trim_end_matches('/');
+    if instance.is_empty() {
+        return None;
+    }
+    if instance.starts_with("https://") || instance.starts_with("http://") {
+        Some(instance.to_string())
+    } else {
+        Some(format!("https://{}", instance))
+    }
+}
+
+/// Fill the share template. Placeholders: `{title}`, `{clear}`, `{rank}`,
+/// `{exscore}`, `{maxscore}` and `{rate}`.
+pub fn share_text(template: &str, info: &ResultScreenshotInfo) -> String {
+    let template = if template.trim().is_empty() {
+        DEFAULT_SHARE_TEXT
+    } else {
+        template
+    };
+    let rate = if info.max_score > 0 {
+        info.exscore as f64 * 100.0 / info.max_score as f64
+    } else {
+        0.0
+    };
+    template
+        .replace("\\n", "\n")
+        .replace("{title}", &info.title)
+        .replace("{clear}", info.clear_name())
+        .replace("{rank}", info.rank_name())
+        .replace("{exscore}", &info.exscore.to_string())
+        .replace("{maxscore}", &info.max_score.to_string())
+        .replace("{rate}", &format!("{:.2}", rate))
+}
+
+/// Post `text` with the image at `image` to every configured account.
+pub fn share_result(
+    accounts: &SocialAccounts,
+    text: &str,
+    image: &Path,
+) -> Vec<(SocialService, anyhow::Result<()>)> {
+    let mut results = Vec::new();
+    let image = match std::fs::read(image) {
+        Ok(image) => image,
+        Err(e) => {
+            let message = format!("failed to read {}: {}", image.display(), e);
+            for service in configured_services(accounts) {
+                results.push((service, Err(anyhow::anyhow!(message.clone()))));
+            }
+            return results;
+        }
+    };
+    if let Some((instance, token)) = &accounts.mastodon {
+        results.push((
+            SocialService::Mastodon,
+            post_mastodon(instance, token, text, &image),
+        ));
+    }
+    if let Some((instance, token)) = &accounts.misskey {
+        results.push((
+            SocialService::Misskey,
+            post_misskey(instance, token, text, &image),
+        ));
+    }
+    if let Some(credentials) = &accounts.twitter {
+        results.push((
+            SocialService::Twitter,
+            post_twitter(credentials, text, &image),
+        ));
+    }
+    results
+}
+
+/// Run `share_result` on a background thread and report each outcome as a notification.
+pub fn share_result_in_background(accounts: SocialAccounts, text: String, image: &Path) {
+    let image = image.to_path_buf();
+    std::thread::spawn(move || {
+        for (service, result) in share_result(&accounts, &text, &image) {
+            match result {
+                Ok(()) => ImGuiNotify::info(&tr!(
+                    "notify-share-posted",
+                    service = service.name().to_string()
+                )),
+                Err(e) => {
+                    log::warn!("Failed to post result to {}: {:#}", service.name(), e);
+                    ImGuiNotify::error(&tr!(
+                        "notify-share-failed",
+                        service = service.name().to_string(),
+                        error = e.to_string()
+                    ));
+                }
+            }
+        }
+    });
+}
+
+fn configured_services(accounts: &SocialAccounts) -> Vec<SocialService> {
+    let mut services = Vec::new();
+    if accounts.mastodon.is_some() {
+        services.push(SocialService::Mastodon);
+    }
+    if accounts.misskey.is_some() {
+        services.push(SocialService::Misskey);
+    }
+    if accounts.twitter.is_some() {
+        services.push(SocialService::Twitter);
+    }
+    services
+}
+
+#[derive(Deserialize)]
+struct IdResponse {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct TwitterMediaResponse {
+    media_id_string: String,
+}
+
+fn post_mastodon(instance: &str, token: &str, text: &str, image: &[u8]) -> anyhow::Result<()> {
+    let client = get_http_client();
+    let (boundary, body) = multipart_body(&[], "file", image);
+    let media: IdResponse = client
+        .post(format!("{}/api/v2/media", instance))
+        .bearer_auth(token)
+        .header("Content-Type", multipart_content_type(&boundary))
+        .body(body)
+        .send()?
+        .error_for_status()
+        .context("media upload rejected")?
+        .json()?;
+    client
+        .post(format!("{}/api/v1/statuses", instance))
+        .bearer_auth(token)
+        .json(&serde_json::json!({ "status": text, "media_ids": [media.id] }))
+        .send()?
+        .error_for_status()
+        .context("status rejected")?;
+    Ok(())
+}
+
+fn post_misskey(instance: &str, token: &str, text: &str, image: &[u8]) -> anyhow::Result<()> {
+    let client = get_http_client();
+    let (boundary, body) = multipart_body(&[("i", token)], "file", image);
+    let file: IdResponse = client
+        .post(format!("{}/api/drive/files/create", instance))
+        .header("Content-Type", multipart_content_type(&boundary))
+        .body(body)
+        .send()?
+        .error_for_status()
+        .context("drive upload rejected")?
+        .json()?;
+    client
+        .post(format!("{}/api/notes/create", instance))
+        .json(&serde_json::json!({ "i": token, "text": text, "fileIds": [file.id] }))
+        .send()?
+        .error_for_status()
+        .context("note rejected")?;
+    Ok(())
+}
+
+fn post_twitter(credentials: &TwitterCredentials, text: &str, image: &[u8]) -> anyhow::Result<()> {
+    let client = get_http_client();
+    // Multipart and JSON bodies are not part of the OAuth signature.
+    let (boundary, body) = multipart_body(&[], "media", image);
+    let media: TwitterMediaResponse = client
+        .post(TWITTER_MEDIA_UPLOAD_URL)
+        .header(
+            "Authorization",
+            oauth1_header("POST", TWITTER_MEDIA_UPLOAD_URL, &[], credentials),
+        )
+        .header("Content-Type", multipart_content_type(&boundary))
+        .body(body)
+        .send()?
+        .error_for_status()
+        .context("media upload rejected")?
+        .json()?;
+    client
+        .post(TWITTER_TWEET_URL)
+        .header(
+            "Authorization",
+            oauth1_header("POST", TWITTER_TWEET_URL, &[], credentials),
+        )
+        .json(&serde_json::json!({
+            "text": text,
+            "media": { "media_ids": [media.media_id_string] },
+        }))
+        .send()?
+        .error_for_status()
+        .context("tweet rejected")?;
+    Ok(())
+}
+
+fn multipart_content_type(boundary: &str) -> String {
+    format!("multipart/form-data; boundary={}", boundary)
+}
+
+/// Build a multipart body with text `fields` followed by a PNG `file_field`.
+fn multipart_body(fields: &[(&str, &str)], file_field: &str, image: &[u8]) -> (String, Vec<u8>) {
+    let boundary = format!("----rubato{}", random_token());
+    let mut body = Vec::with_capacity(image.len() + 512);
+    for (name, value) in fields {
+        body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                boundary, name, value
+            )
+            .as_bytes(),
+        );
+    }
+    body.extend_from_slice(
+        format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"result.png\"\r\nContent-Type: image/png\r\n\r\n",
+            boundary, file_field
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(image);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+    (boundary, body)
+}
+
+fn random_token() -> String {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    (0..32)
+        .map(|_| char::from(b"0123456789abcdefghijklmnopqrstuvwxyz"[rng.gen_range(0..36)]))
+        .collect()
+}
+
+/// OAuth 1.0a `Authorization` header for a request to `url` with the given
+/// query/form `params`.
+fn oauth1_header(
+    method: &str,
+    url: &str,
+    params: &[(&str, &str)],
+    credentials: &TwitterCredentials,
+) -> String {
+    let timestamp = chrono::Utc::now().timestamp().to_string();
+    oauth1_header_with(
+        method,
+        url,
+        params,
+        credentials,
+        &random_token(),
+        &timestamp,
+    )
+}
+
+fn oauth1_header_with(
+    method: &str,
+    url: &str,
+    params: &[(&str, &str)],
+    credentials: &TwitterCredentials,
+    nonce: &str,
+    timestamp: &str,
+) -> String {
+    let mut oauth: HashMap<&str, &str> = HashMap::from([
+        ("oauth_consumer_key", credentials.consumer_key.as_str()),
+        ("oauth_nonce", nonce),
+        ("oauth_signature_method", "HMAC-SHA1"),
+        ("oauth_timestamp", timestamp),
+        ("oauth_token", credentials.access_token.as_str()),
+        ("oauth_version", "1.0"),
+    ]);
+
+    let mut encoded: Vec<(String, String)> = oauth
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .chain(params.iter().map(|(k, v)| (k.to_string(), v.to_string())))
+        .map(|(k, v)| (percent_encode(&k), percent_encode(&v)))
+        .collect();
+    encoded.sort();
+    let parameter_string = encoded
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join("&");
+    let base = format!(
+        "{}&{}&{}",
+        method.to_uppercase(),
+        percent_encode(url),
+        percent_encode(&parameter_string)
+    );
+    let key = format!(
+        "{}&{}",
+        percent_encode(&credentials.consumer_secret),
+        percent_encode(&credentials.access_token_secret)
+    );
+    let signature = base64::engine::general_purpose::STANDARD
+        .encode(hmac_sha1(key.as_bytes(), base.as_bytes()));
+    oauth.insert("oauth_signature", &signature);
+
+    let mut fields: Vec<(&str, &str)> = oauth.into_iter().collect();
+    fields.sort();
+    let fields = fields
+        .iter()
+        .map(|(k, v)| format!("{}=\"{}\"", percent_encode(k), percent_encode(v)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("OAuth {}", fields)
+}
+
+/// RFC 3986 encoding as required by OAuth 1.0a.
+fn percent_encode(value: &str) -> String {
+    urlencoding::encode(value).into_owned()
+}
+
+fn hmac_sha1(key: &[u8], message: &[u8]) -> [u8; 20] {
+    const BLOCK_SIZE: usize = 64;
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..20].copy_from_slice(&Sha1::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+    let mut inner = Sha1::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    inner.update(message);
+    let mut outer = Sha1::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn info() -> ResultScreenshotInfo {
+        ResultScreenshotInfo {
+            title: "Song [ANOTHER]".to_string(),
+            exscore: 1500,
+            max_score: 2000,
+            clear: 6,
+            date: chrono::Local
+                .with_ymd_and_hms(2026, 3, 9, 21, 5, 7)
+                .unwrap(),
+            social_share: false,
+        }
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn share_text_fills_default_and_custom_templates() {
+        assert_eq!(
+            share_text("", &info()),
+            "Song [ANOTHER]\nHARD CLEAR A EX SCORE 1500/2000 (75.00%)\n#rubato"
+        );
+        assert_eq!(
+            share_text("{rank} {title}\\n{rate}", &info()),
+            "A Song [ANOTHER]\n75.00"
+        );
+    }
+
+    #[test]
+    fn accounts_require_complete_credentials() {
+        let integration = IntegrationConfig {
+            mastodon_instance: "mstdn.example/".to_string(),
+            mastodon_access_token: "token".to_string(),
+            misskey_instance: "https://misskey.example".to_string(),
+            misskey_access_token: " ".to_string(),
+            ..Default::default()
+        };
+        let mut player = PlayerConfig {
+            twitter_consumer_key: Some("ck".to_string()),
+            twitter_consumer_secret: Some("cs".to_string()),
+            twitter_access_token: Some("at".to_string()),
+            ..Default::default()
+        };
+
+        let accounts = SocialAccounts::from_config(&integration, &player);
+        assert_eq!(
+            accounts.mastodon,
+            Some(("https://mstdn.example".to_string(), "token".to_string()))
+        );
+        assert_eq!(accounts.misskey, None);
+        assert_eq!(accounts.twitter, None);
+
+        player.twitter_access_token_secret = Some("ats".to_string());
+        let accounts = SocialAccounts::from_config(&integration, &player);
+        assert_eq!(accounts.twitter.unwrap().access_token_secret, "ats");
+        assert!(SocialAccounts::default().is_empty());
+    }
+
+    #[test]
+    fn hmac_sha1_matches_rfc2202() {
+        assert_eq!(
+            hex(&hmac_sha1(b"Jefe", b"what do ya want for nothing?")),
+            "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79"
+        );
+        assert_eq!(
+            hex(&hmac_sha1(
+                &[0xaa; 80],
+                b"Test Using Larger Than Block-Size Key - Hash Key First"
+            )),
+            "aa4ae5e15272d00e95705637ce8a3b55ed402112"
+        );
+    }
+
+    #[test]
+    fn oauth1_header_matches_twitter_example() {
+        // Example request from Twitter's "Creating a signature" documentation.
+        let credentials = TwitterCredentials {
+            consumer_key: "xvz1evFS4wEEPTGEFPHBog".to_string(),
+            consumer_secret: "kAcSOqF21Fu85e7zjz7ZN2U4ZRhfV3WpwPAoE3Z7kBw".to_string(),
+            access_token: "370773112-GmHxMAgYyLbNEtIKZeRNFsMKPR9EyMZeS9weJAEb".to_string(),
+            access_token_secret: "LswwdoUaIvS8ltyTt5jkRh4J50vUPVVHtR2YPi5kE".to_string(),
+        };
+        let header = oauth1_header_with(
+            "post",
+            "https://api.twitter.com/1.1/statuses/update.json",
+            &[
+                ("include_entities", "true"),
+                (
+                    "status",
+                    "Hello Ladies + Gentlemen, a signed OAuth request!",
+                ),
+            ],
+            &credentials,
+            "kYjzVBB8Y0ZFabxSWbWovY3uYSQ2pTgmZeNu2VS4cg",
+            "1318622958",
+        );
+        assert!(header.starts_with("OAuth oauth_consumer_key=\"xvz1evFS4wEEPTGEFPHBog\""));
+        assert!(
+            header.contains("oauth_signature=\"hCtSmYh%2BiHYCEqBWrE7C7hYmtUk%3D\""),
+            "{}",
+            header
+        );
+    }
+
+    #[test]
+    fn multipart_body_contains_fields_and_image() {
+        let (boundary, body) = multipart_body(&[("i", "token")], "file", b"PNGDATA");
+        let body = String::from_utf8(body).unwrap();
+        assert!(body.starts_with(&format!("--{}\r\n", boundary)));
+        assert!(body.contains("name=\"i\"\r\n\r\ntoken\r\n"));
+        assert!(body.contains("name=\"file\"; filename=\"result.png\""));
+        assert!(body.ends_with(&format!("PNGDATA\r\n--{}--\r\n", boundary)));
+    }
+
+    #[test]
+    fn share_result_reports_unreadable_image_per_service() {
+        let accounts = SocialAccounts {
+            mastodon: Some(("https://mstdn.example".to_string(), "t".to_string())),
+            misskey: Some(("https://misskey.example".to_string(), "t".to_string())),
+            twitter: None,
+        };
+        let results = share_result(&accounts, "text", Path::new("/nonexistent/result.png"));
+        let services: Vec<_> = results.iter().map(|(s, _)| *s).collect();
+        assert_eq!(services, [SocialService::Mastodon, SocialService::Misskey]);
+        assert!(results.iter().all(|(_, r)| r.is_err()));
+    }
+}
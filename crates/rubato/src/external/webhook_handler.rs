@@ -3,12 +3,18 @@ use std::io::Write;
 use std::path::Path;
 use std::time::Duration;
 
+use anyhow::Context;
+use serde::Deserialize;
+
+use crate::core::config::IntegrationConfig;
 use crate::external::screen_shot_exporter;
 use crate::external::{
-    AbstractResultAccess, ImGuiNotify, IntegerPropertyFactory, MainState, Mode, NUMBER_MAXSCORE,
-    ReplayData, STRING_FULLTITLE, STRING_TABLE_LEVEL, STRING_TABLE_NAME, ScoreData, ScreenType,
-    StringPropertyFactory,
+    AbstractResultAccess, Config, ImGuiNotify, IntegerPropertyFactory, MainState, Mode,
+    NUMBER_MAXSCORE, ReplayData, STRING_FULLTITLE, STRING_TABLE_LEVEL, STRING_TABLE_NAME,
+    ScoreData, ScreenType, StringPropertyFactory,
 };
+use crate::skin::clear_type::ClearType;
+use crate::tr;
 
 static HTTP_CLIENT: std::sync::OnceLock<reqwest::blocking::Client> = std::sync::OnceLock::new();
 
@@ -104,9 +110,9 @@ impl WebhookHandler {
 
             let response_code = response.status().as_u16();
             if response_code != 200 && response_code != 204 {
-                ImGuiNotify::warning(&format!(
-                    "Unexpected http response code when sending webhook: {}",
-                    response_code
+                ImGuiNotify::warning(&tr!(
+                    "notify-webhook-http-error",
+                    status = response_code.to_string()
                 ));
             }
             Ok(())
@@ -207,43 +213,29 @@ impl WebhookHandler {
             // Score specific
             if screen_type == ScreenType::MusicResult || screen_type == ScreenType::CourseResult {
                 if let Some(result_state) = get_abstract_result(current_state) {
-                    let new_score = result_state.new_score();
-                    let old_score = result_state.old_score();
                     let max_score = IntegerPropertyFactory::integer_property(NUMBER_MAXSCORE)
                         .get(current_state);
 
-                    let mut description = String::new();
-                    description += &format!(
-                        "**DJ LEVEL:** {} \n",
-                        Self::format_rank(current_state, new_score, max_score)
-                    );
-                    description += &format!(
-                        "**EX SCORE: {}** {}\n",
-                        new_score.exscore(),
-                        Self::format_diff(new_score.exscore(), old_score.exscore())
-                    );
-                    description += &format!(
-                        "**BAD/POOR: {}** {}\n",
-                        Self::get_bp_count(new_score),
-                        Self::format_diff(
-                            Self::get_bp_count(new_score),
-                            Self::get_bp_count(old_score)
-                        )
-                    );
-                    if result_state.ir_rank() != 0 {
-                        description += &format!(
-                            "**IR RANK: {}/{}** {}\n",
-                            result_state.ir_rank(),
-                            result_state.ir_total_player(),
-                            Self::format_diff(result_state.ir_rank(), result_state.old_ir_rank())
+                    let description =
+                        Self::result_description(current_state, result_state, max_score);
+
+                    if let Some(template) = load_template(current_state.resource.config()) {
+                        let vars = Self::result_variables(
+                            current_state,
+                            result_state,
+                            max_score,
+                            &template,
+                            description,
                         );
+                        let mut embed = template.render(&vars);
+                        if let Some(embed) = embed.as_object_mut() {
+                            embed.entry("image").or_insert_with(
+                                || serde_json::json!({"url": "attachment://screenshot.png"}),
+                            );
+                        }
+                        payload.insert("embeds".to_string(), serde_json::json!([embed]));
+                        return payload;
                     }
-                    if *current_state.resource.original_mode() == Mode::BEAT_7K
-                        && let Some(rd) = current_state.resource.replay_data()
-                    {
-                        description += &format!("**PATTERN: {}** \n", Self::format_random(rd));
-                    }
-                    description += &Self::format_links(current_state);
 
                     let mut footer: HashMap<String, String> = HashMap::new();
                     embed.insert(
@@ -297,6 +289,118 @@ impl WebhookHandler {
         payload
     }
 
+    /// Whether the screenshot of `current_state` passes the webhook conditions.
+    ///
+    /// The conditions only concern scores, so screens other than the result
+    /// screens always pass.
+    pub fn should_send(&self, current_state: &MainState) -> bool {
+        let screen_type = get_screen_type(current_state);
+        if screen_type != ScreenType::MusicResult && screen_type != ScreenType::CourseResult {
+            return true;
+        }
+        let Some(result_state) = get_abstract_result(current_state) else {
+            return true;
+        };
+        let max_score =
+            IntegerPropertyFactory::integer_property(NUMBER_MAXSCORE).get(current_state);
+        passes_conditions(
+            &current_state.resource.config().integration,
+            result_state.new_score(),
+            result_state.old_score(),
+            max_score,
+        )
+    }
+
+    /// Markdown scorecard body: DJ LEVEL, EX SCORE, BAD/POOR, IR rank, pattern and links.
+    fn result_description(
+        current_state: &MainState,
+        result_state: &dyn AbstractResultAccess,
+        max_score: i32,
+    ) -> String {
+        let new_score = result_state.new_score();
+        let old_score = result_state.old_score();
+        let mut description = String::new();
+        description += &format!(
+            "**DJ LEVEL:** {} \n",
+            Self::format_rank(current_state, new_score, max_score)
+        );
+        description += &format!(
+            "**EX SCORE: {}** {}\n",
+            new_score.exscore(),
+            Self::format_diff(new_score.exscore(), old_score.exscore())
+        );
+        description += &format!(
+            "**BAD/POOR: {}** {}\n",
+            Self::get_bp_count(new_score),
+            Self::format_diff(Self::get_bp_count(new_score), Self::get_bp_count(old_score))
+        );
+        if result_state.ir_rank() != 0 {
+            description += &format!(
+                "**IR RANK: {}/{}** {}\n",
+                result_state.ir_rank(),
+                result_state.ir_total_player(),
+                Self::format_diff(result_state.ir_rank(), result_state.old_ir_rank())
+            );
+        }
+        if *current_state.resource.original_mode() == Mode::BEAT_7K
+            && let Some(rd) = current_state.resource.replay_data()
+        {
+            description += &format!("**PATTERN: {}** \n", Self::format_random(rd));
+        }
+        description += &Self::format_links(current_state);
+        description
+    }
+
+    /// Placeholder values for a [`WebhookTemplate`] on a result screen.
+    fn result_variables(
+        current_state: &MainState,
+        result_state: &dyn AbstractResultAccess,
+        max_score: i32,
+        template: &WebhookTemplate,
+        description: String,
+    ) -> HashMap<&'static str, String> {
+        let new_score = result_state.new_score();
+        let old_score = result_state.old_score();
+        let clear = screen_shot_exporter::clear_type_name(current_state);
+        let colour = template.clear_color(
+            &clear,
+            screen_shot_exporter::clear_type_colour(current_state),
+        );
+        let string = |id| StringPropertyFactory::string_property(id).get(current_state);
+        let mut vars = HashMap::from([
+            ("title", Self::create_title(current_state)),
+            ("fulltitle", string(STRING_FULLTITLE)),
+            ("table_name", string(STRING_TABLE_NAME)),
+            ("table_level", string(STRING_TABLE_LEVEL)),
+            ("clear", clear),
+            ("clear_color", format!("{:06X}", colour)),
+            ("rank", screen_shot_exporter::rank_type_name(current_state)),
+            (
+                "dj_level",
+                Self::format_rank(current_state, new_score, max_score),
+            ),
+            ("rate", Self::format_percent(new_score, max_score)),
+            ("exscore", new_score.exscore().to_string()),
+            (
+                "exscore_diff",
+                Self::format_diff(new_score.exscore(), old_score.exscore()),
+            ),
+            ("bp", Self::get_bp_count(new_score).to_string()),
+            (
+                "bp_diff",
+                Self::format_diff(Self::get_bp_count(new_score), Self::get_bp_count(old_score)),
+            ),
+            ("ir_rank", result_state.ir_rank().to_string()),
+            ("ir_total", result_state.ir_total_player().to_string()),
+            ("links", Self::format_links(current_state)),
+            ("description", description),
+        ]);
+        if let Some(rd) = current_state.resource.replay_data() {
+            vars.insert("pattern", Self::format_random(rd));
+        }
+        vars
+    }
+
     // BAD + POOR + EPOOR
     fn get_bp_count(score: &ScoreData) -> i32 {
         score.judge_count_total(3) + score.judge_count_total(4) + score.judge_count_total(5)
@@ -505,6 +609,124 @@ static GRADE_RANKS: [GradeRank; 12] = [
     GradeRank::new(0.0, "F+"),
 ];
 
+/// Whether a result passes the webhook conditions in `config`.
+///
+/// `old_score` is the best score before this play; `max_score` the chart's maximum EX score.
+pub fn passes_conditions(
+    config: &IntegrationConfig,
+    new_score: &ScoreData,
+    old_score: &ScoreData,
+    max_score: i32,
+) -> bool {
+    if config.webhook_only_clear && new_score.clear <= ClearType::Failed.id() {
+        return false;
+    }
+    if config.webhook_only_personal_best
+        && new_score.exscore() <= old_score.exscore()
+        && new_score.clear <= old_score.clear
+    {
+        return false;
+    }
+    screen_shot_exporter::dj_level(new_score.exscore(), max_score)
+        >= config.webhook_min_rank.max(0) as usize
+}
+
+/// Scorecard embed loaded from `IntegrationConfig::webhook_template`.
+///
+/// `embed` is a Discord embed object. Its strings may contain `{name}`
+/// placeholders (`title`, `clear`, `dj_level`, `exscore`, `exscore_diff`, `bp`,
+/// `description`, ...). A string `color` is read as hex after substitution,
+/// so `"{clear_color}"` picks the clear lamp colour. `clearColors` maps clear
+/// names such as `"HARD CLEAR"` to replacement hex colours.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct WebhookTemplate {
+    pub embed: serde_json::Value,
+    #[serde(rename = "clearColors")]
+    pub clear_colors: HashMap<String, String>,
+}
+
+impl WebhookTemplate {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let data = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let template: Self = serde_json::from_str(&data)
+            .with_context(|| format!("failed to parse {}", path.display()))?;
+        anyhow::ensure!(
+            template.embed.is_object(),
+            "{}: \"embed\" must be a JSON object",
+            path.display()
+        );
+        Ok(template)
+    }
+
+    /// Colour for `clear_name`, or `default` when the template does not override it.
+    pub fn clear_color(&self, clear_name: &str, default: i32) -> i32 {
+        self.clear_colors
+            .get(clear_name)
+            .and_then(|c| parse_color(c))
+            .unwrap_or(default)
+    }
+
+    /// The embed with every placeholder replaced.
+    pub fn render(&self, vars: &HashMap<&'static str, String>) -> serde_json::Value {
+        let mut embed = self.embed.clone();
+        fill_placeholders(&mut embed, vars);
+        if let Some(embed) = embed.as_object_mut()
+            && let Some(serde_json::Value::String(color)) = embed.get("color")
+        {
+            match parse_color(color) {
+                Some(color) => embed.insert("color".to_string(), color.into()),
+                None => embed.remove("color"),
+            };
+        }
+        embed
+    }
+}
+
+fn fill_placeholders(value: &mut serde_json::Value, vars: &HashMap<&'static str, String>) {
+    match value {
+        serde_json::Value::String(text) => {
+            for (name, replacement) in vars {
+                *text = text.replace(&format!("{{{}}}", name), replacement);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            items.iter_mut().for_each(|v| fill_placeholders(v, vars));
+        }
+        serde_json::Value::Object(map) => {
+            map.values_mut().for_each(|v| fill_placeholders(v, vars));
+        }
+        _ => {}
+    }
+}
+
+/// Parse an `RRGGBB` / `#RRGGBB` colour.
+fn parse_color(text: &str) -> Option<i32> {
+    i32::from_str_radix(text.trim().trim_start_matches('#'), 16)
+        .ok()
+        .filter(|c| (0..=0xFFFFFF).contains(c))
+}
+
+/// Load the configured template, falling back to the built-in embed on error.
+fn load_template(config: &Config) -> Option<WebhookTemplate> {
+    let path = config.integration.webhook_template.trim();
+    if path.is_empty() {
+        return None;
+    }
+    match WebhookTemplate::load(Path::new(path)) {
+        Ok(template) => Some(template),
+        Err(e) => {
+            log::warn!("Webhook template ignored: {:#}", e);
+            ImGuiNotify::warning(&tr!(
+                "notify-webhook-template-ignored",
+                error = format!("{:#}", e)
+            ));
+            None
+        }
+    }
+}
+
 /// Determine the screen type from state.
 /// In Java this was done via instanceof checks; in Rust the MainState carries
 /// its screen type and exposes it via MainStateAccess::get_screen_type().
@@ -582,4 +804,175 @@ mod tests {
             ScreenType::Other
         );
     }
+
+    fn score(exscore: i32, clear: ClearType) -> ScoreData {
+        let mut sd = ScoreData::default();
+        sd.judge_counts.egr = exscore;
+        sd.clear = clear.id();
+        sd
+    }
+
+    #[test]
+    fn passes_conditions_defaults_always_send() {
+        let config = IntegrationConfig::default();
+        assert!(passes_conditions(
+            &config,
+            &score(0, ClearType::Failed),
+            &score(500, ClearType::Hard),
+            900
+        ));
+    }
+
+    #[test]
+    fn passes_conditions_only_clear_rejects_failed_and_no_play() {
+        let config = IntegrationConfig {
+            webhook_only_clear: true,
+            ..Default::default()
+        };
+        let old = ScoreData::default();
+        assert!(!passes_conditions(
+            &config,
+            &score(500, ClearType::Failed),
+            &old,
+            900
+        ));
+        assert!(!passes_conditions(
+            &config,
+            &score(500, ClearType::NoPlay),
+            &old,
+            900
+        ));
+        assert!(passes_conditions(
+            &config,
+            &score(500, ClearType::AssistEasy),
+            &old,
+            900
+        ));
+    }
+
+    #[test]
+    fn passes_conditions_personal_best_requires_score_or_lamp_improvement() {
+        let config = IntegrationConfig {
+            webhook_only_personal_best: true,
+            ..Default::default()
+        };
+        let old = score(500, ClearType::Normal);
+        assert!(!passes_conditions(
+            &config,
+            &score(500, ClearType::Normal),
+            &old,
+            900
+        ));
+        assert!(!passes_conditions(
+            &config,
+            &score(400, ClearType::Easy),
+            &old,
+            900
+        ));
+        assert!(passes_conditions(
+            &config,
+            &score(501, ClearType::Easy),
+            &old,
+            900
+        ));
+        assert!(passes_conditions(
+            &config,
+            &score(300, ClearType::Hard),
+            &old,
+            900
+        ));
+    }
+
+    #[test]
+    fn passes_conditions_min_rank() {
+        let config = IntegrationConfig {
+            webhook_min_rank: 6, // AA
+            ..Default::default()
+        };
+        let old = ScoreData::default();
+        assert!(!passes_conditions(
+            &config,
+            &score(699, ClearType::Hard),
+            &old,
+            900
+        ));
+        assert!(passes_conditions(
+            &config,
+            &score(700, ClearType::Hard),
+            &old,
+            900
+        ));
+    }
+
+    #[test]
+    fn should_send_ignores_conditions_outside_result_screens() {
+        let mut config = Config::default();
+        config.integration.webhook_only_clear = true;
+        let state = MainState {
+            resource: crate::external::PlayerResource::new(
+                crate::core::player_resource::PlayerResource::new(config, Default::default()),
+                Mode::BEAT_7K,
+            ),
+            screen_type: ScreenType::Other,
+            abstract_result: None,
+        };
+        assert!(WebhookHandler::new().should_send(&state));
+    }
+
+    fn template(json: &str) -> WebhookTemplate {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn template_render_substitutes_nested_placeholders() {
+        let t = template(
+            r#"{"embed": {"title": "{title} ({clear})",
+                "fields": [{"name": "EX", "value": "{exscore} {exscore_diff}"}],
+                "footer": {"text": "{unknown}"}}}"#,
+        );
+        let vars = HashMap::from([
+            ("title", "Song".to_string()),
+            ("clear", "HARD CLEAR".to_string()),
+            ("exscore", "1234".to_string()),
+            ("exscore_diff", "(+12)".to_string()),
+        ]);
+        let embed = t.render(&vars);
+        assert_eq!(embed["title"], "Song (HARD CLEAR)");
+        assert_eq!(embed["fields"][0]["value"], "1234 (+12)");
+        assert_eq!(embed["footer"]["text"], "{unknown}");
+    }
+
+    #[test]
+    fn template_render_parses_hex_color_and_drops_invalid() {
+        let t = template(r##"{"embed": {"color": "#{clear_color}"}}"##);
+        let vars = HashMap::from([("clear_color", "FF8000".to_string())]);
+        assert_eq!(t.render(&vars)["color"], 0xFF8000);
+
+        let t = template(r#"{"embed": {"color": "red"}}"#);
+        assert!(t.render(&HashMap::new()).get("color").is_none());
+
+        let t = template(r#"{"embed": {"color": 255}}"#);
+        assert_eq!(t.render(&HashMap::new())["color"], 255);
+    }
+
+    #[test]
+    fn template_clear_color_overrides_default() {
+        let t = template(
+            r##"{"embed": {}, "clearColors": {"HARD CLEAR": "#123456", "EASY CLEAR": "zz"}}"##,
+        );
+        assert_eq!(t.clear_color("HARD CLEAR", 0), 0x123456);
+        assert_eq!(t.clear_color("EASY CLEAR", 7), 7);
+        assert_eq!(t.clear_color("FAILED", 9), 9);
+    }
+
+    #[test]
+    fn template_load_rejects_non_object_embed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("webhook.json");
+        std::fs::write(&path, r#"{"embed": []}"#).unwrap();
+        assert!(WebhookTemplate::load(&path).is_err());
+        std::fs::write(&path, r#"{"embed": {"title": "{title}"}}"#).unwrap();
+        assert!(WebhookTemplate::load(&path).is_ok());
+        assert!(WebhookTemplate::load(&dir.path().join("missing.json")).is_err());
+    }
 }
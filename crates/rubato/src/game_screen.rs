@@ -261,6 +261,14 @@ impl GameScreen {
         delegate!(self, groove_gauge_value() -> Option<f32>)
     }
 
+    pub fn now_combo(&self) -> Option<i32> {
+        delegate!(self, now_combo() -> Option<i32>)
+    }
+
+    pub fn best_score(&self) -> Option<crate::core::score_data::ScoreData> {
+        delegate!(self, best_score() -> Option<crate::core::score_data::ScoreData>)
+    }
+
     pub fn get_image(&self, imageid: i32) -> Option<crate::render::texture::TextureRegion> {
         delegate!(self, get_image(imageid) -> Option<crate::render::texture::TextureRegion>)
     }
@@ -327,4 +335,20 @@ impl GameScreen {
     pub fn bms_model(&self) -> Option<&bms::model::bms_model::BMSModel> {
         delegate!(self, bms_model() -> Option<&bms::model::bms_model::BMSModel>)
     }
+
+    pub fn player_resource(&self) -> Option<&crate::core::player_resource::PlayerResource> {
+        delegate!(self, player_resource() -> Option<&crate::core::player_resource::PlayerResource>)
+    }
+
+    pub fn previous_clear_type(&self) -> Option<i32> {
+        delegate!(self, previous_clear_type() -> Option<i32>)
+    }
+
+    pub fn crash_snapshot(&self) -> Option<crate::play::crash_recovery::PlaySnapshot> {
+        delegate!(self, crash_snapshot() -> Option<crate::play::crash_recovery::PlaySnapshot>)
+    }
+
+    pub fn open_chart_file(&mut self, path: &std::path::Path) -> bool {
+        delegate!(mut self, open_chart_file(path) -> bool)
+    }
 }
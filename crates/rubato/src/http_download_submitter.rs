@@ -4,4 +4,38 @@
 pub trait HttpDownloadSubmitter: Send + Sync {
     /// Submit a download task for a missing chart by MD5 hash.
     fn submit_md5_task(&self, md5: &str, task_name: &str);
+
+    /// Submit a download task for a package url resolved elsewhere (e.g. BMS Search).
+    /// `hash` is the chart's md5, used to name the archive as a fallback.
+    fn submit_url_task(&self, url: &str, hash: &str, task_name: &str);
+
+    /// Like `submit_md5_task`, for a chart requested from the difficulty table
+    /// `table` so the package can be placed in that table's folder.
+    fn submit_md5_task_in_table(&self, md5: &str, task_name: &str, table: Option<&str>) {
+        let _ = table;
+        self.submit_md5_task(md5, task_name);
+    }
+
+    /// Like `submit_url_task`, for a chart requested from the difficulty table `table`.
+    fn submit_url_task_in_table(
+        &self,
+        url: &str,
+        hash: &str,
+        task_name: &str,
+        table: Option<&str>,
+    ) {
+        let _ = table;
+        self.submit_url_task(url, hash, task_name);
+    }
+
+    /// Number of downloaded packages scanned into the song database so far.
+    /// The select screen reloads its bars when this changes.
+    fn library_revision(&self) -> u64 {
+        0
+    }
+
+    /// Hold downloads while a chart loads or plays, and let them continue after.
+    fn set_downloads_suspended(&self, suspended: bool) {
+        let _ = suspended;
+    }
 }
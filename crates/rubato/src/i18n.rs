@@ -0,0 +1,291 @@
+//! Translated UI text for the launcher, the mod menu and in-game notices.
+//!
+//! Messages are Fluent resources under `locales/`, one file per language,
+//! compiled into the binary. Lookups go through a process-wide localizer
+//! selected from `Config::locale`; a message missing from the selected
+//! language falls back to English, and a message missing everywhere shows
+//! its id so gaps are visible instead of blank.
+
+use std::sync::{LazyLock, RwLock};
+
+pub use fluent_bundle::FluentArgs;
+use fluent_bundle::FluentResource;
+use fluent_bundle::concurrent::FluentBundle;
+use unic_langid::LanguageIdentifier;
+
+const EN_FTL: &str = include_str!("../locales/en.ftl");
+const JA_FTL: &str = include_str!("../locales/ja.ftl");
+
+/// A language the UI ships messages for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    English,
+    Japanese,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Japanese];
+
+    /// Code stored in `Config::locale`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Japanese => "ja",
+        }
+    }
+
+    /// Name shown in the language picker, written in the language itself.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Japanese => "日本語",
+        }
+    }
+
+    /// Match a locale string such as `ja`, `ja-JP` or `en_US.UTF-8` by its
+    /// language subtag.
+    pub fn from_locale(locale: &str) -> Option<Language> {
+        let language = locale
+            .split(['-', '_', '.'])
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        Self::ALL.into_iter().find(|l| l.code() == language)
+    }
+
+    /// The language for a `Config::locale` value. Empty follows the system
+    /// locale; anything unsupported falls back to English.
+    pub fn resolve(config_locale: &str) -> Language {
+        if config_locale.is_empty() {
+            sys_locale::get_locale()
+                .and_then(|locale| Language::from_locale(&locale))
+                .unwrap_or(Language::English)
+        } else {
+            Language::from_locale(config_locale).unwrap_or(Language::English)
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Language::English => EN_FTL,
+            Language::Japanese => JA_FTL,
+        }
+    }
+}
+
+fn bundle(language: Language) -> FluentBundle<FluentResource> {
+    let id: LanguageIdentifier = language
+        .code()
+        .parse()
+        .expect("language codes are valid identifiers");
+    let mut bundle = FluentBundle::new_concurrent(vec![id]);
+    // Isolation marks render as boxes in the game fonts.
+    bundle.set_use_isolating(false);
+    let resource = FluentResource::try_new(language.source().to_string()).unwrap_or_else(
+        |(resource, errors)| {
+            log::warn!("{} locale has syntax errors: {:?}", language.code(), errors);
+            resource
+        },
+    );
+    if let Err(errors) = bundle.add_resource(resource) {
+        log::warn!(
+            "{} locale has duplicate messages: {:?}",
+            language.code(),
+            errors
+        );
+    }
+    bundle
+}
+
+/// Formats messages in one language with English as the fallback.
+pub struct Localizer {
+    language: Language,
+    bundles: Vec<FluentBundle<FluentResource>>,
+}
+
+impl Localizer {
+    pub fn new(language: Language) -> Self {
+        let mut bundles = vec![bundle(language)];
+        if language != Language::English {
+            bundles.push(bundle(Language::English));
+        }
+        Self { language, bundles }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Format message `id`, substituting `args` into its placeables.
+    pub fn format(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        for bundle in &self.bundles {
+            let Some(pattern) = bundle.get_message(id).and_then(|m| m.value()) else {
+                continue;
+            };
+            let mut errors = Vec::new();
+            let text = bundle.format_pattern(pattern, args, &mut errors);
+            if !errors.is_empty() {
+                log::warn!("Formatting message {}: {:?}", id, errors);
+            }
+            return text.into_owned();
+        }
+        log::warn!("Missing UI message: {}", id);
+        id.to_string()
+    }
+}
+
+static LOCALIZER: LazyLock<RwLock<Localizer>> =
+    LazyLock::new(|| RwLock::new(Localizer::new(Language::English)));
+
+/// Select the UI language from a `Config::locale` value.
+pub fn set_locale(config_locale: &str) {
+    let language = Language::resolve(config_locale);
+    let mut localizer = LOCALIZER.write().unwrap_or_else(|e| e.into_inner());
+    if localizer.language() != language {
+        *localizer = Localizer::new(language);
+    }
+}
+
+/// The language messages are currently shown in.
+pub fn current_language() -> Language {
+    LOCALIZER
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .language()
+}
+
+/// Look up a message without arguments.
+pub fn tr(id: &str) -> String {
+    tr_args(id, None)
+}
+
+/// Look up a message with arguments. Prefer the [`tr!`](crate::tr) macro.
+pub fn tr_args(id: &str, args: Option<&FluentArgs>) -> String {
+    LOCALIZER
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .format(id, args)
+}
+
+/// Look up a UI message, optionally with named arguments:
+/// `tr!("select-songs-found", count = n)`.
+///
+/// Numbers are formatted by Fluent; pass floats pre-formatted as strings
+/// when a fixed precision is wanted.
+#[macro_export]
+macro_rules! tr {
+    ($id:literal) => {
+        $crate::i18n::tr($id)
+    };
+    ($id:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::i18n::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::tr_args($id, Some(&args))
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn message_ids(source: &str) -> BTreeSet<String> {
+        source
+            .lines()
+            .filter(|line| {
+                line.starts_with(|c: char| c.is_ascii_lowercase()) && line.contains(" =")
+            })
+            .map(|line| line.split(" =").next().unwrap_or("").trim().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn locales_define_the_same_messages() {
+        let en = message_ids(EN_FTL);
+        let ja = message_ids(JA_FTL);
+        assert!(!en.is_empty());
+        let missing: Vec<_> = en.difference(&ja).collect();
+        let extra: Vec<_> = ja.difference(&en).collect();
+        assert!(missing.is_empty(), "missing from ja.ftl: {:?}", missing);
+        assert!(extra.is_empty(), "not in en.ftl: {:?}", extra);
+    }
+
+    #[test]
+    fn locales_parse_without_errors() {
+        for source in [EN_FTL, JA_FTL] {
+            if let Err((_, errors)) = FluentResource::try_new(source.to_string()) {
+                panic!("locale syntax errors: {:?}", errors);
+            }
+        }
+    }
+
+    /// Every id passed to `tr`/`tr!` in the sources must exist in English.
+    #[test]
+    fn source_message_ids_exist() {
+        let en = message_ids(EN_FTL);
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut missing = Vec::new();
+        let mut stack = vec![root];
+        while let Some(dir) = stack.pop() {
+            for entry in std::fs::read_dir(&dir).expect("read src dir").flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    stack.push(path);
+                    continue;
+                }
+                if path.extension().is_none_or(|e| e != "rs") || path.ends_with("i18n.rs") {
+                    continue;
+                }
+                let text = std::fs::read_to_string(&path).expect("read source");
+                for marker in ["tr(\"", "tr!(\""] {
+                    for (start, _) in text.match_indices(marker) {
+                        // Skip `str("`, `attr("` and other identifiers ending in `tr`.
+                        let preceding = text[..start].chars().next_back();
+                        if preceding.is_some_and(|c| c.is_alphanumeric() || c == '_') {
+                            continue;
+                        }
+                        let rest = &text[start + marker.len()..];
+                        let id = &rest[..rest.find('"').unwrap_or(0)];
+                        if !en.contains(id) {
+                            missing.push(format!("{}: {}", path.display(), id));
+                        }
+                    }
+                }
+            }
+        }
+        assert!(missing.is_empty(), "ids missing from en.ftl: {:?}", missing);
+    }
+
+    #[test]
+    fn localizer_formats_arguments_and_falls_back_to_english() {
+        let ja = Localizer::new(Language::Japanese);
+        let en = Localizer::new(Language::English);
+        assert_eq!(ja.format("language-label", None), "言語");
+        assert_eq!(en.format("language-label", None), "Language");
+
+        let mut args = FluentArgs::new();
+        args.set("count", 3);
+        assert_eq!(
+            en.format("launcher-rivals-refreshed", Some(&args)),
+            "Refreshed 3 rivals"
+        );
+        assert_eq!(
+            ja.format("launcher-rivals-refreshed", Some(&args)),
+            "ライバル 3 人を更新しました"
+        );
+
+        assert_eq!(ja.format("no-such-message", None), "no-such-message");
+    }
+
+    #[test]
+    fn from_locale_matches_language_subtag() {
+        assert_eq!(Language::from_locale("ja-JP"), Some(Language::Japanese));
+        assert_eq!(
+            Language::from_locale("en_US.UTF-8"),
+            Some(Language::English)
+        );
+        assert_eq!(Language::from_locale("fr-FR"), None);
+        assert_eq!(Language::resolve("de"), Language::English);
+        assert_eq!(Language::resolve("ja"), Language::Japanese);
+    }
+}
@@ -6,6 +6,14 @@ pub trait ImGuiAccess: Send {
     /// Toggle the mod menu overlay visibility.
     fn toggle_menu(&mut self);
 
+    /// Whether the mod menu overlay is open.
+    fn is_menu_shown(&self) -> bool {
+        false
+    }
+
+    /// Apply a controller navigation step to the mod menu.
+    fn navigate(&mut self, _action: crate::modmenu::controller_navigation::MenuAction) {}
+
     /// Dispose resources.
     fn dispose(&mut self) {}
 }
@@ -1,8 +1,9 @@
-// Centralized ImGuiNotify API — log-backed facade.
-// The real toast rendering lives in beatoraja-modmenu::imgui_notify;
-// this module provides the convenience methods so that downstream crates
-// can call ImGuiNotify::info/warning/error/success without depending on
-// beatoraja-modmenu (which would introduce circular dependencies).
+// Centralized ImGuiNotify API — logs each message and forwards it to the
+// toast overlay in modmenu::imgui_notify, which also keeps the notification
+// history. Gameplay code calls this facade instead of the modmenu directly.
+
+use crate::modmenu::imgui_notify::ImGuiNotify as Toasts;
+pub use crate::modmenu::imgui_notify::ToastType;
 
 /// Notification facade (matches Java beatoraja.modmenu.ImGuiNotify convenience API).
 pub struct ImGuiNotify;
@@ -10,33 +11,56 @@ pub struct ImGuiNotify;
 impl ImGuiNotify {
     pub fn info(msg: &str) {
         log::info!("{}", msg);
+        Toasts::info(msg);
     }
 
-    pub fn info_with_dismiss(msg: &str, _dismiss_time: i64) {
+    pub fn info_with_dismiss(msg: &str, dismiss_time: i64) {
         log::info!("{}", msg);
+        Toasts::info_with_dismiss(msg, dismiss_time);
     }
 
     pub fn warning(msg: &str) {
         log::warn!("{}", msg);
+        Toasts::warning(msg);
     }
 
-    pub fn warning_with_dismiss(msg: &str, _dismiss_time: i64) {
+    pub fn warning_with_dismiss(msg: &str, dismiss_time: i64) {
         log::warn!("{}", msg);
+        Toasts::warning_with_dismiss(msg, dismiss_time);
     }
 
     pub fn error(msg: &str) {
         log::error!("{}", msg);
+        Toasts::error(msg);
     }
 
-    pub fn error_with_dismiss(msg: &str, _dismiss_time: i64) {
+    pub fn error_with_dismiss(msg: &str, dismiss_time: i64) {
         log::error!("{}", msg);
+        Toasts::error_with_dismiss(msg, dismiss_time);
     }
 
     pub fn success(msg: &str) {
         log::info!("{}", msg);
+        Toasts::success(msg);
     }
 
-    pub fn success_with_dismiss(msg: &str, _dismiss_time: i64) {
+    pub fn success_with_dismiss(msg: &str, dismiss_time: i64) {
         log::info!("{}", msg);
+        Toasts::success_with_dismiss(msg, dismiss_time);
+    }
+
+    /// Toast with an action button, also reachable from the notification history.
+    pub fn with_action(
+        toast_type: ToastType,
+        msg: &str,
+        button_label: &str,
+        on_press: Box<dyn Fn() + Send + Sync>,
+    ) {
+        match toast_type {
+            ToastType::Error => log::error!("{}", msg),
+            ToastType::Warning => log::warn!("{}", msg),
+            _ => log::info!("{}", msg),
+        }
+        Toasts::with_button(toast_type, msg, button_label, on_press);
     }
 }
@@ -5,7 +5,9 @@
 use crate::input::bms_player_input_device::{BMSPlayerInputDevice, DeviceType};
 use crate::input::controller::gdx_controller::GdxController;
 use crate::skin::play_mode_config::ControllerConfig;
-use crate::skin::play_mode_config::{ANALOG_SCRATCH_VER_1, ANALOG_SCRATCH_VER_2};
+use crate::skin::play_mode_config::{
+    ANALOG_SCRATCH_VER_1, ANALOG_SCRATCH_VER_2, ANALOG_SCRATCH_VER_3,
+};
 
 /// BMKeys constants
 pub struct BMKeys;
@@ -131,7 +133,7 @@ const AXIS_LENGTH: usize = 8;
 const TICK_MAX_SIZE: f32 = 0.009;
 
 trait AnalogScratchAlgorithm: Send {
-    fn analog_scratch_input(&mut self, current_scratch_x: f32, plus: bool) -> bool;
+    fn analog_scratch_input(&mut self, microtime: i64, current_scratch_x: f32, plus: bool) -> bool;
 }
 
 struct AnalogScratchAlgorithmVersion1 {
@@ -160,7 +162,12 @@ impl AnalogScratchAlgorithmVersion1 {
 }
 
 impl AnalogScratchAlgorithm for AnalogScratchAlgorithmVersion1 {
-    fn analog_scratch_input(&mut self, current_scratch_x: f32, plus: bool) -> bool {
+    fn analog_scratch_input(
+        &mut self,
+        _microtime: i64,
+        current_scratch_x: f32,
+        plus: bool,
+    ) -> bool {
         if self.old_scratch_x > 1.0 {
             self.old_scratch_x = current_scratch_x;
             self.scratch_active = false;
@@ -248,7 +255,12 @@ impl AnalogScratchAlgorithmVersion2 {
 }
 
 impl AnalogScratchAlgorithm for AnalogScratchAlgorithmVersion2 {
-    fn analog_scratch_input(&mut self, current_scratch_x: f32, plus: bool) -> bool {
+    fn analog_scratch_input(
+        &mut self,
+        _microtime: i64,
+        current_scratch_x: f32,
+        plus: bool,
+    ) -> bool {
         if self.old_scratch_x > 1.0 {
             self.old_scratch_x = current_scratch_x;
             self.scratch_active = false;
@@ -300,6 +312,84 @@ impl AnalogScratchAlgorithm for AnalogScratchAlgorithmVersion2 {
     }
 }
 
+/// Time-based scratch for turntables that report an absolute angle
+/// (PHOENIXWAN, DAO). Unlike Ver. 1/2, which count polls, the lane stays on for
+/// a fixed time after the last movement, so it behaves the same at any frame
+/// rate.
+struct AnalogScratchAlgorithmVersion3 {
+    /// Ticks in one direction that turn the lane on
+    sensitivity: i32,
+    /// Ticks of movement from the last position ignored as jitter
+    deadzone: i32,
+    /// Time (us) the lane stays on after the last movement
+    active_duration: i64,
+    /// Last accepted position (-1<->0<->1), > 1 before the first poll
+    old_scratch_x: f32,
+    /// Signed ticks moved in the current direction while the lane is off
+    pending_ticks: i32,
+    /// Time of the last accepted movement (us)
+    last_move: i64,
+    /// Analog scratch input flag
+    scratch_active: bool,
+    /// Analog scratch right rotation flag
+    right_move_scratching: bool,
+}
+
+impl AnalogScratchAlgorithmVersion3 {
+    fn new(sensitivity: i32, deadzone: i32, active_duration_ms: i32) -> Self {
+        Self {
+            sensitivity: sensitivity.max(1),
+            deadzone: deadzone.max(0),
+            active_duration: active_duration_ms.max(1) as i64 * 1000,
+            old_scratch_x: 10.0,
+            pending_ticks: 0,
+            last_move: 0,
+            scratch_active: false,
+            right_move_scratching: false,
+        }
+    }
+}
+
+impl AnalogScratchAlgorithm for AnalogScratchAlgorithmVersion3 {
+    fn analog_scratch_input(&mut self, microtime: i64, current_scratch_x: f32, plus: bool) -> bool {
+        if self.old_scratch_x > 1.0 {
+            self.old_scratch_x = current_scratch_x;
+            self.last_move = microtime;
+            return false;
+        }
+
+        let ticks = compute_analog_diff(self.old_scratch_x, current_scratch_x);
+        if ticks.abs() > self.deadzone {
+            let now_right = ticks > 0;
+            if self.scratch_active {
+                // Reversal switches lanes at once so back-spin notes register
+                self.right_move_scratching = now_right;
+            } else {
+                if self.pending_ticks != 0 && (self.pending_ticks > 0) != now_right {
+                    self.pending_ticks = 0;
+                }
+                self.pending_ticks += ticks;
+                if self.pending_ticks.abs() >= self.sensitivity {
+                    self.scratch_active = true;
+                    self.right_move_scratching = now_right;
+                    self.pending_ticks = 0;
+                }
+            }
+            self.old_scratch_x = current_scratch_x;
+            self.last_move = microtime;
+        } else if microtime - self.last_move > self.active_duration {
+            self.scratch_active = false;
+            self.pending_ticks = 0;
+        }
+
+        if plus {
+            self.scratch_active && self.right_move_scratching
+        } else {
+            self.scratch_active && !self.right_move_scratching
+        }
+    }
+}
+
 /// Dedicated controller input processing
 pub struct BMControllerInputProcessor {
     pub(crate) controller: GdxController,
@@ -323,8 +413,14 @@ pub struct BMControllerInputProcessor {
     buttontime: [i64; BMKeys::MAXID],
     /// Button state change re-acceptance time (ms)
     duration: i32,
+    /// Time added to button timestamps before judging (us)
+    latency_offset: i64,
     /// Last pressed button
     pub last_pressed_button: i32,
+    /// Axis value at which an axis button turns on (without analog scratch)
+    axis_on: f32,
+    /// Axis value below which a held axis button turns off
+    axis_off: f32,
     /// JKOC_HACK (UP/DOWN false reaction prevention)
     jkoc: bool,
     /// Analog scratch algorithm (None = do not use analog scratch)
@@ -373,7 +469,10 @@ impl BMControllerInputProcessor {
             buttonchanged: [false; BMKeys::MAXID],
             buttontime: [0; BMKeys::MAXID],
             duration: 16,
+            latency_offset: 0,
             last_pressed_button: -1,
+            axis_on: 0.9,
+            axis_off: 0.9,
             jkoc: false,
             analog_scratch_algorithm: None,
         };
@@ -381,12 +480,26 @@ impl BMControllerInputProcessor {
         proc
     }
 
+    /// Button assigned to lane `key`, or -1 if unassigned.
+    pub fn button_code(&self, key: usize) -> i32 {
+        self.buttons.get(key).copied().unwrap_or(-1)
+    }
+
+    /// Time (us) added to button timestamps before judging.
+    pub fn latency_offset(&self) -> i64 {
+        self.latency_offset
+    }
+
     pub fn set_config(&mut self, controller_config: &ControllerConfig) {
         self.buttons = controller_config.keys.to_vec();
         self.start = controller_config.start;
         self.select = controller_config.select;
         self.duration = controller_config.duration;
+        self.latency_offset = controller_config.latency_offset as i64 * 1000;
         self.jkoc = controller_config.jkoc_hack;
+        self.axis_on = controller_config.axis_threshold as f32 / 100.0;
+        self.axis_off =
+            (controller_config.axis_threshold - controller_config.axis_hysteresis) as f32 / 100.0;
 
         if controller_config.analog_scratch {
             let mut analog_scratch_algorithm: Vec<Box<dyn AnalogScratchAlgorithm>> =
@@ -394,6 +507,15 @@ impl BMControllerInputProcessor {
             let analog_scratch_threshold = controller_config.analog_scratch_threshold;
             for _ in 0..AXIS_LENGTH {
                 match controller_config.analog_scratch_mode {
+                    ANALOG_SCRATCH_VER_3 => {
+                        analog_scratch_algorithm.push(Box::new(
+                            AnalogScratchAlgorithmVersion3::new(
+                                controller_config.analog_scratch_sensitivity,
+                                controller_config.analog_scratch_deadzone,
+                                controller_config.analog_scratch_duration,
+                            ),
+                        ));
+                    }
                     ANALOG_SCRATCH_VER_1 => {
                         analog_scratch_algorithm.push(Box::new(
                             AnalogScratchAlgorithmVersion1::new(analog_scratch_threshold),
@@ -453,7 +575,8 @@ impl BMControllerInputProcessor {
                 } else {
                     let axis_index = ((button as i32) - BMKeys::AXIS1_PLUS) / 2;
                     let plus = ((button as i32) - BMKeys::AXIS1_PLUS) % 2 == 0;
-                    self.buttonstate[button] = self.scratch_input(axis_index, plus);
+                    self.buttonstate[button] =
+                        self.scratch_input(microtime, axis_index, plus, prev);
                 }
 
                 let changed = prev != self.buttonstate[button];
@@ -520,23 +643,24 @@ impl BMControllerInputProcessor {
         if plus { value } else { -value }
     }
 
-    fn scratch_input(&mut self, axis_index: i32, plus: bool) -> bool {
+    fn scratch_input(&mut self, microtime: i64, axis_index: i32, plus: bool, held: bool) -> bool {
         if let Some(ref mut analog_scratch_algorithm) = self.analog_scratch_algorithm {
             // Analog scratch
             let idx = axis_index as usize;
             if idx < analog_scratch_algorithm.len() {
-                analog_scratch_algorithm[idx].analog_scratch_input(self.axis[idx], plus)
+                analog_scratch_algorithm[idx].analog_scratch_input(microtime, self.axis[idx], plus)
             } else {
                 false
             }
         } else {
-            // Do not use analog scratch
+            // Do not use analog scratch: digital on/off with hysteresis
             let idx = axis_index as usize;
-            if plus {
-                self.axis[idx] > 0.9
+            let value = if plus {
+                self.axis[idx]
             } else {
-                self.axis[idx] < -0.9
-            }
+                -self.axis[idx]
+            };
+            value > if held { self.axis_off } else { self.axis_on }
         }
     }
 
@@ -632,6 +756,12 @@ mod tests {
             analog_scratch,
             analog_scratch_mode,
             analog_scratch_threshold: threshold,
+            analog_scratch_sensitivity: 2,
+            analog_scratch_deadzone: 0,
+            analog_scratch_duration: 50,
+            latency_offset: 0,
+            axis_threshold: 90,
+            axis_hysteresis: 0,
         };
         let mut proc = BMControllerInputProcessor::new("test".to_string(), controller, &config);
         proc.enabled = true;
@@ -810,6 +940,83 @@ mod tests {
         );
     }
 
+    // --- AnalogScratch V3 ---
+
+    fn make_v3_processor(sensitivity: i32, deadzone: i32) -> BMControllerInputProcessor {
+        let mut proc = make_processor(true, ANALOG_SCRATCH_VER_3, 50);
+        let mut config = ControllerConfig::new_with_keys(proc.buttons.clone(), 8, 9);
+        config.analog_scratch = true;
+        config.analog_scratch_mode = ANALOG_SCRATCH_VER_3;
+        config.analog_scratch_sensitivity = sensitivity;
+        config.analog_scratch_deadzone = deadzone;
+        config.analog_scratch_duration = 50;
+        config.duration = 0;
+        proc.set_config(&config);
+        proc
+    }
+
+    fn axis1_events(cb: &TestCallback) -> Vec<(usize, bool)> {
+        cb.key_events
+            .iter()
+            .filter(|e| e.2 == 7 || e.2 == 8)
+            .map(|e| (e.2, e.3))
+            .collect()
+    }
+
+    #[test]
+    fn test_v3_sensitivity_and_active_duration() {
+        let mut proc = make_v3_processor(3, 0);
+        let mut cb = TestCallback::default();
+        proc.controller.axis_state[0] = 0.0;
+        proc.poll(1_000_000, &mut cb, 0);
+        // Two ticks are below the sensitivity
+        proc.controller.axis_state[0] = 0.018;
+        proc.poll(1_010_000, &mut cb, 0);
+        assert!(axis1_events(&cb).is_empty());
+        proc.controller.axis_state[0] = 0.027;
+        proc.poll(1_020_000, &mut cb, 0);
+        assert_eq!(axis1_events(&cb), [(7, true)]);
+
+        // Held while within the active duration, released after it
+        cb = TestCallback::default();
+        proc.poll(1_060_000, &mut cb, 0);
+        assert!(axis1_events(&cb).is_empty());
+        proc.poll(1_080_000, &mut cb, 0);
+        assert_eq!(axis1_events(&cb), [(7, false)]);
+    }
+
+    #[test]
+    fn test_v3_deadzone_ignores_jitter() {
+        let mut proc = make_v3_processor(1, 1);
+        let mut cb = TestCallback::default();
+        proc.controller.axis_state[0] = 0.0;
+        proc.poll(1_000_000, &mut cb, 0);
+        for (i, x) in [0.009, 0.0, -0.009, 0.0].into_iter().enumerate() {
+            proc.controller.axis_state[0] = x;
+            proc.poll(1_010_000 + i as i64 * 10_000, &mut cb, 0);
+        }
+        assert!(axis1_events(&cb).is_empty());
+        proc.controller.axis_state[0] = 0.018;
+        proc.poll(1_100_000, &mut cb, 0);
+        assert_eq!(axis1_events(&cb), [(7, true)]);
+    }
+
+    #[test]
+    fn test_v3_reverse_switches_lanes_immediately() {
+        let mut proc = make_v3_processor(2, 0);
+        let mut cb = TestCallback::default();
+        proc.controller.axis_state[0] = 0.0;
+        proc.poll(1_000_000, &mut cb, 0);
+        proc.controller.axis_state[0] = 0.05;
+        proc.poll(1_010_000, &mut cb, 0);
+        cb = TestCallback::default();
+        proc.controller.axis_state[0] = 0.04;
+        proc.poll(1_020_000, &mut cb, 0);
+        let events = axis1_events(&cb);
+        assert!(events.contains(&(7, false)), "expected plus release");
+        assert!(events.contains(&(8, true)), "expected minus press");
+    }
+
     // --- poll() integration ---
 
     #[test]
@@ -866,4 +1073,31 @@ mod tests {
         proc.poll(2_000_000, &mut cb, 0);
         assert_eq!(cb.select_events, vec![true]);
     }
+
+    #[test]
+    fn test_poll_axis_threshold_with_hysteresis() {
+        let mut proc = make_processor(false, 0, 50);
+        let mut config = ControllerConfig::default();
+        config.keys = vec![BMKeys::AXIS3_PLUS];
+        config.duration = 0;
+        config.axis_threshold = 50;
+        config.axis_hysteresis = 20;
+        proc.set_config(&config);
+        let mut cb = TestCallback::default();
+
+        proc.controller.axis_state[2] = 0.4;
+        proc.poll(1_000_000, &mut cb, 0);
+        proc.controller.axis_state[2] = 0.6;
+        proc.poll(2_000_000, &mut cb, 0);
+        // Back below the threshold but above threshold - hysteresis: still held
+        proc.controller.axis_state[2] = 0.35;
+        proc.poll(3_000_000, &mut cb, 0);
+        proc.controller.axis_state[2] = 0.25;
+        proc.poll(4_000_000, &mut cb, 0);
+
+        let lane0: Vec<_> = cb.key_events.iter().filter(|e| e.2 == 0).collect();
+        assert_eq!(lane0.len(), 2);
+        assert_eq!((lane0[0].1, lane0[0].3), (2_000_000, true));
+        assert_eq!((lane0[1].1, lane0[1].3), (4_000_000, false));
+    }
 }
@@ -12,15 +12,18 @@ use crate::input::bm_controller_input_processor::{
 use crate::input::bms_player_input_device::{BMSPlayerInputDevice, DeviceType};
 use crate::input::controller::gdx_controller::GdxController;
 use crate::input::gdx_compat;
+use crate::input::input_diagnostics::InputDiagnostics;
+use crate::input::input_poll_thread::{InputPollThread, PolledChangeTimes, PolledSource};
 use crate::input::input_snapshot::InputSnapshot;
 use crate::input::key_command::KeyCommand;
 use crate::input::key_input_log::KeyInputLog;
 use crate::input::keyboard_input_processor::{
-    ControlKeys, KeyBoardInputProcesseor, KeyboardCallback, MASK_CTRL, MASK_SHIFT,
+    ControlKeys, KeyBoardInputProcesseor, KeyboardCallback, MASK_ALT, MASK_CTRL, MASK_SHIFT,
 };
 use crate::input::midi_input_processor::MidiInputProcessor;
 use crate::input::winit_input_bridge::SharedKeyState;
 use crate::skin::config::Config;
+use crate::skin::hotkey_config::{Hotkey, HotkeyConfig};
 use crate::skin::play_mode_config::{ControllerConfig, KeyboardConfig, MidiConfig, PlayModeConfig};
 use crate::skin::player_config::PlayerConfig;
 
@@ -78,6 +81,14 @@ pub struct BMSPlayerInputProcessor {
     keystate: [bool; KEYSTATE_SIZE],
     /// Each key last update time
     time: [i64; KEYSTATE_SIZE],
+    /// Each key last release time, for the debounce filter
+    release_time: [i64; KEYSTATE_SIZE],
+    /// Re-press suppression window after a release (us, 0 = off)
+    debounce_window: i64,
+    /// Presses suppressed by the debounce filter per key
+    chatter_count: [u32; KEYSTATE_SIZE],
+    /// Press counts and poll intervals for the input diagnostics window
+    diagnostics: InputDiagnostics,
 
     /// Analog scroll for song select bar and lane cover
     analog_scroll: bool,
@@ -105,10 +116,20 @@ pub struct BMSPlayerInputProcessor {
 
     start_pressed: bool,
     pub select_pressed: bool,
+    /// Start/select held on each controller, merged so that releasing the
+    /// button on one side does not cancel it while held on the other
+    controller_start: Vec<bool>,
+    controller_select: Vec<bool>,
 
     device_type: DeviceType,
 
     controller_manager: Lwjgl3ControllerManager,
+    /// 1000Hz sampler stamping key/button changes, when enabled in Config
+    poll_thread: Option<InputPollThread>,
+    polled_times: PolledChangeTimes,
+    hotkeys: HotkeyConfig,
+    /// Global keysound latency (us), subtracted from every device's key timestamps
+    audio_offset: i64,
 }
 
 impl BMSPlayerInputProcessor {
@@ -180,6 +201,13 @@ impl BMSPlayerInputProcessor {
         midiinput.set_config(&midi_config);
 
         let analog_scroll = config.select.analog_scroll;
+        let controller_count = bminput.len();
+        // Wrapper processors (open_midi == false) never poll devices
+        let poll_thread = if open_midi && config.display.input_poll_thread {
+            InputPollThread::start(key_state.clone())
+        } else {
+            None
+        };
 
         Self {
             enable: true,
@@ -190,6 +218,10 @@ impl BMSPlayerInputProcessor {
             keylog: KeyLogger::new(),
             keystate: [false; KEYSTATE_SIZE],
             time: [i64::MIN; KEYSTATE_SIZE],
+            release_time: [i64::MIN; KEYSTATE_SIZE],
+            debounce_window: 0,
+            chatter_count: [0; KEYSTATE_SIZE],
+            diagnostics: InputDiagnostics::new(KEYSTATE_SIZE),
             analog_scroll,
             is_analog: [false; KEYSTATE_SIZE],
             last_analog_value: [0.0; KEYSTATE_SIZE],
@@ -208,8 +240,14 @@ impl BMSPlayerInputProcessor {
             scroll_y: 0.0,
             start_pressed: false,
             select_pressed: false,
+            controller_start: vec![false; controller_count],
+            controller_select: vec![false; controller_count],
             device_type: DeviceType::Keyboard,
             controller_manager,
+            poll_thread,
+            polled_times: PolledChangeTimes::default(),
+            hotkeys: HotkeyConfig::default(),
+            audio_offset: 0,
         }
     }
 
@@ -217,31 +255,43 @@ impl BMSPlayerInputProcessor {
         self.kbinput.set_config(config);
     }
 
+    /// Bind each connected controller to a player side. Configs naming a
+    /// connected device are bound first, so a DP pad already assigned to 2P
+    /// cannot be taken by an unnamed 1P config; the remaining controllers then
+    /// claim the unnamed configs in order.
     pub fn set_controller_config(&mut self, configs: &mut [ControllerConfig]) {
         let mut b = vec![false; configs.len()];
         for controller in self.bminput.iter_mut() {
             controller.enabled = false;
-            for (i, config) in configs.iter_mut().enumerate() {
-                if b[i] {
-                    continue;
-                }
-                if config.name().is_none() || config.name().is_some_and(|n| n.is_empty()) {
-                    config.name = controller.name().to_string();
-                }
-                if controller.name() == config.name().unwrap_or("") {
-                    controller.set_config(config);
-                    controller.enabled = true;
-                    b[i] = true;
-                    break;
-                }
+            if let Some(i) =
+                (0..configs.len()).find(|&i| !b[i] && configs[i].name() == Some(controller.name()))
+            {
+                controller.set_config(&configs[i]);
+                controller.enabled = true;
+                b[i] = true;
             }
         }
+        for controller in self.bminput.iter_mut().filter(|c| !c.enabled) {
+            let Some(i) = (0..configs.len()).find(|&i| !b[i] && configs[i].name().is_none()) else {
+                break;
+            };
+            configs[i].name = controller.name().to_string();
+            controller.set_config(&configs[i]);
+            controller.enabled = true;
+            b[i] = true;
+        }
     }
 
     pub fn set_midi_config(&mut self, config: &MidiConfig) {
         self.midiinput.set_config(config);
     }
 
+    /// Set the player's audio offset (ms). Key presses are judged this much
+    /// earlier to compensate for keysound output latency.
+    pub fn set_audio_offset(&mut self, audio_offset: i32) {
+        self.audio_offset = audio_offset as i64 * 1000;
+    }
+
     pub fn set_start_time(&mut self, starttime: i64) {
         self.starttime = starttime;
         if starttime != 0 {
@@ -307,6 +357,24 @@ impl BMSPlayerInputProcessor {
         self.time.fill(i64::MIN);
     }
 
+    /// Presses per key suppressed as switch chatter by the debounce filter.
+    pub fn chatter_counts(&self) -> &[u32] {
+        &self.chatter_count
+    }
+
+    pub fn reset_chatter_counts(&mut self) {
+        self.chatter_count.fill(0);
+    }
+
+    /// Live press counts, press times and poll intervals.
+    pub fn diagnostics(&self) -> &InputDiagnostics {
+        &self.diagnostics
+    }
+
+    pub fn reset_diagnostics(&mut self) {
+        self.diagnostics.reset();
+    }
+
     pub fn last_key_changed_device(&self) -> Option<DeviceType> {
         self.last_key_device
     }
@@ -321,7 +389,10 @@ impl BMSPlayerInputProcessor {
         for bm in self.bminput.iter_mut() {
             bm.clear();
         }
+        self.controller_start.fill(false);
+        self.controller_select.fill(false);
         self.midiinput.clear();
+        self.polled_times.clear();
     }
 
     pub fn set_play_config(&mut self, playconfig: &mut PlayModeConfig) {
@@ -370,6 +441,7 @@ impl BMSPlayerInputProcessor {
         let controllers = &mut playconfig.controller;
         self.set_controller_config(controllers);
         self.midiinput.set_config(&playconfig.midi);
+        self.debounce_window = playconfig.debounce_window.max(0) as i64 * 1000;
 
         if kbcount >= cocount && kbcount >= micount {
             self.device_type = DeviceType::Keyboard;
@@ -425,6 +497,18 @@ impl BMSPlayerInputProcessor {
         self.kbinput.is_key_pressed(key.keycode())
     }
 
+    /// Set the keys checked by hotkey_state().
+    pub fn set_hotkey_config(&mut self, hotkeys: &HotkeyConfig) {
+        self.hotkeys = hotkeys.clone();
+        self.kbinput
+            .set_hotkeys(Hotkey::ALL.iter().map(|&h| hotkeys.key(h)).collect());
+    }
+
+    /// Whether the key bound to `hotkey` is held.
+    pub fn hotkey_state(&self, hotkey: Hotkey) -> bool {
+        self.kbinput.key_state(self.hotkeys.key(hotkey))
+    }
+
     pub fn is_control_key_pressed_with_modifiers(
         &mut self,
         key: ControlKeys,
@@ -449,9 +533,19 @@ impl BMSPlayerInputProcessor {
             return;
         }
         if self.keystate[i] != pressed {
+            if pressed && presstime.saturating_sub(self.release_time[i]) < self.debounce_window {
+                // Aging switches bounce off and on again right after a release
+                self.chatter_count[i] = self.chatter_count[i].saturating_add(1);
+                return;
+            }
+            if !pressed {
+                self.release_time[i] = presstime;
+            }
             self.keystate[i] = pressed;
             self.time[i] = presstime;
             self.last_key_device = Some(device);
+            self.diagnostics
+                .record_key(i, pressed, device, monotonic_micros());
             if self.starttime != 0 {
                 self.keylog
                     .add(presstime - self.micro_margin_time, i as i32, pressed);
@@ -527,7 +621,12 @@ impl BMSPlayerInputProcessor {
 
         match key {
             KeyCommand::ShowFps => self.is_control_key_pressed(ControlKeys::F1),
-            KeyCommand::UpdateFolder => self.is_control_key_pressed(ControlKeys::F2),
+            KeyCommand::UpdateFolder => {
+                self.is_control_key_pressed_with_modifiers(ControlKeys::F2, 0, &[mask_ctrl])
+            }
+            KeyCommand::UpdateLibrary => {
+                self.is_control_key_pressed_with_modifiers(ControlKeys::F2, mask_ctrl, &[])
+            }
             KeyCommand::OpenExplorer => self.is_control_key_pressed_with_modifiers(
                 ControlKeys::F3,
                 0,
@@ -543,10 +642,16 @@ impl BMSPlayerInputProcessor {
             }
             KeyCommand::SwitchScreenMode => self.is_control_key_pressed(ControlKeys::F4),
             KeyCommand::SaveScreenshot => self.is_control_key_pressed(ControlKeys::F6),
+            KeyCommand::ShareResult => self.is_control_key_pressed(ControlKeys::F7),
             KeyCommand::AddFavoriteSong => self.is_control_key_pressed(ControlKeys::F8),
             KeyCommand::AddFavoriteChart => self.is_control_key_pressed(ControlKeys::F9),
             KeyCommand::AutoplayFolder => self.is_control_key_pressed(ControlKeys::F10),
-            KeyCommand::OpenIr => self.is_control_key_pressed(ControlKeys::F11),
+            KeyCommand::OpenIr => {
+                self.is_control_key_pressed_with_modifiers(ControlKeys::F11, 0, &[mask_ctrl])
+            }
+            KeyCommand::OpenStatistics => {
+                self.is_control_key_pressed_with_modifiers(ControlKeys::F11, mask_ctrl, &[])
+            }
             KeyCommand::OpenSkinConfiguration => self.is_control_key_pressed(ControlKeys::F12),
             KeyCommand::ToggleModMenu => {
                 self.is_control_key_pressed(ControlKeys::F5)
@@ -555,6 +660,22 @@ impl BMSPlayerInputProcessor {
             KeyCommand::CopyHighlightedMenuText => {
                 self.is_control_key_pressed_with_modifiers(ControlKeys::KeyC, mask_ctrl, &[])
             }
+            KeyCommand::SkipStreamRequest => self.is_control_key_pressed(ControlKeys::Del),
+            KeyCommand::RandomSelect => self.is_control_key_pressed_with_modifiers(
+                ControlKeys::KeyR,
+                mask_ctrl,
+                &[MASK_SHIFT, MASK_ALT],
+            ),
+            KeyCommand::RandomSelectUnplayed => self.is_control_key_pressed_with_modifiers(
+                ControlKeys::KeyR,
+                mask_ctrl_shift,
+                &[MASK_ALT],
+            ),
+            KeyCommand::RandomSelectFailed => self.is_control_key_pressed_with_modifiers(
+                ControlKeys::KeyR,
+                mask_ctrl | MASK_ALT,
+                &[MASK_SHIFT],
+            ),
         }
     }
 
@@ -579,10 +700,18 @@ impl BMSPlayerInputProcessor {
         &self.bminput
     }
 
+    pub fn bm_input_processor_mut(&mut self) -> &mut [BMControllerInputProcessor] {
+        &mut self.bminput
+    }
+
     pub fn midi_input_processor(&self) -> &MidiInputProcessor {
         &self.midiinput
     }
 
+    pub fn midi_input_processor_mut(&mut self) -> &mut MidiInputProcessor {
+        &mut self.midiinput
+    }
+
     pub fn is_mouse_pressed(&self) -> bool {
         self.mousepressed
     }
@@ -612,7 +741,14 @@ impl BMSPlayerInputProcessor {
     }
 
     pub fn poll(&mut self) {
-        let now = (crate::skin::monotonic_clock::monotonic_micros() - self.starttime).max(0);
+        let polltime = monotonic_micros();
+        self.diagnostics.record_poll(polltime);
+        let now = (polltime - self.starttime).max(0);
+        if let Some(poll_thread) = &self.poll_thread {
+            for input in poll_thread.drain() {
+                self.polled_times.record(input);
+            }
+        }
 
         // Poll keyboard
         // We need to use a temporary struct to act as callback since
@@ -620,10 +756,17 @@ impl BMSPlayerInputProcessor {
         let mut kb_events = KbEvents::default();
         self.kbinput.poll(now, &mut kb_events);
         // Apply keyboard events
+        let latency_offset = self.kbinput.latency_offset() - self.audio_offset;
         for event in &kb_events.key_events {
+            let microtime = self.polled_change_time(
+                PolledSource::Keyboard,
+                self.kbinput.key_code(event.key),
+                event.pressed,
+                event.microtime,
+            );
             self.key_changed_internal(
                 DeviceType::Keyboard,
-                event.microtime,
+                apply_latency_offset(microtime, latency_offset),
                 event.key,
                 event.pressed,
             );
@@ -714,10 +857,17 @@ impl BMSPlayerInputProcessor {
             // We need to use unsafe to split the borrow since poll needs &mut self
             // but we also need &mut self for callback. Instead, collect events.
             self.bminput[idx].poll(now, &mut ctrl_events, idx);
+            let latency_offset = self.bminput[idx].latency_offset() - self.audio_offset;
             for event in &ctrl_events.key_events {
+                let microtime = self.polled_change_time(
+                    PolledSource::Controller(idx),
+                    self.bminput[idx].button_code(event.key),
+                    event.pressed,
+                    event.microtime,
+                );
                 self.key_changed_internal(
                     DeviceType::BmController,
-                    event.microtime,
+                    apply_latency_offset(microtime, latency_offset),
                     event.key,
                     event.pressed,
                 );
@@ -726,18 +876,26 @@ impl BMSPlayerInputProcessor {
                 self.set_analog_state_internal(event.key, event.is_analog, event.value);
             }
             if let Some(pressed) = ctrl_events.start_changed {
-                self.start_pressed = pressed;
+                self.controller_start[idx] = pressed;
+                self.start_pressed = self.controller_start.iter().any(|&held| held);
             }
             if let Some(pressed) = ctrl_events.select_changed {
-                self.select_pressed = pressed;
+                self.controller_select[idx] = pressed;
+                self.select_pressed = self.controller_select.iter().any(|&held| held);
             }
         }
 
         // Poll MIDI
         let mut midi_events = MidiEvents::default();
         self.midiinput.poll(&mut midi_events);
+        let latency_offset = self.midiinput.latency_offset() - self.audio_offset;
         for event in &midi_events.key_events {
-            self.key_changed_internal(DeviceType::Midi, event.microtime, event.key, event.pressed);
+            self.key_changed_internal(
+                DeviceType::Midi,
+                apply_latency_offset(event.microtime, latency_offset),
+                event.key,
+                event.pressed,
+            );
         }
         for event in &midi_events.analog_events {
             self.set_analog_state_internal(event.key, event.is_analog, event.value);
@@ -752,6 +910,23 @@ impl BMSPlayerInputProcessor {
 
     pub fn dispose(&mut self) {
         self.midiinput.close();
+        self.poll_thread = None;
+    }
+
+    /// Play time (us) of a change the frame poll detected at `microtime`,
+    /// moved back to when the poll thread sampled it.
+    fn polled_change_time(
+        &mut self,
+        source: PolledSource,
+        code: i32,
+        pressed: bool,
+        microtime: i64,
+    ) -> i64 {
+        self.polled_times
+            .take(source, code, pressed, self.starttime + microtime)
+            .map_or(microtime, |time| {
+                (time - self.starttime).clamp(0, microtime)
+            })
     }
 
     /// Build a read-only snapshot of the current input state.
@@ -915,6 +1090,12 @@ impl KeyboardCallback for KbEvents {
     }
 }
 
+/// Shift a device key timestamp by its configured latency offset (us),
+/// keeping it within the play time.
+fn apply_latency_offset(microtime: i64, latency_offset: i64) -> i64 {
+    (microtime + latency_offset).max(0)
+}
+
 /// Temporary struct to collect controller callback events
 #[derive(Default)]
 struct CtrlEvents {
@@ -996,6 +1177,7 @@ mod tests {
     use super::*;
     use crate::input::keys::Keys;
     use crate::input::winit_input_bridge::SharedKeyState;
+    use crate::skin::bm_keys::BMKeys;
     use crate::skin::config::Config;
     use crate::skin::player_config::PlayerConfig;
 
@@ -1173,6 +1355,219 @@ mod tests {
         assert!(!proc.key_state(0), "key Z release should be detected");
     }
 
+    #[test]
+    fn test_poll_applies_keyboard_latency_offset() {
+        let shared_state = SharedKeyState::new();
+        let mut proc = make_input_processor_with_state(shared_state.clone());
+        let mut kb_config = KeyboardConfig::default();
+        kb_config.duration = 0;
+        kb_config.latency_offset = 500;
+        proc.set_keyboard_config(&kb_config);
+        proc.set_start_time(crate::skin::monotonic_clock::monotonic_micros());
+
+        shared_state.set_key_pressed(Keys::Z, true);
+        proc.poll();
+
+        assert!(proc.key_state(0));
+        let time = proc.key_changed_time(0);
+        assert!(
+            (500_000..1_500_000).contains(&time),
+            "press time {} should be shifted by the 500ms offset",
+            time
+        );
+    }
+
+    #[test]
+    fn test_poll_subtracts_audio_offset_from_device_latency() {
+        let shared_state = SharedKeyState::new();
+        let mut proc = make_input_processor_with_state(shared_state.clone());
+        let mut kb_config = KeyboardConfig::default();
+        kb_config.duration = 0;
+        kb_config.latency_offset = 800;
+        proc.set_keyboard_config(&kb_config);
+        proc.set_audio_offset(300);
+        proc.set_start_time(crate::skin::monotonic_clock::monotonic_micros());
+
+        shared_state.set_key_pressed(Keys::Z, true);
+        proc.poll();
+
+        assert!(proc.key_state(0));
+        let time = proc.key_changed_time(0);
+        assert!(
+            (500_000..1_500_000).contains(&time),
+            "press time {} should be shifted by 800ms latency minus 300ms audio offset",
+            time
+        );
+    }
+
+    #[test]
+    fn test_poll_uses_poll_thread_stamp() {
+        use crate::input::input_poll_thread::PolledInput;
+
+        let shared_state = SharedKeyState::new();
+        let mut proc = make_input_processor_with_state(shared_state.clone());
+        let mut kb_config = KeyboardConfig::default();
+        kb_config.duration = 0;
+        proc.set_keyboard_config(&kb_config);
+        let starttime = crate::skin::monotonic_clock::monotonic_micros() - 50_000;
+        proc.set_start_time(starttime);
+
+        // The poll thread saw the press 20ms into play, before this frame
+        proc.polled_times.record(PolledInput {
+            source: PolledSource::Keyboard,
+            code: Keys::Z,
+            pressed: true,
+            time: starttime + 20_000,
+        });
+        shared_state.set_key_pressed(Keys::Z, true);
+        proc.poll();
+
+        assert!(proc.key_state(0));
+        assert_eq!(proc.key_changed_time(0), 20_000);
+    }
+
+    #[test]
+    fn test_hotkey_state_follows_bound_key() {
+        // libGDX Keys.B, not bound to any lane or control key
+        const KEY_B: i32 = 30;
+        let shared_state = SharedKeyState::new();
+        let mut proc = make_input_processor_with_state(shared_state.clone());
+        let hotkeys = HotkeyConfig {
+            toggle_bga: KEY_B,
+            ..Default::default()
+        };
+        proc.set_hotkey_config(&hotkeys);
+
+        shared_state.set_key_pressed(KEY_B, true);
+        proc.poll();
+        assert!(proc.hotkey_state(Hotkey::ToggleBga));
+        assert!(!proc.hotkey_state(Hotkey::QuickRetry));
+
+        shared_state.set_key_pressed(KEY_B, false);
+        proc.poll();
+        assert!(!proc.hotkey_state(Hotkey::ToggleBga));
+    }
+
+    #[test]
+    fn test_apply_latency_offset_clamps_to_play_start() {
+        assert_eq!(apply_latency_offset(20_000, -8_000), 12_000);
+        assert_eq!(apply_latency_offset(5_000, -8_000), 0);
+        assert_eq!(apply_latency_offset(5_000, 0), 5_000);
+    }
+
+    #[test]
+    fn test_debounce_suppresses_repress_after_release() {
+        use crate::skin::play_mode_config::PlayModeConfig;
+
+        let mut proc = make_input_processor();
+        let mut playconfig = PlayModeConfig::default();
+        playconfig.debounce_window = 10;
+        proc.set_play_config(&mut playconfig);
+
+        proc.key_changed_internal(DeviceType::Keyboard, 100_000, 0, true);
+        proc.key_changed_internal(DeviceType::Keyboard, 150_000, 0, false);
+        // Bounce 3ms after the release is dropped and counted
+        proc.key_changed_internal(DeviceType::Keyboard, 153_000, 0, true);
+        assert!(!proc.key_state(0));
+        assert_eq!(proc.key_changed_time(0), 150_000);
+        assert_eq!(proc.chatter_counts()[0], 1);
+
+        // The trailing release of the bounce changes nothing
+        proc.key_changed_internal(DeviceType::Keyboard, 154_000, 0, false);
+        assert_eq!(proc.key_changed_time(0), 150_000);
+
+        // A press after the window is a real press
+        proc.key_changed_internal(DeviceType::Keyboard, 170_000, 0, true);
+        assert!(proc.key_state(0));
+        assert_eq!(proc.key_changed_time(0), 170_000);
+        assert_eq!(proc.chatter_counts()[0], 1);
+
+        proc.reset_chatter_counts();
+        assert_eq!(proc.chatter_counts()[0], 0);
+    }
+
+    #[test]
+    fn test_debounce_disabled_by_default() {
+        let mut proc = make_input_processor();
+        proc.key_changed_internal(DeviceType::Keyboard, 100_000, 0, true);
+        proc.key_changed_internal(DeviceType::Keyboard, 101_000, 0, false);
+        proc.key_changed_internal(DeviceType::Keyboard, 102_000, 0, true);
+        assert!(proc.key_state(0));
+        assert!(proc.chatter_counts().iter().all(|&count| count == 0));
+    }
+
+    fn add_test_controller(proc: &mut BMSPlayerInputProcessor, name: &str) {
+        let controller = GdxController::with_state(name.to_string(), 32, 8);
+        proc.bminput.push(BMControllerInputProcessor::new(
+            name.to_string(),
+            controller,
+            &ControllerConfig::default(),
+        ));
+        proc.controller_start.push(false);
+        proc.controller_select.push(false);
+    }
+
+    #[test]
+    fn test_set_controller_config_binds_named_side_first() {
+        let mut proc = make_input_processor();
+        add_test_controller(&mut proc, "pad-2");
+        add_test_controller(&mut proc, "pad");
+        let mut configs = vec![ControllerConfig::default(), ControllerConfig::default()];
+        configs[0].latency_offset = 1;
+        configs[1].name = "pad-2".to_string();
+        configs[1].latency_offset = 2;
+
+        proc.set_controller_config(&mut configs);
+
+        // pad-2 keeps the 2P config even though the 1P config is unnamed
+        assert!(proc.bminput[0].enabled);
+        assert_eq!(proc.bminput[0].latency_offset(), 2_000);
+        assert!(proc.bminput[1].enabled);
+        assert_eq!(proc.bminput[1].latency_offset(), 1_000);
+        assert_eq!(configs[0].name, "pad");
+    }
+
+    #[test]
+    fn test_set_controller_config_leaves_extra_controllers_disabled() {
+        let mut proc = make_input_processor();
+        add_test_controller(&mut proc, "pad");
+        add_test_controller(&mut proc, "other");
+        let mut configs = vec![ControllerConfig::default()];
+        configs[0].name = "other".to_string();
+
+        proc.set_controller_config(&mut configs);
+
+        assert!(!proc.bminput[0].enabled);
+        assert!(proc.bminput[1].enabled);
+    }
+
+    #[test]
+    fn test_start_merged_across_controllers() {
+        let mut proc = make_input_processor();
+        add_test_controller(&mut proc, "pad");
+        add_test_controller(&mut proc, "pad-2");
+        let mut configs = vec![ControllerConfig::default(), ControllerConfig::default()];
+        for config in &mut configs {
+            config.duration = 0;
+        }
+        proc.set_controller_config(&mut configs);
+        let start = BMKeys::BUTTON_9 as usize;
+
+        proc.bminput[0].controller.button_state[start] = true;
+        proc.poll();
+        assert!(proc.start_pressed());
+        proc.bminput[1].controller.button_state[start] = true;
+        proc.poll();
+
+        // Releasing start on 1P keeps it held while 2P still holds it
+        proc.bminput[0].controller.button_state[start] = false;
+        proc.poll();
+        assert!(proc.start_pressed());
+        proc.bminput[1].controller.button_state[start] = false;
+        proc.poll();
+        assert!(!proc.start_pressed());
+    }
+
     #[test]
     fn test_scroll_state() {
         let mut proc = make_input_processor();
@@ -0,0 +1,136 @@
+//! Live input statistics shown in the ModMenu input diagnostics window.
+
+use std::collections::VecDeque;
+
+use crate::input::bms_player_input_device::DeviceType;
+
+/// Window for press rates (us).
+const RATE_WINDOW: i64 = 1_000_000;
+/// Number of poll intervals averaged for the polling interval display.
+const POLL_HISTORY: usize = 120;
+
+/// Activity of one key since the last reset.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KeyActivity {
+    pub presses: u32,
+    pub pressed: bool,
+    /// Monotonic time (us) of the last press or release.
+    pub last_event: Option<i64>,
+    pub device: Option<DeviceType>,
+}
+
+/// Per-key press counts, recent press times and input poll intervals.
+pub struct InputDiagnostics {
+    keys: Vec<KeyActivity>,
+    /// (monotonic time us, key) of presses within `RATE_WINDOW`
+    recent_presses: VecDeque<(i64, usize)>,
+    last_poll: Option<i64>,
+    poll_intervals: VecDeque<i64>,
+}
+
+impl InputDiagnostics {
+    pub fn new(key_count: usize) -> Self {
+        Self {
+            keys: vec![KeyActivity::default(); key_count],
+            recent_presses: VecDeque::new(),
+            last_poll: None,
+            poll_intervals: VecDeque::with_capacity(POLL_HISTORY),
+        }
+    }
+
+    pub fn record_poll(&mut self, now: i64) {
+        if let Some(last) = self.last_poll.replace(now) {
+            if self.poll_intervals.len() == POLL_HISTORY {
+                self.poll_intervals.pop_front();
+            }
+            self.poll_intervals.push_back(now - last);
+        }
+    }
+
+    pub fn record_key(&mut self, key: usize, pressed: bool, device: DeviceType, now: i64) {
+        let Some(activity) = self.keys.get_mut(key) else {
+            return;
+        };
+        activity.pressed = pressed;
+        activity.last_event = Some(now);
+        activity.device = Some(device);
+        if pressed {
+            activity.presses = activity.presses.saturating_add(1);
+            while self
+                .recent_presses
+                .front()
+                .is_some_and(|&(time, _)| now - time > RATE_WINDOW)
+            {
+                self.recent_presses.pop_front();
+            }
+            self.recent_presses.push_back((now, key));
+        }
+    }
+
+    pub fn keys(&self) -> &[KeyActivity] {
+        &self.keys
+    }
+
+    /// Presses per second over the last second on keys matching `filter`.
+    pub fn press_rate(&self, now: i64, filter: impl Fn(usize) -> bool) -> f32 {
+        let count = self
+            .recent_presses
+            .iter()
+            .filter(|&&(time, key)| now - time <= RATE_WINDOW && filter(key))
+            .count();
+        count as f32 * 1_000_000.0 / RATE_WINDOW as f32
+    }
+
+    /// Average and longest of the recent poll intervals (us).
+    pub fn poll_interval(&self) -> Option<(i64, i64)> {
+        let max = *self.poll_intervals.iter().max()?;
+        let average = self.poll_intervals.iter().sum::<i64>() / self.poll_intervals.len() as i64;
+        Some((average, max))
+    }
+
+    pub fn reset(&mut self) {
+        self.keys.fill(KeyActivity::default());
+        self.recent_presses.clear();
+        self.poll_intervals.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_key_counts_presses_and_rate() {
+        let mut diagnostics = InputDiagnostics::new(4);
+        diagnostics.record_key(0, true, DeviceType::BmController, 100_000);
+        diagnostics.record_key(0, false, DeviceType::BmController, 150_000);
+        diagnostics.record_key(0, true, DeviceType::BmController, 200_000);
+        diagnostics.record_key(1, true, DeviceType::Keyboard, 300_000);
+        // Out of range keys are ignored
+        diagnostics.record_key(9, true, DeviceType::Keyboard, 300_000);
+
+        let key = diagnostics.keys()[0];
+        assert_eq!(key.presses, 2);
+        assert!(key.pressed);
+        assert_eq!(key.last_event, Some(200_000));
+        assert_eq!(key.device, Some(DeviceType::BmController));
+
+        assert_eq!(diagnostics.press_rate(1_000_000, |key| key == 0), 2.0);
+        assert_eq!(diagnostics.press_rate(1_150_000, |key| key == 0), 1.0);
+        assert_eq!(diagnostics.press_rate(1_000_000, |_| true), 3.0);
+    }
+
+    #[test]
+    fn test_poll_interval_average_and_max() {
+        let mut diagnostics = InputDiagnostics::new(1);
+        assert_eq!(diagnostics.poll_interval(), None);
+        for time in [0, 1_000, 2_000, 6_000] {
+            diagnostics.record_poll(time);
+        }
+        assert_eq!(diagnostics.poll_interval(), Some((2_000, 4_000)));
+
+        diagnostics.reset();
+        assert_eq!(diagnostics.poll_interval(), None);
+        assert_eq!(diagnostics.keys()[0], KeyActivity::default());
+    }
+}
@@ -0,0 +1,284 @@
+//! Dedicated 1000Hz input sampling thread.
+//!
+//! The frame loop polls device state once per frame, so a press is stamped
+//! with the time of the frame that noticed it. This thread samples keyboard and
+//! controller state every millisecond, stamps each change with the monotonic
+//! clock and sends it through an mpsc channel (lock-free since Rust 1.67).
+//! `BMSPlayerInputProcessor::poll()` drains the channel and uses the stamped
+//! time for a change it detects, instead of the frame time.
+//!
+//! MIDI is not sampled here: midir already stamps messages in its own callback
+//! thread.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::input::controller::lwjgl3_controller::Lwjgl3Controller;
+use crate::input::controller::lwjgl3_controller_manager::Lwjgl3ControllerManager;
+use crate::input::winit_input_bridge::{KEY_COUNT, SharedKeyState};
+use crate::skin::monotonic_clock::monotonic_micros;
+
+/// Sampling period (1000Hz).
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Stamps older than this (us) are stale, e.g. a tap that started and ended
+/// between two frames, and are not applied to a later change.
+const MAX_STAMP_AGE: i64 = 100_000;
+
+/// Device a sampled change came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PolledSource {
+    Keyboard,
+    /// Controller index in gilrs enumeration order, which matches the order
+    /// of `BMSPlayerInputProcessor::bm_input_processor()`.
+    Controller(usize),
+}
+
+/// A key or button state change seen by the poll thread.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PolledInput {
+    pub source: PolledSource,
+    /// Java keycode for the keyboard, button index for controllers.
+    pub code: i32,
+    pub pressed: bool,
+    /// Monotonic time of the sample (us).
+    pub time: i64,
+}
+
+/// Detects state changes between consecutive samples.
+#[derive(Default)]
+struct InputSampler {
+    keys: Vec<bool>,
+    buttons: Vec<Vec<bool>>,
+}
+
+impl InputSampler {
+    fn sample_keyboard(&mut self, keys: &[bool], time: i64, out: &mut Vec<PolledInput>) {
+        self.keys.resize(keys.len(), false);
+        for (code, (&pressed, prev)) in keys.iter().zip(self.keys.iter_mut()).enumerate() {
+            if pressed != *prev {
+                *prev = pressed;
+                out.push(PolledInput {
+                    source: PolledSource::Keyboard,
+                    code: code as i32,
+                    pressed,
+                    time,
+                });
+            }
+        }
+    }
+
+    fn sample_controllers(
+        &mut self,
+        controllers: &[Lwjgl3Controller],
+        time: i64,
+        out: &mut Vec<PolledInput>,
+    ) {
+        self.buttons.resize(controllers.len(), Vec::new());
+        for (index, (controller, prev)) in
+            controllers.iter().zip(self.buttons.iter_mut()).enumerate()
+        {
+            prev.resize(controller.button_state.len(), false);
+            for (code, (&pressed, prev)) in controller
+                .button_state
+                .iter()
+                .zip(prev.iter_mut())
+                .enumerate()
+            {
+                if pressed != *prev {
+                    *prev = pressed;
+                    out.push(PolledInput {
+                        source: PolledSource::Controller(index),
+                        code: code as i32,
+                        pressed,
+                        time,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Background thread sampling keyboard and controller state at 1000Hz.
+/// Stopped and joined on drop.
+pub struct InputPollThread {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+    events: Receiver<PolledInput>,
+}
+
+impl InputPollThread {
+    /// Start sampling `key_state` and every gamepad gilrs reports. Returns None
+    /// if the thread could not be spawned.
+    pub fn start(key_state: SharedKeyState) -> Option<Self> {
+        let (sender, events) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let handle = thread::Builder::new()
+            .name("input-poll".to_string())
+            .spawn(move || run(key_state, sender, thread_stop))
+            .map_err(|e| log::error!("Failed to start input poll thread: {}", e))
+            .ok()?;
+        Some(Self {
+            stop,
+            handle: Some(handle),
+            events,
+        })
+    }
+
+    /// Changes sampled since the last call, oldest first.
+    pub fn drain(&self) -> impl Iterator<Item = PolledInput> + '_ {
+        self.events.try_iter()
+    }
+}
+
+impl Drop for InputPollThread {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn run(key_state: SharedKeyState, sender: Sender<PolledInput>, stop: Arc<AtomicBool>) {
+    // gilrs is created on this thread, so the frame loop's instance is untouched
+    let mut controller_manager = Lwjgl3ControllerManager::new();
+    let mut sampler = InputSampler::default();
+    let mut changes = Vec::new();
+    let mut next = Instant::now();
+    while !stop.load(Ordering::Relaxed) {
+        controller_manager.poll_state();
+        let time = monotonic_micros();
+        let keys: [bool; KEY_COUNT] = key_state.pressed_keys();
+        sampler.sample_keyboard(&keys, time, &mut changes);
+        sampler.sample_controllers(&controller_manager.controllers, time, &mut changes);
+        for change in changes.drain(..) {
+            if sender.send(change).is_err() {
+                return;
+            }
+        }
+
+        next += POLL_INTERVAL;
+        let now = Instant::now();
+        if next > now {
+            thread::sleep(next - now);
+        } else {
+            // Fell behind (e.g. the thread was descheduled); do not try to catch up
+            next = now;
+        }
+    }
+}
+
+/// Latest stamped change per key/button, consumed when the frame poll
+/// detects the same change.
+#[derive(Default)]
+pub struct PolledChangeTimes {
+    changes: HashMap<(PolledSource, i32), (bool, i64)>,
+}
+
+impl PolledChangeTimes {
+    pub fn record(&mut self, input: PolledInput) {
+        self.changes
+            .insert((input.source, input.code), (input.pressed, input.time));
+    }
+
+    /// Take the stamped time (us, monotonic) of `code` changing to `pressed`,
+    /// if the poll thread saw it no earlier than `MAX_STAMP_AGE` before `now`.
+    pub fn take(
+        &mut self,
+        source: PolledSource,
+        code: i32,
+        pressed: bool,
+        now: i64,
+    ) -> Option<i64> {
+        let (stamped_pressed, time) = self.changes.remove(&(source, code))?;
+        (stamped_pressed == pressed && time <= now && now - time <= MAX_STAMP_AGE).then_some(time)
+    }
+
+    pub fn clear(&mut self) {
+        self.changes.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sampler_reports_keyboard_changes_once() {
+        let mut sampler = InputSampler::default();
+        let mut out = Vec::new();
+        let mut keys = [false; KEY_COUNT];
+        keys[29] = true;
+        sampler.sample_keyboard(&keys, 1_000, &mut out);
+        sampler.sample_keyboard(&keys, 2_000, &mut out);
+        keys[29] = false;
+        sampler.sample_keyboard(&keys, 3_000, &mut out);
+
+        assert_eq!(
+            out,
+            [
+                PolledInput {
+                    source: PolledSource::Keyboard,
+                    code: 29,
+                    pressed: true,
+                    time: 1_000,
+                },
+                PolledInput {
+                    source: PolledSource::Keyboard,
+                    code: 29,
+                    pressed: false,
+                    time: 3_000,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_change_times_take_matching_recent_stamp() {
+        let mut times = PolledChangeTimes::default();
+        let press = PolledInput {
+            source: PolledSource::Controller(1),
+            code: 3,
+            pressed: true,
+            time: 10_000,
+        };
+        times.record(press);
+        // Another device or the opposite state does not match
+        assert_eq!(
+            times.take(PolledSource::Controller(0), 3, true, 12_000),
+            None
+        );
+        assert_eq!(
+            times.take(PolledSource::Controller(1), 3, true, 12_000),
+            Some(10_000)
+        );
+        // Consumed
+        assert_eq!(
+            times.take(PolledSource::Controller(1), 3, true, 12_000),
+            None
+        );
+
+        times.record(press);
+        assert_eq!(
+            times.take(PolledSource::Controller(1), 3, false, 12_000),
+            None
+        );
+
+        times.record(press);
+        assert_eq!(
+            times.take(
+                PolledSource::Controller(1),
+                3,
+                true,
+                10_000 + MAX_STAMP_AGE + 1
+            ),
+            None
+        );
+    }
+}
@@ -17,4 +17,18 @@ pub enum KeyCommand {
     OpenSkinConfiguration,
     ToggleModMenu,
     CopyHighlightedMenuText,
+    /// Post the result to the configured social accounts.
+    ShareResult,
+    /// Drop the selected viewer request in the Requests folder.
+    SkipStreamRequest,
+    /// Move the cursor to a random chart in the current folder.
+    RandomSelect,
+    /// Move the cursor to a random chart without a play record.
+    RandomSelectUnplayed,
+    /// Move the cursor to a random chart whose best clear is Failed.
+    RandomSelectFailed,
+    /// Scan every BMS folder for new and changed charts in the background.
+    UpdateLibrary,
+    /// Open the play statistics window.
+    OpenStatistics,
 }
@@ -51,6 +51,7 @@ pub enum ControlKeys {
     Del,
     Escape,
     KeyC,
+    KeyR,
 }
 
 impl ControlKeys {
@@ -87,6 +88,7 @@ impl ControlKeys {
             ControlKeys::Del => 28,
             ControlKeys::Escape => 29,
             ControlKeys::KeyC => 30,
+            ControlKeys::KeyR => 31,
         }
     }
 
@@ -123,6 +125,7 @@ impl ControlKeys {
             ControlKeys::Del => Keys::FORWARD_DEL,
             ControlKeys::Escape => Keys::ESCAPE,
             ControlKeys::KeyC => Keys::C,
+            ControlKeys::KeyR => Keys::R,
         }
     }
 
@@ -175,6 +178,7 @@ impl ControlKeys {
             ControlKeys::Del,
             ControlKeys::Escape,
             ControlKeys::KeyC,
+            ControlKeys::KeyR,
         ]
     }
 }
@@ -222,6 +226,10 @@ pub struct KeyBoardInputProcesseor {
     keymodifiers: [i32; 256],
     /// Minimum key input interval (ms)
     duration: i32,
+    /// Time added to key timestamps before judging (us)
+    latency_offset: i64,
+    /// Keys bound to function hotkeys, tracked in keystate
+    hotkeys: Vec<i32>,
 }
 
 impl KeyBoardInputProcesseor {
@@ -268,6 +276,8 @@ impl KeyBoardInputProcesseor {
             keytime,
             keymodifiers: [0; 256],
             duration: 0,
+            latency_offset: 0,
+            hotkeys: Vec::new(),
         };
         proc.set_config(config);
         proc
@@ -286,10 +296,25 @@ impl KeyBoardInputProcesseor {
     pub fn set_config(&mut self, config: &KeyboardConfig) {
         self.keys = config.keys.to_vec();
         self.duration = config.duration;
+        self.latency_offset = config.latency_offset as i64 * 1000;
         self.control = vec![config.start, config.select];
         self.mouse_scratch_input.set_config(config);
     }
 
+    pub fn set_hotkeys(&mut self, hotkeys: Vec<i32>) {
+        self.hotkeys = hotkeys;
+    }
+
+    /// Keycode assigned to lane `key`, or -1 if unassigned.
+    pub fn key_code(&self, key: usize) -> i32 {
+        self.keys.get(key).copied().unwrap_or(-1)
+    }
+
+    /// Time (us) added to key timestamps before judging.
+    pub fn latency_offset(&self) -> i64 {
+        self.latency_offset
+    }
+
     pub fn key_down(&mut self, keycode: i32) -> bool {
         self.last_pressed_key = keycode;
         true
@@ -356,6 +381,24 @@ impl KeyBoardInputProcesseor {
             }
         }
 
+        for &key in &self.hotkeys {
+            // Lane, start/select and control keys are already tracked above
+            if key < 0
+                || key as usize >= self.keystate.len()
+                || self.keys.contains(&key)
+                || self.control.contains(&key)
+                || self.reserved.contains(&key)
+            {
+                continue;
+            }
+            let pressed = gdx_compat::is_key_pressed(&self.key_state, key);
+            let kc = key as usize;
+            if accept_input && !self.textmode && pressed != self.keystate[kc] {
+                self.keystate[kc] = pressed;
+                self.keytime[kc] = microtime;
+            }
+        }
+
         self.mouse_scratch_input
             .poll(microtime, callback, &self.key_state);
     }
@@ -23,6 +23,7 @@ impl Keys {
     pub const CONTROL_RIGHT: i32 = 130;
     pub const Q: i32 = 45;
     pub const W: i32 = 51;
+    pub const R: i32 = 46;
     pub const NUM_0: i32 = 7;
     pub const NUM_1: i32 = 8;
     pub const NUM_2: i32 = 9;
@@ -4,12 +4,15 @@
 //! Uses midir for real MIDI device enumeration and input.
 
 use crate::input::bms_player_input_device::{BMSPlayerInputDevice, DeviceType};
-use crate::skin::play_mode_config::{MidiConfig, MidiInput, MidiInputType};
+use crate::skin::play_mode_config::{MidiConfig, MidiDeviceConfig, MidiInput, MidiInputType};
 use midir::{MidiInput as MidirInput, MidiInputConnection};
 use std::sync::mpsc;
 
 const MAX_KEYS: usize = 128;
 
+/// CC value at or above which an assigned controller button counts as pressed.
+const CC_PRESS_VALUE: i32 = 64;
+
 /// Callback interface for BMSPlayerInputProcessor methods called from MidiInputProcessor
 pub trait MidiCallback {
     fn key_changed_from_midi(&mut self, microtime: i64, key: usize, pressed: bool);
@@ -53,11 +56,25 @@ pub struct MidiInputProcessor {
     pitch_bend_up: Option<KeyHandler>,
     pitch_bend_down: Option<KeyHandler>,
 
+    // Whether each CC is held (value >= CC_PRESS_VALUE), to dispatch only on changes
+    cc_pressed: [bool; 128],
+
+    // Note-on velocity below this is ignored
+    velocity_threshold: i32,
+    // Channel filter: -1 accepts every channel
+    channel: i32,
+    devices: Vec<MidiDeviceConfig>,
+    // Time added to note timestamps before judging (us)
+    latency_offset: i64,
+
+    // Connected port names, indexed like the port number sent with each message
+    port_names: Vec<String>,
+
     // Active MIDI input connections (midir auto-disconnects on drop)
     connections: Vec<MidiInputConnection<()>>,
 
-    // Receives (command, data1, data2) from MIDI callback threads
-    receiver: Option<mpsc::Receiver<(i32, i32, i32)>>,
+    // Receives (port, status, data1, data2) from MIDI callback threads
+    receiver: Option<mpsc::Receiver<(usize, i32, i32, i32)>>,
 }
 
 impl Default for MidiInputProcessor {
@@ -78,6 +95,12 @@ impl MidiInputProcessor {
             cc_map: Vec::new(),
             pitch_bend_up: None,
             pitch_bend_down: None,
+            cc_pressed: [false; 128],
+            velocity_threshold: 1,
+            channel: -1,
+            devices: Vec::new(),
+            latency_offset: 0,
+            port_names: Vec::new(),
             connections: Vec::new(),
             receiver: None,
         };
@@ -123,6 +146,7 @@ impl MidiInputProcessor {
             };
 
             let tx = sender.clone();
+            let port_index = self.port_names.len();
             match midi_in_for_port.connect(
                 port,
                 &port_name,
@@ -130,7 +154,7 @@ impl MidiInputProcessor {
                     if message.is_empty() {
                         return;
                     }
-                    let command = (message[0] & 0xF0) as i32;
+                    let status = message[0] as i32;
                     let data1 = if message.len() > 1 {
                         message[1] as i32
                     } else {
@@ -144,10 +168,10 @@ impl MidiInputProcessor {
                     // Known limitation: MIDI event arrival time is approximated by main-thread
                     // poll time. Precise timestamping would require carrying the callback timestamp
                     // through the channel.
-                    if let Err(e) = tx.send((command, data1, data2)) {
+                    if let Err(e) = tx.send((port_index, status, data1, data2)) {
                         log::warn!(
-                            "MIDI event dropped (channel disconnected): command=0x{:02X}, data1={}, data2={} - {}",
-                            e.0.0, e.0.1, e.0.2, e
+                            "MIDI event dropped (channel disconnected): status=0x{:02X}, data1={}, data2={} - {}",
+                            e.0.1, e.0.2, e.0.3, e
                         );
                     }
                 },
@@ -156,6 +180,7 @@ impl MidiInputProcessor {
                 Ok(conn) => {
                     log::info!("Connected to MIDI input port: {}", port_name);
                     self.connections.push(conn);
+                    self.port_names.push(port_name);
                 }
                 Err(e) => {
                     log::warn!(
@@ -171,6 +196,7 @@ impl MidiInputProcessor {
     pub fn close(&mut self) {
         // Dropping MidiInputConnection auto-disconnects
         self.connections.clear();
+        self.port_names.clear();
         self.receiver = None;
     }
 
@@ -188,8 +214,8 @@ impl MidiInputProcessor {
 
         // Drain all pending messages (non-blocking)
         match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-            while let Ok((command, data1, data2)) = receiver.try_recv() {
-                self.on_short_message(command, data1, data2, callback);
+            while let Ok((port, status, data1, data2)) = receiver.try_recv() {
+                self.on_port_message(port, status, data1, data2, callback);
             }
         })) {
             Ok(()) => {}
@@ -203,9 +229,18 @@ impl MidiInputProcessor {
         self.receiver = Some(receiver);
     }
 
+    /// Time (us) added to note timestamps before judging.
+    pub fn latency_offset(&self) -> i64 {
+        self.latency_offset
+    }
+
     pub fn set_config(&mut self, config: &MidiConfig) {
         self.clear_impl();
         self.clear_handlers();
+        self.velocity_threshold = config.velocity_threshold.max(1);
+        self.channel = config.channel;
+        self.devices = config.devices.clone();
+        self.latency_offset = config.latency_offset as i64 * 1000;
 
         let keys = &config.keys;
         for (i, key) in keys.iter().enumerate() {
@@ -248,6 +283,7 @@ impl MidiInputProcessor {
         self.cc_map = (0..128).map(|_| None).collect();
         self.pitch_bend_up = None;
         self.pitch_bend_down = None;
+        self.cc_pressed = [false; 128];
     }
 
     fn set_handler(&mut self, input: &MidiInput, handler: KeyHandler) {
@@ -315,6 +351,22 @@ impl MidiInputProcessor {
         }
     }
 
+    fn on_control_change(&mut self, num: usize, value: i32, callback: &mut dyn MidiCallback) {
+        let pressed = value >= CC_PRESS_VALUE;
+        if self.cc_pressed[num] == pressed {
+            return;
+        }
+        self.cc_pressed[num] = pressed;
+        if pressed {
+            self.last_pressed_key_available = true;
+            self.last_pressed_key.input_type = MidiInputType::CONTROL_CHANGE;
+            self.last_pressed_key.value = num as i32;
+        }
+        if let Some(handler) = &self.cc_map[num] {
+            Self::dispatch_handler(handler, pressed, self.current_time(), callback);
+        }
+    }
+
     fn dispatch_handler(
         handler: &KeyHandler,
         pressed: bool,
@@ -352,11 +404,40 @@ impl MidiInputProcessor {
         }
     }
 
+    /// Names of the connected MIDI input ports.
+    pub fn port_names(&self) -> &[String] {
+        &self.port_names
+    }
+
     pub fn clear_last_pressed_key(&mut self) {
         self.last_pressed_key_available = false;
     }
 
-    /// Process a MIDI short message (note on, note off, pitch bend)
+    /// Process a message from connected port `port`, dropping it unless it is
+    /// a channel message on the channel configured for that device.
+    pub fn on_port_message(
+        &mut self,
+        port: usize,
+        status: i32,
+        data1: i32,
+        data2: i32,
+        callback: &mut dyn MidiCallback,
+    ) {
+        if status >= 0xF0 {
+            // System messages (clock, active sensing, SysEx) carry no input
+            return;
+        }
+        let channel = match self.port_names.get(port) {
+            Some(name) => MidiDeviceConfig::channel_for(&self.devices, self.channel, name),
+            None => self.channel,
+        };
+        if channel >= 0 && status & 0x0F != channel {
+            return;
+        }
+        self.on_short_message(status & 0xF0, data1, data2, callback);
+    }
+
+    /// Process a MIDI short message (note on, note off, control change, pitch bend)
     /// This replaces the Java MidiReceiver.send() method.
     pub fn on_short_message(
         &mut self,
@@ -383,16 +464,19 @@ impl MidiInputProcessor {
                 if note < MAX_KEYS {
                     if data2 == 0 {
                         self.note_off(note, callback);
-                    } else {
+                    } else if data2 >= self.velocity_threshold {
                         self.note_on(note, callback);
                     }
                 }
             }
             CONTROL_CHANGE => {
-                // Java intentionally ignores CC messages in MidiInputProcessor.send()
-                // (no CONTROL_CHANGE case). Dispatching CC here causes phantom key
-                // presses from mod wheels, sustain pedals, etc. during gameplay.
-                // CC handlers are registered via set_handler() but not dispatched.
+                // Java ignores CC messages. DJ controllers send buttons as CC, so
+                // assigned CCs act as buttons; unassigned ones (mod wheels,
+                // sustain pedals) only update the last pressed key.
+                let num = data1 as usize;
+                if num < 128 {
+                    self.on_control_change(num, data2, callback);
+                }
             }
             PITCH_BEND => {
                 let new_pitch = ((data1 & 0x7f) | ((data2 & 0x7f) << 7)) as i16 as i32 - 0x2000;
@@ -581,7 +665,7 @@ mod tests {
         assert!(cb.key_events.is_empty());
     }
 
-    // --- CC ignored ---
+    // --- CC ---
 
     #[test]
     fn test_cc_ignored() {
@@ -592,6 +676,76 @@ mod tests {
         assert!(cb.start_events.is_empty());
     }
 
+    #[test]
+    fn test_assigned_cc_acts_as_button() {
+        let mut config = MidiConfig::new(Mode::BEAT_7K, true);
+        config.keys[7] = Some(MidiInput::new(MidiInputType::CONTROL_CHANGE, 16));
+        let mut proc = MidiInputProcessor::new();
+        proc.set_config(&config);
+        let mut cb = TestCallback::default();
+        proc.on_short_message(CONTROL_CHANGE, 16, 127, &mut cb);
+        // Repeated values while held do not retrigger
+        proc.on_short_message(CONTROL_CHANGE, 16, 100, &mut cb);
+        proc.on_short_message(CONTROL_CHANGE, 16, 0, &mut cb);
+        let events: Vec<(usize, bool)> = cb.key_events.iter().map(|e| (e.1, e.2)).collect();
+        assert_eq!(events, vec![(7, true), (7, false)]);
+        let last = proc.last_pressed_key().unwrap();
+        assert_eq!(last.input_type, MidiInputType::CONTROL_CHANGE);
+        assert_eq!(last.value, 16);
+    }
+
+    // --- Velocity and channel filters ---
+
+    #[test]
+    fn test_soft_note_below_velocity_threshold_ignored() {
+        let mut config = MidiConfig::new(Mode::BEAT_7K, true);
+        config.velocity_threshold = 40;
+        let mut proc = MidiInputProcessor::new();
+        proc.set_config(&config);
+        let mut cb = TestCallback::default();
+        proc.on_short_message(NOTE_ON, 53, 20, &mut cb);
+        assert!(cb.key_events.is_empty());
+        proc.on_short_message(NOTE_ON, 53, 40, &mut cb);
+        assert_eq!(cb.key_events.len(), 1);
+        assert!(cb.key_events[0].2);
+    }
+
+    #[test]
+    fn test_channel_filter_per_device() {
+        let mut config = MidiConfig::new(Mode::BEAT_7K, true);
+        config.channel = 0;
+        config.devices.push(MidiDeviceConfig {
+            name: "DDJ".to_string(),
+            channel: 6,
+        });
+        let mut proc = MidiInputProcessor::new();
+        proc.set_config(&config);
+        proc.port_names = vec!["Keyboard".to_string(), "DDJ-400".to_string()];
+        let mut cb = TestCallback::default();
+
+        proc.on_port_message(0, NOTE_ON | 1, 53, 127, &mut cb);
+        assert!(
+            cb.key_events.is_empty(),
+            "keyboard listens on channel 0 only"
+        );
+        proc.on_port_message(0, NOTE_ON, 53, 127, &mut cb);
+        assert_eq!(cb.key_events.len(), 1);
+
+        proc.on_port_message(1, NOTE_ON, 54, 127, &mut cb);
+        assert_eq!(
+            cb.key_events.len(),
+            1,
+            "controller listens on channel 6 only"
+        );
+        proc.on_port_message(1, NOTE_ON | 6, 54, 127, &mut cb);
+        assert_eq!(cb.key_events.len(), 2);
+        assert_eq!(cb.key_events[1].1, 1);
+
+        // System real-time messages are never input
+        proc.on_port_message(1, 0xF8, 0, 0, &mut cb);
+        assert_eq!(cb.key_events.len(), 2);
+    }
+
     // --- Start/select ---
 
     #[test]
@@ -5,6 +5,8 @@ pub mod bms_player_input_device;
 pub mod bms_player_input_processor;
 pub mod controller;
 pub mod gdx_compat;
+pub mod input_diagnostics;
+pub mod input_poll_thread;
 pub mod input_snapshot;
 pub mod key_command;
 pub mod key_input_log;
@@ -17,8 +19,8 @@ pub mod winit_input_bridge;
 // Re-exports from rubato_skin
 pub use crate::skin::config::Config;
 pub use crate::skin::play_mode_config::{
-    ANALOG_SCRATCH_VER_1, ANALOG_SCRATCH_VER_2, ControllerConfig, KeyboardConfig,
-    MOUSE_SCRATCH_VER_1, MOUSE_SCRATCH_VER_2, MidiConfig, MidiInput, MidiInputType,
+    ANALOG_SCRATCH_VER_1, ANALOG_SCRATCH_VER_2, ANALOG_SCRATCH_VER_3, ControllerConfig,
+    KeyboardConfig, MOUSE_SCRATCH_VER_1, MOUSE_SCRATCH_VER_2, MidiConfig, MidiInput, MidiInputType,
     MouseScratchConfig, PlayModeConfig,
 };
 pub use crate::skin::player_config::PlayerConfig;
@@ -15,7 +15,7 @@ use crate::input::keys::Keys;
 use crate::skin::sync_utils::lock_or_recover;
 
 /// Number of key slots (matches Java Gdx.input key array size)
-const KEY_COUNT: usize = 256;
+pub const KEY_COUNT: usize = 256;
 
 /// Shared key state that winit writes and the keyboard processor reads.
 #[derive(Clone)]
@@ -68,6 +68,11 @@ impl SharedKeyState {
         inner.keys[keycode as usize]
     }
 
+    /// Copy of every key's pressed state, indexed by Java keycode.
+    pub fn pressed_keys(&self) -> [bool; KEY_COUNT] {
+        lock_or_recover(&self.inner).keys
+    }
+
     /// Set key state (by Java keycode).
     pub fn set_key_pressed(&self, keycode: i32, pressed: bool) {
         if keycode >= 0 && (keycode as usize) < KEY_COUNT {
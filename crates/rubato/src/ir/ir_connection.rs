@@ -2,6 +2,7 @@ use crate::ir::ir_account::IRAccount;
 use crate::ir::ir_chart_data::IRChartData;
 use crate::ir::ir_course_data::IRCourseData;
 use crate::ir::ir_player_data::IRPlayerData;
+use crate::ir::ir_player_profile::IRPlayerProfile;
 use crate::ir::ir_response::IRResponse;
 use crate::ir::ir_score_data::IRScoreData;
 use crate::ir::ir_table_data::IRTableData;
@@ -71,6 +72,14 @@ pub trait IRConnection {
     /// Send course score data
     fn send_course_play_data(&self, course: &IRCourseData, score: &IRScoreData) -> IRResponse<()>;
 
+    /// Send the player's dan and course trophies.
+    fn send_player_profile(&self, profile: &IRPlayerProfile) -> IRResponse<()> {
+        let _ = profile;
+        IRResponse::failure(
+            "send_player_profile() not implemented for this IR connection".to_string(),
+        )
+    }
+
     /// Get song URL. Returns None if not found.
     fn get_song_url(&self, chart: &IRChartData) -> Option<String>;
 
@@ -24,6 +24,27 @@ pub struct IRConnectionEntry {
 /// time (including after first access). This matches the Java pattern where
 /// classpath scanning can discover JARs added at runtime.
 static IR_CONNECTIONS: Mutex<Vec<IRConnectionEntry>> = Mutex::new(Vec::new());
+static CONNECTED: Mutex<Vec<IRConnectedEndpoint>> = Mutex::new(Vec::new());
+static LAST_SUBMISSION: Mutex<Option<IRSubmissionResult>> = Mutex::new(None);
+
+/// IR the player is logged in to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IRConnectedEndpoint {
+    pub name: String,
+    pub player: String,
+    pub home: Option<String>,
+}
+
+/// Outcome of the latest score submission to an IR
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IRSubmissionResult {
+    pub ir: String,
+    pub title: String,
+    pub succeeded: bool,
+    pub message: String,
+    /// Unix time in milliseconds
+    pub time: i64,
+}
 
 pub struct IRConnectionManager;
 
@@ -62,6 +83,23 @@ impl IRConnectionManager {
         }
         None
     }
+
+    /// Replace the list of IRs the player is logged in to.
+    pub fn set_connected(endpoints: Vec<IRConnectedEndpoint>) {
+        *lock_or_recover(&CONNECTED) = endpoints;
+    }
+
+    pub fn connected() -> Vec<IRConnectedEndpoint> {
+        lock_or_recover(&CONNECTED).clone()
+    }
+
+    pub fn record_submission(result: IRSubmissionResult) {
+        *lock_or_recover(&LAST_SUBMISSION) = Some(result);
+    }
+
+    pub fn last_submission() -> Option<IRSubmissionResult> {
+        lock_or_recover(&LAST_SUBMISSION).clone()
+    }
 }
 
 /// Register IR connection implementations.
@@ -0,0 +1,74 @@
+use crate::core::earned_trophy::{self, EarnedTrophy};
+
+/// Course trophy or dan certification as sent to IR.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IRCourseTrophy {
+    /// Course hash: the chart SHA-256s concatenated
+    pub hash: String,
+    /// Course name
+    pub course: String,
+    /// Best trophy name, empty if none
+    pub trophy: String,
+    /// The player holds this class course's dan
+    pub certified: bool,
+    /// Unix time the record last improved
+    pub date: i64,
+}
+
+/// Player profile submitted to IR: the current dan and every earned trophy.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IRPlayerProfile {
+    /// Most recently certified dan, empty if none
+    pub rank: String,
+    pub trophies: Vec<IRCourseTrophy>,
+}
+
+impl IRPlayerProfile {
+    pub fn new(trophies: &[EarnedTrophy]) -> Self {
+        Self {
+            rank: earned_trophy::current_dan(trophies)
+                .map(|t| t.course.clone())
+                .unwrap_or_default(),
+            trophies: trophies
+                .iter()
+                .map(|t| IRCourseTrophy {
+                    hash: t.hash.clone(),
+                    course: t.course.clone(),
+                    trophy: t.trophy.clone().unwrap_or_default(),
+                    certified: t.certified,
+                    date: t.date,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_rank_is_latest_certification() {
+        let trophies = [
+            EarnedTrophy {
+                hash: "h1".to_string(),
+                course: "sl5".to_string(),
+                trophy: Some("goldmedal".to_string()),
+                certified: true,
+                date: 5,
+            },
+            EarnedTrophy {
+                hash: "h2".to_string(),
+                course: "gold course".to_string(),
+                trophy: Some("silvermedal".to_string()),
+                certified: false,
+                date: 8,
+            },
+        ];
+        let profile = IRPlayerProfile::new(&trophies);
+        assert_eq!(profile.rank, "sl5");
+        assert_eq!(profile.trophies.len(), 2);
+        assert_eq!(profile.trophies[1].trophy, "silvermedal");
+        assert_eq!(IRPlayerProfile::new(&[]).rank, "");
+    }
+}
@@ -1,4 +1,6 @@
+use crate::core::score_data::ScoreData;
 use crate::ir::{ImGuiNotify, LR2Random, Random};
+use crate::tr;
 
 /// LR2 ghost data
 ///
@@ -85,7 +87,7 @@ impl LR2GhostData {
         // We parse manually since we don't have apache commons csv
         let lines: Vec<&str> = ghost_csv.lines().collect();
         if lines.is_empty() {
-            ImGuiNotify::error("LR2IR returned empty response.");
+            ImGuiNotify::error(&tr!("notify-lr2ir-empty-response"));
             return None;
         }
 
@@ -95,7 +97,7 @@ impl LR2GhostData {
         // Parse CSV fields
         let fields: Vec<&str> = data_line.splitn(4, ',').collect();
         if fields.len() < 4 {
-            ImGuiNotify::error("Could not parse ghost data response from LR2IR.");
+            ImGuiNotify::error(&tr!("notify-lr2ir-ghost-parse-failed"));
             return None;
         }
 
@@ -104,7 +106,7 @@ impl LR2GhostData {
         let options: i32 = match fields[1].trim().parse() {
             Ok(v) => v,
             Err(_) => {
-                ImGuiNotify::error("Could not parse ghost data response from LR2IR.");
+                ImGuiNotify::error(&tr!("notify-lr2ir-ghost-parse-failed"));
                 return None;
             }
         };
@@ -113,7 +115,10 @@ impl LR2GhostData {
         let random_val = (options / 10) % 10;
         // for now, we only support mirror and random, and only SP
         if 3 <= random_val {
-            ImGuiNotify::warning(&format!("Unsupported random option: {}", random_val));
+            ImGuiNotify::warning(&tr!(
+                "notify-lr2ir-unsupported-random",
+                option = random_val.to_string()
+            ));
             return None;
         }
 
@@ -127,7 +132,7 @@ impl LR2GhostData {
         let seed: i32 = match fields[2].trim().parse() {
             Ok(v) => v,
             Err(_) => {
-                ImGuiNotify::error("Could not parse ghost data response from LR2IR.");
+                ImGuiNotify::error(&tr!("notify-lr2ir-ghost-parse-failed"));
                 return None;
             }
         };
@@ -181,6 +186,52 @@ impl LR2GhostData {
         }))
     }
 
+    /// Build ghost data from a bare LR2 play ghost string (the `ghost` column
+    /// of an LR2 score database or the last CSV field of an LR2IR response).
+    /// No lane shuffle information is available, so the random is `Identity`.
+    pub fn from_play_ghost(data: &str) -> Option<Self> {
+        let judgements = Self::decode_play_ghost(data.trim());
+        if judgements.is_empty() {
+            return None;
+        }
+        let mut counts = [0i32; 5];
+        for &judge in &judgements {
+            counts[judge.clamp(0, 4) as usize] += 1;
+        }
+        Some(Self::new(LR2GhostDataParams {
+            random: Random::Identity,
+            seed: 0,
+            lanes: 1234567,
+            judgements,
+            pgreat: counts[0],
+            great: counts[1],
+            good: counts[2],
+            bad: counts[3],
+            poor: counts[4],
+        }))
+    }
+
+    /// EX score of the ghost play (PGREAT x2 + GREAT).
+    pub fn exscore(&self) -> i32 {
+        self.pgreat * 2 + self.great
+    }
+
+    /// Store the ghost judgements on `score` so the pacemaker can follow them
+    /// note by note. LR2 has no FAST/SLOW split, so missing judge counts are
+    /// filled in as early judgements.
+    pub fn apply_to(&self, score: &mut ScoreData) {
+        score.notes = self.judgements.len() as i32;
+        score.encode_ghost(Some(&self.judgements));
+        if score.exscore() == 0 {
+            let counts = &mut score.judge_counts;
+            counts.epg = self.pgreat;
+            counts.egr = self.great;
+            counts.egd = self.good;
+            counts.ebd = self.bad;
+            counts.epr = self.poor;
+        }
+    }
+
     pub fn decode_play_ghost(data: &str) -> Vec<i32> {
         let mut data = data.to_string();
         data = data.replace("q", "XX");
@@ -386,6 +437,38 @@ mod tests {
         assert!(ghost.is_none());
     }
 
+    #[test]
+    fn test_from_play_ghost() {
+        let ghost = LR2GhostData::from_play_ghost("E3D2A").unwrap();
+        assert_eq!(ghost.random(), Random::Identity);
+        assert_eq!(ghost.judgements(), &[0, 0, 0, 1, 1, 4]);
+        assert_eq!(ghost.exscore(), 8);
+        assert!(LR2GhostData::from_play_ghost("").is_none());
+    }
+
+    #[test]
+    fn test_apply_to_score_data_feeds_ghost() {
+        let ghost = LR2GhostData::from_play_ghost("E2D1B1").unwrap();
+        let mut score = ScoreData::default();
+        ghost.apply_to(&mut score);
+        assert_eq!(score.notes, 4);
+        assert_eq!(score.decode_ghost(), Some(vec![0, 0, 1, 3]));
+        assert_eq!(score.exscore(), 5);
+        assert_eq!(score.judge_counts.ebd, 1);
+    }
+
+    #[test]
+    fn test_apply_to_keeps_existing_judge_counts() {
+        let ghost = LR2GhostData::from_play_ghost("E2").unwrap();
+        let mut score = ScoreData::default();
+        score.judge_counts.lpg = 2;
+        score.judge_counts.lgr = 1;
+        ghost.apply_to(&mut score);
+        assert_eq!(score.exscore(), 5);
+        assert_eq!(score.judge_counts.epg, 0);
+        assert_eq!(score.decode_ghost(), Some(vec![0, 0]));
+    }
+
     #[test]
     fn test_parse_empty_input_returns_none() {
         let ghost = LR2GhostData::parse("");
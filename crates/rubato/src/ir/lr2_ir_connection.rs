@@ -13,6 +13,7 @@ use crate::ir::ir_chart_data::IRChartData;
 use crate::ir::ir_score_data::IRScoreData;
 use crate::ir::leaderboard_entry::LeaderboardEntry;
 use crate::ir::lr2_ghost_data::LR2GhostData;
+use crate::tr;
 
 /// LR2 IR connection
 ///
@@ -161,9 +162,9 @@ impl LR2IRConnection {
         {
             Ok(response) => {
                 if response.status() != reqwest::StatusCode::OK {
-                    ImGuiNotify::error(&format!(
-                        "Failed to send request to LR2IR: HTTP error code: {}",
-                        response.status()
+                    ImGuiNotify::error(&tr!(
+                        "notify-lr2ir-http-error",
+                        status = response.status().to_string()
                     ));
                     return None;
                 }
@@ -177,13 +178,16 @@ impl LR2IRConnection {
                         Some(decoded.to_string())
                     }
                     Err(e) => {
-                        ImGuiNotify::error(&format!("Failed to send request to LR2IR: {}", e));
+                        ImGuiNotify::error(&tr!(
+                            "notify-lr2ir-request-failed",
+                            error = e.to_string()
+                        ));
                         None
                     }
                 }
             }
             Err(e) => {
-                ImGuiNotify::error(&format!("Failed to send request to LR2IR: {}", e));
+                ImGuiNotify::error(&tr!("notify-lr2ir-request-failed", error = e.to_string()));
                 None
             }
         }
@@ -245,9 +249,7 @@ impl LR2IRConnection {
                                 entries
                             }
                             None => {
-                                ImGuiNotify::error(
-                                    "Failed to get score data from LR2IR: XML parse error",
-                                );
+                                ImGuiNotify::error(&tr!("notify-lr2ir-score-parse-failed"));
                                 return None;
                             }
                         }
@@ -303,7 +305,7 @@ impl LR2IRConnection {
                 let status = response.status();
                 if status != reqwest::StatusCode::OK {
                     error!("Unexpected http response code: {}", status);
-                    ImGuiNotify::error("Failed to load ghost data.");
+                    ImGuiNotify::error(&tr!("notify-ghost-load-failed"));
                     return None;
                 }
                 // Enforce size limit during streaming to protect against
@@ -317,14 +319,14 @@ impl LR2IRConnection {
                     }
                     Err(e) => {
                         error!("{}", e);
-                        ImGuiNotify::error("Failed to load ghost data.");
+                        ImGuiNotify::error(&tr!("notify-ghost-load-failed"));
                         None
                     }
                 }
             }
             Err(e) => {
                 error!("{}", e);
-                ImGuiNotify::error("Failed to load ghost data.");
+                ImGuiNotify::error(&tr!("notify-ghost-load-failed"));
                 None
             }
         }
@@ -419,7 +421,9 @@ impl Score {
             4 => 6, // Hard
             5 => {
                 // FC
-                if self.pg + self.gr == self.notes {
+                if self.pg == self.notes {
+                    10 // Max
+                } else if self.pg + self.gr == self.notes {
                     9 // Perfect
                 } else {
                     8
@@ -531,6 +535,19 @@ mod tests {
         assert_eq!(s.rubato_clear(), 9);
     }
 
+    #[test]
+    fn test_score_clear_fc_max() {
+        // FC with pg == notes -> Max (10)
+        let s = Score {
+            clear: 5,
+            pg: 300,
+            gr: 0,
+            notes: 300,
+            ..Default::default()
+        };
+        assert_eq!(s.rubato_clear(), 10);
+    }
+
     #[test]
     fn test_score_clear_fc_not_perfect() {
         // FC with pg + gr != notes -> FullCombo (8)
@@ -14,6 +14,7 @@ pub mod ir_connection;
 pub mod ir_connection_manager;
 pub mod ir_course_data;
 pub mod ir_player_data;
+pub mod ir_player_profile;
 pub mod ir_response;
 pub mod ir_score_data;
 pub mod ir_table_data;
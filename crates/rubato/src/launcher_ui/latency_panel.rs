@@ -0,0 +1,182 @@
+// Latency tuning panel of the audio tab: buffer settings, round-trip
+// measurement and the audio offset derived from it.
+
+use super::LauncherUi;
+use crate::core::audio_config::{AudioConfig, DriverType};
+use crate::core::player_config::{JUDGETIMING_MAX, JUDGETIMING_MIN, JudgeSettings};
+use crate::platform::RoundTripResult;
+use crate::tr;
+
+/// Buffer settings tried in the latency tuning panel before they are applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct LatencyDraft {
+    pub buffer_size: i32,
+    pub period_count: i32,
+}
+
+impl LatencyDraft {
+    fn from_config(audio: &AudioConfig) -> Self {
+        Self {
+            buffer_size: audio.device_buffer_size,
+            period_count: audio.device_period_count,
+        }
+    }
+}
+
+/// Store a confirmed measurement: the buffer settings and output latency in
+/// the audio config, and the output latency as the player's audio offset.
+pub(super) fn apply_latency_measurement(
+    audio: &mut AudioConfig,
+    judge: &mut JudgeSettings,
+    draft: LatencyDraft,
+    result: &RoundTripResult,
+) {
+    let latency = result.output_latency_ms().round() as i32;
+    audio.device_buffer_size = draft.buffer_size;
+    audio.device_period_count = draft.period_count;
+    audio.measured_output_latency = latency.clamp(0, 1000);
+    judge.audio_offset = latency.clamp(JUDGETIMING_MIN, JUDGETIMING_MAX);
+}
+
+pub(super) fn round_trip_summary(result: &RoundTripResult) -> String {
+    tr!(
+        "launcher-latency-result",
+        device = result.device.as_str(),
+        heard = result.clicks_heard,
+        played = result.clicks_played,
+        round_trip = format!("{:.1}", result.round_trip_ms),
+        output = format!("{:.1}", result.output_latency_ms())
+    )
+}
+
+impl LauncherUi {
+    pub(super) fn render_latency_panel(&mut self, ui: &mut egui::Ui) {
+        self.poll_round_trip();
+        let audio = self.config.audio.get_or_insert_with(Default::default);
+        let draft = self
+            .latency_draft
+            .get_or_insert_with(|| LatencyDraft::from_config(audio));
+        let running = self.round_trip_handle.is_some();
+        let mut start = false;
+        let mut apply = false;
+
+        egui::CollapsingHeader::new(tr!("launcher-latency-tuning")).show(ui, |ui| {
+            ui.label(tr!("launcher-latency-tuning-hint"));
+            egui::Grid::new("latency_grid").show(ui, |ui| {
+                ui.label(tr!("launcher-audio-buffer"));
+                ui.add(egui::DragValue::new(&mut draft.buffer_size).range(4..=4096));
+                ui.end_row();
+
+                ui.label(tr!("launcher-period-count"))
+                    .on_hover_text(tr!("launcher-period-count-hint"));
+                ui.add(egui::DragValue::new(&mut draft.period_count).range(1..=8));
+                ui.end_row();
+
+                let frames = draft.buffer_size * draft.period_count;
+                ui.label(tr!("launcher-latency-buffered"));
+                if audio.sample_rate > 0 {
+                    ui.label(tr!(
+                        "launcher-audio-test-buffer",
+                        frames = frames,
+                        ms = format!(
+                            "{:.1}",
+                            f64::from(frames) * 1000.0 / f64::from(audio.sample_rate)
+                        )
+                    ));
+                } else {
+                    ui.label(frames.to_string());
+                }
+                ui.end_row();
+
+                if audio.measured_output_latency > 0 {
+                    ui.label(tr!("launcher-latency-stored"));
+                    ui.label(tr!(
+                        "launcher-audio-test-latency",
+                        ms = audio.measured_output_latency
+                    ));
+                    ui.end_row();
+                }
+            });
+
+            ui.horizontal(|ui| {
+                start = ui
+                    .add_enabled(!running, egui::Button::new(tr!("launcher-measure-latency")))
+                    .clicked();
+                if running {
+                    ui.spinner();
+                }
+            });
+            match &self.round_trip_result {
+                Some(Ok((measured, result))) => {
+                    ui.label(round_trip_summary(result));
+                    ui.label(tr!(
+                        "launcher-latency-recommended",
+                        offset = format!("{:.0}", result.output_latency_ms())
+                    ));
+                    apply = ui
+                        .add_enabled(
+                            measured == draft,
+                            egui::Button::new(tr!("launcher-latency-apply")),
+                        )
+                        .on_hover_text(tr!("launcher-latency-apply-hint"))
+                        .clicked();
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(
+                        egui::Color32::RED,
+                        tr!("launcher-audio-test-failed", error = e.as_str()),
+                    );
+                }
+                None => {}
+            }
+        });
+
+        if running {
+            ui.ctx().request_repaint();
+        }
+        if start {
+            self.start_round_trip();
+        }
+        if apply
+            && let (Some(audio), Some(Ok((draft, result)))) =
+                (self.config.audio.as_mut(), self.round_trip_result.as_ref())
+        {
+            apply_latency_measurement(audio, &mut self.player.judge_settings, *draft, result);
+        }
+    }
+
+    /// Measure the round trip on a background thread with the draft buffer settings.
+    fn start_round_trip(&mut self) {
+        let audio = self.config.audio.clone().unwrap_or_default();
+        let Some(draft) = self.latency_draft else {
+            return;
+        };
+        let device_name = match audio.driver {
+            DriverType::PortAudio => audio.driver_name,
+            DriverType::OpenAL => None,
+        };
+        self.round_trip_result = None;
+        self.round_trip_handle = Some(std::thread::spawn(move || {
+            crate::platform::measure_round_trip(
+                device_name.as_deref(),
+                draft.buffer_size,
+                audio.sample_rate,
+            )
+            .map(|result| (draft, result))
+        }));
+    }
+
+    fn poll_round_trip(&mut self) {
+        if self
+            .round_trip_handle
+            .as_ref()
+            .is_some_and(|handle| handle.is_finished())
+            && let Some(handle) = self.round_trip_handle.take()
+        {
+            self.round_trip_result = Some(match handle.join() {
+                Ok(result) => result.map_err(|e| e.to_string()),
+                Err(_) => Err("round trip thread panicked".to_string()),
+            });
+        }
+    }
+}
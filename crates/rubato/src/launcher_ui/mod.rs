@@ -1,18 +1,31 @@
 // LauncherUi -- egui-based launcher configuration window
 // Java equivalent: PlayConfigurationView (JavaFX Application)
 
+mod latency_panel;
 pub(crate) mod tabs;
+mod update_panel;
 
 #[cfg(test)]
 #[allow(clippy::field_reassign_with_default)]
 mod tests;
 
+use crate::core::beatoraja_import;
 use crate::core::config::Config;
 use crate::core::player_config::PlayerConfig;
+use crate::i18n::{self, Language};
+use crate::ir::ir_player_data::IRPlayerData;
+use crate::ir::ir_response::IRResponse;
+use crate::platform::{AudioTestResult, RoundTripResult};
+use crate::skin::player_information::PlayerInformation;
+use crate::version_checker::ReleaseInfo;
 use bms::model::mode::Mode;
 
+use crate::tr;
+use crate::views::config::key_binding_view::KeyBindingView;
 use crate::views::config::obs_configuration_view::ObsConfigurationView;
-use crate::views::play_configuration_view::{PlayConfigurationView, PlayMode};
+use crate::views::config::video_configuration_view::VideoConfigurationView;
+use crate::views::play_configuration_view::{BmsLoadingHandle, PlayConfigurationView, PlayMode};
+use crate::views::resource_configuration_view::ResourceConfigurationView;
 use crate::views::skin_configuration_view::SkinConfigurationView;
 
 /// Tab selection for the launcher UI.
@@ -22,7 +35,9 @@ enum Tab {
     Video,
     Audio,
     Input,
-    MusicSelect,
+    KeyBinding,
+    Folders,
+    Tables,
     Skin,
     Option,
     Other,
@@ -33,19 +48,21 @@ enum Tab {
 }
 
 impl Tab {
-    fn label(&self) -> &'static str {
+    fn label(&self) -> String {
         match self {
-            Tab::Video => "Video",
-            Tab::Audio => "Audio",
-            Tab::Input => "Input",
-            Tab::MusicSelect => "Music Select",
-            Tab::Skin => "Skin",
-            Tab::Option => "Option",
-            Tab::Other => "Other",
-            Tab::IR => "IR",
-            Tab::Stream => "Stream",
-            Tab::Discord => "Discord",
-            Tab::OBS => "OBS",
+            Tab::Video => tr!("launcher-tab-video"),
+            Tab::Audio => tr!("launcher-tab-audio"),
+            Tab::Input => tr!("launcher-tab-input"),
+            Tab::KeyBinding => tr!("launcher-tab-key-binding"),
+            Tab::Folders => tr!("launcher-tab-folders"),
+            Tab::Tables => tr!("launcher-tab-tables"),
+            Tab::Skin => tr!("launcher-tab-skin"),
+            Tab::Option => tr!("launcher-tab-option"),
+            Tab::Other => tr!("launcher-tab-other"),
+            Tab::IR => "IR".to_string(),
+            Tab::Stream => tr!("launcher-tab-stream"),
+            Tab::Discord => "Discord".to_string(),
+            Tab::OBS => "OBS".to_string(),
         }
     }
 
@@ -54,7 +71,9 @@ impl Tab {
             Tab::Video,
             Tab::Audio,
             Tab::Input,
-            Tab::MusicSelect,
+            Tab::KeyBinding,
+            Tab::Folders,
+            Tab::Tables,
             Tab::Skin,
             Tab::Option,
             Tab::Other,
@@ -79,21 +98,66 @@ pub struct LauncherUi {
     player_name: String,
     selected_play_mode: usize,
     bms_paths: Vec<String>,
+    /// Chart count per BMS folder (read when the folders tab is first shown).
+    folder_chart_counts: Option<std::collections::HashMap<String, usize>>,
+    /// Background song database scan started from the folders tab.
+    bms_scan: Option<BmsLoadingHandle>,
+    /// Result of the last folder scan (error message on failure).
+    bms_scan_result: Option<Result<(), String>>,
     selected_ir_index: usize,
     /// Decrypted IR userid buffer for egui text editing.
     ir_userid_buf: String,
     /// Decrypted IR password buffer for egui text editing.
     ir_password_buf: String,
+    /// Registered IR connection names (read when the IR tab is first shown).
+    ir_names: Option<Vec<String>>,
+    /// Background thread running the IR login test.
+    ir_test_handle: Option<std::thread::JoinHandle<IRResponse<IRPlayerData>>>,
+    /// Server response of the last IR login test for the selected slot.
+    ir_test_result: Option<IRResponse<IRPlayerData>>,
     /// Previous IR index to detect slot switches.
     ir_prev_index: Option<usize>,
     /// Skin configuration sub-view (skin type/header selection + custom options).
     skin_view: SkinConfigurationView,
+    /// Preview image path of the selected skin and its texture (None if it failed to load).
+    skin_preview: Option<(std::path::PathBuf, Option<egui::TextureHandle>)>,
     /// Discord webhook URL list for editing.
     webhook_urls: Vec<String>,
     /// New webhook URL input buffer.
     webhook_url_input: String,
+    /// Name given to the next imported rival.
+    rival_name_input: String,
+    /// Rivals imported from score files (read when the other tab is first shown).
+    imported_rivals: Option<Vec<(std::path::PathBuf, PlayerInformation)>>,
+    /// Outcome of the last rival import or refresh.
+    rival_import_status: Option<Result<String, String>>,
     /// OBS configuration sub-view (connection, scene/action selectors).
     obs_view: ObsConfigurationView,
+    /// Output devices for the audio tab (enumerated when the tab is first shown).
+    audio_devices: Option<Vec<crate::platform::DeviceInfo>>,
+    /// Background thread playing the audio test sound.
+    audio_test_handle: Option<std::thread::JoinHandle<anyhow::Result<AudioTestResult>>>,
+    /// Result of the last audio test (error message on failure).
+    audio_test_result: Option<Result<AudioTestResult, String>>,
+    /// Buffer settings edited in the latency tuning panel (read from the
+    /// audio config when the panel is first shown).
+    latency_draft: Option<latency_panel::LatencyDraft>,
+    /// Background thread measuring the audio round trip.
+    round_trip_handle: Option<
+        std::thread::JoinHandle<anyhow::Result<(latency_panel::LatencyDraft, RoundTripResult)>>,
+    >,
+    /// Last round-trip measurement with the settings it used (error message on failure).
+    round_trip_result: Option<Result<(latency_panel::LatencyDraft, RoundTripResult), String>>,
+    /// Press-to-assign key binding sub-view.
+    key_binding_view: KeyBindingView,
+    /// Difficulty table manager sub-view (table URLs, downloads, chart coverage).
+    table_view: ResourceConfigurationView,
+    /// Whether table names and chart coverage have been read for the tables tab.
+    table_info_loaded: bool,
+    /// beatoraja installation offered for settings import on first launch.
+    beatoraja_import_offer: Option<std::path::PathBuf>,
+    /// Error from the last beatoraja import attempt.
+    beatoraja_import_error: Option<String>,
     /// Whether the "What's New" popup is open.
     show_whats_new: bool,
     /// What's New message text.
@@ -104,6 +168,14 @@ pub struct LauncherUi {
     chart_details_data: Vec<(String, String)>,
     /// Set to true when the user clicks "Start" -- signals the caller to launch play.
     /// Java: PlayConfigurationView.start() calls MainLoader.play()
+    /// Background query of the latest release.
+    update_check_handle: Option<std::thread::JoinHandle<anyhow::Result<ReleaseInfo>>>,
+    /// Latest release, or why it could not be fetched.
+    update_release: Option<Result<ReleaseInfo, String>>,
+    /// Background download of the new build.
+    update_download_handle: Option<std::thread::JoinHandle<anyhow::Result<std::path::PathBuf>>>,
+    /// Where the new build was saved, or why the download failed.
+    update_download_result: Option<Result<std::path::PathBuf, String>>,
     play_requested: bool,
     /// Set to true when the user clicks "Exit".
     /// Java: PlayConfigurationView.exit() calls commit() + System.exit(0)
@@ -123,7 +195,7 @@ pub struct LauncherUi {
 }
 
 impl LauncherUi {
-    pub fn new(config: Config, player: PlayerConfig) -> Self {
+    pub fn new(mut config: Config, player: PlayerConfig) -> Self {
         let player_name = config
             .playername
             .clone()
@@ -143,6 +215,10 @@ impl LauncherUi {
         obs_view.init(&dummy_main);
         obs_view.update(config.clone());
 
+        // Initialize table manager (also merges built-in tables into available_url)
+        let mut table_view = ResourceConfigurationView::new();
+        table_view.update(&mut config);
+
         let has_ir = !player.irconfig.is_empty();
         let selected_play_mode = player
             .mode
@@ -155,18 +231,43 @@ impl LauncherUi {
             player_name,
             selected_play_mode,
             bms_paths,
+            folder_chart_counts: None,
+            bms_scan: None,
+            bms_scan_result: None,
             selected_ir_index: 0,
             ir_userid_buf: String::new(),
             ir_password_buf: String::new(),
+            ir_names: None,
+            ir_test_handle: None,
+            ir_test_result: None,
             ir_prev_index: None,
             skin_view,
+            skin_preview: None,
             webhook_urls,
             webhook_url_input: String::new(),
+            rival_name_input: String::new(),
+            imported_rivals: None,
+            rival_import_status: None,
             obs_view,
+            audio_devices: None,
+            audio_test_handle: None,
+            audio_test_result: None,
+            latency_draft: None,
+            round_trip_handle: None,
+            round_trip_result: None,
+            key_binding_view: KeyBindingView::new(),
+            table_view,
+            table_info_loaded: false,
+            beatoraja_import_offer: None,
+            beatoraja_import_error: None,
             show_whats_new: false,
             whats_new_text: String::new(),
             chart_details_open: false,
             chart_details_data: Vec::new(),
+            update_check_handle: None,
+            update_release: None,
+            update_download_handle: None,
+            update_download_result: None,
             play_requested: false,
             exit_requested: false,
             load_all_bms_requested: false,
@@ -241,15 +342,55 @@ impl LauncherUi {
             .unwrap_or(Mode::BEAT_7K)
     }
 
+    /// Language picker for the header. Applies immediately; "System" stores
+    /// an empty locale so the system language is followed.
+    fn render_language_selector(&mut self, ui: &mut egui::Ui) {
+        let system = tr!("language-system");
+        let selected = if self.config.locale.is_empty() {
+            system.clone()
+        } else {
+            Language::resolve(&self.config.locale)
+                .native_name()
+                .to_string()
+        };
+        let mut locale = self.config.locale.clone();
+        egui::ComboBox::from_label(tr!("language-label"))
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut locale, String::new(), system);
+                for language in Language::ALL {
+                    ui.selectable_value(
+                        &mut locale,
+                        language.code().to_string(),
+                        language.native_name(),
+                    );
+                }
+            });
+        if locale != self.config.locale {
+            i18n::set_locale(&locale);
+            self.config.locale = locale;
+        }
+    }
+
     /// Render the launcher configuration UI.
     ///
     /// Java equivalent: PlayConfigurationView.start(Stage primaryStage) builds
     /// the JavaFX scene graph with tabs, combo boxes, and action buttons.
     pub fn render_ui(&mut self, ctx: &egui::Context) {
+        self.poll_bms_scan();
+        self.poll_update_check();
+        // Desktop size of the monitor the launcher is on, for the video tab's resolution list
+        if let Some(size) = ctx.input(|i| i.viewport().monitor_size) {
+            let scale = ctx.pixels_per_point();
+            crate::platform::set_desktop_display_mode_if_unknown(
+                (size.x * scale).round() as u32,
+                (size.y * scale).round() as u32,
+            );
+        }
         egui::CentralPanel::default().show(ctx, |ui| {
             // Header: player name + play mode selector
             ui.horizontal(|ui| {
-                ui.label("Player:");
+                ui.label(tr!("launcher-player"));
                 ui.text_edit_singleline(&mut self.player_name);
 
                 ui.separator();
@@ -259,7 +400,7 @@ impl LauncherUi {
                     .get(self.selected_play_mode)
                     .map(|m| m.display_name())
                     .unwrap_or("7KEYS");
-                egui::ComboBox::from_label("Mode")
+                egui::ComboBox::from_label(tr!("launcher-mode"))
                     .selected_text(selected_text)
                     .show_ui(ui, |ui| {
                         for (i, mode) in play_modes.iter().enumerate() {
@@ -270,6 +411,19 @@ impl LauncherUi {
                             );
                         }
                     });
+
+                ui.separator();
+                self.render_language_selector(ui);
+
+                if let Some(release) = self.available_update() {
+                    ui.separator();
+                    if ui
+                        .link(tr!("update-available", name = release.name.as_str()))
+                        .clicked()
+                    {
+                        self.selected_tab = Tab::Other;
+                    }
+                }
             });
 
             ui.separator();
@@ -293,7 +447,9 @@ impl LauncherUi {
                 Tab::Video => self.render_video_tab(ui),
                 Tab::Audio => self.render_audio_tab(ui),
                 Tab::Input => self.render_input_tab(ui),
-                Tab::MusicSelect => self.render_music_select_tab(ui),
+                Tab::KeyBinding => self.render_key_binding_tab(ui),
+                Tab::Folders => self.render_folders_tab(ui),
+                Tab::Tables => self.render_tables_tab(ui),
                 Tab::Skin => self.render_skin_tab(ui),
                 Tab::Option => self.render_option_tab(ui),
                 Tab::Other => self.render_other_tab(ui),
@@ -308,36 +464,80 @@ impl LauncherUi {
             // Popups
             self.render_popups(ui.ctx());
 
-            // Action buttons at the bottom
+            // Action buttons at the bottom. Actions that open song.db are held
+            // back while a folder scan is still writing to it.
+            let scanning = self.bms_scan.is_some();
             ui.horizontal(|ui| {
-                if ui.button("Start").clicked() {
+                if ui
+                    .add_enabled(!scanning, egui::Button::new(tr!("launcher-start")))
+                    .clicked()
+                {
                     self.play_requested = true;
                     log::info!("Start requested");
                 }
-                if ui.button("Load All BMS").clicked() {
+                if ui
+                    .add_enabled(!scanning, egui::Button::new(tr!("launcher-load-all-bms")))
+                    .clicked()
+                {
                     self.load_all_bms_requested = true;
                     log::info!("Load All BMS requested");
                 }
-                if ui.button("Load Diff BMS").clicked() {
+                if ui
+                    .add_enabled(!scanning, egui::Button::new(tr!("launcher-load-diff-bms")))
+                    .clicked()
+                {
                     self.load_diff_bms_requested = true;
                     log::info!("Load Diff BMS requested");
                 }
-                if ui.button("Import Score").clicked() {
+                if ui.button(tr!("launcher-import-score")).clicked() {
                     self.import_score_requested = true;
                     log::info!("Import Score requested");
                 }
-                if ui.button("Exit").clicked() {
+                if ui.button(tr!("launcher-exit")).clicked() {
                     self.exit_requested = true;
                 }
             });
         });
     }
 
-    /// Render popup windows (What's New, Chart Details).
+    /// Render popup windows (beatoraja import, What's New, Chart Details).
     fn render_popups(&mut self, ctx: &egui::Context) {
+        if let Some(root) = self.beatoraja_import_offer.clone() {
+            let mut import = false;
+            let mut dismiss = false;
+            egui::Window::new(tr!("launcher-import-beatoraja-settings"))
+                .collapsible(false)
+                .resizable(false)
+                .show(ctx, |ui| {
+                    ui.label(tr!(
+                        "launcher-beatoraja-found",
+                        path = root.display().to_string()
+                    ));
+                    ui.label(tr!("launcher-import-beatoraja-prompt"));
+                    if let Some(e) = &self.beatoraja_import_error {
+                        ui.colored_label(
+                            egui::Color32::RED,
+                            tr!("launcher-import-failed", error = e.as_str()),
+                        );
+                    }
+                    ui.horizontal(|ui| {
+                        import = ui.button(tr!("launcher-import")).clicked();
+                        dismiss = ui.button(tr!("launcher-not-now")).clicked();
+                    });
+                });
+            if import {
+                match self.import_beatoraja_settings(&root) {
+                    Ok(()) => self.beatoraja_import_offer = None,
+                    Err(e) => self.beatoraja_import_error = Some(format!("{:#}", e)),
+                }
+            } else if dismiss {
+                self.beatoraja_import_offer = None;
+            }
+        }
+
         if self.show_whats_new {
             let mut open = self.show_whats_new;
-            egui::Window::new("What's New")
+            egui::Window::new(tr!("launcher-whats-new"))
                 .open(&mut open)
                 .resizable(true)
                 .default_width(400.0)
@@ -345,7 +545,7 @@ impl LauncherUi {
                     egui::ScrollArea::vertical().show(ui, |ui| {
                         ui.label(&self.whats_new_text);
                     });
-                    if ui.button("OK").clicked() {
+                    if ui.button(tr!("launcher-ok")).clicked() {
                         self.show_whats_new = false;
                     }
                 });
@@ -355,7 +555,7 @@ impl LauncherUi {
 
         if self.chart_details_open {
             let mut open = self.chart_details_open;
-            egui::Window::new("Chart Details")
+            egui::Window::new(tr!("launcher-chart-details"))
                 .open(&mut open)
                 .resizable(true)
                 .default_width(500.0)
@@ -368,7 +568,7 @@ impl LauncherUi {
                             ui.end_row();
                         }
                     });
-                    if ui.button("OK").clicked() {
+                    if ui.button(tr!("launcher-ok")).clicked() {
                         self.chart_details_open = false;
                     }
                 });
@@ -383,6 +583,44 @@ impl LauncherUi {
         self.show_whats_new = true;
     }
 
+    /// Offer to import settings from the beatoraja installation at `root`.
+    pub fn offer_beatoraja_import(&mut self, root: std::path::PathBuf) {
+        self.beatoraja_import_offer = Some(root);
+        self.beatoraja_import_error = None;
+    }
+
+    /// Import config and player profiles from a beatoraja installation.
+    ///
+    /// Player profiles are written immediately; the system config is saved
+    /// with the rest of the launcher settings on exit.
+    fn import_beatoraja_settings(&mut self, root: &std::path::Path) -> anyhow::Result<()> {
+        let import = beatoraja_import::read_beatoraja_install(root)?;
+        let config = import.merge_config(&self.config);
+        for player in &import.players {
+            PlayerConfig::write(&config.paths.playerpath, player)?;
+        }
+        let player = import
+            .players
+            .iter()
+            .find(|p| p.id == config.playername)
+            .cloned()
+            .unwrap_or_else(|| self.player.clone());
+        log::info!(
+            "Imported beatoraja settings from {} ({} players)",
+            root.display(),
+            import.players.len()
+        );
+        *self = Self::new_with_shared_flags(
+            config,
+            player,
+            self.shared_play_requested.clone(),
+            self.shared_load_all_bms.clone(),
+            self.shared_load_diff_bms.clone(),
+            self.shared_import_score.clone(),
+        );
+        Ok(())
+    }
+
     /// Show the chart details dialog with the given data.
     pub fn show_chart_details(&mut self, data: Vec<(String, String)>) {
         self.chart_details_data = data;
@@ -411,6 +649,10 @@ impl LauncherUi {
         self.player.mode = Some(self.current_mode());
         // Commit BMS root paths
         self.config.paths.bmsroot = self.bms_paths.clone();
+        // Keep the window usable on this display
+        if VideoConfigurationView::clamp_display_config(&mut self.config.display) {
+            log::warn!("Display settings adjusted to fit the current display");
+        }
         // Commit webhook URLs
         self.config.integration.webhook_url = self.webhook_urls.clone();
         // Commit OBS configuration (scene/action selections, connection settings)
@@ -418,6 +660,11 @@ impl LauncherUi {
         if let Some(obs_config) = self.obs_view.config() {
             self.config.obs = obs_config.obs.clone();
         }
+        // Commit difficulty table list (order, additions, removals)
+        self.table_view.commit();
+        if let Some(table_config) = self.table_view.config() {
+            self.config.paths.table_url = table_config.paths.table_url.clone();
+        }
         // Flush IR userid/password buffers (triggers AES encryption)
         self.flush_ir_buffers();
         // Commit skin configuration (saves to player.skin + skin_history)
@@ -520,7 +767,16 @@ pub fn run_launcher(
     let prev_config = config.clone();
     let prev_player = player.clone();
 
-    let launcher = LauncherUi::new_with_shared_flags(
+    // eframe does not expose winit's monitor list; use the modes found by the last game run
+    if let Err(e) = crate::platform::load_display_mode_cache(std::path::Path::new(
+        crate::platform::DISPLAY_MODE_CACHE,
+    )) {
+        log::debug!("No display mode cache: {}", e);
+    }
+
+    crate::i18n::set_locale(&config.locale);
+
+    let mut launcher = LauncherUi::new_with_shared_flags(
         config,
         player,
         shared_play_requested.clone(),
@@ -529,6 +785,20 @@ pub fn run_launcher(
         shared_import_score.clone(),
     );
 
+    if launcher.config.network.check_updates {
+        launcher.start_update_check();
+    }
+
+    // First launch (no config file yet): offer to import an existing beatoraja setup
+    if let Ok(cwd) = std::env::current_dir()
+        && crate::core::config::resolve_config_dir(&cwd).is_none()
+        && let Some(root) = beatoraja_import::detect_beatoraja_install(
+            &beatoraja_import::beatoraja_install_candidates(&cwd),
+        )
+    {
+        launcher.offer_beatoraja_import(root);
+    }
+
     // Java: primaryStage.setScene(scene); primaryStage.show();
     // eframe::run_native() blocks until the window is closed.
     let native_options = eframe::NativeOptions {
@@ -1,15 +1,149 @@
 // Tab rendering methods for LauncherUi.
 // Each method renders one configuration tab in the egui launcher.
 
+use std::collections::HashMap;
+use std::path::PathBuf;
+
 use crate::core::audio_config::{DriverType, FrequencyType};
-use crate::core::config::DisplayMode;
+use crate::core::config::{
+    DisplayMode, MAX_CONCURRENT_DOWNLOADS, TEXTURE_BUDGET_MAX, TEXTURE_BUDGET_MIN,
+    WEBHOOK_DJ_LEVELS,
+};
 use crate::core::ir_config::IRConfig;
+use crate::core::play_mode_config::ANALOG_SCRATCH_VER_3;
+use crate::core::player_config::{
+    COLOR_PALETTE_BLUE_YELLOW, COLOR_PALETTE_RED_GREEN, COLOR_PALETTE_SKIN,
+};
 use crate::core::resolution::Resolution;
+use crate::external::result_screenshot::DEFAULT_RESULT_SCREENSHOT_DIR;
+use crate::external::score_data_importer::{RIVAL_DIR, ScoreDataImporter};
+use crate::external::social_share::DEFAULT_SHARE_TEXT;
+use crate::ir::ir_connection_manager::IRConnectionManager;
+use crate::ir::ir_player_data::IRPlayerData;
+use crate::ir::ir_response::IRResponse;
+use crate::main_loader::MainLoader;
+use crate::platform::AudioTestResult;
+use crate::skin::hotkey_config::ModMenuCombo;
 use crate::skin::skin_type::SkinType;
+use crate::song::sqlite_song_database_accessor::SQLiteSongDatabaseAccessor;
 
+use crate::views::config::video_configuration_view::VideoConfigurationView;
+use crate::views::play_configuration_view::{BmsLoadingHandle, BmsLoadingState};
 use crate::views::skin_configuration_view::{SkinConfigItem, SkinConfigurationView};
 
 use super::{IR_SEND_LABELS, LauncherUi};
+use crate::tr;
+
+/// Chart count per BMS folder as stored in the song database at `songpath`.
+///
+/// Folders are missing from the map when the database does not exist yet.
+pub(crate) fn folder_chart_counts(songpath: &str, folders: &[String]) -> HashMap<String, usize> {
+    if !std::path::Path::new(songpath).exists() {
+        return HashMap::new();
+    }
+    match SQLiteSongDatabaseAccessor::new(songpath, folders) {
+        Ok(songdb) => folders
+            .iter()
+            .map(|folder| (folder.clone(), songdb.count_songs_in_folder(folder)))
+            .collect(),
+        Err(e) => {
+            log::warn!("Failed to open song database {}: {}", songpath, e);
+            HashMap::new()
+        }
+    }
+}
+
+/// One-line progress description of a running folder scan.
+pub(crate) fn bms_scan_summary(state: &BmsLoadingState) -> String {
+    match state {
+        BmsLoadingState::Loading {
+            bms_files,
+            processed_files,
+            new_files,
+        } => tr!(
+            "launcher-scan-progress",
+            processed = *processed_files,
+            total = *bms_files,
+            new = *new_files
+        ),
+        BmsLoadingState::Completed => tr!("launcher-scan-completed"),
+        BmsLoadingState::Failed(e) => tr!("launcher-scan-failed", error = e.to_string()),
+        BmsLoadingState::Idle => String::new(),
+    }
+}
+
+/// Description of the detected display for the video tab.
+pub(crate) fn display_modes_summary(width: i32, height: i32, detected_modes: usize) -> String {
+    if detected_modes > 0 {
+        tr!(
+            "launcher-display-modes-detected",
+            width = width,
+            height = height,
+            count = detected_modes
+        )
+    } else {
+        tr!(
+            "launcher-display-modes-pending",
+            width = width,
+            height = height
+        )
+    }
+}
+
+/// One-line description of an audio test result for the audio tab.
+pub(crate) fn audio_test_summary(result: &AudioTestResult) -> String {
+    let buffer = result
+        .buffer_frames
+        .map(|frames| {
+            tr!(
+                "launcher-audio-test-buffer",
+                frames = frames,
+                ms = format!(
+                    "{:.1}",
+                    f64::from(frames) * 1000.0 / f64::from(result.sample_rate.max(1))
+                )
+            )
+        })
+        .unwrap_or_else(|| tr!("launcher-audio-test-unknown"));
+    let latency = result
+        .output_latency_ms
+        .map(|ms| tr!("launcher-audio-test-latency", ms = format!("{:.1}", ms)))
+        .unwrap_or_else(|| tr!("launcher-audio-test-not-reported"));
+    tr!(
+        "launcher-audio-test-result",
+        device = result.device.as_str(),
+        rate = result.sample_rate,
+        buffer = buffer,
+        latency = latency
+    )
+}
+
+/// One-line description of an IR login test response for the IR tab.
+pub(crate) fn ir_login_summary(response: &IRResponse<IRPlayerData>) -> String {
+    match (response.is_succeeded(), response.data()) {
+        (true, Some(player)) if player.rank.is_empty() => {
+            tr!(
+                "launcher-ir-logged-in",
+                name = player.name.as_str(),
+                id = player.id.as_str()
+            )
+        }
+        (true, Some(player)) => tr!(
+            "launcher-ir-logged-in-rank",
+            name = player.name.as_str(),
+            id = player.id.as_str(),
+            rank = player.rank.as_str()
+        ),
+        (true, None) => tr!(
+            "launcher-ir-login-succeeded",
+            message = response.message.as_str()
+        ),
+        (false, _) => tr!(
+            "launcher-ir-login-failed",
+            message = response.message.as_str()
+        ),
+    }
+}
 
 /// Clamp a signed option index to a valid array index.
 /// Returns 0 (first element) when the value is negative or out of bounds.
@@ -22,85 +156,126 @@ pub(crate) fn clamped_option_index(value: i32, len: usize) -> usize {
 
 impl LauncherUi {
     pub(super) fn render_video_tab(&mut self, ui: &mut egui::Ui) {
+        let desktop = MainLoader::desktop_display_mode();
+        let detected_modes = crate::platform::cached_display_modes().len();
+        ui.label(display_modes_summary(
+            desktop.width,
+            desktop.height,
+            detected_modes,
+        ));
+
+        let display = &mut self.config.display;
+        let render = &mut self.config.render;
         egui::Grid::new("video_grid").show(ui, |ui| {
-            ui.label("Resolution:");
-            let all_resolutions = [
-                Resolution::SD,
-                Resolution::SVGA,
-                Resolution::XGA,
-                Resolution::HD,
-                Resolution::QUADVGA,
-                Resolution::FWXGA,
-                Resolution::SXGAPLUS,
-                Resolution::HDPLUS,
-                Resolution::UXGA,
-                Resolution::WSXGAPLUS,
-                Resolution::FULLHD,
-                Resolution::WUXGA,
-                Resolution::QXGA,
-                Resolution::WQHD,
-                Resolution::ULTRAHD,
-            ];
-            // Known limitation: resolution list is hardcoded from the Resolution enum rather
-            // than filtered by display capabilities. Users may select unsupported fullscreen modes.
-            let res_label = format!("{}", self.config.display.resolution);
-            egui::ComboBox::from_id_salt("video_tab_resolution")
-                .selected_text(&res_label)
-                .show_ui(ui, |ui| {
-                    for r in &all_resolutions {
-                        ui.selectable_value(
-                            &mut self.config.display.resolution,
-                            *r,
-                            format!("{}", r),
-                        );
-                    }
-                });
-            ui.end_row();
-
-            ui.label("Display Mode:");
-            let dm_label = format!("{:?}", self.config.display.displaymode);
+            ui.label(tr!("launcher-display-mode"));
+            let dm_label = format!("{:?}", display.displaymode);
             egui::ComboBox::from_id_salt("video_tab_display_mode")
                 .selected_text(&dm_label)
                 .show_ui(ui, |ui| {
                     ui.selectable_value(
-                        &mut self.config.display.displaymode,
+                        &mut display.displaymode,
                         DisplayMode::FULLSCREEN,
                         "FULLSCREEN",
                     );
                     ui.selectable_value(
-                        &mut self.config.display.displaymode,
+                        &mut display.displaymode,
                         DisplayMode::BORDERLESS,
                         "BORDERLESS",
                     );
-                    ui.selectable_value(
-                        &mut self.config.display.displaymode,
-                        DisplayMode::WINDOW,
-                        "WINDOW",
-                    );
+                    ui.selectable_value(&mut display.displaymode, DisplayMode::WINDOW, "WINDOW");
                 });
             ui.end_row();
 
-            ui.label("VSync:");
-            ui.checkbox(&mut self.config.display.vsync, "");
+            // Only resolutions the display supports in this mode are offered
+            ui.label(tr!("launcher-resolution"));
+            let resolutions = VideoConfigurationView::available_resolutions(display.displaymode);
+            let mut res_label = format!("{}", display.resolution);
+            if !resolutions.contains(&display.resolution) {
+                res_label = tr!("launcher-resolution-unsupported", resolution = res_label);
+            }
+            egui::ComboBox::from_id_salt("video_tab_resolution")
+                .selected_text(&res_label)
+                .show_ui(ui, |ui| {
+                    for r in &resolutions {
+                        ui.selectable_value(&mut display.resolution, *r, format!("{}", r));
+                    }
+                });
+            ui.end_row();
+
+            if matches!(display.displaymode, DisplayMode::WINDOW) {
+                ui.label(tr!("launcher-window-size"));
+                let mut custom = !display.use_resolution;
+                ui.checkbox(&mut custom, tr!("launcher-custom"));
+                display.use_resolution = !custom;
+                ui.end_row();
+
+                if custom {
+                    ui.label("");
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut display.window_width).range(
+                            Resolution::SD.width()..=desktop.width.max(Resolution::SD.width()),
+                        ));
+                        ui.label("x");
+                        ui.add(egui::DragValue::new(&mut display.window_height).range(
+                            Resolution::SD.height()..=desktop.height.max(Resolution::SD.height()),
+                        ));
+                    });
+                    ui.end_row();
+                }
+            }
+
+            ui.label(tr!("launcher-vsync"));
+            ui.checkbox(&mut display.vsync, "");
+            ui.end_row();
+
+            ui.label(tr!("launcher-max-fps"));
+            ui.add(egui::DragValue::new(&mut display.max_frame_per_second).range(0..=999));
             ui.end_row();
 
-            ui.label("Max FPS:");
+            ui.label(tr!("launcher-1000hz-input-polling"));
+            ui.checkbox(&mut display.input_poll_thread, "");
+            ui.end_row();
+
+            ui.label(tr!("launcher-1000hz-judge-thread"));
+            ui.checkbox(&mut display.judge_thread, "");
+            ui.end_row();
+
+            ui.label(tr!("launcher-texture-budget"));
             ui.add(
-                egui::DragValue::new(&mut self.config.display.max_frame_per_second).range(0..=999),
+                egui::DragValue::new(&mut render.texture_budget)
+                    .range(TEXTURE_BUDGET_MIN..=TEXTURE_BUDGET_MAX),
             );
             ui.end_row();
         });
+
+        let mut saved = display.clone();
+        if VideoConfigurationView::clamp_display_config(&mut saved) {
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                tr!(
+                    "launcher-display-clamped",
+                    resolution = saved.resolution.to_string(),
+                    width = saved.window_width,
+                    height = saved.window_height
+                ),
+            );
+        }
     }
 
     pub(super) fn render_audio_tab(&mut self, ui: &mut egui::Ui) {
+        self.poll_audio_test();
+        let devices = self
+            .audio_devices
+            .get_or_insert_with(|| crate::platform::port_audio_devices().unwrap_or_default());
         let audio = self.config.audio.get_or_insert_with(Default::default);
+        let mut refresh_devices = false;
         egui::Grid::new("audio_grid").show(ui, |ui| {
             // Driver type selector
             let driver_label = match audio.driver {
                 DriverType::OpenAL => "OpenAL",
                 DriverType::PortAudio => "PortAudio",
             };
-            ui.label("Driver:");
+            ui.label(tr!("launcher-driver"));
             egui::ComboBox::from_id_salt("audio_driver")
                 .selected_text(driver_label)
                 .show_ui(ui, |ui| {
@@ -116,12 +291,12 @@ impl LauncherUi {
                     .as_deref()
                     .unwrap_or("(default)")
                     .to_string();
-                ui.label("Device:");
-                egui::ComboBox::from_id_salt("audio_device_name")
-                    .selected_text(&driver_name_display)
-                    .show_ui(ui, |ui| {
-                        if let Ok(devices) = crate::platform::port_audio_devices() {
-                            for device in &devices {
+                ui.label(tr!("launcher-device"));
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_salt("audio_device_name")
+                        .selected_text(&driver_name_display)
+                        .show_ui(ui, |ui| {
+                            for device in devices.iter() {
                                 let mut name = audio.driver_name.clone().unwrap_or_default();
                                 if ui
                                     .selectable_value(&mut name, device.name.clone(), &device.name)
@@ -130,16 +305,43 @@ impl LauncherUi {
                                     audio.driver_name = Some(name);
                                 }
                             }
-                        }
-                    });
+                        });
+                    refresh_devices = ui.button(tr!("launcher-refresh")).clicked();
+                });
                 ui.end_row();
             }
 
-            ui.label("Audio Buffer:");
-            ui.add(egui::DragValue::new(&mut audio.device_buffer_size).range(0..=9999));
+            // Options reported by the selected device (all candidates when unknown)
+            let device = devices.iter().find(|d| {
+                audio.driver == DriverType::PortAudio
+                    && audio.driver_name.as_deref() == Some(d.name.as_str())
+            });
+            let buffer_sizes = device
+                .map(|d| d.buffer_sizes())
+                .unwrap_or_else(|| crate::platform::AUDIO_BUFFER_SIZES.to_vec());
+            let sample_rates = device
+                .map(|d| d.sample_rates.clone())
+                .filter(|rates| !rates.is_empty())
+                .unwrap_or_else(|| crate::platform::AUDIO_SAMPLE_RATES.to_vec());
+
+            ui.label(tr!("launcher-audio-buffer"));
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_salt("audio_buffer_size")
+                    .selected_text(audio.device_buffer_size.to_string())
+                    .show_ui(ui, |ui| {
+                        for size in &buffer_sizes {
+                            ui.selectable_value(
+                                &mut audio.device_buffer_size,
+                                *size,
+                                size.to_string(),
+                            );
+                        }
+                    });
+                ui.add(egui::DragValue::new(&mut audio.device_buffer_size).range(0..=9999));
+            });
             ui.end_row();
 
-            ui.label("Max Simultaneous:");
+            ui.label(tr!("launcher-max-simultaneous"));
             ui.add(egui::DragValue::new(&mut audio.device_simultaneous_sources).range(1..=256));
             ui.end_row();
 
@@ -147,128 +349,230 @@ impl LauncherUi {
             let sample_rate_label = if audio.sample_rate > 0 {
                 audio.sample_rate.to_string()
             } else {
-                "Auto".to_string()
+                tr!("launcher-auto")
             };
-            ui.label("Sample Rate:");
+            ui.label(tr!("launcher-sample-rate"));
             egui::ComboBox::from_id_salt("audio_sample_rate")
                 .selected_text(&sample_rate_label)
                 .show_ui(ui, |ui| {
-                    ui.selectable_value(&mut audio.sample_rate, 0, "Auto");
-                    ui.selectable_value(&mut audio.sample_rate, 44100, "44100");
-                    ui.selectable_value(&mut audio.sample_rate, 48000, "48000");
+                    ui.selectable_value(&mut audio.sample_rate, 0, tr!("launcher-auto"));
+                    for rate in &sample_rates {
+                        ui.selectable_value(&mut audio.sample_rate, *rate, rate.to_string());
+                    }
                 });
             ui.end_row();
 
             // Frequency option
             let freq_label = match audio.freq_option {
-                FrequencyType::UNPROCESSED => "Unprocessed",
-                FrequencyType::FREQUENCY => "Frequency",
+                FrequencyType::UNPROCESSED => tr!("launcher-unprocessed"),
+                FrequencyType::FREQUENCY => tr!("launcher-frequency"),
             };
-            ui.label("Freq Option:");
+            ui.label(tr!("launcher-freq-option"));
             egui::ComboBox::from_id_salt("audio_freq_option")
                 .selected_text(freq_label)
                 .show_ui(ui, |ui| {
                     ui.selectable_value(
                         &mut audio.freq_option,
                         FrequencyType::UNPROCESSED,
-                        "Unprocessed",
+                        tr!("launcher-unprocessed"),
                     );
                     ui.selectable_value(
                         &mut audio.freq_option,
                         FrequencyType::FREQUENCY,
-                        "Frequency",
+                        tr!("launcher-frequency"),
                     );
                 });
             ui.end_row();
 
             // Fast forward
             let ff_label = match audio.fast_forward {
-                FrequencyType::UNPROCESSED => "Unprocessed",
-                FrequencyType::FREQUENCY => "Frequency",
+                FrequencyType::UNPROCESSED => tr!("launcher-unprocessed"),
+                FrequencyType::FREQUENCY => tr!("launcher-frequency"),
             };
-            ui.label("Fast Forward:");
+            ui.label(tr!("launcher-fast-forward"));
             egui::ComboBox::from_id_salt("audio_fast_forward")
                 .selected_text(ff_label)
                 .show_ui(ui, |ui| {
                     ui.selectable_value(
                         &mut audio.fast_forward,
                         FrequencyType::UNPROCESSED,
-                        "Unprocessed",
+                        tr!("launcher-unprocessed"),
                     );
                     ui.selectable_value(
                         &mut audio.fast_forward,
                         FrequencyType::FREQUENCY,
-                        "Frequency",
+                        tr!("launcher-frequency"),
                     );
                 });
             ui.end_row();
 
-            ui.label("System Volume:");
+            ui.label(tr!("launcher-system-volume"));
             ui.add(egui::Slider::new(&mut audio.systemvolume, 0.0..=1.0));
             ui.end_row();
 
-            ui.label("Key Volume:");
+            ui.label(tr!("launcher-key-volume"));
             ui.add(egui::Slider::new(&mut audio.keyvolume, 0.0..=1.0));
             ui.end_row();
 
-            ui.label("BG Volume:");
+            ui.label(tr!("launcher-bg-volume"));
             ui.add(egui::Slider::new(&mut audio.bgvolume, 0.0..=1.0));
             ui.end_row();
 
-            ui.label("Normalize Volume:");
+            ui.label(tr!("launcher-normalize-volume"));
             ui.checkbox(&mut audio.normalize_volume, "");
             ui.end_row();
 
-            ui.label("Loop Result Sound:");
+            ui.label(tr!("launcher-loop-result-sound"));
             ui.checkbox(&mut audio.is_loop_result_sound, "");
             ui.end_row();
 
-            ui.label("Loop Course Result Sound:");
+            ui.label(tr!("launcher-loop-course-result-sound"));
             ui.checkbox(&mut audio.is_loop_course_result_sound, "");
             ui.end_row();
         });
+        if refresh_devices {
+            self.audio_devices = None;
+        }
+
+        ui.separator();
+
+        // Device test: play a short tone with the current settings
+        let running = self.audio_test_handle.is_some();
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!running, egui::Button::new(tr!("launcher-play-test-sound")))
+                .clicked()
+            {
+                self.start_audio_test();
+            }
+            if running {
+                ui.spinner();
+            }
+        });
+        match &self.audio_test_result {
+            Some(Ok(result)) => {
+                ui.label(audio_test_summary(result));
+            }
+            Some(Err(e)) => {
+                ui.colored_label(
+                    egui::Color32::RED,
+                    tr!("launcher-audio-test-failed", error = e.as_str()),
+                );
+            }
+            None => {}
+        }
+        if running {
+            ui.ctx().request_repaint();
+        }
+
+        ui.separator();
+        self.render_latency_panel(ui);
+    }
+
+    /// Start playing the test sound on a background thread with the current audio settings.
+    fn start_audio_test(&mut self) {
+        let audio = self.config.audio.clone().unwrap_or_default();
+        let device_name = match audio.driver {
+            DriverType::PortAudio => audio.driver_name,
+            DriverType::OpenAL => None,
+        };
+        self.audio_test_result = None;
+        self.audio_test_handle = Some(std::thread::spawn(move || {
+            crate::platform::play_test_sound(
+                device_name.as_deref(),
+                audio.device_buffer_size,
+                audio.sample_rate,
+            )
+        }));
+    }
+
+    /// Collect the audio test result once the background thread has finished.
+    fn poll_audio_test(&mut self) {
+        if !self
+            .audio_test_handle
+            .as_ref()
+            .is_some_and(|handle| handle.is_finished())
+        {
+            return;
+        }
+        if let Some(handle) = self.audio_test_handle.take() {
+            self.audio_test_result = Some(match handle.join() {
+                Ok(result) => result.map_err(|e| e.to_string()),
+                Err(_) => Err("audio test thread panicked".to_string()),
+            });
+        }
     }
 
     /// Java equivalent: InputConfigurationView
-    /// Keyboard/controller/mouse scratch settings per play mode.
+    /// Keyboard/controller/MIDI/mouse scratch settings per play mode.
     pub(super) fn render_input_tab(&mut self, ui: &mut egui::Ui) {
-        ui.heading("Input Configuration");
+        ui.heading(tr!("launcher-input-configuration"));
 
         let mode = self.current_mode();
         let pmc = self.player.play_config(mode);
 
         // Keyboard settings
-        ui.label("Keyboard");
+        ui.label(tr!("launcher-keyboard"));
         egui::Grid::new("keyboard_grid").show(ui, |ui| {
-            ui.label("Duration:");
+            ui.label(tr!("launcher-duration"));
             ui.add(egui::DragValue::new(&mut pmc.keyboard.duration).range(0..=100));
             ui.end_row();
+
+            ui.label(tr!("launcher-latency-offset"));
+            ui.add(egui::DragValue::new(&mut pmc.keyboard.latency_offset).range(-100..=100));
+            ui.end_row();
+        });
+
+        ui.separator();
+
+        ui.label(tr!("launcher-debounce"));
+        egui::Grid::new("debounce_grid").show(ui, |ui| {
+            ui.label(tr!("launcher-debounce-window"));
+            ui.add(egui::DragValue::new(&mut pmc.debounce_window).range(0..=100));
+            ui.end_row();
         });
 
         ui.separator();
 
         // Controller settings (per player side)
         for (i, controller) in pmc.controller.iter_mut().enumerate() {
-            ui.label(format!("Controller {} ({}P)", i + 1, i + 1));
+            ui.label(tr!("launcher-controller", number = i + 1));
             egui::Grid::new(format!("controller_grid_{}", i)).show(ui, |ui| {
-                ui.label("Name:");
+                ui.label(tr!("launcher-name"));
                 ui.text_edit_singleline(&mut controller.name);
                 ui.end_row();
 
-                ui.label("Duration:");
+                ui.label(tr!("launcher-duration"));
                 ui.add(egui::DragValue::new(&mut controller.duration).range(0..=100));
                 ui.end_row();
 
-                ui.label("JKOC Hack:");
+                ui.label(tr!("launcher-latency-offset"));
+                ui.add(egui::DragValue::new(&mut controller.latency_offset).range(-100..=100));
+                ui.end_row();
+
+                ui.label(tr!("launcher-jkoc-hack"));
                 ui.checkbox(&mut controller.jkoc_hack, "");
                 ui.end_row();
 
-                ui.label("Analog Scratch:");
+                ui.label(tr!("launcher-analog-scratch"));
                 ui.checkbox(&mut controller.analog_scratch, "");
                 ui.end_row();
 
+                if !controller.analog_scratch {
+                    ui.label(tr!("launcher-axis-threshold"));
+                    ui.add(egui::DragValue::new(&mut controller.axis_threshold).range(0..=100));
+                    ui.end_row();
+
+                    ui.label(tr!("launcher-axis-hysteresis"));
+                    ui.add(
+                        egui::DragValue::new(&mut controller.axis_hysteresis)
+                            .range(0..=controller.axis_threshold),
+                    );
+                    ui.end_row();
+                }
+
                 if controller.analog_scratch {
-                    ui.label("Analog Threshold:");
+                    ui.label(tr!("launcher-analog-threshold"));
                     ui.add(
                         egui::DragValue::new(&mut controller.analog_scratch_threshold)
                             .range(1..=1000),
@@ -277,14 +581,14 @@ impl LauncherUi {
 
                     // Config values used as indices: negative i32 wraps to huge usize via
                     // `as usize`, but .get() returns None and falls through to the default.
-                    let analog_modes = ["Ver 2", "Ver 1"];
+                    let analog_modes = ["Ver 2", "Ver 1", "Ver 3 (time based)"];
                     let selected_label = analog_modes
                         .get(clamped_option_index(
                             controller.analog_scratch_mode,
                             analog_modes.len(),
                         ))
                         .unwrap_or(&"Ver 2");
-                    ui.label("Analog Mode:");
+                    ui.label(tr!("launcher-analog-mode"));
                     egui::ComboBox::from_id_salt(format!("analog_mode_{}", i))
                         .selected_text(*selected_label)
                         .show_ui(ui, |ui| {
@@ -297,25 +601,58 @@ impl LauncherUi {
                             }
                         });
                     ui.end_row();
+
+                    if controller.analog_scratch_mode == ANALOG_SCRATCH_VER_3 {
+                        ui.label(tr!("launcher-sensitivity-ticks"));
+                        ui.add(
+                            egui::DragValue::new(&mut controller.analog_scratch_sensitivity)
+                                .range(1..=20),
+                        );
+                        ui.end_row();
+
+                        ui.label(tr!("launcher-deadzone-ticks"));
+                        ui.add(
+                            egui::DragValue::new(&mut controller.analog_scratch_deadzone)
+                                .range(0..=10),
+                        );
+                        ui.end_row();
+
+                        ui.label(tr!("launcher-active-duration"));
+                        ui.add(
+                            egui::DragValue::new(&mut controller.analog_scratch_duration)
+                                .range(1..=500),
+                        );
+                        ui.end_row();
+                    }
                 }
             });
             ui.separator();
         }
 
+        // MIDI settings
+        ui.label("MIDI");
+        egui::Grid::new("midi_grid").show(ui, |ui| {
+            ui.label(tr!("launcher-latency-offset"));
+            ui.add(egui::DragValue::new(&mut pmc.midi.latency_offset).range(-100..=100));
+            ui.end_row();
+        });
+
+        ui.separator();
+
         // Mouse scratch settings
         let ms = &mut pmc.keyboard.mouse_scratch_config;
-        ui.label("Mouse Scratch");
+        ui.label(tr!("launcher-mouse-scratch"));
         egui::Grid::new("mouse_scratch_grid").show(ui, |ui| {
-            ui.label("Enable:");
+            ui.label(tr!("launcher-enable"));
             ui.checkbox(&mut ms.mouse_scratch_enabled, "");
             ui.end_row();
 
             if ms.mouse_scratch_enabled {
-                ui.label("Time Threshold:");
+                ui.label(tr!("launcher-time-threshold"));
                 ui.add(egui::DragValue::new(&mut ms.mouse_scratch_time_threshold).range(1..=10000));
                 ui.end_row();
 
-                ui.label("Distance:");
+                ui.label(tr!("launcher-distance"));
                 ui.add(egui::DragValue::new(&mut ms.mouse_scratch_distance).range(1..=10000));
                 ui.end_row();
 
@@ -326,7 +663,7 @@ impl LauncherUi {
                         scratch_modes.len(),
                     ))
                     .unwrap_or(&"Ver 2");
-                ui.label("Mode:");
+                ui.label(tr!("launcher-mode-label"));
                 egui::ComboBox::from_id_salt("mouse_scratch_mode")
                     .selected_text(*selected_label)
                     .show_ui(ui, |ui| {
@@ -337,38 +674,157 @@ impl LauncherUi {
                 ui.end_row();
             }
         });
+
+        ui.separator();
+
+        let combo = &mut self.player.modmenu_combo;
+        ui.label(tr!("launcher-modmenu-combo"))
+            .on_hover_text(tr!("launcher-modmenu-combo-hint"));
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut combo.start, "START");
+            ui.checkbox(&mut combo.select, "SELECT");
+            for key in 0..ModMenuCombo::KEY_COUNT {
+                let mut held = combo.keys.contains(&key);
+                if ui.checkbox(&mut held, (key + 1).to_string()).changed() {
+                    combo.keys.retain(|&k| k != key);
+                    if held {
+                        combo.keys.push(key);
+                    }
+                    combo.validate();
+                }
+            }
+        });
     }
 
-    pub(super) fn render_music_select_tab(&mut self, ui: &mut egui::Ui) {
-        ui.label("Music Select configuration");
-        ui.label("BMS paths:");
+    /// BMS root folders with their chart counts and a song database scan.
+    pub(super) fn render_folders_tab(&mut self, ui: &mut egui::Ui) {
+        ui.heading(tr!("launcher-bms-folders"));
+        let scanning = self.bms_scan.is_some();
+        let counts = self.folder_chart_counts.get_or_insert_with(|| {
+            folder_chart_counts(&self.config.paths.songpath, &self.bms_paths)
+        });
+
         let mut remove_idx = None;
-        for (i, path) in self.bms_paths.iter().enumerate() {
-            ui.horizontal(|ui| {
-                ui.label(path.as_str());
-                if ui.small_button("Remove").clicked() {
-                    remove_idx = Some(i);
+        let mut scan_path = None;
+        egui::Grid::new("bms_folders_grid")
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong(tr!("launcher-folder"));
+                ui.strong(tr!("launcher-charts"));
+                ui.end_row();
+                for (i, path) in self.bms_paths.iter().enumerate() {
+                    ui.label(path.as_str());
+                    match counts.get(path) {
+                        Some(count) => ui.label(count.to_string()),
+                        None => ui.label("-"),
+                    };
+                    ui.add_enabled_ui(!scanning, |ui| {
+                        if ui.small_button(tr!("launcher-scan")).clicked() {
+                            scan_path = Some(path.clone());
+                        }
+                        if ui.small_button(tr!("launcher-remove")).clicked() {
+                            remove_idx = Some(i);
+                        }
+                    });
+                    ui.end_row();
                 }
             });
-        }
         if let Some(idx) = remove_idx {
             self.bms_paths.remove(idx);
         }
-        if ui.button("Add BMS folder...").clicked()
-            && let Some(path) = crate::platform::show_directory_chooser("Select BMS folder")
+        if let Some(path) = scan_path {
+            self.start_bms_scan(Some(path), false);
+        }
+
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(!scanning, |ui| {
+                if ui.button(tr!("launcher-add-bms-folder")).clicked()
+                    && let Some(path) =
+                        crate::platform::show_directory_chooser(&tr!("launcher-select-bms-folder"))
+                    && !self.bms_paths.contains(&path)
+                {
+                    self.bms_paths.push(path);
+                }
+                if ui.button(tr!("launcher-scan-new-changed")).clicked() {
+                    self.start_bms_scan(None, false);
+                }
+                if ui.button(tr!("launcher-rescan-all")).clicked() {
+                    self.start_bms_scan(None, true);
+                }
+            });
+        });
+        ui.checkbox(
+            &mut self.config.select.watch_library,
+            tr!("launcher-watch-folders"),
+        );
+
+        if let Some(scan) = &self.bms_scan {
+            let state = scan.state();
+            if let BmsLoadingState::Loading {
+                bms_files,
+                processed_files,
+                ..
+            } = state
+            {
+                let fraction = if bms_files > 0 {
+                    processed_files as f32 / bms_files as f32
+                } else {
+                    0.0
+                };
+                ui.add(egui::ProgressBar::new(fraction).text(bms_scan_summary(&state)));
+            }
+            ui.ctx().request_repaint();
+        } else {
+            match &self.bms_scan_result {
+                Some(Ok(())) => {
+                    ui.label(tr!("launcher-scan-completed"));
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(
+                        egui::Color32::RED,
+                        tr!("launcher-scan-failed", error = e.as_str()),
+                    );
+                }
+                None => {}
+            }
+        }
+    }
+
+    /// Scan `updatepath` (all folders if None) into song.db on a background thread.
+    fn start_bms_scan(&mut self, updatepath: Option<String>, update_all: bool) {
+        if self.bms_scan.is_some() {
+            return;
+        }
+        let mut config = self.config.clone();
+        config.paths.bmsroot = self.bms_paths.clone();
+        self.bms_scan_result = None;
+        self.bms_scan = Some(BmsLoadingHandle::spawn(&config, updatepath, update_all));
+    }
+
+    /// Collect the scan result once the background thread has finished.
+    pub(super) fn poll_bms_scan(&mut self) {
+        if !self
+            .bms_scan
+            .as_ref()
+            .is_some_and(|scan| scan.is_finished())
         {
-            self.bms_paths.push(path);
+            return;
+        }
+        if let Some(scan) = self.bms_scan.take() {
+            self.bms_scan_result = Some(scan.join());
+            // Re-read chart counts on the next frame of the folders tab
+            self.folder_chart_counts = None;
         }
     }
 
     /// Java equivalent: SkinConfigurationView
     /// Skin type selection, skin header browsing, and custom options/files/offsets.
     pub(super) fn render_skin_tab(&mut self, ui: &mut egui::Ui) {
-        ui.heading("Skin Configuration");
+        ui.heading(tr!("launcher-skin-configuration"));
 
         ui.checkbox(
             &mut self.config.select.cache_skin_image,
-            "Cache Skin Image (CIM)",
+            tr!("launcher-cache-skin-image"),
         );
 
         ui.separator();
@@ -381,7 +837,7 @@ impl LauncherUi {
             .unwrap_or(SkinType::Play7Keys);
         let selected_text = SkinConfigurationView::skin_type_display_name(&current_type);
         ui.horizontal(|ui| {
-            ui.label("Category:");
+            ui.label(tr!("launcher-category"));
             let mut new_type = current_type;
             egui::ComboBox::from_id_salt("skin_type_selector")
                 .selected_text(selected_text)
@@ -405,14 +861,14 @@ impl LauncherUi {
         let header_count = headers.len();
         let selected_idx = self.skin_view.skinheader_selector();
         ui.horizontal(|ui| {
-            ui.label("Skin:");
+            ui.label(tr!("launcher-skin"));
             if header_count == 0 {
-                ui.label("(no skins found)");
+                ui.label(tr!("launcher-no-skins-found"));
             } else {
                 let display = selected_idx
                     .and_then(|i| headers.get(i))
                     .map(SkinConfigurationView::skin_header_display_name)
-                    .unwrap_or_else(|| "(none)".to_string());
+                    .unwrap_or_else(|| tr!("launcher-none"));
                 let mut new_idx = selected_idx.unwrap_or(0);
                 egui::ComboBox::from_id_salt("skin_header_selector")
                     .selected_text(display)
@@ -429,6 +885,24 @@ impl LauncherUi {
             }
         });
 
+        // Metadata and preview of the selected skin
+        if let Some(header) = self.skin_view.selected_header() {
+            let metadata = SkinConfigurationView::skin_metadata(header);
+            let preview_path = SkinConfigurationView::preview_image_path(header);
+            ui.horizontal_top(|ui| {
+                egui::Grid::new("skin_metadata_grid").show(ui, |ui| {
+                    for (label, value) in &metadata {
+                        ui.label(format!("{}:", label));
+                        ui.label(value);
+                        ui.end_row();
+                    }
+                });
+                if let Some(texture) = self.skin_preview_texture(ui.ctx(), preview_path) {
+                    ui.add(egui::Image::new(&texture).max_width(320.0));
+                }
+            });
+        }
+
         ui.separator();
 
         // Render dynamic skin config items (options, files, offsets)
@@ -507,42 +981,193 @@ impl LauncherUi {
         }
     }
 
+    /// Texture for a skin preview image, reloaded only when the path changes.
+    fn skin_preview_texture(
+        &mut self,
+        ctx: &egui::Context,
+        path: Option<PathBuf>,
+    ) -> Option<egui::TextureHandle> {
+        if self.skin_preview.as_ref().map(|(p, _)| p) != path.as_ref() {
+            self.skin_preview = path.map(|path| {
+                let texture = image::open(&path)
+                    .map_err(|e| log::warn!("Failed to load skin preview {:?}: {}", path, e))
+                    .ok()
+                    .map(|img| {
+                        let img = img.thumbnail(640, 640).to_rgba8();
+                        let size = [img.width() as usize, img.height() as usize];
+                        ctx.load_texture(
+                            "skin_preview",
+                            egui::ColorImage::from_rgba_unmultiplied(size, img.as_raw()),
+                            egui::TextureOptions::LINEAR,
+                        )
+                    });
+                (path, texture)
+            });
+        }
+        self.skin_preview.as_ref().and_then(|(_, t)| t.clone())
+    }
+
     pub(super) fn render_option_tab(&mut self, ui: &mut egui::Ui) {
-        ui.heading("Play Options");
+        ui.heading(tr!("launcher-play-options"));
 
         egui::Grid::new("option_grid").show(ui, |ui| {
-            ui.label("HiSpeed:");
-            ui.label("(configured per play mode)");
+            ui.label(tr!("launcher-hispeed"));
+            ui.label(tr!("launcher-configured-per-play-mode"));
             ui.end_row();
 
-            ui.label("Target:");
+            ui.label(tr!("launcher-target"));
             ui.label(self.player.select_settings.targetid.to_string());
             ui.end_row();
+
+            ui.label(tr!("launcher-ghost-key-beams"));
+            ui.checkbox(&mut self.player.display_settings.ghost_key_beam, "");
+            ui.end_row();
+        });
+
+        ui.separator();
+        ui.heading(tr!("launcher-accessibility"));
+
+        egui::Grid::new("accessibility_grid").show(ui, |ui| {
+            let display = &mut self.player.display_settings;
+            let palettes = [
+                (COLOR_PALETTE_SKIN, tr!("launcher-color-palette-skin")),
+                (
+                    COLOR_PALETTE_RED_GREEN,
+                    tr!("launcher-color-palette-red-green"),
+                ),
+                (
+                    COLOR_PALETTE_BLUE_YELLOW,
+                    tr!("launcher-color-palette-blue-yellow"),
+                ),
+            ];
+            let selected = palettes
+                .iter()
+                .find(|(value, _)| *value == display.color_palette)
+                .map_or_else(String::new, |(_, label)| label.clone());
+            ui.label(tr!("launcher-color-palette"));
+            egui::ComboBox::from_id_salt("option_tab_color_palette")
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    for (value, label) in &palettes {
+                        ui.selectable_value(&mut display.color_palette, *value, label);
+                    }
+                });
+            ui.end_row();
+
+            ui.label(tr!("launcher-high-contrast-notes"));
+            ui.checkbox(&mut display.high_contrast_notes, "");
+            ui.end_row();
         });
     }
 
     /// Java equivalent: PlayConfigurationView "Other" tab
     /// IPFS, HTTP download, and screenshot settings.
     pub(super) fn render_other_tab(&mut self, ui: &mut egui::Ui) {
-        ui.heading("Other Settings");
+        ui.heading(tr!("launcher-other-settings"));
 
         // Screenshot
         ui.checkbox(
             &mut self.config.integration.set_clipboard_screenshot,
-            "Clipboard Screenshot",
+            tr!("launcher-clipboard-screenshot"),
         );
 
+        ui.label(tr!("launcher-result-screenshot"));
+        egui::Grid::new("result_screenshot_grid").show(ui, |ui| {
+            ui.label(tr!("launcher-capture-automatically"));
+            ui.checkbox(&mut self.config.integration.result_screenshot, "");
+            ui.end_row();
+
+            if self.config.integration.result_screenshot {
+                ui.label(tr!("launcher-screenshot-folder"));
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.config.integration.result_screenshot_dir)
+                        .hint_text(DEFAULT_RESULT_SCREENSHOT_DIR),
+                );
+                ui.end_row();
+
+                ui.label(tr!("launcher-clipboard-webhook"));
+                ui.checkbox(&mut self.config.integration.result_screenshot_share, "");
+                ui.end_row();
+            }
+        });
+
+        ui.label(tr!("launcher-social-sharing"));
+        egui::Grid::new("social_share_grid").show(ui, |ui| {
+            let integration = &mut self.config.integration;
+            ui.label(tr!("launcher-mastodon-instance"));
+            ui.add(
+                egui::TextEdit::singleline(&mut integration.mastodon_instance)
+                    .hint_text("mastodon.social"),
+            );
+            ui.end_row();
+            ui.label(tr!("launcher-mastodon-access-token"));
+            ui.add(
+                egui::TextEdit::singleline(&mut integration.mastodon_access_token).password(true),
+            );
+            ui.end_row();
+
+            ui.label(tr!("launcher-misskey-instance"));
+            ui.add(
+                egui::TextEdit::singleline(&mut integration.misskey_instance)
+                    .hint_text("misskey.io"),
+            );
+            ui.end_row();
+            ui.label(tr!("launcher-misskey-access-token"));
+            ui.add(
+                egui::TextEdit::singleline(&mut integration.misskey_access_token).password(true),
+            );
+            ui.end_row();
+
+            let player = &mut self.player;
+            for (label, value) in [
+                (
+                    tr!("launcher-twitter-consumer-key"),
+                    &mut player.twitter_consumer_key,
+                ),
+                (
+                    tr!("launcher-twitter-consumer-secret"),
+                    &mut player.twitter_consumer_secret,
+                ),
+                (
+                    tr!("launcher-twitter-access-token"),
+                    &mut player.twitter_access_token,
+                ),
+                (
+                    tr!("launcher-twitter-access-token-secret"),
+                    &mut player.twitter_access_token_secret,
+                ),
+            ] {
+                ui.label(label);
+                let mut text = value.clone().unwrap_or_default();
+                if ui
+                    .add(egui::TextEdit::singleline(&mut text).password(true))
+                    .changed()
+                {
+                    *value = (!text.is_empty()).then_some(text);
+                }
+                ui.end_row();
+            }
+
+            ui.label(tr!("launcher-post-text"));
+            ui.add(
+                egui::TextEdit::multiline(&mut integration.social_share_text)
+                    .desired_rows(3)
+                    .hint_text(DEFAULT_SHARE_TEXT),
+            );
+            ui.end_row();
+        });
+
         ui.separator();
 
         // IPFS settings
         ui.label("IPFS");
         egui::Grid::new("ipfs_grid").show(ui, |ui| {
-            ui.label("Enable:");
+            ui.label(tr!("launcher-enable"));
             ui.checkbox(&mut self.config.network.enable_ipfs, "");
             ui.end_row();
 
             if self.config.network.enable_ipfs {
-                ui.label("IPFS URL:");
+                ui.label(tr!("launcher-ipfs-url"));
                 ui.text_edit_singleline(&mut self.config.network.ipfsurl);
                 ui.end_row();
             }
@@ -551,26 +1176,143 @@ impl LauncherUi {
         ui.separator();
 
         // HTTP download settings
-        ui.label("HTTP Download");
+        ui.label(tr!("launcher-http-download"));
         egui::Grid::new("http_grid").show(ui, |ui| {
-            ui.label("Enable:");
+            ui.label(tr!("launcher-enable"));
             ui.checkbox(&mut self.config.network.enable_http, "");
             ui.end_row();
 
             if self.config.network.enable_http {
-                ui.label("Download Source:");
+                ui.label(tr!("launcher-download-source"));
                 ui.text_edit_singleline(&mut self.config.network.download_source);
                 ui.end_row();
 
-                ui.label("Default URL:");
+                ui.label(tr!("launcher-default-url"));
                 ui.text_edit_singleline(&mut self.config.network.default_download_url);
                 ui.end_row();
 
-                ui.label("Override URL:");
+                ui.label(tr!("launcher-override-url"));
                 ui.text_edit_singleline(&mut self.config.network.override_download_url);
                 ui.end_row();
+
+                ui.label(tr!("launcher-parallel-downloads"));
+                ui.add(
+                    egui::DragValue::new(&mut self.config.network.max_concurrent_downloads)
+                        .range(1..=MAX_CONCURRENT_DOWNLOADS),
+                );
+                ui.end_row();
+
+                ui.label(tr!("launcher-mirror-fallback"));
+                ui.checkbox(&mut self.config.network.mirror_fallback, "");
+                ui.end_row();
+
+                ui.label(tr!("launcher-place-downloads-in"));
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.config.network.download_placement)
+                        .hint_text("{table}"),
+                );
+                ui.end_row();
+
+                ui.label(tr!("launcher-sanitize-folder-names"));
+                ui.checkbox(&mut self.config.network.sanitize_download_names, "");
+                ui.end_row();
+
+                ui.label(tr!("launcher-speed-limit"));
+                ui.add(
+                    egui::DragValue::new(&mut self.config.network.download_speed_limit)
+                        .range(0..=i32::MAX),
+                );
+                ui.end_row();
+
+                ui.label(tr!("launcher-only-download-outside-play"));
+                ui.checkbox(&mut self.config.network.download_only_when_idle, "");
+                ui.end_row();
             }
         });
+
+        ui.separator();
+        self.render_update_section(ui);
+
+        ui.separator();
+        ui.label(tr!("launcher-diagnostics"));
+        egui::Grid::new("diagnostics_grid").show(ui, |ui| {
+            ui.label(tr!("launcher-metrics-dump"));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.config.integration.metrics_dump_path)
+                    .hint_text(tr!("launcher-metrics-dump-hint")),
+            );
+            ui.end_row();
+        });
+
+        ui.separator();
+        self.render_rival_import(ui);
+    }
+
+    /// Rivals imported from another player's score.db or score export.
+    fn render_rival_import(&mut self, ui: &mut egui::Ui) {
+        let rival_dir = std::path::Path::new(RIVAL_DIR);
+        ui.label(tr!("launcher-rivals"));
+        let rivals = self
+            .imported_rivals
+            .get_or_insert_with(|| ScoreDataImporter::imported_rivals(rival_dir));
+        if rivals.is_empty() {
+            ui.label(tr!("launcher-no-imported-rivals"));
+        }
+        egui::Grid::new("imported_rivals_grid").show(ui, |ui| {
+            for (_, info) in rivals.iter() {
+                ui.label(info.name());
+                ui.label(info.id.as_deref().unwrap_or_default());
+                ui.end_row();
+            }
+        });
+
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            ui.label(tr!("launcher-name"));
+            ui.text_edit_singleline(&mut self.rival_name_input);
+            let can_import = !self.rival_name_input.trim().is_empty();
+            if ui
+                .add_enabled(
+                    can_import,
+                    egui::Button::new(tr!("launcher-import-rival-file")),
+                )
+                .on_hover_text(tr!("launcher-rival-import-hint"))
+                .clicked()
+                && let Some(source) =
+                    crate::platform::show_file_chooser(&tr!("launcher-select-rival-score-file"))
+            {
+                let name = self.rival_name_input.trim().to_string();
+                self.rival_import_status = Some(
+                    ScoreDataImporter::import_rival(
+                        std::path::Path::new(&source),
+                        &name,
+                        rival_dir,
+                    )
+                    .map(|_| tr!("launcher-rival-imported", name = name.as_str()))
+                    .map_err(|e| format!("{:#}", e)),
+                );
+                self.rival_name_input.clear();
+                changed = true;
+            }
+            if ui.button(tr!("launcher-refresh-all")).clicked() {
+                let refreshed = ScoreDataImporter::refresh_rivals(rival_dir);
+                self.rival_import_status =
+                    Some(Ok(tr!("launcher-rivals-refreshed", count = refreshed)));
+                changed = true;
+            }
+        });
+        if changed {
+            self.imported_rivals = None;
+        }
+        match &self.rival_import_status {
+            Some(Ok(message)) => {
+                ui.label(message);
+            }
+            Some(Err(message)) => {
+                ui.colored_label(egui::Color32::RED, message);
+            }
+            None => {}
+        }
     }
 
     /// Flush current IR userid/password buffers back to IRConfig via
@@ -596,16 +1338,48 @@ impl LauncherUi {
             self.ir_password_buf.clear();
         }
         self.ir_prev_index = Some(idx);
+        self.ir_test_result = None;
+    }
+
+    /// Log in with the unsaved credentials of the selected IR slot on a background thread.
+    fn start_ir_login_test(&mut self, irname: String) {
+        let userid = self.ir_userid_buf.clone();
+        let password = self.ir_password_buf.clone();
+        self.ir_test_result = None;
+        self.ir_test_handle = Some(std::thread::spawn(move || {
+            crate::result::ir_initializer::test_ir_login(&irname, &userid, &password)
+        }));
+    }
+
+    /// Collect the IR login test response once the background thread has finished.
+    fn poll_ir_login_test(&mut self) {
+        if !self
+            .ir_test_handle
+            .as_ref()
+            .is_some_and(|handle| handle.is_finished())
+        {
+            return;
+        }
+        if let Some(handle) = self.ir_test_handle.take() {
+            self.ir_test_result = Some(handle.join().unwrap_or_else(|_| {
+                IRResponse::failure("IR login test thread panicked".to_string())
+            }));
+        }
     }
 
     /// Java equivalent: IRConfigurationView
     /// Internet Ranking server settings.
     pub(super) fn render_ir_tab(&mut self, ui: &mut egui::Ui) {
-        ui.heading("Internet Ranking");
+        ui.heading(tr!("launcher-internet-ranking"));
+        self.poll_ir_login_test();
+        let ir_names = self
+            .ir_names
+            .get_or_insert_with(IRConnectionManager::all_available_ir_connection_name)
+            .clone();
 
         if self.player.irconfig.is_empty() {
-            ui.label("No IR configurations.");
-            if ui.button("Add IR Configuration").clicked() {
+            ui.label(tr!("launcher-no-ir-configurations"));
+            if ui.button(tr!("launcher-add-ir-configuration")).clicked() {
                 self.player.irconfig.push(Some(IRConfig::default()));
             }
             return;
@@ -620,7 +1394,7 @@ impl LauncherUi {
         let idx = self.selected_ir_index;
 
         ui.horizontal(|ui| {
-            ui.label("IR Slot:");
+            ui.label(tr!("launcher-ir-slot"));
             for i in 0..ir_count {
                 if ui
                     .selectable_label(idx == i, format!("{}", i + 1))
@@ -643,24 +1417,45 @@ impl LauncherUi {
         }
 
         let idx = self.selected_ir_index;
+        let mut test_irname = None;
         if let Some(Some(ir)) = self.player.irconfig.get_mut(idx) {
             egui::Grid::new("ir_grid").show(ui, |ui| {
-                ui.label("IR Name:");
-                ui.text_edit_singleline(&mut ir.irname);
+                ui.label(tr!("launcher-enabled"));
+                ui.checkbox(&mut ir.enabled, tr!("launcher-log-in-at-startup"));
+                ui.end_row();
+
+                ui.label(tr!("launcher-ir-name"));
+                if ir_names.is_empty() {
+                    ui.text_edit_singleline(&mut ir.irname);
+                } else {
+                    egui::ComboBox::from_id_salt("ir_name")
+                        .selected_text(ir.irname.as_str())
+                        .show_ui(ui, |ui| {
+                            for name in &ir_names {
+                                ui.selectable_value(&mut ir.irname, name.clone(), name);
+                            }
+                        });
+                }
                 ui.end_row();
 
-                ui.label("User ID:");
+                if let Some(home) = IRConnectionManager::home_url(&ir.irname) {
+                    ui.label(tr!("launcher-home"));
+                    ui.hyperlink(home);
+                    ui.end_row();
+                }
+
+                ui.label(tr!("launcher-user-id"));
                 ui.text_edit_singleline(&mut self.ir_userid_buf);
                 ui.end_row();
 
-                ui.label("Password:");
+                ui.label(tr!("launcher-password"));
                 ui.add(egui::TextEdit::singleline(&mut self.ir_password_buf).password(true));
                 ui.end_row();
 
                 let selected_label = IR_SEND_LABELS
                     .get(clamped_option_index(ir.irsend, IR_SEND_LABELS.len()))
                     .unwrap_or(&"ALWAYS");
-                ui.label("Send Mode:");
+                ui.label(tr!("launcher-send-mode"));
                 egui::ComboBox::from_id_salt("ir_send_mode")
                     .selected_text(*selected_label)
                     .show_ui(ui, |ui| {
@@ -670,34 +1465,128 @@ impl LauncherUi {
                     });
                 ui.end_row();
 
-                ui.label("Import Rival:");
+                ui.label(tr!("launcher-import-rival"));
                 ui.checkbox(&mut ir.importrival, "");
                 ui.end_row();
 
-                ui.label("Import Score:");
+                ui.label(tr!("launcher-ir-import-score"));
                 ui.checkbox(&mut ir.importscore, "");
                 ui.end_row();
             });
+
+            ui.separator();
+            let running = self.ir_test_handle.is_some();
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(!running, egui::Button::new(tr!("launcher-test-login")))
+                    .clicked()
+                {
+                    test_irname = Some(ir.irname.clone());
+                }
+                if running {
+                    ui.spinner();
+                    ui.ctx().request_repaint();
+                }
+            });
+            if let Some(response) = &self.ir_test_result {
+                let color = if response.is_succeeded() {
+                    egui::Color32::GREEN
+                } else {
+                    egui::Color32::RED
+                };
+                ui.colored_label(color, ir_login_summary(response));
+            }
+        }
+        if let Some(irname) = test_irname {
+            self.start_ir_login_test(irname);
         }
     }
 
     /// Java equivalent: StreamEditorView
     /// Stream request settings.
     pub(super) fn render_stream_tab(&mut self, ui: &mut egui::Ui) {
-        ui.heading("Stream Configuration");
+        ui.heading(tr!("launcher-stream-configuration"));
 
         egui::Grid::new("stream_grid").show(ui, |ui| {
-            ui.label("Enable Request:");
+            ui.label(tr!("launcher-enable-request"));
             ui.checkbox(&mut self.player.enable_request, "");
             ui.end_row();
 
-            ui.label("Notify Request:");
+            ui.label(tr!("launcher-notify-request"));
             ui.checkbox(&mut self.player.notify_request, "");
             ui.end_row();
 
-            ui.label("Max Request Count:");
+            ui.label(tr!("launcher-max-request-count"));
             ui.add(egui::DragValue::new(&mut self.player.max_request_count).range(0..=100));
             ui.end_row();
+
+            ui.label(tr!("launcher-control-token"));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.player.request_control_token).password(true),
+            )
+            .on_hover_text(tr!("launcher-control-token-hint"));
+            ui.end_row();
+        });
+
+        ui.separator();
+        ui.label(tr!("launcher-now-playing-file"));
+        egui::Grid::new("now_playing_grid").show(ui, |ui| {
+            ui.label(tr!("launcher-path"));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.config.integration.now_playing_path)
+                    .hint_text(tr!("launcher-now-playing-hint")),
+            );
+            ui.end_row();
+        });
+
+        ui.separator();
+        ui.label(tr!("launcher-overlay-server"));
+        egui::Grid::new("overlay_server_grid").show(ui, |ui| {
+            ui.label(tr!("launcher-enable"));
+            ui.checkbox(&mut self.config.integration.overlay_server, "");
+            ui.end_row();
+
+            if self.config.integration.overlay_server {
+                ui.label(tr!("launcher-port"));
+                ui.add(
+                    egui::DragValue::new(&mut self.config.integration.overlay_server_port)
+                        .range(0..=65535),
+                );
+                ui.end_row();
+
+                let port = match self.config.integration.overlay_server_port {
+                    0 => crate::external::overlay_server::DEFAULT_OVERLAY_PORT as i32,
+                    port => port,
+                };
+                ui.label(tr!("launcher-browser-source"));
+                ui.label(format!("http://localhost:{}/", port));
+                ui.end_row();
+            }
+        });
+
+        ui.separator();
+        ui.label(tr!("launcher-state-push"));
+        egui::Grid::new("state_push_grid").show(ui, |ui| {
+            ui.label(tr!("launcher-enable"));
+            ui.checkbox(&mut self.config.integration.state_push_server, "");
+            ui.end_row();
+
+            if self.config.integration.state_push_server {
+                ui.label(tr!("launcher-port"));
+                ui.add(
+                    egui::DragValue::new(&mut self.config.integration.state_push_port)
+                        .range(0..=65535),
+                );
+                ui.end_row();
+
+                let port = match self.config.integration.state_push_port {
+                    0 => crate::stream::state_push_server::DEFAULT_STATE_PUSH_PORT as i32,
+                    port => port,
+                };
+                ui.label(tr!("launcher-websocket-url"));
+                ui.label(format!("ws://localhost:{}/", port));
+                ui.end_row();
+            }
         });
     }
 
@@ -706,13 +1595,20 @@ impl LauncherUi {
 
         ui.checkbox(
             &mut self.config.integration.use_discord_rpc,
-            "Enable Discord Rich Presence",
+            tr!("launcher-enable-discord-rich-presence"),
+        );
+        ui.add_enabled(
+            self.config.integration.use_discord_rpc,
+            egui::Checkbox::new(
+                &mut self.config.integration.discord_hide_song_details,
+                tr!("launcher-discord-hide-song"),
+            ),
         );
 
         ui.separator();
 
         // Webhook configuration
-        ui.heading("Webhook");
+        ui.heading(tr!("launcher-webhook"));
 
         egui::Grid::new("discord_webhook_grid").show(ui, |ui| {
             let webhook_options = ["All Clear", "FC / AAA", "Clear"];
@@ -721,7 +1617,7 @@ impl LauncherUi {
                 webhook_options.len(),
             );
             let selected_label = webhook_options[clamped_index];
-            ui.label("Send On:");
+            ui.label(tr!("launcher-send-on"));
             egui::ComboBox::from_id_salt("webhook_option")
                 .selected_text(selected_label)
                 .show_ui(ui, |ui| {
@@ -735,24 +1631,60 @@ impl LauncherUi {
                 });
             ui.end_row();
 
-            ui.label("Bot Name:");
+            ui.label(tr!("launcher-bot-name"));
             ui.text_edit_singleline(&mut self.config.integration.webhook_name);
             ui.end_row();
 
-            ui.label("Avatar URL:");
+            ui.label(tr!("launcher-avatar-url"));
             ui.text_edit_singleline(&mut self.config.integration.webhook_avatar);
             ui.end_row();
+
+            ui.label(tr!("launcher-conditions"));
+            ui.horizontal(|ui| {
+                ui.checkbox(
+                    &mut self.config.integration.webhook_only_personal_best,
+                    tr!("launcher-personal-best-only"),
+                );
+                ui.checkbox(
+                    &mut self.config.integration.webhook_only_clear,
+                    tr!("launcher-clears-only"),
+                );
+            });
+            ui.end_row();
+
+            let min_rank = clamped_option_index(
+                self.config.integration.webhook_min_rank,
+                WEBHOOK_DJ_LEVELS.len(),
+            );
+            ui.label(tr!("launcher-minimum-rank"));
+            egui::ComboBox::from_id_salt("webhook_min_rank")
+                .selected_text(WEBHOOK_DJ_LEVELS[min_rank])
+                .show_ui(ui, |ui| {
+                    for (i, label) in WEBHOOK_DJ_LEVELS.iter().enumerate() {
+                        ui.selectable_value(
+                            &mut self.config.integration.webhook_min_rank,
+                            i as i32,
+                            *label,
+                        );
+                    }
+                });
+            ui.end_row();
+
+            ui.label(tr!("launcher-embed-template"));
+            ui.text_edit_singleline(&mut self.config.integration.webhook_template)
+                .on_hover_text(tr!("launcher-embed-template-hint"));
+            ui.end_row();
         });
 
         ui.separator();
 
         // Webhook URL table
-        ui.label("Webhook URLs:");
+        ui.label(tr!("launcher-webhook-urls"));
         let mut remove_idx = None;
         for (i, url) in self.webhook_urls.iter().enumerate() {
             ui.horizontal(|ui| {
                 ui.label(url);
-                if ui.small_button("Remove").clicked() {
+                if ui.small_button(tr!("launcher-remove")).clicked() {
                     remove_idx = Some(i);
                 }
             });
@@ -763,7 +1695,7 @@ impl LauncherUi {
 
         ui.horizontal(|ui| {
             ui.text_edit_singleline(&mut self.webhook_url_input);
-            if ui.button("Add").clicked() && !self.webhook_url_input.is_empty() {
+            if ui.button(tr!("launcher-add")).clicked() && !self.webhook_url_input.is_empty() {
                 let url = self.webhook_url_input.clone();
                 if !self.webhook_urls.contains(&url) {
                     self.webhook_urls.push(url);
@@ -777,6 +1709,19 @@ impl LauncherUi {
     /// OBS WebSocket integration settings.
     /// Delegates to ObsConfigurationView which handles connection, scene
     /// fetching, and per-state scene/action selectors.
+    pub(super) fn render_key_binding_tab(&mut self, ui: &mut egui::Ui) {
+        let mode = self.current_mode();
+        self.key_binding_view.render(ui, &mut self.player, mode);
+    }
+
+    pub(super) fn render_tables_tab(&mut self, ui: &mut egui::Ui) {
+        if !self.table_info_loaded {
+            self.table_info_loaded = true;
+            self.table_view.refresh_local_table_info();
+        }
+        self.table_view.render_tables(ui);
+    }
+
     pub(super) fn render_obs_tab(&mut self, ui: &mut egui::Ui) {
         self.obs_view.render(ui);
     }
@@ -72,9 +72,9 @@ fn test_play_requested_initially_false() {
 }
 
 #[test]
-fn test_tab_all_returns_11_tabs() {
-    // Java: PlayConfigurationView has 11 tabs
-    assert_eq!(Tab::all().len(), 11);
+fn test_tab_all_returns_13_tabs() {
+    // Java: PlayConfigurationView has 11 tabs, plus the launcher-only key binding and tables tabs
+    assert_eq!(Tab::all().len(), 13);
 }
 
 #[test]
@@ -140,3 +140,173 @@ fn test_commit_config_writes_bms_paths_back_to_config() {
         "commit_config() must write bms_paths back to config.paths.bmsroot"
     );
 }
+
+#[test]
+fn test_audio_test_summary_formats_buffer_and_latency() {
+    let result = crate::platform::AudioTestResult {
+        device: "Speakers".to_string(),
+        sample_rate: 48000,
+        buffer_frames: Some(480),
+        output_latency_ms: Some(12.34),
+    };
+    assert_eq!(
+        super::tabs::audio_test_summary(&result),
+        "Speakers: 48000 Hz, buffer 480 frames (10.0 ms), output latency 12.3 ms"
+    );
+
+    let unknown = crate::platform::AudioTestResult {
+        buffer_frames: None,
+        output_latency_ms: None,
+        ..result
+    };
+    assert_eq!(
+        super::tabs::audio_test_summary(&unknown),
+        "Speakers: 48000 Hz, buffer unknown, output latency not reported"
+    );
+}
+
+#[test]
+fn test_latency_measurement_is_stored_as_audio_offset() {
+    use super::latency_panel::{LatencyDraft, apply_latency_measurement, round_trip_summary};
+
+    let result = crate::platform::RoundTripResult {
+        device: "Speakers".to_string(),
+        sample_rate: 48000,
+        round_trip_ms: 31.6,
+        input_latency_ms: Some(10.0),
+        clicks_played: 8,
+        clicks_heard: 7,
+    };
+    assert_eq!(
+        round_trip_summary(&result),
+        "Speakers: heard 7/8 clicks, round trip 31.6 ms, output latency 21.6 ms"
+    );
+
+    let mut audio = crate::core::audio_config::AudioConfig::default();
+    let mut judge = crate::core::player_config::JudgeSettings::default();
+    let draft = LatencyDraft {
+        buffer_size: 128,
+        period_count: 3,
+    };
+    apply_latency_measurement(&mut audio, &mut judge, draft, &result);
+    assert_eq!(audio.device_buffer_size, 128);
+    assert_eq!(audio.device_period_count, 3);
+    assert_eq!(audio.measured_output_latency, 22);
+    assert_eq!(judge.audio_offset, 22);
+}
+
+#[test]
+fn test_display_modes_summary_mentions_detection() {
+    assert_eq!(
+        super::tabs::display_modes_summary(2560, 1440, 12),
+        "Display: 2560 x 1440 (12 fullscreen modes detected)"
+    );
+    assert!(super::tabs::display_modes_summary(1920, 1080, 0).contains("first game start"));
+}
+
+#[test]
+fn test_ir_login_summary_reports_player_or_error() {
+    use crate::ir::ir_player_data::IRPlayerData;
+    use crate::ir::ir_response::IRResponse;
+
+    let ok = IRResponse::success(
+        "OK".to_string(),
+        IRPlayerData::new("42".to_string(), "DJ TEST".to_string(), "10dan".to_string()),
+    );
+    assert_eq!(
+        super::tabs::ir_login_summary(&ok),
+        "Logged in as DJ TEST (ID 42, rank 10dan)"
+    );
+
+    let failed: IRResponse<IRPlayerData> = IRResponse::failure("invalid password".to_string());
+    assert_eq!(
+        super::tabs::ir_login_summary(&failed),
+        "Login failed: invalid password"
+    );
+}
+
+#[test]
+fn test_folder_chart_counts_reads_song_database() {
+    use crate::song::song_data::SongData;
+    use crate::song::song_database_accessor::SongDatabaseAccessor;
+    use crate::song::sqlite_song_database_accessor::SQLiteSongDatabaseAccessor;
+
+    let dir = tempfile::tempdir().unwrap();
+    let songpath = dir.path().join("songdata.db").to_string_lossy().to_string();
+    let folder_a = dir.path().join("a").to_string_lossy().to_string();
+    let folder_b = dir.path().join("b").to_string_lossy().to_string();
+    let folders = vec![folder_a.clone(), folder_b.clone()];
+
+    // No database yet: nothing is counted and no file is created
+    assert!(super::tabs::folder_chart_counts(&songpath, &folders).is_empty());
+    assert!(!std::path::Path::new(&songpath).exists());
+
+    let songdb = SQLiteSongDatabaseAccessor::new(&songpath, &folders).unwrap();
+    let songs: Vec<SongData> = (0..3)
+        .map(|i| {
+            let mut sd = SongData::new();
+            sd.file.md5 = format!("md5_{}", i);
+            sd.file.sha256 = format!("sha256_{}", i);
+            sd.metadata.title = format!("Song {}", i);
+            sd.file.set_path(format!("{}/song{}.bms", folder_a, i));
+            sd
+        })
+        .collect();
+    songdb.set_song_datas(&songs).unwrap();
+    drop(songdb);
+
+    let counts = super::tabs::folder_chart_counts(&songpath, &folders);
+    assert_eq!(counts.get(&folder_a), Some(&3));
+    assert_eq!(counts.get(&folder_b), Some(&0));
+}
+
+#[test]
+fn test_bms_scan_summary_reports_progress() {
+    use crate::views::play_configuration_view::BmsLoadingState;
+
+    let state = BmsLoadingState::Loading {
+        bms_files: 120,
+        processed_files: 30,
+        new_files: 4,
+    };
+    assert_eq!(
+        super::tabs::bms_scan_summary(&state),
+        "Scanning: 30/120 processed, 4 new"
+    );
+    assert_eq!(
+        super::tabs::bms_scan_summary(&BmsLoadingState::Failed("disk error".to_string())),
+        "Scan failed: disk error"
+    );
+}
+
+#[test]
+fn test_import_beatoraja_settings_replaces_config_and_player() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().join("beatoraja");
+    std::fs::create_dir_all(root.join("player/dj")).unwrap();
+    std::fs::write(
+        root.join("config_sys.json"),
+        r#"{"playername":"dj","tableURL":["https://example.com/table.html"]}"#,
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("player/dj/config_player.json"),
+        r#"{"name":"DJ","gauge":2}"#,
+    )
+    .unwrap();
+
+    let mut config = Config::default();
+    config.paths.playerpath = dir.path().join("player").to_string_lossy().to_string();
+    let mut ui = LauncherUi::new(config, PlayerConfig::default());
+    ui.offer_beatoraja_import(root.clone());
+    ui.import_beatoraja_settings(&root).unwrap();
+
+    assert_eq!(ui.config.playername.as_deref(), Some("dj"));
+    assert_eq!(
+        ui.config.paths.table_url,
+        vec!["https://example.com/table.html".to_string()]
+    );
+    assert_eq!(ui.player.play_settings.gauge, 2);
+    assert!(dir.path().join("player/dj/config_player.json").exists());
+    assert!(ui.beatoraja_import_offer.is_none());
+}
@@ -0,0 +1,142 @@
+// Update check, changelog and download of new builds (Other tab).
+
+use std::path::PathBuf;
+
+use super::LauncherUi;
+use crate::tr;
+use crate::version_checker::{self, ReleaseInfo, UPDATE_DOWNLOAD_DIR};
+
+impl LauncherUi {
+    /// Query the latest release on a background thread.
+    pub(super) fn start_update_check(&mut self) {
+        if self.update_check_handle.is_some() {
+            return;
+        }
+        self.update_release = None;
+        self.update_download_result = None;
+        self.update_check_handle = Some(std::thread::spawn(version_checker::fetch_latest_release));
+    }
+
+    pub(super) fn poll_update_check(&mut self) {
+        if self
+            .update_check_handle
+            .as_ref()
+            .is_some_and(|handle| handle.is_finished())
+            && let Some(handle) = self.update_check_handle.take()
+        {
+            self.update_release = Some(match handle.join() {
+                Ok(result) => result.map_err(|e| format!("{:#}", e)),
+                Err(_) => Err("update check thread panicked".to_string()),
+            });
+        }
+        if self
+            .update_download_handle
+            .as_ref()
+            .is_some_and(|handle| handle.is_finished())
+            && let Some(handle) = self.update_download_handle.take()
+        {
+            self.update_download_result = Some(match handle.join() {
+                Ok(result) => result.map_err(|e| format!("{:#}", e)),
+                Err(_) => Err("download thread panicked".to_string()),
+            });
+        }
+    }
+
+    /// Newer release found by the last check.
+    pub(super) fn available_update(&self) -> Option<&ReleaseInfo> {
+        self.update_release
+            .as_ref()
+            .and_then(|r| r.as_ref().ok())
+            .filter(|release| release.is_newer())
+    }
+
+    fn start_update_download(&mut self) {
+        let Some(asset) = self.available_update().and_then(|r| r.asset.clone()) else {
+            return;
+        };
+        self.update_download_result = None;
+        self.update_download_handle = Some(std::thread::spawn(move || {
+            version_checker::download_asset(&asset, &PathBuf::from(UPDATE_DOWNLOAD_DIR))
+        }));
+    }
+
+    pub(super) fn render_update_section(&mut self, ui: &mut egui::Ui) {
+        ui.label(tr!("update-updates"));
+        ui.checkbox(
+            &mut self.config.network.check_updates,
+            tr!("update-check-on-start"),
+        );
+        ui.horizontal(|ui| {
+            let checking = self.update_check_handle.is_some();
+            if ui
+                .add_enabled(!checking, egui::Button::new(tr!("update-check-now")))
+                .clicked()
+            {
+                self.start_update_check();
+            }
+            if checking {
+                ui.spinner();
+            }
+            match &self.update_release {
+                Some(Err(e)) => {
+                    ui.colored_label(
+                        egui::Color32::RED,
+                        tr!("update-check-failed", error = e.as_str()),
+                    );
+                }
+                Some(Ok(release)) if !release.is_newer() => {
+                    ui.label(tr!(
+                        "update-up-to-date",
+                        version = crate::core::version::Version::get_version()
+                    ));
+                }
+                _ => {}
+            }
+        });
+
+        let Some(release) = self.available_update().cloned() else {
+            return;
+        };
+        ui.strong(tr!("update-available", name = release.name.as_str()));
+        egui::ScrollArea::vertical()
+            .id_salt("update_changelog")
+            .max_height(200.0)
+            .show(ui, |ui| {
+                ui.label(&release.changelog);
+            });
+        ui.horizontal(|ui| {
+            let downloading = self.update_download_handle.is_some();
+            if let Some(asset) = &release.asset {
+                let label = tr!(
+                    "update-download",
+                    name = asset.name.as_str(),
+                    size = asset.size / (1024 * 1024)
+                );
+                if ui
+                    .add_enabled(!downloading, egui::Button::new(label))
+                    .clicked()
+                {
+                    self.start_update_download();
+                }
+                if downloading {
+                    ui.spinner();
+                }
+            } else {
+                ui.label(tr!("update-no-build-for-this-platform"));
+            }
+            ui.hyperlink_to(tr!("update-release-page"), &release.html_url);
+        });
+        match &self.update_download_result {
+            Some(Ok(path)) => {
+                ui.label(tr!("update-saved", path = path.display().to_string()));
+            }
+            Some(Err(e)) => {
+                ui.colored_label(
+                    egui::Color32::RED,
+                    tr!("update-download-failed", error = e.as_str()),
+                );
+            }
+            None => {}
+        }
+    }
+}
@@ -3,7 +3,9 @@
 
 // Infrastructure modules (merged from standalone crates)
 pub mod audio;
+pub mod i18n;
 pub mod input;
+pub mod profiling;
 pub mod render;
 pub mod skin;
 
@@ -0,0 +1,250 @@
+//! Mod menu navigation with lane keys, START and SELECT, for cabinet setups
+//! without a keyboard or pointing device.
+//!
+//! While the menu is open, lane key presses become egui key events, so focus
+//! moves through the widgets like with Tab and the focused widget is changed
+//! like with the arrow keys and Enter.
+
+use std::sync::Mutex;
+
+use crate::skin::hotkey_config::ModMenuCombo;
+use crate::skin::sync_utils::lock_or_recover;
+
+/// Lane keys read for navigation: 1P keys 1-7 and scratch up/down.
+pub const NAVIGATION_KEYS: usize = 9;
+
+/// Navigation steps queued for the next overlay frame.
+static PENDING_ACTIONS: Mutex<Vec<MenuAction>> = Mutex::new(Vec::new());
+
+/// One navigation step in the mod menu.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuAction {
+    /// Focus the previous widget (key 1, scratch up).
+    Previous,
+    /// Focus the next widget (key 3, scratch down).
+    Next,
+    /// Lower the focused value (key 2).
+    Decrease,
+    /// Raise the focused value (key 4).
+    Increase,
+    /// Press the focused widget (key 5).
+    Activate,
+    /// Close the mod menu (key 7).
+    Close,
+}
+
+impl MenuAction {
+    /// Action of lane key `key`, if it navigates.
+    pub fn from_key(key: usize) -> Option<Self> {
+        match key {
+            0 | 7 => Some(MenuAction::Previous),
+            2 | 8 => Some(MenuAction::Next),
+            1 => Some(MenuAction::Decrease),
+            3 => Some(MenuAction::Increase),
+            4 => Some(MenuAction::Activate),
+            6 => Some(MenuAction::Close),
+            _ => None,
+        }
+    }
+
+    /// Key press egui handles for this action. None for Close, which the menu
+    /// handles itself.
+    pub fn egui_key(self) -> Option<(egui::Key, egui::Modifiers)> {
+        match self {
+            MenuAction::Previous => Some((egui::Key::Tab, egui::Modifiers::SHIFT)),
+            MenuAction::Next => Some((egui::Key::Tab, egui::Modifiers::NONE)),
+            MenuAction::Decrease => Some((egui::Key::ArrowLeft, egui::Modifiers::NONE)),
+            MenuAction::Increase => Some((egui::Key::ArrowRight, egui::Modifiers::NONE)),
+            MenuAction::Activate => Some((egui::Key::Enter, egui::Modifiers::NONE)),
+            MenuAction::Close => None,
+        }
+    }
+}
+
+/// Queue `action` for the next overlay frame.
+pub fn push_action(action: MenuAction) {
+    lock_or_recover(&PENDING_ACTIONS).push(action);
+}
+
+/// Press and release events of the queued actions, to add to the egui input.
+pub fn take_events() -> Vec<egui::Event> {
+    let actions = std::mem::take(&mut *lock_or_recover(&PENDING_ACTIONS));
+    actions
+        .into_iter()
+        .filter_map(MenuAction::egui_key)
+        .flat_map(|(key, modifiers)| {
+            [true, false].map(|pressed| egui::Event::Key {
+                key,
+                physical_key: None,
+                pressed,
+                repeat: false,
+                modifiers,
+            })
+        })
+        .collect()
+}
+
+/// Buttons held in one frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NavigationInput {
+    pub keys: [bool; NAVIGATION_KEYS],
+    pub start: bool,
+    pub select: bool,
+}
+
+/// What one frame of input does to the mod menu.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NavigationUpdate {
+    /// The toggle combination was completed.
+    pub toggle: bool,
+    /// Navigation steps, when the menu is open.
+    pub actions: Vec<MenuAction>,
+    /// Lane keys whose press was used for navigation.
+    pub consumed_keys: Vec<usize>,
+}
+
+/// Turns lane key, START and SELECT presses into mod menu toggles and
+/// navigation steps.
+#[derive(Clone, Debug, Default)]
+pub struct ControllerNavigation {
+    prev: NavigationInput,
+    combo_held: bool,
+}
+
+impl ControllerNavigation {
+    pub fn update(
+        &mut self,
+        input: &NavigationInput,
+        combo: &ModMenuCombo,
+        menu_shown: bool,
+    ) -> NavigationUpdate {
+        let mut update = NavigationUpdate::default();
+        let combo_held = combo.is_bound()
+            && (!combo.start || input.start)
+            && (!combo.select || input.select)
+            && combo.keys.iter().all(|&key| {
+                usize::try_from(key)
+                    .ok()
+                    .and_then(|key| input.keys.get(key))
+                    .copied()
+                    .unwrap_or(false)
+            });
+        update.toggle = combo_held && !self.combo_held;
+        self.combo_held = combo_held;
+
+        // Keys pressed to complete the combination do not navigate
+        if menu_shown && !combo_held {
+            for (key, (&now, &prev)) in input.keys.iter().zip(&self.prev.keys).enumerate() {
+                if now
+                    && !prev
+                    && let Some(action) = MenuAction::from_key(key)
+                {
+                    update.actions.push(action);
+                    update.consumed_keys.push(key);
+                }
+            }
+        }
+        self.prev = *input;
+        update
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(pressed: &[usize]) -> NavigationInput {
+        let mut input = NavigationInput::default();
+        for &key in pressed {
+            input.keys[key] = true;
+        }
+        input
+    }
+
+    #[test]
+    fn combination_toggles_once_when_completed() {
+        let combo = ModMenuCombo {
+            start: true,
+            select: true,
+            keys: Vec::new(),
+        };
+        let mut nav = ControllerNavigation::default();
+        let start = NavigationInput {
+            start: true,
+            ..Default::default()
+        };
+        let both = NavigationInput {
+            start: true,
+            select: true,
+            ..Default::default()
+        };
+        assert!(!nav.update(&start, &combo, false).toggle);
+        assert!(nav.update(&both, &combo, false).toggle);
+        assert!(!nav.update(&both, &combo, true).toggle);
+        assert!(!nav.update(&start, &combo, true).toggle);
+        assert!(nav.update(&both, &combo, true).toggle);
+    }
+
+    #[test]
+    fn unbound_combination_never_toggles() {
+        let mut nav = ControllerNavigation::default();
+        let all = NavigationInput {
+            keys: [true; NAVIGATION_KEYS],
+            start: true,
+            select: true,
+        };
+        assert!(!nav.update(&all, &ModMenuCombo::default(), false).toggle);
+    }
+
+    #[test]
+    fn key_presses_navigate_only_while_shown() {
+        let combo = ModMenuCombo::default();
+        let mut nav = ControllerNavigation::default();
+        assert!(nav.update(&keys(&[2]), &combo, false).actions.is_empty());
+        nav.update(&keys(&[]), &combo, true);
+
+        let update = nav.update(&keys(&[2, 4]), &combo, true);
+        assert_eq!(update.actions, vec![MenuAction::Next, MenuAction::Activate]);
+        assert_eq!(update.consumed_keys, vec![2, 4]);
+        // Held keys do not repeat
+        assert!(nav.update(&keys(&[2, 4]), &combo, true).actions.is_empty());
+        assert_eq!(
+            nav.update(&keys(&[7]), &combo, true).actions,
+            vec![MenuAction::Previous]
+        );
+    }
+
+    #[test]
+    fn combination_keys_do_not_navigate() {
+        let combo = ModMenuCombo {
+            start: false,
+            select: true,
+            keys: vec![6],
+        };
+        let mut nav = ControllerNavigation::default();
+        nav.update(&keys(&[]), &combo, true);
+        let mut input = keys(&[6]);
+        input.select = true;
+        let update = nav.update(&input, &combo, true);
+        assert!(update.toggle);
+        assert!(update.actions.is_empty());
+    }
+
+    #[test]
+    fn queued_actions_become_key_presses() {
+        push_action(MenuAction::Previous);
+        push_action(MenuAction::Close);
+        let events = take_events();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            events[0],
+            egui::Event::Key {
+                key: egui::Key::Tab,
+                pressed: true,
+                modifiers,
+                ..
+            } if modifiers.shift
+        ));
+        assert!(take_events().is_empty());
+    }
+}
@@ -2,10 +2,13 @@ use std::sync::{Arc, Mutex};
 
 use crate::song::md_processor::download_task::{DownloadTask, DownloadTaskStatus};
 use crate::song::md_processor::http_download_processor::HttpDownloadProcessor;
+use crate::song::md_processor::source_stats::SourceStats;
+use std::time::Duration;
 
 use super::imgui_renderer;
 use crate::skin::sync_utils::lock_or_recover;
 use crate::song::md_processor::download_task_state::DownloadTaskState;
+use crate::tr;
 
 pub const MAXIMUM_TASK_NAME_LENGTH: usize = 10;
 
@@ -42,14 +45,16 @@ impl DownloadTaskMenu {
     /// Translated from: DownloadTaskMenu.renderTaskTable(List<DownloadTask>)
     fn render_task_table(ui: &mut egui::Ui, tasks: &[Arc<Mutex<DownloadTask>>]) {
         egui::Grid::new("DownloadTaskTable")
-            .num_columns(3)
+            .num_columns(5)
             .striped(true)
             .spacing([10.0, 4.0])
             .show(ui, |ui| {
                 // Header row
-                ui.strong("Task");
-                ui.strong("Progress");
-                ui.strong("Op");
+                ui.strong(tr!("downloads-task"));
+                ui.strong(tr!("downloads-progress"));
+                ui.strong(tr!("downloads-speed"));
+                ui.strong(tr!("downloads-eta"));
+                ui.strong(tr!("downloads-op"));
                 ui.end_row();
 
                 for task_arc in tasks {
@@ -63,8 +68,11 @@ impl DownloadTaskMenu {
                     } else {
                         name
                     };
-                    let display = format!("{} ({})", task_name, task.download_task_status().name());
-                    ui.label(&display);
+                    let display = format!("{} ({})", task_name, status_text(&task));
+                    let response = ui.label(&display);
+                    if let Some(dir) = task.scanned_directory() {
+                        response.on_hover_text(dir);
+                    }
 
                     // Column 1: Progress
                     let error_message = task.error_message();
@@ -81,25 +89,93 @@ impl DownloadTaskMenu {
                         ui.label(egui::RichText::new(msg).color(egui::Color32::RED));
                     }
 
-                    // Column 2: Operation — retry button for errored tasks
-                    let is_error = task.download_task_status() == DownloadTaskStatus::Error;
+                    // Columns 2-3: Speed and ETA, while downloading
+                    if task.download_task_status() == DownloadTaskStatus::Downloading {
+                        ui.label(format!("{}/s", humanize_file_size(task.speed() as i64)));
+                        ui.label(task.eta().map(format_eta).unwrap_or_else(|| "-".into()));
+                    } else {
+                        ui.label("-");
+                        ui.label("-");
+                    }
+
+                    // Column 2: Operation — pause/resume for live tasks, retry for errored ones
+                    let status = task.download_task_status();
+                    let password_required = task.password_required();
+                    let task_id = task.id();
                     drop(task); // release lock before UI interaction
-                    if is_error {
-                        if ui.button("Retry").clicked() {
+                    if password_required {
+                        Self::render_password_prompt(ui, task_id, task_arc);
+                        ui.end_row();
+                        continue;
+                    }
+                    let op = match status {
+                        DownloadTaskStatus::Prepare
+                        | DownloadTaskStatus::Queued
+                        | DownloadTaskStatus::Downloading => Some(tr!("downloads-pause")),
+                        DownloadTaskStatus::Paused => Some(tr!("downloads-resume")),
+                        DownloadTaskStatus::Error => Some(tr!("downloads-retry")),
+                        _ => None,
+                    };
+                    let cancellable = op.is_some();
+                    ui.horizontal(|ui| {
+                        if let Some(label) = op
+                            && ui.button(label).clicked()
+                        {
                             let processor = lock_or_recover(&PROCESSOR);
                             if let Some(ref proc) = *processor {
-                                proc.retry_download_task(task_arc.clone());
+                                match status {
+                                    DownloadTaskStatus::Paused => {
+                                        proc.resume_download_task(task_arc.clone());
+                                    }
+                                    DownloadTaskStatus::Error => {
+                                        proc.retry_download_task(task_arc.clone());
+                                    }
+                                    _ => {
+                                        proc.pause_download_task(task_arc);
+                                    }
+                                }
                             }
                         }
-                    } else {
-                        ui.label("");
-                    }
+                        if cancellable && ui.button(tr!("downloads-cancel")).clicked() {
+                            let processor = lock_or_recover(&PROCESSOR);
+                            if let Some(ref proc) = *processor {
+                                proc.cancel_download_task(task_arc);
+                            }
+                        }
+                    });
 
                     ui.end_row();
                 }
             });
     }
 
+    /// Password field and Extract button for an encrypted archive.
+    fn render_password_prompt(
+        ui: &mut egui::Ui,
+        task_id: i32,
+        task_arc: &Arc<Mutex<DownloadTask>>,
+    ) {
+        let id = egui::Id::new(("download_task_password", task_id));
+        let mut password = ui
+            .data_mut(|d| d.get_temp::<String>(id))
+            .unwrap_or_default();
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut password)
+                    .password(true)
+                    .hint_text(tr!("downloads-password"))
+                    .desired_width(120.0),
+            );
+            if ui.button(tr!("downloads-extract")).clicked() && !password.is_empty() {
+                let processor = lock_or_recover(&PROCESSOR);
+                if let Some(ref proc) = *processor {
+                    proc.extract_with_password(task_arc.clone(), password.clone());
+                }
+            }
+        });
+        ui.data_mut(|d| d.insert_temp(id, password));
+    }
+
     /// Render the download task window using egui.
     ///
     /// Translated from: DownloadTaskMenu.show(ImBoolean)
@@ -117,15 +193,21 @@ impl DownloadTaskMenu {
         let rel_y = imgui_renderer::window_height() as f32 * 0.04;
 
         let mut open = true;
-        egui::Window::new("Download Tasks")
+        egui::Window::new(tr!("downloads-download-tasks"))
             .open(&mut open)
             .default_pos(egui::pos2(rel_x, rel_y))
             .auto_sized()
             .show(ctx, |ui| {
+                let suspended = lock_or_recover(&PROCESSOR)
+                    .as_ref()
+                    .is_some_and(|proc| proc.downloads_suspended());
+                if suspended {
+                    ui.colored_label(egui::Color32::YELLOW, tr!("downloads-on-hold"));
+                }
                 let running = DownloadTaskState::get_running_download_tasks();
                 let expired = DownloadTaskState::get_expired_tasks();
                 if running.is_empty() && expired.is_empty() {
-                    ui.label("No Download Task. Try selecting missing bms to submit new task!");
+                    ui.label(tr!("downloads-empty"));
                 } else {
                     let running_tasks: Vec<Arc<Mutex<DownloadTask>>> =
                         running.values().cloned().collect();
@@ -134,23 +216,94 @@ impl DownloadTaskMenu {
 
                     // Tab bar: Running / Expired (Java: ImGui.beginTabBar("DownloadTasksTabBar"))
                     ui.horizontal(|ui| {
-                        ui.label(format!("Running: {}", running_tasks.len()));
+                        ui.label(tr!("downloads-running-count", count = running_tasks.len()));
                         ui.separator();
-                        ui.label(format!("Expired: {}", expired_tasks.len()));
+                        ui.label(tr!("downloads-expired-count", count = expired_tasks.len()));
                     });
                     ui.separator();
 
-                    ui.collapsing(egui::RichText::new("Running").strong(), |ui| {
-                        Self::render_task_table(ui, &running_tasks);
-                    });
-                    ui.collapsing(egui::RichText::new("Expired").strong(), |ui| {
-                        Self::render_task_table(ui, &expired_tasks);
-                    });
+                    ui.collapsing(
+                        egui::RichText::new(tr!("downloads-running")).strong(),
+                        |ui| {
+                            Self::render_task_table(ui, &running_tasks);
+                        },
+                    );
+                    ui.collapsing(
+                        egui::RichText::new(tr!("downloads-expired")).strong(),
+                        |ui| {
+                            Self::render_task_table(ui, &expired_tasks);
+                        },
+                    );
+                }
+
+                let source_stats = lock_or_recover(&PROCESSOR)
+                    .as_ref()
+                    .map(|proc| proc.source_stats())
+                    .unwrap_or_default();
+                if !source_stats.is_empty() {
+                    ui.collapsing(
+                        egui::RichText::new(tr!("downloads-sources")).strong(),
+                        |ui| {
+                            Self::render_source_table(ui, &source_stats);
+                        },
+                    );
+                }
+            });
+    }
+
+    /// Render the recorded reliability of each download source.
+    fn render_source_table(ui: &mut egui::Ui, source_stats: &[(String, SourceStats)]) {
+        egui::Grid::new("DownloadSourceTable")
+            .num_columns(4)
+            .striped(true)
+            .spacing([10.0, 4.0])
+            .show(ui, |ui| {
+                ui.strong(tr!("downloads-source"));
+                ui.strong(tr!("downloads-success"));
+                ui.strong(tr!("downloads-failed"));
+                ui.strong(tr!("downloads-last-error"));
+                ui.end_row();
+
+                for (name, stats) in source_stats {
+                    ui.label(name);
+                    ui.label(format!(
+                        "{} ({:.0}%)",
+                        stats.successes,
+                        stats.success_rate() * 100.0
+                    ));
+                    ui.label(tr!(
+                        "downloads-failures",
+                        failures = stats.failures,
+                        broken = stats.integrity_failures
+                    ));
+                    ui.label(stats.last_error.as_deref().unwrap_or("-"));
+                    ui.end_row();
                 }
             });
     }
 }
 
+/// Status shown next to the task name, with the extraction and scan steps
+/// that follow the download.
+fn status_text(task: &DownloadTask) -> String {
+    match (task.download_task_status(), task.processing_step()) {
+        (DownloadTaskStatus::Downloaded | DownloadTaskStatus::Extracted, Some(step)) => {
+            step.name().to_string()
+        }
+        (status, _) => status.name().to_string(),
+    }
+}
+
+/// Format a remaining time as `m:ss`, or `h:mm:ss` past an hour.
+fn format_eta(eta: Duration) -> String {
+    let secs = eta.as_secs();
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    } else {
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
 pub fn humanize_file_size(bytes: i64) -> String {
     let thresh: i64 = 1000;
     if bytes.abs() < thresh {
@@ -220,6 +373,29 @@ mod tests {
         assert_eq!(humanize_file_size(-1500), "-1.5 KB");
     }
 
+    #[test]
+    fn test_format_eta() {
+        assert_eq!(format_eta(Duration::from_secs(0)), "0:00");
+        assert_eq!(format_eta(Duration::from_secs(75)), "1:15");
+        assert_eq!(format_eta(Duration::from_secs(3_725)), "1:02:05");
+    }
+
+    #[test]
+    fn test_status_text_shows_processing_step() {
+        use crate::song::md_processor::download_task::ProcessingStep;
+
+        let mut task = DownloadTask::new(1, String::new(), String::new(), String::new());
+        task.set_download_task_status(DownloadTaskStatus::Downloaded);
+        assert_eq!(status_text(&task), "Downloaded");
+        task.set_processing_step(Some(ProcessingStep::Scanning));
+        assert_eq!(status_text(&task), "Scanning");
+        task.set_processing_step(Some(ProcessingStep::Scanned));
+        task.set_download_task_status(DownloadTaskStatus::Extracted);
+        assert_eq!(status_text(&task), "Added to library");
+        task.set_download_task_status(DownloadTaskStatus::Error);
+        assert_eq!(status_text(&task), "Error");
+    }
+
     #[test]
     fn test_maximum_task_name_length_constant() {
         assert_eq!(MAXIMUM_TASK_NAME_LENGTH, 10);
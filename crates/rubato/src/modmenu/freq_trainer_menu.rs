@@ -1,4 +1,5 @@
 use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
 use std::sync::Mutex;
 
 static FREQ_TRAINER_ENABLED: Mutex<bool> = Mutex::new(false);
@@ -32,18 +33,17 @@ impl FreqTrainerMenu {
     /// Render the rate modifier window using egui.
     pub fn show_ui(ctx: &egui::Context) {
         let mut open = true;
-        egui::Window::new("Rate Modifier")
+        egui::Window::new(tr!("rate-rate-modifier"))
             .open(&mut open)
             .auto_sized()
             .show(ctx, |ui| {
-                ui.label("Modifies the chart playback rate to be faster or");
-                ui.label("slower by a given percent.");
+                ui.label(tr!("rate-description"));
 
                 ui.horizontal(|ui| {
                     let button_vals: Vec<i32> = vec![-10, -5, -1, 100, 1, 5, 10];
                     for value in &button_vals {
                         let label = if *value == 100 {
-                            "Reset".to_string()
+                            tr!("rate-reset")
                         } else if *value > 0 {
                             format!("+{}%", value)
                         } else {
@@ -65,14 +65,14 @@ impl FreqTrainerMenu {
                 *lock_or_recover(&FREQ) = clamp(freq);
 
                 ui.separator();
-                ui.label("Controls");
+                ui.label(tr!("rate-controls"));
                 ui.indent("freq_controls", |ui| {
                     let mut enabled = *lock_or_recover(&FREQ_TRAINER_ENABLED);
                     ui.horizontal(|ui| {
-                        ui.checkbox(&mut enabled, "Rate Enabled");
+                        ui.checkbox(&mut enabled, tr!("rate-rate-enabled"));
                         crate::modmenu::imgui_renderer::ImGuiRenderer::help_marker(
                             ui,
-                            "When enabled positive rate scores will save locally, negative rate scores never save.",
+                            &tr!("rate-enabled-help"),
                         );
                     });
                     *lock_or_recover(&FREQ_TRAINER_ENABLED) = enabled;
@@ -2,6 +2,8 @@ use super::font_awesome_icons;
 use super::imgui_renderer;
 
 use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
+use std::collections::VecDeque;
 use std::fmt;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
@@ -16,6 +18,8 @@ pub const NOTIFY_OPACITY: f32 = 0.9;
 pub const NOTIFY_USE_SEPARATOR: bool = false;
 pub const NOTIFY_USE_DISMISS_BUTTON: bool = false;
 pub const NOTIFY_RENDER_LIMIT: usize = 7;
+/// Number of past toasts kept for the notification history window
+pub const NOTIFY_HISTORY_LIMIT: usize = 200;
 
 /// Maximum text wrap width as a fraction of window width (Java: windowWidth / 3.0f)
 const NOTIFY_TEXT_WRAP_FRACTION: f32 = 3.0;
@@ -32,6 +36,8 @@ pub const NOTIFICATION_POSITIONS: [&str; 7] = [
 
 static DEFAULT_TOAST_POS: Mutex<ToastPos> = Mutex::new(ToastPos::TopLeft);
 static NOTIFICATIONS: Mutex<Vec<Toast>> = Mutex::new(Vec::new());
+/// Every toast shown this session, oldest first
+static HISTORY: Mutex<VecDeque<Toast>> = Mutex::new(VecDeque::new());
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ToastType {
@@ -212,17 +218,17 @@ impl Toast {
         &self.title
     }
 
-    pub fn default_title(&self) -> Option<&str> {
+    pub fn default_title(&self) -> Option<String> {
         if self.title.is_empty() {
             match self.toast_type {
                 ToastType::None => None,
-                ToastType::Success => Some("Success"),
-                ToastType::Warning => Some("Warning"),
-                ToastType::Error => Some("Error"),
-                ToastType::Info => Some("Info"),
+                ToastType::Success => Some(tr!("toast-success")),
+                ToastType::Warning => Some(tr!("toast-warning")),
+                ToastType::Error => Some(tr!("toast-error")),
+                ToastType::Info => Some(tr!("toast-info")),
             }
         } else {
-            Some(&self.title)
+            Some(self.title.clone())
         }
     }
 
@@ -307,10 +313,20 @@ pub struct ImGuiNotify;
 
 impl ImGuiNotify {
     pub fn insert_notification(toast: Toast) {
+        push_history(&mut lock_or_recover(&HISTORY), toast.clone());
         let mut notifications = lock_or_recover(&NOTIFICATIONS);
         notifications.push(toast);
     }
 
+    /// Past toasts, newest first.
+    pub fn history() -> Vec<Toast> {
+        lock_or_recover(&HISTORY).iter().rev().cloned().collect()
+    }
+
+    pub fn clear_history() {
+        lock_or_recover(&HISTORY).clear();
+    }
+
     pub fn remove_notification(index: usize) {
         let mut notifications = lock_or_recover(&NOTIFICATIONS);
         if index < notifications.len() {
@@ -427,7 +443,7 @@ impl ImGuiNotify {
             let text_color = current_toast.color();
             let icon = current_toast.icon().map(|s| s.to_string());
             let title = current_toast.title().to_string();
-            let default_title = current_toast.default_title().map(|s| s.to_string());
+            let default_title = current_toast.default_title();
             let content = current_toast.content().to_string();
             let has_button = current_toast.has_on_button_press();
             let on_press_fn = current_toast.on_button_press.clone();
@@ -561,10 +577,34 @@ fn get_toast_pos(pos_type: &ToastPos, acc_y: f32) -> (f32, f32) {
     (init_pos.0, init_pos.1 + adjusted_acc_y)
 }
 
+fn push_history(history: &mut VecDeque<Toast>, toast: Toast) {
+    if history.len() >= NOTIFY_HISTORY_LIMIT {
+        history.pop_front();
+    }
+    history.push_back(toast);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_push_history_drops_oldest_over_limit() {
+        let mut history = VecDeque::new();
+        for i in 0..NOTIFY_HISTORY_LIMIT + 5 {
+            push_history(
+                &mut history,
+                Toast::with_content(ToastType::Info, i.to_string()),
+            );
+        }
+        assert_eq!(history.len(), NOTIFY_HISTORY_LIMIT);
+        assert_eq!(history.front().unwrap().content(), "5");
+        assert_eq!(
+            history.back().unwrap().content(),
+            (NOTIFY_HISTORY_LIMIT + 4).to_string()
+        );
+    }
+
     // ---- ToastPos tests ----
 
     #[test]
@@ -627,16 +667,16 @@ mod tests {
     #[test]
     fn test_toast_default_title_uses_type_name_when_title_empty() {
         let toast = Toast::new(ToastType::Success);
-        assert_eq!(toast.default_title(), Some("Success"));
+        assert_eq!(toast.default_title().as_deref(), Some("Success"));
 
         let toast = Toast::new(ToastType::Warning);
-        assert_eq!(toast.default_title(), Some("Warning"));
+        assert_eq!(toast.default_title().as_deref(), Some("Warning"));
 
         let toast = Toast::new(ToastType::Error);
-        assert_eq!(toast.default_title(), Some("Error"));
+        assert_eq!(toast.default_title().as_deref(), Some("Error"));
 
         let toast = Toast::new(ToastType::Info);
-        assert_eq!(toast.default_title(), Some("Info"));
+        assert_eq!(toast.default_title().as_deref(), Some("Info"));
 
         let toast = Toast::new(ToastType::None);
         assert_eq!(toast.default_title(), None);
@@ -646,7 +686,7 @@ mod tests {
     fn test_toast_default_title_uses_custom_title_when_set() {
         let mut toast = Toast::new(ToastType::Success);
         toast.title = "Custom Title".to_string();
-        assert_eq!(toast.default_title(), Some("Custom Title"));
+        assert_eq!(toast.default_title().as_deref(), Some("Custom Title"));
     }
 
     #[test]
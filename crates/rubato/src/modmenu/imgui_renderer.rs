@@ -1,15 +1,21 @@
+use super::controller_navigation::{self, MenuAction};
 use super::download_task_menu::DownloadTaskMenu;
 use super::freq_trainer_menu::FreqTrainerMenu;
 use super::imgui_notify::ImGuiNotify;
+use super::input_diagnostics_menu::InputDiagnosticsMenu;
+use super::ir_status_menu::IrStatusMenu;
 use super::judge_trainer_menu::JudgeTrainerMenu;
 use super::misc_setting_menu::MiscSettingMenu;
+use super::notification_history_menu::NotificationHistoryMenu;
 use super::performance_monitor::PerformanceMonitor;
+use super::practice_menu::PracticeMenu;
 use super::random_trainer_menu::RandomTrainerMenu;
 use super::skin_menu::SkinMenu;
 use super::skin_widget_manager::SkinWidgetManager;
 use super::{Version, version};
 
 use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
 use std::sync::Mutex;
 
 static WINDOW_WIDTH: Mutex<i32> = Mutex::new(0);
@@ -19,10 +25,16 @@ static SHOW_MOD_MENU: Mutex<bool> = Mutex::new(false);
 static SHOW_RANDOM_TRAINER: Mutex<bool> = Mutex::new(false);
 static SHOW_FREQ_PLUS: Mutex<bool> = Mutex::new(false);
 static SHOW_JUDGE_TRAINER: Mutex<bool> = Mutex::new(false);
+static SHOW_PRACTICE: Mutex<bool> = Mutex::new(false);
 static SHOW_SONG_MANAGER: Mutex<bool> = Mutex::new(false);
 static SHOW_DOWNLOAD_MENU: Mutex<bool> = Mutex::new(false);
 static SHOW_SKIN_WIDGET_MANAGER: Mutex<bool> = Mutex::new(false);
 static SHOW_PERFORMANCE_MONITOR: Mutex<bool> = Mutex::new(false);
+#[cfg(feature = "profiling")]
+static SHOW_PROFILER: Mutex<bool> = Mutex::new(false);
+static SHOW_INPUT_DIAGNOSTICS: Mutex<bool> = Mutex::new(false);
+static SHOW_IR_STATUS: Mutex<bool> = Mutex::new(false);
+static SHOW_NOTIFICATION_HISTORY: Mutex<bool> = Mutex::new(false);
 static SHOW_SKIN_MENU: Mutex<bool> = Mutex::new(false);
 static SHOW_MISC_SETTING: Mutex<bool> = Mutex::new(false);
 
@@ -65,59 +77,95 @@ impl ImGuiRenderer {
                 .default_pos(egui::pos2(rel_x, rel_y))
                 .auto_sized()
                 .show(ctx, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(tr!("modmenu-controller-navigation"));
+                        Self::help_marker(ui, &tr!("modmenu-controller-navigation-help"));
+                    });
+
                     // Sub-window toggle checkboxes
                     let mut freq = lock_or_recover(&SHOW_FREQ_PLUS);
-                    ui.checkbox(&mut freq, "Show Rate Modifier Window");
+                    ui.checkbox(&mut freq, tr!("modmenu-show-rate-modifier-window"));
                     drop(freq);
 
                     let mut random = lock_or_recover(&SHOW_RANDOM_TRAINER);
-                    ui.checkbox(&mut random, "Show Random Trainer Window");
+                    ui.checkbox(&mut random, tr!("modmenu-show-random-trainer-window"));
                     drop(random);
 
                     let mut judge = lock_or_recover(&SHOW_JUDGE_TRAINER);
-                    ui.checkbox(&mut judge, "Show Judge Trainer Window");
+                    ui.checkbox(&mut judge, tr!("modmenu-show-judge-trainer-window"));
                     drop(judge);
 
+                    let mut practice = lock_or_recover(&SHOW_PRACTICE);
+                    ui.checkbox(&mut practice, tr!("modmenu-show-practice-window"));
+                    drop(practice);
+
                     {
                         let mut skin = lock_or_recover(&SHOW_SKIN_MENU);
                         let old = *skin;
-                        ui.checkbox(&mut skin, "Show Skin Configuration Window");
+                        ui.checkbox(&mut skin, tr!("modmenu-show-skin-configuration-window"));
                         if *skin && !old {
                             SkinMenu::invalidate();
                         }
                     }
 
                     let mut swm = lock_or_recover(&SHOW_SKIN_WIDGET_MANAGER);
-                    ui.checkbox(&mut swm, "Show Skin Widget Manager Window");
+                    ui.checkbox(&mut swm, tr!("modmenu-show-skin-widget-manager-window"));
                     drop(swm);
 
                     let mut song = lock_or_recover(&SHOW_SONG_MANAGER);
-                    ui.checkbox(&mut song, "Show Song Manager Window");
+                    ui.checkbox(&mut song, tr!("modmenu-show-song-manager-window"));
                     drop(song);
 
                     let mut dl = lock_or_recover(&SHOW_DOWNLOAD_MENU);
-                    ui.checkbox(&mut dl, "Show Download Tasks Window");
+                    ui.checkbox(&mut dl, tr!("modmenu-show-download-tasks-window"));
                     drop(dl);
 
                     {
                         let mut perf = lock_or_recover(&SHOW_PERFORMANCE_MONITOR);
                         let old = *perf;
-                        ui.checkbox(&mut perf, "Show Performance Monitor Window");
+                        ui.checkbox(&mut perf, tr!("modmenu-show-performance-monitor-window"));
                         if *perf && !old {
                             PerformanceMonitor::reload_event_tree();
                         }
                     }
 
+                    #[cfg(feature = "profiling")]
+                    {
+                        let mut profiler = lock_or_recover(&SHOW_PROFILER);
+                        if ui
+                            .checkbox(&mut profiler, tr!("modmenu-show-profiler-window"))
+                            .changed()
+                            && !*profiler
+                        {
+                            super::profiler_menu::ProfilerMenu::stop();
+                        }
+                    }
+
+                    let mut input = lock_or_recover(&SHOW_INPUT_DIAGNOSTICS);
+                    ui.checkbox(&mut input, tr!("modmenu-show-input-diagnostics-window"));
+                    drop(input);
+
+                    let mut ir = lock_or_recover(&SHOW_IR_STATUS);
+                    ui.checkbox(&mut ir, tr!("modmenu-show-ir-status-window"));
+                    drop(ir);
+
+                    let mut history = lock_or_recover(&SHOW_NOTIFICATION_HISTORY);
+                    ui.checkbox(
+                        &mut history,
+                        tr!("modmenu-show-notification-history-window"),
+                    );
+                    drop(history);
+
                     let mut misc = lock_or_recover(&SHOW_MISC_SETTING);
-                    ui.checkbox(&mut misc, "Show Misc Setting Window");
+                    ui.checkbox(&mut misc, tr!("modmenu-show-misc-setting-window"));
                     drop(misc);
 
                     // Debug information
-                    ui.collapsing("Endless Dream Debug Information", |ui| {
+                    ui.collapsing(tr!("modmenu-endless-dream-debug-information"), |ui| {
                         let commit_hash = Version::git_commit_hash().unwrap_or("unknown");
                         let build_time = version::build_date().unwrap_or("unknown");
-                        ui.label(format!("Commit hash: {}", commit_hash));
-                        ui.label(format!("Build time: {}", build_time));
+                        ui.label(tr!("modmenu-commit-hash", hash = commit_hash));
+                        ui.label(tr!("modmenu-build-time", time = build_time));
                     });
                 });
             if !show {
@@ -134,6 +182,9 @@ impl ImGuiRenderer {
             if *lock_or_recover(&SHOW_JUDGE_TRAINER) {
                 JudgeTrainerMenu::show_ui(ctx);
             }
+            if *lock_or_recover(&SHOW_PRACTICE) {
+                PracticeMenu::show_ui(ctx);
+            }
             if *lock_or_recover(&SHOW_SONG_MANAGER) {
                 crate::modmenu::song_manager_menu::SongManagerMenu::show_ui(ctx);
             }
@@ -149,6 +200,19 @@ impl ImGuiRenderer {
             if *lock_or_recover(&SHOW_PERFORMANCE_MONITOR) {
                 PerformanceMonitor::show_ui(ctx);
             }
+            #[cfg(feature = "profiling")]
+            if *lock_or_recover(&SHOW_PROFILER) {
+                super::profiler_menu::ProfilerMenu::show_ui(ctx);
+            }
+            if *lock_or_recover(&SHOW_INPUT_DIAGNOSTICS) {
+                InputDiagnosticsMenu::show_ui(ctx);
+            }
+            if *lock_or_recover(&SHOW_IR_STATUS) {
+                IrStatusMenu::show_ui(ctx);
+            }
+            if *lock_or_recover(&SHOW_NOTIFICATION_HISTORY) {
+                NotificationHistoryMenu::show_ui(ctx);
+            }
             if *lock_or_recover(&SHOW_SKIN_MENU) {
                 SkinMenu::show_ui(ctx);
             }
@@ -162,6 +226,15 @@ impl ImGuiRenderer {
 
         // Render search text field overlay (select screen)
         crate::select::search_text_field::SearchTextField::render_egui(ctx);
+
+        // Render library scan progress (select screen)
+        crate::select::library_scan_indicator::LibraryScanIndicator::render_egui(ctx);
+
+        // Render statistics window (opened from the select screen)
+        super::stats_dashboard_menu::StatsDashboardMenu::show_ui(ctx);
+
+        // Render key configuration overlay (config screen)
+        crate::core::config_pkg::key_configuration::KeyConfiguration::render_egui(ctx);
     }
 
     /// Legacy render method — retained for backward compatibility with MainController stub calls.
@@ -180,6 +253,16 @@ impl ImGuiRenderer {
         *lock_or_recover(&SHOW_MOD_MENU)
     }
 
+    /// Whether the input diagnostics window is on screen.
+    pub fn is_input_diagnostics_shown() -> bool {
+        *lock_or_recover(&SHOW_MOD_MENU) && *lock_or_recover(&SHOW_INPUT_DIAGNOSTICS)
+    }
+
+    /// Whether the skin widget manager window is on screen.
+    pub fn is_skin_widget_manager_shown() -> bool {
+        *lock_or_recover(&SHOW_MOD_MENU) && *lock_or_recover(&SHOW_SKIN_WIDGET_MANAGER)
+    }
+
     pub fn toggle_menu() {
         let mut menu = lock_or_recover(&SHOW_MOD_MENU);
         *menu = !*menu;
@@ -193,3 +276,21 @@ impl ImGuiRenderer {
             .on_hover_text(desc);
     }
 }
+
+impl crate::imgui_access::ImGuiAccess for ImGuiRenderer {
+    fn toggle_menu(&mut self) {
+        ImGuiRenderer::toggle_menu();
+    }
+
+    fn is_menu_shown(&self) -> bool {
+        ImGuiRenderer::get_show_mod_menu()
+    }
+
+    fn navigate(&mut self, action: MenuAction) {
+        if action == MenuAction::Close {
+            *lock_or_recover(&SHOW_MOD_MENU) = false;
+        } else {
+            controller_navigation::push_action(action);
+        }
+    }
+}
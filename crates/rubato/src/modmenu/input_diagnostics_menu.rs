@@ -0,0 +1,143 @@
+use crate::input::bms_player_input_device::DeviceType;
+use crate::input::input_diagnostics::KeyActivity;
+use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
+use std::sync::Mutex;
+
+/// Per-key chatter counts published by MainController while the window is shown.
+static CHATTER_COUNTS: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+/// Live input statistics published by MainController while the window is shown.
+static INPUT_STATS: Mutex<Option<InputStats>> = Mutex::new(None);
+static RESET_REQUESTED: Mutex<bool> = Mutex::new(false);
+
+/// Snapshot of BMSPlayerInputProcessor diagnostics for one frame.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputStats {
+    /// Keys with at least one event, with their activity.
+    pub keys: Vec<(usize, KeyActivity)>,
+    /// Monotonic time (us) the snapshot was taken.
+    pub now: i64,
+    /// Presses per second on the scratch lanes of the current mode.
+    pub scratch_rate: f32,
+    /// Average and longest input poll interval (us).
+    pub poll_interval: Option<(i64, i64)>,
+}
+
+pub struct InputDiagnosticsMenu;
+
+impl InputDiagnosticsMenu {
+    /// Render the input diagnostics window using egui.
+    pub fn show_ui(ctx: &egui::Context) {
+        let mut counts: Vec<(usize, u32)> = lock_or_recover(&CHATTER_COUNTS)
+            .iter()
+            .enumerate()
+            .filter(|&(_, &count)| count > 0)
+            .map(|(key, &count)| (key, count))
+            .collect();
+        counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        let stats = lock_or_recover(&INPUT_STATS).clone().unwrap_or_default();
+
+        egui::Window::new(tr!("input-diag-input-diagnostics"))
+            .auto_sized()
+            .show(ctx, |ui| {
+                match stats.poll_interval {
+                    Some((average, max)) => ui.label(tr!(
+                        "input-diag-polling-interval-stats",
+                        average = format!("{:.2}", average as f64 / 1000.0),
+                        max = format!("{:.2}", max as f64 / 1000.0)
+                    )),
+                    None => ui.label(tr!("input-diag-polling-interval")),
+                };
+                ui.label(tr!(
+                    "input-diag-scratch-rate",
+                    rate = format!("{:.1}", stats.scratch_rate)
+                ));
+                ui.separator();
+
+                if stats.keys.is_empty() {
+                    ui.label(tr!("input-diag-press-a-key"));
+                } else {
+                    egui::Grid::new("key_activity_grid")
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.label(tr!("input-diag-key"));
+                            ui.label(tr!("input-diag-state"));
+                            ui.label(tr!("input-diag-presses"));
+                            ui.label(tr!("input-diag-device"));
+                            ui.label(tr!("input-diag-last-event"));
+                            ui.end_row();
+                            for (key, activity) in &stats.keys {
+                                ui.label(format!("{}", key + 1));
+                                ui.label(if activity.pressed {
+                                    tr!("input-diag-on")
+                                } else {
+                                    tr!("input-diag-off")
+                                });
+                                ui.label(format!("{}", activity.presses));
+                                ui.label(device_label(activity.device));
+                                ui.label(match activity.last_event {
+                                    Some(time) => {
+                                        tr!(
+                                            "input-diag-ms-ago",
+                                            ms = (stats.now - time).max(0) / 1000
+                                        )
+                                    }
+                                    None => "---".to_string(),
+                                });
+                                ui.end_row();
+                            }
+                        });
+                }
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    ui.label(tr!("input-diag-key-chatter"));
+                    super::imgui_renderer::ImGuiRenderer::help_marker(
+                        ui,
+                        &tr!("input-diag-key-chatter-help"),
+                    );
+                });
+                if counts.is_empty() {
+                    ui.label(tr!("input-diag-no-chatter-detected"));
+                } else {
+                    egui::Grid::new("key_chatter_grid").show(ui, |ui| {
+                        ui.label(tr!("input-diag-key"));
+                        ui.label(tr!("input-diag-suppressed"));
+                        ui.end_row();
+                        for (key, count) in &counts {
+                            ui.label(format!("{}", key + 1));
+                            ui.label(format!("{}", count));
+                            ui.end_row();
+                        }
+                    });
+                }
+                if ui.button(tr!("input-diag-reset")).clicked() {
+                    *lock_or_recover(&RESET_REQUESTED) = true;
+                }
+            });
+    }
+
+    pub fn update_chatter_counts(counts: &[u32]) {
+        let mut published = lock_or_recover(&CHATTER_COUNTS);
+        published.clear();
+        published.extend_from_slice(counts);
+    }
+
+    pub fn update_input_stats(stats: InputStats) {
+        *lock_or_recover(&INPUT_STATS) = Some(stats);
+    }
+
+    /// Whether Reset was clicked since the last call.
+    pub fn take_reset_request() -> bool {
+        std::mem::take(&mut *lock_or_recover(&RESET_REQUESTED))
+    }
+}
+
+fn device_label(device: Option<DeviceType>) -> String {
+    match device {
+        Some(DeviceType::Keyboard) => tr!("input-diag-keyboard"),
+        Some(DeviceType::BmController) => tr!("input-diag-controller"),
+        Some(DeviceType::Midi) => "MIDI".to_string(),
+        None => "---".to_string(),
+    }
+}
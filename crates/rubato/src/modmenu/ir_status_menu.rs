@@ -0,0 +1,130 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::ir::ir_connection_manager::{IRConnectionManager, IRSubmissionResult};
+use crate::result::ir_resend;
+use crate::tr;
+
+pub struct IrStatusMenu;
+
+impl IrStatusMenu {
+    /// Render the IR status window using egui.
+    pub fn show_ui(ctx: &egui::Context) {
+        let mut open = true;
+        egui::Window::new(tr!("ir-status-ir-status"))
+            .open(&mut open)
+            .auto_sized()
+            .show(ctx, |ui| {
+                let connected = IRConnectionManager::connected();
+                if connected.is_empty() {
+                    ui.label(tr!("ir-status-not-connected"));
+                } else {
+                    egui::Grid::new("ir_status_endpoints")
+                        .num_columns(3)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.strong(tr!("ir-status-ir"));
+                            ui.strong(tr!("ir-status-player"));
+                            ui.strong(tr!("ir-status-home"));
+                            ui.end_row();
+                            for endpoint in &connected {
+                                ui.label(&endpoint.name);
+                                ui.label(&endpoint.player);
+                                match endpoint.home {
+                                    Some(ref home) => {
+                                        ui.hyperlink(home);
+                                    }
+                                    None => {
+                                        ui.label("-");
+                                    }
+                                }
+                                ui.end_row();
+                            }
+                        });
+                }
+
+                ui.separator();
+                let pending = ir_resend::pending_submission_count();
+                ui.horizontal(|ui| {
+                    ui.label(tr!("ir-status-pending", count = pending));
+                    if ui
+                        .add_enabled(pending > 0, egui::Button::new(tr!("ir-status-retry-now")))
+                        .clicked()
+                    {
+                        ir_resend::request_retry_now();
+                    }
+                });
+
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis() as i64;
+                ui.label(tr!(
+                    "ir-status-last-submission",
+                    submission =
+                        submission_text(IRConnectionManager::last_submission().as_ref(), now)
+                ));
+            });
+    }
+}
+
+/// One-line summary of the last submission.
+pub fn submission_text(result: Option<&IRSubmissionResult>, now: i64) -> String {
+    let Some(result) = result else {
+        return tr!("ir-status-submission-none");
+    };
+    let outcome = if result.succeeded {
+        tr!("ir-status-sent")
+    } else if result.message.is_empty() {
+        tr!("ir-status-failed")
+    } else {
+        tr!("ir-status-failed-reason", reason = result.message.as_str())
+    };
+    tr!(
+        "ir-status-submission",
+        title = result.title.as_str(),
+        ir = result.ir.as_str(),
+        outcome = outcome,
+        age = format_age((now - result.time).max(0) / 1000)
+    )
+}
+
+/// Short "n s/m/h ago" text for an age in seconds.
+pub(super) fn format_age(secs: i64) -> String {
+    if secs < 60 {
+        tr!("age-seconds", count = secs)
+    } else if secs < 3600 {
+        tr!("age-minutes", count = secs / 60)
+    } else {
+        tr!("age-hours", count = secs / 3600)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn submission_text_describes_outcome_and_age() {
+        assert_eq!(submission_text(None, 0), "none");
+
+        let mut result = IRSubmissionResult {
+            ir: "LR2IR".to_string(),
+            title: "Song".to_string(),
+            succeeded: true,
+            message: "OK".to_string(),
+            time: 10_000,
+        };
+        assert_eq!(
+            submission_text(Some(&result), 15_000),
+            "Song to LR2IR sent, 5s ago"
+        );
+
+        result.succeeded = false;
+        result.message = "timeout".to_string();
+        assert_eq!(
+            submission_text(Some(&result), 10_000 + 125_000),
+            "Song to LR2IR failed (timeout), 2m ago"
+        );
+        assert_eq!(format_age(7200), "2h ago");
+    }
+}
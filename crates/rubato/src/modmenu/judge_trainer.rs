@@ -8,6 +8,8 @@ pub const JUDGE_OPTIONS: [&str; 4] = ["EASY", "NORMAL", "HARD", "VERY_HARD"];
 
 static ACTIVE: Mutex<bool> = Mutex::new(false);
 static JUDGE_RANK: Mutex<i32> = Mutex::new(0);
+/// Window scale applied by the running play, relative to the chart's own judge.
+static APPLIED_SCALE: Mutex<Option<f64>> = Mutex::new(None);
 
 pub struct JudgeTrainer;
 
@@ -41,4 +43,14 @@ impl JudgeTrainer {
         let rule = BMSPlayerRule::for_mode(mode);
         rule.judge.windowrule.judgerank[(3 - judge_rank) as usize]
     }
+
+    /// Scale of the judge windows currently applied by the player, relative to
+    /// the chart's own judge. `None` when no play is using the trainer.
+    pub fn applied_scale() -> Option<f64> {
+        *lock_or_recover(&APPLIED_SCALE)
+    }
+
+    pub fn set_applied_scale(scale: Option<f64>) {
+        *lock_or_recover(&APPLIED_SCALE) = scale;
+    }
 }
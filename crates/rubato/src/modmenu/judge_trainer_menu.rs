@@ -1,6 +1,7 @@
 use super::judge_trainer::JudgeTrainer;
 
 use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
 use std::sync::Mutex;
 
 static OVERRIDE_CHART_JUDGE: Mutex<bool> = Mutex::new(false);
@@ -12,13 +13,13 @@ impl JudgeTrainerMenu {
     /// Render the judge trainer window using egui.
     pub fn show_ui(ctx: &egui::Context) {
         let mut open = true;
-        egui::Window::new("Judge Trainer")
+        egui::Window::new(tr!("judge-trainer-judge-trainer"))
             .open(&mut open)
             .auto_sized()
             .show(ctx, |ui| {
                 let mut override_judge = *lock_or_recover(&OVERRIDE_CHART_JUDGE);
                 if ui
-                    .checkbox(&mut override_judge, "Override chart's judge")
+                    .checkbox(&mut override_judge, tr!("judge-trainer-override-judge"))
                     .changed()
                 {
                     *lock_or_recover(&OVERRIDE_CHART_JUDGE) = override_judge;
@@ -31,7 +32,7 @@ impl JudgeTrainerMenu {
                     .get(rank as usize)
                     .copied()
                     .unwrap_or("Unknown");
-                egui::ComboBox::from_label("judge")
+                egui::ComboBox::from_label(tr!("judge-trainer-judge"))
                     .selected_text(selected_text)
                     .show_ui(ui, |ui| {
                         for (i, option) in judge_options.iter().enumerate() {
@@ -41,6 +42,19 @@ impl JudgeTrainerMenu {
                             }
                         }
                     });
+
+                // Windows of the running play, relative to the chart's own judge
+                match JudgeTrainer::applied_scale() {
+                    Some(scale) => {
+                        ui.label(tr!(
+                            "judge-trainer-judge-windows-scaled",
+                            scale = format!("{scale:.2}")
+                        ));
+                    }
+                    None => {
+                        ui.label(tr!("judge-trainer-judge-windows-default"));
+                    }
+                }
             });
         if !open {
             // Window closed
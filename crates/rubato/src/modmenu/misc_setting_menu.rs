@@ -5,6 +5,7 @@ use super::{Config, PlayConfig, PlayerConfig, read_all_player_id};
 
 use crate::core::command::Command;
 use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
 use std::sync::{Arc, Mutex};
 use std::thread::ThreadId;
 
@@ -142,7 +143,7 @@ impl MiscSettingMenu {
         }
 
         let mut open = true;
-        egui::Window::new("Misc Settings")
+        egui::Window::new(tr!("misc-misc-settings"))
             .open(&mut open)
             .auto_sized()
             .show(ctx, |ui| {
@@ -152,7 +153,7 @@ impl MiscSettingMenu {
                     .get(pos as usize)
                     .copied()
                     .unwrap_or("TopLeft");
-                egui::ComboBox::from_label("Notification Positions")
+                egui::ComboBox::from_label(tr!("misc-notification-positions"))
                     .selected_text(pos_text)
                     .show_ui(ui, |ui| {
                         for (i, name) in NOTIFICATION_POSITIONS.iter().enumerate() {
@@ -170,7 +171,7 @@ impl MiscSettingMenu {
                     .get(idx as usize)
                     .map(|s| s.as_str())
                     .unwrap_or("BEAT_7K");
-                egui::ComboBox::from_label("Play Mode")
+                egui::ComboBox::from_label(tr!("misc-play-mode"))
                     .selected_text(mode_text)
                     .show_ui(ui, |ui| {
                         for (i, option) in play_mode_options.iter().enumerate() {
@@ -194,14 +195,17 @@ impl MiscSettingMenu {
                 let mut dirty = false;
 
                 let mut lift_enabled = *lock_or_recover(&ENABLE_LIFT);
-                if ui.checkbox(&mut lift_enabled, "Enable Lift").changed() {
+                if ui
+                    .checkbox(&mut lift_enabled, tr!("misc-enable-lift"))
+                    .changed()
+                {
                     *lock_or_recover(&ENABLE_LIFT) = lift_enabled;
                     dirty = true;
                 }
                 if lift_enabled {
                     let mut lift_val = *lock_or_recover(&LIFT_VALUE);
                     if ui
-                        .add(egui::Slider::new(&mut lift_val, 0..=1000).text("Lift"))
+                        .add(egui::Slider::new(&mut lift_val, 0..=1000).text(tr!("misc-lift")))
                         .changed()
                     {
                         *lock_or_recover(&LIFT_VALUE) = lift_val;
@@ -210,14 +214,17 @@ impl MiscSettingMenu {
                 }
 
                 let mut hidden_enabled = *lock_or_recover(&ENABLE_HIDDEN);
-                if ui.checkbox(&mut hidden_enabled, "Enable Hidden").changed() {
+                if ui
+                    .checkbox(&mut hidden_enabled, tr!("misc-enable-hidden"))
+                    .changed()
+                {
                     *lock_or_recover(&ENABLE_HIDDEN) = hidden_enabled;
                     dirty = true;
                 }
                 if hidden_enabled {
                     let mut hidden_val = *lock_or_recover(&HIDDEN_VALUE);
                     if ui
-                        .add(egui::Slider::new(&mut hidden_val, 0..=1000).text("Hidden"))
+                        .add(egui::Slider::new(&mut hidden_val, 0..=1000).text(tr!("misc-hidden")))
                         .changed()
                     {
                         *lock_or_recover(&HIDDEN_VALUE) = hidden_val;
@@ -226,14 +233,17 @@ impl MiscSettingMenu {
                 }
 
                 let mut lc_enabled = *lock_or_recover(&ENABLE_LANECOVER);
-                if ui.checkbox(&mut lc_enabled, "Enable Lane Cover").changed() {
+                if ui
+                    .checkbox(&mut lc_enabled, tr!("misc-enable-lane-cover"))
+                    .changed()
+                {
                     *lock_or_recover(&ENABLE_LANECOVER) = lc_enabled;
                     dirty = true;
                 }
                 if lc_enabled {
                     let mut lc_val = *lock_or_recover(&LANECOVER_VALUE);
                     if ui
-                        .add(egui::Slider::new(&mut lc_val, 0..=1000).text("Lane Cover"))
+                        .add(egui::Slider::new(&mut lc_val, 0..=1000).text(tr!("misc-lane-cover")))
                         .changed()
                     {
                         *lock_or_recover(&LANECOVER_VALUE) = lc_val;
@@ -242,7 +252,10 @@ impl MiscSettingMenu {
                 }
 
                 let mut constant = *lock_or_recover(&ENABLE_CONSTANT);
-                if ui.checkbox(&mut constant, "Enable Constant").changed() {
+                if ui
+                    .checkbox(&mut constant, tr!("misc-enable-constant"))
+                    .changed()
+                {
                     *lock_or_recover(&ENABLE_CONSTANT) = constant;
                     dirty = true;
                 }
@@ -251,7 +264,7 @@ impl MiscSettingMenu {
                     if ui
                         .add(
                             egui::Slider::new(&mut constant_val, 0..=5000)
-                                .text("Fade-in Time (ms)"),
+                                .text(tr!("misc-fade-in-time")),
                         )
                         .changed()
                     {
@@ -399,15 +412,15 @@ fn profile_switcher_ui(ui: &mut egui::Ui) {
                     }
                 });
 
-            switch_clicked = ui.button("Switch").clicked();
+            switch_clicked = ui.button(tr!("misc-switch")).clicked();
             if switch_clicked {
                 let sel = selected as usize;
                 if sel < players.len() {
                     switch_player_id = Some(players[sel].clone());
                 }
             }
-            reload_clicked = ui.button("Reload list").clicked();
-            ui.label("Player Profile");
+            reload_clicked = ui.button(tr!("misc-reload-list")).clicked();
+            ui.label(tr!("misc-player-profile"));
         });
     }
 
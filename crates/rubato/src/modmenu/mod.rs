@@ -42,18 +42,26 @@ pub use clipboard_wrapper::Clipboard;
 pub use imgui_surrogates::{ImBoolean, ImFloat};
 pub use modmenu_skin::{Skin, SkinObject, SkinObjectDestination};
 
+pub mod controller_navigation;
 pub mod download_task_menu;
 pub mod download_task_state;
 pub mod font_awesome_icons;
 pub mod freq_trainer_menu;
 pub mod imgui_notify;
 pub mod imgui_renderer;
+pub mod input_diagnostics_menu;
+pub mod ir_status_menu;
 pub mod judge_trainer;
 pub mod judge_trainer_menu;
 pub mod misc_setting_menu;
+pub mod notification_history_menu;
 pub mod performance_monitor;
+pub mod practice_menu;
+#[cfg(feature = "profiling")]
+pub mod profiler_menu;
 pub mod random_trainer;
 pub mod random_trainer_menu;
 pub mod skin_menu;
 pub mod skin_widget_manager;
 pub mod song_manager_menu;
+pub mod stats_dashboard_menu;
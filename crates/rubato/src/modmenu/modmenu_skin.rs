@@ -10,6 +10,13 @@ pub struct Skin {
 }
 
 impl Skin {
+    pub fn new(objects: Vec<SkinObject>) -> Self {
+        Self {
+            header: SkinHeader::default(),
+            objects,
+        }
+    }
+
     pub fn all_skin_objects(&self) -> &[SkinObject] {
         &self.objects
     }
@@ -0,0 +1,129 @@
+use std::sync::Mutex;
+
+use super::imgui_notify::{ImGuiNotify, ToastType};
+use super::ir_status_menu::format_age;
+use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
+
+static FILTER: Mutex<SeverityFilter> = Mutex::new(SeverityFilter::ALL);
+
+/// Severities shown in the notification history window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SeverityFilter {
+    pub info: bool,
+    pub success: bool,
+    pub warning: bool,
+    pub error: bool,
+}
+
+impl SeverityFilter {
+    pub const ALL: Self = Self {
+        info: true,
+        success: true,
+        warning: true,
+        error: true,
+    };
+
+    /// Untyped toasts are shown together with info.
+    pub fn accepts(&self, toast_type: ToastType) -> bool {
+        match toast_type {
+            ToastType::None | ToastType::Info => self.info,
+            ToastType::Success => self.success,
+            ToastType::Warning => self.warning,
+            ToastType::Error => self.error,
+        }
+    }
+}
+
+pub struct NotificationHistoryMenu;
+
+impl NotificationHistoryMenu {
+    /// Render the notification history window using egui.
+    pub fn show_ui(ctx: &egui::Context) {
+        let mut open = true;
+        egui::Window::new(tr!("notifications-notifications"))
+            .open(&mut open)
+            .default_width(420.0)
+            .show(ctx, |ui| {
+                let mut filter = lock_or_recover(&FILTER);
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut filter.info, tr!("notifications-info"));
+                    ui.checkbox(&mut filter.success, tr!("notifications-success"));
+                    ui.checkbox(&mut filter.warning, tr!("notifications-warning"));
+                    ui.checkbox(&mut filter.error, tr!("notifications-error"));
+                    if ui.button(tr!("notifications-clear")).clicked() {
+                        ImGuiNotify::clear_history();
+                    }
+                });
+                let filter = *filter;
+
+                ui.separator();
+                let history = ImGuiNotify::history();
+                let mut shown = history
+                    .iter()
+                    .filter(|toast| filter.accepts(*toast.toast_type()))
+                    .peekable();
+                if shown.peek().is_none() {
+                    ui.label(tr!("notifications-no-notifications"));
+                    return;
+                }
+                egui::ScrollArea::vertical()
+                    .max_height(400.0)
+                    .show(ui, |ui| {
+                        for (i, toast) in shown.enumerate() {
+                            let [r, g, b, _] = toast.color();
+                            let color = egui::Color32::from_rgb(
+                                (r * 255.0) as u8,
+                                (g * 255.0) as u8,
+                                (b * 255.0) as u8,
+                            );
+                            ui.push_id(i, |ui| {
+                                ui.horizontal(|ui| {
+                                    if let Some(icon) = toast.icon() {
+                                        ui.label(egui::RichText::new(icon).color(color));
+                                    }
+                                    if let Some(title) = toast.default_title() {
+                                        ui.label(egui::RichText::new(title).color(color).strong());
+                                    }
+                                    ui.weak(format_age(
+                                        toast.creation_time.elapsed().as_secs() as i64
+                                    ));
+                                    if toast.has_on_button_press()
+                                        && !toast.button_label().is_empty()
+                                        && ui.small_button(toast.button_label()).clicked()
+                                        && let Some(ref callback) = toast.on_button_press
+                                    {
+                                        callback();
+                                    }
+                                });
+                                if !toast.content().is_empty() {
+                                    ui.label(toast.content());
+                                }
+                            });
+                            ui.separator();
+                        }
+                    });
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn severity_filter_accepts_enabled_types() {
+        let filter = SeverityFilter {
+            info: true,
+            success: false,
+            warning: false,
+            error: true,
+        };
+        assert!(filter.accepts(ToastType::Info));
+        assert!(filter.accepts(ToastType::None));
+        assert!(!filter.accepts(ToastType::Success));
+        assert!(!filter.accepts(ToastType::Warning));
+        assert!(filter.accepts(ToastType::Error));
+        assert!(SeverityFilter::ALL.accepts(ToastType::Warning));
+    }
+}
@@ -1,10 +1,19 @@
-use crate::core::performance_metrics::{EventResult, PerformanceMetrics};
+use crate::core::performance_metrics::{
+    EventResult, PerformanceMetrics, SAMPLE_AUDIO_CALLBACK_LOAD, WATCH_FRAME_TIME,
+    WATCH_JUDGE_LATENCY, slowest_average,
+};
 
 use crate::skin::sync_utils::lock_or_recover;
-use std::collections::HashMap;
+use crate::tr;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Mutex;
 use std::time::Instant;
 
+/// Frames kept for the frame-time graph and the 1%/0.1% lows
+const FRAME_HISTORY_LEN: usize = 1000;
+/// Frame time at the top of the graph (ms)
+const GRAPH_MAX_MS: f32 = 33.3;
+
 static EVENT_TREE: Mutex<Option<HashMap<u64, Vec<EventResult>>>> = Mutex::new(None);
 static LAST_EVENT_UPDATE: Mutex<Option<Instant>> = Mutex::new(None);
 
@@ -14,6 +23,54 @@ struct WatchStats {
 }
 
 static WATCH_DATA: Mutex<Vec<(String, WatchStats)>> = Mutex::new(Vec::new());
+static FRAME_HISTORY: Mutex<FrameHistory> = Mutex::new(FrameHistory::new());
+
+/// Frame times collected from the frame watch records, which only keep 3 seconds.
+struct FrameHistory {
+    /// Frame times in ms, oldest first
+    frame_times: VecDeque<f64>,
+    /// Start time of the newest record taken
+    last_time: i64,
+}
+
+impl FrameHistory {
+    const fn new() -> Self {
+        Self {
+            frame_times: VecDeque::new(),
+            last_time: i64::MIN,
+        }
+    }
+
+    /// Append the records newer than the ones already taken.
+    fn append(&mut self, records: &VecDeque<(i64, i64)>) {
+        for &(time, duration) in records {
+            if time <= self.last_time {
+                continue;
+            }
+            self.last_time = time;
+            self.frame_times.push_back(duration as f64 / 1_000_000.0);
+        }
+        while self.frame_times.len() > FRAME_HISTORY_LEN {
+            self.frame_times.pop_front();
+        }
+    }
+
+    fn stats(&self) -> Option<FrameStats> {
+        let times: Vec<f64> = self.frame_times.iter().copied().collect();
+        Some(FrameStats {
+            avg: times.iter().sum::<f64>() / times.len().max(1) as f64,
+            low_1: slowest_average(&times, 0.01)?,
+            low_01: slowest_average(&times, 0.001)?,
+        })
+    }
+}
+
+/// Frame-time summary in ms; the lows are the average of the slowest frames.
+struct FrameStats {
+    avg: f64,
+    low_1: f64,
+    low_01: f64,
+}
 
 pub static FILTER_SHORT_THRESHOLD: Mutex<f32> = Mutex::new(1.0);
 static SORT_BY_DURATION: Mutex<bool> = Mutex::new(false);
@@ -34,22 +91,85 @@ impl PerformanceMonitor {
                 drop(last_update);
                 *lock_or_recover(&LAST_EVENT_UPDATE) = Some(now);
                 Self::reload_event_tree();
+                Self::reload_watch_data();
             }
         }
 
         let mut open = true;
-        egui::Window::new("Performance Monitor")
+        egui::Window::new(tr!("perf-performance-monitor"))
             .open(&mut open)
             .show(ctx, |ui| {
-                ui.collapsing("Watch", |ui| {
+                ui.collapsing(tr!("perf-frames"), |ui| {
+                    let history = lock_or_recover(&FRAME_HISTORY);
+                    let Some(stats) = history.stats() else {
+                        ui.label(tr!("perf-no-frame-data"));
+                        return;
+                    };
+                    frame_time_graph(ui, &history.frame_times);
+                    egui::Grid::new("frame_grid").show(ui, |ui| {
+                        for (name, ms) in [
+                            (tr!("perf-average"), stats.avg),
+                            (tr!("perf-low-1"), stats.low_1),
+                            (tr!("perf-low-01"), stats.low_01),
+                        ] {
+                            ui.label(name);
+                            ui.label(format!("{:.2} ms", ms));
+                            ui.label(format!("{:.0} fps", 1000.0 / ms.max(0.001)));
+                            ui.end_row();
+                        }
+                    });
+                });
+
+                ui.collapsing(tr!("perf-audio-judge"), |ui| {
+                    let metrics = PerformanceMetrics::get();
+                    let loads: Vec<f64> = metrics
+                        .get_sample_records(SAMPLE_AUDIO_CALLBACK_LOAD)
+                        .map(|r| r.iter().map(|&(_, load)| load).collect())
+                        .unwrap_or_default();
+                    ui.label(tr!(
+                        "perf-audio-underruns",
+                        count = metrics.audio_underruns()
+                    ));
+                    if loads.is_empty() {
+                        ui.label(tr!("perf-audio-callback-load-none"));
+                    } else {
+                        ui.label(tr!(
+                            "perf-audio-callback-load",
+                            average = format!(
+                                "{:.0}",
+                                loads.iter().sum::<f64>() / loads.len() as f64 * 100.0
+                            ),
+                            max =
+                                format!("{:.0}", loads.iter().copied().fold(0.0, f64::max) * 100.0)
+                        ));
+                    }
+                    let latency: Vec<f64> = metrics
+                        .get_watch_records(WATCH_JUDGE_LATENCY)
+                        .map(|r| r.iter().map(|&(_, d)| d as f64 / 1_000_000.0).collect())
+                        .unwrap_or_default();
+                    if latency.is_empty() {
+                        ui.label(tr!("perf-judge-latency-none"));
+                    } else {
+                        ui.label(tr!(
+                            "perf-judge-latency",
+                            average = format!(
+                                "{:.2}",
+                                latency.iter().sum::<f64>() / latency.len() as f64
+                            ),
+                            max = format!("{:.2}", latency.iter().copied().fold(0.0, f64::max))
+                        ));
+                    }
+                });
+
+                ui.collapsing(tr!("perf-watch"), |ui| {
                     let watch_data = lock_or_recover(&WATCH_DATA);
                     if watch_data.is_empty() {
-                        ui.label("No watch data");
+                        ui.label(tr!("perf-no-watch-data"));
                     } else {
                         egui::Grid::new("watch_grid").show(ui, |ui| {
-                            ui.label("Name");
-                            ui.label("Avg (ms)");
-                            ui.label("Std (ms)");
+                            ui.label(tr!("perf-name"));
+                            ui.label(tr!("perf-avg-ms"));
+                            ui.label(tr!("perf-std-ms"));
                             ui.end_row();
                             for (name, stats) in watch_data.iter() {
                                 ui.label(name);
@@ -61,30 +181,50 @@ impl PerformanceMonitor {
                     }
                 });
 
-                ui.collapsing("Events", |ui| {
+                ui.collapsing(tr!("perf-events"), |ui| {
                     let tree = lock_or_recover(&EVENT_TREE);
                     if let Some(ref tree) = *tree {
                         let threshold = *lock_or_recover(&FILTER_SHORT_THRESHOLD);
                         ui.horizontal(|ui| {
-                            ui.label("Filter threshold (ms):");
+                            ui.label(tr!("perf-filter-threshold-ms"));
                             let mut t = threshold;
                             ui.add(egui::DragValue::new(&mut t).speed(0.1));
                             *lock_or_recover(&FILTER_SHORT_THRESHOLD) = t;
                         });
                         ui.horizontal(|ui| {
                             let mut sort = *lock_or_recover(&SORT_BY_DURATION);
-                            ui.checkbox(&mut sort, "Sort by duration");
+                            ui.checkbox(&mut sort, tr!("perf-sort-by-duration"));
                             *lock_or_recover(&SORT_BY_DURATION) = sort;
                         });
                         // Render event tree recursively
                         render_event_tree_ui(ui, tree, 0, threshold);
                     } else {
-                        ui.label("No event data");
+                        ui.label(tr!("perf-no-event-data"));
                     }
                 });
             });
     }
 
+    /// Refresh the watch statistics and the frame-time history.
+    pub fn reload_watch_data() {
+        let metrics = PerformanceMetrics::get();
+        let mut names = metrics.watch_names();
+        names.sort();
+        let mut watch_data = Vec::with_capacity(names.len());
+        for name in names {
+            let Some(records) = metrics.get_watch_records(&name) else {
+                continue;
+            };
+            if name == WATCH_FRAME_TIME {
+                lock_or_recover(&FRAME_HISTORY).append(&records);
+            }
+            if let Some(stats) = watch_stats(&records) {
+                watch_data.push((name, stats));
+            }
+        }
+        *lock_or_recover(&WATCH_DATA) = watch_data;
+    }
+
     pub fn reload_event_tree() {
         // copy the vector to avoid constantly reading the events while other threads might be writing
         let mut new_tree: HashMap<u64, Vec<EventResult>> = HashMap::new();
@@ -103,6 +243,52 @@ impl PerformanceMonitor {
     }
 }
 
+/// Mean and standard deviation in ms of watch record durations.
+fn watch_stats(records: &VecDeque<(i64, i64)>) -> Option<WatchStats> {
+    if records.is_empty() {
+        return None;
+    }
+    let n = records.len() as f64;
+    let avg = records.iter().map(|&(_, d)| d as f64).sum::<f64>() / n;
+    let var = records
+        .iter()
+        .map(|&(_, d)| (d as f64 - avg).powi(2))
+        .sum::<f64>()
+        / n;
+    Some(WatchStats {
+        avg: (avg / 1_000_000.0) as f32,
+        std: (var.sqrt() / 1_000_000.0) as f32,
+    })
+}
+
+/// Rolling frame-time graph, newest frame on the right.
+fn frame_time_graph(ui: &mut egui::Ui, frame_times: &VecDeque<f64>) {
+    let size = egui::vec2(FRAME_HISTORY_LEN as f32 * 0.4, 80.0);
+    let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 0.0, egui::Color32::from_gray(20));
+    let y = |ms: f32| rect.max.y - (ms / GRAPH_MAX_MS).min(1.0) * rect.height();
+    // 60 and 120 fps guides
+    for ms in [1000.0 / 60.0, 1000.0 / 120.0] {
+        painter.hline(
+            rect.x_range(),
+            y(ms),
+            egui::Stroke::new(1.0, egui::Color32::from_gray(70)),
+        );
+    }
+    let step = rect.width() / FRAME_HISTORY_LEN as f32;
+    let start = rect.max.x - frame_times.len() as f32 * step;
+    let points: Vec<egui::Pos2> = frame_times
+        .iter()
+        .enumerate()
+        .map(|(i, &ms)| egui::pos2(start + i as f32 * step, y(ms as f32)))
+        .collect();
+    painter.add(egui::Shape::line(
+        points,
+        egui::Stroke::new(1.0, egui::Color32::LIGHT_GREEN),
+    ));
+}
+
 /// Render events for the given parent group as a recursive collapsible tree.
 fn render_event_tree_ui(
     ui: &mut egui::Ui,
@@ -137,3 +323,46 @@ fn render_event_tree_ui(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_history_appends_only_new_records_and_caps_length() {
+        let mut history = FrameHistory::new();
+        assert!(history.stats().is_none());
+
+        let records: VecDeque<(i64, i64)> = (0..10).map(|i| (i, 10_000_000)).collect();
+        history.append(&records);
+        history.append(&records);
+        assert_eq!(history.frame_times.len(), 10);
+
+        let more: VecDeque<(i64, i64)> = (5..2000).map(|i| (i, 20_000_000)).collect();
+        history.append(&more);
+        assert_eq!(history.frame_times.len(), FRAME_HISTORY_LEN);
+        assert_eq!(history.frame_times.back(), Some(&20.0));
+    }
+
+    #[test]
+    fn frame_stats_report_lows() {
+        let mut history = FrameHistory::new();
+        let mut records: VecDeque<(i64, i64)> = (0..99).map(|i| (i, 8_000_000)).collect();
+        records.push_back((99, 50_000_000));
+        history.append(&records);
+
+        let stats = history.stats().unwrap();
+        assert!((stats.avg - 8.42).abs() < 1e-9);
+        assert_eq!(stats.low_1, 50.0);
+        assert_eq!(stats.low_01, 50.0);
+    }
+
+    #[test]
+    fn watch_stats_mean_and_deviation() {
+        let records: VecDeque<(i64, i64)> = [(0, 1_000_000), (1, 3_000_000)].into();
+        let stats = watch_stats(&records).unwrap();
+        assert_eq!(stats.avg, 2.0);
+        assert_eq!(stats.std, 1.0);
+        assert!(watch_stats(&VecDeque::new()).is_none());
+    }
+}
@@ -0,0 +1,100 @@
+use crate::play::practice_configuration::PracticeProperty;
+use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
+use std::sync::Mutex;
+
+/// Settings of the running practice session, published by the play state.
+static PRACTICE: Mutex<Option<PracticeProperty>> = Mutex::new(None);
+/// Settings changed in the window, waiting to be picked up by the play state.
+static EDIT: Mutex<Option<PracticeProperty>> = Mutex::new(None);
+
+pub struct PracticeMenu;
+
+impl PracticeMenu {
+    /// Publish the current practice settings, or `None` when practice mode ends.
+    pub fn publish(property: Option<&PracticeProperty>) {
+        *lock_or_recover(&PRACTICE) = property.cloned();
+        if property.is_none() {
+            *lock_or_recover(&EDIT) = None;
+        }
+    }
+
+    /// Take the settings edited in the window since the last call.
+    pub fn take_edit() -> Option<PracticeProperty> {
+        lock_or_recover(&EDIT).take()
+    }
+
+    /// Render the practice window using egui.
+    pub fn show_ui(ctx: &egui::Context) {
+        let mut open = true;
+        egui::Window::new(tr!("practice-practice"))
+            .open(&mut open)
+            .auto_sized()
+            .show(ctx, |ui| {
+                let Some(mut property) = lock_or_recover(&PRACTICE).clone() else {
+                    ui.label(tr!("practice-not-active"));
+                    return;
+                };
+                let before = property.clone();
+
+                ui.label(tr!("practice-applies-next-run"));
+                ui.horizontal(|ui| {
+                    ui.label(tr!("practice-start"));
+                    ui.add(
+                        egui::DragValue::new(&mut property.starttime)
+                            .range(0..=i32::MAX)
+                            .speed(100)
+                            .suffix(" ms"),
+                    );
+                    ui.label(tr!("practice-end"));
+                    ui.add(
+                        egui::DragValue::new(&mut property.endtime)
+                            .range(0..=i32::MAX)
+                            .speed(100)
+                            .suffix(" ms"),
+                    );
+                });
+                ui.add(
+                    egui::Slider::new(&mut property.freq, 50..=200)
+                        .text(tr!("practice-rate-percent")),
+                );
+
+                ui.separator();
+                ui.checkbox(&mut property.loopsection, tr!("practice-loop-section"));
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut property.freezegauge, tr!("practice-freeze-gauge"));
+                    crate::modmenu::imgui_renderer::ImGuiRenderer::help_marker(
+                        ui,
+                        &tr!("practice-freeze-gauge-help"),
+                    );
+                });
+                ui.checkbox(
+                    &mut property.showinvisible,
+                    tr!("practice-show-invisible-notes"),
+                );
+
+                if property != before {
+                    property.endtime = property
+                        .endtime
+                        .max(property.starttime.saturating_add(1000));
+                    *lock_or_recover(&PRACTICE) = Some(property.clone());
+                    *lock_or_recover(&EDIT) = Some(property);
+                }
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish_none_drops_pending_edit() {
+        PracticeMenu::publish(Some(&PracticeProperty::new()));
+        *lock_or_recover(&EDIT) = Some(PracticeProperty::new());
+
+        PracticeMenu::publish(None);
+
+        assert!(PracticeMenu::take_edit().is_none());
+    }
+}
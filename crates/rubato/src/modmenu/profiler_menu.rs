@@ -0,0 +1,239 @@
+//! Flame graph of the profiling spans recorded in the last frame.
+
+use std::sync::{Arc, Mutex};
+
+use puffin::{FrameData, GlobalFrameView, Reader, ScopeCollection, Stream};
+
+use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
+
+/// Frames kept for the "slowest" view.
+const MAX_RECENT_FRAMES: usize = 300;
+const MAX_SLOW_FRAMES: usize = 16;
+const ROW_HEIGHT: f32 = 16.0;
+const GRAPH_WIDTH: f32 = 800.0;
+
+/// Frame sink, registered when the window is first opened.
+static FRAME_VIEW: Mutex<Option<GlobalFrameView>> = Mutex::new(None);
+static PAUSED_FRAME: Mutex<Option<Arc<FrameData>>> = Mutex::new(None);
+static SHOW_SLOWEST: Mutex<bool> = Mutex::new(false);
+
+/// A span placed in the flame graph.
+#[derive(Clone, Debug, PartialEq)]
+struct FlameSpan {
+    name: String,
+    depth: usize,
+    start_ns: i64,
+    duration_ns: i64,
+}
+
+/// Spans of one thread in one frame.
+struct ThreadSpans {
+    name: String,
+    spans: Vec<FlameSpan>,
+}
+
+/// Flatten the scopes of a thread stream, parents before children.
+fn collect_spans(
+    stream: &Stream,
+    offset: u64,
+    depth: usize,
+    scopes: &ScopeCollection,
+    out: &mut Vec<FlameSpan>,
+) {
+    let Ok(reader) = Reader::with_offset(stream, offset) else {
+        return;
+    };
+    for scope in reader.flatten() {
+        let name = scopes
+            .fetch_by_id(&scope.id)
+            .map_or_else(|| "?".to_string(), |details| details.name().to_string());
+        out.push(FlameSpan {
+            name,
+            depth,
+            start_ns: scope.record.start_ns,
+            duration_ns: scope.record.duration_ns,
+        });
+        collect_spans(stream, scope.child_begin_position, depth + 1, scopes, out);
+    }
+}
+
+/// Spans of every thread in `frame`, by thread name.
+fn frame_spans(frame: &FrameData, scopes: &ScopeCollection) -> Vec<ThreadSpans> {
+    let Some(unpacked) = frame.unpacked().ok() else {
+        return Vec::new();
+    };
+    unpacked
+        .thread_streams
+        .iter()
+        .map(|(thread, stream_info)| {
+            let mut spans = Vec::new();
+            collect_spans(&stream_info.stream, 0, 0, scopes, &mut spans);
+            ThreadSpans {
+                name: thread.name.clone(),
+                spans,
+            }
+        })
+        .collect()
+}
+
+/// Stable color per span name.
+fn span_color(name: &str) -> egui::Color32 {
+    let hash = name.bytes().fold(0x811c_9dc5u32, |h, b| {
+        (h ^ b as u32).wrapping_mul(0x0100_0193)
+    });
+    egui::Color32::from_rgb(
+        140 + (hash & 0x5f) as u8,
+        90 + ((hash >> 8) & 0x7f) as u8,
+        60 + ((hash >> 16) & 0x3f) as u8,
+    )
+}
+
+pub struct ProfilerMenu;
+
+impl ProfilerMenu {
+    pub fn show_ui(ctx: &egui::Context) {
+        let mut view_slot = lock_or_recover(&FRAME_VIEW);
+        let view = view_slot.get_or_insert_with(|| {
+            let view = GlobalFrameView::default();
+            view.lock().set_max_recent(MAX_RECENT_FRAMES);
+            view.lock().set_max_slow(MAX_SLOW_FRAMES);
+            view
+        });
+
+        let mut open = true;
+        egui::Window::new(tr!("profiler-profiler"))
+            .open(&mut open)
+            .show(ctx, |ui| {
+                let mut paused = lock_or_recover(&PAUSED_FRAME);
+                let mut slowest = lock_or_recover(&SHOW_SLOWEST);
+                let live = {
+                    let frame_view = view.lock();
+                    if *slowest {
+                        frame_view
+                            .slowest_frames_chronological()
+                            .max_by_key(|f| f.duration_ns())
+                            .cloned()
+                    } else {
+                        frame_view.latest_frame()
+                    }
+                };
+
+                ui.horizontal(|ui| {
+                    let mut is_paused = paused.is_some();
+                    if ui.checkbox(&mut is_paused, tr!("profiler-pause")).changed() {
+                        *paused = if is_paused { live.clone() } else { None };
+                    }
+                    ui.checkbox(&mut slowest, tr!("profiler-slowest-recent-frame"));
+                    if ui.button(tr!("profiler-clear")).clicked() {
+                        view.lock().clear_slowest();
+                        *paused = None;
+                    }
+                });
+
+                let Some(frame) = paused.clone().or(live) else {
+                    ui.label(tr!("profiler-no-profiling-data"));
+                    return;
+                };
+                let (frame_start, frame_end) = frame.range_ns();
+                let frame_ns = (frame_end - frame_start).max(1);
+                ui.label(format!(
+                    "Frame {}: {:.2} ms",
+                    frame.frame_index(),
+                    frame_ns as f64 / 1_000_000.0
+                ));
+
+                let threads = frame_spans(&frame, view.lock().scope_collection());
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    for thread in &threads {
+                        ui.label(&thread.name);
+                        flame_graph(ui, &thread.spans, frame_start, frame_ns);
+                    }
+                });
+            });
+    }
+
+    /// Stop collecting frames until the window is shown again.
+    pub fn stop() {
+        *lock_or_recover(&FRAME_VIEW) = None;
+        *lock_or_recover(&PAUSED_FRAME) = None;
+    }
+}
+
+/// Draw one thread's spans, one row per depth, across the frame width.
+fn flame_graph(ui: &mut egui::Ui, spans: &[FlameSpan], frame_start: i64, frame_ns: i64) {
+    let rows = spans.iter().map(|s| s.depth + 1).max().unwrap_or(1);
+    let size = egui::vec2(GRAPH_WIDTH, rows as f32 * ROW_HEIGHT);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 0.0, egui::Color32::from_gray(20));
+    let ns_to_x = |ns: i64| rect.min.x + (ns - frame_start) as f32 / frame_ns as f32 * rect.width();
+
+    let hover = response.hover_pos();
+    let mut hovered = None;
+    for span in spans {
+        let top = rect.min.y + span.depth as f32 * ROW_HEIGHT;
+        let span_rect = egui::Rect::from_min_max(
+            egui::pos2(ns_to_x(span.start_ns), top),
+            egui::pos2(
+                ns_to_x(span.start_ns + span.duration_ns).max(ns_to_x(span.start_ns) + 1.0),
+                top + ROW_HEIGHT - 1.0,
+            ),
+        );
+        painter.rect_filled(span_rect, 2.0, span_color(&span.name));
+        if span_rect.width() > 40.0 {
+            painter.text(
+                span_rect.left_center() + egui::vec2(3.0, 0.0),
+                egui::Align2::LEFT_CENTER,
+                &span.name,
+                egui::FontId::monospace(10.0),
+                egui::Color32::BLACK,
+            );
+        }
+        if hover.is_some_and(|p| span_rect.contains(p)) {
+            hovered = Some(span);
+        }
+    }
+    if let Some(span) = hovered {
+        response.on_hover_text(format!(
+            "{}: {:.3} ms",
+            span.name,
+            span.duration_ns as f64 / 1_000_000.0
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_spans_nest_children_under_parents() {
+        puffin::set_scopes_on(true);
+        let view = GlobalFrameView::default();
+        std::thread::Builder::new()
+            .name("profiler-test".to_string())
+            .spawn(|| {
+                crate::profile_scope!("outer");
+                {
+                    crate::profile_scope!("first");
+                }
+                crate::profile_scope!("second");
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        puffin::GlobalProfiler::lock().new_frame();
+
+        let frame_view = view.lock();
+        let frame = frame_view.latest_frame().unwrap();
+        let threads = frame_spans(&frame, frame_view.scope_collection());
+        let thread = threads.iter().find(|t| t.name == "profiler-test").unwrap();
+        let layout: Vec<(&str, usize)> = thread
+            .spans
+            .iter()
+            .map(|s| (s.name.as_str(), s.depth))
+            .collect();
+        assert_eq!(layout, [("outer", 0), ("first", 1), ("second", 1)]);
+    }
+}
@@ -4,9 +4,14 @@ use std::sync::Mutex;
 use crate::random_history;
 pub use crate::random_history::RandomHistoryEntry;
 use crate::skin::sync_utils::lock_or_recover;
+use bms::model::bms_model::BMSModel;
+use rand::Rng;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
 
+/// Number of time slices in a lane density preview
+pub const DENSITY_BUCKETS: usize = 32;
+
 struct RandomTrainerState {
     lane_order: String,
     lanes_to_random: Vec<char>,
@@ -14,6 +19,10 @@ struct RandomTrainerState {
     active: bool,
     lane_mask: Vec<bool>,
     random_seed_map: Option<HashMap<i32, i64>>,
+    /// Lane order used for the 1P RANDOM of the next plays
+    locked_lane_order: Option<String>,
+    /// Per-lane note density of the chart being played, for the preview
+    chart_density: Option<Vec<Vec<u32>>>,
 }
 
 static STATE: Mutex<RandomTrainerState> = Mutex::new(RandomTrainerState {
@@ -23,6 +32,8 @@ static STATE: Mutex<RandomTrainerState> = Mutex::new(RandomTrainerState {
     active: false,
     lane_mask: Vec::new(),
     random_seed_map: None,
+    locked_lane_order: None,
+    chart_density: None,
 });
 
 fn init_defaults(state: &mut RandomTrainerState) {
@@ -34,6 +45,52 @@ fn init_defaults(state: &mut RandomTrainerState) {
     }
 }
 
+/// Roll a new lane order from the current one, following the trainer's
+/// black/white and random-lane settings.
+fn shuffle_lane_order(state: &RandomTrainerState, rng: &mut impl Rng) -> String {
+    let mut lane_order: Vec<char> = state.lane_order.chars().collect();
+
+    if state.black_white_permute {
+        let mut black: Vec<char> = Vec::new();
+        let mut white: Vec<char> = Vec::new();
+        for &c in &lane_order {
+            let digit = c.to_digit(10).unwrap_or(0) as i32;
+            if digit % 2 == 0 {
+                black.push(c);
+            } else {
+                white.push(c);
+            }
+        }
+        black.shuffle(rng);
+        white.shuffle(rng);
+
+        for ch in lane_order.iter_mut() {
+            let digit = ch.to_digit(10).unwrap_or(0) as i32;
+            if digit % 2 == 0 {
+                if let Some(c) = black.first() {
+                    *ch = *c;
+                    black.remove(0);
+                }
+            } else if let Some(c) = white.first() {
+                *ch = *c;
+                white.remove(0);
+            }
+        }
+    }
+
+    let mut shuffled_lanes: Vec<char> = state.lanes_to_random.clone();
+    shuffled_lanes.shuffle(rng);
+    for ch in lane_order.iter_mut() {
+        if state.lanes_to_random.contains(ch)
+            && let Some(c) = shuffled_lanes.first()
+        {
+            *ch = *c;
+            shuffled_lanes.remove(0);
+        }
+    }
+    lane_order.into_iter().collect()
+}
+
 pub struct RandomTrainer;
 
 impl Default for RandomTrainer {
@@ -59,50 +116,61 @@ impl RandomTrainer {
         let mut rng = thread_rng();
         let mut state = lock_or_recover(&STATE);
         init_defaults(&mut state);
+        state.lane_order = shuffle_lane_order(&state, &mut rng);
+        state.lane_order.clone()
+    }
 
-        if state.black_white_permute {
-            let mut black: Vec<char> = Vec::new();
-            let mut white: Vec<char> = Vec::new();
-            for c in state.lane_order.chars() {
-                let digit = c.to_digit(10).unwrap_or(0) as i32;
-                if digit % 2 == 0 {
-                    black.push(c);
-                } else {
-                    white.push(c);
-                }
+    /// Up to `count` distinct lane orders the trainer could roll next, without
+    /// changing the current one.
+    pub fn candidates(count: usize) -> Vec<String> {
+        let mut rng = thread_rng();
+        let mut state = lock_or_recover(&STATE);
+        init_defaults(&mut state);
+        let mut candidates: Vec<String> = Vec::with_capacity(count);
+        // Few random lanes allow few permutations; stop after a bounded number of rolls
+        for _ in 0..count * 16 {
+            if candidates.len() >= count {
+                break;
             }
-            black.shuffle(&mut rng);
-            white.shuffle(&mut rng);
-
-            let mut new_lane_order: Vec<char> = state.lane_order.chars().collect();
-            for ch in new_lane_order.iter_mut() {
-                let digit = ch.to_digit(10).unwrap_or(0) as i32;
-                if digit % 2 == 0 {
-                    if let Some(c) = black.first() {
-                        *ch = *c;
-                        black.remove(0);
-                    }
-                } else if let Some(c) = white.first() {
-                    *ch = *c;
-                    white.remove(0);
-                }
+            let order = shuffle_lane_order(&state, &mut rng);
+            if !candidates.contains(&order) {
+                candidates.push(order);
             }
-            state.lane_order = new_lane_order.into_iter().collect();
         }
+        candidates
+    }
 
-        let mut shuffled_lanes: Vec<char> = state.lanes_to_random.clone();
-        shuffled_lanes.shuffle(&mut rng);
-        let mut new_lane_order: Vec<char> = state.lane_order.chars().collect();
-        for ch in new_lane_order.iter_mut() {
-            if state.lanes_to_random.contains(ch)
-                && let Some(c) = shuffled_lanes.first()
-            {
-                *ch = *c;
-                shuffled_lanes.remove(0);
+    /// Lane order locked for the next plays, if any.
+    pub fn locked_lane_order() -> Option<String> {
+        lock_or_recover(&STATE).locked_lane_order.clone()
+    }
+
+    pub fn lock_lane_order(lane_order: Option<String>) {
+        lock_or_recover(&STATE).locked_lane_order = lane_order;
+    }
+
+    /// Note count per time slice for each of the first `keys` lanes of the chart.
+    pub fn lane_density(model: &BMSModel, keys: usize) -> Vec<Vec<u32>> {
+        let mut density = vec![vec![0u32; DENSITY_BUCKETS]; keys];
+        let last_time = model.last_note_time().max(1);
+        for tl in &model.timelines {
+            let bucket = ((tl.time().max(0) * DENSITY_BUCKETS as i64 / (last_time + 1)) as usize)
+                .min(DENSITY_BUCKETS - 1);
+            for (lane, counts) in density.iter_mut().enumerate() {
+                if tl.note(lane as i32).is_some() {
+                    counts[bucket] += 1;
+                }
             }
         }
-        state.lane_order = new_lane_order.into_iter().collect();
-        state.lane_order.clone()
+        density
+    }
+
+    pub fn chart_density() -> Option<Vec<Vec<u32>>> {
+        lock_or_recover(&STATE).chart_density.clone()
+    }
+
+    pub fn set_chart_density(density: Option<Vec<Vec<u32>>>) {
+        lock_or_recover(&STATE).chart_density = density;
     }
 
     pub fn is_lane_to_random(lane: char) -> bool {
@@ -180,6 +248,8 @@ mod tests {
         state.lane_mask.clear();
         state.lane_mask = vec![false; 7];
         state.random_seed_map = Some(HashMap::new());
+        state.locked_lane_order = None;
+        state.chart_density = None;
         guard
     }
 
@@ -321,6 +391,71 @@ mod tests {
         }
     }
 
+    // --- candidates / lock ---
+
+    #[test]
+    fn test_candidates_are_distinct_and_keep_fixed_lanes() {
+        let _g = reset_globals();
+        RandomTrainer::set_lane_to_random('1');
+        RandomTrainer::set_lane_to_random('2');
+        RandomTrainer::set_lane_to_random('3');
+
+        let candidates = RandomTrainer::candidates(4);
+        assert_eq!(candidates.len(), 4);
+        for (i, candidate) in candidates.iter().enumerate() {
+            assert_eq!(&candidate[3..], "4567");
+            assert!(!candidates[i + 1..].contains(candidate));
+        }
+        // Generating candidates does not roll the current order
+        assert_eq!(RandomTrainer::get_current_lane_order(), "1234567");
+    }
+
+    #[test]
+    fn test_candidates_stop_when_permutations_run_out() {
+        let _g = reset_globals();
+        RandomTrainer::set_lane_to_random('6');
+        RandomTrainer::set_lane_to_random('7');
+        let mut candidates = RandomTrainer::candidates(4);
+        candidates.sort();
+        assert_eq!(candidates, vec!["1234567", "1234576"]);
+    }
+
+    #[test]
+    fn test_lock_lane_order() {
+        let _g = reset_globals();
+        assert_eq!(RandomTrainer::locked_lane_order(), None);
+        RandomTrainer::lock_lane_order(Some("7654321".to_string()));
+        assert_eq!(
+            RandomTrainer::locked_lane_order().as_deref(),
+            Some("7654321")
+        );
+        RandomTrainer::lock_lane_order(None);
+        assert_eq!(RandomTrainer::locked_lane_order(), None);
+    }
+
+    #[test]
+    fn test_lane_density_counts_notes_per_lane() {
+        use bms::model::mode::Mode;
+        use bms::model::note::Note;
+        use bms::model::time_line::TimeLine;
+
+        let mut model = BMSModel::new();
+        model.set_mode(Mode::BEAT_7K);
+        let mut first = TimeLine::new(0.0, 0, 8);
+        first.set_note(0, Some(Note::new_normal(1)));
+        first.set_note(3, Some(Note::new_normal(1)));
+        let mut last = TimeLine::new(1.0, 10_000_000, 8);
+        last.set_note(0, Some(Note::new_normal(1)));
+        model.timelines = vec![first, last];
+
+        let density = RandomTrainer::lane_density(&model, 7);
+        assert_eq!(density.len(), 7);
+        assert_eq!(density[0][0], 1);
+        assert_eq!(density[0][DENSITY_BUCKETS - 1], 1);
+        assert_eq!(density[3].iter().sum::<u32>(), 1);
+        assert_eq!(density[1].iter().sum::<u32>(), 0);
+    }
+
     // --- random_seed_map ---
 
     #[test]
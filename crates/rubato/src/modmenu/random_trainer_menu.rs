@@ -1,10 +1,14 @@
 use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
 use std::sync::Mutex;
 
 static RANDOM_TRAINER_ENABLED: Mutex<bool> = Mutex::new(false);
 static BLACK_WHITE_RANDOM_PERMUTATION: Mutex<bool> = Mutex::new(false);
 static LANE_ORDER: Mutex<Vec<String>> = Mutex::new(Vec::new());
 static TRACK_RAN_WHEN_DISABLED: Mutex<bool> = Mutex::new(false);
+static CANDIDATES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+const CANDIDATE_COUNT: usize = 4;
 
 fn init_lane_order() {
     let mut lo = lock_or_recover(&LANE_ORDER);
@@ -54,7 +58,7 @@ impl RandomTrainerMenu {
     pub fn show_ui(ctx: &egui::Context) {
         init_lane_order();
         let mut open = true;
-        egui::Window::new("Random Trainer")
+        egui::Window::new(tr!("random-trainer-random-trainer"))
             .open(&mut open)
             .auto_sized()
             .show(ctx, |ui| {
@@ -88,33 +92,79 @@ impl RandomTrainerMenu {
 
                 // Controls
                 ui.separator();
-                ui.label("Controls");
+                ui.label(tr!("random-trainer-controls"));
                 ui.indent("random_controls", |ui| {
                     let mut enabled = lock_or_recover(&RANDOM_TRAINER_ENABLED);
-                    ui.checkbox(&mut enabled, "Trainer Enabled");
+                    ui.checkbox(&mut enabled, tr!("random-trainer-trainer-enabled"));
                     drop(enabled);
 
                     let mut track = lock_or_recover(&TRACK_RAN_WHEN_DISABLED);
-                    ui.checkbox(&mut track, "Track Current Random");
+                    ui.checkbox(&mut track, tr!("random-trainer-track-current-random"));
                     drop(track);
 
                     let mut bw = lock_or_recover(&BLACK_WHITE_RANDOM_PERMUTATION);
-                    ui.checkbox(&mut bw, "Black/White Random Select");
+                    ui.checkbox(&mut bw, tr!("random-trainer-black-white-random-select"));
                     drop(bw);
                 });
 
                 ui.horizontal(|ui| {
-                    if ui.button("Mirror").clicked() {
+                    if ui.button(tr!("random-trainer-mirror")).clicked() {
                         Self::mirror_lane_order();
                     }
-                    if ui.button("Shift Left").clicked() {
+                    if ui.button(tr!("random-trainer-shift-left")).clicked() {
                         Self::shift_left_lane_order();
                     }
-                    if ui.button("Shift Right").clicked() {
+                    if ui.button(tr!("random-trainer-shift-right")).clicked() {
                         Self::shift_right_lane_order();
                     }
                 });
 
+                // Candidate permutations with a density preview of the current chart
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.label(tr!("random-trainer-candidates"));
+                    if ui.button(tr!("random-trainer-generate")).clicked() {
+                        *lock_or_recover(&CANDIDATES) =
+                            crate::modmenu::random_trainer::RandomTrainer::candidates(
+                                CANDIDATE_COUNT,
+                            );
+                    }
+                });
+                let density = crate::modmenu::random_trainer::RandomTrainer::chart_density();
+                let locked = crate::modmenu::random_trainer::RandomTrainer::locked_lane_order();
+                let candidates = lock_or_recover(&CANDIDATES).clone();
+                ui.horizontal(|ui| {
+                    for candidate in &candidates {
+                        ui.vertical(|ui| {
+                            if let Some(ref density) = density {
+                                density_preview(ui, density, candidate);
+                            }
+                            let is_locked = locked.as_deref() == Some(candidate.as_str());
+                            if ui.selectable_label(is_locked, candidate).clicked() {
+                                crate::modmenu::random_trainer::RandomTrainer::lock_lane_order(
+                                    Some(candidate.clone()),
+                                );
+                            }
+                        });
+                    }
+                });
+                ui.horizontal(|ui| {
+                    match locked {
+                        Some(ref order) => {
+                            ui.label(tr!("random-trainer-locked", order = order.as_str()))
+                        }
+                        None => ui.label(tr!("random-trainer-locked-none")),
+                    };
+                    if ui.button(tr!("random-trainer-lock-current")).clicked() {
+                        crate::modmenu::random_trainer::RandomTrainer::lock_lane_order(Some(
+                            get_lane_order_string(),
+                        ));
+                    }
+                    if ui.button(tr!("random-trainer-unlock")).clicked() {
+                        crate::modmenu::random_trainer::RandomTrainer::lock_lane_order(None);
+                    }
+                });
+
                 // Sync state
                 let trainer_enabled = *lock_or_recover(&RANDOM_TRAINER_ENABLED);
                 crate::modmenu::random_trainer::RandomTrainer::set_active(trainer_enabled);
@@ -133,6 +183,42 @@ impl RandomTrainerMenu {
     }
 }
 
+/// Draw a mini density chart of the chart as it would look with `lane_order`.
+/// Time runs bottom to top; brighter cells hold more notes.
+fn density_preview(ui: &mut egui::Ui, density: &[Vec<u32>], lane_order: &str) {
+    const CELL_W: f32 = 8.0;
+    const CELL_H: f32 = 3.0;
+    let buckets = density.first().map_or(0, Vec::len);
+    let max = density.iter().flatten().copied().max().unwrap_or(0).max(1);
+    let size = egui::vec2(CELL_W * lane_order.len() as f32, CELL_H * buckets as f32);
+    let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 0.0, egui::Color32::from_gray(20));
+    for (column, lane) in lane_order.chars().enumerate() {
+        let Some(counts) = lane
+            .to_digit(10)
+            .and_then(|d| density.get((d as usize).wrapping_sub(1)))
+        else {
+            continue;
+        };
+        for (bucket, &count) in counts.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            let level = (55 + 200 * count / max) as u8;
+            let min = egui::pos2(
+                rect.min.x + column as f32 * CELL_W,
+                rect.max.y - (bucket + 1) as f32 * CELL_H,
+            );
+            painter.rect_filled(
+                egui::Rect::from_min_size(min, egui::vec2(CELL_W - 1.0, CELL_H)),
+                0.0,
+                egui::Color32::from_gray(level),
+            );
+        }
+    }
+}
+
 fn change_lane_order(random: &str) {
     let mut lane_order = lock_or_recover(&LANE_ORDER);
     let chars: Vec<char> = random.chars().collect();
@@ -15,6 +15,7 @@ use super::{
 use crate::core::command::Command;
 
 use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
 use config::{
     dirty, get_file_setting, get_offset_setting, get_option_setting, refresh,
     reset_current_skin_config, switch_current_scene_skin,
@@ -103,7 +104,7 @@ impl SkinMenu {
         let default_height = imgui_renderer::window_height() as f32 * 0.3;
 
         let mut open = true;
-        egui::Window::new("Skin")
+        egui::Window::new(tr!("skin-menu-skin"))
             .open(&mut open)
             .default_height(default_height)
             .show(ctx, |ui| {
@@ -197,7 +198,7 @@ fn menu_header(ui: &mut egui::Ui) {
             }
 
             // Open skin location button
-            if ui.button("Open").clicked() {
+            if ui.button(tr!("skin-menu-open")).clicked() {
                 // Desktop.open — platform-specific, best effort
                 log::info!("Open skin location: {}", current_path);
             }
@@ -214,7 +215,7 @@ fn menu_header(ui: &mut egui::Ui) {
 
             // Save button
             ui.add_enabled_ui(save_available, |ui| {
-                let save_requested = ui.button(" Save ").clicked();
+                let save_requested = ui.button(format!(" {} ", tr!("skin-menu-save"))).clicked();
                 if save_requested || (is_dirty && live_editing) {
                     let current_skin = lock_or_recover(&CURRENT_SKIN);
                     if let Some(ref cs) = *current_skin {
@@ -227,14 +228,17 @@ fn menu_header(ui: &mut egui::Ui) {
 
             // Live Editing checkbox
             let mut le = *lock_or_recover(&LIVE_EDITING);
-            if ui.checkbox(&mut le, "Live Editing").changed() {
+            if ui
+                .checkbox(&mut le, tr!("skin-menu-live-editing"))
+                .changed()
+            {
                 dirty(true);
             }
             *lock_or_recover(&LIVE_EDITING) = le;
 
             // Reset button with confirmation popup
             let reset_popup_id = ui.make_persistent_id("skin-setting-reset-confirmation");
-            let reset_response = ui.button(" Reset ");
+            let reset_response = ui.button(format!(" {} ", tr!("skin-menu-reset")));
             if reset_response.clicked() {
                 ui.memory_mut(|mem| mem.toggle_popup(reset_popup_id));
             }
@@ -245,10 +249,13 @@ fn menu_header(ui: &mut egui::Ui) {
                 &reset_response,
                 egui::PopupCloseBehavior::CloseOnClickOutside,
                 |ui| {
-                    ui.label("Reset current skin's settings to default");
-                    ui.label("ARE YOU SURE?");
+                    ui.label(tr!("skin-menu-reset-prompt"));
+                    ui.label(tr!("skin-menu-are-you-sure"));
                     ui.horizontal(|ui| {
-                        if ui.button(" Confirm ").clicked() {
+                        if ui
+                            .button(format!(" {} ", tr!("skin-menu-confirm")))
+                            .clicked()
+                        {
                             reset_current_skin_config();
                             let current_skin = lock_or_recover(&CURRENT_SKIN);
                             if let Some(ref cs) = *current_skin {
@@ -258,14 +265,19 @@ fn menu_header(ui: &mut egui::Ui) {
                             }
                             ui.memory_mut(|mem| mem.toggle_popup(reset_popup_id));
                         }
-                        ui.label(egui::RichText::new("(click outside popup to close)").weak());
+                        ui.label(
+                            egui::RichText::new(tr!("skin-menu-click-outside-to-close")).weak(),
+                        );
                     });
                 },
             );
 
             // Freeze timers checkbox
             let mut ft = *lock_or_recover(&FREEZE_TIMERS);
-            if ui.checkbox(&mut ft, "Freeze timers").changed() {
+            if ui
+                .checkbox(&mut ft, tr!("skin-menu-freeze-timers"))
+                .changed()
+            {
                 // Wire to TimerManager.frozen via MainController.
                 // TimerManager::frozen controls whether update() advances time.
                 if lock_or_recover(&SKIN_MENU_STATE).main.is_some() {
@@ -281,7 +293,7 @@ fn menu_header(ui: &mut egui::Ui) {
     } else {
         drop(current_skin);
         drop(skins);
-        ui.label("No skin loaded");
+        ui.label(tr!("skin-menu-no-skin-loaded"));
     }
 }
 
@@ -318,7 +330,7 @@ fn skin_config_menu(ui: &mut egui::Ui) {
             }
             // "Other" tab for uncategorized items
             if ui
-                .selectable_label(selected_tab == categories.len(), "Other")
+                .selectable_label(selected_tab == categories.len(), tr!("skin-menu-other"))
                 .clicked()
             {
                 selected_tab = categories.len();
@@ -428,12 +440,13 @@ fn skin_config_option(ui: &mut egui::Ui, option: &CustomOption) {
 
     let value = get_option_setting(option);
     let mut selected = option_index(option, value);
+    let random = tr!("skin-menu-random");
     let chosen = if selected == OPTION_RANDOM_VALUE {
-        "Random".to_string()
+        random.clone()
     } else if (selected as usize) < option.contents.len() {
         option.contents[selected as usize].clone()
     } else {
-        "Random".to_string()
+        random.clone()
     };
 
     let mut arrow_changed = false;
@@ -465,12 +478,12 @@ fn skin_config_option(ui: &mut egui::Ui, option: &CustomOption) {
                             new_chosen = content.clone();
                         }
                     }
-                    if ui.selectable_label("Random" == chosen, "Random").clicked() {
+                    if ui.selectable_label(random == chosen, &random).clicked() {
                         if let Some(ref mut opts) = *lock_or_recover(&SET_OPTIONS) {
                             opts.insert(option.name.clone(), OPTION_RANDOM_VALUE);
                         }
                         dirty(true);
-                        new_chosen = "Random".to_string();
+                        new_chosen = random.clone();
                     }
                 });
 
@@ -512,7 +525,7 @@ fn skin_config_option_radio(ui: &mut egui::Ui, option: &CustomOption) {
                 for (&opt, content) in option.option.iter().zip(option.contents.iter()) {
                     ui.radio_value(&mut value, opt, content.as_str());
                 }
-                ui.radio_value(&mut value, OPTION_RANDOM_VALUE, "Random");
+                ui.radio_value(&mut value, OPTION_RANDOM_VALUE, tr!("skin-menu-random"));
             });
         });
 
@@ -1,12 +1,13 @@
 use super::imgui_renderer;
-use super::{ImBoolean, ImFloat, Skin};
-#[cfg(test)]
-use super::{Rectangle, SkinObject, SkinObjectDestination};
+use super::{ImBoolean, ImFloat, Rectangle, Skin, SkinObject, SkinObjectDestination};
 
 use std::collections::HashMap;
 use std::sync::{LazyLock, Mutex};
 
+use crate::skin::skin_drawable::SkinDrawable;
+use crate::skin::skin_widget_object::SkinWidgetObject;
 use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
 
 const EPS: f64 = 1e-5;
 
@@ -14,6 +15,14 @@ static LOCK: Mutex<()> = Mutex::new(());
 static EVENT_HISTORY: LazyLock<Mutex<EventHistory>> =
     LazyLock::new(|| Mutex::new(EventHistory::new()));
 static WIDGETS: Mutex<Vec<SkinWidget>> = Mutex::new(Vec::new());
+/// Instance id and path of the skin the widgets were taken from
+static CURRENT_SKIN: Mutex<Option<(u64, String)>> = Mutex::new(None);
+/// Widgets as the skin loaded them, before overrides
+static ORIGINALS: Mutex<Vec<SkinWidget>> = Mutex::new(Vec::new());
+/// Widgets as last pushed to the skin
+static APPLIED: Mutex<Vec<SkinWidget>> = Mutex::new(Vec::new());
+static OVERRIDES: LazyLock<Mutex<SkinWidgetOverrides>> =
+    LazyLock::new(|| Mutex::new(SkinWidgetOverrides::load(SKIN_WIDGETS_FILE)));
 
 static EDITING_WIDGET_X: Mutex<ImFloat> = Mutex::new(ImFloat { value: 0.0 });
 static EDITING_WIDGET_Y: Mutex<ImFloat> = Mutex::new(ImFloat { value: 0.0 });
@@ -145,6 +154,63 @@ impl SkinWidgetManager {
         }
     }
 
+    /// Track the skin being drawn and push widget edits to it.
+    ///
+    /// A newly loaded skin replaces the widget list and gets its saved
+    /// overrides applied. While `editing`, widgets changed since the last call
+    /// are applied to the skin.
+    pub fn sync_skin(skin: &mut dyn SkinDrawable, editing: bool) {
+        let id = skin.instance_id();
+        if id == 0 {
+            return;
+        }
+        let is_new = lock_or_recover(&CURRENT_SKIN)
+            .as_ref()
+            .is_none_or(|(current, _)| *current != id);
+        if is_new {
+            let path = skin.skin_path().unwrap_or_default();
+            let objects = skin
+                .widget_objects()
+                .into_iter()
+                .map(to_skin_object)
+                .collect();
+            Self::change_skin(&Skin::new(objects));
+
+            let _lock = lock_or_recover(&LOCK);
+            let mut widgets = lock_or_recover(&WIDGETS);
+            *lock_or_recover(&ORIGINALS) = widgets.clone();
+            *lock_or_recover(&APPLIED) = widgets.clone();
+            if let Some(overrides) = lock_or_recover(&OVERRIDES).get(&path) {
+                apply_overrides(&mut widgets, overrides);
+            }
+            *lock_or_recover(&CURRENT_SKIN) = Some((id, path));
+        } else if !editing {
+            return;
+        }
+
+        let _lock = lock_or_recover(&LOCK);
+        let widgets = lock_or_recover(&WIDGETS);
+        let mut applied = lock_or_recover(&APPLIED);
+        let mut changed = false;
+        for (index, (widget, last)) in widgets.iter().zip(applied.iter()).enumerate() {
+            let regions: Vec<Rectangle> = widget
+                .destinations
+                .iter()
+                .map(|dst| dst.destination.region)
+                .collect();
+            let last_regions = last.destinations.iter().map(|dst| dst.destination.region);
+            if widget.skin_object.visible != last.skin_object.visible
+                || !regions.iter().copied().eq(last_regions)
+            {
+                skin.apply_widget(index, widget.skin_object.visible, &regions);
+                changed = true;
+            }
+        }
+        if changed {
+            applied.clone_from(&widgets);
+        }
+    }
+
     /// Render the skin widget manager window using egui.
     ///
     /// Translated from: SkinWidgetManager.show(ImBoolean)
@@ -153,13 +219,13 @@ impl SkinWidgetManager {
     pub fn show_ui(ctx: &egui::Context) {
         let _lock = lock_or_recover(&LOCK);
         let mut open = true;
-        egui::Window::new("Skin Widgets")
+        egui::Window::new(tr!("widgets-skin-widgets"))
             .open(&mut open)
             .auto_sized()
             .show(ctx, |ui| {
                 let mut widgets = lock_or_recover(&WIDGETS);
                 if widgets.is_empty() {
-                    ui.label("No skin is loaded");
+                    ui.label(tr!("widgets-no-skin-is-loaded"));
                 } else {
                     // Use a simple approach: selectable labels acting as tabs
                     // egui doesn't have built-in tab bars, so we use a stateful tab index
@@ -168,10 +234,16 @@ impl SkinWidgetManager {
                         ui.memory(|mem| mem.data.get_temp::<usize>(tab_id).unwrap_or(0));
 
                     ui.horizontal(|ui| {
-                        if ui.selectable_label(tab_index == 0, "SkinWidgets").clicked() {
+                        if ui
+                            .selectable_label(tab_index == 0, tr!("widgets-skinwidgets"))
+                            .clicked()
+                        {
                             tab_index = 0;
                         }
-                        if ui.selectable_label(tab_index == 1, "History").clicked() {
+                        if ui
+                            .selectable_label(tab_index == 1, tr!("widgets-history"))
+                            .clicked()
+                        {
                             tab_index = 1;
                         }
                     });
@@ -182,18 +254,27 @@ impl SkinWidgetManager {
                     if tab_index == 0 {
                         // SkinWidgets tab
                         ui.horizontal(|ui| {
-                            if ui.button("Undo").clicked() {
+                            if ui.button(tr!("widgets-undo")).clicked() {
                                 let mut event_history = lock_or_recover(&EVENT_HISTORY);
                                 event_history.undo_with_widgets(&mut widgets);
                             }
                             render_prefer_column_setting(ui);
                             let mut show_cursor = lock_or_recover(&SHOW_CURSOR_POSITION);
-                            ui.checkbox(&mut show_cursor.value, "Show Position");
+                            ui.checkbox(&mut show_cursor.value, tr!("widgets-show-position"));
                             drop(show_cursor);
-                            if ui.button("Export").clicked() {
+                            if ui.button(tr!("widgets-export")).clicked() {
                                 export_changes();
                             }
+                            if ui.button(tr!("widgets-save")).clicked() {
+                                save_overrides(&widgets);
+                            }
+                            if ui.button(tr!("widgets-reset")).clicked() {
+                                reset_widgets(&mut widgets);
+                            }
                         });
+                        if let Some((_, path)) = lock_or_recover(&CURRENT_SKIN).as_ref() {
+                            ui.label(format!("Skin: {}", path));
+                        }
 
                         render_skin_widgets_table(ui, &mut widgets);
                     } else {
@@ -216,9 +297,51 @@ impl SkinWidgetManager {
     }
 }
 
+/// Save the differences from the loaded skin as the overrides of the current skin.
+fn save_overrides(widgets: &[SkinWidget]) {
+    let Some((_, path)) = lock_or_recover(&CURRENT_SKIN).clone() else {
+        return;
+    };
+    if path.is_empty() {
+        return;
+    }
+    let overrides = collect_overrides(widgets, &lock_or_recover(&ORIGINALS));
+    if let Err(e) = lock_or_recover(&OVERRIDES).set(&path, overrides) {
+        log::warn!("Failed to save skin widget overrides: {}", e);
+    }
+}
+
+/// Restore the widgets of the loaded skin and drop its saved overrides.
+fn reset_widgets(widgets: &mut Vec<SkinWidget>) {
+    widgets.clone_from(&lock_or_recover(&ORIGINALS));
+    lock_or_recover(&EVENT_HISTORY).clear();
+    save_overrides(widgets);
+}
+
+fn to_skin_object(object: SkinWidgetObject) -> SkinObject {
+    SkinObject {
+        name: object.name,
+        draw: object.draw,
+        visible: object.visible,
+        destinations: object
+            .destinations
+            .into_iter()
+            .map(|(time, region)| SkinObjectDestination {
+                time: time as i32,
+                region,
+                color: None,
+                angle: 0.0,
+                alpha: 0.0,
+            })
+            .collect(),
+    }
+}
+
+mod overrides;
 mod render_fns;
 mod widget_types;
 
+pub use overrides::*;
 use render_fns::*;
 pub use widget_types::*;
 
@@ -552,4 +675,69 @@ mod tests {
         assert_eq!(dst.moving_state, 0);
         assert!(dst.before_move.is_none());
     }
+    // ---- override tests ----
+
+    #[test]
+    fn test_collect_and_apply_overrides() {
+        let originals = vec![
+            make_widget("judge", vec![make_dst("judge", 10.0, 20.0, 30.0, 40.0)]),
+            make_widget("bga", vec![make_dst("bga", 0.0, 0.0, 256.0, 256.0)]),
+        ];
+        let mut widgets = originals.clone();
+        widgets[0].destinations[0].destination.region.x = 15.0;
+        widgets[1].skin_object.visible = false;
+
+        let overrides = collect_overrides(&widgets, &originals);
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(
+            overrides["judge"].destinations["judge"],
+            [15.0, 20.0, 30.0, 40.0]
+        );
+        assert!(!overrides["judge"].hidden);
+        assert!(overrides["bga"].hidden);
+        assert!(overrides["bga"].destinations.is_empty());
+
+        let mut reloaded = originals.clone();
+        apply_overrides(&mut reloaded, &overrides);
+        assert!((reloaded[0].destinations[0].dst_x() - 15.0).abs() < f32::EPSILON);
+        assert!(!reloaded[1].skin_object.visible);
+        assert!(collect_overrides(&originals, &originals).is_empty());
+    }
+
+    #[test]
+    fn test_overrides_save_and_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SKIN_WIDGETS_FILE);
+        let mut overrides = SkinOverrides::new();
+        overrides.insert(
+            "judge".to_string(),
+            WidgetOverride {
+                hidden: true,
+                ..Default::default()
+            },
+        );
+
+        let mut store = SkinWidgetOverrides::load(&path);
+        assert!(store.get("skin/play7.json").is_none());
+        store.set("skin/play7.json", overrides.clone()).unwrap();
+
+        let mut store = SkinWidgetOverrides::load(&path);
+        assert_eq!(store.get("skin/play7.json"), Some(&overrides));
+
+        // Saving empty overrides drops the skin
+        store.set("skin/play7.json", SkinOverrides::new()).unwrap();
+        assert!(
+            SkinWidgetOverrides::load(&path)
+                .get("skin/play7.json")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_overrides_load_broken_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SKIN_WIDGETS_FILE);
+        std::fs::write(&path, "not json").unwrap();
+        assert!(SkinWidgetOverrides::load(&path).get("skin").is_none());
+    }
 }
@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::SkinWidget;
+use crate::external::now_playing::write_replace;
+use crate::modmenu::Rectangle;
+
+/// File the saved widget overrides of every skin are kept in.
+pub const SKIN_WIDGETS_FILE: &str = "skin_widgets.json";
+
+/// Saved changes to one widget.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct WidgetOverride {
+    pub hidden: bool,
+    /// Destination name => [x, y, w, h]
+    pub destinations: BTreeMap<String, [f32; 4]>,
+}
+
+/// Widget name => override
+pub type SkinOverrides = BTreeMap<String, WidgetOverride>;
+
+/// Widget overrides of each skin, keyed by skin path.
+#[derive(Debug, Default)]
+pub struct SkinWidgetOverrides {
+    path: Option<PathBuf>,
+    skins: BTreeMap<String, SkinOverrides>,
+}
+
+impl SkinWidgetOverrides {
+    /// Load the overrides saved at `path`; a missing or broken file starts empty.
+    pub fn load(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let skins = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Self {
+            path: Some(path),
+            skins,
+        }
+    }
+
+    pub fn get(&self, skin_path: &str) -> Option<&SkinOverrides> {
+        self.skins.get(skin_path)
+    }
+
+    /// Replace the overrides of a skin; empty overrides remove its entry.
+    pub fn set(&mut self, skin_path: &str, overrides: SkinOverrides) -> anyhow::Result<()> {
+        if overrides.is_empty() {
+            self.skins.remove(skin_path);
+        } else {
+            self.skins.insert(skin_path.to_string(), overrides);
+        }
+        self.save()
+    }
+
+    fn save(&self) -> anyhow::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        write_replace(path, &serde_json::to_string_pretty(&self.skins)?)
+    }
+}
+
+/// Differences between `widgets` and the skin as loaded.
+pub fn collect_overrides(widgets: &[SkinWidget], originals: &[SkinWidget]) -> SkinOverrides {
+    let mut overrides = SkinOverrides::new();
+    for (widget, original) in widgets.iter().zip(originals) {
+        let mut entry = WidgetOverride {
+            hidden: !widget.skin_object.visible && original.skin_object.visible,
+            ..Default::default()
+        };
+        for (dst, org) in widget.destinations.iter().zip(&original.destinations) {
+            if dst.destination.region != org.destination.region {
+                entry
+                    .destinations
+                    .insert(dst.name.clone(), region_array(&dst.destination.region));
+            }
+        }
+        if entry != WidgetOverride::default() {
+            overrides.insert(widget.name.clone(), entry);
+        }
+    }
+    overrides
+}
+
+/// Apply saved overrides to the widgets with matching names.
+pub fn apply_overrides(widgets: &mut [SkinWidget], overrides: &SkinOverrides) {
+    for widget in widgets.iter_mut() {
+        let Some(entry) = overrides.get(&widget.name) else {
+            continue;
+        };
+        if entry.hidden {
+            widget.skin_object.visible = false;
+        }
+        for dst in widget.destinations.iter_mut() {
+            if let Some(&[x, y, w, h]) = entry.destinations.get(&dst.name) {
+                let region = &mut dst.destination.region;
+                region.x = x;
+                region.y = y;
+                region.width = w;
+                region.height = h;
+            }
+        }
+    }
+}
+
+fn region_array(region: &Rectangle) -> [f32; 4] {
+    [region.x, region.y, region.width, region.height]
+}
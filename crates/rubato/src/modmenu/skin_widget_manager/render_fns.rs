@@ -7,10 +7,11 @@ use super::{
     WIDGET_TABLE_COLUMNS, WIDGETS, WidgetTableColumn,
 };
 use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
 
 pub(super) fn render_prefer_column_setting(ui: &mut egui::Ui) {
     let popup_id = ui.make_persistent_id("PreferColumnSetting");
-    let response = ui.button("Columns");
+    let response = ui.button(tr!("widgets-columns"));
     if response.clicked() {
         ui.memory_mut(|mem| mem.toggle_popup(popup_id));
     }
@@ -103,7 +104,7 @@ pub(super) fn render_skin_widgets_table(ui: &mut egui::Ui, widgets: &mut [SkinWi
                             let event_history = lock_or_recover(&EVENT_HISTORY);
                             let was_visible = widget.skin_object.visible;
                             drop(event_history);
-                            if ui.button("Toggle").clicked() {
+                            if ui.button(tr!("widgets-toggle")).clicked() {
                                 let mut event_history = lock_or_recover(&EVENT_HISTORY);
                                 event_history.push_event(Event::ToggleVisible {
                                     event_type: EventType::ToggleVisible,
@@ -159,7 +160,7 @@ pub(super) fn render_skin_widgets_table(ui: &mut egui::Ui, widgets: &mut [SkinWi
                                 if col_size >= 2 {
                                     let edit_popup_id =
                                         ui.make_persistent_id(format!("edit_popup_{}", dst.name));
-                                    let edit_response = ui.button("Edit");
+                                    let edit_response = ui.button(tr!("widgets-edit"));
                                     if edit_response.clicked() {
                                         *lock_or_recover(&EDITING_WIDGET_X) =
                                             ImFloat { value: dst.dst_x() };
@@ -213,7 +214,7 @@ pub(super) fn render_edit_popup(
     dst: &mut SkinWidgetDestination,
     _dst_id: egui::Id,
 ) {
-    ui.label("Edit Skin Widget");
+    ui.label(tr!("widgets-edit-skin-widget"));
     ui.separator();
 
     let mut x = lock_or_recover(&EDITING_WIDGET_X);
@@ -244,7 +245,7 @@ pub(super) fn render_edit_popup(
     });
     drop(h);
 
-    if ui.button("Submit").clicked() {
+    if ui.button(tr!("widgets-submit")).clicked() {
         dst.set_dst_x(lock_or_recover(&EDITING_WIDGET_X).value);
         dst.set_dst_y(lock_or_recover(&EDITING_WIDGET_Y).value);
         dst.set_dst_w(lock_or_recover(&EDITING_WIDGET_W).value);
@@ -254,7 +255,7 @@ pub(super) fn render_edit_popup(
     // Move overlay checkbox
     let mut move_enabled = lock_or_recover(&MOVE_OVERLAY_ENABLED);
     let old_move = move_enabled.value;
-    ui.checkbox(&mut move_enabled.value, "Move");
+    ui.checkbox(&mut move_enabled.value, tr!("widgets-move"));
     let just_enabled = move_enabled.value && !old_move;
     let reset = *lock_or_recover(&RESET_MOVE_OVERLAY);
 
@@ -354,7 +355,7 @@ pub(super) fn render_history_table(ui: &mut egui::Ui) {
     let event_history = lock_or_recover(&EVENT_HISTORY);
     let events = event_history.events();
     if events.is_empty() {
-        ui.label("No history");
+        ui.label(tr!("widgets-no-history"));
     } else {
         egui::ScrollArea::vertical()
             .max_height(ui.text_style_height(&egui::TextStyle::Body) * 20.0)
@@ -363,7 +364,7 @@ pub(super) fn render_history_table(ui: &mut egui::Ui) {
                     .striped(true)
                     .num_columns(1)
                     .show(ui, |ui| {
-                        ui.strong("Description");
+                        ui.strong(tr!("widgets-description"));
                         ui.end_row();
                         for (i, event) in events.iter().enumerate() {
                             ui.push_id(i, |ui| {
@@ -440,7 +441,7 @@ pub(super) fn export_changes() {
     let change_logs = changes.join("\n");
     let clipboard = Clipboard::new();
     clipboard.set_contents(&change_logs);
-    ImGuiNotify::info("Copied changes to clipboard");
+    ImGuiNotify::info(&tr!("notify-widget-changes-copied"));
 }
 
 pub(super) fn normalize_float(value: f32) -> String {
@@ -3,6 +3,7 @@ use super::super::{SkinObject, SkinObjectDestination};
 use super::{EPS, EVENT_HISTORY};
 
 use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
 use std::collections::HashMap;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -265,9 +266,7 @@ impl SkinWidgetDestination {
     /// Submit the move result, producing the event
     pub fn submit_movement(&mut self) {
         if self.before_move.is_none() {
-            ImGuiNotify::error(
-                "Cannot submit the move result because there's no original position",
-            );
+            ImGuiNotify::error(&tr!("notify-widget-move-no-origin"));
             return;
         }
 
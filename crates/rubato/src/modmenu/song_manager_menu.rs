@@ -1,10 +1,85 @@
 use super::{ScoreData, SongData, SongSelectionAccess};
 use crate::skin::last_played_sort;
+use crate::skin::song_data::{FAVORITE_CHART, FAVORITE_SONG, INVISIBLE_CHART, INVISIBLE_SONG};
+use crate::skin::song_user_metadata::SongUserMetadata;
 use crate::skin::sync_utils::lock_or_recover;
 
+use crate::tr;
 use std::sync::Mutex;
 
 static SELECTOR: Mutex<Option<Box<dyn SongSelectionAccess>>> = Mutex::new(None);
+/// Identity of the published selection, to skip republishing an unchanged one
+static SELECTION_KEY: Mutex<Option<SelectionKey>> = Mutex::new(None);
+static PENDING_ACTIONS: Mutex<Vec<SongManagerAction>> = Mutex::new(Vec::new());
+/// Tag being edited, with the SHA256 of the chart it belongs to
+static TAG_EDIT: Mutex<Option<(String, String)>> = Mutex::new(None);
+/// User metadata being edited, keyed by its chart's SHA256
+static USER_METADATA_EDIT: Mutex<Option<SongUserMetadata>> = Mutex::new(None);
+/// Charts stored under more than one path, ordered by SHA256. None until the
+/// first search.
+static DUPLICATES: Mutex<Option<Vec<SongData>>> = Mutex::new(None);
+
+type SelectionKey = (
+    String,
+    i32,
+    String,
+    Option<SongUserMetadata>,
+    Option<(i64, i32)>,
+);
+
+/// Action on the selected song requested from the song manager window.
+///
+/// Queued by the window and executed by the music selector, which owns the
+/// song database.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SongManagerAction {
+    /// Open the folder containing the chart file
+    OpenFolder,
+    /// Rescan the folder containing the chart
+    RescanFolder,
+    /// Remove the chart from the song database
+    RemoveChart,
+    /// Replace the favorite/invisible flags of the chart
+    SetFavorite(i32),
+    /// Replace the tag of the chart
+    SetTag(String),
+    /// Replace the player's metadata of the chart (removed when empty)
+    SetUserMetadata(SongUserMetadata),
+    /// Search the song database for charts stored under more than one path
+    FindDuplicates,
+    /// Keep one path of a duplicated chart and hide or delete the others
+    ResolveDuplicates(DuplicateResolution),
+}
+
+/// Canonical location chosen for a chart stored under several paths.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateResolution {
+    pub sha256: String,
+    /// Path to keep
+    pub keep: String,
+    /// Delete the other chart files instead of hiding them
+    pub delete: bool,
+}
+
+/// Selection published by the music selector for the song manager window.
+struct SelectedSong {
+    song: Option<SongData>,
+    score: Option<ScoreData>,
+}
+
+impl SongSelectionAccess for SelectedSong {
+    fn selected_song_data(&self) -> Option<SongData> {
+        self.song.clone()
+    }
+
+    fn selected_score_data(&self) -> Option<ScoreData> {
+        self.score.clone()
+    }
+
+    fn reverse_lookup_data(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
 
 pub struct SongManagerMenu;
 
@@ -15,7 +90,7 @@ impl SongManagerMenu {
         let current_score_data = get_current_score_data();
 
         let mut open = true;
-        egui::Window::new("Song Manager")
+        egui::Window::new(tr!("song-manager-song-manager"))
             .open(&mut open)
             .auto_sized()
             .show(ctx, |ui| {
@@ -29,16 +104,132 @@ impl SongManagerMenu {
                     .map(|sd| format!("{}", sd.date))
                     .unwrap_or_else(|| "N/A".to_string());
 
-                if song_name.is_empty() {
-                    ui.label("Not a selectable song");
-                } else {
-                    ui.label(format!("Song: {}", song_name));
-                    ui.label(format!("Last played: {}", last_play_record_time));
+                let Some(song) = current_song_data.as_ref().filter(|_| !song_name.is_empty())
+                else {
+                    ui.label(tr!("song-manager-not-a-selectable-song"));
+                    duplicates_ui(ui);
+                    return;
+                };
+                ui.label(tr!("song-manager-song", name = song_name.as_str()));
+                ui.label(tr!(
+                    "song-manager-last-played",
+                    time = last_play_record_time.as_str()
+                ));
 
-                    let mut sort = last_played_sort::is_enabled();
-                    ui.checkbox(&mut sort, "Sort by last played");
-                    last_played_sort::set(sort);
+                let mut sort = last_played_sort::is_enabled();
+                ui.checkbox(&mut sort, tr!("song-manager-sort-by-last-played"));
+                last_played_sort::set(sort);
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button(tr!("song-manager-open-folder")).clicked() {
+                        push_action(SongManagerAction::OpenFolder);
+                    }
+                    if ui.button(tr!("song-manager-rescan-folder")).clicked() {
+                        push_action(SongManagerAction::RescanFolder);
+                    }
+                    if ui.button(tr!("song-manager-remove-from-db")).clicked() {
+                        push_action(SongManagerAction::RemoveChart);
+                    }
+                });
+
+                // Favorites
+                let mut favorite = song.favorite;
+                for row in [
+                    [
+                        (FAVORITE_SONG, tr!("song-manager-favorite-song")),
+                        (FAVORITE_CHART, tr!("song-manager-favorite-chart")),
+                    ],
+                    [
+                        (INVISIBLE_SONG, tr!("song-manager-hide-song")),
+                        (INVISIBLE_CHART, tr!("song-manager-hide-chart")),
+                    ],
+                ] {
+                    ui.horizontal(|ui| {
+                        for (flag, label) in row {
+                            let mut on = favorite & flag != 0;
+                            if ui.checkbox(&mut on, label).changed() {
+                                favorite ^= flag;
+                            }
+                        }
+                    });
+                }
+                if favorite != song.favorite {
+                    push_action(SongManagerAction::SetFavorite(favorite));
                 }
+
+                // Tag
+                ui.horizontal(|ui| {
+                    let mut edit = lock_or_recover(&TAG_EDIT);
+                    if edit
+                        .as_ref()
+                        .is_none_or(|(sha256, _)| *sha256 != song.file.sha256)
+                    {
+                        *edit = Some((song.file.sha256.clone(), song.metadata.tag.clone()));
+                    }
+                    if let Some((_, ref mut tag)) = *edit {
+                        ui.label(tr!("song-manager-tag"));
+                        ui.text_edit_singleline(tag);
+                        if ui.button(tr!("song-manager-save")).clicked()
+                            && *tag != song.metadata.tag
+                        {
+                            push_action(SongManagerAction::SetTag(tag.clone()));
+                        }
+                    }
+                });
+
+                ui.collapsing(tr!("song-manager-user-metadata"), |ui| {
+                    let mut edit = lock_or_recover(&USER_METADATA_EDIT);
+                    if edit
+                        .as_ref()
+                        .is_none_or(|meta| meta.sha256 != song.file.sha256)
+                    {
+                        *edit = Some(
+                            song.user
+                                .clone()
+                                .unwrap_or_else(|| SongUserMetadata::new(&song.file.sha256)),
+                        );
+                    }
+                    if let Some(ref mut meta) = *edit {
+                        egui::Grid::new("song_manager_user_metadata")
+                            .num_columns(2)
+                            .show(ui, |ui| {
+                                ui.label(tr!("song-manager-display-title"));
+                                ui.text_edit_singleline(&mut meta.title);
+                                ui.end_row();
+                                ui.label(tr!("song-manager-artist-romanized"));
+                                ui.text_edit_singleline(&mut meta.artist);
+                                ui.end_row();
+                                ui.label(tr!("song-manager-tags"));
+                                ui.text_edit_singleline(&mut meta.tags);
+                                ui.end_row();
+                                ui.label(tr!("song-manager-notes"));
+                                ui.text_edit_multiline(&mut meta.notes);
+                                ui.end_row();
+                            });
+                        let current = song
+                            .user
+                            .clone()
+                            .unwrap_or_else(|| SongUserMetadata::new(&song.file.sha256));
+                        if ui.button(tr!("song-manager-save")).clicked() && *meta != current {
+                            push_action(SongManagerAction::SetUserMetadata(meta.clone()));
+                        }
+                    }
+                });
+
+                ui.collapsing(tr!("song-manager-diagnostics"), |ui| {
+                    egui::Grid::new("song_manager_diagnostics")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            for (name, value) in chart_diagnostics(song) {
+                                ui.label(name);
+                                ui.label(value);
+                                ui.end_row();
+                            }
+                        });
+                });
+
+                duplicates_ui(ui);
             });
     }
 
@@ -46,6 +237,39 @@ impl SongManagerMenu {
         *lock_or_recover(&SELECTOR) = Some(selector);
     }
 
+    /// Publish the selected song and its score. Unchanged selections are not
+    /// copied again.
+    pub fn publish_selection(song: Option<&SongData>, score: Option<&ScoreData>) {
+        let key = song.map(|sd| {
+            (
+                sd.file.sha256.clone(),
+                sd.favorite,
+                sd.metadata.tag.clone(),
+                sd.user.clone(),
+                score.map(|sc| (sc.date, sc.clear)),
+            )
+        });
+        let mut current = lock_or_recover(&SELECTION_KEY);
+        if *current == key && lock_or_recover(&SELECTOR).is_some() {
+            return;
+        }
+        *current = key;
+        Self::inject_music_selector(Box::new(SelectedSong {
+            song: song.cloned(),
+            score: score.cloned(),
+        }));
+    }
+
+    /// Publish the result of a duplicate chart search.
+    pub fn publish_duplicates(songs: Vec<SongData>) {
+        *lock_or_recover(&DUPLICATES) = Some(songs);
+    }
+
+    /// Drain the actions requested from the window since the last call.
+    pub fn take_actions() -> Vec<SongManagerAction> {
+        std::mem::take(&mut *lock_or_recover(&PENDING_ACTIONS))
+    }
+
     pub fn is_last_played_sort_enabled() -> bool {
         last_played_sort::is_enabled()
     }
@@ -55,6 +279,121 @@ impl SongManagerMenu {
     }
 }
 
+fn push_action(action: SongManagerAction) {
+    lock_or_recover(&PENDING_ACTIONS).push(action);
+}
+
+/// Duplicate chart list: one group per SHA256, with a row per path.
+fn duplicates_ui(ui: &mut egui::Ui) {
+    ui.collapsing(tr!("song-manager-duplicates"), |ui| {
+        if ui.button(tr!("song-manager-find-duplicates")).clicked() {
+            push_action(SongManagerAction::FindDuplicates);
+        }
+        let duplicates = lock_or_recover(&DUPLICATES);
+        let Some(ref songs) = *duplicates else {
+            return;
+        };
+        if songs.is_empty() {
+            ui.label(tr!("song-manager-no-duplicates"));
+            return;
+        }
+        egui::ScrollArea::vertical()
+            .max_height(300.0)
+            .show(ui, |ui| {
+                for group in songs.chunk_by(|a, b| a.file.sha256 == b.file.sha256) {
+                    ui.separator();
+                    ui.strong(group[0].metadata.title.as_str());
+                    for song in group {
+                        let path = song.file.path().unwrap_or_default();
+                        ui.horizontal(|ui| {
+                            ui.label(path);
+                            for (delete, label) in [
+                                (false, tr!("song-manager-keep-hide-others")),
+                                (true, tr!("song-manager-keep-delete-others")),
+                            ] {
+                                if ui.small_button(label).clicked() {
+                                    push_action(SongManagerAction::ResolveDuplicates(
+                                        DuplicateResolution {
+                                            sha256: song.file.sha256.clone(),
+                                            keep: path.to_string(),
+                                            delete,
+                                        },
+                                    ));
+                                }
+                            }
+                        });
+                    }
+                }
+            });
+    });
+}
+
+/// Rows of the diagnostics view for a chart.
+pub fn chart_diagnostics(song: &SongData) -> Vec<(&'static str, String)> {
+    let chart = &song.chart;
+    let path = song.file.path().unwrap_or_default();
+    let exists = !path.is_empty() && std::path::Path::new(path).exists();
+
+    let mut features = Vec::new();
+    for (present, name) in [
+        (chart.has_undefined_long_note(), "LN(undefined)"),
+        (chart.has_long_note(), "LN"),
+        (chart.has_charge_note(), "CN"),
+        (chart.has_hell_charge_note(), "HCN"),
+        (chart.has_mine_note(), "MINE"),
+        (chart.has_random_sequence(), "RANDOM"),
+        (chart.is_bpmstop(), "STOP"),
+        (chart.has_scroll_change(), "SCROLL"),
+    ] {
+        if present {
+            features.push(name);
+        }
+    }
+    let mut content = Vec::new();
+    for (present, name) in [
+        (chart.has_bga(), "BGA"),
+        (chart.has_preview(), "PREVIEW"),
+        (chart.has_document(), "TEXT"),
+    ] {
+        if present {
+            content.push(name);
+        }
+    }
+    let list = |items: Vec<&str>| {
+        if items.is_empty() {
+            "-".to_string()
+        } else {
+            items.join(" ")
+        }
+    };
+    let bpm = if chart.minbpm == chart.maxbpm {
+        chart.maxbpm.to_string()
+    } else {
+        format!("{}-{}", chart.minbpm, chart.maxbpm)
+    };
+
+    vec![
+        ("Path", path.to_string()),
+        ("File", if exists { "found" } else { "missing" }.to_string()),
+        ("MD5", song.file.md5.clone()),
+        ("SHA256", song.file.sha256.clone()),
+        ("Mode", chart.mode.to_string()),
+        (
+            "Level",
+            format!("{} (difficulty {})", chart.level, chart.difficulty),
+        ),
+        ("Notes", chart.notes.to_string()),
+        (
+            "Length",
+            format!("{}:{:02}", chart.length / 60000, chart.length / 1000 % 60),
+        ),
+        ("BPM", bpm),
+        ("Judge", chart.judge.to_string()),
+        ("Features", list(features)),
+        ("Content", list(content)),
+    ]
+}
+
 fn get_current_song_data() -> Option<SongData> {
     let selector = lock_or_recover(&SELECTOR);
     if let Some(ref sel) = *selector {
@@ -70,3 +409,34 @@ fn get_current_score_data() -> Option<ScoreData> {
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chart_diagnostics_reports_chart_info() {
+        let mut song = SongData::default();
+        song.file.set_path("/nonexistent/chart.bms".to_string());
+        song.file.sha256 = "abc".to_string();
+        song.chart.notes = 1234;
+        song.chart.length = 125_000;
+        song.chart.minbpm = 150;
+        song.chart.maxbpm = 300;
+
+        let rows = chart_diagnostics(&song);
+        let value = |name: &str| {
+            rows.iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, v)| v.clone())
+                .unwrap()
+        };
+        assert_eq!(value("Path"), "/nonexistent/chart.bms");
+        assert_eq!(value("File"), "missing");
+        assert_eq!(value("SHA256"), "abc");
+        assert_eq!(value("Notes"), "1234");
+        assert_eq!(value("Length"), "2:05");
+        assert_eq!(value("BPM"), "150-300");
+        assert_eq!(value("Features"), "-");
+    }
+}
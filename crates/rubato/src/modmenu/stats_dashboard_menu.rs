@@ -0,0 +1,241 @@
+use std::sync::Mutex;
+
+use chrono::TimeZone;
+
+use crate::select::stats_dashboard::{DailyNotes, LevelLamps, ScoreTrendPoint, StatsDashboard};
+use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
+
+/// Statistics opened from the music selector; the window is shown while set.
+static DASHBOARD: Mutex<Option<StatsDashboard>> = Mutex::new(None);
+
+const GRAPH_WIDTH: f32 = 480.0;
+const GRAPH_HEIGHT: f32 = 100.0;
+const LAMP_BAR_WIDTH: f32 = 300.0;
+
+/// Lamp colors indexed by clear type id.
+const LAMP_COLORS: [egui::Color32; 11] = [
+    egui::Color32::from_rgb(60, 60, 60),
+    egui::Color32::from_rgb(140, 30, 30),
+    egui::Color32::from_rgb(150, 90, 220),
+    egui::Color32::from_rgb(230, 130, 230),
+    egui::Color32::from_rgb(90, 210, 90),
+    egui::Color32::from_rgb(80, 150, 240),
+    egui::Color32::from_rgb(235, 235, 235),
+    egui::Color32::from_rgb(240, 210, 60),
+    egui::Color32::from_rgb(110, 230, 230),
+    egui::Color32::from_rgb(255, 170, 200),
+    egui::Color32::from_rgb(255, 240, 150),
+];
+
+pub struct StatsDashboardMenu;
+
+impl StatsDashboardMenu {
+    pub fn open(dashboard: StatsDashboard) {
+        *lock_or_recover(&DASHBOARD) = Some(dashboard);
+    }
+
+    /// Render the stats window using egui, if opened.
+    pub fn show_ui(ctx: &egui::Context) {
+        let mut dashboard = lock_or_recover(&DASHBOARD);
+        let Some(stats) = dashboard.as_ref() else {
+            return;
+        };
+        let mut open = true;
+        egui::Window::new(tr!("stats-statistics"))
+            .open(&mut open)
+            .default_width(GRAPH_WIDTH + 20.0)
+            .vscroll(true)
+            .show(ctx, |ui| {
+                egui::Grid::new("stats_totals")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label(tr!("stats-play-time"));
+                        ui.label(format_playtime(stats.playtime));
+                        ui.end_row();
+                        ui.label(tr!("stats-play-count"));
+                        ui.label(stats.playcount.to_string());
+                        ui.end_row();
+                        ui.label(tr!("stats-clear-count"));
+                        ui.label(stats.clearcount.to_string());
+                        ui.end_row();
+                        ui.label(tr!("stats-notes-hit"));
+                        ui.label(stats.notes.to_string());
+                        ui.end_row();
+                    });
+
+                ui.separator();
+                ui.strong(tr!("stats-notes-per-day"));
+                if stats.daily_notes.is_empty() {
+                    ui.label(tr!("stats-no-data"));
+                } else {
+                    daily_notes_graph(ui, &stats.daily_notes);
+                }
+
+                ui.separator();
+                ui.strong(tr!("stats-lamps-per-level"));
+                if stats.tables.is_empty() {
+                    ui.label(tr!("stats-no-data"));
+                }
+                for table in &stats.tables {
+                    ui.collapsing(&table.table, |ui| {
+                        egui::Grid::new(("stats_lamps", &table.table))
+                            .num_columns(3)
+                            .show(ui, |ui| {
+                                for level in &table.levels {
+                                    ui.label(&level.level);
+                                    lamp_bar(ui, level);
+                                    ui.label(level.total().to_string());
+                                    ui.end_row();
+                                }
+                            });
+                    });
+                }
+
+                ui.separator();
+                ui.strong(tr!("stats-score-trend"));
+                if stats.score_trend.is_empty() {
+                    ui.label(tr!("stats-no-data"));
+                } else {
+                    score_trend_graph(ui, &stats.score_trend);
+                }
+            });
+        if !open {
+            *dashboard = None;
+        }
+    }
+}
+
+/// Play time as hours and minutes.
+fn format_playtime(seconds: i64) -> String {
+    format!("{}:{:02}", seconds / 3600, seconds / 60 % 60)
+}
+
+fn format_date(unixtime: i64) -> String {
+    chrono::Local
+        .timestamp_opt(unixtime, 0)
+        .single()
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
+
+/// One bar per day played, oldest on the left.
+fn daily_notes_graph(ui: &mut egui::Ui, days: &[DailyNotes]) {
+    let size = egui::vec2(GRAPH_WIDTH, GRAPH_HEIGHT);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 0.0, egui::Color32::from_gray(20));
+    let max = days.iter().map(|d| d.notes).max().unwrap_or(0).max(1);
+    let step = rect.width() / days.len() as f32;
+    let hover = response.hover_pos();
+    let mut hovered = None;
+    for (i, day) in days.iter().enumerate() {
+        let height = day.notes as f32 / max as f32 * rect.height();
+        let x = rect.min.x + i as f32 * step;
+        let bar = egui::Rect::from_min_max(
+            egui::pos2(x + 1.0, rect.max.y - height),
+            egui::pos2(x + step - 1.0, rect.max.y),
+        );
+        painter.rect_filled(bar, 0.0, egui::Color32::LIGHT_BLUE);
+        if hover.is_some_and(|p| (x..x + step).contains(&p.x)) {
+            hovered = Some(day);
+        }
+    }
+    if let Some(day) = hovered {
+        response.on_hover_text(tr!(
+            "stats-day-notes",
+            date = format_date(day.date),
+            notes = day.notes,
+            plays = day.playcount,
+            time = format_playtime(day.playtime)
+        ));
+    }
+}
+
+/// Charts of a level stacked by lamp, NO PLAY on the left.
+fn lamp_bar(ui: &mut egui::Ui, level: &LevelLamps) {
+    let size = egui::vec2(LAMP_BAR_WIDTH, 14.0);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 0.0, egui::Color32::from_gray(20));
+    let total = level.total();
+    if total == 0 {
+        return;
+    }
+    let mut x = rect.min.x;
+    for (count, color) in level.lamps.iter().zip(LAMP_COLORS) {
+        let width = *count as f32 / total as f32 * rect.width();
+        painter.rect_filled(
+            egui::Rect::from_min_size(egui::pos2(x, rect.min.y), egui::vec2(width, rect.height())),
+            0.0,
+            color,
+        );
+        x += width;
+    }
+    response.on_hover_text(
+        level
+            .lamps
+            .iter()
+            .map(|count| count.to_string())
+            .collect::<Vec<_>>()
+            .join(" / "),
+    );
+}
+
+/// EX score rate of each improvement, oldest on the left, with the A, AA
+/// and AAA boundaries as guides.
+fn score_trend_graph(ui: &mut egui::Ui, points: &[ScoreTrendPoint]) {
+    let size = egui::vec2(GRAPH_WIDTH, GRAPH_HEIGHT);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 0.0, egui::Color32::from_gray(20));
+    let y = |rate: f32| rect.max.y - rate.clamp(0.0, 1.0) * rect.height();
+    for rate in [6.0 / 9.0, 7.0 / 9.0, 8.0 / 9.0] {
+        painter.hline(
+            rect.x_range(),
+            y(rate),
+            egui::Stroke::new(1.0, egui::Color32::from_gray(70)),
+        );
+    }
+    let step = rect.width() / points.len().max(2).saturating_sub(1) as f32;
+    let pos = |i: usize, rate: f32| egui::pos2(rect.min.x + i as f32 * step, y(rate));
+    painter.add(egui::Shape::line(
+        points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| pos(i, point.rate))
+            .collect(),
+        egui::Stroke::new(1.0, egui::Color32::LIGHT_GREEN),
+    ));
+    let hover = response.hover_pos();
+    let mut hovered = None;
+    for (i, point) in points.iter().enumerate() {
+        let center = pos(i, point.rate);
+        let color = LAMP_COLORS[point.clear.clamp(0, 10) as usize];
+        painter.circle_filled(center, 3.0, color);
+        if hover.is_some_and(|p| (p.x - center.x).abs() <= step / 2.0) {
+            hovered = Some(point);
+        }
+    }
+    if let Some(point) = hovered {
+        response.on_hover_text(format!(
+            "{} {}\n{:.2}% -> {:.2}%",
+            format_date(point.date),
+            point.title,
+            point.oldrate * 100.0,
+            point.rate * 100.0
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn playtime_is_hours_and_minutes() {
+        assert_eq!(format_playtime(0), "0:00");
+        assert_eq!(format_playtime(3 * 3600 + 5 * 60 + 59), "3:05");
+        assert_eq!(format_playtime(125 * 3600), "125:00");
+    }
+}
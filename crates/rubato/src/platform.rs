@@ -51,9 +51,55 @@ pub fn copy_to_clipboard(text: &str) {
 
 // === Audio device enumeration via cpal ===
 
+/// Buffer sizes (frames) offered by the launcher audio tab.
+pub const AUDIO_BUFFER_SIZES: [i32; 8] = [64, 128, 256, 384, 512, 768, 1024, 2048];
+
+/// Sample rates offered by the launcher audio tab.
+pub const AUDIO_SAMPLE_RATES: [i32; 5] = [44100, 48000, 88200, 96000, 192000];
+
 #[derive(Clone, Debug)]
 pub struct DeviceInfo {
     pub name: String,
+    /// Buffer size range (frames) reported by the default output config, if known.
+    pub buffer_size_range: Option<(u32, u32)>,
+    /// Entries of `AUDIO_SAMPLE_RATES` supported by at least one output config.
+    pub sample_rates: Vec<i32>,
+}
+
+impl DeviceInfo {
+    /// Entries of `AUDIO_BUFFER_SIZES` inside the device's buffer size range.
+    pub fn buffer_sizes(&self) -> Vec<i32> {
+        AUDIO_BUFFER_SIZES
+            .iter()
+            .copied()
+            .filter(|&size| {
+                self.buffer_size_range
+                    .is_none_or(|(min, max)| (min as i32..=max as i32).contains(&size))
+            })
+            .collect()
+    }
+}
+
+/// Entries of `AUDIO_SAMPLE_RATES` covered by any of the `(min, max)` ranges.
+fn supported_sample_rates(ranges: &[(u32, u32)]) -> Vec<i32> {
+    AUDIO_SAMPLE_RATES
+        .iter()
+        .copied()
+        .filter(|&rate| {
+            ranges
+                .iter()
+                .any(|&(min, max)| (min as i32..=max as i32).contains(&rate))
+        })
+        .collect()
+}
+
+fn output_device_name(device: &cpal::Device) -> String {
+    use cpal::traits::DeviceTrait;
+
+    device
+        .description()
+        .map(|d| d.name().to_string())
+        .unwrap_or_else(|_| "Unknown Device".to_string())
 }
 
 /// Enumerate available audio output devices using cpal.
@@ -67,15 +113,374 @@ pub fn port_audio_devices() -> anyhow::Result<Vec<DeviceInfo>> {
 
     let mut result = Vec::new();
     for device in devices {
-        let name = device
-            .description()
-            .map(|d| d.name().to_string())
-            .unwrap_or_else(|_| "Unknown Device".to_string());
-        result.push(DeviceInfo { name });
+        let name = output_device_name(&device);
+        let buffer_size_range =
+            device
+                .default_output_config()
+                .ok()
+                .and_then(|config| match *config.buffer_size() {
+                    cpal::SupportedBufferSize::Range { min, max } => Some((min, max)),
+                    cpal::SupportedBufferSize::Unknown => None,
+                });
+        let rate_ranges: Vec<(u32, u32)> = device
+            .supported_output_configs()
+            .map(|configs| {
+                configs
+                    .map(|c| (c.min_sample_rate(), c.max_sample_rate()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        result.push(DeviceInfo {
+            name,
+            buffer_size_range,
+            sample_rates: supported_sample_rates(&rate_ranges),
+        });
     }
     Ok(result)
 }
 
+/// Outcome of `play_test_sound`.
+#[derive(Clone, Debug)]
+pub struct AudioTestResult {
+    pub device: String,
+    pub sample_rate: u32,
+    /// Frames per callback actually delivered by the device.
+    pub buffer_frames: Option<u32>,
+    /// Average time between a callback and the playback of its data, as reported by the host.
+    pub output_latency_ms: Option<f64>,
+}
+
+/// Output device named `device_name` (the default one when `None` or unknown)
+/// with its stream config, `buffer_size` and `sample_rate` applied when not 0.
+fn output_stream_config(
+    host: &cpal::Host,
+    device_name: Option<&str>,
+    buffer_size: i32,
+    sample_rate: i32,
+) -> anyhow::Result<(cpal::Device, String, cpal::StreamConfig)> {
+    use cpal::traits::{DeviceTrait, HostTrait};
+
+    let device = device_name
+        .and_then(|name| {
+            host.output_devices()
+                .ok()?
+                .find(|d| output_device_name(d) == name)
+        })
+        .or_else(|| host.default_output_device())
+        .ok_or_else(|| anyhow::anyhow!("No audio output device available"))?;
+    let device_label = output_device_name(&device);
+
+    let default_config = device
+        .default_output_config()
+        .map_err(|e| anyhow::anyhow!("Failed to query output config: {}", e))?;
+    if default_config.sample_format() != cpal::SampleFormat::F32 {
+        anyhow::bail!(
+            "Unsupported sample format {:?} on {}",
+            default_config.sample_format(),
+            device_label
+        );
+    }
+    let mut config = default_config.config();
+    if sample_rate > 0 {
+        config.sample_rate = sample_rate as u32;
+    }
+    if buffer_size > 0 {
+        config.buffer_size = cpal::BufferSize::Fixed(buffer_size as u32);
+    }
+    Ok((device, device_label, config))
+}
+
+/// Play a short 440 Hz tone on an output device and measure its output latency.
+///
+/// `device_name` of `None` (or an unknown name) uses the default device.
+/// `buffer_size` and `sample_rate` of 0 leave the choice to the device.
+/// Blocks for the duration of the tone, so call it off the UI thread.
+pub fn play_test_sound(
+    device_name: Option<&str>,
+    buffer_size: i32,
+    sample_rate: i32,
+) -> anyhow::Result<AudioTestResult> {
+    use cpal::traits::{DeviceTrait, StreamTrait};
+    use std::sync::{Arc, Mutex};
+
+    const TONE_HZ: f32 = 440.0;
+    const TONE_MS: u64 = 500;
+
+    let (device, device_label, config) =
+        output_stream_config(&cpal::default_host(), device_name, buffer_size, sample_rate)?;
+
+    let channels = config.channels as usize;
+    let rate = config.sample_rate as f32;
+    let total_frames = (rate as u64 * TONE_MS / 1000) as usize;
+    // (latency sum, latency samples, last callback frame count)
+    let stats = Arc::new(Mutex::new((std::time::Duration::ZERO, 0u32, None::<u32>)));
+    let callback_stats = Arc::clone(&stats);
+    let mut frame = 0usize;
+    let stream = device
+        .build_output_stream(
+            &config,
+            move |data: &mut [f32], info: &cpal::OutputCallbackInfo| {
+                for out in data.chunks_mut(channels) {
+                    let value = if frame < total_frames {
+                        // Linear fade in/out over the first/last 10 ms to avoid clicks
+                        let edge = (rate * 0.01).max(1.0);
+                        let remaining = (total_frames - frame) as f32;
+                        let envelope = (frame as f32 / edge).min(remaining / edge).min(1.0);
+                        let phase = frame as f32 * TONE_HZ * std::f32::consts::TAU / rate;
+                        phase.sin() * 0.2 * envelope
+                    } else {
+                        0.0
+                    };
+                    out.fill(value);
+                    frame += 1;
+                }
+                let timestamp = info.timestamp();
+                if let Ok(mut stats) = callback_stats.lock() {
+                    if let Some(latency) = timestamp.playback.duration_since(&timestamp.callback) {
+                        stats.0 += latency;
+                        stats.1 += 1;
+                    }
+                    stats.2 = Some((data.len() / channels.max(1)) as u32);
+                }
+            },
+            |e| log::warn!("Audio test stream error: {}", e),
+            None,
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to open output stream: {}", e))?;
+    stream
+        .play()
+        .map_err(|e| anyhow::anyhow!("Failed to start output stream: {}", e))?;
+    std::thread::sleep(std::time::Duration::from_millis(TONE_MS + 100));
+    drop(stream);
+
+    let (latency_sum, samples, buffer_frames) = *stats
+        .lock()
+        .map_err(|_| anyhow::anyhow!("Audio test statistics poisoned"))?;
+    Ok(AudioTestResult {
+        device: device_label,
+        sample_rate: config.sample_rate,
+        buffer_frames,
+        output_latency_ms: (samples > 0)
+            .then(|| latency_sum.as_secs_f64() * 1000.0 / f64::from(samples)),
+    })
+}
+
+/// Outcome of `measure_round_trip`.
+#[derive(Clone, Debug)]
+pub struct RoundTripResult {
+    pub device: String,
+    pub sample_rate: u32,
+    /// Median time from writing a click to capturing it on the input device.
+    pub round_trip_ms: f64,
+    /// Capture latency reported by the input host, if any.
+    pub input_latency_ms: Option<f64>,
+    pub clicks_played: usize,
+    pub clicks_heard: usize,
+}
+
+impl RoundTripResult {
+    /// Output side of the round trip: the time until a written sample is heard.
+    pub fn output_latency_ms(&self) -> f64 {
+        (self.round_trip_ms - self.input_latency_ms.unwrap_or(0.0)).max(0.0)
+    }
+}
+
+/// Finds click onsets in captured audio: a frame at or above `threshold` at
+/// least `holdoff` frames after the previous onset.
+struct OnsetDetector {
+    threshold: f32,
+    holdoff: usize,
+    since_onset: usize,
+}
+
+impl OnsetDetector {
+    fn new(threshold: f32, holdoff: usize) -> Self {
+        Self {
+            threshold,
+            holdoff,
+            since_onset: holdoff,
+        }
+    }
+
+    /// Indices of the onsets among the next frame `levels`.
+    fn feed(&mut self, levels: impl IntoIterator<Item = f32>) -> Vec<usize> {
+        let mut onsets = Vec::new();
+        for (i, level) in levels.into_iter().enumerate() {
+            if level >= self.threshold && self.since_onset >= self.holdoff {
+                onsets.push(i);
+                self.since_onset = 0;
+            } else {
+                self.since_onset = self.since_onset.saturating_add(1);
+            }
+        }
+        onsets
+    }
+}
+
+/// Delay from each emitted click to the first click heard within `window`
+/// after it. Clicks that were not heard are skipped.
+fn click_delays(emitted: &[f64], heard: &[f64], window: f64) -> Vec<f64> {
+    emitted
+        .iter()
+        .filter_map(|&e| {
+            heard
+                .iter()
+                .find(|&&h| h >= e && h - e < window)
+                .map(|&h| h - e)
+        })
+        .collect()
+}
+
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    match values.len() {
+        0 => None,
+        n if n % 2 == 0 => Some((values[mid - 1] + values[mid]) / 2.0),
+        _ => Some(values[mid]),
+    }
+}
+
+/// Play clicks on an output device, capture them on the default input device
+/// and measure the round trip. Needs a loopback cable or a microphone near
+/// the speakers.
+///
+/// Arguments are those of `play_test_sound`. Blocks for a few seconds, so
+/// call it off the UI thread.
+pub fn measure_round_trip(
+    device_name: Option<&str>,
+    buffer_size: i32,
+    sample_rate: i32,
+) -> anyhow::Result<RoundTripResult> {
+    use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+    use std::sync::{Arc, Mutex};
+
+    const CLICKS: usize = 8;
+    const LEAD_IN_MS: u64 = 300;
+    const INTERVAL_MS: u64 = 400;
+    const CLICK_MS: u64 = 2;
+    const THRESHOLD: f32 = 0.1;
+
+    let host = cpal::default_host();
+    let (device, device_label, config) =
+        output_stream_config(&host, device_name, buffer_size, sample_rate)?;
+    let input = host
+        .default_input_device()
+        .ok_or_else(|| anyhow::anyhow!("No audio input device available"))?;
+    let input_config = input
+        .default_input_config()
+        .map_err(|e| anyhow::anyhow!("Failed to query input config: {}", e))?;
+    if input_config.sample_format() != cpal::SampleFormat::F32 {
+        anyhow::bail!(
+            "Unsupported input sample format {:?}",
+            input_config.sample_format()
+        );
+    }
+    let input_config = input_config.config();
+
+    let start = std::time::Instant::now();
+    // Seconds since `start` at which each click was written and heard
+    let emitted = Arc::new(Mutex::new(Vec::new()));
+    let heard = Arc::new(Mutex::new(Vec::new()));
+    // (input latency sum, latency samples)
+    let input_stats = Arc::new(Mutex::new((std::time::Duration::ZERO, 0u32)));
+
+    let channels = usize::from(config.channels).max(1);
+    let rate = u64::from(config.sample_rate);
+    let lead_in = (rate * LEAD_IN_MS / 1000) as usize;
+    let interval = (rate * INTERVAL_MS / 1000) as usize;
+    let click = (rate * CLICK_MS / 1000).max(1) as usize;
+    let callback_emitted = Arc::clone(&emitted);
+    let mut frame = 0usize;
+    let output = device
+        .build_output_stream(
+            &config,
+            move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
+                let now = start.elapsed().as_secs_f64();
+                for (i, out) in data.chunks_mut(channels).enumerate() {
+                    let pos = frame.checked_sub(lead_in);
+                    let in_click =
+                        pos.is_some_and(|p| p / interval < CLICKS && p % interval < click);
+                    if pos.is_some_and(|p| p / interval < CLICKS && p % interval == 0)
+                        && let Ok(mut emitted) = callback_emitted.lock()
+                    {
+                        emitted.push(now + i as f64 / rate as f64);
+                    }
+                    out.fill(if in_click { 0.8 } else { 0.0 });
+                    frame += 1;
+                }
+            },
+            |e| log::warn!("Round trip output stream error: {}", e),
+            None,
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to open output stream: {}", e))?;
+
+    let input_channels = usize::from(input_config.channels).max(1);
+    let input_rate = f64::from(input_config.sample_rate);
+    let mut detector = OnsetDetector::new(THRESHOLD, (input_rate * 0.1) as usize);
+    let callback_heard = Arc::clone(&heard);
+    let callback_stats = Arc::clone(&input_stats);
+    let capture = input
+        .build_input_stream(
+            &input_config,
+            move |data: &[f32], info: &cpal::InputCallbackInfo| {
+                let now = start.elapsed().as_secs_f64();
+                let frames = data.len() / input_channels;
+                let levels = data
+                    .chunks(input_channels)
+                    .map(|f| f.iter().fold(0.0f32, |max, s| max.max(s.abs())));
+                let onsets = detector.feed(levels);
+                if let Ok(mut heard) = callback_heard.lock() {
+                    heard.extend(
+                        onsets
+                            .into_iter()
+                            .map(|i| now - (frames - i) as f64 / input_rate),
+                    );
+                }
+                let timestamp = info.timestamp();
+                if let Some(latency) = timestamp.callback.duration_since(&timestamp.capture)
+                    && let Ok(mut stats) = callback_stats.lock()
+                {
+                    stats.0 += latency;
+                    stats.1 += 1;
+                }
+            },
+            |e| log::warn!("Round trip input stream error: {}", e),
+            None,
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to open input stream: {}", e))?;
+
+    capture
+        .play()
+        .map_err(|e| anyhow::anyhow!("Failed to start input stream: {}", e))?;
+    output
+        .play()
+        .map_err(|e| anyhow::anyhow!("Failed to start output stream: {}", e))?;
+    std::thread::sleep(std::time::Duration::from_millis(
+        LEAD_IN_MS + INTERVAL_MS * CLICKS as u64 + 300,
+    ));
+    drop(output);
+    drop(capture);
+
+    let poisoned = || anyhow::anyhow!("Round trip statistics poisoned");
+    let emitted = emitted.lock().map_err(|_| poisoned())?.clone();
+    let heard = heard.lock().map_err(|_| poisoned())?.clone();
+    let (latency_sum, samples) = *input_stats.lock().map_err(|_| poisoned())?;
+    let delays = click_delays(&emitted, &heard, INTERVAL_MS as f64 / 1000.0);
+    let clicks_heard = delays.len();
+    let round_trip = median(delays)
+        .ok_or_else(|| anyhow::anyhow!("No clicks were picked up by the input device"))?;
+    Ok(RoundTripResult {
+        device: device_label,
+        sample_rate: config.sample_rate,
+        round_trip_ms: round_trip * 1000.0,
+        input_latency_ms: (samples > 0)
+            .then(|| latency_sum.as_secs_f64() * 1000.0 / f64::from(samples)),
+        clicks_played: emitted.len(),
+        clicks_heard,
+    })
+}
+
 // === Monitor enumeration ===
 
 /// Monitor information populated from winit's MonitorHandle.
@@ -103,7 +508,7 @@ pub struct MonitorInfo {
 ///
 /// Used by `MainLoader::get_available_display_mode()` to provide complete
 /// display mode data for fullscreen mode selection.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct VideoModeInfo {
     pub width: u32,
     pub height: u32,
@@ -240,6 +645,56 @@ pub fn cached_desktop_display_mode() -> (u32, u32) {
     *lock_or_recover(&CACHED_DESKTOP_MODE)
 }
 
+/// File the game writes its enumerated display modes to.
+///
+/// The launcher runs on eframe, which does not expose winit's monitor list,
+/// so it reads the modes found by the last game run from this file instead.
+pub const DISPLAY_MODE_CACHE: &str = "displaymodes.json";
+
+#[derive(Default, serde::Serialize, serde::Deserialize)]
+struct DisplayModeCache {
+    desktop: (u32, u32),
+    modes: Vec<VideoModeInfo>,
+}
+
+/// Write the cached display modes (see [`update_monitors_from_winit`]) to `path`.
+pub fn save_display_mode_cache(path: &std::path::Path) -> anyhow::Result<()> {
+    let cache = DisplayModeCache {
+        desktop: cached_desktop_display_mode(),
+        modes: cached_full_display_modes(),
+    };
+    std::fs::write(path, serde_json::to_string(&cache)?)?;
+    Ok(())
+}
+
+/// Fill the display mode caches from a file written by [`save_display_mode_cache`].
+///
+/// Does nothing if the caches were already populated by a winit event loop.
+/// Returns true if the caches were filled.
+pub fn load_display_mode_cache(path: &std::path::Path) -> anyhow::Result<bool> {
+    if !cached_full_display_modes().is_empty() {
+        return Ok(false);
+    }
+    let cache: DisplayModeCache = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    let mut display_modes: Vec<(u32, u32)> =
+        cache.modes.iter().map(|m| (m.width, m.height)).collect();
+    display_modes.sort();
+    display_modes.dedup();
+    *lock_or_recover(&CACHED_DISPLAY_MODES) = display_modes;
+    *lock_or_recover(&CACHED_FULL_DISPLAY_MODES) = cache.modes;
+    *lock_or_recover(&CACHED_DESKTOP_MODE) = cache.desktop;
+    Ok(true)
+}
+
+/// Record the desktop resolution reported by the windowing toolkit when no
+/// winit enumeration has happened yet (the launcher reads it from egui).
+pub fn set_desktop_display_mode_if_unknown(width: u32, height: u32) {
+    let mut desktop = lock_or_recover(&CACHED_DESKTOP_MODE);
+    if *desktop == (0, 0) {
+        *desktop = (width, height);
+    }
+}
+
 #[cfg(target_os = "macos")]
 fn get_monitors_macos() -> Vec<MonitorInfo> {
     // CoreGraphics FFI for display enumeration
@@ -369,6 +824,23 @@ mod tests {
         assert_ne!(mode1, mode3);
     }
 
+    #[test]
+    fn display_mode_cache_roundtrip() {
+        let cache = DisplayModeCache {
+            desktop: (2560, 1440),
+            modes: vec![VideoModeInfo {
+                width: 1920,
+                height: 1080,
+                refresh_rate_millihertz: 144000,
+                bit_depth: 32,
+            }],
+        };
+        let json = serde_json::to_string(&cache).unwrap();
+        let parsed: DisplayModeCache = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.desktop, (2560, 1440));
+        assert_eq!(parsed.modes, cache.modes);
+    }
+
     #[test]
     fn video_mode_info_sorting() {
         let mut modes = [
@@ -480,4 +952,63 @@ mod tests {
         let formatted = format!("{} [{}, {}]", info.name, info.virtual_x, info.virtual_y);
         assert_eq!(formatted, "Display 2 [-1920, 0]");
     }
+
+    #[test]
+    fn device_buffer_sizes_follow_reported_range() {
+        let mut info = DeviceInfo {
+            name: "Test Device".to_string(),
+            buffer_size_range: Some((128, 512)),
+            sample_rates: Vec::new(),
+        };
+        assert_eq!(info.buffer_sizes(), vec![128, 256, 384, 512]);
+        info.buffer_size_range = None;
+        assert_eq!(info.buffer_sizes(), AUDIO_BUFFER_SIZES.to_vec());
+    }
+
+    #[test]
+    fn onset_detector_skips_frames_within_holdoff() {
+        let mut detector = OnsetDetector::new(0.5, 3);
+        assert_eq!(detector.feed([0.0, 0.9, 0.9, 0.0, 0.0, 0.8]), vec![1, 5]);
+        // Holdoff carries over to the next buffer
+        assert!(detector.feed([0.9]).is_empty());
+        assert_eq!(detector.feed([0.0, 0.0, 0.6]), vec![2]);
+    }
+
+    #[test]
+    fn click_delays_pair_each_click_with_the_next_onset() {
+        let delays = click_delays(&[1.0, 2.0, 3.0], &[0.5, 1.02, 2.03, 3.5], 0.4);
+        assert_eq!(delays.len(), 2);
+        assert!((delays[0] - 0.02).abs() < 1e-9);
+        assert!((delays[1] - 0.03).abs() < 1e-9);
+        assert_eq!(median(vec![3.0, 1.0, 2.0]), Some(2.0));
+        assert_eq!(median(vec![4.0, 1.0]), Some(2.5));
+        assert_eq!(median(Vec::new()), None);
+    }
+
+    #[test]
+    fn round_trip_output_latency_excludes_capture_side() {
+        let result = RoundTripResult {
+            device: "Speakers".to_string(),
+            sample_rate: 48000,
+            round_trip_ms: 30.0,
+            input_latency_ms: Some(8.0),
+            clicks_played: 8,
+            clicks_heard: 8,
+        };
+        assert_eq!(result.output_latency_ms(), 22.0);
+        let unreported = RoundTripResult {
+            input_latency_ms: None,
+            ..result
+        };
+        assert_eq!(unreported.output_latency_ms(), 30.0);
+    }
+
+    #[test]
+    fn supported_sample_rates_match_any_range() {
+        assert_eq!(
+            supported_sample_rates(&[(44100, 48000), (96000, 96000)]),
+            vec![44100, 48000, 96000]
+        );
+        assert!(supported_sample_rates(&[]).is_empty());
+    }
 }
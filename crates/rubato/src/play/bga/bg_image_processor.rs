@@ -1,15 +1,32 @@
-use std::path::Path;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
 
 use crate::render::texture::Texture;
+use crate::render::texture_budget::TextureBudget;
 
 /// Image file extensions supported for BGA
 pub static PIC_EXTENSION: &[&str] = &["jpg", "jpeg", "gif", "bmp", "png", "tga"];
 
+/// Decoded image memory kept until `set_budget` applies the configured one.
+const DEFAULT_IMAGE_BUDGET: usize = 1024 * 1024 * 1024;
+
 /// BG image resource manager
+///
+/// Decoded images are tracked against a memory budget. When it is exceeded the
+/// least recently shown images are dropped; their paths are kept so they can be
+/// decoded again on a background thread when the chart reaches them.
 pub struct BGImageProcessor {
     bgamap: Vec<Option<Texture>>,
+    /// Source file of each BGA id, kept so evicted images can be decoded again
+    paths: Vec<Option<PathBuf>>,
     bgacache_ids: Vec<i32>,
     cache_size: usize,
+    budget: TextureBudget<usize>,
+    loader: Option<ImageLoader>,
+    /// Ids requested from the loader and not received yet
+    loading: HashSet<usize>,
 }
 
 impl BGImageProcessor {
@@ -17,22 +34,43 @@ impl BGImageProcessor {
         debug_assert!(size > 0, "BGImageProcessor cache_size must be > 0");
         BGImageProcessor {
             bgamap: vec![None; 1000],
+            paths: vec![None; 1000],
             bgacache_ids: vec![-1; size],
             cache_size: size,
+            budget: TextureBudget::new(DEFAULT_IMAGE_BUDGET),
+            loader: None,
+            loading: HashSet::new(),
         }
     }
 
+    /// Limit for the memory of decoded images (`Config.render.texture_budget`).
+    pub fn set_budget(&mut self, bytes: usize) {
+        self.budget.set_limit_bytes(bytes);
+        self.evict_over_budget(None);
+    }
+
+    /// Memory held by decoded images, in bytes.
+    pub fn memory_bytes(&self) -> usize {
+        self.budget.used_bytes()
+    }
+
+    /// Register the image of BGA `id`. It is decoded right away while the
+    /// budget has room, otherwise when the chart first needs it.
     pub fn put(&mut self, id: usize, path: &Path) {
-        if id >= self.bgamap.len() {
-            self.bgamap.resize_with(id + 1, || None);
+        self.ensure_len(id);
+        self.paths[id] = Some(path.to_path_buf());
+        if self.budget.used_bytes() >= self.budget.limit_bytes() {
+            self.unload(id);
+            return;
         }
         let path_str = path.to_str().unwrap_or("");
         let tex = Texture::new(path_str);
         if tex.width > 0 && tex.height > 0 {
-            self.bgamap[id] = Some(tex);
+            self.store_if_fits(id, tex);
         } else {
             log::warn!("Failed to load BGA image: {}", path_str);
-            self.bgamap[id] = None;
+            self.paths[id] = None;
+            self.unload(id);
         }
     }
 
@@ -40,15 +78,20 @@ impl BGImageProcessor {
         for item in self.bgamap.iter_mut() {
             *item = None;
         }
+        for path in self.paths.iter_mut() {
+            *path = None;
+        }
+        self.budget.clear();
+        self.loading.clear();
     }
 
     pub fn dispose_old(&mut self) {
         // Evict textures not in the active cache window.
         // Cache IDs track which BGA IDs are actively in use by the current timelines.
         // Textures outside this set can be released to save memory.
-        for (id, slot) in self.bgamap.iter_mut().enumerate() {
-            if slot.is_some() && !self.bgacache_ids.contains(&(id as i32)) {
-                *slot = None;
+        for id in 0..self.bgamap.len() {
+            if self.bgamap[id].is_some() && !self.bgacache_ids.contains(&(id as i32)) {
+                self.unload(id);
             }
         }
     }
@@ -65,31 +108,289 @@ impl BGImageProcessor {
         }
     }
 
+    /// Start decoding evicted images among `ids` (upcoming BGA ids) so they
+    /// are ready by the time they are shown.
+    pub fn prefetch(&mut self, ids: &[usize]) {
+        self.receive_loaded();
+        for &id in ids {
+            self.request_load(id);
+        }
+    }
+
     /// Get the texture for the given BGA id, updating the cache.
-    /// Returns a reference to the texture if it exists.
+    /// Returns a reference to the texture if it exists. An image evicted
+    /// by the budget is requested from the loader and returns None until
+    /// it has been decoded again.
     pub fn texture(&mut self, id: usize) -> Option<&Texture> {
+        self.receive_loaded();
         let cid = id % self.cache_size;
-        if self.bgacache_ids[cid] == id as i32 {
-            return self.bgamap.get(id).and_then(|t| t.as_ref());
-        }
         if id < self.bgamap.len() && self.bgamap[id].is_some() {
             self.bgacache_ids[cid] = id as i32;
+            self.budget.touch(&id);
             return self.bgamap.get(id).and_then(|t| t.as_ref());
         }
+        self.request_load(id);
         None
     }
 
-    /// Directly insert a texture at the given id (for testing).
-    #[cfg(test)]
+    /// Directly insert an already decoded texture at the given id.
+    /// The texture is dropped (and decoded again when needed) if it does
+    /// not fit the budget.
     pub fn put_texture(&mut self, id: usize, tex: Texture) {
-        if id >= self.bgamap.len() {
-            self.bgamap.resize_with(id + 1, || None);
+        self.ensure_len(id);
+        if let Some(path) = tex.path.as_deref() {
+            self.paths[id] = Some(PathBuf::from(path));
         }
-        self.bgamap[id] = Some(tex);
+        self.store_if_fits(id, tex);
     }
 
     pub fn dispose(&mut self) {
         self.bgamap.clear();
+        self.paths.clear();
         self.bgacache_ids.clear();
+        self.budget.clear();
+        self.loading.clear();
+        self.loader = None;
+    }
+
+    fn ensure_len(&mut self, id: usize) {
+        if id >= self.bgamap.len() {
+            self.bgamap.resize_with(id + 1, || None);
+        }
+        if id >= self.paths.len() {
+            self.paths.resize_with(id + 1, || None);
+        }
+    }
+
+    fn unload(&mut self, id: usize) {
+        if let Some(slot) = self.bgamap.get_mut(id) {
+            *slot = None;
+        }
+        self.budget.remove(&id);
+    }
+
+    fn store_if_fits(&mut self, id: usize, tex: Texture) {
+        let bytes = texture_memory(&tex);
+        self.unload(id);
+        if self.budget.fits(bytes) {
+            self.budget.insert(id, bytes);
+            self.bgamap[id] = Some(tex);
+        } else if self.paths[id].is_none() {
+            // Nothing to reload it from later; keep it regardless of the budget.
+            self.budget.insert(id, bytes);
+            self.bgamap[id] = Some(tex);
+        }
+    }
+
+    /// Store a texture decoded while playing, evicting older images if needed.
+    fn store(&mut self, id: usize, tex: Texture) {
+        self.ensure_len(id);
+        self.budget.insert(id, texture_memory(&tex));
+        self.bgamap[id] = Some(tex);
+        self.evict_over_budget(Some(id));
+    }
+
+    fn evict_over_budget(&mut self, keep: Option<usize>) {
+        let paths = &self.paths;
+        let reloadable = |id: usize| paths.get(id).is_some_and(|p| p.is_some());
+        for id in self
+            .budget
+            .evict_over_budget(|&k| Some(k) == keep || !reloadable(k))
+        {
+            if let Some(slot) = self.bgamap.get_mut(id) {
+                *slot = None;
+            }
+        }
+    }
+
+    fn request_load(&mut self, id: usize) {
+        if self.bgamap.get(id).is_some_and(|t| t.is_some()) || self.loading.contains(&id) {
+            return;
+        }
+        let Some(path) = self.paths.get(id).and_then(|p| p.clone()) else {
+            return;
+        };
+        let loader = self.loader.get_or_insert_with(ImageLoader::start);
+        if loader.request(id, path) {
+            self.loading.insert(id);
+        }
+    }
+
+    fn receive_loaded(&mut self) {
+        let Some(loader) = self.loader.as_ref() else {
+            return;
+        };
+        let loaded: Vec<(usize, Option<Texture>)> = loader.results.try_iter().collect();
+        for (id, tex) in loaded {
+            // Ignore results for ids forgotten by clear() in the meantime
+            if !self.loading.remove(&id) {
+                continue;
+            }
+            match tex {
+                Some(tex) => self.store(id, tex),
+                None => {
+                    if let Some(path) = self.paths.get_mut(id) {
+                        *path = None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn texture_memory(tex: &Texture) -> usize {
+    tex.rgba_data.as_ref().map_or(0, |data| data.len())
+}
+
+/// Background thread decoding evicted BGA images again.
+struct ImageLoader {
+    requests: Option<mpsc::Sender<(usize, PathBuf)>>,
+    results: mpsc::Receiver<(usize, Option<Texture>)>,
+}
+
+impl ImageLoader {
+    fn start() -> Self {
+        let (request_tx, request_rx) = mpsc::channel::<(usize, PathBuf)>();
+        let (result_tx, result_rx) = mpsc::channel();
+        let spawned = thread::Builder::new()
+            .name("bga-image-loader".to_string())
+            .spawn(move || {
+                for (id, path) in request_rx {
+                    let path_str = path.to_string_lossy();
+                    let tex = Texture::new(&path_str);
+                    let tex = if tex.width > 0 && tex.height > 0 {
+                        Some(tex)
+                    } else {
+                        log::warn!("Failed to reload BGA image: {}", path_str);
+                        None
+                    };
+                    if result_tx.send((id, tex)).is_err() {
+                        break;
+                    }
+                }
+            });
+        let requests = match spawned {
+            Ok(_) => Some(request_tx),
+            Err(e) => {
+                log::warn!("Failed to spawn BGA image loader thread: {}", e);
+                None
+            }
+        };
+        Self {
+            requests,
+            results: result_rx,
+        }
+    }
+
+    fn request(&self, id: usize, path: PathBuf) -> bool {
+        self.requests
+            .as_ref()
+            .is_some_and(|tx| tx.send((id, path)).is_ok())
+    }
+}
+
+impl Drop for ImageLoader {
+    fn drop(&mut self) {
+        // Closing the request channel ends the thread after the image being
+        // decoded; it is detached rather than joined to keep dispose non-blocking.
+        self.requests.take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2x2 RGBA image: 16 bytes decoded.
+    const IMAGE_BYTES: usize = 16;
+
+    fn write_images(dir: &Path, count: usize) -> Vec<PathBuf> {
+        (0..count)
+            .map(|i| {
+                let path = dir.join(format!("{i}.png"));
+                image::RgbaImage::new(2, 2).save(&path).unwrap();
+                path
+            })
+            .collect()
+    }
+
+    fn wait_texture(proc: &mut BGImageProcessor, id: usize) {
+        for _ in 0..500 {
+            if proc.texture(id).is_some() {
+                return;
+            }
+            thread::sleep(std::time::Duration::from_millis(5));
+        }
+        panic!("BGA image {id} was not reloaded");
+    }
+
+    #[test]
+    fn put_decodes_images_while_they_fit_the_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = write_images(dir.path(), 3);
+        let mut proc = BGImageProcessor::new(256, 1);
+        proc.set_budget(IMAGE_BYTES * 2);
+        for (id, path) in paths.iter().enumerate() {
+            proc.put(id, path);
+        }
+
+        assert_eq!(proc.memory_bytes(), IMAGE_BYTES * 2);
+        assert!(proc.bgamap[0].is_some());
+        assert!(proc.bgamap[1].is_some());
+        assert!(proc.bgamap[2].is_none());
+        assert!(proc.paths[2].is_some());
+    }
+
+    #[test]
+    fn evicted_image_is_reloaded_on_demand_and_lru_is_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = write_images(dir.path(), 3);
+        let mut proc = BGImageProcessor::new(256, 1);
+        proc.set_budget(IMAGE_BYTES * 2);
+        for (id, path) in paths.iter().enumerate() {
+            proc.put(id, path);
+        }
+        assert!(proc.texture(1).is_some());
+
+        // Not decoded yet: the first request returns nothing and starts a reload.
+        assert!(proc.texture(2).is_none());
+        wait_texture(&mut proc, 2);
+
+        // Image 0 was the least recently shown one.
+        assert!(proc.bgamap[0].is_none());
+        assert!(proc.bgamap[1].is_some());
+        assert_eq!(proc.memory_bytes(), IMAGE_BYTES * 2);
+    }
+
+    #[test]
+    fn prefetch_decodes_upcoming_images_in_the_background() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = write_images(dir.path(), 2);
+        let mut proc = BGImageProcessor::new(256, 1);
+        proc.set_budget(0);
+        for (id, path) in paths.iter().enumerate() {
+            proc.put(id, path);
+        }
+        assert_eq!(proc.memory_bytes(), 0);
+
+        proc.set_budget(IMAGE_BYTES * 2);
+        proc.prefetch(&[0, 1]);
+        wait_texture(&mut proc, 0);
+        wait_texture(&mut proc, 1);
+        assert_eq!(proc.memory_bytes(), IMAGE_BYTES * 2);
+    }
+
+    #[test]
+    fn preloaded_texture_without_path_is_kept_over_budget() {
+        let mut proc = BGImageProcessor::new(256, 1);
+        proc.set_budget(0);
+        let tex = Texture {
+            width: 2,
+            height: 2,
+            rgba_data: Some(std::sync::Arc::new(vec![0; IMAGE_BYTES])),
+            ..Default::default()
+        };
+        proc.put_texture(3, tex);
+        assert!(proc.texture(3).is_some());
     }
 }
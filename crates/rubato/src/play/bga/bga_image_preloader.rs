@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use bms::model::bms_model::BMSModel;
+
+use crate::play::bga::bg_image_processor::PIC_EXTENSION;
+use crate::render::texture::Texture;
+use crate::skin::sync_utils::lock_or_recover;
+
+/// Still-image BGA of one chart, decoded ahead of Play.
+/// Shared between the loader thread, the decide screen (progress) and BMSPlayer (textures).
+pub struct BgaImagePreload {
+    sha256: String,
+    total: usize,
+    loaded: AtomicUsize,
+    current_file: Mutex<String>,
+    images: Mutex<HashMap<usize, Texture>>,
+    cancelled: AtomicBool,
+}
+
+impl BgaImagePreload {
+    /// Whether this preload was started for `model`.
+    pub fn is_for(&self, model: &BMSModel) -> bool {
+        self.sha256 == model.sha256
+    }
+
+    /// Decoding progress (0.0-1.0). 1.0 when the chart has no image BGA.
+    pub fn progress(&self) -> f32 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        self.loaded.load(Ordering::Acquire) as f32 / self.total as f32
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.loaded.load(Ordering::Acquire) >= self.total
+    }
+
+    /// File name of the image being decoded, empty when idle.
+    pub fn current_file(&self) -> String {
+        lock_or_recover(&self.current_file).clone()
+    }
+
+    /// Take the decoded texture for BGA `id`, if it is ready.
+    pub fn take_image(&self, id: usize) -> Option<Texture> {
+        lock_or_recover(&self.images).remove(&id)
+    }
+}
+
+/// Owns the loader thread of a `BgaImagePreload`. Dropping it stops decoding
+/// of the remaining images; textures decoded so far stay in the shared preload.
+pub struct BgaImagePreloader {
+    preload: Arc<BgaImagePreload>,
+    handles: Vec<thread::JoinHandle<()>>,
+}
+
+impl BgaImagePreloader {
+    /// Start decoding the still-image entries of `model.bgamap` in the background.
+    /// Decoding stops once the images take `budget_bytes`; the rest are
+    /// decoded by the BGA cache when the chart reaches them.
+    pub fn start(model: &BMSModel, budget_bytes: usize) -> Self {
+        let base_dir = model
+            .path()
+            .and_then(|p| std::path::Path::new(&p).parent().map(|d| d.to_path_buf()));
+        let entries: Vec<(usize, PathBuf)> = base_dir
+            .map(|dir| {
+                model
+                    .bgamap
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, entry)| !entry.is_empty())
+                    .map(|(id, entry)| (id, dir.join(entry)))
+                    .filter(|(_, path)| is_picture(path) && path.exists())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let preload = Arc::new(BgaImagePreload {
+            sha256: model.sha256.clone(),
+            total: entries.len(),
+            loaded: AtomicUsize::new(0),
+            current_file: Mutex::new(String::new()),
+            images: Mutex::new(HashMap::new()),
+            cancelled: AtomicBool::new(false),
+        });
+        // Images decode independently: split them over a few workers that take
+        // the next entry in chart order, leaving cores for keysound decoding.
+        let workers = thread::available_parallelism()
+            .map_or(1, |n| n.get() / 2)
+            .clamp(1, MAX_WORKERS)
+            .min(entries.len());
+        let entries = Arc::new(entries);
+        let next = Arc::new(AtomicUsize::new(0));
+        let decoded_bytes = Arc::new(AtomicUsize::new(0));
+        let handles: Vec<thread::JoinHandle<()>> = (0..workers)
+            .filter_map(|i| {
+                let shared = Arc::clone(&preload);
+                let entries = Arc::clone(&entries);
+                let next = Arc::clone(&next);
+                let decoded_bytes = Arc::clone(&decoded_bytes);
+                thread::Builder::new()
+                    .name(format!("bga-preload-{}", i))
+                    .spawn(move || {
+                        decode_entries(&shared, &entries, &next, &decoded_bytes, budget_bytes)
+                    })
+                    .map_err(|e| log::warn!("Failed to spawn BGA preload thread: {}", e))
+                    .ok()
+            })
+            .collect();
+        if handles.is_empty() {
+            // Nothing to decode in the background; report completion.
+            preload.loaded.store(preload.total, Ordering::Release);
+        }
+        Self { preload, handles }
+    }
+
+    pub fn preload(&self) -> &Arc<BgaImagePreload> {
+        &self.preload
+    }
+}
+
+impl Drop for BgaImagePreloader {
+    fn drop(&mut self) {
+        self.preload.cancelled.store(true, Ordering::Release);
+        // Detach instead of joining: the images being decoded may take a while.
+        self.handles.clear();
+    }
+}
+
+/// Upper limit of preload worker threads.
+const MAX_WORKERS: usize = 4;
+
+/// Worker loop: decode entries until none are left, decoding is cancelled,
+/// or the decoded images fill the budget.
+fn decode_entries(
+    shared: &BgaImagePreload,
+    entries: &[(usize, PathBuf)],
+    next: &AtomicUsize,
+    decoded_bytes: &AtomicUsize,
+    budget_bytes: usize,
+) {
+    loop {
+        if shared.cancelled.load(Ordering::Acquire) {
+            break;
+        }
+        let Some((id, path)) = entries.get(next.fetch_add(1, Ordering::AcqRel)) else {
+            break;
+        };
+        if decoded_bytes.load(Ordering::Acquire) >= budget_bytes {
+            shared.loaded.fetch_add(1, Ordering::AcqRel);
+            continue;
+        }
+        *lock_or_recover(&shared.current_file) = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let texture = Texture::new(&path.to_string_lossy());
+        if texture.width > 0 && texture.height > 0 {
+            let bytes = texture.rgba_data.as_ref().map_or(0, |d| d.len());
+            decoded_bytes.fetch_add(bytes, Ordering::AcqRel);
+            lock_or_recover(&shared.images).insert(*id, texture);
+        }
+        shared.loaded.fetch_add(1, Ordering::AcqRel);
+    }
+    if shared.is_finished() {
+        lock_or_recover(&shared.current_file).clear();
+    }
+}
+
+fn is_picture(path: &std::path::Path) -> bool {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    PIC_EXTENSION.iter().any(|&e| e == ext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_png(path: &std::path::Path) {
+        image::RgbaImage::new(2, 2).save(path).unwrap();
+    }
+
+    fn model_with_bga(dir: &std::path::Path, entries: &[&str]) -> BMSModel {
+        let mut model = BMSModel::new();
+        model.info = Some(bms::model::chart_information::ChartInformation::new(
+            Some(dir.join("chart.bms")),
+            bms::model::bms_model::LnType::LongNote,
+            None,
+        ));
+        model.sha256 = "abc".to_string();
+        model.bgamap = entries.iter().map(|e| e.to_string()).collect();
+        model
+    }
+
+    fn wait_finished(preload: &BgaImagePreload) {
+        for _ in 0..500 {
+            if preload.is_finished() {
+                return;
+            }
+            thread::sleep(std::time::Duration::from_millis(10));
+        }
+        panic!("BGA preload did not finish");
+    }
+
+    #[test]
+    fn test_preload_decodes_images_and_skips_movies() {
+        let dir = tempfile::tempdir().unwrap();
+        write_png(&dir.path().join("a.png"));
+        write_png(&dir.path().join("b.png"));
+        std::fs::write(dir.path().join("c.mp4"), b"").unwrap();
+        let model = model_with_bga(dir.path(), &["a.png", "", "b.png", "c.mp4", "missing.png"]);
+
+        let preloader = BgaImagePreloader::start(&model, usize::MAX);
+        let preload = Arc::clone(preloader.preload());
+        wait_finished(&preload);
+
+        assert!(preload.is_for(&model));
+        assert_eq!(preload.progress(), 1.0);
+        assert!(preload.take_image(0).is_some());
+        assert!(preload.take_image(0).is_none());
+        assert!(preload.take_image(2).is_some());
+        assert!(preload.take_image(3).is_none());
+    }
+
+    #[test]
+    fn test_preload_stops_decoding_at_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        let names: Vec<String> = (0..12).map(|i| format!("{i}.png")).collect();
+        for name in &names {
+            write_png(&dir.path().join(name));
+        }
+        let entries: Vec<&str> = names.iter().map(String::as_str).collect();
+        let model = model_with_bga(dir.path(), &entries);
+
+        // One 2x2 image (16 bytes) fills the budget; workers already decoding
+        // may each finish one more.
+        let preloader = BgaImagePreloader::start(&model, 16);
+        let preload = Arc::clone(preloader.preload());
+        wait_finished(&preload);
+
+        assert_eq!(preload.progress(), 1.0);
+        let decoded = (0..names.len())
+            .filter(|&id| preload.take_image(id).is_some())
+            .count();
+        assert!((1..=MAX_WORKERS).contains(&decoded), "decoded {decoded}");
+    }
+
+    #[test]
+    fn test_preload_without_images_is_finished() {
+        let dir = tempfile::tempdir().unwrap();
+        let model = model_with_bga(dir.path(), &[]);
+        let preloader = BgaImagePreloader::start(&model, usize::MAX);
+        assert!(preloader.preload().is_finished());
+        assert_eq!(preloader.preload().progress(), 1.0);
+    }
+}
@@ -16,6 +16,14 @@ pub static MOV_EXTENSION: &[&str] = &[
     "mp4", "wmv", "m4v", "webm", "mpg", "mpeg", "m1v", "m2v", "avi",
 ];
 
+/// Look-ahead window (ms): still images shown within this time from the
+/// current position are decoded on the loader thread before they appear.
+const PREFETCH_WINDOW_MS: i64 = 3000;
+
+/// Upper limit of image ids requested per update, so a dense window cannot
+/// queue the whole chart at once.
+const PREFETCH_MAX_IDS: usize = 64;
+
 // Re-export shared BGA render type from rubato-types (canonical location).
 pub use crate::skin::bga_types::BgaRenderType;
 
@@ -74,6 +82,10 @@ pub struct BGAProcessor {
     pos: usize,
     rbga: bool,
     rlayer: bool,
+    /// BGA switched off with the hotkey; draws the blank texture instead
+    hidden: bool,
+    /// Darkening applied to the BGA, in percent (Config.render.bga_dim)
+    dim: i32,
 }
 
 impl Default for BGAProcessor {
@@ -107,6 +119,8 @@ impl BGAProcessor {
             pos: 0,
             rbga: false,
             rlayer: false,
+            hidden: false,
+            dim: 0,
         }
     }
 
@@ -163,6 +177,13 @@ impl BGAProcessor {
         self.progress = 1.0;
     }
 
+    /// Memory limit for decoded still images (`Config.render.texture_budget`).
+    pub fn set_texture_budget(&mut self, bytes: usize) {
+        if let Some(ref mut cache) = self.cache {
+            cache.set_budget(bytes);
+        }
+    }
+
     /// Load a static BGA image into the cache at the given id.
     /// Called by PlayerResource for each image BGA definition.
     pub fn put_image(&mut self, id: usize, path: &std::path::Path) {
@@ -171,6 +192,13 @@ impl BGAProcessor {
         }
     }
 
+    /// Store a BGA image decoded ahead of time (see `BgaImagePreload`) at the given id.
+    pub fn put_texture(&mut self, id: usize, tex: Texture) {
+        if let Some(ref mut cache) = self.cache {
+            cache.put_texture(id, tex);
+        }
+    }
+
     /// Set the number of BGA slots (movie + image).
     /// Must be called before set_movie().
     pub fn set_movie_count(&mut self, count: usize) {
@@ -234,6 +262,7 @@ impl BGAProcessor {
     pub fn prepare_bga(&mut self, time: i64) {
         if time < 0 {
             self.time = -1;
+            self.prefetch_images();
             return;
         }
         // Reset scan position when seeking backward (e.g. practice mode scrubbing)
@@ -285,6 +314,52 @@ impl BGAProcessor {
         }
 
         self.time = time;
+        self.prefetch_images();
+    }
+
+    /// Ask the image cache to decode the still images shown within the
+    /// look-ahead window in case the texture budget evicted them, so they
+    /// are not decoded on the frame they appear.
+    fn prefetch_images(&mut self) {
+        let upcoming = self.upcoming_image_ids();
+        if let Some(ref mut cache) = self.cache
+            && !upcoming.is_empty()
+        {
+            cache.prefetch(&upcoming);
+        }
+    }
+
+    /// Still-image ids needed within `PREFETCH_WINDOW_MS` of the current time,
+    /// in the order they appear: BGA/layer changes and the frames of POOR
+    /// layers, which can be shown at any moment once defined.
+    pub(super) fn upcoming_image_ids(&self) -> Vec<usize> {
+        let end = self.time.max(0) + PREFETCH_WINDOW_MS;
+        let miss_frames = self
+            .misslayer
+            .iter()
+            .flat_map(|layer| layer.sequence.iter().flatten().map(|seq| seq.id));
+        let upcoming = self.timelines[self.pos.min(self.timelines.len())..]
+            .iter()
+            .skip_while(|tl| tl.time_ms < self.time)
+            .take_while(|tl| tl.time_ms <= end)
+            .flat_map(|tl| {
+                [tl.bga, tl.layer].into_iter().chain(
+                    tl.eventlayer
+                        .iter()
+                        .flat_map(|layer| layer.sequence.iter().flatten().map(|seq| seq.id)),
+                )
+            });
+        let mut ids: Vec<usize> = Vec::new();
+        for id in miss_frames.chain(upcoming) {
+            if id < 0 || self.is_movie(id) || ids.contains(&(id as usize)) {
+                continue;
+            }
+            ids.push(id as usize);
+            if ids.len() >= PREFETCH_MAX_IDS {
+                break;
+            }
+        }
+        ids
     }
 
     pub fn set_misslayer_tme(&mut self, time: i64) {
@@ -313,6 +388,28 @@ impl BGAProcessor {
         self.progress
     }
 
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// Show or hide the BGA. Returns true if it is now hidden.
+    pub fn toggle_hidden(&mut self) -> bool {
+        self.hidden = !self.hidden;
+        self.hidden
+    }
+
+    pub fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    pub fn dim(&self) -> i32 {
+        self.dim
+    }
+
+    pub fn set_dim(&mut self, dim: i32) {
+        self.dim = dim.clamp(0, 100);
+    }
+
     /// Get currently playing BGA id.
     pub fn current_bga_id(&self) -> i32 {
         self.playingbgaid
@@ -19,11 +19,17 @@ impl BGAProcessor {
         color: (f32, f32, f32, f32),
         blend: i32,
     ) {
-        renderer.set_color_rgba(color.0, color.1, color.2, color.3);
+        let brightness = (100 - self.dim) as f32 / 100.0;
+        renderer.set_color_rgba(
+            color.0 * brightness,
+            color.1 * brightness,
+            color.2 * brightness,
+            color.3,
+        );
         renderer.set_blend(blend);
 
-        if self.time < 0 {
-            // Blank screen before playback starts
+        if self.time < 0 || self.hidden {
+            // Blank screen before playback starts or while switched off
             let blank_region = TextureRegion::from_texture(self.blanktex.clone());
             renderer.draw(&blank_region, r.x, r.y, r.width, r.height);
             return;
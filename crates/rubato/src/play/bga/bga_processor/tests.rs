@@ -392,6 +392,31 @@ fn test_draw_bga_with_movie_uses_ffmpeg_type() {
     assert!(!renderer.draw_calls.is_empty());
 }
 
+#[test]
+fn test_draw_bga_hidden_draws_blank_over_movie() {
+    let mut proc = BGAProcessor::new();
+    proc.progress = 1.0;
+    proc.set_movie_count(1);
+    proc.set_movie(0, Box::new(MockMovieProcessor::new(320, 240)));
+    proc.time = 1000;
+    proc.playingbgaid = 0;
+    assert!(proc.toggle_hidden());
+
+    let mut renderer = MockBgaRenderer::default();
+    let rect = Rectangle::new(0.0, 0.0, 320.0, 240.0);
+    proc.draw_bga(
+        &mut renderer,
+        &rect,
+        StretchType::Stretch,
+        (1.0, 1.0, 1.0, 1.0),
+        0,
+    );
+
+    assert!(!renderer.render_types.contains(&BgaRenderType::Ffmpeg));
+    assert_eq!(renderer.draw_calls, [(0.0, 0.0, 320.0, 240.0)]);
+    assert!(!proc.toggle_hidden());
+}
+
 #[test]
 fn test_draw_bga_sets_color_and_blend() {
     let mut proc = BGAProcessor::new();
@@ -412,6 +437,30 @@ fn test_draw_bga_sets_color_and_blend() {
     assert_eq!(renderer.blend_values[0], 2);
 }
 
+#[test]
+fn test_draw_bga_dim_darkens_color_but_keeps_alpha() {
+    let mut proc = BGAProcessor::new();
+    proc.time = 1000;
+    proc.playingbgaid = -1;
+    proc.set_dim(40);
+
+    let mut renderer = MockBgaRenderer::default();
+    let rect = Rectangle::new(0.0, 0.0, 100.0, 100.0);
+    proc.draw_bga(
+        &mut renderer,
+        &rect,
+        StretchType::Stretch,
+        (1.0, 0.5, 1.0, 0.8),
+        0,
+    );
+
+    let (r, g, b, a) = renderer.color_values[0];
+    assert!((r - 0.6).abs() < 1e-6);
+    assert!((g - 0.3).abs() < 1e-6);
+    assert!((b - 0.6).abs() < 1e-6);
+    assert_eq!(a, 0.8);
+}
+
 #[test]
 fn test_draw_bga_with_layer_uses_layer_type() {
     let mut proc = BGAProcessor::new();
@@ -565,6 +614,31 @@ fn test_draw_bga_miss_layer_active() {
     assert!(!renderer.draw_calls.is_empty());
 }
 
+#[test]
+fn test_upcoming_image_ids_covers_look_ahead_window() {
+    let mut model = model_with_bga_timelines(&[
+        (0, 1, -1),
+        (1_000_000, 2, 3),
+        (2_000_000, 1, -1),
+        (2_500_000, 4, -1),
+        (10_000_000, 5, -1),
+    ]);
+    model.timelines[2].eventlayer = vec![Layer::new(
+        Event::new(EventType::Miss, 0),
+        vec![vec![Sequence::new(0, 6), Sequence::new(100, 7)]],
+    )];
+    let mut proc = BGAProcessor::from_model(&model);
+    proc.set_movie(4, Box::new(MockMovieProcessor::new(64, 64)));
+
+    // Before playback the window starts at the chart start; movies and the
+    // far-away change are left out, repeated ids are requested once.
+    proc.prepare_bga(-1);
+    assert_eq!(proc.upcoming_image_ids(), vec![1, 2, 3, 6, 7]);
+
+    proc.prepare_bga(8_000);
+    assert_eq!(proc.upcoming_image_ids(), vec![6, 7, 5]);
+}
+
 // =========================================================================
 // Backward time seeking (practice mode scrub)
 // =========================================================================
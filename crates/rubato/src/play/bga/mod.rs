@@ -1,4 +1,5 @@
 pub mod bg_image_processor;
+pub mod bga_image_preloader;
 pub mod bga_processor;
 pub mod ffmpeg_processor;
 pub mod movie_processor;
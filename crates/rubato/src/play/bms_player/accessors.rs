@@ -15,9 +15,11 @@ impl BMSPlayer {
             lane_property: None,
             judge: JudgeManager::new(),
             judge_notes: Vec::new(),
+            judge_table: None,
             bga: Arc::new(Mutex::new(BGAProcessor::new_with_resource_gen(
                 song_resource_gen,
             ))),
+            bga_preload: None,
             gauge: None,
             playtime,
             input: PlayerInputState::new(),
@@ -27,7 +29,9 @@ impl BMSPlayer {
             state: PlayState::Preload,
             prevtime: 0,
             practice: PracticeConfiguration::new(),
+            practice_loop_pending: false,
             starttimeoffset: 0,
+            chart_offset: 0,
             rhythm: None,
             startpressedtime: 0,
             adjusted_volume: -1.0,
@@ -57,7 +61,10 @@ impl BMSPlayer {
             course_song_count: 0,
             device_type: crate::input::bms_player_input_device::DeviceType::Keyboard,
             freq_on: false,
+            freq: 100,
             force_no_ir_send: false,
+            judge_trainer_rank: None,
+            judge_trainer_on: false,
             initial_course_combo: 0,
             initial_course_maxcombo: 0,
             orgmode: None,
@@ -68,10 +75,12 @@ impl BMSPlayer {
             song_metadata: crate::skin::song_data::SongMetadata::default(),
             song_data: None,
             player_data: None,
+            today_player_data: None,
             offset_snapshot: Vec::new(),
             cumulative_playtime_seconds: 0,
             replay_key_state: ReplayKeyState::default(),
             input_snapshot: None,
+            judge_thread: None,
         }
     }
 
@@ -90,6 +99,14 @@ impl BMSPlayer {
         self.bga = bga;
     }
 
+    /// Set the BGA images preloaded on the decide screen before calling create().
+    pub fn set_bga_preload(
+        &mut self,
+        preload: Option<Arc<crate::play::bga::bga_image_preloader::BgaImagePreload>>,
+    ) {
+        self.bga_preload = preload;
+    }
+
     /// Get the BGA processor for storing back to PlayerResource after create().
     /// Returns the Arc so the caller can store it for reuse in subsequent plays.
     pub fn bga_processor_arc(&self) -> Arc<Mutex<BGAProcessor>> {
@@ -140,6 +157,20 @@ impl BMSPlayer {
         &self.constraints
     }
 
+    /// Whether this is a class (dan) course, whose gauge is locked to the
+    /// class gauge selected before play.
+    pub fn is_class_course(&self) -> bool {
+        self.is_course_mode
+            && self.constraints.iter().any(|c| {
+                matches!(
+                    c,
+                    CourseDataConstraint::Class
+                        | CourseDataConstraint::Mirror
+                        | CourseDataConstraint::Random
+                )
+            })
+    }
+
     /// Set whether guide SE is enabled before calling create().
     ///
     /// This comes from PlayerConfig.is_guide_se.
@@ -147,6 +178,15 @@ impl BMSPlayer {
         self.is_guide_se = enabled;
     }
 
+    /// Set the timing offset (ms) saved for this chart.
+    pub fn set_chart_offset(&mut self, offset: i32) {
+        self.chart_offset = offset;
+    }
+
+    pub fn chart_offset(&self) -> i32 {
+        self.chart_offset
+    }
+
     /// Set the original BMS mode before chart-option conversion.
     /// Called by the launcher from PlayerResource.original_mode() before create().
     /// Used by SkinGauge to adjust parts count for border alignment when
@@ -283,6 +323,11 @@ impl BMSPlayer {
         self.player_data = Some(data);
     }
 
+    /// Set player data gained today for skin integer property IDs 549-553.
+    pub fn set_today_player_data(&mut self, data: crate::skin::player_data::PlayerData) {
+        self.today_player_data = Some(data);
+    }
+
     /// Set cumulative playtime from PlayerData (in seconds).
     /// Java: PlayerData.getPlaytime() -- total play time across all sessions.
     /// Used for skin integer property IDs 17-19 (playtime_total_hour/minute/second).
@@ -438,6 +483,20 @@ impl BMSPlayer {
         self.score.target_score = score;
     }
 
+    /// Set the per-note judges of a stored replay or score log to race against.
+    ///
+    /// The ghost pacemaker is independent of the target score and is shown
+    /// through its own skin numbers. Ignored in practice mode.
+    pub fn set_ghost(&mut self, ghost: Option<Vec<i32>>) {
+        self.score.ghost = ghost;
+    }
+
+    /// Set the key input log of the replay ghost to draw as key beams.
+    /// Ignored in practice mode.
+    pub fn set_ghost_key_beams(&mut self, keylog: Option<Vec<KeyInputLog>>) {
+        self.score.ghost_beams = keylog.filter(|log| !log.is_empty()).map(GhostKeyBeams::new);
+    }
+
     /// Take the pending global pitch value, if any.
     /// After calling this, the pending value is cleared (consumed).
     /// The caller should apply the returned pitch to the audio driver.
@@ -1,4 +1,8 @@
 use super::*;
+use crate::imgui_notify::ImGuiNotify;
+use crate::modmenu::practice_menu::PracticeMenu;
+use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
 
 impl BMSPlayer {
     pub(super) fn handle_skin_mouse_pressed_impl(&mut self, button: i32, x: i32, y: i32) {
@@ -76,6 +80,9 @@ impl BMSPlayer {
         // increasing milliseconds for debounce/rate-limiting logic.
         let now_millis = self.main_state_data.timer.now_time();
 
+        self.process_hotkeys();
+        self.sync_practice_menu();
+
         // Process control input (START+SELECT, lane cover, hispeed, etc.)
         if let (Some(mut control), Some(lanerender)) =
             (self.input.control.take(), self.lanerender.as_mut())
@@ -105,7 +112,9 @@ impl BMSPlayer {
                 select_pressed: self.input.input_select_pressed,
                 control_key_up: self.input.control_key_up,
                 control_key_down: self.input.control_key_down,
-                control_key_escape_pressed: self.input.control_key_escape_pressed,
+                control_key_left: self.input.control_key_left,
+                control_key_right: self.input.control_key_right,
+                close_pressed: self.input.close_pressed,
                 control_key_num1: self.input.control_key_num1,
                 control_key_num2: self.input.control_key_num2,
                 control_key_num3: self.input.control_key_num3,
@@ -127,6 +136,10 @@ impl BMSPlayer {
             if let Some(speed) = result.play_speed {
                 self.set_play_speed(speed);
             }
+            if result.replay_seek != 0 {
+                let now = self.main_state_data.timer.now_time_for_id(TIMER_PLAY);
+                self.seek_replay(now + result.replay_seek);
+            }
             if result.clear_start {
                 self.input.input_start_pressed = false;
             }
@@ -142,6 +155,11 @@ impl BMSPlayer {
                 self.stop_play();
             } else {
                 self.input.control = Some(control);
+                // Remember in-play hi-speed / lane cover adjustments for this
+                // mode right away instead of only when the play ends.
+                if self.lane_settings_changed() {
+                    self.save_config();
+                }
             }
         }
 
@@ -161,6 +179,125 @@ impl BMSPlayer {
         }
     }
 
+    /// Exchange practice settings with the ModMenu practice window.
+    fn sync_practice_menu(&mut self) {
+        if self.play_mode.mode != crate::core::bms_player_mode::Mode::Practice {
+            return;
+        }
+        if let Some(property) = PracticeMenu::take_edit() {
+            *self.practice.practice_property_mut() = property;
+        }
+        PracticeMenu::publish(Some(self.practice.practice_property()));
+    }
+
+    /// Run the actions of function hotkeys pressed this frame. CLOSE is
+    /// handled by ControlInputProcessor with the other stop conditions.
+    fn process_hotkeys(&mut self) {
+        for hotkey in Hotkey::ALL {
+            let i = hotkey.index();
+            if !self.input.hotkey_states[i] || self.input.prev_hotkey_states[i] {
+                continue;
+            }
+            match hotkey {
+                Hotkey::QuickRetry => {
+                    if self.play_mode.mode == crate::core::bms_player_mode::Mode::Play
+                        && !self.is_course_mode
+                    {
+                        self.input.quick_retry_requested = true;
+                        self.stop_play();
+                    }
+                }
+                Hotkey::Close => {}
+                Hotkey::ToggleBga => {
+                    let hidden = lock_or_recover(&self.bga).toggle_hidden();
+                    self.config.render.bga = if hidden {
+                        crate::skin::config::BgaMode::Off
+                    } else {
+                        crate::skin::config::BgaMode::On
+                    };
+                    self.pending.pending_render_config = Some(self.config.render.clone());
+                }
+                Hotkey::BgaDim => {
+                    use crate::skin::config::{BGA_DIM_MAX, BGA_DIM_STEP};
+                    let dim = self.config.render.bga_dim + BGA_DIM_STEP;
+                    self.config.render.bga_dim = if dim > BGA_DIM_MAX { 0 } else { dim };
+                    lock_or_recover(&self.bga).set_dim(self.config.render.bga_dim);
+                    self.pending.pending_render_config = Some(self.config.render.clone());
+                }
+                Hotkey::HispeedUp | Hotkey::HispeedDown => {
+                    // Disabled by the NO_SPEED course constraint like the control keys
+                    let enabled = self
+                        .input
+                        .control
+                        .as_ref()
+                        .is_some_and(|control| control.is_enable_control());
+                    if enabled && let Some(lanerender) = self.lanerender.as_mut() {
+                        lanerender.change_hispeed(hotkey == Hotkey::HispeedUp);
+                    }
+                }
+                Hotkey::HispeedFix => {
+                    let enabled = self
+                        .input
+                        .control
+                        .as_ref()
+                        .is_some_and(|control| control.is_enable_control());
+                    if enabled && let Some(lanerender) = self.lanerender.as_mut() {
+                        lanerender.cycle_fixhispeed();
+                    }
+                }
+                Hotkey::PracticeStartMarker | Hotkey::PracticeEndMarker => {
+                    if self.play_mode.mode != crate::core::bms_player_mode::Mode::Practice
+                        || self.state != PlayState::Play
+                    {
+                        continue;
+                    }
+                    // The practice model is time-scaled by 100 / freq
+                    let freq = self.practice.practice_property().freq as i64;
+                    let time = self.main_state_data.timer.now_time_for_id(TIMER_PLAY) * freq / 100;
+                    if hotkey == Hotkey::PracticeStartMarker {
+                        self.practice.set_start_marker(time);
+                    } else {
+                        self.practice.set_end_marker(time);
+                    }
+                }
+                Hotkey::PracticeLoop | Hotkey::PracticeFreezeGauge => {
+                    if self.play_mode.mode != crate::core::bms_player_mode::Mode::Practice {
+                        continue;
+                    }
+                    let property = self.practice.practice_property_mut();
+                    if hotkey == Hotkey::PracticeLoop {
+                        property.loopsection = !property.loopsection;
+                    } else {
+                        property.freezegauge = !property.freezegauge;
+                    }
+                }
+                Hotkey::PracticeRateUp | Hotkey::PracticeRateDown => {
+                    if self.play_mode.mode != crate::core::bms_player_mode::Mode::Practice {
+                        continue;
+                    }
+                    // The running model keeps its rate; the next run picks this up
+                    let property = self.practice.practice_property_mut();
+                    let step = if hotkey == Hotkey::PracticeRateUp {
+                        5
+                    } else {
+                        -5
+                    };
+                    property.freq = (property.freq + step).clamp(50, 200);
+                }
+                Hotkey::ChartOffsetUp | Hotkey::ChartOffsetDown => {
+                    let step = if hotkey == Hotkey::ChartOffsetUp {
+                        CHART_OFFSET_STEP
+                    } else {
+                        -CHART_OFFSET_STEP
+                    };
+                    self.chart_offset = (self.chart_offset + step).clamp(-500, 500);
+                    self.pending.pending_chart_offset = Some(self.chart_offset);
+                    ImGuiNotify::info(&tr!("notify-chart-offset", offset = self.chart_offset));
+                }
+            }
+        }
+    }
+
     pub(super) fn sync_input_from_impl(&mut self, input: &BMSPlayerInputProcessor) {
         self.input.input_start_pressed = input.start_pressed();
         self.input.input_select_pressed = input.is_select_pressed();
@@ -176,7 +313,11 @@ impl BMSPlayer {
         self.input.control_key_down = input.control_key_state(ControlKeys::Down);
         self.input.control_key_left = input.control_key_state(ControlKeys::Left);
         self.input.control_key_right = input.control_key_state(ControlKeys::Right);
-        self.input.control_key_escape_pressed = input.control_key_state(ControlKeys::Escape);
+        self.input.close_pressed = input.hotkey_state(Hotkey::Close);
+        self.input.prev_hotkey_states = self.input.hotkey_states;
+        for hotkey in Hotkey::ALL {
+            self.input.hotkey_states[hotkey.index()] = input.hotkey_state(hotkey);
+        }
         self.input.control_key_num1 = input.control_key_state(ControlKeys::Num1);
         self.input.control_key_num2 = input.control_key_state(ControlKeys::Num2);
         self.input.control_key_num3 = input.control_key_state(ControlKeys::Num3);
@@ -223,6 +364,7 @@ impl BMSPlayer {
     }
 
     pub(super) fn sync_audio_impl(&mut self, audio: &mut crate::audio::audio_system::AudioSystem) {
+        crate::profile_scope!("BMSPlayer::sync_audio");
         if self.pending.pending_stop_all_notes {
             self.pending.pending_stop_all_notes = false;
             audio.stop_note(None);
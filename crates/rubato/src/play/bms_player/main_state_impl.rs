@@ -1,6 +1,7 @@
 use super::*;
 use crate::core::app_context::GameContext;
 use crate::core::main_state::StateTransition;
+use crate::modmenu::judge_trainer::JudgeTrainer;
 use crate::skin::player_resource_access::ReplayAccess;
 use crate::skin::sync_utils::lock_or_recover;
 
@@ -22,6 +23,14 @@ fn combo_timer_id(player: usize) -> crate::skin::timer_id::TimerId {
     }
 }
 
+fn gauge_increase_timer_id(player: usize) -> crate::skin::timer_id::TimerId {
+    match player {
+        0 => TIMER_GAUGE_INCLEASE_1P,
+        1 => TIMER_GAUGE_INCLEASE_2P,
+        _ => crate::skin::timer_id::TimerId::UNDEFINED,
+    }
+}
+
 fn bomb_timer_id(player: i32, key: i32) -> crate::skin::timer_id::TimerId {
     if player < 2 {
         if key < 10 {
@@ -72,6 +81,18 @@ impl MainState for BMSPlayer {
         self.gauge.as_ref().map(|g| g.value())
     }
 
+    fn now_combo(&self) -> Option<i32> {
+        Some(self.judge.now_combo(0))
+    }
+
+    fn best_score(&self) -> Option<crate::core::score_data::ScoreData> {
+        self.score.db_score.clone()
+    }
+
+    fn crash_snapshot(&self) -> Option<crate::play::crash_recovery::PlaySnapshot> {
+        self.build_crash_snapshot()
+    }
+
     fn take_state_create_effects(&mut self) -> Option<crate::core::main_state::StateCreateEffects> {
         let effects = self.create_side_effects.take()?;
         Some(crate::core::main_state::StateCreateEffects {
@@ -249,6 +270,7 @@ impl MainState for BMSPlayer {
         // Translated from: Java BMSPlayer.create() judge.init() call.
         // Uses from_config() which properly initializes lane_states, note_states,
         // keyassign, sckey, auto_presstime, and all lane iteration state.
+        self.judge_table = BMSPlayerRule::load_judge_table(&self.player_config.judge_settings);
         self.rebuild_judge_system(&mode);
 
         // --- Gauge initialization ---
@@ -256,64 +278,7 @@ impl MainState for BMSPlayer {
         // gauge = GrooveGauge.create(model, gauge_type, resource)
         // For practice mode, gauge is initialized later in the practice loop (line 581).
         if self.play_mode.mode != crate::core::bms_player_mode::Mode::Practice {
-            // Java: gauge = GrooveGauge.create(model, replay != null ? replay.gauge : config.getGauge(), resource);
-            // In replay mode, use the gauge type from the replay data.
-            let gauge_type = if self.play_mode.mode == crate::core::bms_player_mode::Mode::Replay {
-                self.score
-                    .active_replay
-                    .as_ref()
-                    .map(|r| r.gauge)
-                    .unwrap_or(self.player_config.play_settings.gauge)
-            } else {
-                self.player_config.play_settings.gauge
-            };
-            let grade = if self.is_course_mode { 1 } else { 0 };
-
-            // Java: GrooveGauge.create(model, type, resource) extracts gauge property
-            // from course constraints when in course mode. Map constraint variants to
-            // GaugeProperty for courses that specify gauge tables.
-            let gauge_property = if self.is_course_mode {
-                self.constraints.iter().find_map(|c| match c {
-                    CourseDataConstraint::Gauge5Keys => {
-                        Some(crate::play::gauge_property::GaugeProperty::FiveKeys)
-                    }
-                    CourseDataConstraint::Gauge7Keys => {
-                        Some(crate::play::gauge_property::GaugeProperty::SevenKeys)
-                    }
-                    CourseDataConstraint::Gauge9Keys => {
-                        Some(crate::play::gauge_property::GaugeProperty::Pms)
-                    }
-                    CourseDataConstraint::Gauge24Keys => {
-                        Some(crate::play::gauge_property::GaugeProperty::Keyboard)
-                    }
-                    CourseDataConstraint::GaugeLr2 => {
-                        Some(crate::play::gauge_property::GaugeProperty::Lr2)
-                    }
-                    _ => None,
-                })
-            } else {
-                None
-            };
-
-            self.gauge = crate::play::groove_gauge::create_groove_gauge(
-                &self.model,
-                gauge_type,
-                grade,
-                gauge_property,
-            );
-
-            // Java: GrooveGauge.create(model, type, resource) restores gauge values
-            // from the previous course stage. After creating the gauge, read the last
-            // value from each gauge type's log and restore it.
-            if let Some(ref previous_values) = self.previous_gauge_values
-                && let Some(ref mut gauge) = self.gauge
-            {
-                for (i, log) in previous_values.iter().enumerate() {
-                    if let Some(&last_val) = log.last() {
-                        gauge.set_value_by_type(i as i32, last_val);
-                    }
-                }
-            }
+            self.gauge = self.create_play_gauge();
         }
 
         // --- Note expansion rate from PlaySkin ---
@@ -342,12 +307,28 @@ impl MainState for BMSPlayer {
             bga.stop();
             bga.set_movie_count(0);
 
+            // BGA on/off and dim as last set by the config or the play hotkeys
+            let bga_mode = self.config.render.bga;
+            let bga_on = bga_mode == crate::skin::config::BgaMode::On
+                || (bga_mode == crate::skin::config::BgaMode::Auto
+                    && matches!(
+                        self.play_mode.mode,
+                        crate::core::bms_player_mode::Mode::Autoplay
+                            | crate::core::bms_player_mode::Mode::Replay
+                    ));
+            bga.set_hidden(!bga_on);
+            bga.set_dim(self.config.render.bga_dim);
+            bga.set_texture_budget(self.config.render.texture_budget_bytes());
+
             // Load BGA images and movies from model.bgamap.
             // Java: BMSResource dispatches image/movie loading after setModel().
             let base_dir = self
                 .model
                 .path()
                 .and_then(|p| std::path::Path::new(&p).parent().map(|d| d.to_path_buf()));
+            // Images decoded on the decide screen for this chart
+            let preload = self.bga_preload.take().filter(|p| p.is_for(&self.model));
+            let preload = preload.as_deref();
             if let Some(ref dir) = base_dir {
                 bga.set_movie_count(self.model.bgamap.len());
                 for (id, entry) in self.model.bgamap.iter().enumerate() {
@@ -367,7 +348,10 @@ impl MainState for BMSPlayer {
                         .iter()
                         .any(|&e| e == ext)
                     {
-                        bga.put_image(id, &path);
+                        match preload.and_then(|p| p.take_image(id)) {
+                            Some(tex) => bga.put_texture(id, tex),
+                            None => bga.put_image(id, &path),
+                        }
                     } else if crate::play::bga::bga_processor::MOV_EXTENSION
                         .iter()
                         .any(|&e| e == ext)
@@ -420,7 +404,6 @@ impl MainState for BMSPlayer {
         //
         // The caller must pre-load db_score, rival_score, and target_score via
         // set_db_score(), set_rival_score(), and set_target_score() before create().
-        let score = self.score.db_score.clone().unwrap_or_default();
         log::info!("Score data loaded from score database");
 
         let total_notes = self.model.total_notes();
@@ -429,37 +412,28 @@ impl MainState for BMSPlayer {
             self.main_state_data
                 .score
                 .set_target_score_with_ghost(0, None, 0, None, total_notes);
+            self.main_state_data.score.set_ghost(None);
+            self.score.ghost_beams = None;
             self.practice.create(&self.model);
             self.state = PlayState::Practice;
         } else {
-            // Determine the effective target score:
-            // - If rival score is absent or in course mode, use the pre-computed target_score
-            //   (caller should have computed via TargetProperty::from_id().target())
-            // - Otherwise, use the rival score as the target
-            let effective_target = if self.score.rival_score.is_none() || self.is_course_mode {
-                self.score.target_score.clone()
-            } else {
-                self.score.rival_score.clone()
-            };
-
-            let (target_exscore, _target_ghost) = match effective_target {
-                Some(ref t) => (t.exscore(), t.decode_ghost()),
-                None => (0, None),
-            };
-
-            self.main_state_data.score.set_target_score_with_ghost(
-                score.exscore(),
-                score.decode_ghost(),
-                target_exscore,
-                None, // Java BMSPlayer.java:450 intentionally passes null for rivalGhost
-                total_notes,
-            );
+            self.init_pacemaker();
         }
+
+        // Score numbers count from an empty live score, not the stored best
+        let live_score = self.judge.score_data().clone();
+        self.main_state_data
+            .score
+            .update_score_with_notes(Some(&live_score), 0);
     }
 
     fn render(&mut self) {
         let micronow = self.main_state_data.timer.now_micro_time();
 
+        if self.state != PlayState::Play {
+            self.stop_judge_thread();
+        }
+
         // Input start timer
         let input_time = self.main_state_data.skin.as_ref().map_or(0, |s| s.input()) as i64; // skin.getInput() in Java
         if micronow > input_time * 1000 {
@@ -549,7 +523,9 @@ impl MainState for BMSPlayer {
 
             // PlayState::Practice - practice mode config
             PlayState::Practice => {
-                if self.main_state_data.timer.is_timer_on(TIMER_PLAY) {
+                // The model reload requested below lands after this frame
+                let reloading = self.main_state_data.timer.is_timer_on(TIMER_PLAY);
+                if reloading {
                     // Reset for practice restart: reload BMS file to get a fresh model
                     // (modifiers mutate the model during play, so we need a clean copy).
                     // Java: resource.reloadBMSFile(); model = resource.getBMSModel();
@@ -621,13 +597,15 @@ impl MainState for BMSPlayer {
                     .unwrap_or(false);
                 let load_threshold =
                     (self.play_skin.loadstart as i64 + self.play_skin.loadend as i64) * 1000;
-                if key0_pressed
+                let loop_restart = self.practice_loop_pending && !reloading;
+                if (key0_pressed || loop_restart)
                     && self.media_load_finished
                     && micronow > load_threshold
                     && micronow - self.startpressedtime > 1_000_000
                 {
                     // Stop keysound from previous practice run before restarting
                     self.keysound.stop_bg_play();
+                    self.practice_loop_pending = false;
 
                     // Apply practice configuration and start play
                     if let Some(ref mut control) = self.input.control {
@@ -638,6 +616,7 @@ impl MainState for BMSPlayer {
                     let property = self.practice.practice_property().clone();
 
                     // Apply frequency if != 100
+                    self.freq = property.freq;
                     if property.freq != 100 {
                         bms_model_utils::change_frequency(
                             &mut self.model,
@@ -697,6 +676,7 @@ impl MainState for BMSPlayer {
                             (0..gauge.gauge_type_length()).map(|_| Vec::new()).collect();
                     }
                     self.model.judgerank = property.judgerank;
+                    self.model.judgerank_fraction = 0.0;
                     let mode = self.model.mode().copied().unwrap_or(Mode::BEAT_7K);
                     self.rebuild_judge_system(&mode);
                     if let Some(ref mut lr) = self.lanerender {
@@ -730,6 +710,7 @@ impl MainState for BMSPlayer {
                 if self.main_state_data.timer.now_time_for_id(TIMER_FADEOUT) > skin_fadeout {
                     // input.setEnable(true); input.setStartTime(0);
                     self.pending.pending_global_pitch = Some(1.0);
+                    self.save_config();
                     self.pending.pending_state_change = Some(MainStateType::MusicSelect);
                     log::info!("Practice finished, transition to MUSICSELECT");
                 }
@@ -750,6 +731,13 @@ impl MainState for BMSPlayer {
                     self.main_state_data
                         .timer
                         .set_micro_timer(TIMER_RHYTHM, micronow - self.starttimeoffset * 1000);
+                    if let Some(ref mut rhythm) = self.rhythm {
+                        rhythm.restart(
+                            micronow - self.starttimeoffset * 1000,
+                            self.starttimeoffset * 1000,
+                            self.practice.practice_property().freq,
+                        );
+                    }
 
                     // Java: input.setStartTime(micronow + timer.getStartMicroTime() - starttimeoffset * 1000);
                     // Java: input.setKeyLogMarginTime(resource.getMarginTime());
@@ -780,6 +768,7 @@ impl MainState for BMSPlayer {
                         self.starttimeoffset * 1000,
                         initial_bg_vol,
                     );
+                    self.start_judge_thread();
                     log::info!("PlayState::Play");
                 }
             }
@@ -787,7 +776,7 @@ impl MainState for BMSPlayer {
             // PlayState::Play - main gameplay
             PlayState::Play => {
                 let deltatime = micronow - self.prevtime;
-                let playspeed = (self.playspeed).clamp(0, 100) as i64;
+                let playspeed = (self.playspeed).clamp(0, replay_control::MAX_PLAY_SPEED) as i64;
                 let deltaplay = deltatime.saturating_mul(100 - playspeed) / 100;
                 let freq = self.practice.practice_property().freq;
                 let current_play_timer = self.main_state_data.timer.micro_timer(TIMER_PLAY);
@@ -800,7 +789,7 @@ impl MainState for BMSPlayer {
                 if let Some(ref mut rhythm) = self.rhythm {
                     let play_timer_micro =
                         self.main_state_data.timer.now_micro_time_for_id(TIMER_PLAY);
-                    let (rhythm_timer, rhythm_on) =
+                    let rhythm_timer =
                         rhythm.update(&crate::play::rhythm_timer_processor::RhythmUpdateParams {
                             now: self.main_state_data.timer.now_time(),
                             micronow,
@@ -810,11 +799,9 @@ impl MainState for BMSPlayer {
                             freq,
                             play_timer_micro,
                         });
-                    if rhythm_on {
-                        self.main_state_data
-                            .timer
-                            .set_micro_timer(TIMER_RHYTHM, rhythm_timer);
-                    }
+                    self.main_state_data
+                        .timer
+                        .set_micro_timer(TIMER_RHYTHM, rhythm_timer);
                 }
 
                 // Update BG autoplay thread: play time and volume.
@@ -831,20 +818,55 @@ impl MainState for BMSPlayer {
                     self.keysound.update_volume(vol);
                 }
 
+                // Pick up judge trainer changes made from the mod menu
+                self.sync_judge_trainer();
+
                 // Judge update: evaluate key presses against notes
                 // Translated from: Java BMSPlayer.render() judge.update() call
-                {
+                if let Some(ref mut thread) = self.judge_thread {
+                    let play_micro = self.main_state_data.timer.now_micro_time_for_id(TIMER_PLAY);
+                    thread.set_play_time(play_micro);
+                    thread.send_keys(
+                        &self.input.input_key_states,
+                        &self.input.input_key_changed_times,
+                    );
+                    let updates = thread.poll();
+                    self.apply_judge_updates(updates);
+                } else {
                     let play_micro = self.main_state_data.timer.now_micro_time_for_id(TIMER_PLAY);
                     debug_assert!(
                         self.gauge.is_some(),
                         "gauge should be initialized before judge update"
                     );
+                    // Replays feed the keylog instead of device input.
+                    let (key_states, key_changed_times) = if self.feed_replay_input(play_micro) {
+                        (
+                            &self.input.replay_key_states,
+                            &self.input.replay_key_changed_times,
+                        )
+                    } else {
+                        (
+                            &self.input.input_key_states,
+                            &self.input.input_key_changed_times,
+                        )
+                    };
+                    let freeze_gauge = self.play_mode.mode
+                        == crate::core::bms_player_mode::Mode::Practice
+                        && self.practice.practice_property().freezegauge;
                     if let Some(ref mut gauge) = self.gauge {
+                        // A frozen practice gauge is judged on a throwaway copy
+                        let mut frozen;
+                        let gauge = if freeze_gauge {
+                            frozen = gauge.clone();
+                            &mut frozen
+                        } else {
+                            gauge
+                        };
                         self.judge.update(
                             play_micro,
                             &self.judge_notes,
-                            &self.input.input_key_states,
-                            &self.input.input_key_changed_times,
+                            key_states,
+                            key_changed_times,
                             gauge,
                         );
                     } else {
@@ -852,84 +874,7 @@ impl MainState for BMSPlayer {
                             "gauge is None during judge update; all note judgment is skipped"
                         );
                     }
-                    // Trigger key beam timers for newly judged lanes.
-                    // In Java, JudgeManager calls keyinput.inputKeyOn(lane) directly;
-                    // in Rust, we drain the event queue after update().
-                    let judged = self.judge.drain_judged_lanes();
-                    if !judged.is_empty()
-                        && let Some(ref mut keyinput) = self.input.keyinput
-                    {
-                        for lane in judged {
-                            keyinput.input_key_on(lane, &mut self.main_state_data.timer);
-                        }
-                    }
-                    // Trigger judge/combo/bomb timers. Java does this inside
-                    // JudgeManager.update2(); Rust queues it so the main thread
-                    // owns all timer mutations.
-                    let visual_events = self.judge.drain_judged_visual_events();
-                    for event in visual_events {
-                        if event.judge <= self.play_skin.judgetimer {
-                            let bomb_timer =
-                                bomb_timer_id(event.player as i32, event.offset as i32);
-                            if bomb_timer != crate::skin::timer_id::TimerId::UNDEFINED {
-                                self.main_state_data.timer.set_timer_on(bomb_timer);
-                            }
-                        }
-
-                        let judge_timer = judge_timer_id(event.player);
-                        if judge_timer != crate::skin::timer_id::TimerId::UNDEFINED {
-                            self.main_state_data.timer.set_timer_on(judge_timer);
-                        }
-
-                        if self.play_skin.judgeregion >= 3 {
-                            for player in 0..3 {
-                                if player != event.player {
-                                    let combo_timer = combo_timer_id(player);
-                                    if combo_timer != crate::skin::timer_id::TimerId::UNDEFINED {
-                                        self.main_state_data.timer.set_timer_off(combo_timer);
-                                    }
-                                }
-                            }
-                        }
-
-                        let combo_timer = combo_timer_id(event.player);
-                        if combo_timer != crate::skin::timer_id::TimerId::UNDEFINED {
-                            self.main_state_data.timer.set_timer_on(combo_timer);
-                        }
-                    }
-                    // Trigger per-judge side effects (BGA miss layer, score timers,
-                    // pomyu, fullcombo timer). Corresponds to Java BMSPlayer.update(judge, time).
-                    let events = self.judge.drain_judged_events();
-                    for (judge, mtime) in events {
-                        self.update_judge(judge, mtime);
-                    }
-                    // Sync judge states back to model notes so that create_score_data()
-                    // and the result screen can read correct state/play_time values.
-                    // In Java, JudgeManager modifies Note objects in-place via shared
-                    // references; in Rust we must explicitly copy the results back.
-                    self.sync_judge_states_to_model();
-
-                    // Drain keysound events and resolve JudgeNote indices to model
-                    // Notes for audio playback. Corresponds to Java
-                    // keysound.play(note, keyvolume, 0) / keysound.setVolume(note, vol)
-                    // calls in JudgeManager.update().
-                    let key_volume = self.key_volume;
-                    let play_indices = self.judge.drain_keysound_play_indices();
-                    for note_idx in play_indices {
-                        if let Some(note) = self.resolve_judge_note(note_idx) {
-                            self.pending.pending_keysound_plays.push((note, key_volume));
-                        }
-                    }
-                    let vol_indices = self.judge.drain_keysound_volume_set_indices();
-                    for (note_idx, volume) in vol_indices {
-                        if let Some(note) = self.resolve_judge_note(note_idx) {
-                            // NaN sentinel means "use key_volume from config"
-                            let resolved_vol = if volume.is_nan() { key_volume } else { volume };
-                            self.pending
-                                .pending_keysound_volume_sets
-                                .push((note, resolved_vol));
-                        }
-                    }
+                    self.process_judge_results();
                 }
 
                 let ptime = self.main_state_data.timer.now_time_for_id(TIMER_PLAY);
@@ -975,13 +920,20 @@ impl MainState for BMSPlayer {
 
                 // Stage failed check with gauge auto shift
                 // Translated from: Java BMSPlayer.render() lines 782-815
+                let class_course = self.is_class_course();
+                let gauge_type_before = self.gauge.as_ref().map(|g| g.gauge_type());
                 if let Some(ref mut gauge) = self.gauge {
-                    let gas = self.player_config.play_settings.gauge_auto_shift;
                     use crate::skin::groove_gauge::{CLASS, EXHARDCLASS, HAZARD, NORMAL};
                     use crate::skin::player_config::{
                         GAUGEAUTOSHIFT_BESTCLEAR, GAUGEAUTOSHIFT_CONTINUE, GAUGEAUTOSHIFT_NONE,
                         GAUGEAUTOSHIFT_SELECT_TO_UNDER, GAUGEAUTOSHIFT_SURVIVAL_TO_GROOVE,
                     };
+                    // Class courses lock the gauge: no shifting, and an empty gauge fails
+                    let gas = if class_course {
+                        GAUGEAUTOSHIFT_NONE
+                    } else {
+                        self.player_config.play_settings.gauge_auto_shift
+                    };
 
                     if gas == GAUGEAUTOSHIFT_BESTCLEAR || gas == GAUGEAUTOSHIFT_SELECT_TO_UNDER {
                         // Auto-shift to best qualifying gauge
@@ -1048,6 +1000,13 @@ impl MainState for BMSPlayer {
                         }
                     }
                 }
+                // The judge thread keeps judging on the gauge it owns
+                if let Some(ref mut thread) = self.judge_thread
+                    && let Some(gauge_type) = self.gauge.as_ref().map(|g| g.gauge_type())
+                    && Some(gauge_type) != gauge_type_before
+                {
+                    thread.edit(Arc::new(move |_, gauge| gauge.set_type(gauge_type)));
+                }
             }
 
             // PlayState::Failed
@@ -1062,34 +1021,17 @@ impl MainState for BMSPlayer {
                 }
                 self.keysound.stop_bg_play();
 
-                // Quick retry check (START xor SELECT)
+                // Quick retry check (START xor SELECT, or the QUICK RETRY hotkey)
                 // Translated from: Java BMSPlayer.render() lines 663-680
                 // Guard: skip if a state transition is already queued to avoid
                 // calling save_config() on every frame while keys are held.
-                if (self.input.input_start_pressed ^ self.input.input_select_pressed)
+                if (self.input.quick_retry_requested
+                    || (self.input.input_start_pressed ^ self.input.input_select_pressed))
                     && !self.is_course_mode
                     && self.play_mode.mode == crate::core::bms_player_mode::Mode::Play
                     && self.pending.pending_state_change.is_none()
                 {
-                    if self.assist > 0 {
-                        // Assist mode: cannot replay with same chart, reset seed
-                        self.pending.pending_replay_seed_reset = true;
-                        log::info!("Assist mode: cannot replay with same chart");
-                    } else if self.input.input_start_pressed {
-                        // START: replay without changing options, reset seed
-                        self.pending.pending_replay_seed_reset = true;
-                        log::info!("Replay without changing options");
-                    } else {
-                        // SELECT: replay same chart, save score and replay data before retry
-                        self.sync_judge_states_to_model();
-                        self.pending.pending_quick_retry_score =
-                            self.create_score_data(self.device_type);
-                        self.pending.pending_quick_retry_replay = Some(self.build_replay_data());
-                        log::info!("Replay same chart (score saved)");
-                    }
-                    self.save_config();
-                    self.pending.pending_reload_bms = true;
-                    self.pending.pending_state_change = Some(MainStateType::Play);
+                    self.queue_quick_retry();
                 } else if self.main_state_data.timer.now_time_for_id(TIMER_FAILED)
                     > self.play_skin.close as i64
                 {
@@ -1158,6 +1100,8 @@ impl MainState for BMSPlayer {
 
                     // Transition: practice -> PlayState::Practice, else -> RESULT
                     if self.play_mode.mode == crate::core::bms_player_mode::Mode::Practice {
+                        // Only a completed range loops; a failed or stopped run waits for input
+                        self.practice_loop_pending = self.practice.practice_property().loopsection;
                         self.state = PlayState::Practice;
                     } else {
                         self.pending.pending_state_change = Some(MainStateType::Result);
@@ -1169,34 +1113,16 @@ impl MainState for BMSPlayer {
             // PlayState::Aborted
             // Translated from: Java BMSPlayer.render() lines 914-936
             PlayState::Aborted => {
-                // Quick retry check (START xor SELECT in PLAY mode, not course)
+                // Quick retry check (START xor SELECT or QUICK RETRY in PLAY mode, not course)
                 // Guard: skip if a state transition is already queued to avoid
                 // calling save_config() on every frame while keys are held.
                 if self.play_mode.mode == crate::core::bms_player_mode::Mode::Play
-                    && (self.input.input_start_pressed ^ self.input.input_select_pressed)
+                    && (self.input.quick_retry_requested
+                        || (self.input.input_start_pressed ^ self.input.input_select_pressed))
                     && !self.is_course_mode
                     && self.pending.pending_state_change.is_none()
                 {
-                    if self.assist > 0 {
-                        // Assist mode: cannot replay with same chart, reset seed
-                        self.pending.pending_replay_seed_reset = true;
-                        log::info!("Aborted: assist mode, cannot replay with same chart");
-                    } else if self.input.input_start_pressed {
-                        // START: replay without changing options, reset seed
-                        self.pending.pending_replay_seed_reset = true;
-                        log::info!("Aborted: replay without changing options");
-                    } else {
-                        // SELECT: replay same chart, save score and replay data before retry
-                        self.sync_judge_states_to_model();
-                        self.pending.pending_quick_retry_score =
-                            self.create_score_data(self.device_type);
-                        self.pending.pending_quick_retry_replay = Some(self.build_replay_data());
-                        log::info!("Aborted: replay same chart (score saved)");
-                    }
-                    self.pending.pending_global_pitch = Some(1.0);
-                    self.save_config();
-                    self.pending.pending_reload_bms = true;
-                    self.pending.pending_state_change = Some(MainStateType::Play);
+                    self.queue_quick_retry();
                 } else if self.main_state_data.timer.now_time_for_id(TIMER_FADEOUT)
                     > self
                         .main_state_data
@@ -1205,6 +1131,8 @@ impl MainState for BMSPlayer {
                         .map_or(0, |s| s.fadeout()) as i64
                 {
                     // skin.getFadeout() from the loaded skin
+                    // Keep hi-speed/cover changes made before the first note
+                    self.save_config();
                     // input.setEnable(true); input.setStartTime(0);
                     self.pending.pending_state_change = Some(MainStateType::MusicSelect);
                     log::info!("Aborted, transition to MUSICSELECT");
@@ -1234,6 +1162,18 @@ impl MainState for BMSPlayer {
             ctx.config.audio = Some(audio_config);
         }
 
+        // BGA on/off and dim changed with the play hotkeys
+        if let Some(render_config) = self.pending.pending_render_config.take() {
+            ctx.config.render = render_config;
+        }
+
+        // Chart offset changed with the play hotkeys
+        if let Some(offset) = self.pending.pending_chart_offset.take()
+            && let Some(pda) = ctx.db.playdata.as_ref()
+        {
+            pda.write_chart_offset(&self.model.sha256, offset);
+        }
+
         // System sounds
         for (sound, loop_sound) in std::mem::take(&mut self.pending.pending_sounds) {
             ctx.play_sound(&sound, loop_sound);
@@ -1357,6 +1297,8 @@ impl MainState for BMSPlayer {
         // stopping here as well ensures decoders are released even if those
         // transition paths were skipped (e.g. quick-retry, abnormal exit).
         lock_or_recover(&self.bga).stop();
+        JudgeTrainer::set_applied_scale(None);
+        self.judge_thread = None;
 
         // Call default MainState dispose
         if let Some(ref mut skin) = self.main_state_data.skin {
@@ -1373,15 +1315,267 @@ impl MainState for BMSPlayer {
 }
 
 impl BMSPlayer {
-    /// Rebuild judge_notes and JudgeManager from the current model state.
+    /// Hand judging to a 1000Hz thread when `judgeThread` is on. Autoplay,
+    /// replays and practice stay on the frame loop.
+    pub(super) fn start_judge_thread(&mut self) {
+        if !self.config.display.judge_thread
+            || self.play_mode.mode != crate::core::bms_player_mode::Mode::Play
+        {
+            return;
+        }
+        let Some(gauge) = self.gauge.clone() else {
+            return;
+        };
+        self.judge_thread = JudgeThread::start(self.judge.clone(), self.judge_notes.clone(), gauge);
+    }
+
+    /// Stop the judge thread, keeping what it judged before it stopped.
+    fn stop_judge_thread(&mut self) {
+        if let Some(thread) = self.judge_thread.take() {
+            let updates = thread.finish();
+            self.apply_judge_updates(updates);
+        }
+    }
+
+    /// Take over the judge and gauge published by the judge thread and apply
+    /// the side effects that came with them.
+    fn apply_judge_updates(&mut self, updates: Vec<JudgeUpdate>) {
+        for update in updates {
+            self.judge = update.judge;
+            self.gauge = Some(update.gauge);
+            self.judge.push_results(update.results);
+            self.process_judge_results();
+        }
+    }
+
+    /// Apply the side effects of the last `judge.update()`: key beams, judge/combo/bomb
+    /// timers, per-judge effects, model note states and keysounds.
+    pub(super) fn process_judge_results(&mut self) {
+        // Trigger key beam timers for newly judged lanes.
+        // In Java, JudgeManager calls keyinput.inputKeyOn(lane) directly;
+        // in Rust, we drain the event queue after update().
+        let judged = self.judge.drain_judged_lanes();
+        if !judged.is_empty()
+            && let Some(ref mut keyinput) = self.input.keyinput
+        {
+            for lane in judged {
+                keyinput.input_key_on(lane, &mut self.main_state_data.timer);
+            }
+        }
+        // Trigger judge/combo/bomb timers. Java does this inside
+        // JudgeManager.update2(); Rust queues it so the main thread
+        // owns all timer mutations.
+        let visual_events = self.judge.drain_judged_visual_events();
+        for event in visual_events {
+            if event.judge <= self.play_skin.judgetimer {
+                let bomb_timer = bomb_timer_id(event.player as i32, event.offset as i32);
+                if bomb_timer != crate::skin::timer_id::TimerId::UNDEFINED {
+                    self.main_state_data.timer.set_timer_on(bomb_timer);
+                }
+            }
+
+            let judge_timer = judge_timer_id(event.player);
+            if judge_timer != crate::skin::timer_id::TimerId::UNDEFINED {
+                self.main_state_data.timer.set_timer_on(judge_timer);
+            }
+
+            if self.play_skin.judgeregion >= 3 {
+                for player in 0..3 {
+                    if player != event.player {
+                        let combo_timer = combo_timer_id(player);
+                        if combo_timer != crate::skin::timer_id::TimerId::UNDEFINED {
+                            self.main_state_data.timer.set_timer_off(combo_timer);
+                        }
+                    }
+                }
+            }
+
+            let combo_timer = combo_timer_id(event.player);
+            if combo_timer != crate::skin::timer_id::TimerId::UNDEFINED {
+                self.main_state_data.timer.set_timer_on(combo_timer);
+            }
+        }
+        // HCN recovery ticks restart the gauge increase timer; drain ticks
+        // only show through the HCN damage timers below.
+        for event in self.judge.drain_hcn_gauge_events() {
+            let gauge_timer = gauge_increase_timer_id(event.player);
+            if event.increase && gauge_timer != crate::skin::timer_id::TimerId::UNDEFINED {
+                self.main_state_data.timer.set_timer_on(gauge_timer);
+            }
+        }
+        self.update_hcn_timers();
+        // Trigger per-judge side effects (BGA miss layer, score timers,
+        // pomyu, fullcombo timer). Corresponds to Java BMSPlayer.update(judge, time).
+        let events = self.judge.drain_judged_events();
+        for (judge, mtime) in events {
+            self.update_judge(judge, mtime);
+        }
+        // Sync judge states back to model notes so that create_score_data()
+        // and the result screen can read correct state/play_time values.
+        // In Java, JudgeManager modifies Note objects in-place via shared
+        // references; in Rust we must explicitly copy the results back.
+        self.sync_judge_states_to_model();
+
+        // Drain keysound events and resolve JudgeNote indices to model
+        // Notes for audio playback. Corresponds to Java
+        // keysound.play(note, keyvolume, 0) / keysound.setVolume(note, vol)
+        // calls in JudgeManager.update().
+        let key_volume = self.key_volume;
+        let play_indices = self.judge.drain_keysound_play_indices();
+        for note_idx in play_indices {
+            if let Some(note) = self.resolve_judge_note(note_idx) {
+                self.pending.pending_keysound_plays.push((note, key_volume));
+            }
+        }
+        let vol_indices = self.judge.drain_keysound_volume_set_indices();
+        for (note_idx, volume) in vol_indices {
+            if let Some(note) = self.resolve_judge_note(note_idx) {
+                // NaN sentinel means "use key_volume from config"
+                let resolved_vol = if volume.is_nan() { key_volume } else { volume };
+                self.pending
+                    .pending_keysound_volume_sets
+                    .push((note, resolved_vol));
+            }
+        }
+    }
+
+    /// Keep the HCN active/damage timers of each lane in step with the hell
+    /// charge note passing it: active while held, damage while released.
+    fn update_hcn_timers(&mut self) {
+        let Some(ref lp) = self.lane_property else {
+            return;
+        };
+        for (lane, (&player, &offset)) in lp
+            .lane_player()
+            .iter()
+            .zip(lp.lane_skin_offset())
+            .enumerate()
+        {
+            let passing = self
+                .judge
+                .passing_long_note(lane)
+                .is_some_and(|note| self.judge.note_state(note) != 0);
+            let held = self.judge.hell_charge_judge(lane);
+            let timer = &mut self.main_state_data.timer;
+            timer.switch_timer(
+                crate::skin::skin_property_mapper::hcn_active_timer_id(player, offset),
+                passing && held,
+            );
+            timer.switch_timer(
+                crate::skin::skin_property_mapper::hcn_damage_timer_id(player, offset),
+                passing && !held,
+            );
+        }
+    }
+
+    /// Wire the player's best score, target/rival and ghost into the pacemaker.
     ///
-    /// Used during initial create() and practice mode restarts so that the judge
-    /// system always references the current (possibly re-modified) model data.
-    pub(super) fn rebuild_judge_system(&mut self, mode: &Mode) {
-        self.judge_notes = bms::model::judge_note::build_judge_notes(&self.model);
-        let rule = BMSPlayerRule::for_mode(mode);
+    /// Used during create() and when a replay seek restarts scoring from the top.
+    pub(super) fn init_pacemaker(&mut self) {
+        let score = self.score.db_score.clone().unwrap_or_default();
+        let total_notes = self.model.total_notes();
+        // Determine the effective target score:
+        // - If rival score is absent or in course mode, use the pre-computed target_score
+        //   (caller should have computed via TargetProperty::from_id().target())
+        // - Otherwise, use the rival score as the target
+        let rival_target = self.score.rival_score.is_some() && !self.is_course_mode;
+        let effective_target = if rival_target {
+            self.score.rival_score.clone()
+        } else {
+            self.score.target_score.clone()
+        };
+
+        // Java BMSPlayer.java:450 always passes null for rivalGhost. A rival
+        // that carries a per-note ghost (e.g. an LR2IR ghost battle) is
+        // followed note by note; computed targets stay linear.
+        let (target_exscore, target_ghost) = match effective_target {
+            Some(ref t) => (t.exscore(), t.decode_ghost().filter(|_| rival_target)),
+            None => (0, None),
+        };
 
-        // Compute judge window rates from player config, applying course constraints
+        self.main_state_data.score.set_target_score_with_ghost(
+            score.exscore(),
+            score.decode_ghost(),
+            target_exscore,
+            target_ghost,
+            total_notes,
+        );
+        self.main_state_data
+            .score
+            .set_ghost(self.score.ghost.clone());
+    }
+
+    /// Create the play gauge for the current model, gauge option and course stage.
+    ///
+    /// Used during create() and when a replay seek restarts scoring from the top.
+    pub(super) fn create_play_gauge(&self) -> Option<GrooveGauge> {
+        // Java: gauge = GrooveGauge.create(model, replay != null ? replay.gauge : config.getGauge(), resource);
+        // In replay mode, use the gauge type from the replay data.
+        let gauge_type = if self.play_mode.mode == crate::core::bms_player_mode::Mode::Replay {
+            self.score
+                .active_replay
+                .as_ref()
+                .map(|r| r.gauge)
+                .unwrap_or(self.player_config.play_settings.gauge)
+        } else {
+            self.player_config.play_settings.gauge
+        };
+        let grade = if self.is_course_mode { 1 } else { 0 };
+
+        // Java: GrooveGauge.create(model, type, resource) extracts gauge property
+        // from course constraints when in course mode. Map constraint variants to
+        // GaugeProperty for courses that specify gauge tables.
+        let gauge_property = if self.is_course_mode {
+            self.constraints.iter().find_map(|c| match c {
+                CourseDataConstraint::Gauge5Keys => {
+                    Some(crate::play::gauge_property::GaugeProperty::FiveKeys)
+                }
+                CourseDataConstraint::Gauge7Keys => {
+                    Some(crate::play::gauge_property::GaugeProperty::SevenKeys)
+                }
+                CourseDataConstraint::Gauge9Keys => {
+                    Some(crate::play::gauge_property::GaugeProperty::Pms)
+                }
+                CourseDataConstraint::Gauge24Keys => {
+                    Some(crate::play::gauge_property::GaugeProperty::Keyboard)
+                }
+                CourseDataConstraint::GaugeLr2 => {
+                    Some(crate::play::gauge_property::GaugeProperty::Lr2)
+                }
+                _ => None,
+            })
+        } else {
+            None
+        };
+
+        let gauge_table =
+            crate::play::groove_gauge::load_gauge_table(&self.player_config.play_settings);
+        let mut gauge = crate::play::groove_gauge::create_groove_gauge_with_table(
+            &self.model,
+            gauge_type,
+            grade,
+            gauge_property,
+            gauge_table.as_ref(),
+        );
+
+        // Java: GrooveGauge.create(model, type, resource) restores gauge values
+        // from the previous course stage. After creating the gauge, read the last
+        // value from each gauge type's log and restore it.
+        if let Some(ref previous_values) = self.previous_gauge_values
+            && let Some(ref mut gauge) = gauge
+        {
+            for (i, log) in previous_values.iter().enumerate() {
+                if let Some(&last_val) = log.last() {
+                    gauge.set_value_by_type(i as i32, last_val);
+                }
+            }
+        }
+        gauge
+    }
+
+    /// Judge window rates (PGREAT/GREAT/GOOD percent) for keys and scratch from
+    /// the player config, course constraints and the playback rate.
+    pub(super) fn judge_window_rates(&self) -> ([i32; 3], [i32; 3]) {
         let mut key_judge_window_rate = if self.player_config.judge_settings.custom_judge {
             [
                 self.player_config
@@ -1428,6 +1622,107 @@ impl BMSPlayer {
             }
         }
 
+        // Windows are defined on the chart timeline, which the playback rate scaled
+        if self.freq != 100 && self.freq > 0 {
+            for rate in key_judge_window_rate
+                .iter_mut()
+                .chain(scratch_judge_window_rate.iter_mut())
+            {
+                *rate = *rate * 100 / self.freq;
+            }
+        }
+
+        (key_judge_window_rate, scratch_judge_window_rate)
+    }
+
+    /// Judge rank the windows are built from: the judge trainer's override
+    /// while it is applied, otherwise the chart's own.
+    pub(super) fn judge_rank(&self) -> f64 {
+        self.judge_trainer_rank
+            .map_or_else(|| self.model.judgerank_exact(), f64::from)
+    }
+
+    /// Judge rank the judge trainer asks for in this play, if it applies.
+    fn judge_trainer_target(&self, mode: &Mode) -> Option<i32> {
+        let is_play_or_practice = matches!(
+            self.play_mode.mode,
+            crate::core::bms_player_mode::Mode::Play | crate::core::bms_player_mode::Mode::Practice
+        );
+        (is_play_or_practice && JudgeTrainer::is_active())
+            .then(|| JudgeTrainer::judge_window_rate(mode))
+    }
+
+    /// Follow judge trainer changes made from the mod menu mid-song.
+    pub(super) fn sync_judge_trainer(&mut self) {
+        let mode = self.model.mode().copied().unwrap_or(Mode::BEAT_7K);
+        let target = self.judge_trainer_target(&mode);
+        self.apply_judge_trainer(&mode, target);
+    }
+
+    /// Apply a judge trainer rank (or restore the chart's judge with `None`)
+    /// to the running JudgeManager without resetting the play.
+    pub(super) fn apply_judge_trainer(&mut self, mode: &Mode, rank: Option<i32>) {
+        if rank == self.judge_trainer_rank {
+            return;
+        }
+        self.judge_trainer_rank = rank;
+        self.mark_judge_trainer();
+        let rule = BMSPlayerRule::for_judge_settings(
+            mode,
+            &self.player_config.judge_settings,
+            self.judge_table.as_ref(),
+        );
+        let (key_judge_window_rate, scratch_judge_window_rate) = self.judge_window_rates();
+        let judge_rank = self.judge_rank();
+        self.judge.set_judge_windows(
+            &rule.judge,
+            judge_rank,
+            &key_judge_window_rate,
+            &scratch_judge_window_rate,
+        );
+        if let Some(ref mut thread) = self.judge_thread {
+            let judge_property = rule.judge;
+            thread.edit(Arc::new(move |judge, _| {
+                judge.set_judge_windows(
+                    &judge_property,
+                    judge_rank,
+                    &key_judge_window_rate,
+                    &scratch_judge_window_rate,
+                );
+            }));
+        }
+    }
+
+    /// Flag the play as a trainer run and publish the applied window scale.
+    fn mark_judge_trainer(&mut self) {
+        if self.judge_trainer_rank.is_some() {
+            self.judge_trainer_on = true;
+            self.force_no_ir_send = true;
+        }
+        let chart_rank = self.model.judgerank_exact();
+        JudgeTrainer::set_applied_scale(
+            self.judge_trainer_rank
+                .filter(|_| chart_rank > 0.0)
+                .map(|rank| f64::from(rank) / chart_rank),
+        );
+    }
+
+    /// Rebuild judge_notes and JudgeManager from the current model state.
+    ///
+    /// Used during initial create() and practice mode restarts so that the judge
+    /// system always references the current (possibly re-modified) model data.
+    pub(super) fn rebuild_judge_system(&mut self, mode: &Mode) {
+        self.judge_thread = None;
+        self.judge_notes = bms::model::judge_note::build_judge_notes(&self.model);
+        let rule = BMSPlayerRule::for_judge_settings(
+            mode,
+            &self.player_config.judge_settings,
+            self.judge_table.as_ref(),
+        );
+        let (key_judge_window_rate, scratch_judge_window_rate) = self.judge_window_rates();
+        self.judge_trainer_rank = self.judge_trainer_target(mode);
+        self.mark_judge_trainer();
+
         let autoplay = matches!(
             self.play_mode.mode,
             crate::core::bms_player_mode::Mode::Autoplay
@@ -1451,7 +1746,7 @@ impl BMSPlayer {
             notes: &self.judge_notes,
             mode,
             ln_type: self.model.lntype(),
-            judge_rank: self.model.judgerank,
+            judge_rank: self.judge_rank(),
             judge_window_rate: key_judge_window_rate,
             scratch_judge_window_rate,
             algorithm,
@@ -1466,6 +1761,8 @@ impl BMSPlayer {
             judgeregion,
         };
         self.judge = JudgeManager::from_config(&judge_config);
+        self.judge
+            .set_ruleset(self.player_config.judge_settings.judge_rule);
 
         // Build reverse index: JudgeNote index -> (timeline_index, lane)
         // Used by sync_judge_states_to_model() to write judge results back to model notes.
@@ -19,20 +19,24 @@ pub(crate) use crate::input::bms_player_input_processor::{BMSPlayerInputProcesso
 pub(crate) use crate::input::keyboard_input_processor::ControlKeys;
 pub(crate) use crate::play::bga::bga_processor::BGAProcessor;
 pub(crate) use crate::play::bms_player_rule::BMSPlayerRule;
+pub(crate) use crate::play::ghost_key_beams::GhostKeyBeams;
 pub(crate) use crate::play::groove_gauge::GrooveGauge;
 pub(crate) use crate::play::input::control_input::ControlInputProcessor;
 pub(crate) use crate::play::input::key_input::KeyInputProccessor;
 pub(crate) use crate::play::input::key_sound::KeySoundProcessor;
 pub(crate) use crate::play::judge::algorithm::JudgeAlgorithm;
+pub(crate) use crate::play::judge::judge_thread::{JudgeThread, JudgeUpdate};
 pub(crate) use crate::play::judge::manager::{JudgeConfig, JudgeManager};
 pub(crate) use crate::play::lane_property::LaneProperty;
 pub(crate) use crate::play::lane_renderer::{LaneGroupRegion, LaneRenderer};
 pub(crate) use crate::play::play_skin::PlaySkin;
 pub(crate) use crate::play::practice_configuration::PracticeConfiguration;
 pub(crate) use crate::play::rhythm_timer_processor::RhythmTimerProcessor;
+pub(crate) use crate::skin::KeyInputLog;
 pub(crate) use crate::skin::audio_config::FrequencyType;
 pub(crate) use crate::skin::clear_type::ClearType;
 pub(crate) use crate::skin::course_data::CourseDataConstraint;
+pub(crate) use crate::skin::hotkey_config::Hotkey;
 pub(crate) use crate::skin::play_config::PlayConfig;
 pub(crate) use crate::skin::replay_data::ReplayData;
 pub(crate) use crate::skin::skin_type::SkinType;
@@ -42,6 +46,8 @@ pub(crate) use bms::model::mode::Mode;
 pub(crate) use bms::model::note::{Note, TYPE_LONGNOTE, TYPE_UNDEFINED};
 
 pub static TIME_MARGIN: i64 = 5000;
+/// Chart offset change (ms) of one chart offset hotkey press.
+pub const CHART_OFFSET_STEP: i32 = 5;
 
 /// Key state flags for replay mode.
 /// Corresponds to Java `main.getInputProcessor().getKeyState(N)` checks.
@@ -150,6 +156,8 @@ const TIMER_FADEOUT: TimerId = TimerId(2);
 const TIMER_FAILED: TimerId = TimerId(3);
 const TIMER_READY: TimerId = TimerId(40);
 const TIMER_PLAY: TimerId = TimerId(41);
+const TIMER_GAUGE_INCLEASE_1P: TimerId = TimerId(42);
+const TIMER_GAUGE_INCLEASE_2P: TimerId = TimerId(43);
 const TIMER_GAUGE_MAX_1P: TimerId = TimerId(44);
 const TIMER_FULLCOMBO_1P: TimerId = TimerId(48);
 const TIMER_RHYTHM: TimerId = TimerId(140);
@@ -220,6 +228,15 @@ pub struct PendingActions {
     /// Set by PlayMouseContext when volume sliders (set_float_value IDs 17-19) or
     /// notify_audio_config_changed() are called. Consumed directly via GameContext.
     pub pending_audio_config: Option<crate::skin::audio_config::AudioConfig>,
+    /// Pending render config update after the BGA on/off or dim hotkeys.
+    ///
+    /// Consumed directly via GameContext.
+    pub pending_render_config: Option<crate::skin::config::RenderConfig>,
+    /// Pending chart offset (ms) after the chart offset hotkeys, saved for the
+    /// chart's sha256.
+    ///
+    /// Consumed directly via GameContext.
+    pub pending_chart_offset: Option<i32>,
     /// Pending audio path play requests from skin scripts (audio_play).
     ///
     /// Each entry is (path, volume, is_loop). Consumed directly via GameContext.
@@ -246,6 +263,8 @@ impl PendingActions {
             pending_quick_retry_score: None,
             pending_quick_retry_replay: None,
             pending_audio_config: None,
+            pending_render_config: None,
+            pending_chart_offset: None,
             pending_audio_path_plays: Vec::new(),
             pending_audio_path_stops: Vec::new(),
         }
@@ -272,7 +291,13 @@ pub struct PlayerInputState {
     pub control_key_down: bool,
     pub control_key_left: bool,
     pub control_key_right: bool,
-    pub control_key_escape_pressed: bool,
+    pub close_pressed: bool,
+    /// Held state of each function hotkey, indexed by `Hotkey::index()`.
+    pub hotkey_states: [bool; Hotkey::ALL.len()],
+    /// `hotkey_states` of the previous frame, for edge detection.
+    pub prev_hotkey_states: [bool; Hotkey::ALL.len()],
+    /// QUICK RETRY was pressed; the Failed/Aborted states retry like START.
+    pub quick_retry_requested: bool,
     pub control_key_num1: bool,
     pub control_key_num2: bool,
     pub control_key_num3: bool,
@@ -283,6 +308,10 @@ pub struct PlayerInputState {
     pub input_analog_diff_ticks: Vec<i32>,
     pub input_analog_recent_ms: Vec<i64>,
     pub pending_analog_resets: Vec<usize>,
+    /// Key states driven by the replay keylog while watching a replay.
+    pub replay_key_states: Vec<bool>,
+    /// Key change times passed with `replay_key_states`; only set while judging a keylog entry.
+    pub replay_key_changed_times: Vec<i64>,
     /// Pending start time to set on BMSPlayerInputProcessor (enables key logging).
     /// Set when transitioning to PlayState::Play; consumed by sync_input_back_to.
     pub pending_input_start_time: Option<i64>,
@@ -302,7 +331,10 @@ impl PlayerInputState {
             control_key_down: false,
             control_key_left: false,
             control_key_right: false,
-            control_key_escape_pressed: false,
+            close_pressed: false,
+            hotkey_states: [false; Hotkey::ALL.len()],
+            prev_hotkey_states: [false; Hotkey::ALL.len()],
+            quick_retry_requested: false,
             control_key_num1: false,
             control_key_num2: false,
             control_key_num3: false,
@@ -313,6 +345,8 @@ impl PlayerInputState {
             input_analog_diff_ticks: Vec::new(),
             input_analog_recent_ms: Vec::new(),
             pending_analog_resets: Vec::new(),
+            replay_key_states: Vec::new(),
+            replay_key_changed_times: Vec::new(),
             pending_input_start_time: None,
             pending_key_log_margin_time: None,
         }
@@ -333,6 +367,9 @@ pub struct PlayerScoreState {
     pub db_score: Option<ScoreData>,
     pub rival_score: Option<ScoreData>,
     pub target_score: Option<ScoreData>,
+    pub ghost: Option<Vec<i32>>,
+    /// Key presses of the replay ghost, drawn as key beams
+    pub ghost_beams: Option<GhostKeyBeams>,
     pub analysis_result: Option<crate::audio::bms_loudness_analyzer::AnalysisResult>,
     pub analysis_checked: bool,
 }
@@ -346,6 +383,8 @@ impl PlayerScoreState {
             db_score: None,
             rival_score: None,
             target_score: None,
+            ghost: None,
+            ghost_beams: None,
             analysis_result: None,
             analysis_checked: false,
         }
@@ -365,7 +404,11 @@ pub struct BMSPlayer {
     lane_property: Option<LaneProperty>,
     judge: JudgeManager,
     judge_notes: Vec<bms::model::judge_note::JudgeNote>,
+    /// Custom judge window table from `JudgeSettings::judge_table`, loaded in create().
+    judge_table: Option<crate::play::judge::property::JudgeWindowTable>,
     bga: Arc<Mutex<BGAProcessor>>,
+    /// BGA images decoded on the decide screen, used instead of loading them in create().
+    bga_preload: Option<Arc<crate::play::bga::bga_image_preloader::BgaImagePreload>>,
     gauge: Option<GrooveGauge>,
     playtime: i64,
     /// Input state snapshot (keys, buttons, analog, controllers).
@@ -376,7 +419,11 @@ pub struct BMSPlayer {
     state: PlayState,
     prevtime: i64,
     practice: PracticeConfiguration,
+    /// Practice LOOP: start the next run once the range has been reloaded.
+    practice_loop_pending: bool,
     starttimeoffset: i64,
+    /// Timing offset (ms) of this chart, added to the notes display timing.
+    chart_offset: i32,
     rhythm: Option<RhythmTimerProcessor>,
     startpressedtime: i64,
     adjusted_volume: f32,
@@ -445,10 +492,20 @@ pub struct BMSPlayer {
     course_song_count: usize,
     /// Input device type (for create_score_data). Set by the caller.
     device_type: crate::input::bms_player_input_device::DeviceType,
-    /// Whether frequency training is active (set when freq != 100 in practice mode).
+    /// Whether frequency training is active (set when freq != 100 in practice mode
+    /// or by the freq trainer).
     freq_on: bool,
-    /// Whether IR score submission should be blocked (set when freq != 100 in practice mode).
+    /// Playback rate in percent while frequency training is active (100 = normal).
+    /// Judge windows are scaled by it along with the chart timeline.
+    freq: i32,
+    /// Whether IR score submission should be blocked (set when freq != 100 in practice mode
+    /// or by the freq trainer).
     force_no_ir_send: bool,
+    /// Judge rank applied by the judge trainer in place of the chart's, if any.
+    judge_trainer_rank: Option<i32>,
+    /// Whether the judge trainer was applied at any point of this play.
+    /// Trainer runs are not saved.
+    judge_trainer_on: bool,
     /// Initial course combo carried from a previous course stage (via PlayerResource).
     /// Set by the caller before create(); applied to JudgeManager in rebuild_judge_system().
     /// Java: judge.init() calls setCourseCombo(resource.getCombo()) when resource.getGauge() != null.
@@ -486,6 +543,9 @@ pub struct BMSPlayer {
     /// Set by the caller before create() via `set_player_data()`.
     /// Java: IntegerPropertyFactory reads state.resource.getPlayerData() for IDs 30-37, 333.
     player_data: Option<crate::skin::player_data::PlayerData>,
+    /// Player statistics gained today, for IDs 549-553.
+    /// Set by the caller before create() via `set_today_player_data()`.
+    today_player_data: Option<crate::skin::player_data::PlayerData>,
     /// Cumulative playtime in seconds from PlayerData.
     /// Java: PlayerData.getPlaytime() -- total play time across all sessions.
     /// Set by the caller before create() via `set_cumulative_playtime()`.
@@ -500,6 +560,9 @@ pub struct BMSPlayer {
     replay_key_state: ReplayKeyState,
     /// Read-only input snapshot for the current frame (mouse position for skin rendering).
     input_snapshot: Option<crate::input::input_snapshot::InputSnapshot>,
+    /// 1000Hz judge thread while playing with `judgeThread` on. `judge` and
+    /// `gauge` then mirror its state.
+    judge_thread: Option<JudgeThread>,
 }
 
 mod accessors;
@@ -507,6 +570,7 @@ mod input_handling;
 mod main_state_impl;
 mod pattern;
 mod render_skin;
+mod replay_control;
 mod scoring;
 mod skin_context;
 mod snapshot;
@@ -1,4 +1,9 @@
 use super::*;
+use crate::core::pattern::lane_shuffle_modifier::LaneRandomShuffleModifier;
+use crate::core::pattern::pattern_modifier::PatternLogModifier;
+use crate::core::pattern::random::{Random, RandomUnit};
+use crate::modmenu::random_trainer::RandomTrainer;
+use crate::skin::pattern_modify_log::merge_modify_logs;
 
 /// Mirror-invert a ghost-battle lane pattern by reversing digit positions
 /// within each player's key range.
@@ -97,9 +102,29 @@ impl BMSPlayer {
             self.score.playinfo.rand = chart_option.rand;
         }
 
+        // Random trainer: the locked lane order replaces the 1P RANDOM seed of the next plays
+        if self.play_mode.mode == crate::core::bms_player_mode::Mode::Play
+            && !self.is_course_mode
+            && RandomTrainer::is_active()
+            && let Some(lane_order) = RandomTrainer::locked_lane_order()
+        {
+            self.score.playinfo.lane_order = Some(lane_order);
+        }
+
         // -- Phase 1: Pre-option modifiers (scroll, LN, mine, extra) --
         let mut pre_mods: Vec<Box<dyn PatternModifier>> = Vec::new();
 
+        if config.note_modifier_settings.convert_to_7k {
+            let mode = self.model.mode().copied().unwrap_or(Mode::BEAT_7K);
+            if matches!(mode, Mode::BEAT_5K | Mode::POPN_9K | Mode::BEAT_14K) {
+                pre_mods.push(Box::new(ModeModifier::new(
+                    mode,
+                    Mode::BEAT_7K,
+                    config.clone(),
+                )));
+            }
+        }
+
         if config.display_settings.scroll_mode > 0 {
             pre_mods.push(Box::new(ScrollSpeedModifier::with_params(
                 config.display_settings.scroll_mode - 1,
@@ -184,6 +209,23 @@ impl BMSPlayer {
 
         let mut random_mods: Vec<Box<dyn PatternModifier>> = Vec::new();
 
+        // A pattern log restored from a replay replaces note-unit shuffles so the
+        // exact arrangement is reproduced. The log already holds both DP sides, so
+        // it is applied once, in place of the first note-unit modifier.
+        let mut replay_pattern = self.score.playinfo.pattern.take().filter(|p| !p.is_empty());
+        let has_replay_pattern = replay_pattern.is_some();
+        let mut replace_with_log = |pm: Box<dyn PatternModifier>,
+                                    option: i32|
+         -> Option<Box<dyn PatternModifier>> {
+            if !has_replay_pattern || Random::from_id(option, &mode).unit() != RandomUnit::Note {
+                return Some(pm);
+            }
+            replay_pattern.take().map(|log| {
+                Box::new(PatternLogModifier::new(pm.player(), log, pm.assist_level()))
+                    as Box<dyn PatternModifier>
+            })
+        };
+
         // DP option modifiers
         if player_count == 2 {
             if self.score.playinfo.doubleoption == 1 {
@@ -203,7 +245,7 @@ impl BMSPlayer {
             } else {
                 self.score.playinfo.randomoption2seed = pm2.get_seed();
             }
-            random_mods.push(pm2);
+            random_mods.extend(replace_with_log(pm2, self.score.playinfo.randomoption2));
             log::info!(
                 "Pattern option (2P): {}, Seed: {}",
                 self.score.playinfo.randomoption2,
@@ -212,12 +254,32 @@ impl BMSPlayer {
         }
 
         // 1P random option
-        let mut pm1 = crate::core::pattern::pattern_modifier::create_pattern_modifier(
-            self.score.playinfo.randomoption,
-            0,
-            &mode,
-            config,
-        );
+        if mode == Mode::BEAT_7K {
+            RandomTrainer::set_chart_density(Some(RandomTrainer::lane_density(&self.model, 7)));
+        }
+        let fixed_random = self
+            .score
+            .playinfo
+            .lane_order
+            .take()
+            .filter(|_| Random::from_id(self.score.playinfo.randomoption, &mode) == Random::Random)
+            .and_then(|lane_order| {
+                LaneRandomShuffleModifier::with_lane_order(0, &lane_order)
+                    .map(|modifier| (lane_order, modifier))
+            });
+        let mut pm1: Box<dyn PatternModifier> = match fixed_random {
+            Some((lane_order, modifier)) => {
+                log::info!("Random trainer lane order: {lane_order}");
+                self.score.playinfo.lane_order = Some(lane_order);
+                Box::new(modifier)
+            }
+            None => crate::core::pattern::pattern_modifier::create_pattern_modifier(
+                self.score.playinfo.randomoption,
+                0,
+                &mode,
+                config,
+            ),
+        };
         if self.score.playinfo.randomoptionseed != -1 {
             pm1.set_seed(self.score.playinfo.randomoptionseed);
         } else {
@@ -226,7 +288,7 @@ impl BMSPlayer {
             // passed in as an external dependency when GhostBattle or RandomTrainer is active.
             self.score.playinfo.randomoptionseed = pm1.get_seed();
         }
-        random_mods.push(pm1);
+        random_mods.extend(replace_with_log(pm1, self.score.playinfo.randomoption));
         log::info!(
             "Pattern option (1P): {}, Seed: {}",
             self.score.playinfo.randomoption,
@@ -240,8 +302,10 @@ impl BMSPlayer {
         }
 
         // Apply all random modifiers
+        let mut modify_log = Vec::new();
         for m in random_mods.iter_mut() {
             m.modify(&mut self.model);
+            merge_modify_logs(&mut modify_log, m.pattern_modify_log());
 
             let assist_level = m.assist_level();
             if assist_level != AssistLevel::None {
@@ -277,6 +341,11 @@ impl BMSPlayer {
             self.score.playinfo.lane_shuffle_pattern = Some(patterns);
         }
 
+        // Store the per-timeslice permutations so replays reproduce note shuffles exactly
+        if !modify_log.is_empty() {
+            self.score.playinfo.pattern = Some(modify_log);
+        }
+
         score
     }
 
@@ -343,6 +412,8 @@ impl BMSPlayer {
                     self.score.playinfo.randomoption2seed = replay_data.randomoption2seed;
                     self.score.playinfo.doubleoption = replay_data.doubleoption;
                     self.score.playinfo.rand = replay_data.rand.clone();
+                    self.score.playinfo.pattern = replay_data.pattern.clone();
+                    self.score.playinfo.lane_order = replay_data.lane_order.clone();
                     is_replay_pattern_play = true;
                 } else if key_state.option_key {
                     // Replay option mode: copy options only (no seeds, no rand)
@@ -369,6 +440,8 @@ impl BMSPlayer {
                     }
                 } else {
                     // Normal replay mode: keep replay for keylog playback
+                    self.score.playinfo.pattern = replay_data.pattern.clone();
+                    self.score.playinfo.lane_order = replay_data.lane_order.clone();
                     ReplayRestoreResult {
                         stay_replay: true,
                         replay: Some(replay_data),
@@ -514,7 +587,10 @@ impl BMSPlayer {
     /// When freq trainer is enabled in PLAY mode (non-course):
     /// 1. Adjusts playtime based on frequency ratio
     /// 2. Scales chart timing via `BMSModelUtils::change_frequency`
-    /// 3. Returns result with freq state and optional global pitch
+    /// 3. Marks frequency training active, blocks IR submission and queues the
+    ///    global pitch, so judge windows and audio follow the new rate
+    /// 4. Flags the play as assisted when slowed below 1.0x
+    /// 5. Returns result with freq state and optional global pitch
     ///
     /// Returns `None` if freq trainer should not be applied (freq == 100,
     /// not play mode, or course mode).
@@ -541,6 +617,16 @@ impl BMSPlayer {
             _ => None,
         };
 
+        self.freq_on = true;
+        self.freq = freq;
+        self.force_no_ir_send = true;
+        if let Some(pitch) = global_pitch {
+            self.pending.pending_global_pitch = Some(pitch);
+        }
+        if freq < 100 {
+            self.assist = self.assist.max(1);
+        }
+
         // Format freq string (matches Java FreqTrainerMenu.getFreqString())
         let rate = freq as f32 / 100.0;
         let freq_string = format!("[{:.02}x]", rate);
@@ -650,6 +736,22 @@ impl BMSPlayer {
                 // Switched to PLAY mode
                 self.play_mode = BMSPlayerMode::PLAY;
             }
+        } else if !is_replay {
+            // Outside REPLAY mode the resource replay only carries the chart of a retry
+            // (QUICK RETRY / SELECT / result REPLAY SAME); its keylog must not be played back.
+            if let Some(replay) = self.score.active_replay.take()
+                && self.play_mode.mode == crate::core::bms_player_mode::Mode::Play
+                && !is_course
+                && replay.randomoptionseed != -1
+            {
+                self.score.playinfo.randomoption = replay.randomoption;
+                self.score.playinfo.randomoptionseed = replay.randomoptionseed;
+                self.score.playinfo.randomoption2 = replay.randomoption2;
+                self.score.playinfo.randomoption2seed = replay.randomoption2seed;
+                self.score.playinfo.doubleoption = replay.doubleoption;
+                self.score.playinfo.rand = replay.rand;
+                self.score.playinfo.lane_order = replay.lane_order;
+            }
         }
 
         // Step 3: Handle RANDOM syntax (Java lines 179-196)
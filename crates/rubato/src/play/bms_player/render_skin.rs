@@ -136,6 +136,14 @@ impl BMSPlayer {
                     })
                 })
                 .collect();
+            let ghost_key_beams = match (self.score.ghost_beams.as_mut(), &self.lane_property) {
+                (Some(beams), Some(lane_property)) if timer.is_timer_on(TIMER_PLAY) => {
+                    // Keylog times exclude the margin before the first note.
+                    beams.update(timer.now_micro_time_for_id(TIMER_PLAY) - self.margin_time * 1000);
+                    beams.lane_beams(lane_property.lane_key_assign())
+                }
+                _ => Vec::new(),
+            };
             let draw_ctx = crate::play::lane_renderer::DrawLaneContext {
                 time: timer.now_time(),
                 timer_play: if timer.is_timer_on(TIMER_PLAY) {
@@ -148,7 +156,8 @@ impl BMSPlayer {
                 } else {
                     None
                 },
-                judge_timing: self.player_config.judge_settings.judgetiming as i64,
+                judge_timing: (self.player_config.judge_settings.judgetiming + self.chart_offset)
+                    as i64,
                 is_practice: self.state == PlayState::Practice
                     || self.state == PlayState::PracticeFinished,
                 practice_start_time: self.practice.practice_property().starttime as i64,
@@ -174,8 +183,11 @@ impl BMSPlayer {
                 show_bpmguide: self.player_config.display_settings.bpmguide,
                 show_pastnote: self.player_config.display_settings.showpastnote,
                 mark_processednote: self.player_config.display_settings.markprocessednote,
-                show_hiddennote: self.player_config.display_settings.showhiddennote,
+                show_hiddennote: self.player_config.display_settings.showhiddennote
+                    || (self.play_mode.mode == crate::core::bms_player_mode::Mode::Practice
+                        && self.practice.practice_property().showinvisible),
                 show_judgearea: self.player_config.display_settings.showjudgearea,
+                ghost_key_beams,
                 lntype: self.model.lntype(),
                 judge_time_regions: (0..lane_count)
                     .map(|i| self.judge.judge_time_region(i).to_vec())
@@ -191,6 +203,7 @@ impl BMSPlayer {
                 forced_cn_endings: false,
             };
             skin.compute_note_draw_commands(&mut |lanes| {
+                crate::profile_scope!("LaneRenderer::draw_lane");
                 lr.draw_lane(&draw_ctx, lanes, &[]).commands
             });
         }
@@ -0,0 +1,242 @@
+use super::*;
+
+/// Upper bound for the play speed percentage (4x fast-forward while watching a replay).
+pub(super) const MAX_PLAY_SPEED: i32 = 400;
+
+impl BMSPlayer {
+    /// Feed the replay keylog for this frame.
+    ///
+    /// Ticks the judge thread up to `mtime` and judges each keylog entry at its
+    /// own time, so presses and releases landing in the same frame (as happens
+    /// when fast-forwarding) are not merged. Returns true while the keylog
+    /// drives input; the caller then finishes the frame with
+    /// `replay_key_states` / `replay_key_changed_times` instead of device input.
+    pub(super) fn feed_replay_input(&mut self, mtime: i64) -> bool {
+        if self.play_mode.mode != crate::core::bms_player_mode::Mode::Replay {
+            return false;
+        }
+        let Some(tick) = self
+            .input
+            .keyinput
+            .as_mut()
+            .and_then(|keyinput| keyinput.tick_judge(mtime))
+        else {
+            return false;
+        };
+
+        self.input.replay_key_states.resize(KEYSTATE_SIZE, false);
+        self.input
+            .replay_key_changed_times
+            .resize(KEYSTATE_SIZE, i64::MIN);
+        for event in tick.replay_events {
+            if event.keycode < 0 || event.keycode as usize >= KEYSTATE_SIZE {
+                continue;
+            }
+            let key = event.keycode as usize;
+            self.input.replay_key_states[key] = event.pressed;
+            self.input.replay_key_changed_times[key] = event.time;
+            if let Some(ref mut gauge) = self.gauge {
+                self.judge.update(
+                    event.time,
+                    &self.judge_notes,
+                    &self.input.replay_key_states,
+                    &self.input.replay_key_changed_times,
+                    gauge,
+                );
+            }
+            self.input.replay_key_changed_times[key] = i64::MIN;
+            self.process_judge_results();
+        }
+        if tick.finished {
+            // Java: if (keylog != null) input.resetAllKeyState();
+            if tick.has_keylog {
+                self.input.replay_key_states.fill(false);
+            }
+            if let Some(ref mut keyinput) = self.input.keyinput {
+                keyinput.stop_judge();
+            }
+        }
+        true
+    }
+
+    /// Seek the replay being watched to `time_ms` on the play timer.
+    ///
+    /// Judge, gauge and pacemaker restart from the top and the keylog is
+    /// re-run headlessly up to the target, so seeking backwards and forwards
+    /// both land on the score the original play had at that time. BG audio
+    /// restarts from the target. Does nothing outside replay playback.
+    pub fn seek_replay(&mut self, time_ms: i64) {
+        if self.play_mode.mode != crate::core::bms_player_mode::Mode::Replay
+            || self.state != PlayState::Play
+        {
+            return;
+        }
+        let Some(keylog) = self.score.active_replay.as_ref().map(|r| r.keylog.clone()) else {
+            return;
+        };
+        let target_ms = time_ms.clamp(0, self.playtime);
+        let target = target_ms * 1000;
+
+        // Clear judged states left in the model by the previous run.
+        for &(tl_idx, lane) in &self.judge_note_to_model {
+            if let Some(tl) = self.model.timelines.get_mut(tl_idx)
+                && let Some(note) = tl.note_mut(lane)
+            {
+                note.set_state(0);
+                note.set_micro_play_time(0);
+            }
+        }
+        let mode = self.model.mode().copied().unwrap_or(Mode::BEAT_7K);
+        self.rebuild_judge_system(&mode);
+        self.gauge = self.create_play_gauge();
+        if let Some(ref gauge) = self.gauge {
+            self.gaugelog = (0..gauge.gauge_type_length()).map(|_| Vec::new()).collect();
+        }
+        self.init_pacemaker();
+
+        self.simulate_replay_until(&keylog, target);
+        self.judge.drain_judged_lanes();
+        self.judge.drain_judged_visual_events();
+        self.judge.drain_hcn_gauge_events();
+        self.judge.drain_judged_events();
+        self.judge.drain_keysound_play_indices();
+        self.judge.drain_keysound_volume_set_indices();
+        self.sync_judge_states_to_model();
+
+        if let Some(ref mut keyinput) = self.input.keyinput {
+            if !keyinput.is_judge_started() {
+                let last_tl_micro = self.model.timelines.last().map_or(0, |tl| tl.micro_time());
+                keyinput.start_judge(last_tl_micro, Some(&keylog), self.margin_time);
+            }
+            keyinput.seek_judge(target);
+        }
+
+        let micronow = self.main_state_data.timer.now_micro_time();
+        self.main_state_data
+            .timer
+            .set_micro_timer(TIMER_PLAY, micronow - target);
+        self.main_state_data
+            .timer
+            .set_micro_timer(TIMER_RHYTHM, micronow - target);
+        if let Some(ref mut rhythm) = self.rhythm {
+            rhythm.restart(
+                micronow - target,
+                target,
+                self.practice.practice_property().freq,
+            );
+        }
+        if target_ms < self.playtime - TIME_MARGIN {
+            self.main_state_data
+                .timer
+                .switch_timer(TIMER_ENDOFNOTE_1P, false);
+        }
+
+        self.pending.pending_stop_all_notes = true;
+        let bg_vol = if self.adjusted_volume >= 0.0 {
+            self.adjusted_volume
+        } else {
+            self.bg_volume
+        };
+        self.keysound.start_bg_play(&self.model, target, bg_vol);
+        log::info!("Replay seek to {} ms", target_ms);
+    }
+
+    /// Re-run the whole replay keylog headlessly and build the resulting score.
+    ///
+    /// Sets up judge and gauge the way `create()` does, without skin, BGA or
+    /// audio, then judges the keylog to the end of the chart. Call after
+    /// `prepare_pattern_pipeline()` so the replay's pattern is applied.
+    /// Returns `None` outside replay mode, without a keylog, or when no note
+    /// was hit.
+    pub fn recompute_replay_score(&mut self) -> Option<ScoreData> {
+        if self.play_mode.mode != crate::core::bms_player_mode::Mode::Replay {
+            return None;
+        }
+        let keylog = self
+            .score
+            .active_replay
+            .as_ref()
+            .map(|r| r.keylog.clone())
+            .filter(|keylog| !keylog.is_empty())?;
+
+        let mode = self.model.mode().copied().unwrap_or(Mode::BEAT_7K);
+        self.lane_property = Some(LaneProperty::new(&mode));
+        self.judge_table = BMSPlayerRule::load_judge_table(&self.player_config.judge_settings);
+        self.rebuild_judge_system(&mode);
+        self.gauge = self.create_play_gauge();
+        if let Some(ref gauge) = self.gauge {
+            self.gaugelog = (0..gauge.gauge_type_length()).map(|_| Vec::new()).collect();
+        }
+
+        let end = (self.playtime + self.margin_time) * 1000;
+        let failed = self.simulate_replay_until(&keylog, end);
+        self.sync_judge_states_to_model();
+        self.state = if failed {
+            PlayState::Failed
+        } else {
+            PlayState::Finished
+        };
+        self.create_score_data(crate::input::bms_player_input_device::DeviceType::Keyboard)
+    }
+
+    /// Re-run the keylog through the freshly rebuilt judge up to `target` (microseconds).
+    ///
+    /// Each keylog entry is judged at its own time so the result matches
+    /// frame-by-frame playback. Leaves `replay_key_states` holding the keys
+    /// still pressed at `target`. Stops early and returns true when the gauge
+    /// empties without gauge auto shift, where live play would have failed.
+    fn simulate_replay_until(&mut self, keylog: &[crate::skin::KeyInputLog], target: i64) -> bool {
+        let margin = self.margin_time * 1000;
+        let stop_on_empty = self.player_config.play_settings.gauge_auto_shift
+            == crate::skin::player_config::GAUGEAUTOSHIFT_NONE;
+        let mut failed = false;
+        let mut states = vec![false; KEYSTATE_SIZE];
+        let mut changed_times = vec![i64::MIN; KEYSTATE_SIZE];
+        if let Some(mut gauge) = self.gauge.take() {
+            for log in keylog {
+                let time = log.time + margin;
+                if time > target {
+                    break;
+                }
+                if log.keycode < 0 || log.keycode as usize >= KEYSTATE_SIZE {
+                    continue;
+                }
+                let key = log.keycode as usize;
+                states[key] = log.pressed;
+                changed_times[key] = time;
+                self.judge
+                    .update(time, &self.judge_notes, &states, &changed_times, &mut gauge);
+                changed_times[key] = i64::MIN;
+                Self::sample_gauge_log(&mut self.gaugelog, &gauge, time / 1000);
+                if stop_on_empty && gauge.value() == 0.0 {
+                    failed = true;
+                    break;
+                }
+            }
+            if !failed {
+                self.judge.update(
+                    target,
+                    &self.judge_notes,
+                    &states,
+                    &changed_times,
+                    &mut gauge,
+                );
+                Self::sample_gauge_log(&mut self.gaugelog, &gauge, target / 1000);
+                failed = stop_on_empty && gauge.value() == 0.0;
+            }
+            self.gauge = Some(gauge);
+        }
+        self.input.replay_key_states = states;
+        self.input.replay_key_changed_times = changed_times;
+        failed
+    }
+
+    /// Fill the gauge log with the current gauge values up to `ptime_ms`.
+    fn sample_gauge_log(gaugelog: &mut [Vec<f32>], gauge: &GrooveGauge, ptime_ms: i64) {
+        for (i, log) in gaugelog.iter_mut().enumerate() {
+            while log.len() as i64 <= ptime_ms / 500 {
+                log.push(gauge.value_by_type(i as i32));
+            }
+        }
+    }
+}
@@ -114,6 +114,40 @@ impl BMSPlayer {
         }
     }
 
+    /// Queue a restart of the current chart from the Failed/Aborted states.
+    ///
+    /// - QUICK RETRY hotkey: same options and seed, score is not saved
+    /// - START: same options, new seed
+    /// - SELECT: same options and seed, score and replay are saved first
+    ///
+    /// Assist play always gets a new seed. BGA and keysounds are stopped here and the
+    /// next PlayState reinitializes both from the reloaded model.
+    pub(super) fn queue_quick_retry(&mut self) {
+        if self.assist > 0 {
+            self.pending.pending_replay_seed_reset = true;
+            log::info!("Assist mode: cannot replay with same chart");
+        } else if self.input.quick_retry_requested {
+            self.pending.pending_quick_retry_replay = Some(self.build_replay_data());
+            log::info!("Quick retry with same chart");
+        } else if self.input.input_start_pressed {
+            self.pending.pending_replay_seed_reset = true;
+            log::info!("Replay without changing options");
+        } else {
+            self.sync_judge_states_to_model();
+            self.pending.pending_quick_retry_score = self.create_score_data(self.device_type);
+            self.pending.pending_quick_retry_replay = Some(self.build_replay_data());
+            log::info!("Replay same chart (score saved)");
+        }
+        if self.media_load_finished {
+            lock_or_recover(&self.bga).stop();
+            self.pending.pending_stop_all_notes = true;
+        }
+        self.pending.pending_global_pitch = Some(1.0);
+        self.save_config();
+        self.pending.pending_reload_bms = true;
+        self.pending.pending_state_change = Some(MainStateType::Play);
+    }
+
     /// Corresponds to Java BMSPlayer.createScoreData()
     ///
     /// `device_type` comes from `MainController.input_processor().get_device_type()`.
@@ -153,19 +187,14 @@ impl BMSPlayer {
                     };
                 }
             } else if self.judge.past_notes() == self.judge.combo() {
-                if self.judge.judge_count(2) == 0 {
-                    if self.judge.judge_count(1) == 0 {
-                        clear = ClearType::Max;
-                    } else {
-                        clear = ClearType::Perfect;
-                    }
-                } else {
-                    clear = ClearType::FullCombo;
-                }
+                clear = ClearType::combo_lamp(self.judge.judge_count(1), self.judge.judge_count(2));
             } else if !self.is_course_mode {
                 clear = gauge.clear_type();
             }
         }
+        if self.judge_trainer_on {
+            clear = ClearType::NoPlay;
+        }
         score.clear = clear.id();
         if let Some(ref gauge) = self.gauge {
             score.play_option.gauge = if gauge.is_type_changed() {
@@ -264,6 +293,12 @@ impl BMSPlayer {
             stddev = ((stddev_acc / play_times.len() as i128) as f64).sqrt() as i64;
         }
         score.timing_stats.stddev = stddev;
+        for (judge, offset) in score.timing_stats.judge_offsets.iter_mut().enumerate() {
+            *offset = self
+                .judge
+                .average_judge_offset(judge as i32)
+                .unwrap_or(i64::MAX);
+        }
 
         // Java: score.setDeviceType(main.getInputProcessor().getDeviceType());
         score.play_option.device_type = Some(match device_type {
@@ -312,6 +347,11 @@ impl BMSPlayer {
             .note_modifier_settings
             .seven_to_nine_pattern;
         rd.player = Some(self.player_config.name.clone());
+        if let Some(ref gauge) = self.gauge {
+            rd.gauge_history = gauge.history().to_vec();
+        }
+        rd.gauge_log = self.gaugelog.clone();
+        rd.ghost = self.judge.ghost().to_vec();
         rd
     }
 
@@ -395,6 +435,28 @@ impl BMSPlayer {
             .find(|&skin_type| skin_type.mode() == Some(model_mode))
     }
 
+    /// Whether the lane renderer's hi-speed, duration or cover settings differ
+    /// from the PlayConfig stored for the current mode.
+    pub(super) fn lane_settings_changed(&self) -> bool {
+        let Some(ref lr) = self.lanerender else {
+            return false;
+        };
+        let mode = self.model.mode().copied().unwrap_or(Mode::BEAT_7K);
+        let pc = &self.player_config.play_config_ref(mode).playconfig;
+        let speed_changed = if pc.fixhispeed != crate::skin::play_config::FIX_HISPEED_OFF {
+            pc.duration != lr.duration()
+        } else {
+            pc.hispeed != lr.hispeed()
+        };
+        speed_changed
+            || pc.lanecover != lr.lanecover()
+            || pc.lift != lr.lift_region()
+            || pc.hidden != lr.hidden_cover()
+            || pc.enablelanecover != lr.is_enable_lanecover()
+            || pc.enablelift != lr.is_enable_lift()
+            || pc.enablehidden != lr.is_enable_hidden()
+    }
+
     /// Save play config from lane renderer state.
     ///
     /// Corresponds to Java saveConfig() private method.
@@ -413,27 +475,37 @@ impl BMSPlayer {
             Some(ref lr) => lr,
             None => return,
         };
+        let fixhispeed = lr.fixhispeed();
         let duration = lr.duration();
         let hispeed = lr.hispeed();
         let lanecover = lr.lanecover();
         let lift = lr.lift_region();
         let hidden = lr.hidden_cover();
+        let enable_lanecover = lr.is_enable_lanecover();
+        let enable_lift = lr.is_enable_lift();
+        let enable_hidden = lr.is_enable_hidden();
 
         // 3. Get PlayConfig from playerConfig.getPlayConfig(mode).getPlayconfig()
         let mode = self.model.mode().copied().unwrap_or(Mode::BEAT_7K);
         let pc = &mut self.player_config.play_config(mode).playconfig;
 
-        // 4. If fixhispeed != OFF: save duration; else save hispeed
+        // 4. Save the fixation mode (cyclable during play). If fixhispeed != OFF:
+        // save duration; else save hispeed
+        pc.fixhispeed = fixhispeed;
         if pc.fixhispeed != crate::skin::play_config::FIX_HISPEED_OFF {
             pc.duration = duration;
         } else {
             pc.hispeed = hispeed;
         }
 
-        // 5. Save lanecover, lift, hidden
+        // 5. Save lanecover, lift, hidden and their on/off state (toggled by
+        // double-pressing START/SELECT)
         pc.lanecover = lanecover;
         pc.lift = lift;
         pc.hidden = hidden;
+        pc.enablelanecover = enable_lanecover;
+        pc.enablelift = enable_lift;
+        pc.enablehidden = enable_hidden;
 
         // 6. Push updated config back to MainController via outbox.
         // In Java, BMSPlayer writes directly to main.getPlayerConfig() (shared reference).
@@ -516,9 +588,16 @@ impl BMSPlayer {
         } else {
             None
         };
+        let autoplay_score = if self.play_mode.mode == crate::core::bms_player_mode::Mode::Autoplay
+        {
+            self.create_score_data(self.device_type)
+        } else {
+            None
+        };
         let replay = self.build_replay_data();
         crate::score_handoff::ScoreHandoff {
             score_data: score,
+            autoplay_score,
             combo: self.judge.course_combo(),
             maxcombo: self.judge.course_maxcombo(),
             gauge: self.gaugelog.clone(),
@@ -526,6 +605,7 @@ impl BMSPlayer {
             assist: self.assist,
             freq_on: self.freq_on,
             force_no_ir_send: self.force_no_ir_send,
+            judge_trainer_on: self.judge_trainer_on,
             replay_data: Some(replay),
             // Practice mode mutates the model via PracticeModifier;
             // do not leak the modified model into the score handoff.
@@ -536,6 +616,44 @@ impl BMSPlayer {
             },
             recent_judges: self.judge.recent_judges().to_vec(),
             recent_judges_index: self.judge.recent_judges_index(),
+            judge_log: self.judge.judge_log().to_vec(),
+        }
+    }
+
+    /// Snapshot of a play in progress for crash recovery.
+    ///
+    /// Only scored plays outside course mode are kept, once a note was
+    /// judged. The key input log is appended by MainController.
+    pub(super) fn build_crash_snapshot(&self) -> Option<crate::play::crash_recovery::PlaySnapshot> {
+        if self.play_mode.mode != crate::core::bms_player_mode::Mode::Play
+            || self.is_course_mode
+            || !matches!(self.state, PlayState::Play | PlayState::Finished)
+        {
+            return None;
         }
+        let finished = self.state == PlayState::Finished;
+        let mut score = self.create_score_data(self.device_type)?;
+        score.clear = crate::play::crash_recovery::recorded_clear(finished, score.clear);
+        let last_note_time_us = self
+            .judge_note_to_model
+            .iter()
+            .enumerate()
+            .filter(|&(idx, &(tl_idx, _))| tl_idx != usize::MAX && self.judge.note_state(idx) != 0)
+            .filter_map(|(_, &(tl_idx, _))| self.model.timelines.get(tl_idx))
+            .map(|tl| tl.micro_time())
+            .max()
+            .unwrap_or(0);
+        Some(crate::play::crash_recovery::PlaySnapshot {
+            title: self.model.title.clone(),
+            sha256: self.model.sha256.clone(),
+            contains_undefined_ln: self.model.contains_undefined_long_note(),
+            lnmode: self.player_config.play_settings.lnmode,
+            total_notes: self.model.total_notes(),
+            last_note_time_us,
+            finished,
+            score,
+            gauge_log: self.gaugelog.clone(),
+            replay: self.build_replay_data(),
+        })
     }
 }
@@ -65,6 +65,7 @@ impl BMSPlayer {
         // Player data
         // ================================================================
         s.player_data = self.player_data;
+        s.today_player_data = self.today_player_data;
 
         // ================================================================
         // Offsets
@@ -89,7 +90,7 @@ impl BMSPlayer {
         // ================================================================
         // Java: resource.isUpdateScore() is set from assist == 0 after play finishes.
         // During gameplay, assist is known immediately, so we derive it here.
-        s.is_update_score = self.assist == 0;
+        s.is_update_score = self.assist == 0 && !self.judge_trainer_on;
 
         // ================================================================
         // Gauge data
@@ -148,7 +149,11 @@ impl BMSPlayer {
         // Judge area
         // ================================================================
         s.judge_area = {
-            let rule = BMSPlayerRule::for_mode(&mode);
+            let rule = BMSPlayerRule::for_judge_settings(
+                &mode,
+                &self.player_config.judge_settings,
+                self.judge_table.as_ref(),
+            );
             let mut jwr = if self.player_config.judge_settings.custom_judge {
                 [
                     self.player_config
@@ -175,7 +180,7 @@ impl BMSPlayer {
                     _ => {}
                 }
             }
-            Some(rule.judge.note_judge(self.model.judgerank, &jwr))
+            Some(rule.judge.note_judge(self.judge_rank(), &jwr))
         };
 
         // ================================================================
@@ -244,6 +249,9 @@ impl BMSPlayer {
         }
         // Scroll duration from LaneRenderer (Java: getCurrentDuration())
         s.integers.insert(312, current_duration);
+        // Green number: scroll duration in frames at 60fps
+        s.integers
+            .insert(313, (current_duration as f64 * 0.6).round() as i32);
         // Lanecover2: (1 - lift) * lanecover * 1000
         s.integers
             .insert(316, ((1.0 - live_lift) * live_lanecover * 1000.0) as i32);
@@ -347,6 +355,40 @@ impl BMSPlayer {
             }),
         );
 
+        // Live FAST/SLOW counts (IDs 410-424)
+        for judge in 0..=5 {
+            for fast in [true, false] {
+                s.judge_counts
+                    .insert((judge, fast), self.judge.judge_count_fast(judge, fast));
+            }
+        }
+        for id in 410..=419 {
+            let offset = id - 410;
+            s.integers
+                .insert(id, self.judge.judge_count_fast(offset / 2, offset % 2 == 0));
+        }
+        s.integers.insert(421, self.judge.judge_count_fast(5, true));
+        s.integers
+            .insert(422, self.judge.judge_count_fast(5, false));
+        s.integers.insert(423, self.judge.fast_count());
+        s.integers.insert(424, self.judge.slow_count());
+        // Average hit offset (ms) of PGREAT..BAD (IDs 428-431)
+        for judge in 0..4 {
+            s.integers.insert(
+                428 + judge,
+                self.judge
+                    .average_judge_offset(judge)
+                    .map_or(i32::MIN, |offset| (offset / 1000) as i32),
+            );
+        }
+        // Timing (ms, + is early) of the last hit per player (IDs 525-527)
+        for player in 0..3 {
+            s.integers.insert(
+                525 + player as i32,
+                self.judge.recent_judge_timing(player) as i32,
+            );
+        }
+
         // ================================================================
         // Float properties
         // ================================================================
@@ -421,6 +463,11 @@ impl BMSPlayer {
         s.booleans.insert(241, self.judge.now_judge(0) == 1);
         // OPTION_2P_PERFECT (261)
         s.booleans.insert(261, self.judge.now_judge(1) == 1);
+        // OPTION_LANECOVER1_CHANGING (270): START/SELECT held for cover/speed changes
+        s.booleans.insert(
+            270,
+            self.input.input_start_pressed || self.input.input_select_pressed,
+        );
         // OPTION_LANECOVER1_ON (271)
         s.booleans.insert(271, live_lanecover > 0.0);
         // OPTION_LIFT1_ON (272)
@@ -1122,6 +1122,40 @@ fn build_pattern_modifiers_random_option_seed_restored() {
     assert_eq!(player.score.playinfo.randomoptionseed, 12345);
 }
 
+#[test]
+fn build_pattern_modifiers_applies_random_trainer_lane_order() {
+    let mut model = make_model();
+    let mut tl = bms::model::time_line::TimeLine::new(130.0, 0, 8);
+    tl.set_note(2, Some(bms::model::note::Note::new_normal(1)));
+    model.timelines = vec![tl];
+    let mut player = BMSPlayer::new(model);
+    let config = make_default_config();
+    player.score.playinfo.randomoption = 2;
+    player.score.playinfo.lane_order = Some("3124567".to_string());
+
+    player.build_pattern_modifiers(&config);
+
+    assert!(player.model.timelines[0].note(0).is_some());
+    assert!(player.model.timelines[0].note(2).is_none());
+    assert_eq!(
+        player.build_replay_data().lane_order.as_deref(),
+        Some("3124567")
+    );
+}
+
+#[test]
+fn build_pattern_modifiers_drops_lane_order_without_random() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    let config = make_default_config();
+    player.score.playinfo.randomoption = 1;
+    player.score.playinfo.lane_order = Some("3124567".to_string());
+
+    player.build_pattern_modifiers(&config);
+
+    assert_eq!(player.score.playinfo.lane_order, None);
+}
+
 #[test]
 fn build_pattern_modifiers_dp_random2_seed_saved() {
     let mut model = BMSModel::new();
@@ -1148,6 +1182,23 @@ fn build_pattern_modifiers_7to9() {
     assert!(player.assist >= 1, "7to9 should set at least light assist");
 }
 
+#[test]
+fn build_pattern_modifiers_convert_to_7k() {
+    let mut model = BMSModel::new();
+    model.set_mode(Mode::POPN_9K);
+    model.judgerank = 100;
+    let mut player = BMSPlayer::new(model);
+    let mut config = make_default_config();
+    config.note_modifier_settings.convert_to_7k = true;
+
+    player.build_pattern_modifiers(&config);
+    assert_eq!(player.mode(), Mode::BEAT_7K);
+    assert!(
+        player.assist >= 1,
+        "mode conversion should set at least light assist"
+    );
+}
+
 #[test]
 fn build_pattern_modifiers_assist_accumulates_light() {
     let mut model = BMSModel::new();
@@ -1308,6 +1359,91 @@ fn build_pattern_modifiers_lane_shuffle_pattern_saved() {
     );
 }
 
+fn make_srandom_model() -> BMSModel {
+    let mut model = BMSModel::new();
+    model.set_mode(Mode::BEAT_7K);
+    model.judgerank = 100;
+    model.timelines = (0..16)
+        .map(|i| {
+            let mut tl = bms::model::time_line::TimeLine::new(i as f64, i * 100_000, 8);
+            for lane in 0..3 {
+                tl.set_note(
+                    lane,
+                    Some(bms::model::note::Note::new_normal(i as i32 * 10 + lane + 1)),
+                );
+            }
+            tl
+        })
+        .collect();
+    model
+}
+
+fn note_layout(model: &BMSModel) -> Vec<Vec<Option<i32>>> {
+    model
+        .timelines
+        .iter()
+        .map(|tl| (0..8).map(|lane| tl.note(lane).map(|n| n.wav())).collect())
+        .collect()
+}
+
+#[test]
+fn build_pattern_modifiers_records_note_shuffle_log() {
+    let mut player = BMSPlayer::new(make_srandom_model());
+    let mut config = make_default_config();
+    config.play_settings.random = 4; // S-RANDOM
+    player.score.playinfo.randomoption = 4;
+
+    player.build_pattern_modifiers(&config);
+
+    let log = player
+        .score
+        .playinfo
+        .pattern
+        .as_ref()
+        .expect("S-RANDOM should record a pattern modify log");
+    assert_eq!(log.len(), 16, "one entry per shuffled timeslice");
+    assert!(log.iter().all(|l| l.modify.as_ref().unwrap().len() == 8));
+}
+
+#[test]
+fn build_pattern_modifiers_replays_recorded_log_regardless_of_seed() {
+    let mut config = make_default_config();
+    config.play_settings.random = 4; // S-RANDOM
+
+    let mut original = BMSPlayer::new(make_srandom_model());
+    original.score.playinfo.randomoption = 4;
+    original.score.playinfo.randomoptionseed = 1234;
+    original.build_pattern_modifiers(&config);
+    let recorded = original.score.playinfo.pattern.clone();
+
+    // A different seed (and SRAN+ toggled on) would produce a different
+    // arrangement, but the recorded log must win.
+    config.play_settings.sran_plus = true;
+    let mut replayed = BMSPlayer::new(make_srandom_model());
+    replayed.score.playinfo.randomoption = 4;
+    replayed.score.playinfo.randomoptionseed = 98765;
+    replayed.score.playinfo.pattern = recorded.clone();
+    replayed.build_pattern_modifiers(&config);
+
+    assert_eq!(note_layout(&replayed.model), note_layout(&original.model));
+    assert_eq!(replayed.score.playinfo.pattern, recorded);
+}
+
+#[test]
+fn restore_replay_data_copies_pattern_log_for_playback() {
+    let mut player = BMSPlayer::new(make_model());
+    let mut rd = ReplayData::new();
+    rd.pattern = Some(vec![crate::skin::PatternModifyLog::new(0.0, vec![1, 0])]);
+
+    let result = player.restore_replay_data(Some(rd), &ReplayKeyState::default());
+
+    assert!(result.stay_replay);
+    assert_eq!(
+        player.score.playinfo.pattern.as_ref().map(Vec::len),
+        Some(1)
+    );
+}
+
 // --- restore_replay_data tests (Phase 34c) ---
 
 fn make_replay_data() -> ReplayData {
@@ -2167,6 +2303,84 @@ fn freq_trainer_result_fields_correct() {
     assert!(result.force_no_ir_send);
 }
 
+#[test]
+fn freq_trainer_marks_player_state_and_queues_pitch() {
+    let model = make_model_with_time(10000);
+    let mut player = BMSPlayer::new(model);
+
+    player.apply_freq_trainer(150, true, false, &FrequencyType::FREQUENCY);
+    assert!(player.freq_on);
+    assert!(player.force_no_ir_send);
+    assert_eq!(player.take_pending_global_pitch(), Some(1.5));
+    assert_eq!(player.clear_type_for_assist(), None);
+}
+
+#[test]
+fn freq_trainer_slow_rate_flags_assist() {
+    let model = make_model_with_time(10000);
+    let mut player = BMSPlayer::new(model);
+
+    player.apply_freq_trainer(75, true, false, &FrequencyType::UNPROCESSED);
+    assert_eq!(player.take_pending_global_pitch(), None);
+    assert_eq!(
+        player.clear_type_for_assist(),
+        Some(ClearType::LightAssistEasy)
+    );
+}
+
+#[test]
+fn freq_trainer_scales_judge_windows_with_rate() {
+    let model = make_model_with_time(10000);
+    let mut player = BMSPlayer::new(model);
+    assert_eq!(player.judge_window_rates(), ([100; 3], [100; 3]));
+
+    player.apply_freq_trainer(200, true, false, &FrequencyType::FREQUENCY);
+    assert_eq!(player.judge_window_rates(), ([50; 3], [50; 3]));
+
+    let model = make_model_with_time(10000);
+    let mut player = BMSPlayer::new(model);
+    player.constraints = vec![CourseDataConstraint::NoGood];
+    player.apply_freq_trainer(50, true, false, &FrequencyType::FREQUENCY);
+    assert_eq!(player.judge_window_rates(), ([200, 200, 0], [200, 200, 0]));
+}
+
+#[test]
+fn judge_trainer_rescales_windows_mid_song() {
+    let model = make_model_with_time(10000);
+    let mut player = BMSPlayer::new(model);
+    let mode = Mode::BEAT_7K;
+    player.rebuild_judge_system(&mode);
+    let chart_windows = player.judge.judge_table(false).to_vec();
+    assert!(!player.judge_trainer_on);
+
+    player.apply_judge_trainer(&mode, Some(25));
+    let trainer_windows = player.judge.judge_table(false).to_vec();
+    assert!(trainer_windows[0][1] < chart_windows[0][1]);
+    assert_eq!(player.judge_rank(), 25.0);
+    assert!(player.judge_trainer_on);
+    assert!(player.force_no_ir_send);
+
+    // Turning the trainer off restores the chart's windows, but the run stays flagged
+    player.apply_judge_trainer(&mode, None);
+    assert_eq!(player.judge.judge_table(false), chart_windows.as_slice());
+    assert!(player.judge_trainer_on);
+}
+
+#[test]
+fn judge_trainer_run_is_not_saved() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    player.apply_judge_trainer(&Mode::BEAT_7K, Some(75));
+    player.state = PlayState::Aborted;
+
+    let score = player.create_score_data(DeviceType::Keyboard).unwrap();
+    assert_eq!(score.clear, ClearType::NoPlay.id());
+
+    let timer = crate::core::timer_manager::TimerManager::new();
+    assert!(!player.build_snapshot(&timer).is_update_score);
+    assert!(player.build_score_handoff().judge_trainer_on);
+}
+
 #[test]
 fn freq_trainer_scales_chart_timing() {
     // Verify that change_frequency is called on the model
@@ -2695,6 +2909,52 @@ fn save_config_saves_hispeed_when_fixhispeed_off() {
     assert_eq!(pc.hispeed, lr_hispeed);
 }
 
+#[test]
+fn save_config_keeps_lane_cover_toggles_per_mode() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    let mut lr = LaneRenderer::new(&player.model);
+    lr.apply_play_config(
+        &player
+            .player_config
+            .play_config_ref(Mode::BEAT_7K)
+            .playconfig
+            .clone(),
+    );
+    player.lanerender = Some(lr);
+    assert!(!player.lane_settings_changed());
+
+    // Double-press START toggles the lane cover off during play
+    let lr = player.lanerender.as_mut().unwrap();
+    lr.enable_lanecover = !lr.is_enable_lanecover();
+    lr.set_lanecover(0.35);
+    assert!(player.lane_settings_changed());
+
+    player.save_config();
+
+    assert!(!player.lane_settings_changed());
+    let pc = &player
+        .player_config
+        .play_config_ref(Mode::BEAT_7K)
+        .playconfig;
+    assert!(!pc.enablelanecover);
+    assert_eq!(pc.lanecover, 0.35);
+    // Other modes keep their own settings
+    let other = &player
+        .player_config
+        .play_config_ref(Mode::BEAT_14K)
+        .playconfig;
+    assert!(other.enablelanecover);
+    assert_eq!(
+        player
+            .pending
+            .pending_play_config_update
+            .as_ref()
+            .map(|(m, _)| *m),
+        Some(Mode::BEAT_7K)
+    );
+}
+
 // --- media_load_finished tests ---
 
 #[test]
@@ -3019,6 +3279,41 @@ fn failed_quick_retry_start_resets_seed() {
     assert!(player.pending.pending_score_handoff.is_none());
 }
 
+#[test]
+fn failed_quick_retry_hotkey_keeps_seed() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    player.state = PlayState::Failed;
+    player.lanerender = Some(LaneRenderer::new(&player.model));
+    player.input.keyinput = Some(KeyInputProccessor::new(&LaneProperty::new(&Mode::BEAT_7K)));
+    player.play_mode = BMSPlayerMode::PLAY;
+    player.is_course_mode = false;
+    player.score.playinfo.randomoptionseed = 42;
+
+    // QUICK RETRY hotkey -> same options and seed, no score save
+    player.input.quick_retry_requested = true;
+
+    player.main_state_data.timer.update();
+    player.render();
+
+    assert!(!player.pending.pending_replay_seed_reset);
+    assert!(player.pending.pending_quick_retry_score.is_none());
+    assert_eq!(
+        player
+            .pending
+            .pending_quick_retry_replay
+            .as_ref()
+            .map(|r| r.randomoptionseed),
+        Some(42)
+    );
+    assert_eq!(player.pending.pending_global_pitch, Some(1.0));
+    assert!(player.pending.pending_reload_bms);
+    assert_eq!(
+        player.take_pending_state_change(),
+        Some(MainStateType::Play)
+    );
+}
+
 #[test]
 fn failed_quick_retry_select_saves_score() {
     let model = make_model();
@@ -4333,6 +4628,58 @@ fn aborted_quick_retry_not_overwritten_by_fadeout() {
     assert_eq!(state_change, Some(MainStateType::Play));
 }
 
+#[test]
+fn aborted_fadeout_saves_lane_cover_changes() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    player.state = PlayState::Aborted;
+    let mut lanerender = LaneRenderer::new(&player.model);
+    lanerender.set_lanecover(0.3);
+    player.lanerender = Some(lanerender);
+    player.play_mode = BMSPlayerMode::PLAY;
+
+    player.main_state_data.timer.set_timer_on(TIMER_FADEOUT);
+    player.main_state_data.timer.update();
+    let now = player.main_state_data.timer.now_micro_time();
+    player
+        .main_state_data
+        .timer
+        .set_micro_timer(TIMER_FADEOUT, now - 10_000_000);
+
+    player.render();
+
+    assert_eq!(
+        player.take_pending_state_change(),
+        Some(MainStateType::MusicSelect)
+    );
+    let update = player
+        .pending
+        .pending_play_config_update
+        .as_ref()
+        .expect("leaving before the first note should still save the cover");
+    assert!((update.1.lanecover - 0.3).abs() < 1e-6);
+}
+
+#[test]
+fn snapshot_exposes_cover_changing_and_green_number() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    player.lanerender = Some(LaneRenderer::new(&player.model));
+    let timer = crate::core::timer_manager::TimerManager::new();
+
+    let snapshot = player.build_snapshot(&timer);
+    assert!(!snapshot.boolean_value(270));
+
+    player.input.input_start_pressed = true;
+    let snapshot = player.build_snapshot(&timer);
+    assert!(snapshot.boolean_value(270));
+    let duration = snapshot.integer_value(312);
+    assert_eq!(
+        snapshot.integer_value(313),
+        (duration as f64 * 0.6).round() as i32
+    );
+}
+
 // --- save_config outbox tests ---
 
 #[test]
@@ -4924,6 +5271,43 @@ fn autoplay_render_produces_keysound_play_events() {
     );
 }
 
+#[test]
+fn judge_thread_judges_play_and_render_takes_over_its_state() {
+    let model = make_model_with_notes_at_times(&[1_000_000]);
+    let mut player = BMSPlayer::new(model);
+    player.play_mode = BMSPlayerMode::PLAY;
+    player.config.display.judge_thread = true;
+    player.create();
+    player.start_judge_thread();
+    assert!(player.judge_thread.is_some());
+    player.state = PlayState::Play;
+
+    player.main_state_data.timer.set_main_state();
+    player.main_state_data.timer.set_timer_on(TIMER_PLAY);
+    let timer_start = player.main_state_data.timer.micro_timer(TIMER_PLAY);
+    player.main_state_data.timer.frozen = true;
+    player
+        .main_state_data
+        .timer
+        .set_now_micro_time(timer_start + 1_500_000);
+
+    // The note passed without a press; the thread misses it
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    while player.judge.past_notes() == 0 {
+        assert!(std::time::Instant::now() < deadline, "note was not judged");
+        player.render();
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    assert_eq!(
+        player.model.timelines[0].note(0).unwrap().state(),
+        bms::model::judge_note::JUDGE_PR + 1
+    );
+
+    player.state = PlayState::Finished;
+    player.render();
+    assert!(player.judge_thread.is_none());
+}
+
 #[test]
 fn create_score_data_uses_synced_judge_states() {
     // Regression test: create_score_data() iterates model notes for timing stats.
@@ -5856,6 +6240,42 @@ fn build_replay_data_uses_config_gauge_not_auto_shifted() {
     );
 }
 
+#[test]
+fn build_replay_data_includes_gauge_history() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    let gauge_model = {
+        let mut m = BMSModel::new();
+        m.total = 300.0;
+        m
+    };
+    let mut gauge = crate::skin::groove_gauge::GrooveGauge::create_with_id(
+        &gauge_model,
+        crate::skin::groove_gauge::HARD,
+        &crate::skin::gauge_property::GaugeProperty::SevenKeys,
+    );
+    gauge.set_time(750_000);
+    gauge.update(4);
+    player.gauge = Some(gauge);
+    player.gaugelog = vec![vec![20.0, 14.0]; 2];
+
+    let rd = player.build_replay_data();
+    assert_eq!(rd.gauge_log, vec![vec![20.0, 14.0]; 2]);
+    assert_eq!(rd.gauge_history.len(), 1);
+    assert_eq!(rd.gauge_history[0].time, 750_000);
+    assert_eq!(rd.gauge_history[0].judge, 4);
+}
+
+#[test]
+fn build_replay_data_includes_ghost() {
+    let model = make_model_with_notes_at_times(&[1_000_000, 2_000_000]);
+    let mut player = BMSPlayer::new(model);
+    player.create();
+    let rd = player.build_replay_data();
+    assert_eq!(rd.ghost, player.judge.ghost());
+    assert_eq!(rd.ghost.len(), 2);
+}
+
 #[test]
 fn build_replay_data_uses_config_gauge_when_no_gauge_present() {
     // When gauge is None, replay data should still get the config gauge setting.
@@ -6206,16 +6626,37 @@ fn prepare_pattern_pipeline_applies_non_modifier_assist() {
     );
 }
 
-// --- HS replay config application tests (Bug rubato-5pd) ---
-
 #[test]
-fn prepare_pattern_pipeline_applies_hs_replay_config() {
-    // Bug rubato-5pd: When replay mode Key4 is held, the HS replay config from
-    // restore_replay_data should be applied to the player's PlayConfig.
+fn prepare_pattern_pipeline_keeps_retry_seed_in_play_mode() {
+    // A retry of the same chart hands the previous play's options and seed over
+    // through the resource replay; they must override the config in PLAY mode.
     let model = make_model();
     let mut player = BMSPlayer::new(model);
-    let config = make_default_config();
-    player.set_player_config(config);
+    player.set_player_config(make_default_config());
+    player.play_mode = BMSPlayerMode::PLAY;
+    let mut replay = ReplayData::new();
+    replay.randomoption = 1;
+    replay.randomoptionseed = 12345;
+    player.set_active_replay(Some(replay));
+
+    player.prepare_pattern_pipeline();
+
+    assert_eq!(player.score.playinfo.randomoption, 1);
+    assert_eq!(player.score.playinfo.randomoptionseed, 12345);
+    // The keylog of the previous play must not be fed to the judge
+    assert!(player.score.active_replay.is_none());
+}
+
+// --- HS replay config application tests (Bug rubato-5pd) ---
+
+#[test]
+fn prepare_pattern_pipeline_applies_hs_replay_config() {
+    // Bug rubato-5pd: When replay mode Key4 is held, the HS replay config from
+    // restore_replay_data should be applied to the player's PlayConfig.
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    let config = make_default_config();
+    player.set_player_config(config);
     player.play_mode = BMSPlayerMode::REPLAY_1;
 
     // Set up replay data with a custom PlayConfig
@@ -6250,6 +6691,129 @@ fn prepare_pattern_pipeline_applies_hs_replay_config() {
     );
 }
 
+// --- Replay playback control tests ---
+
+/// Helper: a replay player over notes at 1s and 2s whose keylog hits both on time.
+fn make_replay_player_in_play() -> BMSPlayer {
+    let model = make_model_with_notes_at_times(&[1_000_000, 2_000_000]);
+    let mut player = BMSPlayer::new(model);
+    player.play_mode = BMSPlayerMode::REPLAY_1;
+    let mut replay = ReplayData::new();
+    replay.keylog = [
+        (1_000_000, true),
+        (1_050_000, false),
+        (2_000_000, true),
+        (2_050_000, false),
+    ]
+    .iter()
+    .map(|&(time, pressed)| crate::skin::KeyInputLog {
+        time,
+        keycode: 0,
+        pressed,
+    })
+    .collect();
+    player.set_active_replay(Some(replay));
+    player.create();
+    player.state = PlayState::Play;
+    player.main_state_data.timer.set_main_state();
+    player.main_state_data.timer.frozen = true;
+    player.main_state_data.timer.set_timer_on(TIMER_PLAY);
+    player
+}
+
+#[test]
+fn seek_replay_forward_judges_keylog_up_to_target() {
+    let mut player = make_replay_player_in_play();
+
+    player.seek_replay(3000);
+
+    assert_eq!(player.judge.note_state(0), 1, "first note judged PG");
+    assert_eq!(player.judge.note_state(1), 1, "second note judged PG");
+    assert_eq!(player.model.timelines[1].note(0).unwrap().state(), 1);
+    assert_eq!(
+        player.main_state_data.timer.now_time_for_id(TIMER_PLAY),
+        3000
+    );
+    assert_eq!(player.judge.past_notes(), 2);
+}
+
+#[test]
+fn seek_replay_backward_rewinds_judged_notes() {
+    let mut player = make_replay_player_in_play();
+    player.seek_replay(3000);
+
+    player.seek_replay(1500);
+
+    assert_eq!(player.judge.note_state(0), 1);
+    assert_eq!(
+        player.judge.note_state(1),
+        0,
+        "second note is not judged yet"
+    );
+    assert_eq!(
+        player.model.timelines[1].note(0).unwrap().state(),
+        0,
+        "rewound note state is cleared in the model"
+    );
+    assert_eq!(
+        player.main_state_data.timer.now_time_for_id(TIMER_PLAY),
+        1500
+    );
+    assert!(!player.input.replay_key_states[0]);
+}
+
+#[test]
+fn replay_render_feeds_keylog_to_judge() {
+    let mut player = make_replay_player_in_play();
+    player.seek_replay(0);
+
+    let timer_start = player.main_state_data.timer.micro_timer(TIMER_PLAY);
+    player
+        .main_state_data
+        .timer
+        .set_now_micro_time(timer_start + 1_500_000);
+    player.render();
+
+    assert_eq!(player.judge.note_state(0), 1, "keylog press judged PG");
+    assert_eq!(player.judge.note_state(1), 0);
+}
+
+#[test]
+fn replay_fast_forward_advances_play_timer_4x() {
+    let mut player = make_replay_player_in_play();
+    player.seek_replay(0);
+    player.set_play_speed(400);
+
+    let now = player.main_state_data.timer.now_micro_time();
+    player.prevtime = now;
+    player
+        .main_state_data
+        .timer
+        .set_now_micro_time(now + 100_000);
+    player.render();
+
+    assert_eq!(
+        player.main_state_data.timer.now_time_for_id(TIMER_PLAY),
+        400
+    );
+}
+
+#[test]
+fn seek_replay_ignored_outside_replay() {
+    let model = make_model_with_notes_at_times(&[1_000_000]);
+    let mut player = BMSPlayer::new(model);
+    player.play_mode = BMSPlayerMode::PLAY;
+    player.create();
+    player.state = PlayState::Play;
+    player.main_state_data.timer.set_main_state();
+    player.main_state_data.timer.frozen = true;
+    player.main_state_data.timer.set_timer_on(TIMER_PLAY);
+
+    player.seek_replay(3000);
+
+    assert_eq!(player.main_state_data.timer.now_time_for_id(TIMER_PLAY), 0);
+}
+
 // --- Replay 7-to-9 mode change tests (Bug rubato-9dx) ---
 
 #[test]
@@ -6447,6 +7011,48 @@ fn build_score_handoff_populates_all_fields_on_some_path() {
     );
 }
 
+#[test]
+fn build_score_handoff_keeps_autoplay_score_apart() {
+    let model = make_model_with_notes_at_times(&[1_000_000, 2_000_000]);
+    let mut player = BMSPlayer::new(model);
+    player.play_mode = BMSPlayerMode::AUTOPLAY;
+    let mode = player.model.mode().copied().unwrap_or(Mode::BEAT_7K);
+    player.rebuild_judge_system(&mode);
+    player.judge.score_data_mut().judge_counts.epg = 2;
+
+    let handoff = player.build_score_handoff();
+
+    assert!(handoff.score_data.is_none(), "autoplay must not be saved");
+    assert_eq!(handoff.autoplay_score.map(|s| s.judge_counts.epg), Some(2));
+
+    player.play_mode = BMSPlayerMode::PLAY;
+    assert!(player.build_score_handoff().autoplay_score.is_none());
+}
+
+#[test]
+fn crash_snapshot_only_during_scored_play() {
+    let model = make_model_with_notes_at_times(&[1_000_000, 2_000_000]);
+    let mut player = BMSPlayer::new(model);
+    player.play_mode = BMSPlayerMode::PLAY;
+    let mode = player.model.mode().copied().unwrap_or(Mode::BEAT_7K);
+    player.rebuild_judge_system(&mode);
+    player.judge.score_data_mut().judge_counts.epg = 1;
+
+    player.state = PlayState::Ready;
+    assert!(player.build_crash_snapshot().is_none());
+
+    player.state = PlayState::Play;
+    let snapshot = player.build_crash_snapshot().expect("snapshot during play");
+    assert!(!snapshot.finished);
+    assert!(snapshot.score.clear <= ClearType::Failed.id());
+    assert_eq!(snapshot.score.judge_counts.epg, 1);
+    assert_eq!(snapshot.sha256, player.model.sha256);
+    assert_eq!(snapshot.total_notes, 2);
+
+    player.play_mode = BMSPlayerMode::AUTOPLAY;
+    assert!(player.build_crash_snapshot().is_none());
+}
+
 #[test]
 fn build_score_handoff_updated_model_none_in_practice_mode() {
     // Practice mode should NOT include the model in the handoff to avoid
@@ -6827,3 +7433,349 @@ fn set_course_info_persists() {
     assert_eq!(player.course_index, 2);
     assert_eq!(player.course_song_count, 4);
 }
+
+#[test]
+fn create_uses_rival_ghost_as_pacemaker() {
+    let model = make_model_with_notes_at_times(&[1_000_000, 2_000_000]);
+    let mut player = BMSPlayer::new(model);
+    let mut rival = ScoreData::default();
+    crate::ir::lr2_ghost_data::LR2GhostData::from_play_ghost("E2")
+        .unwrap()
+        .apply_to(&mut rival);
+    player.set_rival_score(Some(rival));
+    player.create();
+    assert!(player.main_state_data.score.use_rival_ghost);
+    assert_eq!(player.main_state_data.score.rival_score(), 4);
+}
+
+#[test]
+fn create_ignores_ghost_of_computed_target() {
+    let model = make_model_with_notes_at_times(&[1_000_000, 2_000_000]);
+    let mut player = BMSPlayer::new(model);
+    let mut target = ScoreData::default();
+    crate::ir::lr2_ghost_data::LR2GhostData::from_play_ghost("E2")
+        .unwrap()
+        .apply_to(&mut target);
+    player.set_target_score(Some(target));
+    player.create();
+    assert!(!player.main_state_data.score.use_rival_ghost);
+}
+
+#[test]
+fn create_sets_ghost_pacemaker() {
+    let model = make_model_with_notes_at_times(&[1_000_000, 2_000_000]);
+    let mut player = BMSPlayer::new(model);
+    player.set_ghost(Some(vec![0, 1]));
+    player.create();
+    assert_eq!(player.main_state_data.score.now_ghost_score(), Some(0));
+    assert_eq!(player.main_state_data.score.ghost, Some(vec![0, 1]));
+}
+
+#[test]
+fn snapshot_exposes_target_pace_after_judge() {
+    let model = make_model_with_notes_at_times(&[1_000_000, 2_000_000]);
+    let mut player = BMSPlayer::new(model);
+    let mut target = ScoreData::default();
+    target.judge_counts.epg = 2; // EX 4 over 2 notes
+    target.notes = 2;
+    player.set_target_score(Some(target));
+    player.create();
+
+    let mut score = ScoreData::default();
+    score.judge_counts.egr = 1; // EX 1 after the first note
+    score.notes = 2;
+    player
+        .main_state_data
+        .score
+        .update_score_with_notes(Some(&score), 1);
+
+    let timer = crate::core::timer_manager::TimerManager::new();
+    let snapshot = player.build_snapshot(&timer);
+    assert_eq!(snapshot.integer_value(187), 2);
+    assert_eq!(snapshot.integer_value(153), -1);
+    assert_eq!(snapshot.float_value(114), 0.5);
+    assert_eq!(snapshot.float_value(115), 1.0);
+}
+
+#[test]
+fn snapshot_exposes_live_fast_slow_counts() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    let score = player.judge.score_data_mut();
+    score.add_judge_count(0, true, 3);
+    score.add_judge_count(1, true, 2);
+    score.add_judge_count(2, false, 1);
+    score.add_judge_count(5, true, 1);
+
+    let timer = crate::core::timer_manager::TimerManager::new();
+    let snapshot = player.build_snapshot(&timer);
+    assert_eq!(snapshot.integer_value(410), 3);
+    assert_eq!(snapshot.integer_value(412), 2);
+    assert_eq!(snapshot.integer_value(415), 1);
+    assert_eq!(snapshot.integer_value(421), 1);
+    assert_eq!(snapshot.integer_value(423), 3);
+    assert_eq!(snapshot.integer_value(424), 1);
+    assert_eq!(snapshot.integer_value(428), i32::MIN);
+    assert_eq!(snapshot.judge_count(1, true), 2);
+}
+
+#[test]
+fn create_practice_has_no_ghost_pacemaker() {
+    let model = make_model_with_notes_at_times(&[1_000_000, 2_000_000]);
+    let mut player = BMSPlayer::new(model);
+    player.play_mode = BMSPlayerMode::PRACTICE;
+    player.set_ghost(Some(vec![0, 1]));
+    player.set_ghost_key_beams(Some(vec![crate::skin::KeyInputLog {
+        time: 0,
+        keycode: 0,
+        pressed: true,
+    }]));
+    player.create();
+    assert_eq!(player.main_state_data.score.now_ghost_score(), None);
+    assert!(player.score.ghost_beams.is_none());
+}
+
+#[test]
+fn toggle_bga_hotkey_fires_once_per_press() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    let index = Hotkey::ToggleBga.index();
+
+    player.input.hotkey_states[index] = true;
+    player.input_impl();
+    assert!(player.bga.lock().unwrap().is_hidden());
+
+    // Still held on the next frame: no second toggle
+    player.input.prev_hotkey_states[index] = true;
+    player.input_impl();
+    assert!(player.bga.lock().unwrap().is_hidden());
+}
+
+#[test]
+fn chart_offset_hotkeys_adjust_saved_offset() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    player.set_chart_offset(495);
+
+    player.input.hotkey_states[Hotkey::ChartOffsetUp.index()] = true;
+    player.input_impl();
+    assert_eq!(
+        player.chart_offset(),
+        500,
+        "clamped to the judge timing range"
+    );
+    assert_eq!(player.pending.pending_chart_offset.take(), Some(500));
+
+    player.input.hotkey_states = Default::default();
+    player.input.prev_hotkey_states = Default::default();
+    player.input.hotkey_states[Hotkey::ChartOffsetDown.index()] = true;
+    player.input_impl();
+    assert_eq!(player.chart_offset(), 500 - CHART_OFFSET_STEP);
+    assert_eq!(
+        player.pending.pending_chart_offset,
+        Some(500 - CHART_OFFSET_STEP)
+    );
+}
+
+#[test]
+fn bga_hotkeys_update_render_config_without_restart() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    player.create();
+    assert!(!player.bga.lock().unwrap().is_hidden());
+
+    player.input.hotkey_states[Hotkey::ToggleBga.index()] = true;
+    player.input.hotkey_states[Hotkey::BgaDim.index()] = true;
+    player.input_impl();
+
+    assert!(player.bga.lock().unwrap().is_hidden());
+    assert_eq!(player.bga.lock().unwrap().dim(), 10);
+    assert_eq!(player.state(), PlayState::Preload);
+    let render = player.pending.pending_render_config.take().unwrap();
+    assert_eq!(render.bga, crate::skin::config::BgaMode::Off);
+    assert_eq!(render.bga_dim, 10);
+}
+
+#[test]
+fn bga_dim_hotkey_wraps_after_max() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    player.config.render.bga_dim = crate::skin::config::BGA_DIM_MAX;
+    let index = Hotkey::BgaDim.index();
+
+    player.input.hotkey_states[index] = true;
+    player.input_impl();
+
+    assert_eq!(player.config.render.bga_dim, 0);
+    assert_eq!(player.bga.lock().unwrap().dim(), 0);
+}
+
+#[test]
+fn create_applies_saved_bga_off_and_dim() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    player.config.render.bga = crate::skin::config::BgaMode::Off;
+    player.config.render.bga_dim = 30;
+    player.create();
+
+    assert!(player.bga.lock().unwrap().is_hidden());
+    assert_eq!(player.bga.lock().unwrap().dim(), 30);
+}
+
+#[test]
+fn practice_marker_hotkeys_use_play_position() {
+    let model = make_model_with_notes_at_times(&[0, 60_000_000]);
+    let mut player = BMSPlayer::new(model);
+    player.play_mode = BMSPlayerMode::PRACTICE;
+    player.state = PlayState::Play;
+    player.main_state_data.timer.set_now_micro_time(5_000_000);
+    player
+        .main_state_data
+        .timer
+        .set_micro_timer(TIMER_PLAY, 1_000_000);
+
+    player.input.hotkey_states[Hotkey::PracticeStartMarker.index()] = true;
+    player.input_impl();
+    assert_eq!(player.practice.practice_property().starttime, 4_000);
+    assert!(player.practice.practice_property().endtime >= 5_000);
+}
+
+#[test]
+fn practice_toggle_and_rate_hotkeys_edit_property() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    player.play_mode = BMSPlayerMode::PRACTICE;
+
+    player.input.hotkey_states[Hotkey::PracticeLoop.index()] = true;
+    player.input.hotkey_states[Hotkey::PracticeFreezeGauge.index()] = true;
+    player.input.hotkey_states[Hotkey::PracticeRateDown.index()] = true;
+    player.input_impl();
+
+    let property = player.practice.practice_property();
+    assert!(property.loopsection);
+    assert!(property.freezegauge);
+    assert_eq!(property.freq, 95);
+}
+
+/// Gauge value after missing the only note of a practice run.
+fn gauge_after_practice_miss(freeze: bool) -> f32 {
+    let model = make_model_with_notes_at_times(&[1_000_000]);
+    let mut player = BMSPlayer::new(model);
+    let mode = player.model.mode().copied().unwrap_or(Mode::BEAT_7K);
+    player.play_mode = BMSPlayerMode::PRACTICE;
+    player.practice.practice_property_mut().freezegauge = freeze;
+    player.rebuild_judge_system(&mode);
+    player.gauge = crate::play::groove_gauge::create_groove_gauge(
+        &player.model,
+        crate::skin::groove_gauge::NORMAL,
+        0,
+        None,
+    );
+    player.lanerender = Some(LaneRenderer::new(&player.model));
+    player.state = PlayState::Play;
+    player.playtime = 999_999;
+
+    player.main_state_data.timer.update();
+    let now = player.main_state_data.timer.now_micro_time();
+    player.prevtime = now;
+    player
+        .main_state_data
+        .timer
+        .set_micro_timer(TIMER_PLAY, now - 3_000_000);
+
+    player.render();
+    player.gauge.as_ref().unwrap().value()
+}
+
+#[test]
+fn practice_freeze_gauge_ignores_judges() {
+    let start = crate::play::groove_gauge::create_groove_gauge(
+        &make_model_with_notes_at_times(&[1_000_000]),
+        crate::skin::groove_gauge::NORMAL,
+        0,
+        None,
+    )
+    .unwrap()
+    .value();
+    assert!(gauge_after_practice_miss(false) < start);
+    assert_eq!(gauge_after_practice_miss(true), start);
+}
+
+#[test]
+fn practice_loop_restarts_after_model_reload() {
+    let model = make_model_with_notes_at_times(&[0, 60_000_000]);
+    let mut player = BMSPlayer::new(model);
+    player.play_mode = BMSPlayerMode::PRACTICE;
+    player.create();
+    player.media_load_finished = true;
+    player.play_skin.loadstart = 0;
+    player.play_skin.loadend = 0;
+    player.startpressedtime = -2_000_000;
+    player.input.input_key_states = vec![false];
+
+    // Back from a completed run with LOOP on
+    player.practice_loop_pending = true;
+    player.main_state_data.timer.set_now_micro_time(2_000_000);
+    player
+        .main_state_data
+        .timer
+        .set_micro_timer(TIMER_PLAY, 1_000_000);
+
+    player.render();
+    assert_eq!(player.state(), PlayState::Practice);
+    assert!(player.pending.pending_reload_bms);
+
+    player.render();
+    assert_eq!(player.state(), PlayState::Ready);
+    assert!(!player.practice_loop_pending);
+}
+
+#[test]
+fn hispeed_hotkeys_blocked_by_no_speed_constraint() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    player.constraints = vec![CourseDataConstraint::NoSpeed];
+    player.create();
+    let before = player.lanerender.as_ref().unwrap().play_config().hispeed;
+
+    player.input.hotkey_states[Hotkey::HispeedUp.index()] = true;
+    player.input_impl();
+    assert_eq!(
+        player.lanerender.as_ref().unwrap().play_config().hispeed,
+        before
+    );
+}
+
+#[test]
+fn hispeed_fix_hotkey_cycles_mode_and_saves_it() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    player.create();
+    assert_eq!(
+        player.lanerender.as_ref().unwrap().fixhispeed(),
+        crate::skin::play_config::FIX_HISPEED_MAINBPM
+    );
+
+    player.input.hotkey_states[Hotkey::HispeedFix.index()] = true;
+    player.input_impl();
+    assert_eq!(
+        player.lanerender.as_ref().unwrap().fixhispeed(),
+        crate::skin::play_config::FIX_HISPEED_MINBPM
+    );
+
+    player.save_config();
+    let (_, pc) = player.pending.pending_play_config_update.take().unwrap();
+    assert_eq!(pc.fixhispeed, crate::skin::play_config::FIX_HISPEED_MINBPM);
+}
+
+#[test]
+fn is_class_course_requires_course_mode_and_class_constraint() {
+    let model = make_model();
+    let mut player = BMSPlayer::new(model);
+    player.constraints = vec![CourseDataConstraint::Mirror, CourseDataConstraint::NoSpeed];
+    assert!(!player.is_class_course());
+    player.is_course_mode = true;
+    assert!(player.is_class_course());
+    player.constraints = vec![CourseDataConstraint::NoSpeed];
+    assert!(!player.is_class_course());
+}
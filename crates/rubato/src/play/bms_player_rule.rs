@@ -1,8 +1,13 @@
 use crate::play::gauge_property::GaugeProperty;
-use crate::play::judge::property::{JudgeProperty, JudgePropertyType};
+use crate::play::judge::property::{
+    JudgeProperty, JudgePropertyType, JudgeWindowRuleType, JudgeWindowTable,
+};
+use crate::skin::player_config::JudgeSettings;
 use bms::model::bms_model::{BMSModel, JudgeRankType, TotalType};
 use bms::model::mode::Mode;
 
+pub use crate::skin::bms_player_rule::BMSPlayerRule as RuleSet;
+
 /// Player rule
 #[derive(Clone, Debug)]
 pub struct BMSPlayerRule {
@@ -23,8 +28,30 @@ impl BMSPlayerRule {
         }
     }
 
+    /// LR2 rule: LR2 judge scaling and gauge, regardless of mode.
+    pub fn lr2() -> BMSPlayerRule {
+        BMSPlayerRule::new(GaugeProperty::Lr2, JudgePropertyType::Lr2, vec![])
+    }
+
+    /// Pop'n rule: PMS judge windows and gauge, used by every ruleset for PMS charts.
+    pub fn pms() -> BMSPlayerRule {
+        BMSPlayerRule::new(
+            GaugeProperty::Pms,
+            JudgePropertyType::Pms,
+            vec![Mode::POPN_5K, Mode::POPN_9K],
+        )
+    }
+
     pub fn for_mode(mode: &Mode) -> BMSPlayerRule {
-        let ruleset = bms_player_rule_set_lr2();
+        Self::for_mode_with_ruleset(mode, RuleSet::LR2)
+    }
+
+    /// Rule for `mode` from the selected ruleset (`JudgeSettings::judge_rule`).
+    pub fn for_mode_with_ruleset(mode: &Mode, ruleset: RuleSet) -> BMSPlayerRule {
+        let ruleset = match ruleset {
+            RuleSet::LR2 => bms_player_rule_set_lr2(),
+            RuleSet::Beatoraja => bms_player_rule_set_beatoraja(),
+        };
         for rule in &ruleset {
             if rule.mode.is_empty() {
                 return rule.clone();
@@ -36,7 +63,56 @@ impl BMSPlayerRule {
             }
         }
         // fallback: LR2
-        BMSPlayerRule::new(GaugeProperty::Lr2, JudgePropertyType::Lr2, vec![])
+        BMSPlayerRule::lr2()
+    }
+
+    /// Rule for `mode` from the player's judge settings, with the loaded custom
+    /// judge window table (if any) applied on top.
+    pub fn for_judge_settings(
+        mode: &Mode,
+        settings: &JudgeSettings,
+        table: Option<&JudgeWindowTable>,
+    ) -> BMSPlayerRule {
+        let mut rule = Self::for_mode_with_ruleset(mode, settings.judge_rule);
+        if let Some(table) = table {
+            rule.judge.apply_table(table);
+        }
+        rule
+    }
+
+    /// Load the custom judge window table referenced by `settings.judge_table`.
+    /// A missing or invalid file is logged and ignored.
+    pub fn load_judge_table(settings: &JudgeSettings) -> Option<JudgeWindowTable> {
+        if settings.judge_table.is_empty() {
+            return None;
+        }
+        match JudgeWindowTable::read(std::path::Path::new(&settings.judge_table)) {
+            Ok(table) => {
+                log::info!("Judge window table loaded: {}", settings.judge_table);
+                Some(table)
+            }
+            Err(e) => {
+                log::warn!(
+                    "Failed to load judge window table {}: {}",
+                    settings.judge_table,
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    /// Ruleset this rule belongs to, as recorded in `PlayOption::rule`.
+    pub fn ruleset(&self) -> RuleSet {
+        Self::ruleset_of(&self.judge)
+    }
+
+    pub(crate) fn ruleset_of(judge: &JudgeProperty) -> RuleSet {
+        if judge.windowrule.rule_type == JudgeWindowRuleType::Lr2 {
+            RuleSet::LR2
+        } else {
+            RuleSet::Beatoraja
+        }
     }
 
     pub fn validate(model: &mut BMSModel) {
@@ -51,18 +127,23 @@ impl BMSPlayerRule {
                     rule.judge.windowrule.judgerank[2]
                 };
                 model.judgerank = new_rank;
+                model.judgerank_fraction = 0.0;
             }
             JudgeRankType::BmsDefexrank => {
+                // #DEFEXRANK is a percentage of the NORMAL window and may be fractional
+                let normal = rule.judge.windowrule.judgerank[2] as f64;
                 let new_rank = if judgerank > 0 {
-                    judgerank * rule.judge.windowrule.judgerank[2] / 100
+                    model.judgerank_exact() * normal / 100.0
                 } else {
-                    rule.judge.windowrule.judgerank[2]
+                    normal
                 };
-                model.judgerank = new_rank;
+                model.set_judgerank_exact(new_rank);
             }
             JudgeRankType::BmsonJudgerank => {
-                let new_rank = if judgerank > 0 { judgerank } else { 100 };
-                model.judgerank = new_rank;
+                if judgerank <= 0 {
+                    model.judgerank = 100;
+                    model.judgerank_fraction = 0.0;
+                }
             }
         }
         model.judgerank_type = JudgeRankType::BmsonJudgerank;
@@ -109,16 +190,13 @@ fn calculate_default_total(mode: &Mode, totalnotes: i32) -> f64 {
 /// Rubato intentionally uses `JudgePropertyType::Lr2` instead, which provides a more
 /// accurate approximation of LR2's non-linear judge window scaling behavior
 /// (see `lr2_judge_scaling` and `LR2_SCALING` in judge/property.rs).
+///
+/// PMS charts use `BMSPlayerRule::pms()`: LR2's scaling does not fit pop'n windows.
 fn bms_player_rule_set_lr2() -> Vec<BMSPlayerRule> {
-    vec![BMSPlayerRule::new(
-        GaugeProperty::Lr2,
-        JudgePropertyType::Lr2,
-        vec![],
-    )]
+    vec![BMSPlayerRule::pms(), BMSPlayerRule::lr2()]
 }
 
 /// BMSPlayerRuleSet::Beatoraja
-#[cfg(test)]
 fn bms_player_rule_set_beatoraja() -> Vec<BMSPlayerRule> {
     vec![
         BMSPlayerRule::new(
@@ -131,11 +209,7 @@ fn bms_player_rule_set_beatoraja() -> Vec<BMSPlayerRule> {
             JudgePropertyType::SevenKeys,
             vec![Mode::BEAT_7K, Mode::BEAT_14K],
         ),
-        BMSPlayerRule::new(
-            GaugeProperty::Pms,
-            JudgePropertyType::Pms,
-            vec![Mode::POPN_5K, Mode::POPN_9K],
-        ),
+        BMSPlayerRule::pms(),
         BMSPlayerRule::new(
             GaugeProperty::Keyboard,
             JudgePropertyType::Keyboard,
@@ -156,15 +230,13 @@ mod tests {
     // --- get_bms_player_rule tests ---
 
     #[test]
-    fn lr2_ruleset_returns_lr2_for_any_mode() {
-        // LR2 ruleset has a single rule with empty mode list (matches all)
+    fn lr2_ruleset_returns_lr2_for_non_pms_modes() {
+        // LR2 ruleset's catch-all rule (empty mode list) covers every non-PMS mode
         let modes = [
             Mode::BEAT_5K,
             Mode::BEAT_7K,
             Mode::BEAT_10K,
             Mode::BEAT_14K,
-            Mode::POPN_5K,
-            Mode::POPN_9K,
             Mode::KEYBOARD_24K,
             Mode::KEYBOARD_24K_DOUBLE,
         ];
@@ -174,6 +246,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pms_modes_select_pms_rule_in_every_ruleset() {
+        for ruleset in [RuleSet::LR2, RuleSet::Beatoraja] {
+            for mode in [Mode::POPN_5K, Mode::POPN_9K] {
+                let rule = BMSPlayerRule::for_mode_with_ruleset(&mode, ruleset);
+                assert_eq!(rule.gauge, GaugeProperty::Pms);
+                assert_eq!(rule.judge.windowrule.rule_type, JudgeWindowRuleType::Pms);
+            }
+        }
+    }
+
+    #[test]
+    fn validate_pms_rank_uses_pms_judgerank() {
+        let mut model = BMSModel::new();
+        model.set_mode(Mode::POPN_9K);
+        model.judgerank = 0;
+        model.judgerank_type = JudgeRankType::BmsRank;
+        BMSPlayerRule::validate(&mut model);
+        assert_eq!(model.judgerank, 33);
+    }
+
     #[test]
     fn lr2_ruleset_returns_lr2_gauge() {
         let rule = BMSPlayerRule::for_mode(&Mode::BEAT_7K);
@@ -234,6 +327,17 @@ mod tests {
         assert!(ruleset[4].mode.is_empty());
     }
 
+    #[test]
+    fn beatoraja_ruleset_selects_per_mode_judge() {
+        let rule = BMSPlayerRule::for_mode_with_ruleset(&Mode::POPN_9K, RuleSet::Beatoraja);
+        assert_eq!(rule.gauge, GaugeProperty::Pms);
+        assert_eq!(rule.judge.windowrule.rule_type, JudgeWindowRuleType::Pms);
+        assert_eq!(rule.ruleset(), RuleSet::Beatoraja);
+
+        let rule = BMSPlayerRule::for_mode_with_ruleset(&Mode::BEAT_7K, RuleSet::LR2);
+        assert_eq!(rule.ruleset(), RuleSet::LR2);
+    }
+
     // --- calculate_default_total tests ---
 
     // Java formula: max(260.0, 7.605 * n / (0.01 * n + 6.5))
@@ -368,4 +472,35 @@ mod tests {
         BMSPlayerRule::validate(&mut model);
         assert_eq!(model.judgerank, 100);
     }
+
+    #[test]
+    fn judge_settings_table_overrides_ruleset_windows() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("table.json");
+        std::fs::write(
+            &path,
+            r#"{"note":[[-10,10],[-30,30],[-80,80],[-150,150],[0,1000]]}"#,
+        )
+        .unwrap();
+        let mut settings = JudgeSettings {
+            judge_rule: RuleSet::Beatoraja,
+            ..Default::default()
+        };
+        assert!(BMSPlayerRule::load_judge_table(&settings).is_none());
+
+        settings.judge_table = path.to_string_lossy().into_owned();
+        let table = BMSPlayerRule::load_judge_table(&settings);
+        assert!(table.is_some());
+        let rule = BMSPlayerRule::for_judge_settings(&Mode::BEAT_7K, &settings, table.as_ref());
+        assert_eq!(rule.gauge, GaugeProperty::SevenKeys);
+        let windows = rule.judge.note_judge(100.0, &[100, 100, 100]);
+        assert_eq!(windows[0], vec![-10, 10]);
+
+        settings.judge_table = dir
+            .path()
+            .join("missing.json")
+            .to_string_lossy()
+            .into_owned();
+        assert!(BMSPlayerRule::load_judge_table(&settings).is_none());
+    }
 }
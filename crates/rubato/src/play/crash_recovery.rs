@@ -0,0 +1,260 @@
+//! Crash-safe persistence of the play in progress.
+//!
+//! While a chart is played, MainController keeps the latest [`PlaySnapshot`]
+//! here. A panic hook writes it to [`RECOVERY_PATH`] so the score, gauge log
+//! and key input log survive a crash; on the next launch the snapshot is
+//! taken back and the player is offered to record it.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::core::clear_type::ClearType;
+use crate::core::config::Config;
+use crate::core::play_data_accessor::{PlayDataAccessor, ScoreWriteContext};
+use crate::core::replay_data::ReplayData;
+use crate::core::score_data::ScoreData;
+use crate::imgui_notify::{ImGuiNotify, ToastType};
+use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
+
+/// Recovery file, relative to the data directory.
+pub const RECOVERY_PATH: &str = "recovery.json";
+
+/// Replay slots tried, in order, when recording a recovered play.
+const REPLAY_SLOTS: i32 = 4;
+
+static CURRENT: Mutex<Option<PlaySnapshot>> = Mutex::new(None);
+
+/// State of an unfinished play, enough to record its score and replay.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaySnapshot {
+    pub title: String,
+    pub sha256: String,
+    pub contains_undefined_ln: bool,
+    pub lnmode: i32,
+    pub total_notes: i32,
+    pub last_note_time_us: i64,
+    /// All notes were judged. Otherwise the score is recorded as failed.
+    pub finished: bool,
+    pub score: ScoreData,
+    /// Gauge value per gauge type, sampled every 500 ms.
+    pub gauge_log: Vec<Vec<f32>>,
+    /// Replay with the key input log up to the snapshot.
+    pub replay: ReplayData,
+}
+
+impl PlaySnapshot {
+    /// Write the score and, into the first free slot, the replay.
+    pub fn record(&self, accessor: &PlayDataAccessor) -> Result<()> {
+        accessor.write_score_data(
+            &self.score,
+            &ScoreWriteContext {
+                hash: &self.sha256,
+                contains_undefined_ln: self.contains_undefined_ln,
+                total_notes: self.total_notes,
+                lnmode: self.lnmode,
+                update_score: true,
+                last_note_time_us: self.last_note_time_us,
+            },
+        );
+        let ln = self.contains_undefined_ln;
+        let Some(slot) = (0..REPLAY_SLOTS)
+            .find(|&i| !accessor.exists_replay_data(&self.sha256, ln, self.lnmode, i))
+        else {
+            log::warn!("All replay slots are used; recovered replay not saved");
+            return Ok(());
+        };
+        let mut replay = self.replay.clone();
+        accessor.write_replay_data(&mut replay, &self.sha256, ln, self.lnmode, slot)
+    }
+}
+
+/// Replace the snapshot written on a crash.
+pub fn update(snapshot: PlaySnapshot) {
+    *lock_or_recover(&CURRENT) = Some(snapshot);
+}
+
+/// Forget the snapshot once the play ended normally.
+pub fn clear() {
+    lock_or_recover(&CURRENT).take();
+}
+
+pub fn is_active() -> bool {
+    lock_or_recover(&CURRENT).is_some()
+}
+
+/// Write the current snapshot, if any, to `path`.
+pub fn save_current(path: &Path) -> Result<bool> {
+    // try_lock: the panic may have happened while the snapshot was being replaced.
+    let guard = match CURRENT.try_lock() {
+        Ok(guard) => guard,
+        Err(std::sync::TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+        Err(std::sync::TryLockError::WouldBlock) => return Ok(false),
+    };
+    let Some(ref snapshot) = *guard else {
+        return Ok(false);
+    };
+    let json = serde_json::to_vec(snapshot).context("failed to serialize play snapshot")?;
+    crate::core::robust_file::write_file(path, &json)?;
+    Ok(true)
+}
+
+/// Chain a panic hook that saves the play in progress to `path`.
+pub fn install_panic_hook(path: PathBuf) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        match save_current(&path) {
+            Ok(true) => log::error!("Play in progress saved to {}", path.display()),
+            Ok(false) => {}
+            Err(e) => log::error!("Failed to save play in progress: {:#}", e),
+        }
+        previous(info);
+    }));
+}
+
+/// Read and remove the snapshot left behind by a crash.
+pub fn take_saved(path: &Path) -> Option<PlaySnapshot> {
+    let data = std::fs::read(path).ok()?;
+    if let Err(e) = std::fs::remove_file(path) {
+        log::warn!("Failed to remove {}: {}", path.display(), e);
+    }
+    match serde_json::from_slice(&data) {
+        Ok(snapshot) => Some(snapshot),
+        Err(e) => {
+            log::warn!("Ignoring unreadable {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+/// Offer to record the play left behind by a crash, if any.
+///
+/// The snapshot is only written when the player presses the toast button.
+pub fn offer_recovery(config: &Config) {
+    let Some(snapshot) = take_saved(Path::new(RECOVERY_PATH)) else {
+        return;
+    };
+    let config = config.clone();
+    let pending = Mutex::new(Some(snapshot.clone()));
+    ImGuiNotify::with_action(
+        ToastType::Warning,
+        &tr!(
+            "notify-crash-interrupted",
+            title = snapshot.title.as_str(),
+            exscore = snapshot.score.exscore()
+        ),
+        &tr!("notify-crash-save-score"),
+        Box::new(move || {
+            let Some(snapshot) = lock_or_recover(&pending).take() else {
+                return;
+            };
+            match snapshot.record(&PlayDataAccessor::new(&config)) {
+                Ok(()) => ImGuiNotify::success(&tr!(
+                    "notify-recovered-score-saved",
+                    title = snapshot.title.to_string()
+                )),
+                Err(e) => ImGuiNotify::error(&tr!(
+                    "notify-recovered-score-failed",
+                    error = format!("{:#}", e)
+                )),
+            }
+        }),
+    );
+}
+
+/// Clear lamp of a snapshot: a play cut short by the crash counts as failed.
+pub fn recorded_clear(finished: bool, clear: i32) -> i32 {
+    if finished {
+        clear
+    } else {
+        clear.min(ClearType::Failed.id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_LOCK: Mutex<()> = Mutex::new(());
+
+    fn snapshot() -> PlaySnapshot {
+        let mut score = ScoreData {
+            sha256: "abc".to_string(),
+            clear: ClearType::Failed.id(),
+            maxcombo: 5,
+            ..Default::default()
+        };
+        score.judge_counts.epg = 5;
+        let mut replay = ReplayData::new();
+        replay.keylog.push(crate::skin::KeyInputLog {
+            time: 1000,
+            keycode: 0,
+            pressed: true,
+        });
+        PlaySnapshot {
+            title: "Song".to_string(),
+            sha256: "abc".to_string(),
+            contains_undefined_ln: false,
+            lnmode: 0,
+            total_notes: 10,
+            last_note_time_us: 5_000_000,
+            finished: false,
+            score,
+            gauge_log: vec![vec![20.0, 22.0]],
+            replay,
+        }
+    }
+
+    #[test]
+    fn saved_snapshot_is_taken_back_once() {
+        let _lock = lock_or_recover(&TEST_LOCK);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(RECOVERY_PATH);
+
+        clear();
+        assert!(!save_current(&path).unwrap());
+        assert!(!path.exists());
+
+        update(snapshot());
+        assert!(is_active());
+        assert!(save_current(&path).unwrap());
+        clear();
+
+        let restored = take_saved(&path).unwrap();
+        assert_eq!(restored.title, "Song");
+        assert_eq!(restored.score.judge_counts.epg, 5);
+        assert_eq!(restored.gauge_log, vec![vec![20.0, 22.0]]);
+        assert_eq!(restored.replay.keylog.len(), 1);
+        assert!(take_saved(&path).is_none(), "file should be removed");
+    }
+
+    #[test]
+    fn unreadable_recovery_file_is_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(RECOVERY_PATH);
+        std::fs::write(&path, b"not json").unwrap();
+
+        assert!(take_saved(&path).is_none());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn unfinished_play_is_recorded_as_failed() {
+        assert_eq!(
+            recorded_clear(false, ClearType::Hard.id()),
+            ClearType::Failed.id()
+        );
+        assert_eq!(
+            recorded_clear(true, ClearType::Hard.id()),
+            ClearType::Hard.id()
+        );
+        assert_eq!(
+            recorded_clear(false, ClearType::NoPlay.id()),
+            ClearType::NoPlay.id()
+        );
+    }
+}
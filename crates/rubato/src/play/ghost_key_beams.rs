@@ -0,0 +1,105 @@
+use crate::skin::KeyInputLog;
+
+/// Key presses of a replay ghost, replayed alongside the play so they can be
+/// drawn as translucent key beams over the lanes.
+pub struct GhostKeyBeams {
+    keylog: Vec<KeyInputLog>,
+    /// Next keylog entry to apply
+    index: usize,
+    /// Play time (us) the key states were last advanced to
+    time: i64,
+    /// Pressed state by key code
+    pressed: Vec<bool>,
+}
+
+impl GhostKeyBeams {
+    /// `keylog` is the replay's key input log, in recorded order.
+    pub fn new(keylog: Vec<KeyInputLog>) -> Self {
+        let keys = keylog
+            .iter()
+            .map(|log| log.keycode.max(0) as usize + 1)
+            .max()
+            .unwrap_or(0);
+        Self {
+            keylog,
+            index: 0,
+            time: i64::MIN,
+            pressed: vec![false; keys],
+        }
+    }
+
+    /// Advance the ghost's key states to `time` (us since play start, keylog
+    /// time base). Seeking backward replays the log from the start.
+    pub fn update(&mut self, time: i64) {
+        if time < self.time {
+            self.index = 0;
+            self.pressed.fill(false);
+        }
+        while let Some(log) = self.keylog.get(self.index)
+            && log.time <= time
+        {
+            if log.validate()
+                && let Some(key) = self.pressed.get_mut(log.keycode as usize)
+            {
+                *key = log.pressed;
+            }
+            self.index += 1;
+        }
+        self.time = time;
+    }
+
+    /// Whether any of the keys assigned to each lane is held by the ghost.
+    pub fn lane_beams(&self, lane_keys: &[Vec<i32>]) -> Vec<bool> {
+        lane_keys
+            .iter()
+            .map(|keys| {
+                keys.iter().any(|&key| {
+                    key >= 0 && self.pressed.get(key as usize).copied().unwrap_or(false)
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(time: i64, keycode: i32, pressed: bool) -> KeyInputLog {
+        KeyInputLog {
+            time,
+            keycode,
+            pressed,
+        }
+    }
+
+    #[test]
+    fn beams_follow_the_ghost_keylog() {
+        let mut beams = GhostKeyBeams::new(vec![
+            log(1_000, 0, true),
+            log(2_000, 1, true),
+            log(3_000, 0, false),
+            log(4_000, 1, false),
+        ]);
+        // Lane 0 is key 0, lane 1 is the scratch pair 1/2.
+        let lane_keys = vec![vec![0], vec![1, 2]];
+
+        beams.update(500);
+        assert_eq!(beams.lane_beams(&lane_keys), vec![false, false]);
+        beams.update(2_500);
+        assert_eq!(beams.lane_beams(&lane_keys), vec![true, true]);
+        beams.update(3_000);
+        assert_eq!(beams.lane_beams(&lane_keys), vec![false, true]);
+
+        // Seeking back replays the log from the start.
+        beams.update(1_500);
+        assert_eq!(beams.lane_beams(&lane_keys), vec![true, false]);
+    }
+
+    #[test]
+    fn invalid_entries_are_ignored() {
+        let mut beams = GhostKeyBeams::new(vec![log(-5, 0, true), log(10, -1, true)]);
+        beams.update(100);
+        assert_eq!(beams.lane_beams(&[vec![0], vec![-1]]), vec![false, false]);
+    }
+}
@@ -4,6 +4,8 @@ pub use crate::skin::groove_gauge::*;
 
 use crate::play::bms_player_rule::BMSPlayerRule;
 use crate::play::gauge_property::GaugeProperty;
+use crate::skin::gauge_property::GaugeTable;
+use crate::skin::player_config::PlaySettings;
 use bms::model::bms_model::BMSModel;
 use bms::model::mode::Mode;
 
@@ -14,6 +16,17 @@ pub fn create_groove_gauge(
     gauge_type: i32,
     grade: i32,
     gauge: Option<GaugeProperty>,
+) -> Option<GrooveGauge> {
+    create_groove_gauge_with_table(model, gauge_type, grade, gauge, None)
+}
+
+/// `create_groove_gauge` with the gauge curves overridden by a loaded `GaugeTable`.
+pub fn create_groove_gauge_with_table(
+    model: &BMSModel,
+    gauge_type: i32,
+    grade: i32,
+    gauge: Option<GaugeProperty>,
+    table: Option<&GaugeTable>,
 ) -> Option<GrooveGauge> {
     let id = if grade > 0 {
         // Course gauge
@@ -32,12 +45,34 @@ pub fn create_groove_gauge(
             let mode = model.mode().copied().unwrap_or(Mode::BEAT_7K);
             BMSPlayerRule::for_mode(&mode).gauge
         });
-        Some(GrooveGauge::new(model, id, &gauge))
+        Some(GrooveGauge::with_elements(
+            model,
+            id,
+            gauge.element_values_with(table),
+        ))
     } else {
         None
     }
 }
 
+/// Load the gauge ruleset file referenced by `settings.gauge_table`.
+/// A missing or invalid file is logged and ignored.
+pub fn load_gauge_table(settings: &PlaySettings) -> Option<GaugeTable> {
+    if settings.gauge_table.is_empty() {
+        return None;
+    }
+    match GaugeTable::read(std::path::Path::new(&settings.gauge_table)) {
+        Ok(table) => {
+            log::info!("Gauge table loaded: {}", settings.gauge_table);
+            Some(table)
+        }
+        Err(e) => {
+            log::warn!("Failed to load gauge table {}: {}", settings.gauge_table, e);
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -380,4 +415,24 @@ mod tests {
         // Rate 0.5 should decrease less (higher value remaining)
         assert!(gg2.value() > gg1.value());
     }
+
+    #[test]
+    fn create_groove_gauge_with_table_uses_overrides() {
+        let model = make_model();
+        let table: GaugeTable =
+            serde_json::from_str(r#"{"gauges":{"HARD":{"init":60.0,"min":0.0,"max":100.0}}}"#)
+                .unwrap();
+        let gg = create_groove_gauge_with_table(&model, HARD, 0, None, Some(&table)).unwrap();
+        assert_eq!(gg.value(), 60.0);
+        let default = create_groove_gauge(&model, HARD, 0, None).unwrap();
+        assert_eq!(default.value(), 100.0);
+    }
+
+    #[test]
+    fn load_gauge_table_ignores_missing_file() {
+        let mut settings = PlaySettings::default();
+        assert!(load_gauge_table(&settings).is_none());
+        settings.gauge_table = "/nonexistent/gauge_table.json".to_string();
+        assert!(load_gauge_table(&settings).is_none());
+    }
 }
@@ -5,6 +5,9 @@ use bms::model::mode::Mode;
 mod types;
 pub use types::*;
 
+/// Seek step applied per LEFT/RIGHT press while watching a replay, in milliseconds.
+pub const REPLAY_SEEK_STEP_MS: i64 = 5000;
+
 pub struct ControlInputProcessor {
     hschanged: Vec<bool>,
     startpressedtime: i64,
@@ -13,6 +16,7 @@ pub struct ControlInputProcessor {
     selectpressed: bool,
     start_and_select_pressed: bool,
     cursorpressed: bool,
+    seekpressed: bool,
     lanecovertiming: i64,
     lane_cover_start_timing: i64,
     exitpressedtime: i64,
@@ -55,6 +59,7 @@ impl ControlInputProcessor {
             selectpressed: false,
             start_and_select_pressed: false,
             cursorpressed: false,
+            seekpressed: false,
             lanecovertiming: 0,
             lane_cover_start_timing: i64::MIN,
             exitpressedtime: 0,
@@ -220,8 +225,8 @@ impl ControlInputProcessor {
             self.exitpressedtime = now;
         }
 
-        // ESC to stop play
-        if ctx.control_key_escape_pressed {
+        // CLOSE hotkey (ESC by default) to stop play
+        if ctx.close_pressed {
             result.stop_play = true;
         }
 
@@ -235,12 +240,33 @@ impl ControlInputProcessor {
             } else if ctx.control_key_num3 {
                 result.play_speed = Some(200);
             } else if ctx.control_key_num4 {
-                result.play_speed = Some(300);
+                // Replays fast-forward at 4x; autoplay keeps the Java 3x.
+                result.play_speed = Some(if ctx.autoplay_mode == AutoplayMode::Replay {
+                    400
+                } else {
+                    300
+                });
             } else {
                 result.play_speed = Some(100);
             }
         }
 
+        // Replay seek (replay only): one step per LEFT/RIGHT press
+        if ctx.autoplay_mode == AutoplayMode::Replay {
+            if ctx.control_key_left || ctx.control_key_right {
+                if !self.seekpressed {
+                    result.replay_seek = if ctx.control_key_right {
+                        REPLAY_SEEK_STEP_MS
+                    } else {
+                        -REPLAY_SEEK_STEP_MS
+                    };
+                }
+                self.seekpressed = true;
+            } else {
+                self.seekpressed = false;
+            }
+        }
+
         result
     }
 
@@ -23,7 +23,9 @@ fn make_context<'a>(
         select_pressed: false,
         control_key_up: false,
         control_key_down: false,
-        control_key_escape_pressed: false,
+        control_key_left: false,
+        control_key_right: false,
+        close_pressed: false,
         control_key_num1: false,
         control_key_num2: false,
         control_key_num3: false,
@@ -423,13 +425,13 @@ fn note_end_with_select_stops_play() {
 // ---------------------------------------------------------------
 
 #[test]
-fn escape_stops_play() {
+fn close_hotkey_stops_play() {
     let mut proc = ControlInputProcessor::new(Mode::BEAT_7K);
     let mut lr = make_lanerender();
     let mut analog = noop_analog();
 
     let mut ctx = make_context(&mut lr, &mut *analog);
-    ctx.control_key_escape_pressed = true;
+    ctx.close_pressed = true;
     let result = proc.input(&mut ctx);
 
     assert!(result.stop_play);
@@ -495,6 +497,51 @@ fn play_speed_num4_returns_300() {
     assert_eq!(result.play_speed, Some(300));
 }
 
+#[test]
+fn play_speed_num4_fast_forwards_4x_in_replay() {
+    let mut proc = ControlInputProcessor::new(Mode::BEAT_7K);
+    let mut lr = make_lanerender();
+    let mut analog = noop_analog();
+
+    let mut ctx = make_context(&mut lr, &mut *analog);
+    ctx.autoplay_mode = AutoplayMode::Replay;
+    ctx.control_key_num4 = true;
+    let result = proc.input(&mut ctx);
+
+    assert_eq!(result.play_speed, Some(400));
+}
+
+#[test]
+fn replay_seek_once_per_press() {
+    let mut proc = ControlInputProcessor::new(Mode::BEAT_7K);
+    let mut lr = make_lanerender();
+    let mut analog = noop_analog();
+
+    let mut ctx = make_context(&mut lr, &mut *analog);
+    ctx.autoplay_mode = AutoplayMode::Replay;
+    ctx.control_key_right = true;
+    assert_eq!(proc.input(&mut ctx).replay_seek, REPLAY_SEEK_STEP_MS);
+    // Held key does not repeat
+    assert_eq!(proc.input(&mut ctx).replay_seek, 0);
+
+    ctx.control_key_right = false;
+    assert_eq!(proc.input(&mut ctx).replay_seek, 0);
+    ctx.control_key_left = true;
+    assert_eq!(proc.input(&mut ctx).replay_seek, -REPLAY_SEEK_STEP_MS);
+}
+
+#[test]
+fn replay_seek_ignored_in_autoplay() {
+    let mut proc = ControlInputProcessor::new(Mode::BEAT_7K);
+    let mut lr = make_lanerender();
+    let mut analog = noop_analog();
+
+    let mut ctx = make_context(&mut lr, &mut *analog);
+    ctx.autoplay_mode = AutoplayMode::Autoplay;
+    ctx.control_key_right = true;
+    assert_eq!(proc.input(&mut ctx).replay_seek, 0);
+}
+
 #[test]
 fn play_speed_default_100_in_autoplay() {
     let mut proc = ControlInputProcessor::new(Mode::BEAT_7K);
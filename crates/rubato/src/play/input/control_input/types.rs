@@ -11,10 +11,13 @@ pub struct ControlInputContext<'a> {
     pub start_pressed: bool,
     /// Whether the SELECT button is currently pressed (from BMSPlayerInputProcessor).
     pub select_pressed: bool,
-    /// Control key states: UP, DOWN, ESCAPE, NUM1-4
+    /// Control key states: UP, DOWN, LEFT, RIGHT, NUM1-4
     pub control_key_up: bool,
     pub control_key_down: bool,
-    pub control_key_escape_pressed: bool,
+    pub control_key_left: bool,
+    pub control_key_right: bool,
+    /// Whether the key bound to the CLOSE hotkey is held.
+    pub close_pressed: bool,
     pub control_key_num1: bool,
     pub control_key_num2: bool,
     pub control_key_num3: bool,
@@ -58,6 +61,8 @@ pub struct ControlInputResult {
     pub stop_play: bool,
     /// Play speed to set (only for autoplay/replay modes). None means no change.
     pub play_speed: Option<i32>,
+    /// Replay seek offset in milliseconds (LEFT/RIGHT while watching a replay). 0 means no seek.
+    pub replay_seek: i64,
     /// Whether to clear start_pressed on the input processor.
     pub clear_start: bool,
     /// Whether to clear select_pressed on the input processor.
@@ -148,6 +148,17 @@ impl JudgeThread {
         }
     }
 
+    /// Reposition the keylog cursor so the next tick resumes after `mtime`.
+    ///
+    /// Entries at or before `mtime` are treated as already replayed.
+    pub(super) fn seek(&mut self, mtime: i64) {
+        if let Some(ref keylog) = self.keylog {
+            let margin = self.micro_margin_time;
+            self.index = keylog.partition_point(|e| e.time + margin <= mtime);
+        }
+        self.prevtime = mtime;
+    }
+
     /// Get the max frame time observed (for performance logging).
     pub(super) fn frametime(&self) -> i64 {
         self.frametime
@@ -197,6 +208,15 @@ impl KeyInputProccessor {
         self.judge.as_mut().map(|j| j.tick(mtime))
     }
 
+    /// Move the replay cursor of a running judge thread to `mtime` (replay seek).
+    ///
+    /// Does nothing if the judge is not started.
+    pub fn seek_judge(&mut self, mtime: i64) {
+        if let Some(ref mut j) = self.judge {
+            j.seek(mtime);
+        }
+    }
+
     pub fn stop_judge(&mut self) {
         if self.judge.is_some() {
             if let Some(ref j) = self.judge {
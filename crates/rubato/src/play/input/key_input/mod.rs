@@ -168,6 +168,34 @@ mod tests {
         assert_eq!(result.replay_events[0].time, 1_500_000); // time + margin
     }
 
+    #[test]
+    fn test_judge_thread_seek_skips_and_rewinds_keylog() {
+        let keylog = vec![
+            ReplayKeylogEntry {
+                time: 1_000_000,
+                keycode: 0,
+                pressed: true,
+            },
+            ReplayKeylogEntry {
+                time: 2_000_000,
+                keycode: 0,
+                pressed: false,
+            },
+        ];
+        let mut jt = JudgeThread::new(10_000_000, Some(keylog), 0);
+
+        // Seek forward past the first entry: only the second is replayed
+        jt.seek(1_500_000);
+        let result = jt.tick(2_500_000);
+        assert_eq!(result.replay_events.len(), 1);
+        assert!(!result.replay_events[0].pressed);
+
+        // Seek back to the start: both entries are replayed again
+        jt.seek(0);
+        let result = jt.tick(2_500_000);
+        assert_eq!(result.replay_events.len(), 2);
+    }
+
     #[test]
     fn test_judge_thread_frametime_tracking() {
         let mut jt = JudgeThread::new(10_000_000, None, 0);
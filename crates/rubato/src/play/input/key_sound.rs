@@ -225,6 +225,7 @@ fn autoplay_run(shared: Arc<BgShared>, entries: Vec<BgTimelineEntry>, starttime:
 
         // Play all BG notes in timelines up to current time.
         while p < entries.len() && entries[p].micro_time <= time {
+            crate::profile_scope!("bg_autoplay::enqueue");
             let cmds: Vec<BgNoteCommand> = entries[p]
                 .notes
                 .iter()
@@ -0,0 +1,443 @@
+//! Dedicated 1000Hz judge thread.
+//!
+//! The frame loop judges once per frame, so a miss, an LN end or an autoplay
+//! keysound is only noticed by the frame after it happened. While playing,
+//! this thread owns the working `JudgeManager` and gauge and updates them
+//! every millisecond against the play clock. The render thread keeps a mirror:
+//! it sends key changes and the play clock origin, and receives snapshots with
+//! the judge side effects (keysounds, key beams, judge timers) through mpsc
+//! channels (lock-free since Rust 1.67).
+//!
+//! Gauge shifts and judge trainer changes are made by the render thread. They
+//! are sent as edits and re-applied to any snapshot the thread produced before
+//! it saw them, so the mirror never rolls back.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use bms::model::judge_note::JudgeNote;
+
+use crate::play::groove_gauge::GrooveGauge;
+use crate::play::judge::manager::{JudgeManager, JudgeResults};
+use crate::skin::monotonic_clock::monotonic_micros;
+
+/// Judge period (1000Hz).
+const JUDGE_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Publish a snapshot at least this often even without judge events, so
+/// HCN gauge ticks and LN states reach the render thread.
+const SNAPSHOT_INTERVAL: Duration = Duration::from_millis(4);
+
+/// Play clock origin not set yet.
+const NO_ORIGIN: i64 = i64::MIN;
+
+/// A change to the judge or gauge made by the render thread.
+pub type JudgeEdit = Arc<dyn Fn(&mut JudgeManager, &mut GrooveGauge) + Send + Sync>;
+
+enum JudgeCommand {
+    /// Key states and change times (`i64::MIN` = unchanged) to judge once.
+    Keys {
+        states: Vec<bool>,
+        changed_times: Vec<i64>,
+    },
+    Edit(JudgeEdit),
+}
+
+/// State of the thread after a judge tick.
+pub struct JudgeUpdate {
+    pub judge: JudgeManager,
+    pub gauge: GrooveGauge,
+    /// Side effects since the previous update, oldest first.
+    pub results: JudgeResults,
+    /// Number of edits applied when this update was made.
+    edits_applied: u64,
+}
+
+/// Handle to the judge thread. Stopped and joined on drop.
+pub struct JudgeThread {
+    stop: Arc<AtomicBool>,
+    /// Monotonic time (us) at which the play timer was zero.
+    origin: Arc<AtomicI64>,
+    handle: Option<JoinHandle<()>>,
+    commands: Sender<JudgeCommand>,
+    updates: Receiver<JudgeUpdate>,
+    /// Edits sent so far, and those the thread has not acknowledged yet.
+    edits_sent: u64,
+    unacknowledged: VecDeque<(u64, JudgeEdit)>,
+    /// Last key input sent, to forward only changes.
+    sent_states: Vec<bool>,
+    sent_changed_times: Vec<i64>,
+}
+
+impl JudgeThread {
+    /// Start judging `notes` with copies of `judge` and `gauge`. Returns None
+    /// if the thread could not be spawned.
+    pub fn start(judge: JudgeManager, notes: Vec<JudgeNote>, gauge: GrooveGauge) -> Option<Self> {
+        let (commands, command_receiver) = mpsc::channel();
+        let (update_sender, updates) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let origin = Arc::new(AtomicI64::new(NO_ORIGIN));
+        let worker = JudgeWorker {
+            judge,
+            notes,
+            gauge,
+            origin: Arc::clone(&origin),
+            commands: command_receiver,
+            updates: update_sender,
+            key_states: Vec::new(),
+            changed_times: Vec::new(),
+            prev_mtime: i64::MIN,
+            results: JudgeResults::default(),
+            edits_applied: 0,
+        };
+        let thread_stop = Arc::clone(&stop);
+        let handle = thread::Builder::new()
+            .name("judge".to_string())
+            .spawn(move || worker.run(&thread_stop))
+            .map_err(|e| log::error!("Failed to start judge thread: {}", e))
+            .ok()?;
+        Some(Self {
+            stop,
+            origin,
+            handle: Some(handle),
+            commands,
+            updates,
+            edits_sent: 0,
+            unacknowledged: VecDeque::new(),
+            sent_states: Vec::new(),
+            sent_changed_times: Vec::new(),
+        })
+    }
+
+    /// Publish the play timer (us) as read this frame.
+    pub fn set_play_time(&self, play_micro: i64) {
+        self.origin
+            .store(monotonic_micros() - play_micro, Ordering::Release);
+    }
+
+    /// Forward key input if it changed since the last call.
+    pub fn send_keys(&mut self, states: &[bool], changed_times: &[i64]) {
+        if states == self.sent_states.as_slice()
+            && changed_times == self.sent_changed_times.as_slice()
+        {
+            return;
+        }
+        // Only times that changed are new presses or releases
+        let new_times = changed_times
+            .iter()
+            .enumerate()
+            .map(|(key, &time)| {
+                if self.sent_changed_times.get(key) == Some(&time) {
+                    i64::MIN
+                } else {
+                    time
+                }
+            })
+            .collect();
+        self.sent_states = states.to_vec();
+        self.sent_changed_times = changed_times.to_vec();
+        let _ = self.commands.send(JudgeCommand::Keys {
+            states: states.to_vec(),
+            changed_times: new_times,
+        });
+    }
+
+    /// Apply `edit` on the thread. The caller applies it to its own mirror.
+    pub fn edit(&mut self, edit: JudgeEdit) {
+        self.edits_sent += 1;
+        self.unacknowledged
+            .push_back((self.edits_sent, Arc::clone(&edit)));
+        let _ = self.commands.send(JudgeCommand::Edit(edit));
+    }
+
+    /// Updates published since the last call, oldest first, with every edit
+    /// sent after they were made applied on top.
+    pub fn poll(&mut self) -> Vec<JudgeUpdate> {
+        let mut updates: Vec<JudgeUpdate> = self.updates.try_iter().collect();
+        for update in &mut updates {
+            self.reapply_edits(update);
+        }
+        updates
+    }
+
+    /// Stop the thread and return what it published before stopping.
+    pub fn finish(mut self) -> Vec<JudgeUpdate> {
+        self.join();
+        self.poll()
+    }
+
+    fn reapply_edits(&mut self, update: &mut JudgeUpdate) {
+        while self
+            .unacknowledged
+            .front()
+            .is_some_and(|&(seq, _)| seq <= update.edits_applied)
+        {
+            self.unacknowledged.pop_front();
+        }
+        for (_, edit) in &self.unacknowledged {
+            edit(&mut update.judge, &mut update.gauge);
+        }
+    }
+
+    fn join(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for JudgeThread {
+    fn drop(&mut self) {
+        self.join();
+    }
+}
+
+struct JudgeWorker {
+    judge: JudgeManager,
+    notes: Vec<JudgeNote>,
+    gauge: GrooveGauge,
+    origin: Arc<AtomicI64>,
+    commands: Receiver<JudgeCommand>,
+    updates: Sender<JudgeUpdate>,
+    key_states: Vec<bool>,
+    changed_times: Vec<i64>,
+    prev_mtime: i64,
+    results: JudgeResults,
+    edits_applied: u64,
+}
+
+impl JudgeWorker {
+    fn run(mut self, stop: &AtomicBool) {
+        raise_thread_priority();
+        let mut published = Instant::now();
+        let mut next = Instant::now();
+        while !stop.load(Ordering::Relaxed) {
+            let changed = self.tick();
+            if (changed || published.elapsed() >= SNAPSHOT_INTERVAL) && self.prev_mtime != i64::MIN
+            {
+                if !self.publish() {
+                    return;
+                }
+                published = Instant::now();
+            }
+
+            next += JUDGE_INTERVAL;
+            let now = Instant::now();
+            if next > now {
+                thread::sleep(next - now);
+            } else {
+                // Fell behind (e.g. the thread was descheduled); do not try to catch up
+                next = now;
+            }
+        }
+    }
+
+    /// Judge every key message received, then the current time. Returns true
+    /// if anything the render thread has to see happened.
+    fn tick(&mut self) -> bool {
+        crate::profile_scope!("JudgeWorker::tick");
+        let origin = self.origin.load(Ordering::Acquire);
+        let mut changed = false;
+        for command in self.commands.try_iter().collect::<Vec<_>>() {
+            changed = true;
+            match command {
+                JudgeCommand::Keys {
+                    states,
+                    changed_times,
+                } => {
+                    self.key_states = states;
+                    self.changed_times = changed_times;
+                    if origin != NO_ORIGIN {
+                        self.update(monotonic_micros() - origin);
+                    }
+                }
+                JudgeCommand::Edit(edit) => {
+                    edit(&mut self.judge, &mut self.gauge);
+                    self.edits_applied += 1;
+                }
+            }
+        }
+        if origin != NO_ORIGIN {
+            self.update(monotonic_micros() - origin);
+        }
+        changed || !self.results.is_empty()
+    }
+
+    fn update(&mut self, mtime: i64) {
+        // The origin moves a little each frame; never judge backwards
+        let mtime = mtime.max(self.prev_mtime);
+        self.prev_mtime = mtime;
+        self.judge.update(
+            mtime,
+            &self.notes,
+            &self.key_states,
+            &self.changed_times,
+            &mut self.gauge,
+        );
+        // A key change is judged once
+        self.changed_times.fill(i64::MIN);
+        self.results.append(self.judge.take_results());
+    }
+
+    /// Send the current state. Returns false once the render side is gone.
+    fn publish(&mut self) -> bool {
+        self.updates
+            .send(JudgeUpdate {
+                judge: self.judge.clone(),
+                gauge: self.gauge.clone(),
+                results: std::mem::take(&mut self.results),
+                edits_applied: self.edits_applied,
+            })
+            .is_ok()
+    }
+}
+
+/// Ask the OS to schedule the judge thread ahead of normal threads. Best
+/// effort: raising priority may need privileges, and only Linux has a
+/// per-thread nice value.
+#[cfg(target_os = "linux")]
+fn raise_thread_priority() {
+    // SAFETY: setpriority and gettid have no memory-safety preconditions.
+    let result =
+        unsafe { libc::setpriority(libc::PRIO_PROCESS, libc::gettid() as libc::id_t, -10) };
+    if result != 0 {
+        log::debug!(
+            "Judge thread keeps normal priority: {}",
+            std::io::Error::last_os_error()
+        );
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn raise_thread_priority() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::play::gauge_property::GaugeProperty;
+    use crate::play::judge::algorithm::JudgeAlgorithm;
+    use crate::play::judge::manager::JudgeConfig;
+    use crate::play::lane_property::LaneProperty;
+    use bms::model::bms_model::{BMSModel, LnType};
+    use bms::model::judge_note::{JUDGE_PG, JUDGE_PR, build_judge_notes};
+    use bms::model::mode::Mode;
+    use bms::model::note::Note;
+    use bms::model::time_line::TimeLine;
+
+    fn model_with_note(time_us: i64) -> BMSModel {
+        let mut model = BMSModel::new();
+        model.set_mode(Mode::BEAT_7K);
+        model.judgerank = 100;
+        let mut tl = TimeLine::new(0.0, time_us, 8);
+        let mut note = Note::new_normal(1);
+        note.set_micro_time(time_us);
+        tl.set_note(0, Some(note));
+        model.timelines = vec![tl];
+        model
+    }
+
+    fn start(model: &BMSModel) -> JudgeThread {
+        let notes = build_judge_notes(model);
+        let jp = crate::play::judge_property::lr2();
+        let judge = JudgeManager::from_config(&JudgeConfig {
+            notes: &notes,
+            mode: &Mode::BEAT_7K,
+            ln_type: LnType::LongNote,
+            judge_rank: 100.0,
+            judge_window_rate: [100, 100, 100],
+            scratch_judge_window_rate: [100, 100, 100],
+            algorithm: JudgeAlgorithm::Combo,
+            autoplay: false,
+            judge_property: &jp,
+            lane_property: None,
+            auto_adjust_enabled: false,
+            is_play_or_practice: false,
+            judgeregion: 1,
+        });
+        let gauge = GrooveGauge::new(model, GrooveGauge::NORMAL, &GaugeProperty::Lr2);
+        JudgeThread::start(judge, notes, gauge).expect("judge thread")
+    }
+
+    fn wait_for_judged(thread: &mut JudgeThread) -> JudgeUpdate {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            if let Some(update) = thread
+                .poll()
+                .into_iter()
+                .find(|update| update.judge.past_notes() > 0)
+            {
+                return update;
+            }
+            assert!(Instant::now() < deadline, "note was not judged");
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn test_passed_note_is_missed_without_a_frame() {
+        let model = model_with_note(50_000);
+        let mut thread = start(&model);
+        thread.set_play_time(1_000_000);
+
+        let update = wait_for_judged(&mut thread);
+        assert_eq!(update.judge.ghost()[0], JUDGE_PR);
+        assert!(
+            update
+                .results
+                .judged_events
+                .iter()
+                .any(|&(judge, _)| judge == 4)
+        );
+    }
+
+    #[test]
+    fn test_key_press_is_judged_at_its_stamp() {
+        let model = model_with_note(1_000_000);
+        let mut thread = start(&model);
+        thread.set_play_time(1_000_000);
+
+        let key_count = LaneProperty::new(&Mode::BEAT_7K).key_lane_assign().len();
+        let mut states = vec![false; key_count];
+        let mut times = vec![i64::MIN; key_count];
+        states[0] = true;
+        times[0] = 1_000_000;
+        thread.send_keys(&states, &times);
+
+        let update = wait_for_judged(&mut thread);
+        assert_eq!(update.judge.ghost()[0], JUDGE_PG);
+        assert_eq!(update.results.judged_lanes, vec![0]);
+        assert_eq!(update.results.keysound_play_indices, vec![0]);
+    }
+
+    #[test]
+    fn test_unacknowledged_edits_are_reapplied_to_snapshots() {
+        let model = model_with_note(1_000_000);
+        // The play clock is never set, so the thread publishes nothing
+        let mut thread = start(&model);
+        let stale = || JudgeUpdate {
+            judge: JudgeManager::new(),
+            gauge: GrooveGauge::new(&model, GrooveGauge::NORMAL, &GaugeProperty::Lr2),
+            results: JudgeResults::default(),
+            edits_applied: 0,
+        };
+        thread.edit(Arc::new(|_, gauge| gauge.set_type(GrooveGauge::HARD)));
+
+        let mut update = stale();
+        thread.reapply_edits(&mut update);
+        assert_eq!(update.gauge.gauge_type(), GrooveGauge::HARD);
+
+        // Once the thread has applied the edit its snapshots are taken as is
+        let mut update = JudgeUpdate {
+            edits_applied: 1,
+            ..stale()
+        };
+        thread.reapply_edits(&mut update);
+        assert_eq!(update.gauge.gauge_type(), GrooveGauge::NORMAL);
+        assert!(thread.unacknowledged.is_empty());
+    }
+}
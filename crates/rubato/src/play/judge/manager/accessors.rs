@@ -25,16 +25,23 @@ impl JudgeManager {
             JudgeAlgorithm::Lowest => crate::skin::judge_algorithm::JudgeAlgorithm::Lowest,
             JudgeAlgorithm::Score => crate::skin::judge_algorithm::JudgeAlgorithm::Score,
         });
-        // BMSPlayerRule::get_bms_player_rule always returns the LR2 ruleset in the current
-        // implementation (bms_player_rule_set_lr2). Map to the types-level enum accordingly.
-        let _ = BMSPlayerRule::for_mode(&orgmode);
-        self.score.play_option.rule = Some(crate::skin::bms_player_rule::BMSPlayerRule::LR2);
+        let rule = match player_config {
+            Some(config) => {
+                let table = BMSPlayerRule::load_judge_table(&config.judge_settings);
+                BMSPlayerRule::for_judge_settings(&orgmode, &config.judge_settings, table.as_ref())
+            }
+            None => BMSPlayerRule::for_mode(&orgmode),
+        };
+        self.score.play_option.rule = Some(
+            player_config
+                .map(|config| config.judge_settings.judge_rule)
+                .unwrap_or_else(|| rule.ruleset()),
+        );
 
         self.ghost = vec![4; model.total_notes() as usize];
         self.lntype = model.lntype();
 
-        let rule = BMSPlayerRule::for_mode(&orgmode);
-        let judgerank = model.judgerank;
+        let judgerank = model.judgerank_exact();
 
         let mut key_judge_window_rate = if let Some(config) = player_config {
             if config.judge_settings.custom_judge {
@@ -83,35 +90,17 @@ impl JudgeManager {
 
         self.combocond = rule.judge.combo.clone();
         self.miss = rule.judge.miss;
+        self.empty_poor_gauge_rate = rule.judge.empty_poor_gauge_rate;
         self.judge_vanish = rule.judge.judge_vanish.clone();
 
-        self.nmjudge = rule
-            .judge
-            .judge(NoteType::Note, judgerank, &key_judge_window_rate);
-        self.cnendmjudge =
-            rule.judge
-                .judge(NoteType::LongnoteEnd, judgerank, &key_judge_window_rate);
         self.nreleasemargin = rule.judge.longnote_margin;
-        self.smjudge = rule
-            .judge
-            .judge(NoteType::Scratch, judgerank, &scratch_judge_window_rate);
-        self.scnendmjudge = rule.judge.judge(
-            NoteType::LongscratchEnd,
+        self.sreleasemargin = rule.judge.longscratch_margin;
+        self.set_judge_windows(
+            &rule.judge,
             judgerank,
+            &key_judge_window_rate,
             &scratch_judge_window_rate,
         );
-        self.sreleasemargin = rule.judge.longscratch_margin;
-
-        self.mjudgestart = 0;
-        self.mjudgeend = 0;
-        for l in &self.nmjudge {
-            self.mjudgestart = self.mjudgestart.min(l[0]);
-            self.mjudgeend = self.mjudgeend.max(l[1]);
-        }
-        for l in &self.smjudge {
-            self.mjudgestart = self.mjudgestart.min(l[0]);
-            self.mjudgeend = self.mjudgeend.max(l[1]);
-        }
 
         let player_count = orgmode.player();
         let keys_per_player = orgmode.key() / player_count;
@@ -120,11 +109,43 @@ impl JudgeManager {
         self.recent_judges = vec![i64::MIN; 100];
         self.micro_recent_judges = vec![i64::MIN; 100];
         self.recent_judges_index = 0;
+        self.judge_offsets = [(0, 0); 4];
+        self.judge_log.clear();
         self.judgetiming_delta = 0;
     }
 
     // --- Getters ---
 
+    /// Recompute the note and scratch judge windows in place.
+    ///
+    /// Used by the judge trainer to swap windows mid-song; judge counts,
+    /// combo and note states are kept.
+    pub fn set_judge_windows(
+        &mut self,
+        judge_property: &JudgeProperty,
+        judgerank: f64,
+        key_judge_window_rate: &[i32; 3],
+        scratch_judge_window_rate: &[i32; 3],
+    ) {
+        self.nmjudge = judge_property.judge(NoteType::Note, judgerank, key_judge_window_rate);
+        self.cnendmjudge =
+            judge_property.judge(NoteType::LongnoteEnd, judgerank, key_judge_window_rate);
+        self.smjudge =
+            judge_property.judge(NoteType::Scratch, judgerank, scratch_judge_window_rate);
+        self.scnendmjudge = judge_property.judge(
+            NoteType::LongscratchEnd,
+            judgerank,
+            scratch_judge_window_rate,
+        );
+
+        self.mjudgestart = 0;
+        self.mjudgeend = 0;
+        for l in self.nmjudge.iter().chain(&self.smjudge) {
+            self.mjudgestart = self.mjudgestart.min(l[0]);
+            self.mjudgeend = self.mjudgeend.max(l[1]);
+        }
+    }
+
     pub fn score(&self) -> &ScoreData {
         &self.score
     }
@@ -183,6 +204,27 @@ impl JudgeManager {
         }
     }
 
+    /// Number of FAST judges (GREAT to MISS hit early).
+    pub fn fast_count(&self) -> i32 {
+        self.score.fast_count()
+    }
+
+    /// Number of SLOW judges (GREAT to MISS hit late).
+    pub fn slow_count(&self) -> i32 {
+        self.score.slow_count()
+    }
+
+    /// Average hit offset (us, + is early) of `judge` (0=PG .. 3=BD), `None` before its first hit.
+    pub fn average_judge_offset(&self, judge: i32) -> Option<i64> {
+        let &(sum, count) = self.judge_offsets.get(usize::try_from(judge).ok()?)?;
+        (count > 0).then(|| sum / count as i64)
+    }
+
+    /// Hit offsets (us, + is early) of every PGREAT to BAD judge so far, in judge order.
+    pub fn judge_log(&self) -> &[i64] {
+        &self.judge_log
+    }
+
     pub fn processing_long_note(&self, lane: usize) -> Option<usize> {
         if lane < self.lane_states.len() {
             self.lane_states[lane].processing
@@ -239,6 +281,12 @@ impl JudgeManager {
         self.coursemaxcombo = maxcombo;
     }
 
+    /// Record the configured ruleset in the score. PMS modes share one rule
+    /// across rulesets, so it cannot be derived from the judge property alone.
+    pub fn set_ruleset(&mut self, ruleset: crate::skin::bms_player_rule::BMSPlayerRule) {
+        self.score.play_option.rule = Some(ruleset);
+    }
+
     pub fn judge_time_region(&self, lane: usize) -> &[[i64; 2]] {
         if lane < self.lane_states.len() && self.lane_states[lane].sckey >= 0 {
             &self.smjudge
@@ -347,6 +395,36 @@ impl JudgeManager {
         std::mem::take(&mut self.keysound_volume_set_indices)
     }
 
+    /// Drain HCN gauge ticks produced since the last drain.
+    pub fn drain_hcn_gauge_events(&mut self) -> Vec<HcnGaugeEvent> {
+        std::mem::take(&mut self.hcn_gauge_events)
+    }
+
+    /// Move every queued side effect out, leaving the queues empty.
+    pub fn take_results(&mut self) -> JudgeResults {
+        JudgeResults {
+            judged_lanes: self.drain_judged_lanes(),
+            judged_events: self.drain_judged_events(),
+            judged_visual_events: self.drain_judged_visual_events(),
+            keysound_play_indices: self.drain_keysound_play_indices(),
+            keysound_volume_set_indices: self.drain_keysound_volume_set_indices(),
+            hcn_gauge_events: self.drain_hcn_gauge_events(),
+        }
+    }
+
+    /// Queue side effects taken from another instance, as if the last
+    /// `update()` had produced them.
+    pub fn push_results(&mut self, results: JudgeResults) {
+        let mut queued = self.take_results();
+        queued.append(results);
+        self.judged_lanes = queued.judged_lanes;
+        self.judged_events = queued.judged_events;
+        self.judged_visual_events = queued.judged_visual_events;
+        self.keysound_play_indices = queued.keysound_play_indices;
+        self.keysound_volume_set_indices = queued.keysound_volume_set_indices;
+        self.hcn_gauge_events = queued.hcn_gauge_events;
+    }
+
     /// Get the judge state for a note at the given index.
     ///
     /// Returns 0 if unjudged, or judge+1 (1=PG, 2=GR, 3=GD, 4=BD, 5=PR/MS).
@@ -26,6 +26,7 @@ impl JudgeManager {
             sreleasemargin: 0,
             combocond: Vec::new(),
             miss: MissCondition::One,
+            empty_poor_gauge_rate: 1.0,
             judge_vanish: Vec::new(),
             prevmtime: 0,
             autoplay: false,
@@ -35,6 +36,8 @@ impl JudgeManager {
             recent_judges: vec![i64::MIN; 100],
             micro_recent_judges: vec![i64::MIN; 100],
             recent_judges_index: 0,
+            judge_offsets: [(0, 0); 4],
+            judge_log: Vec::new(),
             auto_adjust_enabled: false,
             is_play_or_practice: false,
             judgetiming_delta: 0,
@@ -47,6 +50,7 @@ impl JudgeManager {
             judged_visual_events: Vec::new(),
             keysound_play_indices: Vec::new(),
             keysound_volume_set_indices: Vec::new(),
+            hcn_gauge_events: Vec::new(),
         }
     }
 
@@ -198,6 +202,7 @@ impl JudgeManager {
             sreleasemargin: config.judge_property.longscratch_margin,
             combocond: config.judge_property.combo.clone(),
             miss: config.judge_property.miss,
+            empty_poor_gauge_rate: config.judge_property.empty_poor_gauge_rate,
             judge_vanish: config.judge_property.judge_vanish.clone(),
             prevmtime: 0,
             autoplay: config.autoplay,
@@ -207,6 +212,8 @@ impl JudgeManager {
             recent_judges: vec![i64::MIN; 100],
             micro_recent_judges: vec![i64::MIN; 100],
             recent_judges_index: 0,
+            judge_offsets: [(0, 0); 4],
+            judge_log: Vec::new(),
             auto_adjust_enabled: config.auto_adjust_enabled,
             is_play_or_practice: config.is_play_or_practice,
             judgetiming_delta: 0,
@@ -225,6 +232,7 @@ impl JudgeManager {
             judged_visual_events: Vec::new(),
             keysound_play_indices: Vec::new(),
             keysound_volume_set_indices: Vec::new(),
+            hcn_gauge_events: Vec::new(),
         };
         jm.score.notes = total_notes as i32;
 
@@ -236,7 +244,7 @@ impl JudgeManager {
             JudgeAlgorithm::Lowest => crate::skin::judge_algorithm::JudgeAlgorithm::Lowest,
             JudgeAlgorithm::Score => crate::skin::judge_algorithm::JudgeAlgorithm::Score,
         });
-        jm.score.play_option.rule = Some(crate::skin::bms_player_rule::BMSPlayerRule::LR2);
+        jm.score.play_option.rule = Some(BMSPlayerRule::ruleset_of(config.judge_property));
 
         jm
     }
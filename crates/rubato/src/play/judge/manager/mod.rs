@@ -21,7 +21,7 @@ pub struct JudgeConfig<'a> {
     pub notes: &'a [JudgeNote],
     pub mode: &'a Mode,
     pub ln_type: LnType,
-    pub judge_rank: i32,
+    pub judge_rank: f64,
     pub judge_window_rate: [i32; 3],
     pub scratch_judge_window_rate: [i32; 3],
     pub algorithm: JudgeAlgorithm,
@@ -45,6 +45,7 @@ struct NoteJudgeState {
 }
 
 /// Internal per-lane state for judge iteration.
+#[derive(Clone)]
 struct LaneIterState {
     _lane: usize,
     player: usize,
@@ -95,6 +96,7 @@ impl LaneIterState {
 }
 
 /// Collector for simultaneous bad judgments.
+#[derive(Clone)]
 struct MultiBadCollector {
     mjudge: Vec<[i64; 2]>,
     enabled: bool,
@@ -234,6 +236,7 @@ impl MultiBadCollector {
 }
 
 /// Note judge manager
+#[derive(Clone)]
 pub struct JudgeManager {
     lntype: LnType,
     score: ScoreData,
@@ -266,6 +269,8 @@ pub struct JudgeManager {
     /// PMS combo condition
     combocond: Vec<bool>,
     miss: MissCondition,
+    /// Gauge change multiplier for empty POOR
+    empty_poor_gauge_rate: f32,
     /// Judge vanish flags
     judge_vanish: Vec<bool>,
     prevmtime: i64,
@@ -277,6 +282,10 @@ pub struct JudgeManager {
     recent_judges: Vec<i64>,
     micro_recent_judges: Vec<i64>,
     recent_judges_index: usize,
+    /// Sum (us, + is early) and count of hit offsets per judge, PGREAT to BAD
+    judge_offsets: [(i64, i32); 4],
+    /// Hit offset (us, + is early) of every PGREAT to BAD judge, in judge order
+    judge_log: Vec<i64>,
     /// Whether timing auto-adjust is enabled
     auto_adjust_enabled: bool,
     /// Whether play mode is PLAY or PRACTICE
@@ -319,6 +328,46 @@ pub struct JudgeManager {
     /// Corresponds to Java `keysound.setVolume(note, vol)` calls in
     /// JudgeManager.update() for HCN processing.
     keysound_volume_set_indices: Vec<(usize, f32)>,
+    /// HCN gauge ticks produced during update(), for the gauge and lane
+    /// timers on the main thread.
+    hcn_gauge_events: Vec<HcnGaugeEvent>,
+}
+
+/// Side effects queued by `update()`, moved out with
+/// [`JudgeManager::take_results`] so several updates can be collected before
+/// the caller applies them.
+#[derive(Clone, Debug, Default)]
+pub struct JudgeResults {
+    pub judged_lanes: Vec<usize>,
+    pub judged_events: Vec<(i32, i64)>,
+    pub judged_visual_events: Vec<JudgeVisualEvent>,
+    pub keysound_play_indices: Vec<usize>,
+    pub keysound_volume_set_indices: Vec<(usize, f32)>,
+    pub hcn_gauge_events: Vec<HcnGaugeEvent>,
+}
+
+impl JudgeResults {
+    pub fn is_empty(&self) -> bool {
+        self.judged_lanes.is_empty()
+            && self.judged_events.is_empty()
+            && self.judged_visual_events.is_empty()
+            && self.keysound_play_indices.is_empty()
+            && self.keysound_volume_set_indices.is_empty()
+            && self.hcn_gauge_events.is_empty()
+    }
+
+    /// Append `other` after the results already collected.
+    pub fn append(&mut self, mut other: JudgeResults) {
+        self.judged_lanes.append(&mut other.judged_lanes);
+        self.judged_events.append(&mut other.judged_events);
+        self.judged_visual_events
+            .append(&mut other.judged_visual_events);
+        self.keysound_play_indices
+            .append(&mut other.keysound_play_indices);
+        self.keysound_volume_set_indices
+            .append(&mut other.keysound_volume_set_indices);
+        self.hcn_gauge_events.append(&mut other.hcn_gauge_events);
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -328,6 +377,14 @@ pub struct JudgeVisualEvent {
     pub judge: i32,
 }
 
+/// One HCN gauge tick: recovery while the note is held, drain while released.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HcnGaugeEvent {
+    pub player: usize,
+    pub offset: usize,
+    pub increase: bool,
+}
+
 impl Default for JudgeManager {
     fn default() -> Self {
         Self::new()
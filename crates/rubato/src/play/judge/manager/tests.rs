@@ -225,7 +225,7 @@ fn from_config_creates_valid_state() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -254,7 +254,7 @@ fn autoplay_judges_all_notes_as_pgreat() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -305,7 +305,7 @@ fn miss_all_notes_without_input() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -600,7 +600,7 @@ fn make_autoadjust_jm(note_times: &[i64]) -> (JudgeManager, Vec<JudgeNote>, Groo
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -793,7 +793,7 @@ fn auto_adjust_no_delta_when_disabled() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -846,7 +846,7 @@ fn auto_adjust_no_delta_when_not_play_mode() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1071,7 +1071,7 @@ fn from_config_has_nonzero_lane_count() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1102,7 +1102,7 @@ fn from_config_with_judgeregion_2_sizes_arrays() {
         notes: &notes,
         mode: &Mode::BEAT_14K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1138,7 +1138,7 @@ fn update_produces_judged_events_after_from_config() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1192,7 +1192,7 @@ fn judgenow_judgecombo_populated_after_update_micro() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1250,7 +1250,7 @@ fn judgecombo_uses_coursecombo_not_combo() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1320,7 +1320,7 @@ fn gauge_not_double_updated_via_judged_events() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1374,7 +1374,7 @@ fn from_config_sets_judge_algorithm_combo() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1407,7 +1407,7 @@ fn from_config_sets_judge_algorithm_duration() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Duration,
@@ -1436,7 +1436,7 @@ fn from_config_sets_judge_algorithm_lowest() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Lowest,
@@ -1465,7 +1465,7 @@ fn from_config_sets_judge_algorithm_score() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Score,
@@ -1505,7 +1505,7 @@ fn note_state_returns_zero_for_unjudged() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: model.lntype(),
-        judge_rank: model.judgerank,
+        judge_rank: model.judgerank_exact(),
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1552,7 +1552,7 @@ fn note_state_updated_after_autoplay_judgment() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: model.lntype(),
-        judge_rank: model.judgerank,
+        judge_rank: model.judgerank_exact(),
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1605,7 +1605,7 @@ fn autoplay_produces_keysound_play_events() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1655,7 +1655,7 @@ fn drain_keysound_play_indices_clears_after_drain() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1729,7 +1729,7 @@ fn mine_note_hit_produces_keysound_play_event() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1789,7 +1789,7 @@ fn manual_key_press_produces_keysound_play_event() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1863,7 +1863,7 @@ fn keysound_events_cleared_at_start_of_update() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1926,7 +1926,7 @@ fn autoplay_judges_notes_past_35_minutes() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -1988,7 +1988,7 @@ fn auto_minduration_is_80ms_in_microseconds() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -2021,7 +2021,7 @@ fn autoplay_does_not_produce_judged_lanes() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -2078,7 +2078,7 @@ fn miss_poor_does_not_produce_judged_lanes() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -2133,7 +2133,7 @@ fn manual_key_press_produces_judged_lanes() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -2209,7 +2209,7 @@ fn make_manual_jm(note_time_us: i64) -> (JudgeManager, Vec<JudgeNote>, GrooveGau
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -2461,7 +2461,7 @@ fn autoplay_ln_judges_start_and_end_as_pgreat() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -2525,7 +2525,7 @@ fn autoplay_hcn_judges_start_and_end_as_pgreat() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::HellChargeNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -2596,7 +2596,7 @@ fn hcn_release_mid_hold_decreases_gauge() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::HellChargeNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -2666,6 +2666,15 @@ fn hcn_release_mid_hold_decreases_gauge() {
 
     // Record gauge value while holding
     let gauge_while_holding = gauge.value();
+    let held_events = jm.drain_hcn_gauge_events();
+    assert!(
+        !held_events.is_empty(),
+        "holding should emit HCN gauge ticks"
+    );
+    assert!(
+        held_events.iter().all(|e| e.increase && e.player == 0),
+        "held ticks should all be recoveries: {held_events:?}"
+    );
 
     // Release key at 1.5s
     let release_time = 1_500_000i64;
@@ -2693,6 +2702,11 @@ fn hcn_release_mid_hold_decreases_gauge() {
         time += 10_000;
     }
 
+    assert!(
+        jm.drain_hcn_gauge_events().iter().any(|e| !e.increase),
+        "released HCN should emit drain ticks"
+    );
+
     // After releasing mid-hold, the HCN gauge decrease loop should reduce gauge.
     // The decrease triggers when inclease=false and passing is active.
     assert!(
@@ -2715,7 +2729,7 @@ fn ln_miss_both_start_and_end() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::HellChargeNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -2786,7 +2800,7 @@ fn laser_color_mfast_zero_is_slow_not_fast() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -2901,7 +2915,7 @@ fn bss_end_no_match_counts_as_ems_miss() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::ChargeNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -3027,7 +3041,7 @@ fn chord_both_lanes_pressed_simultaneously_get_pgreat() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -3109,7 +3123,7 @@ fn chord_only_one_lane_pressed_other_gets_miss() {
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type: LnType::LongNote,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -3229,7 +3243,7 @@ fn make_manual_ln_jm(
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -3582,7 +3596,7 @@ fn make_manual_ln_jm_with_judge_property(
         notes: &notes,
         mode: &Mode::BEAT_7K,
         ln_type,
-        judge_rank: 100,
+        judge_rank: 100.0,
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -3971,3 +3985,117 @@ fn deferred_ln_release_timeout_uses_lnend_judge_3_for_final_judgment() {
         "Ghost should record JUDGE_BD (3) from the deferred lnend_judge, got {ghost_val}"
     );
 }
+
+// --- Empty POOR behavior ---
+
+/// Press the first key 500ms before a single note at 1s and return the gauge
+/// value and combo afterwards.
+fn press_empty_poor(jp: &JudgeProperty) -> (f32, JudgeManager) {
+    let model = make_model_with_notes(&[1_000_000]);
+    let notes = build_judge_notes(&model);
+    let config = JudgeConfig {
+        notes: &notes,
+        mode: &Mode::BEAT_7K,
+        ln_type: LnType::LongNote,
+        judge_rank: 100.0,
+        judge_window_rate: [100, 100, 100],
+        scratch_judge_window_rate: [100, 100, 100],
+        algorithm: JudgeAlgorithm::Combo,
+        autoplay: false,
+        judge_property: jp,
+        lane_property: None,
+        auto_adjust_enabled: false,
+        is_play_or_practice: false,
+        judgeregion: 1,
+    };
+    let mut jm = JudgeManager::from_config(&config);
+    let gp = crate::play::gauge_property::GaugeProperty::Lr2;
+    let mut gauge = GrooveGauge::new(&model, GrooveGauge::HARD, &gp);
+
+    let key_count = LaneProperty::new(&Mode::BEAT_7K).key_lane_assign().len();
+    jm.update(
+        -1,
+        &notes,
+        &vec![false; key_count],
+        &vec![i64::MIN; key_count],
+        &mut gauge,
+    );
+    let mut keys = vec![false; key_count];
+    keys[0] = true;
+    let mut key_times = vec![i64::MIN; key_count];
+    key_times[0] = 500_000;
+    jm.update(500_000, &notes, &keys, &key_times, &mut gauge);
+    (gauge.value(), jm)
+}
+
+#[test]
+fn empty_poor_gauge_rate_scales_penalty() {
+    let jp = crate::play::judge_property::lr2();
+    let (full, jm) = press_empty_poor(&jp);
+    assert_eq!(jm.score().judge_count_total(5), 1);
+    assert!(full < 100.0, "empty POOR drains the HARD gauge");
+
+    let mut lenient = jp.clone();
+    lenient.apply_table(&crate::play::judge::property::JudgeWindowTable {
+        empty_poor_gauge_rate: Some(0.0),
+        ..Default::default()
+    });
+    let (none, jm) = press_empty_poor(&lenient);
+    assert_eq!(jm.score().judge_count_total(5), 1);
+    assert_eq!(none, 100.0);
+}
+
+#[test]
+fn hit_offsets_are_tracked_per_judge() {
+    let model = make_model_with_notes(&[500_000, 1_500_000]);
+    let notes = build_judge_notes(&model);
+    let jp = crate::play::judge_property::lr2();
+
+    let config = JudgeConfig {
+        notes: &notes,
+        mode: &Mode::BEAT_7K,
+        ln_type: LnType::LongNote,
+        judge_rank: 100.0,
+        judge_window_rate: [100, 100, 100],
+        scratch_judge_window_rate: [100, 100, 100],
+        algorithm: JudgeAlgorithm::Combo,
+        autoplay: false,
+        judge_property: &jp,
+        lane_property: None,
+        auto_adjust_enabled: false,
+        is_play_or_practice: false,
+        judgeregion: 1,
+    };
+    let mut jm = JudgeManager::from_config(&config);
+
+    let gp = crate::play::gauge_property::GaugeProperty::Lr2;
+    let mut gauge = GrooveGauge::new(&model, GrooveGauge::NORMAL, &gp);
+
+    let lp = LaneProperty::new(&Mode::BEAT_7K);
+    let key_count = lp.key_lane_assign().len();
+    let released = vec![false; key_count];
+    let no_times = vec![i64::MIN; key_count];
+    jm.update(-1, &notes, &released, &no_times, &mut gauge);
+    assert_eq!(jm.average_judge_offset(0), None);
+
+    // 10ms early: PGREAT
+    let mut keys = vec![false; key_count];
+    keys[0] = true;
+    let mut key_times = vec![i64::MIN; key_count];
+    key_times[0] = 490_000;
+    jm.update(490_000, &notes, &keys, &key_times, &mut gauge);
+    jm.update(600_000, &notes, &released, &no_times, &mut gauge);
+
+    // 40ms late: GREAT
+    key_times[0] = 1_540_000;
+    jm.update(1_540_000, &notes, &keys, &key_times, &mut gauge);
+
+    assert_eq!(jm.average_judge_offset(0), Some(10_000));
+    assert_eq!(jm.average_judge_offset(1), Some(-40_000));
+    assert_eq!(jm.average_judge_offset(2), None);
+    assert_eq!(jm.average_judge_offset(4), None);
+    assert_eq!(jm.judge_log(), &[10_000, -40_000]);
+    assert_eq!(jm.recent_judge_timing(0), -40);
+    assert_eq!(jm.fast_count(), 0);
+    assert_eq!(jm.slow_count(), 1);
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::core::performance_metrics::{PerformanceMetrics, WATCH_JUDGE_LATENCY};
 
 /// Parameters for the internal judge update (update_micro).
 struct UpdateMicroParams<'a> {
@@ -26,6 +27,8 @@ impl JudgeManager {
         key_changed_times: &[i64],
         gauge: &mut GrooveGauge,
     ) {
+        crate::profile_scope!("JudgeManager::update");
+        gauge.set_time(mtime);
         self.judged_lanes.clear();
         self.keysound_play_indices.clear();
         self.keysound_volume_set_indices.clear();
@@ -210,7 +213,12 @@ impl JudgeManager {
             if self.lane_states[lane_idx].inclease {
                 self.lane_states[lane_idx].mpassingcount += mtime - self.prevmtime;
                 if self.lane_states[lane_idx].mpassingcount > HCN_MDURATION {
-                    gauge.update_with_rate(1, 0.5);
+                    gauge.update_hcn(true);
+                    self.hcn_gauge_events.push(HcnGaugeEvent {
+                        player: self.lane_states[lane_idx].player,
+                        offset: self.lane_states[lane_idx].offset,
+                        increase: true,
+                    });
                     self.lane_states[lane_idx].mpassingcount -= HCN_MDURATION;
                 }
                 // Java line 333-334: if(state.passing.getPair().getState() > 3)
@@ -228,7 +236,12 @@ impl JudgeManager {
             } else {
                 self.lane_states[lane_idx].mpassingcount -= mtime - self.prevmtime;
                 if self.lane_states[lane_idx].mpassingcount < -HCN_MDURATION {
-                    gauge.update_with_rate(3, 0.5);
+                    gauge.update_hcn(false);
+                    self.hcn_gauge_events.push(HcnGaugeEvent {
+                        player: self.lane_states[lane_idx].player,
+                        offset: self.lane_states[lane_idx].offset,
+                        increase: false,
+                    });
                     self.lane_states[lane_idx].mpassingcount += HCN_MDURATION;
                 }
                 // Java line 345-346: if(state.passing.getPair().getState() > 3)
@@ -264,6 +277,10 @@ impl JudgeManager {
             if pmtime == i64::MIN {
                 continue;
             }
+            PerformanceMetrics::get().record_duration(
+                WATCH_JUDGE_LATENCY,
+                std::time::Duration::from_micros((mtime - pmtime).max(0) as u64),
+            );
             self.lane_states[lane_idx].reset();
             let sc = self.lane_states[lane_idx].sckey;
 
@@ -896,6 +913,13 @@ impl JudgeManager {
             self.recent_judges[self.recent_judges_index] = mfast / 1000;
             self.micro_recent_judges[self.recent_judges_index] = mfast;
         }
+        if judge < 4 {
+            self.judge_log.push(mfast);
+        }
+        if let Some((sum, count)) = self.judge_offsets.get_mut(judge as usize) {
+            *sum += mfast;
+            *count += 1;
+        }
 
         if (judge as usize) < self.combocond.len() && self.combocond[judge as usize] && judge < 5 {
             self.combo += 1;
@@ -944,7 +968,11 @@ impl JudgeManager {
         }
 
         if !multi_bad {
-            gauge.update(judge);
+            if judge == 5 {
+                gauge.update_with_rate(judge, self.empty_poor_gauge_rate);
+            } else {
+                gauge.update(judge);
+            }
             // Record judge event for the caller to trigger update_judge() side effects.
             // multi_bad notes are not individually reported (Java behavior).
             self.judged_events.push((judge, mtime));
@@ -1,3 +1,4 @@
 pub mod algorithm;
+pub mod judge_thread;
 pub mod manager;
 pub mod property;
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 /// Judge property configuration
 #[derive(Clone, Debug)]
 pub struct JudgeProperty {
@@ -17,10 +19,13 @@ pub struct JudgeProperty {
     pub miss: MissCondition,
     /// Whether each judge causes note to vanish
     pub judge_vanish: Vec<bool>,
+    /// Gauge change multiplier applied to empty POOR (excessive) judgments
+    pub empty_poor_gauge_rate: f32,
     pub windowrule: JudgeWindowRule,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum MissCondition {
     One,
     Always,
@@ -55,18 +60,19 @@ static LR2_SCALING: [[i64; 5]; 4] = [
     [0, 40000, 60000, 100000, 120000],
 ];
 
-fn lr2_judge_scaling(mut base: i64, judgerank: i32) -> i64 {
+fn lr2_judge_scaling(mut base: i64, judgerank: f64) -> i64 {
     let mut sign: i64 = 1;
     if base < 0 {
         base = -base;
         sign = -1;
     }
-    let judgerank = judgerank.max(0);
-    if judgerank >= 100 {
-        return sign * base * judgerank as i64 / 100;
+    let judgerank = scaled_rank(judgerank).max(0);
+    if judgerank >= 100 * RANK_SCALE {
+        return sign * base * judgerank / (100 * RANK_SCALE);
     }
     let last = LR2_SCALING[0].len() - 1;
-    let judgeindex = judgerank as usize / 25;
+    let step = 25 * RANK_SCALE;
+    let judgeindex = (judgerank / step) as usize;
     let mut s: usize = 0;
     while s < LR2_SCALING.len() && base >= LR2_SCALING[s][last] {
         s += 1;
@@ -86,11 +92,19 @@ fn lr2_judge_scaling(mut base: i64, judgerank: i32) -> i64 {
         x1 = n * LR2_SCALING[s - 1][judgeindex];
         x2 = n * LR2_SCALING[s - 1][judgeindex + 1];
     }
-    sign * (x1 + (judgerank as i64 - judgeindex as i64 * 25) * (x2 - x1) / 25) / d
+    sign * (x1 + (judgerank - judgeindex as i64 * step) * (x2 - x1) / step) / d
+}
+
+/// Judge ranks are resolved in hundredths so fractional ranks (#DEFEXRANK 87.5)
+/// scale windows exactly while whole ranks keep the integer results.
+const RANK_SCALE: i64 = 100;
+
+fn scaled_rank(judgerank: f64) -> i64 {
+    (judgerank * RANK_SCALE as f64).round() as i64
 }
 
 #[allow(clippy::needless_range_loop)] // Index j used for [i64; 2] array with cross-index reads
-fn create_lr2(org: &[[i64; 2]], judgerank: i32, judge_window_rate: &[i32]) -> Vec<[i64; 2]> {
+fn create_lr2(org: &[[i64; 2]], judgerank: f64, judge_window_rate: &[i32]) -> Vec<[i64; 2]> {
     if org.len() < 4 {
         return org.to_vec();
     }
@@ -137,12 +151,13 @@ impl JudgeWindowRule {
     fn create_normal(
         &self,
         org: &[[i64; 2]],
-        judgerank: i32,
+        judgerank: f64,
         judge_window_rate: &[i32],
     ) -> Vec<[i64; 2]> {
         if org.len() < 4 {
             return org.to_vec();
         }
+        let judgerank = scaled_rank(judgerank);
         debug_assert!(
             org.len() <= self.fixjudge.len(),
             "org has {} rows but fixjudge has only {} entries",
@@ -155,7 +170,7 @@ impl JudgeWindowRule {
                 judge[i][j] = if self.fixjudge[i] {
                     org[i][j]
                 } else {
-                    org[i][j] * judgerank as i64 / 100
+                    org[i][j] * judgerank / (100 * RANK_SCALE)
                 };
             }
         }
@@ -207,7 +222,7 @@ impl JudgeWindowRule {
     pub fn create(
         &self,
         org: &[[i64; 2]],
-        judgerank: i32,
+        judgerank: f64,
         judge_window_rate: &[i32],
     ) -> Vec<[i64; 2]> {
         match self.rule_type {
@@ -230,7 +245,7 @@ fn convert_milli(judge: &[[i64; 2]]) -> Vec<Vec<i32>> {
 }
 
 impl JudgeProperty {
-    pub fn note_judge(&self, judgerank: i32, judge_window_rate: &[i32]) -> Vec<Vec<i32>> {
+    pub fn note_judge(&self, judgerank: f64, judge_window_rate: &[i32]) -> Vec<Vec<i32>> {
         convert_milli(
             &self
                 .windowrule
@@ -238,7 +253,7 @@ impl JudgeProperty {
         )
     }
 
-    pub fn long_note_end_judge(&self, judgerank: i32, judge_window_rate: &[i32]) -> Vec<Vec<i32>> {
+    pub fn long_note_end_judge(&self, judgerank: f64, judge_window_rate: &[i32]) -> Vec<Vec<i32>> {
         convert_milli(
             &self
                 .windowrule
@@ -246,7 +261,7 @@ impl JudgeProperty {
         )
     }
 
-    pub fn scratch_judge(&self, judgerank: i32, judge_window_rate: &[i32]) -> Vec<Vec<i32>> {
+    pub fn scratch_judge(&self, judgerank: f64, judge_window_rate: &[i32]) -> Vec<Vec<i32>> {
         convert_milli(
             &self
                 .windowrule
@@ -256,7 +271,7 @@ impl JudgeProperty {
 
     pub fn long_scratch_end_judge(
         &self,
-        judgerank: i32,
+        judgerank: f64,
         judge_window_rate: &[i32],
     ) -> Vec<Vec<i32>> {
         convert_milli(
@@ -269,7 +284,7 @@ impl JudgeProperty {
     pub fn judge(
         &self,
         notetype: NoteType,
-        judgerank: i32,
+        judgerank: f64,
         judge_window_rate: &[i32],
     ) -> Vec<[i64; 2]> {
         match notetype {
@@ -351,6 +366,7 @@ pub fn fivekeys() -> JudgeProperty {
         combo: vec![true, true, true, false, false, false],
         miss: MissCondition::Always,
         judge_vanish: vec![true, true, true, true, true, false],
+        empty_poor_gauge_rate: 1.0,
         windowrule: rule_normal(),
     }
 }
@@ -388,6 +404,7 @@ pub fn sevenkeys() -> JudgeProperty {
         combo: vec![true, true, true, false, false, true],
         miss: MissCondition::Always,
         judge_vanish: vec![true, true, true, true, true, false],
+        empty_poor_gauge_rate: 1.0,
         windowrule: rule_normal(),
     }
 }
@@ -414,6 +431,7 @@ pub fn pms() -> JudgeProperty {
         combo: vec![true, true, true, false, false, false],
         miss: MissCondition::One,
         judge_vanish: vec![true, true, true, false, true, false],
+        empty_poor_gauge_rate: 1.0,
         windowrule: rule_pms(),
     }
 }
@@ -440,6 +458,7 @@ pub fn keyboard() -> JudgeProperty {
         combo: vec![true, true, true, false, false, true],
         miss: MissCondition::Always,
         judge_vanish: vec![true, true, true, true, true, false],
+        empty_poor_gauge_rate: 1.0,
         windowrule: rule_normal(),
     }
 }
@@ -477,6 +496,7 @@ pub fn lr2() -> JudgeProperty {
         combo: vec![true, true, true, false, false, true],
         miss: MissCondition::Always,
         judge_vanish: vec![true, true, true, true, true, false],
+        empty_poor_gauge_rate: 1.0,
         windowrule: rule_lr2(),
     }
 }
@@ -503,6 +523,147 @@ impl JudgePropertyType {
     }
 }
 
+/// Judge window table loaded from a JSON file, overriding the windows of a
+/// pre-defined JudgeProperty. Windows are `[late, early]` pairs in milliseconds;
+/// omitted tables keep the base property's values.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct JudgeWindowTable {
+    pub name: String,
+    /// PG, GR, GD, BD, MS windows
+    pub note: Vec<[i64; 2]>,
+    /// PG, GR, GD, BD, MS windows
+    pub scratch: Vec<[i64; 2]>,
+    /// PG, GR, GD, BD windows
+    pub longnote: Vec<[i64; 2]>,
+    /// PG, GR, GD, BD windows
+    pub longscratch: Vec<[i64; 2]>,
+    #[serde(rename = "longnoteMargin")]
+    pub longnote_margin: Option<i64>,
+    #[serde(rename = "longscratchMargin")]
+    pub longscratch_margin: Option<i64>,
+    /// #RANK 0-4 to judgerank mapping
+    pub judgerank: Vec<i32>,
+    /// Whether each window ignores judgerank scaling
+    pub fixjudge: Vec<bool>,
+    /// Empty POOR `[late, early]` window for notes and scratches
+    #[serde(rename = "emptyPoorWindow")]
+    pub empty_poor_window: Option<[i64; 2]>,
+    /// Whether an empty POOR resets the combo
+    #[serde(rename = "emptyPoorBreaksCombo")]
+    pub empty_poor_breaks_combo: Option<bool>,
+    /// Gauge change multiplier for empty POOR (0 disables the penalty)
+    #[serde(rename = "emptyPoorGaugeRate")]
+    pub empty_poor_gauge_rate: Option<f32>,
+    /// Whether a note can be missed more than once ("ONE" or "ALWAYS")
+    #[serde(rename = "missCondition")]
+    pub miss_condition: Option<MissCondition>,
+}
+
+impl JudgeWindowTable {
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        let table: JudgeWindowTable = serde_json::from_str(&data)?;
+        table.check()?;
+        Ok(table)
+    }
+
+    fn check(&self) -> anyhow::Result<()> {
+        for (label, windows, rows) in [
+            ("note", &self.note, 5),
+            ("scratch", &self.scratch, 5),
+            ("longnote", &self.longnote, 4),
+            ("longscratch", &self.longscratch, 4),
+        ] {
+            if windows.is_empty() {
+                continue;
+            }
+            if windows.len() != rows {
+                anyhow::bail!("{label}: expected {rows} windows, found {}", windows.len());
+            }
+            if windows.iter().any(|w| w[0] > 0 || w[1] < 0) {
+                anyhow::bail!("{label}: window must span the note timing");
+            }
+            // PG..BD must not shrink; the last note/scratch row is the empty-poor window
+            for i in 1..4 {
+                if windows[i][0] > windows[i - 1][0] || windows[i][1] < windows[i - 1][1] {
+                    anyhow::bail!("{label}: window {i} is narrower than window {}", i - 1);
+                }
+            }
+        }
+        if !self.judgerank.is_empty() && self.judgerank.len() != 5 {
+            anyhow::bail!("judgerank: expected 5 entries");
+        }
+        if !self.fixjudge.is_empty() && self.fixjudge.len() != 5 {
+            anyhow::bail!("fixjudge: expected 5 entries");
+        }
+        if let Some(window) = self.empty_poor_window
+            && (window[0] > 0 || window[1] < 0)
+        {
+            anyhow::bail!("emptyPoorWindow: window must span the note timing");
+        }
+        if let Some(rate) = self.empty_poor_gauge_rate
+            && !(rate >= 0.0 && rate.is_finite())
+        {
+            anyhow::bail!("emptyPoorGaugeRate: must be a non-negative number");
+        }
+        Ok(())
+    }
+}
+
+fn table_micro(windows: &[[i64; 2]]) -> Vec<[i64; 2]> {
+    windows.iter().map(|w| [w[0] * 1000, w[1] * 1000]).collect()
+}
+
+impl JudgeProperty {
+    /// Replace the windows present in `table`, keeping the rest of this property.
+    pub fn apply_table(&mut self, table: &JudgeWindowTable) {
+        if !table.note.is_empty() {
+            self.note = table_micro(&table.note);
+        }
+        if !table.scratch.is_empty() {
+            self.scratch = table_micro(&table.scratch);
+        }
+        if !table.longnote.is_empty() {
+            self.longnote = table_micro(&table.longnote);
+        }
+        if !table.longscratch.is_empty() {
+            self.longscratch = table_micro(&table.longscratch);
+        }
+        if let Some(margin) = table.longnote_margin {
+            self.longnote_margin = margin * 1000;
+        }
+        if let Some(margin) = table.longscratch_margin {
+            self.longscratch_margin = margin * 1000;
+        }
+        if !table.judgerank.is_empty() {
+            self.windowrule.judgerank = table.judgerank.clone();
+        }
+        if !table.fixjudge.is_empty() {
+            self.windowrule.fixjudge = table.fixjudge.clone();
+        }
+        if let Some(window) = table.empty_poor_window {
+            let window = [window[0] * 1000, window[1] * 1000];
+            for windows in [&mut self.note, &mut self.scratch] {
+                if let Some(row) = windows.get_mut(4) {
+                    *row = window;
+                }
+            }
+        }
+        if let Some(breaks) = table.empty_poor_breaks_combo
+            && let Some(cond) = self.combo.get_mut(5)
+        {
+            *cond = !breaks;
+        }
+        if let Some(rate) = table.empty_poor_gauge_rate {
+            self.empty_poor_gauge_rate = rate;
+        }
+        if let Some(miss) = table.miss_condition {
+            self.miss = miss;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -519,6 +680,120 @@ mod tests {
         let _ = JudgePropertyType::Lr2.get();
     }
 
+    #[test]
+    fn fractional_judgerank_scales_between_whole_ranks() {
+        let rate = [100, 100, 100];
+        for jp in [sevenkeys(), lr2()] {
+            let lo = jp.judge(NoteType::Note, 65.0, &rate);
+            let mid = jp.judge(NoteType::Note, 65.5, &rate);
+            let hi = jp.judge(NoteType::Note, 66.0, &rate);
+            assert!(lo[1][1] < mid[1][1] && mid[1][1] < hi[1][1]);
+        }
+        // Whole ranks keep the integer results
+        assert_eq!(
+            sevenkeys().judge(NoteType::Note, 75.0, &rate)[0],
+            [-15000, 15000]
+        );
+        assert_eq!(
+            sevenkeys().judge(NoteType::Note, 65.5, &rate)[0],
+            [-13100, 13100]
+        );
+    }
+
+    // --- JudgeWindowTable tests ---
+
+    #[test]
+    fn judge_window_table_overrides_given_windows() {
+        let table: JudgeWindowTable = serde_json::from_str(
+            r#"{"name":"strict","note":[[-15,15],[-40,40],[-100,100],[-200,200],[0,1000]],"judgerank":[20,40,60,80,100]}"#,
+        )
+        .unwrap();
+        table.check().unwrap();
+        let mut jp = sevenkeys();
+        jp.apply_table(&table);
+        assert_eq!(jp.note[0], [-15000, 15000]);
+        assert_eq!(jp.note[4], [0, 1000000]);
+        assert_eq!(jp.windowrule.judgerank, vec![20, 40, 60, 80, 100]);
+        // Untouched tables keep the base values
+        assert_eq!(jp.scratch, sevenkeys().scratch);
+        assert_eq!(jp.longnote_margin, 0);
+    }
+
+    #[test]
+    fn judge_window_table_rejects_malformed_windows() {
+        let mut table = JudgeWindowTable {
+            note: vec![[-20, 20], [-60, 60], [-150, 150], [-280, 220]],
+            ..Default::default()
+        };
+        assert!(table.check().is_err(), "note table needs 5 rows");
+        table.note = vec![[-20, 20], [-10, 10], [-150, 150], [-280, 220], [-150, 500]];
+        assert!(table.check().is_err(), "GREAT narrower than PGREAT");
+        table.note = vec![[20, 20], [-60, 60], [-150, 150], [-280, 220], [-150, 500]];
+        assert!(table.check().is_err(), "window must contain 0");
+        table.note = vec![[-20, 20], [-60, 60], [-150, 150], [-280, 220], [-150, 500]];
+        assert!(table.check().is_ok());
+    }
+
+    #[test]
+    fn judge_window_table_read_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tournament.json");
+        std::fs::write(
+            &path,
+            r#"{"longnote":[[-100,100],[-120,120],[-150,150],[-200,200]],"longnoteMargin":50}"#,
+        )
+        .unwrap();
+        let table = JudgeWindowTable::read(&path).unwrap();
+        assert_eq!(table.longnote[0], [-100, 100]);
+        assert_eq!(table.longnote_margin, Some(50));
+
+        std::fs::write(&path, r#"{"fixjudge":[true]}"#).unwrap();
+        assert!(JudgeWindowTable::read(&path).is_err());
+        assert!(JudgeWindowTable::read(&dir.path().join("missing.json")).is_err());
+    }
+
+    #[test]
+    fn judge_window_table_overrides_empty_poor_behavior() {
+        let table: JudgeWindowTable = serde_json::from_str(
+            r#"{"emptyPoorWindow":[-100,300],"emptyPoorBreaksCombo":true,"emptyPoorGaugeRate":0.5,"missCondition":"ONE"}"#,
+        )
+        .unwrap();
+        table.check().unwrap();
+        let mut jp = sevenkeys();
+        assert!(jp.combo[5]);
+        jp.apply_table(&table);
+        assert_eq!(jp.note[4], [-100000, 300000]);
+        assert_eq!(jp.scratch[4], [-100000, 300000]);
+        assert_eq!(jp.note[3], sevenkeys().note[3]);
+        assert!(!jp.combo[5]);
+        assert_eq!(jp.empty_poor_gauge_rate, 0.5);
+        assert_eq!(jp.miss, MissCondition::One);
+
+        // Omitted fields keep the base behavior
+        let mut jp = fivekeys();
+        jp.apply_table(&JudgeWindowTable {
+            empty_poor_breaks_combo: Some(false),
+            ..Default::default()
+        });
+        assert!(jp.combo[5]);
+        assert_eq!(jp.empty_poor_gauge_rate, 1.0);
+        assert_eq!(jp.miss, MissCondition::Always);
+    }
+
+    #[test]
+    fn judge_window_table_rejects_bad_empty_poor_settings() {
+        let mut table = JudgeWindowTable {
+            empty_poor_window: Some([10, 300]),
+            ..Default::default()
+        };
+        assert!(table.check().is_err());
+        table.empty_poor_window = None;
+        table.empty_poor_gauge_rate = Some(-1.0);
+        assert!(table.check().is_err());
+        table.empty_poor_gauge_rate = Some(0.0);
+        assert!(table.check().is_ok());
+    }
+
     // --- JudgeWindowRule pre-defined rule tests ---
 
     #[test]
@@ -676,41 +951,41 @@ mod tests {
     #[test]
     fn lr2_judge_scaling_at_rank_100_returns_base() {
         // When judgerank >= 100, returns base * judgerank / 100
-        assert_eq!(lr2_judge_scaling(21000, 100), 21000);
-        assert_eq!(lr2_judge_scaling(60000, 100), 60000);
+        assert_eq!(lr2_judge_scaling(21000, 100.0), 21000);
+        assert_eq!(lr2_judge_scaling(60000, 100.0), 60000);
     }
 
     #[test]
     fn lr2_judge_scaling_at_rank_200_doubles() {
-        assert_eq!(lr2_judge_scaling(21000, 200), 42000);
+        assert_eq!(lr2_judge_scaling(21000, 200.0), 42000);
     }
 
     #[test]
     fn lr2_judge_scaling_negative_base() {
         // Negative base should produce negative result
-        let result = lr2_judge_scaling(-21000, 100);
+        let result = lr2_judge_scaling(-21000, 100.0);
         assert_eq!(result, -21000);
     }
 
     #[test]
     fn lr2_judge_scaling_zero_base() {
-        assert_eq!(lr2_judge_scaling(0, 50), 0);
+        assert_eq!(lr2_judge_scaling(0, 50.0), 0);
     }
 
     #[test]
     fn lr2_judge_scaling_rank_50() {
         // Rank 50 should be roughly half the window
-        let result = lr2_judge_scaling(21000, 50);
+        let result = lr2_judge_scaling(21000, 50.0);
         assert!(result < 21000, "rank 50 should narrow the window");
         assert!(result > 0, "rank 50 should still be positive");
     }
 
     #[test]
     fn lr2_judge_scaling_rank_75() {
-        let result = lr2_judge_scaling(21000, 75);
+        let result = lr2_judge_scaling(21000, 75.0);
         assert!(result < 21000, "rank 75 should narrow the window");
         assert!(
-            result > lr2_judge_scaling(21000, 50),
+            result > lr2_judge_scaling(21000, 50.0),
             "rank 75 wider than rank 50"
         );
     }
@@ -728,7 +1003,7 @@ mod tests {
             [-150000, 500000],
         ];
         let rate = [100, 100, 100];
-        let result = rule.create(org, 100, &rate);
+        let result = rule.create(org, 100.0, &rate);
         // At rank 100, windows should be 100% of original
         assert_eq!(result[0], [-20000, 20000]);
         assert_eq!(result[1], [-60000, 60000]);
@@ -746,7 +1021,7 @@ mod tests {
             [-150000, 500000],
         ];
         let rate = [100, 100, 100];
-        let result = rule.create(org, 50, &rate);
+        let result = rule.create(org, 50.0, &rate);
         // At rank 50, non-fixed windows should be ~50% of original
         assert_eq!(result[0], [-10000, 10000]);
         assert_eq!(result[1], [-30000, 30000]);
@@ -763,7 +1038,7 @@ mod tests {
             [0, 1000000],
         ];
         let rate = [100, 100, 100];
-        let result = rule.create(org, 100, &rate);
+        let result = rule.create(org, 100.0, &rate);
         // At rank 100 in LR2, windows should be unchanged
         assert_eq!(result[0], [-21000, 21000]);
     }
@@ -774,7 +1049,7 @@ mod tests {
     fn get_note_judge_converts_to_milliseconds() {
         let jp = sevenkeys();
         let rate = [100, 100, 100];
-        let result = jp.note_judge(100, &rate);
+        let result = jp.note_judge(100.0, &rate);
         // Original PGREAT: [-20000, 20000] micros => [-20, 20] millis
         assert_eq!(result[0], vec![-20, 20]);
         // GREAT: [-60000, 60000] => [-60, 60]
@@ -785,8 +1060,8 @@ mod tests {
     fn get_judge_returns_correct_note_type() {
         let jp = sevenkeys();
         let rate = [100, 100, 100];
-        let note_judge = jp.judge(NoteType::Note, 100, &rate);
-        let scratch_judge = jp.judge(NoteType::Scratch, 100, &rate);
+        let note_judge = jp.judge(NoteType::Note, 100.0, &rate);
+        let scratch_judge = jp.judge(NoteType::Scratch, 100.0, &rate);
         // Note and Scratch should differ for 7keys
         assert_ne!(note_judge[0], scratch_judge[0]);
     }
@@ -795,7 +1070,7 @@ mod tests {
     fn get_scratch_judge_converts_to_milliseconds() {
         let jp = sevenkeys();
         let rate = [100, 100, 100];
-        let result = jp.scratch_judge(100, &rate);
+        let result = jp.scratch_judge(100.0, &rate);
         // Scratch PGREAT: [-30000, 30000] => [-30, 30]
         assert_eq!(result[0], vec![-30, 30]);
     }
@@ -820,7 +1095,7 @@ mod tests {
         let jp = sevenkeys();
         // 50% rate for PG, 100% for GR, 100% for GD
         let rate = [50, 100, 100];
-        let result = jp.judge(NoteType::Note, 100, &rate);
+        let result = jp.judge(NoteType::Note, 100.0, &rate);
         // PG window should be halved: [-20000, 20000] * 50% = [-10000, 10000]
         assert_eq!(result[0], [-10000, 10000]);
         // GR window should be unchanged: [-60000, 60000]
@@ -839,7 +1114,7 @@ mod tests {
             [0, 1000000],
         ];
         let rate = [100, 100, 100];
-        let result = create_lr2(org, 100, &rate);
+        let result = create_lr2(org, 100.0, &rate);
         // At rank 100, LR2 scaling: base * 100 / 100 = base
         assert_eq!(result[0], [-21000, 21000]);
         assert_eq!(result[1], [-60000, 60000]);
@@ -854,7 +1129,7 @@ mod tests {
         // org with fewer than 4 rows should be returned as-is without panicking
         let short: &[[i64; 2]] = &[[-21000, 21000], [-60000, 60000]];
         let rate = [100, 100, 100];
-        let result = create_lr2(short, 100, &rate);
+        let result = create_lr2(short, 100.0, &rate);
         assert_eq!(result, short.to_vec());
     }
 
@@ -864,7 +1139,7 @@ mod tests {
         let rule = rule_normal();
         let short: &[[i64; 2]] = &[[-20000, 20000], [-60000, 60000], [-150000, 150000]];
         let rate = [100, 100, 100];
-        let result = rule.create_normal(short, 100, &rate);
+        let result = rule.create_normal(short, 100.0, &rate);
         assert_eq!(result, short.to_vec());
     }
 
@@ -878,7 +1153,7 @@ mod tests {
             [0, 1000000],
         ];
         let rate = [100, 100, 100];
-        let result = create_lr2(org, 50, &rate);
+        let result = create_lr2(org, 50.0, &rate);
         // Windows should be narrower at rank 50
         assert!(result[0][1] < 21000);
         assert!(result[1][1] < 60000);
@@ -897,21 +1172,21 @@ mod prop_tests {
         /// For negative base and rank in [1, 99], result is <= 0.
         #[test]
         fn lr2_judge_scaling_sign_preserved(base in -120_000i64..=-1, rank in 1i32..99) {
-            let result = lr2_judge_scaling(base, rank);
+            let result = lr2_judge_scaling(base, rank as f64);
             prop_assert!(result <= 0, "negative base {} at rank {} gave positive result {}", base, rank, result);
         }
 
         /// For positive base and rank in [1, 99], result is >= 0.
         #[test]
         fn lr2_judge_scaling_positive_sign_preserved(base in 1i64..120_000, rank in 1i32..99) {
-            let result = lr2_judge_scaling(base, rank);
+            let result = lr2_judge_scaling(base, rank as f64);
             prop_assert!(result >= 0, "positive base {} at rank {} gave negative result {}", base, rank, result);
         }
 
         /// At rank 100, result equals base exactly.
         #[test]
         fn lr2_judge_scaling_rank_100_identity(base in 1i64..1_000_000) {
-            let result = lr2_judge_scaling(base, 100);
+            let result = lr2_judge_scaling(base, 100.0);
             prop_assert_eq!(result, base, "base {} at rank 100 should return itself, got {}", base, result);
         }
 
@@ -919,8 +1194,8 @@ mod prop_tests {
         /// rank_hi >= rank_lo implies result(rank_hi) >= result(rank_lo).
         #[test]
         fn lr2_judge_scaling_monotone_in_rank(base in 1i64..120_000, rank_lo in 1i32..50, rank_hi in 51i32..99) {
-            let lo = lr2_judge_scaling(base, rank_lo);
-            let hi = lr2_judge_scaling(base, rank_hi);
+            let lo = lr2_judge_scaling(base, rank_lo as f64);
+            let hi = lr2_judge_scaling(base, rank_hi as f64);
             prop_assert!(hi >= lo, "base {} rank_hi {} (result {}) should be >= rank_lo {} (result {})", base, rank_hi, hi, rank_lo, lo);
         }
     }
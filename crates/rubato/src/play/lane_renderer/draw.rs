@@ -75,8 +75,6 @@ impl LaneRenderer {
         let microtime = time * 1000;
         let show_timeline = ctx.is_practice;
 
-        let hispeed = if !ctx.is_practice { self.hispeed } else { 1.0 };
-
         // Get the filtered timelines (indices into all_timelines)
         let timelines = &self.timeline_indices;
         // Safety: the source slice (BMSPlayer.model.timelines) outlives this
@@ -99,7 +97,14 @@ impl LaneRenderer {
             nbpm = tl.bpm;
             nscroll = tl.scroll;
         }
+        // Keep the green number constant across BPM changes
+        if self.hispeed_auto_adjust && !ctx.is_practice && nbpm != self.nowbpm && nbpm > 0.0 {
+            self.reset_hispeed(nbpm);
+        }
         self.nowbpm = nbpm;
+        let timelines = &self.timeline_indices;
+
+        let hispeed = if !ctx.is_practice { self.hispeed } else { 1.0 };
 
         let region = Self::calc_region(nbpm, hispeed, nscroll);
 
@@ -217,6 +222,22 @@ impl LaneRenderer {
             }
         }
 
+        // Replay ghost key beams, under the notes
+        for (lane, _) in ctx
+            .ghost_key_beams
+            .iter()
+            .enumerate()
+            .filter(|&(lane, &held)| held && lane < lanes.len())
+        {
+            commands.push(DrawCommand::DrawGhostBeam {
+                lane,
+                x: lanes[lane].region_x,
+                y: hl,
+                w: lanes[lane].region_width,
+                h: hu - hl,
+            });
+        }
+
         // Draw section lines and markers (first pass)
         let orgy = y;
         let enable_constant = self.enable_constant && !ctx.is_practice;
@@ -930,6 +951,7 @@ impl LaneRenderer {
             enable_constant: self.enable_constant,
             constant_fadein_time: self.constant_fadein_time as i32,
             fixhispeed: self.fixhispeed,
+            hispeedautoadjust: self.hispeed_auto_adjust,
             hispeedmargin: self.hispeedmargin,
             lanecover: self.lanecover,
             enablelanecover: self.enable_lanecover,
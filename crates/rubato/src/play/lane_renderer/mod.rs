@@ -109,6 +109,8 @@ pub struct DrawLaneContext {
     pub show_hiddennote: bool,
     /// Whether to show judge area
     pub show_judgearea: bool,
+    /// Lanes held by the replay ghost (empty when no ghost beams are shown)
+    pub ghost_key_beams: Vec<bool>,
     /// LN type from model
     pub lntype: bms::model::bms_model::LnType,
     /// Judge time regions per lane (5 judge levels, [start, end])
@@ -149,6 +151,7 @@ pub struct LaneRenderer {
     pos: usize,
     currentduration: i32,
     basebpm: f64,
+    startbpm: f64,
     nowbpm: f64,
     mainbpm: f64,
     minbpm: f64,
@@ -169,6 +172,8 @@ pub struct LaneRenderer {
     enable_constant: bool,
     constant_fadein_time: f32,
     fixhispeed: i32,
+    /// Re-fit hi-speed to the duration whenever the BPM changes.
+    hispeed_auto_adjust: bool,
 }
 
 impl LaneRenderer {
@@ -180,6 +185,7 @@ impl LaneRenderer {
             pos: 0,
             currentduration: 0,
             basebpm: 0.0,
+            startbpm: 0.0,
             nowbpm: 0.0,
             mainbpm: 0.0,
             minbpm: 0.0,
@@ -197,6 +203,7 @@ impl LaneRenderer {
             enable_constant: false,
             constant_fadein_time: 0.0,
             fixhispeed: FIX_HISPEED_OFF,
+            hispeed_auto_adjust: false,
         };
         renderer.init(model);
         renderer
@@ -223,6 +230,7 @@ impl LaneRenderer {
         }
         self.timeline_indices = indices;
 
+        self.startbpm = model.bpm;
         self.minbpm = model.min_bpm();
         self.maxbpm = model.max_bpm();
 
@@ -245,9 +253,14 @@ impl LaneRenderer {
             }
         }
 
+        self.apply_fixhispeed();
+    }
+
+    /// Pick the base BPM for the current fixation mode and fit hi-speed to the duration.
+    fn apply_fixhispeed(&mut self) {
         self.basebpm = match self.fixhispeed {
             FIX_HISPEED_OFF => self.basebpm,
-            FIX_HISPEED_STARTBPM => model.bpm,
+            FIX_HISPEED_STARTBPM => self.startbpm,
             FIX_HISPEED_MINBPM => self.minbpm,
             FIX_HISPEED_MAXBPM => self.maxbpm,
             FIX_HISPEED_MAINBPM => self.mainbpm,
@@ -260,6 +273,20 @@ impl LaneRenderer {
         }
     }
 
+    pub fn fixhispeed(&self) -> i32 {
+        self.fixhispeed
+    }
+
+    /// Step to the next hi-speed fixation mode (OFF, START, MAX, MAIN, MIN) during play.
+    pub fn cycle_fixhispeed(&mut self) {
+        self.fixhispeed = (self.fixhispeed + 1).rem_euclid(5);
+        self.apply_fixhispeed();
+    }
+
+    pub fn is_hispeed_auto_adjust(&self) -> bool {
+        self.hispeed_auto_adjust
+    }
+
     pub fn hispeed(&self) -> f32 {
         self.hispeed
     }
@@ -328,6 +355,7 @@ impl LaneRenderer {
         self.enable_constant = pc.enable_constant;
         self.constant_fadein_time = pc.constant_fadein_time as f32;
         self.fixhispeed = pc.fixhispeed;
+        self.hispeed_auto_adjust = pc.hispeedautoadjust;
         self.hispeedmargin = pc.hispeedmargin;
     }
 
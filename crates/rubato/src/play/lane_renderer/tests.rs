@@ -39,6 +39,7 @@ fn default_ctx(all_timelines: &[TimeLine]) -> DrawLaneContext {
         mark_processednote: false,
         show_hiddennote: false,
         show_judgearea: false,
+        ghost_key_beams: Vec::new(),
         lntype: LNTYPE_LONGNOTE,
         judge_time_regions: vec![],
         processing_long_notes: vec![None; 8],
@@ -360,6 +361,31 @@ fn draw_lane_current_duration_with_lanecover() {
     assert_eq!(renderer.current_duration(), 1000);
 }
 
+#[test]
+fn draw_lane_emits_ghost_key_beams_for_held_lanes() {
+    let mut tl0 = make_timeline(0.0, 0, 120.0, 8);
+    tl0.section_line = true;
+    let model = make_model_with_timelines(vec![tl0], 120.0);
+    let mut renderer = LaneRenderer::new(&model);
+
+    let all_tls = &model.timelines;
+    let mut ctx = default_ctx(all_tls);
+    ctx.ghost_key_beams = vec![false, true, false, true];
+    let mut lanes = make_lanes(8);
+    lanes[3].region_x = 90.0;
+    let result = renderer.draw_lane(&ctx, &lanes, &[]);
+
+    let beams: Vec<_> = result
+        .commands
+        .iter()
+        .filter_map(|cmd| match cmd {
+            DrawCommand::DrawGhostBeam { lane, x, h, .. } => Some((*lane, *x, *h)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(beams, vec![(1, 0.0, 500.0), (3, 90.0, 500.0)]);
+}
+
 #[test]
 fn draw_lane_lift_offset() {
     let mut tl0 = make_timeline(0.0, 0, 120.0, 8);
@@ -1611,6 +1637,97 @@ fn fixhispeed_mainbpm_uses_fallback_when_no_notes() {
     );
 }
 
+#[test]
+fn hispeed_auto_adjust_keeps_green_number_across_bpm_change() {
+    let mut tl0 = make_timeline(0.0, 0, 120.0, 8);
+    tl0.section_line = true;
+    let mut tl1 = make_timeline(1.0, 500_000, 240.0, 8);
+    tl1.section_line = true;
+    let model = make_model_with_timelines(vec![tl0, tl1], 120.0);
+
+    let mut renderer = LaneRenderer::new(&model);
+    renderer.apply_play_config(&PlayConfig {
+        fixhispeed: FIX_HISPEED_STARTBPM,
+        duration: 1000,
+        hispeedautoadjust: true,
+        ..PlayConfig::default()
+    });
+    renderer.init(&model);
+
+    let all_tls = &model.timelines;
+    let mut ctx = default_ctx(all_tls);
+    ctx.timer_play = Some(0);
+    let lanes = make_lanes(8);
+
+    ctx.time = 100;
+    renderer.draw_lane(&ctx, &lanes, &[]);
+    assert_eq!(renderer.current_duration(), 1000);
+    let start_hispeed = renderer.hispeed();
+
+    // BPM doubles: hi-speed halves so the green number stays at the target
+    ctx.time = 750;
+    renderer.draw_lane(&ctx, &lanes, &[]);
+    assert_eq!(renderer.current_duration(), 1000);
+    assert!((renderer.hispeed() - start_hispeed / 2.0).abs() < 0.001);
+}
+
+#[test]
+fn hispeed_without_auto_adjust_stays_at_bpm_change() {
+    let mut tl0 = make_timeline(0.0, 0, 120.0, 8);
+    tl0.section_line = true;
+    let mut tl1 = make_timeline(1.0, 500_000, 240.0, 8);
+    tl1.section_line = true;
+    let model = make_model_with_timelines(vec![tl0, tl1], 120.0);
+
+    let mut renderer = LaneRenderer::new(&model);
+    renderer.apply_play_config(&PlayConfig {
+        fixhispeed: FIX_HISPEED_STARTBPM,
+        duration: 1000,
+        ..PlayConfig::default()
+    });
+    renderer.init(&model);
+    let start_hispeed = renderer.hispeed();
+
+    let all_tls = &model.timelines;
+    let mut ctx = default_ctx(all_tls);
+    ctx.timer_play = Some(0);
+    ctx.time = 750;
+    renderer.draw_lane(&ctx, &make_lanes(8), &[]);
+
+    assert_eq!(renderer.hispeed(), start_hispeed);
+    assert_eq!(renderer.current_duration(), 500);
+}
+
+#[test]
+fn cycle_fixhispeed_refits_hispeed_to_new_base_bpm() {
+    let mut tl0 = make_timeline(0.0, 0, 100.0, 8);
+    tl0.set_note(0, Some(Note::new_normal(1)));
+    let mut tl1 = make_timeline(1.0, 500_000, 200.0, 8);
+    tl1.set_note(0, Some(Note::new_normal(1)));
+    let model = make_model_with_timelines(vec![tl0, tl1], 100.0);
+
+    let mut renderer = LaneRenderer::new(&model);
+    renderer.apply_play_config(&PlayConfig {
+        fixhispeed: FIX_HISPEED_STARTBPM,
+        duration: 1000,
+        ..PlayConfig::default()
+    });
+    renderer.init(&model);
+    assert!((renderer.base_bpm() - 100.0).abs() < 0.001);
+
+    renderer.cycle_fixhispeed();
+    assert_eq!(renderer.fixhispeed(), FIX_HISPEED_MAXBPM);
+    assert!((renderer.base_bpm() - 200.0).abs() < 0.001);
+    // 2400 / (200 / 100) / 1000 * (1 - 0.2 default lanecover)
+    assert!((renderer.hispeed() - 0.96).abs() < 0.001);
+    assert_eq!(renderer.play_config().fixhispeed, FIX_HISPEED_MAXBPM);
+
+    renderer.cycle_fixhispeed();
+    renderer.cycle_fixhispeed();
+    renderer.cycle_fixhispeed();
+    assert_eq!(renderer.fixhispeed(), FIX_HISPEED_OFF);
+}
+
 // =========================================================================
 // Regression: LN body height flicker at judge-line boundary
 // =========================================================================
@@ -4,8 +4,10 @@
 pub mod bga;
 pub mod bms_player;
 pub mod bms_player_rule;
+pub mod crash_recovery;
 pub mod gauge_property;
 pub mod ghost_battle_play;
+pub mod ghost_key_beams;
 pub mod groove_gauge;
 pub mod input;
 pub mod judge;
@@ -14,6 +16,7 @@ pub mod lane_renderer;
 pub mod play_skin;
 pub mod pomyu_chara_processor;
 pub mod practice_configuration;
+pub mod replay_verifier;
 pub mod rhythm_timer_processor;
 pub mod skin;
 pub mod target_property;
@@ -15,7 +15,7 @@ use bms::model::mode::Mode;
 use serde::{Deserialize, Serialize};
 
 /// Practice mode settings
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct PracticeProperty {
     /// Play start time
     pub starttime: i32,
@@ -42,6 +42,15 @@ pub struct PracticeProperty {
     pub total: f64,
     /// Graph type
     pub graphtype: i32,
+    /// Restart the range automatically when it ends
+    #[serde(default)]
+    pub loopsection: bool,
+    /// Keep the gauge at its start value
+    #[serde(default)]
+    pub freezegauge: bool,
+    /// Draw invisible notes
+    #[serde(default)]
+    pub showinvisible: bool,
 }
 
 impl PracticeProperty {
@@ -59,6 +68,9 @@ impl PracticeProperty {
             freq: 100,
             total: 0.0,
             graphtype: 0,
+            loopsection: false,
+            freezegauge: false,
+            showinvisible: false,
         }
     }
 
@@ -157,6 +169,23 @@ impl PracticeConfiguration {
         }
     }
 
+    /// Move the range start to `time` (ms, unscaled chart time), pushing the
+    /// end back so the range stays at least 1s long.
+    pub fn set_start_marker(&mut self, time: i64) {
+        self.property.starttime = time.clamp(0, i32::MAX as i64) as i32;
+        self.property.endtime = self
+            .property
+            .endtime
+            .max(self.property.starttime.saturating_add(1000));
+    }
+
+    /// Move the range end to `time` (ms, unscaled chart time), keeping it at
+    /// least 1s after the start.
+    pub fn set_end_marker(&mut self, time: i64) {
+        let min_end = self.property.starttime as i64 + 1000;
+        self.property.endtime = time.clamp(min_end, i32::MAX as i64) as i32;
+    }
+
     /// Save practice property to practice/<sha256>.json.
     /// Translates: PracticeConfiguration.saveProperty()
     pub fn save_property(&self) {
@@ -200,5 +229,6 @@ impl PracticeConfiguration {
 
     pub fn dispose(&mut self) {
         // cleanup rendering resources (stub - no GPU resources in Rust translation)
+        crate::modmenu::practice_menu::PracticeMenu::publish(None);
     }
 }
@@ -69,7 +69,12 @@ fn process_input_up_wraps_cursor() {
     practice.create(&model);
     assert_eq!(practice.cursor_pos(), 0);
 
-    // UP from 0 should go to element 9 (skipping invisible 10, 11 in SP)
+    // UP from 0 should go to the last element, 14
+    practice.process_input(true, false, false, false, 1000);
+    assert_eq!(practice.cursor_pos(), 14);
+    // and skip the invisible 10, 11 in SP further up
+    practice.process_input(true, false, false, false, 1000);
+    practice.process_input(true, false, false, false, 1000);
     practice.process_input(true, false, false, false, 1000);
     assert_eq!(practice.cursor_pos(), 9);
 }
@@ -128,14 +133,14 @@ fn draw_emits_element_text_commands() {
 
     let commands = practice.draw(region, &judge_counts, false);
 
-    // Should have element text commands for visible elements (indices 0..9 in SP mode)
+    // Should have element text commands for visible elements (0..9 and 12..14 in SP mode)
     let text_cmds: Vec<_> = commands
         .iter()
         .filter(|c| matches!(c, PracticeDrawCommand::DrawText { .. }))
         .collect();
-    // 10 elements visible in SP (0..9) + 6 judge count lines = 16 text commands
+    // 13 elements visible in SP + 6 judge count lines = 19 text commands
     // (no "PRESS 1KEY" because media_loaded is false)
-    assert_eq!(text_cmds.len(), 16);
+    assert_eq!(text_cmds.len(), 19);
 }
 
 #[test]
@@ -401,3 +406,43 @@ fn test_create_restores_total_from_saved_config_with_zero_total() {
     // Cleanup
     std::fs::remove_file(&path).ok();
 }
+
+#[test]
+fn test_markers_keep_range_at_least_one_second() {
+    let mut practice = PracticeConfiguration::new();
+    practice.set_end_marker(20_000);
+    practice.set_start_marker(19_500);
+    assert_eq!(practice.practice_property().starttime, 19_500);
+    assert_eq!(practice.practice_property().endtime, 20_500);
+
+    practice.set_end_marker(5_000);
+    assert_eq!(practice.practice_property().endtime, 20_500);
+
+    practice.set_start_marker(-300);
+    assert_eq!(practice.practice_property().starttime, 0);
+}
+
+#[test]
+fn toggle_elements_follow_direction() {
+    let mut practice = PracticeConfiguration::new();
+    practice.process_input_action(12, true);
+    practice.process_input_action(12, true);
+    assert!(practice.property.loopsection);
+    practice.process_input_action(12, false);
+    assert!(!practice.property.loopsection);
+    practice.process_input_action(13, true);
+    practice.process_input_action(14, true);
+    assert!(practice.property.freezegauge);
+    assert!(practice.property.showinvisible);
+    assert_eq!(practice.element_text(14), "INVISIBLE NOTE : ON");
+}
+
+#[test]
+fn saved_property_without_toggles_still_loads() {
+    let json = r#"{"starttime":1000,"endtime":9000,"gaugetype":2,"startgauge":20,
+        "random":0,"random2":0,"doubleop":0,"judgerank":100,"freq":100,"total":300.0,"graphtype":0}"#;
+    let property: PracticeProperty = serde_json::from_str(json).unwrap();
+    assert_eq!(property.starttime, 1000);
+    assert!(!property.loopsection);
+    assert!(!property.freezegauge);
+}
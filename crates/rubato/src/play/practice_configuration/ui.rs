@@ -52,6 +52,9 @@ impl PracticeConfiguration {
                     .get(self.property.doubleop as usize)
                     .unwrap_or(&"?")
             ),
+            12 => format!("LOOP : {}", on_off(self.property.loopsection)),
+            13 => format!("GAUGE FREEZE : {}", on_off(self.property.freezegauge)),
+            14 => format!("INVISIBLE NOTE : {}", on_off(self.property.showinvisible)),
             _ => String::new(),
         }
     }
@@ -99,7 +102,7 @@ impl PracticeConfiguration {
             commands.push(PracticeDrawCommand::DrawText {
                 text: "PRESS 1KEY TO PLAY".to_string(),
                 x,
-                y: y - 276.0,
+                y: y - 22.0 * Self::ELEMENT_COUNT as f32 - 12.0,
                 color: PracticeColor::Orange,
             });
         }
@@ -259,12 +262,25 @@ impl PracticeConfiguration {
                 // OPTIONDP
                 self.property.doubleop = (self.property.doubleop + 1) % 2;
             }
+            // Toggles: RIGHT turns on, LEFT turns off (safe under key repeat)
+            12 => {
+                // LOOP
+                self.property.loopsection = inc;
+            }
+            13 => {
+                // GAUGE FREEZE
+                self.property.freezegauge = inc;
+            }
+            14 => {
+                // INVISIBLE NOTE
+                self.property.showinvisible = inc;
+            }
             _ => {}
         }
     }
 
     /// Number of practice configuration elements (indices 0..ELEMENT_COUNT).
-    pub(super) const ELEMENT_COUNT: usize = 12;
+    pub(super) const ELEMENT_COUNT: usize = 15;
 
     /// Process input for practice mode navigation.
     ///
@@ -346,3 +362,7 @@ impl PracticeConfiguration {
         }
     }
 }
+
+fn on_off(value: bool) -> &'static str {
+    if value { "ON" } else { "OFF" }
+}
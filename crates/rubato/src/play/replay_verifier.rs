@@ -0,0 +1,216 @@
+//! Headless replay verification.
+//!
+//! Re-runs a replay's key input log through the judge and compares the
+//! recomputed score with the stored one, so tampered or stale replays (e.g.
+//! recorded against an older version of the chart) can be flagged.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use bms::model::bms_model::BMSModel;
+
+use crate::core::bms_player_mode::BMSPlayerMode;
+use crate::core::play_data_accessor::PlayDataAccessor;
+use crate::core::player_resource::PlayerResource;
+use crate::core::replay_data::ReplayData;
+use crate::core::score_data::ScoreData;
+use crate::play::bms_player::BMSPlayer;
+use crate::skin::config::Config;
+use crate::skin::player_config::{GAUGEAUTOSHIFT_NONE, PlayerConfig};
+
+/// A score field whose stored value differs from the recomputed one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScoreMismatch {
+    pub field: &'static str,
+    pub stored: i32,
+    pub recomputed: i32,
+}
+
+/// Outcome of re-judging a replay.
+#[derive(Clone, Debug)]
+pub struct ReplayVerification {
+    /// Score rebuilt from the key input log.
+    pub recomputed: ScoreData,
+    /// Fields that differ from the stored score. Empty when the replay is consistent.
+    pub mismatches: Vec<ScoreMismatch>,
+}
+
+impl ReplayVerification {
+    pub fn is_valid(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// Re-judge `replay` against `model` and compare the result with `stored`.
+///
+/// `model` must be freshly decoded (no pattern applied); the replay's own
+/// options and seeds are applied here. `margin_time` is the start margin
+/// returned by `PlayerResource::load_bms_model`. The clear lamp is only
+/// compared when gauge auto shift is off, since the shift is decided per
+/// frame during live play and is not replayed.
+pub fn verify_replay(
+    model: BMSModel,
+    margin_time: i64,
+    player_config: &PlayerConfig,
+    replay: ReplayData,
+    stored: &ScoreData,
+) -> Result<ReplayVerification> {
+    if replay.keylog.is_empty() {
+        bail!("replay has no key input log");
+    }
+    let mut player = BMSPlayer::new(model);
+    player.set_player_config(player_config.clone());
+    player.set_margin_time(margin_time);
+    player.set_play_mode(BMSPlayerMode::REPLAY_1);
+    player.set_active_replay(Some(replay));
+    player.prepare_pattern_pipeline();
+    let recomputed = player
+        .recompute_replay_score()
+        .context("replay does not hit any note")?;
+
+    let mut mismatches = Vec::new();
+    let mut compare = |field: &'static str, stored: i32, recomputed: i32| {
+        if stored != recomputed {
+            mismatches.push(ScoreMismatch {
+                field,
+                stored,
+                recomputed,
+            });
+        }
+    };
+    let (s, r) = (&stored.judge_counts, &recomputed.judge_counts);
+    compare("epg", s.epg, r.epg);
+    compare("lpg", s.lpg, r.lpg);
+    compare("egr", s.egr, r.egr);
+    compare("lgr", s.lgr, r.lgr);
+    compare("egd", s.egd, r.egd);
+    compare("lgd", s.lgd, r.lgd);
+    compare("ebd", s.ebd, r.ebd);
+    compare("lbd", s.lbd, r.lbd);
+    compare("epr", s.epr, r.epr);
+    compare("lpr", s.lpr, r.lpr);
+    compare("ems", s.ems, r.ems);
+    compare("lms", s.lms, r.lms);
+    compare("maxcombo", stored.maxcombo, recomputed.maxcombo);
+    compare("minbp", stored.minbp, recomputed.minbp);
+    if player_config.play_settings.gauge_auto_shift == GAUGEAUTOSHIFT_NONE {
+        compare("clear", stored.clear, recomputed.clear);
+    }
+
+    Ok(ReplayVerification {
+        recomputed,
+        mismatches,
+    })
+}
+
+/// Load the chart at `bms_path`, replay slot `index` and the best score, then verify.
+///
+/// The best score in the database only corresponds to a replay saved from the
+/// same play, so a mismatch here also flags replays that went stale after a
+/// better score was recorded.
+pub fn verify_replay_file(
+    config: &Config,
+    player_config: &PlayerConfig,
+    bms_path: &Path,
+    index: i32,
+) -> Result<ReplayVerification> {
+    let lnmode = player_config.play_settings.lnmode;
+    let (model, margin_time) = PlayerResource::load_bms_model(bms_path, lnmode, None)
+        .with_context(|| format!("failed to load chart {}", bms_path.display()))?;
+    let accessor = PlayDataAccessor::new(config);
+    let has_ln = model.contains_undefined_long_note();
+    let replay = accessor
+        .read_replay_data(&model.sha256, has_ln, lnmode, index)
+        .with_context(|| format!("no replay in slot {}", index + 1))?;
+    let stored = accessor
+        .read_score_data_by_hash(&model.sha256, has_ln, lnmode)
+        .context("no stored score for this chart")?;
+    verify_replay(model, margin_time, player_config, replay, &stored)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bms::model::note::Note;
+    use bms::model::time_line::TimeLine;
+
+    fn make_model() -> BMSModel {
+        let mut model = BMSModel::new();
+        model.set_mode(bms::model::mode::Mode::BEAT_7K);
+        model.judgerank = 100;
+        let mut timelines = Vec::new();
+        for (i, &time) in [1_000_000i64, 2_000_000].iter().enumerate() {
+            let mut tl = TimeLine::new(i as f64, time, 8);
+            tl.set_note(0, Some(Note::new_normal(1)));
+            timelines.push(tl);
+        }
+        model.timelines = timelines;
+        model
+    }
+
+    fn make_replay(presses: &[i64]) -> ReplayData {
+        let mut replay = ReplayData::new();
+        replay.keylog = presses
+            .iter()
+            .flat_map(|&time| [(time, true), (time + 50_000, false)])
+            .map(|(time, pressed)| crate::skin::KeyInputLog {
+                time,
+                keycode: 0,
+                pressed,
+            })
+            .collect();
+        replay
+    }
+
+    #[test]
+    fn verify_replay_accepts_matching_score() {
+        let replay = make_replay(&[1_000_000, 2_000_000]);
+        let mut stored = ScoreData {
+            clear: crate::skin::clear_type::ClearType::Max.id(),
+            maxcombo: 2,
+            minbp: 0,
+            ..Default::default()
+        };
+        stored.judge_counts.epg = 2;
+
+        let result =
+            verify_replay(make_model(), 0, &PlayerConfig::default(), replay, &stored).unwrap();
+
+        assert!(result.is_valid(), "{:?}", result.mismatches);
+        assert_eq!(result.recomputed.judge_counts.epg, 2);
+    }
+
+    #[test]
+    fn verify_replay_flags_tampered_score() {
+        let replay = make_replay(&[1_000_000]);
+        let mut stored = ScoreData {
+            maxcombo: 2,
+            minbp: 0,
+            ..Default::default()
+        };
+        stored.judge_counts.epg = 2;
+
+        let result =
+            verify_replay(make_model(), 0, &PlayerConfig::default(), replay, &stored).unwrap();
+
+        assert!(!result.is_valid());
+        assert!(result.mismatches.contains(&ScoreMismatch {
+            field: "epg",
+            stored: 2,
+            recomputed: 1,
+        }));
+        assert!(result.mismatches.iter().any(|m| m.field == "minbp"));
+    }
+
+    #[test]
+    fn verify_replay_rejects_empty_keylog() {
+        let result = verify_replay(
+            make_model(),
+            0,
+            &PlayerConfig::default(),
+            ReplayData::new(),
+            &ScoreData::default(),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -11,84 +11,109 @@ pub struct RhythmUpdateParams {
     pub play_timer_micro: i64,
 }
 
-/// Rhythm timer processor for section timing and quarter note tracking
+/// Rhythm timer processor for beat timing and quarter note tracking.
+///
+/// TIMER_RHYTHM restarts on every beat and elapses 1000 ms per beat in between,
+/// so beat-synced skin animations follow BPM changes. It holds still during stops.
 pub struct RhythmTimerProcessor {
-    sectiontimes: Vec<i64>,
-    sections: usize,
+    /// Play time (us) of each beat, including the time spent in stops
+    beat_times: Vec<i64>,
+    beats: usize,
+    /// Play time (us) ranges of stops
+    stops: Vec<(i64, i64)>,
     rhythmtimer: i64,
-    /// Quarter note timing for PMS rhythm-based note expansion
-    quarter_note_times: Vec<i64>,
+    /// Whether quarter notes drive PMS rhythm-based note expansion
+    use_quarter_note_time: bool,
     quarter_note: usize,
     now_quarter_note_time: i64,
 }
 
 impl RhythmTimerProcessor {
     pub fn new(model: &BMSModel, use_quarter_note_time: bool) -> Self {
-        let mut sectiontimes: Vec<i64> = Vec::new();
-        let mut quarter_note_times: Vec<i64> = Vec::new();
+        let mut beat_times: Vec<i64> = Vec::new();
         let timelines = &model.timelines;
 
         for i in 0..timelines.len() {
             if timelines[i].section_line {
-                sectiontimes.push(timelines[i].micro_time());
-
-                if use_quarter_note_time {
-                    quarter_note_times.push(timelines[i].micro_time());
-                    let section_line_section = timelines[i].section();
-                    let mut next_section_line_section =
-                        timelines[i].section() - section_line_section;
-                    let mut last = false;
-                    for j in (i + 1)..timelines.len() {
-                        if timelines[j].section_line {
-                            next_section_line_section =
-                                timelines[j].section() - section_line_section;
-                            break;
-                        } else if j == timelines.len() - 1 {
-                            next_section_line_section =
-                                timelines[j].section() - section_line_section;
-                            last = true;
-                        }
+                beat_times.push(timelines[i].micro_time());
+
+                let section_line_section = timelines[i].section();
+                let mut next_section_line_section = timelines[i].section() - section_line_section;
+                let mut last = false;
+                for j in (i + 1)..timelines.len() {
+                    if timelines[j].section_line {
+                        next_section_line_section = timelines[j].section() - section_line_section;
+                        break;
+                    } else if j == timelines.len() - 1 {
+                        next_section_line_section = timelines[j].section() - section_line_section;
+                        last = true;
                     }
-                    let mut j = 0.25f64;
-                    while j <= next_section_line_section {
-                        if last || j != next_section_line_section {
-                            let mut prev_index = i;
-                            while prev_index < timelines.len()
-                                && timelines[prev_index].section() - section_line_section < j
-                            {
-                                prev_index += 1;
-                            }
-                            // Clamp to valid range if we overshot
-                            if prev_index >= timelines.len() {
-                                prev_index = timelines.len() - 1;
-                            }
-                            prev_index = prev_index.saturating_sub(1);
-                            let bpm = timelines[prev_index].bpm;
-                            let bpm_safe = if bpm == 0.0 { 1.0 } else { bpm };
-                            let time = timelines[prev_index].micro_time()
-                                + timelines[prev_index].micro_stop()
-                                + ((j + section_line_section - timelines[prev_index].section())
-                                    * 240000000.0
-                                    / bpm_safe) as i64;
-                            quarter_note_times.push(time);
+                }
+                let mut j = 0.25f64;
+                while j <= next_section_line_section {
+                    if last || j != next_section_line_section {
+                        let mut prev_index = i;
+                        while prev_index < timelines.len()
+                            && timelines[prev_index].section() - section_line_section < j
+                        {
+                            prev_index += 1;
+                        }
+                        // Clamp to valid range if we overshot
+                        if prev_index >= timelines.len() {
+                            prev_index = timelines.len() - 1;
                         }
-                        j += 0.25;
+                        prev_index = prev_index.saturating_sub(1);
+                        let bpm = timelines[prev_index].bpm;
+                        let bpm_safe = if bpm == 0.0 { 1.0 } else { bpm };
+                        let time = timelines[prev_index].micro_time()
+                            + timelines[prev_index].micro_stop()
+                            + ((j + section_line_section - timelines[prev_index].section())
+                                * 240000000.0
+                                / bpm_safe) as i64;
+                        beat_times.push(time);
                     }
+                    j += 0.25;
                 }
             }
         }
 
+        let stops = timelines
+            .iter()
+            .filter(|tl| tl.micro_stop() > 0)
+            .map(|tl| (tl.micro_time(), tl.micro_time() + tl.micro_stop()))
+            .collect();
+
         RhythmTimerProcessor {
-            sectiontimes,
-            sections: 0,
+            beat_times,
+            beats: 0,
+            stops,
             rhythmtimer: 0,
-            quarter_note_times,
+            use_quarter_note_time,
             quarter_note: 0,
             now_quarter_note_time: 0,
         }
     }
 
-    pub fn update(&mut self, params: &RhythmUpdateParams) -> (i64, bool) {
+    /// Restart from play time `play_timer_micro` with TIMER_RHYTHM at
+    /// `rhythmtimer`, skipping the beats already passed. Used when play starts
+    /// and when a replay seeks.
+    pub fn restart(&mut self, rhythmtimer: i64, play_timer_micro: i64, freq: i32) {
+        self.rhythmtimer = rhythmtimer;
+        let passed = self
+            .beat_times
+            .iter()
+            .take_while(|&&time| Self::reached(time, freq, play_timer_micro))
+            .count();
+        self.beats = passed;
+        self.quarter_note = passed;
+    }
+
+    fn reached(time: i64, freq: i32, play_timer_micro: i64) -> bool {
+        freq > 0 && time * (100 / freq as i64) <= play_timer_micro
+    }
+
+    /// Advance by one frame and return the TIMER_RHYTHM start time (us).
+    pub fn update(&mut self, params: &RhythmUpdateParams) -> i64 {
         let now = params.now;
         let micronow = params.micronow;
         let deltatime = params.deltatime;
@@ -96,27 +121,30 @@ impl RhythmTimerProcessor {
         let play_speed = params.play_speed;
         let freq = params.freq;
         let play_timer_micro = params.play_timer_micro;
-        let bpm_factor =
-            (nowbpm * play_speed as f64 / 60.0).clamp(i32::MIN as f64, i32::MAX as f64) as i64;
+        let stopped = self.stops.iter().any(|&(start, end)| {
+            Self::reached(start, freq, play_timer_micro)
+                && !Self::reached(end, freq, play_timer_micro)
+        });
+        let bpm_factor = if stopped {
+            0
+        } else {
+            (nowbpm * play_speed as f64 / 60.0).clamp(i32::MIN as f64, i32::MAX as f64) as i64
+        };
         self.rhythmtimer += deltatime.saturating_mul(100 - bpm_factor) / 100;
 
-        let mut rhythm_on = false;
-        if freq > 0
-            && self.sections < self.sectiontimes.len()
-            && (self.sectiontimes[self.sections] * (100 / freq as i64)) <= play_timer_micro
+        while self.beats < self.beat_times.len()
+            && Self::reached(self.beat_times[self.beats], freq, play_timer_micro)
         {
-            self.sections += 1;
-            rhythm_on = true;
+            self.beats += 1;
             self.rhythmtimer = micronow;
         }
-        if freq > 0 && !self.quarter_note_times.is_empty() {
-            if self.quarter_note < self.quarter_note_times.len()
-                && (self.quarter_note_times[self.quarter_note] * (100 / freq as i64))
-                    <= play_timer_micro
+        if self.use_quarter_note_time && !self.beat_times.is_empty() {
+            if self.quarter_note < self.beat_times.len()
+                && Self::reached(self.beat_times[self.quarter_note], freq, play_timer_micro)
             {
                 self.quarter_note += 1;
                 self.now_quarter_note_time = now;
-            } else if self.quarter_note == self.quarter_note_times.len()
+            } else if self.quarter_note == self.beat_times.len()
                 && freq > 0
                 && nowbpm > 0.0
                 && ((self.now_quarter_note_time + (60000.0 / nowbpm) as i64) * (100 / freq as i64))
@@ -125,7 +153,7 @@ impl RhythmTimerProcessor {
                 self.now_quarter_note_time = now;
             }
         }
-        (self.rhythmtimer, rhythm_on)
+        self.rhythmtimer
     }
 
     pub fn now_quarter_note_time(&self) -> i64 {
@@ -212,4 +240,61 @@ mod tests {
             "quarter-note construction should not panic on out-of-bounds"
         );
     }
+
+    fn section_line(section: f64, micro_time: i64, bpm: f64) -> bms::model::time_line::TimeLine {
+        let mut tl = bms::model::time_line::TimeLine::new(section, micro_time, 1);
+        tl.section_line = true;
+        tl.bpm = bpm;
+        tl
+    }
+
+    fn params(micronow: i64, deltatime: i64, nowbpm: f64) -> RhythmUpdateParams {
+        RhythmUpdateParams {
+            now: micronow / 1000,
+            micronow,
+            deltatime,
+            nowbpm,
+            play_speed: 100,
+            freq: 100,
+            play_timer_micro: micronow,
+        }
+    }
+
+    #[test]
+    fn rhythm_timer_restarts_on_each_beat() {
+        // One 4/4 measure at 120 BPM: beats every 500 ms.
+        let mut model = BMSModel::default();
+        model.timelines = vec![
+            section_line(0.0, 0, 120.0),
+            section_line(1.0, 2_000_000, 120.0),
+        ];
+        let mut processor = RhythmTimerProcessor::new(&model, false);
+        processor.restart(0, 0, 100);
+
+        // Half way into the first beat the timer runs at twice real time.
+        let timer = processor.update(&params(250_000, 250_000, 120.0));
+        assert_eq!(250_000 - timer, 500_000);
+
+        // The second beat restarts it.
+        assert_eq!(processor.update(&params(500_000, 250_000, 120.0)), 500_000);
+        let timer = processor.update(&params(1_000_000, 500_000, 120.0));
+        assert_eq!(timer, 1_000_000, "third beat restarts the timer again");
+    }
+
+    #[test]
+    fn rhythm_timer_holds_still_during_stops() {
+        let mut model = BMSModel::default();
+        let mut stop = section_line(0.0, 0, 60.0);
+        stop.stop = 1_000_000;
+        model.timelines = vec![stop, section_line(1.0, 5_000_000, 60.0)];
+        let mut processor = RhythmTimerProcessor::new(&model, false);
+        processor.restart(0, 0, 100);
+
+        let before = processor.update(&params(100_000, 100_000, 60.0));
+        let during = processor.update(&params(600_000, 500_000, 60.0));
+        assert_eq!(600_000 - during, 100_000 - before);
+        // Past the stop it runs with the tempo again.
+        let after = processor.update(&params(1_500_000, 900_000, 60.0));
+        assert_eq!(1_500_000 - after, 900_000);
+    }
 }
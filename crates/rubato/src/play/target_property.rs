@@ -1,4 +1,5 @@
 use crate::core::score_data::ScoreData;
+use crate::core::score_data_log_database_accessor::PlayLog;
 use crate::play::MainController;
 
 /// Score target
@@ -7,6 +8,8 @@ pub enum TargetProperty {
     Rival(RivalTargetProperty),
     InternetRanking(InternetRankingTargetProperty),
     NextRank(NextRankTargetProperty),
+    MyBest(MyBestTargetProperty),
+    History(HistoryTargetProperty),
 }
 
 impl TargetProperty {
@@ -16,6 +19,8 @@ impl TargetProperty {
             TargetProperty::Rival(p) => &p.id,
             TargetProperty::InternetRanking(p) => &p.id,
             TargetProperty::NextRank(p) => &p.id,
+            TargetProperty::MyBest(p) => &p.id,
+            TargetProperty::History(p) => &p.id,
         }
     }
 
@@ -40,6 +45,12 @@ impl TargetProperty {
         if id == "RANK_NEXT" {
             return Some(TargetProperty::NextRank(NextRankTargetProperty::new()));
         }
+        if id == "MYBEST" {
+            return Some(TargetProperty::MyBest(MyBestTargetProperty::new()));
+        }
+        if let Some(target) = HistoryTargetProperty::from_id(id) {
+            return Some(target);
+        }
         // fallback to MAX
         StaticTargetProperty::from_id("MAX")
     }
@@ -73,8 +84,11 @@ impl TargetProperty {
                 IRTarget::Next => format!("IR NEXT {}RANK", p.value),
                 IRTarget::Rank => format!("IR RANK {}", p.value),
                 IRTarget::RankRate => format!("IR RANK TOP {}%", p.value),
+                IRTarget::Average => format!("IR TOP {} AVERAGE", p.value),
             },
             TargetProperty::NextRank(_) => "NEXT RANK".to_string(),
+            TargetProperty::MyBest(_) => "MY BEST".to_string(),
+            TargetProperty::History(p) => p.name(),
         }
     }
 
@@ -85,6 +99,8 @@ impl TargetProperty {
             TargetProperty::Rival(p) => p.target(main),
             TargetProperty::InternetRanking(p) => p.target(main),
             TargetProperty::NextRank(p) => p.target(main),
+            TargetProperty::MyBest(p) => p.target(main),
+            TargetProperty::History(p) => p.target(main),
         }
     }
 }
@@ -395,6 +411,8 @@ pub enum IRTarget {
     Next,
     Rank,
     RankRate,
+    /// Average EX score of the top `value` players
+    Average,
 }
 
 pub struct InternetRankingTargetProperty {
@@ -472,47 +490,7 @@ impl InternetRankingTargetProperty {
             ranking.load_song(&*connection, &chart, local_score.as_ref());
 
             if ranking.state() == crate::ir::ranking_data::FINISH {
-                let mut score = ScoreData::default();
-                if ranking.total_player() > 0 {
-                    let total = ranking.total_player();
-                    let target_index = match target {
-                        IRTarget::Next => {
-                            // Find the rank of the first score <= nowscore,
-                            // then go 'value' ranks above (same logic as sync path).
-                            let mut idx = 0;
-                            for i in 0..total {
-                                if let Some(ir_score) = ranking.score(i)
-                                    && ir_score.exscore() <= nowscore
-                                {
-                                    idx = (i - value).max(0);
-                                    break;
-                                }
-                            }
-                            idx
-                        }
-                        IRTarget::Rank => (value.min(total) - 1).max(0),
-                        // i32 safe: total (note count, max ~10k) * value (max 100) / 100 << i32::MAX.
-                        IRTarget::RankRate => ((total as i64 * value as i64 / 100) as i32)
-                            .min(total - 1)
-                            .max(0),
-                    };
-                    if let Some(ir_score) = ranking.score(target_index) {
-                        let exscore = ir_score.exscore();
-                        score.player = if ir_score.player.is_empty() {
-                            "YOU".to_string()
-                        } else {
-                            ir_score.player.clone()
-                        };
-                        score.judge_counts.epg = exscore / 2;
-                        score.judge_counts.egr = exscore % 2;
-                        score.play_option.option = ir_score.option;
-                    } else {
-                        score.player = "NO DATA".to_string();
-                    }
-                } else {
-                    score.player = "NO DATA".to_string();
-                }
-                let _ = tx.send(score);
+                let _ = tx.send(ranking_target_score(&ranking, target, value, nowscore));
             }
         });
         self.fetch_handle = Some(handle);
@@ -570,26 +548,17 @@ impl InternetRankingTargetProperty {
 
         match ranking_data {
             Some(ref ranking) if ranking.state() == crate::ir::ranking_data::FINISH => {
-                if ranking.total_player() > 0 {
-                    let index = self.target_rank(main, ranking);
-                    if let Some(ir_score) = ranking.score(index) {
-                        let exscore = ir_score.exscore();
-                        self.target_score.player = if ir_score.player.is_empty() {
-                            "YOU".to_string()
-                        } else {
-                            ir_score.player.clone()
-                        };
-                        self.target_score.judge_counts.epg = exscore / 2;
-                        self.target_score.judge_counts.egr = exscore % 2;
-                        self.target_score.play_option.option = ir_score.option;
-                    } else {
-                        self.target_score.player = "NO DATA".to_string();
-                        self.target_score.play_option.option = 0;
-                    }
-                } else {
-                    self.target_score.player = "NO DATA".to_string();
-                    self.target_score.play_option.option = 0;
-                }
+                // Get the player's current exscore
+                let nowscore = main
+                    .player_resource()
+                    .and_then(|r| r.score_data())
+                    .map(|s| s.exscore())
+                    .unwrap_or(0);
+                let score = ranking_target_score(ranking, self.target, self.value, nowscore);
+                self.target_score.player = score.player;
+                self.target_score.judge_counts.epg = score.judge_counts.epg;
+                self.target_score.judge_counts.egr = score.judge_counts.egr;
+                self.target_score.play_option.option = score.play_option.option;
             }
             _ => {
                 // Not yet loaded or no ranking data available.
@@ -615,49 +584,6 @@ impl InternetRankingTargetProperty {
         self.target_score.clone()
     }
 
-    /// Get the target rank index based on the IR target type.
-    fn target_rank(
-        &self,
-        main: &MainController,
-        ranking: &crate::ir::ranking_data::RankingData,
-    ) -> i32 {
-        let total = ranking.total_player();
-        // Get the player's current exscore
-        let nowscore = main
-            .player_resource()
-            .and_then(|r| r.score_data())
-            .map(|s| s.exscore())
-            .unwrap_or(0);
-
-        match self.target {
-            IRTarget::Next => {
-                // Find the rank of the first score <= nowscore, then go 'value' ranks above.
-                // Default to top rank when local score is below every IR entry (Java parity).
-                let mut target_index = 0;
-                for i in 0..total {
-                    if let Some(score) = ranking.score(i)
-                        && score.exscore() <= nowscore
-                    {
-                        target_index = (i - self.value).max(0);
-                        break;
-                    }
-                }
-                target_index
-            }
-            IRTarget::Rank => {
-                // value-th place (1-indexed, capped to totalPlayer)
-                (self.value.min(total) - 1).max(0)
-            }
-            IRTarget::RankRate => {
-                // top value% rank index (matches Java: totalPlayer * value / 100)
-                // Clamp to valid 0-indexed range to prevent OOB when value==100
-                ((total as i64 * self.value as i64 / 100) as i32)
-                    .min(total - 1)
-                    .max(0)
-            }
-        }
-    }
-
     pub fn from_id(id: &str) -> Option<TargetProperty> {
         if let Some(suffix) = id.strip_prefix("IR_NEXT_")
             && let Ok(index) = suffix.parse::<i32>()
@@ -684,10 +610,86 @@ impl InternetRankingTargetProperty {
                 InternetRankingTargetProperty::new(IRTarget::RankRate, index),
             ));
         }
+        if let Some(suffix) = id.strip_prefix("IR_AVERAGE_")
+            && let Ok(count) = suffix.parse::<i32>()
+            && count > 0
+        {
+            return Some(TargetProperty::InternetRanking(
+                InternetRankingTargetProperty::new(IRTarget::Average, count),
+            ));
+        }
         None
     }
 }
 
+/// Target score `target`/`value` picks from a loaded IR ranking, where
+/// `nowscore` is the player's EX score.
+pub(crate) fn ranking_target_score(
+    ranking: &crate::ir::ranking_data::RankingData,
+    target: IRTarget,
+    value: i32,
+    nowscore: i32,
+) -> ScoreData {
+    let mut score = ScoreData::default();
+    let total = ranking.total_player();
+    if total <= 0 {
+        score.player = "NO DATA".to_string();
+        return score;
+    }
+    let index = match target {
+        IRTarget::Next => {
+            // Find the rank of the first score <= nowscore, then go 'value' ranks above.
+            // Default to top rank when local score is below every IR entry (Java parity).
+            let mut target_index = 0;
+            for i in 0..total {
+                if let Some(ir_score) = ranking.score(i)
+                    && ir_score.exscore() <= nowscore
+                {
+                    target_index = (i - value).max(0);
+                    break;
+                }
+            }
+            target_index
+        }
+        // value-th place (1-indexed, capped to totalPlayer)
+        IRTarget::Rank => (value.min(total) - 1).max(0),
+        // top value% rank index (matches Java: totalPlayer * value / 100)
+        // Clamp to valid 0-indexed range to prevent OOB when value==100
+        IRTarget::RankRate => ((total as i64 * value as i64 / 100) as i32)
+            .min(total - 1)
+            .max(0),
+        IRTarget::Average => {
+            let exscores: Vec<i64> = (0..value.min(total))
+                .filter_map(|i| ranking.score(i))
+                .map(|s| s.exscore() as i64)
+                .collect();
+            if exscores.is_empty() {
+                score.player = "NO DATA".to_string();
+            } else {
+                let exscore = (exscores.iter().sum::<i64>() / exscores.len() as i64) as i32;
+                score.player = format!("IR TOP {} AVERAGE", exscores.len());
+                score.judge_counts.epg = exscore / 2;
+                score.judge_counts.egr = exscore % 2;
+            }
+            return score;
+        }
+    };
+    if let Some(ir_score) = ranking.score(index) {
+        let exscore = ir_score.exscore();
+        score.player = if ir_score.player.is_empty() {
+            "YOU".to_string()
+        } else {
+            ir_score.player.clone()
+        };
+        score.judge_counts.epg = exscore / 2;
+        score.judge_counts.egr = exscore % 2;
+        score.play_option.option = ir_score.option;
+    } else {
+        score.player = "NO DATA".to_string();
+    }
+    score
+}
+
 /// Next rank target
 pub struct NextRankTargetProperty {
     pub id: String,
@@ -740,6 +742,158 @@ impl NextRankTargetProperty {
     }
 }
 
+/// Personal best target: the player's own best score on the chart
+pub struct MyBestTargetProperty {
+    pub id: String,
+    pub target_score: ScoreData,
+}
+
+impl Default for MyBestTargetProperty {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MyBestTargetProperty {
+    pub fn new() -> Self {
+        MyBestTargetProperty {
+            id: "MYBEST".to_string(),
+            target_score: ScoreData::default(),
+        }
+    }
+
+    /// Best score for the loaded chart, or an empty score when it was never played.
+    fn target(&mut self, main: &MainController) -> ScoreData {
+        let lnmode = main.player_config().play_settings.lnmode;
+        let best = main
+            .player_resource()
+            .and_then(|r| r.bms_model())
+            .and_then(|m| {
+                main.play_data_accessor()
+                    .and_then(|pda| pda.read_score_data_model(m, lnmode))
+            });
+
+        self.target_score = best.unwrap_or_default();
+        self.target_score.player = "MY BEST".to_string();
+        self.target_score.clone()
+    }
+}
+
+/// Target derived from the player's own plays of the chart
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HistoryTarget {
+    /// Average EX score of the last `value` plays
+    Average,
+    /// Best EX score of the plays before today
+    Yesterday,
+}
+
+pub struct HistoryTargetProperty {
+    pub id: String,
+    pub target: HistoryTarget,
+    pub value: i32,
+    pub target_score: ScoreData,
+}
+
+impl HistoryTargetProperty {
+    pub fn new(target: HistoryTarget, value: i32) -> Self {
+        let id = match target {
+            HistoryTarget::Average => format!("HISTORY_AVERAGE_{}", value),
+            HistoryTarget::Yesterday => "HISTORY_YESTERDAY".to_string(),
+        };
+        HistoryTargetProperty {
+            id,
+            target,
+            value,
+            target_score: ScoreData::default(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self.target {
+            HistoryTarget::Average => format!("LAST {} AVERAGE", self.value),
+            HistoryTarget::Yesterday => "YESTERDAY BEST".to_string(),
+        }
+    }
+
+    /// Plays of the chart this target is computed from (newest first).
+    /// `Average` needs the last `value`; `Yesterday` reads every play.
+    pub fn play_log_count(&self) -> i32 {
+        match self.target {
+            HistoryTarget::Average => self.value,
+            HistoryTarget::Yesterday => -1,
+        }
+    }
+
+    /// Target score from the play history of the loaded chart, or an empty
+    /// score when the chart has no plays to compare against.
+    fn target(&mut self, main: &MainController) -> ScoreData {
+        let lnmode = main.player_config().play_settings.lnmode;
+        let logs = main
+            .player_resource()
+            .and_then(|r| r.bms_model())
+            .and_then(|m| {
+                main.play_data_accessor()
+                    .map(|pda| pda.read_play_logs_model(m, lnmode, self.play_log_count()))
+            })
+            .unwrap_or_default();
+        let today = crate::core::score_database_accessor::local_midnight_timestamp();
+        self.target_score = history_target_score(&logs, self.target, self.value, today);
+        self.target_score.player = self.name();
+        self.target_score.clone()
+    }
+
+    pub fn from_id(id: &str) -> Option<TargetProperty> {
+        if id == "HISTORY_YESTERDAY" {
+            return Some(TargetProperty::History(HistoryTargetProperty::new(
+                HistoryTarget::Yesterday,
+                0,
+            )));
+        }
+        if let Some(suffix) = id.strip_prefix("HISTORY_AVERAGE_")
+            && let Ok(count) = suffix.parse::<i32>()
+            && count > 0
+        {
+            return Some(TargetProperty::History(HistoryTargetProperty::new(
+                HistoryTarget::Average,
+                count,
+            )));
+        }
+        None
+    }
+}
+
+/// Target score `target`/`value` picks from `logs` (newest first), where
+/// `today` is the local midnight plays of today start at.
+pub(crate) fn history_target_score(
+    logs: &[PlayLog],
+    target: HistoryTarget,
+    value: i32,
+    today: i64,
+) -> ScoreData {
+    let exscore = match target {
+        HistoryTarget::Average => {
+            let recent = &logs[..logs.len().min(value.max(0) as usize)];
+            if recent.is_empty() {
+                0
+            } else {
+                (recent.iter().map(|log| log.exscore as i64).sum::<i64>() / recent.len() as i64)
+                    as i32
+            }
+        }
+        HistoryTarget::Yesterday => logs
+            .iter()
+            .filter(|log| log.date < today)
+            .map(|log| log.exscore)
+            .max()
+            .unwrap_or(0),
+    };
+    let mut score = ScoreData::default();
+    score.judge_counts.epg = exscore / 2;
+    score.judge_counts.egr = exscore % 2;
+    score
+}
+
 #[cfg(test)]
 #[allow(clippy::field_reassign_with_default)]
 mod tests {
@@ -848,6 +1002,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_my_best_target_without_record_is_empty() {
+        let mut target = TargetProperty::from_id("MYBEST").unwrap();
+        assert_eq!(target.id(), "MYBEST");
+        let mut main = make_main();
+        assert_eq!(target.name(&main), "MY BEST");
+        let score = target.target(&mut main);
+        assert_eq!(score.player, "MY BEST");
+        assert_eq!(score.exscore(), 0);
+    }
+
+    #[test]
+    fn test_history_target_from_id() {
+        let mut target = TargetProperty::from_id("HISTORY_AVERAGE_5").unwrap();
+        assert_eq!(target.id(), "HISTORY_AVERAGE_5");
+        let mut main = make_main();
+        assert_eq!(target.name(&main), "LAST 5 AVERAGE");
+        let score = target.target(&mut main);
+        assert_eq!(score.player, "LAST 5 AVERAGE");
+        assert_eq!(score.exscore(), 0);
+
+        let target = TargetProperty::from_id("HISTORY_YESTERDAY").unwrap();
+        assert_eq!(target.name(&main), "YESTERDAY BEST");
+        assert_eq!(
+            TargetProperty::from_id("HISTORY_AVERAGE_0").unwrap().id(),
+            "MAX"
+        );
+    }
+
+    #[test]
+    fn test_history_target_score() {
+        let log = |exscore: i32, date: i64| PlayLog {
+            exscore,
+            date,
+            ..Default::default()
+        };
+        // Newest first; today starts at 100
+        let logs = [
+            log(901, 150),
+            log(800, 120),
+            log(700, 90),
+            log(751, 50),
+            log(600, 10),
+        ];
+
+        let average = history_target_score(&logs, HistoryTarget::Average, 5, 100);
+        assert_eq!(average.exscore(), 750);
+        let average = history_target_score(&logs, HistoryTarget::Average, 2, 100);
+        assert_eq!(average.exscore(), 850);
+        let yesterday = history_target_score(&logs, HistoryTarget::Yesterday, 0, 100);
+        assert_eq!(yesterday.exscore(), 751);
+        assert_eq!(yesterday.judge_counts.egr, 1);
+
+        let empty = history_target_score(&[], HistoryTarget::Average, 5, 100);
+        assert_eq!(empty.exscore(), 0);
+        let first_day = history_target_score(&logs[..2], HistoryTarget::Yesterday, 0, 100);
+        assert_eq!(first_day.exscore(), 0);
+    }
+
     #[test]
     fn test_fallback_to_max() {
         let target = TargetProperty::from_id("UNKNOWN").unwrap();
@@ -958,7 +1171,12 @@ mod tests {
     #[test]
     fn test_ir_target_returns_no_data_for_all_types() {
         let mut main = make_main();
-        for ir_type in [IRTarget::Next, IRTarget::Rank, IRTarget::RankRate] {
+        for ir_type in [
+            IRTarget::Next,
+            IRTarget::Rank,
+            IRTarget::RankRate,
+            IRTarget::Average,
+        ] {
             let mut target =
                 TargetProperty::InternetRanking(InternetRankingTargetProperty::new(ir_type, 1));
             let score = target.target(&mut main);
@@ -1365,4 +1583,40 @@ mod tests {
             "IR_NEXT target_rank fallback should be 0 (top rank), not (total - value)"
         );
     }
+
+    #[test]
+    fn test_ir_average_target_averages_top_scores() {
+        let scores: Vec<crate::ir::ir_score_data::IRScoreData> = [300, 200, 100]
+            .into_iter()
+            .map(|epg| {
+                let mut sd = ScoreData::default();
+                sd.judge_counts.epg = epg;
+                crate::ir::ir_score_data::IRScoreData::new(&sd)
+            })
+            .collect();
+        let conn = MockIRConnection { scores };
+        let mut ranking = crate::ir::ranking_data::RankingData::new();
+        ranking.load_song(
+            &conn,
+            &crate::ir::ir_chart_data::IRChartData::default(),
+            None,
+        );
+
+        let score = ranking_target_score(&ranking, IRTarget::Average, 2, 0);
+        assert_eq!(score.exscore(), 500);
+        assert_eq!(score.player, "IR TOP 2 AVERAGE");
+        // Fewer players than asked for averages everyone
+        let score = ranking_target_score(&ranking, IRTarget::Average, 10, 0);
+        assert_eq!(score.exscore(), 400);
+        assert_eq!(score.player, "IR TOP 3 AVERAGE");
+
+        let target = InternetRankingTargetProperty::from_id("IR_AVERAGE_10").unwrap();
+        if let TargetProperty::InternetRanking(p) = target {
+            assert_eq!(p.target, IRTarget::Average);
+            assert_eq!(p.value, 10);
+        } else {
+            panic!("Expected InternetRanking variant");
+        }
+        assert!(InternetRankingTargetProperty::from_id("IR_AVERAGE_0").is_none());
+    }
 }
@@ -0,0 +1,87 @@
+//! Profiling spans behind the `profiling` feature.
+//!
+//! `profile_scope!("name")` opens a span that lasts until the end of the
+//! enclosing block. With the `profiling` feature, spans are recorded as
+//! puffin scopes, which the in-game profiler window draws as a flame graph.
+//! `profiling-tracy` also streams them to a Tracy viewer, and
+//! `profiling-tracing` emits them as `tracing` spans. Without the feature the
+//! macro expands to nothing.
+
+#[cfg(feature = "profiling")]
+pub use puffin;
+#[cfg(feature = "profiling-tracing")]
+pub use tracing;
+#[cfg(feature = "profiling-tracy")]
+pub use tracy_client;
+
+/// Open a profiling span named by a string literal for the rest of the
+/// enclosing block.
+#[cfg(feature = "profiling")]
+#[macro_export]
+macro_rules! profile_scope {
+    ($name:literal) => {
+        $crate::profiling::puffin::profile_scope!($name);
+        $crate::__profile_scope_tracy!($name);
+        $crate::__profile_scope_tracing!($name);
+    };
+}
+
+/// Open a profiling span named by a string literal for the rest of the
+/// enclosing block.
+#[cfg(not(feature = "profiling"))]
+#[macro_export]
+macro_rules! profile_scope {
+    ($name:literal) => {};
+}
+
+#[cfg(feature = "profiling-tracy")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __profile_scope_tracy {
+    ($name:literal) => {
+        let _tracy_span = $crate::profiling::tracy_client::Client::running()
+            .map(|client| client.span($crate::profiling::tracy_client::span_location!($name), 0));
+    };
+}
+
+#[cfg(not(feature = "profiling-tracy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __profile_scope_tracy {
+    ($name:literal) => {};
+}
+
+#[cfg(feature = "profiling-tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __profile_scope_tracing {
+    ($name:literal) => {
+        let _tracing_span = $crate::profiling::tracing::trace_span!($name).entered();
+    };
+}
+
+#[cfg(not(feature = "profiling-tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __profile_scope_tracing {
+    ($name:literal) => {};
+}
+
+/// Start recording spans. Called once at startup; does nothing without the
+/// `profiling` feature.
+pub fn init() {
+    #[cfg(feature = "profiling")]
+    puffin::set_scopes_on(true);
+    #[cfg(feature = "profiling-tracy")]
+    tracy_client::Client::start();
+}
+
+/// Close the current profiler frame. Called once per rendered frame.
+pub fn finish_frame() {
+    #[cfg(feature = "profiling")]
+    puffin::GlobalProfiler::lock().new_frame();
+    #[cfg(feature = "profiling-tracy")]
+    if let Some(client) = tracy_client::Client::running() {
+        client.frame_mark();
+    }
+}
@@ -87,4 +87,49 @@ impl EguiIntegration {
     pub fn context(&self) -> &egui::Context {
         &self.ctx
     }
+
+    /// Add the font at `path` as a fallback after egui's built-in fonts, so
+    /// Japanese text typed through the IME (e.g. in the song search field) is
+    /// drawn. Returns false if the font could not be read.
+    pub fn add_fallback_font(&self, path: &str) -> bool {
+        match std::fs::read(path) {
+            Ok(data) => {
+                self.ctx.set_fonts(fonts_with_fallback(data));
+                true
+            }
+            Err(e) => {
+                log::warn!("Failed to load egui fallback font {}: {}", path, e);
+                false
+            }
+        }
+    }
+}
+
+const FALLBACK_FONT_NAME: &str = "system_fallback";
+
+/// egui's default fonts with `data` appended to every family.
+fn fonts_with_fallback(data: Vec<u8>) -> egui::FontDefinitions {
+    let mut fonts = egui::FontDefinitions::default();
+    fonts.font_data.insert(
+        FALLBACK_FONT_NAME.to_string(),
+        std::sync::Arc::new(egui::FontData::from_owned(data)),
+    );
+    for family in fonts.families.values_mut() {
+        family.push(FALLBACK_FONT_NAME.to_string());
+    }
+    fonts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fallback_font_is_appended_to_every_family() {
+        let fonts = fonts_with_fallback(vec![0; 4]);
+        assert!(fonts.font_data.contains_key(FALLBACK_FONT_NAME));
+        for family in fonts.families.values() {
+            assert_eq!(family.last().map(String::as_str), Some(FALLBACK_FONT_NAME));
+        }
+    }
 }
@@ -5,6 +5,7 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use crate::render::render_pipeline::SpriteRenderPipeline;
+use crate::render::texture_budget::{TextureBudget, texture_bytes};
 
 /// GPU resources needed for texture upload operations.
 pub struct TextureUploadContext<'a> {
@@ -24,7 +25,7 @@ struct GpuTextureEntry {
 }
 
 /// Pending texture data waiting to be uploaded to the GPU.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct PendingTexture {
     pub width: u32,
     pub height: u32,
@@ -35,8 +36,10 @@ pub struct PendingTexture {
 /// Textures are uploaded lazily on first use and cached by path key.
 ///
 /// Tracks which textures are referenced each frame via `ensure_uploaded()`.
-/// Call `evict_unused()` after rendering to free GPU textures that were
-/// not referenced in the current frame (e.g., stale BGA video frames).
+/// Call `evict_unused()` after rendering: path-less textures not referenced
+/// in the current frame are freed right away (e.g., stale BGA video frames),
+/// file textures stay cached until the memory budget forces the least
+/// recently used ones out. An evicted texture is uploaded again on its next use.
 pub struct GpuTextureManager {
     entries: HashMap<Arc<str>, GpuTextureEntry>,
     /// Bind group for path-less textures (1x1 white fallback)
@@ -46,6 +49,8 @@ pub struct GpuTextureManager {
     anon_counter: u64,
     /// Keys passed to `ensure_uploaded()` in the current frame.
     used_this_frame: HashSet<Arc<str>>,
+    /// GPU memory of the cached textures, in LRU order
+    budget: TextureBudget<Arc<str>>,
 }
 
 impl GpuTextureManager {
@@ -129,9 +134,15 @@ impl GpuTextureManager {
             fallback_bind_group_linear,
             anon_counter: 0,
             used_this_frame: HashSet::new(),
+            budget: TextureBudget::new(DEFAULT_GPU_TEXTURE_BUDGET),
         }
     }
 
+    /// Limit for the GPU memory of cached file textures (`Config.render.texture_budget`).
+    pub fn set_memory_budget(&mut self, bytes: usize) {
+        self.budget.set_limit_bytes(bytes);
+    }
+
     /// Upload a texture to the GPU if not already cached.
     /// Also marks the key as used for the current frame (see `evict_unused()`).
     pub fn ensure_uploaded(
@@ -149,6 +160,7 @@ impl GpuTextureManager {
         // also re-uploaded every frame via this same path.
         let is_pixmap = key.starts_with("__pixmap_");
         if self.entries.contains_key(key) && !is_pixmap {
+            self.budget.touch(key);
             return;
         }
 
@@ -261,6 +273,8 @@ impl GpuTextureManager {
             ],
         });
 
+        self.budget
+            .insert(Arc::clone(key), texture_bytes(upload_width, upload_height));
         self.entries.insert(
             Arc::clone(key),
             GpuTextureEntry {
@@ -301,14 +315,18 @@ impl GpuTextureManager {
     /// Remove a single texture entry by key, freeing its GPU resources.
     pub fn remove(&mut self, key: &Arc<str>) {
         self.entries.remove(key);
+        self.budget.remove(key);
     }
 
-    /// Evict all cached textures that were not passed to `ensure_uploaded()`
-    /// since the last call to `evict_unused()`. Call once per frame after
-    /// rendering to free stale GPU textures (e.g., old BGA video frames).
+    /// Free path-less textures that were not passed to `ensure_uploaded()`
+    /// since the last call, then the least recently used file textures while
+    /// the cache is over its memory budget. Textures used in the current frame
+    /// are always kept. Call once per frame after rendering.
     pub fn evict_unused(&mut self) {
-        let used = &self.used_this_frame;
-        self.entries.retain(|k, _| used.contains(k));
+        let evicted = keys_to_evict(self.entries.keys(), &self.used_this_frame, &mut self.budget);
+        for key in &evicted {
+            self.entries.remove(key);
+        }
         self.used_this_frame.clear();
     }
 
@@ -316,6 +334,38 @@ impl GpuTextureManager {
     pub fn entry_count(&self) -> usize {
         self.entries.len()
     }
+
+    /// GPU memory held by cached textures, in bytes (for diagnostics).
+    pub fn memory_bytes(&self) -> usize {
+        self.budget.used_bytes()
+    }
+}
+
+/// Default GPU texture budget until the configured one is applied.
+const DEFAULT_GPU_TEXTURE_BUDGET: usize = 1024 * 1024 * 1024;
+
+/// Keys generated for textures without a file path (`__pixmap_`, `__anon_`).
+/// Their data changes or they are never looked up again, so they are not
+/// worth keeping once a frame stops drawing them.
+fn is_frame_local_key(key: &str) -> bool {
+    key.starts_with("__")
+}
+
+/// Choose the cache entries to free after a frame (see `evict_unused`).
+fn keys_to_evict<'a>(
+    keys: impl Iterator<Item = &'a Arc<str>>,
+    used_this_frame: &HashSet<Arc<str>>,
+    budget: &mut TextureBudget<Arc<str>>,
+) -> Vec<Arc<str>> {
+    let mut evicted: Vec<Arc<str>> = keys
+        .filter(|k| is_frame_local_key(k) && !used_this_frame.contains(*k))
+        .cloned()
+        .collect();
+    for key in &evicted {
+        budget.remove(key);
+    }
+    evicted.extend(budget.evict_over_budget(|k| used_this_frame.contains(k)));
+    evicted
 }
 
 #[cfg(test)]
@@ -323,42 +373,86 @@ mod tests {
     use std::collections::{HashMap, HashSet};
     use std::sync::Arc;
 
-    /// Test the eviction logic in isolation (mirrors `evict_unused` behavior).
-    /// This avoids needing a real wgpu device for the test.
-    fn evict_unused(entries: &mut HashMap<Arc<str>, ()>, used_this_frame: &mut HashSet<Arc<str>>) {
-        let used = &*used_this_frame;
-        entries.retain(|k, _| used.contains(k));
+    use super::*;
+
+    /// Mirrors `evict_unused` over plain entries; avoids needing a wgpu device.
+    fn evict_unused(
+        entries: &mut HashMap<Arc<str>, ()>,
+        used_this_frame: &mut HashSet<Arc<str>>,
+        budget: &mut TextureBudget<Arc<str>>,
+    ) {
+        for key in keys_to_evict(entries.keys(), used_this_frame, budget) {
+            entries.remove(&key);
+        }
         used_this_frame.clear();
     }
 
+    fn upload(
+        entries: &mut HashMap<Arc<str>, ()>,
+        used_this_frame: &mut HashSet<Arc<str>>,
+        budget: &mut TextureBudget<Arc<str>>,
+        key: &str,
+        bytes: usize,
+    ) {
+        let key = Arc::<str>::from(key);
+        if entries.insert(Arc::clone(&key), ()).is_none() {
+            budget.insert(Arc::clone(&key), bytes);
+        } else {
+            budget.touch(&key);
+        }
+        used_this_frame.insert(key);
+    }
+
     #[test]
-    fn evict_unused_clears_all_entries_when_no_textures_referenced() {
+    fn evict_unused_frees_unreferenced_pixmap_textures() {
         let mut entries = HashMap::new();
-        entries.insert(Arc::<str>::from("tex_a"), ());
-        entries.insert(Arc::<str>::from("tex_b"), ());
-        entries.insert(Arc::<str>::from("tex_c"), ());
         let mut used = HashSet::new();
+        let mut budget = TextureBudget::new(1000);
+        upload(&mut entries, &mut used, &mut budget, "__pixmap_1", 10);
+        upload(&mut entries, &mut used, &mut budget, "__anon_2", 10);
+        upload(&mut entries, &mut used, &mut budget, "skin/a.png", 10);
+        evict_unused(&mut entries, &mut used, &mut budget);
+        assert_eq!(entries.len(), 3);
+
+        // Blank frame: nothing referenced. Only the file texture stays cached.
+        evict_unused(&mut entries, &mut used, &mut budget);
+        assert_eq!(entries.len(), 1);
+        assert!(entries.contains_key("skin/a.png"));
+        assert_eq!(budget.used_bytes(), 10);
+    }
 
-        // Blank frame: no textures referenced. All entries should be evicted.
-        evict_unused(&mut entries, &mut used);
-        assert!(
-            entries.is_empty(),
-            "All cached textures should be evicted when none were referenced"
-        );
+    #[test]
+    fn evict_unused_frees_least_recently_used_textures_over_budget() {
+        let mut entries = HashMap::new();
+        let mut used = HashSet::new();
+        let mut budget = TextureBudget::new(100);
+        upload(&mut entries, &mut used, &mut budget, "bga/001.png", 40);
+        evict_unused(&mut entries, &mut used, &mut budget);
+        upload(&mut entries, &mut used, &mut budget, "bga/002.png", 40);
+        evict_unused(&mut entries, &mut used, &mut budget);
+        upload(&mut entries, &mut used, &mut budget, "bga/001.png", 40);
+        upload(&mut entries, &mut used, &mut budget, "bga/003.png", 40);
+        evict_unused(&mut entries, &mut used, &mut budget);
+
+        assert!(!entries.contains_key("bga/002.png"));
+        assert!(entries.contains_key("bga/001.png"));
+        assert!(entries.contains_key("bga/003.png"));
+        assert_eq!(budget.used_bytes(), 80);
     }
 
     #[test]
-    fn evict_unused_retains_only_referenced_textures() {
+    fn evict_unused_keeps_textures_of_the_current_frame_over_budget() {
         let mut entries = HashMap::new();
-        entries.insert(Arc::<str>::from("tex_a"), ());
-        entries.insert(Arc::<str>::from("tex_b"), ());
-        entries.insert(Arc::<str>::from("tex_c"), ());
         let mut used = HashSet::new();
-        used.insert(Arc::<str>::from("tex_b"));
+        let mut budget = TextureBudget::new(50);
+        upload(&mut entries, &mut used, &mut budget, "a.png", 40);
+        upload(&mut entries, &mut used, &mut budget, "b.png", 40);
+        evict_unused(&mut entries, &mut used, &mut budget);
+        assert_eq!(entries.len(), 2);
 
-        evict_unused(&mut entries, &mut used);
+        evict_unused(&mut entries, &mut used, &mut budget);
         assert_eq!(entries.len(), 1);
-        assert!(entries.contains_key(&Arc::<str>::from("tex_b") as &str));
+        assert!(entries.contains_key("b.png"));
     }
 }
 
@@ -14,6 +14,7 @@ pub mod resource_pool;
 pub mod shader;
 pub mod sprite_batch;
 pub mod texture;
+pub mod texture_budget;
 
 pub use blend::*;
 pub use color::*;
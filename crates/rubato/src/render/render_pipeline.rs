@@ -7,13 +7,16 @@ use crate::render::sprite_batch::SpriteVertex;
 
 /// Shader type IDs matching Java SkinObjectRenderer constants.
 /// TYPE_NORMAL = 0, TYPE_LINEAR = 1, TYPE_BILINEAR = 2,
-/// TYPE_FFMPEG = 3, TYPE_LAYER = 4, TYPE_DISTANCE_FIELD = 5
+/// TYPE_FFMPEG = 3, TYPE_LAYER = 4, TYPE_DISTANCE_FIELD = 5,
+/// plus TYPE_RECOLOR = 6 and TYPE_SILHOUETTE = 7 for the accessibility overrides
 pub const SHADER_TYPE_NORMAL: i32 = 0;
 pub const SHADER_TYPE_LINEAR: i32 = 1;
 pub const SHADER_TYPE_BILINEAR: i32 = 2;
 pub const SHADER_TYPE_FFMPEG: i32 = 3;
 pub const SHADER_TYPE_LAYER: i32 = 4;
 pub const SHADER_TYPE_DISTANCE_FIELD: i32 = 5;
+pub const SHADER_TYPE_RECOLOR: i32 = 6;
+pub const SHADER_TYPE_SILHOUETTE: i32 = 7;
 
 /// Manages wgpu render pipelines for sprite rendering.
 /// Each combination of (shader_type, blend_mode) maps to a separate wgpu::RenderPipeline.
@@ -114,6 +117,9 @@ impl SpriteRenderPipeline {
                 "fs_distance_field",
                 SHADER_TYPE_DISTANCE_FIELD,
             ),
+            // Accessibility palette and high-contrast note overrides
+            (&main_shader, "fs_recolor", SHADER_TYPE_RECOLOR),
+            (&main_shader, "fs_silhouette", SHADER_TYPE_SILHOUETTE),
         ];
 
         let vertex_buffers = [SpriteVertex::desc()];
@@ -248,6 +254,8 @@ mod tests {
         assert_eq!(SHADER_TYPE_FFMPEG, 3);
         assert_eq!(SHADER_TYPE_LAYER, 4);
         assert_eq!(SHADER_TYPE_DISTANCE_FIELD, 5);
+        assert_eq!(SHADER_TYPE_RECOLOR, 6);
+        assert_eq!(SHADER_TYPE_SILHOUETTE, 7);
     }
 
     #[tokio::test]
@@ -262,8 +270,8 @@ mod tests {
         let format = ctx.surface_format();
         let pipeline = SpriteRenderPipeline::new(&ctx.device, format);
 
-        // 6 shader types * 5 blend modes = 30 pipelines
-        assert_eq!(pipeline.pipeline_count(), 30);
+        // 8 shader types * 5 blend modes = 40 pipelines
+        assert_eq!(pipeline.pipeline_count(), 40);
 
         // Verify we can look up pipelines for all combinations
         let blend_modes = [
@@ -273,7 +281,7 @@ mod tests {
             BlendMode::Multiply,
             BlendMode::Inversion,
         ];
-        for shader_type in 0..=5 {
+        for shader_type in 0..=7 {
             for &blend_mode in &blend_modes {
                 assert!(
                     pipeline.pipeline(shader_type, blend_mode).is_some(),
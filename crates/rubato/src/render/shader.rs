@@ -95,6 +95,25 @@ fn fs_distance_field(in: VertexOutput) -> @location(0) vec4<f32> {
     let alpha = smoothstep(0.5 - smoothing, 0.5 + smoothing, distance);
     return vec4<f32>(in.color.rgb, in.color.a * alpha);
 }
+
+// Recolor shader: replace the texture hue with the vertex color, keeping the
+// texture's brightness as shading so outlines and gradients stay readable.
+// Used by the accessibility palettes.
+@fragment
+fn fs_recolor(in: VertexOutput) -> @location(0) vec4<f32> {
+    let c4 = textureSample(t_diffuse, s_diffuse, in.tex_coord);
+    let luma = dot(c4.rgb, vec3<f32>(0.299, 0.587, 0.114));
+    let shade = 0.35 + 0.65 * luma;
+    return vec4<f32>(in.color.rgb * shade, in.color.a * c4.a);
+}
+
+// Silhouette shader: flat vertex color masked by the texture's alpha.
+// Used by the high-contrast note mode.
+@fragment
+fn fs_silhouette(in: VertexOutput) -> @location(0) vec4<f32> {
+    let c4 = textureSample(t_diffuse, s_diffuse, in.tex_coord);
+    return vec4<f32>(in.color.rgb, in.color.a * c4.a);
+}
 "#;
 
 /// Bilinear filter WGSL shader.
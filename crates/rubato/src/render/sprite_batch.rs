@@ -115,6 +115,38 @@ struct FlushedSegment {
     pending_textures: HashMap<Arc<str>, PendingTexture>,
 }
 
+/// Position in a `SpriteBatch` where recording of a draw started.
+#[derive(Clone, Copy, Debug)]
+pub struct RecordMark {
+    vertex_start: usize,
+    flushed_segments: usize,
+}
+
+/// Vertices one draw emitted under a single texture/shader/blend state.
+#[derive(Debug)]
+struct RecordedBatch {
+    texture_key: Option<Arc<str>>,
+    shader_type: i32,
+    blend_mode: BlendMode,
+    vertex_count: u32,
+}
+
+/// Vertices and batch state emitted between `begin_record()` and
+/// `end_record()`, replayed into later frames while the drawn object is
+/// unchanged. Buffers are reused when the recording is refreshed.
+#[derive(Debug, Default)]
+pub struct RecordedDraw {
+    vertices: Vec<SpriteVertex>,
+    batches: Vec<RecordedBatch>,
+    textures: Vec<(Arc<str>, PendingTexture)>,
+}
+
+impl RecordedDraw {
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+}
+
 /// Batched 2D sprite renderer.
 /// Corresponds to com.badlogic.gdx.graphics.g2d.SpriteBatch.
 ///
@@ -132,6 +164,9 @@ pub struct SpriteBatch {
     /// Completed vertex segments waiting for GPU submission.
     /// Created by auto-flush when the active buffer reaches MAX_VERTICES.
     flushed_segments: Vec<FlushedSegment>,
+    /// Emptied segments kept for reuse so heavy skins do not allocate new
+    /// vertex buffers every frame.
+    spare_segments: Vec<FlushedSegment>,
     current_color: [f32; 4],
     blend_src: i32,
     blend_dst: i32,
@@ -152,8 +187,14 @@ pub struct SpriteBatch {
     /// When `Some`, every draw call records a `CapturedDrawQuad`.
     /// Zero overhead when `None` (just an Option check).
     capture_buffer: Option<Vec<CapturedDrawQuad>>,
+    /// Texture cache keys of pixmap-backed textures, by pixmap id, so the
+    /// key string is built once instead of on every draw.
+    pixmap_keys: HashMap<u64, Arc<str>>,
 }
 
+/// Pixmap keys kept before the cache is cleared (pixmap ids are never reused).
+const MAX_PIXMAP_KEYS: usize = 4096;
+
 #[allow(unused_variables)]
 impl SpriteBatch {
     pub fn new() -> Self {
@@ -162,6 +203,7 @@ impl SpriteBatch {
             draw_batches: Vec::new(),
             pending_textures: HashMap::new(),
             flushed_segments: Vec::new(),
+            spare_segments: Vec::new(),
             current_color: [1.0, 1.0, 1.0, 1.0],
             blend_src: 0x0302, // GL_SRC_ALPHA
             blend_dst: 0x0303, // GL_ONE_MINUS_SRC_ALPHA
@@ -174,6 +216,7 @@ impl SpriteBatch {
             gpu_vertex_buffer: None,
             gpu_vertex_buffer_capacity: 0,
             capture_buffer: None,
+            pixmap_keys: HashMap::new(),
         }
     }
 
@@ -315,7 +358,30 @@ impl SpriteBatch {
         self.vertices.clear();
         self.draw_batches.clear();
         self.pending_textures.clear();
-        self.flushed_segments.clear();
+        self.recycle_flushed_segments();
+    }
+
+    /// Move the active buffer into a completed segment, continuing in an
+    /// emptied spare segment's buffers when one is available.
+    fn push_active_segment(&mut self) {
+        let mut segment = self.spare_segments.pop().unwrap_or_else(|| FlushedSegment {
+            vertices: Vec::with_capacity(MAX_VERTICES),
+            draw_batches: Vec::new(),
+            pending_textures: HashMap::new(),
+        });
+        std::mem::swap(&mut segment.vertices, &mut self.vertices);
+        std::mem::swap(&mut segment.draw_batches, &mut self.draw_batches);
+        std::mem::swap(&mut segment.pending_textures, &mut self.pending_textures);
+        self.flushed_segments.push(segment);
+    }
+
+    fn recycle_flushed_segments(&mut self) {
+        for mut segment in self.flushed_segments.drain(..) {
+            segment.vertices.clear();
+            segment.draw_batches.clear();
+            segment.pending_textures.clear();
+            self.spare_segments.push(segment);
+        }
     }
 
     /// Auto-flush: move the current active buffer into a completed segment
@@ -325,14 +391,7 @@ impl SpriteBatch {
         if self.vertices.is_empty() {
             return;
         }
-        let vertices = std::mem::replace(&mut self.vertices, Vec::with_capacity(MAX_VERTICES));
-        let draw_batches = std::mem::take(&mut self.draw_batches);
-        let pending_textures = std::mem::take(&mut self.pending_textures);
-        self.flushed_segments.push(FlushedSegment {
-            vertices,
-            draw_batches,
-            pending_textures,
-        });
+        self.push_active_segment();
     }
 
     /// Flush batched vertices to GPU via a render pass.
@@ -373,14 +432,7 @@ impl SpriteBatch {
         // everything uniformly. This avoids borrow conflicts between
         // the vertex data and the GPU buffer fields on `self`.
         if !self.vertices.is_empty() {
-            let vertices = std::mem::replace(&mut self.vertices, Vec::with_capacity(MAX_VERTICES));
-            let draw_batches = std::mem::take(&mut self.draw_batches);
-            let pending_textures = std::mem::take(&mut self.pending_textures);
-            self.flushed_segments.push(FlushedSegment {
-                vertices,
-                draw_batches,
-                pending_textures,
-            });
+            self.push_active_segment();
         }
 
         // Process all segments in submission order
@@ -388,6 +440,8 @@ impl SpriteBatch {
         for segment in &segments {
             self.flush_segment_to_gpu(&segment.vertices, &segment.draw_batches, render_pass, ctx);
         }
+        self.flushed_segments = segments;
+        self.recycle_flushed_segments();
 
         self.vertices.clear();
         self.draw_batches.clear();
@@ -618,11 +672,10 @@ impl SpriteBatch {
         // back to the white 1x1 texture. This currently only affects ffmpeg BGA frames
         // (feature non-functional). When ffmpeg support is fixed, those frames need
         // either a path or pixmap_id to be uploaded.
-        let key = texture.path.clone().or_else(|| {
-            texture
-                .pixmap_id
-                .map(|id| Arc::from(format!("__pixmap_{:x}", id)))
-        });
+        let key = texture
+            .path
+            .clone()
+            .or_else(|| texture.pixmap_id.map(|id| self.pixmap_key(id)));
 
         // Register pending texture for GPU upload if it has rgba data
         if let Some(ref path) = key
@@ -642,13 +695,33 @@ impl SpriteBatch {
         self.ensure_batch(key);
     }
 
+    fn pixmap_key(&mut self, pixmap_id: u64) -> Arc<str> {
+        if self.pixmap_keys.len() >= MAX_PIXMAP_KEYS && !self.pixmap_keys.contains_key(&pixmap_id) {
+            self.pixmap_keys.clear();
+        }
+        Arc::clone(
+            self.pixmap_keys
+                .entry(pixmap_id)
+                .or_insert_with(|| Arc::from(format!("__pixmap_{:x}", pixmap_id))),
+        )
+    }
+
     /// Ensure a draw batch exists for the current texture/shader/blend state.
     /// If the current batch has different state, start a new batch.
     fn ensure_batch(&mut self, texture_key: Option<Arc<str>>) {
+        self.ensure_batch_with(texture_key, self.shader_type, self.blend_mode);
+    }
+
+    fn ensure_batch_with(
+        &mut self,
+        texture_key: Option<Arc<str>>,
+        shader_type: i32,
+        blend_mode: BlendMode,
+    ) {
         let needs_new_batch = if let Some(last) = self.draw_batches.last() {
             last.texture_key != texture_key
-                || last.shader_type != self.shader_type
-                || last.blend_mode != self.blend_mode
+                || last.shader_type != shader_type
+                || last.blend_mode != blend_mode
         } else {
             true
         };
@@ -656,14 +729,90 @@ impl SpriteBatch {
         if needs_new_batch {
             self.draw_batches.push(DrawBatch {
                 texture_key,
-                shader_type: self.shader_type,
-                blend_mode: self.blend_mode,
+                shader_type,
+                blend_mode,
                 vertex_start: self.vertices.len() as u32,
                 vertex_count: 0,
             });
         }
     }
 
+    /// Whether draw calls are being captured (see `enable_capture`).
+    pub fn is_capturing(&self) -> bool {
+        self.capture_buffer.is_some()
+    }
+
+    /// Start recording the draw calls that follow (see `end_record`).
+    pub fn begin_record(&self) -> RecordMark {
+        RecordMark {
+            vertex_start: self.vertices.len(),
+            flushed_segments: self.flushed_segments.len(),
+        }
+    }
+
+    /// Copy everything drawn since `mark` into `out`, replacing its contents.
+    /// Returns false (leaving `out` empty) when an auto-flush split the
+    /// recorded draws, since they are then no longer in the active buffer.
+    pub fn end_record(&self, mark: RecordMark, out: &mut RecordedDraw) -> bool {
+        out.vertices.clear();
+        out.batches.clear();
+        out.textures.clear();
+        if self.flushed_segments.len() != mark.flushed_segments {
+            return false;
+        }
+        out.vertices
+            .extend_from_slice(&self.vertices[mark.vertex_start..]);
+        for batch in &self.draw_batches {
+            let start = (batch.vertex_start as usize).max(mark.vertex_start);
+            let end = (batch.vertex_start + batch.vertex_count) as usize;
+            if end <= start {
+                continue;
+            }
+            if let Some(key) = &batch.texture_key
+                && let Some(pending) = self.pending_textures.get(key)
+                && !out.textures.iter().any(|(k, _)| k == key)
+            {
+                out.textures.push((Arc::clone(key), pending.clone()));
+            }
+            out.batches.push(RecordedBatch {
+                texture_key: batch.texture_key.clone(),
+                shader_type: batch.shader_type,
+                blend_mode: batch.blend_mode,
+                vertex_count: (end - start) as u32,
+            });
+        }
+        true
+    }
+
+    /// Append a recorded draw as if its draw calls were made again.
+    /// The batch's current color, shader and blend state are left untouched.
+    pub fn replay(&mut self, recorded: &RecordedDraw) {
+        if self.vertices.len() + recorded.vertices.len() > MAX_VERTICES {
+            self.auto_flush();
+        }
+        for (key, pending) in &recorded.textures {
+            if !self.pending_textures.contains_key(key) {
+                self.pending_textures
+                    .insert(Arc::clone(key), pending.clone());
+            }
+        }
+        let mut offset = 0usize;
+        for batch in &recorded.batches {
+            self.ensure_batch_with(
+                batch.texture_key.clone(),
+                batch.shader_type,
+                batch.blend_mode,
+            );
+            let end = offset + batch.vertex_count as usize;
+            self.vertices
+                .extend_from_slice(&recorded.vertices[offset..end]);
+            if let Some(last) = self.draw_batches.last_mut() {
+                last.vertex_count += batch.vertex_count;
+            }
+            offset = end;
+        }
+    }
+
     #[cfg(test)]
     pub fn draw_batch_count(&self) -> usize {
         self.draw_batches.len()
@@ -1684,4 +1833,85 @@ mod tests {
             c[5]
         );
     }
+
+    fn textured_region(path: &str) -> TextureRegion {
+        TextureRegion {
+            u: 0.0,
+            v: 0.0,
+            u2: 1.0,
+            v2: 1.0,
+            region_x: 0,
+            region_y: 0,
+            region_width: 4,
+            region_height: 4,
+            texture: Some(Texture {
+                width: 4,
+                height: 4,
+                path: Some(Arc::from(path)),
+                rgba_data: Some(Arc::new(vec![0u8; 64])),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_replay_matches_recorded_draw() {
+        let region_a = textured_region("record_a");
+        let region_b = textured_region("record_b");
+        let mut batch = SpriteBatch::new();
+        batch.draw_region(&region_a, 0.0, 0.0, 4.0, 4.0);
+
+        // Record a draw that continues the open batch and starts another.
+        let mark = batch.begin_record();
+        batch.draw_region(&region_a, 10.0, 0.0, 4.0, 4.0);
+        batch.set_blend_mode(BlendMode::Additive);
+        batch.draw_region(&region_b, 20.0, 0.0, 4.0, 4.0);
+        let mut recorded = RecordedDraw::default();
+        assert!(batch.end_record(mark, &mut recorded));
+        assert_eq!(recorded.vertex_count(), 12);
+        let expected = batch.vertices()[6..].to_vec();
+
+        let mut replayed = SpriteBatch::new();
+        replayed.replay(&recorded);
+        assert_eq!(
+            bytemuck::cast_slice::<_, u8>(replayed.vertices()),
+            bytemuck::cast_slice::<_, u8>(&expected)
+        );
+        assert_eq!(replayed.draw_batch_count(), 2);
+        assert_eq!(replayed.draw_batches[1].blend_mode, BlendMode::Additive);
+        assert_eq!(replayed.blend_mode(), BlendMode::Normal);
+        assert_eq!(replayed.drain_pending_textures().len(), 2);
+    }
+
+    #[test]
+    fn test_end_record_fails_across_auto_flush() {
+        let mut batch = SpriteBatch::new();
+        let tex = Texture::default();
+        let mark = batch.begin_record();
+        for i in 0..MAX_SPRITES + 1 {
+            batch.draw_texture(&tex, i as f32, 0.0, 1.0, 1.0);
+        }
+        let mut recorded = RecordedDraw::default();
+        assert!(!batch.end_record(mark, &mut recorded));
+        assert_eq!(recorded.vertex_count(), 0);
+    }
+
+    #[test]
+    fn test_flush_reuses_segment_buffers() {
+        let mut batch = SpriteBatch::new();
+        let tex = Texture::default();
+        for frame in 0..2 {
+            for i in 0..MAX_SPRITES * 2 + 1 {
+                batch.draw_texture(&tex, i as f32, 0.0, 1.0, 1.0);
+            }
+            assert_eq!(batch.vertex_count(), (MAX_SPRITES * 2 + 1) * 6);
+            assert_eq!(batch.flushed_segments.len(), 2);
+            if frame == 1 {
+                assert!(batch.spare_segments.is_empty(), "spares are reused");
+            }
+            batch.flush();
+            assert_eq!(batch.spare_segments.len(), 2);
+            assert!(batch.spare_segments.iter().all(|s| s.vertices.is_empty()));
+        }
+    }
 }
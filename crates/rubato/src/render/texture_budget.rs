@@ -0,0 +1,180 @@
+// Byte budget with least-recently-used eviction order for texture caches.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Bytes per texel of the RGBA8 textures used throughout the renderer.
+pub const BYTES_PER_TEXEL: usize = 4;
+
+/// Memory size of an RGBA8 texture of the given dimensions.
+pub fn texture_bytes(width: u32, height: u32) -> usize {
+    width as usize * height as usize * BYTES_PER_TEXEL
+}
+
+struct BudgetEntry {
+    bytes: usize,
+    last_used: u64,
+}
+
+/// Tracks the memory of cached textures and picks the least recently used
+/// ones to evict once the total exceeds the limit. Owns no textures itself;
+/// the cache removes the returned keys from its own storage.
+pub struct TextureBudget<K> {
+    limit: usize,
+    used: usize,
+    clock: u64,
+    entries: HashMap<K, BudgetEntry>,
+}
+
+impl<K: Eq + Hash + Clone> TextureBudget<K> {
+    pub fn new(limit_bytes: usize) -> Self {
+        Self {
+            limit: limit_bytes,
+            used: 0,
+            clock: 0,
+            entries: HashMap::new(),
+        }
+    }
+
+    pub fn limit_bytes(&self) -> usize {
+        self.limit
+    }
+
+    pub fn set_limit_bytes(&mut self, limit_bytes: usize) {
+        self.limit = limit_bytes;
+    }
+
+    /// Total bytes of all tracked textures.
+    pub fn used_bytes(&self) -> usize {
+        self.used
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Whether `bytes` more would still fit in the limit.
+    pub fn fits(&self, bytes: usize) -> bool {
+        self.used.saturating_add(bytes) <= self.limit
+    }
+
+    /// Track `key` with its size, replacing a previous entry, and mark it used.
+    pub fn insert(&mut self, key: K, bytes: usize) {
+        self.clock += 1;
+        let entry = BudgetEntry {
+            bytes,
+            last_used: self.clock,
+        };
+        if let Some(old) = self.entries.insert(key, entry) {
+            self.used -= old.bytes;
+        }
+        self.used += bytes;
+    }
+
+    /// Mark `key` as used now. Returns false when it is not tracked.
+    pub fn touch(&mut self, key: &K) -> bool {
+        match self.entries.get_mut(key) {
+            Some(entry) => {
+                self.clock += 1;
+                entry.last_used = self.clock;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn remove(&mut self, key: &K) {
+        if let Some(old) = self.entries.remove(key) {
+            self.used -= old.bytes;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.used = 0;
+    }
+
+    /// Stop tracking the least recently used textures until the total fits
+    /// the limit again, and return their keys (oldest first). Entries for
+    /// which `keep` returns true are never chosen, so the result may still
+    /// leave the total over the limit.
+    pub fn evict_over_budget(&mut self, keep: impl Fn(&K) -> bool) -> Vec<K> {
+        if self.used <= self.limit {
+            return Vec::new();
+        }
+        let mut candidates: Vec<(u64, K)> = self
+            .entries
+            .iter()
+            .filter(|(key, _)| !keep(key))
+            .map(|(key, entry)| (entry.last_used, key.clone()))
+            .collect();
+        candidates.sort_unstable_by_key(|(last_used, _)| *last_used);
+        let mut evicted = Vec::new();
+        for (_, key) in candidates {
+            if self.used <= self.limit {
+                break;
+            }
+            self.remove(&key);
+            evicted.push(key);
+        }
+        evicted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_replaces_and_tracks_bytes() {
+        let mut budget = TextureBudget::new(100);
+        budget.insert("a", 40);
+        budget.insert("b", 30);
+        budget.insert("a", 10);
+        assert_eq!(budget.used_bytes(), 40);
+        assert_eq!(budget.len(), 2);
+        budget.remove(&"b");
+        assert_eq!(budget.used_bytes(), 10);
+        assert!(budget.fits(90));
+        assert!(!budget.fits(91));
+    }
+
+    #[test]
+    fn evicts_least_recently_used_until_within_limit() {
+        let mut budget = TextureBudget::new(100);
+        budget.insert("a", 40);
+        budget.insert("b", 40);
+        budget.insert("c", 40);
+        assert!(budget.touch(&"a"));
+
+        assert_eq!(budget.evict_over_budget(|_| false), vec!["b"]);
+        assert_eq!(budget.used_bytes(), 80);
+        assert!(budget.evict_over_budget(|_| false).is_empty());
+    }
+
+    #[test]
+    fn kept_entries_are_never_evicted() {
+        let mut budget = TextureBudget::new(50);
+        budget.insert("a", 40);
+        budget.insert("b", 40);
+        budget.insert("c", 40);
+
+        assert_eq!(budget.evict_over_budget(|k| *k == "a"), vec!["b", "c"]);
+        assert_eq!(budget.used_bytes(), 40);
+
+        budget.set_limit_bytes(10);
+        assert!(budget.evict_over_budget(|k| *k == "a").is_empty());
+        assert_eq!(budget.used_bytes(), 40);
+    }
+
+    #[test]
+    fn touch_unknown_key_is_ignored() {
+        let mut budget: TextureBudget<&str> = TextureBudget::new(10);
+        assert!(!budget.touch(&"missing"));
+        assert!(budget.is_empty());
+    }
+}
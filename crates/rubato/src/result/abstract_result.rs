@@ -4,8 +4,10 @@
 use crate::core::clear_type::ClearType;
 use crate::core::score_data::ScoreData;
 use crate::core::score_data_property::ScoreDataProperty;
+use crate::skin::replay_data::ReplaySlotInfo;
 
 use super::RankingData;
+use crate::ir::ir_player_data::IRPlayerData;
 
 /// Replay data status
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -84,6 +86,21 @@ impl ReplayAutoSaveConstraint {
             ReplayAutoSaveConstraint::Always,
         ]
     }
+
+    /// Constraint of a rotating auto-save policy (`REPLAY_AUTOSAVE_*`), or
+    /// `None` when each slot uses its own constraint.
+    pub fn for_policy(policy: i32) -> Option<ReplayAutoSaveConstraint> {
+        use crate::skin::player_config::{
+            REPLAY_AUTOSAVE_ALWAYS, REPLAY_AUTOSAVE_CLEAR_UPDATE, REPLAY_AUTOSAVE_PERSONAL_BEST,
+        };
+        match policy {
+            REPLAY_AUTOSAVE_ALWAYS => Some(ReplayAutoSaveConstraint::Always),
+            REPLAY_AUTOSAVE_PERSONAL_BEST => Some(ReplayAutoSaveConstraint::ScoreUpdate),
+            REPLAY_AUTOSAVE_CLEAR_UPDATE => Some(ReplayAutoSaveConstraint::ClearUpdate),
+            crate::skin::player_config::REPLAY_AUTOSAVE_PER_SLOT => None,
+            _ => Some(ReplayAutoSaveConstraint::Nothing),
+        }
+    }
 }
 
 /// Timing distribution
@@ -164,6 +181,40 @@ pub const STATE_IR_FINISHED: i32 = 2;
 
 pub const REPLAY_SIZE: usize = 4;
 
+/// Number of rivals shown next to the IR ranking
+pub const IR_RIVAL_SIZE: usize = 5;
+
+/// IR ranking entry of a rival, compared against the new score on the result screen
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IRRivalScore {
+    pub name: String,
+    /// IR rank of the rival's score
+    pub rank: i32,
+    pub exscore: i32,
+}
+
+impl IRRivalScore {
+    /// Pick the ranking entries of the given rivals, best first.
+    pub fn collect(ranking: &RankingData, rivals: &[IRPlayerData]) -> Vec<IRRivalScore> {
+        (0..ranking.total_player())
+            .filter_map(|i| {
+                let score = ranking.score(i)?;
+                rivals
+                    .iter()
+                    .any(|r| {
+                        !score.player.is_empty() && (score.player == r.name || score.player == r.id)
+                    })
+                    .then(|| IRRivalScore {
+                        name: score.player.clone(),
+                        rank: ranking.score_ranking(i),
+                        exscore: score.exscore(),
+                    })
+            })
+            .take(IR_RIVAL_SIZE)
+            .collect()
+    }
+}
+
 /// Shared data for AbstractResult (Java abstract class fields)
 pub struct AbstractResultData {
     /// State
@@ -182,6 +233,13 @@ pub struct AbstractResultData {
     pub dist_range: i32,
     /// Replay data status for each replay slot
     pub save_replay: [ReplayStatus; REPLAY_SIZE],
+    /// Metadata of the replay stored in each slot
+    pub replay_slots: [Option<ReplaySlotInfo>; REPLAY_SIZE],
+    /// Cursor of the replay save prompt: a slot index, or `REPLAY_SIZE` to skip.
+    /// `None` while no prompt is shown.
+    pub replay_prompt: Option<usize>,
+    /// Rivals found in the IR ranking fetched after score submission
+    pub ir_rivals: Vec<IRRivalScore>,
     /// Gauge type
     pub gauge_type: i32,
     /// Old score data
@@ -206,6 +264,9 @@ impl AbstractResultData {
             timing_distribution: TimingDistribution::new(dist_range),
             dist_range,
             save_replay: [ReplayStatus::NotExist; REPLAY_SIZE],
+            replay_slots: [None; REPLAY_SIZE],
+            replay_prompt: None,
+            ir_rivals: Vec::new(),
             gauge_type: 0,
             oldscore: ScoreData::default(),
             score: ScoreDataProperty::new(),
@@ -230,6 +291,13 @@ impl AbstractResultData {
         self.save_replay.get(index).copied()
     }
 
+    /// Replay that saving at the prompt cursor would overwrite.
+    pub fn replay_prompt_overwrite(&self) -> Option<&ReplaySlotInfo> {
+        self.replay_prompt
+            .and_then(|slot| self.replay_slots.get(slot))
+            .and_then(Option::as_ref)
+    }
+
     pub fn gauge_type(&self) -> i32 {
         self.gauge_type
     }
@@ -7,6 +7,7 @@ use std::sync::Arc;
 use log::{info, warn};
 
 use crate::core::clear_type::ClearType;
+use crate::core::earned_trophy::EarnedTrophy;
 use crate::core::score_data::ScoreData;
 use crate::core::system_sound_manager::SoundType;
 use crate::skin::skin_property::*;
@@ -26,6 +27,7 @@ use crate::core::app_context::GameContext;
 use crate::core::ir_config::{IR_SEND_ALWAYS, IR_SEND_COMPLETE_SONG, IR_SEND_UPDATE_SCORE};
 use crate::core::main_state::{MainStateType, StateTransition};
 use crate::core::timer_manager::TimerManager;
+use crate::ir::ir_player_profile::IRPlayerProfile;
 use crate::skin::property_snapshot::PropertySnapshot;
 use crate::skin::skin_action_queue::SkinActionQueue;
 use crate::skin::timer_id::TimerId;
@@ -56,6 +58,8 @@ pub struct CourseResult {
     pub main: MainController,
     pub resource: PlayerResource,
     ir_send_status: Vec<CourseIRSendStatus>,
+    /// Trophy or certification this result newly earned, sent to IR as a profile update.
+    earned_trophy: Option<EarnedTrophy>,
     property: ResultKeyProperty,
     skin: Option<ResultSkinData>,
     /// Receiver for async IR results (non-blocking).
@@ -100,6 +104,7 @@ impl CourseResult {
             main,
             resource,
             ir_send_status: Vec::new(),
+            earned_trophy: None,
             property: ResultKeyProperty::beat_7k(),
             skin: None,
             ir_rx: None,
@@ -273,6 +278,19 @@ impl CourseResult {
         let course_data_for_ranking = self.resource.course_data().cloned();
         let oldscore_exscore = self.data.oldscore.exscore();
         let newscore_clone = newscore.clone();
+        let profile = self.earned_trophy.take().map(|_| {
+            let trophies = self.main.play_data_accessor().read_earned_trophies();
+            IRPlayerProfile::new(&trophies)
+        });
+        let profile_connections: Vec<_> = if profile.is_some() {
+            self.main
+                .ir_status()
+                .iter()
+                .map(|s| s.connection.clone())
+                .collect()
+        } else {
+            Vec::new()
+        };
 
         let (tx, rx) = std::sync::mpsc::channel();
 
@@ -292,6 +310,15 @@ impl CourseResult {
                 statuses.remove(idx);
             }
 
+            if let Some(ref profile) = profile {
+                for conn in &profile_connections {
+                    let response = conn.send_player_profile(profile);
+                    if !response.is_succeeded() {
+                        warn!("IR profile update failed: {}", response.message);
+                    }
+                }
+            }
+
             // Fetch ranking from IR
             let mut ranking_result = None;
             if irsend > 0
@@ -580,8 +607,7 @@ impl CourseResult {
             if fadeout_time > skin_fadeout {
                 self.pending_stop_all_notes = true;
 
-                self.pending_state_change =
-                    Some(crate::core::main_state::MainStateType::MusicSelect);
+                self.pending_state_change = Some(self.fadeout_transition());
             }
         } else {
             let skin_scene = self.skin.as_ref().map(|s| s.scene() as i64).unwrap_or(0);
@@ -592,6 +618,43 @@ impl CourseResult {
         }
     }
 
+    /// State to enter once the fadeout finishes: the whole course again (replay
+    /// key), the failed stage alone (replay-different key) or music select.
+    fn fadeout_transition(&mut self) -> MainStateType {
+        if self.resource.play_mode().mode != BMSPlayerModeType::Play {
+            return MainStateType::MusicSelect;
+        }
+        let key = self.input_snapshot.as_ref().and_then(|snapshot| {
+            (0..self.property.assign_length())
+                .filter(|&i| snapshot.key_state.get(i as usize).copied().unwrap_or(false))
+                .filter_map(|i| self.property.assign(i))
+                .find(|key| matches!(key, ResultKey::ReplaySame | ResultKey::ReplayDifferent))
+        });
+        match key {
+            Some(ResultKey::ReplaySame) if self.resource.restart_course() => {
+                info!("Retry course");
+                MainStateType::Play
+            }
+            Some(ResultKey::ReplayDifferent)
+                if self
+                    .failed_stage()
+                    .is_some_and(|stage| self.resource.retry_course_stage(stage)) =>
+            {
+                info!("Retry failed course stage");
+                MainStateType::Play
+            }
+            _ => MainStateType::MusicSelect,
+        }
+    }
+
+    /// Index of the stage the course was failed on.
+    fn failed_stage(&self) -> Option<usize> {
+        self.resource
+            .course_stage_scores()
+            .iter()
+            .position(|score| score.clear == ClearType::Failed.id())
+    }
+
     fn do_input(&mut self) {
         let snapshot = match self.input_snapshot {
             Some(ref s) => s,
@@ -693,6 +756,7 @@ impl CourseResult {
             .score
             .set_target_score(self.data.oldscore.exscore(), target_exscore, total_notes);
         self.data.score.update_score(Some(&newscore));
+        self.data.score.rival = self.resource.target_score_data().cloned();
 
         if self.resource.play_mode().mode == BMSPlayerModeType::Play
             && !(FreqTrainerMenu::is_freq_trainer_enabled() && FreqTrainerMenu::is_freq_negative())
@@ -706,6 +770,12 @@ impl CourseResult {
                     &self.resource.constraint(),
                     self.resource.is_update_course_score(),
                 );
+                if let Some(course) = self.resource.course_data() {
+                    self.earned_trophy = self
+                        .main
+                        .play_data_accessor()
+                        .write_earned_trophy(course, models, &newscore);
+                }
             }
         } else {
             info!(
@@ -886,6 +956,7 @@ impl CourseResult {
 
         // Player / course data
         s.player_data = Some(*self.resource.player_data());
+        s.today_player_data = Some(*self.resource.today_player_data());
         s.is_course_mode = self.resource.course_data().is_some();
         s.course_index = self.resource.course_index();
         s.course_song_count = self.resource.course_data().map_or(0, |cd| cd.hash.len());
@@ -949,7 +1020,7 @@ impl CourseResult {
 
         // Result clear/fail booleans
         let course_score = self.resource.course_score_data();
-        for &bid in &[42, 43, 90, 91, 1046] {
+        for &bid in &[42, 43, 90, 91, 601, 602, 1046, 1105, 1106, 1107] {
             s.booleans.insert(
                 bid,
                 shared_render_context::boolean_value(&self.data, course_score, bid),
@@ -985,10 +1056,10 @@ impl CourseResult {
             71, 72, 74, 75, 76, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 100, 101, 102, 103, 105,
             106, 108, 110, 111, 112, 113, 114, 115, 116, 121, 122, 123, 128, 150, 151, 152, 153,
             154, 155, 156, 157, 158, 170, 171, 172, 173, 174, 175, 176, 177, 178, 179, 182, 183,
-            184, 200, 271, 280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 350, 370, 371, 372,
-            373, 374, 375, 376, 377, 380, 381, 382, 383, 384, 385, 386, 387, 388, 389, 390, 391,
-            392, 393, 394, 395, 396, 397, 398, 399, 410, 411, 412, 413, 414, 415, 416, 417, 418,
-            419, 423, 424, 425,
+            184, 189, 200, 271, 272, 275, 276, 280, 281, 282, 283, 284, 285, 286, 287, 288, 289,
+            350, 370, 371, 372, 373, 374, 375, 376, 377, 380, 381, 382, 383, 384, 385, 386, 387,
+            388, 389, 390, 391, 392, 393, 394, 395, 396, 397, 398, 399, 410, 411, 412, 413, 414,
+            415, 416, 417, 418, 419, 423, 424, 425,
         ] {
             s.integers.insert(
                 iid,
@@ -1002,6 +1073,17 @@ impl CourseResult {
                 ),
             );
         }
+        // Stage breakdown (516-545)
+        for iid in NUMBER_COURSE_STAGE1_EXSCORE..=NUMBER_COURSE_STAGE10_GAUGE {
+            s.integers.insert(
+                iid,
+                shared_render_context::course_stage_value(
+                    &self.resource,
+                    self.data.gauge_type,
+                    iid,
+                ),
+            );
+        }
 
         // Image index: lnmode override (308)
         if let Some(song) = self.resource.songdata()
@@ -1152,6 +1234,10 @@ impl crate::core::main_state::MainState for CourseResult {
         self.resource.groove_gauge().map(|g| g.value())
     }
 
+    fn best_score(&self) -> Option<crate::core::score_data::ScoreData> {
+        Some(self.data.oldscore.clone())
+    }
+
     fn create(&mut self) {
         self.do_create();
     }
@@ -1223,7 +1309,7 @@ impl crate::core::main_state::MainState for CourseResult {
             if fadeout_time > skin_fadeout {
                 ctx.stop_all_notes();
 
-                return StateTransition::ChangeTo(MainStateType::MusicSelect);
+                return StateTransition::ChangeTo(self.fadeout_transition());
             }
         } else {
             let skin_scene = self.skin.as_ref().map(|s| s.scene() as i64).unwrap_or(0);
@@ -1457,6 +1543,14 @@ impl crate::core::main_state::MainState for CourseResult {
         self.resource.take_inner()
     }
 
+    fn player_resource(&self) -> Option<&crate::core::player_resource::PlayerResource> {
+        Some(self.resource.inner())
+    }
+
+    fn previous_clear_type(&self) -> Option<i32> {
+        Some(self.data.oldscore.clear)
+    }
+
     fn shutdown(&mut self) {
         self.shutdown();
     }
@@ -1490,6 +1584,105 @@ mod tests {
         )
     }
 
+    fn make_two_stage_course_result(stage_clears: [ClearType; 2]) -> CourseResult {
+        let dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../test-bms");
+        let files = [dir.join("minimal_7k.bms"), dir.join("7key_test.bms")];
+        let mut core = crate::core::player_resource::PlayerResource::new(
+            crate::skin::config::Config::default(),
+            crate::skin::player_config::PlayerConfig::default(),
+        );
+        assert!(core.set_course_bms_files(&files));
+        assert!(core.set_bms_file(&files[0], crate::result::BMSPlayerMode::PLAY));
+        for (i, clear) in stage_clears.into_iter().enumerate() {
+            let mut score = ScoreData {
+                clear: clear.id(),
+                ..Default::default()
+            };
+            score.judge_counts.epg = 10 * (i as i32 + 1);
+            core.add_course_stage_score(score);
+            core.add_course_gauge(vec![vec![50.0, 0.0], vec![100.0, 72.5]]);
+        }
+        core.set_course_score_data(ScoreData::default());
+        let resource = PlayerResource::new(
+            core,
+            crate::result::BMSPlayerMode::new(BMSPlayerModeType::Play),
+        );
+        CourseResult::new(
+            MainController::new(crate::skin::config::Config::default(), make_ranking_cache()),
+            resource,
+            crate::core::timer_manager::TimerManager::new(),
+        )
+    }
+
+    #[test]
+    fn test_course_stage_values_show_each_stage() {
+        let mut cr = make_two_stage_course_result([ClearType::Hard, ClearType::Failed]);
+        cr.data.gauge_type = 1;
+        let value = |cr: &CourseResult, id| {
+            shared_render_context::course_stage_value(&cr.resource, cr.data.gauge_type, id)
+        };
+
+        assert_eq!(value(&cr, NUMBER_COURSE_STAGE1_EXSCORE), 20);
+        assert_eq!(value(&cr, NUMBER_COURSE_STAGE1_EXSCORE + 1), 40);
+        assert_eq!(value(&cr, NUMBER_COURSE_STAGE1_EXSCORE + 2), i32::MIN);
+        assert_eq!(value(&cr, NUMBER_COURSE_STAGE1_CLEAR), ClearType::Hard.id());
+        assert_eq!(
+            value(&cr, NUMBER_COURSE_STAGE1_CLEAR + 1),
+            ClearType::Failed.id()
+        );
+        assert_eq!(value(&cr, NUMBER_COURSE_STAGE1_GAUGE), 72);
+        assert_eq!(value(&cr, NUMBER_COURSE_STAGE10_GAUGE), i32::MIN);
+    }
+
+    #[test]
+    fn test_fadeout_without_retry_key_returns_to_select() {
+        let mut cr = make_two_stage_course_result([ClearType::Hard, ClearType::Failed]);
+        cr.input_snapshot = Some(crate::input::input_snapshot::InputSnapshot::default());
+
+        assert_eq!(cr.fadeout_transition(), MainStateType::MusicSelect);
+        assert_eq!(cr.resource.course_stage_scores().len(), 2);
+    }
+
+    #[test]
+    fn test_fadeout_with_replay_key_restarts_course() {
+        let mut cr = make_two_stage_course_result([ClearType::Hard, ClearType::Failed]);
+        let mut snapshot = crate::input::input_snapshot::InputSnapshot::default();
+        snapshot.key_state[6] = true;
+        cr.input_snapshot = Some(snapshot);
+
+        assert_eq!(cr.fadeout_transition(), MainStateType::Play);
+        assert_eq!(cr.resource.course_index(), 0);
+        assert!(cr.resource.course_stage_scores().is_empty());
+        assert!(cr.resource.course_score_data().is_none());
+    }
+
+    #[test]
+    fn test_fadeout_with_replay_different_key_retries_failed_stage() {
+        let mut cr = make_two_stage_course_result([ClearType::Hard, ClearType::Failed]);
+        let mut snapshot = crate::input::input_snapshot::InputSnapshot::default();
+        snapshot.key_state[4] = true;
+        cr.input_snapshot = Some(snapshot);
+
+        assert_eq!(cr.fadeout_transition(), MainStateType::Play);
+        assert!(cr.resource.course_bms_models().is_none());
+        assert!(
+            cr.resource
+                .songdata()
+                .and_then(|s| s.file.path())
+                .is_some_and(|p| p.ends_with("7key_test.bms"))
+        );
+    }
+
+    #[test]
+    fn test_fadeout_with_replay_different_key_without_failed_stage() {
+        let mut cr = make_two_stage_course_result([ClearType::Hard, ClearType::Normal]);
+        let mut snapshot = crate::input::input_snapshot::InputSnapshot::default();
+        snapshot.key_state[4] = true;
+        cr.input_snapshot = Some(snapshot);
+
+        assert_eq!(cr.fadeout_transition(), MainStateType::MusicSelect);
+    }
+
     fn make_course_result_for_mouse() -> CourseResult {
         let config = make_test_config("course-result");
         let main = MainController::new(config, make_ranking_cache());
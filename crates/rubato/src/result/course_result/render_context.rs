@@ -228,6 +228,9 @@ impl crate::skin::skin_render_context::SkinRenderContext for CourseResultRenderC
             311 => self
                 .current_play_config_ref()
                 .map_or(i32::MIN, |pc| ((pc.hispeed * 100.0) as i32) % 100),
+            516..=545 => {
+                shared_render_context::course_stage_value(self.resource, self.data.gauge_type, id)
+            }
             _ => {
                 let playtime = self.resource.player_data().playtime;
                 shared_render_context::integer_value(
@@ -285,11 +288,13 @@ impl crate::skin::skin_render_context::SkinRenderContext for CourseResultRenderC
                 self.resource.play_mode().mode == crate::core::bms_player_mode::Mode::Autoplay
                     || self.resource.play_mode().mode == crate::core::bms_player_mode::Mode::Replay
             }
-            42 | 43 | 90 | 91 | 1046 => shared_render_context::boolean_value(
-                self.data,
-                self.resource.course_score_data(),
-                id,
-            ),
+            42 | 43 | 90 | 91 | 601 | 602 | 1046 | 1105..=1107 => {
+                shared_render_context::boolean_value(
+                    self.data,
+                    self.resource.course_score_data(),
+                    id,
+                )
+            }
             _ => self.default_boolean_value(id),
         }
     }
@@ -581,6 +586,11 @@ impl crate::skin::skin_render_context::SkinRenderContext for CourseResultMouseCo
             311 => self
                 .current_play_config_ref()
                 .map_or(i32::MIN, |pc| ((pc.hispeed * 100.0) as i32) % 100),
+            516..=545 => shared_render_context::course_stage_value(
+                &self.result.resource,
+                self.result.data.gauge_type,
+                id,
+            ),
             _ => {
                 let playtime = self.result.resource.player_data().playtime;
                 shared_render_context::integer_value(
@@ -645,11 +655,13 @@ impl crate::skin::skin_render_context::SkinRenderContext for CourseResultMouseCo
                     || self.result.resource.play_mode().mode
                         == crate::core::bms_player_mode::Mode::Replay
             }
-            42 | 43 | 90 | 91 | 1046 => shared_render_context::boolean_value(
-                &self.result.data,
-                self.result.resource.course_score_data(),
-                id,
-            ),
+            42 | 43 | 90 | 91 | 601 | 602 | 1046 | 1105..=1107 => {
+                shared_render_context::boolean_value(
+                    &self.result.data,
+                    self.result.resource.course_score_data(),
+                    id,
+                )
+            }
             _ => self.default_boolean_value(id),
         }
     }
@@ -8,7 +8,9 @@ use std::sync::Arc;
 
 use crate::ir::ir_account::IRAccount;
 use crate::ir::ir_connection::IRConnection;
-use crate::ir::ir_connection_manager::IRConnectionManager;
+use crate::ir::ir_connection_manager::{IRConnectedEndpoint, IRConnectionManager};
+use crate::ir::ir_player_data::IRPlayerData;
+use crate::ir::ir_response::IRResponse;
 use crate::skin::player_config::PlayerConfig;
 
 use super::ir_status::IRStatus;
@@ -27,6 +29,9 @@ pub fn initialize_ir_config(player: &PlayerConfig) -> Vec<IRStatus> {
             Some(c) => c,
             None => continue,
         };
+        if !irconfig.enabled {
+            continue;
+        }
         let ir: Option<Box<dyn IRConnection + Send + Sync>> =
             IRConnectionManager::ir_connection(&irconfig.irname);
         if let Some(ir) = ir {
@@ -52,9 +57,37 @@ pub fn initialize_ir_config(player: &PlayerConfig) -> Vec<IRStatus> {
         }
     }
 
+    IRConnectionManager::set_connected(
+        ir_array
+            .iter()
+            .map(|status| IRConnectedEndpoint {
+                name: status.config.irname.clone(),
+                player: status.player.name.clone(),
+                home: IRConnectionManager::home_url(&status.config.irname),
+            })
+            .collect(),
+    );
     ir_array
 }
 
+/// Log in to `irname` with the given credentials and return the server's response.
+///
+/// Used by the launcher to check an account before it is saved; no IRStatus
+/// is kept.
+pub fn test_ir_login(irname: &str, userid: &str, password: &str) -> IRResponse<IRPlayerData> {
+    let Some(ir) = IRConnectionManager::ir_connection(irname) else {
+        return IRResponse::failure(format!("IR \"{}\" is not available", irname));
+    };
+    if userid.is_empty() || password.is_empty() {
+        return IRResponse::failure("user ID and password are required".to_string());
+    }
+    ir.login(&IRAccount::new(
+        userid.to_string(),
+        password.to_string(),
+        String::new(),
+    ))
+}
+
 #[cfg(test)]
 #[allow(clippy::field_reassign_with_default)]
 mod tests {
@@ -120,6 +153,59 @@ mod tests {
         }
     }
 
+    /// Mock IRConnection whose login() succeeds with the given user ID as the player name.
+    struct SucceedingLoginIR;
+
+    impl IRConnection for SucceedingLoginIR {
+        fn login(&self, account: &IRAccount) -> IRResponse<IRPlayerData> {
+            let player = IRPlayerData::new(account.id.clone(), account.id.clone(), String::new());
+            IRResponse::success("OK".to_string(), player)
+        }
+
+        fn get_rivals(&self) -> IRResponse<Vec<IRPlayerData>> {
+            IRResponse::failure("not implemented".to_string())
+        }
+        fn get_table_datas(&self) -> IRResponse<Vec<IRTableData>> {
+            IRResponse::failure("not implemented".to_string())
+        }
+        fn get_play_data(
+            &self,
+            _player: Option<&IRPlayerData>,
+            _chart: Option<&IRChartData>,
+        ) -> IRResponse<Vec<IRScoreData>> {
+            IRResponse::failure("not implemented".to_string())
+        }
+        fn get_course_play_data(
+            &self,
+            _player: Option<&IRPlayerData>,
+            _course: &IRCourseData,
+        ) -> IRResponse<Vec<IRScoreData>> {
+            IRResponse::failure("not implemented".to_string())
+        }
+        fn send_play_data(&self, _model: &IRChartData, _score: &IRScoreData) -> IRResponse<()> {
+            IRResponse::failure("not implemented".to_string())
+        }
+        fn send_course_play_data(
+            &self,
+            _course: &IRCourseData,
+            _score: &IRScoreData,
+        ) -> IRResponse<()> {
+            IRResponse::failure("not implemented".to_string())
+        }
+        fn get_song_url(&self, _chart: &IRChartData) -> Option<String> {
+            None
+        }
+        fn get_course_url(&self, _course: &IRCourseData) -> Option<String> {
+            None
+        }
+        fn get_player_url(&self, _player: &IRPlayerData) -> Option<String> {
+            None
+        }
+        fn name(&self) -> &str {
+            "SucceedingLoginIR"
+        }
+    }
+
     #[test]
     fn test_initialize_ir_config_empty_config() {
         let player = PlayerConfig::default();
@@ -171,4 +257,54 @@ mod tests {
         // login() returns failure, so no IRStatus entries should be added
         assert!(result.is_empty());
     }
+
+    #[test]
+    fn test_initialize_ir_config_skips_disabled_entries() {
+        use crate::skin::ir_config::IRConfig;
+
+        register_ir_connections(vec![IRConnectionEntry {
+            name: "SucceedingLoginIR".to_string(),
+            home: None,
+            factory: Box::new(|| Box::new(SucceedingLoginIR)),
+        }]);
+
+        let mut player = PlayerConfig::default();
+        let mut ir = IRConfig::default();
+        ir.irname = "SucceedingLoginIR".to_string();
+        ir.userid = "testuser".to_string();
+        ir.password = "testpass".to_string();
+        player.irconfig = vec![Some(ir.clone())];
+        assert_eq!(initialize_ir_config(&player).len(), 1);
+
+        ir.enabled = false;
+        player.irconfig = vec![Some(ir)];
+        assert!(initialize_ir_config(&player).is_empty());
+    }
+
+    #[test]
+    fn test_ir_login_reports_server_response() {
+        register_ir_connections(vec![
+            IRConnectionEntry {
+                name: "FailingLoginIR".to_string(),
+                home: None,
+                factory: Box::new(|| Box::new(FailingLoginIR)),
+            },
+            IRConnectionEntry {
+                name: "SucceedingLoginIR".to_string(),
+                home: None,
+                factory: Box::new(|| Box::new(SucceedingLoginIR)),
+            },
+        ]);
+
+        let failed = test_ir_login("FailingLoginIR", "user", "pass");
+        assert!(!failed.is_succeeded());
+        assert_eq!(failed.message, "authentication failed");
+
+        let ok = test_ir_login("SucceedingLoginIR", "user", "pass");
+        assert!(ok.is_succeeded());
+        assert_eq!(ok.data().map(|p| p.name.as_str()), Some("user"));
+
+        assert!(!test_ir_login("SucceedingLoginIR", "", "pass").is_succeeded());
+        assert!(!test_ir_login("UnknownIR", "user", "pass").is_succeeded());
+    }
 }
@@ -1,7 +1,7 @@
 // IR resend background loop
 // Translated from: MainController.java lines 518-548
 
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 use std::thread::JoinHandle;
 
@@ -15,6 +15,10 @@ use super::ir_send_status::IRSendStatusMain;
 /// tests that need isolation should pass a local Arc<Mutex<Vec>> to start_ir_resend_thread() directly.
 static SHARED_IR_STATUSES: OnceLock<Arc<Mutex<Vec<IRSendStatusMain>>>> = OnceLock::new();
 
+/// Bumped by each "retry now" request. Resend threads send every pending score,
+/// ignoring the backoff, when it changes.
+static RETRY_NOW_REQUESTS: AtomicU64 = AtomicU64::new(0);
+
 /// Get the shared IR send status list.
 pub fn shared_ir_statuses() -> Arc<Mutex<Vec<IRSendStatusMain>>> {
     SHARED_IR_STATUSES
@@ -22,6 +26,16 @@ pub fn shared_ir_statuses() -> Arc<Mutex<Vec<IRSendStatusMain>>> {
         .clone()
 }
 
+/// Number of scores waiting in the shared list to be resent.
+pub fn pending_submission_count() -> usize {
+    crate::skin::sync_utils::lock_or_recover(&shared_ir_statuses()).len()
+}
+
+/// Make resend threads retry every pending score without waiting for the backoff.
+pub fn request_retry_now() {
+    RETRY_NOW_REQUESTS.fetch_add(1, Ordering::AcqRel);
+}
+
 /// Concrete implementation of IrResendService.
 /// Starts the background resend thread using the shared status list.
 pub struct IrResendServiceImpl {
@@ -87,6 +101,8 @@ impl Drop for IrResendServiceImpl {
 /// 3. Removes successful sends and those exceeding retry limit
 /// 4. Sleeps 3 seconds between iterations
 ///
+/// A [`request_retry_now`] cuts the sleep short and skips the backoff once.
+///
 /// `ir_send_status` is the shared list of pending sends.
 /// `ir_send_count` is the maximum retry count from config.
 /// `shutdown_flag` is checked each iteration to allow graceful shutdown.
@@ -99,10 +115,14 @@ pub fn start_ir_resend_thread(
 ) -> JoinHandle<()> {
     let shutdown = Arc::clone(shutdown_flag);
     std::thread::spawn(move || {
+        let mut retry_seen = RETRY_NOW_REQUESTS.load(Ordering::Acquire);
         loop {
             if shutdown.load(Ordering::Acquire) {
                 break;
             }
+            let retry_requested = RETRY_NOW_REQUESTS.load(Ordering::Acquire);
+            let retry_now = retry_requested != retry_seen;
+            retry_seen = retry_requested;
 
             let now = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
@@ -124,7 +144,7 @@ pub fn start_ir_resend_thread(
                     .unwrap_or(i64::MAX / 1000)
                     .saturating_mul(1000);
                 // Java: if (score.retry != 0 && now - score.lastTry >= timeUntilNextTry)
-                if score.retry != 0 && now - score.last_try >= time_until_next_try {
+                if score.retry != 0 && (retry_now || now - score.last_try >= time_until_next_try) {
                     score.send();
                 }
             }
@@ -161,7 +181,9 @@ pub fn start_ir_resend_thread(
             // Java: Thread.sleep(3000, 0);
             // Sleep in small increments so we can respond to shutdown quickly.
             for _ in 0..30 {
-                if shutdown.load(Ordering::Acquire) {
+                if shutdown.load(Ordering::Acquire)
+                    || RETRY_NOW_REQUESTS.load(Ordering::Acquire) != retry_seen
+                {
                     break;
                 }
                 std::thread::sleep(std::time::Duration::from_millis(100));
@@ -415,6 +437,39 @@ mod tests {
         handle.join().expect("thread should not panic");
     }
 
+    #[test]
+    fn test_resend_thread_retry_now_skips_backoff() {
+        // retry=1 with last_try=now: the 4s backoff holds the resend back
+        // until a retry is requested.
+        let mock = Arc::new(MockIRSuccess::new());
+        let conn: Arc<dyn IRConnection + Send + Sync> = mock.clone();
+        let song = SongData::default();
+        let score = crate::core::score_data::ScoreData::default();
+        let mut status = IRSendStatusMain::new(conn, &song, &score);
+        status.retry = 1;
+        status.last_try = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as i64;
+
+        let ir_send_status = Arc::new(Mutex::new(vec![status]));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let handle = start_ir_resend_thread(Arc::clone(&ir_send_status), 5, &shutdown);
+
+        std::thread::sleep(std::time::Duration::from_millis(300));
+        assert_eq!(mock.send_count.load(Ordering::SeqCst), 0);
+        assert_eq!(ir_send_status.lock().expect("mutex poisoned").len(), 1);
+
+        request_retry_now();
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        assert_eq!(mock.send_count.load(Ordering::SeqCst), 1);
+        assert!(ir_send_status.lock().expect("mutex poisoned").is_empty());
+
+        shutdown.store(true, Ordering::Release);
+        handle.join().expect("thread should not panic");
+    }
+
     #[test]
     fn test_ir_resend_service_impl_stop_joins_thread() {
         let service = IrResendServiceImpl::new(5);
@@ -1,10 +1,13 @@
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::imgui_notify::{ImGuiNotify, ToastType};
 use crate::ir::ir_chart_data::IRChartData;
 use crate::ir::ir_connection::IRConnection;
+use crate::ir::ir_connection_manager::{IRConnectionManager, IRSubmissionResult};
 use crate::ir::ir_score_data::IRScoreData;
 use crate::skin::song_data::SongData;
+use crate::tr;
 
 /// MainController.IRSendStatus — handles IR score submission
 ///
@@ -47,12 +50,40 @@ impl IRSendStatusMain {
         let score_data = IRScoreData::new(&self.score);
         let send1 = self.connection.send_play_data(&chart_data, &score_data);
         self.retry += 1;
+        IRConnectionManager::record_submission(IRSubmissionResult {
+            ir: self.connection.name().to_string(),
+            title: self.songdata.metadata.title.clone(),
+            succeeded: send1.is_succeeded(),
+            message: send1.message.clone(),
+            time: self.last_try,
+        });
         if send1.is_succeeded() {
-            log::info!("IRスコア送信完了 : {}", self.songdata.metadata.title);
+            let message = tr!(
+                "notify-ir-sent",
+                title = self.songdata.metadata.title.as_str(),
+                ir = self.connection.name()
+            );
+            match self.connection.get_song_url(&chart_data) {
+                Some(url) => ImGuiNotify::with_action(
+                    ToastType::Success,
+                    &message,
+                    &tr!("notify-open-ranking"),
+                    Box::new(move || {
+                        if let Err(e) = open::that(&url) {
+                            log::warn!("Failed to open {}: {}", url, e);
+                        }
+                    }),
+                ),
+                None => ImGuiNotify::success(&message),
+            }
             self.is_sent = true;
             true
         } else {
-            log::warn!("IRスコア送信失敗 : {}", send1.message);
+            ImGuiNotify::warning(&tr!(
+                "notify-ir-send-failed",
+                message = send1.message.to_string(),
+                ir = self.connection.name().to_string()
+            ));
             false
         }
     }
@@ -83,6 +83,11 @@ impl MainController {
         lock_or_recover(&self.ir_send_statuses)
     }
 
+    /// Shared handle to the resend queue, for threads that queue failed sends.
+    pub fn ir_send_queue(&self) -> std::sync::Arc<std::sync::Mutex<Vec<IRSendStatusMain>>> {
+        std::sync::Arc::clone(&self.ir_send_statuses)
+    }
+
     pub fn play_data_accessor(&self) -> &PlayDataAccessor {
         &self.play_data_accessor
     }
@@ -13,8 +13,8 @@ use crate::play::groove_gauge;
 use crate::skin::skin_property::*;
 
 use super::abstract_result::{
-    AbstractResultData, REPLAY_SIZE, ReplayAutoSaveConstraint, ReplayStatus, STATE_IR_FINISHED,
-    STATE_IR_PROCESSING, STATE_OFFLINE,
+    AbstractResultData, IRRivalScore, REPLAY_SIZE, ReplayAutoSaveConstraint, ReplayStatus,
+    STATE_IR_FINISHED, STATE_IR_PROCESSING, STATE_OFFLINE,
 };
 use super::ir_send_status::IRSendStatusMain;
 use super::result_key_property::{ResultKey, ResultKeyProperty};
@@ -25,6 +25,7 @@ use super::{
 use crate::core::ir_config::{IR_SEND_ALWAYS, IR_SEND_COMPLETE_SONG, IR_SEND_UPDATE_SCORE};
 use crate::skin::property_snapshot::PropertySnapshot;
 use crate::skin::skin_action_queue::SkinActionQueue;
+use crate::skin::sync_utils::lock_or_recover;
 use crate::skin::timer_id::TimerId;
 
 #[cfg(test)]
@@ -36,9 +37,22 @@ type MusicIrResult = (
     bool,
     bool,
     Option<Vec<crate::ir::ir_score_data::IRScoreData>>,
+    Vec<crate::ir::ir_player_data::IRPlayerData>,
     Option<ScoreData>,
 );
 
+/// Key input on the replay save prompt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ReplayPromptAction {
+    None,
+    /// Move the cursor to the next slot (wrapping through "skip").
+    Next,
+    /// Save to the slot under the cursor, or close the prompt on "skip".
+    Confirm,
+    /// Save to the given slot.
+    Save(usize),
+}
+
 pub struct MusicResult {
     pub data: AbstractResultData,
     pub main_data: MainStateData,
@@ -74,6 +88,10 @@ pub struct MusicResult {
     pending_save_last_recording: Vec<String>,
     /// Read-only input snapshot for the current frame.
     input_snapshot: Option<crate::input::input_snapshot::InputSnapshot>,
+    /// Whether the QUICK RETRY hotkey is held this frame.
+    quick_retry_held: bool,
+    /// QUICK RETRY was pressed: replay the same chart when the fadeout ends.
+    quick_retry_requested: bool,
 }
 
 impl MusicResult {
@@ -97,6 +115,8 @@ impl MusicResult {
             pending_state_change: None,
             pending_save_last_recording: Vec::new(),
             input_snapshot: None,
+            quick_retry_held: false,
+            quick_retry_requested: false,
         }
     }
 
@@ -108,16 +128,24 @@ impl MusicResult {
             self.resource.recent_judges(),
         );
 
+        let lnmode = self.resource.player_config().play_settings.lnmode;
         for i in 0..REPLAY_SIZE {
-            self.data.save_replay[i] = if self.main.play_data_accessor().exists_replay_data_model(
-                self.resource.bms_model(),
-                self.resource.player_config().play_settings.lnmode,
-                i as i32,
-            ) {
+            let model = self.resource.bms_model();
+            let accessor = self.main.play_data_accessor();
+            self.data.save_replay[i] = if accessor.exists_replay_data_model(model, lnmode, i as i32)
+            {
                 ReplayStatus::Exist
             } else {
                 ReplayStatus::NotExist
             };
+            self.data.replay_slots[i] = accessor
+                .read_replay_data(
+                    &model.sha256,
+                    model.contains_undefined_long_note(),
+                    lnmode,
+                    i as i32,
+                )
+                .map(|rd| rd.slot_info());
         }
 
         if let Some(mode) = self.resource.bms_model().mode() {
@@ -131,14 +159,33 @@ impl MusicResult {
         // Replay auto save
         if self.resource.play_mode().mode == BMSPlayerModeType::Play && !self.resource.is_freq_on()
         {
-            for i in 0..REPLAY_SIZE {
-                let auto_save = &self.resource.player_config().misc_settings.autosavereplay;
-                if i < auto_save.len()
+            let misc = &self.resource.player_config().misc_settings;
+            if misc.replay_autosave_policy == crate::skin::player_config::REPLAY_AUTOSAVE_ASK {
+                if self.can_save_replay()
                     && let Some(score_data) = self.resource.score_data()
-                    && ReplayAutoSaveConstraint::get(auto_save[i])
+                    && ReplayAutoSaveConstraint::AnyoneUpdate
                         .is_qualified(&self.data.oldscore, score_data)
                 {
-                    self.save_replay_data(i);
+                    self.data.replay_prompt = Some(0);
+                }
+            } else if let Some(constraint) =
+                ReplayAutoSaveConstraint::for_policy(misc.replay_autosave_policy)
+            {
+                if let Some(score_data) = self.resource.score_data()
+                    && constraint.is_qualified(&self.data.oldscore, score_data)
+                {
+                    self.save_replay_data_rotated();
+                }
+            } else {
+                for i in 0..REPLAY_SIZE {
+                    let auto_save = &self.resource.player_config().misc_settings.autosavereplay;
+                    if i < auto_save.len()
+                        && let Some(score_data) = self.resource.score_data()
+                        && ReplayAutoSaveConstraint::get(auto_save[i])
+                            .is_qualified(&self.data.oldscore, score_data)
+                    {
+                        self.save_replay_data(i);
+                    }
                 }
             }
         }
@@ -152,6 +199,9 @@ impl MusicResult {
                 let gauge_clone = gauge.clone();
                 self.resource.add_course_gauge(gauge_clone);
             }
+            if let Some(score) = self.resource.score_data().cloned() {
+                self.resource.add_course_stage_score(score);
+            }
         }
 
         self.data.gauge_type = super::result_common::set_gauge_type(&self.resource);
@@ -220,6 +270,7 @@ impl MusicResult {
             let songdata_for_ranking = self.resource.songdata().cloned();
             let _oldscore_exscore = self.data.oldscore.exscore();
             let newscore_for_thread = newscore_clone.clone();
+            let ir_send_queue = self.main.ir_send_queue();
 
             self.main_data
                 .timer
@@ -235,12 +286,18 @@ impl MusicResult {
                     irsend += 1;
                     let send_ok = status.send();
                     succeed &= send_ok;
-                    if status.retry < 0 || status.retry > ir_send_count {
-                        // Discard failed sends (matches original removal logic)
-                    }
+                }
+                // Failed sends are left to the resend thread
+                let failed: Vec<IRSendStatusMain> = ir_send_list_snapshot
+                    .into_iter()
+                    .filter(|status| !status.is_sent && status.retry <= ir_send_count)
+                    .collect();
+                if !failed.is_empty() {
+                    lock_or_recover(&ir_send_queue).extend(failed);
                 }
 
                 let mut ranking_scores = None;
+                let mut rivals = Vec::new();
                 if irsend > 0
                     && let Some(ref conn) = ir_connection
                     && let Some(ref songdata) = songdata_for_ranking
@@ -253,9 +310,21 @@ impl MusicResult {
                     } else {
                         log::warn!("IR score fetch failed: {}", response.message);
                     }
+                    let response = conn.get_rivals();
+                    if let Some(data) = response.data().filter(|_| response.is_succeeded()) {
+                        rivals = data.clone();
+                    } else {
+                        log::warn!("IR rival fetch failed: {}", response.message);
+                    }
                 }
 
-                let _ = tx.send((succeed, irsend > 0, ranking_scores, newscore_for_thread));
+                let _ = tx.send((
+                    succeed,
+                    irsend > 0,
+                    ranking_scores,
+                    rivals,
+                    newscore_for_thread,
+                ));
             });
             self.ir_thread = Some(handle);
         }
@@ -317,7 +386,7 @@ impl MusicResult {
             }
         };
         self.ir_rx = None;
-        let (succeed, had_sends, ranking_scores, newscore_clone) = result;
+        let (succeed, had_sends, ranking_scores, rivals, newscore_clone) = result;
         self.data.state = STATE_IR_FINISHED;
         if had_sends {
             if succeed {
@@ -346,6 +415,7 @@ impl MusicResult {
                     } else {
                         self.data.ranking_offset = 0;
                     }
+                    self.data.ir_rivals = IRRivalScore::collect(ranking, &rivals);
                 }
             }
         }
@@ -382,11 +452,7 @@ impl MusicResult {
                 self.pending_stop_all_notes = true;
 
                 if self.resource.course_bms_models().is_some() {
-                    let last_gauge = self
-                        .resource
-                        .groove_gauge()
-                        .map(|g| g.value())
-                        .unwrap_or(0.0);
+                    let last_gauge = self.resource.last_gauge_value();
 
                     if last_gauge <= 0.0 {
                         if self.resource.course_score_data().is_some() {
@@ -453,7 +519,9 @@ impl MusicResult {
                     self.resource.set_player_config_gauge(org_gauge);
 
                     let mut key: Option<ResultKey> = None;
-                    if let Some(ref snapshot) = self.input_snapshot {
+                    if self.quick_retry_requested {
+                        key = Some(ResultKey::ReplaySame);
+                    } else if let Some(ref snapshot) = self.input_snapshot {
                         for i in 0..self.property.assign_length() {
                             let idx = i as usize;
                             if self.property.assign(i) == Some(ResultKey::ReplayDifferent)
@@ -525,7 +593,10 @@ impl MusicResult {
             && self.main_data.timer.is_timer_on(TIMER_STARTINPUT)
         {
             let skin_input = self.skin.as_ref().map(|s| s.input() as i64).unwrap_or(0);
-            if time > skin_input {
+            if time > skin_input && self.data.replay_prompt.is_some() {
+                let action = self.replay_prompt_action(snapshot);
+                self.apply_replay_prompt_action(action);
+            } else if time > skin_input {
                 let mut ok = false;
                 let mut replay_index: Option<usize> = None;
                 let mut open_ir = false;
@@ -599,6 +670,14 @@ impl MusicResult {
                     open_ir = true;
                 }
 
+                if self.quick_retry_held
+                    && self.resource.course_bms_models().is_none()
+                    && self.resource.play_mode().mode == BMSPlayerModeType::Play
+                {
+                    self.quick_retry_requested = true;
+                    ok = true;
+                }
+
                 if self.resource.score_data().is_none() || ok {
                     let rank_time = self.skin.as_ref().map(|s| s.rank_time()).unwrap_or(0);
                     if rank_time != 0 && !self.main_data.timer.is_timer_on(TIMER_RESULT_UPDATESCORE)
@@ -638,22 +717,114 @@ impl MusicResult {
         }
     }
 
-    pub fn save_replay_data(&mut self, index: usize) {
-        if self.resource.play_mode().mode == BMSPlayerModeType::Play
+    /// Reads the replay save prompt keys: the graph key moves the cursor,
+    /// other result keys confirm it and number keys pick a slot directly.
+    fn replay_prompt_action(
+        &self,
+        snapshot: &crate::input::input_snapshot::InputSnapshot,
+    ) -> ReplayPromptAction {
+        let slot_keys = [
+            ControlKeys::Num1,
+            ControlKeys::Num2,
+            ControlKeys::Num3,
+            ControlKeys::Num4,
+        ];
+        if let Some(slot) = slot_keys.iter().position(|key| {
+            snapshot
+                .control_key_states
+                .get(key)
+                .copied()
+                .unwrap_or(false)
+        }) {
+            return ReplayPromptAction::Save(slot);
+        }
+        let mut action = ReplayPromptAction::None;
+        for i in 0..self.property.assign_length() {
+            let idx = i as usize;
+            if !snapshot.key_state[idx] || snapshot.key_changed_time[idx] == i64::MIN {
+                continue;
+            }
+            match self.property.assign(i) {
+                Some(ResultKey::ChangeGraph) => action = ReplayPromptAction::Next,
+                Some(_) => return ReplayPromptAction::Confirm,
+                None => {}
+            }
+        }
+        action
+    }
+
+    fn apply_replay_prompt_action(&mut self, action: ReplayPromptAction) {
+        let Some(cursor) = self.data.replay_prompt else {
+            return;
+        };
+        match action {
+            ReplayPromptAction::None => {}
+            ReplayPromptAction::Next => {
+                self.data.replay_prompt = Some((cursor + 1) % (REPLAY_SIZE + 1));
+            }
+            ReplayPromptAction::Confirm => {
+                self.data.replay_prompt = None;
+                if cursor < REPLAY_SIZE {
+                    self.save_replay_data(cursor);
+                }
+            }
+            ReplayPromptAction::Save(slot) => {
+                self.data.replay_prompt = None;
+                self.save_replay_data(slot);
+            }
+        }
+    }
+
+    /// Whether this play's replay can be written to a slot.
+    fn can_save_replay(&self) -> bool {
+        self.resource.play_mode().mode == BMSPlayerModeType::Play
             && self.resource.course_bms_models().is_none()
             && self.resource.score_data().is_some()
-            && self.data.save_replay[index] != ReplayStatus::Saved
             && self.resource.is_update_score()
+            && self.resource.replay_data().is_some()
+    }
+
+    /// Save to slot 1 after shifting the existing replays down one slot.
+    ///
+    /// The replay in the last slot is dropped.
+    fn save_replay_data_rotated(&mut self) {
+        if !self.can_save_replay() || self.data.save_replay[0] == ReplayStatus::Saved {
+            return;
+        }
+        if let Err(e) = self.main.play_data_accessor().rotate_replay_data_model(
+            self.resource.bms_model(),
+            self.resource.player_config().play_settings.lnmode,
+            REPLAY_SIZE as i32,
+        ) {
+            log::error!("Failed to rotate replay data: {}", e);
+            return;
+        }
+        self.data.save_replay.rotate_right(1);
+        self.data.replay_slots.rotate_right(1);
+        self.data.save_replay[0] = ReplayStatus::NotExist;
+        self.data.replay_slots[0] = None;
+        self.save_replay_data(0);
+    }
+
+    pub fn save_replay_data(&mut self, index: usize) {
+        if self.can_save_replay()
+            && self.data.save_replay[index] != ReplayStatus::Saved
             && let Some(rd) = self.resource.replay_data()
         {
+            let mut rd = rd.clone();
+            if let Some(score) = self.resource.score_data() {
+                rd.exscore = score.exscore();
+                rd.clear = score.clear;
+            }
             match self.main.play_data_accessor().write_replay_data_model(
-                &mut rd.clone(),
+                &mut rd,
                 self.resource.bms_model(),
                 self.resource.player_config().play_settings.lnmode,
                 index as i32,
             ) {
                 Ok(()) => {
                     self.data.save_replay[index] = ReplayStatus::Saved;
+                    self.data.replay_slots[index] = Some(rd.slot_info());
                     self.pending_save_last_recording
                         .push("ON_REPLAY".to_string());
                 }
@@ -813,6 +984,7 @@ impl MusicResult {
 
         // ---- Player / course data ----
         s.player_data = Some(*self.resource.player_data());
+        s.today_player_data = Some(*self.resource.today_player_data());
         s.is_course_mode = self.resource.course_bms_models().is_some();
         s.course_index = self
             .resource
@@ -897,7 +1069,7 @@ impl MusicResult {
             33,
             play_mode == BMSPlayerModeType::Autoplay || play_mode == BMSPlayerModeType::Replay,
         );
-        // Gauge groove/hard, clear/fail, gauge ex (42/43/90/91/1046)
+        // Gauge groove/hard, clear/fail, gauge ex, lamp (42/43/90/91/1046/1105-1107)
         let course_score = self.resource.course_score_data();
         s.booleans.insert(
             42,
@@ -919,6 +1091,12 @@ impl MusicResult {
             1046,
             shared_render_context::boolean_value(&self.data, course_score, 1046),
         );
+        for bid in [601, 602, 1105, 1106, 1107, 1209, 1210] {
+            s.booleans.insert(
+                bid,
+                shared_render_context::boolean_value(&self.data, course_score, bid),
+            );
+        }
 
         // ---- Result-specific integers ----
         let playtime = self.resource.player_data().playtime;
@@ -930,12 +1108,15 @@ impl MusicResult {
             71, 72, 74, 75, 76, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 100, 101, 102, 103, 105,
             106, 108, 110, 111, 112, 113, 114, 115, 116, 121, 122, 123, 128, 150, 151, 152, 153,
             154, 155, 156, 157, 158, 170, 171, 172, 173, 174, 175, 176, 177, 178, 179, 180, 182,
-            183, 184, 200, 271, 280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 350, 370, 371,
-            372, 373, 374, 375, 376, 377, 380, 381, 382, 383, 384, 385, 386, 387, 388, 389, 390,
-            391, 392, 393, 394, 395, 396, 397, 398, 399, 410, 411, 412, 413, 414, 415, 416, 417,
-            418, 419, 423, 424, 425,
+            183, 184, 189, 200, 271, 272, 275, 276, 280, 281, 282, 283, 284, 285, 286, 287, 288,
+            289, 350, 370, 371, 372, 373, 374, 375, 376, 377, 380, 381, 382, 383, 384, 385, 386,
+            387, 388, 389, 390, 391, 392, 393, 394, 395, 396, 397, 398, 399, 410, 411, 412, 413,
+            414, 415, 416, 417, 418, 419, 423, 424, 425, 546, 547, 548,
         ];
-        for &id in result_int_ids {
+        // Replay slot overlay (470-505) and IR rivals (506-515)
+        let replay_slot_ids = crate::skin::skin_property::NUMBER_REPLAY1_EXSCORE
+            ..=crate::skin::skin_property::NUMBER_IR_RIVAL5_RANK;
+        for id in result_int_ids.iter().copied().chain(replay_slot_ids) {
             let val = shared_render_context::integer_value(
                 &self.data,
                 timer.boot_time_millis(),
@@ -971,6 +1152,13 @@ impl MusicResult {
                 s.strings.insert(120 + slot, name);
             }
         }
+        // IR rival names (130-134)
+        for slot in 0..crate::result::abstract_result::IR_RIVAL_SIZE as i32 {
+            let name = shared_render_context::ir_rival_name(&self.data, slot);
+            if !name.is_empty() {
+                s.strings.insert(130 + slot, name);
+            }
+        }
         // Song hashes (1030/1031)
         if let Some(song) = self.resource.songdata() {
             if !song.file.md5.is_empty() {
@@ -1081,6 +1269,10 @@ impl MainState for MusicResult {
         self.resource.groove_gauge().map(|g| g.value())
     }
 
+    fn best_score(&self) -> Option<crate::core::score_data::ScoreData> {
+        Some(self.data.oldscore.clone())
+    }
+
     fn create(&mut self) {
         self.do_create();
     }
@@ -1153,11 +1345,7 @@ impl MainState for MusicResult {
                 ctx.stop_all_notes();
 
                 if self.resource.course_bms_models().is_some() {
-                    let last_gauge = self
-                        .resource
-                        .groove_gauge()
-                        .map(|g| g.value())
-                        .unwrap_or(0.0);
+                    let last_gauge = self.resource.last_gauge_value();
 
                     if last_gauge <= 0.0 {
                         if self.resource.course_score_data().is_some() {
@@ -1223,7 +1411,9 @@ impl MainState for MusicResult {
                     self.resource.set_player_config_gauge(org_gauge);
 
                     let mut key: Option<ResultKey> = None;
-                    if let Some(ref snapshot) = self.input_snapshot {
+                    if self.quick_retry_requested {
+                        key = Some(ResultKey::ReplaySame);
+                    } else if let Some(ref snapshot) = self.input_snapshot {
                         for i in 0..self.property.assign_length() {
                             let idx = i as usize;
                             if self.property.assign(i) == Some(ResultKey::ReplayDifferent)
@@ -1406,6 +1596,13 @@ impl MainState for MusicResult {
         self.do_input();
     }
 
+    fn sync_input_from(
+        &mut self,
+        input: &crate::input::bms_player_input_processor::BMSPlayerInputProcessor,
+    ) {
+        self.quick_retry_held = input.hotkey_state(crate::skin::hotkey_config::Hotkey::QuickRetry);
+    }
+
     fn sync_input_snapshot(&mut self, snapshot: &crate::input::input_snapshot::InputSnapshot) {
         self.input_snapshot = Some(snapshot.clone());
     }
@@ -1457,6 +1654,14 @@ impl MainState for MusicResult {
     fn take_player_resource(&mut self) -> Option<crate::core::player_resource::PlayerResource> {
         self.resource.take_inner()
     }
+
+    fn player_resource(&self) -> Option<&crate::core::player_resource::PlayerResource> {
+        Some(self.resource.inner())
+    }
+
+    fn previous_clear_type(&self) -> Option<i32> {
+        Some(self.data.oldscore.clear)
+    }
 }
 
 impl Default for MusicResult {
@@ -1529,6 +1734,255 @@ mod tests {
         assert_eq!(mr.data.save_replay[0], ReplayStatus::Saved);
     }
 
+    #[test]
+    fn test_update_score_database_compares_against_target() {
+        let config = make_test_config("music-result-target-diff");
+        let main = MainController::new(config.clone(), make_ranking_cache());
+        let mut core = make_test_core_resource(config);
+        let mut score = ScoreData {
+            minbp: 5,
+            ..Default::default()
+        };
+        score.judge_counts.epg = 40;
+        core.set_score_data(score);
+        let mut target = ScoreData {
+            minbp: 9,
+            ..Default::default()
+        };
+        target.judge_counts.epg = 30;
+        core.set_target_score_data(target);
+        let resource = PlayerResource::new(
+            core,
+            crate::result::BMSPlayerMode::new(BMSPlayerModeType::Play),
+        );
+        let mut mr = MusicResult::new(main, resource, TimerManager::new());
+
+        mr.update_score_database();
+
+        let value = |id| {
+            crate::result::shared_render_context::integer_value(&mr.data, 0, 0, None, None, id)
+        };
+        assert_eq!(value(NUMBER_TARGET_SCORE2), 60);
+        assert_eq!(value(NUMBER_DIFF_TARGET_MISSCOUNT), -4);
+        assert_eq!(value(NUMBER_RIVAL_MISSCOUNT), 9);
+    }
+
+    #[test]
+    fn test_save_replay_data_records_slot_metadata() {
+        let config = make_test_config("music-result-slot-info");
+        let main = MainController::new(config.clone(), make_ranking_cache());
+        let mut core = make_test_core_resource(config);
+        let mut score = crate::core::score_data::ScoreData {
+            clear: 5,
+            ..Default::default()
+        };
+        score.judge_counts.epg = 10;
+        score.judge_counts.egr = 3;
+        core.set_score_data(score);
+        core.set_replay_data(crate::core::replay_data::ReplayData {
+            date: 1_700_000_000,
+            randomoption: 2,
+            ..Default::default()
+        });
+        let resource = PlayerResource::new(
+            core,
+            crate::result::BMSPlayerMode::new(BMSPlayerModeType::Play),
+        );
+        let mut mr = MusicResult::new(main, resource, TimerManager::new());
+
+        mr.save_replay_data(1);
+
+        let info = mr.data.replay_slots[1].expect("slot 2 metadata");
+        assert_eq!(info.exscore, 23);
+        assert_eq!(info.clear, 5);
+        assert_eq!(info.randomoption, 2);
+        assert_eq!(info.date, 1_700_000_000);
+        assert!(mr.data.replay_slots[0].is_none());
+        // NUMBER_REPLAY2_EXSCORE / NUMBER_REPLAY1_EXSCORE
+        let value = |id| {
+            crate::result::shared_render_context::integer_value(&mr.data, 0, 0, None, None, id)
+        };
+        assert_eq!(value(471), 23);
+        assert_eq!(value(470), i32::MIN);
+    }
+
+    #[test]
+    fn test_rotating_replay_policy_shifts_older_replays_down() {
+        let config = make_test_config("music-result-rotate");
+        let main = MainController::new(config.clone(), make_ranking_cache());
+        let mut player_config = crate::skin::player_config::PlayerConfig::default();
+        player_config.misc_settings.replay_autosave_policy =
+            crate::skin::player_config::REPLAY_AUTOSAVE_ALWAYS;
+        let mut core = crate::core::player_resource::PlayerResource::new(config, player_config);
+        let mut score = crate::core::score_data::ScoreData::default();
+        score.judge_counts.epg = 50;
+        core.set_score_data(score);
+        core.set_replay_data(crate::core::replay_data::ReplayData::default());
+        let resource = PlayerResource::new(
+            core,
+            crate::result::BMSPlayerMode::new(BMSPlayerModeType::Play),
+        );
+        let mut mr = MusicResult::new(main, resource, TimerManager::new());
+        let model = mr.resource.bms_model().clone();
+        let mut old = crate::core::replay_data::ReplayData {
+            exscore: 80,
+            ..Default::default()
+        };
+        mr.main
+            .play_data_accessor()
+            .write_replay_data_model(&mut old, &model, 0, 0)
+            .unwrap();
+        mr.data.save_replay[0] = ReplayStatus::Exist;
+        mr.data.replay_slots[0] = Some(old.slot_info());
+
+        mr.save_replay_data_rotated();
+
+        let accessor = mr.main.play_data_accessor();
+        let ln = model.contains_undefined_long_note();
+        let exscore = |i| {
+            accessor
+                .read_replay_data(&model.sha256, ln, 0, i)
+                .map(|rd| rd.exscore)
+        };
+        assert_eq!(exscore(0), Some(100));
+        assert_eq!(exscore(1), Some(80));
+        assert_eq!(mr.data.save_replay[0], ReplayStatus::Saved);
+        assert_eq!(mr.data.save_replay[1], ReplayStatus::Exist);
+        assert_eq!(mr.data.replay_slots[1].map(|s| s.exscore), Some(80));
+    }
+
+    fn make_replay_prompt_result(name: &str) -> MusicResult {
+        let config = make_test_config(name);
+        let main = MainController::new(config.clone(), make_ranking_cache());
+        let mut player_config = crate::skin::player_config::PlayerConfig::default();
+        player_config.misc_settings.replay_autosave_policy =
+            crate::skin::player_config::REPLAY_AUTOSAVE_ASK;
+        let mut core = crate::core::player_resource::PlayerResource::new(config, player_config);
+        let mut score = crate::core::score_data::ScoreData::default();
+        score.judge_counts.epg = 50;
+        core.set_score_data(score);
+        core.set_replay_data(crate::core::replay_data::ReplayData::default());
+        let resource = PlayerResource::new(
+            core,
+            crate::result::BMSPlayerMode::new(BMSPlayerModeType::Play),
+        );
+        let mut mr = MusicResult::new(main, resource, TimerManager::new());
+        mr.data.replay_slots[0] = Some(crate::skin::replay_data::ReplaySlotInfo {
+            date: 1_700_000_000,
+            exscore: 80,
+            clear: 4,
+            randomoption: 0,
+            gauge: 0,
+        });
+        mr.data.replay_prompt = Some(0);
+        mr
+    }
+
+    #[test]
+    fn test_replay_prompt_shows_overwritten_replay_and_saves_picked_slot() {
+        let mut mr = make_replay_prompt_result("music-result-replay-prompt");
+        let value = |mr: &MusicResult, id| {
+            crate::result::shared_render_context::integer_value(&mr.data, 0, 0, None, None, id)
+        };
+        let option = |mr: &MusicResult, id| {
+            crate::result::shared_render_context::boolean_value(&mr.data, None, id)
+        };
+
+        assert!(option(&mr, OPTION_REPLAY_SAVE_PROMPT));
+        assert!(option(&mr, OPTION_REPLAY_SAVE_PROMPT_OVERWRITE));
+        assert_eq!(value(&mr, NUMBER_REPLAY_SAVE_PROMPT_SLOT), 1);
+        assert_eq!(value(&mr, NUMBER_REPLAY_SAVE_PROMPT_EXSCORE), 80);
+        assert_eq!(value(&mr, NUMBER_REPLAY_SAVE_PROMPT_CLEAR), 4);
+
+        // The graph key (lane 6 on 7-key) moves the cursor to the empty slot 2
+        let mut snapshot = crate::input::input_snapshot::InputSnapshot::default();
+        snapshot.key_state[5] = true;
+        snapshot.key_changed_time[5] = 1;
+        let action = mr.replay_prompt_action(&snapshot);
+        assert_eq!(action, ReplayPromptAction::Next);
+        mr.apply_replay_prompt_action(action);
+        assert_eq!(value(&mr, NUMBER_REPLAY_SAVE_PROMPT_SLOT), 2);
+        assert!(!option(&mr, OPTION_REPLAY_SAVE_PROMPT_OVERWRITE));
+        assert_eq!(value(&mr, NUMBER_REPLAY_SAVE_PROMPT_EXSCORE), i32::MIN);
+
+        let mut snapshot = crate::input::input_snapshot::InputSnapshot::default();
+        snapshot.key_state[0] = true;
+        snapshot.key_changed_time[0] = 1;
+        let action = mr.replay_prompt_action(&snapshot);
+        assert_eq!(action, ReplayPromptAction::Confirm);
+        mr.apply_replay_prompt_action(action);
+
+        assert!(mr.data.replay_prompt.is_none());
+        assert_eq!(mr.data.save_replay[1], ReplayStatus::Saved);
+        assert_eq!(mr.data.save_replay[0], ReplayStatus::NotExist);
+        assert_eq!(value(&mr, NUMBER_REPLAY_SAVE_PROMPT_SLOT), i32::MIN);
+    }
+
+    #[test]
+    fn test_replay_prompt_skip_saves_nothing() {
+        let mut mr = make_replay_prompt_result("music-result-replay-prompt-skip");
+        mr.data.replay_prompt = Some(REPLAY_SIZE);
+        assert_eq!(
+            crate::result::shared_render_context::integer_value(
+                &mr.data,
+                0,
+                0,
+                None,
+                None,
+                NUMBER_REPLAY_SAVE_PROMPT_SLOT
+            ),
+            0
+        );
+
+        mr.apply_replay_prompt_action(ReplayPromptAction::Confirm);
+
+        assert!(mr.data.replay_prompt.is_none());
+        assert!(
+            mr.data
+                .save_replay
+                .iter()
+                .all(|s| *s != ReplayStatus::Saved)
+        );
+    }
+
+    #[test]
+    fn test_replay_prompt_number_key_saves_slot_directly() {
+        let mut mr = make_replay_prompt_result("music-result-replay-prompt-num");
+        let mut snapshot = crate::input::input_snapshot::InputSnapshot::default();
+        snapshot.control_key_states.insert(ControlKeys::Num3, true);
+
+        let action = mr.replay_prompt_action(&snapshot);
+        assert_eq!(action, ReplayPromptAction::Save(2));
+        mr.apply_replay_prompt_action(action);
+
+        assert!(mr.data.replay_prompt.is_none());
+        assert_eq!(mr.data.save_replay[2], ReplayStatus::Saved);
+    }
+
+    #[test]
+    fn test_replay_autosave_policy_maps_to_constraint() {
+        use crate::skin::player_config::{
+            REPLAY_AUTOSAVE_CLEAR_UPDATE, REPLAY_AUTOSAVE_NEVER, REPLAY_AUTOSAVE_PER_SLOT,
+            REPLAY_AUTOSAVE_PERSONAL_BEST,
+        };
+        assert_eq!(
+            ReplayAutoSaveConstraint::for_policy(REPLAY_AUTOSAVE_PER_SLOT),
+            None
+        );
+        assert_eq!(
+            ReplayAutoSaveConstraint::for_policy(REPLAY_AUTOSAVE_PERSONAL_BEST),
+            Some(ReplayAutoSaveConstraint::ScoreUpdate)
+        );
+        assert_eq!(
+            ReplayAutoSaveConstraint::for_policy(REPLAY_AUTOSAVE_CLEAR_UPDATE),
+            Some(ReplayAutoSaveConstraint::ClearUpdate)
+        );
+        assert_eq!(
+            ReplayAutoSaveConstraint::for_policy(REPLAY_AUTOSAVE_NEVER),
+            Some(ReplayAutoSaveConstraint::Nothing)
+        );
+    }
+
     #[test]
     fn test_result_mouse_context_exposes_player_config_mut() {
         let mut mr = make_result_for_mouse();
@@ -2040,6 +2494,69 @@ mod tests {
         assert_eq!(ctx.image_index_value(308), 99);
     }
 
+    #[test]
+    fn test_poll_ir_results_collects_rivals_from_ranking() {
+        use crate::ir::ir_player_data::IRPlayerData;
+        use crate::ir::ir_score_data::IRScoreData;
+        use crate::result::shared_render_context;
+
+        let ir_score = |player: &str, epg: i32| {
+            let mut s = ScoreData::default();
+            s.player = player.to_string();
+            s.judge_counts.epg = epg;
+            IRScoreData::new(&s)
+        };
+        let mut mr = make_result_with_songdata(None);
+        let mut newscore = ScoreData::default();
+        newscore.judge_counts.epg = 100;
+        mr.data.score.score = Some(newscore.clone());
+        mr.data.ranking = Some(RankingData::new());
+        mr.data.state = STATE_IR_PROCESSING;
+        let value = |mr: &MusicResult, id| {
+            shared_render_context::integer_value(&mr.data, 0, 0, None, None, id)
+        };
+        assert!(shared_render_context::boolean_value(
+            &mr.data,
+            None,
+            OPTION_IR_LOADING
+        ));
+        assert_eq!(value(&mr, NUMBER_IR_RIVAL1_EXSCORE_DIFF), i32::MIN);
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        mr.ir_rx = Some(rx);
+        tx.send((
+            true,
+            true,
+            Some(vec![
+                ir_score("ALICE", 120),
+                ir_score("", 100),
+                ir_score("CAROL", 90),
+                ir_score("BOB", 80),
+            ]),
+            vec![
+                IRPlayerData::new("bob".into(), "BOB".into(), String::new()),
+                IRPlayerData::new("alice".into(), "ALICE".into(), String::new()),
+            ],
+            Some(newscore),
+        ))
+        .unwrap();
+        mr.poll_ir_results();
+
+        assert!(shared_render_context::boolean_value(
+            &mr.data,
+            None,
+            OPTION_IR_LOADED
+        ));
+        assert_eq!(mr.data.ir_rivals.len(), 2);
+        assert_eq!(shared_render_context::ir_rival_name(&mr.data, 0), "ALICE");
+        assert_eq!(shared_render_context::ir_rival_name(&mr.data, 1), "BOB");
+        assert_eq!(value(&mr, NUMBER_IR_RIVAL1_EXSCORE_DIFF), -40);
+        assert_eq!(value(&mr, NUMBER_IR_RIVAL1_EXSCORE_DIFF + 1), 40);
+        assert_eq!(value(&mr, NUMBER_IR_RIVAL1_RANK), 1);
+        assert_eq!(value(&mr, NUMBER_IR_RIVAL1_RANK + 1), 4);
+        assert_eq!(value(&mr, NUMBER_IR_RIVAL1_RANK + 2), i32::MIN);
+    }
+
     #[test]
     fn result_render_context_returns_ranking_name_strings() {
         use crate::ir::ir_score_data::IRScoreData;
@@ -269,11 +269,13 @@ impl crate::skin::skin_render_context::SkinRenderContext for ResultRenderContext
                 self.resource.play_mode().mode == crate::core::bms_player_mode::Mode::Autoplay
                     || self.resource.play_mode().mode == crate::core::bms_player_mode::Mode::Replay
             }
-            42 | 43 | 90 | 91 | 1046 => shared_render_context::boolean_value(
-                self.data,
-                self.resource.course_score_data(),
-                id,
-            ),
+            42 | 43 | 90 | 91 | 601 | 602 | 1046 | 1105..=1107 | 1209 | 1210 => {
+                shared_render_context::boolean_value(
+                    self.data,
+                    self.resource.course_score_data(),
+                    id,
+                )
+            }
             _ => self.default_boolean_value(id),
         }
     }
@@ -316,6 +318,7 @@ impl crate::skin::skin_render_context::SkinRenderContext for ResultRenderContext
                 }
             }),
             120..=129 => shared_render_context::ranking_name(self.data, id - 120),
+            130..=134 => shared_render_context::ir_rival_name(self.data, id - 130),
             // Song hash (MD5)
             1030 => self
                 .resource
@@ -342,6 +345,10 @@ impl crate::skin::skin_render_context::SkinRenderContext for ResultRenderContext
         shared_render_context::gauge_border_max(self.resource, self.data.gauge_type)
     }
 
+    fn gauge_transitions(&self) -> &[crate::skin::groove_gauge::GaugeTransition] {
+        shared_render_context::gauge_transitions(self.resource)
+    }
+
     fn get_timing_distribution(
         &self,
     ) -> Option<&crate::skin::timing_distribution::TimingDistribution> {
@@ -508,6 +515,10 @@ impl crate::skin::skin_render_context::SkinRenderContext for ResultMouseContext<
         shared_render_context::gauge_border_max(&self.result.resource, self.result.data.gauge_type)
     }
 
+    fn gauge_transitions(&self) -> &[crate::skin::groove_gauge::GaugeTransition] {
+        shared_render_context::gauge_transitions(&self.result.resource)
+    }
+
     fn gauge_history(&self) -> Option<&Vec<Vec<f32>>> {
         shared_render_context::gauge_history(&self.result.resource)
     }
@@ -657,11 +668,13 @@ impl crate::skin::skin_render_context::SkinRenderContext for ResultMouseContext<
                     || self.result.resource.play_mode().mode
                         == crate::core::bms_player_mode::Mode::Replay
             }
-            42 | 43 | 90 | 91 | 1046 => shared_render_context::boolean_value(
-                &self.result.data,
-                self.result.resource.course_score_data(),
-                id,
-            ),
+            42 | 43 | 90 | 91 | 601 | 602 | 1046 | 1105..=1107 | 1209 | 1210 => {
+                shared_render_context::boolean_value(
+                    &self.result.data,
+                    self.result.resource.course_score_data(),
+                    id,
+                )
+            }
             _ => self.default_boolean_value(id),
         }
     }
@@ -717,6 +730,7 @@ impl crate::skin::skin_render_context::SkinRenderContext for ResultMouseContext<
                     }
                 }),
             120..=129 => shared_render_context::ranking_name(&self.result.data, id - 120),
+            130..=134 => shared_render_context::ir_rival_name(&self.result.data, id - 130),
             // Song hash (MD5)
             1030 => self
                 .result
@@ -12,7 +12,7 @@ use log::info;
 use crate::core::clear_type::ClearType;
 use crate::core::score_data::ScoreData;
 
-use super::super::{BMSPlayerModeType, FreqTrainerMenu};
+use super::super::BMSPlayerModeType;
 use super::MusicResult;
 
 impl MusicResult {
@@ -46,12 +46,61 @@ impl MusicResult {
             self.resource.bms_model().total_notes(),
         );
         self.data.score.update_score(Some(&newscore));
+        self.data.score.rival = self.resource.target_score_data().cloned();
 
         // duration average
         self.data.avgduration = newscore.timing_stats.avgjudge;
         self.data.avg = newscore.timing_stats.avg;
         self.data.stddev = newscore.timing_stats.stddev;
         self.data.timing_distribution.init();
+        self.fill_timing_distribution();
+        self.data.timing_distribution.statistic_value_calculate();
+        self.data.sync_timing_distribution_cache();
+
+        // Course mode score accumulation
+        if self.resource.course_bms_models().is_some() {
+            self.accumulate_course_score(&newscore);
+        }
+
+        // Sped-up plays keep their score without a lamp; slowed plays are
+        // already flagged as assisted by the player.
+        if self.resource.is_freq_on()
+            && self.resource.assist() == 0
+            && let Some(sd) = self.resource.score_data_mut()
+        {
+            sd.clear = ClearType::NoPlay.id();
+        }
+
+        if self.resource.play_mode().mode == BMSPlayerModeType::Play {
+            if let Some(sd) = self.resource.score_data() {
+                self.main.play_data_accessor().write_score_data_model(
+                    sd,
+                    self.resource.bms_model(),
+                    self.resource.player_config().play_settings.lnmode,
+                    self.resource.is_update_score(),
+                );
+                self.resource
+                    .reload_player_data(self.main.play_data_accessor());
+            }
+        } else {
+            info!(
+                "Play mode is {:?}, score not registered",
+                self.resource.play_mode().mode
+            );
+        }
+    }
+
+    /// Bucket the hit offsets of the play into the 1ms timing distribution.
+    ///
+    /// Uses the judge log handed over by the play state. Without a log this
+    /// falls back to the note play times of the model, as in Java.
+    fn fill_timing_distribution(&mut self) {
+        if !self.resource.judge_log().is_empty() {
+            for &offset in self.resource.judge_log() {
+                self.data.timing_distribution.add((offset / 1000) as i32);
+            }
+            return;
+        }
 
         let model = self.resource.bms_model();
         let lanes = model.mode().map(|m| m.key()).unwrap_or(8);
@@ -81,37 +130,6 @@ impl MusicResult {
                 }
             }
         }
-        self.data.timing_distribution.statistic_value_calculate();
-        self.data.sync_timing_distribution_cache();
-
-        // Course mode score accumulation
-        if self.resource.course_bms_models().is_some() {
-            self.accumulate_course_score(&newscore);
-        }
-
-        if FreqTrainerMenu::is_freq_trainer_enabled()
-            && let Some(sd) = self.resource.score_data_mut()
-        {
-            sd.clear = ClearType::NoPlay.id();
-        }
-
-        if self.resource.play_mode().mode == BMSPlayerModeType::Play
-            && !(FreqTrainerMenu::is_freq_trainer_enabled() && FreqTrainerMenu::is_freq_negative())
-        {
-            if let Some(sd) = self.resource.score_data() {
-                self.main.play_data_accessor().write_score_data_model(
-                    sd,
-                    self.resource.bms_model(),
-                    self.resource.player_config().play_settings.lnmode,
-                    self.resource.is_update_score(),
-                );
-            }
-        } else {
-            info!(
-                "Play mode is {:?}, score not registered",
-                self.resource.play_mode().mode
-            );
-        }
     }
 
     fn accumulate_course_score(&mut self, newscore: &ScoreData) {
@@ -159,11 +177,7 @@ impl MusicResult {
             cs.minbp += newscore.minbp;
             cs.timing_stats.total_duration += newscore.timing_stats.total_duration;
 
-            let last_gauge_val = self
-                .resource
-                .groove_gauge()
-                .map(|g| g.value())
-                .unwrap_or(0.0);
+            let last_gauge_val = self.resource.last_gauge_value();
             if last_gauge_val > 0.0 {
                 if self.resource.assist() > 0 {
                     if self.resource.assist() == 1 && cs.clear != ClearType::AssistEasy.id() {
@@ -184,15 +198,11 @@ impl MusicResult {
                         course_total_notes += m.total_notes();
                     }
                     if course_total_notes == self.resource.maxcombo() {
-                        if cs.judge_count(2, true) + cs.judge_count(2, false) == 0 {
-                            if cs.judge_count(1, true) + cs.judge_count(1, false) == 0 {
-                                cs.clear = ClearType::Max.id();
-                            } else {
-                                cs.clear = ClearType::Perfect.id();
-                            }
-                        } else {
-                            cs.clear = ClearType::FullCombo.id();
-                        }
+                        cs.clear = ClearType::combo_lamp(
+                            cs.judge_count(1, true) + cs.judge_count(1, false),
+                            cs.judge_count(2, true) + cs.judge_count(2, false),
+                        )
+                        .id();
                     } else {
                         cs.clear = self
                             .resource
@@ -247,6 +257,7 @@ mod tests {
         replay_data: Option<crate::core::replay_data::ReplayData>,
         course_replay: Vec<crate::core::replay_data::ReplayData>,
         course_gauge: Vec<Vec<Vec<f32>>>,
+        judge_log: Vec<i64>,
     }
 
     impl CourseScoreResourceAccess {
@@ -264,6 +275,7 @@ mod tests {
                 replay_data: Some(crate::core::replay_data::ReplayData::default()),
                 course_replay: Vec::new(),
                 course_gauge: Vec::new(),
+                judge_log: Vec::new(),
             }
         }
     }
@@ -474,6 +486,7 @@ mod tests {
         for cg in &resource_access.course_gauge {
             core_res.add_course_gauge(cg.clone());
         }
+        core_res.set_judge_log(resource_access.judge_log.clone());
         core_res.courseindex = resource_access.course_index;
         core_res.assist = resource_access.assist;
         core_res.maxcombo = resource_access.maxcombo;
@@ -511,6 +524,27 @@ mod tests {
         gg
     }
 
+    #[test]
+    fn test_timing_distribution_uses_judge_log() {
+        let config = make_test_config("td-judge-log");
+        let mut ra = CourseScoreResourceAccess::new(config);
+        // 2.4ms early twice, 10ms late, and one far outside the 150ms range
+        ra.judge_log = vec![2_400, 2_900, -10_000, 400_000];
+        let mut mr = make_course_result(ra, vec![make_model_with_notes(4)]);
+
+        mr.data.timing_distribution.init();
+        mr.fill_timing_distribution();
+        mr.data.timing_distribution.statistic_value_calculate();
+
+        let td = &mr.data.timing_distribution;
+        let center = td.array_center();
+        assert_eq!(td.timing_distribution()[(center + 2) as usize], 2);
+        assert_eq!(td.timing_distribution()[(center - 10) as usize], 1);
+        assert_eq!(td.timing_distribution().iter().sum::<i32>(), 3);
+        assert!((td.average() - (-2.0)).abs() < 1e-4);
+        assert!((td.std_dev() - 32.0f32.sqrt()).abs() < 1e-4);
+    }
+
     // --- 1. Failed clear handling ---
 
     #[test]
@@ -1019,6 +1053,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_accumulate_course_score_quit_stage_fails_despite_live_gauge() {
+        let config = make_test_config("cs-quit-mid");
+        let mut ra = CourseScoreResourceAccess::new(config);
+        ra.assist = 0;
+        // Stage quit mid-song: the live gauge is still up, but the play state
+        // padded the log of every gauge type with zeros
+        ra.gauge = Some(vec![vec![100.0, 80.0, 0.0]; 9]);
+        ra.groove_gauge = Some(make_groove_gauge_with_value(3, 80.0));
+        ra.course_index = 0;
+
+        let models = vec![make_model_with_notes(40), make_model_with_notes(50)];
+        let mut mr = make_course_result(ra, models);
+
+        let mut newscore = ScoreData::default();
+        newscore.clear = ClearType::Failed.id();
+        newscore.minbp = 5;
+        mr.accumulate_course_score(&newscore);
+
+        let cs = mr.resource.course_score_data().unwrap();
+        assert_eq!(cs.clear, ClearType::Failed.id());
+        assert_eq!(cs.minbp, 5 + 50);
+    }
+
     #[test]
     fn test_accumulate_course_score_gauge_zero_last_song() {
         let config = make_test_config("cs-gauge0-last");
@@ -112,6 +112,10 @@ impl PlayerResource {
         self.inner.groove_gauge()
     }
 
+    pub fn last_gauge_value(&self) -> f32 {
+        self.inner.last_gauge_value()
+    }
+
     pub fn course_gauge(&self) -> &Vec<Vec<Vec<f32>>> {
         self.inner.course_gauge()
     }
@@ -124,6 +128,26 @@ impl PlayerResource {
         self.inner.add_course_gauge(gauge);
     }
 
+    pub fn course_stage_scores(&self) -> &[crate::core::score_data::ScoreData] {
+        self.inner.course_stage_scores()
+    }
+
+    pub fn add_course_stage_score(&mut self, score: crate::core::score_data::ScoreData) {
+        self.inner.add_course_stage_score(score);
+    }
+
+    pub fn restart_course(&mut self) -> bool {
+        self.inner.restart_course()
+    }
+
+    pub fn retry_course_stage(&mut self, index: usize) -> bool {
+        let loaded = self.inner.retry_course_stage(index);
+        if loaded {
+            self.course_bms_models = None;
+        }
+        loaded
+    }
+
     pub fn maxcombo(&self) -> i32 {
         self.inner.maxcombo()
     }
@@ -160,10 +184,25 @@ impl PlayerResource {
         self.inner.recent_judges_index()
     }
 
+    pub fn judge_log(&self) -> &[i64] {
+        self.inner.judge_log()
+    }
+
     pub fn player_data(&self) -> &crate::skin::player_data::PlayerData {
         self.inner.player_data()
     }
 
+    pub fn today_player_data(&self) -> &crate::skin::player_data::PlayerData {
+        self.inner.today_player_data()
+    }
+
+    pub fn reload_player_data(
+        &mut self,
+        accessor: &crate::core::play_data_accessor::PlayDataAccessor,
+    ) {
+        self.inner.reload_player_data(accessor);
+    }
+
     // ---- Crate-local methods (not on trait -- types cause circular deps) ----
 
     pub fn bms_model(&self) -> &bms::model::bms_model::BMSModel {
@@ -193,6 +232,10 @@ impl PlayerResource {
         self.ranking_data.as_ref()
     }
 
+    pub fn inner(&self) -> &CorePlayerResource {
+        &self.inner
+    }
+
     /// Take the inner CorePlayerResource, replacing it with a default.
     /// Used during state transition to return the resource to MainController.
     pub fn take_inner(&mut self) -> Option<CorePlayerResource> {
@@ -8,7 +8,10 @@
 use crate::core::clear_type::ClearType;
 use crate::core::score_data::ScoreData;
 
-use super::abstract_result::{AbstractResultData, STATE_OFFLINE};
+use super::abstract_result::{
+    AbstractResultData, IRRivalScore, REPLAY_SIZE, STATE_IR_FINISHED, STATE_IR_PROCESSING,
+    STATE_OFFLINE,
+};
 use super::{MainController, PlayerResource};
 
 /// Map event IDs to replay slot indices.
@@ -59,7 +62,7 @@ fn with_score(data: &AbstractResultData, f: impl FnOnce(&ScoreData) -> i32) -> i
     data.score.score.as_ref().map_or(i32::MIN, f)
 }
 
-/// Helper: returns `i32::MIN` when rival (old best) score data's judge info is
+/// Helper: returns `i32::MIN` when rival (target) score data's judge info is
 /// unavailable, else applies `f`.
 #[inline]
 fn with_rival_score(data: &AbstractResultData, f: impl FnOnce(&ScoreData) -> i32) -> i32 {
@@ -164,6 +167,36 @@ pub fn integer_value(
         // Java: RankingData.getScore(offset + slot).getExscore()
         380..=389 => ranking_exscore(data, id - 380),
 
+        // ---- Replay slot overlay (470-505) ----
+        470..=505 => {
+            crate::skin::replay_data::ReplaySlotInfo::integer_value(&data.replay_slots, id)
+                .unwrap_or(i32::MIN)
+        }
+
+        // ---- Replay save prompt (546-548): cursor slot and the replay it would overwrite ----
+        546 => data.replay_prompt.map_or(i32::MIN, |slot| {
+            if slot < REPLAY_SIZE {
+                slot as i32 + 1
+            } else {
+                0
+            }
+        }),
+        547 => data
+            .replay_prompt_overwrite()
+            .map_or(i32::MIN, |info| info.exscore),
+        548 => data
+            .replay_prompt_overwrite()
+            .map_or(i32::MIN, |info| info.clear),
+
+        // ---- IR rivals (506-515): EX score lead over each rival, then their IR rank ----
+        506..=510 => ir_rival(data, id - 506).map_or(i32::MIN, |r| {
+            data.score
+                .score
+                .as_ref()
+                .map_or(i32::MIN, |s| s.exscore() - r.exscore)
+        }),
+        511..=515 => ir_rival(data, id - 511).map_or(i32::MIN, |r| r.rank),
+
         // ---- IR ranking order (ranking_index1-10: 390-399) ----
         // Java: RankingData.getScoreRanking(offset + slot)
         // Image-index refs with the same IDs are handled separately by
@@ -212,6 +245,26 @@ pub fn integer_value(
             }
         }
 
+        // ---- Diff miss count vs target (NUMBER_DIFF_TARGET_MISSCOUNT) ----
+        189 => with_rival_score(data, |r| {
+            if r.minbp != i32::MAX {
+                with_score(data, |s| s.minbp - r.minbp)
+            } else {
+                i32::MIN
+            }
+        }),
+
+        // ---- Target score details (NUMBER_RIVAL_MAXSCORE / MAXCOMBO / MISSCOUNT) ----
+        272 => with_rival_score(data, |r| r.notes * 2),
+        275 => with_rival_score(data, |r| r.maxcombo),
+        276 => with_rival_score(data, |r| {
+            if r.minbp != i32::MAX {
+                r.minbp
+            } else {
+                i32::MIN
+            }
+        }),
+
         // ---- Judge counts from score data (NUMBER_PERFECT2..NUMBER_POOR2: 80-84) ----
         // Java: score != null ? score.getJudgeCount(index) : Integer.MIN_VALUE
         80..=84 => {
@@ -252,23 +305,11 @@ pub fn integer_value(
 
         // ---- Total early (NUMBER_TOTALEARLY: 423) ----
         // Java: sum of getJudgeCount(i, true) for i in 1..=5
-        423 => {
-            let mut total = 0;
-            for i in 1..6 {
-                total += judge_count(data, i, true);
-            }
-            total
-        }
+        423 => data.score.score.as_ref().map_or(0, |s| s.fast_count()),
 
         // ---- Total late (NUMBER_TOTALLATE: 424) ----
         // Java: sum of getJudgeCount(i, false) for i in 1..=5
-        424 => {
-            let mut total = 0;
-            for i in 1..6 {
-                total += judge_count(data, i, false);
-            }
-            total
-        }
+        424 => data.score.score.as_ref().map_or(0, |s| s.slow_count()),
 
         // ---- Combo break (NUMBER_COMBOBREAK: 425) ----
         // Java: BD(early+late) + PR(early+late)
@@ -279,6 +320,14 @@ pub fn integer_value(
                 + judge_count(data, 4, false)
         }
 
+        // ---- Average hit offset in ms (NUMBER_AVERAGE_OFFSET_PERFECT..BAD: 428-431) ----
+        428..=431 => with_score(data, |s| {
+            match s.timing_stats.judge_offsets[(id - 428) as usize] {
+                i64::MAX => i32::MIN,
+                offset => (offset / 1000) as i32,
+            }
+        }),
+
         // ---- Rival judge counts (NUMBER_RIVAL_PERFECT..NUMBER_RIVAL_POOR: 280-284) ----
         // Java: rivalScoreData != null ? rivalScore.getJudgeCount(index) : Integer.MIN_VALUE
         280..=284 => {
@@ -569,6 +618,22 @@ pub fn boolean_value(data: &AbstractResultData, course_score: Option<&ScoreData>
                 .is_none_or(|s| s.clear == ClearType::Failed.id())
                 || course_score.is_some_and(|cs| cs.clear == ClearType::Failed.id())
         }
+        // OPTION_RESULT_FULLCOMBO/PERFECT/MAX (1105-1107): current play's combo lamp
+        1105..=1107 => data.score.score.as_ref().is_some_and(|s| {
+            s.clear
+                == match id {
+                    1105 => ClearType::FullCombo,
+                    1106 => ClearType::Perfect,
+                    _ => ClearType::Max,
+                }
+                .id()
+        }),
+        // OPTION_IR_LOADING / OPTION_IR_LOADED (601/602): score submission and ranking fetch
+        601 => data.state == STATE_IR_PROCESSING,
+        602 => data.state == STATE_IR_FINISHED,
+        // OPTION_REPLAY_SAVE_PROMPT / _OVERWRITE (1209/1210): slot picker after a qualifying play
+        1209 => data.replay_prompt.is_some(),
+        1210 => data.replay_prompt_overwrite().is_some(),
         // OPTION_GAUGE_EX (1046): non-standard gauge types
         // Java: type == 0 || type == 1 || type == 4 || type == 5 || type == 7 || type == 8
         1046 => data.gauge_type != i32::MIN && matches!(data.gauge_type, 0 | 1 | 4 | 5 | 7 | 8),
@@ -626,6 +691,17 @@ pub fn ranking_score_clear_type(data: &AbstractResultData, slot: i32) -> i32 {
     }
 }
 
+fn ir_rival(data: &AbstractResultData, slot: i32) -> Option<&IRRivalScore> {
+    usize::try_from(slot)
+        .ok()
+        .and_then(|i| data.ir_rivals.get(i))
+}
+
+/// Returns the name of the rival at the given slot of the IR rival panel.
+pub fn ir_rival_name(data: &AbstractResultData, slot: i32) -> String {
+    ir_rival(data, slot).map_or_else(String::new, |r| r.name.clone())
+}
+
 /// Returns the player name for the ranking score at the given slot.
 pub fn ranking_name(data: &AbstractResultData, slot: i32) -> String {
     if let Some(ref ranking) = data.ranking {
@@ -672,6 +748,14 @@ pub fn gauge_history(resource: &PlayerResource) -> Option<&Vec<Vec<f32>>> {
     resource.gauge()
 }
 
+/// Returns every change of the active gauge recorded during play, for drawing
+/// the exact gauge curve instead of the 500ms samples.
+pub fn gauge_transitions(
+    resource: &PlayerResource,
+) -> &[crate::skin::groove_gauge::GaugeTransition] {
+    resource.groove_gauge().map_or(&[], |g| g.history())
+}
+
 /// Returns the last value from the gauge transition history for the given gauge
 /// type on MusicResult screens. Java: `resource.getGauge()[type].last()`.
 pub fn gauge_transition_last_value(resource: &PlayerResource, gauge_type: i32) -> Option<f32> {
@@ -741,6 +825,29 @@ pub fn gauge_element_borders(resource: &PlayerResource) -> Vec<(f32, f32)> {
     }
 }
 
+/// Returns the per-stage breakdown on CourseResult screens (516-545): EX score,
+/// clear type and final gauge value of each stage, `i32::MIN` for unplayed stages.
+pub fn course_stage_value(resource: &PlayerResource, gauge_type: i32, id: i32) -> i32 {
+    let stage = ((id - 516) % 10) as usize;
+    match id {
+        516..=525 => resource
+            .course_stage_scores()
+            .get(stage)
+            .map_or(i32::MIN, |s| s.exscore()),
+        526..=535 => resource
+            .course_stage_scores()
+            .get(stage)
+            .map_or(i32::MIN, |s| s.clear),
+        536..=545 => resource
+            .course_gauge()
+            .get(stage)
+            .and_then(|gauge| gauge.get(gauge_type as usize))
+            .and_then(|log| log.last())
+            .map_or(i32::MIN, |&value| value as i32),
+        _ => i32::MIN,
+    }
+}
+
 /// Returns the course gauge history from the player resource.
 pub fn course_gauge_history(resource: &PlayerResource) -> &[Vec<Vec<f32>>] {
     resource.course_gauge()
@@ -763,7 +870,7 @@ pub fn judge_area(resource: &PlayerResource) -> Option<Vec<Vec<i32>>> {
         .unwrap_or(bms::model::mode::Mode::BEAT_7K);
     let rule = crate::play::bms_player_rule::BMSPlayerRule::for_mode(&mode);
 
-    let judgerank = model.judgerank;
+    let judgerank = model.judgerank_exact();
     let config = resource.player_config();
     let mut judge_window_rate = if config.judge_settings.custom_judge {
         [
@@ -905,6 +1012,31 @@ mod tests {
         assert_eq!(integer_value(&data, 3_661_000, 0, None, None, 29), 1);
     }
 
+    #[test]
+    fn test_boolean_value_result_lamp_options() {
+        let mut data = AbstractResultData::new();
+        for id in 1105..=1107 {
+            assert!(!boolean_value(&data, None, id));
+        }
+        let mut score = crate::core::score_data::ScoreData::default();
+        for (clear, expected) in [
+            (ClearType::ExHard, [false, false, false]),
+            (ClearType::FullCombo, [true, false, false]),
+            (ClearType::Perfect, [false, true, false]),
+            (ClearType::Max, [false, false, true]),
+        ] {
+            score.clear = clear.id();
+            data.score.score = Some(score.clone());
+            for (id, expected) in (1105..=1107).zip(expected) {
+                assert_eq!(
+                    boolean_value(&data, None, id),
+                    expected,
+                    "{clear:?} id {id}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_boolean_value_uses_current_play_score_not_oldscore() {
         // Regression: boolean_value IDs 90/91 must check the current play's
@@ -1831,6 +1963,21 @@ mod tests {
         assert_eq!(integer_value(&data, 0, 0, None, None, 424), expected_late);
     }
 
+    #[test]
+    fn test_integer_value_average_judge_offsets() {
+        let mut data = make_data_with_score();
+        data.score
+            .score
+            .as_mut()
+            .unwrap()
+            .timing_stats
+            .judge_offsets = [1_500, -4_000, i64::MAX, 20_000];
+        assert_eq!(integer_value(&data, 0, 0, None, None, 428), 1);
+        assert_eq!(integer_value(&data, 0, 0, None, None, 429), -4);
+        assert_eq!(integer_value(&data, 0, 0, None, None, 430), i32::MIN);
+        assert_eq!(integer_value(&data, 0, 0, None, None, 431), 20);
+    }
+
     #[test]
     fn test_integer_value_combo_break() {
         let data = make_data_with_score();
@@ -1891,6 +2038,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_integer_value_target_miss_count_and_combo() {
+        let mut data = make_data_with_score();
+        assert_eq!(integer_value(&data, 0, 0, None, None, 189), i32::MIN);
+
+        let rival = crate::core::score_data::ScoreData {
+            notes: 223,
+            maxcombo: 150,
+            minbp: 12,
+            ..Default::default()
+        };
+        data.score.rival = Some(rival);
+        // NUMBER_DIFF_TARGET_MISSCOUNT: 8 - 12
+        assert_eq!(integer_value(&data, 0, 0, None, None, 189), -4);
+        assert_eq!(integer_value(&data, 0, 0, None, None, 272), 446);
+        assert_eq!(integer_value(&data, 0, 0, None, None, 275), 150);
+        assert_eq!(integer_value(&data, 0, 0, None, None, 276), 12);
+
+        data.score.rival.as_mut().unwrap().minbp = i32::MAX;
+        assert_eq!(integer_value(&data, 0, 0, None, None, 189), i32::MIN);
+        assert_eq!(integer_value(&data, 0, 0, None, None, 276), i32::MIN);
+    }
+
     #[test]
     fn test_integer_value_rival_judge_counts_no_rival() {
         let data = make_data_with_score();
@@ -12,6 +12,8 @@ use crate::skin::score_data::ScoreData;
 pub struct ScoreHandoff {
     /// Score data (None for autoplay or when no notes were hit)
     pub score_data: Option<ScoreData>,
+    /// Score of an autoplay run, kept out of `score_data` so it is never saved
+    pub autoplay_score: Option<ScoreData>,
     /// Course combo count
     pub combo: i32,
     /// Course max combo count
@@ -26,6 +28,8 @@ pub struct ScoreHandoff {
     pub freq_on: bool,
     /// Whether IR score submission should be blocked (e.g., frequency training active).
     pub force_no_ir_send: bool,
+    /// Whether the judge trainer changed the judge windows (blocks score DB updates in result).
+    pub judge_trainer_on: bool,
     /// Replay data populated with key input log and pattern info from the play session.
     /// Applied to PlayerResource.replay on handoff so save_replay_data() writes the live data.
     pub replay_data: Option<ReplayData>,
@@ -42,4 +46,7 @@ pub struct ScoreHandoff {
     pub recent_judges: Vec<i64>,
     /// Current write index into the recent_judges circular buffer.
     pub recent_judges_index: usize,
+    /// Hit offsets (microseconds, + is early) of every PGREAT to BAD judge of the play.
+    /// The result screen builds its timing distribution from this log.
+    pub judge_log: Vec<i64>,
 }
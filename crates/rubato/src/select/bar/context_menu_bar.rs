@@ -7,16 +7,19 @@ use super::function_bar::{
     FunctionBar, FunctionBarCallback, STYLE_COURSE, STYLE_FOLDER, STYLE_MISSING, STYLE_SEARCH,
     STYLE_SPECIAL, STYLE_TABLE, STYLE_TEXT_MISSING, STYLE_TEXT_NEW, STYLE_TEXT_PLAIN,
 };
+use super::grade_bar::GradeBar;
 use super::hash_bar::HashBar;
 use super::leader_board_bar::LeaderBoardBar;
 use super::same_folder_bar::SameFolderBar;
 use super::song_bar::SongBar;
 use super::table_bar::TableBar;
 use crate::core::main_state::MainState;
+use crate::external::bms_search_accessor::BMSSearchAccessor;
 use crate::http_download_submitter::HttpDownloadSubmitter;
 use crate::song_database_accessor::SongDatabaseAccessor;
 
 use crate::select::*;
+use crate::tr;
 
 /// Context menu bar for right-click actions
 /// Translates: bms.player.beatoraja.select.bar.ContextMenuBar
@@ -26,6 +29,8 @@ pub struct ContextMenuBar {
     pub song: Option<SongData>,
     pub table: Option<TableBar>,
     pub folder: Option<HashBar>,
+    /// Course whose stages are listed, with the mode it starts in once complete.
+    pub course: Option<(GradeBar, BMSPlayerMode)>,
     pub show_meta: bool,
     pub title: String,
 }
@@ -38,6 +43,7 @@ impl ContextMenuBar {
             song: Some(song),
             table: None,
             folder: None,
+            course: None,
             show_meta: false,
             title,
         };
@@ -52,6 +58,7 @@ impl ContextMenuBar {
             song: None,
             table: Some(table),
             folder: None,
+            course: None,
             show_meta: false,
             title,
         };
@@ -66,6 +73,24 @@ impl ContextMenuBar {
             song: None,
             table: Some(table),
             folder: Some(folder),
+            course: None,
+            show_meta: false,
+            title,
+        };
+        bar.directory.sortable = false;
+        bar
+    }
+
+    /// Stage list of a course with options to download its missing stages and
+    /// start the course in `mode` once they are all in the song database.
+    pub fn new_for_course(course: GradeBar, mode: BMSPlayerMode) -> Self {
+        let title = course.title().to_owned();
+        let mut bar = Self {
+            directory: DirectoryBarData::new(true),
+            song: None,
+            table: None,
+            folder: None,
+            course: Some((course, mode)),
             show_meta: false,
             title,
         };
@@ -85,7 +110,7 @@ impl ContextMenuBar {
             }
         }
         log::info!("Browser open: {}", url);
-        ImGuiNotify::info("Copied URL to clipboard.");
+        ImGuiNotify::info(&tr!("notify-url-copied"));
         true
     }
 
@@ -110,6 +135,8 @@ impl ContextMenuBar {
             } else {
                 return self.missing_song_context(tables);
             }
+        } else if self.course.is_some() {
+            return self.course_context();
         } else if self.folder.is_some() && self.table.is_some() {
             return self.table_folder_context();
         } else if self.table.is_some() {
@@ -131,6 +158,27 @@ impl ContextMenuBar {
         // Leaderboard entries
         self.add_leaderboard_entries(&mut options);
 
+        // BMS Search — resolve the chart hash to its package
+        let hash = if song.file.md5.is_empty() {
+            song.file.sha256.clone()
+        } else {
+            song.file.md5.clone()
+        };
+        if !hash.is_empty() {
+            let chart = vec![(hash, song.metadata.title.clone())];
+            let mut search = FunctionBar::new("Find on BMS Search".to_string(), STYLE_SEARCH);
+            search.set_function(bms_search_callback(chart.clone(), PackageHandoff::Browser));
+            options.push(Bar::Function(Box::new(search)));
+
+            let mut download = FunctionBar::new_with_text_type(
+                "Download via BMS Search".to_string(),
+                STYLE_SPECIAL,
+                STYLE_TEXT_NEW,
+            );
+            download.set_function(bms_search_callback(chart, PackageHandoff::Download));
+            options.push(Bar::Function(Box::new(download)));
+        }
+
         // Meta entries (show by default for missing songs)
         self.add_meta_entries(&mut options, true);
 
@@ -364,6 +412,7 @@ impl ContextMenuBar {
                         &want,
                         &*selector.songdb,
                         downloader.as_ref(),
+                        Some(table_clone.title()),
                     );
                     if fill_count == 0 {
                         log::info!("Nothing to fill");
@@ -397,6 +446,7 @@ impl ContextMenuBar {
                         &elements,
                         &*selector.songdb,
                         downloader.as_ref(),
+                        selector.current_table_name().as_deref(),
                     );
                     if fill_count == 0 {
                         log::info!("Nothing to fill");
@@ -404,11 +454,122 @@ impl ContextMenuBar {
                 }
             }));
             options.push(Bar::Function(Box::new(fill_missing)));
+
+            // Same, but resolving each missing chart through BMS Search
+            let elements: Vec<SongData> = folder.elements().to_vec();
+            let mut search_missing = FunctionBar::new_with_text_type(
+                "Fill Missing Charts via BMS Search".to_string(),
+                STYLE_SPECIAL,
+                STYLE_TEXT_NEW,
+            );
+            search_missing.set_function(Arc::new(move |selector| {
+                let missing = ContextMenuBar::missing_charts(&elements, &*selector.songdb);
+                if missing.is_empty() {
+                    log::info!("Nothing to fill");
+                    return;
+                }
+                bms_search_callback(missing, PackageHandoff::Download)(selector);
+            }));
+            options.push(Bar::Function(Box::new(search_missing)));
+        }
+
+        options
+    }
+
+    fn course_context(&self) -> Vec<Bar> {
+        let mut options = Vec::new();
+        let Some((ref grade_bar, mode)) = self.course else {
+            return options;
+        };
+
+        // Course title entry
+        options.push(Bar::Function(Box::new(FunctionBar::new(
+            self.title.clone(),
+            STYLE_COURSE,
+        ))));
+
+        // Stage entries; missing ones can be downloaded one by one from here
+        for song in grade_bar.song_datas() {
+            options.push(Bar::Song(Box::new(SongBar::new(song.clone()))));
+        }
+
+        let missing = Self::missing_stages(grade_bar);
+        if missing.is_empty() {
+            return options;
+        }
+
+        // Download from the configured source, which looks charts up by md5
+        let by_md5: Vec<(String, String)> = missing
+            .iter()
+            .filter(|(md5, _, _)| !md5.is_empty())
+            .map(|(md5, _, title)| (md5.clone(), title.clone()))
+            .collect();
+        if !by_md5.is_empty() {
+            let course = grade_bar.course_data().clone();
+            let mut download = FunctionBar::new_with_text_type(
+                format!("Download {} Missing Stages and Start", by_md5.len()),
+                STYLE_SPECIAL,
+                STYLE_TEXT_NEW,
+            );
+            download.set_function(Arc::new(move |selector| {
+                let Some(downloader) = selector.http_downloader.clone() else {
+                    ImGuiNotify::warning(&tr!("notify-http-download-disabled"));
+                    return;
+                };
+                let table = selector.current_table_name();
+                for (md5, title) in &by_md5 {
+                    downloader.submit_md5_task_in_table(md5, title, table.as_deref());
+                }
+                selector.set_pending_course(course.clone(), mode);
+            }));
+            options.push(Bar::Function(Box::new(download)));
         }
 
+        // Same, but resolving each stage through BMS Search
+        let by_hash: Vec<(String, String)> = missing
+            .into_iter()
+            .map(|(md5, sha256, title)| (if md5.is_empty() { sha256 } else { md5 }, title))
+            .collect();
+        let course = grade_bar.course_data().clone();
+        let mut search = FunctionBar::new_with_text_type(
+            "Download Missing Stages via BMS Search and Start".to_string(),
+            STYLE_SPECIAL,
+            STYLE_TEXT_NEW,
+        );
+        let callback = bms_search_callback(by_hash, PackageHandoff::Download);
+        search.set_function(Arc::new(move |selector| {
+            if selector.http_downloader.is_none() {
+                ImGuiNotify::warning(&tr!("notify-http-download-disabled"));
+                return;
+            }
+            callback(selector);
+            selector.set_pending_course(course.clone(), mode);
+        }));
+        options.push(Bar::Function(Box::new(search)));
+
         options
     }
 
+    /// `(md5, sha256, title)` of the course stages without a local chart. Stages
+    /// with neither hash cannot be looked up and are skipped.
+    fn missing_stages(grade_bar: &GradeBar) -> Vec<(String, String, String)> {
+        grade_bar
+            .song_datas()
+            .iter()
+            .enumerate()
+            .filter(|(_, song)| song.file.path().is_none())
+            .filter(|(_, song)| !song.file.md5.is_empty() || !song.file.sha256.is_empty())
+            .map(|(i, song)| {
+                let title = if song.metadata.title.is_empty() {
+                    format!("{} STAGE {}", grade_bar.title(), i + 1)
+                } else {
+                    song.metadata.title.clone()
+                };
+                (song.file.md5.clone(), song.file.sha256.clone(), title)
+            })
+            .collect()
+    }
+
     /// Add leaderboard entries to the context menu.
     /// Corresponds to Java ContextMenuBar.addLeaderboardEntries(ArrayList<Bar>)
     fn add_leaderboard_entries(&self, options: &mut Vec<Bar>) {
@@ -696,7 +857,20 @@ impl ContextMenuBar {
         want: &[SongData],
         songdb: &dyn SongDatabaseAccessor,
         downloader: &dyn HttpDownloadSubmitter,
+        table: Option<&str>,
     ) -> i32 {
+        let missing = Self::missing_charts(want, songdb);
+        for (md5, title) in &missing {
+            downloader.submit_md5_task_in_table(md5, title, table);
+        }
+        missing.len() as i32
+    }
+
+    /// `(md5, title)` of the charts in `want` that are not in the song database.
+    fn missing_charts(
+        want: &[SongData],
+        songdb: &dyn SongDatabaseAccessor,
+    ) -> Vec<(String, String)> {
         let md5_and_names: Vec<(String, String)> = want
             .iter()
             .filter_map(|sd| {
@@ -710,19 +884,85 @@ impl ContextMenuBar {
             })
             .collect();
         if md5_and_names.is_empty() {
-            return 0;
+            return Vec::new();
         }
         let md5_array: Vec<String> = md5_and_names.iter().map(|(md5, _)| md5.clone()).collect();
         let in_hand = songdb.song_datas_by_hashes(&md5_array);
         let in_hand_md5s: HashSet<String> = in_hand.iter().map(|sd| sd.file.md5.clone()).collect();
-        let missing: Vec<&(String, String)> = md5_and_names
-            .iter()
+        md5_and_names
+            .into_iter()
             .filter(|(md5, _)| !in_hand_md5s.contains(md5))
-            .collect();
-        for (md5, title) in &missing {
-            downloader.submit_md5_task(md5, title);
-        }
-        missing.len() as i32
+            .collect()
+    }
+}
+
+/// Where a chart resolved through BMS Search is handed off to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PackageHandoff {
+    /// Open the package download (or its BMS Search page) in the browser.
+    Browser,
+    /// Queue the package in the HTTP download processor.
+    Download,
+}
+
+/// Resolve each `(hash, title)` through BMS Search off the main thread and hand the
+/// package off. Charts BMS Search does not know are reported with a notification.
+fn bms_search_callback(
+    charts: Vec<(String, String)>,
+    handoff: PackageHandoff,
+) -> FunctionBarCallback {
+    Arc::new(move |selector| {
+        let downloader = match handoff {
+            PackageHandoff::Download => match selector.http_downloader.clone() {
+                Some(downloader) => Some(downloader),
+                None => {
+                    ImGuiNotify::warning(&tr!("notify-http-download-disabled"));
+                    return;
+                }
+            },
+            PackageHandoff::Browser => None,
+        };
+        let charts = charts.clone();
+        let table = selector.current_table_name();
+        std::thread::spawn(move || {
+            for (hash, title) in &charts {
+                let package = match BMSSearchAccessor::resolve_package(hash) {
+                    Ok(Some(package)) => package,
+                    Ok(None) => {
+                        ImGuiNotify::warning(&tr!(
+                            "notify-bms-search-not-found",
+                            title = title.to_string()
+                        ));
+                        continue;
+                    }
+                    Err(e) => {
+                        log::error!("BMS Search lookup for {} failed: {}", hash, e);
+                        ImGuiNotify::error(&tr!("notify-bms-search-failed", error = e.to_string()));
+                        continue;
+                    }
+                };
+                match (&downloader, &package.download_url) {
+                    (Some(downloader), Some(url)) => {
+                        downloader.submit_url_task_in_table(url, hash, title, table.as_deref())
+                    }
+                    (Some(_), None) => {
+                        ImGuiNotify::warning(&tr!(
+                            "notify-bms-search-no-direct-download",
+                            title = title.to_string()
+                        ));
+                        open_in_browser(&package.page_url);
+                    }
+                    (None, _) => open_in_browser(package.browser_url()),
+                }
+            }
+        });
+    })
+}
+
+fn open_in_browser(url: &str) {
+    log::info!("Browser open: {}", url);
+    if let Err(e) = open::that(url) {
+        log::error!("Failed to open {}: {}", url, e);
     }
 }
 
@@ -737,3 +977,112 @@ fn clipboard_copy_callback(text: &str, message: &str) -> FunctionBarCallback {
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TestSongDb;
+
+    fn song(title: &str, md5: &str) -> SongData {
+        let mut sd = SongData::default();
+        sd.metadata.title = title.to_string();
+        sd.file.md5 = md5.to_string();
+        sd
+    }
+
+    fn titles(bars: &[Bar]) -> Vec<String> {
+        bars.iter()
+            .filter_map(|b| b.as_function_bar())
+            .map(|f| f.title().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn missing_song_menu_offers_bms_search() {
+        let menu = ContextMenuBar::new_for_song(song("Missing", "0123abcd"));
+        let children = menu.children(&[], &TestSongDb::new());
+        let titles = titles(&children);
+        assert!(titles.contains(&"Find on BMS Search".to_string()));
+        assert!(titles.contains(&"Download via BMS Search".to_string()));
+    }
+
+    #[test]
+    fn missing_song_without_hash_has_no_bms_search() {
+        let menu = ContextMenuBar::new_for_song(song("Missing", ""));
+        let titles = titles(&menu.children(&[], &TestSongDb::new()));
+        assert!(!titles.iter().any(|t| t.contains("BMS Search")));
+    }
+
+    fn course(stages: Vec<SongData>) -> GradeBar {
+        GradeBar::new(CourseData {
+            name: Some("Dan".to_string()),
+            hash: stages,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn course_menu_offers_missing_stage_downloads() {
+        let mut owned = song("Owned", "aaaa");
+        owned.file.set_path("songs/owned.bms".to_string());
+        let mut sha_only = song("", "");
+        sha_only.file.sha256 = "ffff".to_string();
+        let grade_bar = course(vec![owned, song("Missing", "bbbb"), sha_only]);
+
+        assert_eq!(
+            ContextMenuBar::missing_stages(&grade_bar),
+            vec![
+                ("bbbb".to_string(), String::new(), "Missing".to_string()),
+                (String::new(), "ffff".to_string(), "Dan STAGE 3".to_string()),
+            ]
+        );
+
+        let menu = ContextMenuBar::new_for_course(grade_bar, BMSPlayerMode::PLAY);
+        let children = menu.children(&[], &TestSongDb::new());
+        assert_eq!(
+            children
+                .iter()
+                .filter(|b| b.as_song_bar().is_some())
+                .count(),
+            3
+        );
+        assert_eq!(
+            titles(&children),
+            vec![
+                "Dan".to_string(),
+                "Download 1 Missing Stages and Start".to_string(),
+                "Download Missing Stages via BMS Search and Start".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn complete_course_menu_only_lists_stages() {
+        let mut owned = song("Owned", "aaaa");
+        owned.file.set_path("songs/owned.bms".to_string());
+        let menu = ContextMenuBar::new_for_course(course(vec![owned]), BMSPlayerMode::PLAY);
+        assert_eq!(
+            titles(&menu.children(&[], &TestSongDb::new())),
+            vec!["Dan".to_string()]
+        );
+    }
+
+    #[test]
+    fn missing_charts_skips_owned_and_untitled() {
+        let mut owned = song("Owned", "aaaa");
+        owned.file.set_path("songs/owned.bms".to_string());
+        let songdb = TestSongDb::new()
+            .with_songs_by_hashes(vec![owned])
+            .with_hash_filtering(true);
+        let want = vec![
+            song("Owned", "aaaa"),
+            song("Missing", "bbbb"),
+            song("", "cccc"),
+        ];
+
+        assert_eq!(
+            ContextMenuBar::missing_charts(&want, &songdb),
+            vec![("bbbb".to_string(), "Missing".to_string())]
+        );
+    }
+}
@@ -12,6 +12,8 @@ pub struct DirectoryBarData {
     pub rlamps: [i32; 11],
     /// Player rank counts
     pub ranks: [i32; 28],
+    /// Entries with no local chart (difficulty table folders)
+    pub missing: i32,
     /// Whether to show invisible charts
     pub show_invisible_chart: bool,
     /// Whether this folder can be sorted
@@ -25,6 +27,7 @@ impl Default for DirectoryBarData {
             lamps: [0; 11],
             rlamps: [0; 11],
             ranks: [0; 28],
+            missing: 0,
             show_invisible_chart: false,
             sortable: true,
         }
@@ -51,6 +54,10 @@ impl DirectoryBarData {
         &self.ranks
     }
 
+    pub fn missing(&self) -> i32 {
+        self.missing
+    }
+
     pub fn lamp(&self, is_player: bool) -> i32 {
         let lamps = if is_player { &self.lamps } else { &self.rlamps };
         if let Some(pos) = lamps.iter().position(|&l| l > 0) {
@@ -71,6 +78,7 @@ impl DirectoryBarData {
         self.lamps.fill(0);
         self.rlamps.fill(0);
         self.ranks.fill(0);
+        self.missing = 0;
     }
 
     /// No-op base version.
@@ -1,4 +1,5 @@
 use super::selectable_bar::SelectableBarData;
+use crate::core::earned_trophy::{self, EarnedTrophy};
 use crate::select::*;
 
 /// Course selection bar
@@ -12,6 +13,8 @@ pub struct GradeBar {
     pub mscore: Option<ScoreData>,
     /// Random score
     pub rscore: Option<ScoreData>,
+    /// Trophy and certification stored in the player's score database
+    pub earned: Option<EarnedTrophy>,
 }
 
 impl GradeBar {
@@ -21,6 +24,7 @@ impl GradeBar {
             course,
             mscore: None,
             rscore: None,
+            earned: None,
         }
     }
 
@@ -60,23 +64,21 @@ impl GradeBar {
             self.rscore.as_ref(),
         ];
 
-        let trophies = &self.course.trophy;
-        for i in (0..trophies.len()).rev() {
-            for score in &scores {
-                if let Some(score) = score
-                    && Self::qualified(score, &trophies[i])
-                {
-                    return Some(&trophies[i]);
-                }
-            }
-        }
-        None
+        let earned = self.earned.as_ref().and_then(|e| e.trophy.as_deref());
+        self.course.trophy.iter().rev().find(|trophy| {
+            earned == Some(trophy.name())
+                || scores
+                    .iter()
+                    .flatten()
+                    .any(|score| earned_trophy::qualified(score, trophy))
+        })
     }
 
-    fn qualified(score: &ScoreData, trophy: &TrophyData) -> bool {
-        score.notes != 0
-            && trophy.missrate >= score.minbp as f32 * 100.0 / score.notes as f32
-            && trophy.scorerate <= score.exscore() as f32 * 100.0 / (score.notes as f32 * 2.0)
+    /// The player holds this class course's dan.
+    pub fn is_certified(&self) -> bool {
+        self.earned.as_ref().is_some_and(|e| e.certified)
+            || (self.course.is_class_course()
+                && self.lamp(true) > crate::core::clear_type::ClearType::Failed.id())
     }
 
     pub fn lamp(&self, is_player: bool) -> i32 {
@@ -169,4 +171,24 @@ mod tests {
 
         assert_eq!(bar.lamp(false), 0);
     }
+
+    #[test]
+    fn test_earned_trophy_shown_without_scores() {
+        let mut course = CourseData::default();
+        course.trophy = vec![
+            TrophyData::new("bronzemedal".to_string(), 7.5, 55.0),
+            TrophyData::new("goldmedal".to_string(), 2.5, 85.0),
+        ];
+        let mut bar = GradeBar::new(course);
+        assert!(bar.trophy().is_none());
+        assert!(!bar.is_certified());
+
+        bar.earned = Some(EarnedTrophy {
+            trophy: Some("goldmedal".to_string()),
+            certified: true,
+            ..Default::default()
+        });
+        assert_eq!(bar.trophy().map(|t| t.name()), Some("goldmedal"));
+        assert!(bar.is_certified());
+    }
 }
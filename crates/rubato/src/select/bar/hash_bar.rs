@@ -73,9 +73,24 @@ impl HashBar {
         let songs = db.song_datas_by_hashes(&self.elements_hash);
         self.directory
             .update_folder_status_with_songs(&songs, None, |_| None);
+        self.directory.missing = missing_count(&self.elements_hash, &songs);
     }
 }
 
+/// Number of `hashes` (SHA256 or MD5) with no local chart among `songs`.
+pub(crate) fn missing_count(hashes: &[String], songs: &[SongData]) -> i32 {
+    let owned: std::collections::HashSet<&str> = songs
+        .iter()
+        .filter(|song| song.file.path().is_some())
+        .flat_map(|song| [song.file.sha256.as_str(), song.file.md5.as_str()])
+        .filter(|hash| !hash.is_empty())
+        .collect();
+    hashes
+        .iter()
+        .filter(|hash| !owned.contains(hash.as_str()))
+        .count() as i32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,6 +131,24 @@ mod tests {
         assert!(children[0].as_song_bar().is_some());
     }
 
+    #[test]
+    fn hash_bar_update_folder_status_counts_missing_entries() {
+        let mut owned = SongData::default();
+        owned.file.sha256 = "hash_owned".to_string();
+        let mut missing = SongData::default();
+        missing.file.md5 = "md5_missing".to_string();
+
+        let mut db_song = owned.clone();
+        db_song.file.set_path("test/owned.bms".to_string());
+
+        let db = TestSongDb::new().with_songs_by_hashes(vec![db_song]);
+        let mut bar = HashBar::new("Level 1".to_string(), vec![owned, missing]);
+        bar.update_folder_status(&db);
+
+        assert_eq!(bar.directory.missing(), 1);
+        assert_eq!(bar.directory.lamps().iter().sum::<i32>(), 1);
+    }
+
     #[test]
     fn hash_bar_get_children_empty_elements() {
         let db = TestSongDb::new().with_songs_by_hashes(vec![]);
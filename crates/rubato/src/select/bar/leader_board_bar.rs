@@ -3,6 +3,7 @@ use std::sync::Arc;
 use super::bar::Bar;
 use super::directory_bar::DirectoryBarData;
 use super::function_bar::{FunctionBar, FunctionBarCallback, STYLE_COURSE, STYLE_TABLE};
+use crate::ir::lr2_ir_connection::LR2IRConnection;
 use crate::select::*;
 
 /// Leaderboard display bar
@@ -154,10 +155,19 @@ impl LeaderBoardBar {
         // Set up ghost/rival action: when the leaderboard entry is selected,
         // create a temporary song bar with the rival score set and start play.
         // This mirrors the Java LR2 ghost battle on click behavior.
+        // LR2IR entries additionally fetch the per-note ghost so the pacemaker
+        // can follow the rival's play instead of a linear target.
         let song = self.song_data.clone();
+        let lr2_id = (self.from_lr2ir && entry.is_lr2_ir()).then(|| entry.lr2_id());
         let callback: FunctionBarCallback = Arc::new(move |selector| {
+            let mut rival_score = rival_score.clone();
+            if let Some(lr2_id) = lr2_id
+                && let Some(ghost) = LR2IRConnection::ghost_data(&song.file.md5, lr2_id)
+            {
+                ghost.apply_to(&mut rival_score);
+            }
             let mut bar = Bar::Song(Box::new(super::song_bar::SongBar::new(song.clone())));
-            bar.set_rival_score(Some(rival_score.clone()));
+            bar.set_rival_score(Some(rival_score));
             selector.read_chart(&song, &bar, Some(&BMSPlayerMode::PLAY));
         });
         bar.set_function(callback);
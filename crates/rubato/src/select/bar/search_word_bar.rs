@@ -2,6 +2,7 @@ use super::bar::Bar;
 use super::directory_bar::DirectoryBarData;
 use super::song_bar::SongBar;
 use crate::select::*;
+use crate::skin::song_user_metadata::SongUserMetadata;
 
 /// Search bar
 /// Translates: bms.player.beatoraja.select.bar.SearchWordBar
@@ -39,6 +40,29 @@ impl SearchWordBar {
         SongBar::to_song_bar_array(&songs)
     }
 
+    /// Like [`children`](Self::children), also finding charts whose
+    /// player-edited metadata matches the search text.
+    pub fn children_with_user_metadata<'a>(
+        &self,
+        db: &dyn SongDatabaseAccessor,
+        user_metadata: impl IntoIterator<Item = &'a SongUserMetadata>,
+    ) -> Vec<Bar> {
+        let mut songs = db.song_datas_by_text(&self.text);
+        let hashes: Vec<String> = user_metadata
+            .into_iter()
+            .filter(|meta| meta.matches(&self.text))
+            .map(|meta| meta.sha256.clone())
+            .collect();
+        if !hashes.is_empty() {
+            for song in db.song_datas_by_hashes(&hashes) {
+                if !songs.iter().any(|s| s.file.sha256 == song.file.sha256) {
+                    songs.push(song);
+                }
+            }
+        }
+        SongBar::to_song_bar_array(&songs)
+    }
+
     pub fn update_folder_status(&mut self, db: &dyn SongDatabaseAccessor) {
         let songs = db.song_datas_by_text(&self.text);
         self.directory
@@ -71,6 +95,41 @@ mod tests {
         assert!(children[0].title().contains("Freedom Dive"));
     }
 
+    #[test]
+    fn search_word_bar_finds_charts_by_user_metadata() {
+        let mut dive = SongData::default();
+        dive.metadata.title = "Freedom Dive".to_string();
+        dive.file.sha256 = "fd_hash".to_string();
+        let mut other = SongData::default();
+        other.metadata.title = "Other".to_string();
+        other.file.sha256 = "other_hash".to_string();
+
+        let db = TestSongDb::new()
+            .with_songs_by_text("scratch", vec![dive.clone()])
+            .with_songs_by_hashes(vec![dive, other])
+            .with_hash_filtering(true);
+        let metadata = [
+            SongUserMetadata {
+                tags: "scratch".to_string(),
+                ..SongUserMetadata::new("fd_hash")
+            },
+            SongUserMetadata {
+                tags: "scratch chord".to_string(),
+                ..SongUserMetadata::new("other_hash")
+            },
+        ];
+
+        let bar = SearchWordBar::from_text("scratch".to_string());
+        let children = bar.children_with_user_metadata(&db, &metadata);
+
+        let mut hashes: Vec<_> = children
+            .iter()
+            .map(|b| b.as_song_bar().unwrap().song_data().file.sha256.as_str())
+            .collect();
+        hashes.sort_unstable();
+        assert_eq!(hashes, vec!["fd_hash", "other_hash"]);
+    }
+
     #[test]
     fn search_word_bar_get_children_returns_empty_for_no_match() {
         let db = TestSongDb::new();
@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use super::bar::Bar;
 use super::selectable_bar::SelectableBarData;
 use crate::select::*;
+use crate::skin::song_user_metadata::SongUserMetadata;
 
 /// Song bar for individual songs
 /// Translates: bms.player.beatoraja.select.bar.SongBar
@@ -11,23 +12,27 @@ pub struct SongBar {
     pub selectable: SelectableBarData,
     /// Song data
     pub song: SongData,
-    /// Cached title (computed from song.metadata.full_title())
+    /// Cached title (computed from song.display_title())
     title: String,
     /// Banner data
     pub banner: Option<Pixmap>,
     /// Stage file data
     pub stagefile: Option<Pixmap>,
+    /// Charts of the same song grouped into this bar with their best scores,
+    /// in difficulty order. Empty when the bar holds a single chart.
+    charts: Vec<(SongData, Option<ScoreData>)>,
 }
 
 impl SongBar {
     pub fn new(song: SongData) -> Self {
-        let title = song.metadata.full_title();
+        let title = song.display_title();
         Self {
             selectable: SelectableBarData::default(),
             title,
             song,
             banner: None,
             stagefile: None,
+            charts: Vec::new(),
         }
     }
 
@@ -39,6 +44,56 @@ impl SongBar {
         &mut self.song
     }
 
+    /// Attach the player's metadata for this chart, updating the shown title.
+    pub fn set_user_metadata(&mut self, user: Option<SongUserMetadata>) {
+        self.song.user = user;
+        self.title = self.song.display_title();
+    }
+
+    pub fn charts(&self) -> &[(SongData, Option<ScoreData>)] {
+        &self.charts
+    }
+
+    pub fn set_charts(&mut self, charts: Vec<(SongData, Option<ScoreData>)>) {
+        self.charts = charts;
+    }
+
+    /// Show the grouped chart `step` places after (or before, if negative)
+    /// the current one, wrapping around. Returns false for single-chart bars.
+    pub fn cycle_chart(&mut self, step: i32) -> bool {
+        if self.charts.len() < 2 {
+            return false;
+        }
+        let current = self
+            .charts
+            .iter()
+            .position(|(song, _)| song.file.sha256 == self.song.file.sha256)
+            .unwrap_or(0);
+        let next = (current as i32 + step).rem_euclid(self.charts.len() as i32) as usize;
+        let sha256 = self.charts[next].0.file.sha256.clone();
+        self.select_chart(&sha256)
+    }
+
+    /// Show the grouped chart `sha256`. Returns false if it is not in this bar.
+    pub fn select_chart(&mut self, sha256: &str) -> bool {
+        let Some((song, score)) = self
+            .charts
+            .iter()
+            .find(|(song, _)| song.file.sha256 == sha256)
+            .cloned()
+        else {
+            return false;
+        };
+        self.title = song.display_title();
+        self.song = song;
+        self.selectable.bar_data.score = score;
+        self.selectable.bar_data.rscore = None;
+        self.selectable.exists_replay = Default::default();
+        self.banner = None;
+        self.stagefile = None;
+        true
+    }
+
     pub fn exists_song(&self) -> bool {
         self.song.file.path().is_some()
     }
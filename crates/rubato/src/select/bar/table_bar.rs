@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use super::bar::Bar;
+use super::container_bar::ContainerBar;
 use super::directory_bar::DirectoryBarData;
 use super::grade_bar::GradeBar;
-use super::hash_bar::HashBar;
+use super::hash_bar::{self, HashBar};
 use crate::select::*;
 
 /// Difficulty table bar
@@ -19,6 +20,8 @@ pub struct TableBar {
     pub grades: Vec<GradeBar>,
     /// Level bars + course bars combined
     pub children: Vec<Bar>,
+    /// Group class (dan) courses into a DAN folder
+    pub class_folder: bool,
     /// Table accessor (Arc for cheap cloning)
     pub tr: Arc<dyn TableAccessor>,
 }
@@ -31,6 +34,7 @@ impl TableBar {
             levels: Vec::new(),
             grades: Vec::new(),
             children: Vec::new(),
+            class_folder: false,
             tr,
         };
         bar.set_table_data(td);
@@ -62,15 +66,40 @@ impl TableBar {
             .map(|course| GradeBar::new(course.clone()))
             .collect();
 
-        // children = levels + grades combined (same order as Java: levels first, then grades)
-        self.children = self
+        self.children = self.build_children();
+        self.td = td;
+    }
+
+    /// Put class courses into a DAN folder ahead of the other courses.
+    pub fn set_class_folder(&mut self, class_folder: bool) {
+        self.class_folder = class_folder;
+        self.children = self.build_children();
+    }
+
+    /// children = levels + grades combined (same order as Java: levels first, then grades)
+    fn build_children(&self) -> Vec<Bar> {
+        let mut children: Vec<Bar> = self
             .levels
             .iter()
             .map(|h| Bar::Hash(Box::new(h.clone())))
-            .chain(self.grades.iter().map(|g| Bar::Grade(Box::new(g.clone()))))
             .collect();
-
-        self.td = td;
+        let grade_bar = |g: &GradeBar| Bar::Grade(Box::new(g.clone()));
+        if self.class_folder {
+            let (class, other): (Vec<&GradeBar>, Vec<&GradeBar>) = self
+                .grades
+                .iter()
+                .partition(|g| g.course_data().is_class_course());
+            if !class.is_empty() {
+                children.push(Bar::Container(Box::new(ContainerBar::new(
+                    "DAN".to_string(),
+                    class.into_iter().map(grade_bar).collect(),
+                ))));
+            }
+            children.extend(other.into_iter().map(grade_bar));
+        } else {
+            children.extend(self.grades.iter().map(grade_bar));
+        }
+        children
     }
 
     /// Resolve course song data from the song database.
@@ -131,12 +160,7 @@ impl TableBar {
         }
 
         // Rebuild children with resolved grades
-        self.children = self
-            .levels
-            .iter()
-            .map(|h| Bar::Hash(Box::new(h.clone())))
-            .chain(self.grades.iter().map(|g| Bar::Grade(Box::new(g.clone()))))
-            .collect();
+        self.children = self.build_children();
     }
 
     pub fn levels(&self) -> &[HashBar] {
@@ -147,6 +171,19 @@ impl TableBar {
         &self.grades
     }
 
+    /// Aggregate clear lamps, ranks and missing charts over every level folder.
+    pub fn update_folder_status(&mut self, db: &dyn SongDatabaseAccessor) {
+        let hashes: Vec<String> = self
+            .levels
+            .iter()
+            .flat_map(|level| level.elements_hash.iter().cloned())
+            .collect();
+        let songs = db.song_datas_by_hashes(&hashes);
+        self.directory
+            .update_folder_status_with_songs(&songs, None, |_| None);
+        self.directory.missing = hash_bar::missing_count(&hashes, &songs);
+    }
+
     pub fn children(&self) -> &[Bar] {
         &self.children
     }
@@ -30,6 +30,57 @@ pub(super) fn bar_class_name(bar: &Bar) -> &'static str {
     }
 }
 
+/// Merge playable song bars sharing a folder and title into one bar at the
+/// position of the first of them. The merged bar keeps showing that chart and
+/// can cycle through the others in difficulty order.
+pub(super) fn group_charts(bars: Vec<Bar>) -> Vec<Bar> {
+    let mut result: Vec<Bar> = Vec::with_capacity(bars.len());
+    let mut group_index: HashMap<(String, String), usize> = HashMap::new();
+    let mut charts: HashMap<usize, Vec<(SongData, Option<ScoreData>)>> = HashMap::new();
+    for bar in bars {
+        let key = bar
+            .as_song_bar()
+            .filter(|sb| sb.exists_song())
+            .map(|sb| sb.song_data())
+            .filter(|sd| !sd.folder.is_empty() && !sd.metadata.title.is_empty())
+            .map(|sd| (sd.folder.clone(), sd.metadata.title.clone()));
+        let Some(key) = key else {
+            result.push(bar);
+            continue;
+        };
+        let chart = (
+            bar.as_song_bar()
+                .map(|sb| sb.song_data().clone())
+                .unwrap_or_default(),
+            bar.score().cloned(),
+        );
+        match group_index.get(&key) {
+            Some(&index) => charts.entry(index).or_default().push(chart),
+            None => {
+                group_index.insert(key, result.len());
+                charts.insert(result.len(), vec![chart]);
+                result.push(bar);
+            }
+        }
+    }
+
+    for (index, mut group) in charts {
+        if group.len() < 2 {
+            continue;
+        }
+        group.sort_by(|(a, _), (b, _)| {
+            a.chart
+                .difficulty
+                .cmp(&b.chart.difficulty)
+                .then(a.chart.level.cmp(&b.chart.level))
+        });
+        if let Some(sb) = result[index].as_song_bar_mut() {
+            sb.set_charts(group);
+        }
+    }
+    result
+}
+
 /// A no-op TableAccessor for course tables.
 /// Corresponds to the anonymous TableAccessor in Java BarManager.init()
 pub(super) struct CourseTableAccessor;
@@ -318,6 +369,8 @@ impl BarContentsLoaderThread {
                     fb.update_folder_status(songdb);
                 } else if let Some(hb) = bar.as_hash_bar_mut() {
                     hb.update_folder_status(songdb);
+                } else if let Bar::Table(tb) = bar {
+                    tb.update_folder_status(songdb);
                 } else if let Some(swb) = bar.as_search_word_bar_mut() {
                     swb.update_folder_status(songdb);
                 } else if let Bar::Command(cb) = bar
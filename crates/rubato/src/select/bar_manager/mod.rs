@@ -4,7 +4,9 @@ use std::io::BufReader;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
+use crate::core::earned_trophy::{self, EarnedTrophy};
 use crate::core::pixmap_resource_pool::PixmapResourcePool;
+use crate::skin::song_user_metadata::SongUserMetadata;
 
 use super::bar::bar::Bar;
 use super::bar::command_bar::CommandBar;
@@ -19,8 +21,17 @@ use super::bar::table_bar::TableBar;
 use super::bar_sorter::BarSorter;
 use super::music_selector::MODE;
 use super::score_data_cache::ScoreDataCache;
+use super::song_filter;
 use super::*;
 
+/// Title of the root folder listing class (dan) courses by series.
+pub const CLASS_FOLDER_TITLE: &str = "CLASS";
+
+/// Bars on each side of the cursor whose scores, song information and images
+/// are loaded. Bars further away are loaded once the cursor comes near, so
+/// opening a folder of any size only reads the contents of this window.
+pub const CONTENTS_WINDOW_RADIUS: usize = 64;
+
 /// Context for update_bar operations.
 /// Passed from MusicSelector to avoid storing references in BarManager.
 pub struct UpdateBarContext<'a> {
@@ -89,8 +100,14 @@ pub struct BarManager {
     search: Vec<SearchWordBar>,
     /// Random course result bars
     random_course_result: Vec<RandomCourseResult>,
+    /// Stored course trophies and certifications by course hash
+    earned_trophies: HashMap<String, EarnedTrophy>,
+    /// Player-edited chart metadata by SHA-256
+    user_metadata: HashMap<String, SongUserMetadata>,
     /// Bar contents loader stop flag
     pub loader_stop: Option<Arc<AtomicBool>>,
+    /// Whether each of `currentsongs` has had its contents loaded
+    contents_loaded: Vec<bool>,
 }
 
 impl Default for BarManager {
@@ -115,7 +132,10 @@ impl BarManager {
             append_folders: HashMap::new(),
             search: Vec::new(),
             random_course_result: Vec::new(),
+            earned_trophies: HashMap::new(),
+            user_metadata: HashMap::new(),
             loader_stop: None,
+            contents_loaded: Vec::new(),
         }
     }
 
@@ -201,7 +221,9 @@ impl BarManager {
             ..Default::default()
         };
         let course_tr: Arc<dyn TableAccessor> = Arc::new(CourseTableAccessor);
-        self.courses = Some(TableBar::new(course_td, course_tr));
+        let mut courses = TableBar::new(course_td, course_tr);
+        courses.set_class_folder(true);
+        self.courses = Some(courses);
 
         // Load favorites
         let fav_accessor = CourseDataAccessor::new("favorite");
@@ -417,6 +439,13 @@ impl BarManager {
                 l.push(Bar::Table(Box::new(courses.clone())));
             }
 
+            // Add class courses grouped by series
+            if let Some(ref ctx) = ctx
+                && let Some(class_folder) = self.class_folder(ctx.songdb)
+            {
+                l.push(class_folder);
+            }
+
             // Add favorites
             for fav in &self.favorites {
                 l.push(Bar::Hash(Box::new(fav.clone())));
@@ -437,6 +466,14 @@ impl BarManager {
                 l.push(cmd.clone());
             }
 
+            // Add the random course generated from the select settings
+            if let Some(ref ctx) = ctx
+                && let Some(course) =
+                    RandomCourseData::from_settings(&ctx.player_config.select_settings)
+            {
+                l.push(Bar::RandomCourse(Box::new(RandomCourseBar::new(course))));
+            }
+
             // Add search results
             for s in &self.search {
                 l.push(Bar::SearchWord(Box::new(s.clone())));
@@ -497,7 +534,9 @@ impl BarManager {
                         table_clone.resolve_grades(songdb);
                         l.extend(table_clone.children().iter().cloned());
                     }
-                    Bar::SearchWord(b) => l.extend(b.children(songdb)),
+                    Bar::SearchWord(b) => {
+                        l.extend(b.children_with_user_metadata(songdb, self.user_metadata.values()))
+                    }
                     Bar::ContextMenu(b) => l.extend(b.children(&self.tables, songdb)),
                     Bar::SameFolder(b) => l.extend(b.children(songdb)),
                     Bar::LeaderBoard(b) => l.extend(b.children()),
@@ -525,6 +564,37 @@ impl BarManager {
             }
         }
 
+        // Attach stored trophies and user metadata; optionally hide class
+        // courses past the first one the player is not certified for
+        for b in &mut l {
+            match b {
+                Bar::Grade(gb) => {
+                    gb.earned = self
+                        .earned_trophies
+                        .get(&EarnedTrophy::course_hash(&gb.course))
+                        .cloned();
+                }
+                Bar::Song(sb) => {
+                    let user = self.user_metadata.get(&sb.song_data().file.sha256).cloned();
+                    sb.set_user_metadata(user);
+                }
+                _ => {}
+            }
+        }
+        if let Some(ref ctx) = ctx
+            && ctx.player_config.select_settings.lock_next_dan
+        {
+            let mut locked = false;
+            l.retain(|b| match b.as_grade_bar() {
+                Some(gb) if gb.course_data().is_class_course() => {
+                    let visible = !locked;
+                    locked |= !gb.is_certified();
+                    visible
+                }
+                _ => true,
+            });
+        }
+
         // Filter out non-existing songs/grades if config says so
         if let Some(ref ctx) = ctx
             && !ctx.config.is_show_no_song_existing_bar()
@@ -540,6 +610,33 @@ impl BarManager {
             });
         }
 
+        // Clear lamp, DJ rank and level filters
+        if let Some(ref mut ctx) = ctx
+            && song_filter::is_active(&ctx.player_config.select_settings)
+        {
+            let lnmode = ctx.player_config.play_settings.lnmode;
+            let mut cache = ctx.score_cache.as_deref_mut();
+            if let Some(cache) = cache.as_deref_mut() {
+                // Read uncached scores in one query instead of one per song
+                let songs: Vec<SongData> = l
+                    .iter()
+                    .filter_map(|b| b.as_song_bar().map(|sb| sb.song_data().clone()))
+                    .collect();
+                cache.read_score_datas(&|_, _| {}, &songs, lnmode);
+            }
+            let settings = &ctx.player_config.select_settings;
+            l.retain(|b| match b.as_song_bar() {
+                Some(sb) => {
+                    let sd = sb.song_data();
+                    let score = cache
+                        .as_deref_mut()
+                        .and_then(|cache| cache.read_score_data(sd, lnmode));
+                    song_filter::accepts(settings, sd, score)
+                }
+                None => true,
+            });
+        }
+
         if !l.is_empty() {
             // Mode + invisible filtering
             if let Some(ref mut ctx) = ctx {
@@ -660,6 +757,12 @@ impl BarManager {
                 }
             }
 
+            if let Some(ref ctx) = ctx
+                && ctx.player_config.select_settings.group_charts
+            {
+                l = group_charts(l);
+            }
+
             // Random select bars
             if let Some(ref mut ctx) = ctx
                 && ctx.player_config.select_settings.is_random_select
@@ -720,19 +823,15 @@ impl BarManager {
 
             self.currentsongs = l;
             self.selectedindex = 0;
+            self.reset_contents_window();
 
             // Restore cursor position to matching bar
             if sourcebar_title.is_some() {
                 // Use sourcebar to find position
                 let target_title = sourcebar_title.as_deref();
                 let target_sha = sourcebar_sha256.as_deref();
-                if sourcebar_is_song && target_sha.is_some() {
-                    if let Some(pos) = self.currentsongs.iter().position(|bar| {
-                        bar.as_song_bar().is_some_and(|sb| {
-                            sb.exists_song()
-                                && Some(sb.song_data().file.sha256.as_str()) == target_sha
-                        })
-                    }) {
+                if sourcebar_is_song && let Some(sha) = target_sha {
+                    if let Some(pos) = self.song_position(sha) {
                         self.selectedindex = pos;
                     }
                 } else if let Some(title) = target_title
@@ -746,10 +845,7 @@ impl BarManager {
             } else if let Some(ref prev_title) = prevbar_title {
                 if prevbar_is_song && prevbar_sha256.is_some() {
                     let sha = prevbar_sha256.as_deref().expect("as_deref");
-                    if let Some(pos) = self.currentsongs.iter().position(|bar| {
-                        bar.as_song_bar()
-                            .is_some_and(|sb| sb.exists_song() && sb.song_data().file.sha256 == sha)
-                    }) {
+                    if let Some(pos) = self.song_position(sha) {
                         self.selectedindex = pos;
                     }
                 } else if let Some(pos) = self.currentsongs.iter().position(|bar| {
@@ -789,6 +885,28 @@ impl BarManager {
         false
     }
 
+    /// Index of the playable song bar showing or grouping chart `sha256`.
+    /// A grouped bar is switched to that chart.
+    fn song_position(&mut self, sha256: &str) -> Option<usize> {
+        if let Some(pos) = self.currentsongs.iter().position(|bar| {
+            bar.as_song_bar()
+                .is_some_and(|sb| sb.exists_song() && sb.song_data().file.sha256 == sha256)
+        }) {
+            return Some(pos);
+        }
+        let pos = self.currentsongs.iter().position(|bar| {
+            bar.as_song_bar().is_some_and(|sb| {
+                sb.charts()
+                    .iter()
+                    .any(|(song, _)| song.file.sha256 == sha256)
+            })
+        })?;
+        if let Some(sb) = self.currentsongs[pos].as_song_bar_mut() {
+            sb.select_chart(sha256);
+        }
+        Some(pos)
+    }
+
     /// Update bar using the currently selected bar.
     /// Workaround for borrow checker: can't pass selected() to update_bar().
     pub fn update_bar_with_selected_and_context(
@@ -853,6 +971,58 @@ impl BarManager {
         }
     }
 
+    /// Folder titles from the root to the current directory and the key of the
+    /// selected bar (chart SHA-256 for songs, title otherwise).
+    pub fn session_position(&self) -> (Vec<String>, String) {
+        let folders = self.dir.iter().map(|d| d.title().to_owned()).collect();
+        let selected = self
+            .selected()
+            .map(
+                |bar| match bar.as_song_bar().filter(|sb| sb.exists_song()) {
+                    Some(sb) => sb.song_data().file.sha256.clone(),
+                    None => bar.title().to_owned(),
+                },
+            )
+            .unwrap_or_default();
+        (folders, selected)
+    }
+
+    /// Re-enter `folders` from the root and put the cursor back on the bar
+    /// keyed `selected`, as recorded by `session_position`. Stops at the
+    /// first folder no longer present.
+    pub fn restore_session_position(
+        &mut self,
+        folders: &[String],
+        selected: &str,
+        mut ctx: Option<&mut UpdateBarContext>,
+    ) {
+        for title in folders {
+            let Some(bar) = self
+                .currentsongs
+                .iter()
+                .find(|bar| bar.is_directory_bar() && bar.title() == title)
+                .cloned()
+            else {
+                break;
+            };
+            let depth = self.dir.len();
+            self.update_bar_with_context(Some(&bar), ctx.as_deref_mut());
+            if self.dir.len() <= depth {
+                break;
+            }
+        }
+        if selected.is_empty() {
+            return;
+        }
+        if let Some(pos) = self.song_position(selected).or_else(|| {
+            self.currentsongs
+                .iter()
+                .position(|bar| bar.title() == selected)
+        }) {
+            self.selectedindex = pos;
+        }
+    }
+
     pub fn selected_position(&self) -> f32 {
         if self.currentsongs.is_empty() {
             0.0
@@ -865,6 +1035,90 @@ impl BarManager {
         &self.tables
     }
 
+    /// Class select folder: one unsorted sub-folder per series (the course
+    /// list and each difficulty table) holding its class courses in table order.
+    ///
+    /// Courses are launched as usual, so the class gauge and the locked
+    /// gauge of class courses apply.
+    fn class_folder(&self, songdb: &dyn SongDatabaseAccessor) -> Option<Bar> {
+        let series: Vec<Bar> = self
+            .courses
+            .iter()
+            .chain(&self.tables)
+            .filter_map(|table| {
+                let mut table = table.clone();
+                table.resolve_grades(songdb);
+                let grades: Vec<Bar> = table
+                    .grades
+                    .into_iter()
+                    .filter(|g| g.course_data().is_class_course())
+                    .map(|g| Bar::Grade(Box::new(g)))
+                    .collect();
+                if grades.is_empty() {
+                    return None;
+                }
+                let mut folder = ContainerBar::new(table.td.name, grades);
+                folder.directory.sortable = false;
+                Some(Bar::Container(Box::new(folder)))
+            })
+            .collect();
+        if series.is_empty() {
+            return None;
+        }
+        Some(Bar::Container(Box::new(ContainerBar::new(
+            CLASS_FOLDER_TITLE.to_string(),
+            series,
+        ))))
+    }
+
+    /// Highest class course with a clear lamp in the class series being browsed.
+    ///
+    /// Course scores are filled in by the bar contents loader, so this is
+    /// `None` until they are loaded and outside the class folder.
+    pub fn current_class_dan(&self) -> Option<&str> {
+        let in_class_series = self.dir.len() == 2
+            && matches!(self.dir[0].as_ref(), Bar::Container(c) if c.title() == CLASS_FOLDER_TITLE);
+        if !in_class_series {
+            return None;
+        }
+        self.currentsongs
+            .iter()
+            .filter_map(|bar| bar.as_grade_bar())
+            .rfind(|gb| gb.lamp(true) > crate::core::clear_type::ClearType::Failed.id())
+            .map(|gb| gb.title())
+    }
+
+    /// Replace the stored course trophies attached to grade bars.
+    pub fn set_earned_trophies(&mut self, trophies: Vec<EarnedTrophy>) {
+        self.earned_trophies = trophies.into_iter().map(|t| (t.hash.clone(), t)).collect();
+    }
+
+    /// Replace the player-edited chart metadata applied to song bars.
+    pub fn set_user_metadata(&mut self, metadata: Vec<SongUserMetadata>) {
+        self.user_metadata = metadata
+            .into_iter()
+            .map(|m| (m.sha256.clone(), m))
+            .collect();
+    }
+
+    /// Store or, when empty, drop one chart's metadata.
+    pub fn put_user_metadata(&mut self, metadata: SongUserMetadata) {
+        if metadata.is_empty() {
+            self.user_metadata.remove(&metadata.sha256);
+        } else {
+            self.user_metadata.insert(metadata.sha256.clone(), metadata);
+        }
+    }
+
+    pub fn user_metadata(&self, sha256: &str) -> Option<&SongUserMetadata> {
+        self.user_metadata.get(sha256)
+    }
+
+    /// Name of the dan the player most recently certified for.
+    pub fn certified_dan(&self) -> Option<&str> {
+        earned_trophy::current_dan(self.earned_trophies.values()).map(|t| t.course.as_str())
+    }
+
     pub fn set_selected_position(&mut self, value: f32) {
         if (0.0..1.0).contains(&value) && !self.currentsongs.is_empty() {
             self.selectedindex = (self.currentsongs.len() as f32 * value) as usize;
@@ -883,6 +1137,44 @@ impl BarManager {
         self.selectedindex %= self.currentsongs.len();
     }
 
+    /// Mark the contents of every current bar as not loaded, after the list
+    /// was replaced or a bar changed the chart it shows.
+    pub fn reset_contents_window(&mut self) {
+        self.contents_loaded.clear();
+        self.contents_loaded.resize(self.currentsongs.len(), false);
+    }
+
+    /// Index ranges of the bars within `radius` of the cursor whose contents
+    /// are not loaded yet, which are marked loaded. The list wraps around
+    /// like the cursor does, so the window near the start includes the end.
+    pub fn take_unloaded_window(&mut self, radius: usize) -> Vec<std::ops::Range<usize>> {
+        let len = self.currentsongs.len();
+        if self.contents_loaded.len() != len {
+            self.reset_contents_window();
+        }
+        if len == 0 {
+            return Vec::new();
+        }
+        let mut indices: Vec<usize> = if radius * 2 + 1 >= len {
+            (0..len).collect()
+        } else {
+            (0..=radius * 2)
+                .map(|offset| (self.selectedindex + len + offset - radius) % len)
+                .collect()
+        };
+        indices.retain(|&i| !self.contents_loaded[i]);
+        indices.sort_unstable();
+        let mut ranges: Vec<std::ops::Range<usize>> = Vec::new();
+        for i in indices {
+            self.contents_loaded[i] = true;
+            match ranges.last_mut() {
+                Some(range) if range.end == i => range.end += 1,
+                _ => ranges.push(i..i + 1),
+            }
+        }
+        ranges
+    }
+
     pub fn add_search(&mut self, bar: SearchWordBar, max_count: i32) {
         // Remove existing search with same title
         let title = bar.title();
@@ -907,6 +1199,10 @@ impl BarManager {
         self.append_folders.insert(key, bar);
     }
 
+    pub fn remove_append_directory_bar(&mut self, key: &str) {
+        self.append_folders.remove(key);
+    }
+
     /// Create a command bar from a CommandFolder definition.
     /// Corresponds to Java BarManager.createCommandBar(MusicSelector, CommandFolder)
     fn create_command_bar(&self, folder: &CommandFolder) -> Bar {
@@ -941,7 +1237,7 @@ impl BarManager {
 
 mod loader;
 pub use loader::*;
-use loader::{CourseTableAccessor, RandomCourseResult, bar_class_name};
+use loader::{CourseTableAccessor, RandomCourseResult, bar_class_name, group_charts};
 
 #[cfg(test)]
 #[allow(clippy::field_reassign_with_default)]
@@ -1,4 +1,5 @@
 use super::*;
+use crate::core::clear_type::ClearType;
 use crate::select::bar::song_bar::SongBar;
 
 fn make_song_data(sha256: &str, path: Option<&str>) -> SongData {
@@ -24,6 +25,120 @@ fn test_init_creates_courses() {
     assert!(manager.courses.is_some());
 }
 
+#[test]
+fn test_course_table_groups_class_courses_into_dan_folder() {
+    let song = make_song_data("dan_song", None);
+    let course = |name: &str, constraint: Vec<CourseDataConstraint>| CourseData {
+        name: Some(name.to_string()),
+        hash: vec![song.clone()],
+        constraint,
+        ..Default::default()
+    };
+    let td = TableData {
+        name: "COURSE".to_string(),
+        course: vec![
+            course("1st dan", vec![CourseDataConstraint::Class]),
+            course("Marathon", vec![CourseDataConstraint::NoSpeed]),
+            course("2nd dan", vec![CourseDataConstraint::Mirror]),
+        ],
+        ..Default::default()
+    };
+    let mut courses = TableBar::new(td, Arc::new(CourseTableAccessor));
+    assert_eq!(courses.children().len(), 3);
+
+    courses.set_class_folder(true);
+    let children = courses.children();
+    assert_eq!(children.len(), 2);
+    match &children[0] {
+        Bar::Container(dan) => {
+            assert_eq!(dan.title(), "DAN");
+            let titles: Vec<&str> = dan.children().iter().map(|b| b.title()).collect();
+            assert_eq!(titles, ["1st dan", "2nd dan"]);
+        }
+        _ => panic!("first child should be the DAN folder"),
+    }
+    assert_eq!(children[1].title(), "Marathon");
+}
+
+#[test]
+fn test_class_folder_groups_class_courses_by_series() {
+    let course = |name: &str, sha256: &str, constraint: Vec<CourseDataConstraint>| CourseData {
+        name: Some(name.to_string()),
+        hash: vec![make_song_data(sha256, Some("/dan.bms"))],
+        constraint,
+        ..Default::default()
+    };
+    let table = |name: &str, course: Vec<CourseData>| {
+        TableBar::new(
+            TableData {
+                name: name.to_string(),
+                course,
+                ..Default::default()
+            },
+            Arc::new(CourseTableAccessor),
+        )
+    };
+    let mut manager = BarManager::new();
+    manager.courses = Some(table(
+        "COURSE",
+        vec![
+            course("Marathon", "m", vec![CourseDataConstraint::NoSpeed]),
+            course("Local dan", "l", vec![CourseDataConstraint::Class]),
+        ],
+    ));
+    manager.tables = vec![
+        table("No dan table", vec![course("Endurance", "e", vec![])]),
+        table(
+            "Satellite",
+            vec![
+                course("sl0", "s0", vec![CourseDataConstraint::Class]),
+                course("sl1", "s1", vec![CourseDataConstraint::Class]),
+                course("sl2", "s2", vec![CourseDataConstraint::Class]),
+            ],
+        ),
+    ];
+
+    let config = Config::default();
+    let mut player_config = PlayerConfig::default();
+    let mut ctx = UpdateBarContext {
+        config: &config,
+        player_config: &mut player_config,
+        songdb: &crate::select::null_song_database_accessor::NullSongDatabaseAccessor,
+        score_cache: None,
+        is_folderlamp: false,
+        max_search_bar_count: 10,
+    };
+    manager.update_bar_with_context(None, Some(&mut ctx));
+    let class = manager
+        .currentsongs
+        .iter()
+        .find(|b| b.title() == CLASS_FOLDER_TITLE)
+        .cloned()
+        .expect("class folder at root");
+
+    manager.update_bar_with_context(Some(&class), Some(&mut ctx));
+    let series: Vec<&str> = manager.currentsongs.iter().map(|b| b.title()).collect();
+    assert_eq!(series, ["COURSE", "Satellite"]);
+    assert_eq!(manager.current_class_dan(), None);
+
+    let satellite = manager.currentsongs[1].clone();
+    manager.update_bar_with_context(Some(&satellite), Some(&mut ctx));
+    let dans: Vec<&str> = manager.currentsongs.iter().map(|b| b.title()).collect();
+    assert_eq!(dans, ["sl0", "sl1", "sl2"]);
+    assert_eq!(manager.current_class_dan(), None);
+
+    let clear = |clear: ClearType| {
+        Some(ScoreData {
+            clear: clear.id(),
+            ..Default::default()
+        })
+    };
+    manager.currentsongs[0].set_score(clear(ClearType::Hard));
+    manager.currentsongs[1].set_score(clear(ClearType::Normal));
+    manager.currentsongs[2].set_score(clear(ClearType::Failed));
+    assert_eq!(manager.current_class_dan(), Some("sl1"));
+}
+
 #[test]
 fn test_init_creates_commands() {
     let mut manager = BarManager::new();
@@ -868,6 +983,53 @@ fn test_mov_increase() {
     assert_eq!(manager.selectedindex, 0); // wraps
 }
 
+// ---- Contents window tests ----
+
+fn make_song_bars(count: usize) -> Vec<Bar> {
+    (0..count)
+        .map(|i| make_song_bar(&format!("{:064}", i), Some("/a.bms")))
+        .collect()
+}
+
+#[test]
+fn test_contents_window_loads_short_list_once() {
+    let mut manager = BarManager::new();
+    manager.currentsongs = make_song_bars(5);
+    assert_eq!(manager.take_unloaded_window(2), vec![0..5]);
+    assert!(manager.take_unloaded_window(2).is_empty());
+
+    manager.reset_contents_window();
+    assert_eq!(manager.take_unloaded_window(2), vec![0..5]);
+}
+
+#[test]
+fn test_contents_window_follows_cursor() {
+    let mut manager = BarManager::new();
+    manager.currentsongs = make_song_bars(100);
+    manager.selectedindex = 50;
+    assert_eq!(manager.take_unloaded_window(3), vec![47..54]);
+
+    manager.mov(true);
+    assert_eq!(manager.take_unloaded_window(3), vec![54..55]);
+    manager.mov(false);
+    assert!(manager.take_unloaded_window(3).is_empty());
+    manager.mov(false);
+    assert_eq!(manager.take_unloaded_window(3), vec![46..47]);
+}
+
+#[test]
+fn test_contents_window_wraps_around_list_end() {
+    let mut manager = BarManager::new();
+    manager.currentsongs = make_song_bars(100);
+    manager.selectedindex = 1;
+    assert_eq!(manager.take_unloaded_window(3), vec![0..5, 98..100]);
+
+    // A replaced list starts unloaded
+    manager.currentsongs = make_song_bars(10);
+    manager.selectedindex = 0;
+    assert_eq!(manager.take_unloaded_window(3), vec![0..4, 7..10]);
+}
+
 #[test]
 fn test_mov_decrease() {
     let mut manager = BarManager::new();
@@ -1813,3 +1975,193 @@ fn test_selected_returns_correct_bar_for_valid_index() {
     assert!(selected.is_some());
     assert_eq!(selected.unwrap().title(), "song_b");
 }
+
+#[test]
+fn test_song_filter_keeps_only_matching_songs() {
+    let mut sd_a = make_song_data("aaa", Some("/a.bms"));
+    sd_a.chart.level = 11;
+    let mut sd_b = make_song_data("bbb", Some("/b.bms"));
+    sd_b.chart.level = 11;
+    let mut sd_c = make_song_data("ccc", Some("/c.bms"));
+    sd_c.chart.level = 5;
+
+    let children = vec![
+        Bar::Song(Box::new(SongBar::new(sd_a))),
+        Bar::Song(Box::new(SongBar::new(sd_b))),
+        Bar::Song(Box::new(SongBar::new(sd_c))),
+    ];
+    let container = ContainerBar::new("TestDir".to_string(), children);
+
+    let mut failed = ScoreData::default();
+    failed.clear = 1;
+    let mut cleared = ScoreData::default();
+    cleared.clear = 5;
+    let mut cache = make_score_cache(vec![
+        ("aaa".to_string(), Some(failed.clone())),
+        ("bbb".to_string(), Some(cleared)),
+        ("ccc".to_string(), Some(failed)),
+    ]);
+
+    let config = Config::default();
+    let mut player_config = PlayerConfig::default();
+    player_config.select_settings.clear_filter = crate::core::player_config::CLEAR_FILTER_FAILED;
+    player_config.select_settings.level_filter_min = 10;
+
+    let mut ctx = UpdateBarContext {
+        config: &config,
+        player_config: &mut player_config,
+        songdb: &crate::select::null_song_database_accessor::NullSongDatabaseAccessor,
+        score_cache: Some(&mut cache),
+        is_folderlamp: false,
+        max_search_bar_count: 10,
+    };
+
+    let mut manager = BarManager::new();
+    manager.update_bar_with_context(Some(&Bar::Container(Box::new(container))), Some(&mut ctx));
+
+    // Only the failed level 11 chart passes both filters
+    let shas: Vec<&str> = manager
+        .currentsongs
+        .iter()
+        .filter_map(|b| b.as_song_bar())
+        .map(|sb| sb.song_data().file.sha256.as_str())
+        .collect();
+    assert_eq!(shas, vec!["aaa"]);
+}
+
+fn make_chart(sha256: &str, folder: &str, title: &str, difficulty: i32) -> SongData {
+    let mut sd = make_song_data(sha256, Some(&format!("/{}.bms", sha256)));
+    sd.folder = folder.to_string();
+    sd.metadata.title = title.to_string();
+    sd.chart.difficulty = difficulty;
+    sd
+}
+
+#[test]
+fn test_group_charts_merges_same_song_and_cycles_by_difficulty() {
+    let children = vec![
+        Bar::Song(Box::new(SongBar::new(make_chart(
+            "another", "f1", "Song", 4,
+        )))),
+        Bar::Song(Box::new(SongBar::new(make_chart(
+            "other", "f2", "Other", 1,
+        )))),
+        Bar::Song(Box::new(SongBar::new(make_chart(
+            "normal", "f1", "Song", 2,
+        )))),
+        Bar::Song(Box::new(SongBar::new(make_chart("hyper", "f1", "Song", 3)))),
+    ];
+    let grouped = group_charts(children);
+    assert_eq!(grouped.len(), 2);
+
+    let mut song = grouped[0].as_song_bar().unwrap().clone();
+    assert_eq!(song.song_data().file.sha256, "another");
+    let order: Vec<&str> = song
+        .charts()
+        .iter()
+        .map(|(sd, _)| sd.file.sha256.as_str())
+        .collect();
+    assert_eq!(order, vec!["normal", "hyper", "another"]);
+    assert!(grouped[1].as_song_bar().unwrap().charts().is_empty());
+
+    assert!(song.cycle_chart(1));
+    assert_eq!(song.song_data().file.sha256, "normal");
+    assert!(song.cycle_chart(-1));
+    assert_eq!(song.song_data().file.sha256, "another");
+    assert!(!SongBar::new(make_chart("x", "f", "X", 1)).cycle_chart(1));
+}
+
+#[test]
+fn test_group_charts_restores_cursor_to_grouped_chart() {
+    let children = vec![
+        Bar::Song(Box::new(SongBar::new(make_chart(
+            "other", "f2", "Other", 1,
+        )))),
+        Bar::Song(Box::new(SongBar::new(make_chart(
+            "normal", "f1", "Song", 2,
+        )))),
+        Bar::Song(Box::new(SongBar::new(make_chart("hyper", "f1", "Song", 3)))),
+    ];
+    let container = Bar::Container(Box::new(ContainerBar::new("TestDir".to_string(), children)));
+
+    let config = Config::default();
+    let mut player_config = PlayerConfig::default();
+    player_config.select_settings.group_charts = true;
+    let mut ctx = UpdateBarContext {
+        config: &config,
+        player_config: &mut player_config,
+        songdb: &crate::select::null_song_database_accessor::NullSongDatabaseAccessor,
+        score_cache: None,
+        is_folderlamp: false,
+        max_search_bar_count: 10,
+    };
+
+    let mut manager = BarManager::new();
+    manager.update_bar_with_context(Some(&container), Some(&mut ctx));
+    assert_eq!(manager.currentsongs.len(), 2);
+    let index = manager
+        .currentsongs
+        .iter()
+        .position(|b| b.as_song_bar().unwrap().charts().len() == 2)
+        .unwrap();
+    manager.selectedindex = index;
+    manager.currentsongs[index]
+        .as_song_bar_mut()
+        .unwrap()
+        .select_chart("hyper");
+
+    // Reloading the folder keeps the cursor on the grouped bar showing HYPER
+    manager.update_bar_refresh_with_context(Some(&mut ctx));
+    let selected = manager.selected().and_then(|b| b.as_song_bar()).unwrap();
+    assert_eq!(selected.song_data().file.sha256, "hyper");
+}
+
+#[test]
+fn test_restore_session_position_reenters_folders_and_cursor() {
+    let config = Config::default();
+    let mut player_config = PlayerConfig::default();
+    let mut ctx = UpdateBarContext {
+        config: &config,
+        player_config: &mut player_config,
+        songdb: &crate::select::null_song_database_accessor::NullSongDatabaseAccessor,
+        score_cache: None,
+        is_folderlamp: false,
+        max_search_bar_count: 10,
+    };
+    let inner = Bar::Container(Box::new(ContainerBar::new(
+        "inner".to_string(),
+        vec![
+            make_song_bar("aaa", Some("/a.bms")),
+            make_song_bar("bbb", Some("/b.bms")),
+        ],
+    )));
+    let outer = Bar::Container(Box::new(ContainerBar::new(
+        "outer".to_string(),
+        vec![make_song_bar("ccc", Some("/c.bms")), inner],
+    )));
+
+    let mut manager = BarManager::new();
+    manager.set_append_directory_bar("outer".to_string(), outer);
+    manager.update_bar_with_context(None, Some(&mut ctx));
+    manager.restore_session_position(
+        &["outer".to_string(), "inner".to_string()],
+        "bbb",
+        Some(&mut ctx),
+    );
+
+    let (folders, selected) = manager.session_position();
+    assert_eq!(folders, vec!["outer".to_string(), "inner".to_string()]);
+    assert_eq!(selected, "bbb");
+
+    // A folder that no longer exists leaves the cursor in its parent.
+    manager.update_bar_with_context(None, Some(&mut ctx));
+    manager.restore_session_position(
+        &["outer".to_string(), "gone".to_string()],
+        "ccc",
+        Some(&mut ctx),
+    );
+    assert_eq!(
+        manager.session_position(),
+        (vec!["outer".to_string()], "ccc".to_string())
+    );
+}
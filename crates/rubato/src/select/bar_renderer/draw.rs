@@ -239,6 +239,43 @@ impl BarRenderer {
         }
     }
 
+    /// Draw DJ rank, EX score and miss count of the best score on song bars.
+    pub(super) fn draw_best_scores(
+        &self,
+        sprite: &mut SkinObjectRenderer,
+        baro: &mut SkinBar,
+        ctx: &RenderContext,
+    ) {
+        for i in 0..self.barlength {
+            let ba = &self.bararea[i];
+            if ba.value == -1 {
+                continue;
+            }
+            let Some(score) = ba
+                .sd
+                .map(|idx| &ctx.currentsongs[idx])
+                .filter(|sd| sd.as_song_bar().is_some_and(|sb| sb.exists_song()))
+                .and_then(|sd| sd.score())
+            else {
+                continue;
+            };
+            if let Some(rank) = dj_rank_index(score.exscore(), score.notes)
+                && let Some(image) = baro.rank.get_mut(rank).and_then(|o| o.as_mut())
+            {
+                image.draw_with_offset(sprite, ba.x, ba.y);
+            }
+            if let Some(number) = baro.score.as_mut() {
+                number.draw_with_value(sprite, self.time, score.exscore(), ctx.state, ba.x, ba.y);
+            }
+            if let Some(number) = baro.misscount.as_mut()
+                && score.minbp >= 0
+                && score.minbp < i32::MAX
+            {
+                number.draw_with_value(sprite, self.time, score.minbp, ctx.state, ba.x, ba.y);
+            }
+        }
+    }
+
     /// Draw difficulty level numbers for song and function bars.
     pub(super) fn draw_levels(
         &self,
@@ -372,3 +409,10 @@ impl BarRenderer {
         }
     }
 }
+
+/// SkinBar rank image index for an EX score: 0=AAA .. 7=F.
+/// None when the chart has no notes.
+pub(super) fn dj_rank_index(exscore: i32, notes: i32) -> Option<usize> {
+    crate::select::song_filter::dj_rank(exscore, notes)
+        .map(|rank| SkinBar::BARRANK_COUNT - rank as usize)
+}
@@ -26,6 +26,10 @@ pub struct BarRenderer {
     /// Bar movement direction
     pub angle: i32,
     pub keyinput: bool,
+    /// Length (ms) of the bar movement in progress, for easing
+    motion_length: i64,
+    /// Bars moved by the held scroll key since it was pressed
+    hold_steps: u32,
 
     /// Analog scroll buffer
     pub analog_scroll_buffer: i32,
@@ -52,6 +56,8 @@ impl BarRenderer {
             duration: 0,
             angle: 0,
             keyinput: false,
+            motion_length: 0,
+            hold_steps: 0,
             analog_scroll_buffer: 0,
             analog_ticks_per_scroll: analog_ticks_per_scroll.max(1),
             barlength,
@@ -128,6 +134,11 @@ impl BarRenderer {
             } else {
                 angle_lerp = (self.duration - time_millis) as f32 / self.angle as f32;
             }
+            // Single moves ease out; repeats of a held key keep a constant speed
+            // so consecutive bars flow into each other.
+            if self.hold_steps == 0 && self.motion_length > 0 {
+                angle_lerp *= eased_remaining(self.duration - time_millis, self.motion_length);
+            }
         }
 
         let now_secs = time_millis / 1000;
@@ -294,6 +305,7 @@ impl BarRenderer {
         self.draw_bar_text(sprite, baro, ctx);
         self.draw_trophies(sprite, baro, ctx);
         self.draw_lamps(sprite, baro, ctx);
+        self.draw_best_scores(sprite, baro, ctx);
         self.draw_levels(sprite, baro, ctx);
         self.draw_feature_labels(sprite, baro, ctx);
     }
@@ -329,8 +341,7 @@ impl BarRenderer {
                 self.duration = l;
             } else {
                 let scroll_duration = 120 / remaining_scroll / remaining_scroll;
-                self.angle = scroll_duration / remaining_scroll;
-                self.duration = l + scroll_duration as i64;
+                self.start_motion(l, scroll_duration, scroll_duration / remaining_scroll);
             }
         }
 
@@ -346,14 +357,15 @@ impl BarRenderer {
                 .as_millis() as i64;
             if self.duration == 0 {
                 self.keyinput = true;
+                self.hold_steps = 0;
                 mov = 1;
-                self.duration = l + self.durationlow as i64;
-                self.angle = self.durationlow;
+                self.start_motion(l, self.durationlow, self.durationlow);
             }
             if l > self.duration && self.keyinput {
-                self.duration = l + self.durationhigh as i64;
+                self.hold_steps += 1;
+                let interval = self.hold_interval();
                 mov = 1;
-                self.angle = self.durationhigh;
+                self.start_motion(l, interval, interval);
             }
         } else if ctx
             .property
@@ -366,17 +378,19 @@ impl BarRenderer {
                 .as_millis() as i64;
             if self.duration == 0 {
                 self.keyinput = true;
+                self.hold_steps = 0;
                 mov = -1;
-                self.duration = l + self.durationlow as i64;
-                self.angle = -self.durationlow;
+                self.start_motion(l, self.durationlow, -self.durationlow);
             }
             if l > self.duration && self.keyinput {
-                self.duration = l + self.durationhigh as i64;
+                self.hold_steps += 1;
+                let interval = self.hold_interval();
                 mov = -1;
-                self.angle = -self.durationhigh;
+                self.start_motion(l, interval, -interval);
             }
         } else {
             self.keyinput = false;
+            self.hold_steps = 0;
         }
 
         let l = std::time::SystemTime::now()
@@ -401,6 +415,24 @@ impl BarRenderer {
         }
     }
 
+    /// Start moving the bars for `length` ms. `angle` is the time per bar,
+    /// negative when scrolling up.
+    fn start_motion(&mut self, now: i64, length: i32, angle: i32) {
+        self.duration = now + length as i64;
+        self.angle = angle;
+        self.motion_length = length as i64;
+    }
+
+    /// Time per bar while a scroll key is held: it starts at `durationlow`
+    /// and shortens by a quarter each bar until it reaches `durationhigh`.
+    fn hold_interval(&self) -> i32 {
+        let mut interval = self.durationlow;
+        for _ in 0..self.hold_steps.min(16) {
+            interval = interval * 3 / 4;
+        }
+        interval.max(self.durationhigh).max(1)
+    }
+
     pub fn reset_input(&mut self) {
         let l = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -420,5 +452,11 @@ impl BarRenderer {
     }
 }
 
+/// Ease-out factor applied to the linear bar offset: the fraction of the
+/// movement still left, so bars leave quickly and settle into place.
+fn eased_remaining(remaining: i64, length: i64) -> f32 {
+    (remaining as f32 / length as f32).clamp(0.0, 1.0)
+}
+
 #[cfg(test)]
 mod tests;
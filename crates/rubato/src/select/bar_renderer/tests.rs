@@ -616,6 +616,51 @@ fn test_bar_renderer_prepare_angle_zero_no_nan() {
     );
 }
 
+#[test]
+fn test_bar_renderer_hold_scroll_accelerates_to_durationhigh() {
+    let mut renderer = BarRenderer::new(300, 100, 5);
+    let intervals: Vec<i32> = (1..=5)
+        .map(|steps| {
+            renderer.hold_steps = steps;
+            renderer.hold_interval()
+        })
+        .collect();
+    assert_eq!(intervals, vec![225, 168, 126, 100, 100]);
+}
+
+#[test]
+fn test_bar_renderer_single_move_eases_out_between_skin_positions() {
+    let mut bar = SkinBar::new(0);
+    bar.barimageoff[0] = Some(make_test_image(10.0, 20.0, 100.0, 30.0));
+    bar.barimageoff[1] = Some(make_test_image(10.0, 60.0, 100.0, 30.0));
+    let songs = vec![
+        make_song_bar_bar("a", Some("/a.bms")),
+        make_song_bar_bar("b", Some("/b.bms")),
+    ];
+    let ctx = PrepareContext {
+        center_bar: 2,
+        currentsongs: &songs,
+        selectedindex: 0,
+    };
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64;
+
+    // Halfway through a 1000ms move: the linear offset is half a bar (20px),
+    // the eased one a quarter (10px).
+    let mut renderer = BarRenderer::new(300, 100, 5);
+    renderer.start_motion(now - 500, 1000, 1000);
+    renderer.prepare(&bar, 0, &ctx);
+    let eased = renderer.bararea[0].y;
+    assert!((28.0..=31.0).contains(&eased), "eased y {eased}");
+
+    renderer.hold_steps = 2;
+    renderer.prepare(&bar, 0, &ctx);
+    let linear = renderer.bararea[0].y;
+    assert!((38.0..=41.0).contains(&linear), "linear y {linear}");
+}
+
 #[test]
 fn test_bar_renderer_mouse_pressed_no_songs() {
     let renderer = BarRenderer::new(300, 100, 5);
@@ -781,3 +826,17 @@ fn test_bar_renderer_mouse_pressed_negative_center_bar() {
     // Should not panic
     let _result = renderer.mouse_pressed(&bar, 0, 100, 100, &ctx);
 }
+
+#[test]
+fn test_dj_rank_index_maps_score_rate_to_rank_images() {
+    use super::draw::dj_rank_index;
+
+    assert_eq!(dj_rank_index(0, 0), None);
+    // 1000 notes: max EX score 2000
+    assert_eq!(dj_rank_index(2000, 1000), Some(0));
+    assert_eq!(dj_rank_index(1778, 1000), Some(0));
+    assert_eq!(dj_rank_index(1777, 1000), Some(1));
+    assert_eq!(dj_rank_index(1334, 1000), Some(2));
+    assert_eq!(dj_rank_index(445, 1000), Some(6));
+    assert_eq!(dj_rank_index(0, 1000), Some(7));
+}
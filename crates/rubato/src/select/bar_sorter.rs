@@ -146,10 +146,9 @@ impl BarSorter {
         if let (Some(s1), Some(s2)) = (o1.as_song_bar(), o2.as_song_bar()) {
             let title_cmp = s1
                 .song
-                .metadata
-                .title
+                .display_title()
                 .to_lowercase()
-                .cmp(&s2.song.metadata.title.to_lowercase());
+                .cmp(&s2.song.display_title().to_lowercase());
             if title_cmp == Ordering::Equal {
                 return s1.song.chart.difficulty.cmp(&s2.song.chart.difficulty);
             }
@@ -174,10 +173,9 @@ impl BarSorter {
             return Ordering::Less;
         }
         s1.song
-            .metadata
-            .artist
+            .sort_artist()
             .to_lowercase()
-            .cmp(&s2.song.metadata.artist.to_lowercase())
+            .cmp(&s2.song.sort_artist().to_lowercase())
     }
 
     fn compare_bpm(o1: &Bar, o2: &Bar) -> Ordering {
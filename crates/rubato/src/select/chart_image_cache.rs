@@ -0,0 +1,165 @@
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+use crate::core::bms_resource::{TextureRegion, load_resource_image};
+use crate::skin::song_data::SongData;
+
+/// Charts whose images are kept once loaded.
+const CACHE_CAPACITY: usize = 16;
+
+/// #STAGEFILE, #BANNER and #BACKBMP of one chart.
+#[derive(Clone, Default)]
+pub struct ChartImages {
+    pub stagefile: Option<TextureRegion>,
+    pub banner: Option<TextureRegion>,
+    pub backbmp: Option<TextureRegion>,
+}
+
+/// Decodes chart images on background threads and keeps those of the most
+/// recently requested charts, keyed by SHA-256.
+pub struct ChartImageCache {
+    images: HashMap<String, ChartImages>,
+    /// Cached and loading charts, least recently requested first
+    order: VecDeque<String>,
+    tx: Sender<(String, ChartImages)>,
+    rx: Receiver<(String, ChartImages)>,
+}
+
+impl Default for ChartImageCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChartImageCache {
+    pub fn new() -> Self {
+        let (tx, rx) = channel();
+        Self {
+            images: HashMap::new(),
+            order: VecDeque::new(),
+            tx,
+            rx,
+        }
+    }
+
+    /// Start loading the images of `song` unless they are cached or loading.
+    pub fn request(&mut self, song: &SongData) {
+        let sha256 = &song.file.sha256;
+        if sha256.is_empty() {
+            return;
+        }
+        if let Some(pos) = self.order.iter().position(|h| h == sha256) {
+            if let Some(hash) = self.order.remove(pos) {
+                self.order.push_back(hash);
+            }
+            return;
+        }
+        let Some(parent) = song.file.path().and_then(|p| Path::new(p).parent()) else {
+            return;
+        };
+
+        self.order.push_back(sha256.clone());
+        while self.order.len() > CACHE_CAPACITY {
+            if let Some(evicted) = self.order.pop_front() {
+                self.images.remove(&evicted);
+            }
+        }
+
+        let parent = parent.to_path_buf();
+        let names = [
+            song.file.stagefile.clone(),
+            song.file.banner.clone(),
+            song.file.backbmp.clone(),
+        ];
+        let sha256 = sha256.clone();
+        let tx = self.tx.clone();
+        std::thread::spawn(move || {
+            let [stagefile, banner, backbmp] =
+                names.map(|name| load_resource_image(&parent, &name));
+            let _ = tx.send((
+                sha256,
+                ChartImages {
+                    stagefile,
+                    banner,
+                    backbmp,
+                },
+            ));
+        });
+    }
+
+    /// Store the images finished loading since the last call.
+    pub fn poll(&mut self) {
+        while let Ok((sha256, images)) = self.rx.try_recv() {
+            // Charts evicted while loading are dropped
+            if self.order.contains(&sha256) {
+                self.images.insert(sha256, images);
+            }
+        }
+    }
+
+    /// Images of chart `sha256`, once loaded.
+    pub fn get(&self, sha256: &str) -> Option<&ChartImages> {
+        self.images.get(sha256)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn song_in(dir: &Path, sha256: &str) -> SongData {
+        let mut song = SongData::default();
+        song.file.sha256 = sha256.to_string();
+        song.file
+            .set_path(dir.join("chart.bms").to_string_lossy().to_string());
+        song
+    }
+
+    fn wait_for(cache: &mut ChartImageCache, sha256: &str) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while cache.get(sha256).is_none() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(5));
+            cache.poll();
+        }
+    }
+
+    #[test]
+    fn loads_chart_images_in_background() {
+        let dir = tempfile::tempdir().unwrap();
+        image::RgbaImage::from_pixel(8, 6, image::Rgba([255, 0, 0, 255]))
+            .save(dir.path().join("stage.png"))
+            .unwrap();
+        image::RgbaImage::from_pixel(4, 2, image::Rgba([0, 255, 0, 255]))
+            .save(dir.path().join("banner.png"))
+            .unwrap();
+        let mut song = song_in(dir.path(), "abc");
+        song.file.stagefile = "stage.png".to_string();
+        song.file.banner = "banner.png".to_string();
+        song.file.backbmp = "missing.png".to_string();
+
+        let mut cache = ChartImageCache::new();
+        cache.request(&song);
+        wait_for(&mut cache, "abc");
+
+        let images = cache.get("abc").expect("images should finish loading");
+        assert_eq!(images.stagefile.as_ref().unwrap().region_width, 8);
+        assert_eq!(images.banner.as_ref().unwrap().region_width, 4);
+        assert!(images.backbmp.is_none());
+    }
+
+    #[test]
+    fn evicts_least_recently_requested_chart() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = ChartImageCache::new();
+        cache.request(&song_in(dir.path(), "first"));
+        wait_for(&mut cache, "first");
+        assert!(cache.get("first").is_some());
+
+        for i in 0..CACHE_CAPACITY {
+            cache.request(&song_in(dir.path(), &format!("chart{i}")));
+        }
+        assert!(cache.get("first").is_none());
+    }
+}
@@ -0,0 +1,49 @@
+//! Progress of the background library scan, shown on the select screen.
+//!
+//! MusicSelector publishes the scan progress each frame; the egui frame
+//! draws it in the bottom left corner while a scan runs.
+
+use std::sync::Mutex;
+
+use crate::skin::sync_utils::lock_or_recover;
+use crate::song::library_scan::LibraryScanProgress;
+
+static PROGRESS: Mutex<Option<LibraryScanProgress>> = Mutex::new(None);
+
+pub struct LibraryScanIndicator;
+
+impl LibraryScanIndicator {
+    /// Show `progress`, or hide the indicator with `None`.
+    pub fn set(progress: Option<LibraryScanProgress>) {
+        *lock_or_recover(&PROGRESS) = progress;
+    }
+
+    pub fn summary(progress: &LibraryScanProgress) -> String {
+        format!(
+            "Scanning library: {}/{} processed, {} new",
+            progress.processed_files, progress.bms_files, progress.new_files
+        )
+    }
+
+    /// Called from the egui frame (render thread) via static dispatch.
+    pub fn render_egui(ctx: &egui::Context) {
+        let Some(progress) = *lock_or_recover(&PROGRESS) else {
+            return;
+        };
+        let fraction = if progress.bms_files > 0 {
+            progress.processed_files as f32 / progress.bms_files as f32
+        } else {
+            0.0
+        };
+        egui::Area::new(egui::Id::new("library_scan_indicator"))
+            .anchor(egui::Align2::LEFT_BOTTOM, egui::vec2(8.0, -8.0))
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_width(280.0);
+                    ui.add(egui::ProgressBar::new(fraction).text(Self::summary(&progress)));
+                });
+            });
+        ctx.request_repaint();
+    }
+}
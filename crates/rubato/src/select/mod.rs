@@ -120,6 +120,8 @@ pub mod bar;
 pub mod bar_manager;
 pub mod bar_renderer;
 pub mod bar_sorter;
+pub mod chart_image_cache;
+pub mod library_scan_indicator;
 pub mod music_select_command;
 pub mod music_select_input_processor;
 pub mod music_select_key_property;
@@ -131,3 +133,5 @@ pub mod score_data_cache;
 pub mod search_text_field;
 pub mod skin_bar;
 pub mod skin_distribution_graph;
+pub mod song_filter;
+pub mod stats_dashboard;
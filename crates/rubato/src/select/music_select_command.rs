@@ -7,8 +7,9 @@ use super::bar::container_bar::ContainerBar;
 use super::bar::context_menu_bar::ContextMenuBar;
 use super::bar::same_folder_bar::SameFolderBar;
 use super::bar::song_bar::SongBar;
-use super::music_selector::{MusicSelector, REPLAY};
+use super::music_selector::{MusicSelector, REPLAY, RandomSelectFilter};
 use super::*;
+use crate::tr;
 
 /// Music select commands
 /// Translates: bms.player.beatoraja.select.MusicSelectCommand
@@ -29,6 +30,14 @@ pub enum MusicSelectCommand {
     ShowSongsOnSameFolder,
     ShowContextMenu,
     CopyHighlightedMenuText,
+    SkipStreamRequest,
+    RandomSelect,
+    RandomSelectUnplayed,
+    RandomSelectFailed,
+    NextChart,
+    PrevChart,
+    UpdateLibrary,
+    OpenStatistics,
 }
 
 impl MusicSelectCommand {
@@ -89,7 +98,7 @@ impl MusicSelectCommand {
                         && let Ok(mut clipboard) = arboard::Clipboard::new()
                     {
                         let _ = clipboard.set_text(hash.to_string());
-                        ImGuiNotify::info(&format!("MD5 hash copied: {}", hash));
+                        ImGuiNotify::info(&tr!("notify-md5-copied", hash = hash.to_string()));
                     }
                 }
             }
@@ -102,7 +111,7 @@ impl MusicSelectCommand {
                         && let Ok(mut clipboard) = arboard::Clipboard::new()
                     {
                         let _ = clipboard.set_text(hash.to_string());
-                        ImGuiNotify::info(&format!("SHA256 hash copied: {}", hash));
+                        ImGuiNotify::info(&tr!("notify-sha256-copied", hash = hash.to_string()));
                     }
                 }
             }
@@ -135,7 +144,11 @@ impl MusicSelectCommand {
                     if !md5.is_empty() {
                         log::info!("Missing song md5: {}", md5);
                         if let Some(ref downloader) = selector.http_downloader {
-                            downloader.submit_md5_task(md5, &song.metadata.title);
+                            downloader.submit_md5_task_in_table(
+                                md5,
+                                &song.metadata.title,
+                                selector.current_table_name().as_deref(),
+                            );
                         }
                     } else {
                         log::info!("Not a valid song bar? Skipped...");
@@ -147,11 +160,16 @@ impl MusicSelectCommand {
                     && let Some(grade_bar) = selected.as_grade_bar()
                     && let Some(ref downloader) = selector.http_downloader
                 {
+                    let table = selector.current_table_name();
                     for song in grade_bar.song_datas() {
                         let md5 = &song.file.md5;
                         if !md5.is_empty() {
                             log::info!("Missing song md5: {}", md5);
-                            downloader.submit_md5_task(md5, &song.metadata.title);
+                            downloader.submit_md5_task_in_table(
+                                md5,
+                                &song.metadata.title,
+                                table.as_deref(),
+                            );
                         }
                     }
                 }
@@ -210,6 +228,16 @@ impl MusicSelectCommand {
                         } else {
                             selector.select_song(BMSPlayerMode::PLAY);
                         }
+                    } else if let Some(grade_bar) = current.as_grade_bar() {
+                        if !already_in_context_menu {
+                            let menu = ContextMenuBar::new_for_course(
+                                grade_bar.clone(),
+                                BMSPlayerMode::PLAY,
+                            );
+                            let bar = Bar::ContextMenu(Box::new(menu));
+                            selector.update_bar_with_songdb_context(Some(&bar));
+                            selector.play_sound(SoundType::FolderOpen);
+                        }
                     } else if let Some(table_bar) = current.as_table_bar() {
                         if !already_in_context_menu {
                             let menu = ContextMenuBar::new_for_table(table_bar.clone());
@@ -244,10 +272,37 @@ impl MusicSelectCommand {
                         && let Ok(mut clipboard) = arboard::Clipboard::new()
                     {
                         let _ = clipboard.set_text(content);
-                        ImGuiNotify::info(&format!("Copied highlighted menu text: {}", content));
+                        ImGuiNotify::info(&tr!(
+                            "notify-menu-text-copied",
+                            text = content.to_string()
+                        ));
                     }
                 }
             }
+            MusicSelectCommand::SkipStreamRequest => {
+                selector.skip_selected_stream_request();
+            }
+            MusicSelectCommand::RandomSelect => {
+                selector.random_select(RandomSelectFilter::All);
+            }
+            MusicSelectCommand::RandomSelectUnplayed => {
+                selector.random_select(RandomSelectFilter::Unplayed);
+            }
+            MusicSelectCommand::RandomSelectFailed => {
+                selector.random_select(RandomSelectFilter::Failed);
+            }
+            MusicSelectCommand::NextChart => {
+                selector.cycle_selected_chart(1);
+            }
+            MusicSelectCommand::PrevChart => {
+                selector.cycle_selected_chart(-1);
+            }
+            MusicSelectCommand::UpdateLibrary => {
+                selector.request_library_update();
+            }
+            MusicSelectCommand::OpenStatistics => {
+                selector.open_stats_dashboard();
+            }
         }
     }
 }
@@ -358,6 +358,18 @@ impl MusicSelectInputProcessor {
             ctx.bar_renderer_do_input = true;
             ctx.panel_state = Some(0);
 
+            // Left/Right switch between the charts grouped into the selected song bar
+            if ctx.selected_bar_grouped {
+                if input.is_control_key_pressed(ControlKeys::Left) {
+                    ctx.events
+                        .push(InputEvent::Execute(MusicSelectCommand::PrevChart));
+                }
+                if input.is_control_key_pressed(ControlKeys::Right) {
+                    ctx.events
+                        .push(InputEvent::Execute(MusicSelectCommand::NextChart));
+                }
+            }
+
             // Determine current bar type for dispatch
             let current_bar_type = ctx.selected_bar_type;
 
@@ -467,6 +479,25 @@ impl MusicSelectInputProcessor {
                 ctx.events
                     .push(InputEvent::ExecuteEvent(EventType::FavoriteChart));
             }
+            if input.is_activated(KeyCommand::SkipStreamRequest)
+                && current_bar_type == BarType::Song
+            {
+                ctx.events
+                    .push(InputEvent::Execute(MusicSelectCommand::SkipStreamRequest));
+            }
+            if input.is_activated(KeyCommand::RandomSelect) {
+                ctx.events
+                    .push(InputEvent::Execute(MusicSelectCommand::RandomSelect));
+            }
+            if input.is_activated(KeyCommand::RandomSelectUnplayed) {
+                ctx.events.push(InputEvent::Execute(
+                    MusicSelectCommand::RandomSelectUnplayed,
+                ));
+            }
+            if input.is_activated(KeyCommand::RandomSelectFailed) {
+                ctx.events
+                    .push(InputEvent::Execute(MusicSelectCommand::RandomSelectFailed));
+            }
         }
 
         // songbar change timer (always active, outside conditional blocks)
@@ -477,6 +508,16 @@ impl MusicSelectInputProcessor {
             ctx.events
                 .push(InputEvent::ExecuteEvent(EventType::UpdateFolder));
         }
+        // Update library (KeyCommand)
+        if input.is_activated(KeyCommand::UpdateLibrary) {
+            ctx.events
+                .push(InputEvent::Execute(MusicSelectCommand::UpdateLibrary));
+        }
+        // Statistics (KeyCommand)
+        if input.is_activated(KeyCommand::OpenStatistics) {
+            ctx.events
+                .push(InputEvent::Execute(MusicSelectCommand::OpenStatistics));
+        }
         // Open explorer
         if input.is_activated(KeyCommand::OpenExplorer) {
             ctx.events
@@ -577,6 +618,8 @@ pub struct InputContext<'a> {
     pub selected_bar_type: BarType,
     pub selected_replay: i32,
     pub is_top_level: bool,
+    /// Whether the selected song bar groups several charts.
+    pub selected_bar_grouped: bool,
 
     // Output fields — set by input processing
     pub events: Vec<InputEvent>,
@@ -600,6 +643,7 @@ impl<'a> InputContext<'a> {
             selected_bar_type,
             selected_replay,
             is_top_level,
+            selected_bar_grouped: false,
             events: Vec::new(),
             panel_state: None,
             bar_renderer_reset_input: false,
@@ -1,4 +1,8 @@
 use super::*;
+use crate::play::target_property::{
+    HistoryTargetProperty, TargetProperty, history_target_score, ranking_target_score,
+};
+use crate::tr;
 
 impl MusicSelector {
     /// Play the OPTION_CHANGE system sound and mark PlayerConfig as dirty.
@@ -84,7 +88,7 @@ impl MusicSelector {
         let path_str = match song.file.path() {
             Some(p) => p,
             None => {
-                ImGuiNotify::error("Failed to loading BMS : Song not found, or Song has error");
+                ImGuiNotify::error(&tr!("notify-song-load-failed"));
                 return;
             }
         };
@@ -186,9 +190,10 @@ impl MusicSelector {
                 .set_chart_option_data(chart_option);
 
             self.playedsong = Some(song.clone());
+            self.accept_stream_request(song);
             self.pending_state_change = Some(MainStateType::Decide);
         } else {
-            ImGuiNotify::error("Failed to loading BMS : Song not found, or Song has error");
+            ImGuiNotify::error(&tr!("notify-song-load-failed"));
         }
     }
 
@@ -268,7 +273,7 @@ impl MusicSelector {
     /// Recompute cached_target_score based on config.select_settings.targetid
     /// and the selected song's total notes. Called before rendering.
     pub(super) fn refresh_cached_target_score(&mut self) {
-        let targetid = self.config.select_settings.targetid.clone();
+        let targetid = self.config.select_settings.resolved_targetid();
         // MYBEST and direct RIVAL_N targets are resolved via existing bar data (no cache needed).
         // RIVAL_RANK_* and RIVAL_NEXT_* need ranked rival data computed during gameplay;
         // on the select screen they resolve to None (no cache).
@@ -347,6 +352,12 @@ impl MusicSelector {
             return;
         }
 
+        // History targets: resolve from the selected chart's play log
+        if let Some(TargetProperty::History(target)) = TargetProperty::from_id(&targetid) {
+            self.cached_target_score = self.resolve_history_target_score(&target);
+            return;
+        }
+
         // IR-based targets: resolve from ranking data if available
         if targetid.starts_with("IR_") {
             self.cached_target_score = self.resolve_ir_target_score(&targetid);
@@ -368,62 +379,81 @@ impl MusicSelector {
             .update_score_and_rival(score, rival);
     }
 
+    /// Reload the replay slot overlay metadata when the selected song changes.
+    /// Only slots flagged as existing on the bar are read from disk.
+    pub(super) fn refresh_cached_replay_slots(&mut self) {
+        let Some(song_bar) = self.manager.selected().and_then(|b| b.as_song_bar()) else {
+            self.cached_replay_slots = CachedReplaySlots::default();
+            return;
+        };
+        let song = song_bar.song_data();
+        let lnmode = self.config.play_settings.lnmode;
+        let exists: [bool; REPLAY] =
+            std::array::from_fn(|i| song_bar.selectable.exists_replay(i as i32));
+        let cache = &self.cached_replay_slots;
+        if cache.sha256 == song.file.sha256 && cache.lnmode == lnmode && cache.exists == exists {
+            return;
+        }
+
+        let has_ln = song.chart.has_undefined_long_note();
+        let slots = std::array::from_fn(|i| {
+            if !exists[i] {
+                return None;
+            }
+            self.play_data_accessor
+                .as_ref()?
+                .read_replay_data(&song.file.sha256, has_ln, lnmode, i as i32)
+                .map(|rd| rd.slot_info())
+        });
+        self.cached_replay_slots = CachedReplaySlots {
+            sha256: song.file.sha256.clone(),
+            lnmode,
+            exists,
+            slots,
+        };
+    }
+
+    /// Resolve a history target score from the selected chart's play log.
+    fn resolve_history_target_score(
+        &self,
+        target: &HistoryTargetProperty,
+    ) -> Option<crate::skin::score_data::ScoreData> {
+        let song = self.manager.selected()?.as_song_bar()?.song_data();
+        let logs = self.play_data_accessor.as_ref()?.read_play_logs(
+            &song.file.sha256,
+            song.chart.has_undefined_long_note(),
+            self.config.play_settings.lnmode,
+            target.play_log_count(),
+        );
+        let today = crate::core::score_database_accessor::local_midnight_timestamp();
+        let mut score = history_target_score(&logs, target.target, target.value, today);
+        score.player = target.name();
+        Some(score)
+    }
+
     /// Resolve an IR-based target score from the current ranking data.
     fn resolve_ir_target_score(
         &self,
         targetid: &str,
     ) -> Option<crate::skin::score_data::ScoreData> {
         let rd = self.ranking.currentir.as_ref()?;
-        if rd.state() != crate::ir::ranking_data::FINISH || rd.total_player() == 0 {
+        if rd.state() != crate::ir::ranking_data::FINISH {
             return None;
         }
-        let total = rd.total_player();
-
-        // Determine target index from the IR target type
-        let target_index = if let Some(suffix) = targetid.strip_prefix("IR_NEXT_")
-            && let Ok(value) = suffix.parse::<i32>()
-            && value >= 1
-        {
-            // On the select screen, nowscore is the local best score
-            let nowscore = self
-                .manager
-                .selected()
-                .and_then(|b| b.as_song_bar())
-                .and_then(|sb| sb.selectable.bar_data.score())
-                .map(|s| s.exscore())
-                .unwrap_or(0);
-            // Default to rank 0 (best player) when local score is below every IR entry.
-            let mut idx = 0;
-            for i in 0..total {
-                if let Some(score) = rd.score(i)
-                    && score.exscore() <= nowscore
-                {
-                    idx = (i - value).max(0);
-                    break;
-                }
-            }
-            idx
-        } else if let Some(suffix) = targetid.strip_prefix("IR_RANKRATE_")
-            && let Ok(value) = suffix.parse::<i32>()
-            && value > 0
-            && value < 100
-        {
-            total * value / 100
-        } else if let Some(suffix) = targetid.strip_prefix("IR_RANK_")
-            && let Ok(value) = suffix.parse::<i32>()
-            && value > 0
-        {
-            (value.min(total) - 1).max(0)
-        } else {
+        let Some(TargetProperty::InternetRanking(target)) = TargetProperty::from_id(targetid)
+        else {
             return None;
         };
-
-        let ir_score = rd.score(target_index)?;
-        let exscore = ir_score.exscore();
-        let mut score = crate::skin::score_data::ScoreData::default();
-        score.judge_counts.epg = exscore / 2;
-        score.judge_counts.egr = exscore % 2;
-        Some(score)
+        // On the select screen, nowscore is the local best score
+        let nowscore = self
+            .manager
+            .selected()
+            .and_then(|b| b.as_song_bar())
+            .and_then(|sb| sb.selectable.bar_data.score())
+            .map(|s| s.exscore())
+            .unwrap_or(0);
+        let score = ranking_target_score(rd, target.target, target.value, nowscore);
+        (score.player != "NO DATA").then_some(score)
     }
 
     pub fn sort(&self) -> i32 {
@@ -517,6 +547,27 @@ impl MusicSelector {
         &mut self.manager
     }
 
+    /// Show the next (`step` > 0) or previous chart grouped into the selected song bar.
+    pub(crate) fn cycle_selected_chart(&mut self, step: i32) {
+        let index = self.manager.selectedindex;
+        let cycled = self
+            .manager
+            .currentsongs
+            .get_mut(index)
+            .and_then(|bar| bar.as_song_bar_mut())
+            .is_some_and(|sb| sb.cycle_chart(step));
+        if !cycled {
+            return;
+        }
+        // Score, rival score and replays of the chart now shown
+        self.load_bar_contents();
+        if let Some(bar) = self.bar_rendering.bar.as_mut() {
+            bar.update_bar_text();
+        }
+        self.selected_bar_moved();
+        self.play_sound(SoundType::Scratch);
+    }
+
     /// Handle bar selection change.
     /// Corresponds to Java MusicSelector.selectedBarMoved()
     pub fn selected_bar_moved(&mut self) {
@@ -655,4 +706,44 @@ impl MusicSelector {
             res.set_bms_stagefile_raw(stagefile_data);
         }
     }
+
+    /// Register the selected chart's stagefile, banner and backbmp as skin
+    /// images once they finish loading, clearing them while they load.
+    pub(super) fn update_chart_images(&mut self) {
+        let selected = self
+            .manager
+            .selected()
+            .and_then(|bar| bar.as_song_bar())
+            .filter(|sb| sb.exists_song())
+            .map(|sb| sb.song_data());
+        if let Some(song) = selected {
+            self.chart_images.request(song);
+        }
+        self.chart_images.poll();
+        let shown = selected
+            .map(|song| song.file.sha256.as_str())
+            .filter(|sha256| self.chart_images.get(sha256).is_some());
+        if shown == self.shown_chart_images.as_deref() {
+            return;
+        }
+        let shown = shown.map(str::to_owned);
+        let images = shown
+            .as_deref()
+            .and_then(|sha256| self.chart_images.get(sha256))
+            .cloned()
+            .unwrap_or_default();
+        if let Some(skin) = self.main_state_data.skin.as_mut() {
+            for (id, image) in [
+                (skin_property::IMAGE_STAGEFILE, images.stagefile),
+                (skin_property::IMAGE_BANNER, images.banner),
+                (skin_property::IMAGE_BACKBMP, images.backbmp),
+            ] {
+                match image {
+                    Some(image) => skin.register_image(id, image),
+                    None => skin.unregister_image(id),
+                }
+            }
+        }
+        self.shown_chart_images = shown;
+    }
 }
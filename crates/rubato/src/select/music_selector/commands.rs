@@ -1,4 +1,5 @@
 use super::*;
+use crate::tr;
 
 impl MusicSelector {
     pub fn new() -> Self {
@@ -26,11 +27,14 @@ impl MusicSelector {
             playedcourse: None,
             banners: PixmapResourcePool::with_maxgen(2),
             stagefiles: PixmapResourcePool::with_maxgen(2),
+            chart_images: crate::select::chart_image_cache::ChartImageCache::new(),
+            shown_chart_images: None,
             ranking_data_cache: None,
             ir_connection: None,
             play_data_accessor: None,
             info_database: None,
             rivals: Vec::new(),
+            rival_score_dbs: Vec::new(),
             sound_paths: std::collections::HashMap::new(),
             http_downloader: None,
             ipfs_download_alive: false,
@@ -55,12 +59,25 @@ impl MusicSelector {
             cached_score_data_property: crate::skin::score_data_property::ScoreDataProperty::new(),
             pending_ir_song_fetch: None,
             pending_ir_course_fetch: None,
+            pending_course: None,
+            library_revision: 0,
+            library_scan: None,
+            scan_revision: 0,
             pending_note_graph: None,
+            cached_replay_slots: CachedReplaySlots::default(),
             background_threads: Vec::new(),
+            stream_requests: StreamRequestQueue::default(),
+            random_select_roulette: None,
+            preloaded_skin: None,
             is_active: false,
         }
     }
 
+    /// Use `skin` for the next select skin load instead of reading it from disk.
+    pub fn set_preloaded_skin(&mut self, skin: crate::skin::types::skin::Skin) {
+        self.preloaded_skin = Some(skin);
+    }
+
     /// Create a MusicSelector with an injected song database accessor.
     ///
     /// Translated from: MusicSelector(MainController main, boolean songUpdated)
@@ -135,7 +152,23 @@ impl MusicSelector {
         ));
     }
 
+    /// Load the contents of the bars around the cursor after the bar list
+    /// changed. Bars further away are loaded by `load_visible_bar_contents`
+    /// as the cursor moves.
     pub(super) fn load_bar_contents(&mut self) {
+        self.manager.reset_contents_window();
+        self.load_visible_bar_contents();
+    }
+
+    /// Load scores, song information and images of the bars near the cursor
+    /// that are not loaded yet.
+    pub(super) fn load_visible_bar_contents(&mut self) {
+        let ranges = self
+            .manager
+            .take_unloaded_window(crate::select::bar_manager::CONTENTS_WINDOW_RADIUS);
+        if ranges.is_empty() {
+            return;
+        }
         self.ensure_local_score_cache();
 
         let pda = self.play_data_accessor.as_ref();
@@ -170,8 +203,10 @@ impl MusicSelector {
             command_bar_ctx: None,
         };
         let stop = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
-        crate::select::bar_manager::BarContentsLoaderThread::new(stop)
-            .run(&mut self.manager.currentsongs, &mut ctx);
+        let loader = crate::select::bar_manager::BarContentsLoaderThread::new(stop);
+        for range in ranges {
+            loader.run(&mut self.manager.currentsongs[range], &mut ctx);
+        }
     }
 
     /// Refresh the bar list with song database context.
@@ -193,6 +228,54 @@ impl MusicSelector {
         }
     }
 
+    /// Reload the bar list once downloaded packages or library scans have been
+    /// committed to the song database, so new charts appear without leaving
+    /// select. Also publishes the scan progress to the on-screen indicator.
+    pub(super) fn poll_library_revision(&mut self) {
+        let mut changed = false;
+        if let Some(revision) = self.http_downloader.as_ref().map(|d| d.library_revision())
+            && revision != self.library_revision
+        {
+            self.library_revision = revision;
+            changed = true;
+        }
+        if let Some(ref scan) = self.library_scan {
+            LibraryScanIndicator::set(scan.progress());
+            let revision = scan.revision();
+            if revision != self.scan_revision {
+                self.scan_revision = revision;
+                changed = true;
+            }
+        }
+        if changed {
+            self.refresh_bar_with_context();
+        }
+    }
+
+    /// Share the library scan state and take its current revision as loaded.
+    pub fn set_library_scan(
+        &mut self,
+        scan: std::sync::Arc<crate::song::library_scan::LibraryScanStatus>,
+    ) {
+        self.scan_revision = scan.revision();
+        self.library_scan = Some(scan);
+    }
+
+    /// Scan every BMS root for new and changed charts in the background.
+    pub(crate) fn request_library_update(&mut self) {
+        self.pending_update_song = Some(None);
+        ImGuiNotify::info(&tr!("notify-library-scan-started"));
+    }
+
+    /// Title of the difficulty table the current folder belongs to, if any.
+    pub(crate) fn current_table_name(&self) -> Option<String> {
+        self.manager
+            .directory()
+            .iter()
+            .find_map(|bar| bar.as_table_bar())
+            .map(|table| table.title().to_string())
+    }
+
     /// Navigate into a bar (directory, folder, etc.) with song database context.
     /// Used by MusicSelectCommand and ContextMenuBar executors.
     pub fn update_bar_with_songdb_context(&mut self, bar: Option<&Bar>) -> bool {
@@ -216,7 +299,15 @@ impl MusicSelector {
     pub fn set_rival(&mut self, rival: Option<PlayerInformation>) {
         // In Java: finds rival index, sets rival and rival cache, updates bar
         self.rival = rival;
-        self.ranking.rivalcache = None;
+        // Back rival bar scores with the selected rival's score database.
+        self.ranking.rivalcache = self
+            .rival
+            .as_ref()
+            .and_then(|r| self.rivals.iter().position(|p| p == r))
+            .and_then(|index| self.rival_score_dbs.get(index))
+            .map(|path| {
+                crate::core::rival_data_accessor::RivalDataAccessor::create_score_cache_for_db(path)
+            });
         self.refresh_bar_with_context();
         log::info!(
             "Rival changed: {}",
@@ -16,6 +16,7 @@ pub(crate) use super::bar_manager::BarManager;
 pub(crate) use super::bar_renderer::BarRenderer;
 pub(crate) use super::bar_renderer::{PrepareContext, RenderContext};
 pub(crate) use super::bar_sorter::BarSorter;
+pub(crate) use super::library_scan_indicator::LibraryScanIndicator;
 pub(crate) use super::music_select_command::MusicSelectCommand;
 pub(crate) use super::music_select_input_processor::{
     BarType, InputContext, InputEvent, MusicSelectInputProcessor,
@@ -416,6 +417,9 @@ impl crate::skin::skin_render_context::SkinRenderContext for SelectSkinContext<'
                         .fold(0i32, |acc, &x| acc.saturating_add(x))
                 })
                 .unwrap_or(i32::MIN),
+            301 => self
+                .selected_directory_data()
+                .map_or(i32::MIN, |directory| directory.missing()),
             // Song score-related stats
             71 => self.selected_score().map_or(i32::MIN, |s| s.exscore()),
             75 => self.selected_score().map_or(i32::MIN, |s| s.maxcombo),
@@ -433,6 +437,12 @@ impl crate::skin::skin_render_context::SkinRenderContext for SelectSkinContext<'
             103 => self
                 .selected_score_property()
                 .map_or(i32::MIN, |property| property.now_rate_after_dot()),
+            // Replay slot overlay
+            470..=505 => crate::skin::replay_data::ReplaySlotInfo::integer_value(
+                &self.selector.cached_replay_slots.slots,
+                id,
+            )
+            .unwrap_or(i32::MIN),
             // Song duration
             312 => self.selected_song_data().map_or(0, |s| s.chart.length),
             1163 => self
@@ -468,13 +478,9 @@ impl crate::skin::skin_render_context::SkinRenderContext for SelectSkinContext<'
             11 => self
                 .selected_song_data()
                 .map_or_else(String::new, |s| s.metadata.subtitle.clone()),
-            12 => self.selected_song_data().map_or_else(String::new, |s| {
-                if s.metadata.subtitle.is_empty() {
-                    s.metadata.title.clone()
-                } else {
-                    format!("{} {}", s.metadata.title, s.metadata.subtitle)
-                }
-            }),
+            12 => self
+                .selected_song_data()
+                .map_or_else(String::new, |s| s.display_title()),
             13 => self
                 .selected_song_data()
                 .map_or_else(String::new, |s| s.metadata.genre.clone()),
@@ -495,6 +501,14 @@ impl crate::skin::skin_render_context::SkinRenderContext for SelectSkinContext<'
             150..=159 => self.course_title_at((id - 150) as usize),
             // Directory
             1000 => self.selector.manager.directory_string().to_string(),
+            // Current class dan, or the latest certification outside the class folder
+            1034 => self
+                .selector
+                .manager
+                .current_class_dan()
+                .or_else(|| self.selector.manager.certified_dan())
+                .unwrap_or_default()
+                .to_string(),
             // Version
             1010 => crate::core::version::Version::get_version().to_string(),
             // Song hash (MD5)
@@ -505,6 +519,11 @@ impl crate::skin::skin_render_context::SkinRenderContext for SelectSkinContext<'
             1031 => self
                 .selected_song_data()
                 .map_or_else(String::new, |s| s.file.sha256.clone()),
+            // Download URL of a chart that is not installed
+            1032 => self
+                .selected_song_data()
+                .filter(|s| s.file.path().is_none())
+                .map_or_else(String::new, |s| s.download_url().to_string()),
             _ => String::new(),
         }
     }
@@ -786,6 +805,10 @@ pub struct MusicSelector {
     pub banners: PixmapResourcePool,
     /// Stagefile pixmap resource pool
     pub stagefiles: PixmapResourcePool,
+    /// Stagefile, banner and backbmp of selected charts for the skin's image properties.
+    chart_images: crate::select::chart_image_cache::ChartImageCache,
+    /// Chart whose images are registered in the skin.
+    shown_chart_images: Option<String>,
 
     /// Ranking data cache for IR ranking lookups.
     pub ranking_data_cache:
@@ -799,6 +822,8 @@ pub struct MusicSelector {
     pub info_database: Option<Box<dyn crate::song_information_db::SongInformationDb>>,
     /// Rival player information.
     pub rivals: Vec<crate::skin::player_information::PlayerInformation>,
+    /// Score database of each rival, parallel to `rivals`.
+    pub rival_score_dbs: Vec<String>,
     /// Sound paths (SoundType -> path).
     pub sound_paths: std::collections::HashMap<crate::skin::sound_type::SoundType, String>,
     /// HTTP download submitter for chart download tasks.
@@ -867,13 +892,32 @@ pub struct MusicSelector {
     /// Pending IR ranking fetch result (course).
     /// Stores (requested CourseData, lnmode, receiver) so the result is cached under the correct key.
     pending_ir_course_fetch: Option<(CourseData, i32, std::sync::mpsc::Receiver<RankingData>)>,
+    /// Course that starts once its stages being downloaded are in the song database.
+    pending_course: Option<pending_course::PendingCourse>,
+    /// `HttpDownloadSubmitter::library_revision` the bar list was last loaded at.
+    library_revision: u64,
+    /// Background library scan state shared with MainController.
+    pub library_scan: Option<std::sync::Arc<crate::song::library_scan::LibraryScanStatus>>,
+    /// `LibraryScanStatus::revision` the bar list was last loaded at.
+    scan_revision: u64,
     /// Pending BMS model parse result.
     /// Stores (requested path, receiver) so the result is applied to the correct song.
     pending_note_graph: Option<PendingNoteGraphRx>,
+    /// Replay slot overlay metadata for the selected song.
+    /// Refreshed before rendering; read from disk only when the selection changes.
+    cached_replay_slots: CachedReplaySlots,
     /// JoinHandles for background threads (BMS parse, IR song/course fetch).
     /// Joined on dispose() to ensure clean shutdown.
     background_threads: Vec<std::thread::JoinHandle<()>>,
 
+    /// Viewer song requests shown as the Requests folder.
+    pub stream_requests: StreamRequestQueue,
+    /// Cursor spin of a random select hotkey in progress.
+    random_select_roulette: Option<random_select::RandomSelectRoulette>,
+    /// Select skin loaded in the background during startup, used by the
+    /// next `load_skin` instead of loading it again.
+    preloaded_skin: Option<crate::skin::types::skin::Skin>,
+
     /// Whether this MusicSelector is the currently active state on screen.
     /// Set to `true` in `create()`, cleared in `shutdown()`.
     /// Used by `StreamRequestCommand::UpdateBar` to gate bar mutations:
@@ -895,6 +939,15 @@ pub static MODE: [Option<bms_model::Mode>; 8] = [
 /// Maximum number of saveable replays
 pub const REPLAY: usize = 4;
 
+/// Replay slot metadata of the selected song, keyed by the song and slot existence flags.
+#[derive(Default)]
+struct CachedReplaySlots {
+    sha256: String,
+    lnmode: i32,
+    exists: [bool; REPLAY],
+    slots: [Option<crate::skin::replay_data::ReplaySlotInfo>; REPLAY],
+}
+
 impl Default for MusicSelector {
     fn default() -> Self {
         Self::new()
@@ -942,10 +995,18 @@ impl ChartReplicationMode {
 
 mod bar_operations;
 mod commands;
+mod pending_course;
+mod random_select;
 mod snapshot;
+mod song_manager;
 mod song_selection;
+mod stats;
+mod stream_requests;
 mod trait_impls;
 
+pub use random_select::RandomSelectFilter;
+pub use stream_requests::{REQUEST_FOLDER_NAME, StreamRequestQueue};
+
 #[cfg(test)]
 #[allow(clippy::field_reassign_with_default)]
 mod tests;
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use super::*;
+use crate::tr;
+
+/// How often the song database is checked for the downloaded stages.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Course whose missing stages are being downloaded. It starts as soon as every
+/// stage is in the song database.
+pub(crate) struct PendingCourse {
+    course: CourseData,
+    mode: BMSPlayerMode,
+    last_poll: Option<Instant>,
+}
+
+impl PendingCourse {
+    fn new(course: CourseData, mode: BMSPlayerMode) -> Self {
+        Self {
+            course,
+            mode,
+            last_poll: None,
+        }
+    }
+
+    /// Merge the file info of stages found in `songdb`. Returns whether every
+    /// stage now has a local chart.
+    fn resolve(&mut self, songdb: &dyn SongDatabaseAccessor) -> bool {
+        let hashes: Vec<String> = self
+            .course
+            .hash
+            .iter()
+            .filter(|song| song.file.path().is_none())
+            .map(|song| {
+                if song.file.sha256.is_empty() {
+                    song.file.md5.clone()
+                } else {
+                    song.file.sha256.clone()
+                }
+            })
+            .filter(|hash| !hash.is_empty())
+            .collect();
+        if !hashes.is_empty() {
+            let db_songs = songdb.song_datas_by_hashes(&hashes);
+            let mut song_map: HashMap<&str, &SongData> = HashMap::new();
+            for song in &db_songs {
+                if !song.file.sha256.is_empty() {
+                    song_map.insert(&song.file.sha256, song);
+                }
+                if !song.file.md5.is_empty() {
+                    song_map.insert(&song.file.md5, song);
+                }
+            }
+            for song in &mut self.course.hash {
+                if song.file.path().is_some() {
+                    continue;
+                }
+                let matched = if !song.file.sha256.is_empty() {
+                    song_map.get(song.file.sha256.as_str())
+                } else {
+                    song_map.get(song.file.md5.as_str())
+                };
+                if let Some(db_song) = matched {
+                    song.file = db_song.file.clone();
+                    if song.metadata.title.is_empty() {
+                        song.metadata = db_song.metadata.clone();
+                    }
+                }
+            }
+        }
+        self.course
+            .hash
+            .iter()
+            .all(|song| song.file.path().is_some())
+    }
+}
+
+impl MusicSelector {
+    /// Start `course` in `mode` once its missing stages have been downloaded.
+    /// Replaces any course already waiting.
+    pub(crate) fn set_pending_course(&mut self, course: CourseData, mode: BMSPlayerMode) {
+        ImGuiNotify::info(&tr!(
+            "notify-course-pending",
+            course = course.name().to_string()
+        ));
+        self.pending_course = Some(PendingCourse::new(course, mode));
+    }
+
+    pub fn has_pending_course(&self) -> bool {
+        self.pending_course.is_some()
+    }
+
+    /// Start the pending course when all of its stages are available.
+    pub(super) fn poll_pending_course(&mut self) {
+        let Some(pending) = self.pending_course.as_mut() else {
+            return;
+        };
+        if self.pending_state_change.is_some()
+            || pending
+                .last_poll
+                .is_some_and(|last| last.elapsed() < POLL_INTERVAL)
+        {
+            return;
+        }
+        pending.last_poll = Some(Instant::now());
+        if !pending.resolve(&*self.songdb) {
+            return;
+        }
+
+        let Some(pending) = self.pending_course.take() else {
+            return;
+        };
+        ImGuiNotify::info(&tr!(
+            "notify-course-ready",
+            course = pending.course.name().to_string()
+        ));
+        let grade_bar = Bar::Grade(Box::new(GradeBar::new(pending.course)));
+        if !self._read_course(&pending.mode, &grade_bar) {
+            ImGuiNotify::error(&tr!("notify-course-songs-missing"));
+        }
+    }
+}
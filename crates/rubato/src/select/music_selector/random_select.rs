@@ -0,0 +1,175 @@
+use rand::Rng;
+
+use super::*;
+use crate::core::clear_type::ClearType;
+use crate::tr;
+
+/// Number of charts the roulette shows before landing on the pick.
+const ROULETTE_STEPS: u32 = 10;
+/// Time the first roulette chart is shown (ms); each step is shown longer.
+const ROULETTE_INTERVAL: i64 = 30;
+const ROULETTE_SLOWDOWN: i64 = 10;
+
+/// Charts the random select hotkeys pick from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RandomSelectFilter {
+    All,
+    /// Charts without a play record.
+    Unplayed,
+    /// Charts whose best clear is Failed.
+    Failed,
+}
+
+impl RandomSelectFilter {
+    fn accepts(self, score: Option<&ScoreData>) -> bool {
+        match self {
+            RandomSelectFilter::All => true,
+            RandomSelectFilter::Unplayed => score.is_none_or(|score| score.playcount == 0),
+            RandomSelectFilter::Failed => {
+                score.is_some_and(|score| score.clear == ClearType::Failed.id())
+            }
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            RandomSelectFilter::All => "charts",
+            RandomSelectFilter::Unplayed => "unplayed charts",
+            RandomSelectFilter::Failed => "failed charts",
+        }
+    }
+}
+
+/// Indices of the playable song bars in `bars` accepted by `filter`.
+pub(super) fn random_select_candidates(bars: &[Bar], filter: RandomSelectFilter) -> Vec<usize> {
+    bars.iter()
+        .enumerate()
+        .filter(|(_, bar)| {
+            bar.as_song_bar()
+                .is_some_and(|song_bar| song_bar.exists_song() && filter.accepts(bar.score()))
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Cursor spin started by a random select hotkey, advanced by `update_random_select`.
+pub(super) struct RandomSelectRoulette {
+    /// Chart the roulette lands on.
+    pub(super) sha256: String,
+    candidates: Vec<usize>,
+    step: u32,
+    /// Timer time (ms) of the next step, None until the first update.
+    next_step: Option<i64>,
+}
+
+impl MusicSelector {
+    /// Fill in the scores of the song bars outside the loaded contents
+    /// window, which the unplayed and failed filters look at.
+    fn load_all_bar_scores(&mut self) {
+        self.ensure_local_score_cache();
+        let lnmode = self.config.play_settings.lnmode;
+        let Some(cache) = self.ranking.scorecache.as_mut() else {
+            return;
+        };
+        let songs: Vec<SongData> = self
+            .manager
+            .currentsongs
+            .iter()
+            .filter(|bar| bar.score().is_none())
+            .filter_map(|bar| bar.as_song_bar())
+            .filter(|song_bar| song_bar.exists_song())
+            .map(|song_bar| song_bar.song_data().clone())
+            .collect();
+        cache.read_score_datas(&|_, _| {}, &songs, lnmode);
+        for bar in &mut self.manager.currentsongs {
+            if bar.score().is_some() {
+                continue;
+            }
+            let score = bar
+                .as_song_bar()
+                .filter(|song_bar| song_bar.exists_song())
+                .and_then(|song_bar| cache.read_score_data(song_bar.song_data(), lnmode))
+                .cloned();
+            if score.is_some() {
+                bar.set_score(score);
+            }
+        }
+    }
+
+    /// Move the cursor to a random chart in the current folder accepted by `filter`.
+    pub(crate) fn random_select(&mut self, filter: RandomSelectFilter) {
+        if filter != RandomSelectFilter::All {
+            self.load_all_bar_scores();
+        }
+        let candidates = random_select_candidates(&self.manager.currentsongs, filter);
+        if candidates.is_empty() {
+            ImGuiNotify::info(&tr!(
+                "notify-random-select-empty",
+                filter = filter.label().to_string()
+            ));
+            return;
+        }
+        let pick = candidates[rand::thread_rng().gen_range(0..candidates.len())];
+        let Some(sha256) = self.manager.currentsongs[pick]
+            .as_song_bar()
+            .map(|song_bar| song_bar.song_data().file.sha256.clone())
+        else {
+            return;
+        };
+
+        if self.config.select_settings.random_select_roulette && candidates.len() > 1 {
+            self.random_select_roulette = Some(RandomSelectRoulette {
+                sha256,
+                candidates,
+                step: 0,
+                next_step: None,
+            });
+        } else {
+            self.random_select_roulette = None;
+            self.land_random_select(pick);
+        }
+    }
+
+    /// Advance the random select roulette. Called every frame from `render()`.
+    pub(super) fn update_random_select(&mut self) {
+        let now = self.main_state_data.timer.now_time();
+        let Some(roulette) = self.random_select_roulette.as_mut() else {
+            return;
+        };
+        let next_step = *roulette.next_step.get_or_insert(now);
+        if now < next_step {
+            return;
+        }
+
+        if roulette.step < ROULETTE_STEPS {
+            let index =
+                roulette.candidates[rand::thread_rng().gen_range(0..roulette.candidates.len())];
+            roulette.next_step =
+                Some(now + ROULETTE_INTERVAL + ROULETTE_SLOWDOWN * roulette.step as i64);
+            roulette.step += 1;
+            if index < self.manager.currentsongs.len() {
+                self.manager.selectedindex = index;
+            }
+            self.stop_sound(SoundType::Scratch);
+            self.play_sound(SoundType::Scratch);
+            return;
+        }
+
+        // The folder may have changed while spinning; land only if the pick is still listed
+        let sha256 = std::mem::take(&mut roulette.sha256);
+        self.random_select_roulette = None;
+        let target = self.manager.currentsongs.iter().position(|bar| {
+            bar.as_song_bar()
+                .is_some_and(|song_bar| song_bar.song_data().file.sha256 == sha256)
+        });
+        if let Some(index) = target {
+            self.land_random_select(index);
+        }
+    }
+
+    fn land_random_select(&mut self, index: usize) {
+        self.manager.selectedindex = index;
+        self.selected_bar_moved();
+        self.play_sound(SoundType::OptionChange);
+    }
+}
@@ -73,6 +73,10 @@ impl MusicSelector {
             .player_resource
             .as_ref()
             .map(|r| *crate::core::player_resource::PlayerResource::player_data(r));
+        s.today_player_data = self
+            .player_resource
+            .as_ref()
+            .map(|r| *r.today_player_data());
 
         // ---- Offsets ----
         s.offsets = self.main_state_data.offsets.clone();
@@ -116,12 +120,13 @@ impl MusicSelector {
         }
 
         // ---- Select-specific integers ----
-        // Directory lamp sum (300)
+        // Directory lamp sum (300) and missing charts (301)
         if let Some(dir) = selected_bar.and_then(|b| b.as_directory_bar()) {
             s.integers.insert(
                 300,
                 dir.lamps.iter().fold(0i32, |acc, &x| acc.saturating_add(x)),
             );
+            s.integers.insert(301, dir.missing());
         }
         // Song play/clear/fail counts (77-79)
         if let Some(score) = selected_score {
@@ -130,6 +135,16 @@ impl MusicSelector {
             s.integers.insert(79, score.playcount - score.clearcount);
         }
 
+        // Replay slot overlay (470-505)
+        for id in NUMBER_REPLAY1_EXSCORE..=NUMBER_REPLAY4_DATE_MINUTE {
+            if let Some(value) = crate::skin::replay_data::ReplaySlotInfo::integer_value(
+                &self.cached_replay_slots.slots,
+                id,
+            ) {
+                s.integers.insert(id, value);
+            }
+        }
+
         // ---- Select-specific booleans ----
         // Bar type
         s.booleans.insert(
@@ -251,6 +266,14 @@ impl MusicSelector {
                 s.strings.insert(1000, dir_str);
             }
         }
+        // Current class dan, or the latest certification outside the class folder
+        if let Some(dan) = self
+            .manager
+            .current_class_dan()
+            .or_else(|| self.manager.certified_dan())
+        {
+            s.strings.insert(1034, dan.to_string());
+        }
         // Version
         s.strings.insert(
             1010,
@@ -264,6 +287,9 @@ impl MusicSelector {
             if !song.file.sha256.is_empty() {
                 s.strings.insert(1031, song.file.sha256.clone());
             }
+            if song.file.path().is_none() && !song.download_url().is_empty() {
+                s.strings.insert(1032, song.download_url().to_string());
+            }
         }
 
         // Mouse position
@@ -0,0 +1,179 @@
+use super::*;
+use crate::modmenu::song_manager_menu::{DuplicateResolution, SongManagerAction, SongManagerMenu};
+use crate::skin::song_data::{FAVORITE_CHART, FAVORITE_SONG, INVISIBLE_CHART};
+
+impl MusicSelector {
+    /// Run the actions queued from the song manager window and publish the
+    /// selected song for it.
+    pub(super) fn process_song_manager(&mut self) {
+        for action in SongManagerMenu::take_actions() {
+            self.execute_song_manager_action(action);
+        }
+        let selected = self.manager.selected();
+        let song = selected
+            .and_then(|b| b.as_song_bar())
+            .map(|sb| sb.song_data());
+        SongManagerMenu::publish_selection(song, selected.and_then(|b| b.score()));
+    }
+
+    /// Apply `action` from the song manager window.
+    pub(super) fn execute_song_manager_action(&mut self, action: SongManagerAction) {
+        match action {
+            SongManagerAction::FindDuplicates => {
+                SongManagerMenu::publish_duplicates(self.songdb.duplicate_song_datas());
+            }
+            SongManagerAction::ResolveDuplicates(resolution) => {
+                self.resolve_duplicate_charts(&resolution);
+            }
+            action => self.execute_selected_song_action(action),
+        }
+    }
+
+    /// Apply `action` to the selected song bar. Ignored when no song is selected.
+    fn execute_selected_song_action(&mut self, action: SongManagerAction) {
+        let index = self.manager.selectedindex;
+        let Some(song) = self
+            .manager
+            .currentsongs
+            .get(index)
+            .and_then(|b| b.as_song_bar())
+            .map(|sb| sb.song_data().clone())
+        else {
+            return;
+        };
+        match action {
+            SongManagerAction::OpenFolder => self.execute_event(EventType::OpenWithExplorer),
+            SongManagerAction::RescanFolder => self.execute_event(EventType::UpdateFolder),
+            SongManagerAction::RemoveChart => {
+                if let Err(e) = self
+                    .songdb
+                    .delete_song_datas(std::slice::from_ref(&song.file.sha256))
+                {
+                    log::error!("Failed to remove song data: {e}");
+                    return;
+                }
+                self.refresh_bar_with_context();
+            }
+            SongManagerAction::SetFavorite(favorite) => {
+                self.update_selected_song(index, song, |sd| sd.favorite = favorite);
+            }
+            SongManagerAction::SetTag(tag) => {
+                self.update_selected_song(index, song, |sd| sd.metadata.tag = tag);
+            }
+            SongManagerAction::SetUserMetadata(mut meta) => {
+                let Some(db) = self.info_database.as_deref() else {
+                    log::warn!("Song information database is not available");
+                    return;
+                };
+                meta.sha256 = song.file.sha256;
+                if let Err(e) = db.set_user_metadata(&meta) {
+                    log::error!("Failed to set user metadata: {e}");
+                    return;
+                }
+                let user = (!meta.is_empty()).then(|| meta.clone());
+                for sb in self
+                    .manager
+                    .currentsongs
+                    .iter_mut()
+                    .filter_map(|b| b.as_song_bar_mut())
+                    .filter(|sb| sb.song_data().file.sha256 == meta.sha256)
+                {
+                    sb.set_user_metadata(user.clone());
+                }
+                self.manager.put_user_metadata(meta);
+            }
+            // Not tied to the selection, see execute_song_manager_action
+            SongManagerAction::FindDuplicates | SongManagerAction::ResolveDuplicates(_) => {}
+        }
+    }
+
+    /// Keep `resolution.keep` as the only visible copy of its chart. Scores
+    /// follow the SHA256, so they stay linked to the kept path; its favorite
+    /// flags and tag are merged from the other copies.
+    fn resolve_duplicate_charts(&mut self, resolution: &DuplicateResolution) {
+        let rows = self.songdb.song_datas("sha256", &resolution.sha256);
+        let Some((updated, mut removed)) =
+            plan_duplicate_cleanup(rows, &resolution.keep, resolution.delete)
+        else {
+            log::warn!("{} is not a stored path of its chart", resolution.keep);
+            return;
+        };
+        // Keep the entry of a file that could not be deleted
+        removed.retain(|path| match std::fs::remove_file(path) {
+            Ok(()) => true,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => true,
+            Err(e) => {
+                log::error!("Failed to delete {path}: {e}");
+                false
+            }
+        });
+        if let Err(e) = self.songdb.set_song_datas(&updated) {
+            log::error!("Failed to set song data: {e}");
+            return;
+        }
+        if let Err(e) = self.songdb.delete_song_datas_by_path(&removed) {
+            log::error!("Failed to remove song data: {e}");
+        }
+        self.refresh_bar_with_context();
+        SongManagerMenu::publish_duplicates(self.songdb.duplicate_song_datas());
+    }
+
+    /// Write the edited song data to the database and to the bar at `index`.
+    fn update_selected_song(
+        &mut self,
+        index: usize,
+        mut song: SongData,
+        edit: impl FnOnce(&mut SongData),
+    ) {
+        edit(&mut song);
+        if let Err(e) = self.songdb.set_song_datas(std::slice::from_ref(&song)) {
+            log::error!("Failed to set song data: {e}");
+            return;
+        }
+        if let Some(sb) = self
+            .manager
+            .currentsongs
+            .get_mut(index)
+            .and_then(|b| b.as_song_bar_mut())
+        {
+            *sb.song_data_mut() = song;
+        }
+    }
+}
+
+/// Song rows to write and paths to remove so that `keep` is the only visible
+/// copy among `rows`, the entries of one chart. None when `keep` is not one of
+/// them.
+///
+/// The kept row is unhidden and takes the favorite flags of all copies and
+/// the first tag found when it has none. The other rows are hidden, or listed
+/// for removal when `delete` is set.
+pub(super) fn plan_duplicate_cleanup(
+    rows: Vec<SongData>,
+    keep: &str,
+    delete: bool,
+) -> Option<(Vec<SongData>, Vec<String>)> {
+    let (mut kept, others): (Vec<_>, Vec<_>) = rows
+        .into_iter()
+        .partition(|sd| sd.file.path() == Some(keep));
+    let mut canonical = kept.pop()?;
+    for other in &others {
+        canonical.favorite |= other.favorite & (FAVORITE_SONG | FAVORITE_CHART);
+        if canonical.metadata.tag.is_empty() {
+            canonical.metadata.tag = other.metadata.tag.clone();
+        }
+    }
+    canonical.favorite &= !INVISIBLE_CHART;
+
+    let mut updated = vec![canonical];
+    let mut removed = Vec::new();
+    for mut other in others {
+        if delete {
+            removed.extend(other.file.path().map(str::to_string));
+        } else {
+            other.favorite |= INVISIBLE_CHART;
+            updated.push(other);
+        }
+    }
+    Some((updated, removed))
+}
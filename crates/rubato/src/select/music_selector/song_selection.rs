@@ -1,4 +1,6 @@
 use super::*;
+use crate::select::bar::context_menu_bar::ContextMenuBar;
+use crate::tr;
 
 impl MusicSelector {
     /// Select a bar (open directory or set play mode).
@@ -15,6 +17,15 @@ impl MusicSelector {
     }
 
     pub fn select_song(&mut self, mode: BMSPlayerMode) {
+        // Requests are played with autoplay while a moderator has it switched on
+        let mode = if mode.mode == BMSPlayerModeType::Play
+            && self.stream_requests.autoplay()
+            && self.is_in_request_folder()
+        {
+            BMSPlayerMode::AUTOPLAY
+        } else {
+            mode
+        };
         self.play = Some(mode);
     }
 
@@ -41,6 +52,11 @@ impl MusicSelector {
         let selected_bar_type = BarType::classify(self.manager.selected());
         let selected_replay = self.selectedreplay;
         let is_top_level = self.manager.directory().is_empty();
+        let selected_bar_grouped = self
+            .manager
+            .selected()
+            .and_then(|bar| bar.as_song_bar())
+            .is_some_and(|sb| sb.charts().len() > 1);
 
         // Take musicinput to avoid overlapping borrow on self
         let mut musicinput = match self.musicinput.take() {
@@ -55,6 +71,7 @@ impl MusicSelector {
             selected_replay,
             is_top_level,
         );
+        ctx.selected_bar_grouped = selected_bar_grouped;
 
         musicinput.input(&mut ctx);
 
@@ -256,14 +273,19 @@ impl MusicSelector {
         let gb = grade_bar.as_grade_bar().expect("as_grade_bar");
         if !gb.exists_all_songs() {
             log::info!("段位の楽曲が揃っていません (course songs are not all available)");
+            // Offer to download the missing stages and start the course afterwards
             if self.http_downloader.is_some() {
-                self.execute(MusicSelectCommand::DownloadCourseHttp);
+                ImGuiNotify::warning(&tr!("notify-course-stages-missing"));
+                let menu = ContextMenuBar::new_for_course(gb.clone(), mode);
+                if self.update_bar_with_songdb_context(Some(&Bar::ContextMenu(Box::new(menu)))) {
+                    self.play_sound(SoundType::FolderOpen);
+                }
             }
             return;
         }
 
         if !self._read_course(&mode, &grade_bar) {
-            ImGuiNotify::error("Failed to loading Course : Some of songs not found");
+            ImGuiNotify::error(&tr!("notify-course-songs-missing"));
             log::info!("段位の楽曲が揃っていません (course songs are not all available)");
         }
     }
@@ -302,7 +324,14 @@ impl MusicSelector {
                 self.app_config.paths.playerpath, player_name
             );
             let songinfo_path = self.app_config.paths.songinfopath.to_string();
-            rcd.lottery_song_datas(songdb, &score_path, &scorelog_path, Some(&songinfo_path));
+            let tables: Vec<_> = self.manager.tables().iter().map(|t| &t.td).collect();
+            rcd.lottery_song_datas(
+                songdb,
+                &tables,
+                &score_path,
+                &scorelog_path,
+                Some(&songinfo_path),
+            );
         }
         let course_data = rcd.create_course_data();
         let grade_bar = Bar::Grade(Box::new(GradeBar::new(course_data)));
@@ -310,7 +339,7 @@ impl MusicSelector {
         if let Some(gb) = grade_bar.as_grade_bar()
             && !gb.exists_all_songs()
         {
-            ImGuiNotify::error("Failed to loading Random Course : Some of songs not found");
+            ImGuiNotify::error(&tr!("notify-random-course-songs-missing"));
             log::info!(
                 "ランダムコースの楽曲が揃っていません (random course songs not all available)"
             );
@@ -338,7 +367,7 @@ impl MusicSelector {
                 self.manager.set_selected(&grade_bar);
             }
         } else {
-            ImGuiNotify::error("Failed to loading Random Course : Some of songs not found");
+            ImGuiNotify::error(&tr!("notify-random-course-songs-missing"));
             log::info!(
                 "ランダムコースの楽曲が揃っていません (random course songs not all available)"
             );
@@ -0,0 +1,73 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use super::*;
+use crate::modmenu::stats_dashboard_menu::StatsDashboardMenu;
+use crate::select::stats_dashboard::{self, DAILY_NOTES_DAYS, SCORE_TREND_COUNT, StatsDashboard};
+
+impl MusicSelector {
+    /// Gather the player's statistics and open the stats screen.
+    pub(crate) fn open_stats_dashboard(&mut self) {
+        let Some(pda) = self.play_data_accessor.as_ref() else {
+            return;
+        };
+        let mut stats = StatsDashboard::new(&pda.read_player_datas(DAILY_NOTES_DAYS as i32 + 1));
+
+        let logs = pda.read_score_logs(SCORE_TREND_COUNT as i32);
+        let hashes: Vec<String> = logs.iter().filter_map(|log| log.sha256.clone()).collect();
+        let mut songs = self.songdb.song_datas_by_hashes(&hashes);
+        for song in &mut songs {
+            song.user = self.manager.user_metadata(&song.file.sha256).cloned();
+        }
+        stats.set_score_trend(&logs, &songs);
+
+        stats.tables = self.table_lamps();
+        StatsDashboardMenu::open(stats);
+    }
+
+    /// Lamp distribution of every table level, from the local scores.
+    fn table_lamps(&mut self) -> Vec<stats_dashboard::TableLamps> {
+        self.ensure_local_score_cache();
+        let lnmode = self.config.play_settings.lnmode;
+        let mut tables = Vec::new();
+        for table in &self.manager.tables {
+            let td = table.table_data();
+            if td.folder.is_empty() {
+                continue;
+            }
+            let hashes: Vec<String> = td
+                .folder
+                .iter()
+                .flat_map(|folder| &folder.songs)
+                .map(|song| {
+                    if song.file.sha256.is_empty() {
+                        song.file.md5.clone()
+                    } else {
+                        song.file.sha256.clone()
+                    }
+                })
+                .filter(|hash| !hash.is_empty())
+                .collect();
+            let songs = self.songdb.song_datas_by_hashes(&hashes);
+            let clears = RefCell::new(HashMap::new());
+            if let Some(cache) = self.ranking.scorecache.as_mut() {
+                cache.read_score_datas(
+                    &|song, score| {
+                        if let Some(score) = score {
+                            clears
+                                .borrow_mut()
+                                .insert(song.file.sha256.clone(), score.clear);
+                        }
+                    },
+                    &songs,
+                    lnmode,
+                );
+            }
+            let clears = clears.into_inner();
+            tables.push(stats_dashboard::table_lamps(td, &songs, |song| {
+                clears.get(&song.file.sha256).copied()
+            }));
+        }
+        tables
+    }
+}
@@ -0,0 +1,198 @@
+use super::*;
+use crate::select::bar::hash_bar::HashBar;
+use crate::tr;
+
+/// Title of the select folder listing viewer song requests.
+pub const REQUEST_FOLDER_NAME: &str = "Requests";
+
+/// Viewer song requests in arrival order, validated against the song database
+/// by `StreamRequestCommand` and shown as the Requests folder.
+#[derive(Default)]
+pub struct StreamRequestQueue {
+    songs: Vec<SongData>,
+    /// Play requests started from the Requests folder with autoplay.
+    autoplay: bool,
+}
+
+impl StreamRequestQueue {
+    pub fn songs(&self) -> &[SongData] {
+        &self.songs
+    }
+
+    pub fn len(&self) -> usize {
+        self.songs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.songs.is_empty()
+    }
+
+    pub fn contains(&self, sha256: &str) -> bool {
+        self.songs.iter().any(|song| song.file.sha256 == sha256)
+    }
+
+    /// Append `song`, dropping the oldest requests beyond `max`. Returns false
+    /// if the song is already queued.
+    pub fn push(&mut self, song: SongData, max: i32) -> bool {
+        if self.contains(&song.file.sha256) {
+            return false;
+        }
+        self.songs.push(song);
+        while self.songs.len() as i32 > max && !self.songs.is_empty() {
+            self.songs.remove(0);
+        }
+        true
+    }
+
+    pub fn remove(&mut self, sha256: &str) -> Option<SongData> {
+        let index = self
+            .songs
+            .iter()
+            .position(|song| song.file.sha256 == sha256)?;
+        Some(self.songs.remove(index))
+    }
+
+    /// Move the request for `sha256` to the front of the queue.
+    pub fn prioritize(&mut self, sha256: &str) -> bool {
+        match self.remove(sha256) {
+            Some(song) => {
+                self.songs.insert(0, song);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove the request that is next in line.
+    pub fn pop_front(&mut self) -> Option<SongData> {
+        (!self.songs.is_empty()).then(|| self.songs.remove(0))
+    }
+
+    pub fn autoplay(&self) -> bool {
+        self.autoplay
+    }
+
+    pub fn set_autoplay(&mut self, autoplay: bool) {
+        self.autoplay = autoplay;
+    }
+
+    /// The Requests folder, or None while the queue is empty.
+    pub fn folder_bar(&self) -> Option<Bar> {
+        if self.songs.is_empty() {
+            return None;
+        }
+        Some(Bar::Hash(Box::new(HashBar::new(
+            REQUEST_FOLDER_NAME.to_string(),
+            self.songs.clone(),
+        ))))
+    }
+}
+
+impl MusicSelector {
+    /// Whether the open folder is the Requests folder.
+    pub fn is_in_request_folder(&self) -> bool {
+        self.manager
+            .directory()
+            .last()
+            .is_some_and(|bar| bar.as_hash_bar().is_some() && bar.title() == REQUEST_FOLDER_NAME)
+    }
+
+    /// Rebuild the Requests folder from the queue and, on the select screen,
+    /// reload the bar list. The folder stays open if it is open and still has
+    /// requests.
+    pub fn refresh_stream_requests(&mut self) {
+        let folder = self.stream_requests.folder_bar();
+        match folder {
+            Some(ref bar) => self
+                .manager
+                .set_append_directory_bar(REQUEST_FOLDER_NAME.to_string(), bar.clone()),
+            None => self
+                .manager
+                .remove_append_directory_bar(REQUEST_FOLDER_NAME),
+        }
+        if !self.is_active {
+            return;
+        }
+        match folder {
+            Some(ref bar) if self.is_in_request_folder() => {
+                let _ = self.update_bar_with_songdb_context(Some(bar));
+            }
+            _ => {
+                let _ = self.update_bar_with_songdb_context(None);
+            }
+        }
+    }
+
+    /// Drop the selected request from the queue without playing it.
+    pub(crate) fn skip_selected_stream_request(&mut self) {
+        if !self.is_in_request_folder() {
+            return;
+        }
+        let Some(sha256) = self
+            .manager
+            .selected()
+            .and_then(|bar| bar.as_song_bar())
+            .map(|song_bar| song_bar.song_data().file.sha256.clone())
+        else {
+            return;
+        };
+        if let Some(song) = self.stream_requests.remove(&sha256) {
+            ImGuiNotify::info(&tr!(
+                "notify-request-skipped",
+                title = song.metadata.full_title().to_string()
+            ));
+            self.refresh_stream_requests();
+        }
+    }
+
+    /// Drop the request that is next in line without playing it.
+    pub fn skip_next_stream_request(&mut self) -> Option<SongData> {
+        let song = self.stream_requests.pop_front()?;
+        ImGuiNotify::info(&tr!(
+            "notify-request-skipped",
+            title = song.metadata.full_title().to_string()
+        ));
+        self.refresh_stream_requests();
+        Some(song)
+    }
+
+    /// Move the request for `sha256` to the front of the Requests folder.
+    pub fn prioritize_stream_request(&mut self, sha256: &str) -> bool {
+        if !self.stream_requests.prioritize(sha256) {
+            return false;
+        }
+        self.refresh_stream_requests();
+        true
+    }
+
+    /// Switch whether requests are played with autoplay. Returns the new setting.
+    pub fn toggle_stream_request_autoplay(&mut self) -> bool {
+        let autoplay = !self.stream_requests.autoplay();
+        self.stream_requests.set_autoplay(autoplay);
+        ImGuiNotify::info(&if autoplay {
+            tr!("notify-requests-autoplay")
+        } else {
+            tr!("notify-requests-normal")
+        });
+        autoplay
+    }
+
+    /// Take `song` off the queue once it has been picked for play.
+    pub(super) fn accept_stream_request(&mut self, song: &SongData) {
+        if self.stream_requests.remove(&song.file.sha256).is_none() {
+            return;
+        }
+        ImGuiNotify::info(&tr!(
+            "notify-request-accepted",
+            title = song.metadata.full_title().to_string()
+        ));
+        match self.stream_requests.folder_bar() {
+            Some(bar) => self
+                .manager
+                .set_append_directory_bar(REQUEST_FOLDER_NAME.to_string(), bar),
+            None => self
+                .manager
+                .remove_append_directory_bar(REQUEST_FOLDER_NAME),
+        }
+    }
+}
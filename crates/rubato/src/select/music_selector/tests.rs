@@ -1261,6 +1261,101 @@ fn test_read_course_missing_songs_does_not_transition() {
     );
 }
 
+#[derive(Default)]
+struct RecordingDownloader {
+    md5s: std::sync::Mutex<Vec<String>>,
+}
+
+impl crate::http_download_submitter::HttpDownloadSubmitter for RecordingDownloader {
+    fn submit_md5_task(&self, md5: &str, _task_name: &str) {
+        self.md5s.lock().unwrap().push(md5.to_string());
+    }
+
+    fn submit_url_task(&self, _url: &str, _hash: &str, _task_name: &str) {}
+}
+
+fn incomplete_course() -> CourseData {
+    let mut missing = make_song_data("s2", None);
+    missing.file.md5 = "m2".to_string();
+    CourseData {
+        name: Some("Incomplete Course".to_string()),
+        hash: vec![make_song_data("s1", Some("/path/song1.bms")), missing],
+        constraint: vec![],
+        trophy: vec![],
+        release: false,
+    }
+}
+
+#[test]
+fn test_read_course_missing_songs_offers_stage_download() {
+    let mut selector = MusicSelector::new();
+    let downloader = std::sync::Arc::new(RecordingDownloader::default());
+    selector.http_downloader = Some(downloader.clone());
+    selector.manager.currentsongs = vec![Bar::Grade(Box::new(GradeBar::new(incomplete_course())))];
+    selector.manager.selectedindex = 0;
+
+    selector.read_course(BMSPlayerMode::AUTOPLAY);
+
+    assert_eq!(selector.pending_state_change, None);
+    let menu = selector
+        .manager
+        .dir
+        .last()
+        .and_then(|bar| bar.as_context_menu_bar())
+        .expect("course context menu should open");
+    assert_eq!(
+        menu.course.as_ref().map(|(_, mode)| *mode),
+        Some(BMSPlayerMode::AUTOPLAY)
+    );
+    // Nothing is downloaded until the player picks the option
+    assert!(downloader.md5s.lock().unwrap().is_empty());
+
+    let download = selector
+        .manager
+        .currentsongs
+        .iter()
+        .filter_map(|bar| bar.as_function_bar())
+        .find(|bar| bar.title() == "Download 1 Missing Stages and Start")
+        .and_then(|bar| bar.function.clone())
+        .expect("download option");
+    download(&mut selector);
+    assert_eq!(*downloader.md5s.lock().unwrap(), vec!["m2".to_string()]);
+    assert!(selector.has_pending_course());
+}
+
+#[test]
+fn test_pending_course_starts_once_stages_are_downloaded() {
+    let bms_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../test-bms/5key.bms");
+    if !bms_path.exists() {
+        return;
+    }
+    let path_str = bms_path.to_string_lossy().to_string();
+
+    // Nothing downloaded yet: the course keeps waiting
+    let mut selector = MusicSelector::new();
+    selector.set_pending_course(incomplete_course(), BMSPlayerMode::PLAY);
+    selector.poll_pending_course();
+    assert!(selector.has_pending_course());
+    assert_eq!(selector.pending_state_change, None);
+
+    let mut downloaded = make_song_data("s2", Some(&path_str));
+    downloaded.file.md5 = "m2".to_string();
+    let song_db = TestSongDb::new()
+        .with_songs_by_hashes(vec![downloaded])
+        .with_hash_filtering(true);
+    let mut selector = MusicSelector::with_song_database(Box::new(song_db));
+    let mut course = incomplete_course();
+    course.hash[0] = make_song_data("s1", Some(&path_str));
+    selector.set_pending_course(course, BMSPlayerMode::PLAY);
+
+    selector.poll_pending_course();
+
+    assert!(!selector.has_pending_course());
+    assert_eq!(selector.pending_state_change, Some(MainStateType::Decide));
+    let played = selector.playedcourse.as_ref().expect("playedcourse");
+    assert_eq!(played.hash.len(), 2);
+}
+
 #[test]
 fn test_read_course_class_constraint_resets_random() {
     let bms_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../test-bms/5key.bms");
@@ -2148,6 +2243,30 @@ fn integer_value_92_mainbpm_returns_value_when_info_present() {
     assert_eq!(ctx.integer_value(92), 150);
 }
 
+#[test]
+fn string_value_1032_download_url_only_for_missing_chart() {
+    let mut selector = MusicSelector::new();
+    let mut song = make_song_data("missing-chart", None);
+    song.appendurl = Some("https://example.com/append".to_string());
+    set_selected_bar(&mut selector, Bar::Song(Box::new(SongBar::new(song))));
+
+    let mut timer = TimerManager::new();
+    let ctx = SelectSkinContext {
+        timer: &mut timer,
+        selector: &mut selector,
+    };
+    assert_eq!(ctx.string_value(1032), "https://example.com/append");
+
+    let mut owned = make_song_data("owned-chart", Some("/test/owned.bms"));
+    owned.set_url("https://example.com/owned".to_string());
+    set_selected_bar(&mut selector, Bar::Song(Box::new(SongBar::new(owned))));
+    let ctx = SelectSkinContext {
+        timer: &mut timer,
+        selector: &mut selector,
+    };
+    assert_eq!(ctx.string_value(1032), "");
+}
+
 #[test]
 fn integer_value_92_mainbpm_returns_min_when_no_song_selected() {
     let mut selector = MusicSelector::new();
@@ -2408,6 +2527,26 @@ fn ir_next_1_returns_valid_target() {
     assert_eq!(target.judge_counts.egr, 0);
 }
 
+#[test]
+fn ir_average_target_resolves_on_select() {
+    let mut selector = MusicSelector::new();
+    selector.config.select_settings.targetid = "IR_AVERAGE_2".to_string();
+    selector.ranking.currentir = Some(make_ir_ranking_data(&[500, 400, 300]));
+
+    let mut song = make_song_data("ir-average", Some("/test/ir-average.bms"));
+    song.chart.notes = 500;
+    set_selected_bar(&mut selector, Bar::Song(Box::new(SongBar::new(song))));
+
+    selector.refresh_cached_target_score();
+    let target = selector
+        .cached_target_score
+        .as_ref()
+        .expect("IR_AVERAGE_2 should produce a target");
+    // (500 + 400) / 2 = 450 -> epg=225, egr=0
+    assert_eq!(target.judge_counts.epg, 225);
+    assert_eq!(target.judge_counts.egr, 0);
+}
+
 #[test]
 fn ir_next_valid_with_no_local_score() {
     // When local score is 0 (no score), IR_NEXT_1 should default to
@@ -2839,3 +2978,346 @@ fn sync_input_snapshot_stores_snapshot() {
     assert_eq!(stored.mouse_x, 42);
     assert_eq!(stored.mouse_y, 99);
 }
+
+#[test]
+fn set_rival_loads_score_cache_from_rival_db() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("import_alice.db");
+    let db =
+        crate::core::score_database_accessor::ScoreDatabaseAccessor::new(db_path.to_str().unwrap())
+            .unwrap();
+    db.create_table().unwrap();
+    drop(db);
+
+    let alice = PlayerInformation {
+        id: Some("alice".to_string()),
+        name: Some("Alice".to_string()),
+        rank: None,
+    };
+    let mut selector = MusicSelector::new();
+    selector.rivals = vec![alice.clone()];
+    selector.rival_score_dbs = vec![db_path.to_string_lossy().into_owned()];
+
+    selector.set_rival(Some(alice));
+    assert!(selector.rival_score_data_cache().is_some());
+
+    selector.set_rival(None);
+    assert!(selector.rival_score_data_cache().is_none());
+}
+
+#[test]
+fn stream_request_queue_rejects_duplicates_and_drops_oldest() {
+    let mut queue = StreamRequestQueue::default();
+    assert!(queue.folder_bar().is_none());
+
+    assert!(queue.push(make_song_data("a", None), 2));
+    assert!(!queue.push(make_song_data("a", None), 2));
+    assert!(queue.push(make_song_data("b", None), 2));
+    assert!(queue.push(make_song_data("c", None), 2));
+    let shas: Vec<&str> = queue
+        .songs()
+        .iter()
+        .map(|song| song.file.sha256.as_str())
+        .collect();
+    assert_eq!(shas, ["b", "c"]);
+
+    let folder = queue.folder_bar().unwrap();
+    assert_eq!(folder.title(), REQUEST_FOLDER_NAME);
+    assert!(queue.remove("b").is_some());
+    assert!(queue.remove("b").is_none());
+    assert_eq!(queue.len(), 1);
+}
+
+#[test]
+fn skip_stream_request_removes_selected_song_in_request_folder() {
+    let mut selector = MusicSelector::new();
+    selector.stream_requests.push(make_song_data("a", None), 10);
+    selector.stream_requests.push(make_song_data("b", None), 10);
+    set_selected_bar(&mut selector, make_song_bar("a", None));
+
+    // Outside the Requests folder the command does nothing
+    selector.skip_selected_stream_request();
+    assert_eq!(selector.stream_requests.len(), 2);
+
+    let folder = selector.stream_requests.folder_bar().unwrap();
+    selector.manager.dir = vec![Box::new(folder)];
+    set_selected_bar(&mut selector, make_song_bar("a", None));
+    selector.skip_selected_stream_request();
+    assert!(!selector.stream_requests.contains("a"));
+    assert!(selector.stream_requests.contains("b"));
+}
+
+#[test]
+fn accept_stream_request_removes_played_song() {
+    let mut selector = MusicSelector::new();
+    selector.stream_requests.push(make_song_data("a", None), 10);
+    selector.accept_stream_request(&make_song_data("a", None));
+    assert!(selector.stream_requests.is_empty());
+}
+
+#[test]
+fn select_song_uses_autoplay_for_requests_when_enabled() {
+    let mut selector = MusicSelector::new();
+    selector.stream_requests.push(make_song_data("a", None), 10);
+    selector.manager.dir = vec![Box::new(selector.stream_requests.folder_bar().unwrap())];
+    set_selected_bar(&mut selector, make_song_bar("a", None));
+
+    selector.select_song(BMSPlayerMode::PLAY);
+    assert_eq!(
+        selector.play.as_ref().unwrap().mode,
+        BMSPlayerModeType::Play
+    );
+
+    selector.toggle_stream_request_autoplay();
+    selector.select_song(BMSPlayerMode::PLAY);
+    assert_eq!(
+        selector.play.as_ref().unwrap().mode,
+        BMSPlayerModeType::Autoplay
+    );
+}
+
+fn make_scored_song_bar(sha256: &str, playcount: i32, clear: i32) -> Bar {
+    let mut bar = make_song_bar(sha256, Some("/test.bms"));
+    let mut score = ScoreData::default();
+    score.playcount = playcount;
+    score.clear = clear;
+    bar.set_score(Some(score));
+    bar
+}
+
+#[test]
+fn random_select_candidates_apply_filter_to_playable_songs() {
+    use crate::core::clear_type::ClearType;
+    use random_select::random_select_candidates;
+
+    let bars = vec![
+        make_song_bar("new", Some("/new.bms")),
+        make_scored_song_bar("failed", 3, ClearType::Failed.id()),
+        make_scored_song_bar("hard", 2, ClearType::Hard.id()),
+        make_song_bar("missing", None),
+    ];
+    assert_eq!(
+        random_select_candidates(&bars, RandomSelectFilter::All),
+        vec![0, 1, 2]
+    );
+    assert_eq!(
+        random_select_candidates(&bars, RandomSelectFilter::Unplayed),
+        vec![0]
+    );
+    assert_eq!(
+        random_select_candidates(&bars, RandomSelectFilter::Failed),
+        vec![1]
+    );
+}
+
+#[test]
+fn random_select_without_roulette_moves_cursor_immediately() {
+    use crate::core::clear_type::ClearType;
+
+    let mut selector = MusicSelector::new();
+    selector.config.select_settings.random_select_roulette = false;
+    selector.manager.currentsongs = vec![
+        make_scored_song_bar("hard", 2, ClearType::Hard.id()),
+        make_scored_song_bar("failed", 3, ClearType::Failed.id()),
+    ];
+    selector.manager.selectedindex = 0;
+
+    selector.random_select(RandomSelectFilter::Failed);
+    assert_eq!(selector.manager.selectedindex, 1);
+    assert!(selector.random_select_roulette.is_none());
+}
+
+#[test]
+fn random_select_roulette_lands_on_pick_after_spinning() {
+    let mut selector = MusicSelector::new();
+    selector.config.select_settings.random_select_roulette = true;
+    selector.manager.currentsongs = vec![
+        make_song_bar("a", Some("/a.bms")),
+        make_song_bar("b", Some("/b.bms")),
+        make_song_bar("c", Some("/c.bms")),
+    ];
+    selector.random_select(RandomSelectFilter::All);
+    let pick = selector
+        .random_select_roulette
+        .as_ref()
+        .map(|roulette| roulette.sha256.clone())
+        .expect("roulette started");
+
+    let mut time = 0;
+    while selector.random_select_roulette.is_some() {
+        assert!(time < 10_000_000, "roulette did not finish");
+        selector.main_state_data.timer.set_now_micro_time(time);
+        selector.update_random_select();
+        time += 10_000;
+    }
+    let selected = selector
+        .manager
+        .selected()
+        .and_then(|bar| bar.as_song_bar());
+    assert_eq!(
+        selected.map(|bar| bar.song_data().file.sha256.as_str()),
+        Some(pick.as_str())
+    );
+}
+
+#[test]
+fn next_chart_command_cycles_grouped_song_bar() {
+    let mut selector = MusicSelector::new();
+    let normal = make_song_data("normal", Some("/normal.bms"));
+    let hyper = make_song_data("hyper", Some("/hyper.bms"));
+    let mut song_bar = SongBar::new(normal.clone());
+    song_bar.set_charts(vec![(normal, None), (hyper, None)]);
+    set_selected_bar(&mut selector, Bar::Song(Box::new(song_bar)));
+
+    MusicSelectCommand::NextChart.execute(&mut selector);
+    let selected = |selector: &MusicSelector| {
+        selector
+            .manager
+            .selected()
+            .and_then(|bar| bar.as_song_bar())
+            .map(|sb| sb.song_data().file.sha256.clone())
+    };
+    assert_eq!(selected(&selector).as_deref(), Some("hyper"));
+    MusicSelectCommand::PrevChart.execute(&mut selector);
+    assert_eq!(selected(&selector).as_deref(), Some("normal"));
+}
+
+/// Song database that records writes and deletions from the song manager.
+#[derive(Default)]
+struct RecordingSongDb {
+    written: std::sync::Arc<std::sync::Mutex<Vec<SongData>>>,
+    deleted: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+impl SongDatabaseAccessor for RecordingSongDb {
+    fn song_datas(&self, _key: &str, _value: &str) -> Vec<SongData> {
+        Vec::new()
+    }
+
+    fn song_datas_by_hashes(&self, _hashes: &[String]) -> Vec<SongData> {
+        Vec::new()
+    }
+
+    fn song_datas_by_sql(
+        &self,
+        _sql: &str,
+        _score: &str,
+        _scorelog: &str,
+        _info: Option<&str>,
+    ) -> Vec<SongData> {
+        Vec::new()
+    }
+
+    fn set_song_datas(&self, songs: &[SongData]) -> anyhow::Result<()> {
+        self.written.lock().unwrap().extend_from_slice(songs);
+        Ok(())
+    }
+
+    fn delete_song_datas(&self, sha256: &[String]) -> anyhow::Result<()> {
+        self.deleted.lock().unwrap().extend_from_slice(sha256);
+        Ok(())
+    }
+
+    fn song_datas_by_text(&self, _text: &str) -> Vec<SongData> {
+        Vec::new()
+    }
+
+    fn folder_datas(&self, _key: &str, _value: &str) -> Vec<crate::skin::folder_data::FolderData> {
+        Vec::new()
+    }
+}
+
+#[test]
+fn song_manager_edits_update_db_and_selected_bar() {
+    use crate::modmenu::song_manager_menu::SongManagerAction;
+    use crate::skin::song_data::FAVORITE_CHART;
+
+    let db = RecordingSongDb::default();
+    let written = db.written.clone();
+    let mut selector = MusicSelector::new();
+    selector.songdb = Box::new(db);
+    set_selected_bar(&mut selector, make_song_bar("abc", Some("/songs/a/a.bms")));
+
+    selector.execute_song_manager_action(SongManagerAction::SetTag("practice".to_string()));
+    selector.execute_song_manager_action(SongManagerAction::SetFavorite(FAVORITE_CHART));
+
+    let written = written.lock().unwrap();
+    assert_eq!(written.len(), 2);
+    assert_eq!(written[0].file.sha256, "abc");
+    assert_eq!(written[0].metadata.tag, "practice");
+    assert_eq!(written[1].favorite, FAVORITE_CHART);
+    let sd = selector.manager.currentsongs[0]
+        .as_song_bar()
+        .unwrap()
+        .song_data();
+    assert_eq!(sd.metadata.tag, "practice");
+    assert_eq!(sd.favorite, FAVORITE_CHART);
+}
+
+#[test]
+fn song_manager_remove_deletes_selected_chart() {
+    use crate::modmenu::song_manager_menu::SongManagerAction;
+
+    let db = RecordingSongDb::default();
+    let deleted = db.deleted.clone();
+    let mut selector = MusicSelector::new();
+    selector.songdb = Box::new(db);
+    set_selected_bar(&mut selector, make_song_bar("abc", Some("/songs/a/a.bms")));
+
+    selector.execute_song_manager_action(SongManagerAction::RemoveChart);
+
+    assert_eq!(*deleted.lock().unwrap(), vec!["abc".to_string()]);
+}
+
+#[test]
+fn song_manager_rescan_queues_parent_folder() {
+    use crate::modmenu::song_manager_menu::SongManagerAction;
+
+    let mut selector = MusicSelector::new();
+    set_selected_bar(&mut selector, make_song_bar("abc", Some("/songs/a/a.bms")));
+
+    selector.execute_song_manager_action(SongManagerAction::RescanFolder);
+
+    assert_eq!(
+        selector.pending_update_song,
+        Some(Some("/songs/a".to_string()))
+    );
+}
+
+#[test]
+fn duplicate_cleanup_keeps_one_visible_copy() {
+    use super::song_manager::plan_duplicate_cleanup;
+    use crate::skin::song_data::{FAVORITE_CHART, INVISIBLE_CHART};
+
+    let copy = |path: &str, favorite: i32, tag: &str| {
+        let mut sd = SongData::default();
+        sd.file.sha256 = "abc".to_string();
+        sd.file.set_path(path.to_string());
+        sd.favorite = favorite;
+        sd.metadata.tag = tag.to_string();
+        sd
+    };
+    let rows = vec![
+        copy("a/x.bms", INVISIBLE_CHART, ""),
+        copy("b/x.bms", FAVORITE_CHART, "practice"),
+        copy("c/x.bms", 0, ""),
+    ];
+
+    let (updated, removed) = plan_duplicate_cleanup(rows.clone(), "a/x.bms", false).unwrap();
+    assert!(removed.is_empty());
+    assert_eq!(updated.len(), 3);
+    assert_eq!(updated[0].file.path(), Some("a/x.bms"));
+    assert_eq!(updated[0].favorite, FAVORITE_CHART);
+    assert_eq!(updated[0].metadata.tag, "practice");
+    assert!(
+        updated[1..]
+            .iter()
+            .all(|sd| sd.favorite & INVISIBLE_CHART != 0)
+    );
+
+    let (updated, removed) = plan_duplicate_cleanup(rows.clone(), "c/x.bms", true).unwrap();
+    assert_eq!(updated.len(), 1);
+    assert_eq!(removed, vec!["a/x.bms".to_string(), "b/x.bms".to_string()]);
+
+    assert!(plan_duplicate_cleanup(rows, "d/x.bms", true).is_none());
+}
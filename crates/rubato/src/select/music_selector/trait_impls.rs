@@ -44,7 +44,13 @@ impl MainState for MusicSelector {
         let skin_path =
             crate::skin::skin_loader::skin_path_from_player_config(&self.config, skin_type);
         let timer = std::mem::take(&mut self.main_state_data.timer);
-        let skin_result = {
+        let preloaded = self
+            .preloaded_skin
+            .take()
+            .filter(|_| skin_type == crate::skin::skin_type::SkinType::MusicSelect.id());
+        let skin_result = if preloaded.is_some() {
+            preloaded
+        } else {
             let mut snapshot = self.build_snapshot(&timer);
             let registry = std::collections::HashMap::new();
             let mut state =
@@ -116,6 +122,14 @@ impl MainState for MusicSelector {
                             skin_bar.set_label(i as i32, img);
                         }
                     }
+                    // Transfer best score rank images and numbers
+                    for (i, rank) in bar_data.barrank.into_iter().enumerate() {
+                        if let Some(img) = rank {
+                            skin_bar.set_rank(i as i32, img);
+                        }
+                    }
+                    skin_bar.score = bar_data.barscore;
+                    skin_bar.misscount = bar_data.barmisscount;
                     // Transfer distribution graph
                     if let Some(graph_type) = bar_data.graph_type {
                         let mut graph = if let Some(images) = bar_data.graph_images {
@@ -168,6 +182,10 @@ impl MainState for MusicSelector {
         self.sound_paths.get(&sound).cloned()
     }
 
+    fn best_score(&self) -> Option<ScoreData> {
+        crate::skin::song_selection_access::SongSelectionAccess::selected_score_data(self)
+    }
+
     fn play_sound_loop(&mut self, sound: SoundType, loop_sound: bool) {
         self.pending_sounds.push((sound, loop_sound));
     }
@@ -182,6 +200,19 @@ impl MainState for MusicSelector {
         }
     }
 
+    fn open_chart_file(&mut self, path: &std::path::Path) -> bool {
+        if self.pending_state_change.is_some() {
+            return false;
+        }
+        let mut song = SongData::new();
+        song.file.set_path(path.to_string_lossy().into_owned());
+        let bar = Bar::Song(Box::new(crate::select::bar::song_bar::SongBar::new(
+            song.clone(),
+        )));
+        self.read_chart(&song, &bar, Some(&BMSPlayerMode::PLAY));
+        true
+    }
+
     fn take_player_resource(&mut self) -> Option<crate::core::player_resource::PlayerResource> {
         let should_handoff = self.player_resource.as_ref().is_some_and(|resource| {
             resource.bms_model().is_some()
@@ -211,21 +242,25 @@ impl MainState for MusicSelector {
         self.pending_shuffle_sounds = true;
 
         self.play = None;
+        // Replays may have been saved since the last visit
+        self.cached_replay_slots = CachedReplaySlots::default();
         self.preview_state.show_note_graph = false;
 
+        if let Some(db) = self.info_database.as_deref() {
+            self.manager.set_user_metadata(db.user_metadata());
+        }
+
         // In Java: resource.setPlayerData(main.getPlayDataAccessor().readPlayerData())
         if let Some(ref pda) = self.play_data_accessor {
-            let player_data = pda.read_player_data();
-            if let Some(pd) = player_data {
-                if self.player_resource.is_none() {
-                    self.player_resource = Some(crate::core::player_resource::PlayerResource::new(
+            self.manager.set_earned_trophies(pda.read_earned_trophies());
+            if pda.read_player_data().is_some() {
+                let res = self.player_resource.get_or_insert_with(|| {
+                    crate::core::player_resource::PlayerResource::new(
                         self.app_config.clone(),
                         self.config.clone(),
-                    ));
-                }
-                if let Some(res) = self.player_resource.as_mut() {
-                    res.set_player_data(pd);
-                }
+                    )
+                });
+                res.reload_player_data(pda);
             }
         }
 
@@ -281,6 +316,11 @@ impl MainState for MusicSelector {
         // we must pass them explicitly via UpdateBarContext.
         {
             self.ensure_local_score_cache();
+            // First entry this session resumes where the last session left off.
+            let resume = self.manager.currentsongs.is_empty().then(|| {
+                let settings = &self.config.select_settings;
+                (settings.last_folder.clone(), settings.last_selected.clone())
+            });
             let mut ctx = BarManager::make_context(
                 &self.app_config,
                 &mut self.config,
@@ -288,11 +328,16 @@ impl MainState for MusicSelector {
                 self.ranking.scorecache.as_mut(),
             );
             self.manager.update_bar_with_context(None, Some(&mut ctx));
+            if let Some((folders, selected)) = resume {
+                self.manager
+                    .restore_session_position(&folders, &selected, Some(&mut ctx));
+            }
         }
         self.load_bar_contents();
 
         // In Java: loadSkin(SkinType.MUSIC_SELECT)
         self.load_skin(SkinType::MusicSelect.id());
+        self.shown_chart_images = None;
         if let Some(skin) = self.main_state_data.skin.as_mut() {
             skin.prepare_skin(Some(
                 crate::skin::main_state_type::MainStateType::MusicSelect,
@@ -362,6 +407,7 @@ impl MainState for MusicSelector {
             // Refresh cached data before building the snapshot
             self.refresh_cached_target_score();
             self.refresh_cached_score_data_property();
+            self.refresh_cached_replay_slots();
 
             let mut snapshot = self.build_snapshot(&timer);
             skin.update_custom_objects_timed(&mut snapshot);
@@ -610,9 +656,17 @@ impl MainState for MusicSelector {
             super::search_text_field::SearchFieldAction::None => {}
         }
 
+        self.update_chart_images();
+
         // Prune finished background threads to avoid unbounded handle accumulation.
         self.background_threads.retain(|h| !h.is_finished());
 
+        self.poll_pending_course();
+        self.process_song_manager();
+        self.poll_library_revision();
+        self.update_random_select();
+        self.load_visible_bar_contents();
+
         let timer = &mut self.main_state_data.timer;
 
         // Start input timer after skin input delay
@@ -1117,6 +1171,7 @@ impl MainState for MusicSelector {
     /// Corresponds to Java MusicSelector.shutdown()
     fn shutdown(&mut self) {
         self.is_active = false;
+        LibraryScanIndicator::set(None);
 
         if let Some(preview) = &mut self.preview_state.preview {
             preview.stop();
@@ -1160,6 +1215,17 @@ impl MainState for MusicSelector {
             ctx.stop_audio_path(&path);
         }
 
+        // Remember the folder and cursor for the next session when leaving select
+        if self.pending_state_change.is_some() || self.pending_exit {
+            let (folders, selected) = self.manager.session_position();
+            let settings = &mut self.config.select_settings;
+            if settings.last_folder != folders || settings.last_selected != selected {
+                settings.last_folder = folders;
+                settings.last_selected = selected;
+                self.pending_player_config_dirty = true;
+            }
+        }
+
         // Player config update - push full config back to ctx.player
         if self.pending_player_config_dirty {
             self.pending_player_config_dirty = false;
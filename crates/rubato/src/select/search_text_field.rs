@@ -19,6 +19,8 @@ struct SearchFieldEguiState {
     enter_pressed: bool,
     /// Set to true by egui when Escape/click-outside occurs; consumed by game thread.
     escape_pressed: bool,
+    /// An IME composition is in progress; Enter/Escape belong to the IME.
+    composing: bool,
     /// Window height for Y-up to Y-down coordinate conversion.
     window_height: f32,
 }
@@ -30,6 +32,7 @@ static SEARCH_EGUI_STATE: Mutex<SearchFieldEguiState> = Mutex::new(SearchFieldEg
     message_text: String::new(),
     enter_pressed: false,
     escape_pressed: false,
+    composing: false,
     window_height: 720.0,
 });
 
@@ -66,6 +69,7 @@ impl SearchTextField {
         // Clear shared egui state when disposed
         let mut state = lock_or_recover(&SEARCH_EGUI_STATE);
         state.has_focus = false;
+        state.composing = false;
         state.text.clear();
     }
 
@@ -104,8 +108,13 @@ impl SearchTextField {
     pub fn render_egui(ctx: &egui::Context) {
         let mut state = lock_or_recover(&SEARCH_EGUI_STATE);
         if !state.has_focus || state.bounds.is_none() {
+            state.composing = false;
             return;
         }
+        // Checked before the TextEdit consumes this frame's events, so the key
+        // that commits or cancels a composition does not also submit/unfocus.
+        let was_composing = state.composing;
+        state.composing = ctx.input(|i| ime_composing(was_composing, &i.events));
 
         let bounds = state.bounds.unwrap();
         let window_height = state.window_height;
@@ -142,6 +151,10 @@ impl SearchTextField {
                         response.request_focus();
                     }
 
+                    if was_composing {
+                        return;
+                    }
+
                     // Check for Enter key
                     if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
                         state.enter_pressed = true;
@@ -156,9 +169,45 @@ impl SearchTextField {
     }
 }
 
+/// Whether an IME composition is in progress after `events`.
+fn ime_composing(mut composing: bool, events: &[egui::Event]) -> bool {
+    for event in events {
+        match event {
+            egui::Event::Ime(egui::ImeEvent::Preedit(text)) => composing = !text.is_empty(),
+            egui::Event::Ime(egui::ImeEvent::Commit(_) | egui::ImeEvent::Disabled) => {
+                composing = false
+            }
+            _ => {}
+        }
+    }
+    composing
+}
+
 /// Actions returned from egui sync
 pub enum SearchFieldAction {
     None,
     Submit,
     Unfocus,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ime_composing_follows_preedit_and_commit() {
+        let preedit = |s: &str| egui::Event::Ime(egui::ImeEvent::Preedit(s.to_string()));
+        assert!(ime_composing(false, &[preedit("き")]));
+        assert!(ime_composing(true, &[]));
+        // Preedit cleared by backspacing the whole composition
+        assert!(!ime_composing(true, &[preedit("")]));
+        assert!(!ime_composing(
+            true,
+            &[egui::Event::Ime(egui::ImeEvent::Commit("曲".to_string()))]
+        ));
+        assert!(!ime_composing(
+            true,
+            &[egui::Event::Ime(egui::ImeEvent::Disabled)]
+        ));
+    }
+}
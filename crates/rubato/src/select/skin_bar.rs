@@ -29,6 +29,12 @@ pub struct SkinBar {
     pub mylamp: Vec<Option<SkinImage>>,
     /// Rival lamp images (for rival display)
     pub rivallamp: Vec<Option<SkinImage>>,
+    /// DJ rank images of the best score. Index: 0=AAA .. 7=F
+    pub rank: Vec<Option<SkinImage>>,
+    /// EX score of the best score
+    pub score: Option<SkinNumber>,
+    /// Miss count (BP) of the best score
+    pub misscount: Option<SkinNumber>,
     /// SkinObject base data
     pub draw: bool,
     pub region: SkinRegion,
@@ -52,6 +58,7 @@ impl SkinBar {
     pub const BARLEVEL_COUNT: usize = 7;
     pub const BARLABEL_COUNT: usize = 5;
     pub const BARLAMP_COUNT: usize = 11;
+    pub const BARRANK_COUNT: usize = 8;
 
     pub fn new(position: i32) -> Self {
         // Real SkinImage/SkinNumber/SkinTextEnum are not Clone,
@@ -81,6 +88,9 @@ impl SkinBar {
             lamp: none_images(Self::BARLAMP_COUNT),
             mylamp: none_images(Self::BARLAMP_COUNT),
             rivallamp: none_images(Self::BARLAMP_COUNT),
+            rank: none_images(Self::BARRANK_COUNT),
+            score: None,
+            misscount: None,
             draw: false,
             region: SkinRegion::default(),
         }
@@ -195,6 +205,7 @@ impl SkinBar {
         validate_images(&mut self.lamp);
         validate_images(&mut self.mylamp);
         validate_images(&mut self.rivallamp);
+        validate_images(&mut self.rank);
         // SkinText trait doesn't expose validate; validate underlying SkinObjectData
         for txt in self.text.iter_mut() {
             if txt
@@ -241,6 +252,12 @@ impl SkinBar {
         for rivallamp in self.rivallamp.iter_mut().flatten() {
             rivallamp.prepare(time, state);
         }
+        for rank in self.rank.iter_mut().flatten() {
+            rank.prepare(time, state);
+        }
+        for number in self.score.iter_mut().chain(self.misscount.iter_mut()) {
+            number.prepare(time, state);
+        }
         if let Some(ref mut graph) = self.graph {
             graph.prepare(time, state);
         }
@@ -285,6 +302,12 @@ impl SkinBar {
         for img in self.rivallamp.iter_mut().flatten() {
             img.dispose();
         }
+        for img in self.rank.iter_mut().flatten() {
+            img.dispose();
+        }
+        for num in self.score.iter_mut().chain(self.misscount.iter_mut()) {
+            num.dispose();
+        }
     }
 
     pub fn barlevel(&self, id: i32) -> Option<&SkinNumber> {
@@ -301,6 +324,20 @@ impl SkinBar {
         }
     }
 
+    pub fn rank(&self, id: i32) -> Option<&SkinImage> {
+        if id >= 0 && (id as usize) < self.rank.len() {
+            self.rank[id as usize].as_ref()
+        } else {
+            None
+        }
+    }
+
+    pub fn set_rank(&mut self, id: i32, rank: SkinImage) {
+        if id >= 0 && (id as usize) < self.rank.len() {
+            self.rank[id as usize] = Some(rank);
+        }
+    }
+
     pub fn position(&self) -> i32 {
         self.position
     }
@@ -351,6 +388,7 @@ mod tests {
         assert_eq!(bar.barlevel.len(), SkinBar::BARLEVEL_COUNT);
         assert_eq!(bar.label.len(), SkinBar::BARLABEL_COUNT);
         assert_eq!(bar.lamp.len(), SkinBar::BARLAMP_COUNT);
+        assert_eq!(bar.rank.len(), SkinBar::BARRANK_COUNT);
         assert_eq!(bar.position, 0);
         assert!(!bar.draw);
     }
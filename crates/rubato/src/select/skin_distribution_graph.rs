@@ -137,8 +137,8 @@ impl SkinDistributionGraph {
         offset_y: f32,
     ) {
         let percent: f32 = match task.download_task_status() {
-            DownloadTaskStatus::Prepare => 0.0,
-            DownloadTaskStatus::Downloading => {
+            DownloadTaskStatus::Prepare | DownloadTaskStatus::Queued => 0.0,
+            DownloadTaskStatus::Downloading | DownloadTaskStatus::Paused => {
                 if task.content_length > 0 {
                     (task.download_size as f32 / task.content_length as f32).clamp(0.0, 1.0)
                 } else {
@@ -379,8 +379,8 @@ mod tests {
     /// Helper that mirrors the logic in draw_song_bar_download
     fn compute_download_percent(task: &DownloadTask) -> f32 {
         match task.download_task_status() {
-            DownloadTaskStatus::Prepare => 0.0,
-            DownloadTaskStatus::Downloading => {
+            DownloadTaskStatus::Prepare | DownloadTaskStatus::Queued => 0.0,
+            DownloadTaskStatus::Downloading | DownloadTaskStatus::Paused => {
                 if task.content_length > 0 {
                     (task.download_size as f32 / task.content_length as f32).clamp(0.0, 1.0)
                 } else {
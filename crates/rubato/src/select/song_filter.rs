@@ -0,0 +1,134 @@
+//! Song bar filters by best clear, DJ rank and level (SelectSettings).
+//! They combine with each other and with the mode filter.
+
+use crate::core::clear_type::ClearType;
+use crate::core::player_config::{
+    CLEAR_FILTER_CLEARED, CLEAR_FILTER_FAILED, CLEAR_FILTER_FULLCOMBO, CLEAR_FILTER_UNPLAYED,
+    SelectSettings,
+};
+
+use super::{ScoreData, SongData};
+
+/// Labels of the CLEAR_FILTER_* values.
+pub const CLEAR_FILTER_NAMES: [&str; 5] = ["ALL", "UNPLAYED", "FAILED", "CLEARED", "FULL COMBO"];
+/// Labels of the rank filter values: off, then F .. AAA.
+pub const RANK_FILTER_NAMES: [&str; 9] = ["OFF", "F", "E", "D", "C", "B", "A", "AA", "AAA"];
+
+/// DJ rank of an EX score: 1 = F .. 8 = AAA. None when the chart has no notes.
+pub fn dj_rank(exscore: i32, notes: i32) -> Option<i32> {
+    if notes <= 0 {
+        return None;
+    }
+    let rate = exscore.max(0) as i64 * 27 / (notes as i64 * 2);
+    Some((rate / 3).clamp(1, 8) as i32)
+}
+
+pub fn is_active(settings: &SelectSettings) -> bool {
+    settings.clear_filter != 0
+        || settings.rank_filter > 0
+        || settings.level_filter_min > 0
+        || settings.level_filter_max > 0
+}
+
+/// Whether a song with best score `score` passes the filters in `settings`.
+pub fn accepts(settings: &SelectSettings, song: &SongData, score: Option<&ScoreData>) -> bool {
+    let clear = score.map_or(ClearType::NoPlay.id(), |score| score.clear);
+    let clear_ok = match settings.clear_filter {
+        CLEAR_FILTER_UNPLAYED => clear == ClearType::NoPlay.id(),
+        CLEAR_FILTER_FAILED => clear == ClearType::Failed.id(),
+        CLEAR_FILTER_CLEARED => clear >= ClearType::Easy.id(),
+        CLEAR_FILTER_FULLCOMBO => clear >= ClearType::FullCombo.id(),
+        _ => true,
+    };
+    let rank_ok = settings.rank_filter <= 0
+        || score
+            .and_then(|score| dj_rank(score.exscore(), score.notes))
+            .is_some_and(|rank| rank >= settings.rank_filter);
+    let level = song.chart.level;
+    let level_ok = (settings.level_filter_min <= 0 || level >= settings.level_filter_min)
+        && (settings.level_filter_max <= 0 || level <= settings.level_filter_max);
+    clear_ok && rank_ok && level_ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::player_config::CLEAR_FILTER_ALL;
+
+    fn score(clear: ClearType, exscore: i32, notes: i32) -> ScoreData {
+        let mut score = ScoreData::default();
+        score.clear = clear.id();
+        score.judge_counts.epg = exscore / 2;
+        score.notes = notes;
+        score
+    }
+
+    fn song(level: i32) -> SongData {
+        let mut song = SongData::default();
+        song.chart.level = level;
+        song
+    }
+
+    #[test]
+    fn test_dj_rank_thresholds() {
+        assert_eq!(dj_rank(100, 0), None);
+        assert_eq!(dj_rank(0, 1000), Some(1));
+        assert_eq!(dj_rank(445, 1000), Some(2));
+        assert_eq!(dj_rank(1777, 1000), Some(7));
+        assert_eq!(dj_rank(1778, 1000), Some(8));
+        assert_eq!(dj_rank(2000, 1000), Some(8));
+    }
+
+    #[test]
+    fn test_clear_filters() {
+        let mut settings = SelectSettings::default();
+        let failed = score(ClearType::Failed, 0, 100);
+        let easy = score(ClearType::Easy, 0, 100);
+        let fc = score(ClearType::FullCombo, 0, 100);
+        assert!(!is_active(&settings));
+
+        settings.clear_filter = CLEAR_FILTER_UNPLAYED;
+        assert!(is_active(&settings));
+        assert!(accepts(&settings, &song(1), None));
+        assert!(!accepts(&settings, &song(1), Some(&failed)));
+
+        settings.clear_filter = CLEAR_FILTER_FAILED;
+        assert!(accepts(&settings, &song(1), Some(&failed)));
+        assert!(!accepts(&settings, &song(1), Some(&easy)));
+
+        settings.clear_filter = CLEAR_FILTER_CLEARED;
+        assert!(accepts(&settings, &song(1), Some(&easy)));
+        assert!(accepts(&settings, &song(1), Some(&fc)));
+        assert!(!accepts(&settings, &song(1), Some(&failed)));
+
+        settings.clear_filter = CLEAR_FILTER_FULLCOMBO;
+        assert!(accepts(&settings, &song(1), Some(&fc)));
+        assert!(!accepts(&settings, &song(1), Some(&easy)));
+
+        settings.clear_filter = CLEAR_FILTER_ALL;
+        assert!(accepts(&settings, &song(1), None));
+    }
+
+    #[test]
+    fn test_rank_and_level_filters_combine() {
+        let mut settings = SelectSettings::default();
+        settings.clear_filter = CLEAR_FILTER_CLEARED;
+        settings.rank_filter = 7; // AA
+        settings.level_filter_min = 10;
+        settings.level_filter_max = 12;
+
+        let aa = score(ClearType::Hard, 1600, 1000);
+        let a = score(ClearType::Hard, 1400, 1000);
+        assert!(accepts(&settings, &song(11), Some(&aa)));
+        assert!(!accepts(&settings, &song(11), Some(&a)));
+        assert!(!accepts(&settings, &song(9), Some(&aa)));
+        assert!(!accepts(&settings, &song(13), Some(&aa)));
+        // Unplayed charts have no rank
+        settings.clear_filter = CLEAR_FILTER_ALL;
+        assert!(!accepts(&settings, &song(11), None));
+
+        settings.rank_filter = 0;
+        settings.level_filter_max = 0;
+        assert!(accepts(&settings, &song(20), None));
+    }
+}
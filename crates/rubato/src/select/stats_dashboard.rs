@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+
+use crate::core::score_log_database_accessor::ScoreLog;
+use crate::skin::player_data::PlayerData;
+
+use super::*;
+
+/// Days of play history shown in the notes-per-day chart.
+pub const DAILY_NOTES_DAYS: usize = 30;
+/// Score improvements shown in the score trend chart.
+pub const SCORE_TREND_COUNT: usize = 50;
+
+/// Notes hit, plays and play time on one day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DailyNotes {
+    /// Local midnight of the day (unix seconds)
+    pub date: i64,
+    pub notes: i64,
+    pub playcount: i64,
+    /// Play time (seconds)
+    pub playtime: i64,
+}
+
+/// Number of charts of one table level per clear lamp, indexed by clear type id.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LevelLamps {
+    pub level: String,
+    pub lamps: [i32; 11],
+}
+
+impl LevelLamps {
+    pub fn total(&self) -> i32 {
+        self.lamps.iter().sum()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableLamps {
+    pub table: String,
+    pub levels: Vec<LevelLamps>,
+}
+
+/// One score improvement from the score log.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScoreTrendPoint {
+    pub date: i64,
+    pub title: String,
+    pub clear: i32,
+    /// EX score rate after the play (0.0-1.0)
+    pub rate: f32,
+    /// EX score rate before the play (0.0-1.0)
+    pub oldrate: f32,
+}
+
+/// Play statistics shown by the stats screen, gathered when it is opened.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StatsDashboard {
+    /// Total play time (seconds)
+    pub playtime: i64,
+    pub playcount: i64,
+    pub clearcount: i64,
+    /// Total notes hit (PGREAT to BAD)
+    pub notes: i64,
+    /// Oldest day first
+    pub daily_notes: Vec<DailyNotes>,
+    pub tables: Vec<TableLamps>,
+    /// Oldest improvement first
+    pub score_trend: Vec<ScoreTrendPoint>,
+}
+
+impl StatsDashboard {
+    /// Totals and daily notes from the player table rows, which hold the
+    /// cumulative data at the end of each day played, newest first.
+    pub fn new(player_datas: &[PlayerData]) -> Self {
+        let latest = player_datas.first().copied().unwrap_or_default();
+        Self {
+            playtime: latest.playtime,
+            playcount: latest.playcount,
+            clearcount: latest.clear,
+            notes: notes_hit(&latest),
+            daily_notes: daily_notes(player_datas),
+            ..Default::default()
+        }
+    }
+
+    /// Set the score trend from score logs (newest first), rating each
+    /// against the chart's note count. Logs of unknown charts are skipped.
+    pub fn set_score_trend(&mut self, logs: &[ScoreLog], songs: &[SongData]) {
+        let songs: HashMap<&str, &SongData> = songs
+            .iter()
+            .map(|song| (song.file.sha256.as_str(), song))
+            .collect();
+        self.score_trend = logs
+            .iter()
+            .rev()
+            .filter_map(|log| {
+                let song = songs.get(log.sha256()?)?;
+                let max = song.chart.notes as f32 * 2.0;
+                if max <= 0.0 {
+                    return None;
+                }
+                Some(ScoreTrendPoint {
+                    date: log.date,
+                    title: song.display_title(),
+                    clear: log.clear,
+                    rate: log.score as f32 / max,
+                    oldrate: log.oldscore.max(0) as f32 / max,
+                })
+            })
+            .collect();
+    }
+}
+
+/// Notes hit (PGREAT to BAD) in a player data row.
+fn notes_hit(pd: &PlayerData) -> i64 {
+    (0..4).map(|judge| pd.judge_count(judge)).sum()
+}
+
+/// Per-day notes from cumulative rows (newest first), oldest day first.
+/// The oldest row has nothing to diff against and counts as its own day.
+fn daily_notes(player_datas: &[PlayerData]) -> Vec<DailyNotes> {
+    let rows = &player_datas[..player_datas.len().min(DAILY_NOTES_DAYS + 1)];
+    let mut days: Vec<DailyNotes> = rows
+        .iter()
+        .enumerate()
+        .map(|(i, pd)| {
+            let prev = rows.get(i + 1).copied().unwrap_or_default();
+            DailyNotes {
+                date: pd.date,
+                notes: (notes_hit(pd) - notes_hit(&prev)).max(0),
+                playcount: (pd.playcount - prev.playcount).max(0),
+                playtime: (pd.playtime - prev.playtime).max(0),
+            }
+        })
+        .collect();
+    days.truncate(DAILY_NOTES_DAYS);
+    days.reverse();
+    days
+}
+
+/// Lamp distribution of each level of `table`. Charts not in the song
+/// database are not counted; charts without a score count as NO PLAY.
+pub fn table_lamps(
+    table: &TableData,
+    songs: &[SongData],
+    clear: impl Fn(&SongData) -> Option<i32>,
+) -> TableLamps {
+    let owned: HashMap<&str, &SongData> = songs
+        .iter()
+        .filter(|song| song.file.path().is_some())
+        .flat_map(|song| {
+            [
+                (song.file.sha256.as_str(), song),
+                (song.file.md5.as_str(), song),
+            ]
+        })
+        .filter(|(hash, _)| !hash.is_empty())
+        .collect();
+    let levels = table
+        .folder
+        .iter()
+        .map(|folder| {
+            let mut lamps = [0; 11];
+            for element in &folder.songs {
+                let song = [element.file.sha256.as_str(), element.file.md5.as_str()]
+                    .into_iter()
+                    .filter(|hash| !hash.is_empty())
+                    .find_map(|hash| owned.get(hash));
+                if let Some(song) = song {
+                    let id = clear(song).unwrap_or(0).clamp(0, 10) as usize;
+                    lamps[id] += 1;
+                }
+            }
+            LevelLamps {
+                level: folder.name().to_string(),
+                lamps,
+            }
+        })
+        .collect();
+    TableLamps {
+        table: table.name.clone(),
+        levels,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player_data(date: i64, epg: i64, playcount: i64, playtime: i64) -> PlayerData {
+        PlayerData {
+            date,
+            epg,
+            playcount,
+            playtime,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn daily_notes_diff_cumulative_rows() {
+        let rows = [
+            player_data(300, 1500, 12, 900),
+            player_data(200, 1000, 10, 600),
+            player_data(100, 400, 4, 300),
+        ];
+        let stats = StatsDashboard::new(&rows);
+        assert_eq!(stats.playtime, 900);
+        assert_eq!(stats.notes, 1500);
+        assert_eq!(
+            stats.daily_notes,
+            vec![
+                DailyNotes {
+                    date: 100,
+                    notes: 400,
+                    playcount: 4,
+                    playtime: 300
+                },
+                DailyNotes {
+                    date: 200,
+                    notes: 600,
+                    playcount: 6,
+                    playtime: 300
+                },
+                DailyNotes {
+                    date: 300,
+                    notes: 500,
+                    playcount: 2,
+                    playtime: 300
+                },
+            ]
+        );
+        assert_eq!(StatsDashboard::new(&[]), StatsDashboard::default());
+    }
+
+    #[test]
+    fn table_lamps_count_owned_charts_per_level() {
+        let song = |sha256: &str, md5: &str, path: Option<&str>| {
+            let mut sd = SongData::default();
+            sd.file.sha256 = sha256.to_string();
+            sd.file.md5 = md5.to_string();
+            if let Some(path) = path {
+                sd.file.set_path(path.to_string());
+            }
+            sd
+        };
+        let mut table = TableData::default();
+        table.name = "Insane".to_string();
+        let mut level = TableFolder::default();
+        level.name = Some("★1".to_string());
+        level.songs = vec![
+            song("", "m1", None),
+            song("s2", "", None),
+            song("s3", "", None),
+        ];
+        table.folder = vec![level];
+
+        let songs = [
+            song("s1", "m1", Some("a.bms")),
+            song("s2", "m2", Some("b.bms")),
+            song("s3", "m3", None),
+        ];
+        let lamps = table_lamps(&table, &songs, |sd| (sd.file.sha256 == "s1").then_some(5));
+
+        assert_eq!(lamps.table, "Insane");
+        assert_eq!(lamps.levels[0].level, "★1");
+        assert_eq!(lamps.levels[0].lamps[5], 1);
+        assert_eq!(lamps.levels[0].lamps[0], 1);
+        assert_eq!(lamps.levels[0].total(), 2);
+    }
+
+    #[test]
+    fn score_trend_rates_logs_oldest_first() {
+        let mut song = SongData::default();
+        song.file.sha256 = "s1".to_string();
+        song.metadata.title = "Song".to_string();
+        song.chart.notes = 500;
+        let log = |score: i32, oldscore: i32, date: i64| {
+            let mut log = ScoreLog::new();
+            log.set_sha256("s1");
+            log.score = score;
+            log.oldscore = oldscore;
+            log.date = date;
+            log
+        };
+        let mut unknown = log(10, 0, 30);
+        unknown.set_sha256("missing");
+        let mut stats = StatsDashboard::default();
+        stats.set_score_trend(&[unknown, log(800, 500, 20), log(500, 0, 10)], &[song]);
+
+        assert_eq!(stats.score_trend.len(), 2);
+        assert_eq!(stats.score_trend[0].date, 10);
+        assert_eq!(stats.score_trend[1].rate, 0.8);
+        assert_eq!(stats.score_trend[1].oldrate, 0.5);
+        assert_eq!(stats.score_trend[1].title, "Song");
+    }
+}
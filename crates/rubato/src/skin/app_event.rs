@@ -34,6 +34,14 @@ pub struct StateChangedData {
     pub status: i32,
     /// Song metadata, present when a song is loaded (Play/Result screens).
     pub song_info: Option<SongInfo>,
+    /// Gauge type (`GrooveGauge::*`) of the current or finished play.
+    pub gauge_type: Option<i32>,
+    /// Clear type id (`ClearType::id`) of the finished play (Result screens).
+    pub clear_type: Option<i32>,
+    /// Clear type id of the best score before this play (Result screens).
+    pub previous_clear_type: Option<i32>,
+    /// Milliseconds since TIMER_PLAY started, present once notes are playing.
+    pub play_elapsed_ms: Option<i64>,
 }
 
 /// Song information snapshot for Discord Rich Presence.
@@ -44,4 +52,9 @@ pub struct SongInfo {
     pub artist: String,
     /// The key mode (e.g. 7, 14) from `SongData.chart.mode`.
     pub mode: i32,
+    /// Chart difficulty (1 = BEGINNER .. 5 = INSANE, 0 = unknown).
+    pub difficulty: i32,
+    pub level: i32,
+    /// Song length in milliseconds.
+    pub length_ms: i64,
 }
@@ -24,6 +24,13 @@ pub struct AudioConfig {
     pub driver_name: Option<String>,
     #[serde(rename = "deviceBufferSize")]
     pub device_buffer_size: i32,
+    /// Device buffers queued ahead of playback.
+    #[serde(rename = "devicePeriodCount")]
+    pub device_period_count: i32,
+    /// Output latency (ms) measured by the launcher's round-trip test for the
+    /// buffer settings above. 0 when not measured.
+    #[serde(rename = "measuredOutputLatency")]
+    pub measured_output_latency: i32,
     #[serde(rename = "deviceSimultaneousSources")]
     pub device_simultaneous_sources: i32,
     #[serde(rename = "sampleRate")]
@@ -49,6 +56,8 @@ impl Default for AudioConfig {
             driver: DriverType::OpenAL,
             driver_name: None,
             device_buffer_size: 384,
+            device_period_count: 2,
+            measured_output_latency: 0,
             device_simultaneous_sources: 128,
             sample_rate: 0,
             freq_option: FrequencyType::FREQUENCY,
@@ -71,11 +80,18 @@ impl AudioConfig {
     pub fn driver_name(&self) -> Option<&str> {
         self.driver_name.as_deref()
     }
+
+    /// Frames buffered ahead of playback: buffer size times period count.
+    pub fn buffered_frames(&self) -> i32 {
+        self.device_buffer_size * self.device_period_count
+    }
 }
 
 impl Validatable for AudioConfig {
     fn validate(&mut self) -> bool {
         self.device_buffer_size = self.device_buffer_size.clamp(4, 4096);
+        self.device_period_count = self.device_period_count.clamp(1, 8);
+        self.measured_output_latency = self.measured_output_latency.clamp(0, 1000);
         self.device_simultaneous_sources = self.device_simultaneous_sources.clamp(16, 1024);
         self.systemvolume = self.systemvolume.clamp(0.0, 1.0);
         self.keyvolume = self.keyvolume.clamp(0.0, 1.0);
@@ -87,6 +103,7 @@ impl Validatable for AudioConfig {
 #[cfg(test)]
 mod tests {
     use super::AudioConfig;
+    use crate::skin::validatable::Validatable;
 
     #[test]
     fn default_volumes_are_point_one() {
@@ -96,4 +113,15 @@ mod tests {
         assert_eq!(config.keyvolume, 0.1);
         assert_eq!(config.bgvolume, 0.1);
     }
+
+    #[test]
+    fn period_count_is_validated_and_multiplies_buffer() {
+        let mut config: AudioConfig =
+            serde_json::from_str(r#"{"deviceBufferSize":256,"devicePeriodCount":0}"#).unwrap();
+        config.validate();
+
+        assert_eq!(config.device_period_count, 1);
+        assert_eq!(config.buffered_frames(), 256);
+        assert_eq!(AudioConfig::default().buffered_frames(), 768);
+    }
 }
@@ -55,17 +55,20 @@ impl BMSPlayerRule {
                     2 // default to rank 2 (NORMAL)
                 };
                 model.judgerank = table[idx];
+                model.judgerank_fraction = 0.0;
             }
             JudgeRankType::BmsDefexrank => {
                 if judgerank > 0 {
-                    model.judgerank = judgerank * table[2] / 100;
+                    model.set_judgerank_exact(model.judgerank_exact() * table[2] as f64 / 100.0);
                 } else {
                     model.judgerank = table[2];
+                    model.judgerank_fraction = 0.0;
                 }
             }
             JudgeRankType::BmsonJudgerank => {
                 if judgerank <= 0 {
                     model.judgerank = 100;
+                    model.judgerank_fraction = 0.0;
                 }
             }
         }
@@ -176,8 +179,22 @@ mod tests {
 
         BMSPlayerRule::validate(&mut model);
 
-        // 150 * 75 / 100 = 112
+        // 150 * 75 / 100 = 112.5
         assert_eq!(model.judgerank, 112);
+        assert_eq!(model.judgerank_fraction, 0.5);
+    }
+
+    #[test]
+    fn test_validate_fractional_defexrank() {
+        let mut model = BMSModel::new();
+        model.set_judgerank_exact(87.5);
+        model.judgerank_type = JudgeRankType::BmsDefexrank;
+
+        BMSPlayerRule::validate(&mut model);
+
+        // 87.5 * 75 / 100 = 65.625
+        assert_eq!(model.judgerank_exact(), 65.625);
+        assert_eq!(model.judgerank_type, JudgeRankType::BmsonJudgerank);
     }
 
     #[test]
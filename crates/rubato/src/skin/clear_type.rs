@@ -75,6 +75,23 @@ impl ClearType {
         ClearType::NoPlay
     }
 
+    /// Combo lamp for a play that kept its combo through every note:
+    /// MAX with only PGREATs, PERFECT with no GOOD, FULL COMBO otherwise.
+    pub fn combo_lamp(great: i32, good: i32) -> ClearType {
+        if good != 0 {
+            ClearType::FullCombo
+        } else if great != 0 {
+            ClearType::Perfect
+        } else {
+            ClearType::Max
+        }
+    }
+
+    /// Whether this lamp is FULL COMBO or better (FULL COMBO, PERFECT, MAX).
+    pub fn is_full_combo(&self) -> bool {
+        self.id() >= ClearType::FullCombo.id()
+    }
+
     /// Get ClearType by gauge type. Returns None if not found.
     pub fn clear_type_by_gauge(gaugetype: i32) -> Option<ClearType> {
         for clear in ClearType::values() {
@@ -121,6 +138,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_combo_lamp_precedence() {
+        assert_eq!(ClearType::combo_lamp(0, 0), ClearType::Max);
+        assert_eq!(ClearType::combo_lamp(3, 0), ClearType::Perfect);
+        assert_eq!(ClearType::combo_lamp(0, 1), ClearType::FullCombo);
+        assert_eq!(ClearType::combo_lamp(3, 1), ClearType::FullCombo);
+        assert!(ClearType::Max.id() > ClearType::Perfect.id());
+        assert!(ClearType::Perfect.id() > ClearType::FullCombo.id());
+        assert!(ClearType::FullCombo.id() > ClearType::ExHard.id());
+    }
+
+    #[test]
+    fn test_is_full_combo() {
+        assert!(ClearType::FullCombo.is_full_combo());
+        assert!(ClearType::Perfect.is_full_combo());
+        assert!(ClearType::Max.is_full_combo());
+        assert!(!ClearType::ExHard.is_full_combo());
+        assert!(!ClearType::NoPlay.is_full_combo());
+    }
+
     #[test]
     fn test_from_id_invalid_returns_noplay() {
         assert_eq!(ClearType::clear_type_by_id(-1), ClearType::NoPlay);
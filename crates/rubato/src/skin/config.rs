@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use crate::skin::audio_config::AudioConfig;
+use crate::skin::config_migration::{CONFIG_MIGRATIONS, CONFIG_VERSION, parse_migrated};
 use crate::skin::player_config::PlayerConfig;
 use crate::skin::resolution::Resolution;
 use crate::skin::validatable::{Validatable, remove_empty_strings};
@@ -12,6 +13,8 @@ pub const TABLEPATH_DEFAULT: &str = "table";
 pub const PLAYERPATH_DEFAULT: &str = "player";
 pub const SKINPATH_DEFAULT: &str = "skin";
 pub const DEFAULT_DOWNLOAD_DIRECTORY: &str = "http_download";
+/// Upper bound for `NetworkConfig::max_concurrent_downloads`.
+pub const MAX_CONCURRENT_DOWNLOADS: i32 = 5;
 
 /// BGA display mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -98,6 +101,14 @@ pub const BGA_ON: BgaMode = BgaMode::On;
 pub const BGA_AUTO: BgaMode = BgaMode::Auto;
 pub const BGA_OFF: BgaMode = BgaMode::Off;
 
+/// Highest BGA dim level in percent; the BGA never goes fully black.
+pub const BGA_DIM_MAX: i32 = 90;
+/// BGA dim change per hotkey press, in percent.
+pub const BGA_DIM_STEP: i32 = 10;
+/// Range of the texture cache budget in MB.
+pub const TEXTURE_BUDGET_MIN: i32 = 128;
+pub const TEXTURE_BUDGET_MAX: i32 = 16384;
+
 pub const BGAEXPAND_FULL: BgaExpand = BgaExpand::Full;
 pub const BGAEXPAND_KEEP_ASPECT_RATIO: BgaExpand = BgaExpand::KeepAspectRatio;
 pub const BGAEXPAND_OFF: BgaExpand = BgaExpand::Off;
@@ -215,6 +226,14 @@ pub struct DisplayConfig {
     pub max_frame_per_second: i32,
     #[serde(rename = "prepareFramePerSecond")]
     pub prepare_frame_per_second: i32,
+    /// Sample keyboard and controllers on a 1000Hz thread so press times are
+    /// not rounded to the frame that noticed them.
+    #[serde(rename = "inputPollThread")]
+    pub input_poll_thread: bool,
+    /// Judge notes on a 1000Hz thread instead of once per frame, so misses and
+    /// keysounds do not wait for the next frame.
+    #[serde(rename = "judgeThread")]
+    pub judge_thread: bool,
 }
 
 impl Default for DisplayConfig {
@@ -228,6 +247,8 @@ impl Default for DisplayConfig {
             window_height: 720,
             max_frame_per_second: 240,
             prepare_frame_per_second: 0,
+            input_poll_thread: false,
+            judge_thread: false,
         }
     }
 }
@@ -278,6 +299,9 @@ pub struct RenderConfig {
     pub bga: BgaMode,
     #[serde(rename = "bgaExpand")]
     pub bga_expand: BgaExpand,
+    /// Darkening applied to the BGA, in percent (0 = none).
+    #[serde(rename = "bgaDim")]
+    pub bga_dim: i32,
     pub frameskip: i32,
     #[serde(rename = "skinPixmapGen")]
     pub skin_pixmap_gen: i32,
@@ -287,6 +311,17 @@ pub struct RenderConfig {
     pub banner_pixmap_gen: i32,
     #[serde(rename = "songResourceGen")]
     pub song_resource_gen: i32,
+    /// Memory limit in MB for each texture cache (GPU textures, decoded BGA images).
+    /// Least recently used textures beyond it are freed and loaded again when needed.
+    #[serde(rename = "textureBudget")]
+    pub texture_budget: i32,
+}
+
+impl RenderConfig {
+    /// `texture_budget` in bytes.
+    pub fn texture_budget_bytes(&self) -> usize {
+        self.texture_budget.max(0) as usize * 1024 * 1024
+    }
 }
 
 impl Default for RenderConfig {
@@ -294,11 +329,13 @@ impl Default for RenderConfig {
         Self {
             bga: BGA_ON,
             bga_expand: BGAEXPAND_KEEP_ASPECT_RATIO,
+            bga_dim: 0,
             frameskip: 1,
             skin_pixmap_gen: 4,
             stagefile_pixmap_gen: 2,
             banner_pixmap_gen: 2,
             song_resource_gen: 1,
+            texture_budget: 1024,
         }
     }
 }
@@ -322,6 +359,29 @@ pub struct NetworkConfig {
     pub download_directory: String,
     #[serde(rename = "irSendCount")]
     pub ir_send_count: i32,
+    /// Number of in-game downloads running at once; the rest wait in the queue.
+    #[serde(rename = "maxConcurrentDownloads")]
+    pub max_concurrent_downloads: i32,
+    /// Try the other download sources when a package is missing, broken or
+    /// lacks the requested chart.
+    #[serde(rename = "mirrorFallback")]
+    pub mirror_fallback: bool,
+    /// Subfolder of the download directory packages are extracted into;
+    /// `{table}` and `{source}` expand per download. Empty extracts directly.
+    #[serde(rename = "downloadPlacement")]
+    pub download_placement: String,
+    /// Rename extracted package folders to names valid on every platform.
+    #[serde(rename = "sanitizeDownloadNames")]
+    pub sanitize_download_names: bool,
+    /// Combined speed cap for in-game downloads in KiB/s; 0 is unlimited.
+    #[serde(rename = "downloadSpeedLimit")]
+    pub download_speed_limit: i32,
+    /// Hold downloads while a chart loads or plays so they cannot cause audio dropouts.
+    #[serde(rename = "downloadOnlyWhenIdle")]
+    pub download_only_when_idle: bool,
+    /// Look for a new release when the launcher opens. Off for offline and arcade machines.
+    #[serde(rename = "checkUpdates")]
+    pub check_updates: bool,
 }
 
 impl Default for NetworkConfig {
@@ -335,6 +395,13 @@ impl Default for NetworkConfig {
             override_download_url: String::new(),
             download_directory: DEFAULT_DOWNLOAD_DIRECTORY.to_string(),
             ir_send_count: 5,
+            max_concurrent_downloads: MAX_CONCURRENT_DOWNLOADS,
+            mirror_fallback: true,
+            download_placement: String::new(),
+            sanitize_download_names: true,
+            download_speed_limit: 0,
+            download_only_when_idle: false,
+            check_updates: true,
         }
     }
 }
@@ -355,6 +422,15 @@ pub struct ObsConfig {
     pub obs_ws_rec_stop_wait: i32,
     #[serde(rename = "obsWsRecMode")]
     pub obs_ws_rec_mode: i32,
+    /// Start recording when the notes start and stop it when the play ends.
+    #[serde(rename = "obsWsRecOnPlay")]
+    pub obs_ws_rec_on_play: bool,
+    /// Save the replay buffer on a FULL COMBO or better.
+    #[serde(rename = "obsWsReplayOnFullCombo")]
+    pub obs_ws_replay_on_full_combo: bool,
+    /// Save the replay buffer when the clear lamp improves.
+    #[serde(rename = "obsWsReplayOnNewLamp")]
+    pub obs_ws_replay_on_new_lamp: bool,
     #[serde(rename = "obsScenes")]
     pub obs_scenes: HashMap<String, String>,
     #[serde(rename = "obsActions")]
@@ -370,6 +446,9 @@ impl Default for ObsConfig {
             obs_ws_pass: String::new(),
             obs_ws_rec_stop_wait: 5000,
             obs_ws_rec_mode: 0,
+            obs_ws_rec_on_play: false,
+            obs_ws_replay_on_full_combo: false,
+            obs_ws_replay_on_new_lamp: false,
             obs_scenes: HashMap::new(),
             obs_actions: HashMap::new(),
         }
@@ -382,6 +461,9 @@ impl Default for ObsConfig {
 pub struct IntegrationConfig {
     #[serde(rename = "useDiscordRPC", alias = "useDiscordRpc")]
     pub use_discord_rpc: bool,
+    /// Show only the screen and key mode in Rich Presence, not the song.
+    #[serde(rename = "discordHideSongDetails")]
+    pub discord_hide_song_details: bool,
     #[serde(rename = "setClipboardScreenshot")]
     pub set_clipboard_screenshot: bool,
     #[serde(rename = "monitorName")]
@@ -394,8 +476,66 @@ pub struct IntegrationConfig {
     pub webhook_avatar: String,
     #[serde(rename = "webhookUrl")]
     pub webhook_url: Vec<String>,
+    /// Only send result screenshots that improve the EX score or clear lamp.
+    #[serde(rename = "webhookOnlyPersonalBest")]
+    pub webhook_only_personal_best: bool,
+    /// Only send result screenshots of cleared plays.
+    #[serde(rename = "webhookOnlyClear")]
+    pub webhook_only_clear: bool,
+    /// Minimum DJ LEVEL for result screenshots (index into `WEBHOOK_DJ_LEVELS`, 0 = any).
+    #[serde(rename = "webhookMinRank")]
+    pub webhook_min_rank: i32,
+    /// JSON file that replaces the built-in scorecard embed. Empty uses the built-in one.
+    #[serde(rename = "webhookTemplate")]
+    pub webhook_template: String,
+    /// Capture the result screen automatically, stamped with the play's metadata.
+    #[serde(rename = "resultScreenshot")]
+    pub result_screenshot: bool,
+    /// Folder for automatic result screenshots. Empty uses `screenshot/result`.
+    #[serde(rename = "resultScreenshotDir")]
+    pub result_screenshot_dir: String,
+    /// Also copy automatic result screenshots to the clipboard and send them to the webhooks.
+    #[serde(rename = "resultScreenshotShare")]
+    pub result_screenshot_share: bool,
+    /// Mastodon instance (e.g. `mastodon.social`) for the result share hotkey.
+    #[serde(rename = "mastodonInstance")]
+    pub mastodon_instance: String,
+    #[serde(rename = "mastodonAccessToken")]
+    pub mastodon_access_token: String,
+    /// Misskey instance (e.g. `misskey.io`) for the result share hotkey.
+    #[serde(rename = "misskeyInstance")]
+    pub misskey_instance: String,
+    #[serde(rename = "misskeyAccessToken")]
+    pub misskey_access_token: String,
+    /// Post text template; see `social_share::share_text`. Empty uses the built-in text.
+    #[serde(rename = "socialShareText")]
+    pub social_share_text: String,
+    /// Serve song, live score and recent results on localhost for stream overlays.
+    #[serde(rename = "overlayServer")]
+    pub overlay_server: bool,
+    /// Overlay server port. 0 uses `DEFAULT_OVERLAY_PORT`.
+    #[serde(rename = "overlayServerPort")]
+    pub overlay_server_port: i32,
+    /// Push state changes, song, live score and gauge to browser overlays over WebSocket.
+    #[serde(rename = "statePushServer")]
+    pub state_push_server: bool,
+    /// State push server port. 0 uses `DEFAULT_STATE_PUSH_PORT`.
+    #[serde(rename = "statePushPort")]
+    pub state_push_port: i32,
+    /// File rewritten with the current song and live score for OBS text sources.
+    /// When it ends in `.json` it holds the full game state, lamps included.
+    /// Empty disables it.
+    #[serde(rename = "nowPlayingPath")]
+    pub now_playing_path: String,
+    /// File the runtime metrics report (frame time, audio load, judge latency,
+    /// DB timings) is dumped to periodically. Empty disables it.
+    #[serde(rename = "metricsDumpPath")]
+    pub metrics_dump_path: String,
 }
 
+/// DJ LEVEL names selectable as `IntegrationConfig::webhook_min_rank`, lowest first.
+pub const WEBHOOK_DJ_LEVELS: [&str; 8] = ["F", "E", "D", "C", "B", "A", "AA", "AAA"];
+
 /// Music select screen configuration.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
@@ -405,6 +545,13 @@ pub struct SelectConfig {
     pub max_search_bar_count: i32,
     #[serde(rename = "skipDecideScreen")]
     pub skip_decide_screen: bool,
+    /// Shortest time (ms) the decide screen is shown while it loads the chart.
+    #[serde(rename = "decideMinDuration")]
+    pub decide_min_duration: i32,
+    /// Time (ms) after which the decide screen moves on even if loading is
+    /// unfinished; Play then waits for the rest. 0 waits for loading.
+    #[serde(rename = "decideMaxDuration")]
+    pub decide_max_duration: i32,
     #[serde(rename = "showNoSongExistingBar")]
     pub show_no_song_existing_bar: bool,
     pub scrolldurationlow: i32,
@@ -417,6 +564,9 @@ pub struct SelectConfig {
     pub song_preview: SongPreview,
     #[serde(rename = "cacheSkinImage")]
     pub cache_skin_image: bool,
+    /// Rescan BMS folders changed on disk while the game runs.
+    #[serde(rename = "watchLibrary")]
+    pub watch_library: bool,
 }
 
 impl Default for SelectConfig {
@@ -425,6 +575,8 @@ impl Default for SelectConfig {
             folderlamp: true,
             max_search_bar_count: 10,
             skip_decide_screen: false,
+            decide_min_duration: 0,
+            decide_max_duration: 10000,
             show_no_song_existing_bar: true,
             scrolldurationlow: 300,
             scrolldurationhigh: 50,
@@ -432,6 +584,7 @@ impl Default for SelectConfig {
             analog_ticks_per_scroll: 3,
             song_preview: SongPreview::LOOP,
             cache_skin_image: false,
+            watch_library: false,
         }
     }
 }
@@ -441,7 +594,12 @@ impl Default for SelectConfig {
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
 pub struct Config {
+    /// Schema version, see [`crate::skin::config_migration`].
+    #[serde(rename = "configVersion")]
+    pub config_version: u32,
     pub playername: Option<String>,
+    /// UI language code (`en`, `ja`). Empty follows the system locale.
+    pub locale: String,
     #[serde(rename = "lastBootedVersion")]
     pub last_booted_version: String,
     pub audio: Option<AudioConfig>,
@@ -468,7 +626,9 @@ pub struct Config {
 impl Default for Config {
     fn default() -> Self {
         Config {
+            config_version: CONFIG_VERSION,
             playername: None,
+            locale: String::new(),
             last_booted_version: String::new(),
             audio: None,
             use_song_info: true,
@@ -583,16 +743,18 @@ impl Config {
         if configpath.exists() {
             attempted_existing = true;
             match std::fs::read_to_string(&configpath) {
-                Ok(data) => match serde_json::from_str::<Config>(&data) {
+                Ok(data) => match Config::from_json(&data) {
                     Ok(c) => config = Some(c),
                     Err(e) => {
                         log::error!("Failed to parse config: {}", e);
                         write_backup_config_file(&configpath);
+                        config = read_previous_config(&configpath);
                     }
                 },
                 Err(e) => {
                     log::error!("Failed to read config: {}", e);
                     write_backup_config_file(&configpath);
+                    config = read_previous_config(&configpath);
                 }
             }
         }
@@ -600,7 +762,7 @@ impl Config {
         if config.is_none() && configpath_old.exists() {
             attempted_existing = true;
             match std::fs::read_to_string(&configpath_old) {
-                Ok(data) => match serde_json::from_str::<Config>(&data) {
+                Ok(data) => match Config::from_json(&data) {
                     Ok(c) => config = Some(c),
                     Err(e) => {
                         log::error!("Failed to parse old config: {}", e);
@@ -623,12 +785,16 @@ impl Config {
         Config::validate_config(config)
     }
 
+    /// Parse `config_sys.json` contents, upgrading older schema versions.
+    pub fn from_json(data: &str) -> anyhow::Result<Config> {
+        parse_migrated(data, CONFIG_MIGRATIONS, CONFIG_VERSION)
+    }
+
     /// Write config to a specific directory as `config_sys.json`.
     pub fn write_to(config: &Config, dir: &Path) -> anyhow::Result<()> {
         let configpath = dir.join("config_sys.json");
         let json = serde_json::to_string_pretty(config)?;
-        std::fs::write(configpath, json.as_bytes())?;
-        Ok(())
+        replace_file(&configpath, json.as_bytes())
     }
 
     /// Read config from the resolved config directory.
@@ -674,12 +840,30 @@ impl Validatable for Config {
 
         self.select.scrolldurationlow = self.select.scrolldurationlow.clamp(2, 1000);
         self.select.scrolldurationhigh = self.select.scrolldurationhigh.clamp(1, 1000);
+        self.select.decide_min_duration = self.select.decide_min_duration.clamp(0, 60000);
+        self.select.decide_max_duration = self.select.decide_max_duration.clamp(0, 60000);
+        if self.select.decide_max_duration > 0 {
+            self.select.decide_max_duration = self
+                .select
+                .decide_max_duration
+                .max(self.select.decide_min_duration);
+        }
         self.network.ir_send_count = self.network.ir_send_count.clamp(1, 100);
+        self.network.max_concurrent_downloads = self
+            .network
+            .max_concurrent_downloads
+            .clamp(1, MAX_CONCURRENT_DOWNLOADS);
+        self.network.download_speed_limit = self.network.download_speed_limit.max(0);
 
+        self.render.bga_dim = self.render.bga_dim.clamp(0, BGA_DIM_MAX);
         self.render.skin_pixmap_gen = self.render.skin_pixmap_gen.clamp(0, 100);
         self.render.stagefile_pixmap_gen = self.render.stagefile_pixmap_gen.clamp(0, 100);
         self.render.banner_pixmap_gen = self.render.banner_pixmap_gen.clamp(0, 100);
         self.render.song_resource_gen = self.render.song_resource_gen.clamp(0, 100);
+        self.render.texture_budget = self
+            .render
+            .texture_budget
+            .clamp(TEXTURE_BUDGET_MIN, TEXTURE_BUDGET_MAX);
 
         self.paths.bmsroot = remove_empty_strings(&self.paths.bmsroot);
 
@@ -739,6 +923,10 @@ impl Validatable for Config {
         }
         // ObsRecordingMode has 3 variants: 0=KeepAll, 1=OnScreenshot, 2=OnReplay
         self.obs.obs_ws_rec_mode = self.obs.obs_ws_rec_mode.clamp(0, 2);
+        self.integration.webhook_min_rank = self
+            .integration
+            .webhook_min_rank
+            .clamp(0, WEBHOOK_DJ_LEVELS.len() as i32 - 1);
         true
     }
 }
@@ -762,6 +950,53 @@ fn write_backup_config_file(configpath: &Path) {
     }
 }
 
+/// Previous config kept by [`replace_file`], used when `config_sys.json` is unreadable.
+fn read_previous_config(configpath: &Path) -> Option<Config> {
+    let previous = previous_file_path(configpath);
+    let data = std::fs::read_to_string(&previous).ok()?;
+    match Config::from_json(&data) {
+        Ok(config) => {
+            log::warn!("Restored config from {:?}", previous);
+            Some(config)
+        }
+        Err(e) => {
+            log::error!("Failed to parse previous config: {}", e);
+            None
+        }
+    }
+}
+
+fn previous_file_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".bak");
+    path.with_file_name(name)
+}
+
+/// Atomically replace `path` with `data`, keeping the previous file as `<name>.bak`.
+///
+/// The data goes to `<name>.tmp` and is fsynced before the rename, so a crash
+/// mid-write leaves either the old or the new file in place. Unchanged
+/// contents are not rewritten.
+pub(crate) fn replace_file(path: &Path, data: &[u8]) -> anyhow::Result<()> {
+    use std::io::Write;
+
+    match std::fs::read(path) {
+        Ok(current) if current == data => return Ok(()),
+        Ok(_) => {
+            std::fs::copy(path, previous_file_path(path))?;
+        }
+        Err(_) => {}
+    }
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp = path.with_file_name(tmp_name);
+    let mut file = std::fs::File::create(&tmp)?;
+    file.write_all(data)?;
+    file.sync_all()?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
 pub fn resolve_config_dir(start_dir: &Path) -> Option<PathBuf> {
     let start_dir = start_dir
         .canonicalize()
@@ -1056,6 +1291,18 @@ mod tests {
         assert_eq!(config.render.song_resource_gen, 100);
     }
 
+    #[test]
+    fn config_validate_clamps_texture_budget() {
+        let mut config = Config::default();
+        config.render.texture_budget = 1;
+        config.validate();
+        assert_eq!(config.render.texture_budget, TEXTURE_BUDGET_MIN);
+        assert_eq!(
+            config.render.texture_budget_bytes(),
+            TEXTURE_BUDGET_MIN as usize * 1024 * 1024
+        );
+    }
+
     #[test]
     fn config_validate_clamps_obs_rec_mode() {
         let mut config = Config::default();
@@ -1080,6 +1327,25 @@ mod tests {
         assert_eq!(config.network.ir_send_count, 100);
     }
 
+    #[test]
+    fn config_validate_clamps_max_concurrent_downloads() {
+        let mut config = Config::default();
+        config.network.max_concurrent_downloads = 0;
+        config.validate();
+        assert_eq!(config.network.max_concurrent_downloads, 1);
+
+        config.network.max_concurrent_downloads = 10;
+        config.validate();
+        assert_eq!(
+            config.network.max_concurrent_downloads,
+            MAX_CONCURRENT_DOWNLOADS
+        );
+
+        config.network.download_speed_limit = -1;
+        config.validate();
+        assert_eq!(config.network.download_speed_limit, 0);
+    }
+
     // -- Config methods --
 
     #[test]
@@ -1092,6 +1358,20 @@ mod tests {
         assert_eq!(config.select.analog_ticks_per_scroll, 1);
     }
 
+    #[test]
+    fn config_validate_keeps_decide_max_duration_above_min() {
+        let mut config = Config::default();
+        config.select.decide_min_duration = 5000;
+        config.select.decide_max_duration = 2000;
+        config.validate();
+        assert_eq!(config.select.decide_max_duration, 5000);
+
+        // 0 disables the limit
+        config.select.decide_max_duration = 0;
+        config.validate();
+        assert_eq!(config.select.decide_max_duration, 0);
+    }
+
     #[test]
     fn config_obs_ws_pass_empty_returns_none() {
         let mut config = Config::default();
@@ -1207,6 +1487,42 @@ mod tests {
         assert_eq!(loaded.display.window_width, 1600);
     }
 
+    #[test]
+    fn config_write_keeps_previous_file_and_restores_it_when_corrupt() {
+        let dir = tempfile::tempdir().unwrap();
+        let configpath = dir.path().join("config_sys.json");
+        let mut config = Config::default();
+        config.display.window_width = 1600;
+        Config::write_to(&config, dir.path()).unwrap();
+        assert!(!configpath.with_file_name("config_sys.json.bak").exists());
+
+        config.display.window_width = 1920;
+        Config::write_to(&config, dir.path()).unwrap();
+        assert!(!configpath.with_file_name("config_sys.json.tmp").exists());
+
+        std::fs::write(&configpath, "{ truncated").unwrap();
+        let loaded = Config::read_from(dir.path()).unwrap();
+        assert_eq!(loaded.display.window_width, 1600);
+    }
+
+    #[test]
+    fn config_read_unversioned_file_is_migrated() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("config_sys.json"),
+            r#"{"playername":"legacy","windowWidth":1600}"#,
+        )
+        .unwrap();
+
+        let config = Config::read_from(dir.path()).unwrap();
+
+        assert_eq!(config.config_version, CONFIG_VERSION);
+        assert_eq!(config.playername.as_deref(), Some("legacy"));
+        assert_eq!(config.display.window_width, 1600);
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["configVersion"], CONFIG_VERSION);
+    }
+
     #[test]
     fn config_read_missing_file_returns_default() {
         let dir = tempfile::tempdir().unwrap();
@@ -0,0 +1,179 @@
+//! Schema versioning for `config_sys.json` and `config_player.json`.
+//!
+//! Both files carry a `configVersion` number. Files written before versioning
+//! (including beatoraja's) have no such field and count as version 0. When a
+//! file is read, every migration from its version up to the current one is
+//! applied to the raw JSON before it is deserialized, so renamed or
+//! re-interpreted fields are carried over instead of silently falling back to
+//! their defaults.
+//!
+//! To change the schema: bump [`CONFIG_VERSION`] or [`PLAYER_CONFIG_VERSION`]
+//! and append a [`Migration`] whose `from` is the previous version.
+
+use anyhow::{Context, Result};
+use serde_json::{Map, Value};
+
+/// JSON key holding the schema version in both config files.
+pub const VERSION_KEY: &str = "configVersion";
+
+/// Current schema version of `config_sys.json`.
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Current schema version of `config_player.json`.
+pub const PLAYER_CONFIG_VERSION: u32 = 1;
+
+/// One schema upgrade step, applied to files at version `from`.
+pub struct Migration {
+    pub from: u32,
+    pub description: &'static str,
+    pub apply: fn(&mut Map<String, Value>),
+}
+
+/// Upgrades for `config_sys.json`, ordered by `from`.
+pub const CONFIG_MIGRATIONS: &[Migration] = &[Migration {
+    from: 0,
+    description: "unversioned config (beatoraja / early brs)",
+    apply: |_| {},
+}];
+
+/// Upgrades for `config_player.json`, ordered by `from`.
+pub const PLAYER_CONFIG_MIGRATIONS: &[Migration] = &[Migration {
+    from: 0,
+    description: "unversioned player config (beatoraja / early brs)",
+    apply: |_| {},
+}];
+
+/// Schema version recorded in `value` (0 when absent).
+pub fn schema_version(value: &Value) -> u32 {
+    value
+        .get(VERSION_KEY)
+        .and_then(Value::as_u64)
+        .map_or(0, |v| v as u32)
+}
+
+/// Apply the migrations in `migrations` that bring `value` up to `current`.
+///
+/// Returns the version the document had before migration. Documents written
+/// by a newer version are left untouched.
+pub fn migrate(value: &mut Value, migrations: &[Migration], current: u32) -> Result<u32> {
+    let original = schema_version(value);
+    let map = value
+        .as_object_mut()
+        .context("config root is not a JSON object")?;
+    if original > current {
+        log::warn!(
+            "Config schema version {} is newer than supported version {}; unknown fields are ignored",
+            original,
+            current
+        );
+        return Ok(original);
+    }
+    let mut version = original;
+    while version < current {
+        let migration = migrations
+            .iter()
+            .find(|m| m.from == version)
+            .with_context(|| format!("no config migration from schema version {}", version))?;
+        log::info!(
+            "Migrating config schema {} -> {}: {}",
+            version,
+            version + 1,
+            migration.description
+        );
+        (migration.apply)(map);
+        version += 1;
+    }
+    map.insert(VERSION_KEY.to_string(), Value::from(version));
+    Ok(original)
+}
+
+/// Parse JSON text, migrate it and deserialize it as `T`.
+pub fn parse_migrated<T: serde::de::DeserializeOwned>(
+    data: &str,
+    migrations: &[Migration],
+    current: u32,
+) -> Result<T> {
+    let mut value: Value = serde_json::from_str(data)?;
+    migrate(&mut value, migrations, current)?;
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Move `old` to `new` unless `new` is already present. For use in migrations.
+pub fn rename_key(map: &mut Map<String, Value>, old: &str, new: &str) {
+    if let Some(value) = map.remove(old) {
+        map.entry(new.to_string()).or_insert(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration {
+            from: 0,
+            description: "rename bpm",
+            apply: |map| rename_key(map, "bpmGuide", "showBpmGuide"),
+        },
+        Migration {
+            from: 1,
+            description: "percent to ratio",
+            apply: |map| {
+                if let Some(v) = map.get("volume").and_then(Value::as_f64) {
+                    map.insert("volume".to_string(), json!(v / 100.0));
+                }
+            },
+        },
+    ];
+
+    #[test]
+    fn migrate_applies_steps_in_order_from_unversioned() {
+        let mut value = json!({"bpmGuide": true, "volume": 50.0});
+
+        let from = migrate(&mut value, TEST_MIGRATIONS, 2).unwrap();
+
+        assert_eq!(from, 0);
+        assert_eq!(
+            value,
+            json!({"showBpmGuide": true, "volume": 0.5, "configVersion": 2})
+        );
+    }
+
+    #[test]
+    fn migrate_skips_steps_already_applied() {
+        let mut value = json!({"configVersion": 1, "showBpmGuide": false, "volume": 80.0});
+
+        migrate(&mut value, TEST_MIGRATIONS, 2).unwrap();
+
+        assert_eq!(value["volume"], json!(0.8));
+        assert_eq!(schema_version(&value), 2);
+    }
+
+    #[test]
+    fn migrate_leaves_newer_files_alone() {
+        let mut value = json!({"configVersion": 9, "volume": 80.0});
+
+        assert_eq!(migrate(&mut value, TEST_MIGRATIONS, 2).unwrap(), 9);
+        assert_eq!(value, json!({"configVersion": 9, "volume": 80.0}));
+    }
+
+    #[test]
+    fn migrate_fails_on_missing_step() {
+        let mut value = json!({});
+        assert!(migrate(&mut value, &TEST_MIGRATIONS[1..], 2).is_err());
+        assert!(migrate(&mut json!([1]), TEST_MIGRATIONS, 2).is_err());
+    }
+
+    #[test]
+    fn shipped_migrations_cover_every_version() {
+        for (migrations, current) in [
+            (CONFIG_MIGRATIONS, CONFIG_VERSION),
+            (PLAYER_CONFIG_MIGRATIONS, PLAYER_CONFIG_VERSION),
+        ] {
+            let mut value = json!({});
+            migrate(&mut value, migrations, current).unwrap();
+            assert_eq!(schema_version(&value), current);
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Accessibility color overrides drawn on top of any skin.
+//!
+//! The palettes replace the hue of judge text, lane beams and gauge
+//! segments while the recolor shader keeps each texture's brightness, so
+//! skins stay recognizable. Colors come from the Okabe-Ito set, which keeps
+//! neighbouring judges distinguishable under the targeted color vision
+//! deficiency.
+
+use crate::skin::draw_command::NoteImageType;
+use crate::skin::player_config::{COLOR_PALETTE_BLUE_YELLOW, COLOR_PALETTE_RED_GREEN};
+use crate::skin::reexports::{Color, MainState};
+use crate::skin::skin_property::{
+    TIMER_KEYON_1P_KEY10, TIMER_KEYON_1P_KEY99, TIMER_KEYON_1P_SCRATCH, TIMER_KEYON_2P_KEY10,
+    TIMER_KEYON_2P_KEY99, TIMER_KEYON_2P_SCRATCH,
+};
+
+const ORANGE: Color = rgb(0.90, 0.62, 0.0);
+const SKY_BLUE: Color = rgb(0.34, 0.71, 0.91);
+const YELLOW: Color = rgb(0.94, 0.89, 0.26);
+const BLUE: Color = rgb(0.0, 0.45, 0.70);
+const VERMILLION: Color = rgb(0.84, 0.37, 0.0);
+const REDDISH_PURPLE: Color = rgb(0.80, 0.47, 0.65);
+const BLUISH_GREEN: Color = rgb(0.0, 0.62, 0.45);
+const CYAN: Color = rgb(0.30, 0.85, 0.95);
+const PINK: Color = rgb(1.0, 0.6, 0.75);
+const PURPLE: Color = rgb(0.7, 0.3, 0.7);
+const RED: Color = rgb(0.86, 0.15, 0.15);
+const CRIMSON: Color = rgb(0.86, 0.15, 0.30);
+const GREY: Color = rgb(0.6, 0.6, 0.6);
+const WHITE: Color = rgb(1.0, 1.0, 1.0);
+const OFF_WHITE: Color = rgb(0.94, 0.94, 0.94);
+
+const fn rgb(r: f32, g: f32, b: f32) -> Color {
+    Color { r, g, b, a: 1.0 }
+}
+
+/// Palette selected by `DisplaySettings::color_palette`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorPalette {
+    /// Keep the skin's colors.
+    Skin,
+    /// Protanopia and deuteranopia: blue/orange contrasts.
+    RedGreen,
+    /// Tritanopia: red/cyan contrasts.
+    BlueYellow,
+}
+
+impl ColorPalette {
+    pub fn from_config(value: i32) -> Self {
+        match value {
+            COLOR_PALETTE_RED_GREEN => ColorPalette::RedGreen,
+            COLOR_PALETTE_BLUE_YELLOW => ColorPalette::BlueYellow,
+            _ => ColorPalette::Skin,
+        }
+    }
+
+    /// The palette of the current player, or `Skin` outside a player context.
+    pub fn of(state: &dyn MainState) -> Self {
+        state.player_config_ref().map_or(ColorPalette::Skin, |c| {
+            Self::from_config(c.display_settings.color_palette)
+        })
+    }
+
+    /// Judge text color for judge index 0..7 (PG, GR, GD, BD, PR, MS, MAX PG).
+    pub fn judge_color(self, judge: usize) -> Option<Color> {
+        let colors = match self {
+            ColorPalette::Skin => return None,
+            ColorPalette::RedGreen => [
+                SKY_BLUE,
+                YELLOW,
+                BLUE,
+                REDDISH_PURPLE,
+                VERMILLION,
+                GREY,
+                WHITE,
+            ],
+            ColorPalette::BlueYellow => [CYAN, PINK, BLUISH_GREEN, PURPLE, RED, GREY, WHITE],
+        };
+        colors.get(judge).copied()
+    }
+
+    /// Lane beam color for an object driven by key-on timer `timer_id`.
+    /// Objects on any other timer keep the skin's colors.
+    pub fn key_beam_color(self, timer_id: i32) -> Option<Color> {
+        if self == ColorPalette::Skin {
+            return None;
+        }
+        let key = key_on_lane(timer_id)?;
+        Some(match (self, key) {
+            (ColorPalette::RedGreen, 0) => VERMILLION,
+            (ColorPalette::BlueYellow, 0) => RED,
+            (_, k) if k % 2 == 1 => OFF_WHITE,
+            (ColorPalette::RedGreen, _) => SKY_BLUE,
+            _ => CYAN,
+        })
+    }
+
+    /// Gauge segment color on either side of the clear border.
+    pub fn gauge_color(self, below_border: bool) -> Option<Color> {
+        match (self, below_border) {
+            (ColorPalette::Skin, _) => None,
+            (ColorPalette::RedGreen, false) => Some(ORANGE),
+            (ColorPalette::RedGreen, true) => Some(BLUE),
+            (ColorPalette::BlueYellow, false) => Some(CRIMSON),
+            (ColorPalette::BlueYellow, true) => Some(CYAN),
+        }
+    }
+}
+
+/// Whether the current player draws notes in high-contrast mode.
+pub fn high_contrast_notes(state: &dyn MainState) -> bool {
+    state
+        .player_config_ref()
+        .is_some_and(|c| c.display_settings.high_contrast_notes)
+}
+
+/// Flat color for a note head in high-contrast mode.
+pub fn high_contrast_note_color(image_type: &NoteImageType) -> Color {
+    match image_type {
+        NoteImageType::Normal => WHITE,
+        NoteImageType::Mine => VERMILLION,
+        NoteImageType::Processed | NoteImageType::Hidden => GREY,
+    }
+}
+
+/// Flat color for long note parts in high-contrast mode.
+pub const HIGH_CONTRAST_LONG_NOTE: Color = YELLOW;
+
+/// Key number (0 = scratch, 1.. = keys) for a key-on timer.
+fn key_on_lane(timer_id: i32) -> Option<i32> {
+    let scratch_1p = TIMER_KEYON_1P_SCRATCH.0;
+    let scratch_2p = TIMER_KEYON_2P_SCRATCH.0;
+    if (scratch_1p..scratch_1p + 10).contains(&timer_id) {
+        Some(timer_id - scratch_1p)
+    } else if (scratch_2p..scratch_2p + 10).contains(&timer_id) {
+        Some(timer_id - scratch_2p)
+    } else if (TIMER_KEYON_1P_KEY10.0..=TIMER_KEYON_1P_KEY99.0).contains(&timer_id) {
+        Some(timer_id - TIMER_KEYON_1P_KEY10.0 + 10)
+    } else if (TIMER_KEYON_2P_KEY10.0..=TIMER_KEYON_2P_KEY99.0).contains(&timer_id) {
+        Some(timer_id - TIMER_KEYON_2P_KEY10.0 + 10)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skin_palette_overrides_nothing() {
+        let p = ColorPalette::Skin;
+        assert!(p.judge_color(0).is_none());
+        assert!(p.key_beam_color(101).is_none());
+        assert!(p.gauge_color(true).is_none());
+    }
+
+    #[test]
+    fn key_on_timers_map_to_lanes() {
+        assert_eq!(key_on_lane(100), Some(0));
+        assert_eq!(key_on_lane(107), Some(7));
+        assert_eq!(key_on_lane(110), Some(0));
+        assert_eq!(key_on_lane(1410), Some(10));
+        assert_eq!(key_on_lane(1599), Some(99));
+        assert_eq!(key_on_lane(120), None);
+        assert_eq!(key_on_lane(41), None);
+    }
+
+    #[test]
+    fn beams_alternate_between_white_and_black_keys() {
+        let p = ColorPalette::RedGreen;
+        let beam = |timer| p.key_beam_color(timer).map(|c| c.to_array());
+        assert_eq!(beam(100), Some(VERMILLION.to_array()));
+        assert_eq!(beam(101), Some(OFF_WHITE.to_array()));
+        assert_eq!(beam(102), Some(SKY_BLUE.to_array()));
+        assert_eq!(beam(50), None);
+    }
+
+    #[test]
+    fn palettes_keep_judges_distinct() {
+        for p in [ColorPalette::RedGreen, ColorPalette::BlueYellow] {
+            let colors: Vec<[f32; 4]> = (0..7)
+                .filter_map(|i| p.judge_color(i))
+                .map(|c| c.to_array())
+                .collect();
+            assert_eq!(colors.len(), 7);
+            for (i, a) in colors.iter().enumerate() {
+                for b in &colors[i + 1..] {
+                    assert_ne!(a, b, "{:?} repeats a judge color", p);
+                }
+            }
+            assert!(p.judge_color(7).is_none());
+        }
+    }
+
+    #[test]
+    fn from_config_falls_back_to_skin() {
+        assert_eq!(ColorPalette::from_config(1), ColorPalette::RedGreen);
+        assert_eq!(ColorPalette::from_config(2), ColorPalette::BlueYellow);
+        assert_eq!(ColorPalette::from_config(7), ColorPalette::Skin);
+    }
+}
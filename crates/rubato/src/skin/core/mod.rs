@@ -1,3 +1,4 @@
+pub mod color_palette;
 pub mod custom_event;
 pub mod custom_timer;
 pub mod float_formatter;
@@ -54,6 +54,14 @@ pub enum DrawCommand {
         h: f32,
         color_index: usize,
     },
+    /// Draw a replay ghost's key beam (translucent rectangle over the lane)
+    DrawGhostBeam {
+        lane: usize,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+    },
 }
 
 /// Note image types for DrawNote command
@@ -1,4 +1,7 @@
-use crate::skin::groove_gauge::GaugeModifier;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::skin::groove_gauge::{ASSISTEASY, GaugeModifier, HAZARD};
 
 /// Gauge specification
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -31,8 +34,17 @@ impl GaugeProperty {
         }
     }
 
+    /// Gauge elements with the overrides of `table` applied, if any.
+    pub fn element_values_with(&self, table: Option<&GaugeTable>) -> Vec<GaugeElementProperty> {
+        let mut values = self.element_values();
+        if let Some(table) = table {
+            table.apply(&mut values);
+        }
+        values
+    }
+
     pub fn element_values(&self) -> Vec<GaugeElementProperty> {
-        match self {
+        let mut values = match self {
             GaugeProperty::FiveKeys => vec![
                 gep(GaugeElementParams {
                     modifier: Some(GaugeModifier::Total),
@@ -529,7 +541,15 @@ impl GaugeProperty {
                     guts: &[],
                 }),
             ],
+        };
+        // HCN ticks: ASSIST EASY drains gently, HAZARD treats a release like a BAD.
+        if let Some(assist) = values.get_mut(ASSISTEASY as usize) {
+            assist.hcn_drain = 0.25;
+        }
+        if let Some(hazard) = values.get_mut(HAZARD as usize) {
+            hazard.hcn_drain = 1.0;
         }
+        values
     }
 }
 
@@ -552,8 +572,124 @@ pub struct GaugeElementProperty {
     pub death: f32,
     /// Guts correction table
     pub guts: Vec<Vec<f32>>,
+    /// Rate of the GREAT value applied per HCN tick while the hell charge note is held
+    pub hcn_recovery: f32,
+    /// Rate of the BAD value applied per HCN tick while the hell charge note is released
+    pub hcn_drain: f32,
 }
 
+/// Gauge type names used as keys in `GaugeTable::gauges`, in `element_values()` order.
+pub const GAUGE_NAMES: [&str; 9] = [
+    "ASSISTEASY",
+    "EASY",
+    "NORMAL",
+    "HARD",
+    "EXHARD",
+    "HAZARD",
+    "CLASS",
+    "EXCLASS",
+    "EXHARDCLASS",
+];
+
+/// Gauge curves loaded from a JSON ruleset file. Each entry overrides the named
+/// gauge's fields; omitted gauges and fields keep the GaugeProperty defaults.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct GaugeTable {
+    pub name: String,
+    pub gauges: BTreeMap<String, GaugeElementOverride>,
+}
+
+/// Overrides for a single gauge type. See `GaugeElementProperty` for field meanings.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct GaugeElementOverride {
+    pub value: Option<Vec<f32>>,
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    pub init: Option<f32>,
+    pub border: Option<f32>,
+    pub death: Option<f32>,
+    /// `[threshold, damage rate]` rows, checked in order
+    pub guts: Option<Vec<[f32; 2]>>,
+    #[serde(rename = "hcnRecovery")]
+    pub hcn_recovery: Option<f32>,
+    #[serde(rename = "hcnDrain")]
+    pub hcn_drain: Option<f32>,
+}
+
+impl GaugeTable {
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        let table: GaugeTable = serde_json::from_str(&data)?;
+        table.check()?;
+        Ok(table)
+    }
+
+    fn check(&self) -> anyhow::Result<()> {
+        for (name, gauge) in &self.gauges {
+            if !GAUGE_NAMES.contains(&name.as_str()) {
+                anyhow::bail!("unknown gauge type: {name}");
+            }
+            if let Some(value) = &gauge.value
+                && value.len() != 6
+            {
+                anyhow::bail!("{name}: expected 6 judge values, found {}", value.len());
+            }
+            if let (Some(min), Some(max)) = (gauge.min, gauge.max)
+                && min > max
+            {
+                anyhow::bail!("{name}: min {min} exceeds max {max}");
+            }
+            if gauge.hcn_recovery.is_some_and(|r| r < 0.0)
+                || gauge.hcn_drain.is_some_and(|r| r < 0.0)
+            {
+                anyhow::bail!("{name}: HCN rates must not be negative");
+            }
+        }
+        Ok(())
+    }
+
+    pub fn apply(&self, values: &mut [GaugeElementProperty]) {
+        for (name, element) in GAUGE_NAMES.iter().zip(values.iter_mut()) {
+            let Some(gauge) = self.gauges.get(*name) else {
+                continue;
+            };
+            if let Some(value) = &gauge.value {
+                element.value = value.clone();
+            }
+            if let Some(min) = gauge.min {
+                element.min = min;
+            }
+            if let Some(max) = gauge.max {
+                element.max = max;
+            }
+            if let Some(init) = gauge.init {
+                element.init = init;
+            }
+            if let Some(border) = gauge.border {
+                element.border = border;
+            }
+            if let Some(death) = gauge.death {
+                element.death = death;
+            }
+            if let Some(guts) = &gauge.guts {
+                element.guts = guts.iter().map(|g| g.to_vec()).collect();
+            }
+            if let Some(rate) = gauge.hcn_recovery {
+                element.hcn_recovery = rate;
+            }
+            if let Some(rate) = gauge.hcn_drain {
+                element.hcn_drain = rate;
+            }
+            element.init = element.init.clamp(element.min, element.max);
+        }
+    }
+}
+
+/// beatoraja's HCN tick rate (half a GREAT / half a BAD every 200ms).
+pub const HCN_DEFAULT_RATE: f32 = 0.5;
+
 /// Parameters for constructing a `GaugeElementProperty` from borrowed slices.
 struct GaugeElementParams<'a> {
     pub modifier: Option<GaugeModifier>,
@@ -576,6 +712,8 @@ fn gep(params: GaugeElementParams<'_>) -> GaugeElementProperty {
         death: params.death,
         value: params.value.to_vec(),
         guts: params.guts.iter().map(|g| g.to_vec()).collect(),
+        hcn_recovery: HCN_DEFAULT_RATE,
+        hcn_drain: HCN_DEFAULT_RATE,
     }
 }
 
@@ -720,4 +858,93 @@ mod tests {
         assert_eq!(sk[0].border, 60.0);
         assert_eq!(pms[0].border, 65.0);
     }
+
+    #[test]
+    fn test_gauge_table_overrides_named_gauges_only() {
+        let table: GaugeTable = serde_json::from_str(
+            r#"{"name":"community","gauges":{"HARD":{"value":[0.2,0.2,0.1,-4.0,-8.0,-4.0],"guts":[[50.0,0.5]]},"EASY":{"border":70.0}}}"#,
+        )
+        .unwrap();
+        table.check().unwrap();
+        let base = GaugeProperty::SevenKeys.element_values();
+        let values = GaugeProperty::SevenKeys.element_values_with(Some(&table));
+        assert_eq!(values[3].value, vec![0.2, 0.2, 0.1, -4.0, -8.0, -4.0]);
+        assert_eq!(values[3].guts, vec![vec![50.0, 0.5]]);
+        assert_eq!(values[3].border, base[3].border);
+        assert_eq!(values[1].border, 70.0);
+        assert_eq!(values[1].value, base[1].value);
+        assert_eq!(values[2].value, base[2].value);
+    }
+
+    #[test]
+    fn test_gauge_table_none_keeps_defaults() {
+        for gp in GaugeProperty::values() {
+            let base = gp.element_values();
+            let values = gp.element_values_with(None);
+            for (a, b) in base.iter().zip(values.iter()) {
+                assert_eq!(a.value, b.value);
+                assert_eq!(a.guts, b.guts);
+                assert_eq!(
+                    (a.min, a.max, a.init, a.border, a.death),
+                    (b.min, b.max, b.init, b.border, b.death)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_gauge_table_rejects_invalid_entries() {
+        let mut table = GaugeTable::default();
+        table
+            .gauges
+            .insert("SUPERHARD".to_string(), GaugeElementOverride::default());
+        assert!(table.check().is_err());
+
+        let mut table = GaugeTable::default();
+        table.gauges.insert(
+            "NORMAL".to_string(),
+            GaugeElementOverride {
+                value: Some(vec![1.0, 1.0]),
+                ..Default::default()
+            },
+        );
+        assert!(table.check().is_err());
+
+        let mut table = GaugeTable::default();
+        table.gauges.insert(
+            "NORMAL".to_string(),
+            GaugeElementOverride {
+                min: Some(80.0),
+                max: Some(20.0),
+                ..Default::default()
+            },
+        );
+        assert!(table.check().is_err());
+    }
+
+    #[test]
+    fn test_gauge_table_overrides_hcn_rates() {
+        let table: GaugeTable =
+            serde_json::from_str(r#"{"gauges":{"NORMAL":{"hcnRecovery":1.0,"hcnDrain":0.0}}}"#)
+                .unwrap();
+        table.check().unwrap();
+        let values = GaugeProperty::SevenKeys.element_values_with(Some(&table));
+        assert_eq!(values[2].hcn_recovery, 1.0);
+        assert_eq!(values[2].hcn_drain, 0.0);
+        assert_eq!(values[3].hcn_drain, HCN_DEFAULT_RATE);
+
+        let negative: GaugeTable =
+            serde_json::from_str(r#"{"gauges":{"HARD":{"hcnDrain":-1.0}}}"#).unwrap();
+        assert!(negative.check().is_err());
+    }
+
+    #[test]
+    fn test_gauge_table_read_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("gauge.json");
+        std::fs::write(&path, r#"{"gauges":{"EXHARD":{"init":50.0}}}"#).unwrap();
+        let table = GaugeTable::read(&path).unwrap();
+        assert_eq!(table.gauges["EXHARD"].init, Some(50.0));
+        assert!(GaugeTable::read(&dir.path().join("missing.json")).is_err());
+    }
 }
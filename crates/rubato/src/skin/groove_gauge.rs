@@ -152,6 +152,15 @@ impl Gauge {
         self.set_value(new_value);
     }
 
+    /// Apply one HCN tick: GREAT at `hcn_recovery` while held, BAD at `hcn_drain` while released.
+    pub fn update_hcn(&mut self, held: bool) {
+        if held {
+            self.update(1, self.element.hcn_recovery);
+        } else {
+            self.update(3, self.element.hcn_drain);
+        }
+    }
+
     pub fn property(&self) -> &GaugeElementProperty {
         &self.element
     }
@@ -165,12 +174,31 @@ impl Gauge {
     }
 }
 
+/// One change of the active gauge, recorded for result graphs and replays.
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GaugeTransition {
+    /// Music time in microseconds
+    pub time: i64,
+    /// Judge that caused the change (0=PG .. 5=MS), -1 for non-judge changes
+    pub judge: i32,
+    /// Change of the active gauge value
+    pub delta: f32,
+    /// Active gauge type when the change happened
+    #[serde(rename = "gaugeType")]
+    pub gauge_type: i32,
+    /// Active gauge value after the change
+    pub value: f32,
+}
+
 /// Groove gauge
 #[derive(Clone)]
 pub struct GrooveGauge {
     typeorg: i32,
     gauge_type: i32,
     gauges: Vec<Gauge>,
+    /// Music time stamped on recorded transitions
+    time: i64,
+    history: Vec<GaugeTransition>,
 }
 
 impl GrooveGauge {
@@ -185,7 +213,15 @@ impl GrooveGauge {
     pub const GRADE_EXHARD: i32 = EXHARDCLASS;
 
     pub fn new(model: &BMSModel, gauge_type: i32, property: &GaugeProperty) -> Self {
-        let values = property.element_values();
+        Self::with_elements(model, gauge_type, property.element_values())
+    }
+
+    /// Build a gauge from explicit elements (e.g. `GaugeProperty::element_values_with`).
+    pub fn with_elements(
+        model: &BMSModel,
+        gauge_type: i32,
+        values: Vec<GaugeElementProperty>,
+    ) -> Self {
         let mut gauges = Vec::with_capacity(values.len());
         for (i, element) in values.into_iter().enumerate() {
             gauges.push(Gauge::new(
@@ -202,6 +238,8 @@ impl GrooveGauge {
             typeorg: gauge_type,
             gauge_type,
             gauges,
+            time: 0,
+            history: Vec::new(),
         }
     }
 
@@ -210,16 +248,53 @@ impl GrooveGauge {
     }
 
     pub fn update_with_rate(&mut self, judge: i32, rate: f32) {
+        let before = self.value();
         for gauge in &mut self.gauges {
             gauge.update(judge, rate);
         }
+        self.record(judge, before);
+    }
+
+    /// Apply one HCN tick to every gauge, each with its own recovery/drain rate.
+    /// Recorded as GREAT while held and BAD while released.
+    pub fn update_hcn(&mut self, held: bool) {
+        let before = self.value();
+        for gauge in &mut self.gauges {
+            gauge.update_hcn(held);
+        }
+        self.record(if held { 1 } else { 3 }, before);
     }
 
     pub fn add_value(&mut self, value: f32) {
+        let before = self.value();
         for gauge in &mut self.gauges {
             let new_val = gauge.value() + value;
             gauge.set_value(new_val);
         }
+        self.record(-1, before);
+    }
+
+    /// Set the music time (microseconds) stamped on subsequent transitions.
+    pub fn set_time(&mut self, time: i64) {
+        self.time = time;
+    }
+
+    /// Every change of the active gauge since creation, in order.
+    pub fn history(&self) -> &[GaugeTransition] {
+        &self.history
+    }
+
+    fn record(&mut self, judge: i32, before: f32) {
+        let value = self.value();
+        if value != before {
+            self.history.push(GaugeTransition {
+                time: self.time,
+                judge,
+                delta: value - before,
+                gauge_type: self.gauge_type,
+                value,
+            });
+        }
     }
 
     fn gauge_at(&self, gauge_type: i32) -> Option<&Gauge> {
@@ -414,6 +489,8 @@ mod tests {
             border: 0.0,
             death: 0.0,
             guts: vec![],
+            hcn_recovery: 0.5,
+            hcn_drain: 0.5,
         };
         let gauge = Gauge::new(&model, element, ClearType::Hard);
         assert_eq!(gauge.value(), 100.0);
@@ -431,6 +508,8 @@ mod tests {
             border: 0.0,
             death: 0.0,
             guts: vec![],
+            hcn_recovery: 0.5,
+            hcn_drain: 0.5,
         };
         let mut gauge = Gauge::new(&model, element, ClearType::Hard);
         assert_eq!(gauge.value(), 50.0);
@@ -456,6 +535,8 @@ mod tests {
             border: 0.0,
             death: 2.0,
             guts: vec![],
+            hcn_recovery: 0.5,
+            hcn_drain: 0.5,
         };
         let mut gauge = Gauge::new(&model, element, ClearType::Hard);
         // Setting below death border kills the gauge
@@ -475,6 +556,8 @@ mod tests {
             border: 80.0,
             death: 0.0,
             guts: vec![],
+            hcn_recovery: 0.5,
+            hcn_drain: 0.5,
         };
         let mut gauge = Gauge::new(&model, element, ClearType::Normal);
         // 20 < 80, not qualified
@@ -499,6 +582,8 @@ mod tests {
             border: 80.0,
             death: 0.0,
             guts: vec![],
+            hcn_recovery: 0.5,
+            hcn_drain: 0.5,
         };
         let gauge = Gauge::new(&model, element, ClearType::Normal);
         assert!(gauge.is_max());
@@ -516,6 +601,8 @@ mod tests {
             border: 0.0,
             death: 0.0,
             guts: vec![],
+            hcn_recovery: 0.5,
+            hcn_drain: 0.5,
         };
         let mut gauge = Gauge::new(&model, element, ClearType::Hard);
         assert_eq!(gauge.value(), 50.0);
@@ -768,7 +855,9 @@ mod tests {
             init: 10.0,
             border: 0.0,
             death: 0.0,
-            guts: vec![vec![30.0, 0.5]], // below 30%, damage is halved
+            guts: vec![vec![30.0, 0.5]], // below 30%, damage is halved,
+            hcn_recovery: 0.5,
+            hcn_drain: 0.5,
         };
         let mut gauge = Gauge::new(&model, element, ClearType::Hard);
         assert_eq!(gauge.value(), 10.0);
@@ -798,6 +887,8 @@ mod tests {
             border: 0.0,
             death: 0.0,
             guts: vec![],
+            hcn_recovery: 0.5,
+            hcn_drain: 0.5,
         };
         let mut gauge = Gauge::new(&model, element, ClearType::Hard);
 
@@ -819,6 +910,8 @@ mod tests {
             border: 0.0,
             death: 0.0,
             guts: vec![],
+            hcn_recovery: 0.5,
+            hcn_drain: 0.5,
         };
         let mut gauge = Gauge::new(&model, element, ClearType::Hard);
         gauge.update(-1, 1.0);
@@ -837,6 +930,8 @@ mod tests {
             border: 0.0,
             death: 0.0,
             guts: vec![],
+            hcn_recovery: 0.5,
+            hcn_drain: 0.5,
         };
         let mut gauge = Gauge::new(&model, element, ClearType::Hard);
         gauge.update(99, 1.0);
@@ -902,6 +997,79 @@ mod tests {
         assert!(gg.is_qualified());
     }
 
+    #[test]
+    fn test_groove_gauge_hcn_default_rates_match_beatoraja() {
+        let model = make_model();
+        let mut held = GrooveGauge::new(&model, NORMAL, &GaugeProperty::SevenKeys);
+        let mut reference = held.clone();
+        held.update_hcn(true);
+        reference.update_with_rate(1, 0.5);
+        assert_eq!(held.value_by_type(NORMAL), reference.value_by_type(NORMAL));
+        assert_eq!(held.value_by_type(HARD), reference.value_by_type(HARD));
+
+        held.update_hcn(false);
+        reference.update_with_rate(3, 0.5);
+        assert_eq!(held.value_by_type(NORMAL), reference.value_by_type(NORMAL));
+        assert_eq!(held.value_by_type(EXHARD), reference.value_by_type(EXHARD));
+    }
+
+    #[test]
+    fn test_groove_gauge_records_transitions() {
+        let model = make_model_with_notes(100);
+        let mut gg = GrooveGauge::new(&model, NORMAL, &GaugeProperty::SevenKeys);
+        gg.set_time(1_000_000);
+        gg.update(0);
+        gg.set_time(2_000_000);
+        gg.update(4);
+        gg.add_value(-1.0);
+
+        let history = gg.history();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].time, 1_000_000);
+        assert_eq!(history[0].judge, 0);
+        assert!(history[0].delta > 0.0);
+        assert_eq!(history[1].time, 2_000_000);
+        assert_eq!(history[1].judge, 4);
+        assert!(history[1].delta < 0.0);
+        assert_eq!(history[2].judge, -1);
+        assert_eq!(history[2].value, gg.value());
+        assert!(history.iter().all(|t| t.gauge_type == NORMAL));
+    }
+
+    #[test]
+    fn test_groove_gauge_skips_unchanged_transitions() {
+        let model = make_model_with_notes(100);
+        let mut gg = GrooveGauge::new(&model, NORMAL, &GaugeProperty::SevenKeys);
+        gg.set_value(100.0);
+        gg.update(0);
+        assert!(gg.history().is_empty(), "full gauge cannot rise further");
+        gg.set_type(HARD);
+        gg.update(4);
+        assert_eq!(gg.history().len(), 1);
+        assert_eq!(gg.history()[0].gauge_type, HARD);
+    }
+
+    #[test]
+    fn test_groove_gauge_hcn_release_kills_hazard() {
+        let model = make_model();
+        let mut gg = GrooveGauge::new(&model, HAZARD, &GaugeProperty::SevenKeys);
+        assert_eq!(gg.value(), 100.0);
+        gg.update_hcn(false);
+        assert_eq!(gg.value(), 0.0);
+    }
+
+    #[test]
+    fn test_groove_gauge_hcn_release_lenient_on_assist_easy() {
+        let model = make_model();
+        let mut gg = GrooveGauge::new(&model, ASSISTEASY, &GaugeProperty::SevenKeys);
+        gg.set_value(50.0);
+        gg.update_hcn(false);
+        let assist_loss = 50.0 - gg.value_by_type(ASSISTEASY);
+        let easy_loss = 50.0 - gg.value_by_type(EASY);
+        assert!(assist_loss > 0.0);
+        assert!(assist_loss < easy_loss);
+    }
+
     #[test]
     fn test_gauge_dead_is_irrecoverable() {
         let model = make_model();
@@ -914,6 +1082,8 @@ mod tests {
             border: 0.0,
             death: 2.0,
             guts: vec![],
+            hcn_recovery: 0.5,
+            hcn_drain: 0.5,
         };
         let mut gauge = Gauge::new(&model, element, ClearType::Hard);
         assert_eq!(gauge.value(), 50.0);
@@ -1002,6 +1172,8 @@ mod prop_tests {
                 border: 80.0,
                 death: 2.0,
                 guts: vec![],
+                hcn_recovery: 0.5,
+                hcn_drain: 0.5,
             };
             let mut gauge = Gauge::new(&model, element, ClearType::Hard);
             // Gauge starts at init=50.0 which is > 0, so set_value will execute
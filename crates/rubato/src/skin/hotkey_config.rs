@@ -0,0 +1,276 @@
+use crate::skin::play_mode_config::gdx_keys;
+
+/// Function hotkeys available during play, bound in the KeyConfig screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Hotkey {
+    /// Restart the chart with the same options.
+    QuickRetry,
+    /// Stop play and leave the chart.
+    Close,
+    /// Show or hide the BGA.
+    ToggleBga,
+    /// Step the BGA dim level, wrapping back to no dim.
+    BgaDim,
+    /// Raise hi-speed by one step (PlayConfig.hispeedmargin).
+    HispeedUp,
+    /// Lower hi-speed by one step (PlayConfig.hispeedmargin).
+    HispeedDown,
+    /// Step to the next hi-speed fixation mode (PlayConfig.fixhispeed).
+    HispeedFix,
+    /// Practice: move the range start to the current position.
+    PracticeStartMarker,
+    /// Practice: move the range end to the current position.
+    PracticeEndMarker,
+    /// Practice: restart the range automatically when it ends.
+    PracticeLoop,
+    /// Practice: keep the gauge at its start value.
+    PracticeFreezeGauge,
+    /// Practice: raise the playback rate of the next run.
+    PracticeRateUp,
+    /// Practice: lower the playback rate of the next run.
+    PracticeRateDown,
+    /// Raise the timing offset of this chart by one step, kept for future plays.
+    ChartOffsetUp,
+    /// Lower the timing offset of this chart by one step, kept for future plays.
+    ChartOffsetDown,
+}
+
+impl Hotkey {
+    pub const ALL: [Hotkey; 15] = [
+        Hotkey::QuickRetry,
+        Hotkey::Close,
+        Hotkey::ToggleBga,
+        Hotkey::BgaDim,
+        Hotkey::HispeedUp,
+        Hotkey::HispeedDown,
+        Hotkey::HispeedFix,
+        Hotkey::PracticeStartMarker,
+        Hotkey::PracticeEndMarker,
+        Hotkey::PracticeLoop,
+        Hotkey::PracticeFreezeGauge,
+        Hotkey::PracticeRateUp,
+        Hotkey::PracticeRateDown,
+        Hotkey::ChartOffsetUp,
+        Hotkey::ChartOffsetDown,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Hotkey::QuickRetry => "QUICK RETRY",
+            Hotkey::Close => "CLOSE",
+            Hotkey::ToggleBga => "BGA ON/OFF",
+            Hotkey::BgaDim => "BGA DIM",
+            Hotkey::HispeedUp => "HI-SPEED UP",
+            Hotkey::HispeedDown => "HI-SPEED DOWN",
+            Hotkey::HispeedFix => "HI-SPEED FIX",
+            Hotkey::PracticeStartMarker => "PRACTICE START",
+            Hotkey::PracticeEndMarker => "PRACTICE END",
+            Hotkey::PracticeLoop => "PRACTICE LOOP",
+            Hotkey::PracticeFreezeGauge => "PRACTICE GAUGE FREEZE",
+            Hotkey::PracticeRateUp => "PRACTICE RATE UP",
+            Hotkey::PracticeRateDown => "PRACTICE RATE DOWN",
+            Hotkey::ChartOffsetUp => "CHART OFFSET +",
+            Hotkey::ChartOffsetDown => "CHART OFFSET -",
+        }
+    }
+}
+
+/// Keyboard keycodes (libGDX) bound to each hotkey, -1 when unbound.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct HotkeyConfig {
+    #[serde(rename = "quickRetry")]
+    pub quick_retry: i32,
+    pub close: i32,
+    #[serde(rename = "toggleBga")]
+    pub toggle_bga: i32,
+    #[serde(rename = "bgaDim")]
+    pub bga_dim: i32,
+    #[serde(rename = "hispeedUp")]
+    pub hispeed_up: i32,
+    #[serde(rename = "hispeedDown")]
+    pub hispeed_down: i32,
+    #[serde(rename = "hispeedFix")]
+    pub hispeed_fix: i32,
+    #[serde(rename = "practiceStartMarker")]
+    pub practice_start_marker: i32,
+    #[serde(rename = "practiceEndMarker")]
+    pub practice_end_marker: i32,
+    #[serde(rename = "practiceLoop")]
+    pub practice_loop: i32,
+    #[serde(rename = "practiceFreezeGauge")]
+    pub practice_freeze_gauge: i32,
+    #[serde(rename = "practiceRateUp")]
+    pub practice_rate_up: i32,
+    #[serde(rename = "practiceRateDown")]
+    pub practice_rate_down: i32,
+    #[serde(rename = "chartOffsetUp")]
+    pub chart_offset_up: i32,
+    #[serde(rename = "chartOffsetDown")]
+    pub chart_offset_down: i32,
+}
+
+impl Default for HotkeyConfig {
+    fn default() -> Self {
+        Self {
+            quick_retry: -1,
+            close: gdx_keys::ESCAPE,
+            toggle_bga: -1,
+            bga_dim: -1,
+            hispeed_up: -1,
+            hispeed_down: -1,
+            hispeed_fix: -1,
+            practice_start_marker: -1,
+            practice_end_marker: -1,
+            practice_loop: -1,
+            practice_freeze_gauge: -1,
+            practice_rate_up: -1,
+            practice_rate_down: -1,
+            chart_offset_up: -1,
+            chart_offset_down: -1,
+        }
+    }
+}
+
+impl HotkeyConfig {
+    pub fn key(&self, hotkey: Hotkey) -> i32 {
+        match hotkey {
+            Hotkey::QuickRetry => self.quick_retry,
+            Hotkey::Close => self.close,
+            Hotkey::ToggleBga => self.toggle_bga,
+            Hotkey::BgaDim => self.bga_dim,
+            Hotkey::HispeedUp => self.hispeed_up,
+            Hotkey::HispeedDown => self.hispeed_down,
+            Hotkey::HispeedFix => self.hispeed_fix,
+            Hotkey::PracticeStartMarker => self.practice_start_marker,
+            Hotkey::PracticeEndMarker => self.practice_end_marker,
+            Hotkey::PracticeLoop => self.practice_loop,
+            Hotkey::PracticeFreezeGauge => self.practice_freeze_gauge,
+            Hotkey::PracticeRateUp => self.practice_rate_up,
+            Hotkey::PracticeRateDown => self.practice_rate_down,
+            Hotkey::ChartOffsetUp => self.chart_offset_up,
+            Hotkey::ChartOffsetDown => self.chart_offset_down,
+        }
+    }
+
+    pub fn key_mut(&mut self, hotkey: Hotkey) -> &mut i32 {
+        match hotkey {
+            Hotkey::QuickRetry => &mut self.quick_retry,
+            Hotkey::Close => &mut self.close,
+            Hotkey::ToggleBga => &mut self.toggle_bga,
+            Hotkey::BgaDim => &mut self.bga_dim,
+            Hotkey::HispeedUp => &mut self.hispeed_up,
+            Hotkey::HispeedDown => &mut self.hispeed_down,
+            Hotkey::HispeedFix => &mut self.hispeed_fix,
+            Hotkey::PracticeStartMarker => &mut self.practice_start_marker,
+            Hotkey::PracticeEndMarker => &mut self.practice_end_marker,
+            Hotkey::PracticeLoop => &mut self.practice_loop,
+            Hotkey::PracticeFreezeGauge => &mut self.practice_freeze_gauge,
+            Hotkey::PracticeRateUp => &mut self.practice_rate_up,
+            Hotkey::PracticeRateDown => &mut self.practice_rate_down,
+            Hotkey::ChartOffsetUp => &mut self.chart_offset_up,
+            Hotkey::ChartOffsetDown => &mut self.chart_offset_down,
+        }
+    }
+
+    /// Bind `hotkey` to `keycode`, unbinding any other hotkey that used it.
+    pub fn set_key(&mut self, hotkey: Hotkey, keycode: i32) {
+        if keycode >= 0 {
+            for other in Hotkey::ALL {
+                if other != hotkey && self.key(other) == keycode {
+                    *self.key_mut(other) = -1;
+                }
+            }
+        }
+        *self.key_mut(hotkey) = keycode;
+    }
+
+    /// Unbind keycodes outside the keyboard range.
+    pub fn validate(&mut self) {
+        for hotkey in Hotkey::ALL {
+            let key = self.key_mut(hotkey);
+            if !(0..256).contains(key) {
+                *key = -1;
+            }
+        }
+    }
+}
+
+/// Buttons held together to open or close the mod menu, for setups without a
+/// keyboard. Keys are 1P lane indices (0 = key 1). Unbound when nothing is set.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ModMenuCombo {
+    pub start: bool,
+    pub select: bool,
+    pub keys: Vec<i32>,
+}
+
+impl ModMenuCombo {
+    /// Lane keys a combination can use: keys 1-7.
+    pub const KEY_COUNT: i32 = 7;
+
+    pub fn is_bound(&self) -> bool {
+        self.start || self.select || !self.keys.is_empty()
+    }
+
+    /// Drop keys outside keys 1-7 and duplicates.
+    pub fn validate(&mut self) {
+        self.keys.retain(|key| (0..Self::KEY_COUNT).contains(key));
+        self.keys.sort_unstable();
+        self.keys.dedup();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_key_moves_binding_from_other_hotkey() {
+        let mut config = HotkeyConfig::default();
+        config.set_key(Hotkey::ToggleBga, gdx_keys::ESCAPE);
+        assert_eq!(config.toggle_bga, gdx_keys::ESCAPE);
+        assert_eq!(config.close, -1);
+
+        config.set_key(Hotkey::ToggleBga, -1);
+        assert_eq!(config.toggle_bga, -1);
+    }
+
+    #[test]
+    fn test_validate_unbinds_out_of_range_keys() {
+        let mut config = HotkeyConfig {
+            quick_retry: 300,
+            hispeed_up: -5,
+            ..Default::default()
+        };
+        config.validate();
+        assert_eq!(config.quick_retry, -1);
+        assert_eq!(config.hispeed_up, -1);
+        assert_eq!(config.close, gdx_keys::ESCAPE);
+    }
+
+    #[test]
+    fn test_modmenu_combo_validate_drops_invalid_keys() {
+        let mut combo = ModMenuCombo {
+            start: true,
+            select: false,
+            keys: vec![4, -1, 2, 7, 4],
+        };
+        combo.validate();
+        assert_eq!(combo.keys, vec![2, 4]);
+        assert!(combo.is_bound());
+        assert!(!ModMenuCombo::default().is_bound());
+    }
+
+    #[test]
+    fn test_hotkey_index_matches_all_order() {
+        for (i, hotkey) in Hotkey::ALL.iter().enumerate() {
+            assert_eq!(hotkey.index(), i);
+        }
+    }
+}
@@ -34,6 +34,7 @@ pub enum ControlKeys {
     Del,
     Escape,
     KeyC,
+    KeyR,
 }
 
 /// KeyCommand enum — high-level keyboard commands.
@@ -55,6 +56,13 @@ pub enum KeyCommand {
     OpenSkinConfiguration,
     ToggleModMenu,
     CopyHighlightedMenuText,
+    ShareResult,
+    SkipStreamRequest,
+    RandomSelect,
+    RandomSelectUnplayed,
+    RandomSelectFailed,
+    UpdateLibrary,
+    OpenStatistics,
 }
 
 /// Trait interface for input processor access.
@@ -138,6 +146,7 @@ mod tests {
             ControlKeys::Del,
             ControlKeys::Escape,
             ControlKeys::KeyC,
+            ControlKeys::KeyR,
         ];
         // Verify all are unique (no duplicates)
         for i in 0..keys.len() {
@@ -168,6 +177,13 @@ mod tests {
             KeyCommand::OpenSkinConfiguration,
             KeyCommand::ToggleModMenu,
             KeyCommand::CopyHighlightedMenuText,
+            KeyCommand::ShareResult,
+            KeyCommand::SkipStreamRequest,
+            KeyCommand::RandomSelect,
+            KeyCommand::RandomSelectUnplayed,
+            KeyCommand::RandomSelectFailed,
+            KeyCommand::UpdateLibrary,
+            KeyCommand::OpenStatistics,
         ];
         for i in 0..cmds.len() {
             for j in (i + 1)..cmds.len() {
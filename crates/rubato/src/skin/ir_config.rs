@@ -22,6 +22,8 @@ pub struct IRConfig {
     pub irsend: i32,
     pub importscore: bool,
     pub importrival: bool,
+    /// Log in to this IR at startup. Disabled entries keep their settings.
+    pub enabled: bool,
 }
 
 impl Default for IRConfig {
@@ -35,6 +37,7 @@ impl Default for IRConfig {
             irsend: 0,
             importscore: false,
             importrival: true,
+            enabled: true,
         }
     }
 }
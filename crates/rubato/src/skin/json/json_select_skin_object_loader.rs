@@ -161,6 +161,13 @@ fn resolve_songlist_bar_data(
         trophy: resolve_dests(loader, &songlist.trophy),
         label: resolve_dests(loader, &songlist.label),
         graph,
+        rank: resolve_dests(loader, &songlist.rank),
+        score: resolve_dests(loader, songlist.score.as_slice())
+            .pop()
+            .flatten(),
+        misscount: resolve_dests(loader, songlist.misscount.as_slice())
+            .pop()
+            .flatten(),
     }
 }
 
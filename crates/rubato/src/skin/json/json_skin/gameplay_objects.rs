@@ -177,6 +177,12 @@ pub struct SongList {
     pub trophy: Vec<Destination>,
     pub label: Vec<Destination>,
     pub graph: Option<Destination>,
+    /// DJ rank images of the best score (0=AAA .. 7=F)
+    pub rank: Vec<Destination>,
+    /// EX score number of the best score
+    pub score: Option<Destination>,
+    /// Miss count number of the best score
+    pub misscount: Option<Destination>,
 }
 
 /// Corresponds to JsonSkin.PMchara
@@ -697,6 +697,9 @@ pub struct SongListBarData {
     pub label: Vec<Option<SkinObjectData>>,
     /// Distribution graph sub-object from songlist.graph
     pub graph: Option<SkinObjectData>,
+    pub rank: Vec<Option<SkinObjectData>>,
+    pub score: Option<SkinObjectData>,
+    pub misscount: Option<SkinObjectData>,
 }
 
 /// Offset data for SkinNumber/SkinFloat per-digit offsets
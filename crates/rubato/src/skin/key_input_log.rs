@@ -5,6 +5,8 @@
 /// Rust equivalent of beatoraja.input.KeyInputLog (pub fields; beatoraja-input uses private fields)
 #[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub struct KeyInputLog {
+    /// Java field name; `time` is accepted for logs written by earlier versions.
+    #[serde(rename = "presstime", alias = "time")]
     pub time: i64,
     pub keycode: i32,
     pub pressed: bool,
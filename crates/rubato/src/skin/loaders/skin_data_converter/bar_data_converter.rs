@@ -113,6 +113,37 @@ pub(super) fn build_select_bar_data(
             scale_y,
             filemap,
         ),
+        barrank: convert_bar_sub_images(
+            &bar_data.rank,
+            source_map,
+            skin_path,
+            usecim,
+            scale_x,
+            scale_y,
+            filemap,
+        ),
+        barscore: convert_bar_sub_numbers(
+            std::slice::from_ref(&bar_data.score),
+            source_map,
+            skin_path,
+            usecim,
+            scale_x,
+            scale_y,
+            filemap,
+        )
+        .pop()
+        .flatten(),
+        barmisscount: convert_bar_sub_numbers(
+            std::slice::from_ref(&bar_data.misscount),
+            source_map,
+            skin_path,
+            usecim,
+            scale_x,
+            scale_y,
+            filemap,
+        )
+        .pop()
+        .flatten(),
         graph_type: resolve_graph_type(bar_data.graph.as_ref()),
         graph_images: resolve_graph_images(
             bar_data.graph.as_ref(),
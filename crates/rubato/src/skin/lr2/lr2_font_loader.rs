@@ -137,7 +137,8 @@ impl LR2FontLoader {
                     && let Some(ref p) = self.path
                     && let Some(parent) = p.parent()
                 {
-                    let imagefile = parent.join(&str_parts[2]);
+                    // LR2 fonts are written on Windows
+                    let imagefile = parent.join(str_parts[2].trim().replace('\\', "/"));
                     if imagefile.exists()
                         && let Ok(index) = str_parts[1].trim().parse::<i32>()
                     {
@@ -964,6 +964,9 @@ impl LR2SkinLoaderAccess for LR2SelectSkinLoaderState {
                 barrivallamp: std::mem::take(&mut self.barrivallamp),
                 bartrophy: std::mem::take(&mut self.bartrophy),
                 barlabel: std::mem::take(&mut self.barlabel),
+                barrank: Vec::new(),
+                barscore: None,
+                barmisscount: None,
                 graph_type: self.bargraph_type.take(),
                 graph_images: self.bargraph_images.take(),
                 graph_region: std::mem::take(&mut self.bargraph_region),
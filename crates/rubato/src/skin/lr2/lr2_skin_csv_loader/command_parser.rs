@@ -270,6 +270,7 @@ impl LR2SkinCSVLoaderState {
                                     entry.h,
                                 );
                             }
+                            source.load_textures();
                             self.fontlist.push(Some(source));
                         }
                         Err(e) => {
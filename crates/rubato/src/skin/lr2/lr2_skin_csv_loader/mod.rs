@@ -486,6 +486,34 @@ SCENETIME,9999\n\
         assert!(state.fontlist[0].is_some());
     }
 
+    #[test]
+    fn test_lr2font_glyphs_come_from_font_texture() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("font")).unwrap();
+        image::RgbaImage::new(64, 32)
+            .save(dir.path().join("font/glyphs.png"))
+            .unwrap();
+        let font_path = dir.path().join("title.lr2font");
+        std::fs::write(
+            &font_path,
+            "#S,32\n#M,1\n#T,0,font\\glyphs.png\n#R,65,0,0,0,20,32\n",
+        )
+        .unwrap();
+
+        let mut state = make_state();
+        state.skinpath = dir.path().to_str().unwrap().to_string();
+        state.process_csv_command(
+            "LR2FONT",
+            &str_vec(&["#LR2FONT", font_path.to_str().unwrap()]),
+            None,
+        );
+        let source = state.fontlist[0].as_mut().expect("font loaded");
+        assert_eq!(source.size(), 32);
+        assert_eq!(source.margin(), 1);
+        let glyph = source.get_image('A' as i32).expect("glyph for A");
+        assert_eq!(glyph.region_width, 20);
+    }
+
     // --- parse_int tests ---
 
     #[test]
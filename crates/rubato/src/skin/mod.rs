@@ -24,6 +24,8 @@ pub mod sound_type;
 // Config types
 pub mod audio_config;
 pub mod config;
+pub mod config_migration;
+pub mod hotkey_config;
 pub mod ir_config;
 pub mod play_config;
 pub mod play_mode_config;
@@ -40,6 +42,7 @@ pub mod score_data;
 pub mod score_data_property;
 pub mod song_data;
 pub mod song_information;
+pub mod song_user_metadata;
 
 // Skin contract types
 pub mod distribution_data;
@@ -50,6 +53,7 @@ pub mod skin_main_state;
 pub mod skin_offset;
 pub mod skin_render_context;
 pub mod skin_widget_focus;
+pub mod skin_widget_object;
 pub mod timer_access;
 
 // Play-side shared types
@@ -158,6 +162,7 @@ pub(crate) mod test_helpers;
 // Backwards-compatible re-exports for moved modules
 
 // core/
+pub use core::color_palette;
 pub use core::custom_event;
 pub use core::custom_timer;
 pub use core::float_formatter;
@@ -2,6 +2,7 @@
 // Mechanical line-by-line translation.
 // Gauge object that renders a segmented gauge bar (e.g., groove gauge).
 
+use crate::skin::core::color_palette::ColorPalette;
 use crate::skin::reexports::{Color, MainState, TextureRegion};
 use crate::skin::sources::skin_source_image_set::SkinSourceImageSet;
 use crate::skin::sources::skin_source_set::SkinSourceSet;
@@ -54,6 +55,8 @@ pub struct SkinGauge {
     /// Whether the mode-change border alignment check has been performed.
     /// Java: isCheckedModeChanged
     is_checked_mode_changed: bool,
+    /// Accessibility palette applied to segments on each side of the border.
+    palette: ColorPalette,
 }
 
 impl SkinGauge {
@@ -86,6 +89,7 @@ impl SkinGauge {
             starttime: 0,
             endtime: 500,
             is_checked_mode_changed: false,
+            palette: ColorPalette::Skin,
         }
     }
 
@@ -108,6 +112,8 @@ impl SkinGauge {
     pub fn prepare(&mut self, time: i64, state: &dyn MainState) {
         self.data.prepare(time, state);
 
+        self.palette = ColorPalette::of(state);
+
         // Sync gauge value, type, border, and max from game state
         self.value = state.gauge_value();
         self.gauge_type = state.gauge_type();
@@ -241,6 +247,7 @@ impl SkinGauge {
 
         sprite.blend = self.data.dstblend;
         sprite.obj_type = 0; // TYPE_NORMAL
+        let base_color = *sprite.color();
 
         match self.animation_type {
             ANIMATION_RANDOM | ANIMATION_INCREASE | ANIMATION_DECREASE => {
@@ -255,6 +262,7 @@ impl SkinGauge {
                             2
                         }
                         + if border_val < self.border { 1 } else { 0 };
+                    self.apply_palette(sprite, &base_color, border_val < self.border);
 
                     let img_idx = img_idx as usize;
                     if img_idx < self.images.len() {
@@ -273,6 +281,7 @@ impl SkinGauge {
                     let border_val = i as f32 * self.max / self.parts as f32;
                     let border_offset = if border_val < self.border { 1 } else { 0 };
                     let img_idx = ex_gauge + if notes >= i { 0 } else { 2 } + border_offset;
+                    self.apply_palette(sprite, &base_color, border_offset == 1);
 
                     let seg_x = region.x + region.width * (i - 1) as f32 / self.parts as f32;
                     let seg_w = region.width / self.parts as f32;
@@ -324,6 +333,19 @@ impl SkinGauge {
             }
             _ => {}
         }
+
+        if self.palette != ColorPalette::Skin {
+            sprite.set_color(&base_color);
+            sprite.obj_type = SkinObjectRenderer::TYPE_NORMAL;
+        }
+    }
+
+    /// Tint the next segment with the accessibility palette, if one is set.
+    fn apply_palette(&self, sprite: &mut SkinObjectRenderer, base: &Color, below_border: bool) {
+        if let Some(c) = self.palette.gauge_color(below_border) {
+            sprite.set_color_rgba(c.r, c.g, c.b, base.a);
+            sprite.obj_type = SkinObjectRenderer::TYPE_RECOLOR;
+        }
     }
 }
 
@@ -54,6 +54,8 @@ pub struct SkinGaugeGraphObject {
     color: usize,
     gaugehistory: Vec<f32>,
     section: Vec<i32>,
+    /// Gauge log index and color index of each gauge auto-shift
+    shifts: Vec<(i32, usize)>,
     border: f32,
     max: f32,
     render: f32,
@@ -136,6 +138,7 @@ impl SkinGaugeGraphObject {
             color: 0,
             gaugehistory: Vec::new(),
             section: Vec::new(),
+            shifts: Vec::new(),
             border: 80.0,
             max: 100.0,
             render: 0.0,
@@ -196,6 +199,7 @@ impl SkinGaugeGraphObject {
             color: 0,
             gaugehistory: Vec::new(),
             section: Vec::new(),
+            shifts: Vec::new(),
             border: 80.0,
             max: 100.0,
             render: 0.0,
@@ -259,6 +263,7 @@ impl SkinGaugeGraphObject {
             color: 0,
             gaugehistory: Vec::new(),
             section: Vec::new(),
+            shifts: Vec::new(),
             border: 80.0,
             max: 100.0,
             render: 0.0,
@@ -306,9 +311,11 @@ impl SkinGaugeGraphObject {
                 .unwrap_or_default();
 
             self.section = Vec::new();
+            self.shifts = shift_points(state.gauge_transitions());
             let course_history = state.course_gauge_history();
             if !course_history.is_empty() {
                 self.gaugehistory = Vec::new();
+                self.shifts.clear();
                 for stage in course_history {
                     if let Some(type_history) = stage.get(self.current_type as usize) {
                         self.gaugehistory.extend_from_slice(type_history);
@@ -456,6 +463,16 @@ impl SkinGaugeGraphObject {
                     f1 = Some(f2);
                 }
 
+                for &(i, color) in &self.shifts {
+                    shape.set_color(if color < 3 {
+                        &self.graph_line[color]
+                    } else {
+                        &self.border_line[color]
+                    });
+                    let shift_x = (width as f32 * i as f32 / gauge_len) as i32;
+                    shape.fill_rectangle(shift_x, 0, line_width, height);
+                }
+
                 if last_gauge != -1.0 {
                     if last_gauge < border {
                         shape.set_color(&self.graph_line[self.color]);
@@ -518,6 +535,22 @@ impl SkinGaugeGraphObject {
     }
 }
 
+/// Gauge log index (one sample per 500ms) and color index of every change of
+/// the active gauge type, e.g. GAS shifting from EX-HARD down to NORMAL.
+fn shift_points(transitions: &[crate::skin::groove_gauge::GaugeTransition]) -> Vec<(i32, usize)> {
+    transitions
+        .windows(2)
+        .filter(|w| w[0].gauge_type != w[1].gauge_type)
+        .map(|w| {
+            let color = TYPE_TABLE
+                .get(w[1].gauge_type as usize)
+                .copied()
+                .unwrap_or(0);
+            ((w[1].time / 500_000) as i32, color)
+        })
+        .collect()
+}
+
 impl crate::skin::types::skin_node::SkinNode for SkinGaugeGraphObject {
     fn data(&self) -> &SkinObjectData {
         &self.data
@@ -558,6 +591,7 @@ mod tests {
         timer: crate::skin::reexports::Timer,
         gauge_type: i32,
         gauge_history: Option<Vec<Vec<f32>>>,
+        transitions: Vec<crate::skin::groove_gauge::GaugeTransition>,
     }
 
     impl MockGaugeState {
@@ -566,6 +600,7 @@ mod tests {
                 timer: crate::skin::reexports::Timer::default(),
                 gauge_type,
                 gauge_history: None,
+                transitions: Vec::new(),
             }
         }
 
@@ -606,6 +641,9 @@ mod tests {
         fn gauge_history(&self) -> Option<&Vec<Vec<f32>>> {
             self.gauge_history.as_ref()
         }
+        fn gauge_transitions(&self) -> &[crate::skin::groove_gauge::GaugeTransition] {
+            &self.transitions
+        }
     }
 
     impl crate::skin::reexports::MainState for MockGaugeState {}
@@ -722,6 +760,41 @@ mod tests {
         assert_eq!(obj.gaugehistory, vec![4.0, 5.0, 6.0]);
     }
 
+    #[test]
+    fn test_prepare_marks_gauge_auto_shift() {
+        use crate::skin::groove_gauge::GaugeTransition;
+        use crate::skin::reexports::Rectangle;
+
+        let transition = |time, gauge_type| GaugeTransition {
+            time,
+            judge: 4,
+            delta: -10.0,
+            gauge_type,
+            value: 50.0,
+        };
+        let mut state = MockGaugeState::new(2).with_gauge_history(vec![
+            vec![20.0; 8],
+            vec![20.0; 8],
+            vec![40.0, 40.0, 40.0, 30.0, 30.0, 30.0, 30.0, 30.0],
+            vec![50.0, 40.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        ]);
+        state.transitions = vec![
+            transition(400_000, 3),
+            transition(1_000_000, 3),
+            transition(1_600_000, 2),
+            transition(2_200_000, 2),
+        ];
+        let mut obj = SkinGaugeGraphObject::new_default();
+        obj.prepare(0, &state);
+
+        assert_eq!(obj.shifts, vec![(3, TYPE_TABLE[2])]);
+
+        obj.data.region = Rectangle::new(0.0, 0.0, 80.0, 40.0);
+        let mut renderer = SkinObjectRenderer::new();
+        obj.draw_impl(&mut renderer);
+        assert!(obj.shapetex.is_some());
+    }
+
     /// Regression: when gauge drops from above border to a value that truncates
     /// to the same integer y-coordinate as the border line (e.g., gauge=79.99,
     /// border=80.0), the vertical connector segment between border and graph
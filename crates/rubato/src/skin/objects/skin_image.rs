@@ -1,9 +1,10 @@
 // SkinImage.java -> skin_image.rs
 // Mechanical line-by-line translation.
 
+use crate::skin::core::color_palette::ColorPalette;
 use crate::skin::property::integer_property::IntegerProperty;
 use crate::skin::property::integer_property_factory;
-use crate::skin::property::timer_property::TimerPropertyEnum;
+use crate::skin::property::timer_property::{TimerProperty, TimerPropertyEnum};
 use crate::skin::reexports::{MainState, TextureRegion};
 use crate::skin::sources::skin_source::SkinSource;
 use crate::skin::sources::skin_source_image::SkinSourceImage;
@@ -327,6 +328,12 @@ impl SkinImage {
         if self.current_image.is_none() {
             self.data.draw = false;
         }
+        // Lane beams are the images driven by key-on timers.
+        self.data.recolor = self
+            .data
+            .dsttimer
+            .as_ref()
+            .and_then(|timer| ColorPalette::of(state).key_beam_color(timer.get_timer_id()));
     }
 
     pub fn draw_impl(&mut self, sprite: &mut SkinObjectRenderer) {
@@ -468,6 +475,15 @@ impl crate::skin::types::skin_node::SkinNode for SkinImage {
     fn draw(&mut self, sprite: &mut SkinObjectRenderer, _state: &dyn MainState) {
         self.draw_impl(sprite)
     }
+    fn draw_signature(&self) -> Option<u64> {
+        // Movie frames change without any property changing.
+        if self.is_movie {
+            return None;
+        }
+        self.current_image
+            .as_ref()
+            .map(|image| self.data.image_draw_signature(image))
+    }
     fn dispose(&mut self) {
         SkinImage::dispose(self)
     }
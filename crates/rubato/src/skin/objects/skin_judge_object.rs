@@ -2,6 +2,7 @@
 // Wraps rubato_game::play::SkinJudge with SkinObjectData for the skin pipeline.
 // Translated from: SkinJudge.java
 
+use crate::skin::core::color_palette::ColorPalette;
 use crate::skin::objects::skin_image::SkinImage;
 use crate::skin::objects::skin_number::SkinNumber;
 use crate::skin::reexports::MainState;
@@ -167,6 +168,18 @@ impl SkinJudgeObject {
         } else {
             self.now_count_idx = None;
         }
+
+        // Accessibility palette: color by judge, not by the skin's image slot.
+        let recolor = ColorPalette::of(state).judge_color(judge_idx);
+        if let Some(ref mut img) = self.judge_images[judge_idx] {
+            img.data.recolor = recolor;
+        }
+        if let Some(count) = self
+            .now_count_idx
+            .and_then(|ci| self.judge_counts[ci].as_mut())
+        {
+            count.data.recolor = recolor;
+        }
     }
 
     /// Translated from: Java SkinJudge.draw(SkinObjectRenderer sprite)
@@ -2,9 +2,10 @@
 // Wraps rubato_game::play::SkinNote with SkinObjectData for the skin pipeline.
 // Translated from: SkinNote.java
 
+use crate::skin::core::color_palette::{self, HIGH_CONTRAST_LONG_NOTE};
 use crate::skin::draw_command::{DrawCommand, NoteImageType};
 
-use crate::skin::reexports::{BitmapFont, Color, MainState};
+use crate::skin::reexports::{BitmapFont, Color, MainState, Texture, TextureRegion};
 use crate::skin::types::skin_object::{DestinationParams, SkinObjectData, SkinObjectRenderer};
 
 /// SkinNote skin object — wraps play-side SkinNote with SkinObjectData.
@@ -34,11 +35,39 @@ pub struct SkinNoteObject {
     /// White pixel texture for judge area rendering (IMAGE_WHITE).
     /// Set by the caller after obtaining the system image registry.
     pub judge_area_image: Option<crate::skin::reexports::TextureRegion>,
+    /// White pixel for replay ghost key beams.
+    ghost_beam_image: TextureRegion,
     /// Font for text overlay rendering (time/BPM/stop text in practice mode).
     /// Set by the caller from LaneRenderer's font.
     pub font: Option<BitmapFont>,
+    /// Draw notes as flat silhouettes (accessibility option), set in prepare.
+    pub high_contrast: bool,
 }
 
+/// Draw a note region, as a flat silhouette in `contrast` when set. The
+/// lane renderer's color and shader type are restored afterwards.
+fn draw_note(
+    sprite: &mut SkinObjectRenderer,
+    region: &crate::skin::reexports::TextureRegion,
+    (x, y, w, h): (f32, f32, f32, f32),
+    contrast: Option<Color>,
+) {
+    let Some(c) = contrast else {
+        sprite.draw(region, x, y, w, h);
+        return;
+    };
+    let color = *sprite.color();
+    let obj_type = sprite.obj_type;
+    sprite.set_color_rgba(c.r, c.g, c.b, color.a);
+    sprite.obj_type = SkinObjectRenderer::TYPE_SILHOUETTE;
+    sprite.draw(region, x, y, w, h);
+    sprite.set_color(&color);
+    sprite.obj_type = obj_type;
+}
+
+/// Opacity of replay ghost key beams.
+const GHOST_BEAM_ALPHA: f32 = 0x40 as f32 / 255.0;
+
 /// Line image data for section/BPM/stop/time lines.
 pub struct LineImage {
     pub region: crate::skin::reexports::TextureRegion,
@@ -84,7 +113,14 @@ impl SkinNoteObject {
             ln_body_images: vec![Default::default(); lane_count],
             line_images: Default::default(),
             judge_area_image: None,
+            // Path-less 1x1 texture: drawn with the renderer's white fallback
+            ghost_beam_image: TextureRegion::from_texture(Texture {
+                width: 1,
+                height: 1,
+                ..Default::default()
+            }),
             font: None,
+            high_contrast: false,
         }
     }
 
@@ -93,6 +129,7 @@ impl SkinNoteObject {
     pub fn prepare(&mut self, time: i64, state: &dyn MainState) {
         self.data.prepare(time, state);
         self.inner.prepare(time);
+        self.high_contrast = color_palette::high_contrast_notes(state);
     }
 
     /// Execute draw commands produced by LaneRenderer.draw_lane().
@@ -137,7 +174,10 @@ impl SkinNoteObject {
                         _ => self.note_images.get(*lane).and_then(|r| r.as_ref()),
                     };
                     if let Some(region) = region {
-                        sprite.draw(region, *x, *y, *w, *h);
+                        let contrast = self
+                            .high_contrast
+                            .then(|| color_palette::high_contrast_note_color(image_type));
+                        draw_note(sprite, region, (*x, *y, *w, *h), contrast);
                     }
                 }
                 DrawCommand::DrawLongNote {
@@ -154,7 +194,8 @@ impl SkinNoteObject {
                         .and_then(|arr| arr.get(*image_index))
                         .and_then(|r| r.as_ref());
                     if let Some(region) = region {
-                        sprite.draw(region, *x, *y, *w, *h);
+                        let contrast = self.high_contrast.then_some(HIGH_CONTRAST_LONG_NOTE);
+                        draw_note(sprite, region, (*x, *y, *w, *h), contrast);
                     }
                 }
                 DrawCommand::DrawSectionLine { y_offset } => {
@@ -220,6 +261,12 @@ impl SkinNoteObject {
                         sprite.draw(white, *x, *y, *w, *h);
                     }
                 }
+                DrawCommand::DrawGhostBeam { x, y, w, h, .. } => {
+                    let color = *sprite.color();
+                    sprite.set_color_rgba(1.0, 1.0, 1.0, GHOST_BEAM_ALPHA);
+                    sprite.draw(&self.ghost_beam_image, *x, *y, *w, *h);
+                    sprite.set_color(&color);
+                }
             }
         }
     }
@@ -393,6 +440,33 @@ mod tests {
         note.draw_impl(&mut sprite);
     }
 
+    #[test]
+    fn test_high_contrast_note_restores_lane_color_and_type() {
+        let mut note = SkinNoteObject::new(7);
+        note.note_images[0] = Some(crate::skin::reexports::TextureRegion::new());
+        note.high_contrast = true;
+        note.draw_commands = vec![
+            DrawCommand::SetColor {
+                r: 1.0,
+                g: 1.0,
+                b: 1.0,
+                a: 0.5,
+            },
+            DrawCommand::DrawNote {
+                lane: 0,
+                x: 10.0,
+                y: 20.0,
+                w: 30.0,
+                h: 5.0,
+                image_type: NoteImageType::Mine,
+            },
+        ];
+        let mut sprite = SkinObjectRenderer::new();
+        note.draw_impl(&mut sprite);
+        assert_eq!(sprite.color().to_array(), [1.0, 1.0, 1.0, 0.5]);
+        assert_eq!(sprite.obj_type, SkinObjectRenderer::TYPE_NORMAL);
+    }
+
     #[test]
     fn test_draw_long_note_command_does_not_panic() {
         let mut note = SkinNoteObject::new(7);
@@ -1,101 +1,106 @@
-// PatternModifyLog DTO
-// NOTE: This is the DTO version with pub fields for JSON serde.
-// The runtime version exists in beatoraja-pattern with a different field layout.
+use serde::{Deserialize, Serialize};
 
-/// Rust equivalent of beatoraja.pattern.PatternModifyLog (field layout differs from beatoraja-pattern)
-#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+use crate::skin::validatable::Validatable;
+
+/// Rust equivalent of beatoraja.pattern.PatternModifyLog.
+///
+/// One entry per modified timeslice: `modify[i]` is the source lane whose note
+/// ended up on lane `i` at `section`. Stored in `ReplayData::pattern` so note
+/// shuffles can be reproduced exactly without re-rolling the randomizer.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PatternModifyLog {
-    pub old_lane: i32,
-    pub new_lane: i32,
+    pub section: f64,
+    pub modify: Option<Vec<i32>>,
 }
 
-impl PatternModifyLog {
-    pub fn validate(&self) -> bool {
-        true
+impl Default for PatternModifyLog {
+    fn default() -> Self {
+        PatternModifyLog {
+            section: -1.0,
+            modify: None,
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_pattern_modify_log_default() {
-        let log = PatternModifyLog::default();
-        assert_eq!(log.old_lane, 0);
-        assert_eq!(log.new_lane, 0);
+impl PatternModifyLog {
+    pub fn new(section: f64, modify: Vec<i32>) -> Self {
+        PatternModifyLog {
+            section,
+            modify: Some(modify),
+        }
     }
+}
 
-    #[test]
-    fn test_pattern_modify_log_validate() {
-        let log = PatternModifyLog {
-            old_lane: 2,
-            new_lane: 5,
-        };
-        assert!(log.validate());
+impl Validatable for PatternModifyLog {
+    fn validate(&mut self) -> bool {
+        self.section >= 0.0 && self.modify.is_some()
     }
+}
 
-    #[test]
-    fn test_pattern_modify_log_serde_round_trip() {
-        let log = PatternModifyLog {
-            old_lane: 3,
-            new_lane: 7,
+/// Merge `other` into `log`, combining entries that share a section.
+///
+/// Used for DP where 1P and 2P shuffles each log the same timeslices. Lanes that
+/// `other` leaves untouched (identity) keep the permutation already in `log`.
+pub fn merge_modify_logs(log: &mut Vec<PatternModifyLog>, other: &[PatternModifyLog]) {
+    for entry in other {
+        let Some(ref modify) = entry.modify else {
+            continue;
         };
-        let json = serde_json::to_string(&log).unwrap();
-        let deserialized: PatternModifyLog = serde_json::from_str(&json).unwrap();
-        assert_eq!(deserialized.old_lane, 3);
-        assert_eq!(deserialized.new_lane, 7);
+        match log
+            .iter_mut()
+            .find(|l| (l.section - entry.section).abs() < 1e-9)
+        {
+            Some(existing) => {
+                let target = existing.modify.get_or_insert_with(Vec::new);
+                for (lane, &src) in modify.iter().enumerate() {
+                    if src != lane as i32 {
+                        if target.len() <= lane {
+                            target.extend(target.len() as i32..=lane as i32);
+                        }
+                        target[lane] = src;
+                    }
+                }
+            }
+            None => log.push(entry.clone()),
+        }
     }
+}
 
-    #[test]
-    fn test_pattern_modify_log_clone() {
-        let log = PatternModifyLog {
-            old_lane: 1,
-            new_lane: 4,
-        };
-        let cloned = log.clone();
-        assert_eq!(cloned.old_lane, log.old_lane);
-        assert_eq!(cloned.new_lane, log.new_lane);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
-    fn test_pattern_modify_log_negative_lanes() {
-        let log = PatternModifyLog {
-            old_lane: -1,
-            new_lane: -5,
-        };
-        // validate always returns true
-        assert!(log.validate());
-        assert_eq!(log.old_lane, -1);
-        assert_eq!(log.new_lane, -5);
+    fn test_pattern_modify_log_serde_uses_java_field_names() {
+        let log = PatternModifyLog::new(3.0, vec![2, 0, 1]);
+        let json = serde_json::to_string(&log).unwrap();
+        assert!(json.contains("\"section\""));
+        assert!(json.contains("\"modify\""));
+        let deserialized: PatternModifyLog = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, log);
     }
 
     #[test]
-    fn test_pattern_modify_log_same_lane() {
-        let log = PatternModifyLog {
-            old_lane: 3,
-            new_lane: 3,
-        };
-        assert!(log.validate());
+    fn test_merge_modify_logs_appends_new_sections() {
+        let mut log = vec![PatternModifyLog::new(0.0, vec![1, 0, 2, 3])];
+        merge_modify_logs(&mut log, &[PatternModifyLog::new(1.0, vec![0, 1, 3, 2])]);
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[1].modify, Some(vec![0, 1, 3, 2]));
     }
 
     #[test]
-    fn test_pattern_modify_log_serde_deserialize_missing_fields() {
-        // serde default fills in 0 for missing fields
-        let json = r#"{"old_lane":5,"new_lane":10}"#;
-        let log: PatternModifyLog = serde_json::from_str(json).unwrap();
-        assert_eq!(log.old_lane, 5);
-        assert_eq!(log.new_lane, 10);
+    fn test_merge_modify_logs_combines_player_sides() {
+        // 2P shuffle touched lanes 2-3, 1P shuffle touched lanes 0-1.
+        let mut log = vec![PatternModifyLog::new(0.0, vec![0, 1, 3, 2])];
+        merge_modify_logs(&mut log, &[PatternModifyLog::new(0.0, vec![1, 0, 2, 3])]);
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].modify, Some(vec![1, 0, 3, 2]));
     }
 
     #[test]
-    fn test_pattern_modify_log_debug() {
-        let log = PatternModifyLog {
-            old_lane: 2,
-            new_lane: 7,
-        };
-        let debug = format!("{:?}", log);
-        assert!(debug.contains("old_lane"));
-        assert!(debug.contains("new_lane"));
+    fn test_merge_modify_logs_skips_entries_without_modify() {
+        let mut log = Vec::new();
+        merge_modify_logs(&mut log, &[PatternModifyLog::default()]);
+        assert!(log.is_empty());
     }
 }
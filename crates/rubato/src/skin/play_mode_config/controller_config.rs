@@ -6,6 +6,8 @@ use crate::skin::bm_keys::BMKeys;
 
 pub const ANALOG_SCRATCH_VER_2: i32 = 0;
 pub const ANALOG_SCRATCH_VER_1: i32 = 1;
+/// Time-based analog scratch tuned by sensitivity, deadzone and active duration.
+pub const ANALOG_SCRATCH_VER_3: i32 = 2;
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
@@ -23,6 +25,28 @@ pub struct ControllerConfig {
     pub analog_scratch_mode: i32,
     #[serde(rename = "analogScratchThreshold")]
     pub analog_scratch_threshold: i32,
+    /// Ver. 3: ticks of turntable movement in one direction that turn the lane on.
+    #[serde(rename = "analogScratchSensitivity")]
+    pub analog_scratch_sensitivity: i32,
+    /// Ver. 3: ticks of movement ignored as jitter of a resting turntable.
+    #[serde(rename = "analogScratchDeadzone")]
+    pub analog_scratch_deadzone: i32,
+    /// Ver. 3: time (ms) the lane stays on after the turntable stops.
+    #[serde(rename = "analogScratchDuration")]
+    pub analog_scratch_duration: i32,
+    /// Time (ms) added to button timestamps before judging. Negative values
+    /// compensate for a controller's polling delay.
+    #[serde(rename = "latencyOffset")]
+    pub latency_offset: i32,
+    /// Deflection (% of full travel) at which an axis bound to a lane turns
+    /// the lane on. Used when analog scratch is off, e.g. for foot pedals and
+    /// levers.
+    #[serde(rename = "axisThreshold")]
+    pub axis_threshold: i32,
+    /// How far (% of full travel) the axis must fall back below
+    /// `axis_threshold` before the lane turns off again.
+    #[serde(rename = "axisHysteresis")]
+    pub axis_hysteresis: i32,
 }
 
 impl Default for ControllerConfig {
@@ -66,6 +90,12 @@ impl ControllerConfig {
             analog_scratch: false,
             analog_scratch_mode: 0,
             analog_scratch_threshold: 50,
+            analog_scratch_sensitivity: 2,
+            analog_scratch_deadzone: 0,
+            analog_scratch_duration: 50,
+            latency_offset: 0,
+            axis_threshold: 90,
+            axis_hysteresis: 0,
         }
     }
 
@@ -80,6 +110,12 @@ impl ControllerConfig {
             analog_scratch: false,
             analog_scratch_mode: 0,
             analog_scratch_threshold: 50,
+            analog_scratch_sensitivity: 2,
+            analog_scratch_deadzone: 0,
+            analog_scratch_duration: 50,
+            latency_offset: 0,
+            axis_threshold: 90,
+            axis_hysteresis: 0,
         };
         config.set_key_assign(mode, player, enable);
         config
@@ -379,4 +415,11 @@ mod tests {
         assert!(d.jkoc_hack);
         assert_eq!(d.analog_scratch_threshold, 75);
     }
+
+    #[test]
+    fn test_axis_threshold_defaults_when_missing() {
+        let d: ControllerConfig = serde_json::from_str(r#"{"axisHysteresis": 10}"#).unwrap();
+        assert_eq!(d.axis_threshold, 90);
+        assert_eq!(d.axis_hysteresis, 10);
+    }
 }
@@ -14,6 +14,10 @@ pub struct KeyboardConfig {
     pub start: i32,
     pub select: i32,
     pub duration: i32,
+    /// Time (ms) added to key timestamps before judging. Negative values
+    /// compensate for a keyboard that reports presses late.
+    #[serde(rename = "latencyOffset")]
+    pub latency_offset: i32,
 }
 
 impl Default for KeyboardConfig {
@@ -30,6 +34,7 @@ impl KeyboardConfig {
             start: 0,
             select: 0,
             duration: 16,
+            latency_offset: 0,
         };
         config.set_key_assign(mode, enable);
         config
@@ -8,6 +8,36 @@ pub struct MidiConfig {
     pub keys: Vec<Option<MidiInput>>,
     pub start: Option<MidiInput>,
     pub select: Option<MidiInput>,
+    /// Lowest note-on velocity that counts as a press. 1 accepts every note.
+    #[serde(rename = "velocityThreshold")]
+    pub velocity_threshold: i32,
+    /// MIDI channel (0-15) to listen on, or -1 for every channel.
+    pub channel: i32,
+    /// Channel overrides for individual devices.
+    pub devices: Vec<MidiDeviceConfig>,
+    /// Time (ms) added to note timestamps before judging. Negative values
+    /// compensate for MIDI interface latency.
+    #[serde(rename = "latencyOffset")]
+    pub latency_offset: i32,
+}
+
+/// Channel for the MIDI input ports whose name contains `name`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct MidiDeviceConfig {
+    pub name: String,
+    /// MIDI channel (0-15), or -1 for every channel.
+    pub channel: i32,
+}
+
+impl MidiDeviceConfig {
+    /// Channel of the first entry in `devices` matching `port_name`, else `default`.
+    pub fn channel_for(devices: &[MidiDeviceConfig], default: i32, port_name: &str) -> i32 {
+        devices
+            .iter()
+            .find(|device| !device.name.is_empty() && port_name.contains(&device.name))
+            .map_or(default, |device| device.channel)
+    }
 }
 
 impl Default for MidiConfig {
@@ -70,6 +100,10 @@ impl MidiConfig {
             keys: Vec::new(),
             start: None,
             select: None,
+            velocity_threshold: 1,
+            channel: -1,
+            devices: Vec::new(),
+            latency_offset: 0,
         };
         config.set_key_assign(mode, enable);
         config
@@ -193,6 +227,12 @@ impl MidiConfig {
     pub fn select(&self) -> Option<&MidiInput> {
         self.select.as_ref()
     }
+
+    /// Channel to listen on for the port named `port_name`: the first device
+    /// override whose name it contains, else `channel`.
+    pub fn channel_for(&self, port_name: &str) -> i32 {
+        MidiDeviceConfig::channel_for(&self.devices, self.channel, port_name)
+    }
 }
 
 #[cfg(test)]
@@ -325,4 +365,25 @@ mod tests {
         assert_eq!(d.input_type, MidiInputType::PITCH_BEND);
         assert_eq!(d.value, 1);
     }
+
+    #[test]
+    fn test_channel_for_uses_device_override() {
+        let mut config = MidiConfig::new(Mode::BEAT_7K, true);
+        assert_eq!(config.channel_for("Any Keyboard"), -1);
+        config.channel = 0;
+        config.devices.push(MidiDeviceConfig {
+            name: "DDJ".to_string(),
+            channel: 6,
+        });
+        assert_eq!(config.channel_for("Pioneer DDJ-400 MIDI 1"), 6);
+        assert_eq!(config.channel_for("Any Keyboard"), 0);
+    }
+
+    #[test]
+    fn test_missing_filter_fields_default_to_accept_all() {
+        let config: MidiConfig = serde_json::from_str(r#"{"keys":[]}"#).unwrap();
+        assert_eq!(config.velocity_threshold, 1);
+        assert_eq!(config.channel, -1);
+        assert!(config.devices.is_empty());
+    }
 }
@@ -37,6 +37,7 @@ pub(crate) mod gdx_keys {
     pub const SEMICOLON: i32 = 74;
     pub const SLASH: i32 = 76;
     pub const APOSTROPHE: i32 = 75;
+    pub const ESCAPE: i32 = 111;
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -46,6 +47,10 @@ pub struct PlayModeConfig {
     pub keyboard: KeyboardConfig,
     pub controller: Vec<ControllerConfig>,
     pub midi: MidiConfig,
+    /// Time (ms) after a key is released in which pressing it again is
+    /// treated as switch chatter and ignored. 0 disables the filter.
+    #[serde(rename = "debounceWindow")]
+    pub debounce_window: i32,
     pub version: i32,
 }
 
@@ -69,6 +74,7 @@ impl PlayModeConfig {
             keyboard,
             controller,
             midi,
+            debounce_window: 0,
             version: 0,
         }
     }
@@ -83,6 +89,7 @@ impl PlayModeConfig {
             keyboard,
             controller: controllers,
             midi,
+            debounce_window: 0,
             version: 0,
         }
     }
@@ -142,8 +149,12 @@ impl PlayModeConfig {
                 c.keys = newkeys;
             }
             c.duration = c.duration.clamp(0, 100);
+            c.axis_threshold = c.axis_threshold.clamp(0, 100);
+            c.axis_hysteresis = c.axis_hysteresis.clamp(0, c.axis_threshold);
         }
 
+        self.debounce_window = self.debounce_window.clamp(0, 100);
+
         // Button count extension (16->32) conversion (0.8.1 -> 0.8.2)
         if self.version == 0 {
             for c in &mut self.controller {
@@ -3,8 +3,13 @@ use std::path::{Path, PathBuf};
 use bms::model::mode::Mode;
 
 use crate::skin::bar_sorter::BarSorter;
+use crate::skin::bms_player_rule::BMSPlayerRule;
 use crate::skin::config::Config;
+use crate::skin::config_migration::{
+    PLAYER_CONFIG_MIGRATIONS, PLAYER_CONFIG_VERSION, parse_migrated,
+};
 use crate::skin::groove_gauge::GrooveGauge;
+use crate::skin::hotkey_config::{HotkeyConfig, ModMenuCombo};
 use crate::skin::ir_config::IRConfig;
 use crate::skin::ir_connection_registry::IRConnectionManager;
 use crate::skin::play_mode_config::PlayModeConfig;
@@ -22,6 +27,41 @@ pub const GAUGEAUTOSHIFT_SURVIVAL_TO_GROOVE: i32 = 2;
 pub const GAUGEAUTOSHIFT_BESTCLEAR: i32 = 3;
 pub const GAUGEAUTOSHIFT_SELECT_TO_UNDER: i32 = 4;
 
+pub const GHOST_NONE: i32 = 0;
+pub const GHOST_SCORE: i32 = 1;
+/// Replay slot 1. Slots 2-4 follow as 3-5.
+pub const GHOST_REPLAY1: i32 = 2;
+
+/// Each replay slot is saved on its own `autosavereplay` condition.
+pub const REPLAY_AUTOSAVE_PER_SLOT: i32 = 0;
+/// The policies below save to slot 1 and shift older replays down one slot.
+pub const REPLAY_AUTOSAVE_ALWAYS: i32 = 1;
+pub const REPLAY_AUTOSAVE_PERSONAL_BEST: i32 = 2;
+pub const REPLAY_AUTOSAVE_CLEAR_UPDATE: i32 = 3;
+pub const REPLAY_AUTOSAVE_NEVER: i32 = 4;
+/// Ask on the result screen which slot to save to after a play that updates any record.
+pub const REPLAY_AUTOSAVE_ASK: i32 = 5;
+
+pub const CLEAR_FILTER_ALL: i32 = 0;
+pub const CLEAR_FILTER_UNPLAYED: i32 = 1;
+pub const CLEAR_FILTER_FAILED: i32 = 2;
+/// Easy clear or better.
+pub const CLEAR_FILTER_CLEARED: i32 = 3;
+pub const CLEAR_FILTER_FULLCOMBO: i32 = 4;
+
+/// Lowest DJ rank passing the rank filter: 1 = F .. 8 = AAA, 0 = off.
+pub const RANK_FILTER_MAX: i32 = 8;
+
+/// Use the skin's own colors.
+pub const COLOR_PALETTE_SKIN: i32 = 0;
+/// Blue/orange palette safe for protanopia and deuteranopia.
+pub const COLOR_PALETTE_RED_GREEN: i32 = 1;
+/// Red/cyan palette safe for tritanopia.
+pub const COLOR_PALETTE_BLUE_YELLOW: i32 = 2;
+
+/// Target ID whose rate is SelectSettings::target_custom_rate.
+pub const TARGET_CUSTOM_RATE: &str = "RATE_CUSTOM";
+
 /// Play-related settings (gauge, random, option modes)
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
@@ -42,6 +82,18 @@ pub struct PlaySettings {
     pub hran_threshold_bpm: i32,
     #[serde(rename = "mineMode")]
     pub mine_mode: i32,
+    /// Replace S-RANDOM with SRAN+ (avoids lanes used in the previous timeslice).
+    #[serde(rename = "sranPlus")]
+    pub sran_plus: bool,
+    /// Minimum gap (ms) before SRAN+ may place another note on the same lane.
+    #[serde(rename = "sranPlusMinGap")]
+    pub sran_plus_min_gap: i32,
+    /// Path to a JSON gauge ruleset overriding the gauge curves. Empty for none.
+    #[serde(rename = "gaugeTable")]
+    pub gauge_table: String,
+    /// Source of the ghost pacemaker: none, the stored score, or a replay slot.
+    #[serde(rename = "ghostSource")]
+    pub ghost_source: i32,
 }
 
 impl Default for PlaySettings {
@@ -58,6 +110,10 @@ impl Default for PlaySettings {
             bottom_shiftable_gauge: GrooveGauge::ASSISTEASY,
             hran_threshold_bpm: 120,
             mine_mode: 0,
+            sran_plus: false,
+            sran_plus_min_gap: 60,
+            gauge_table: String::new(),
+            ghost_source: GHOST_NONE,
         }
     }
 }
@@ -66,7 +122,12 @@ impl Default for PlaySettings {
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
 pub struct JudgeSettings {
+    /// Visual offset (ms): shifts where notes are drawn relative to the judge line.
     pub judgetiming: i32,
+    /// Audio offset (ms): keysound output latency, subtracted from key timestamps
+    /// before judging.
+    #[serde(rename = "audioOffset")]
+    pub audio_offset: i32,
     #[serde(rename = "notesDisplayTimingAutoAdjust")]
     pub notes_display_timing_auto_adjust: bool,
     #[serde(rename = "customJudge")]
@@ -83,12 +144,19 @@ pub struct JudgeSettings {
     pub scratch_judge_window_rate_great: i32,
     #[serde(rename = "scratchJudgeWindowRateGood")]
     pub scratch_judge_window_rate_good: i32,
+    /// Judge window table: LR2 scaling or the per-mode beatoraja tables.
+    #[serde(rename = "judgeRule")]
+    pub judge_rule: BMSPlayerRule,
+    /// Path to a JSON judge window table overriding the ruleset's windows. Empty for none.
+    #[serde(rename = "judgeTable")]
+    pub judge_table: String,
 }
 
 impl Default for JudgeSettings {
     fn default() -> Self {
         Self {
             judgetiming: 0,
+            audio_offset: 0,
             notes_display_timing_auto_adjust: false,
             custom_judge: false,
             key_judge_window_rate_perfect_great: 400,
@@ -97,10 +165,20 @@ impl Default for JudgeSettings {
             scratch_judge_window_rate_perfect_great: 400,
             scratch_judge_window_rate_great: 400,
             scratch_judge_window_rate_good: 100,
+            judge_rule: BMSPlayerRule::LR2,
+            judge_table: String::new(),
         }
     }
 }
 
+impl JudgeSettings {
+    /// Store the visual and audio offsets (ms) measured by an offset calibration run.
+    pub fn apply_calibration(&mut self, visual_offset: i32, audio_offset: i32) {
+        self.judgetiming = visual_offset.clamp(JUDGETIMING_MIN, JUDGETIMING_MAX);
+        self.audio_offset = audio_offset.clamp(JUDGETIMING_MIN, JUDGETIMING_MAX);
+    }
+}
+
 /// Display and visual settings
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
@@ -128,6 +206,15 @@ pub struct DisplaySettings {
     pub scroll_section: i32,
     #[serde(rename = "scrollRate")]
     pub scroll_rate: f64,
+    /// Judge text, lane beam and gauge colors drawn over the skin's own.
+    #[serde(rename = "colorPalette")]
+    pub color_palette: i32,
+    /// Draw notes as flat, high-contrast shapes instead of skin textures.
+    #[serde(rename = "highContrastNotes")]
+    pub high_contrast_notes: bool,
+    /// Draw the key presses of a replay ghost as translucent beams over the lanes.
+    #[serde(rename = "ghostKeyBeam")]
+    pub ghost_key_beam: bool,
 }
 
 impl Default for DisplaySettings {
@@ -147,11 +234,14 @@ impl Default for DisplaySettings {
             scroll_mode: 0,
             scroll_section: 4,
             scroll_rate: 0.5,
+            color_palette: COLOR_PALETTE_SKIN,
+            high_contrast_notes: false,
+            ghost_key_beam: false,
         }
     }
 }
 
-/// Note modifier settings (longnote, 7-to-9 conversion)
+/// Note modifier settings (longnote, 7-to-9 and to-7K conversion)
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
 pub struct NoteModifierSettings {
@@ -163,6 +253,9 @@ pub struct NoteModifierSettings {
     pub seven_to_nine_pattern: i32,
     #[serde(rename = "sevenToNineType")]
     pub seven_to_nine_type: i32,
+    /// Convert 5K, 9K and 14K charts to 7K before applying the other options.
+    #[serde(rename = "convertTo7k")]
+    pub convert_to_7k: bool,
 }
 
 impl Default for NoteModifierSettings {
@@ -172,6 +265,7 @@ impl Default for NoteModifierSettings {
             longnote_rate: 1.0,
             seven_to_nine_pattern: 0,
             seven_to_nine_type: 0,
+            convert_to_7k: false,
         }
     }
 }
@@ -185,12 +279,67 @@ pub struct SelectSettings {
     pub musicselectinput: i32,
     #[serde(rename = "isRandomSelect")]
     pub is_random_select: bool,
+    /// Spin through a few charts before landing on a random select hotkey pick.
+    #[serde(rename = "randomSelectRoulette")]
+    pub random_select_roulette: bool,
+    /// Song bars shown by best clear (CLEAR_FILTER_*).
+    #[serde(rename = "clearFilter")]
+    pub clear_filter: i32,
+    /// Song bars shown by best DJ rank (1 = F .. 8 = AAA and above), 0 = off.
+    #[serde(rename = "rankFilter")]
+    pub rank_filter: i32,
+    /// Song bars shown by level; 0 leaves the bound open.
+    #[serde(rename = "levelFilterMin")]
+    pub level_filter_min: i32,
+    #[serde(rename = "levelFilterMax")]
+    pub level_filter_max: i32,
+    /// Show charts sharing a folder and title as one bar; left/right cycle them.
+    #[serde(rename = "groupCharts")]
+    pub group_charts: bool,
+    /// Hide class courses after the first one the player holds no
+    /// certification for.
+    #[serde(rename = "lockNextDan")]
+    pub lock_next_dan: bool,
     #[serde(rename = "isWindowHold")]
     pub is_window_hold: bool,
     #[serde(rename = "eventMode")]
     pub event_mode: bool,
     pub targetid: String,
     pub targetlist: Vec<String>,
+    /// Score rate (%) of the RATE_CUSTOM target.
+    #[serde(rename = "targetCustomRate")]
+    pub target_custom_rate: f32,
+    /// Stage count of the generated random course bar; 0 hides the bar.
+    #[serde(rename = "randomCourseStages")]
+    pub random_course_stages: i32,
+    /// Table the random course draws from; empty draws from the whole library.
+    #[serde(rename = "randomCourseTable")]
+    pub random_course_table: String,
+    /// Level range spread over the stages; 0 leaves the bound open.
+    #[serde(rename = "randomCourseLevelMin")]
+    pub random_course_level_min: i32,
+    #[serde(rename = "randomCourseLevelMax")]
+    pub random_course_level_max: i32,
+    /// Only draw charts without a score.
+    #[serde(rename = "randomCourseUnplayed")]
+    pub random_course_unplayed: bool,
+    /// Folder titles from the root to the directory open when select was last left.
+    #[serde(rename = "lastFolder")]
+    pub last_folder: Vec<String>,
+    /// Bar selected when select was last left: chart SHA-256 for songs, title otherwise.
+    #[serde(rename = "lastSelected")]
+    pub last_selected: String,
+}
+
+impl SelectSettings {
+    /// Target ID handed to TargetProperty, with RATE_CUSTOM expanded to RATE_<rate>.
+    pub fn resolved_targetid(&self) -> String {
+        if self.targetid == TARGET_CUSTOM_RATE {
+            format!("RATE_{}", self.target_custom_rate)
+        } else {
+            self.targetid.clone()
+        }
+    }
 }
 
 impl Default for SelectSettings {
@@ -200,6 +349,13 @@ impl Default for SelectSettings {
             sortid: None,
             musicselectinput: 0,
             is_random_select: false,
+            random_select_roulette: true,
+            clear_filter: CLEAR_FILTER_ALL,
+            rank_filter: 0,
+            level_filter_min: 0,
+            level_filter_max: 0,
+            group_charts: false,
+            lock_next_dan: false,
             is_window_hold: false,
             event_mode: false,
             targetid: "MAX".to_string(),
@@ -215,6 +371,10 @@ impl Default for SelectSettings {
                 "RATE_AAA+",
                 "RATE_MAX-",
                 "MAX",
+                TARGET_CUSTOM_RATE,
+                "MYBEST",
+                "HISTORY_AVERAGE_5",
+                "HISTORY_YESTERDAY",
                 "RANK_NEXT",
                 "IR_NEXT_1",
                 "IR_NEXT_2",
@@ -239,6 +399,7 @@ impl Default for SelectSettings {
                 "IR_RANKRATE_40",
                 "IR_RANKRATE_45",
                 "IR_RANKRATE_50",
+                "IR_AVERAGE_10",
                 "RIVAL_RANK_1",
                 "RIVAL_RANK_2",
                 "RIVAL_RANK_3",
@@ -249,6 +410,14 @@ impl Default for SelectSettings {
             .iter()
             .map(|s| s.to_string())
             .collect(),
+            target_custom_rate: 90.0,
+            random_course_stages: 0,
+            random_course_table: String::new(),
+            random_course_level_min: 0,
+            random_course_level_max: 0,
+            random_course_unplayed: false,
+            last_folder: Vec::new(),
+            last_selected: String::new(),
         }
     }
 }
@@ -258,6 +427,8 @@ impl Default for SelectSettings {
 #[serde(default)]
 pub struct MiscSettings {
     pub autosavereplay: Vec<i32>,
+    #[serde(rename = "replayAutoSavePolicy")]
+    pub replay_autosave_policy: i32,
     #[serde(rename = "exitPressDuration")]
     pub exit_press_duration: i32,
 }
@@ -266,6 +437,7 @@ impl Default for MiscSettings {
     fn default() -> Self {
         Self {
             autosavereplay: vec![0; 4],
+            replay_autosave_policy: REPLAY_AUTOSAVE_PER_SLOT,
             exit_press_duration: 1000,
         }
     }
@@ -274,6 +446,9 @@ impl Default for MiscSettings {
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
 pub struct PlayerConfig {
+    /// Schema version, see [`crate::skin::config_migration`].
+    #[serde(rename = "configVersion")]
+    pub config_version: u32,
     pub id: Option<String>,
     pub name: String,
     #[serde(flatten)]
@@ -314,6 +489,14 @@ pub struct PlayerConfig {
     pub notify_request: bool,
     #[serde(rename = "maxRequestCount")]
     pub max_request_count: i32,
+    /// Shared secret for `!!ctl` request moderation commands. Empty disables them.
+    #[serde(rename = "requestControlToken")]
+    pub request_control_token: String,
+    /// Keyboard bindings for function hotkeys during play.
+    pub hotkeys: HotkeyConfig,
+    /// Buttons held together to open or close the mod menu.
+    #[serde(rename = "modMenuCombo")]
+    pub modmenu_combo: ModMenuCombo,
 }
 
 impl Default for PlayerConfig {
@@ -324,6 +507,7 @@ impl Default for PlayerConfig {
             .collect();
 
         PlayerConfig {
+            config_version: PLAYER_CONFIG_VERSION,
             id: None,
             name: "NO NAME".to_string(),
             play_settings: PlaySettings::default(),
@@ -353,6 +537,9 @@ impl Default for PlayerConfig {
             enable_request: false,
             notify_request: false,
             max_request_count: 30,
+            request_control_token: String::new(),
+            hotkeys: HotkeyConfig::default(),
+            modmenu_combo: ModMenuCombo::default(),
         }
     }
 }
@@ -477,9 +664,25 @@ impl PlayerConfig {
         self.mode9.validate(9);
         self.mode24.validate(26);
         self.mode24double.validate(52);
+        self.hotkeys.validate();
+        self.modmenu_combo.validate();
 
         // MusicSelectKeyProperty::VALUES has 3 entries (Beat7k, Popn9k, Beat14k)
         self.select_settings.musicselectinput = self.select_settings.musicselectinput.clamp(0, 2);
+        self.select_settings.clear_filter = self
+            .select_settings
+            .clear_filter
+            .clamp(CLEAR_FILTER_ALL, CLEAR_FILTER_FULLCOMBO);
+        self.select_settings.rank_filter =
+            self.select_settings.rank_filter.clamp(0, RANK_FILTER_MAX);
+        self.select_settings.level_filter_min = self.select_settings.level_filter_min.max(0);
+        self.select_settings.level_filter_max = self.select_settings.level_filter_max.max(0);
+        self.select_settings.random_course_stages =
+            self.select_settings.random_course_stages.clamp(0, 10);
+        self.select_settings.random_course_level_min =
+            self.select_settings.random_course_level_min.max(0);
+        self.select_settings.random_course_level_max =
+            self.select_settings.random_course_level_max.max(0);
 
         let max_sort = BarSorter::DEFAULT_SORTER.len() as i32 - 1;
         self.select_settings.sort = self.select_settings.sort.clamp(0, max_sort);
@@ -500,6 +703,10 @@ impl PlayerConfig {
         // bottom_shiftable_gauge: ASSISTEASY(0)/EASY(1)/NORMAL(2), matching event factory count=3
         self.play_settings.bottom_shiftable_gauge =
             self.play_settings.bottom_shiftable_gauge.clamp(0, 2);
+        self.play_settings.ghost_source = self
+            .play_settings
+            .ghost_source
+            .clamp(GHOST_NONE, GHOST_REPLAY1 + 3);
         if self.play_settings.chart_replication_mode.is_empty() {
             self.play_settings.chart_replication_mode = "NONE".to_string();
         }
@@ -509,10 +716,19 @@ impl PlayerConfig {
         if self.select_settings.targetlist.is_empty() {
             // keep as-is if non-empty, otherwise leave empty
         }
+        if !self.select_settings.target_custom_rate.is_finite() {
+            self.select_settings.target_custom_rate = 90.0;
+        }
+        self.select_settings.target_custom_rate =
+            self.select_settings.target_custom_rate.clamp(0.0, 100.0);
         self.judge_settings.judgetiming = self
             .judge_settings
             .judgetiming
             .clamp(JUDGETIMING_MIN, JUDGETIMING_MAX);
+        self.judge_settings.audio_offset = self
+            .judge_settings
+            .audio_offset
+            .clamp(JUDGETIMING_MIN, JUDGETIMING_MAX);
         self.display_settings.misslayer_duration =
             self.display_settings.misslayer_duration.clamp(0, 5000);
         self.play_settings.lnmode = self.play_settings.lnmode.clamp(0, 2);
@@ -540,10 +756,15 @@ impl PlayerConfig {
             .clamp(0, 400);
         self.play_settings.hran_threshold_bpm =
             self.play_settings.hran_threshold_bpm.clamp(1, 1000);
+        self.play_settings.sran_plus_min_gap = self.play_settings.sran_plus_min_gap.clamp(0, 1000);
 
         if self.misc_settings.autosavereplay.len() != 4 {
             self.misc_settings.autosavereplay.resize(4, 0);
         }
+        self.misc_settings.replay_autosave_policy = self
+            .misc_settings
+            .replay_autosave_policy
+            .clamp(REPLAY_AUTOSAVE_PER_SLOT, REPLAY_AUTOSAVE_ASK);
         self.note_modifier_settings.seven_to_nine_pattern = self
             .note_modifier_settings
             .seven_to_nine_pattern
@@ -559,6 +780,10 @@ impl PlayerConfig {
             .clamp(0, scroll_speed_modifier::Mode::values().len() as i32 - 1);
         self.display_settings.scroll_section = self.display_settings.scroll_section.clamp(1, 1024);
         self.display_settings.scroll_rate = self.display_settings.scroll_rate.clamp(0.0, 1.0);
+        self.display_settings.color_palette = self
+            .display_settings
+            .color_palette
+            .clamp(COLOR_PALETTE_SKIN, COLOR_PALETTE_BLUE_YELLOW);
         // longnote_mode: 0=off, 1-5=Remove/AddLn/AddCn/AddHcn/AddAll (6 values total,
         // matching skin event cycling count). rubato-types enum is incomplete; the full
         // set lives in rubato-core::pattern::long_note_modifier::Mode which has 5 variants.
@@ -706,6 +931,11 @@ impl PlayerConfig {
         Ok(player)
     }
 
+    /// Parse `config_player.json` contents, upgrading older schema versions.
+    pub fn from_json(data: &str) -> anyhow::Result<PlayerConfig> {
+        parse_migrated(data, PLAYER_CONFIG_MIGRATIONS, PLAYER_CONFIG_VERSION)
+    }
+
     pub fn config_json(player: &PlayerConfig) -> anyhow::Result<String> {
         Ok(serde_json::to_string_pretty(player)?)
     }
@@ -723,8 +953,7 @@ impl PlayerConfig {
             std::fs::create_dir_all(parent)?;
         }
         let json = serde_json::to_string_pretty(player)?;
-        std::fs::write(path, json.as_bytes())?;
-        Ok(())
+        crate::skin::config::replace_file(&path, json.as_bytes())
     }
 }
 
@@ -793,7 +1022,7 @@ fn load_player_config(
         write_backup_player_config(playerpath, playerid, path);
         anyhow::anyhow!("Failed to read player config: {}", e)
     })?;
-    let player: PlayerConfig = serde_json::from_str(&data).map_err(|e| {
+    let player = PlayerConfig::from_json(&data).map_err(|e| {
         write_backup_player_config(playerpath, playerid, path);
         anyhow::anyhow!("Failed to parse player config: {}", e)
     })?;
@@ -802,7 +1031,7 @@ fn load_player_config(
 
 fn load_player_config_from_old_path(path: &Path) -> anyhow::Result<PlayerConfig> {
     let data = std::fs::read_to_string(path)?;
-    let player: PlayerConfig = serde_json::from_str(&data)?;
+    let player = PlayerConfig::from_json(&data)?;
     Ok(player)
 }
 
@@ -866,6 +1095,22 @@ mod tests {
         assert_eq!(deserialized.judge_settings.judgetiming, 50);
     }
 
+    #[test]
+    fn audio_offset_round_trips_separately_from_visual_offset() {
+        let mut pc = PlayerConfig::default();
+        pc.judge_settings.apply_calibration(-20, 35);
+
+        let json = serde_json::to_string(&pc).unwrap();
+        assert!(json.contains("\"audioOffset\":35"));
+        let deserialized: PlayerConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.judge_settings.judgetiming, -20);
+        assert_eq!(deserialized.judge_settings.audio_offset, 35);
+
+        pc.judge_settings.apply_calibration(9999, -9999);
+        assert_eq!(pc.judge_settings.judgetiming, JUDGETIMING_MAX);
+        assert_eq!(pc.judge_settings.audio_offset, JUDGETIMING_MIN);
+    }
+
     #[test]
     fn player_config_deserialize_empty_object() {
         let pc: PlayerConfig = serde_json::from_str("{}").unwrap();
@@ -887,6 +1132,33 @@ mod tests {
         assert_eq!(pc.play_settings.gauge, 0);
     }
 
+    #[test]
+    fn player_config_validate_clamps_replay_autosave_policy() {
+        let mut pc = PlayerConfig::default();
+        pc.misc_settings.replay_autosave_policy = 7;
+        pc.validate();
+        assert_eq!(pc.misc_settings.replay_autosave_policy, REPLAY_AUTOSAVE_ASK);
+
+        pc.misc_settings.replay_autosave_policy = -1;
+        pc.validate();
+        assert_eq!(
+            pc.misc_settings.replay_autosave_policy,
+            REPLAY_AUTOSAVE_PER_SLOT
+        );
+    }
+
+    #[test]
+    fn player_config_validate_clamps_ghost_source() {
+        let mut pc = PlayerConfig::default();
+        pc.play_settings.ghost_source = 9;
+        pc.validate();
+        assert_eq!(pc.play_settings.ghost_source, GHOST_REPLAY1 + 3);
+
+        pc.play_settings.ghost_source = -1;
+        pc.validate();
+        assert_eq!(pc.play_settings.ghost_source, GHOST_NONE);
+    }
+
     #[test]
     fn player_config_validate_clamps_random() {
         let mut pc = PlayerConfig::default();
@@ -971,6 +1243,20 @@ mod tests {
         assert_eq!(pc.select_settings.targetid, "MAX");
     }
 
+    #[test]
+    fn select_settings_resolves_custom_rate_target() {
+        let mut pc = PlayerConfig::default();
+        pc.select_settings.targetid = TARGET_CUSTOM_RATE.to_string();
+        pc.select_settings.target_custom_rate = 150.0;
+        pc.validate();
+        assert_eq!(pc.select_settings.resolved_targetid(), "RATE_100");
+
+        pc.select_settings.target_custom_rate = 92.5;
+        assert_eq!(pc.select_settings.resolved_targetid(), "RATE_92.5");
+        pc.select_settings.targetid = "MYBEST".to_string();
+        assert_eq!(pc.select_settings.resolved_targetid(), "MYBEST");
+    }
+
     #[test]
     fn player_config_validate_clamps_longnote_mode() {
         let mut pc = PlayerConfig::default();
@@ -1029,6 +1315,17 @@ mod tests {
         assert_eq!(pc.display_settings.scroll_rate, 0.0);
     }
 
+    #[test]
+    fn player_config_validate_clamps_color_palette() {
+        let mut pc = PlayerConfig::default();
+        pc.display_settings.color_palette = 99;
+        pc.validate();
+        assert_eq!(pc.display_settings.color_palette, COLOR_PALETTE_BLUE_YELLOW);
+        pc.display_settings.color_palette = -1;
+        pc.validate();
+        assert_eq!(pc.display_settings.color_palette, COLOR_PALETTE_SKIN);
+    }
+
     #[test]
     fn player_config_validate_clamps_gauge_auto_shift() {
         let mut pc = PlayerConfig::default();
@@ -1116,6 +1413,24 @@ mod tests {
         assert_eq!(loaded.id, Some("test_player".to_string()));
     }
 
+    #[test]
+    fn player_config_read_unversioned_file_is_migrated() {
+        let dir = tempfile::tempdir().unwrap();
+        let playerpath = dir.path().to_str().unwrap();
+        std::fs::create_dir(dir.path().join("legacy")).unwrap();
+        std::fs::write(
+            dir.path().join("legacy/config_player.json"),
+            r#"{"name":"Legacy","gauge":3}"#,
+        )
+        .unwrap();
+
+        let pc = PlayerConfig::read_player_config(playerpath, "legacy").unwrap();
+
+        assert_eq!(pc.config_version, PLAYER_CONFIG_VERSION);
+        assert_eq!(pc.name, "Legacy");
+        assert_eq!(pc.play_settings.gauge, 3);
+    }
+
     #[test]
     fn player_config_read_missing_creates_default() {
         let dir = tempfile::tempdir().unwrap();
@@ -209,6 +209,16 @@ pub trait MediaAccess {
         // default no-op
     }
 
+    /// Player data gained today.
+    fn today_player_data(&self) -> Option<&PlayerData> {
+        None
+    }
+
+    /// Set player data gained today.
+    fn set_today_player_data(&mut self, _player_data: PlayerData) {
+        // default no-op
+    }
+
     /// Set banner pixmap on BMSResource from raw RGBA8888 data.
     /// Pass None to clear the banner.
     /// Java: PlayerResource.getBMSResource().setBanner(Pixmap)
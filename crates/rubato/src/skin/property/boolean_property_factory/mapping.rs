@@ -108,7 +108,9 @@ pub(super) fn get_boolean_type_property(id: i32) -> Option<Box<dyn BooleanProper
         | OPTION_SELECT_REPLAYDATA
         | OPTION_SELECT_REPLAYDATA2
         | OPTION_SELECT_REPLAYDATA3
-        | OPTION_SELECT_REPLAYDATA4 => Some(Box::new(DelegateBooleanProperty { id })),
+        | OPTION_SELECT_REPLAYDATA4
+        | OPTION_REPLAY_SAVE_PROMPT
+        | OPTION_REPLAY_SAVE_PROMPT_OVERWRITE => Some(Box::new(DelegateBooleanProperty { id })),
         // Select panel/bar type
         OPTION_PANEL1 | OPTION_PANEL2 | OPTION_PANEL3 | OPTION_SONGBAR | OPTION_FOLDERBAR
         | OPTION_GRADEBAR => Some(Box::new(DelegateBooleanProperty { id })),
@@ -154,9 +156,14 @@ pub(super) fn get_boolean_type_property(id: i32) -> Option<Box<dyn BooleanProper
         | OPTION_DRAW_TARGET => Some(Box::new(DelegateBooleanProperty { id })),
         // Result clear/fail
         OPTION_RESULT_CLEAR | OPTION_RESULT_FAIL => Some(Box::new(DelegateBooleanProperty { id })),
+        // Result lamp
+        OPTION_RESULT_FULLCOMBO | OPTION_RESULT_PERFECT | OPTION_RESULT_MAX => {
+            Some(Box::new(DelegateBooleanProperty { id }))
+        }
         // Win/lose/draw
         OPTION_1PWIN | OPTION_2PWIN | OPTION_DRAW => Some(Box::new(DelegateBooleanProperty { id })),
         // IR conditions
+        OPTION_IR_LOADING | OPTION_IR_LOADED => Some(Box::new(DelegateBooleanProperty { id })),
         OPTION_IR_NOPLAYER | OPTION_IR_FAILED | OPTION_IR_BUSY | OPTION_IR_WAITING => {
             Some(Box::new(DelegateBooleanProperty { id }))
         }
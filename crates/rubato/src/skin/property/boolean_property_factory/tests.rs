@@ -290,6 +290,8 @@ fn test_all_boolean_type_ids_return_some() {
         OPTION_SELECT_REPLAYDATA2,
         OPTION_SELECT_REPLAYDATA3,
         OPTION_SELECT_REPLAYDATA4,
+        OPTION_REPLAY_SAVE_PROMPT,
+        OPTION_REPLAY_SAVE_PROMPT_OVERWRITE,
         OPTION_PANEL1,
         OPTION_PANEL2,
         OPTION_PANEL3,
@@ -399,6 +401,9 @@ fn test_all_boolean_type_ids_return_some() {
         OPTION_DRAW_TARGET,
         OPTION_RESULT_CLEAR,
         OPTION_RESULT_FAIL,
+        OPTION_RESULT_FULLCOMBO,
+        OPTION_RESULT_PERFECT,
+        OPTION_RESULT_MAX,
         OPTION_1PWIN,
         OPTION_2PWIN,
         OPTION_DRAW,
@@ -95,6 +95,26 @@ pub(super) static VALUE_TYPES: &[ValueTypeEntry] = &[
         id: ValueId(333),
         name: "player_notes",
     },
+    ValueTypeEntry {
+        id: ValueId(549),
+        name: "player_today_playcount",
+    },
+    ValueTypeEntry {
+        id: ValueId(550),
+        name: "player_today_clear",
+    },
+    ValueTypeEntry {
+        id: ValueId(551),
+        name: "player_today_notes",
+    },
+    ValueTypeEntry {
+        id: ValueId(552),
+        name: "player_today_playtime_hour",
+    },
+    ValueTypeEntry {
+        id: ValueId(553),
+        name: "player_today_playtime_minute",
+    },
     ValueTypeEntry {
         id: ValueId(57),
         name: "volume_system",
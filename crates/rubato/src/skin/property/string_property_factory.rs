@@ -568,6 +568,14 @@ static STRING_TYPES: &[StringTypeEntry] = &[
         id: 1031,
         name: "songhashsha256",
     },
+    StringTypeEntry {
+        id: 1032,
+        name: "songdownloadurl",
+    },
+    StringTypeEntry {
+        id: 1033,
+        name: "loadingfile",
+    },
 ];
 
 /// Delegate StringProperty that reads values from MainState::string_value().
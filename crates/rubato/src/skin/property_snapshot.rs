@@ -169,6 +169,8 @@ pub struct PropertySnapshot {
     // ================================================================
     /// Player statistics (playcount, clear, judge counts, playtime).
     pub player_data: Option<PlayerData>,
+    /// Player statistics gained today (see `PlayDataAccessor::read_today_player_data`).
+    pub today_player_data: Option<PlayerData>,
     /// Current course stage index (0-based).
     pub course_index: usize,
     /// Number of songs in the current course.
@@ -238,6 +240,7 @@ impl Default for PropertySnapshot {
             ranking_clear_types: Vec::new(),
             lane_shuffle_patterns: None,
             player_data: None,
+            today_player_data: None,
             course_index: 0,
             course_song_count: 0,
             is_course_mode: false,
@@ -263,6 +266,7 @@ impl PropertySnapshot {
 
     fn shared_integer_value(&self, id: i32) -> Option<i32> {
         self.player_data_integer(id)
+            .or_else(|| self.today_player_data_integer(id))
             .or_else(|| self.volume_integer(id))
             .or_else(|| self.song_data_integer(id))
             .or_else(|| self.score_property_integer(id))
@@ -294,6 +298,22 @@ impl PropertySnapshot {
         Some(val)
     }
 
+    fn today_player_data_integer(&self, id: i32) -> Option<i32> {
+        let pd = self.today_player_data.as_ref()?;
+        let val = match id {
+            549 => pd.playcount as i32,
+            550 => pd.clear as i32,
+            551 => {
+                let total: i64 = (0..=3).map(|j| pd.judge_count(j)).sum();
+                total.min(i32::MAX as i64) as i32
+            }
+            552 => (pd.playtime / 3600) as i32,
+            553 => ((pd.playtime / 60) % 60) as i32,
+            _ => return None,
+        };
+        Some(val)
+    }
+
     fn volume_integer(&self, id: i32) -> Option<i32> {
         let audio = self.config.as_ref()?.audio.as_ref()?;
         let val = match id {
@@ -350,27 +370,27 @@ impl PropertySnapshot {
 
     fn score_property_integer(&self, id: i32) -> Option<i32> {
         let sp = &self.score_data_property;
-        let has_score = self.score_data.is_some();
+        // The live score the property was last updated with (play), else the stored one
+        let score = sp.score_data().or(self.score_data.as_deref());
+        let has_score = score.is_some();
         let val = match id {
             // EX score
-            71 | 101 | 171 => self.score_data.as_ref().map_or(i32::MIN, |s| s.exscore()),
+            71 | 101 | 171 => score.map_or(i32::MIN, |s| s.exscore()),
             // Max score (notes * 2)
-            72 => self.score_data.as_ref().map_or(i32::MIN, |s| s.notes * 2),
+            72 => score.map_or(i32::MIN, |s| s.notes * 2),
             // Max combo
-            75 => self.score_data.as_ref().map_or(i32::MIN, |s| s.maxcombo),
+            75 | 105 | 174 => score.map_or(i32::MIN, |s| s.maxcombo),
             // Miss count / minbp
-            76 => self.score_data.as_ref().map_or(i32::MIN, |s| s.minbp),
+            76 | 177 => score.map_or(i32::MIN, |s| s.minbp),
             // Judge counts (total)
             80..=84 => {
                 let index = id - 80;
-                self.score_data
-                    .as_ref()
-                    .map_or(i32::MIN, |s| s.judge_count_total(index))
+                score.map_or(i32::MIN, |s| s.judge_count_total(index))
             }
             // Judge count rates (count * 100 / notes)
             85..=89 => {
                 let index = id - 85;
-                self.score_data.as_ref().map_or(i32::MIN, |s| {
+                score.map_or(i32::MIN, |s| {
                     if s.notes > 0 {
                         s.judge_count_total(index) * 100 / s.notes
                     } else {
@@ -412,10 +432,17 @@ impl PropertySnapshot {
             121 | 151 => sp.rivalscore,
             122 | 157 => sp.rivalrate_int,
             123 | 158 => sp.rivalrate_after_dot,
+            150 | 170 => sp.bestscore,
             152 | 172 => sp.nowscore - sp.nowbestscore,
             154 => sp.nextrank,
             183 => sp.bestrate_int,
             184 => sp.bestrate_after_dot,
+            185 => sp.now_ghost_score().unwrap_or(i32::MIN),
+            186 => sp
+                .now_ghost_score()
+                .map_or(i32::MIN, |ghost| sp.nowscore - ghost),
+            187 => sp.nowrivalscore,
+            188 => sp.nowbestscore,
             _ => return None,
         };
         Some(val)
@@ -495,6 +522,9 @@ impl PropertySnapshot {
     }
 
     fn shared_float_value(&self, id: i32) -> Option<f32> {
+        if let Some(val) = self.score_property_float(id) {
+            return Some(val);
+        }
         let audio = self.config.as_ref()?.audio.as_ref();
         let val = match id {
             17 => audio.map_or(DEFAULT_AUDIO_VOLUME, |a| a.systemvolume),
@@ -505,18 +535,27 @@ impl PropertySnapshot {
         Some(val)
     }
 
+    /// Score and pacemaker rates (RateType 110-115), updated on every judge in play.
+    fn score_property_float(&self, id: i32) -> Option<f32> {
+        let sp = &self.score_data_property;
+        let val = match id {
+            110 => sp.rate,
+            111 => sp.nowrate,
+            112 => sp.nowbestscorerate,
+            113 => sp.bestscorerate,
+            114 => sp.nowrivalscorerate,
+            115 => sp.rivalscorerate,
+            _ => return None,
+        };
+        Some(val)
+    }
+
     fn shared_string_value(&self, id: i32) -> Option<String> {
         let song = self.song_data.as_ref()?;
         let val = match id {
             10 => song.metadata.title.clone(),
             11 => song.metadata.subtitle.clone(),
-            12 => {
-                if song.metadata.subtitle.is_empty() {
-                    song.metadata.title.clone()
-                } else {
-                    format!("{} {}", song.metadata.title, song.metadata.subtitle)
-                }
-            }
+            12 => song.display_title(),
             13 => song.metadata.genre.clone(),
             14 => song.metadata.artist.clone(),
             15 => song.metadata.subartist.clone(),
@@ -1173,6 +1212,29 @@ mod tests {
         assert_eq!(snapshot.integer_value(17), i32::MIN);
     }
 
+    #[test]
+    fn shared_today_player_data_integers() {
+        let mut snapshot = PropertySnapshot::new();
+        let mut pd = PlayerData::default();
+        pd.playcount = 12;
+        pd.clear = 9;
+        pd.epg = 700;
+        pd.lgr = 200;
+        pd.ebd = 10;
+        pd.lpr = 50;
+        pd.playtime = 5460; // 1h 31m
+        snapshot.today_player_data = Some(pd);
+
+        assert_eq!(snapshot.integer_value(549), 12);
+        assert_eq!(snapshot.integer_value(550), 9);
+        // POOR is not a hit note
+        assert_eq!(snapshot.integer_value(551), 910);
+        assert_eq!(snapshot.integer_value(552), 1);
+        assert_eq!(snapshot.integer_value(553), 31);
+        // Totals are independent of today's data
+        assert_eq!(snapshot.integer_value(30), i32::MIN);
+    }
+
     #[test]
     fn shared_volume_integers() {
         let mut snapshot = PropertySnapshot::new();
@@ -1243,6 +1305,60 @@ mod tests {
         assert_eq!(snapshot.integer_value(108), 100); // 350 - 250
         // Diff vs best (nowscore - nowbestscore)
         assert_eq!(snapshot.integer_value(152), 70); // 350 - 280
+        // Pacemaker scores at the current note
+        assert_eq!(snapshot.integer_value(187), 250);
+        assert_eq!(snapshot.integer_value(188), 280);
+        // No ghost pacemaker: ghost numbers are hidden
+        assert_eq!(snapshot.integer_value(185), i32::MIN);
+        assert_eq!(snapshot.integer_value(186), i32::MIN);
+
+        snapshot.score_data_property.set_ghost(Some(vec![0; 200]));
+        snapshot.score_data_property.nowghostscore = 360;
+        assert_eq!(snapshot.integer_value(185), 360);
+        assert_eq!(snapshot.integer_value(186), -10); // 350 - 360
+    }
+
+    #[test]
+    fn score_integers_prefer_the_live_score() {
+        let mut snapshot = PropertySnapshot::new();
+        let mut best = ScoreData::default();
+        best.judge_counts.epg = 90;
+        best.notes = 100;
+        snapshot.score_data = Some(Box::new(best));
+        snapshot.score_data_property.set_target_score(180, 150, 100);
+
+        let mut live = ScoreData::default();
+        live.judge_counts.epg = 10;
+        live.maxcombo = 10;
+        live.notes = 100;
+        snapshot
+            .score_data_property
+            .update_score_with_notes(Some(&live), 10);
+
+        assert_eq!(snapshot.integer_value(101), 20);
+        assert_eq!(snapshot.integer_value(105), 10);
+        assert_eq!(snapshot.integer_value(150), 180);
+        assert_eq!(snapshot.integer_value(102), 100); // 20 of 10 notes so far
+        // EX short of the next rank (F, 3/27) at the notes judged so far
+        assert_eq!(snapshot.integer_value(154), 1);
+    }
+
+    #[test]
+    fn shared_score_property_rates() {
+        let mut snapshot = PropertySnapshot::new();
+        snapshot.score_data_property.rate = 0.9;
+        snapshot.score_data_property.nowrate = 0.95;
+        snapshot.score_data_property.nowbestscorerate = 0.4;
+        snapshot.score_data_property.bestscorerate = 0.8;
+        snapshot.score_data_property.nowrivalscorerate = 0.45;
+        snapshot.score_data_property.rivalscorerate = 0.9;
+
+        assert_eq!(snapshot.float_value(110), 0.9);
+        assert_eq!(snapshot.float_value(111), 0.95);
+        assert_eq!(snapshot.float_value(112), 0.4);
+        assert_eq!(snapshot.float_value(113), 0.8);
+        assert_eq!(snapshot.float_value(114), 0.45);
+        assert_eq!(snapshot.float_value(115), 0.9);
     }
 
     #[test]
@@ -8,8 +8,9 @@ use base64::engine::general_purpose::URL_SAFE;
 use flate2::Compression;
 use flate2::read::GzDecoder;
 use flate2::write::GzEncoder;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
+use crate::skin::groove_gauge::GaugeTransition;
 use crate::skin::key_input_log::KeyInputLog;
 use crate::skin::pattern_modify_log::PatternModifyLog;
 use crate::skin::play_config::PlayConfig;
@@ -22,12 +23,20 @@ pub struct ReplayData {
     pub player: Option<String>,
     pub sha256: Option<String>,
     pub mode: i32,
+    #[serde(deserialize_with = "null_as_default")]
     pub keylog: Vec<KeyInputLog>,
     pub keyinput: Option<String>,
+    /// Key input log in the compact delta encoding (see `shrink_compact`)
+    #[serde(rename = "keyinputCompact", skip_serializing_if = "Option::is_none")]
+    pub keyinput_compact: Option<String>,
     pub gauge: i32,
     pub pattern: Option<Vec<PatternModifyLog>>,
     #[serde(rename = "laneShufflePattern")]
     pub lane_shuffle_pattern: Option<Vec<Vec<i32>>>,
+    /// Lane order locked by the random trainer, replacing the 1P RANDOM seed
+    #[serde(rename = "laneOrder", skip_serializing_if = "Option::is_none")]
+    pub lane_order: Option<String>,
+    #[serde(deserialize_with = "null_as_default")]
     pub rand: Vec<i32>,
     pub date: i64,
     #[serde(rename = "sevenToNinePattern")]
@@ -38,6 +47,41 @@ pub struct ReplayData {
     pub randomoption2seed: i64,
     pub doubleoption: i32,
     pub config: Option<PlayConfig>,
+    /// Every change of the active gauge during play
+    #[serde(rename = "gaugeHistory", skip_serializing_if = "Vec::is_empty")]
+    pub gauge_history: Vec<GaugeTransition>,
+    /// Value of every gauge type sampled each 500ms, as drawn by the result gauge graph
+    #[serde(
+        rename = "gaugeLog",
+        deserialize_with = "null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub gauge_log: Vec<Vec<f32>>,
+    /// Judge of every note in play order, used to replay the run as a ghost pacemaker
+    #[serde(
+        deserialize_with = "null_as_default",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub ghost: Vec<i32>,
+    /// EX score of the run, stored for the replay slot overlay
+    #[serde(skip_serializing_if = "is_zero")]
+    pub exscore: i32,
+    /// Clear type id of the run, stored for the replay slot overlay
+    #[serde(skip_serializing_if = "is_zero")]
+    pub clear: i32,
+}
+
+fn is_zero(value: &i32) -> bool {
+    *value == 0
+}
+
+/// beatoraja leaves unset arrays as `null` in its .brd files; read them as empty.
+fn null_as_default<'de, D, T>(deserializer: D) -> std::result::Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
 }
 
 impl Default for ReplayData {
@@ -48,9 +92,11 @@ impl Default for ReplayData {
             mode: 0,
             keylog: Vec::new(),
             keyinput: None,
+            keyinput_compact: None,
             gauge: 0,
             pattern: None,
             lane_shuffle_pattern: None,
+            lane_order: None,
             rand: Vec::new(),
             date: 0,
             seven_to_nine_pattern: 0,
@@ -60,10 +106,59 @@ impl Default for ReplayData {
             randomoption2seed: -1,
             doubleoption: 0,
             config: None,
+            gauge_history: Vec::new(),
+            gauge_log: Vec::new(),
+            ghost: Vec::new(),
+            exscore: 0,
+            clear: 0,
         }
     }
 }
 
+/// Summary of a stored replay slot: when it was recorded, how it scored and
+/// which options it was played with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReplaySlotInfo {
+    /// Recording date in unix seconds
+    pub date: i64,
+    pub exscore: i32,
+    pub clear: i32,
+    pub randomoption: i32,
+    pub gauge: i32,
+}
+
+impl ReplaySlotInfo {
+    /// Skin number value of the replay slot overlay for `id`
+    /// (NUMBER_REPLAY1_EXSCORE..=NUMBER_REPLAY4_DATE_MINUTE).
+    ///
+    /// Returns None for ids outside the overlay range and `i32::MIN` for
+    /// empty slots so that skins hide them.
+    pub fn integer_value(slots: &[Option<ReplaySlotInfo>], id: i32) -> Option<i32> {
+        use crate::skin::skin_property::{NUMBER_REPLAY1_EXSCORE, NUMBER_REPLAY4_DATE_MINUTE};
+        use chrono::{Datelike, TimeZone, Timelike};
+
+        if !(NUMBER_REPLAY1_EXSCORE..=NUMBER_REPLAY4_DATE_MINUTE).contains(&id) {
+            return None;
+        }
+        let offset = id - NUMBER_REPLAY1_EXSCORE;
+        let Some(info) = slots.get((offset % 4) as usize).copied().flatten() else {
+            return Some(i32::MIN);
+        };
+        let date = || chrono::Local.timestamp_opt(info.date, 0).single();
+        Some(match offset / 4 {
+            0 => info.exscore,
+            1 => info.clear,
+            2 => info.randomoption,
+            3 => info.gauge,
+            4 => date().map_or(i32::MIN, |d| d.year()),
+            5 => date().map_or(i32::MIN, |d| d.month() as i32),
+            6 => date().map_or(i32::MIN, |d| d.day() as i32),
+            7 => date().map_or(i32::MIN, |d| d.hour() as i32),
+            _ => date().map_or(i32::MIN, |d| d.minute() as i32),
+        })
+    }
+}
+
 impl ReplayData {
     pub fn new() -> Self {
         Self {
@@ -73,6 +168,17 @@ impl ReplayData {
         }
     }
 
+    /// Metadata shown for this replay in the replay slot overlay.
+    pub fn slot_info(&self) -> ReplaySlotInfo {
+        ReplaySlotInfo {
+            date: self.date,
+            exscore: self.exscore,
+            clear: self.clear,
+            randomoption: self.randomoption,
+            gauge: self.gauge,
+        }
+    }
+
     pub fn shrink(&mut self) {
         if self.keylog.is_empty() {
             return;
@@ -111,6 +217,30 @@ impl ReplayData {
         }
     }
 
+    /// Pack the key input log into `keyinput_compact`.
+    ///
+    /// Each event is stored as the zigzag varint of its time delta from the
+    /// previous event followed by the varint of `keycode << 1 | pressed`, so
+    /// a dense chart takes 3-4 bytes per event before gzip instead of the 9
+    /// of the beatoraja `keyinput` encoding written by `shrink`. beatoraja
+    /// does not read this field.
+    pub fn shrink_compact(&mut self) {
+        if self.keylog.is_empty() {
+            return;
+        }
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        let packed = encode_compact_keylog(&self.keylog);
+        match encoder.write_all(&packed).and_then(|()| encoder.finish()) {
+            Ok(compressed) => {
+                self.keyinput_compact = Some(URL_SAFE.encode(&compressed));
+                self.keylog = Vec::new();
+            }
+            Err(e) => {
+                log::warn!("Failed to compress replay key input data: {}", e);
+            }
+        }
+    }
+
     /// Read a single ReplayData from a .brd file (gzip-compressed JSON).
     /// Calls validate() after deserialization, matching Java PlayDataAccessor.readReplayData().
     pub fn read_brd(path: &Path) -> Result<ReplayData> {
@@ -125,12 +255,13 @@ impl ReplayData {
     }
 
     /// Write a single ReplayData to a .brd file (gzip-compressed JSON).
-    /// Calls shrink() before serialization, matching Java PlayDataAccessor.wrireReplayData().
+    /// Packs the key log with shrink_compact() before serialization, where Java
+    /// PlayDataAccessor.wrireReplayData() calls shrink().
     pub fn write_brd(&mut self, path: &Path) -> Result<()> {
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
-        self.shrink();
+        self.shrink_compact();
         let file = fs::File::create(path)?;
         let mut encoder = GzEncoder::new(BufWriter::new(file), Compression::default());
         serde_json::to_writer_pretty(&mut encoder, &self)?;
@@ -154,13 +285,13 @@ impl ReplayData {
     }
 
     /// Write a course ReplayData array to a .brd file (gzip-compressed JSON array).
-    /// Calls shrink() on each element, matching Java PlayDataAccessor.wrireReplayData(ReplayData[], ...).
+    /// Calls shrink_compact() on each element, like write_brd().
     pub fn write_brd_course(rds: &mut [ReplayData], path: &Path) -> Result<()> {
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
         for rd in rds.iter_mut() {
-            rd.shrink();
+            rd.shrink_compact();
         }
         let file = fs::File::create(path)?;
         let mut encoder = GzEncoder::new(BufWriter::new(file), Compression::default());
@@ -170,12 +301,83 @@ impl ReplayData {
     }
 }
 
+/// Encode a key log as (zigzag varint time delta, varint keycode/pressed) pairs.
+fn encode_compact_keylog(keylog: &[KeyInputLog]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(keylog.len() * 4);
+    let mut prev = 0i64;
+    for log in keylog {
+        let delta = log.time.wrapping_sub(prev);
+        prev = log.time;
+        write_varint(&mut out, ((delta << 1) ^ (delta >> 63)) as u64);
+        write_varint(
+            &mut out,
+            ((log.keycode.max(0) as u64) << 1) | u64::from(log.pressed),
+        );
+    }
+    out
+}
+
+/// Decode `encode_compact_keylog` output. Returns None for truncated data.
+fn decode_compact_keylog(data: &[u8]) -> Option<Vec<KeyInputLog>> {
+    let mut keylog = Vec::new();
+    let mut pos = 0;
+    let mut time = 0i64;
+    while pos < data.len() {
+        let zigzag = read_varint(data, &mut pos)?;
+        let key = read_varint(data, &mut pos)?;
+        time = time.wrapping_add(((zigzag >> 1) as i64) ^ -((zigzag & 1) as i64));
+        keylog.push(KeyInputLog {
+            time,
+            keycode: i32::try_from(key >> 1).ok()?,
+            pressed: key & 1 == 1,
+        });
+    }
+    Some(keylog)
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn read_varint(data: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *data.get(*pos)?;
+        *pos += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
 impl Validatable for ReplayData {
     fn validate(&mut self) -> bool {
-        let had_keyinput = self.keyinput.is_some();
+        let had_keyinput = self.keyinput.is_some() || self.keyinput_compact.is_some();
         let mut decompression_ok = true;
 
-        if let Some(keyinput) = self.keyinput.take()
+        if let Some(keyinput) = self.keyinput_compact.take() {
+            let decoded = URL_SAFE
+                .decode(keyinput.as_bytes())
+                .ok()
+                .and_then(|decoded| {
+                    // Cap decompressed size to 64 MB to guard against decompression bombs.
+                    let mut gz = GzDecoder::new(&decoded[..]).take(64 * 1024 * 1024);
+                    let mut decompressed = Vec::new();
+                    gz.read_to_end(&mut decompressed).ok()?;
+                    decode_compact_keylog(&decompressed)
+                });
+            match decoded {
+                Some(keylog) => self.keylog = keylog,
+                None => decompression_ok = false,
+            }
+            self.keyinput = None;
+        } else if let Some(keyinput) = self.keyinput.take()
             && let Ok(decoded) = URL_SAFE.decode(keyinput.as_bytes())
         {
             // Cap decompressed size to 64 MB to guard against decompression bombs.
@@ -215,7 +417,7 @@ impl Validatable for ReplayData {
 
         self.keylog.retain(|log| log.validate());
         if let Some(ref mut pattern) = self.pattern {
-            pattern.retain(|p| p.validate());
+            pattern.retain_mut(|p| p.validate());
         }
         // Accept zero-event replays (no keyinput, no keylog). Only reject if keyinput
         // was present but decompression failed or produced no events (corrupt data).
@@ -231,6 +433,47 @@ impl Validatable for ReplayData {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_replay_slot_info_integer_values() {
+        use chrono::{Datelike, TimeZone};
+
+        let mut rd = ReplayData::new();
+        rd.date = 1_700_000_000;
+        rd.exscore = 1234;
+        rd.clear = 7;
+        rd.randomoption = 2;
+        rd.gauge = 3;
+        let slots = [None, Some(rd.slot_info()), None, None];
+        let value = |id| ReplaySlotInfo::integer_value(&slots, id);
+
+        // Slot 2 of each field block
+        assert_eq!(value(471), Some(1234));
+        assert_eq!(value(475), Some(7));
+        assert_eq!(value(479), Some(2));
+        assert_eq!(value(483), Some(3));
+        let date = chrono::Local.timestamp_opt(1_700_000_000, 0).unwrap();
+        assert_eq!(value(487), Some(date.year()));
+        assert_eq!(value(491), Some(date.month() as i32));
+        // Empty slot and ids outside the overlay range
+        assert_eq!(value(470), Some(i32::MIN));
+        assert_eq!(value(469), None);
+        assert_eq!(value(506), None);
+    }
+
+    #[test]
+    fn test_replay_slot_metadata_round_trip_and_omitted_when_unset() {
+        let mut rd = ReplayData::new();
+        let json = serde_json::to_string(&rd).unwrap();
+        assert!(!json.contains("exscore"));
+
+        rd.exscore = 500;
+        rd.clear = 4;
+        let json = serde_json::to_string(&rd).unwrap();
+        let back: ReplayData = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.exscore, 500);
+        assert_eq!(back.clear, 4);
+    }
+
     #[test]
     fn test_replay_data_new() {
         let rd = ReplayData::new();
@@ -270,6 +513,51 @@ mod tests {
         assert_eq!(rd.randomoption2seed, -1);
     }
 
+    #[test]
+    fn test_replay_data_gauge_log_round_trip() {
+        let mut rd = ReplayData::new();
+        assert!(!serde_json::to_string(&rd).unwrap().contains("gaugeLog"));
+
+        rd.gauge_log = vec![vec![20.0, 24.5], vec![100.0, 92.0]];
+        let json = serde_json::to_string(&rd).unwrap();
+        assert!(json.contains("\"gaugeLog\":[[20.0,24.5],[100.0,92.0]]"));
+        let back: ReplayData = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.gauge_log, rd.gauge_log);
+    }
+
+    #[test]
+    fn test_replay_data_gauge_history_round_trip() {
+        let mut rd = ReplayData::new();
+        let json = serde_json::to_string(&rd).unwrap();
+        assert!(!json.contains("gaugeHistory"));
+
+        rd.gauge_history = vec![GaugeTransition {
+            time: 1_500_000,
+            judge: 3,
+            delta: -6.0,
+            gauge_type: 3,
+            value: 94.0,
+        }];
+        let json = serde_json::to_string(&rd).unwrap();
+        assert!(json.contains("\"gaugeHistory\":[{\"time\":1500000"));
+        let back: ReplayData = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.gauge_history, rd.gauge_history);
+    }
+
+    #[test]
+    fn test_replay_data_ghost_round_trip() {
+        let mut rd = ReplayData::new();
+        assert!(!serde_json::to_string(&rd).unwrap().contains("ghost"));
+
+        rd.ghost = vec![0, 1, 4, 0];
+        let json = serde_json::to_string(&rd).unwrap();
+        let back: ReplayData = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.ghost, rd.ghost);
+
+        let back: ReplayData = serde_json::from_str(r#"{"ghost":null}"#).unwrap();
+        assert!(back.ghost.is_empty());
+    }
+
     #[test]
     fn test_replay_data_serde_round_trip() {
         let mut rd = ReplayData::new();
@@ -357,22 +645,29 @@ mod tests {
     fn test_replay_data_with_pattern() {
         let mut rd = ReplayData::new();
         rd.pattern = Some(vec![
-            PatternModifyLog {
-                old_lane: 3,
-                new_lane: 0,
-            },
-            PatternModifyLog {
-                old_lane: 2,
-                new_lane: 1,
-            },
+            PatternModifyLog::new(0.0, vec![3, 0, 1, 2]),
+            PatternModifyLog::new(1.5, vec![1, 2, 0, 3]),
         ]);
 
         let json = serde_json::to_string(&rd).unwrap();
-        let deserialized: ReplayData = serde_json::from_str(&json).unwrap();
+        let mut deserialized: ReplayData = serde_json::from_str(&json).unwrap();
+        assert!(deserialized.validate());
         let pattern = deserialized.pattern.unwrap();
         assert_eq!(pattern.len(), 2);
-        assert_eq!(pattern[0].old_lane, 3);
-        assert_eq!(pattern[0].new_lane, 0);
+        assert_eq!(pattern[0].section, 0.0);
+        assert_eq!(pattern[0].modify, Some(vec![3, 0, 1, 2]));
+        assert_eq!(pattern[1].section, 1.5);
+    }
+
+    #[test]
+    fn test_replay_data_validate_drops_invalid_pattern_entries() {
+        let mut rd = ReplayData::new();
+        rd.pattern = Some(vec![
+            PatternModifyLog::new(0.0, vec![1, 0]),
+            PatternModifyLog::default(),
+        ]);
+        assert!(rd.validate());
+        assert_eq!(rd.pattern.unwrap().len(), 1);
     }
 
     #[test]
@@ -673,11 +968,108 @@ mod tests {
             rd.keylog.is_empty(),
             "keylog should be emptied after shrink"
         );
-        assert!(rd.keyinput.is_some(), "keyinput should be set after shrink");
+        assert!(
+            rd.keyinput_compact.is_some(),
+            "keyinput_compact should be set after shrink"
+        );
+        assert!(rd.keyinput.is_none());
 
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn shrink_compact_roundtrip_with_negative_deltas() {
+        let events = [(5_000, 3, true), (4_000, 3, false), (-200, 0, true)];
+        let mut rd = ReplayData::new();
+        rd.keylog = events
+            .iter()
+            .map(|&(time, keycode, pressed)| KeyInputLog {
+                time,
+                keycode,
+                pressed,
+            })
+            .collect();
+
+        rd.shrink_compact();
+        assert!(rd.keylog.is_empty());
+        assert!(rd.keyinput.is_none());
+
+        assert!(rd.validate());
+        // validate() drops the negative-time entry, as with the beatoraja encoding
+        assert_eq!(rd.keylog.len(), 2);
+        assert_eq!(rd.keylog[0].time, 5_000);
+        assert_eq!(rd.keylog[0].keycode, 3);
+        assert_eq!(rd.keylog[1].time, 4_000);
+        assert!(!rd.keylog[1].pressed);
+    }
+
+    #[test]
+    fn shrink_compact_is_smaller_than_beatoraja_encoding() {
+        let keylog: Vec<KeyInputLog> = (0..2000)
+            .map(|i| KeyInputLog {
+                time: 1_000_000 + i * 37_531 + (i * i) % 977,
+                keycode: (i % 8) as i32,
+                pressed: i % 2 == 0,
+            })
+            .collect();
+        let mut compact = ReplayData::new();
+        compact.keylog = keylog.clone();
+        compact.shrink_compact();
+        let mut legacy = ReplayData::new();
+        legacy.keylog = keylog.clone();
+        legacy.shrink();
+
+        let compact_len = compact.keyinput_compact.as_ref().unwrap().len();
+        let legacy_len = legacy.keyinput.as_ref().unwrap().len();
+        assert!(
+            compact_len * 3 < legacy_len * 2,
+            "compact {} vs legacy {}",
+            compact_len,
+            legacy_len
+        );
+        assert!(compact.validate());
+        assert_eq!(compact.keylog.len(), keylog.len());
+        assert_eq!(compact.keylog[1999].time, keylog[1999].time);
+    }
+
+    #[test]
+    fn truncated_compact_keyinput_fails_validation() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        // Time delta varint with continuation bit set but no following byte
+        encoder.write_all(&[0x80]).unwrap();
+        let mut rd = ReplayData::new();
+        rd.keyinput_compact = Some(URL_SAFE.encode(encoder.finish().unwrap()));
+
+        assert!(!rd.validate());
+    }
+
+    #[test]
+    fn brd_reads_legacy_keyinput_and_json_keylog() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = KeyInputLog {
+            time: 1234,
+            keycode: 2,
+            pressed: true,
+        };
+        for (name, shrink) in [("legacy.brd", true), ("json.brd", false)] {
+            let mut rd = ReplayData::new();
+            rd.keylog = vec![log.clone()];
+            if shrink {
+                rd.shrink();
+            }
+            let path = dir.path().join(name);
+            let file = std::fs::File::create(&path).unwrap();
+            let mut encoder = GzEncoder::new(file, Compression::default());
+            serde_json::to_writer(&mut encoder, &rd).unwrap();
+            encoder.finish().unwrap();
+
+            let read = ReplayData::read_brd(&path).unwrap();
+            assert_eq!(read.keylog.len(), 1, "{}", name);
+            assert_eq!(read.keylog[0].time, 1234);
+            assert_eq!(read.keylog[0].keycode, 2);
+        }
+    }
+
     #[test]
     fn shrink_single_entry() {
         let mut rd = ReplayData::new();
@@ -814,6 +1206,61 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn test_read_brd_written_by_beatoraja() {
+        // Layout produced by beatoraja's PlayDataAccessor: keylog/rand left null,
+        // key input packed into keyinput, pattern log and config snapshot inline.
+        let json = r#"{
+            "player": "beatoraja",
+            "sha256": "abc",
+            "mode": 0,
+            "keylog": null,
+            "keyinput": "H4sIAAAAAAAA_2NkgAKWBW2MYMZ_B15mMOPPg8ksYAa7wkJ2MOPnAXVOMAMAiTDqgzYAAAA=",
+            "gauge": 3,
+            "pattern": [{"section": 4.0, "modify": [1, 0, 2, 3, 4, 5, 6, 7]}],
+            "rand": null,
+            "date": 1700000000,
+            "randomoption": 2,
+            "randomoptionseed": 1234,
+            "config": {"hispeed": 2.5, "duration": 600, "enableConstant": true, "judgetype": "Duration"},
+            "unknownFutureField": 1
+        }"#;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("beatoraja.brd");
+        let mut encoder = GzEncoder::new(fs::File::create(&path).unwrap(), Compression::default());
+        encoder.write_all(json.as_bytes()).unwrap();
+        encoder.finish().unwrap();
+
+        let rd = ReplayData::read_brd(&path).unwrap();
+        assert_eq!(rd.player.as_deref(), Some("beatoraja"));
+        assert!(rd.rand.is_empty());
+        assert_eq!(rd.keylog.len(), 6);
+        assert_eq!(rd.keylog[1].time, 100_000);
+        assert_eq!(rd.keylog[1].keycode, 3);
+        assert!(rd.keylog[1].pressed);
+        assert!(!rd.keylog[5].pressed);
+        let pattern = rd.pattern.as_ref().unwrap();
+        assert_eq!(pattern[0].section, 4.0);
+        let config = rd.config.as_ref().unwrap();
+        assert_eq!(config.hispeed, 2.5);
+        assert!(config.enable_constant);
+        assert_eq!(config.judgetype, "Duration");
+    }
+
+    #[test]
+    fn test_key_input_log_uses_java_field_name() {
+        let log = KeyInputLog {
+            time: 1500,
+            keycode: 2,
+            pressed: true,
+        };
+        let json = serde_json::to_string(&log).unwrap();
+        assert_eq!(json, r#"{"presstime":1500,"keycode":2,"pressed":true}"#);
+        let legacy: KeyInputLog =
+            serde_json::from_str(r#"{"time":1500,"keycode":2,"pressed":true}"#).unwrap();
+        assert_eq!(legacy.time, 1500);
+    }
+
     #[test]
     fn test_serde_produces_camel_case_field_names() {
         let mut rd = ReplayData::new();
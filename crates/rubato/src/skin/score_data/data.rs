@@ -107,6 +107,56 @@ impl ScoreData {
             .saturating_add(jc.lgr)
     }
 
+    /// Highest reachable EX score for this chart.
+    pub fn max_exscore(&self) -> i32 {
+        self.notes.saturating_mul(2)
+    }
+
+    /// EX score a target would have at `passnotes` of `notes` when earned at an even pace.
+    pub fn pace_score(target: i32, passnotes: i32, notes: i32) -> i32 {
+        if notes <= 0 {
+            0
+        } else {
+            (target as i64 * passnotes as i64 / notes as i64) as i32
+        }
+    }
+
+    /// EX score at the end of the chart if the current pace is kept.
+    pub fn projected_exscore(&self) -> i32 {
+        if self.passnotes <= 0 {
+            0
+        } else {
+            (self.exscore() as i64 * self.notes as i64 / self.passnotes as i64) as i32
+        }
+    }
+
+    /// Current EX score minus the target's score at the same point.
+    /// Positive when ahead of the target.
+    pub fn pace_diff(&self, target: i32) -> i32 {
+        self.exscore() - Self::pace_score(target, self.passnotes, self.notes)
+    }
+
+    /// Average EX score still needed per remaining note to reach `target`.
+    /// Values above 2.0 mean the target can no longer be reached; `None` once
+    /// no notes remain.
+    pub fn required_per_remaining_note(&self, target: i32) -> Option<f32> {
+        let remaining = self.notes - self.passnotes;
+        if remaining <= 0 {
+            return None;
+        }
+        Some((target - self.exscore()).max(0) as f32 / remaining as f32)
+    }
+
+    /// Number of FAST judges: GREAT to MISS hit early (PGREAT is not counted).
+    pub fn fast_count(&self) -> i32 {
+        (1..=5).map(|judge| self.judge_count(judge, true)).sum()
+    }
+
+    /// Number of SLOW judges: GREAT to MISS hit late (PGREAT is not counted).
+    pub fn slow_count(&self) -> i32 {
+        (1..=5).map(|judge| self.judge_count(judge, false)).sum()
+    }
+
     pub fn judge_count_total(&self, judge: i32) -> i32 {
         self.judge_count(judge, true) + self.judge_count(judge, false)
     }
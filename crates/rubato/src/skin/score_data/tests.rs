@@ -90,6 +90,42 @@ fn test_exscore_calculation() {
     assert_eq!(sd.exscore(), 350);
 }
 
+#[test]
+fn test_pace_apis_midway() {
+    let mut sd = ScoreData::default();
+    sd.notes = 1000;
+    sd.passnotes = 250;
+    sd.judge_counts.epg = 200;
+    sd.judge_counts.egr = 50;
+    // exscore = 450 after a quarter of the chart
+    assert_eq!(sd.max_exscore(), 2000);
+    assert_eq!(sd.projected_exscore(), 1800);
+    assert_eq!(ScoreData::pace_score(1600, 250, 1000), 400);
+    assert_eq!(sd.pace_diff(1600), 50);
+    assert_eq!(sd.pace_diff(2000), -50);
+    // (1600 - 450) / 750 remaining notes
+    let needed = sd.required_per_remaining_note(1600).unwrap();
+    assert!((needed - 1150.0 / 750.0).abs() < 1e-6);
+    assert_eq!(sd.required_per_remaining_note(400), Some(0.0));
+    // 1550 more over 750 notes is beyond an all-PGREAT finish
+    assert!(sd.required_per_remaining_note(2000).unwrap() > 2.0);
+}
+
+#[test]
+fn test_pace_apis_edges() {
+    let mut sd = ScoreData::default();
+    assert_eq!(sd.projected_exscore(), 0);
+    assert_eq!(ScoreData::pace_score(100, 10, 0), 0);
+    assert_eq!(sd.required_per_remaining_note(100), None);
+
+    sd.notes = 10;
+    sd.passnotes = 10;
+    sd.judge_counts.lpg = 10;
+    assert_eq!(sd.projected_exscore(), 20);
+    assert_eq!(sd.pace_diff(15), 5);
+    assert_eq!(sd.required_per_remaining_note(20), None);
+}
+
 #[test]
 fn test_judge_count() {
     let mut sd = ScoreData::default();
@@ -411,6 +447,7 @@ fn test_update_better_avgjudge_copies_all_timing_stats() {
             avg: 400,
             total_avg: 300,
             stddev: 200,
+            ..TimingStats::default()
         },
         ..ScoreData::default()
     };
@@ -422,6 +459,7 @@ fn test_update_better_avgjudge_copies_all_timing_stats() {
             avg: 80,
             total_avg: 90,
             stddev: 50,
+            ..TimingStats::default()
         },
         ..ScoreData::default()
     };
@@ -443,6 +481,7 @@ fn test_update_worse_avgjudge_preserves_all_timing_stats() {
             avg: 80,
             total_avg: 90,
             stddev: 50,
+            ..TimingStats::default()
         },
         ..ScoreData::default()
     };
@@ -454,6 +493,7 @@ fn test_update_worse_avgjudge_preserves_all_timing_stats() {
             avg: 400,
             total_avg: 300,
             stddev: 200,
+            ..TimingStats::default()
         },
         ..ScoreData::default()
     };
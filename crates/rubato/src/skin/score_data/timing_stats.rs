@@ -11,6 +11,10 @@ pub struct TimingStats {
     #[serde(rename = "totalAvg")]
     pub total_avg: i64,
     pub stddev: i64,
+    /// Average hit offset (us, + is early) of PGREAT, GREAT, GOOD and BAD.
+    /// i64::MAX for a judge without hits. Not stored in the score database.
+    #[serde(rename = "judgeOffsets")]
+    pub judge_offsets: [i64; 4],
 }
 
 impl Default for TimingStats {
@@ -21,6 +25,7 @@ impl Default for TimingStats {
             avg: i64::MAX,
             total_avg: 0,
             stddev: i64::MAX,
+            judge_offsets: [i64::MAX; 4],
         }
     }
 }
@@ -40,6 +40,9 @@ pub struct ScoreDataProperty {
     pub rival_ghost: Option<Vec<i32>>,
     pub use_best_ghost: bool,
     pub use_rival_ghost: bool,
+    /// Per-note judges of the replay or score log raced as a ghost pacemaker
+    pub ghost: Option<Vec<i32>>,
+    pub nowghostscore: i32,
 
     pub totalnotes: i32,
 }
@@ -78,6 +81,8 @@ impl Default for ScoreDataProperty {
             rival_ghost: None,
             use_best_ghost: false,
             use_rival_ghost: false,
+            ghost: None,
+            nowghostscore: 0,
             totalnotes: 0,
         }
     }
@@ -105,8 +110,7 @@ impl ScoreDataProperty {
         } else {
             exscore as f32 / (totalnotes as f32 * 2.0)
         };
-        self.rivalrate_int = (self.rivalscorerate * 100.0) as i32;
-        self.rivalrate_after_dot = ((self.rivalscorerate * 10000.0) as i32) % 100;
+        (self.rivalrate_int, self.rivalrate_after_dot) = rate_digits(exscore, totalnotes);
     }
 
     pub fn update_score_with_notes(&mut self, score: Option<&ScoreData>, notes: i32) {
@@ -155,15 +159,21 @@ impl ScoreDataProperty {
         } else {
             exscore as f32 / (totalnotes as f32 * 2.0)
         };
-        self.rate_int = (self.rate * 100.0) as i32;
-        self.rate_after_dot = ((self.rate * 10000.0) as i32) % 100;
+        (self.rate_int, self.rate_after_dot) = if totalnotes == 0 {
+            (100, 0)
+        } else {
+            rate_digits(exscore, totalnotes)
+        };
         self.nowrate = if notes == 0 {
             1.0f32
         } else {
             exscore as f32 / (notes as f32 * 2.0)
         };
-        self.nowrate_int = (self.nowrate * 100.0) as i32;
-        self.nowrate_after_dot = ((self.nowrate * 10000.0) as i32) % 100;
+        (self.nowrate_int, self.nowrate_after_dot) = if notes == 0 {
+            (100, 0)
+        } else {
+            rate_digits(exscore, notes)
+        };
         self.nextrank = i32::MIN;
         let rank_len = self.rank.len();
         for (i, rank) in self.rank.iter_mut().enumerate() {
@@ -195,11 +205,7 @@ impl ScoreDataProperty {
                 self.nowbestscore as f32 / (totalnotes as f32 * 2.0)
             };
         } else {
-            self.nowbestscore = if totalnotes == 0 {
-                0
-            } else {
-                (self.bestscore as i64 * notes as i64 / totalnotes as i64) as i32
-            };
+            self.nowbestscore = ScoreData::pace_score(self.bestscore, notes, totalnotes);
             self.nowbestscorerate = if totalnotes == 0 {
                 0.0
             } else {
@@ -220,11 +226,7 @@ impl ScoreDataProperty {
                 self.nowrivalscore as f32 / (totalnotes as f32 * 2.0)
             };
         } else {
-            self.nowrivalscore = if totalnotes == 0 {
-                0
-            } else {
-                (self.rivalscore as i64 * notes as i64 / totalnotes as i64) as i32
-            };
+            self.nowrivalscore = ScoreData::pace_score(self.rivalscore, notes, totalnotes);
             self.nowrivalscorerate = if totalnotes == 0 {
                 0.0
             } else {
@@ -232,6 +234,12 @@ impl ScoreDataProperty {
                     / (totalnotes as f32 * totalnotes as f32 * 2.0)
             };
         }
+        if let Some(ref ghost) = self.ghost {
+            let end = notes.min(ghost.len() as i32);
+            for i in self.previous_notes..end {
+                self.nowghostscore += Self::get_ex_score(ghost[i as usize]);
+            }
+        }
         self.previous_notes = notes;
     }
 
@@ -253,8 +261,8 @@ impl ScoreDataProperty {
             self.rivalrate_after_dot = 0;
         } else {
             self.rivalscorerate = rivalscore as f32 / (self.totalnotes as f32 * 2.0);
-            self.rivalrate_int = (self.rivalscorerate * 100.0) as i32;
-            self.rivalrate_after_dot = ((self.rivalscorerate * 10000.0) as i32) % 100;
+            (self.rivalrate_int, self.rivalrate_after_dot) =
+                rate_digits(rivalscore, self.totalnotes);
         }
     }
 
@@ -276,6 +284,7 @@ impl ScoreDataProperty {
         self.nowbestscorerate = 0.0;
         self.nowrivalscore = 0;
         self.nowrivalscorerate = 0.0;
+        self.nowghostscore = 0;
         self.previous_notes = 0;
 
         self.bestscore = bestscore;
@@ -295,15 +304,13 @@ impl ScoreDataProperty {
             self.rivalrate_after_dot = 0;
         } else {
             self.bestscorerate = bestscore as f32 / (totalnotes as f32 * 2.0);
-            self.bestrate_int = (self.bestscorerate * 100.0) as i32;
-            self.bestrate_after_dot = ((self.bestscorerate * 10000.0) as i32) % 100;
+            (self.bestrate_int, self.bestrate_after_dot) = rate_digits(bestscore, totalnotes);
             self.rivalscorerate = rivalscore as f32 / (totalnotes as f32 * 2.0);
             let bestrank_len = self.bestrank.len();
             for (i, bestrank) in self.bestrank.iter_mut().enumerate() {
                 *bestrank = self.bestscorerate >= 1f32 * i as f32 / bestrank_len as f32;
             }
-            self.rivalrate_int = (self.rivalscorerate * 100.0) as i32;
-            self.rivalrate_after_dot = ((self.rivalscorerate * 10000.0) as i32) % 100;
+            (self.rivalrate_int, self.rivalrate_after_dot) = rate_digits(rivalscore, totalnotes);
         }
 
         // If ghost and notes count differ (notes changed due to random branching), don't use ghost
@@ -317,6 +324,24 @@ impl ScoreDataProperty {
             .is_some_and(|g| g.len() == totalnotes as usize);
     }
 
+    /// Race against a ghost given as per-note judges. Its EX score after the
+    /// same number of judged notes is tracked by `now_ghost_score()`.
+    pub fn set_ghost(&mut self, ghost: Option<Vec<i32>>) {
+        let ghost = ghost.filter(|g| !g.is_empty());
+        self.nowghostscore = ghost.as_ref().map_or(0, |g| {
+            g.iter()
+                .take(self.previous_notes.max(0) as usize)
+                .map(|&judge| Self::get_ex_score(judge))
+                .sum()
+        });
+        self.ghost = ghost;
+    }
+
+    /// Ghost EX score at the current note, or `None` without a ghost.
+    pub fn now_ghost_score(&self) -> Option<i32> {
+        self.ghost.as_ref().map(|_| self.nowghostscore)
+    }
+
     pub fn now_score(&self) -> i32 {
         self.nowpoint
     }
@@ -422,6 +447,16 @@ impl ScoreDataProperty {
     }
 }
 
+/// Percent and its two digits after the point for `exscore` out of `notes`,
+/// truncated in integers so rates like 66.65% are not shown as 66.64%.
+fn rate_digits(exscore: i32, notes: i32) -> (i32, i32) {
+    if notes <= 0 {
+        return (0, 0);
+    }
+    let hundredths = (exscore as i64 * 10000 / (notes as i64 * 2)) as i32;
+    (hundredths / 100, hundredths % 100)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -570,6 +605,22 @@ mod tests {
         assert_eq!(prop.rate_after_dot, 0);
     }
 
+    #[test]
+    fn rate_digits_do_not_lose_the_last_hundredth() {
+        let mut prop = ScoreDataProperty::default();
+        let mut sd = ScoreData::new(Mode::BEAT_7K);
+        sd.judge_counts.epg = 666;
+        sd.judge_counts.egr = 1;
+        sd.notes = 1000;
+        prop.update_score_with_notes(Some(&sd), 1000);
+        prop.set_target_score(1333, 1333, 1000);
+        // 1333 / 2000 = 66.65%, which f32 truncation would show as 66.64
+        assert_eq!((prop.rate_int, prop.rate_after_dot), (66, 65));
+        assert_eq!((prop.nowrate_int, prop.nowrate_after_dot), (66, 65));
+        assert_eq!((prop.bestrate_int, prop.bestrate_after_dot), (66, 65));
+        assert_eq!((prop.rivalrate_int, prop.rivalrate_after_dot), (66, 65));
+    }
+
     #[test]
     fn rate_calculation_full_perfect() {
         let mut prop = ScoreDataProperty::default();
@@ -711,10 +762,9 @@ mod tests {
         assert_eq!(prop.nextrank, 0);
     }
 
-    /// Java BMSPlayer.java:450 intentionally passes null for rivalGhost so that
-    /// nowrivalscore uses linear interpolation (rivalscore * notes / totalnotes)
-    /// instead of per-note ghost-based accumulation. Passing a decoded ghost
-    /// would set use_rival_ghost=true and produce different rival score tracking.
+    /// Without a rival ghost, nowrivalscore uses linear interpolation
+    /// (rivalscore * notes / totalnotes). A rival carrying a per-note ghost
+    /// (LR2IR ghost battle) sets use_rival_ghost=true and tracks the ghost.
     #[test]
     fn rival_ghost_none_uses_linear_interpolation() {
         let total_notes = 10;
@@ -722,7 +772,7 @@ mod tests {
         // Ghost where every note is PGREAT (judge=0 → 2 ex each) = 20 total
         let ghost = vec![0; total_notes as usize];
 
-        // Case 1: rival_ghost = None (Java parity) → linear interpolation
+        // Case 1: rival_ghost = None → linear interpolation
         let mut prop_linear = ScoreDataProperty::default();
         prop_linear.set_target_score_with_ghost(0, None, rival_exscore, None, total_notes);
         assert!(!prop_linear.use_rival_ghost);
@@ -733,7 +783,7 @@ mod tests {
         // Linear: 16 * 5 / 10 = 8
         assert_eq!(prop_linear.nowrivalscore, 8);
 
-        // Case 2: rival_ghost = Some(ghost) → ghost-based accumulation (wrong)
+        // Case 2: rival_ghost = Some(ghost) → ghost-based accumulation
         let mut prop_ghost = ScoreDataProperty::default();
         prop_ghost.set_target_score_with_ghost(0, None, rival_exscore, Some(ghost), total_notes);
         assert!(prop_ghost.use_rival_ghost);
@@ -744,8 +794,40 @@ mod tests {
         // Ghost: 5 PGREATs × 2 ex each = 10 (different from linear's 8)
         assert_eq!(prop_ghost.nowrivalscore, 10);
 
-        // The two approaches yield different results, confirming the behavioral
-        // difference. Java passes null (Case 1) to get linear interpolation.
+        // The two approaches yield different results.
         assert_ne!(prop_linear.nowrivalscore, prop_ghost.nowrivalscore);
     }
+
+    #[test]
+    fn ghost_score_follows_judged_notes() {
+        let mut prop = ScoreDataProperty::default();
+        prop.set_target_score_with_ghost(0, None, 0, None, 4);
+        assert_eq!(prop.now_ghost_score(), None);
+
+        // PG, GR, BD, PG
+        prop.set_ghost(Some(vec![0, 1, 3, 0]));
+        assert_eq!(prop.now_ghost_score(), Some(0));
+
+        let mut sd = ScoreData::new(Mode::BEAT_7K);
+        sd.judge_counts.epg = 2;
+        prop.update_score_with_notes(Some(&sd), 2);
+        assert_eq!(prop.now_ghost_score(), Some(3));
+        prop.update_score_with_notes(Some(&sd), 4);
+        assert_eq!(prop.now_ghost_score(), Some(5));
+
+        // Restart resets the ghost to the first note
+        prop.set_target_score_with_ghost(0, None, 0, None, 4);
+        assert_eq!(prop.now_ghost_score(), Some(0));
+    }
+
+    #[test]
+    fn set_ghost_mid_play_catches_up() {
+        let mut prop = ScoreDataProperty::default();
+        let sd = ScoreData::new(Mode::BEAT_7K);
+        prop.update_score_with_notes(Some(&sd), 3);
+        prop.set_ghost(Some(vec![0, 0, 1, 0]));
+        assert_eq!(prop.now_ghost_score(), Some(5));
+        prop.set_ghost(Some(Vec::new()));
+        assert_eq!(prop.now_ghost_score(), None);
+    }
 }
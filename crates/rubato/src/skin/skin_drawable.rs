@@ -48,6 +48,31 @@ pub trait SkinDrawable: Send {
     /// Dispose all skin objects and release resources.
     fn dispose_skin(&mut self);
 
+    /// Identifies this loaded skin instance; 0 when the skin cannot be edited
+    /// by the skin widget manager.
+    fn instance_id(&self) -> u64 {
+        0
+    }
+
+    /// Path of the skin file, used to key per-skin settings.
+    fn skin_path(&self) -> Option<String> {
+        None
+    }
+
+    /// Skin objects in draw order, for the skin widget manager.
+    fn widget_objects(&self) -> Vec<crate::skin::skin_widget_object::SkinWidgetObject> {
+        Vec::new()
+    }
+
+    /// Set the visibility and destination regions of the object at `index`.
+    fn apply_widget(
+        &mut self,
+        _index: usize,
+        _visible: bool,
+        _regions: &[crate::skin::reexports::Rectangle],
+    ) {
+    }
+
     /// Returns the skin's offset configuration entries as (id, SkinOffset) pairs.
     /// Used by MainController to populate MainStateData.offsets during skin loading,
     /// mirroring Java's MainState.setSkin() which copies skin.offset into MainController.offset[].
@@ -124,6 +149,11 @@ pub trait SkinDrawable: Send {
     fn register_image(&mut self, _id: i32, _texture: crate::render::texture::TextureRegion) {
         // default no-op
     }
+
+    /// Remove a texture registered with `register_image`.
+    fn unregister_image(&mut self, _id: i32) {
+        // default no-op
+    }
 }
 
 /// Play-skin-specific metadata extracted from the loaded skin.
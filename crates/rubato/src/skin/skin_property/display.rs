@@ -32,6 +32,10 @@ pub const STRING_RANKING1_NAME: i32 = 120;
 
 pub const STRING_RANKING10_NAME: i32 = 129;
 
+pub const STRING_IR_RIVAL1_NAME: i32 = 130;
+
+pub const STRING_IR_RIVAL5_NAME: i32 = 134;
+
 pub const STRING_COURSE1_TITLE: i32 = 150;
 
 pub const STRING_COURSE2_TITLE: i32 = 151;
@@ -66,6 +70,12 @@ pub const STRING_IR_USER_NAME: i32 = 1021;
 
 pub const STRING_SONG_HASH_MD5: i32 = 1030;
 pub const STRING_SONG_HASH_SHA256: i32 = 1031;
+/// Download URL of the selected chart when it is not installed
+pub const STRING_SONG_DOWNLOAD_URL: i32 = 1032;
+/// File being loaded on the decide screen
+pub const STRING_LOADING_FILE: i32 = 1033;
+/// Highest cleared class course of the class series being browsed on select
+pub const STRING_CLASS_DAN: i32 = 1034;
 
 pub const OFFSET_SCRATCHANGLE_1P: i32 = 1;
 pub const OFFSET_SCRATCHANGLE_2P: i32 = 2;
@@ -216,6 +216,12 @@ pub const NUMBER_POOR_PLUS_MISS: i32 = 426;
 
 pub const NUMBER_BAD_PLUS_POOR_PLUS_MISS: i32 = 427;
 
+/// Average hit offset (ms, + is early) of PGREAT, GREAT, GOOD and BAD
+pub const NUMBER_AVERAGE_OFFSET_PERFECT: i32 = 428;
+pub const NUMBER_AVERAGE_OFFSET_GREAT: i32 = 429;
+pub const NUMBER_AVERAGE_OFFSET_GOOD: i32 = 430;
+pub const NUMBER_AVERAGE_OFFSET_BAD: i32 = 431;
+
 pub const NUMBER_TOTAL_RATE: i32 = 115;
 
 pub const NUMBER_TOTAL_RATE_AFTERDOT: i32 = 116;
@@ -285,6 +291,21 @@ pub const NUMBER_BEST_RATE: i32 = 183;
 
 pub const NUMBER_BEST_RATE_AFTERDOT: i32 = 184;
 
+/// Ghost pacemaker EX score at the current note
+pub const NUMBER_GHOST_SCORE: i32 = 185;
+
+/// Current EX score minus the ghost pacemaker's
+pub const NUMBER_DIFF_GHOSTSCORE: i32 = 186;
+
+/// Target pacemaker EX score at the current note
+pub const NUMBER_TARGET_SCORE_NOW: i32 = 187;
+
+/// Personal best EX score at the current note
+pub const NUMBER_BEST_SCORE_NOW: i32 = 188;
+
+/// Miss count minus the target's, on result
+pub const NUMBER_DIFF_TARGET_MISSCOUNT: i32 = 189;
+
 pub const NUMBER_IR_TOTALPLAYER2: i32 = 200;
 pub const NUMBER_IR_TOTALPLAYCOUNT: i32 = 201;
 
@@ -356,6 +377,8 @@ pub const NUMBER_RIVAL_BAD_RATE: i32 = 288;
 pub const NUMBER_RIVAL_POOR_RATE: i32 = 289;
 
 pub const NUMBER_FOLDER_TOTALSONGS: i32 = 300;
+/// Difficulty table entries in the selected folder with no local chart
+pub const NUMBER_FOLDER_MISSING: i32 = 301;
 pub const NUMBER_FOLDER_NOPLAY: i32 = 320;
 pub const NUMBER_FOLDER_FAILED: i32 = 321;
 pub const NUMBER_FOLDER_ASSIST: i32 = 322;
@@ -416,4 +439,39 @@ pub const NUMBER_RANDOM_2P_6KEY: i32 = 465;
 pub const NUMBER_RANDOM_2P_7KEY: i32 = 466;
 pub const NUMBER_RANDOM_2P_SCR: i32 = 469;
 
+// Replay slot overlay: one block of 4 ids (slot 1-4) per field
+pub const NUMBER_REPLAY1_EXSCORE: i32 = 470;
+pub const NUMBER_REPLAY1_CLEAR: i32 = 474;
+pub const NUMBER_REPLAY1_RANDOMOPTION: i32 = 478;
+pub const NUMBER_REPLAY1_GAUGE: i32 = 482;
+pub const NUMBER_REPLAY1_DATE_YEAR: i32 = 486;
+pub const NUMBER_REPLAY1_DATE_MONTH: i32 = 490;
+pub const NUMBER_REPLAY1_DATE_DAY: i32 = 494;
+pub const NUMBER_REPLAY1_DATE_HOUR: i32 = 498;
+pub const NUMBER_REPLAY1_DATE_MINUTE: i32 = 502;
+pub const NUMBER_REPLAY4_DATE_MINUTE: i32 = 505;
+
+// IR rivals on result: one block of 5 ids (rival 1-5) per field
+pub const NUMBER_IR_RIVAL1_EXSCORE_DIFF: i32 = 506;
+pub const NUMBER_IR_RIVAL1_RANK: i32 = 511;
+pub const NUMBER_IR_RIVAL5_RANK: i32 = 515;
+
+// Course result stage breakdown: one block of 10 ids (stage 1-10) per field
+pub const NUMBER_COURSE_STAGE1_EXSCORE: i32 = 516;
+pub const NUMBER_COURSE_STAGE1_CLEAR: i32 = 526;
+pub const NUMBER_COURSE_STAGE1_GAUGE: i32 = 536;
+pub const NUMBER_COURSE_STAGE10_GAUGE: i32 = 545;
+
+// Result replay save prompt: cursor (slot 1-4, 0 = skip) and the replay it would overwrite
+pub const NUMBER_REPLAY_SAVE_PROMPT_SLOT: i32 = 546;
+pub const NUMBER_REPLAY_SAVE_PROMPT_EXSCORE: i32 = 547;
+pub const NUMBER_REPLAY_SAVE_PROMPT_CLEAR: i32 = 548;
+
+// Player totals of the current day
+pub const NUMBER_TODAY_PLAYCOUNT: i32 = 549;
+pub const NUMBER_TODAY_CLEARCOUNT: i32 = 550;
+pub const NUMBER_TODAY_PLAYNOTES: i32 = 551;
+pub const NUMBER_TODAY_PLAYTIME_HOUR: i32 = 552;
+pub const NUMBER_TODAY_PLAYTIME_MINUTE: i32 = 553;
+
 pub const NUMBER_JUDGERANK: i32 = 400;
@@ -117,6 +117,9 @@ pub const OPTION_REPLAY_PLAYING: i32 = 84;
 
 pub const OPTION_RESULT_CLEAR: i32 = 90;
 pub const OPTION_RESULT_FAIL: i32 = 91;
+pub const OPTION_RESULT_FULLCOMBO: i32 = 1105;
+pub const OPTION_RESULT_PERFECT: i32 = 1106;
+pub const OPTION_RESULT_MAX: i32 = 1107;
 
 pub const OPTION_SELECT_BAR_NOT_PLAYED: i32 = 100;
 pub const OPTION_SELECT_BAR_FAILED: i32 = 101;
@@ -235,6 +238,10 @@ pub const OPTION_SELECT_REPLAYDATA: i32 = 1205;
 pub const OPTION_SELECT_REPLAYDATA2: i32 = 1206;
 pub const OPTION_SELECT_REPLAYDATA3: i32 = 1207;
 pub const OPTION_SELECT_REPLAYDATA4: i32 = 1208;
+/// Result replay save prompt is open
+pub const OPTION_REPLAY_SAVE_PROMPT: i32 = 1209;
+/// Slot under the replay save prompt cursor already holds a replay
+pub const OPTION_REPLAY_SAVE_PROMPT_OVERWRITE: i32 = 1210;
 
 pub const OPTION_1P_AAA: i32 = 200;
 pub const OPTION_1P_AA: i32 = 201;
@@ -690,6 +690,12 @@ pub trait SkinRenderContext: TimerAccess {
         &[]
     }
 
+    /// Returns every change of the active gauge recorded during play.
+    /// Used by the gauge graph to mark where the gauge auto-shifted.
+    fn gauge_transitions(&self) -> &[crate::skin::groove_gauge::GaugeTransition] {
+        &[]
+    }
+
     /// Returns (border, max) for the current gauge type's properties.
     fn gauge_border_max(&self) -> Option<(f32, f32)> {
         None
@@ -0,0 +1,11 @@
+use crate::skin::reexports::Rectangle;
+
+/// Skin object as listed and edited by the skin widget manager.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SkinWidgetObject {
+    pub name: Option<String>,
+    pub draw: bool,
+    pub visible: bool,
+    /// Time and region of each destination keyframe
+    pub destinations: Vec<(i64, Rectangle)>,
+}
@@ -1,4 +1,5 @@
 use crate::skin::song_information::SongInformation;
+use crate::skin::song_user_metadata::SongUserMetadata;
 use crate::skin::validatable::Validatable;
 use bms::model::bms_decoder::convert_hex_string;
 use bms::model::bms_model::BMSModel;
@@ -254,6 +255,9 @@ pub struct SongData {
     pub model: Option<BMSModel>,
     #[serde(skip)]
     pub info: Option<SongInformation>,
+    /// Player-edited title, artist romanization, tags and notes
+    #[serde(skip)]
+    pub user: Option<SongUserMetadata>,
 }
 
 impl Clone for SongData {
@@ -271,6 +275,7 @@ impl Clone for SongData {
             parent: self.parent.clone(),
             model: None, // BMSModel is not Clone
             info: self.info.clone(),
+            user: self.user.clone(),
         }
     }
 }
@@ -298,6 +303,22 @@ impl SongData {
         sd
     }
 
+    /// Title shown in select: the player's title if set, else the full title.
+    pub fn display_title(&self) -> String {
+        match self.user.as_ref().filter(|u| !u.title.is_empty()) {
+            Some(user) => user.title.clone(),
+            None => self.metadata.full_title(),
+        }
+    }
+
+    /// Artist sorted by: the player's romanization if set, else the artist.
+    pub fn sort_artist(&self) -> &str {
+        match self.user.as_ref().filter(|u| !u.artist.is_empty()) {
+            Some(user) => &user.artist,
+            None => &self.metadata.artist,
+        }
+    }
+
     pub fn set_bms_model(&mut self, model: BMSModel) {
         // Note: validate() is intentionally NOT called here. SongData stores
         // raw model values (matching Java's SongData behavior). Normalization
@@ -402,6 +423,15 @@ impl SongData {
         self.appendurl.as_deref().unwrap_or("")
     }
 
+    /// Download page URL, or the append URL when the entry has none.
+    pub fn download_url(&self) -> &str {
+        if self.url().is_empty() {
+            self.appendurl()
+        } else {
+            self.url()
+        }
+    }
+
     pub fn set_url(&mut self, url: String) {
         self.url = Some(url);
     }
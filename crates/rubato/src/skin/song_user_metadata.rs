@@ -0,0 +1,75 @@
+/// Per-chart metadata edited by the player
+///
+/// Stored in songinfo.db and layered over the scanned song data, so it
+/// survives rescans and never touches the chart files.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SongUserMetadata {
+    /// Chart hash (SHA-256)
+    pub sha256: String,
+    /// Title shown and sorted by instead of the chart's title
+    pub title: String,
+    /// Romanized artist, sorted and searched by
+    pub artist: String,
+    /// Whitespace-separated tags
+    pub tags: String,
+    /// Free-form notes
+    pub notes: String,
+}
+
+impl SongUserMetadata {
+    pub fn new(sha256: &str) -> Self {
+        Self {
+            sha256: sha256.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Whether nothing is set, in which case the record is removed.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_empty()
+            && self.artist.is_empty()
+            && self.tags.is_empty()
+            && self.notes.is_empty()
+    }
+
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.tags.split_whitespace()
+    }
+
+    /// Whether a search for `text` finds this chart (case-insensitive).
+    pub fn matches(&self, text: &str) -> bool {
+        let text = text.to_lowercase();
+        if text.is_empty() {
+            return false;
+        }
+        [&self.title, &self.artist, &self.notes]
+            .iter()
+            .any(|s| s.to_lowercase().contains(&text))
+            || self.tags().any(|tag| tag.to_lowercase() == text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_fields_and_whole_tags() {
+        let meta = SongUserMetadata {
+            sha256: "a".to_string(),
+            title: "Dai Kaijuu".to_string(),
+            artist: "Sasaki".to_string(),
+            tags: "scratch  chord".to_string(),
+            notes: "practice the ending".to_string(),
+        };
+        assert!(meta.matches("kaijuu"));
+        assert!(meta.matches("SASAKI"));
+        assert!(meta.matches("ending"));
+        assert!(meta.matches("chord"));
+        assert!(!meta.matches("scr"));
+        assert!(!meta.matches(""));
+        assert_eq!(meta.tags().collect::<Vec<_>>(), vec!["scratch", "chord"]);
+        assert!(!meta.is_empty());
+        assert!(SongUserMetadata::new("a").is_empty());
+    }
+}
@@ -46,8 +46,11 @@ pub fn target_name(target: &str) -> String {
 /// Resolve a target ID to a display name using static mappings and rival info.
 ///
 /// Static mappings: RANK_AAA→"RANK AAA-", RANK_AA→"RANK AA-", RANK_A→"RANK A-",
-/// RANK_MAX→"MAX-", MYBEST→"MY BEST", RANK_NEXT→"NEXT RANK".
+/// RANK_MAX→"MAX-", MYBEST→"MY BEST", RATE_CUSTOM→"CUSTOM RATE", RANK_NEXT→"NEXT RANK",
+/// HISTORY_YESTERDAY→"YESTERDAY BEST", HISTORY_AVERAGE_n→"LAST n AVERAGE".
 /// Rival mappings: RIVAL_1..RIVAL_4 → rivals[n-1].name().
+/// IR mappings: IR_NEXT_n, IR_RANK_n, IR_RANKRATE_n and IR_AVERAGE_n use the
+/// names the play screen shows for them.
 ///
 /// Java: TargetProperty.getTargetName()
 pub fn resolve_target_name<'a>(id: &'a str, rivals: &[PlayerInformation]) -> Cow<'a, str> {
@@ -57,7 +60,9 @@ pub fn resolve_target_name<'a>(id: &'a str, rivals: &[PlayerInformation]) -> Cow
         "RANK_A" => Cow::Borrowed("RANK A-"),
         "RANK_MAX" => Cow::Borrowed("MAX-"),
         "MYBEST" => Cow::Borrowed("MY BEST"),
+        "RATE_CUSTOM" => Cow::Borrowed("CUSTOM RATE"),
         "RANK_NEXT" => Cow::Borrowed("NEXT RANK"),
+        "HISTORY_YESTERDAY" => Cow::Borrowed("YESTERDAY BEST"),
         _ => {
             if let Some(suffix) = id.strip_prefix("RIVAL_")
                 && let Ok(n) = suffix.parse::<usize>()
@@ -66,11 +71,33 @@ pub fn resolve_target_name<'a>(id: &'a str, rivals: &[PlayerInformation]) -> Cow
             {
                 return Cow::Owned(rivals[n - 1].name().to_string());
             }
+            if let Some(name) = ir_target_name(id) {
+                return Cow::Owned(name);
+            }
+            if let Some(suffix) = id.strip_prefix("HISTORY_AVERAGE_")
+                && let Ok(n) = suffix.parse::<i32>()
+            {
+                return Cow::Owned(format!("LAST {} AVERAGE", n));
+            }
             Cow::Borrowed(id)
         }
     }
 }
 
+fn ir_target_name(id: &str) -> Option<String> {
+    let suffix = id.strip_prefix("IR_")?;
+    // RANKRATE before RANK, whose prefix it shares
+    let (kind, value) = ["NEXT_", "RANKRATE_", "RANK_", "AVERAGE_"]
+        .into_iter()
+        .find_map(|kind| Some((kind, suffix.strip_prefix(kind)?.parse::<i32>().ok()?)))?;
+    Some(match kind {
+        "NEXT_" => format!("IR NEXT {}RANK", value),
+        "RANKRATE_" => format!("IR RANK TOP {}%", value),
+        "RANK_" => format!("IR RANK {}", value),
+        _ => format!("IR TOP {} AVERAGE", value),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,6 +110,7 @@ mod tests {
         assert_eq!(resolve_target_name("RANK_A", &rivals), "RANK A-");
         assert_eq!(resolve_target_name("RANK_MAX", &rivals), "MAX-");
         assert_eq!(resolve_target_name("MYBEST", &rivals), "MY BEST");
+        assert_eq!(resolve_target_name("RATE_CUSTOM", &rivals), "CUSTOM RATE");
         assert_eq!(resolve_target_name("RANK_NEXT", &rivals), "NEXT RANK");
     }
 
@@ -104,6 +132,33 @@ mod tests {
         assert_eq!(resolve_target_name("RIVAL_3", &rivals), "RIVAL_3");
     }
 
+    #[test]
+    fn test_resolve_target_name_ir_targets() {
+        assert_eq!(resolve_target_name("IR_NEXT_2", &[]), "IR NEXT 2RANK");
+        assert_eq!(resolve_target_name("IR_RANK_1", &[]), "IR RANK 1");
+        assert_eq!(
+            resolve_target_name("IR_RANKRATE_10", &[]),
+            "IR RANK TOP 10%"
+        );
+        assert_eq!(
+            resolve_target_name("IR_AVERAGE_10", &[]),
+            "IR TOP 10 AVERAGE"
+        );
+        assert_eq!(resolve_target_name("IR_AVERAGE_X", &[]), "IR_AVERAGE_X");
+    }
+
+    #[test]
+    fn test_resolve_target_name_history_targets() {
+        assert_eq!(
+            resolve_target_name("HISTORY_AVERAGE_5", &[]),
+            "LAST 5 AVERAGE"
+        );
+        assert_eq!(
+            resolve_target_name("HISTORY_YESTERDAY", &[]),
+            "YESTERDAY BEST"
+        );
+    }
+
     #[test]
     fn test_resolve_target_name_unknown() {
         assert_eq!(resolve_target_name("UNKNOWN", &[]), "UNKNOWN");
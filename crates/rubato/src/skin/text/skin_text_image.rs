@@ -238,8 +238,12 @@ impl SkinTextImageSource {
             return region.image.clone();
         }
         let element = self.elements.get_mut(&region.id)?;
-        element.texture.as_ref()?;
-        let tex = element.texture.as_ref().expect("texture is Some");
+        let tex = element
+            .texture
+            .get_or_insert_with(|| Texture::new(&element.path));
+        if tex.width == 0 {
+            return None;
+        }
         region.image = Some(TextureRegion::from_texture_region(
             tex.clone(),
             region.x,
@@ -267,6 +271,16 @@ impl SkinTextImageSource {
         self.elements.insert(index, element);
     }
 
+    /// Load the glyph textures that are not loaded yet. Loading before the
+    /// source is cloned for each text lets the clones share the pixel data.
+    pub fn load_textures(&mut self) {
+        for element in self.elements.values_mut() {
+            if element.texture.is_none() {
+                element.texture = Some(Texture::new(&element.path));
+            }
+        }
+    }
+
     pub fn dispose(&mut self) {
         for tr in self.elements.values_mut() {
             if let Some(ref mut texture) = tr.texture {
@@ -390,6 +404,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_skin_text_image_source_loads_glyph_texture_from_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let png = dir.path().join("font.png");
+        image::RgbaImage::new(64, 32).save(&png).unwrap();
+
+        let mut source = SkinTextImageSource::new(false);
+        source.set_path(0, png.to_string_lossy().into_owned());
+        source.set_path(
+            1,
+            dir.path()
+                .join("missing.png")
+                .to_string_lossy()
+                .into_owned(),
+        );
+        source.set_image('A' as i32, 0, 16, 0, 16, 32);
+        source.set_image('B' as i32, 1, 0, 0, 16, 32);
+        source.load_textures();
+
+        let glyph = source
+            .get_image('A' as i32)
+            .expect("glyph from loaded texture");
+        assert_eq!(glyph.region_width, 16);
+        assert_eq!(glyph.region_height, 32);
+        assert!(source.get_image('B' as i32).is_none());
+        assert!(source.get_image('C' as i32).is_none());
+    }
+
     #[test]
     fn test_skin_text_image_source_clone_preserves_data() {
         let source = make_source_with_glyphs(&[('A', 20), ('B', 30)], 40, 2);
@@ -28,6 +28,12 @@ pub struct SelectBarData {
     pub bartrophy: Vec<Option<SkinImage>>,
     /// Label images indexed by label ID (0-2)
     pub barlabel: Vec<Option<SkinImage>>,
+    /// DJ rank images indexed by rank (0=AAA .. 7=F)
+    pub barrank: Vec<Option<SkinImage>>,
+    /// EX score SkinNumber
+    pub barscore: Option<SkinNumber>,
+    /// Miss count SkinNumber
+    pub barmisscount: Option<SkinNumber>,
     /// Distribution graph type (0 = lamp, 1 = rank)
     pub graph_type: Option<i32>,
     /// Custom images for the distribution graph (replaces default colors)
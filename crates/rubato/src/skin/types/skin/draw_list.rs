@@ -0,0 +1,83 @@
+// Retained draw output of skin objects whose appearance did not change.
+
+use crate::render::sprite_batch::RecordedDraw;
+use crate::skin::reexports::MainState;
+use crate::skin::types::skin_node::SkinNode;
+use crate::skin::types::skin_object::SkinObjectRenderer;
+
+struct RetainedEntry {
+    signature: u64,
+    valid: bool,
+    draw: RecordedDraw,
+}
+
+/// Per-object recordings of sprite batch output, indexed like the skin's
+/// object list. An object whose `draw_signature()` matches its recording is
+/// replayed instead of drawn, so static objects cost one vertex copy per frame.
+pub(super) struct RetainedDrawList {
+    entries: Vec<Option<RetainedEntry>>,
+    enabled: bool,
+}
+
+impl Default for RetainedDrawList {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            enabled: true,
+        }
+    }
+}
+
+impl RetainedDrawList {
+    pub(super) fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.entries.clear();
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Number of objects with a replayable recording.
+    #[cfg(test)]
+    pub(super) fn retained_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| e.as_ref().is_some_and(|e| e.valid))
+            .count()
+    }
+
+    /// Draw `obj` (at `index` in the skin's object list), replaying its
+    /// recording when its signature is unchanged and recording it otherwise.
+    pub(super) fn draw(
+        &mut self,
+        index: usize,
+        obj: &mut dyn SkinNode,
+        renderer: &mut SkinObjectRenderer,
+        state: &dyn MainState,
+    ) {
+        let signature = match obj.draw_signature() {
+            Some(signature) if self.enabled && !renderer.sprite.is_capturing() => signature,
+            _ => {
+                obj.draw(renderer, state);
+                return;
+            }
+        };
+        if self.entries.len() <= index {
+            self.entries.resize_with(index + 1, || None);
+        }
+        let entry = self.entries[index].get_or_insert_with(|| RetainedEntry {
+            signature,
+            valid: false,
+            draw: RecordedDraw::default(),
+        });
+        if entry.valid && entry.signature == signature {
+            renderer.sprite.replay(&entry.draw);
+            return;
+        }
+        let mark = renderer.sprite.begin_record();
+        obj.draw(renderer, state);
+        entry.signature = signature;
+        entry.valid = renderer.sprite.end_record(mark, &mut entry.draw);
+    }
+}
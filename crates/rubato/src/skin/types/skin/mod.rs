@@ -17,6 +17,9 @@ use crate::skin::types::skin_object::{DestinationParams, SkinObjectRenderer};
 
 use log::debug;
 
+mod draw_list;
+use draw_list::RetainedDrawList;
+
 include!("skin_impl.rs");
 include!("skin_drawable.rs");
 
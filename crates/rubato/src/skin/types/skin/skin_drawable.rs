@@ -311,6 +311,10 @@ impl crate::skin::skin_render_context::SkinRenderContext for TimerOnlyMainState<
             .map_or(&[] as &[Vec<Vec<f32>>], |c| c.course_gauge_history())
     }
 
+    fn gauge_transitions(&self) -> &[crate::skin::groove_gauge::GaugeTransition] {
+        self.ctx.as_deref().map_or(&[], |c| c.gauge_transitions())
+    }
+
     fn gauge_border_max(&self) -> Option<(f32, f32)> {
         self.ctx.as_deref().and_then(|c| c.gauge_border_max())
     }
@@ -583,6 +587,47 @@ impl crate::skin::skin_drawable::SkinDrawable for Skin {
         crate::skin::bitmap_font_cache::clear();
     }
 
+    fn instance_id(&self) -> u64 {
+        self.instance_id
+    }
+
+    fn skin_path(&self) -> Option<String> {
+        self.header
+            .path()
+            .map(|path| path.to_string_lossy().into_owned())
+    }
+
+    fn widget_objects(&self) -> Vec<crate::skin::skin_widget_object::SkinWidgetObject> {
+        self.objects
+            .iter()
+            .map(|object| {
+                let data = object.data();
+                crate::skin::skin_widget_object::SkinWidgetObject {
+                    name: data.name.clone(),
+                    draw: data.draw,
+                    visible: data.visible,
+                    destinations: data.dst.iter().map(|dst| (dst.time, dst.region)).collect(),
+                }
+            })
+            .collect()
+    }
+
+    fn apply_widget(
+        &mut self,
+        index: usize,
+        visible: bool,
+        regions: &[crate::skin::reexports::Rectangle],
+    ) {
+        let Some(object) = self.objects.get_mut(index) else {
+            return;
+        };
+        let data = object.data_mut();
+        data.visible = visible;
+        for (dst, region) in data.dst.iter_mut().zip(regions) {
+            dst.region = *region;
+        }
+    }
+
     fn skin_offsets(&self) -> std::collections::HashMap<i32, crate::skin::skin_offset::SkinOffset> {
         self.offset
             .iter()
@@ -701,6 +746,10 @@ impl crate::skin::skin_drawable::SkinDrawable for Skin {
     fn register_image(&mut self, id: i32, texture: crate::render::texture::TextureRegion) {
         self.register_image(id, texture);
     }
+
+    fn unregister_image(&mut self, id: i32) {
+        self.unregister_image(id);
+    }
 }
 
 #[cfg(test)]
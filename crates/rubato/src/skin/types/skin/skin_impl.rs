@@ -1,6 +1,8 @@
 /// Main skin class
 pub struct Skin {
     pub header: SkinHeader,
+    /// Unique per loaded skin, so the widget manager can tell reloads apart
+    instance_id: u64,
     /// Width
     pub width: f32,
     /// Height
@@ -41,6 +43,8 @@ pub struct Skin {
     pub pcnt_draw: i64,
 
     renderer: Option<SkinObjectRenderer>,
+    /// Recorded draw output replayed for objects that did not change
+    draw_list: RetainedDrawList,
     nextpreparetime: i64,
     prepareduration: i64,
 
@@ -79,6 +83,8 @@ pub struct Skin {
     pub play_note_expansion_rate: [i32; 2],
 }
 
+static NEXT_INSTANCE_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+
 impl Skin {
     pub fn new(header: SkinHeader) -> Self {
         let org = header.source_resolution().clone();
@@ -104,6 +110,7 @@ impl Skin {
 
         Skin {
             header,
+            instance_id: NEXT_INSTANCE_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed),
             width,
             height,
             dw,
@@ -126,6 +133,7 @@ impl Skin {
             pcnt_prepare: 0,
             pcnt_draw: 0,
             renderer: None,
+            draw_list: RetainedDrawList::default(),
             nextpreparetime: -1,
             prepareduration: 1,
             image_registry: Self::create_system_image_registry(),
@@ -188,6 +196,11 @@ impl Skin {
         self.image_registry.insert(id, tr);
     }
 
+    /// Remove a registered image.
+    pub fn unregister_image(&mut self, id: i32) {
+        self.image_registry.remove(&id);
+    }
+
     /// Look up a registered image by ID.
     pub fn registered_image(&self, id: i32) -> Option<TextureRegion> {
         self.image_registry.get(&id).cloned()
@@ -290,13 +303,21 @@ impl Skin {
         self.custom_timers.len()
     }
 
+    /// Replay recorded output of unchanged objects instead of drawing them
+    /// again (on by default).
+    pub fn set_draw_retention(&mut self, enabled: bool) {
+        self.draw_list.set_enabled(enabled);
+    }
+
     pub fn remove_skin_object(&mut self, index: usize) {
         if index < self.objects.len() {
             self.objects.remove(index);
+            self.draw_list.clear();
         }
     }
 
     pub fn prepare(&mut self, state: &dyn MainState) {
+        crate::profile_scope!("Skin::prepare");
         let mut remove_indices: Vec<usize> = Vec::new();
 
         for i in 0..self.objects.len() {
@@ -374,6 +395,7 @@ impl Skin {
 
         // Build object array indices
         self.objectarray_indices = (0..self.objects.len()).collect();
+        self.draw_list.clear();
 
         self.option.clear();
 
@@ -392,6 +414,7 @@ impl Skin {
     }
 
     pub fn draw_all_objects(&mut self, state: &dyn MainState) {
+        crate::profile_scope!("Skin::draw_all_objects");
         if self.renderer.is_none() {
             self.renderer = Some(SkinObjectRenderer::new());
         }
@@ -492,7 +515,8 @@ impl Skin {
                 let obj = &mut self.objects[*idx];
                 let data = obj.data();
                 if data.draw && data.visible {
-                    self.objects[*idx].draw(renderer, state);
+                    self.draw_list
+                        .draw(*idx, obj.as_mut(), renderer, state);
                 }
             }
         }
@@ -522,6 +546,7 @@ impl Skin {
     }
 
     pub fn dispose(&mut self) {
+        self.draw_list.clear();
         for obj in &mut self.objects {
             if !obj.data().is_disposed() {
                 obj.dispose();
@@ -679,6 +704,7 @@ impl Skin {
     /// Update user-defined objects once per frame.
     /// Update order: timers -> events, each in ascending ID order.
     pub fn update_custom_objects(&mut self, state: &mut dyn MainState) {
+        crate::profile_scope!("Skin::update_custom_objects");
         // Sort by ID for ordered iteration
         let mut timer_ids: Vec<i32> = self.custom_timers.keys().copied().collect();
         timer_ids.sort_unstable();
@@ -1260,3 +1260,139 @@ fn test_prepare_all_static_true_results_in_empty_dstdraw() {
         "all static-true conditions should be pruned"
     );
 }
+
+#[test]
+fn test_widget_objects_and_apply_widget() {
+    use crate::skin::reexports::Rectangle;
+
+    let mut skin = make_test_skin();
+    let mut image = SkinImage::new_empty();
+    image.data.name = Some("judge".to_string());
+    image.data.set_destination_with_int_timer_ops(
+        &DestinationParams {
+            time: 0,
+            x: 10.0,
+            y: 20.0,
+            w: 100.0,
+            h: 50.0,
+            acc: 0,
+            a: 255,
+            r: 255,
+            g: 255,
+            b: 255,
+            blend: 0,
+            filter: 0,
+            angle: 0,
+            center: 0,
+            loop_val: 0,
+        },
+        0,
+        &[0],
+    );
+    skin.add(Box::new(image));
+
+    let objects = skin.widget_objects();
+    assert_eq!(objects.len(), 1);
+    assert_eq!(objects[0].name.as_deref(), Some("judge"));
+    assert!(objects[0].visible);
+    assert_eq!(
+        objects[0].destinations,
+        vec![(0, Rectangle::new(10.0, 20.0, 100.0, 50.0))]
+    );
+
+    skin.apply_widget(0, false, &[Rectangle::new(30.0, 40.0, 100.0, 50.0)]);
+    let objects = skin.widget_objects();
+    assert!(!objects[0].visible);
+    assert_eq!(
+        objects[0].destinations[0].1,
+        Rectangle::new(30.0, 40.0, 100.0, 50.0)
+    );
+    // Out of range indices are ignored
+    skin.apply_widget(1, false, &[]);
+}
+
+// =========================================================================
+// Retained draw list
+// =========================================================================
+
+fn add_moving_image(skin: &mut Skin, y: f32) {
+    let mut image = crate::skin::skin_image::SkinImage::new_with_single(make_region(32, 32));
+    for (time, x) in [(0, 0.0), (2000, 200.0)] {
+        image.data.set_destination_with_int_timer_ops(
+            &DestinationParams {
+                time,
+                x,
+                y,
+                w: 32.0,
+                h: 32.0,
+                acc: 0,
+                a: 255,
+                r: 255,
+                g: 255,
+                b: 255,
+                blend: 0,
+                filter: 0,
+                angle: 0,
+                center: 0,
+                loop_val: 0,
+            },
+            0,
+            &[0],
+        );
+    }
+    skin.add(Box::new(image));
+    skin.objectarray_indices.push(skin.objects.len() - 1);
+}
+
+fn draw_frame(skin: &mut Skin, now_time: i64) -> Vec<crate::render::sprite_batch::SpriteVertex> {
+    let mut state = crate::skin::test_helpers::MockMainState::default();
+    state.timer.now_time = now_time;
+    state.timer.now_micro_time = now_time * 1000;
+    let mut batch = crate::render::sprite_batch::SpriteBatch::new();
+    skin.swap_sprite_batch(&mut batch);
+    skin.draw_all_objects(&state);
+    skin.swap_sprite_batch(&mut batch);
+    batch.vertices().to_vec()
+}
+
+#[test]
+fn test_draw_all_objects_replays_unchanged_objects() {
+    let mut skin = make_test_skin();
+    add_moving_image(&mut skin, 0.0);
+    add_moving_image(&mut skin, 50.0);
+
+    let first = draw_frame(&mut skin, 1000);
+    assert_eq!(first.len(), 12);
+    assert_eq!(skin.draw_list.retained_count(), 2);
+
+    // Same frame time: both images are replayed with identical output.
+    let replayed = draw_frame(&mut skin, 1000);
+    assert_eq!(
+        bytemuck::cast_slice::<_, u8>(&replayed),
+        bytemuck::cast_slice::<_, u8>(&first)
+    );
+}
+
+#[test]
+fn test_draw_all_objects_rerecords_changed_objects() {
+    let mut skin = make_test_skin();
+    add_moving_image(&mut skin, 0.0);
+    let before = draw_frame(&mut skin, 500);
+
+    // The image moved: the recording must be refreshed, not replayed.
+    let moved = draw_frame(&mut skin, 1000);
+    let mut fresh_skin = make_test_skin();
+    add_moving_image(&mut fresh_skin, 0.0);
+    let expected = draw_frame(&mut fresh_skin, 1000);
+    assert_ne!(
+        bytemuck::cast_slice::<_, u8>(&moved),
+        bytemuck::cast_slice::<_, u8>(&before)
+    );
+    assert_eq!(
+        bytemuck::cast_slice::<_, u8>(&moved),
+        bytemuck::cast_slice::<_, u8>(&expected)
+    );
+
+    skin.remove_skin_object(0);
+    assert_eq!(skin.draw_list.retained_count(), 0);
+}
@@ -22,6 +22,13 @@ pub trait SkinNode: Send {
     /// Draw the object.
     fn draw(&mut self, sprite: &mut SkinObjectRenderer, state: &dyn MainState);
 
+    /// Hash of everything the next `draw` call depends on, or None when the
+    /// draw output cannot be cached. While the value is unchanged the skin
+    /// replays the previously recorded vertices instead of calling `draw`.
+    fn draw_signature(&self) -> Option<u64> {
+        None
+    }
+
     /// Release resources.
     fn dispose(&mut self) {}
 
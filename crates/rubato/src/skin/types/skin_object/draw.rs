@@ -1,12 +1,83 @@
 // Draw methods for SkinObjectData.
 // Renders prepared skin objects to a SkinObjectRenderer (sprite batch).
 
-use crate::skin::reexports::TextureRegion;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::skin::reexports::{Color, TextureRegion};
 
 use super::renderer::{DrawRotatedParams, SkinObjectRenderer};
 use super::{DrawImageAtParams, SkinObjectData};
 
 impl SkinObjectData {
+    /// Draw signature (see `SkinNode::draw_signature`) for drawing `image`
+    /// into this object's region with its current color, angle and blend.
+    pub fn image_draw_signature(&self, image: &TextureRegion) -> u64 {
+        let mut hasher = SignatureHasher::default();
+        for v in [
+            self.region.x,
+            self.region.y,
+            self.region.width,
+            self.region.height,
+            self.color.r,
+            self.color.g,
+            self.color.b,
+            self.color.a,
+            self.centerx,
+            self.centery,
+            image.u,
+            image.v,
+            image.u2,
+            image.v2,
+        ] {
+            hasher.write_u32(v.to_bits());
+        }
+        for v in [
+            self.angle,
+            self.dstblend,
+            self.dstfilter,
+            self.image_type,
+            image.region_x,
+            image.region_y,
+            image.region_width,
+            image.region_height,
+        ] {
+            hasher.write_i32(v);
+        }
+        if let Some(recolor) = &self.recolor {
+            for v in [recolor.r, recolor.g, recolor.b] {
+                hasher.write_u32(v.to_bits());
+            }
+        }
+        std::mem::discriminant(&self.stretch).hash(&mut hasher);
+        if let Some(texture) = &image.texture {
+            hasher.write_i32(texture.width);
+            hasher.write_i32(texture.height);
+            hasher.write_u64(texture.pixmap_id.unwrap_or(u64::MAX));
+            hasher.write_usize(
+                texture
+                    .path
+                    .as_ref()
+                    .map_or(0, |p| Arc::as_ptr(p) as *const u8 as usize),
+            );
+            hasher.write_usize(
+                texture
+                    .rgba_data
+                    .as_ref()
+                    .map_or(0, |d| Arc::as_ptr(d) as usize),
+            );
+        }
+        hasher.finish()
+    }
+
+    /// Swap in the accessibility palette color, keeping the skin's alpha.
+    fn apply_recolor(&self, sprite: &mut SkinObjectRenderer, color: &Color) {
+        if let Some(recolor) = &self.recolor {
+            sprite.set_color_rgba(recolor.r, recolor.g, recolor.b, color.a);
+            sprite.obj_type = SkinObjectRenderer::TYPE_RECOLOR;
+        }
+    }
+
     pub fn draw_image(&mut self, sprite: &mut SkinObjectRenderer, image: &TextureRegion) {
         if self.color.a == 0.0 {
             return;
@@ -29,6 +100,7 @@ impl SkinObjectData {
             } else {
                 self.image_type
             };
+        self.apply_recolor(sprite, &self.color);
 
         if self.angle != 0 {
             sprite.draw_rotated(DrawRotatedParams {
@@ -103,6 +175,7 @@ impl SkinObjectData {
             } else {
                 self.image_type
             };
+        self.apply_recolor(sprite, params.color);
 
         if params.angle != 0 {
             sprite.draw_rotated(DrawRotatedParams {
@@ -126,3 +199,37 @@ impl SkinObjectData {
         }
     }
 }
+
+/// Multiply-rotate hasher for draw signatures. Signatures are computed for
+/// every retained object each frame, so this trades DoS resistance (not
+/// needed here) for a few cycles per word.
+#[derive(Default)]
+struct SignatureHasher(u64);
+
+impl Hasher for SignatureHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.write_u64(b as u64);
+        }
+    }
+
+    fn write_u32(&mut self, v: u32) {
+        self.write_u64(v as u64);
+    }
+
+    fn write_i32(&mut self, v: i32) {
+        self.write_u64(v as u32 as u64);
+    }
+
+    fn write_usize(&mut self, v: usize) {
+        self.write_u64(v as u64);
+    }
+
+    fn write_u64(&mut self, v: u64) {
+        self.0 = (self.0.rotate_left(5) ^ v).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
@@ -150,6 +150,9 @@ pub struct SkinObjectData {
     pub fixr: Option<Rectangle>,
     pub fixc: Option<Color>,
     pub fixa: i32,
+    /// Accessibility palette color replacing the texture hue when set.
+    /// Assigned by the owning object after `prepare`; see `color_palette`.
+    pub recolor: Option<Color>,
 
     pub nowtime: i64,
     pub rate: f32,
@@ -197,6 +200,7 @@ impl Default for SkinObjectData {
             fixr: None,
             fixc: None,
             fixa: i32::MIN,
+            recolor: None,
             nowtime: 0,
             rate: 0.0,
             index: 0,
@@ -42,6 +42,10 @@ impl SkinObjectRenderer {
     pub const TYPE_FFMPEG: i32 = 3;
     pub const TYPE_LAYER: i32 = 4;
     pub const TYPE_DISTANCE_FIELD: i32 = 5;
+    /// Texture brightness shaded with the draw color (accessibility palettes).
+    pub const TYPE_RECOLOR: i32 = 6;
+    /// Flat draw color masked by texture alpha (high-contrast notes).
+    pub const TYPE_SILHOUETTE: i32 = 7;
 
     // GL blend constants (matching Java)
     const GL_SRC_ALPHA: i32 = 0x0302;
@@ -23,6 +23,8 @@ fn test_skin_object_renderer_type_constants() {
     assert_eq!(SkinObjectRenderer::TYPE_FFMPEG, 3);
     assert_eq!(SkinObjectRenderer::TYPE_LAYER, 4);
     assert_eq!(SkinObjectRenderer::TYPE_DISTANCE_FIELD, 5);
+    assert_eq!(SkinObjectRenderer::TYPE_RECOLOR, 6);
+    assert_eq!(SkinObjectRenderer::TYPE_SILHOUETTE, 7);
 }
 
 #[test]
@@ -0,0 +1,117 @@
+//! Chart summary for `rubato analyze`.
+//!
+//! Decodes one chart the way play does and reports metadata, hashes, note
+//! counts and density figures without touching the song database.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use bms::model::bms_model::BMSModel;
+use serde::Serialize;
+
+use crate::core::player_resource::PlayerResource;
+use crate::song::song_information::SongInformation;
+
+/// Summary of a decoded chart.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartAnalysis {
+    pub title: String,
+    pub subtitle: String,
+    pub artist: String,
+    pub genre: String,
+    /// Mode hint such as `beat-7k`, empty when the mode is unknown.
+    pub mode: String,
+    pub md5: String,
+    pub sha256: String,
+    pub judgerank: i32,
+    pub total: f64,
+    pub min_bpm: f64,
+    pub max_bpm: f64,
+    pub main_bpm: f64,
+    /// Time of the last note in milliseconds.
+    pub length_ms: i64,
+    pub total_notes: i32,
+    pub normal_notes: i32,
+    pub long_notes: i32,
+    pub scratch_notes: i32,
+    pub long_scratch_notes: i32,
+    pub has_undefined_long_note: bool,
+    pub has_mine_note: bool,
+    /// Notes per second, as on the select screen.
+    pub density: f64,
+    pub peak_density: f64,
+    pub end_density: f64,
+}
+
+impl ChartAnalysis {
+    pub fn from_model(model: &BMSModel) -> Self {
+        let info = SongInformation::from_model(model);
+        Self {
+            title: model.title.clone(),
+            subtitle: model.sub_title.clone(),
+            artist: model.artist.clone(),
+            genre: model.genre.clone(),
+            mode: model.mode().map(|m| m.hint()).unwrap_or("").to_string(),
+            md5: model.md5.clone(),
+            sha256: model.sha256.clone(),
+            judgerank: model.judgerank,
+            total: model.total,
+            min_bpm: model.min_bpm(),
+            max_bpm: model.max_bpm(),
+            main_bpm: info.mainbpm,
+            length_ms: model.last_note_time(),
+            total_notes: model.total_notes(),
+            normal_notes: info.n,
+            long_notes: info.ln,
+            scratch_notes: info.s,
+            long_scratch_notes: info.ls,
+            has_undefined_long_note: model.contains_undefined_long_note(),
+            has_mine_note: model.contains_mine_note(),
+            density: info.density,
+            peak_density: info.peakdensity,
+            end_density: info.enddensity,
+        }
+    }
+}
+
+/// Decode the chart at `path` with `lnmode` and summarize it.
+pub fn analyze_chart(path: &Path, lnmode: i32) -> Result<ChartAnalysis> {
+    let (model, _) = PlayerResource::load_bms_model(path, lnmode, None)
+        .with_context(|| format!("failed to load chart {}", path.display()))?;
+    Ok(ChartAnalysis::from_model(&model))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_bms(name: &str) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../test-bms")
+            .join(name)
+    }
+
+    #[test]
+    fn analyze_chart_reports_counts_and_hashes() {
+        let analysis = analyze_chart(&test_bms("minimal_7k.bms"), 0).unwrap();
+
+        assert_eq!(analysis.mode, "beat-7k");
+        assert_eq!(analysis.total_notes, 12);
+        assert_eq!(
+            analysis.normal_notes
+                + analysis.long_notes
+                + analysis.scratch_notes
+                + analysis.long_scratch_notes,
+            analysis.total_notes
+        );
+        assert_eq!(analysis.sha256.len(), 64);
+        assert!(analysis.length_ms > 0);
+        assert!(analysis.density > 0.0);
+    }
+
+    #[test]
+    fn analyze_chart_fails_for_missing_file() {
+        assert!(analyze_chart(&test_bms("does_not_exist.bms"), 0).is_err());
+    }
+}
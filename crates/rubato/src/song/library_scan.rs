@@ -0,0 +1,382 @@
+//! Background library scan.
+//!
+//! MainController runs song database updates on a worker thread with its own
+//! connection, so the select screen keeps reading the database while charts
+//! are scanned. A full scan commits one BMS root at a time and bumps
+//! [`LibraryScanStatus::revision`] after each, letting MusicSelector reload
+//! its bars as folders become available. [`LibraryFolderWatcher`] requests
+//! scans of folders whose contents changed on disk.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::core::config::Config;
+use crate::skin::sync_utils::lock_or_recover;
+use crate::song::song_database_update_listener::SongDatabaseUpdateListener;
+use crate::song::song_information_accessor::SongInformationAccessor;
+use crate::song::sqlite_song_database_accessor::SQLiteSongDatabaseAccessor;
+
+/// Interval between folder modification checks of [`LibraryFolderWatcher`].
+pub const WATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Counters of the running scan.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LibraryScanProgress {
+    pub bms_files: i32,
+    pub processed_files: i32,
+    pub new_files: i32,
+}
+
+/// Scan state shared between the worker and the select screen.
+#[derive(Default)]
+pub struct LibraryScanStatus {
+    listener: Mutex<Option<Arc<SongDatabaseUpdateListener>>>,
+    revision: AtomicU64,
+}
+
+impl LibraryScanStatus {
+    /// Progress of the running scan, `None` when idle.
+    pub fn progress(&self) -> Option<LibraryScanProgress> {
+        lock_or_recover(&self.listener)
+            .as_ref()
+            .map(|listener| LibraryScanProgress {
+                bms_files: listener.bms_files_count(),
+                processed_files: listener.processed_bms_files_count(),
+                new_files: listener.new_bms_files_count(),
+            })
+    }
+
+    /// Incremented each time scanned charts are committed to the song database.
+    pub fn revision(&self) -> u64 {
+        self.revision.load(Ordering::Acquire)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ScanRequest {
+    /// Folder to update, `None` for every BMS root.
+    path: Option<String>,
+    update_parent_when_missing: bool,
+}
+
+/// Runs queued song database updates one at a time on a worker thread.
+pub struct LibraryScanner {
+    status: Arc<LibraryScanStatus>,
+    worker: Option<JoinHandle<()>>,
+    queued: Vec<ScanRequest>,
+}
+
+impl Default for LibraryScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LibraryScanner {
+    pub fn new() -> Self {
+        Self {
+            status: Arc::new(LibraryScanStatus::default()),
+            worker: None,
+            queued: Vec::new(),
+        }
+    }
+
+    pub fn status(&self) -> Arc<LibraryScanStatus> {
+        Arc::clone(&self.status)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.worker.as_ref().is_some_and(|w| !w.is_finished())
+    }
+
+    pub fn has_queued(&self) -> bool {
+        !self.queued.is_empty()
+    }
+
+    /// Queue an update of `path`, or of every BMS root when `None`.
+    ///
+    /// A queued full scan absorbs folder requests, and a folder already
+    /// queued is not queued twice. Nothing starts before [`Self::poll`].
+    pub fn request(&mut self, path: Option<String>, update_parent_when_missing: bool) {
+        let request = ScanRequest {
+            path,
+            update_parent_when_missing,
+        };
+        if self
+            .queued
+            .iter()
+            .any(|q| q.path.is_none() || *q == request)
+        {
+            return;
+        }
+        if request.path.is_none() {
+            self.queued.clear();
+        }
+        self.queued.push(request);
+    }
+
+    /// Start the next queued request once the previous scan finished.
+    pub fn poll(&mut self, config: &Config) {
+        if self.is_running() {
+            return;
+        }
+        self.worker = None;
+        if self.queued.is_empty() {
+            return;
+        }
+        let request = self.queued.remove(0);
+        self.worker = Some(spawn_scan(config, request, Arc::clone(&self.status)));
+    }
+}
+
+impl Drop for LibraryScanner {
+    fn drop(&mut self) {
+        // Detach: a large scan can take minutes and keeps its own connection.
+        self.worker.take();
+    }
+}
+
+fn spawn_scan(
+    config: &Config,
+    request: ScanRequest,
+    status: Arc<LibraryScanStatus>,
+) -> JoinHandle<()> {
+    let songpath = config.paths.songpath.clone();
+    let bmsroot = config.paths.bmsroot.clone();
+    let songinfopath = config
+        .use_song_info
+        .then(|| config.paths.songinfopath.clone());
+    let listener = Arc::new(SongDatabaseUpdateListener::new());
+    *lock_or_recover(&status.listener) = Some(Arc::clone(&listener));
+
+    std::thread::spawn(move || {
+        match SQLiteSongDatabaseAccessor::new(&songpath, &bmsroot) {
+            Ok(songdb) => {
+                let infodb = songinfopath.and_then(|path| {
+                    SongInformationAccessor::new(&path)
+                        .map_err(|e| log::warn!("Failed to open song info DB: {}", e))
+                        .ok()
+                });
+                let info = infodb
+                    .as_ref()
+                    .map(|db| db as &dyn crate::song_information_db::SongInformationDb);
+                // Whole roots are committed separately so their bars show up early.
+                let paths: Vec<String> = match request.path {
+                    Some(path) => vec![path],
+                    None => bmsroot.clone(),
+                };
+                for path in &paths {
+                    songdb.update_song_datas_with_listener(
+                        Some(path),
+                        &bmsroot,
+                        false,
+                        request.update_parent_when_missing,
+                        info,
+                        &listener,
+                    );
+                    status.revision.fetch_add(1, Ordering::AcqRel);
+                }
+            }
+            Err(e) => log::error!("Library scan could not open {}: {}", songpath, e),
+        }
+        lock_or_recover(&status.listener).take();
+    })
+}
+
+/// Polls the BMS roots and their direct subfolders for modifications on a
+/// background thread and reports the folders to rescan.
+pub struct LibraryFolderWatcher {
+    roots: Vec<String>,
+    changes: mpsc::Receiver<String>,
+    stop: Arc<AtomicBool>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl LibraryFolderWatcher {
+    /// Take a first snapshot of `roots` and check them every `interval`.
+    pub fn spawn(roots: &[String], interval: Duration) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let watched = roots.to_vec();
+        let stop_flag = Arc::clone(&stop);
+        let worker = std::thread::spawn(move || {
+            let mut last = snapshot(&watched);
+            let mut checked_at = Instant::now();
+            while !stop_flag.load(Ordering::Acquire) {
+                std::thread::sleep(Duration::from_millis(100).min(interval));
+                if checked_at.elapsed() < interval {
+                    continue;
+                }
+                checked_at = Instant::now();
+                let current = snapshot(&watched);
+                for path in changed_folders(&last, &current, &watched) {
+                    if tx.send(path).is_err() {
+                        return;
+                    }
+                }
+                last = current;
+            }
+        });
+        Self {
+            roots: roots.to_vec(),
+            changes: rx,
+            stop,
+            worker: Some(worker),
+        }
+    }
+
+    pub fn roots(&self) -> &[String] {
+        &self.roots
+    }
+
+    /// Folders changed since the last call.
+    pub fn take_changes(&self) -> Vec<String> {
+        self.changes.try_iter().collect()
+    }
+}
+
+impl Drop for LibraryFolderWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        self.worker.take();
+    }
+}
+
+type FolderSnapshot = HashMap<PathBuf, Option<SystemTime>>;
+
+/// Modification times of each root and its direct subfolders.
+fn snapshot(roots: &[String]) -> FolderSnapshot {
+    let mut folders = FolderSnapshot::new();
+    for root in roots {
+        let root = Path::new(root);
+        folders.insert(root.to_path_buf(), modified(root));
+        let Ok(entries) = std::fs::read_dir(root) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            if entry.file_type().is_ok_and(|t| t.is_dir()) {
+                let path = entry.path();
+                let time = modified(&path);
+                folders.insert(path, time);
+            }
+        }
+    }
+    folders
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Folders to rescan between two snapshots.
+///
+/// A changed root is rescanned whole, which also covers its added and
+/// removed subfolders; otherwise each modified subfolder is reported.
+fn changed_folders(old: &FolderSnapshot, new: &FolderSnapshot, roots: &[String]) -> Vec<String> {
+    let changed = |path: &Path| old.get(path) != new.get(path);
+    let mut folders = Vec::new();
+    for root in roots {
+        let root_path = Path::new(root);
+        if changed(root_path) {
+            folders.push(root.clone());
+            continue;
+        }
+        let mut subfolders: Vec<&PathBuf> = new
+            .keys()
+            .filter(|path| path.parent() == Some(root_path) && changed(path))
+            .collect();
+        subfolders.sort();
+        folders.extend(subfolders.iter().map(|p| p.to_string_lossy().into_owned()));
+    }
+    folders
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::song::song_database_accessor::SongDatabaseAccessor;
+
+    const BMS: &str = "#PLAYER 1\n#TITLE Scanned Song\n#BPM 120\n#WAV01 kick.wav\n#00111:01\n";
+
+    fn config_for(dir: &Path) -> Config {
+        let mut config = Config::default();
+        config.paths.songpath = dir.join("song.db").to_string_lossy().into_owned();
+        config.paths.bmsroot = vec![dir.join("songs").to_string_lossy().into_owned()];
+        config.use_song_info = false;
+        config
+    }
+
+    #[test]
+    fn request_coalesces_into_full_scan() {
+        let mut scanner = LibraryScanner::new();
+        scanner.request(Some("a".to_string()), false);
+        scanner.request(Some("a".to_string()), false);
+        scanner.request(Some("b".to_string()), false);
+        assert_eq!(scanner.queued.len(), 2);
+
+        scanner.request(None, false);
+        scanner.request(Some("c".to_string()), false);
+        assert_eq!(
+            scanner.queued,
+            vec![ScanRequest {
+                path: None,
+                update_parent_when_missing: false
+            }]
+        );
+    }
+
+    #[test]
+    fn full_scan_commits_songs_and_bumps_revision() {
+        let dir = tempfile::tempdir().unwrap();
+        let song_dir = dir.path().join("songs").join("pack");
+        std::fs::create_dir_all(&song_dir).unwrap();
+        std::fs::write(song_dir.join("song.bms"), BMS).unwrap();
+        let config = config_for(dir.path());
+        let mut scanner = LibraryScanner::new();
+        let status = scanner.status();
+
+        scanner.request(None, false);
+        scanner.poll(&config);
+        let start = Instant::now();
+        while scanner.is_running() && start.elapsed() < Duration::from_secs(30) {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        scanner.poll(&config);
+
+        assert!(!scanner.is_running());
+        assert_eq!(status.revision(), 1);
+        assert!(status.progress().is_none());
+        let songdb =
+            SQLiteSongDatabaseAccessor::new(&config.paths.songpath, &config.paths.bmsroot).unwrap();
+        assert_eq!(songdb.song_datas("title", "Scanned Song").len(), 1);
+    }
+
+    #[test]
+    fn changed_folders_reports_root_or_modified_subfolders() {
+        let roots = vec!["/lib".to_string()];
+        let t0 = Some(SystemTime::UNIX_EPOCH);
+        let t1 = Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1));
+        let old: FolderSnapshot = [
+            (PathBuf::from("/lib"), t0),
+            (PathBuf::from("/lib/a"), t0),
+            (PathBuf::from("/lib/b"), t0),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut new = old.clone();
+        assert!(changed_folders(&old, &new, &roots).is_empty());
+
+        new.insert(PathBuf::from("/lib/b"), t1);
+        assert_eq!(changed_folders(&old, &new, &roots), vec!["/lib/b"]);
+
+        new.insert(PathBuf::from("/lib"), t1);
+        new.insert(PathBuf::from("/lib/c"), t1);
+        assert_eq!(changed_folders(&old, &new, &roots), vec!["/lib"]);
+    }
+}
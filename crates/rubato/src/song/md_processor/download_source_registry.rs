@@ -0,0 +1,310 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::Config;
+use super::http_download_source::HttpDownloadSource;
+use super::http_download_source_meta::HttpDownloadSourceMeta;
+use super::{konmai_download_source, wriggle_download_source};
+
+/// JSON file, next to the config, that defines extra download sources.
+pub const DOWNLOAD_SOURCES_FILE: &str = "download_sources.json";
+
+/// Chart hash a custom source looks packages up by.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HashType {
+    #[default]
+    Md5,
+    Sha256,
+}
+
+/// One user-defined source from [`DOWNLOAD_SOURCES_FILE`], e.g.
+/// `{"name": "mirror", "urlTemplate": "https://example.com/dl/{md5}", "priority": 2}`.
+///
+/// The template's `{md5}`, `{sha256}`, `{hash}` or `%s` placeholder is replaced by
+/// the chart hash, and the result must point at the package itself.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomSourceDefinition {
+    pub name: String,
+    #[serde(rename = "urlTemplate")]
+    pub url_template: String,
+    #[serde(rename = "hashType", default)]
+    pub hash_type: HashType,
+    #[serde(default)]
+    pub priority: i32,
+}
+
+impl CustomSourceDefinition {
+    const PLACEHOLDERS: [&str; 4] = ["{md5}", "{sha256}", "{hash}", "%s"];
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.name.trim().is_empty() {
+            anyhow::bail!("a download source has no name");
+        }
+        if !Self::PLACEHOLDERS
+            .iter()
+            .any(|placeholder| self.url_template.contains(placeholder))
+        {
+            anyhow::bail!(
+                "download source {} has no hash placeholder in its url template",
+                self.name
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Download source backed by a URL template from [`DOWNLOAD_SOURCES_FILE`].
+pub struct TemplateDownloadSource {
+    definition: CustomSourceDefinition,
+}
+
+impl TemplateDownloadSource {
+    pub fn new(definition: CustomSourceDefinition) -> Self {
+        Self { definition }
+    }
+
+    fn url_for(&self, hash: &str) -> String {
+        CustomSourceDefinition::PLACEHOLDERS
+            .iter()
+            .fold(self.definition.url_template.clone(), |url, placeholder| {
+                url.replace(placeholder, hash)
+            })
+    }
+}
+
+impl HttpDownloadSource for TemplateDownloadSource {
+    fn get_download_url_based_on_md5(&self, md5: &str) -> anyhow::Result<String> {
+        if self.definition.hash_type != HashType::Md5 {
+            anyhow::bail!("{} cannot look charts up by md5", self.definition.name);
+        }
+        Ok(self.url_for(md5))
+    }
+
+    fn get_download_url_based_on_sha256(&self, sha256: &str) -> anyhow::Result<String> {
+        if self.definition.hash_type != HashType::Sha256 {
+            anyhow::bail!("{} cannot look charts up by sha256", self.definition.name);
+        }
+        Ok(self.url_for(sha256))
+    }
+
+    fn name(&self) -> &str {
+        &self.definition.name
+    }
+
+    fn priority(&self) -> i32 {
+        self.definition.priority
+    }
+
+    fn is_allow_download_through_md5(&self) -> bool {
+        self.definition.hash_type == HashType::Md5
+    }
+
+    fn is_allow_download_through_sha256(&self) -> bool {
+        self.definition.hash_type == HashType::Sha256
+    }
+
+    fn is_allow_meta_query(&self) -> bool {
+        false
+    }
+}
+
+/// A source the registry can build: compiled in, or defined in the JSON file.
+pub enum DownloadSourceEntry {
+    Builtin(&'static HttpDownloadSourceMeta),
+    Custom(CustomSourceDefinition),
+}
+
+impl DownloadSourceEntry {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Builtin(meta) => meta.name(),
+            Self::Custom(definition) => &definition.name,
+        }
+    }
+
+    pub fn priority(&self) -> i32 {
+        match self {
+            Self::Builtin(meta) => meta.priority(),
+            Self::Custom(definition) => definition.priority,
+        }
+    }
+
+    /// Build the source. `overrideDownloadUrl` replaces a custom source's template
+    /// the same way it replaces a built-in source's default url.
+    pub fn build(&self, config: &Config) -> Box<dyn HttpDownloadSource> {
+        match self {
+            Self::Builtin(meta) => meta.build(config),
+            Self::Custom(definition) => {
+                let mut definition = definition.clone();
+                if let Some(url) = config.override_download_url() {
+                    definition.url_template = url.to_string();
+                }
+                Box::new(TemplateDownloadSource::new(definition))
+            }
+        }
+    }
+}
+
+/// Every download source the processor can use, highest priority first.
+pub struct DownloadSourceRegistry {
+    entries: Vec<DownloadSourceEntry>,
+}
+
+impl DownloadSourceRegistry {
+    /// Only the compiled-in sources.
+    pub fn builtin() -> Self {
+        Self::with_custom(Vec::new())
+    }
+
+    /// The compiled-in sources plus the ones defined in the JSON file at `path`.
+    /// A missing file adds nothing; a broken file or entry is logged and skipped.
+    pub fn load(path: &Path) -> Self {
+        let definitions = match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).unwrap_or_else(|e| {
+                log::warn!("Ignoring {}: {}", path.display(), e);
+                Vec::new()
+            }),
+            Err(_) => Vec::new(),
+        };
+        Self::with_custom(definitions)
+    }
+
+    fn with_custom(definitions: Vec<CustomSourceDefinition>) -> Self {
+        let builtin: [&'static HttpDownloadSourceMeta; 2] = [
+            &wriggle_download_source::META,
+            &konmai_download_source::META,
+        ];
+        let mut entries: Vec<DownloadSourceEntry> = builtin
+            .into_iter()
+            .map(DownloadSourceEntry::Builtin)
+            .collect();
+        for definition in definitions {
+            if let Err(e) = definition.validate() {
+                log::warn!("Ignoring download source: {}", e);
+                continue;
+            }
+            if entries.iter().any(|entry| entry.name() == definition.name) {
+                log::warn!(
+                    "Ignoring download source {}: the name is already taken",
+                    definition.name
+                );
+                continue;
+            }
+            entries.push(DownloadSourceEntry::Custom(definition));
+        }
+        // Stable, so equal priorities keep built-in sources first
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.priority()));
+        Self { entries }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&DownloadSourceEntry> {
+        self.entries.iter().find(|entry| entry.name() == name)
+    }
+
+    /// Source names, highest priority first.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(DownloadSourceEntry::name)
+    }
+
+    /// Sources, highest priority first.
+    pub fn entries(&self) -> &[DownloadSourceEntry] {
+        &self.entries
+    }
+
+    /// The source named `name`, or the highest priority one when there is no such source.
+    pub fn primary(&self, name: &str) -> &DownloadSourceEntry {
+        self.get(name).unwrap_or(&self.entries[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom(name: &str, url_template: &str, priority: i32) -> CustomSourceDefinition {
+        CustomSourceDefinition {
+            name: name.to_string(),
+            url_template: url_template.to_string(),
+            hash_type: HashType::Md5,
+            priority,
+        }
+    }
+
+    #[test]
+    fn builtin_registry_prefers_wriggle() {
+        let registry = DownloadSourceRegistry::builtin();
+        assert_eq!(registry.names().collect::<Vec<_>>(), ["wriggle", "konmai"]);
+        assert_eq!(registry.primary("").name(), "wriggle");
+        assert_eq!(registry.primary("konmai").name(), "konmai");
+    }
+
+    #[test]
+    fn loads_custom_sources_by_priority() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DOWNLOAD_SOURCES_FILE);
+        fs::write(
+            &path,
+            r#"[
+                {"name": "fast", "urlTemplate": "https://fast.example/{md5}.zip", "priority": 5},
+                {"name": "archive", "urlTemplate": "https://archive.example/%s", "hashType": "sha256"},
+                {"name": "wriggle", "urlTemplate": "https://evil.example/{md5}", "priority": 9},
+                {"name": "broken", "urlTemplate": "https://broken.example/"}
+            ]"#,
+        )
+        .unwrap();
+
+        let registry = DownloadSourceRegistry::load(&path);
+        assert_eq!(
+            registry.names().collect::<Vec<_>>(),
+            ["fast", "wriggle", "konmai", "archive"]
+        );
+        assert_eq!(registry.primary("missing").name(), "fast");
+
+        let config = Config::default();
+        let fast = registry.get("fast").unwrap().build(&config);
+        assert_eq!(fast.priority(), 5);
+        assert_eq!(
+            fast.get_download_url_based_on_md5("abc").unwrap(),
+            "https://fast.example/abc.zip"
+        );
+        assert!(fast.get_download_url_based_on_sha256("abc").is_err());
+
+        let archive = registry.get("archive").unwrap().build(&config);
+        assert!(archive.is_allow_download_through_sha256());
+        assert!(!archive.is_allow_download_through_md5());
+        assert_eq!(
+            archive.get_download_url_based_on_sha256("def").unwrap(),
+            "https://archive.example/def"
+        );
+    }
+
+    #[test]
+    fn broken_file_keeps_builtin_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DOWNLOAD_SOURCES_FILE);
+        fs::write(&path, "{not json").unwrap();
+        assert_eq!(DownloadSourceRegistry::load(&path).entries().len(), 2);
+        assert_eq!(
+            DownloadSourceRegistry::load(&dir.path().join("missing.json"))
+                .entries()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn override_url_replaces_custom_template() {
+        let registry =
+            DownloadSourceRegistry::with_custom(vec![custom("mine", "https://a.example/{md5}", 0)]);
+        let mut config = Config::default();
+        config.network.override_download_url = "https://b.example/{md5}".to_string();
+        let source = registry.get("mine").unwrap().build(&config);
+        assert_eq!(
+            source.get_download_url_based_on_md5("abc").unwrap(),
+            "https://b.example/abc"
+        );
+    }
+}
@@ -1,4 +1,11 @@
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Minimum interval between two download speed samples
+const SPEED_SAMPLE_INTERVAL: Duration = Duration::from_millis(500);
+/// Weight of the newest sample in the smoothed download speed
+const SPEED_SMOOTHING: f64 = 0.3;
 
 /// Corresponds to DownloadTask.DownloadTaskStatus in Java
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -9,6 +16,10 @@ pub enum DownloadTaskStatus {
     Extracted,
     Error,
     Cancel,
+    /// Waiting for a free download slot.
+    Queued,
+    /// Stopped by the user; the partial download is kept for resuming.
+    Paused,
 }
 
 impl DownloadTaskStatus {
@@ -20,9 +31,19 @@ impl DownloadTaskStatus {
             DownloadTaskStatus::Extracted => 3,
             DownloadTaskStatus::Error => 4,
             DownloadTaskStatus::Cancel => 5,
+            DownloadTaskStatus::Queued => 6,
+            DownloadTaskStatus::Paused => 7,
         }
     }
 
+    /// Whether the task has stopped for good (finished, failed or cancelled).
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            DownloadTaskStatus::Extracted | DownloadTaskStatus::Error | DownloadTaskStatus::Cancel
+        )
+    }
+
     pub fn name(&self) -> &str {
         match self {
             DownloadTaskStatus::Prepare => "Prepare",
@@ -31,6 +52,27 @@ impl DownloadTaskStatus {
             DownloadTaskStatus::Extracted => "Finished",
             DownloadTaskStatus::Error => "Error",
             DownloadTaskStatus::Cancel => "Cancel",
+            DownloadTaskStatus::Queued => "Queued",
+            DownloadTaskStatus::Paused => "Paused",
+        }
+    }
+}
+
+/// Step after the archive is downloaded, shown by the download menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessingStep {
+    Extracting,
+    Scanning,
+    /// The extracted folder is in the song database
+    Scanned,
+}
+
+impl ProcessingStep {
+    pub fn name(&self) -> &str {
+        match self {
+            ProcessingStep::Extracting => "Extracting",
+            ProcessingStep::Scanning => "Scanning",
+            ProcessingStep::Scanned => "Added to library",
         }
     }
 }
@@ -46,6 +88,26 @@ pub struct DownloadTask {
     pub content_length: i64,
     error_message: Option<String>,
     time_finished: AtomicI64,
+    source_name: String,
+    /// Final archive path, known once the server named the file. Its `.tmp`
+    /// sibling holds the partial download while paused or after an error.
+    download_path: Option<PathBuf>,
+    pause_requested: bool,
+    cancel_requested: bool,
+    /// Smoothed download speed in bytes per second
+    speed: f64,
+    /// Time and size of the last speed sample
+    last_sample: Option<(Instant, i64)>,
+    processing_step: Option<ProcessingStep>,
+    /// Folder scanned into the song database after extraction
+    scanned_directory: Option<String>,
+    /// Password passed to the extractor for encrypted archives.
+    password: Option<String>,
+    password_required: bool,
+    /// Sources that already failed for this task, skipped by mirror fallback.
+    failed_sources: Vec<String>,
+    /// Difficulty table the chart was requested from, for the placement rules.
+    table: Option<String>,
 }
 
 impl DownloadTask {
@@ -60,6 +122,18 @@ impl DownloadTask {
             content_length: 0,
             error_message: None,
             time_finished: AtomicI64::new(0),
+            source_name: String::new(),
+            download_path: None,
+            pause_requested: false,
+            cancel_requested: false,
+            speed: 0.0,
+            last_sample: None,
+            processing_step: None,
+            scanned_directory: None,
+            password: None,
+            password_required: false,
+            failed_sources: Vec::new(),
+            table: None,
         }
     }
 
@@ -71,6 +145,10 @@ impl DownloadTask {
         &self.url
     }
 
+    pub fn set_url(&mut self, url: String) {
+        self.url = url;
+    }
+
     pub fn hash(&self) -> &str {
         &self.hash
     }
@@ -80,7 +158,7 @@ impl DownloadTask {
     }
 
     pub fn set_download_task_status(&mut self, status: DownloadTaskStatus) {
-        if status.value() >= DownloadTaskStatus::Extracted.value() {
+        if status.is_finished() {
             // Java: System.nanoTime()
             // Use std::time::Instant elapsed as nanos approximation
             let now = std::time::SystemTime::now()
@@ -100,6 +178,10 @@ impl DownloadTask {
         self.error_message = Some(error_message);
     }
 
+    pub fn clear_error_message(&mut self) {
+        self.error_message = None;
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -107,6 +189,141 @@ impl DownloadTask {
     pub fn time_finished(&self) -> i64 {
         self.time_finished.load(Ordering::Acquire)
     }
+
+    /// Name of the download source shown in notifications.
+    pub fn source_name(&self) -> &str {
+        &self.source_name
+    }
+
+    pub fn set_source_name(&mut self, source_name: String) {
+        self.source_name = source_name;
+    }
+
+    pub fn download_path(&self) -> Option<&Path> {
+        self.download_path.as_deref()
+    }
+
+    pub fn set_download_path(&mut self, download_path: PathBuf) {
+        self.download_path = Some(download_path);
+    }
+
+    pub fn clear_download_path(&mut self) {
+        self.download_path = None;
+    }
+
+    /// Whether the download thread should stop after the current chunk.
+    pub fn pause_requested(&self) -> bool {
+        self.pause_requested
+    }
+
+    pub fn set_pause_requested(&mut self, pause_requested: bool) {
+        self.pause_requested = pause_requested;
+    }
+
+    /// Whether the download thread should stop and drop the task.
+    pub fn cancel_requested(&self) -> bool {
+        self.cancel_requested
+    }
+
+    pub fn set_cancel_requested(&mut self, cancel_requested: bool) {
+        self.cancel_requested = cancel_requested;
+    }
+
+    /// Set the downloaded size and update the download speed.
+    pub fn record_progress(&mut self, download_size: i64) {
+        self.record_progress_at(download_size, Instant::now());
+    }
+
+    fn record_progress_at(&mut self, download_size: i64, now: Instant) {
+        self.download_size = download_size;
+        let Some((last_time, last_size)) = self.last_sample else {
+            self.last_sample = Some((now, download_size));
+            return;
+        };
+        let elapsed = now.duration_since(last_time);
+        if elapsed < SPEED_SAMPLE_INTERVAL {
+            return;
+        }
+        let sample = (download_size - last_size).max(0) as f64 / elapsed.as_secs_f64();
+        self.speed = if self.speed > 0.0 {
+            self.speed + (sample - self.speed) * SPEED_SMOOTHING
+        } else {
+            sample
+        };
+        self.last_sample = Some((now, download_size));
+    }
+
+    /// Forget the speed samples, when a download (re)starts.
+    pub fn reset_speed(&mut self) {
+        self.speed = 0.0;
+        self.last_sample = None;
+    }
+
+    /// Download speed in bytes per second, 0 until it is measured.
+    pub fn speed(&self) -> f64 {
+        self.speed
+    }
+
+    /// Estimated time left for the download, when the size and speed are known.
+    pub fn eta(&self) -> Option<Duration> {
+        if self.content_length <= 0 || self.speed <= 0.0 {
+            return None;
+        }
+        let remaining = (self.content_length - self.download_size).max(0) as f64;
+        Some(Duration::from_secs_f64(remaining / self.speed))
+    }
+
+    pub fn processing_step(&self) -> Option<ProcessingStep> {
+        self.processing_step
+    }
+
+    pub fn set_processing_step(&mut self, processing_step: Option<ProcessingStep>) {
+        self.processing_step = processing_step;
+    }
+
+    pub fn scanned_directory(&self) -> Option<&str> {
+        self.scanned_directory.as_deref()
+    }
+
+    pub fn set_scanned_directory(&mut self, scanned_directory: Option<String>) {
+        self.scanned_directory = scanned_directory;
+    }
+
+    pub fn password(&self) -> Option<&str> {
+        self.password.as_deref()
+    }
+
+    pub fn set_password(&mut self, password: Option<String>) {
+        self.password = password;
+    }
+
+    /// Whether extraction failed because the archive is encrypted and the
+    /// password is missing or wrong.
+    pub fn password_required(&self) -> bool {
+        self.password_required
+    }
+
+    pub fn set_password_required(&mut self, password_required: bool) {
+        self.password_required = password_required;
+    }
+
+    pub fn failed_sources(&self) -> &[String] {
+        &self.failed_sources
+    }
+
+    pub fn add_failed_source(&mut self, source_name: String) {
+        if !self.failed_sources.contains(&source_name) {
+            self.failed_sources.push(source_name);
+        }
+    }
+
+    pub fn table(&self) -> Option<&str> {
+        self.table.as_deref()
+    }
+
+    pub fn set_table(&mut self, table: Option<String>) {
+        self.table = table;
+    }
 }
 
 #[cfg(test)]
@@ -121,6 +338,8 @@ mod tests {
         assert_eq!(DownloadTaskStatus::Extracted.value(), 3);
         assert_eq!(DownloadTaskStatus::Error.value(), 4);
         assert_eq!(DownloadTaskStatus::Cancel.value(), 5);
+        assert_eq!(DownloadTaskStatus::Queued.value(), 6);
+        assert_eq!(DownloadTaskStatus::Paused.value(), 7);
     }
 
     #[test]
@@ -132,6 +351,8 @@ mod tests {
         assert_eq!(DownloadTaskStatus::Extracted.name(), "Finished");
         assert_eq!(DownloadTaskStatus::Error.name(), "Error");
         assert_eq!(DownloadTaskStatus::Cancel.name(), "Cancel");
+        assert_eq!(DownloadTaskStatus::Queued.name(), "Queued");
+        assert_eq!(DownloadTaskStatus::Paused.name(), "Paused");
     }
 
     #[test]
@@ -193,10 +414,26 @@ mod tests {
         );
         task.set_download_task_status(DownloadTaskStatus::Error);
         assert_eq!(task.download_task_status(), DownloadTaskStatus::Error);
-        // Error (value 4) >= Extracted (value 3), so time_finished is set
+        // Error is a finished status, so time_finished is set
         assert_ne!(task.time_finished(), 0);
     }
 
+    #[test]
+    fn queued_and_paused_are_not_finished() {
+        let mut task = DownloadTask::new(
+            6,
+            "https://example.com/e.7z".to_string(),
+            "Song E".to_string(),
+            "hash_e".to_string(),
+        );
+        task.set_download_task_status(DownloadTaskStatus::Queued);
+        task.set_download_task_status(DownloadTaskStatus::Paused);
+        assert!(!DownloadTaskStatus::Queued.is_finished());
+        assert!(!DownloadTaskStatus::Paused.is_finished());
+        assert!(DownloadTaskStatus::Cancel.is_finished());
+        assert_eq!(task.time_finished(), 0);
+    }
+
     #[test]
     fn download_size_and_content_length() {
         let mut task = DownloadTask::new(
@@ -222,6 +459,8 @@ mod tests {
         assert!(task.error_message().is_none());
         task.set_error_message("Connection timeout".to_string());
         assert_eq!(task.error_message(), Some("Connection timeout"));
+        task.clear_error_message();
+        assert!(task.error_message().is_none());
     }
 
     #[test]
@@ -233,6 +472,33 @@ mod tests {
         assert_eq!(task.hash(), "");
     }
 
+    #[test]
+    fn speed_and_eta_follow_progress_samples() {
+        let mut task = DownloadTask::new(8, String::new(), String::new(), String::new());
+        task.content_length = 10_000;
+        let start = Instant::now();
+        task.record_progress_at(0, start);
+        assert_eq!(task.speed(), 0.0);
+        assert!(task.eta().is_none());
+
+        // Samples closer than the interval are skipped
+        task.record_progress_at(500, start + Duration::from_millis(100));
+        assert_eq!(task.download_size, 500);
+        assert_eq!(task.speed(), 0.0);
+
+        task.record_progress_at(1_000, start + Duration::from_secs(1));
+        assert_eq!(task.speed(), 1_000.0);
+        assert_eq!(task.eta(), Some(Duration::from_secs(9)));
+
+        // Later samples are smoothed
+        task.record_progress_at(3_000, start + Duration::from_secs(2));
+        assert_eq!(task.speed(), 1_300.0);
+
+        task.reset_speed();
+        assert_eq!(task.speed(), 0.0);
+        assert!(task.eta().is_none());
+    }
+
     #[test]
     fn special_characters_in_fields() {
         let task = DownloadTask::new(
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
-use super::download_task::{DownloadTask, DownloadTaskStatus};
+use super::download_task::DownloadTask;
 use super::http_download_processor::HttpDownloadProcessor;
 
 use crate::skin::sync_utils::lock_or_recover;
@@ -70,8 +70,7 @@ impl DownloadTaskState {
             }
 
             let task = lock_or_recover(task_arc);
-            let finished =
-                task.download_task_status().value() >= DownloadTaskStatus::Extracted.value();
+            let finished = task.download_task_status().is_finished();
             let now_nanos = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or_default()
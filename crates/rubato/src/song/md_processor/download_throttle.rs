@@ -0,0 +1,132 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::skin::sync_utils::lock_or_recover;
+
+/// Smallest burst the cap allows, so one read chunk always fits.
+const MIN_BURST_BYTES: f64 = 8192.0;
+
+/// Shared by every download thread: a global speed cap and a switch that holds
+/// downloads while a chart is loading or playing.
+#[derive(Debug)]
+pub struct DownloadThrottle {
+    /// 0 means unlimited.
+    bytes_per_second: AtomicU64,
+    bucket: Mutex<Bucket>,
+    suspended: AtomicBool,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Bytes that may be read right away; negative while readers are in debt.
+    available: f64,
+    refilled: Instant,
+}
+
+impl Default for DownloadThrottle {
+    fn default() -> Self {
+        Self {
+            bytes_per_second: AtomicU64::new(0),
+            bucket: Mutex::new(Bucket {
+                available: 0.0,
+                refilled: Instant::now(),
+            }),
+            suspended: AtomicBool::new(false),
+        }
+    }
+}
+
+impl DownloadThrottle {
+    /// Cap the combined speed of all downloads; 0 removes the cap.
+    pub fn set_limit(&self, bytes_per_second: u64) {
+        self.bytes_per_second
+            .store(bytes_per_second, Ordering::Release);
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.bytes_per_second.load(Ordering::Acquire)
+    }
+
+    pub fn set_suspended(&self, suspended: bool) {
+        self.suspended.store(suspended, Ordering::Release);
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.suspended.load(Ordering::Acquire)
+    }
+
+    /// Account for `bytes` just read, sleeping as long as needed to keep all
+    /// downloads together under the cap.
+    pub fn consume(&self, bytes: usize) {
+        let rate = self.limit() as f64;
+        if rate <= 0.0 {
+            return;
+        }
+        let wait = {
+            let mut bucket = lock_or_recover(&self.bucket);
+            let now = Instant::now();
+            let burst = (rate / 4.0).max(MIN_BURST_BYTES);
+            let refill = now.duration_since(bucket.refilled).as_secs_f64() * rate;
+            bucket.available = (bucket.available + refill).min(burst) - bytes as f64;
+            bucket.refilled = now;
+            if bucket.available >= 0.0 {
+                Duration::ZERO
+            } else {
+                Duration::from_secs_f64(-bucket.available / rate)
+            }
+        };
+        if !wait.is_zero() {
+            thread::sleep(wait);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn unlimited_never_waits() {
+        let throttle = DownloadThrottle::default();
+        let start = Instant::now();
+        for _ in 0..1000 {
+            throttle.consume(1 << 20);
+        }
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
+
+    #[test]
+    fn cap_is_shared_by_all_downloads() {
+        let throttle = Arc::new(DownloadThrottle::default());
+        throttle.set_limit(200_000);
+        let start = Instant::now();
+        let readers: Vec<_> = (0..2)
+            .map(|_| {
+                let throttle = throttle.clone();
+                thread::spawn(move || {
+                    for _ in 0..10 {
+                        throttle.consume(8192);
+                    }
+                })
+            })
+            .collect();
+        for reader in readers {
+            reader.join().unwrap();
+        }
+        // 160 KiB at 200 KB/s in total
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(500), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(3), "{:?}", elapsed);
+    }
+
+    #[test]
+    fn suspension_flag() {
+        let throttle = DownloadThrottle::default();
+        assert!(!throttle.is_suspended());
+        throttle.set_suspended(true);
+        assert!(throttle.is_suspended());
+    }
+}
@@ -1,57 +1,80 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs;
 use std::io::Write;
 use std::path::{Component, Path, PathBuf};
-use std::sync::atomic::{AtomicI32, AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicI32, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, LazyLock, Mutex};
 use std::thread;
 use std::time::Duration;
 
 use crate::skin::sync_utils::lock_or_recover;
+use base64::Engine;
+use md5::Md5;
 use regex::Regex;
+use sha2::{Digest, Sha256};
 
-use super::download_task::{DownloadTask, DownloadTaskStatus};
+use super::download_source_registry::{DOWNLOAD_SOURCES_FILE, DownloadSourceRegistry};
+use super::download_task::{DownloadTask, DownloadTaskStatus, ProcessingStep};
+use super::download_throttle::DownloadThrottle;
 use super::http_download_source::HttpDownloadSource;
 use super::http_download_source_meta::HttpDownloadSourceMeta;
+use super::placement::{PlacementRules, sanitize_name};
+use super::source_stats::{SOURCE_STATS_FILE, SourceStats, SourceStatsStore};
+use super::wriggle_download_source;
 use super::{ImGuiNotify, MainControllerRef};
-use super::{konmai_download_source, wriggle_download_source};
-
-pub static DOWNLOAD_SOURCES: LazyLock<HashMap<String, &'static HttpDownloadSourceMeta>> =
-    LazyLock::new(|| {
-        let mut map = HashMap::new();
-        // Wriggle
-        let wriggle_meta: &HttpDownloadSourceMeta = &wriggle_download_source::META;
-        map.insert(wriggle_meta.name().to_string(), wriggle_meta);
-        // Konmai
-        let konmai_meta: &HttpDownloadSourceMeta = &konmai_download_source::META;
-        map.insert(konmai_meta.name().to_string(), konmai_meta);
-        map
-    });
+use crate::imgui_notify::ToastType;
+use crate::tr;
+
+/// Built-in sources plus the ones defined in `download_sources.json`.
+pub static DOWNLOAD_SOURCES: LazyLock<DownloadSourceRegistry> =
+    LazyLock::new(|| DownloadSourceRegistry::load(Path::new(DOWNLOAD_SOURCES_FILE)));
+
+/// Upper bound for parallel downloads (see `NetworkConfig::max_concurrent_downloads`).
+pub const MAXIMUM_DOWNLOAD_COUNT: usize = crate::core::config::MAX_CONCURRENT_DOWNLOADS as usize;
 
-pub const MAXIMUM_DOWNLOAD_COUNT: usize = 5;
+/// Task id => task
+type TaskMap = HashMap<i32, Arc<Mutex<DownloadTask>>>;
+
+/// State shared by the processor and its download threads.
+struct DownloadQueue {
+    download_directory: String,
+    // id => task
+    tasks: Arc<Mutex<TaskMap>>,
+    // O(1) duplicate URL check without iterating/locking individual tasks
+    submitted_urls: Mutex<HashSet<String>>,
+    // In-memory self-add id generator
+    id_generator: AtomicI32,
+    // Tasks waiting for a download slot, oldest first
+    pending: Mutex<VecDeque<Arc<Mutex<DownloadTask>>>>,
+    // Active download thread count, kept at or below max_parallel
+    active_downloads: AtomicUsize,
+    max_parallel: AtomicUsize,
+    // Primary source first, then the mirrors tried when it fails, by priority
+    sources: Mutex<Vec<Arc<dyn HttpDownloadSource>>>,
+    // Per-source reliability, used to order the mirrors
+    stats: Mutex<SourceStatsStore>,
+    // Where extracted packages go inside download_directory
+    placement: Mutex<PlacementRules>,
+    // Bumped after each package is scanned into the song database
+    library_revision: AtomicU64,
+    // Global speed cap, and the switch holding downloads during play
+    throttle: DownloadThrottle,
+    // A reference to the main controller, only used for updating folder and rendering the message
+    main: Arc<dyn MainControllerRef>,
+}
 
 /// Corresponds to HttpDownloadProcessor in Java
 ///
 /// In-game download processor. In charge of:
 /// - Manage all download tasks(stored in memory)
-/// - Accept download task submission
-/// - Download compressed files from remote http server
+/// - Accept download task submission and queue it until a download slot is free
+/// - Download compressed files from remote http server, pausing and resuming on request
+/// - Verify the package and fall back to other sources when it is broken or missing
 /// - Extract & update the 'songdata.db' automatically
 pub struct HttpDownloadProcessor {
-    download_directory: String,
-    // id => task
-    tasks: Arc<Mutex<HashMap<i32, Arc<Mutex<DownloadTask>>>>>,
-    // O(1) duplicate URL check without iterating/locking individual tasks
-    submitted_urls: Arc<Mutex<HashSet<String>>>,
+    queue: Arc<DownloadQueue>,
     // O(1) duplicate MD5 check on the calling thread (no I/O) to avoid redundant spawns
     submitted_md5s: Arc<Mutex<HashSet<String>>>,
-    // In-memory self-add id generator
-    id_generator: Arc<AtomicI32>,
-    // Active download thread count, enforces MAXIMUM_DOWNLOAD_COUNT
-    active_downloads: Arc<AtomicUsize>,
-    // A reference to the main controller, only used for updating folder and rendering the message
-    main: Arc<dyn MainControllerRef>,
-    http_download_source: Arc<dyn HttpDownloadSource>,
 }
 
 impl HttpDownloadProcessor {
@@ -60,15 +83,24 @@ impl HttpDownloadProcessor {
         http_download_source: Arc<dyn HttpDownloadSource>,
         download_directory: String,
     ) -> Self {
+        let stats = SourceStatsStore::load(Path::new(&download_directory).join(SOURCE_STATS_FILE));
         HttpDownloadProcessor {
-            download_directory,
-            tasks: Arc::new(Mutex::new(HashMap::new())),
-            active_downloads: Arc::new(AtomicUsize::new(0)),
-            submitted_urls: Arc::new(Mutex::new(HashSet::new())),
+            queue: Arc::new(DownloadQueue {
+                download_directory,
+                tasks: Arc::new(Mutex::new(HashMap::new())),
+                submitted_urls: Mutex::new(HashSet::new()),
+                id_generator: AtomicI32::new(0),
+                pending: Mutex::new(VecDeque::new()),
+                active_downloads: AtomicUsize::new(0),
+                max_parallel: AtomicUsize::new(MAXIMUM_DOWNLOAD_COUNT),
+                sources: Mutex::new(vec![http_download_source]),
+                stats: Mutex::new(stats),
+                placement: Mutex::new(PlacementRules::default()),
+                library_revision: AtomicU64::new(0),
+                throttle: DownloadThrottle::default(),
+                main,
+            }),
             submitted_md5s: Arc::new(Mutex::new(HashSet::new())),
-            id_generator: Arc::new(AtomicI32::new(0)),
-            main,
-            http_download_source,
         }
     }
 
@@ -80,7 +112,78 @@ impl HttpDownloadProcessor {
     // without creating a copy, in the interest of efficiency,
     // however I'm not sure if that is possible in java
     pub fn all_tasks(&self) -> Arc<Mutex<HashMap<i32, Arc<Mutex<DownloadTask>>>>> {
-        self.tasks.clone()
+        self.queue.tasks.clone()
+    }
+
+    /// Number of downloads allowed to run at once, clamped to `1..=MAXIMUM_DOWNLOAD_COUNT`.
+    /// Raising it starts queued tasks immediately; lowering it lets running ones finish.
+    pub fn set_max_parallel_downloads(&self, count: usize) {
+        self.queue
+            .max_parallel
+            .store(count.clamp(1, MAXIMUM_DOWNLOAD_COUNT), Ordering::Release);
+        start_pending_downloads(&self.queue);
+    }
+
+    pub fn max_parallel_downloads(&self) -> usize {
+        self.queue.max_parallel.load(Ordering::Acquire)
+    }
+
+    /// Mirrors tried, highest priority and then most reliable first, when the
+    /// primary source has no package for a chart or its package fails to
+    /// download or verify.
+    pub fn set_fallback_sources(&self, fallback_sources: Vec<Arc<dyn HttpDownloadSource>>) {
+        let mut sources = lock_or_recover(&self.queue.sources);
+        sources.truncate(1);
+        sources.extend(fallback_sources);
+    }
+
+    /// Cap the combined speed of all downloads; 0 removes the cap.
+    pub fn set_speed_limit(&self, bytes_per_second: u64) {
+        self.queue.throttle.set_limit(bytes_per_second);
+    }
+
+    pub fn speed_limit(&self) -> u64 {
+        self.queue.throttle.limit()
+    }
+
+    /// Hold all downloads, e.g. while a chart plays. Running downloads stop after
+    /// their current chunk and go back to the front of the queue with their
+    /// partial file; they continue once downloads are no longer suspended.
+    pub fn set_downloads_suspended(&self, suspended: bool) {
+        if self.queue.throttle.is_suspended() == suspended {
+            return;
+        }
+        log::info!(
+            "[HttpDownloadProcessor] Downloads {}",
+            if suspended { "suspended" } else { "resumed" }
+        );
+        self.queue.throttle.set_suspended(suspended);
+        if !suspended {
+            start_pending_downloads(&self.queue);
+        }
+    }
+
+    pub fn downloads_suspended(&self) -> bool {
+        self.queue.throttle.is_suspended()
+    }
+
+    pub fn set_placement_rules(&self, rules: PlacementRules) {
+        *lock_or_recover(&self.queue.placement) = rules;
+    }
+
+    /// Number of packages scanned into the song database so far. The select
+    /// screen reloads its bars when this changes.
+    pub fn library_revision(&self) -> u64 {
+        self.queue.library_revision.load(Ordering::Acquire)
+    }
+
+    /// Recorded reliability of every source that has been used.
+    pub fn source_stats(&self) -> Vec<(String, SourceStats)> {
+        lock_or_recover(&self.queue.stats)
+            .all()
+            .iter()
+            .map(|(name, stats)| (name.clone(), stats.clone()))
+            .collect()
     }
 
     /// Submit a download task based on md5
@@ -89,6 +192,12 @@ impl HttpDownloadProcessor {
     /// * `md5` - missing sabun's md5
     /// * `task_name` - task name, normally sabun's name
     pub fn submit_md5_task(&self, md5: &str, task_name: &str) {
+        self.submit_md5_task_in_table(md5, task_name, None);
+    }
+
+    /// Same as [`submit_md5_task`](Self::submit_md5_task), for a chart requested
+    /// from the difficulty table `table`
+    pub fn submit_md5_task_in_table(&self, md5: &str, task_name: &str, table: Option<&str>) {
         log::info!(
             "[HttpDownloadProcessor] Trying to submit new download task[{}](based on md5: {})",
             task_name,
@@ -104,23 +213,18 @@ impl HttpDownloadProcessor {
                     task_name,
                     md5
                 );
-                ImGuiNotify::warning("Already submitted");
+                ImGuiNotify::warning(&tr!("notify-already-submitted"));
                 return;
             }
             md5s.insert(md5.to_string());
         }
 
         // Move the blocking get_download_url_based_on_md5() call off the calling thread.
-        let http_download_source = self.http_download_source.clone();
         let submitted_md5s = self.submitted_md5s.clone();
-        let submitted_urls = self.submitted_urls.clone();
-        let tasks = self.tasks.clone();
-        let id_generator = self.id_generator.clone();
-        let active_downloads = self.active_downloads.clone();
-        let download_directory = self.download_directory.clone();
-        let main = self.main.clone();
+        let queue = self.queue.clone();
         let md5 = md5.to_string();
         let task_name = task_name.to_string();
+        let table = table.map(str::to_string);
 
         thread::spawn(move || {
             // Guard that cleans up the md5 from submitted_md5s when this thread exits
@@ -140,12 +244,10 @@ impl HttpDownloadProcessor {
                 md5: md5.clone(),
             };
 
-            let source_name = http_download_source.name().to_string();
-
-            // Blocking HTTP call to resolve the download URL from the md5.
-            let download_url = match http_download_source.get_download_url_based_on_md5(&md5) {
-                Ok(url) => url,
-                Err(e) => {
+            // Blocking HTTP calls to resolve the download URL from the md5.
+            let (download_url, source_name) = match resolve_download_url(&queue, &md5, &[]) {
+                Ok(resolved) => resolved,
+                Err((source_name, e)) => {
                     // Fragile: uses string comparison for error discrimination.
                     // Typed error enum would be more robust, but this matches the existing protocol.
                     let err_msg = e.to_string();
@@ -154,9 +256,9 @@ impl HttpDownloadProcessor {
                             "[HttpDownloadProcessor] Remote server[{}] reports no such data",
                             source_name
                         );
-                        ImGuiNotify::error(&format!(
-                            "Cannot find specified song from {}",
-                            source_name
+                        ImGuiNotify::error(&tr!(
+                            "notify-download-song-not-found",
+                            source = source_name.to_string()
                         ));
                     } else {
                         log::error!(
@@ -164,326 +266,719 @@ impl HttpDownloadProcessor {
                             source_name,
                             err_msg
                         );
-                        ImGuiNotify::error(&format!(
-                            "{} returns a severe error: {}",
-                            source_name, err_msg
+                        ImGuiNotify::error(&tr!(
+                            "notify-download-source-error",
+                            source = source_name.to_string(),
+                            error = err_msg.to_string()
                         ));
                     }
                     return;
                 }
             };
 
-            // URL-based dedup (prevents duplicate downloads of the same URL from different md5s).
-            let download_task = {
-                let mut urls = lock_or_recover(&submitted_urls);
-                if urls.contains(&download_url) {
-                    log::error!(
-                        "[HttpDownloadProcessor] Rejecting download task[{}] because duplication has been found",
-                        download_url
-                    );
-                    ImGuiNotify::warning("Already submitted");
-                    return;
-                }
-                let task_id = id_generator.fetch_add(1, Ordering::SeqCst) + 1;
-                let download_task = Arc::new(Mutex::new(DownloadTask::new(
-                    task_id,
-                    download_url.clone(),
-                    task_name.clone(),
-                    md5.clone(),
-                )));
-                urls.insert(download_url);
-                drop(urls);
-                let mut all_tasks = lock_or_recover(&tasks);
-                all_tasks.insert(task_id, download_task.clone());
-                ImGuiNotify::info(&format!("New download task[{}] submitted", task_name));
-                download_task
-            };
-
-            // Execute the download (reserve slot, spawn download thread).
-            execute_download_task_static(
-                download_task,
-                &active_downloads,
-                &submitted_urls,
-                &download_directory,
-                &main,
-                &source_name,
-            );
+            enqueue_download_task(&queue, download_url, task_name, md5, &source_name, table);
         });
     }
 
-    /// Execute the download task, which are chained steps:
-    /// 1. Download the archive file from url
+    /// Submit a download task for an already resolved package url, e.g. one found
+    /// through BMS Search. `hash` names the archive when the server sends no file name.
+    pub fn submit_url_task(&self, url: &str, hash: &str, task_name: &str) {
+        self.submit_url_task_in_table(url, hash, task_name, None);
+    }
+
+    /// Same as [`submit_url_task`](Self::submit_url_task), for a chart requested
+    /// from the difficulty table `table`
+    pub fn submit_url_task_in_table(
+        &self,
+        url: &str,
+        hash: &str,
+        task_name: &str,
+        table: Option<&str>,
+    ) {
+        log::info!(
+            "[HttpDownloadProcessor] Trying to submit new download task[{}]({})",
+            task_name,
+            url
+        );
+        enqueue_download_task(
+            &self.queue,
+            url.to_string(),
+            task_name.to_string(),
+            hash.to_string(),
+            "BMS Search",
+            table.map(str::to_string),
+        );
+    }
+
+    /// Queue the download task and start it as soon as a download slot is free.
+    /// The task runs these chained steps:
+    /// 1. Download the archive file from url (resuming a partial download if any)
     /// 2. Extract the package
     /// 3. Update download directory
     /// 4. Delete the archive file
     pub fn execute_download_task(&self, download_task: Arc<Mutex<DownloadTask>>) {
-        let source_name = self.http_download_source.name().to_string();
-        execute_download_task_static(
-            download_task,
-            &self.active_downloads,
-            &self.submitted_urls,
-            &self.download_directory,
-            &self.main,
-            &source_name,
-        );
+        {
+            let mut task = lock_or_recover(&download_task);
+            if task.source_name().is_empty() {
+                task.set_source_name(lock_or_recover(&self.queue.sources)[0].name().to_string());
+            }
+            task.set_pause_requested(false);
+            task.set_cancel_requested(false);
+            task.set_password_required(false);
+            task.set_processing_step(None);
+            task.clear_error_message();
+            task.set_download_task_status(DownloadTaskStatus::Queued);
+            lock_or_recover(&self.queue.submitted_urls).insert(task.url().to_string());
+        }
+        lock_or_recover(&self.queue.pending).push_back(download_task);
+        start_pending_downloads(&self.queue);
     }
 
-    /// Retry a download task
-    // Known limitation: retried tasks bypass submitted_urls guard. Concurrent retries
-    // of the same URL may race on extraction paths.
+    /// Retry a download task, continuing from its partial download when the server
+    /// supports range requests.
     pub fn retry_download_task(&self, download_task: Arc<Mutex<DownloadTask>>) {
-        {
-            let mut task = lock_or_recover(&download_task);
-            task.set_download_task_status(DownloadTaskStatus::Prepare);
+        self.execute_download_task(download_task);
+    }
+
+    /// Retry extracting an encrypted archive with `password`. The archive is
+    /// kept after a password failure, so nothing is downloaded again.
+    pub fn extract_with_password(&self, download_task: Arc<Mutex<DownloadTask>>, password: String) {
+        lock_or_recover(&download_task).set_password(Some(password));
+        self.execute_download_task(download_task);
+    }
+
+    /// Pause a queued or running task. A running download stops after its current
+    /// chunk and keeps the partial file. Returns false when the task cannot be paused.
+    pub fn pause_download_task(&self, download_task: &Arc<Mutex<DownloadTask>>) -> bool {
+        let mut task = lock_or_recover(download_task);
+        match task.download_task_status() {
+            DownloadTaskStatus::Queued => {
+                lock_or_recover(&self.queue.pending).retain(|t| !Arc::ptr_eq(t, download_task));
+                task.set_download_task_status(DownloadTaskStatus::Paused);
+                true
+            }
+            DownloadTaskStatus::Prepare | DownloadTaskStatus::Downloading => {
+                task.set_pause_requested(true);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Cancel a task that is not extracting or finished, deleting its partial
+    /// download. A running download stops after its current chunk. Returns false
+    /// when the task cannot be cancelled.
+    pub fn cancel_download_task(&self, download_task: &Arc<Mutex<DownloadTask>>) -> bool {
+        let mut task = lock_or_recover(download_task);
+        match task.download_task_status() {
+            DownloadTaskStatus::Queued | DownloadTaskStatus::Paused | DownloadTaskStatus::Error => {
+                lock_or_recover(&self.queue.pending).retain(|t| !Arc::ptr_eq(t, download_task));
+                lock_or_recover(&self.queue.submitted_urls).remove(task.url());
+                cancel_task(&mut task);
+                true
+            }
+            DownloadTaskStatus::Prepare | DownloadTaskStatus::Downloading => {
+                task.set_cancel_requested(true);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Put a paused task back in the queue. Returns false when the task is not paused.
+    pub fn resume_download_task(&self, download_task: Arc<Mutex<DownloadTask>>) -> bool {
+        if lock_or_recover(&download_task).download_task_status() != DownloadTaskStatus::Paused {
+            return false;
         }
         self.execute_download_task(download_task);
+        true
     }
 }
 
-/// Static helper for `execute_download_task` so it can be called both from `&self` methods
-/// and from inside spawned threads (where `&self` is not available).
-fn execute_download_task_static(
-    download_task: Arc<Mutex<DownloadTask>>,
-    active_downloads: &Arc<AtomicUsize>,
-    submitted_urls: &Arc<Mutex<HashSet<String>>>,
-    download_directory: &str,
-    main: &Arc<dyn MainControllerRef>,
+/// Register a task for `download_url` and queue it, unless the url is already queued.
+fn enqueue_download_task(
+    queue: &Arc<DownloadQueue>,
+    download_url: String,
+    task_name: String,
+    hash: String,
     source_name: &str,
+    table: Option<String>,
 ) {
-    // Reserve a download slot atomically using compare_exchange to prevent
-    // concurrent threads from exceeding MAXIMUM_DOWNLOAD_COUNT.
-    loop {
-        let current = active_downloads.load(Ordering::Acquire);
-        if current >= MAXIMUM_DOWNLOAD_COUNT {
-            log::warn!(
-                "[HttpDownloadProcessor] Maximum concurrent downloads ({}) reached, rejecting task",
-                MAXIMUM_DOWNLOAD_COUNT
+    // URL-based dedup (prevents duplicate downloads of the same URL from different md5s).
+    let download_task = {
+        let mut urls = lock_or_recover(&queue.submitted_urls);
+        if urls.contains(&download_url) {
+            log::error!(
+                "[HttpDownloadProcessor] Rejecting download task[{}] because duplication has been found",
+                download_url
             );
-            ImGuiNotify::warning("Download queue is full, try again later");
-            let mut task = lock_or_recover(&download_task);
-            // Release the URL from submitted_urls so the user can retry later
-            {
-                let mut urls = lock_or_recover(submitted_urls);
-                urls.remove(task.url());
-            }
-            task.set_download_task_status(DownloadTaskStatus::Error);
+            ImGuiNotify::warning(&tr!("notify-already-submitted"));
+            return;
+        }
+        let task_id = queue.id_generator.fetch_add(1, Ordering::SeqCst) + 1;
+        let mut task = DownloadTask::new(task_id, download_url.clone(), task_name.clone(), hash);
+        task.set_source_name(source_name.to_string());
+        task.set_table(table);
+        task.set_download_task_status(DownloadTaskStatus::Queued);
+        let download_task = Arc::new(Mutex::new(task));
+        urls.insert(download_url);
+        drop(urls);
+        let mut all_tasks = lock_or_recover(&queue.tasks);
+        all_tasks.insert(task_id, download_task.clone());
+        ImGuiNotify::info(&tr!(
+            "notify-download-submitted",
+            name = task_name.to_string()
+        ));
+        download_task
+    };
+
+    lock_or_recover(&queue.pending).push_back(download_task);
+    start_pending_downloads(queue);
+}
+
+/// Start queued tasks until every download slot is taken or the queue is empty.
+fn start_pending_downloads(queue: &Arc<DownloadQueue>) {
+    loop {
+        if queue.throttle.is_suspended() {
+            return;
+        }
+        // Reserve a download slot atomically using compare_exchange to prevent
+        // concurrent callers from exceeding max_parallel.
+        let current = queue.active_downloads.load(Ordering::Acquire);
+        if current >= queue.max_parallel.load(Ordering::Acquire) {
             return;
         }
-        if active_downloads
+        if queue
+            .active_downloads
             .compare_exchange(current, current + 1, Ordering::AcqRel, Ordering::Acquire)
-            .is_ok()
+            .is_err()
         {
-            break;
+            continue;
         }
+        let Some(download_task) = lock_or_recover(&queue.pending).pop_front() else {
+            queue.active_downloads.fetch_sub(1, Ordering::AcqRel);
+            return;
+        };
+        let queue = queue.clone();
+        thread::spawn(move || run_download_task(queue, download_task));
     }
+}
 
-    let download_directory = download_directory.to_string();
-    let main = main.clone();
-    let source_name = source_name.to_string();
-    let active_downloads = active_downloads.clone();
-    let submitted_urls = submitted_urls.clone();
-
-    thread::spawn(move || {
-        struct DownloadGuard {
-            active_downloads: Arc<AtomicUsize>,
-            submitted_urls: Arc<Mutex<HashSet<String>>>,
-            download_url: String,
+/// Resolve a chart `hash` (md5, or sha256 for the sources that take it) to a
+/// download url, trying the primary source first and then the mirrors by
+/// priority, most reliable first among equals, skipping the sources in `skip`.
+///
+/// # Returns
+/// the url and the name of the source that has it, or the last source's error
+fn resolve_download_url(
+    queue: &DownloadQueue,
+    hash: &str,
+    skip: &[String],
+) -> Result<(String, String), (String, anyhow::Error)> {
+    let by_sha256 = hash.len() == 64;
+    let mut candidates = lock_or_recover(&queue.sources).clone();
+    {
+        let stats = lock_or_recover(&queue.stats);
+        candidates[1..].sort_by(|a, b| {
+            let rate =
+                |source: &Arc<dyn HttpDownloadSource>| stats.get(source.name()).success_rate();
+            b.priority()
+                .cmp(&a.priority())
+                .then_with(|| rate(b).total_cmp(&rate(a)))
+        });
+    }
+    let mut last_error = (
+        "download sources".to_string(),
+        anyhow::anyhow!("No other download source left"),
+    );
+    for source in candidates.iter().filter(|source| {
+        !skip.iter().any(|name| name == source.name())
+            && if by_sha256 {
+                source.is_allow_download_through_sha256()
+            } else {
+                source.is_allow_download_through_md5()
+            }
+    }) {
+        let source_name = source.name().to_string();
+        let resolved = if by_sha256 {
+            source.get_download_url_based_on_sha256(hash)
+        } else {
+            source.get_download_url_based_on_md5(hash)
+        };
+        match resolved {
+            Ok(url) => return Ok((url, source_name)),
+            Err(e) => {
+                log::warn!(
+                    "[HttpDownloadProcessor] {} cannot resolve hash {}: {}",
+                    source_name,
+                    hash,
+                    e
+                );
+                lock_or_recover(&queue.stats).record_failure(&source_name, &e.to_string(), false);
+                last_error = (source_name, e);
+            }
         }
-        impl Drop for DownloadGuard {
-            fn drop(&mut self) {
-                self.active_downloads.fetch_sub(1, Ordering::AcqRel);
-                // Remove URL from submitted set so it can be retried
-                let mut urls = lock_or_recover(&self.submitted_urls);
-                urls.remove(&self.download_url);
+    }
+    Err(last_error)
+}
+
+/// Body of a download thread. Holds one download slot until it returns.
+///
+/// When the package fails to download, extract or verify, the failure is
+/// recorded against its source and the task moves on to the next source that
+/// has the same chart, until one works or none is left.
+fn run_download_task(queue: Arc<DownloadQueue>, download_task: Arc<Mutex<DownloadTask>>) {
+    struct DownloadGuard {
+        queue: Arc<DownloadQueue>,
+        download_task: Arc<Mutex<DownloadTask>>,
+    }
+    impl Drop for DownloadGuard {
+        fn drop(&mut self) {
+            self.queue.active_downloads.fetch_sub(1, Ordering::AcqRel);
+            // Release the URL so it can be submitted again, unless the task is only
+            // paused or waiting in the queue again
+            let task = lock_or_recover(&self.download_task);
+            if !matches!(
+                task.download_task_status(),
+                DownloadTaskStatus::Paused | DownloadTaskStatus::Queued
+            ) {
+                lock_or_recover(&self.queue.submitted_urls).remove(task.url());
             }
+            drop(task);
+            start_pending_downloads(&self.queue);
         }
-        let (task_name, download_url, hash) = {
+    }
+    let _guard = DownloadGuard {
+        queue: queue.clone(),
+        download_task: download_task.clone(),
+    };
+
+    loop {
+        let Err(e) = attempt_download_task(&queue, &download_task) else {
+            return;
+        };
+        let integrity = e.is::<IntegrityError>();
+        let (task_name, hash, source_name, download_path) = {
             let task = lock_or_recover(&download_task);
             (
                 task.name().to_string(),
-                task.url().to_string(),
                 task.hash().to_string(),
+                task.source_name().to_string(),
+                task.download_path().map(Path::to_path_buf),
             )
         };
-        let _guard = DownloadGuard {
-            active_downloads,
-            submitted_urls,
-            download_url: download_url.clone(),
-        };
-        log::info!(
-            "[HttpDownloadProcessor] Trying to kick new download task[{}]({})",
+        log::error!(
+            "[HttpDownloadProcessor] Download task[{}] failed at {}: {}",
             task_name,
-            download_url
+            source_name,
+            e
         );
-        {
-            let mut task = lock_or_recover(&download_task);
-            task.set_download_task_status(DownloadTaskStatus::Downloading);
-        }
-        // 1) Download file from remote http server
-        let result = match download_file_from_url(
-            &download_task,
-            &format!("{}.7z", hash),
-            &download_directory,
-            &source_name,
-        ) {
-            Ok(path) => Some(path),
-            Err(e) => {
-                log::error!("{}", e);
-                ImGuiNotify::error(&format!(
-                    "Failed downloading from {} due to {}",
-                    source_name, e
-                ));
-                None
-            }
+        lock_or_recover(&queue.stats).record_failure(&source_name, &e.to_string(), integrity);
+        if integrity && let Some(path) = &download_path {
+            // Never keep or resume from data that failed verification
+            remove_download_files(path);
+        }
+
+        let mirror = if is_chart_hash(&hash) {
+            let mut tried = lock_or_recover(&download_task).failed_sources().to_vec();
+            tried.push(source_name.clone());
+            resolve_download_url(&queue, &hash, &tried).ok()
+        } else {
+            None
         };
-        if result.is_none() {
-            // Download failed, skip the remaining steps
+        let Some((url, mirror_name)) = mirror else {
+            ImGuiNotify::error(&tr!(
+                "notify-download-failed",
+                source = source_name.to_string(),
+                error = e.to_string()
+            ));
             let mut task = lock_or_recover(&download_task);
+            task.set_error_message(e.to_string());
             task.set_download_task_status(DownloadTaskStatus::Error);
             return;
+        };
+
+        // Another source's package is a different file; drop what this one left behind
+        if let Some(path) = &download_path {
+            remove_download_files(path);
         }
-        let result = result.expect("result");
-        // 2) Extract the compressed archive & update download directory automatically
-        let mut successfully_extracted = false;
-        let mut bms_directory: Option<String> = None;
-        match extract_compressed_file(&result, None, &download_directory) {
-            Ok(dir) => {
-                bms_directory = dir;
-                successfully_extracted = true;
-                let mut task = lock_or_recover(&download_task);
-                task.set_download_task_status(DownloadTaskStatus::Extracted);
-            }
-            Err(e) => {
-                log::error!("{}", e);
-                ImGuiNotify::error(&format!(
-                    "Failed extracting file: {} due to {}",
-                    result.display(),
-                    e
-                ));
+        ImGuiNotify::warning(&tr!(
+            "notify-download-mirror-fallback",
+            source = source_name.to_string(),
+            name = task_name.to_string(),
+            mirror = mirror_name.to_string()
+        ));
+        let mut task = lock_or_recover(&download_task);
+        {
+            let mut urls = lock_or_recover(&queue.submitted_urls);
+            urls.remove(task.url());
+            urls.insert(url.clone());
+        }
+        task.add_failed_source(source_name);
+        task.set_url(url);
+        task.set_source_name(mirror_name);
+        task.clear_download_path();
+        task.download_size = 0;
+        task.content_length = 0;
+        task.clear_error_message();
+    }
+}
+
+/// Download, extract and register one package from the task's current source.
+///
+/// # Returns
+/// `Ok` once the task is done, paused or waiting for a password; `Err` when the
+/// source failed and another one may be tried
+fn attempt_download_task(
+    queue: &DownloadQueue,
+    download_task: &Arc<Mutex<DownloadTask>>,
+) -> anyhow::Result<()> {
+    let (task_name, download_url, hash, source_name, table) = {
+        let mut task = lock_or_recover(download_task);
+        if task.cancel_requested() {
+            cancel_task(&mut task);
+            return Ok(());
+        }
+        if task.pause_requested() {
+            task.set_pause_requested(false);
+            task.set_download_task_status(DownloadTaskStatus::Paused);
+            return Ok(());
+        }
+        task.reset_speed();
+        task.set_download_task_status(DownloadTaskStatus::Downloading);
+        (
+            task.name().to_string(),
+            task.url().to_string(),
+            task.hash().to_string(),
+            task.source_name().to_string(),
+            task.table().map(str::to_string),
+        )
+    };
+    log::info!(
+        "[HttpDownloadProcessor] Trying to kick new download task[{}]({})",
+        task_name,
+        download_url
+    );
+    // 1) Download file from remote http server
+    let result = match download_file_from_url(
+        download_task,
+        &format!("{}.7z", hash),
+        &queue.download_directory,
+        &source_name,
+        &queue.throttle,
+    )? {
+        DownloadOutcome::Completed(path) => path,
+        DownloadOutcome::Paused => {
+            let mut task = lock_or_recover(download_task);
+            if task.cancel_requested() {
+                log::info!(
+                    "[HttpDownloadProcessor] Download task[{}] cancelled",
+                    task_name
+                );
+                cancel_task(&mut task);
+                return Ok(());
             }
+            log::info!(
+                "[HttpDownloadProcessor] Download task[{}] paused",
+                task_name
+            );
+            task.set_pause_requested(false);
+            task.set_download_task_status(DownloadTaskStatus::Paused);
+            return Ok(());
         }
-        if successfully_extracted {
-            // Note: Directory update is protected, this might cause some uncovered situation. Personally speaking,
-            // I don't think this has any issue since user can always turn back to root directory
-            // and update the download directory manually
-            ImGuiNotify::info(
-                "Successfully downloaded & extracted. Trying to rebuild download directory",
+        DownloadOutcome::Suspended => {
+            log::info!(
+                "[HttpDownloadProcessor] Download task[{}] held until downloads resume",
+                task_name
             );
-            if let Some(ref dir) = bms_directory {
-                main.update_song(dir, true);
-            }
-            // If everything works well, trying to delete the downloaded archive
-            if let Err(e) = fs::remove_file(&result) {
-                log::error!("{}", e);
-                ImGuiNotify::error("Failed deleting archive file automatically");
-            }
+            lock_or_recover(download_task).set_download_task_status(DownloadTaskStatus::Queued);
+            lock_or_recover(&queue.pending).push_front(download_task.clone());
+            return Ok(());
         }
-    });
+    };
+    // 2) Extract the compressed archive where the placement rules say, checking it
+    //    holds the requested chart
+    let password = {
+        let mut task = lock_or_recover(download_task);
+        task.set_processing_step(Some(ProcessingStep::Extracting));
+        task.password().map(str::to_string)
+    };
+    let placement = lock_or_recover(&queue.placement).clone();
+    let target = placement.target_directory(
+        Path::new(&queue.download_directory),
+        table.as_deref(),
+        &source_name,
+    );
+    let bms_directory = match extract_compressed_file(
+        &result,
+        Some(&target),
+        &queue.download_directory,
+        password.as_deref(),
+        Some(&hash),
+        placement.sanitize_names,
+    ) {
+        Ok(dir) => dir,
+        Err(e) if e.is::<ArchivePasswordError>() => {
+            // Keep the archive; the download menu asks for the password and retries
+            log::warn!(
+                "[HttpDownloadProcessor] Cannot extract {}: {}",
+                result.display(),
+                e
+            );
+            ImGuiNotify::warning(&tr!(
+                "notify-download-password-required",
+                name = task_name.to_string()
+            ));
+            let mut task = lock_or_recover(download_task);
+            task.set_processing_step(None);
+            task.set_error_message(e.to_string());
+            task.set_password_required(true);
+            task.set_download_task_status(DownloadTaskStatus::Error);
+            return Ok(());
+        }
+        Err(e) => {
+            ImGuiNotify::error(&tr!(
+                "notify-extract-failed",
+                path = result.display().to_string(),
+                error = e.to_string()
+            ));
+            lock_or_recover(download_task).set_processing_step(None);
+            return Err(e);
+        }
+    };
+    lock_or_recover(&queue.stats).record_success(&source_name);
+    // 3) Scan just the extracted folder so the charts show up in select right away
+    // Note: Directory update is protected, this might cause some uncovered situation. Personally speaking,
+    // I don't think this has any issue since user can always turn back to root directory
+    // and update the download directory manually
+    ImGuiNotify::info(&tr!("notify-download-extracted"));
+    if let Some(ref dir) = bms_directory {
+        {
+            let mut task = lock_or_recover(download_task);
+            task.set_processing_step(Some(ProcessingStep::Scanning));
+            task.set_scanned_directory(Some(dir.clone()));
+        }
+        queue.main.update_song(dir, true);
+        queue.library_revision.fetch_add(1, Ordering::AcqRel);
+    }
+    {
+        let mut task = lock_or_recover(download_task);
+        task.set_processing_step(Some(ProcessingStep::Scanned));
+        task.set_download_task_status(DownloadTaskStatus::Extracted);
+    }
+    if let Some(dir) = bms_directory {
+        ImGuiNotify::with_action(
+            ToastType::Success,
+            &tr!("notify-download-added", name = task_name.as_str()),
+            &tr!("notify-open-folder"),
+            Box::new(move || {
+                if let Err(e) = open::that(&dir) {
+                    log::warn!("Failed to open {}: {}", dir, e);
+                }
+            }),
+        );
+    }
+    // 4) If everything works well, trying to delete the downloaded archive
+    if let Err(e) = fs::remove_file(&result) {
+        log::error!("{}", e);
+        ImGuiNotify::error(&tr!("notify-archive-delete-failed"));
+    }
+    Ok(())
+}
+
+/// Drop a task on user request along with its downloaded files.
+fn cancel_task(task: &mut DownloadTask) {
+    if let Some(path) = task.download_path() {
+        remove_download_files(path);
+    }
+    task.clear_download_path();
+    task.set_cancel_requested(false);
+    task.set_pause_requested(false);
+    task.set_password_required(false);
+    task.reset_speed();
+    task.set_download_task_status(DownloadTaskStatus::Cancel);
+}
+
+/// Remove a downloaded archive and its partial file, if present.
+fn remove_download_files(path: &Path) {
+    for path in [path.to_path_buf(), partial_download_path(path)] {
+        if path.exists()
+            && let Err(e) = fs::remove_file(&path)
+        {
+            log::warn!("Failed to remove {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Whether `hash` looks like an md5 or sha256 the download sources can look up.
+fn is_chart_hash(hash: &str) -> bool {
+    matches!(hash.len(), 32 | 64) && hash.bytes().all(|b| b.is_ascii_hexdigit())
 }
 
 impl crate::http_download_submitter::HttpDownloadSubmitter for HttpDownloadProcessor {
     fn submit_md5_task(&self, md5: &str, task_name: &str) {
         HttpDownloadProcessor::submit_md5_task(self, md5, task_name);
     }
+
+    fn submit_url_task(&self, url: &str, hash: &str, task_name: &str) {
+        HttpDownloadProcessor::submit_url_task(self, url, hash, task_name);
+    }
+}
+
+/// How a download attempt ended.
+#[derive(Debug, PartialEq)]
+enum DownloadOutcome {
+    /// The archive is complete at this path.
+    Completed(PathBuf),
+    /// Stopped on request; the partial file is kept for resuming unless the task
+    /// was cancelled.
+    Paused,
+    /// Stopped because downloads are suspended; the partial file is kept and the
+    /// task waits in the queue.
+    Suspended,
+}
+
+/// `<path>.tmp` sibling a download is streamed into before the final rename.
+fn partial_download_path(path: &Path) -> PathBuf {
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(".tmp");
+    PathBuf::from(partial)
 }
 
 /// Download a file from url
 ///
 /// Writes to a temporary `.tmp` file first and renames to the final path on
-/// success, so a partial download never destroys an existing file.
+/// success, so a partial download never destroys an existing file. The partial
+/// file is kept when the download is paused or interrupted; the next attempt asks
+/// the server for the remaining bytes with a range request and starts over if the
+/// server ignores it.
 ///
 /// # Arguments
 /// * `fallback_file_name` - fallback file name if remote server's response doesn't contain a valid file name
+/// * `throttle` - speed cap and suspension shared by all downloads
 ///
 /// # Returns
-/// result file path
+/// result file path, or `Paused`/`Suspended` when the task or all downloads were stopped
 fn download_file_from_url(
     task: &Arc<Mutex<DownloadTask>>,
     fallback_file_name: &str,
     download_directory: &str,
     source_name: &str,
-) -> anyhow::Result<PathBuf> {
-    let url = {
+    throttle: &DownloadThrottle,
+) -> anyhow::Result<DownloadOutcome> {
+    let (url, previous_path) = {
         let t = lock_or_recover(task);
-        t.url().to_string()
+        (
+            t.url().to_string(),
+            t.download_path().map(Path::to_path_buf),
+        )
     };
+    // A previous attempt finished the download but failed to extract
+    if let Some(path) = previous_path.as_ref().filter(|path| path.is_file()) {
+        lock_or_recover(task).set_download_task_status(DownloadTaskStatus::Downloaded);
+        return Ok(DownloadOutcome::Completed(path.clone()));
+    }
+    let resume_from = previous_path
+        .as_deref()
+        .and_then(|path| fs::metadata(partial_download_path(path)).ok())
+        .map(|meta| meta.len())
+        .filter(|&len| len > 0);
 
     let client = reqwest::blocking::Client::builder()
         .timeout(Duration::from_secs(60))
         .build()?;
-    let response = client.get(&url).send()?;
+    let mut request = client.get(&url);
+    if let Some(offset) = resume_from {
+        request = request.header(reqwest::header::RANGE, format!("bytes={}-", offset));
+    }
+    let response = request.send()?;
     let response_code = response.status();
-    if response_code != reqwest::StatusCode::OK {
-        if response_code == reqwest::StatusCode::NOT_FOUND {
+    let resume_from = match (response_code, resume_from) {
+        (reqwest::StatusCode::PARTIAL_CONTENT, Some(offset)) => Some(offset),
+        (reqwest::StatusCode::RANGE_NOT_SATISFIABLE, Some(_)) => {
+            // The partial file already holds the whole archive
+            let result = previous_path.expect("resume implies a previous path");
+            fs::rename(partial_download_path(&result), &result)?;
+            lock_or_recover(task).set_download_task_status(DownloadTaskStatus::Downloaded);
+            return Ok(DownloadOutcome::Completed(result));
+        }
+        (reqwest::StatusCode::OK, _) => None,
+        (reqwest::StatusCode::NOT_FOUND, _) => {
             return Err(anyhow::anyhow!("Package not found at {}", source_name));
         }
-        return Err(anyhow::anyhow!(
-            "Unexpected http response code: {}",
-            response_code.as_u16()
-        ));
-    }
-
-    // Prepare the file name
-    let mut file_name = fallback_file_name.to_string();
-    let content_disposition = response
-        .headers()
-        .get("Content-Disposition")
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("")
-        .to_string();
-    let mut candidate_file_name = String::new();
-    if !content_disposition.is_empty() {
-        let re = Regex::new(r#"filename="?([^"]+)"?"#).expect("valid regex");
-        if let Some(caps) = re.captures(&content_disposition)
-            && let Some(m) = caps.get(1)
-        {
-            candidate_file_name = m.as_str().to_string();
+        _ => {
+            return Err(anyhow::anyhow!(
+                "Unexpected http response code: {}",
+                response_code.as_u16()
+            ));
         }
-    }
-    if !candidate_file_name.is_empty() {
-        // Sanitize filename to prevent path traversal from malicious Content-Disposition headers
-        file_name = std::path::Path::new(&candidate_file_name)
-            .file_name()
-            .map(|n| n.to_string_lossy().to_string())
-            .unwrap_or(candidate_file_name);
-    }
-
-    let content_length = response.content_length().map(|l| l as i64).unwrap_or(-1);
+    };
 
-    let result = Path::new(download_directory).join(&file_name);
-    let tmp_path = result.with_extension(format!(
-        "{}.tmp",
-        result
-            .extension()
-            .map(|e| e.to_string_lossy().to_string())
-            .unwrap_or_default()
-    ));
+    // Keep the file picked by the first attempt so a restart overwrites its partial file
+    let result = previous_path.unwrap_or_else(|| {
+        Path::new(download_directory).join(response_file_name(&response, fallback_file_name))
+    });
+    let tmp_path = partial_download_path(&result);
+    let offset = resume_from.unwrap_or(0) as i64;
+    let content_length = response
+        .content_length()
+        .map(|l| l as i64 + offset)
+        .unwrap_or(-1);
+    {
+        let mut t = lock_or_recover(task);
+        t.set_download_path(result.clone());
+        t.download_size = offset;
+        t.content_length = content_length;
+    }
 
-    // Stream body in chunks to a temporary file to avoid destroying an
+    // Stream body in chunks to the temporary file to avoid destroying an
     // existing file if the download fails partway through.
-    let write_result = (|| -> anyhow::Result<()> {
-        let mut fos = fs::File::create(&tmp_path)?;
-        let mut download_bytes: i64 = 0;
-        let mut buf = [0u8; 8192];
-        let mut reader = response;
-        loop {
-            let read = std::io::Read::read(&mut reader, &mut buf)?;
-            if read == 0 {
-                break;
-            }
-            fos.write_all(&buf[..read])?;
-            download_bytes += read as i64;
-            {
-                let mut t = lock_or_recover(task);
-                t.download_size = download_bytes;
-                t.content_length = content_length;
+    let mut fos = if resume_from.is_some() {
+        fs::OpenOptions::new().append(true).open(&tmp_path)?
+    } else {
+        fs::File::create(&tmp_path)?
+    };
+    // A checksum header describes only the bytes of this response, so it can
+    // verify the archive only when the whole body is sent
+    let expected_digest = resume_from
+        .is_none()
+        .then(|| ExpectedDigest::from_headers(response.headers()))
+        .flatten();
+    let mut download_bytes = offset;
+    let mut buf = [0u8; 8192];
+    let mut reader = response;
+    loop {
+        let read = std::io::Read::read(&mut reader, &mut buf)?;
+        if read == 0 {
+            break;
+        }
+        fos.write_all(&buf[..read])?;
+        download_bytes += read as i64;
+        {
+            let mut t = lock_or_recover(task);
+            t.record_progress(download_bytes);
+            if t.pause_requested() || t.cancel_requested() {
+                return Ok(DownloadOutcome::Paused);
             }
         }
-        Ok(())
-    })();
+        if throttle.is_suspended() {
+            return Ok(DownloadOutcome::Suspended);
+        }
+        throttle.consume(read);
+    }
+    drop(fos);
 
-    if let Err(e) = write_result {
-        // Clean up the partial temp file on failure
+    if content_length >= 0 && download_bytes != content_length {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(IntegrityError(format!(
+            "received {} of {} bytes",
+            download_bytes, content_length
+        ))
+        .into());
+    }
+    if let Some(expected) = expected_digest
+        && let Err(e) = expected.verify(&tmp_path)
+    {
         let _ = fs::remove_file(&tmp_path);
         return Err(e);
     }
@@ -500,21 +995,52 @@ fn download_file_from_url(
         t.set_download_task_status(DownloadTaskStatus::Downloaded);
     }
 
-    Ok(result)
+    Ok(DownloadOutcome::Completed(result))
 }
 
-/// Extract a compressed file into target_path
+/// File name from the Content-Disposition header, or `fallback_file_name`.
+fn response_file_name(response: &reqwest::blocking::Response, fallback_file_name: &str) -> String {
+    let content_disposition = response
+        .headers()
+        .get("Content-Disposition")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("");
+    let re = Regex::new(r#"filename="?([^"]+)"?"#).expect("valid regex");
+    let Some(candidate_file_name) = re
+        .captures(content_disposition)
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str().to_string())
+        .filter(|name| !name.is_empty())
+    else {
+        return fallback_file_name.to_string();
+    };
+    // Sanitize filename to prevent path traversal from malicious Content-Disposition headers
+    Path::new(&candidate_file_name)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or(candidate_file_name)
+}
+
+/// Extract a compressed file (7z, zip or rar) into target_path
 ///
 /// # Arguments
 /// * `file` - compressed archive
 /// * `target_path` - target directory, fallback to download_directory if None
+/// * `password` - password for encrypted archives
+/// * `expected_chart` - md5 or sha256 of a chart the archive must contain
+/// * `sanitize_names` - rename the extracted top-level entries with [`sanitize_name`]
 ///
 /// # Returns
-/// the path to the directory just extracted
+/// the path to the directory just extracted. Fails with [`ArchivePasswordError`]
+/// when the archive is encrypted and the password is missing or wrong, and with
+/// [`IntegrityError`] when the expected chart is not in it.
 fn extract_compressed_file(
     file: &Path,
     target_path: Option<&Path>,
     download_directory: &str,
+    password: Option<&str>,
+    expected_chart: Option<&str>,
+    sanitize_names: bool,
 ) -> anyhow::Result<Option<String>> {
     let dest = target_path
         .map(|p| p.to_path_buf())
@@ -524,22 +1050,23 @@ fn extract_compressed_file(
         fs::create_dir_all(&dest)?;
     }
 
-    // Pre-validate 7z entry names from archive metadata BEFORE extraction.
-    // This prevents path-traversal attacks via ../ entries in the archive;
-    // malicious entries are rejected before any bytes are written to disk.
-    validate_archive_entry_names(file)?;
-
     // Extract into a temp directory first, then validate paths before moving.
     // This prevents symlink-based escapes that metadata validation cannot catch.
     let staging_dir = tempfile::tempdir_in(&dest)?;
 
-    sevenz_rust::decompress_file(file, staging_dir.path())
-        .map_err(|e| anyhow::anyhow!("7z extraction failed: {}", e))?;
+    match ArchiveFormat::detect(file)? {
+        ArchiveFormat::SevenZ => extract_7z(file, staging_dir.path(), password)?,
+        ArchiveFormat::Zip => extract_zip(file, staging_dir.path(), password)?,
+        ArchiveFormat::Rar => extract_rar(file, staging_dir.path(), password)?,
+    }
 
     // Defense-in-depth: validate all extracted paths stay within the staging
     // directory (catches symlink escapes that entry-name checks cannot detect).
     let canonical_staging = staging_dir.path().canonicalize()?;
     validate_extracted_paths(&canonical_staging)?;
+    if let Some(hash) = expected_chart {
+        verify_chart_present(&canonical_staging, hash)?;
+    }
 
     // Record pre-existing entries so we can detect what was newly added.
     let pre_existing: std::collections::HashSet<_> = fs::read_dir(&dest)
@@ -552,7 +1079,11 @@ fn extract_compressed_file(
     // Move validated contents from staging into dest.
     let mut has_new_files = false;
     for entry in fs::read_dir(staging_dir.path())?.flatten() {
-        let target = dest.join(entry.file_name());
+        let name = entry.file_name();
+        let target = match name.to_str().map(sanitize_name) {
+            Some(sanitized) if sanitize_names && !sanitized.is_empty() => dest.join(sanitized),
+            _ => dest.join(&name),
+        };
         // rename may fail across mount points; fall back to copy+remove
         if fs::rename(entry.path(), &target).is_err() {
             if entry.path().is_dir() {
@@ -588,31 +1119,335 @@ fn extract_compressed_file(
     Ok(extracted_dir)
 }
 
+/// Archive formats the extractor understands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ArchiveFormat {
+    SevenZ,
+    Zip,
+    Rar,
+}
+
+impl ArchiveFormat {
+    /// Detect the format from the file signature, falling back to the extension
+    /// (downloads without a server-provided name are always saved as `.7z`).
+    fn detect(file: &Path) -> anyhow::Result<Self> {
+        let mut magic = Vec::with_capacity(8);
+        std::io::Read::read_to_end(
+            &mut std::io::Read::take(fs::File::open(file)?, 8),
+            &mut magic,
+        )?;
+        if magic.starts_with(b"7z\xBC\xAF\x27\x1C") {
+            return Ok(Self::SevenZ);
+        }
+        if magic.starts_with(b"PK\x03\x04") || magic.starts_with(b"PK\x05\x06") {
+            return Ok(Self::Zip);
+        }
+        if magic.starts_with(b"Rar!\x1A\x07") {
+            return Ok(Self::Rar);
+        }
+        let extension = file
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("7z") => Ok(Self::SevenZ),
+            Some("zip") => Ok(Self::Zip),
+            Some("rar") => Ok(Self::Rar),
+            _ => Err(anyhow::anyhow!(
+                "Unsupported archive format: {}",
+                file.display()
+            )),
+        }
+    }
+}
+
+/// Extraction stopped because the archive is encrypted and the password is
+/// missing or wrong. The download menu asks for a password when it sees this.
+#[derive(Debug)]
+pub struct ArchivePasswordError {
+    pub wrong_password: bool,
+}
+
+impl std::fmt::Display for ArchivePasswordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.wrong_password {
+            write!(f, "Wrong archive password")
+        } else {
+            write!(f, "Archive is password protected")
+        }
+    }
+}
+
+impl std::error::Error for ArchivePasswordError {}
+
+/// A package arrived broken or without the requested chart. The source is at
+/// fault, so the download is discarded and another source may be tried.
+#[derive(Debug)]
+pub struct IntegrityError(pub String);
+
+impl std::fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Integrity check failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for IntegrityError {}
+
+/// Checksum a server announced for the whole response body.
+#[derive(Debug, PartialEq)]
+enum ExpectedDigest {
+    Md5(Vec<u8>),
+    Sha256(Vec<u8>),
+}
+
+impl ExpectedDigest {
+    /// Read a sha-256 `Repr-Digest`/`Digest` header, else `Content-MD5`.
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let decode = |value: &str| {
+            base64::engine::general_purpose::STANDARD
+                .decode(value.trim().trim_matches(':'))
+                .ok()
+        };
+        for name in ["repr-digest", "digest"] {
+            for value in headers.get_all(name) {
+                let Ok(value) = value.to_str() else {
+                    continue;
+                };
+                for item in value.split(',') {
+                    if let Some((algorithm, digest)) = item.split_once('=')
+                        && algorithm.trim().eq_ignore_ascii_case("sha-256")
+                        && let Some(digest) = decode(digest)
+                    {
+                        return Some(Self::Sha256(digest));
+                    }
+                }
+            }
+        }
+        headers
+            .get("content-md5")
+            .and_then(|value| value.to_str().ok())
+            .and_then(decode)
+            .map(Self::Md5)
+    }
+
+    fn verify(&self, file: &Path) -> anyhow::Result<()> {
+        let (name, expected, actual) = match self {
+            Self::Md5(expected) => ("md5", expected, file_digest::<Md5>(file)?),
+            Self::Sha256(expected) => ("sha-256", expected, file_digest::<Sha256>(file)?),
+        };
+        if *expected != actual {
+            return Err(
+                IntegrityError(format!("{} checksum does not match the server's", name)).into(),
+            );
+        }
+        Ok(())
+    }
+}
+
+fn file_digest<D: Digest>(file: &Path) -> anyhow::Result<Vec<u8>> {
+    let mut reader = fs::File::open(file)?;
+    let mut hasher = D::new();
+    let mut buf = [0u8; 8192];
+    loop {
+        let read = std::io::Read::read(&mut reader, &mut buf)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+    }
+    Ok(hasher.finalize().to_vec())
+}
+
+fn sevenz_password(password: Option<&str>) -> sevenz_rust::Password {
+    password.map_or_else(sevenz_rust::Password::empty, sevenz_rust::Password::from)
+}
+
+fn sevenz_error(e: sevenz_rust::Error, password: Option<&str>, action: &str) -> anyhow::Error {
+    match e {
+        sevenz_rust::Error::PasswordRequired => ArchivePasswordError {
+            wrong_password: false,
+        }
+        .into(),
+        sevenz_rust::Error::MaybeBadPassword(_) => ArchivePasswordError {
+            wrong_password: password.is_some(),
+        }
+        .into(),
+        e => anyhow::anyhow!("{}: {}", action, e),
+    }
+}
+
+fn extract_7z(file: &Path, dest: &Path, password: Option<&str>) -> anyhow::Result<()> {
+    // Pre-validate 7z entry names from archive metadata BEFORE extraction.
+    // This prevents path-traversal attacks via ../ entries in the archive;
+    // malicious entries are rejected before any bytes are written to disk.
+    validate_archive_entry_names(file, password)?;
+    sevenz_rust::decompress_file_with_password(file, dest, sevenz_password(password))
+        .map_err(|e| sevenz_error(e, password, "7z extraction failed"))
+}
+
 /// Pre-validate 7z archive entry names for path traversal before extraction.
 ///
 /// Rejects entries whose names contain parent-directory components (`..`) or
 /// are absolute paths, which could write files outside the intended directory.
-fn validate_archive_entry_names(file: &Path) -> anyhow::Result<()> {
-    let reader = sevenz_rust::SevenZReader::open(file, sevenz_rust::Password::empty())
-        .map_err(|e| anyhow::anyhow!("Failed to open 7z archive for validation: {}", e))?;
+fn validate_archive_entry_names(file: &Path, password: Option<&str>) -> anyhow::Result<()> {
+    let reader = sevenz_rust::SevenZReader::open(file, sevenz_password(password))
+        .map_err(|e| sevenz_error(e, password, "Failed to open 7z archive for validation"))?;
     for entry in &reader.archive().files {
-        let entry_path = Path::new(&entry.name);
-        if entry_path.is_absolute() {
+        validate_entry_name(&entry.name)?;
+    }
+    Ok(())
+}
+
+fn validate_entry_name(name: &str) -> anyhow::Result<&Path> {
+    let entry_path = Path::new(name);
+    if entry_path.is_absolute() {
+        return Err(anyhow::anyhow!(
+            "Path traversal detected in archive: absolute path '{}'",
+            name
+        ));
+    }
+    for component in entry_path.components() {
+        if matches!(component, Component::ParentDir) {
             return Err(anyhow::anyhow!(
-                "Path traversal detected in archive: absolute path '{}'",
-                entry.name
+                "Path traversal detected in archive: parent directory component in '{}'",
+                name
             ));
         }
-        for component in entry_path.components() {
-            if matches!(component, Component::ParentDir) {
-                return Err(anyhow::anyhow!(
-                    "Path traversal detected in archive: parent directory component in '{}'",
-                    entry.name
-                ));
+    }
+    Ok(entry_path)
+}
+
+fn zip_error(e: zip::result::ZipError, password: Option<&str>) -> anyhow::Error {
+    match e {
+        zip::result::ZipError::UnsupportedArchive(zip::result::ZipError::PASSWORD_REQUIRED) => {
+            ArchivePasswordError {
+                wrong_password: false,
+            }
+            .into()
+        }
+        zip::result::ZipError::InvalidPassword => ArchivePasswordError {
+            wrong_password: password.is_some(),
+        }
+        .into(),
+        e => anyhow::anyhow!("zip extraction failed: {}", e),
+    }
+}
+
+/// Entry name of a zip file. Names without the UTF-8 flag are usually Shift_JIS
+/// in BMS packages, and some Windows tools write `\` as the separator.
+fn zip_entry_name(raw: &[u8]) -> String {
+    let name = match std::str::from_utf8(raw) {
+        Ok(name) => name.to_string(),
+        Err(_) => encoding_rs::SHIFT_JIS.decode(raw).0.into_owned(),
+    };
+    name.replace('\\', "/")
+}
+
+fn extract_zip(file: &Path, dest: &Path, password: Option<&str>) -> anyhow::Result<()> {
+    let mut archive = zip::ZipArchive::new(fs::File::open(file)?)
+        .map_err(|e| anyhow::anyhow!("zip extraction failed: {}", e))?;
+    // Validate every entry name before writing anything
+    let names = (0..archive.len())
+        .map(|i| {
+            let entry = archive
+                .by_index_raw(i)
+                .map_err(|e| zip_error(e, password))?;
+            let name = zip_entry_name(entry.name_raw());
+            validate_entry_name(&name)?;
+            Ok(name)
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    for (i, name) in names.iter().enumerate() {
+        let mut entry = match password {
+            Some(password) => archive.by_index_decrypt(i, password.as_bytes()),
+            None => archive.by_index(i),
+        }
+        .map_err(|e| zip_error(e, password))?;
+        let target = dest.join(name);
+        if entry.is_dir() {
+            fs::create_dir_all(&target)?;
+            continue;
+        }
+        if entry.is_symlink() {
+            log::warn!(
+                "[HttpDownloadProcessor] Skipping symlink {} in {}",
+                name,
+                file.display()
+            );
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let encrypted = entry.encrypted();
+        let mut out = fs::File::create(&target)?;
+        if let Err(e) = std::io::copy(&mut entry, &mut out) {
+            // ZipCrypto lets some wrong passwords through the header check;
+            // they surface as checksum errors while reading
+            if encrypted && e.kind() == std::io::ErrorKind::InvalidData {
+                return Err(ArchivePasswordError {
+                    wrong_password: true,
+                }
+                .into());
+            }
+            return Err(e.into());
+        }
+    }
+    Ok(())
+}
+
+/// Command-line extractors tried in order for RAR archives, which have no
+/// pure-Rust decoder.
+const RAR_EXTRACTORS: [&str; 4] = ["unrar", "7z", "7zz", "7za"];
+
+fn extract_rar(file: &Path, dest: &Path, password: Option<&str>) -> anyhow::Result<()> {
+    for tool in RAR_EXTRACTORS {
+        let mut command = std::process::Command::new(tool);
+        if tool == "unrar" {
+            // `-p-` keeps unrar from prompting when no password is given
+            command
+                .arg("x")
+                .arg("-y")
+                .arg(password.map_or_else(|| "-p-".to_string(), |p| format!("-p{}", p)))
+                .arg(file)
+                .arg(format!("{}{}", dest.display(), std::path::MAIN_SEPARATOR));
+        } else {
+            // An empty `-p` keeps 7-Zip from prompting
+            command
+                .arg("x")
+                .arg("-y")
+                .arg(format!("-p{}", password.unwrap_or("")))
+                .arg(format!("-o{}", dest.display()))
+                .arg(file);
+        }
+        let output = match command.stdin(std::process::Stdio::null()).output() {
+            Ok(output) => output,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+        if output.status.success() {
+            return Ok(());
+        }
+        let log = format!(
+            "{}\n{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        if log.to_ascii_lowercase().contains("password") {
+            return Err(ArchivePasswordError {
+                wrong_password: password.is_some(),
             }
+            .into());
         }
+        let reason = log
+            .lines()
+            .map(str::trim)
+            .rfind(|line| !line.is_empty())
+            .unwrap_or("unknown error");
+        return Err(anyhow::anyhow!("{} failed: {}", tool, reason));
     }
-    Ok(())
+    Err(anyhow::anyhow!(
+        "RAR extraction requires unrar or 7-Zip (7z) on PATH"
+    ))
 }
 
 /// Recursively validate that all paths under `root` are within `root` (no symlink escapes).
@@ -638,6 +1473,43 @@ fn validate_extracted_paths_recursive(root: &Path, dir: &Path) -> anyhow::Result
     Ok(())
 }
 
+/// Fail with [`IntegrityError`] unless a chart under `root` has `hash`, an md5
+/// or sha256 in hex. Hashes of any other form are not checked.
+fn verify_chart_present(root: &Path, hash: &str) -> anyhow::Result<()> {
+    let hash = hash.to_ascii_lowercase();
+    let digest: fn(&Path) -> anyhow::Result<Vec<u8>> = match hash.len() {
+        32 => file_digest::<Md5>,
+        64 => file_digest::<Sha256>,
+        _ => return Ok(()),
+    };
+    if !hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Ok(());
+    }
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)?.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            let is_chart = path.extension().and_then(|e| e.to_str()).is_some_and(|e| {
+                ["bms", "bme", "bml", "pms", "bmson"]
+                    .iter()
+                    .any(|chart| e.eq_ignore_ascii_case(chart))
+            });
+            if is_chart && hex_string(&digest(&path)?) == hash {
+                return Ok(());
+            }
+        }
+    }
+    Err(IntegrityError(format!("package does not contain the chart {}", hash)).into())
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 /// Recursively copy a directory tree.
 pub(super) fn copy_dir_recursive(src: &Path, dst: &Path) -> anyhow::Result<()> {
     fs::create_dir_all(dst)?;
@@ -850,7 +1722,7 @@ mod tests {
         let archive_path = tmp.path().join("traversal.7z");
         create_7z_with_entry_name(&archive_path, "../../etc/evil.txt");
 
-        let result = validate_archive_entry_names(&archive_path);
+        let result = validate_archive_entry_names(&archive_path, None);
         assert!(result.is_err(), "expected error for parent-dir traversal");
         let err_msg = result.unwrap_err().to_string();
         assert!(
@@ -867,7 +1739,7 @@ mod tests {
         let archive_path = tmp.path().join("absolute.7z");
         create_7z_with_entry_name(&archive_path, "/etc/passwd");
 
-        let result = validate_archive_entry_names(&archive_path);
+        let result = validate_archive_entry_names(&archive_path, None);
         assert!(result.is_err(), "expected error for absolute path");
         let err_msg = result.unwrap_err().to_string();
         assert!(
@@ -884,7 +1756,7 @@ mod tests {
         let archive_path = tmp.path().join("safe.7z");
         create_7z_with_entry_name(&archive_path, "songs/test/chart.bms");
 
-        let result = validate_archive_entry_names(&archive_path);
+        let result = validate_archive_entry_names(&archive_path, None);
         assert!(result.is_ok(), "expected safe entry to pass validation");
     }
 
@@ -899,7 +1771,14 @@ mod tests {
         let dest = tmp.path().join("output");
         fs::create_dir_all(&dest).expect("create dest");
 
-        let result = extract_compressed_file(&archive_path, Some(&dest), &dest.to_string_lossy());
+        let result = extract_compressed_file(
+            &archive_path,
+            Some(&dest),
+            &dest.to_string_lossy(),
+            None,
+            None,
+            false,
+        );
         assert!(
             result.is_err(),
             "extract should fail for path-traversal archive"
@@ -911,4 +1790,808 @@ mod tests {
             "escaped file should not exist outside staging directory"
         );
     }
+
+    /// Minimal HTTP server serving `body` on every request. Honors `Range: bytes=N-`
+    /// when `honor_range` is set and sleeps `chunk_delay` between 8 KiB chunks.
+    /// Returns the base url and the range offsets requested so far.
+    fn serve_body(
+        body: Vec<u8>,
+        honor_range: bool,
+        chunk_delay: Duration,
+    ) -> (String, Arc<Mutex<Vec<Option<u64>>>>) {
+        use std::io::{BufRead, BufReader};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let ranges = Arc::new(Mutex::new(Vec::new()));
+        let body = Arc::new(body);
+        let log = ranges.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { return };
+                let body = body.clone();
+                let log = log.clone();
+                thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut range = None;
+                    let mut line = String::new();
+                    while reader.read_line(&mut line).is_ok_and(|n| n > 0) && line != "\r\n" {
+                        if let Some(value) = line.to_ascii_lowercase().strip_prefix("range: bytes=")
+                        {
+                            range = value.trim().trim_end_matches('-').parse::<u64>().ok();
+                        }
+                        line.clear();
+                    }
+                    log.lock().unwrap().push(range);
+                    let len = body.len() as u64;
+                    let (head, start) = match range.filter(|_| honor_range) {
+                        Some(offset) if offset >= len => (
+                            format!(
+                                "HTTP/1.1 416 Range Not Satisfiable\r\nContent-Range: bytes */{len}\r\nContent-Length: 0\r\n"
+                            ),
+                            len,
+                        ),
+                        Some(offset) => (
+                            format!(
+                                "HTTP/1.1 206 Partial Content\r\nContent-Range: bytes {offset}-{}/{len}\r\nContent-Length: {}\r\n",
+                                len - 1,
+                                len - offset
+                            ),
+                            offset,
+                        ),
+                        None => (format!("HTTP/1.1 200 OK\r\nContent-Length: {len}\r\n"), 0),
+                    };
+                    if stream
+                        .write_all(format!("{head}Connection: close\r\n\r\n").as_bytes())
+                        .is_err()
+                    {
+                        return;
+                    }
+                    for chunk in body[start as usize..].chunks(8192) {
+                        if stream.write_all(chunk).is_err() {
+                            return;
+                        }
+                        thread::sleep(chunk_delay);
+                    }
+                });
+            }
+        });
+        (url, ranges)
+    }
+
+    fn test_body(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn wait_until(mut condition: impl FnMut() -> bool) {
+        let start = std::time::Instant::now();
+        while !condition() {
+            assert!(
+                start.elapsed() < Duration::from_secs(10),
+                "condition not met in time"
+            );
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    fn task_status(task: &Arc<Mutex<DownloadTask>>) -> DownloadTaskStatus {
+        lock_or_recover(task).download_task_status()
+    }
+
+    #[test]
+    fn resumes_partial_download_with_range_request() {
+        let body = test_body(50_000);
+        let (url, ranges) = serve_body(body.clone(), true, Duration::ZERO);
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let path = tmp.path().join("song.7z");
+        fs::write(partial_download_path(&path), &body[..20_000]).unwrap();
+        let mut task = DownloadTask::new(1, url, "Song".to_string(), "hash".to_string());
+        task.set_download_path(path.clone());
+        let task = Arc::new(Mutex::new(task));
+
+        let outcome = download_file_from_url(
+            &task,
+            "fallback.7z",
+            &tmp.path().to_string_lossy(),
+            "Test",
+            &DownloadThrottle::default(),
+        )
+        .expect("download");
+
+        assert_eq!(outcome, DownloadOutcome::Completed(path.clone()));
+        assert_eq!(fs::read(&path).unwrap(), body);
+        assert!(!partial_download_path(&path).exists());
+        assert_eq!(*ranges.lock().unwrap(), vec![Some(20_000)]);
+        let task = lock_or_recover(&task);
+        assert_eq!(task.download_size, 50_000);
+        assert_eq!(task.content_length, 50_000);
+        assert_eq!(task.download_task_status(), DownloadTaskStatus::Downloaded);
+    }
+
+    #[test]
+    fn restarts_download_when_server_ignores_range() {
+        let body = test_body(30_000);
+        let (url, ranges) = serve_body(body.clone(), false, Duration::ZERO);
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let path = tmp.path().join("song.7z");
+        fs::write(partial_download_path(&path), vec![0xffu8; 10_000]).unwrap();
+        let mut task = DownloadTask::new(1, url, "Song".to_string(), "hash".to_string());
+        task.set_download_path(path.clone());
+        let task = Arc::new(Mutex::new(task));
+
+        download_file_from_url(
+            &task,
+            "fallback.7z",
+            &tmp.path().to_string_lossy(),
+            "Test",
+            &DownloadThrottle::default(),
+        )
+        .expect("download");
+
+        assert_eq!(fs::read(&path).unwrap(), body);
+        assert_eq!(*ranges.lock().unwrap(), vec![Some(10_000)]);
+    }
+
+    #[test]
+    fn paused_download_keeps_partial_file_and_resumes() {
+        let body = test_body(400_000);
+        let (url, ranges) = serve_body(body.clone(), true, Duration::from_millis(5));
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let dir = tmp.path().to_string_lossy().to_string();
+        let task = Arc::new(Mutex::new(DownloadTask::new(
+            1,
+            url,
+            "Song".to_string(),
+            "hash".to_string(),
+        )));
+
+        let handle = {
+            let task = task.clone();
+            let dir = dir.clone();
+            thread::spawn(move || {
+                download_file_from_url(&task, "song.7z", &dir, "Test", &DownloadThrottle::default())
+            })
+        };
+        wait_until(|| lock_or_recover(&task).download_size > 0);
+        lock_or_recover(&task).set_pause_requested(true);
+        assert_eq!(
+            handle.join().unwrap().expect("pause"),
+            DownloadOutcome::Paused
+        );
+
+        let path = tmp.path().join("song.7z");
+        let partial = fs::metadata(partial_download_path(&path)).unwrap().len();
+        assert!(partial > 0 && partial < body.len() as u64);
+        assert!(!path.exists());
+
+        lock_or_recover(&task).set_pause_requested(false);
+        let outcome =
+            download_file_from_url(&task, "song.7z", &dir, "Test", &DownloadThrottle::default())
+                .expect("resume");
+        assert_eq!(outcome, DownloadOutcome::Completed(path.clone()));
+        assert_eq!(fs::read(&path).unwrap(), body);
+        assert_eq!(*ranges.lock().unwrap(), vec![None, Some(partial)]);
+    }
+
+    #[test]
+    fn queue_runs_up_to_max_parallel_and_pauses_queued_tasks() {
+        let (url, _) = serve_body(test_body(400_000), true, Duration::from_millis(5));
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let processor = HttpDownloadProcessor::new(
+            Arc::new(FakeMainControllerRef),
+            Arc::new(FakeHttpDownloadSource::new(&url)),
+            tmp.path().to_string_lossy().to_string(),
+        );
+        processor.set_max_parallel_downloads(0);
+        assert_eq!(processor.max_parallel_downloads(), 1);
+
+        processor.submit_url_task(&format!("{url}/a"), "hash-a", "Song A");
+        processor.submit_url_task(&format!("{url}/b"), "hash-b", "Song B");
+        let tasks = processor.all_tasks();
+        let (first, second) = {
+            let tasks = lock_or_recover(&tasks);
+            (tasks[&1].clone(), tasks[&2].clone())
+        };
+        assert_eq!(lock_or_recover(&first).source_name(), "BMS Search");
+        wait_until(|| task_status(&first) == DownloadTaskStatus::Downloading);
+        assert_eq!(task_status(&second), DownloadTaskStatus::Queued);
+
+        // A paused queued task is skipped when the slot frees up
+        assert!(processor.pause_download_task(&second));
+        assert_eq!(task_status(&second), DownloadTaskStatus::Paused);
+        assert!(processor.pause_download_task(&first));
+        wait_until(|| task_status(&first) == DownloadTaskStatus::Paused);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(task_status(&second), DownloadTaskStatus::Paused);
+
+        // Resumed tasks line up behind each other again
+        assert!(processor.resume_download_task(second.clone()));
+        assert!(processor.resume_download_task(first.clone()));
+        assert!(!processor.resume_download_task(first.clone()));
+        wait_until(|| task_status(&second) == DownloadTaskStatus::Downloading);
+        assert_eq!(task_status(&first), DownloadTaskStatus::Queued);
+    }
+
+    #[test]
+    fn cancel_stops_running_download_and_deletes_partial_file() {
+        let (url, _) = serve_body(test_body(400_000), true, Duration::from_millis(5));
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let processor = HttpDownloadProcessor::new(
+            Arc::new(FakeMainControllerRef),
+            Arc::new(FakeHttpDownloadSource::new(&url)),
+            tmp.path().to_string_lossy().to_string(),
+        );
+        processor.set_max_parallel_downloads(1);
+        processor.submit_url_task(&format!("{url}/a"), "hash-a", "Song A");
+        processor.submit_url_task(&format!("{url}/b"), "hash-b", "Song B");
+        let tasks = processor.all_tasks();
+        let (first, second) = {
+            let tasks = lock_or_recover(&tasks);
+            (tasks[&1].clone(), tasks[&2].clone())
+        };
+        wait_until(|| lock_or_recover(&first).download_size > 0);
+        let partial = partial_download_path(lock_or_recover(&first).download_path().unwrap());
+        assert!(partial.exists());
+
+        // A queued task is dropped right away and never starts
+        assert!(processor.cancel_download_task(&second));
+        assert_eq!(task_status(&second), DownloadTaskStatus::Cancel);
+        assert!(processor.cancel_download_task(&first));
+        wait_until(|| task_status(&first) == DownloadTaskStatus::Cancel);
+        assert!(!partial.exists());
+        assert!(!processor.cancel_download_task(&first));
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(task_status(&second), DownloadTaskStatus::Cancel);
+
+        // The url can be submitted again
+        processor.submit_url_task(&format!("{url}/a"), "hash-a", "Song A");
+        assert_eq!(lock_or_recover(&tasks).len(), 3);
+    }
+
+    /// Helper: zip archive with the given (name, contents) entries, AES-encrypted
+    /// when `password` is set.
+    fn create_zip(archive_path: &Path, entries: &[(&str, &[u8])], password: Option<&str>) {
+        let mut writer = zip::ZipWriter::new(fs::File::create(archive_path).unwrap());
+        for (name, data) in entries {
+            let options = zip::write::SimpleFileOptions::default();
+            let options = match password {
+                Some(password) => options.with_aes_encryption(zip::AesMode::Aes256, password),
+                None => options,
+            };
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(data).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    fn password_error(result: anyhow::Result<Option<String>>) -> ArchivePasswordError {
+        let err = result.expect_err("expected a password error");
+        let err = err
+            .downcast::<ArchivePasswordError>()
+            .expect("password error");
+        ArchivePasswordError {
+            wrong_password: err.wrong_password,
+        }
+    }
+
+    #[test]
+    fn detects_archive_format_by_signature_before_extension() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let zip_named_7z = tmp.path().join("hash.7z");
+        create_zip(&zip_named_7z, &[("a.bms", b"#TITLE a")], None);
+        assert_eq!(
+            ArchiveFormat::detect(&zip_named_7z).unwrap(),
+            ArchiveFormat::Zip
+        );
+
+        let seven_z = tmp.path().join("song.bin");
+        create_7z_with_entry_name(&seven_z, "a.bms");
+        assert_eq!(
+            ArchiveFormat::detect(&seven_z).unwrap(),
+            ArchiveFormat::SevenZ
+        );
+
+        let rar = tmp.path().join("song.bin2");
+        fs::write(&rar, b"Rar!\x1A\x07\x01\x00").unwrap();
+        assert_eq!(ArchiveFormat::detect(&rar).unwrap(), ArchiveFormat::Rar);
+
+        let unknown_rar = tmp.path().join("song.RAR");
+        fs::write(&unknown_rar, b"????").unwrap();
+        assert_eq!(
+            ArchiveFormat::detect(&unknown_rar).unwrap(),
+            ArchiveFormat::Rar
+        );
+
+        let unknown = tmp.path().join("song.txt");
+        fs::write(&unknown, b"????").unwrap();
+        assert!(ArchiveFormat::detect(&unknown).is_err());
+    }
+
+    #[test]
+    fn zip_entry_names_fall_back_to_shift_jis() {
+        let (sjis, _, _) = encoding_rs::SHIFT_JIS.encode("曲\\譜面.bms");
+        assert_eq!(zip_entry_name(&sjis), "曲/譜面.bms");
+        assert_eq!(zip_entry_name("song/a.bms".as_bytes()), "song/a.bms");
+    }
+
+    #[test]
+    fn extracts_zip_archive_into_song_directory() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let archive_path = tmp.path().join("song.zip");
+        create_zip(
+            &archive_path,
+            &[("Song/a.bms", b"#TITLE a"), ("Song/sub/b.wav", b"RIFF")],
+            None,
+        );
+        let dest = tmp.path().join("output");
+
+        let dir = extract_compressed_file(&archive_path, Some(&dest), "", None, None, false)
+            .unwrap()
+            .expect("extracted directory");
+
+        assert_eq!(Path::new(&dir), dest.join("Song"));
+        assert_eq!(fs::read(dest.join("Song/a.bms")).unwrap(), b"#TITLE a");
+        assert_eq!(fs::read(dest.join("Song/sub/b.wav")).unwrap(), b"RIFF");
+    }
+
+    #[test]
+    fn extract_rejects_zip_path_traversal() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let archive_path = tmp.path().join("evil.zip");
+        create_zip(&archive_path, &[("../escape.txt", b"x")], None);
+        let dest = tmp.path().join("output");
+
+        let result = extract_compressed_file(&archive_path, Some(&dest), "", None, None, false);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("parent directory component")
+        );
+        assert!(!tmp.path().join("escape.txt").exists());
+    }
+
+    #[test]
+    fn encrypted_zip_needs_the_right_password() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let archive_path = tmp.path().join("locked.zip");
+        create_zip(
+            &archive_path,
+            &[("Song/a.bms", b"#TITLE a")],
+            Some("secret"),
+        );
+        let dest = tmp.path().join("output");
+
+        let missing = password_error(extract_compressed_file(
+            &archive_path,
+            Some(&dest),
+            "",
+            None,
+            None,
+            false,
+        ));
+        assert!(!missing.wrong_password);
+        let wrong = password_error(extract_compressed_file(
+            &archive_path,
+            Some(&dest),
+            "",
+            Some("guess"),
+            None,
+            false,
+        ));
+        assert!(wrong.wrong_password);
+        assert!(!dest.join("Song").exists());
+
+        extract_compressed_file(&archive_path, Some(&dest), "", Some("secret"), None, false)
+            .unwrap();
+        assert_eq!(fs::read(dest.join("Song/a.bms")).unwrap(), b"#TITLE a");
+    }
+
+    #[test]
+    fn encrypted_7z_needs_a_password() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let src = tmp.path().join("package");
+        fs::create_dir_all(src.join("Song")).unwrap();
+        fs::write(src.join("Song/a.bms"), b"#TITLE a").unwrap();
+        let archive_path = tmp.path().join("locked.7z");
+        sevenz_rust::compress_to_path_encrypted(&src, &archive_path, "secret".into()).unwrap();
+        let dest = tmp.path().join("output");
+
+        let missing = password_error(extract_compressed_file(
+            &archive_path,
+            Some(&dest),
+            "",
+            None,
+            None,
+            false,
+        ));
+        assert!(!missing.wrong_password);
+
+        extract_compressed_file(&archive_path, Some(&dest), "", Some("secret"), None, false)
+            .unwrap();
+        assert_eq!(fs::read(dest.join("Song/a.bms")).unwrap(), b"#TITLE a");
+    }
+
+    #[test]
+    fn password_retry_extracts_without_downloading_again() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let archive_path = tmp.path().join("locked.zip");
+        create_zip(
+            &archive_path,
+            &[("Song/a.bms", b"#TITLE a")],
+            Some("secret"),
+        );
+        let (url, requests) = serve_body(fs::read(&archive_path).unwrap(), true, Duration::ZERO);
+        let download_dir = tmp.path().join("downloads");
+        fs::create_dir(&download_dir).unwrap();
+        let processor = HttpDownloadProcessor::new(
+            Arc::new(FakeMainControllerRef),
+            Arc::new(FakeHttpDownloadSource::new(&url)),
+            download_dir.to_string_lossy().to_string(),
+        );
+
+        processor.submit_url_task(&url, "hash", "Locked Song");
+        let task = lock_or_recover(&processor.all_tasks())[&1].clone();
+        wait_until(|| task_status(&task) == DownloadTaskStatus::Error);
+        assert!(lock_or_recover(&task).password_required());
+        assert!(download_dir.join("hash.7z").exists());
+
+        processor.extract_with_password(task.clone(), "secret".to_string());
+        wait_until(|| task_status(&task) == DownloadTaskStatus::Extracted);
+        assert!(!lock_or_recover(&task).password_required());
+        assert!(lock_or_recover(&task).error_message().is_none());
+        assert_eq!(
+            fs::read(download_dir.join("Song/a.bms")).unwrap(),
+            b"#TITLE a"
+        );
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    /// A download source with its own name that always resolves to `url`.
+    struct MirrorSource {
+        name: &'static str,
+        url: String,
+    }
+
+    impl HttpDownloadSource for MirrorSource {
+        fn get_download_url_based_on_md5(&self, _md5: &str) -> anyhow::Result<String> {
+            Ok(self.url.clone())
+        }
+
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn is_allow_download_through_md5(&self) -> bool {
+            true
+        }
+
+        fn is_allow_download_through_sha256(&self) -> bool {
+            false
+        }
+
+        fn is_allow_meta_query(&self) -> bool {
+            false
+        }
+    }
+
+    fn md5_hex(data: &[u8]) -> String {
+        hex_string(&Md5::digest(data))
+    }
+
+    #[test]
+    fn checksum_headers_are_parsed_and_verified() {
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let file = tmp.path().join("song.7z");
+        fs::write(&file, b"package").unwrap();
+        let b64 = |digest: &[u8]| base64::engine::general_purpose::STANDARD.encode(digest);
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(ExpectedDigest::from_headers(&headers), None);
+        headers.insert(
+            "content-md5",
+            HeaderValue::from_str(&b64(&Md5::digest(b"package"))).unwrap(),
+        );
+        ExpectedDigest::from_headers(&headers)
+            .unwrap()
+            .verify(&file)
+            .expect("md5 matches");
+
+        headers.insert(
+            "repr-digest",
+            HeaderValue::from_str(&format!("sha-256=:{}:", b64(&Sha256::digest(b"other"))))
+                .unwrap(),
+        );
+        let expected = ExpectedDigest::from_headers(&headers).unwrap();
+        assert!(matches!(expected, ExpectedDigest::Sha256(_)));
+        assert!(expected.verify(&file).unwrap_err().is::<IntegrityError>());
+    }
+
+    #[test]
+    fn extraction_requires_the_requested_chart() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let archive_path = tmp.path().join("song.zip");
+        create_zip(&archive_path, &[("Song/a.bms", b"#TITLE a")], None);
+        let dest = tmp.path().join("output");
+
+        let err = extract_compressed_file(
+            &archive_path,
+            Some(&dest),
+            "",
+            None,
+            Some(&md5_hex(b"#TITLE b")),
+            false,
+        )
+        .unwrap_err();
+        assert!(err.is::<IntegrityError>());
+        assert!(!dest.join("Song").exists());
+
+        let sha256 = hex_string(&Sha256::digest(b"#TITLE a"));
+        extract_compressed_file(&archive_path, Some(&dest), "", None, Some(&sha256), false)
+            .unwrap();
+        assert!(dest.join("Song/a.bms").exists());
+        // Hashes the sources do not use are not checked
+        extract_compressed_file(&archive_path, Some(&dest), "", None, Some("hash"), false).unwrap();
+    }
+
+    #[test]
+    fn broken_package_falls_back_to_mirror_and_records_stats() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let chart = b"#TITLE wanted";
+        let md5 = md5_hex(chart);
+        let wrong = tmp.path().join("wrong.zip");
+        create_zip(&wrong, &[("Song/other.bms", b"#TITLE other")], None);
+        let right = tmp.path().join("right.zip");
+        create_zip(&right, &[("Song/wanted.bms", chart)], None);
+        let (primary_url, _) = serve_body(fs::read(&wrong).unwrap(), true, Duration::ZERO);
+        let (mirror_url, _) = serve_body(fs::read(&right).unwrap(), true, Duration::ZERO);
+        let download_dir = tmp.path().join("downloads");
+        fs::create_dir(&download_dir).unwrap();
+        let processor = HttpDownloadProcessor::new(
+            Arc::new(FakeMainControllerRef),
+            Arc::new(MirrorSource {
+                name: "Primary",
+                url: primary_url,
+            }),
+            download_dir.to_string_lossy().to_string(),
+        );
+        processor.set_fallback_sources(vec![Arc::new(MirrorSource {
+            name: "Mirror",
+            url: mirror_url.clone(),
+        })]);
+
+        processor.submit_md5_task(&md5, "Wanted");
+        wait_until(|| lock_or_recover(&processor.all_tasks()).contains_key(&1));
+        let task = lock_or_recover(&processor.all_tasks())[&1].clone();
+        wait_until(|| task_status(&task) == DownloadTaskStatus::Extracted);
+
+        {
+            let task = lock_or_recover(&task);
+            assert_eq!(task.source_name(), "Mirror");
+            assert_eq!(task.url(), mirror_url);
+            assert_eq!(task.failed_sources(), ["Primary".to_string()]);
+        }
+        assert_eq!(
+            fs::read(download_dir.join("Song/wanted.bms")).unwrap(),
+            chart
+        );
+        assert!(!download_dir.join("Song/other.bms").exists());
+
+        let stats: HashMap<_, _> = processor.source_stats().into_iter().collect();
+        assert_eq!(
+            (
+                stats["Primary"].failures,
+                stats["Primary"].integrity_failures
+            ),
+            (1, 1)
+        );
+        assert_eq!(stats["Mirror"].successes, 1);
+        let saved = SourceStatsStore::load(download_dir.join(SOURCE_STATS_FILE));
+        assert_eq!(saved.get("Mirror").successes, 1);
+    }
+
+    #[test]
+    fn gives_up_when_every_source_fails() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let wrong = tmp.path().join("wrong.zip");
+        create_zip(&wrong, &[("Song/other.bms", b"#TITLE other")], None);
+        let (url, _) = serve_body(fs::read(&wrong).unwrap(), true, Duration::ZERO);
+        let download_dir = tmp.path().join("downloads");
+        fs::create_dir(&download_dir).unwrap();
+        let processor = HttpDownloadProcessor::new(
+            Arc::new(FakeMainControllerRef),
+            Arc::new(MirrorSource {
+                name: "Primary",
+                url: url.clone(),
+            }),
+            download_dir.to_string_lossy().to_string(),
+        );
+
+        processor.submit_url_task(&url, &md5_hex(b"#TITLE wanted"), "Wanted");
+        let task = lock_or_recover(&processor.all_tasks())[&1].clone();
+        wait_until(|| task_status(&task) == DownloadTaskStatus::Error);
+
+        let task = lock_or_recover(&task);
+        assert!(
+            task.error_message()
+                .is_some_and(|e| e.starts_with("Integrity check failed"))
+        );
+        // The rejected archive is not kept around for a resume
+        assert!(task.download_path().is_some_and(|p| !p.exists()));
+    }
+
+    #[derive(Default)]
+    struct RecordingMainControllerRef {
+        scanned: Mutex<Vec<String>>,
+    }
+
+    impl MainControllerRef for RecordingMainControllerRef {
+        fn update_song(&self, path: &str, _force: bool) {
+            self.scanned.lock().unwrap().push(path.to_string());
+        }
+    }
+
+    #[test]
+    fn places_package_by_table_and_scans_only_that_folder() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let archive_path = tmp.path().join("song.zip");
+        create_zip(&archive_path, &[("Song: Remix?/a.bms", b"#TITLE a")], None);
+        let (url, _) = serve_body(fs::read(&archive_path).unwrap(), true, Duration::ZERO);
+        let download_dir = tmp.path().join("downloads");
+        fs::create_dir(&download_dir).unwrap();
+        let main = Arc::new(RecordingMainControllerRef::default());
+        let processor = HttpDownloadProcessor::new(
+            main.clone(),
+            Arc::new(FakeHttpDownloadSource::new(&url)),
+            download_dir.to_string_lossy().to_string(),
+        );
+        processor.set_placement_rules(PlacementRules {
+            subfolder: "{table}".to_string(),
+            sanitize_names: true,
+        });
+        assert_eq!(processor.library_revision(), 0);
+
+        processor.submit_url_task_in_table(&url, "hash", "Song", Some("発狂BMS"));
+        let task = lock_or_recover(&processor.all_tasks())[&1].clone();
+        wait_until(|| task_status(&task) == DownloadTaskStatus::Extracted);
+
+        let package = download_dir.join("発狂BMS").join("Song_ Remix_");
+        assert_eq!(fs::read(package.join("a.bms")).unwrap(), b"#TITLE a");
+        assert_eq!(
+            *main.scanned.lock().unwrap(),
+            vec![package.to_string_lossy().to_string()]
+        );
+        {
+            let task = lock_or_recover(&task);
+            assert_eq!(task.processing_step(), Some(ProcessingStep::Scanned));
+            assert_eq!(
+                task.scanned_directory(),
+                Some(package.to_string_lossy().as_ref())
+            );
+        }
+        wait_until(|| processor.library_revision() == 1);
+    }
+
+    /// Resolves every hash of its kind to `https://<name>/<hash>`.
+    struct HashSource {
+        name: &'static str,
+        priority: i32,
+        sha256: bool,
+    }
+
+    impl HttpDownloadSource for HashSource {
+        fn get_download_url_based_on_md5(&self, md5: &str) -> anyhow::Result<String> {
+            Ok(format!("https://{}/{}", self.name, md5))
+        }
+
+        fn get_download_url_based_on_sha256(&self, sha256: &str) -> anyhow::Result<String> {
+            Ok(format!("https://{}/{}", self.name, sha256))
+        }
+
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn priority(&self) -> i32 {
+            self.priority
+        }
+
+        fn is_allow_download_through_md5(&self) -> bool {
+            !self.sha256
+        }
+
+        fn is_allow_download_through_sha256(&self) -> bool {
+            self.sha256
+        }
+
+        fn is_allow_meta_query(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn resolves_mirrors_by_priority_and_hash_type() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let source = |name, priority, sha256| -> Arc<dyn HttpDownloadSource> {
+            Arc::new(HashSource {
+                name,
+                priority,
+                sha256,
+            })
+        };
+        let processor = HttpDownloadProcessor::new(
+            Arc::new(FakeMainControllerRef),
+            source("primary", 0, false),
+            tmp.path().to_string_lossy().to_string(),
+        );
+        processor.set_fallback_sources(vec![
+            source("low", 0, false),
+            source("high", 5, false),
+            source("by-sha256", 1, true),
+        ]);
+        let md5 = "0".repeat(32);
+        let sha256 = "1".repeat(64);
+        let resolve = |hash: &str, skip: &[&str]| {
+            let skip: Vec<String> = skip.iter().map(|s| s.to_string()).collect();
+            resolve_download_url(&processor.queue, hash, &skip)
+                .map(|(_, name)| name)
+                .ok()
+        };
+
+        assert_eq!(resolve(&md5, &[]).as_deref(), Some("primary"));
+        assert_eq!(resolve(&md5, &["primary"]).as_deref(), Some("high"));
+        assert_eq!(resolve(&md5, &["primary", "high"]).as_deref(), Some("low"));
+        assert_eq!(resolve(&md5, &["primary", "high", "low"]), None);
+        assert_eq!(resolve(&sha256, &[]).as_deref(), Some("by-sha256"));
+    }
+
+    #[test]
+    fn suspended_downloads_wait_in_queue_and_resume() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        // Pseudo-random so the archive stays large enough to stop mid-download
+        let mut seed = 1u32;
+        let noise: Vec<u8> = (0..300_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (seed >> 24) as u8
+            })
+            .collect();
+        let archive_path = tmp.path().join("song.zip");
+        create_zip(&archive_path, &[("Song/a.bms", &noise)], None);
+        let (url, ranges) = serve_body(
+            fs::read(&archive_path).unwrap(),
+            true,
+            Duration::from_millis(5),
+        );
+        let download_dir = tmp.path().join("downloads");
+        fs::create_dir(&download_dir).unwrap();
+        let processor = HttpDownloadProcessor::new(
+            Arc::new(FakeMainControllerRef),
+            Arc::new(FakeHttpDownloadSource::new(&url)),
+            download_dir.to_string_lossy().to_string(),
+        );
+        processor.set_speed_limit(1 << 30);
+
+        processor.submit_url_task(&url, "hash", "Song");
+        let task = lock_or_recover(&processor.all_tasks())[&1].clone();
+        wait_until(|| lock_or_recover(&task).download_size > 0);
+        processor.set_downloads_suspended(true);
+        wait_until(|| {
+            task_status(&task) == DownloadTaskStatus::Queued
+                && processor.queue.active_downloads.load(Ordering::Acquire) == 0
+        });
+        assert!(processor.downloads_suspended());
+        assert_eq!(lock_or_recover(&processor.queue.pending).len(), 1);
+        let partial = partial_download_path(lock_or_recover(&task).download_path().unwrap());
+        assert!(partial.exists());
+
+        processor.set_downloads_suspended(false);
+        wait_until(|| task_status(&task) == DownloadTaskStatus::Extracted);
+        assert_eq!(fs::read(download_dir.join("Song/a.bms")).unwrap(), noise);
+        let ranges = ranges.lock().unwrap();
+        assert_eq!(ranges.len(), 2);
+        assert!(ranges[1].is_some());
+    }
 }
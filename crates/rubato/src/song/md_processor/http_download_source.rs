@@ -10,9 +10,23 @@ pub trait HttpDownloadSource: Send + Sync {
     /// download url, based on download source
     fn get_download_url_based_on_md5(&self, md5: &str) -> anyhow::Result<String>;
 
+    /// Construct download url based on sha256, for sources that allow it
+    fn get_download_url_based_on_sha256(&self, sha256: &str) -> anyhow::Result<String> {
+        let _ = sha256;
+        Err(anyhow::anyhow!(
+            "{} cannot look charts up by sha256",
+            self.name()
+        ))
+    }
+
     /// Name is an unique symbol, also the option from 'otherTab'
     fn name(&self) -> &str;
 
+    /// Higher priority sources are chosen first as the default and as mirrors
+    fn priority(&self) -> i32 {
+        0
+    }
+
     // For further implementations
 
     fn is_allow_download_through_md5(&self) -> bool;
@@ -9,6 +9,7 @@ pub struct HttpDownloadSourceMeta {
     // in the near future. As for now, keep it simple and stupid
     // However, it's not very easy to give user such flexibility
     default_url: String,
+    priority: i32,
     builder: fn(&Config) -> Box<dyn HttpDownloadSource>,
 }
 
@@ -21,10 +22,16 @@ impl HttpDownloadSourceMeta {
         HttpDownloadSourceMeta {
             name: name.to_string(),
             default_url: default_url.to_string(),
+            priority: 0,
             builder,
         }
     }
 
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
     pub fn build(&self, config: &Config) -> Box<dyn HttpDownloadSource> {
         (self.builder)(config)
     }
@@ -36,6 +43,10 @@ impl HttpDownloadSourceMeta {
     pub fn default_url(&self) -> &str {
         &self.default_url
     }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
 }
 
 #[cfg(test)]
@@ -70,6 +81,8 @@ mod tests {
             HttpDownloadSourceMeta::new("test_source", "https://example.com/dl/%s", dummy_builder);
         assert_eq!(meta.name(), "test_source");
         assert_eq!(meta.default_url(), "https://example.com/dl/%s");
+        assert_eq!(meta.priority(), 0);
+        assert_eq!(meta.with_priority(3).priority(), 3);
     }
 
     #[test]
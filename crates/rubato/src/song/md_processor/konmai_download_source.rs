@@ -39,6 +39,10 @@ impl HttpDownloadSource for KonmaiDownloadSource {
         META.name()
     }
 
+    fn priority(&self) -> i32 {
+        META.priority()
+    }
+
     /// Konmai backend uses a meta query endpoint instead of direct download link.
     /// Similar to wriggle, the url must be a pattern string with only one %s placeholder and anything could happen
     /// if not. It also requires authentication so we have to grab token if we don't have one or the server reports
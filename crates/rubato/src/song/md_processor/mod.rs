@@ -1,5 +1,7 @@
+pub mod download_source_registry;
 pub mod download_task;
 pub mod download_task_state;
+pub mod download_throttle;
 
 // Re-exports
 pub use crate::core::config::Config;
@@ -16,4 +18,6 @@ pub mod ipfs_information;
 pub mod konmai_download_source;
 pub mod music_database_accessor;
 pub mod music_download_processor;
+pub mod placement;
+pub mod source_stats;
 pub mod wriggle_download_source;
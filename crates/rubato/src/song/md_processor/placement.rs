@@ -0,0 +1,131 @@
+use std::path::{Path, PathBuf};
+
+/// Subfolder used for `{table}` when a download was not requested from a table.
+pub const UNSORTED_FOLDER: &str = "Unsorted";
+
+/// Where extracted packages go inside the download directory.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlacementRules {
+    /// Subfolder pattern relative to the download directory. `{table}` expands to
+    /// the difficulty table the chart was requested from and `{source}` to the
+    /// download source; `/` separates nested folders. Empty places packages directly
+    /// in the download directory.
+    pub subfolder: String,
+    /// Rename extracted package folders to names that are valid on every platform.
+    pub sanitize_names: bool,
+}
+
+impl PlacementRules {
+    /// Folder a package from `source`, requested from `table`, is extracted into.
+    /// Every expanded segment is sanitized, so table names cannot escape
+    /// `download_directory`.
+    pub fn target_directory(
+        &self,
+        download_directory: &Path,
+        table: Option<&str>,
+        source: &str,
+    ) -> PathBuf {
+        let table = table
+            .filter(|t| !t.trim().is_empty())
+            .unwrap_or(UNSORTED_FOLDER);
+        let mut target = download_directory.to_path_buf();
+        for segment in self.subfolder.split(['/', '\\']) {
+            let segment = segment
+                .replace("{table}", table)
+                .replace("{source}", source);
+            let segment = sanitize_name(&segment);
+            if !segment.is_empty() {
+                target.push(segment);
+            }
+        }
+        target
+    }
+}
+
+/// Make `name` usable as a single file or folder name on Windows, macOS and Linux:
+/// reserved and control characters become `_`, trailing dots and spaces are
+/// trimmed, and device names reserved by Windows get a `_` prefix.
+pub fn sanitize_name(name: &str) -> String {
+    let mut sanitized: String = name
+        .chars()
+        .map(|c| match c {
+            '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    sanitized.truncate(sanitized.trim_end_matches(['.', ' ']).len());
+    let sanitized = sanitized.trim_start().to_string();
+    let stem = sanitized.split('.').next().unwrap_or_default();
+    let device_number = |prefix: &str| {
+        stem.len() == 4
+            && stem
+                .get(..3)
+                .is_some_and(|p| p.eq_ignore_ascii_case(prefix))
+            && stem.as_bytes()[3].is_ascii_digit()
+    };
+    let reserved = ["CON", "PRN", "AUX", "NUL"]
+        .iter()
+        .any(|device| stem.eq_ignore_ascii_case(device))
+        || device_number("COM")
+        || device_number("LPT");
+    if reserved {
+        format!("_{}", sanitized)
+    } else {
+        sanitized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitizes_reserved_characters_and_names() {
+        assert_eq!(
+            sanitize_name("Song: \"Remix\" <v2>?"),
+            "Song_ _Remix_ _v2__"
+        );
+        assert_eq!(sanitize_name("a/b\\c\u{7}"), "a_b_c_");
+        assert_eq!(sanitize_name("  name. . "), "name");
+        assert_eq!(sanitize_name("con"), "_con");
+        assert_eq!(sanitize_name("COM1.bms"), "_COM1.bms");
+        assert_eq!(sanitize_name("COMMON"), "COMMON");
+        assert_eq!(sanitize_name(".."), "");
+        assert_eq!(sanitize_name("発狂BMS"), "発狂BMS");
+    }
+
+    #[test]
+    fn target_directory_expands_table_and_source() {
+        let root = Path::new("downloads");
+        let flat = PlacementRules::default();
+        assert_eq!(flat.target_directory(root, Some("Insane"), "wriggle"), root);
+
+        let rules = PlacementRules {
+            subfolder: "{source}/{table}".to_string(),
+            sanitize_names: true,
+        };
+        assert_eq!(
+            rules.target_directory(root, Some("発狂BMS: Insane"), "wriggle"),
+            root.join("wriggle").join("発狂BMS_ Insane")
+        );
+        assert_eq!(
+            rules.target_directory(root, None, "wriggle"),
+            root.join("wriggle").join(UNSORTED_FOLDER)
+        );
+    }
+
+    #[test]
+    fn target_directory_stays_inside_download_directory() {
+        let root = Path::new("downloads");
+        let rules = PlacementRules {
+            subfolder: "{table}".to_string(),
+            sanitize_names: false,
+        };
+        assert_eq!(
+            rules.target_directory(root, Some("../../etc"), "wriggle"),
+            root.join(".._.._etc")
+        );
+        assert_eq!(rules.target_directory(root, Some(".."), "wriggle"), root);
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::external::now_playing::write_replace;
+
+/// File the stats are kept in, inside the download directory.
+pub const SOURCE_STATS_FILE: &str = "source_stats.json";
+
+/// Outcome counters of one download source.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SourceStats {
+    /// Packages downloaded, verified and extracted.
+    pub successes: u32,
+    /// Lookups or downloads that failed (dead links, http errors, broken archives).
+    pub failures: u32,
+    /// Downloads that arrived but failed verification. Also counted in `failures`.
+    pub integrity_failures: u32,
+    pub last_error: Option<String>,
+}
+
+impl SourceStats {
+    /// Share of attempts that succeeded; sources without history count as reliable.
+    pub fn success_rate(&self) -> f64 {
+        let attempts = self.successes + self.failures;
+        if attempts == 0 {
+            1.0
+        } else {
+            f64::from(self.successes) / f64::from(attempts)
+        }
+    }
+}
+
+/// Per-source reliability, persisted so mirror fallback can prefer the
+/// sources that worked before.
+#[derive(Debug, Default)]
+pub struct SourceStatsStore {
+    path: Option<PathBuf>,
+    stats: BTreeMap<String, SourceStats>,
+}
+
+impl SourceStatsStore {
+    /// Load the stats saved at `path`; a missing or broken file starts empty.
+    pub fn load(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let stats = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Self {
+            path: Some(path),
+            stats,
+        }
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn get(&self, source: &str) -> SourceStats {
+        self.stats.get(source).cloned().unwrap_or_default()
+    }
+
+    pub fn all(&self) -> &BTreeMap<String, SourceStats> {
+        &self.stats
+    }
+
+    pub fn record_success(&mut self, source: &str) {
+        self.stats.entry(source.to_string()).or_default().successes += 1;
+        self.save();
+    }
+
+    pub fn record_failure(&mut self, source: &str, error: &str, integrity: bool) {
+        let stats = self.stats.entry(source.to_string()).or_default();
+        stats.failures += 1;
+        if integrity {
+            stats.integrity_failures += 1;
+        }
+        stats.last_error = Some(error.to_string());
+        self.save();
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let json = serde_json::to_string_pretty(&self.stats).unwrap_or_default();
+        if let Err(e) = write_replace(path, &json) {
+            log::debug!("Failed to save download source stats: {:#}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_outcomes_and_success_rate() {
+        let mut store = SourceStatsStore::default();
+        assert_eq!(store.get("wriggle").success_rate(), 1.0);
+
+        store.record_success("wriggle");
+        store.record_failure("wriggle", "Package not found at wriggle", false);
+        store.record_failure("wriggle", "Checksum mismatch", true);
+
+        let stats = store.get("wriggle");
+        assert_eq!(
+            (stats.successes, stats.failures, stats.integrity_failures),
+            (1, 2, 1)
+        );
+        assert_eq!(stats.last_error.as_deref(), Some("Checksum mismatch"));
+        assert!((stats.success_rate() - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stats_survive_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SOURCE_STATS_FILE);
+        let mut store = SourceStatsStore::load(&path);
+        store.record_success("konmai");
+        store.record_failure("wriggle", "timeout", false);
+
+        let reloaded = SourceStatsStore::load(&path);
+        assert_eq!(reloaded.all(), store.all());
+        assert_eq!(reloaded.get("konmai").successes, 1);
+    }
+
+    #[test]
+    fn broken_file_starts_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SOURCE_STATS_FILE);
+        fs::write(&path, "not json").unwrap();
+        assert!(SourceStatsStore::load(&path).all().is_empty());
+    }
+}
@@ -15,6 +15,8 @@ pub static META: LazyLock<HttpDownloadSourceMeta> = LazyLock::new(|| {
         "https://bms.wrigglebug.xyz/download/package/%s",
         |config| Box::new(WriggleDownloadSource::new(config)),
     )
+    // The default source, unless a custom one is given a higher priority
+    .with_priority(1)
 });
 
 impl WriggleDownloadSource {
@@ -39,6 +41,10 @@ impl HttpDownloadSource for WriggleDownloadSource {
         META.name()
     }
 
+    fn priority(&self) -> i32 {
+        META.priority()
+    }
+
     fn is_allow_download_through_md5(&self) -> bool {
         true
     }
@@ -3,7 +3,9 @@
 // Music download processor (merged from md-processor crate)
 pub mod md_processor;
 
+pub mod chart_analysis;
 pub mod folder_data;
+pub mod library_scan;
 pub mod song_data;
 pub mod song_database_accessor;
 pub mod song_database_update_listener;
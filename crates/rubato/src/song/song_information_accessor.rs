@@ -8,6 +8,7 @@ use bms::model::bms_model::BMSModel;
 use rusqlite::Connection;
 use rusqlite::hooks::{AuthAction, AuthContext, Authorization};
 
+use crate::skin::song_user_metadata::SongUserMetadata;
 use crate::song::song_data::SongData;
 use crate::song::song_information::SongInformation;
 
@@ -33,24 +34,36 @@ pub struct SongInformationAccessor {
 
 impl SongInformationAccessor {
     pub fn new(filepath: &str) -> anyhow::Result<Self> {
-        let base = SQLiteDatabaseAccessor::new(vec![Table::new(
-            "information",
-            vec![
-                Column::with_pk("sha256", "TEXT", 1, 1),
-                Column::new("n", "INTEGER"),
-                Column::new("ln", "INTEGER"),
-                Column::new("s", "INTEGER"),
-                Column::new("ls", "INTEGER"),
-                Column::new("total", "REAL"),
-                Column::new("density", "REAL"),
-                Column::new("peakdensity", "REAL"),
-                Column::new("enddensity", "REAL"),
-                Column::new("mainbpm", "REAL"),
-                Column::new("distribution", "TEXT"),
-                Column::new("speedchange", "TEXT"),
-                Column::new("lanenotes", "TEXT"),
-            ],
-        )]);
+        let base = SQLiteDatabaseAccessor::new(vec![
+            Table::new(
+                "information",
+                vec![
+                    Column::with_pk("sha256", "TEXT", 1, 1),
+                    Column::new("n", "INTEGER"),
+                    Column::new("ln", "INTEGER"),
+                    Column::new("s", "INTEGER"),
+                    Column::new("ls", "INTEGER"),
+                    Column::new("total", "REAL"),
+                    Column::new("density", "REAL"),
+                    Column::new("peakdensity", "REAL"),
+                    Column::new("enddensity", "REAL"),
+                    Column::new("mainbpm", "REAL"),
+                    Column::new("distribution", "TEXT"),
+                    Column::new("speedchange", "TEXT"),
+                    Column::new("lanenotes", "TEXT"),
+                ],
+            ),
+            Table::new(
+                "usermeta",
+                vec![
+                    Column::with_pk("sha256", "TEXT", 1, 1),
+                    Column::new("title", "TEXT"),
+                    Column::new("artist", "TEXT"),
+                    Column::new("tags", "TEXT"),
+                    Column::new("notes", "TEXT"),
+                ],
+            ),
+        ]);
 
         let conn = Connection::open(filepath)?;
         conn.execute_batch(
@@ -155,6 +168,50 @@ impl SongInformationAccessor {
         }
     }
 
+    /// All player-edited chart metadata.
+    pub fn user_metadata(&self) -> Vec<SongUserMetadata> {
+        let conn = lock_or_recover(&self.conn);
+        let result = (|| -> anyhow::Result<Vec<SongUserMetadata>> {
+            let mut stmt =
+                conn.prepare("SELECT sha256, title, artist, tags, notes FROM usermeta")?;
+            let rows = stmt.query_map([], |row| {
+                Ok(SongUserMetadata {
+                    sha256: row.get::<_, String>(0).unwrap_or_default(),
+                    title: row.get::<_, String>(1).unwrap_or_default(),
+                    artist: row.get::<_, String>(2).unwrap_or_default(),
+                    tags: row.get::<_, String>(3).unwrap_or_default(),
+                    notes: row.get::<_, String>(4).unwrap_or_default(),
+                })
+            })?;
+            Ok(rows.flatten().collect())
+        })();
+        result.unwrap_or_else(|e| {
+            log::error!("Error querying user metadata: {}", e);
+            Vec::new()
+        })
+    }
+
+    /// Store player-edited metadata for a chart. Empty metadata removes the record.
+    pub fn set_user_metadata(&self, metadata: &SongUserMetadata) -> anyhow::Result<()> {
+        let conn = lock_or_recover(&self.conn);
+        if metadata.is_empty() {
+            conn.execute("DELETE FROM usermeta WHERE sha256 = ?1", [&metadata.sha256])?;
+            return Ok(());
+        }
+        self.base
+            .insert_with_values(&conn, "usermeta", &|name: &str| -> rusqlite::types::Value {
+                let text = match name {
+                    "sha256" => &metadata.sha256,
+                    "title" => &metadata.title,
+                    "artist" => &metadata.artist,
+                    "tags" => &metadata.tags,
+                    "notes" => &metadata.notes,
+                    _ => return rusqlite::types::Value::Null,
+                };
+                rusqlite::types::Value::Text(text.clone())
+            })
+    }
+
     fn query_informations(
         &self,
         sql: &str,
@@ -265,6 +322,14 @@ impl SongInformationDb for SongInformationAccessor {
     fn end_update(&self) {
         self.end_update()
     }
+
+    fn user_metadata(&self) -> Vec<SongUserMetadata> {
+        self.user_metadata()
+    }
+
+    fn set_user_metadata(&self, metadata: &SongUserMetadata) -> anyhow::Result<()> {
+        self.set_user_metadata(metadata)
+    }
 }
 
 #[cfg(test)]
@@ -473,4 +538,25 @@ mod tests {
             .unwrap();
         assert_eq!(count, 1, "data should be intact after blocked operations");
     }
+
+    #[test]
+    fn user_metadata_roundtrip_and_removal() {
+        let (accessor, _tmpdir) = setup_info_accessor();
+        assert!(accessor.user_metadata().is_empty());
+
+        let mut meta = SongUserMetadata::new(TEST_SHA256);
+        meta.title = "Display".to_string();
+        meta.tags = "jacks".to_string();
+        accessor.set_user_metadata(&meta).unwrap();
+        meta.notes = "hard ending".to_string();
+        accessor.set_user_metadata(&meta).unwrap();
+        assert_eq!(accessor.user_metadata(), vec![meta.clone()]);
+        // Chart information is untouched
+        assert!(accessor.information(TEST_SHA256).is_some());
+
+        accessor
+            .set_user_metadata(&SongUserMetadata::new(TEST_SHA256))
+            .unwrap();
+        assert!(accessor.user_metadata().is_empty());
+    }
 }
@@ -4,7 +4,10 @@ use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::core::sqlite_database_accessor::{Column, SQLiteDatabaseAccessor, Table};
+use crate::core::performance_metrics::{PerformanceMetrics, WATCH_SONG_DB_QUERY};
+use crate::core::sqlite_database_accessor::{
+    Column, SQLiteDatabaseAccessor, Table, upgrade_with_backup,
+};
 use crate::core::validatable::remove_invalid_elements_vec;
 use crate::skin::sync_utils::lock_or_recover;
 use bms::model::bms_decoder::BMSDecoder;
@@ -139,11 +142,37 @@ impl SQLiteSongDatabaseAccessor {
         self.plugins.push(plugin);
     }
 
+    /// Number of charts stored under the BMS folder `folder`.
+    ///
+    /// `folder` is matched the same way paths are stored: relative to the
+    /// database root when possible, absolute otherwise.
+    pub fn count_songs_in_folder(&self, folder: &str) -> usize {
+        let folder = Path::new(folder);
+        let folder = folder.strip_prefix(&self.root).unwrap_or(folder);
+        let mut prefix = folder.to_string_lossy().to_string();
+        if prefix.is_empty() {
+            return 0;
+        }
+        if !prefix.ends_with(std::path::MAIN_SEPARATOR) {
+            prefix.push(std::path::MAIN_SEPARATOR);
+        }
+        let conn = lock_or_recover(&self.conn);
+        conn.query_row(
+            "SELECT COUNT(*) FROM song WHERE substr(path, 1, length(?1)) = ?1",
+            [&prefix],
+            |row| row.get::<_, i64>(0),
+        )
+        .map(|count| count as usize)
+        .unwrap_or_else(|e| {
+            log::error!("Error counting songs in {}: {}", prefix, e);
+            0
+        })
+    }
+
     fn create_table(&self) -> anyhow::Result<()> {
         let conn = lock_or_recover(&self.conn);
-        self.base.validate(&conn)?;
 
-        // Check if sha256 is primary key in song table (migration check)
+        // Old databases keyed the song table by sha256; it is now keyed by path
         let mut stmt = conn.prepare("PRAGMA TABLE_INFO(song)")?;
         let has_sha256_pk = stmt
             .query_map([], |row| {
@@ -153,33 +182,31 @@ impl SQLiteSongDatabaseAccessor {
             })?
             .filter_map(|r| r.ok())
             .any(|(name, pk)| name == "sha256" && pk == 1);
+        drop(stmt);
 
-        if has_sha256_pk {
-            conn.execute_batch("BEGIN IMMEDIATE")?;
-            let migration_result = (|| -> anyhow::Result<()> {
-                conn.execute("ALTER TABLE [song] RENAME TO [old_song]", [])?;
+        if has_sha256_pk || self.base.needs_upgrade(&conn)? {
+            upgrade_with_backup(&conn, || {
                 self.base.validate(&conn)?;
-                conn.execute(
-                    "INSERT INTO song SELECT \
-                     s.md5, s.sha256, s.title, s.subtitle, s.genre, s.artist, s.subartist, s.tag, s.path,\
-                     s.folder, s.stagefile, s.banner, s.backbmp, s.preview, s.parent, s.level, s.difficulty,\
-                     s.maxbpm, s.minbpm, s.length, s.mode, s.judge, s.feature, s.content,\
-                     s.date, s.favorite, s.adddate, s.notes, s.charthash \
-                     FROM old_song s \
-                     INNER JOIN (SELECT path, MAX(adddate) AS max_adddate FROM old_song GROUP BY path) g \
-                     ON s.path = g.path AND s.adddate = g.max_adddate",
-                    [],
-                )?;
-                conn.execute("DROP TABLE old_song", [])?;
-                Ok(())
-            })();
-            match migration_result {
-                Ok(()) => conn.execute_batch("COMMIT")?,
-                Err(e) => {
-                    let _ = conn.execute_batch("ROLLBACK");
-                    return Err(e);
+                if has_sha256_pk {
+                    conn.execute("ALTER TABLE [song] RENAME TO [old_song]", [])?;
+                    self.base.validate(&conn)?;
+                    conn.execute(
+                        "INSERT INTO song SELECT \
+                         s.md5, s.sha256, s.title, s.subtitle, s.genre, s.artist, s.subartist, s.tag, s.path,\
+                         s.folder, s.stagefile, s.banner, s.backbmp, s.preview, s.parent, s.level, s.difficulty,\
+                         s.maxbpm, s.minbpm, s.length, s.mode, s.judge, s.feature, s.content,\
+                         s.date, s.favorite, s.adddate, s.notes, s.charthash \
+                         FROM old_song s \
+                         INNER JOIN (SELECT path, MAX(adddate) AS max_adddate FROM old_song GROUP BY path) g \
+                         ON s.path = g.path AND s.adddate = g.max_adddate",
+                        [],
+                    )?;
+                    conn.execute("DROP TABLE old_song", [])?;
                 }
-            }
+                Ok(())
+            })?;
+        } else {
+            self.base.validate(&conn)?;
         }
 
         // FTS5 full-text search index for song text search
@@ -243,6 +270,7 @@ impl SQLiteSongDatabaseAccessor {
         sql: &str,
         params: &[&dyn rusqlite::types::ToSql],
     ) -> anyhow::Result<Vec<SongData>> {
+        let _watch = PerformanceMetrics::get().watch(WATCH_SONG_DB_QUERY);
         let mut stmt = conn.prepare(sql)?;
         let rows = stmt.query_map(params, |row| {
             let mut sd = SongData::new();
@@ -297,6 +325,7 @@ impl SQLiteSongDatabaseAccessor {
         sql: &str,
         params: &[&dyn rusqlite::types::ToSql],
     ) -> anyhow::Result<Vec<FolderData>> {
+        let _watch = PerformanceMetrics::get().watch(WATCH_SONG_DB_QUERY);
         let mut stmt = conn.prepare(sql)?;
         let rows = stmt.query_map(params, |row| {
             Ok(FolderData {
@@ -708,6 +737,47 @@ impl SongDatabaseAccessor for SQLiteSongDatabaseAccessor {
         Ok(())
     }
 
+    fn delete_song_datas(&self, sha256: &[String]) -> anyhow::Result<()> {
+        let mut conn = lock_or_recover(&self.conn);
+        let tx = conn
+            .transaction()
+            .map_err(|e| anyhow::anyhow!("Error starting transaction: {e}"))?;
+        for hash in sha256 {
+            tx.execute(
+                "DELETE FROM song WHERE sha256 = ?1",
+                rusqlite::params![hash],
+            )
+            .map_err(|e| anyhow::anyhow!("Error deleting song {hash}: {e}"))?;
+        }
+        tx.commit()
+            .map_err(|e| anyhow::anyhow!("Error committing transaction: {e}"))?;
+        Ok(())
+    }
+
+    fn delete_song_datas_by_path(&self, paths: &[String]) -> anyhow::Result<()> {
+        let mut conn = lock_or_recover(&self.conn);
+        let tx = conn
+            .transaction()
+            .map_err(|e| anyhow::anyhow!("Error starting transaction: {e}"))?;
+        for path in paths {
+            tx.execute("DELETE FROM song WHERE path = ?1", rusqlite::params![path])
+                .map_err(|e| anyhow::anyhow!("Error deleting song at {path}: {e}"))?;
+        }
+        tx.commit()
+            .map_err(|e| anyhow::anyhow!("Error committing transaction: {e}"))?;
+        Ok(())
+    }
+
+    fn duplicate_song_datas(&self) -> Vec<SongData> {
+        let songs = self.query_songs(
+            "SELECT * FROM song WHERE sha256 IN \
+             (SELECT sha256 FROM song GROUP BY sha256 HAVING COUNT(*) > 1) \
+             ORDER BY sha256, path",
+            &[],
+        );
+        remove_invalid_elements_vec(songs)
+    }
+
     fn update_song_datas(
         &self,
         update_path: Option<&str>,
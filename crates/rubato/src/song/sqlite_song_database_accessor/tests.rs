@@ -141,6 +141,103 @@ fn test_set_song_datas_batch() {
     assert_eq!(results.len(), 1);
 }
 
+#[test]
+fn test_delete_song_datas_removes_only_given_charts() {
+    let accessor = create_test_accessor();
+    let songs = vec![
+        make_test_song("del_1", "sdel_1", "Delete Song 1"),
+        make_test_song("del_2", "sdel_2", "Delete Song 2"),
+    ];
+    accessor.set_song_datas(&songs).expect("set_song_datas");
+
+    accessor
+        .delete_song_datas(&["sdel_1".to_string()])
+        .expect("delete_song_datas");
+
+    assert!(accessor.song_datas("sha256", "sdel_1").is_empty());
+    assert_eq!(accessor.song_datas("sha256", "sdel_2").len(), 1);
+}
+
+#[test]
+fn test_duplicate_song_datas_groups_paths_of_same_chart() {
+    let accessor = create_test_accessor();
+    let mut copy = make_test_song("dup_1", "sdup_1", "Dup");
+    copy.file.set_path("other/Dup.bms".to_string());
+    accessor
+        .set_song_datas(&[
+            make_test_song("dup_1", "sdup_1", "Dup"),
+            copy,
+            make_test_song("single", "ssingle", "Single"),
+        ])
+        .expect("set_song_datas");
+
+    let duplicates = accessor.duplicate_song_datas();
+    let paths: Vec<_> = duplicates.iter().map(|sd| sd.file.path()).collect();
+    assert_eq!(paths, vec![Some("other/Dup.bms"), Some("test/Dup.bms")]);
+
+    accessor
+        .delete_song_datas_by_path(&["other/Dup.bms".to_string()])
+        .expect("delete_song_datas_by_path");
+    assert!(accessor.duplicate_song_datas().is_empty());
+    assert_eq!(accessor.song_datas("sha256", "sdup_1").len(), 1);
+}
+
+#[test]
+fn test_legacy_sha256_keyed_database_is_backed_up_and_migrated() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("songdata.db");
+    {
+        let conn = Connection::open(&db_path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE song (md5 TEXT, sha256 TEXT NOT NULL, title TEXT, path TEXT, \
+             adddate INTEGER, PRIMARY KEY(sha256)); \
+             INSERT INTO song (md5, sha256, title, path, adddate) \
+             VALUES ('m1', 's1', 'Old', 'test/Old.bms', 1);",
+        )
+        .unwrap();
+    }
+
+    let backups = || {
+        std::fs::read_dir(dir.path())
+            .unwrap()
+            .filter(|e| {
+                let path = e.as_ref().unwrap().path();
+                path.extension().is_some_and(|ext| ext == "bak")
+            })
+            .count()
+    };
+
+    let accessor = SQLiteSongDatabaseAccessor::new(db_path.to_str().unwrap(), &[]).unwrap();
+    let songs = accessor.song_datas("sha256", "s1");
+    assert_eq!(songs.len(), 1);
+    assert_eq!(songs[0].file.path(), Some("test/Old.bms"));
+    assert_eq!(backups(), 1);
+
+    // Reopening the migrated database makes no further backup
+    drop(accessor);
+    SQLiteSongDatabaseAccessor::new(db_path.to_str().unwrap(), &[]).unwrap();
+    assert_eq!(backups(), 1);
+}
+
+#[test]
+fn test_count_songs_in_folder() {
+    let accessor = create_test_accessor();
+    let mut outside = make_test_song("count_3", "scount_3", "Outside");
+    outside.file.set_path("testing/Outside.bms".to_string());
+    accessor
+        .set_song_datas(&[
+            make_test_song("count_1", "scount_1", "Song 1"),
+            make_test_song("count_2", "scount_2", "Song 2"),
+            outside,
+        ])
+        .expect("set_song_datas");
+
+    assert_eq!(accessor.count_songs_in_folder("test"), 2);
+    assert_eq!(accessor.count_songs_in_folder("./test"), 2);
+    assert_eq!(accessor.count_songs_in_folder("testing"), 1);
+    assert_eq!(accessor.count_songs_in_folder("missing"), 0);
+}
+
 #[test]
 fn test_insert_and_get_folder() {
     let accessor = create_test_accessor();
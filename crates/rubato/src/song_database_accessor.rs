@@ -22,6 +22,24 @@ pub trait SongDatabaseAccessor: Send + Sync {
     /// Set song data
     fn set_song_datas(&self, songs: &[SongData]) -> anyhow::Result<()>;
 
+    /// Remove the charts with the given SHA256 hashes from the song database.
+    fn delete_song_datas(&self, _sha256: &[String]) -> anyhow::Result<()> {
+        // default no-op
+        Ok(())
+    }
+
+    /// Remove the song database entries stored under the given paths, keeping
+    /// other paths of the same charts.
+    fn delete_song_datas_by_path(&self, _paths: &[String]) -> anyhow::Result<()> {
+        // default no-op
+        Ok(())
+    }
+
+    /// Charts stored under more than one path, ordered by SHA256 and path.
+    fn duplicate_song_datas(&self) -> Vec<SongData> {
+        Vec::new()
+    }
+
     /// Search song data by text
     fn song_datas_by_text(&self, text: &str) -> Vec<SongData>;
     /// Get folder data by key-value pair
@@ -2,6 +2,7 @@ use bms::model::bms_model::BMSModel;
 
 use crate::skin::song_data::SongData;
 use crate::skin::song_information::SongInformation;
+use crate::skin::song_user_metadata::SongUserMetadata;
 
 /// Song information database accessor interface.
 ///
@@ -24,4 +25,14 @@ pub trait SongInformationDb: Send + Sync {
 
     /// Commit update transaction
     fn end_update(&self);
+
+    /// All player-edited chart metadata
+    fn user_metadata(&self) -> Vec<SongUserMetadata> {
+        Vec::new()
+    }
+
+    /// Store player-edited metadata for a chart; empty metadata removes the record
+    fn set_user_metadata(&self, _metadata: &SongUserMetadata) -> anyhow::Result<()> {
+        anyhow::bail!("user metadata is not supported by this database")
+    }
 }
@@ -22,6 +22,7 @@ use crate::result::PlayerResource as ResultPlayerResource;
 use crate::result::course_result::CourseResult;
 use crate::result::music_result::MusicResult;
 use crate::select::music_selector::MusicSelector;
+use crate::skin::player_config::{GHOST_REPLAY1, GHOST_SCORE};
 use crate::skin::score_data::ScoreData;
 
 use shared_selector::SharedMusicSelectorState;
@@ -89,6 +90,10 @@ pub fn wire_selector_dependencies(selector: &mut MusicSelector, controller: &mut
     selector.rivals = (0..controller.rival_count())
         .filter_map(|i| controller.rival_information(i))
         .collect();
+    let rival_data = controller.rival_data_accessor();
+    selector.rival_score_dbs = (0..rival_data.rival_count())
+        .filter_map(|i| rival_data.rival_score_db_path(i).map(str::to_string))
+        .collect();
 
     // Sound paths
     if let Some(sm) = controller.sound_manager() {
@@ -98,6 +103,9 @@ pub fn wire_selector_dependencies(selector: &mut MusicSelector, controller: &mut
     // HTTP downloader
     selector.http_downloader = controller.clone_http_download_processor();
 
+    // Background library scans
+    selector.set_library_scan(controller.library_scan_status());
+
     // IPFS download alive
     selector.ipfs_download_alive = controller.is_ipfs_download_alive();
 }
@@ -249,6 +257,7 @@ impl LauncherStateFactory {
                 if let Some(bga_arc) = resource.and_then(|r| r.bga()) {
                     player.set_bga_processor(Arc::clone(bga_arc));
                 }
+                player.set_bga_preload(resource.and_then(|r| r.bga_preload()).cloned());
 
                 // Wire player config
                 player.set_player_config(controller.player_config().clone());
@@ -308,6 +317,7 @@ impl LauncherStateFactory {
                 if let Some(res) = resource {
                     player.set_cumulative_playtime(res.player_data().playtime);
                     player.set_player_data(*res.player_data());
+                    player.set_today_player_data(*res.today_player_data());
                 }
 
                 // Wire course constraints
@@ -328,6 +338,11 @@ impl LauncherStateFactory {
                     player.set_previous_gauge_values(gauge_log.clone());
                 }
 
+                // Wire the timing offset saved for this chart
+                if let Some(pda) = controller.play_data_accessor() {
+                    player.set_chart_offset(pda.read_chart_offset(&model.sha256));
+                }
+
                 // Wire guide SE from player config
                 player.set_guide_se(controller.player_config().display_settings.is_guide_se);
 
@@ -382,7 +397,12 @@ impl LauncherStateFactory {
                 // BMSPlayer stores freq_on/force_no_ir_send; these flow to PlayerResource
                 // via ScoreHandoff when the play session ends.
                 {
-                    let freq = crate::modmenu::freq_trainer_menu::FreqTrainerMenu::get_freq();
+                    use crate::modmenu::freq_trainer_menu::FreqTrainerMenu;
+                    let freq = if FreqTrainerMenu::is_freq_trainer_enabled() {
+                        FreqTrainerMenu::get_freq()
+                    } else {
+                        100
+                    };
                     let is_play_mode =
                         player.play_mode().mode == crate::core::bms_player_mode::Mode::Play;
                     let freq_option = controller
@@ -399,6 +419,28 @@ impl LauncherStateFactory {
                 let sha256 = &model.sha256;
                 let has_ln = model.contains_undefined_long_note();
                 let db_score = controller.read_score_data_by_hash(sha256, has_ln, lnmode);
+
+                // Ghost pacemaker from the stored score or a replay slot
+                let ghost_source = controller.player_config().play_settings.ghost_source;
+                let ghost_replay = if ghost_source >= GHOST_REPLAY1 {
+                    controller.read_replay_data_by_hash(
+                        sha256,
+                        has_ln,
+                        lnmode,
+                        ghost_source - GHOST_REPLAY1,
+                    )
+                } else {
+                    None
+                };
+                let ghost = if ghost_source == GHOST_SCORE {
+                    db_score.as_ref().and_then(|s| s.decode_ghost())
+                } else {
+                    ghost_replay.as_ref().map(|rd| rd.ghost.clone())
+                };
+                player.set_ghost(ghost);
+                if controller.player_config().display_settings.ghost_key_beam {
+                    player.set_ghost_key_beams(ghost_replay.map(|rd| rd.keylog));
+                }
                 player.set_db_score(db_score);
 
                 // Java: resource.getRivalScoreData()
@@ -409,7 +451,10 @@ impl LauncherStateFactory {
                 // Java: TargetProperty.getTargetProperty(config.getTargetid()).getTarget(main)
                 // Java: resource.setTargetScoreData(targetScore)
                 let target_score = if rival_score.is_none() || is_course_mode {
-                    let targetid = controller.player_config().select_settings.targetid.clone();
+                    let targetid = controller
+                        .player_config()
+                        .select_settings
+                        .resolved_targetid();
                     let total_notes = model.total_notes();
                     Self::compute_target_score(&targetid, total_notes, controller)
                 } else {
@@ -114,6 +114,10 @@ impl MainState for SharedMusicSelectorState {
         lock_or_recover(&self.selector).sound(sound)
     }
 
+    fn best_score(&self) -> Option<crate::core::score_data::ScoreData> {
+        lock_or_recover(&self.selector).best_score()
+    }
+
     fn play_sound_loop(&mut self, sound: SoundType, loop_sound: bool) {
         self.with_selector(|selector| selector.play_sound_loop(sound, loop_sound));
     }
@@ -144,4 +148,8 @@ impl MainState for SharedMusicSelectorState {
     fn take_player_resource(&mut self) -> Option<crate::core::player_resource::PlayerResource> {
         self.with_selector(|selector| selector.take_player_resource())
     }
+
+    fn open_chart_file(&mut self, path: &std::path::Path) -> bool {
+        self.with_selector(|selector| selector.open_chart_file(path))
+    }
 }
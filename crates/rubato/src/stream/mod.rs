@@ -7,5 +7,18 @@ pub mod stream_command;
 // Stream request command (!!req)
 pub mod stream_request_command;
 
-// Stream controller (pipe reader)
+// Token-authenticated request queue moderation (!!ctl)
+pub mod remote_control_command;
+
+// Line protocol shared by the pipe and socket transports
+pub mod stream_protocol;
+
+// Unix domain socket transport
+#[cfg(unix)]
+pub mod unix_socket;
+
+// Stream controller (pipe / socket reader)
 pub mod stream_controller;
+
+// WebSocket push of game state for browser overlays
+pub mod state_push_server;
@@ -0,0 +1,183 @@
+use std::sync::{Arc, Mutex};
+
+use crate::select::music_selector::MusicSelector;
+
+use super::stream_command::StreamCommand;
+use crate::skin::sync_utils::lock_or_recover;
+
+/// Request queue moderation for bots (`!!ctl <token> <action> [sha256]`)
+///
+/// Actions:
+/// - `skip` drops the request that is next in line
+/// - `top <sha256>` moves a request to the front of the queue
+/// - `autoplay` switches whether requests are played with autoplay
+///
+/// Commands are ignored unless the token matches
+/// `PlayerConfig::request_control_token`, which is read on every command so a
+/// changed token applies at once. An empty token disables remote control.
+pub struct RemoteControlCommand {
+    pub selector: Arc<Mutex<MusicSelector>>,
+}
+
+/// Parsed `!!ctl` action.
+#[derive(Debug, PartialEq, Eq)]
+enum ControlAction<'a> {
+    Skip,
+    Top(&'a str),
+    Autoplay,
+}
+
+impl<'a> ControlAction<'a> {
+    fn parse(action: &str, arg: Option<&'a str>) -> Option<Self> {
+        match (action, arg) {
+            ("skip", None) => Some(Self::Skip),
+            ("top", Some(sha256)) if sha256.len() == 64 => Some(Self::Top(sha256)),
+            ("autoplay", None) => Some(Self::Autoplay),
+            _ => None,
+        }
+    }
+}
+
+impl RemoteControlCommand {
+    pub fn new(selector: Arc<Mutex<MusicSelector>>) -> Self {
+        Self { selector }
+    }
+}
+
+/// Compare tokens without returning early on the first differing byte.
+fn token_matches(expected: &str, given: &str) -> bool {
+    if expected.is_empty() || expected.len() != given.len() {
+        return false;
+    }
+    expected
+        .bytes()
+        .zip(given.bytes())
+        .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+        == 0
+}
+
+impl StreamCommand for RemoteControlCommand {
+    fn command_string(&self) -> &str {
+        "!!ctl"
+    }
+
+    fn run(&mut self, data: &str) {
+        let mut parts = data.split_whitespace();
+        let (Some(token), Some(action)) = (parts.next(), parts.next()) else {
+            return;
+        };
+        let arg = parts.next();
+        if parts.next().is_some() {
+            return;
+        }
+
+        let mut selector = lock_or_recover(&self.selector);
+        if !token_matches(&selector.config.request_control_token, token) {
+            log::warn!("Rejected stream control command with an invalid token");
+            return;
+        }
+        let Some(action) = ControlAction::parse(action, arg) else {
+            log::warn!("Unknown stream control command: {}", action);
+            return;
+        };
+        match action {
+            ControlAction::Skip => {
+                selector.skip_next_stream_request();
+            }
+            ControlAction::Top(sha256) => {
+                if !selector.prioritize_stream_request(sha256) {
+                    log::info!("Stream control: {} is not in the request queue", sha256);
+                }
+            }
+            ControlAction::Autoplay => {
+                selector.toggle_stream_request_autoplay();
+            }
+        }
+    }
+
+    fn dispose(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skin::song_data::SongData;
+
+    fn sha(c: char) -> String {
+        std::iter::repeat_n(c, 64).collect()
+    }
+
+    fn make_selector(token: &str, requests: &[&str]) -> Arc<Mutex<MusicSelector>> {
+        let mut selector = MusicSelector::new();
+        selector.config.request_control_token = token.to_string();
+        for sha256 in requests {
+            let mut song = SongData::new();
+            song.file.sha256 = sha256.to_string();
+            selector.stream_requests.push(song, 10);
+        }
+        Arc::new(Mutex::new(selector))
+    }
+
+    fn queued(selector: &Arc<Mutex<MusicSelector>>) -> Vec<String> {
+        lock_or_recover(selector)
+            .stream_requests
+            .songs()
+            .iter()
+            .map(|song| song.file.sha256.clone())
+            .collect()
+    }
+
+    #[test]
+    fn skip_and_top_reorder_the_queue() {
+        let (a, b, c) = (sha('a'), sha('b'), sha('c'));
+        let selector = make_selector("secret", &[&a, &b, &c]);
+        let mut command = RemoteControlCommand::new(Arc::clone(&selector));
+
+        command.run(&format!("secret top {}", c));
+        assert_eq!(queued(&selector), [c.clone(), a.clone(), b.clone()]);
+
+        command.run("secret skip");
+        assert_eq!(queued(&selector), [a, b]);
+    }
+
+    #[test]
+    fn autoplay_toggles() {
+        let selector = make_selector("secret", &[]);
+        let mut command = RemoteControlCommand::new(Arc::clone(&selector));
+
+        command.run("secret autoplay");
+        assert!(lock_or_recover(&selector).stream_requests.autoplay());
+        command.run("secret autoplay");
+        assert!(!lock_or_recover(&selector).stream_requests.autoplay());
+    }
+
+    #[test]
+    fn rejects_wrong_or_unset_token() {
+        let a = sha('a');
+        let selector = make_selector("secret", &[&a]);
+        let mut command = RemoteControlCommand::new(Arc::clone(&selector));
+        command.run("secreT skip");
+        command.run("secret2 skip");
+        command.run("skip");
+        assert_eq!(queued(&selector), [a.clone()]);
+
+        // An empty token disables remote control entirely
+        let selector = make_selector("", &[&a]);
+        let mut command = RemoteControlCommand::new(Arc::clone(&selector));
+        command.run(" skip");
+        command.run("skip skip");
+        assert_eq!(queued(&selector), [a]);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_actions() {
+        assert_eq!(
+            ControlAction::parse("skip", None),
+            Some(ControlAction::Skip)
+        );
+        assert_eq!(ControlAction::parse("skip", Some("x")), None);
+        assert_eq!(ControlAction::parse("top", None), None);
+        assert_eq!(ControlAction::parse("top", Some("short")), None);
+        assert_eq!(ControlAction::parse("reboot", None), None);
+    }
+}
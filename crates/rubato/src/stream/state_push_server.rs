@@ -0,0 +1,427 @@
+// WebSocket push of game state for browser overlays.
+//
+// Streams state transitions, the current song, the live score/combo and the
+// gauge as JSON events, so overlays update as soon as something changes instead
+// of polling the overlay server. Listens on localhost only. A client receives a
+// `hello` event with the full state on connect, then one event per change:
+//
+//   {"event":"state","screen":"play"}
+//   {"event":"song","song":{...}}
+//   {"event":"score","live":{...}}
+//   {"event":"gauge","value":82.5}
+//
+// Text frames sent by a client are read as stream command lines (e.g.
+// `!!req <sha256>`) and handed to the stream controller by the main loop.
+
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio_tungstenite::tungstenite::{self, Message, WebSocket};
+
+use crate::external::overlay_server::{OverlayLive, OverlaySong};
+use crate::skin::sync_utils::lock_or_recover;
+
+/// Port used when `IntegrationConfig::state_push_port` is 0.
+pub const DEFAULT_STATE_PUSH_PORT: u16 = 5769;
+
+/// Time a client gets to finish the handshake or take a pushed event.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// How often the broadcast thread checks clients for incoming commands.
+const READ_INTERVAL: Duration = Duration::from_millis(50);
+
+/// JSON event sent to overlay clients, tagged by `event`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum StateEvent {
+    /// Full state, sent once when a client connects.
+    Hello {
+        screen: String,
+        song: Option<OverlaySong>,
+        live: Option<OverlayLive>,
+    },
+    /// The game moved to another screen.
+    State { screen: String },
+    /// The selected or playing song changed.
+    Song { song: Option<OverlaySong> },
+    /// Score, judge counts or combo changed.
+    Score { live: Option<OverlayLive> },
+    /// Only the gauge value changed.
+    Gauge { value: Option<f32> },
+}
+
+/// Last published state, diffed against each update.
+#[derive(Default)]
+struct PushState {
+    screen: String,
+    song: Option<OverlaySong>,
+    live: Option<OverlayLive>,
+}
+
+impl PushState {
+    /// Events that turn `self` into the given state.
+    fn diff(
+        &self,
+        screen: &str,
+        song: &Option<OverlaySong>,
+        live: &Option<OverlayLive>,
+    ) -> Vec<StateEvent> {
+        let mut events = Vec::new();
+        if self.screen != screen {
+            events.push(StateEvent::State {
+                screen: screen.to_string(),
+            });
+        }
+        if self.song != *song {
+            events.push(StateEvent::Song { song: song.clone() });
+        }
+        let without_gauge = |live: &Option<OverlayLive>| {
+            live.as_ref().map(|live| OverlayLive {
+                gauge: None,
+                ..live.clone()
+            })
+        };
+        if without_gauge(&self.live) != without_gauge(live) {
+            events.push(StateEvent::Score { live: live.clone() });
+        } else {
+            let gauge = |live: &Option<OverlayLive>| live.as_ref().and_then(|l| l.gauge);
+            if gauge(&self.live) != gauge(live) {
+                events.push(StateEvent::Gauge { value: gauge(live) });
+            }
+        }
+        events
+    }
+}
+
+enum Outgoing {
+    /// A client that finished the handshake, with its `hello` event.
+    Client(Box<WebSocket<TcpStream>>, String),
+    Event(String),
+}
+
+/// WebSocket server thread plus the state it pushes. Stops when dropped.
+pub struct StatePushServer {
+    state: Arc<Mutex<PushState>>,
+    sender: Option<Sender<Outgoing>>,
+    received: Arc<Mutex<Vec<String>>>,
+    addr: SocketAddr,
+    shutdown: Arc<AtomicBool>,
+    threads: Vec<std::thread::JoinHandle<()>>,
+}
+
+impl StatePushServer {
+    /// Bind `127.0.0.1:port` (0 picks `DEFAULT_STATE_PUSH_PORT`) and start serving.
+    pub fn start(port: u16) -> anyhow::Result<Self> {
+        let port = if port == 0 {
+            DEFAULT_STATE_PUSH_PORT
+        } else {
+            port
+        };
+        Self::bind(SocketAddr::from(([127, 0, 0, 1], port)))
+    }
+
+    pub(crate) fn bind(addr: SocketAddr) -> anyhow::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(PushState::default()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let (sender, receiver) = mpsc::channel();
+        let accept = {
+            let state = state.clone();
+            let shutdown = shutdown.clone();
+            let sender = sender.clone();
+            std::thread::Builder::new()
+                .name("state-push-accept".to_string())
+                .spawn(move || accept_clients(listener, &state, &sender, &shutdown))?
+        };
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let broadcast = {
+            let received = received.clone();
+            std::thread::Builder::new()
+                .name("state-push-broadcast".to_string())
+                .spawn(move || broadcast(receiver, &received))?
+        };
+        log::info!("State push server listening on ws://{}", addr);
+        Ok(Self {
+            state,
+            sender: Some(sender),
+            received,
+            addr,
+            shutdown,
+            threads: vec![accept, broadcast],
+        })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Command lines received from clients since the last call.
+    pub fn take_received(&self) -> Vec<String> {
+        std::mem::take(&mut *lock_or_recover(&self.received))
+    }
+
+    /// Push an event for each part of the state that differs from the last update.
+    pub fn update(&self, screen: &str, song: Option<OverlaySong>, live: Option<OverlayLive>) {
+        let mut state = lock_or_recover(&self.state);
+        let events = state.diff(screen, &song, &live);
+        if events.is_empty() {
+            return;
+        }
+        // Sent under the lock so a client connecting now gets a hello that
+        // matches the events that follow it.
+        if let Some(ref sender) = self.sender {
+            for event in &events {
+                match serde_json::to_string(event) {
+                    Ok(json) => {
+                        let _ = sender.send(Outgoing::Event(json));
+                    }
+                    Err(e) => log::warn!("Failed to serialize state event: {}", e),
+                }
+            }
+        }
+        state.screen = screen.to_string();
+        state.song = song;
+        state.live = live;
+    }
+}
+
+impl Drop for StatePushServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Release);
+        // The broadcast thread exits once every sender is gone.
+        self.sender = None;
+        // Wake the blocking accept() so the thread sees the flag.
+        let _ = TcpStream::connect_timeout(&self.addr, Duration::from_millis(200));
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn accept_clients(
+    listener: TcpListener,
+    state: &Mutex<PushState>,
+    sender: &Sender<Outgoing>,
+    shutdown: &AtomicBool,
+) {
+    for stream in listener.incoming() {
+        if shutdown.load(Ordering::Acquire) {
+            break;
+        }
+        let Ok(stream) = stream else {
+            continue;
+        };
+        let _ = stream.set_read_timeout(Some(CLIENT_TIMEOUT));
+        let _ = stream.set_write_timeout(Some(CLIENT_TIMEOUT));
+        let socket = match tungstenite::accept(stream) {
+            Ok(socket) => socket,
+            Err(e) => {
+                log::debug!("State push handshake failed: {}", e);
+                continue;
+            }
+        };
+        let state = lock_or_recover(state);
+        let hello = StateEvent::Hello {
+            screen: state.screen.clone(),
+            song: state.song.clone(),
+            live: state.live.clone(),
+        };
+        match serde_json::to_string(&hello) {
+            Ok(json) => {
+                let _ = sender.send(Outgoing::Client(Box::new(socket), json));
+            }
+            Err(e) => log::warn!("Failed to serialize state event: {}", e),
+        }
+    }
+}
+
+/// Write each event to every client and collect the lines they send, dropping
+/// clients that fail to take an event or close the connection.
+fn broadcast(receiver: Receiver<Outgoing>, received: &Mutex<Vec<String>>) {
+    let mut clients: Vec<WebSocket<TcpStream>> = Vec::new();
+    loop {
+        match receiver.recv_timeout(READ_INTERVAL) {
+            Ok(Outgoing::Client(mut socket, hello)) => {
+                if socket.send(Message::Text(hello)).is_ok() {
+                    clients.push(*socket);
+                }
+            }
+            Ok(Outgoing::Event(json)) => {
+                clients.retain_mut(|socket| socket.send(Message::Text(json.clone())).is_ok());
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                clients.retain_mut(|socket| read_lines(socket, received));
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+    for mut socket in clients {
+        let _ = socket.close(None);
+        let _ = socket.flush();
+    }
+}
+
+/// Read the frames a client has already sent without blocking. Returns false
+/// once the client has closed or failed.
+fn read_lines(socket: &mut WebSocket<TcpStream>, received: &Mutex<Vec<String>>) -> bool {
+    if socket.get_mut().set_nonblocking(true).is_err() {
+        return false;
+    }
+    let alive = loop {
+        match socket.read() {
+            Ok(Message::Text(text)) => {
+                let mut received = lock_or_recover(received);
+                received.extend(
+                    text.lines()
+                        .map(str::trim)
+                        .filter(|line| !line.is_empty())
+                        .map(str::to_string),
+                );
+            }
+            Ok(Message::Close(_)) => break false,
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(e)) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                break true;
+            }
+            Err(_) => break false,
+        }
+    };
+    alive && socket.get_mut().set_nonblocking(false).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connect(server: &StatePushServer) -> WebSocket<TcpStream> {
+        let stream = TcpStream::connect(server.addr()).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let (socket, _) =
+            tungstenite::client::client(format!("ws://{}/", server.addr()), stream).unwrap();
+        socket
+    }
+
+    fn next_event(socket: &mut WebSocket<TcpStream>) -> serde_json::Value {
+        match socket.read().unwrap() {
+            Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    fn live(exscore: i32, gauge: f32) -> Option<OverlayLive> {
+        Some(OverlayLive {
+            exscore,
+            combo: exscore / 2,
+            gauge: Some(gauge),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn diff_reports_only_what_changed() {
+        let state = PushState {
+            screen: "play".to_string(),
+            song: None,
+            live: live(10, 50.0),
+        };
+        assert!(state.diff("play", &None, &live(10, 50.0)).is_empty());
+        assert_eq!(
+            state.diff("play", &None, &live(10, 52.0)),
+            [StateEvent::Gauge { value: Some(52.0) }]
+        );
+        assert_eq!(
+            state.diff("play", &None, &live(12, 52.0)),
+            [StateEvent::Score {
+                live: live(12, 52.0)
+            }]
+        );
+        let song = Some(OverlaySong {
+            title: "Song".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(
+            state.diff("result", &song, &None),
+            [
+                StateEvent::State {
+                    screen: "result".to_string()
+                },
+                StateEvent::Song { song: song.clone() },
+                StateEvent::Score { live: None },
+            ]
+        );
+    }
+
+    #[test]
+    fn pushes_hello_then_changes_to_each_client() {
+        let server = StatePushServer::bind(SocketAddr::from(([127, 0, 0, 1], 0))).unwrap();
+        let song = Some(OverlaySong {
+            title: "Song".to_string(),
+            mode: 7,
+            ..Default::default()
+        });
+        server.update("decide", song.clone(), None);
+
+        let mut client = connect(&server);
+        let hello = next_event(&mut client);
+        assert_eq!(hello["event"], "hello");
+        assert_eq!(hello["screen"], "decide");
+        assert_eq!(hello["song"]["title"], "Song");
+
+        server.update("play", song.clone(), live(100, 80.0));
+        let state = next_event(&mut client);
+        assert_eq!(state["event"], "state");
+        assert_eq!(state["screen"], "play");
+        let score = next_event(&mut client);
+        assert_eq!(score["event"], "score");
+        assert_eq!(score["live"]["exscore"], 100);
+        assert_eq!(score["live"]["combo"], 50);
+
+        // Unchanged state pushes nothing; the next event is the gauge change
+        server.update("play", song.clone(), live(100, 80.0));
+        server.update("play", song, live(100, 78.0));
+        let gauge = next_event(&mut client);
+        assert_eq!(gauge["event"], "gauge");
+        assert_eq!(gauge["value"], 78.0);
+
+        let mut late = connect(&server);
+        let hello = next_event(&mut late);
+        assert_eq!(hello["screen"], "play");
+        assert_eq!(hello["live"]["gauge"], 78.0);
+    }
+
+    #[test]
+    fn collects_command_lines_from_clients() {
+        let server = StatePushServer::bind(SocketAddr::from(([127, 0, 0, 1], 0))).unwrap();
+        let mut client = connect(&server);
+        next_event(&mut client);
+        client
+            .send(Message::Text("!!req abc\n\n!!req def".to_string()))
+            .unwrap();
+
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        let mut lines = Vec::new();
+        while lines.len() < 2 && std::time::Instant::now() < deadline {
+            lines.extend(server.take_received());
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(lines, ["!!req abc", "!!req def"]);
+        assert!(server.take_received().is_empty());
+    }
+
+    #[test]
+    fn drop_stops_the_server() {
+        let server = StatePushServer::bind(SocketAddr::from(([127, 0, 0, 1], 0))).unwrap();
+        let addr = server.addr();
+        let mut client = connect(&server);
+        next_event(&mut client);
+        drop(server);
+        assert!(TcpListener::bind(addr).is_ok(), "port should be released");
+    }
+}
@@ -1,4 +1,5 @@
-use std::io::{BufRead, BufReader};
+#[cfg(not(unix))]
+use std::io::BufReader;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -7,8 +8,12 @@ use crate::skin::sync_utils::lock_or_recover;
 
 use crate::select::music_selector::MusicSelector;
 
+use super::remote_control_command::RemoteControlCommand;
 use super::stream_command::StreamCommand;
+use super::stream_protocol;
 use super::stream_request_command::StreamRequestCommand;
+#[cfg(unix)]
+use super::unix_socket::{self, StreamSocket, StreamSocketCloser};
 
 type SharedCommands = Arc<Mutex<Vec<Box<dyn StreamCommand>>>>;
 
@@ -16,16 +21,27 @@ type SharedCommands = Arc<Mutex<Vec<Box<dyn StreamCommand>>>>;
 #[cfg(windows)]
 const PIPE_PATH: &str = r"\\.\pipe\beatoraja";
 
+/// Channel stream commands arrive on.
+pub enum StreamTransport {
+    /// Windows named pipe `\\.\pipe\beatoraja`, opened as a client.
+    #[cfg(not(unix))]
+    Pipe(BufReader<std::fs::File>),
+    /// Unix domain socket that streaming tools connect to.
+    #[cfg(unix)]
+    Socket(StreamSocket),
+}
+
 /// Stream controller for processing strings received via beatoraja pipe
 /// Translates: bms.player.beatoraja.stream.StreamController
 ///
-/// Java reads from Windows named pipe `\\.\pipe\beatoraja`.
-/// On non-Windows platforms, pipe support is unavailable.
+/// Java reads from Windows named pipe `\\.\pipe\beatoraja`. On Linux and macOS
+/// the same line protocol is served on a Unix domain socket instead (see
+/// [`unix_socket::socket_path`]).
 ///
 /// Implements `StreamControllerAccess` for cross-crate usage via MainController.
 pub struct StreamController {
     pub commands: Vec<Box<dyn StreamCommand>>,
-    pub pipe_buffer: Option<BufReader<std::fs::File>>,
+    pub transport: Option<StreamTransport>,
     pub polling: Option<thread::JoinHandle<()>>,
     pub is_active: bool,
     pub selector: Arc<Mutex<MusicSelector>>,
@@ -33,14 +49,20 @@ pub struct StreamController {
     shared_commands: Option<SharedCommands>,
     /// Shutdown flag: set to true by dispose() to signal reader thread exit.
     shutdown: Arc<AtomicBool>,
+    /// Wakes the socket reader thread so it sees the shutdown flag.
+    #[cfg(unix)]
+    socket_closer: Option<StreamSocketCloser>,
 }
 
 impl StreamController {
     pub fn new(selector: Arc<Mutex<MusicSelector>>) -> Self {
-        let mut commands: Vec<Box<dyn StreamCommand>> =
-            vec![Box::new(StreamRequestCommand::new(Arc::clone(&selector)))];
+        let mut commands: Vec<Box<dyn StreamCommand>> = vec![
+            Box::new(StreamRequestCommand::new(Arc::clone(&selector))),
+            Box::new(RemoteControlCommand::new(Arc::clone(&selector))),
+        ];
 
-        let (pipe_buffer, is_active) = Self::open_pipe();
+        let transport = Self::open_transport();
+        let is_active = transport.is_some();
 
         if !is_active {
             for cmd in commands.iter_mut() {
@@ -50,15 +72,28 @@ impl StreamController {
 
         Self {
             commands,
-            pipe_buffer,
+            transport,
             polling: None,
             is_active,
             selector,
             shared_commands: None,
             shutdown: Arc::new(AtomicBool::new(false)),
+            #[cfg(unix)]
+            socket_closer: None,
         }
     }
 
+    #[cfg(not(unix))]
+    fn open_transport() -> Option<StreamTransport> {
+        let (pipe_buffer, _) = Self::open_pipe();
+        pipe_buffer.map(StreamTransport::Pipe)
+    }
+
+    #[cfg(unix)]
+    fn open_transport() -> Option<StreamTransport> {
+        Self::open_socket(&unix_socket::socket_path()).map(StreamTransport::Socket)
+    }
+
     /// Opens the Windows named pipe.
     /// Returns (Some(reader), true) on success, (None, false) on failure or non-Windows.
     #[cfg(windows)]
@@ -81,16 +116,35 @@ impl StreamController {
         }
     }
 
-    /// On non-Windows platforms, named pipe is not available.
-    #[cfg(not(windows))]
+    /// Named pipes are only available on Windows.
+    #[cfg(not(any(windows, unix)))]
     fn open_pipe() -> (Option<BufReader<std::fs::File>>, bool) {
         log::info!("Named pipe not available on this platform");
         (None, false)
     }
 
+    /// Listens on the Unix domain socket at `path`.
+    #[cfg(unix)]
+    fn open_socket(path: &std::path::Path) -> Option<StreamSocket> {
+        match StreamSocket::bind(path) {
+            Ok(socket) => {
+                log::info!("Stream socket listening: {}", path.display());
+                Some(socket)
+            }
+            Err(e) => {
+                log::error!(
+                    "Failed to listen on stream socket {}: {}",
+                    path.display(),
+                    e
+                );
+                None
+            }
+        }
+    }
+
     pub fn run(&mut self) {
         // Combine check and extraction to avoid TOCTOU race
-        let Some(pipe_buffer) = self.pipe_buffer.take() else {
+        let Some(transport) = self.transport.take() else {
             return;
         };
         let commands: Vec<Box<dyn StreamCommand>> = std::mem::take(&mut self.commands);
@@ -100,27 +154,28 @@ impl StreamController {
 
         // Keep a reference so dispose() can access commands
         self.shared_commands = Some(Arc::clone(&commands));
+        #[cfg(unix)]
+        {
+            // The socket is the only transport on Unix
+            let StreamTransport::Socket(socket) = &transport;
+            self.socket_closer = Some(socket.closer());
+        }
 
         // In Java: busy-wait until pipeBuffer.ready()
         // We skip this in Rust -- readLine() will block anyway
 
         let handle = thread::spawn(move || {
-            let reader = pipe_buffer;
-            for line_result in reader.lines() {
-                if shutdown.load(Ordering::SeqCst) {
-                    break;
-                }
-                match line_result {
-                    Ok(line) => {
-                        log::info!("Received: {}", line);
-                        let mut cmds = lock_or_recover(&commands_clone);
-                        Self::execute_commands(&mut cmds, &line);
-                    }
-                    Err(e) => {
+            match transport {
+                #[cfg(not(unix))]
+                StreamTransport::Pipe(reader) => {
+                    if let Err(e) =
+                        stream_protocol::read_commands(reader, &commands_clone, &shutdown)
+                    {
                         log::error!("{}", e);
-                        break;
                     }
                 }
+                #[cfg(unix)]
+                StreamTransport::Socket(socket) => socket.serve(&commands_clone, &shutdown),
             }
             // Thread exiting: dispose all commands
             let mut cmds = lock_or_recover(&commands_clone);
@@ -137,6 +192,10 @@ impl StreamController {
     pub fn dispose(&mut self) {
         // Signal the reader thread to stop
         self.shutdown.store(true, Ordering::SeqCst);
+        #[cfg(unix)]
+        if let Some(closer) = self.socket_closer.take() {
+            closer.close();
+        }
 
         // Dispose commands owned by the reader thread
         if let Some(ref shared) = self.shared_commands {
@@ -154,8 +213,8 @@ impl StreamController {
             // blocking indefinitely on a pipe read.
             drop(handle);
         }
-        // pipe_buffer is already moved or None
-        self.pipe_buffer = None;
+        // transport is already moved or None
+        self.transport = None;
 
         // Dispose any commands still owned by self (before run() was called)
         for cmd in self.commands.iter_mut() {
@@ -163,15 +222,6 @@ impl StreamController {
         }
         log::info!("Pipe resource disposal complete");
     }
-
-    fn execute_commands(commands: &mut [Box<dyn StreamCommand>], line: &str) {
-        for cmd in commands.iter_mut() {
-            let cmd_str = format!("{} ", cmd.command_string());
-            if let Some(data) = line.strip_prefix(&cmd_str) {
-                cmd.run(data);
-            }
-        }
-    }
 }
 
 impl crate::stream_controller_access::StreamControllerAccess for StreamController {
@@ -182,11 +232,19 @@ impl crate::stream_controller_access::StreamControllerAccess for StreamControlle
     fn dispose(&mut self) {
         StreamController::dispose(self);
     }
+
+    fn dispatch_line(&mut self, line: &str) {
+        match self.shared_commands {
+            Some(ref shared) => stream_protocol::dispatch_line(&mut lock_or_recover(shared), line),
+            None => stream_protocol::dispatch_line(&mut self.commands, line),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::stream::stream_protocol;
     use std::sync::Mutex as StdMutex;
 
     /// Mock StreamCommand that records all calls for verification.
@@ -233,7 +291,7 @@ mod tests {
 
         let sha256 = "a".repeat(64);
         let line = format!("!!req {}", sha256);
-        StreamController::execute_commands(&mut commands, &line);
+        stream_protocol::dispatch_line(&mut commands, &line);
 
         let recorded = calls.lock().unwrap();
         assert_eq!(recorded.len(), 1);
@@ -246,7 +304,7 @@ mod tests {
         let mut commands: Vec<Box<dyn StreamCommand>> = vec![Box::new(cmd)];
 
         // Line that doesn't start with "!!req " -- run() should not be called
-        StreamController::execute_commands(&mut commands, "hello world");
+        stream_protocol::dispatch_line(&mut commands, "hello world");
 
         let recorded = calls.lock().unwrap();
         assert_eq!(recorded.len(), 0);
@@ -257,7 +315,7 @@ mod tests {
         let (cmd, calls, _disposed) = MockCommand::new("!!req");
         let mut commands: Vec<Box<dyn StreamCommand>> = vec![Box::new(cmd)];
 
-        StreamController::execute_commands(&mut commands, "");
+        stream_protocol::dispatch_line(&mut commands, "");
 
         let recorded = calls.lock().unwrap();
         assert_eq!(recorded.len(), 0);
@@ -269,7 +327,7 @@ mod tests {
         let (cmd2, calls2, _disposed2) = MockCommand::new("!!play");
         let mut commands: Vec<Box<dyn StreamCommand>> = vec![Box::new(cmd1), Box::new(cmd2)];
 
-        StreamController::execute_commands(&mut commands, "!!play some_data");
+        stream_protocol::dispatch_line(&mut commands, "!!play some_data");
 
         // !!req should not be called (no match)
         let recorded1 = calls1.lock().unwrap();
@@ -285,16 +343,19 @@ mod tests {
     fn execute_commands_empty_commands_slice() {
         let mut commands: Vec<Box<dyn StreamCommand>> = vec![];
         // Should not panic with empty commands
-        StreamController::execute_commands(&mut commands, "!!req some_data");
+        stream_protocol::dispatch_line(&mut commands, "!!req some_data");
     }
 
+    #[cfg(unix)]
     #[test]
-    fn run_returns_immediately_when_pipe_buffer_is_none() {
-        // Verify that run() does not panic when pipe_buffer is None.
-        // We can't easily construct a full StreamController without MusicSelector,
-        // but we can test open_pipe on non-Windows returns (None, false).
-        let (pipe_buffer, is_active) = StreamController::open_pipe();
-        assert!(pipe_buffer.is_none());
-        assert!(!is_active);
+    fn open_socket_listens_at_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("beatoraja.sock");
+        let socket = StreamController::open_socket(&path).expect("socket");
+        assert!(std::os::unix::net::UnixStream::connect(&path).is_ok());
+        drop(socket);
+        assert!(
+            StreamController::open_socket(&dir.path().join("missing/beatoraja.sock")).is_none()
+        );
     }
 }
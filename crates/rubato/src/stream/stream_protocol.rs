@@ -0,0 +1,84 @@
+use std::io::BufRead;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::skin::sync_utils::lock_or_recover;
+
+use super::stream_command::StreamCommand;
+
+/// Line protocol shared by every stream transport: each line is
+/// `<command string> <data>`, e.g. `!!req <sha256>`.
+///
+/// Hand `line` to every command whose command string it starts with.
+pub fn dispatch_line(commands: &mut [Box<dyn StreamCommand>], line: &str) {
+    // Clients on Windows may end lines with "\r\n"
+    let line = line.trim_end_matches('\r');
+    for cmd in commands.iter_mut() {
+        let cmd_str = format!("{} ", cmd.command_string());
+        if let Some(data) = line.strip_prefix(&cmd_str) {
+            cmd.run(data);
+        }
+    }
+}
+
+/// Dispatch lines from `reader` until it reaches EOF or `shutdown` is set.
+pub fn read_commands<R: BufRead>(
+    reader: R,
+    commands: &Mutex<Vec<Box<dyn StreamCommand>>>,
+    shutdown: &AtomicBool,
+) -> std::io::Result<()> {
+    for line in reader.lines() {
+        if shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+        let line = line?;
+        log::info!("Received: {}", line);
+        let mut cmds = lock_or_recover(commands);
+        dispatch_line(&mut cmds, &line);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    struct Recorder {
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl StreamCommand for Recorder {
+        fn command_string(&self) -> &str {
+            "!!req"
+        }
+
+        fn run(&mut self, data: &str) {
+            self.calls.lock().unwrap().push(data.to_string());
+        }
+
+        fn dispose(&mut self) {}
+    }
+
+    #[test]
+    fn read_commands_dispatches_each_line() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let commands: Mutex<Vec<Box<dyn StreamCommand>>> = Mutex::new(vec![Box::new(Recorder {
+            calls: Arc::clone(&calls),
+        })]);
+        let input = Cursor::new("!!req a\r\nhello\n!!req b\n");
+        read_commands(input, &commands, &AtomicBool::new(false)).unwrap();
+        assert_eq!(*calls.lock().unwrap(), ["a", "b"]);
+    }
+
+    #[test]
+    fn read_commands_stops_on_shutdown() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let commands: Mutex<Vec<Box<dyn StreamCommand>>> = Mutex::new(vec![Box::new(Recorder {
+            calls: Arc::clone(&calls),
+        })]);
+        read_commands(Cursor::new("!!req a\n"), &commands, &AtomicBool::new(true)).unwrap();
+        assert!(calls.lock().unwrap().is_empty());
+    }
+}
@@ -3,14 +3,12 @@ use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
 
-use crate::select::SongData;
-use crate::select::bar::bar::Bar;
-use crate::select::bar::hash_bar::HashBar;
 use crate::select::music_selector::MusicSelector;
 
 use super::ImGuiNotify;
 use super::stream_command::StreamCommand;
 use crate::skin::sync_utils::lock_or_recover;
+use crate::tr;
 
 /// Request command processing
 /// Translates: bms.player.beatoraja.stream.command.StreamRequestCommand
@@ -66,8 +64,10 @@ impl StreamCommand for StreamRequestCommand {
 
 /// UpdateBar inner class translated as a struct
 /// Translates: bms.player.beatoraja.stream.command.StreamRequestCommand.UpdateBar
+///
+/// The accepted requests live in the selector's `stream_requests` queue so the
+/// select screen can skip and accept them.
 pub struct UpdateBar {
-    pub song_datas: Vec<SongData>,
     /// sha256 stack
     pub stack: Vec<String>,
     pub selector: Arc<Mutex<MusicSelector>>,
@@ -76,7 +76,6 @@ pub struct UpdateBar {
 impl UpdateBar {
     pub fn new(selector: Arc<Mutex<MusicSelector>>) -> Self {
         Self {
-            song_datas: Vec::new(),
             stack: Vec::new(),
             selector,
         }
@@ -86,15 +85,11 @@ impl UpdateBar {
         let selector = lock_or_recover(&self.selector);
         let escaped = Self::escape(sha256);
         let song_datas_result = selector.songdb.song_datas_by_hashes(&[escaped]);
+        let queued = selector.stream_requests.contains(sha256);
         drop(selector);
         if !song_datas_result.is_empty() {
             let data = &song_datas_result[0];
-            if self
-                .song_datas
-                .iter()
-                .filter(|song| song.file.sha256 == sha256)
-                .count()
-                > 0
+            if queued
                 || self
                     .stack
                     .iter()
@@ -103,19 +98,18 @@ impl UpdateBar {
                     > 1
             {
                 // Already added, skip
-                ImGuiNotify::warning(&format!(
-                    "{} has already been added",
-                    data.metadata.full_title()
+                ImGuiNotify::warning(&tr!(
+                    "notify-request-duplicate",
+                    title = data.metadata.full_title().to_string()
                 ));
                 return;
             }
-            self.song_datas.push(data.clone());
-            ImGuiNotify::info(&format!(
-                "Added {} to stream request list",
-                data.metadata.full_title()
+            ImGuiNotify::info(&tr!(
+                "notify-request-added",
+                title = data.metadata.full_title().to_string()
             ));
         } else {
-            ImGuiNotify::warning("Doesn't have requested song in collection");
+            ImGuiNotify::warning(&tr!("notify-request-song-missing"));
         }
     }
 
@@ -132,40 +126,24 @@ impl UpdateBar {
         }
 
         // Process accumulated stack items
+        let mut selector = lock_or_recover(&self.selector);
         while let Some(sha256) = self.stack.pop() {
-            if self
-                .song_datas
-                .iter()
-                .filter(|song| song.file.sha256 == sha256)
-                .count()
-                > 0
-            {
+            if selector.stream_requests.contains(&sha256) {
                 // Already added, skip
                 continue;
             }
-            let selector = lock_or_recover(&self.selector);
             let escaped = Self::escape(&sha256);
             let song_datas_result = selector.songdb.song_datas_by_hashes(&[escaped]);
             if !song_datas_result.is_empty() {
-                self.song_datas.push(song_datas_result[0].clone());
-            }
-            let max_length = selector.config.max_request_count;
-            drop(selector);
-            if self.song_datas.len() as i32 > max_length {
-                self.song_datas.remove(0);
+                let max_length = selector.config.max_request_count;
+                selector
+                    .stream_requests
+                    .push(song_datas_result[0].clone(), max_length);
             }
         }
 
-        if !self.song_datas.is_empty() {
-            let mut selector = lock_or_recover(&self.selector);
-            let bar = Bar::Hash(Box::new(HashBar::new(
-                "Stream Request".to_string(),
-                self.song_datas.clone(),
-            )));
-            selector
-                .manager
-                .set_append_directory_bar("Stream Request".to_string(), bar);
-            let _ = selector.update_bar_with_songdb_context(None);
+        if !selector.stream_requests.is_empty() {
+            selector.refresh_stream_requests();
         }
     }
 
@@ -288,7 +266,7 @@ mod tests {
         updater.stack.push("cccc".to_string());
         updater.update();
         assert_eq!(
-            updater.song_datas.len(),
+            lock_or_recover(&selector).stream_requests.len(),
             2,
             "should trim to max_request_count=2"
         );
@@ -301,7 +279,7 @@ mod tests {
         updater.stack.push("eeee".to_string());
         updater.update();
         assert_eq!(
-            updater.song_datas.len(),
+            lock_or_recover(&selector).stream_requests.len(),
             4,
             "should respect updated max_request_count=5 from live config"
         );
@@ -375,10 +353,10 @@ mod tests {
     fn command_string_is_req() {
         // Verify the command string constant used by StreamRequestCommand.
         // We can't construct StreamRequestCommand without MusicSelector,
-        // so we verify the expected value directly used in execute_commands
+        // so we verify the expected value directly used in stream_protocol::dispatch_line
         // dispatch logic.
         assert_eq!("!!req", "!!req");
-        // Also verify that the format used in execute_commands includes
+        // Also verify that the format used in stream_protocol::dispatch_line includes
         // a trailing space for proper splitting
         let cmd_str = format!("{} ", "!!req");
         assert_eq!(cmd_str, "!!req ");
@@ -462,7 +440,7 @@ mod tests {
         // update() should early-return without processing the stack
         updater.update();
         assert!(
-            updater.song_datas.is_empty(),
+            lock_or_recover(&selector).stream_requests.is_empty(),
             "should not process stack when selector is inactive"
         );
         assert_eq!(
@@ -477,7 +455,7 @@ mod tests {
         // Now update() should process the stack
         updater.update();
         assert_eq!(
-            updater.song_datas.len(),
+            lock_or_recover(&selector).stream_requests.len(),
             1,
             "should process stack when selector becomes active"
         );
@@ -0,0 +1,202 @@
+use std::io::{BufReader, ErrorKind};
+use std::net::Shutdown;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::skin::sync_utils::lock_or_recover;
+
+use super::stream_command::StreamCommand;
+use super::stream_protocol;
+
+/// Environment variable that overrides the socket path.
+pub const SOCKET_PATH_ENV: &str = "BEATORAJA_STREAM_SOCKET";
+
+/// Socket file name, the Unix counterpart of `\\.\pipe\beatoraja`.
+const SOCKET_FILE_NAME: &str = "beatoraja.sock";
+
+/// Where streaming tools connect: `$BEATORAJA_STREAM_SOCKET`, else
+/// `beatoraja.sock` in `$XDG_RUNTIME_DIR`, else in the temp directory.
+pub fn socket_path() -> PathBuf {
+    if let Some(path) = std::env::var_os(SOCKET_PATH_ENV).filter(|p| !p.is_empty()) {
+        return PathBuf::from(path);
+    }
+    std::env::var_os("XDG_RUNTIME_DIR")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(std::env::temp_dir)
+        .join(SOCKET_FILE_NAME)
+}
+
+/// Unix domain socket that streaming tools connect to and write stream
+/// commands into, one client at a time.
+pub struct StreamSocket {
+    listener: UnixListener,
+    path: PathBuf,
+    client: Arc<Mutex<Option<UnixStream>>>,
+}
+
+/// Stops a [`StreamSocket`] served on another thread.
+#[derive(Clone)]
+pub struct StreamSocketCloser {
+    path: PathBuf,
+    client: Arc<Mutex<Option<UnixStream>>>,
+}
+
+impl StreamSocket {
+    /// Listen at `path`. A socket file left behind by a crashed instance is
+    /// replaced; one another instance is still listening on is not.
+    pub fn bind(path: &Path) -> std::io::Result<Self> {
+        if path.exists() {
+            if UnixStream::connect(path).is_ok() {
+                return Err(std::io::Error::new(
+                    ErrorKind::AddrInUse,
+                    format!("{} is in use by another instance", path.display()),
+                ));
+            }
+            std::fs::remove_file(path)?;
+        }
+        let listener = UnixListener::bind(path)?;
+        Ok(Self {
+            listener,
+            path: path.to_path_buf(),
+            client: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn closer(&self) -> StreamSocketCloser {
+        StreamSocketCloser {
+            path: self.path.clone(),
+            client: Arc::clone(&self.client),
+        }
+    }
+
+    /// Accept clients and dispatch their lines until `shutdown` is set.
+    pub fn serve(&self, commands: &Mutex<Vec<Box<dyn StreamCommand>>>, shutdown: &AtomicBool) {
+        for stream in self.listener.incoming() {
+            if shutdown.load(Ordering::SeqCst) {
+                break;
+            }
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    log::error!("Stream socket accept failed: {}", e);
+                    continue;
+                }
+            };
+            log::info!("Stream client connected: {}", self.path.display());
+            *lock_or_recover(&self.client) = stream.try_clone().ok();
+            if let Err(e) =
+                stream_protocol::read_commands(BufReader::new(stream), commands, shutdown)
+            {
+                log::warn!("Stream client disconnected: {}", e);
+            }
+            *lock_or_recover(&self.client) = None;
+        }
+    }
+}
+
+impl Drop for StreamSocket {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+impl StreamSocketCloser {
+    /// Disconnect the current client and wake the accept loop so
+    /// [`StreamSocket::serve`] can see the shutdown flag.
+    pub fn close(&self) {
+        if let Some(client) = lock_or_recover(&self.client).take() {
+            let _ = client.shutdown(Shutdown::Both);
+        }
+        let _ = UnixStream::connect(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    struct Recorder {
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl StreamCommand for Recorder {
+        fn command_string(&self) -> &str {
+            "!!req"
+        }
+
+        fn run(&mut self, data: &str) {
+            self.calls.lock().unwrap().push(data.to_string());
+        }
+
+        fn dispose(&mut self) {}
+    }
+
+    fn wait_for_calls(calls: &Mutex<Vec<String>>, count: usize) {
+        let start = Instant::now();
+        while calls.lock().unwrap().len() < count {
+            assert!(start.elapsed() < Duration::from_secs(5), "timed out");
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn serves_commands_from_each_client_until_closed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SOCKET_FILE_NAME);
+        let socket = StreamSocket::bind(&path).unwrap();
+        let closer = socket.closer();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let commands: Arc<Mutex<Vec<Box<dyn StreamCommand>>>> =
+            Arc::new(Mutex::new(vec![Box::new(Recorder {
+                calls: Arc::clone(&calls),
+            })]));
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let server = {
+            let commands = Arc::clone(&commands);
+            let shutdown = Arc::clone(&shutdown);
+            thread::spawn(move || socket.serve(&commands, &shutdown))
+        };
+
+        let mut first = UnixStream::connect(&path).unwrap();
+        first.write_all(b"!!req one\n").unwrap();
+        drop(first);
+        wait_for_calls(&calls, 1);
+
+        // Held open so close() has to disconnect it
+        let mut second = UnixStream::connect(&path).unwrap();
+        second.write_all(b"!!req two\n").unwrap();
+        wait_for_calls(&calls, 2);
+
+        shutdown.store(true, Ordering::SeqCst);
+        closer.close();
+        server.join().unwrap();
+        assert_eq!(*calls.lock().unwrap(), ["one", "two"]);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn bind_replaces_stale_socket_but_not_live_one() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SOCKET_FILE_NAME);
+        // A socket file nobody listens on, as left by a crash
+        drop(UnixListener::bind(&path).unwrap());
+        assert!(path.exists());
+
+        let socket = StreamSocket::bind(&path).unwrap();
+        let err = StreamSocket::bind(&path).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::AddrInUse);
+        assert!(path.exists());
+        drop(socket);
+        assert!(!path.exists());
+    }
+}
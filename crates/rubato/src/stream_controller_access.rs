@@ -1,14 +1,18 @@
 /// Trait for stream controller access across crate boundaries.
 ///
 /// The concrete implementation lives in beatoraja-stream where the Windows
-/// named pipe listener is implemented. MainController holds this as a trait
+/// named pipe and Unix domain socket listeners are implemented. MainController holds this as a trait
 /// object to avoid depending on beatoraja-stream.
 ///
 /// Translated from: bms.player.beatoraja.stream.StreamController
 pub trait StreamControllerAccess: Send {
-    /// Start the pipe polling thread.
+    /// Start the pipe / socket polling thread.
     fn run(&mut self);
 
     /// Dispose of resources and stop the polling thread.
     fn dispose(&mut self);
+
+    /// Handle a command line received from another channel, such as a
+    /// WebSocket overlay client, as if it had arrived on the pipe.
+    fn dispatch_line(&mut self, line: &str);
 }
@@ -1,6 +1,7 @@
 // VersionChecker: queries GitHub API for the latest release.
 
 use std::io::Write;
+use std::path::{Path, PathBuf};
 
 pub use crate::core::version::Version;
 use anyhow::{Result, bail};
@@ -58,6 +59,119 @@ fn read_response_bytes_limited(
     }
 }
 
+/// GitHub API endpoint for the latest release.
+const LATEST_RELEASE_URL: &str =
+    "https://api.github.com/repos/seraxis/lr2oraja-endlessdream/releases/latest";
+
+/// Largest release build accepted by [`download_asset`].
+const MAX_ASSET_BYTES: u64 = 1024 * 1024 * 1024; // 1 GB
+
+/// Folder, relative to the data directory, that new builds are downloaded into.
+pub const UPDATE_DOWNLOAD_DIR: &str = "updates";
+
+/// Latest published release.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReleaseInfo {
+    pub name: String,
+    pub html_url: String,
+    /// Release notes as written on the release page (Markdown).
+    pub changelog: String,
+    /// Build for this platform, if the release has one.
+    pub asset: Option<ReleaseAsset>,
+}
+
+/// Downloadable file attached to a release.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub url: String,
+    pub size: u64,
+}
+
+impl ReleaseInfo {
+    /// Parse a GitHub `releases/latest` response, picking the asset for `os`
+    /// (`std::env::consts::OS`).
+    pub fn from_json(value: &serde_json::Value, os: &str) -> Self {
+        let assets: Vec<ReleaseAsset> = value["assets"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|asset| {
+                Some(ReleaseAsset {
+                    name: asset["name"].as_str()?.to_string(),
+                    url: asset["browser_download_url"].as_str()?.to_string(),
+                    size: asset["size"].as_u64().unwrap_or(0),
+                })
+            })
+            .collect();
+        Self {
+            name: value["name"].as_str().unwrap_or("").to_string(),
+            html_url: value["html_url"].as_str().unwrap_or("").to_string(),
+            changelog: value["body"].as_str().unwrap_or("").to_string(),
+            asset: platform_asset(&assets, os).cloned(),
+        }
+    }
+
+    /// The release is newer than the running build.
+    pub fn is_newer(&self) -> bool {
+        Version::compare_to_string(Some(&self.name)) == -1
+    }
+}
+
+/// The archive among `assets` built for `os`, matched by name.
+fn platform_asset<'a>(assets: &'a [ReleaseAsset], os: &str) -> Option<&'a ReleaseAsset> {
+    let keywords: &[&str] = match os {
+        "windows" => &["windows", "win64", "win"],
+        "macos" => &["macos", "darwin", "osx", "mac"],
+        "linux" => &["linux"],
+        _ => return None,
+    };
+    const ARCHIVES: [&str; 5] = [".zip", ".tar.gz", ".7z", ".dmg", ".appimage"];
+    keywords.iter().find_map(|keyword| {
+        assets.iter().find(|asset| {
+            let name = asset.name.to_lowercase();
+            name.contains(keyword) && ARCHIVES.iter().any(|ext| name.ends_with(ext))
+        })
+    })
+}
+
+fn http_client(timeout: std::time::Duration) -> Result<reqwest::blocking::Client> {
+    Ok(reqwest::blocking::Client::builder()
+        .user_agent("rubato")
+        .timeout(timeout)
+        .build()?)
+}
+
+/// Query the latest release. Blocking: call from a background thread.
+pub fn fetch_latest_release() -> Result<ReleaseInfo> {
+    const MAX_RESPONSE_BYTES: u64 = 4 * 1024 * 1024; // 4 MB
+
+    let client = http_client(std::time::Duration::from_secs(10))?;
+    let response = client.get(LATEST_RELEASE_URL).send()?.error_for_status()?;
+    let bytes = read_response_bytes_limited(response, MAX_RESPONSE_BYTES)?;
+    let value: serde_json::Value = serde_json::from_slice(&bytes)?;
+    Ok(ReleaseInfo::from_json(&value, std::env::consts::OS))
+}
+
+/// Download `asset` into `dir` and return the file written. Blocking: call
+/// from a background thread. The file only appears once complete.
+pub fn download_asset(asset: &ReleaseAsset, dir: &Path) -> Result<PathBuf> {
+    // The name comes from the server; keep only its last component.
+    let Some(file_name) = Path::new(&asset.name).file_name() else {
+        bail!("Invalid asset name: {}", asset.name);
+    };
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(file_name);
+    let partial = path.with_extension("part");
+
+    let client = http_client(std::time::Duration::from_secs(600))?;
+    let response = client.get(&asset.url).send()?.error_for_status()?;
+    let bytes = read_response_bytes_limited(response, MAX_ASSET_BYTES)?;
+    std::fs::write(&partial, &bytes)?;
+    std::fs::rename(&partial, &path)?;
+    Ok(path)
+}
+
 /// Version checker that queries GitHub API for the latest release.
 ///
 /// Translated from: MainLoader.GithubVersionChecker
@@ -88,15 +202,17 @@ impl VersionChecker {
     }
 
     fn information(&mut self) {
-        let result = self.fetch_latest_release();
-        match result {
-            Ok((name, html_url)) => {
-                let cmp = Version::compare_to_string(Some(&name));
+        match fetch_latest_release() {
+            Ok(release) => {
+                let cmp = Version::compare_to_string(Some(&release.name));
                 if cmp == 0 {
                     self.message = Some("Already on the latest version".to_string());
                 } else if cmp == -1 {
-                    self.message = Some(format!("Version [{}] is available to download", name));
-                    self.download_url = Some(html_url);
+                    self.message = Some(format!(
+                        "Version [{}] is available to download",
+                        release.name
+                    ));
+                    self.download_url = Some(release.html_url);
                 } else {
                     self.message = Some(format!(
                         "On Development Build for {}",
@@ -110,21 +226,46 @@ impl VersionChecker {
             }
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release_json() -> serde_json::Value {
+        serde_json::json!({
+            "name": "v9.0.0",
+            "html_url": "https://github.com/example/releases/tag/v9.0.0",
+            "body": "- Faster loading",
+            "assets": [
+                {"name": "rubato-v9.0.0-linux-x86_64.tar.gz", "browser_download_url": "https://example.com/linux.tar.gz", "size": 10},
+                {"name": "rubato-v9.0.0-windows.zip", "browser_download_url": "https://example.com/windows.zip", "size": 20},
+                {"name": "checksums.txt", "browser_download_url": "https://example.com/checksums.txt", "size": 1}
+            ]
+        })
+    }
+
+    #[test]
+    fn release_json_is_parsed_with_the_platform_build() {
+        let release = ReleaseInfo::from_json(&release_json(), "windows");
+        assert_eq!(release.name, "v9.0.0");
+        assert_eq!(release.changelog, "- Faster loading");
+        let asset = release.asset.unwrap();
+        assert_eq!(asset.name, "rubato-v9.0.0-windows.zip");
+        assert_eq!(asset.size, 20);
+
+        let linux = ReleaseInfo::from_json(&release_json(), "linux");
+        assert_eq!(linux.asset.unwrap().url, "https://example.com/linux.tar.gz");
+        assert!(
+            ReleaseInfo::from_json(&release_json(), "macos")
+                .asset
+                .is_none()
+        );
+    }
 
-    fn fetch_latest_release(&self) -> anyhow::Result<(String, String)> {
-        const MAX_RESPONSE_BYTES: u64 = 4 * 1024 * 1024; // 4 MB
-
-        let client = reqwest::blocking::Client::builder()
-            .user_agent("rubato")
-            .timeout(std::time::Duration::from_secs(10))
-            .build()?;
-        let response = client
-            .get("https://api.github.com/repos/seraxis/lr2oraja-endlessdream/releases/latest")
-            .send()?;
-        let bytes = read_response_bytes_limited(response, MAX_RESPONSE_BYTES)?;
-        let resp: serde_json::Value = serde_json::from_slice(&bytes)?;
-        let name = resp["name"].as_str().unwrap_or("").to_string();
-        let html_url = resp["html_url"].as_str().unwrap_or("").to_string();
-        Ok((name, html_url))
+    #[test]
+    fn missing_fields_leave_release_empty() {
+        let release = ReleaseInfo::from_json(&serde_json::json!({}), "linux");
+        assert_eq!(release, ReleaseInfo::default());
     }
 }
@@ -3,7 +3,9 @@
 use bms::model::mode::Mode;
 use egui;
 
-use crate::core::play_mode_config::{ANALOG_SCRATCH_VER_1, ANALOG_SCRATCH_VER_2, PlayModeConfig};
+use crate::core::play_mode_config::{
+    ANALOG_SCRATCH_VER_1, ANALOG_SCRATCH_VER_2, ANALOG_SCRATCH_VER_3, PlayModeConfig,
+};
 use crate::core::player_config::PlayerConfig;
 
 use crate::util::controller_config_view_model::ControllerConfigViewModel;
@@ -296,6 +298,8 @@ impl InputConfigurationView {
     pub fn analog_scratch_mode_to_string(mode: i32) -> &'static str {
         if mode == ANALOG_SCRATCH_VER_2 {
             "Ver. 2 (Newest)"
+        } else if mode == ANALOG_SCRATCH_VER_3 {
+            "Ver. 3 (Time based)"
         } else {
             "Ver. 1 (~0.6.9)"
         }
@@ -305,6 +309,8 @@ impl InputConfigurationView {
     pub fn analog_scratch_mode_from_string(s: &str) -> i32 {
         if s == "Ver. 2 (Newest)" {
             ANALOG_SCRATCH_VER_2
+        } else if s == "Ver. 3 (Time based)" {
+            ANALOG_SCRATCH_VER_3
         } else {
             ANALOG_SCRATCH_VER_1
         }
@@ -367,6 +373,7 @@ impl InputConfigurationView {
         let analog_mode_labels = [
             (ANALOG_SCRATCH_VER_2, "Ver. 2 (Newest)"),
             (ANALOG_SCRATCH_VER_1, "Ver. 1 (~0.6.9)"),
+            (ANALOG_SCRATCH_VER_3, "Ver. 3 (Time based)"),
         ];
 
         egui::Grid::new("input_controller_table")
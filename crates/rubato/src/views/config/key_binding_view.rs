@@ -0,0 +1,508 @@
+// Launcher key binding panel.
+//
+// Press-to-assign editor for the keyboard, controller and MIDI bindings of each
+// play mode, so initial setup does not require the in-game KeyConfiguration
+// screen. Assignments go through the same KeyConfiguration setters, so a lane
+// stays bound to a single device as in Java.
+
+use bms::model::mode::Mode;
+use egui;
+
+use crate::core::config_pkg::key_configuration::KeyConfiguration;
+use crate::core::player_config::PlayerConfig;
+use crate::input::bm_controller_input_processor::{
+    BMControllerCallback, BMControllerInputProcessor, BMKeys,
+};
+use crate::input::bms_player_input_device::BMSPlayerInputDevice;
+use crate::input::controller::gdx_controller::GdxController;
+use crate::input::controller::lwjgl3_controller_manager::Lwjgl3ControllerManager;
+use crate::input::keys::Keys;
+use crate::input::midi_input_processor::{MidiCallback, MidiInputProcessor};
+use crate::input::winit_input_bridge::{WinitKeyCode, winit_keycode_to_java};
+use crate::skin::play_mode_config::{ControllerConfig, MidiInput, PlayModeConfig};
+use crate::tr;
+
+const NO_ASSIGN_LABEL: &str = "---";
+
+/// Discards gameplay events; the panel only reads the last pressed input.
+struct NullCallback;
+
+impl BMControllerCallback for NullCallback {
+    fn key_changed_from_controller(&mut self, _: usize, _: i64, _: usize, _: bool) {}
+    fn start_changed(&mut self, _: bool) {}
+    fn set_select_pressed(&mut self, _: bool) {}
+    fn set_analog_state(&mut self, _: usize, _: bool, _: f32) {}
+}
+
+impl MidiCallback for NullCallback {
+    fn key_changed_from_midi(&mut self, _: i64, _: usize, _: bool) {}
+    fn start_changed(&mut self, _: bool) {}
+    fn set_select_pressed(&mut self, _: bool) {}
+    fn set_analog_state(&mut self, _: usize, _: bool, _: f32) {}
+}
+
+/// Key binding tab of the launcher.
+///
+/// Devices are opened lazily on first render, so gilrs and MIDI ports are only
+/// touched when the tab is shown.
+#[derive(Default)]
+pub struct KeyBindingView {
+    /// Key index waiting for input (-1: START, -2: SELECT).
+    capture: Option<i32>,
+    controller_manager: Option<Lwjgl3ControllerManager>,
+    controllers: Vec<BMControllerInputProcessor>,
+    midi: Option<MidiInputProcessor>,
+    /// Modifier state of the previous frame (egui reports modifiers without key events).
+    modifiers: egui::Modifiers,
+    status: String,
+}
+
+impl KeyBindingView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the key index currently waiting for input.
+    pub fn capture(&self) -> Option<i32> {
+        self.capture
+    }
+
+    /// Starts waiting for input for the given key index.
+    pub fn start_capture(&mut self, index: i32) {
+        self.capture = Some(index);
+        for controller in self.controllers.iter_mut() {
+            controller.clear();
+        }
+        if let Some(midi) = self.midi.as_mut() {
+            midi.clear_last_pressed_key();
+        }
+        self.status = tr!("keybind-press-input");
+    }
+
+    pub fn cancel_capture(&mut self) {
+        self.capture = None;
+        self.status.clear();
+    }
+
+    /// Display names of the keyboard, controller and MIDI bindings at `index`.
+    pub fn binding_labels(pmc: &PlayModeConfig, index: i32) -> [String; 3] {
+        let keycode = KeyConfiguration::keyboard_key_assign(&pmc.keyboard, index);
+        let keyboard = if keycode < 0 {
+            NO_ASSIGN_LABEL.to_string()
+        } else {
+            KeyConfiguration::keyboard_key_name(keycode).to_string()
+        };
+
+        let buttons: Vec<String> = (0..pmc.controller.len())
+            .filter_map(|device| {
+                let button =
+                    KeyConfiguration::controller_key_assign(&pmc.controller, device, index);
+                (button >= 0).then(|| {
+                    if pmc.controller.len() > 1 {
+                        format!("{}P {}", device + 1, BMKeys::to_string(button))
+                    } else {
+                        BMKeys::to_string(button).to_string()
+                    }
+                })
+            })
+            .collect();
+        let controller = if buttons.is_empty() {
+            NO_ASSIGN_LABEL.to_string()
+        } else {
+            buttons.join(", ")
+        };
+
+        let midi_input = match index {
+            -1 => pmc.midi.start,
+            -2 => pmc.midi.select,
+            i if i >= 0 => pmc.midi.keys.get(i as usize).copied().flatten(),
+            _ => None,
+        };
+        let midi = midi_input
+            .map(|input| input.to_string())
+            .unwrap_or_else(|| NO_ASSIGN_LABEL.to_string());
+
+        [keyboard, controller, midi]
+    }
+
+    /// Binds a keyboard key (libGDX key code) to `index`.
+    pub fn assign_keyboard(pmc: &mut PlayModeConfig, index: i32, keycode: i32) {
+        KeyConfiguration::set_keyboard_key_assign(pmc, index, keycode, false);
+    }
+
+    /// Binds a controller button to `index` on the config named after the device.
+    /// Returns false when every config already belongs to another device.
+    pub fn assign_controller(
+        pmc: &mut PlayModeConfig,
+        index: i32,
+        controller_name: &str,
+        button: i32,
+    ) -> bool {
+        KeyConfiguration::claim_controller_key_assign(pmc, index, controller_name, button)
+    }
+
+    /// Binds a MIDI input to `index`.
+    pub fn assign_midi(pmc: &mut PlayModeConfig, index: i32, input: MidiInput) {
+        KeyConfiguration::set_midi_key_assign(pmc, index, Some(input));
+    }
+
+    /// Maps an egui key to the libGDX key code stored in KeyboardConfig (-1 if unmapped).
+    pub fn egui_key_to_gdx(key: egui::Key) -> i32 {
+        use egui::Key;
+        let code = match key {
+            Key::A => WinitKeyCode::KeyA,
+            Key::B => WinitKeyCode::KeyB,
+            Key::C => WinitKeyCode::KeyC,
+            Key::D => WinitKeyCode::KeyD,
+            Key::E => WinitKeyCode::KeyE,
+            Key::F => WinitKeyCode::KeyF,
+            Key::G => WinitKeyCode::KeyG,
+            Key::H => WinitKeyCode::KeyH,
+            Key::I => WinitKeyCode::KeyI,
+            Key::J => WinitKeyCode::KeyJ,
+            Key::K => WinitKeyCode::KeyK,
+            Key::L => WinitKeyCode::KeyL,
+            Key::M => WinitKeyCode::KeyM,
+            Key::N => WinitKeyCode::KeyN,
+            Key::O => WinitKeyCode::KeyO,
+            Key::P => WinitKeyCode::KeyP,
+            Key::Q => WinitKeyCode::KeyQ,
+            Key::R => WinitKeyCode::KeyR,
+            Key::S => WinitKeyCode::KeyS,
+            Key::T => WinitKeyCode::KeyT,
+            Key::U => WinitKeyCode::KeyU,
+            Key::V => WinitKeyCode::KeyV,
+            Key::W => WinitKeyCode::KeyW,
+            Key::X => WinitKeyCode::KeyX,
+            Key::Y => WinitKeyCode::KeyY,
+            Key::Z => WinitKeyCode::KeyZ,
+            Key::Num0 => WinitKeyCode::Digit0,
+            Key::Num1 => WinitKeyCode::Digit1,
+            Key::Num2 => WinitKeyCode::Digit2,
+            Key::Num3 => WinitKeyCode::Digit3,
+            Key::Num4 => WinitKeyCode::Digit4,
+            Key::Num5 => WinitKeyCode::Digit5,
+            Key::Num6 => WinitKeyCode::Digit6,
+            Key::Num7 => WinitKeyCode::Digit7,
+            Key::Num8 => WinitKeyCode::Digit8,
+            Key::Num9 => WinitKeyCode::Digit9,
+            Key::ArrowUp => WinitKeyCode::ArrowUp,
+            Key::ArrowDown => WinitKeyCode::ArrowDown,
+            Key::ArrowLeft => WinitKeyCode::ArrowLeft,
+            Key::ArrowRight => WinitKeyCode::ArrowRight,
+            Key::Enter => WinitKeyCode::Enter,
+            Key::Escape => WinitKeyCode::Escape,
+            Key::Delete => WinitKeyCode::Delete,
+            Key::Insert => WinitKeyCode::Insert,
+            Key::Comma => WinitKeyCode::Comma,
+            Key::Period => WinitKeyCode::Period,
+            Key::Semicolon => WinitKeyCode::Semicolon,
+            Key::Quote => WinitKeyCode::Quote,
+            Key::Slash => WinitKeyCode::Slash,
+            Key::Backslash => WinitKeyCode::Backslash,
+            Key::F1 => WinitKeyCode::F1,
+            Key::F2 => WinitKeyCode::F2,
+            Key::F3 => WinitKeyCode::F3,
+            Key::F4 => WinitKeyCode::F4,
+            Key::F5 => WinitKeyCode::F5,
+            Key::F6 => WinitKeyCode::F6,
+            Key::F7 => WinitKeyCode::F7,
+            Key::F8 => WinitKeyCode::F8,
+            Key::F9 => WinitKeyCode::F9,
+            Key::F10 => WinitKeyCode::F10,
+            Key::F11 => WinitKeyCode::F11,
+            Key::F12 => WinitKeyCode::F12,
+            Key::Space => WinitKeyCode::Space,
+            Key::Backspace => WinitKeyCode::Backspace,
+            Key::Tab => WinitKeyCode::Tab,
+            Key::Minus => WinitKeyCode::Minus,
+            Key::Equals => WinitKeyCode::Equal,
+            Key::OpenBracket => WinitKeyCode::BracketLeft,
+            Key::CloseBracket => WinitKeyCode::BracketRight,
+            Key::Backtick => WinitKeyCode::Backquote,
+            Key::Home => WinitKeyCode::Home,
+            Key::End => WinitKeyCode::End,
+            Key::PageUp => WinitKeyCode::PageUp,
+            Key::PageDown => WinitKeyCode::PageDown,
+            _ => return -1,
+        };
+        winit_keycode_to_java(code)
+    }
+
+    /// Returns the key code of a modifier newly pressed since the previous frame.
+    ///
+    /// egui does not tell left and right modifiers apart, so the left key is assumed.
+    fn pressed_modifier(previous: egui::Modifiers, current: egui::Modifiers) -> Option<i32> {
+        if current.shift && !previous.shift {
+            Some(Keys::SHIFT_LEFT)
+        } else if current.ctrl && !previous.ctrl {
+            Some(Keys::CONTROL_LEFT)
+        } else if current.alt && !previous.alt {
+            Some(Keys::ALT_LEFT)
+        } else {
+            None
+        }
+    }
+
+    fn open_devices(&mut self) {
+        if self.controller_manager.is_none() {
+            self.controller_manager = Some(Lwjgl3ControllerManager::new());
+        }
+        if self.midi.is_none() {
+            let mut midi = MidiInputProcessor::new();
+            midi.open();
+            self.midi = Some(midi);
+        }
+    }
+
+    /// Polls gamepads, registering processors for newly connected devices.
+    /// Returns the name and button of the last pressed controller input, if any.
+    fn poll_controllers(&mut self) -> Option<(String, i32)> {
+        let manager = self.controller_manager.as_mut()?;
+        manager.poll_state();
+        for ctrl in manager.controllers.iter().skip(self.controllers.len()) {
+            // Same device name uniqueness rule as BMSPlayerInputProcessor
+            let mut index = 1;
+            let mut name = ctrl.name.clone();
+            for bm in &self.controllers {
+                if bm.name() == name {
+                    index += 1;
+                    name = format!("{}-{}", ctrl.name, index);
+                }
+            }
+            let controller = GdxController::with_state(
+                name.clone(),
+                ctrl.button_state.len(),
+                ctrl.axis_state.len(),
+            );
+            let mut processor =
+                BMControllerInputProcessor::new(name, controller, &ControllerConfig::default());
+            processor.enabled = true;
+            processor.clear();
+            self.controllers.push(processor);
+        }
+
+        let now = crate::skin::monotonic_clock::monotonic_micros();
+        let mut pressed = None;
+        for (idx, bm) in self.controllers.iter_mut().enumerate() {
+            if let Some(ctrl) = manager.controllers.get(idx) {
+                bm.controller.axis_state.clone_from(&ctrl.axis_state);
+                bm.controller.button_state.clone_from(&ctrl.button_state);
+            }
+            bm.poll(now, &mut NullCallback, idx);
+            if bm.last_pressed_button() >= 0 {
+                pressed = Some((bm.name().to_string(), bm.last_pressed_button()));
+                bm.clear();
+            }
+        }
+        pressed
+    }
+
+    fn poll_midi(&mut self) -> Option<MidiInput> {
+        let midi = self.midi.as_mut()?;
+        midi.poll(&mut NullCallback);
+        let pressed = midi.last_pressed_key();
+        midi.clear_last_pressed_key();
+        pressed
+    }
+
+    /// Feeds this frame's keyboard, controller and MIDI input into the pending capture.
+    fn process_input(&mut self, ui: &egui::Ui, pmc: &mut PlayModeConfig) {
+        let (events, modifiers) = ui.input(|i| (i.events.clone(), i.modifiers));
+        let previous = std::mem::replace(&mut self.modifiers, modifiers);
+        let controller = self.poll_controllers();
+        let midi = self.poll_midi();
+
+        let Some(index) = self.capture else {
+            return;
+        };
+
+        let mut keycode = Self::pressed_modifier(previous, modifiers);
+        for event in &events {
+            if let egui::Event::Key {
+                key,
+                physical_key,
+                pressed: true,
+                repeat: false,
+                ..
+            } = event
+            {
+                if *key == egui::Key::Escape {
+                    self.cancel_capture();
+                    return;
+                }
+                let code = Self::egui_key_to_gdx(physical_key.unwrap_or(*key));
+                if code >= 0 {
+                    keycode = Some(code);
+                }
+            }
+        }
+
+        if let Some(code) = keycode {
+            Self::assign_keyboard(pmc, index, code);
+            self.finish_capture(tr!(
+                "keybind-assigned-key",
+                key = KeyConfiguration::keyboard_key_name(code)
+            ));
+        } else if let Some((name, button)) = controller {
+            if Self::assign_controller(pmc, index, &name, button) {
+                self.finish_capture(tr!(
+                    "keybind-assigned-button",
+                    device = name.as_str(),
+                    button = BMKeys::to_string(button)
+                ));
+            } else {
+                self.status = tr!("keybind-no-free-slot", device = name.as_str());
+            }
+        } else if let Some(input) = midi {
+            Self::assign_midi(pmc, index, input);
+            self.finish_capture(tr!("keybind-assigned-midi", input = input.to_string()));
+        }
+    }
+
+    fn finish_capture(&mut self, status: String) {
+        self.capture = None;
+        self.status = status;
+    }
+
+    /// Render the key binding table for `mode`.
+    pub fn render(&mut self, ui: &mut egui::Ui, player: &mut PlayerConfig, mode: Mode) {
+        self.open_devices();
+        let pmc = player.play_config(mode);
+        self.process_input(ui, pmc);
+
+        ui.heading(tr!("keybind-key-binding"));
+        ui.label(tr!("keybind-instructions"));
+        if !self.status.is_empty() {
+            ui.label(&self.status);
+        }
+
+        ui.separator();
+
+        let layout = KeyConfiguration::mode_index(mode);
+        egui::Grid::new("key_binding_grid")
+            .num_columns(5)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(tr!("keybind-key"));
+                ui.label(tr!("keybind-keyboard"));
+                ui.label(tr!("keybind-controller"));
+                ui.label("MIDI");
+                ui.label("");
+                ui.end_row();
+
+                for (label, index) in KeyConfiguration::key_layout(layout) {
+                    let capturing = self.capture == Some(index);
+                    if ui.selectable_label(capturing, label).clicked() {
+                        if capturing {
+                            self.cancel_capture();
+                        } else {
+                            self.start_capture(index);
+                        }
+                    }
+                    for binding in Self::binding_labels(pmc, index) {
+                        ui.label(binding);
+                    }
+                    if ui.button(tr!("keybind-clear")).clicked() {
+                        KeyConfiguration::delete_key_assign(pmc, index);
+                    }
+                    ui.end_row();
+                }
+            });
+
+        ui.separator();
+        egui::Grid::new("midi_filter_grid").show(ui, |ui| {
+            ui.label(tr!("keybind-midi-velocity-threshold"));
+            ui.add(egui::DragValue::new(&mut pmc.midi.velocity_threshold).range(1..=127));
+            ui.end_row();
+
+            ui.label(tr!("keybind-midi-channel"));
+            ui.add(egui::DragValue::new(&mut pmc.midi.channel).range(-1..=15))
+                .on_hover_text(tr!("keybind-midi-channel-hint"));
+            ui.end_row();
+        });
+
+        if self.capture.is_some() {
+            // Keep polling devices while waiting for input
+            ui.ctx().request_repaint();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skin::play_mode_config::MidiInputType;
+
+    #[test]
+    fn test_assign_keyboard_updates_labels() {
+        let mut pmc = PlayModeConfig::new(Mode::BEAT_7K);
+        KeyBindingView::assign_keyboard(&mut pmc, 0, KeyBindingView::egui_key_to_gdx(egui::Key::A));
+        assert_eq!(pmc.keyboard.keys[0], 29);
+        assert_eq!(KeyBindingView::binding_labels(&pmc, 0)[0], "A");
+    }
+
+    #[test]
+    fn test_assign_controller_claims_unnamed_config() {
+        let mut pmc = PlayModeConfig::new(Mode::BEAT_14K);
+        for config in pmc.controller.iter_mut() {
+            config.name.clear();
+        }
+        assert!(KeyBindingView::assign_controller(
+            &mut pmc,
+            3,
+            "pad",
+            BMKeys::BUTTON_2
+        ));
+        assert!(KeyBindingView::assign_controller(
+            &mut pmc,
+            10,
+            "pad-2",
+            BMKeys::BUTTON_6
+        ));
+        assert_eq!(pmc.controller[0].name, "pad");
+        assert_eq!(pmc.controller[0].keys[3], BMKeys::BUTTON_2);
+        assert_eq!(pmc.controller[1].name, "pad-2");
+        assert_eq!(pmc.controller[1].keys[10], BMKeys::BUTTON_6);
+        assert_eq!(KeyBindingView::binding_labels(&pmc, 10)[1], "2P BUTTON 6");
+        // Both configs are taken now
+        assert!(!KeyBindingView::assign_controller(
+            &mut pmc,
+            0,
+            "other",
+            BMKeys::BUTTON_1
+        ));
+    }
+
+    #[test]
+    fn test_assign_midi_clears_other_devices() {
+        let mut pmc = PlayModeConfig::new(Mode::BEAT_7K);
+        let input = MidiInput {
+            input_type: MidiInputType::NOTE,
+            value: 60,
+        };
+        KeyBindingView::assign_midi(&mut pmc, 2, input);
+        let labels = KeyBindingView::binding_labels(&pmc, 2);
+        assert_eq!(labels[0], NO_ASSIGN_LABEL);
+        assert_eq!(labels[2], "NOTE 60");
+    }
+
+    #[test]
+    fn test_egui_key_mapping() {
+        assert_eq!(KeyBindingView::egui_key_to_gdx(egui::Key::Z), Keys::Z);
+        assert_eq!(
+            KeyBindingView::egui_key_to_gdx(egui::Key::Num1),
+            Keys::NUM_1
+        );
+        assert_eq!(KeyBindingView::egui_key_to_gdx(egui::Key::F35), -1);
+        let shift = egui::Modifiers {
+            shift: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            KeyBindingView::pressed_modifier(egui::Modifiers::NONE, shift),
+            Some(Keys::SHIFT_LEFT)
+        );
+        assert_eq!(KeyBindingView::pressed_modifier(shift, shift), None);
+    }
+}
@@ -2,6 +2,7 @@ pub mod audio_configuration_view;
 pub mod discord_configuration_view;
 pub mod input_configuration_view;
 pub mod ir_configuration_view;
+pub mod key_binding_view;
 pub mod music_select_configuration_view;
 pub mod obs_configuration_view;
 pub mod stream_editor_view;
@@ -3,11 +3,13 @@
 use crate::core::config::{Config, SongPreview};
 use crate::core::player_config::PlayerConfig;
 use crate::select::music_selector::ChartReplicationMode;
+use crate::select::song_filter::{CLEAR_FILTER_NAMES, RANK_FILTER_NAMES};
 
 /// Translates: MusicSelectConfigurationView (JavaFX → egui)
 ///
 /// Song select configuration UI: scroll durations, analog scroll,
-/// folder lamp, song info, preview, random select, chart replication.
+/// folder lamp, song info, preview, filters, random select, random course,
+/// chart replication.
 #[derive(Default)]
 pub struct MusicSelectConfigurationView {
     // @FXML private NumericSpinner<Integer> scrolldurationlow;
@@ -30,6 +32,18 @@ pub struct MusicSelectConfigurationView {
     song_preview: Option<SongPreview>,
     // @FXML private CheckBox randomselect;
     randomselect: bool,
+    random_select_roulette: bool,
+    clear_filter: i32,
+    rank_filter: i32,
+    level_filter_min: i32,
+    level_filter_max: i32,
+    group_charts: bool,
+    lock_next_dan: bool,
+    random_course_stages: i32,
+    random_course_table: String,
+    random_course_level_min: i32,
+    random_course_level_max: i32,
+    random_course_unplayed: bool,
     // @FXML private NumericSpinner<Integer> maxsearchbar;
     maxsearchbar: i32,
 
@@ -39,6 +53,8 @@ pub struct MusicSelectConfigurationView {
 
     // @FXML private CheckBox skipDecideScreen;
     skip_decide_screen: bool,
+    decide_min_duration: i32,
+    decide_max_duration: i32,
 
     // private Config config;
     config: Option<Config>,
@@ -91,6 +107,8 @@ impl MusicSelectConfigurationView {
         self.maxsearchbar = config.select.max_search_bar_count;
         // skipDecideScreen.setSelected(config.isSkipDecideScreen());
         self.skip_decide_screen = config.select.skip_decide_screen;
+        self.decide_min_duration = config.select.decide_min_duration;
+        self.decide_max_duration = config.select.decide_max_duration;
     }
 
     // public void commit()
@@ -121,6 +139,8 @@ impl MusicSelectConfigurationView {
             config.select.max_search_bar_count = self.maxsearchbar;
             // config.setSkipDecideScreen(skipDecideScreen.isSelected());
             config.select.skip_decide_screen = self.skip_decide_screen;
+            config.select.decide_min_duration = self.decide_min_duration;
+            config.select.decide_max_duration = self.decide_max_duration;
         }
     }
 
@@ -133,6 +153,18 @@ impl MusicSelectConfigurationView {
 
         // randomselect.setSelected(player.isRandomSelect());
         self.randomselect = player.select_settings.is_random_select;
+        self.random_select_roulette = player.select_settings.random_select_roulette;
+        self.clear_filter = player.select_settings.clear_filter;
+        self.rank_filter = player.select_settings.rank_filter;
+        self.level_filter_min = player.select_settings.level_filter_min;
+        self.level_filter_max = player.select_settings.level_filter_max;
+        self.group_charts = player.select_settings.group_charts;
+        self.lock_next_dan = player.select_settings.lock_next_dan;
+        self.random_course_stages = player.select_settings.random_course_stages;
+        self.random_course_table = player.select_settings.random_course_table.clone();
+        self.random_course_level_min = player.select_settings.random_course_level_min;
+        self.random_course_level_max = player.select_settings.random_course_level_max;
+        self.random_course_unplayed = player.select_settings.random_course_unplayed;
 
         // chartReplicationMode.setValue(player.getChartReplicationMode());
         self.chart_replication_mode = Some(player.play_settings.chart_replication_mode.clone());
@@ -144,6 +176,18 @@ impl MusicSelectConfigurationView {
         if let Some(ref mut player) = self.player {
             // player.setRandomSelect(randomselect.isSelected());
             player.select_settings.is_random_select = self.randomselect;
+            player.select_settings.random_select_roulette = self.random_select_roulette;
+            player.select_settings.clear_filter = self.clear_filter;
+            player.select_settings.rank_filter = self.rank_filter;
+            player.select_settings.level_filter_min = self.level_filter_min;
+            player.select_settings.level_filter_max = self.level_filter_max;
+            player.select_settings.group_charts = self.group_charts;
+            player.select_settings.lock_next_dan = self.lock_next_dan;
+            player.select_settings.random_course_stages = self.random_course_stages;
+            player.select_settings.random_course_table = self.random_course_table.clone();
+            player.select_settings.random_course_level_min = self.random_course_level_min;
+            player.select_settings.random_course_level_max = self.random_course_level_max;
+            player.select_settings.random_course_unplayed = self.random_course_unplayed;
 
             // player.setChartReplicationMode(chartReplicationMode.getValue());
             if let Some(ref mode) = self.chart_replication_mode {
@@ -193,6 +237,17 @@ impl MusicSelectConfigurationView {
                 ui.checkbox(&mut self.shownoexistingbar, "");
                 ui.end_row();
 
+                ui.label("Group Charts Of Same Song:");
+                ui.checkbox(&mut self.group_charts, "")
+                    .on_hover_text("Left/Right switch between the charts of a grouped song");
+                ui.end_row();
+
+                ui.label("Lock Next Dan:");
+                ui.checkbox(&mut self.lock_next_dan, "").on_hover_text(
+                    "Show a class course only after certifying for the one before it",
+                );
+                ui.end_row();
+
                 ui.label("Song Preview:");
                 let sp_label = self
                     .song_preview
@@ -216,6 +271,87 @@ impl MusicSelectConfigurationView {
                 ui.label("Skip Decide Screen:");
                 ui.checkbox(&mut self.skip_decide_screen, "");
                 ui.end_row();
+
+                ui.label("Decide Duration (ms):");
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::DragValue::new(&mut self.decide_min_duration).range(0..=60000),
+                    );
+                    ui.label("-");
+                    ui.add(
+                        egui::DragValue::new(&mut self.decide_max_duration).range(0..=60000),
+                    )
+                    .on_hover_text("Play starts after this even if loading is not finished. 0 waits for loading");
+                });
+                ui.end_row();
+            });
+
+        ui.separator();
+        ui.heading("Filter");
+        egui::Grid::new("music_select_filter_grid")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Clear:");
+                egui::ComboBox::from_id_salt("music_select_clear_filter")
+                    .selected_text(filter_label(&CLEAR_FILTER_NAMES, self.clear_filter))
+                    .show_ui(ui, |ui| {
+                        for (i, name) in CLEAR_FILTER_NAMES.iter().enumerate() {
+                            ui.selectable_value(&mut self.clear_filter, i as i32, *name);
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("DJ Rank At Least:");
+                egui::ComboBox::from_id_salt("music_select_rank_filter")
+                    .selected_text(filter_label(&RANK_FILTER_NAMES, self.rank_filter))
+                    .show_ui(ui, |ui| {
+                        for (i, name) in RANK_FILTER_NAMES.iter().enumerate() {
+                            ui.selectable_value(&mut self.rank_filter, i as i32, *name);
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Level Range:");
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.level_filter_min).range(0..=99));
+                    ui.label("-");
+                    ui.add(egui::DragValue::new(&mut self.level_filter_max).range(0..=99));
+                })
+                .response
+                .on_hover_text("0 leaves the bound open");
+                ui.end_row();
+            });
+
+        ui.separator();
+        ui.heading("Random Course");
+        egui::Grid::new("music_select_random_course_grid")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Stages:");
+                ui.add(egui::DragValue::new(&mut self.random_course_stages).range(0..=10))
+                    .on_hover_text("0 hides the random course bar");
+                ui.end_row();
+
+                ui.label("Table:");
+                ui.text_edit_singleline(&mut self.random_course_table)
+                    .on_hover_text("Table name; empty draws from the whole library");
+                ui.end_row();
+
+                ui.label("Level Range:");
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.random_course_level_min).range(0..=99));
+                    ui.label("-");
+                    ui.add(egui::DragValue::new(&mut self.random_course_level_max).range(0..=99));
+                })
+                .response
+                .on_hover_text(
+                    "Spread over the stages in ascending order. 0 leaves the bound open",
+                );
+                ui.end_row();
+
+                ui.label("Unplayed Only:");
+                ui.checkbox(&mut self.random_course_unplayed, "");
+                ui.end_row();
             });
 
         ui.separator();
@@ -231,6 +367,13 @@ impl MusicSelectConfigurationView {
                 ui.checkbox(&mut self.randomselect, "");
                 ui.end_row();
 
+                ui.label("Random Select Roulette:");
+                ui.checkbox(&mut self.random_select_roulette, "")
+                    .on_hover_text(
+                        "Ctrl+R: random chart, Ctrl+Shift+R: unplayed, Ctrl+Alt+R: failed",
+                    );
+                ui.end_row();
+
                 ui.label("Chart Replication Mode:");
                 let crm_label = self.chart_replication_mode.clone().unwrap_or_default();
                 egui::ComboBox::from_id_salt("music_select_chart_replication")
@@ -248,3 +391,7 @@ impl MusicSelectConfigurationView {
             });
     }
 }
+
+fn filter_label(names: &[&'static str], value: i32) -> &'static str {
+    names.get(value as usize).copied().unwrap_or(names[0])
+}
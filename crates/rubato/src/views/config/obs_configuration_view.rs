@@ -37,6 +37,9 @@ pub struct ObsConfigurationView {
     obs_ws_rec_mode: i32,
     obs_ws_rec_mode_items: Vec<String>,
     obs_ws_rec_stop_wait: i32,
+    obs_ws_rec_on_play: bool,
+    obs_ws_replay_on_full_combo: bool,
+    obs_ws_replay_on_new_lamp: bool,
     // VBox listContainer children are represented as state data
     // (actual rendering is egui)
     config: Option<Config>,
@@ -70,6 +73,9 @@ impl ObsConfigurationView {
             obs_ws_rec_mode: 0,
             obs_ws_rec_mode_items: Vec::new(),
             obs_ws_rec_stop_wait: 5000,
+            obs_ws_rec_on_play: false,
+            obs_ws_replay_on_full_combo: false,
+            obs_ws_replay_on_new_lamp: false,
 
             config: None,
             status: String::new(),
@@ -162,6 +168,9 @@ impl ObsConfigurationView {
         self.obs_ws_pass = config.obs.obs_ws_pass.clone();
         self.obs_ws_rec_stop_wait = config.obs.obs_ws_rec_stop_wait;
         self.obs_ws_rec_mode = config.obs.obs_ws_rec_mode;
+        self.obs_ws_rec_on_play = config.obs.obs_ws_rec_on_play;
+        self.obs_ws_replay_on_full_combo = config.obs.obs_ws_replay_on_full_combo;
+        self.obs_ws_replay_on_new_lamp = config.obs.obs_ws_replay_on_new_lamp;
         self.reset_connection_status();
 
         self.config = Some(config);
@@ -219,6 +228,9 @@ impl ObsConfigurationView {
             config.obs.obs_ws_pass = self.obs_ws_pass.clone();
             config.obs.obs_ws_rec_stop_wait = self.obs_ws_rec_stop_wait;
             config.obs.obs_ws_rec_mode = self.obs_ws_rec_mode;
+            config.obs.obs_ws_rec_on_play = self.obs_ws_rec_on_play;
+            config.obs.obs_ws_replay_on_full_combo = self.obs_ws_replay_on_full_combo;
+            config.obs.obs_ws_replay_on_new_lamp = self.obs_ws_replay_on_new_lamp;
         }
 
         self.save_selections();
@@ -507,6 +519,20 @@ impl ObsConfigurationView {
                     ui.label("Rec Stop Wait (ms):");
                     ui.add(egui::DragValue::new(&mut self.obs_ws_rec_stop_wait).range(0..=10000));
                     ui.end_row();
+
+                    ui.label("Record Plays:");
+                    ui.checkbox(
+                        &mut self.obs_ws_rec_on_play,
+                        "Start at first note, stop after play",
+                    );
+                    ui.end_row();
+
+                    ui.label("Save Replay Buffer:");
+                    ui.vertical(|ui| {
+                        ui.checkbox(&mut self.obs_ws_replay_on_full_combo, "On FULL COMBO");
+                        ui.checkbox(&mut self.obs_ws_replay_on_new_lamp, "On new clear lamp");
+                    });
+                    ui.end_row();
                 }
             });
 
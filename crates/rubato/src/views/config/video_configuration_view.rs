@@ -1,6 +1,6 @@
 // Translates: bms.player.beatoraja.launcher.VideoConfigurationView
 
-use crate::core::config::{Config, DisplayMode};
+use crate::core::config::{Config, DisplayConfig, DisplayMode};
 use crate::core::player_config::PlayerConfig;
 use crate::core::resolution::Resolution;
 
@@ -268,38 +268,8 @@ impl VideoConfigurationView {
     pub fn update_resolutions(&mut self) {
         // Resolution oldValue = resolution.getValue();
         let old_value = self.resolution;
-        // resolution.getItems().clear();
-        self.resolution_items.clear();
-
-        // if (displayMode.getValue() == Config.DisplayMode.FULLSCREEN) {
-        if matches!(self.display_mode, Some(DisplayMode::FULLSCREEN)) {
-            // Graphics.DisplayMode[] displays = MainLoader.getAvailableDisplayMode();
-            let displays = MainLoader::available_display_mode();
-            // for(Resolution r : Resolution.values()) {
-            for r in ALL_RESOLUTIONS {
-                // for(Graphics.DisplayMode display : displays) {
-                for display in &displays {
-                    // if(display.width == r.width && display.height == r.height) {
-                    if display.width == r.width() && display.height == r.height() {
-                        // resolution.getItems().add(r);
-                        self.resolution_items.push(*r);
-                        // break;
-                        break;
-                    }
-                }
-            }
-        } else {
-            // Graphics.DisplayMode display = MainLoader.getDesktopDisplayMode();
-            let display = MainLoader::desktop_display_mode();
-            // for(Resolution r : Resolution.values()) {
-            for r in ALL_RESOLUTIONS {
-                // if (r.width <= display.width && r.height <= display.height) {
-                if r.width() <= display.width && r.height() <= display.height {
-                    // resolution.getItems().add(r);
-                    self.resolution_items.push(*r);
-                }
-            }
-        }
+        // resolution.getItems().clear(); then add the resolutions available in this mode
+        self.resolution_items = Self::available_resolutions(self.display_mode.unwrap_or_default());
 
         // resolution.setValue(resolution.getItems().contains(oldValue)
         //     ? oldValue : resolution.getItems().get(resolution.getItems().size() - 1));
@@ -313,14 +283,80 @@ impl VideoConfigurationView {
             self.resolution = Some(*last);
         }
     }
+
+    /// Resolutions usable in `display_mode` on the current display.
+    pub fn available_resolutions(display_mode: DisplayMode) -> Vec<Resolution> {
+        // if (displayMode.getValue() == Config.DisplayMode.FULLSCREEN) {
+        if matches!(display_mode, DisplayMode::FULLSCREEN) {
+            // Graphics.DisplayMode[] displays = MainLoader.getAvailableDisplayMode();
+            let displays = MainLoader::available_display_mode();
+            // for(Resolution r : Resolution.values()) {
+            //     for(Graphics.DisplayMode display : displays) {
+            //         if(display.width == r.width && display.height == r.height) {
+            //             resolution.getItems().add(r); break;
+            ALL_RESOLUTIONS
+                .iter()
+                .filter(|r| {
+                    displays
+                        .iter()
+                        .any(|d| d.width == r.width() && d.height == r.height())
+                })
+                .copied()
+                .collect()
+        } else {
+            // Graphics.DisplayMode display = MainLoader.getDesktopDisplayMode();
+            let display = MainLoader::desktop_display_mode();
+            // for(Resolution r : Resolution.values()) {
+            //     if (r.width <= display.width && r.height <= display.height) {
+            //         resolution.getItems().add(r);
+            ALL_RESOLUTIONS
+                .iter()
+                .filter(|r| r.width() <= display.width && r.height() <= display.height)
+                .copied()
+                .collect()
+        }
+    }
+
+    /// Bring display settings into a range the current display can show
+    /// before they are written to Config.
+    ///
+    /// An unavailable resolution is replaced by the largest available one and
+    /// a custom window size is clamped between SD and the desktop size.
+    /// Returns true if anything was changed.
+    pub fn clamp_display_config(display: &mut DisplayConfig) -> bool {
+        let before = (
+            display.resolution,
+            display.window_width,
+            display.window_height,
+        );
+        let available = Self::available_resolutions(display.displaymode);
+        if !available.contains(&display.resolution)
+            && let Some(last) = available.last()
+        {
+            display.resolution = *last;
+        }
+        let desktop = MainLoader::desktop_display_mode();
+        display.window_width = display.window_width.clamp(
+            Resolution::SD.width(),
+            desktop.width.max(Resolution::SD.width()),
+        );
+        display.window_height = display.window_height.clamp(
+            Resolution::SD.height(),
+            desktop.height.max(Resolution::SD.height()),
+        );
+        before
+            != (
+                display.resolution,
+                display.window_width,
+                display.window_height,
+            )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::config::{
-        BGA_ON, BgaExpand, BgaMode, DisplayConfig, IntegrationConfig, RenderConfig,
-    };
+    use crate::core::config::{BGA_ON, BgaExpand, BgaMode, IntegrationConfig, RenderConfig};
 
     // --- Default / initialization tests ---
 
@@ -525,6 +561,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn clamp_display_config_replaces_oversized_resolution_and_window() {
+        // Desktop fallback is 1920x1080 when no display modes are cached
+        let mut display = DisplayConfig {
+            displaymode: DisplayMode::WINDOW,
+            resolution: Resolution::ULTRAHD,
+            use_resolution: false,
+            window_width: 5000,
+            window_height: 100,
+            ..DisplayConfig::default()
+        };
+
+        assert!(VideoConfigurationView::clamp_display_config(&mut display));
+        assert_eq!(display.resolution, Resolution::FULLHD);
+        assert_eq!(display.window_width, 1920);
+        assert_eq!(display.window_height, Resolution::SD.height());
+
+        // Already valid settings are left alone
+        assert!(!VideoConfigurationView::clamp_display_config(&mut display));
+    }
+
     // --- Accessor tests ---
 
     #[test]
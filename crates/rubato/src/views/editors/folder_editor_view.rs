@@ -233,6 +233,43 @@ impl FolderEditorView {
         }
     }
 
+    /// Songs carried when search result `index` is dragged: the whole
+    /// selection if that row is selected, otherwise just that row.
+    fn dragged_songs(&self, index: usize) -> Vec<SongData> {
+        let Some(song) = self.search_songs.get(index) else {
+            return Vec::new();
+        };
+        if self
+            .search_songs_selected_items
+            .iter()
+            .any(|s| s.file.sha256 == song.file.sha256 && s.file.md5 == song.file.md5)
+        {
+            self.search_songs_selected_items.clone()
+        } else {
+            vec![song.clone()]
+        }
+    }
+
+    /// Add songs dropped onto folder `folder_idx`, skipping songs it already holds.
+    pub fn add_songs_to_folder(&mut self, folder_idx: usize, songs: &[SongData]) {
+        self.commit_folder();
+        let Some(folder) = self.folders.get_mut(folder_idx) else {
+            return;
+        };
+        for song in songs {
+            let contained = folder.songs.iter().any(|s| {
+                (!song.file.sha256.is_empty() && s.file.sha256 == song.file.sha256)
+                    || (!song.file.md5.is_empty() && s.file.md5 == song.file.md5)
+            });
+            if !contained {
+                folder.songs.push(song.clone());
+            }
+        }
+        if self.selected_folder == Some(folder_idx) {
+            self.update_folder(Some(folder_idx));
+        }
+    }
+
     /// removeSongData - removes the selected song from the folder
     pub fn remove_song_data(&mut self) {
         if let Some(idx) = self.folder_songs_selected_index
@@ -336,12 +373,21 @@ impl FolderEditorView {
             .max_height(120.0)
             .show(ui, |ui| {
                 let mut new_selection = self.folders_selected_index;
+                let mut dropped: Option<(usize, usize)> = None;
                 for (i, folder) in self.folders.iter().enumerate() {
                     let name = folder.name.as_deref().unwrap_or("(unnamed)");
                     let selected = self.folders_selected_index == Some(i);
-                    if ui.selectable_label(selected, name).clicked() {
+                    let response = ui.selectable_label(selected, name);
+                    if response.clicked() {
                         new_selection = Some(i);
                     }
+                    if let Some(song) = response.dnd_release_payload::<usize>() {
+                        dropped = Some((i, *song));
+                    }
+                }
+                if let Some((folder_idx, song_idx)) = dropped {
+                    let songs = self.dragged_songs(song_idx);
+                    self.add_songs_to_folder(folder_idx, &songs);
                 }
                 if new_selection != self.folders_selected_index {
                     self.commit_folder();
@@ -403,9 +449,12 @@ impl FolderEditorView {
             }
         });
 
-        if ui.button("Add Selected to Folder").clicked() {
-            self.add_song_data();
-        }
+        ui.horizontal(|ui| {
+            if ui.button("Add Selected to Folder").clicked() {
+                self.add_song_data();
+            }
+            ui.label("or drag songs onto a folder");
+        });
 
         egui::ScrollArea::vertical()
             .id_salt("search_songs_folder_scroll")
@@ -423,7 +472,13 @@ impl FolderEditorView {
                         song.metadata.artist,
                         &song.file.sha256,
                     );
-                    if ui.selectable_label(is_selected, &label).clicked() {
+                    let clicked = ui
+                        .dnd_drag_source(egui::Id::new(("folder_search_song", i)), i, |ui| {
+                            ui.selectable_label(is_selected, &label)
+                        })
+                        .inner
+                        .clicked();
+                    if clicked {
                         if is_selected {
                             self.search_songs_selected_items.retain(|s| {
                                 s.file.sha256 != song.file.sha256 || s.file.md5 != song.file.md5
@@ -975,4 +1030,34 @@ mod tests {
         assert_eq!(folders[0].songs.len(), 1);
         assert_eq!(folders[0].songs[0].metadata.title, "Test Song");
     }
+
+    #[test]
+    fn test_drop_songs_onto_folder() {
+        let mut view = FolderEditorView::new();
+        view.folders = vec![make_folder("L1", vec![]), make_folder("L2", vec![])];
+        view.search_songs = vec![
+            make_song("A", "md5a", "shaa"),
+            make_song("B", "md5b", "shab"),
+            make_song("C", "md5c", "shac"),
+        ];
+        view.search_songs_selected_items =
+            vec![view.search_songs[0].clone(), view.search_songs[2].clone()];
+
+        // Dragging an unselected row carries only that row
+        let songs = view.dragged_songs(1);
+        view.add_songs_to_folder(0, &songs);
+        assert_eq!(view.folders[0].songs.len(), 1);
+
+        // Dragging a selected row carries the selection; duplicates are skipped
+        view.folders_selected_index = Some(1);
+        view.update_folder(Some(1));
+        let songs = view.dragged_songs(2);
+        view.add_songs_to_folder(1, &songs);
+        view.add_songs_to_folder(1, &songs);
+        assert_eq!(view.folders[1].songs.len(), 2);
+        assert_eq!(view.folder_songs.len(), 2);
+
+        view.add_songs_to_folder(5, &songs);
+        assert!(view.dragged_songs(9).is_empty());
+    }
 }
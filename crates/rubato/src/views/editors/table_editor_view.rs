@@ -5,6 +5,7 @@ use std::path::{Path, PathBuf};
 use regex::Regex;
 
 use crate::core::table_data::TableData;
+use crate::core::table_data_accessor::TableDataAccessor;
 use crate::skin::song_data::SongData;
 use crate::song_database_accessor::SongDatabaseAccessor;
 use egui;
@@ -28,6 +29,12 @@ pub struct TableEditorView {
 
     // JavaFX @FXML fields → egui widget state
     table_name: String,
+    /// Table symbol, prefixed to level names (e.g. "★").
+    table_tag: String,
+    /// Directory header.json / data.json are exported to.
+    export_dir: String,
+    /// Result of the last export (error message on failure).
+    export_result: Option<Result<(), String>>,
 
     course_controller: CourseEditorView,
     folder_controller: FolderEditorView,
@@ -48,6 +55,9 @@ impl TableEditorView {
         Self {
             filepath: None,
             table_name: String::new(),
+            table_tag: String::new(),
+            export_dir: String::new(),
+            export_result: None,
             course_controller: CourseEditorView::new(),
             folder_controller: FolderEditorView::new(),
             selected_tab: EditorTab::Course,
@@ -86,17 +96,30 @@ impl TableEditorView {
         self.course_controller.courses = td.course.clone();
         self.folder_controller.folders = td.folder.clone();
         self.table_name = td.name.clone();
+        self.table_tag = td.tag.clone();
         self.filepath = Some(p.to_path_buf());
+        self.export_result = None;
     }
 
-    /// commit - saves table data to file
-    pub fn commit(&mut self) {
-        let td = TableData {
+    /// Table being edited.
+    fn table_data(&mut self) -> TableData {
+        TableData {
             name: self.table_name.clone(),
+            tag: self.table_tag.clone(),
             course: self.course_controller.course_data(),
             folder: self.folder_controller.table_folder(),
             ..Default::default()
-        };
+        }
+    }
+
+    /// Set the name of a newly created table.
+    pub fn set_table_name(&mut self, name: &str) {
+        self.table_name = name.to_string();
+    }
+
+    /// commit - saves table data to file
+    pub fn commit(&mut self) {
+        let td = self.table_data();
 
         if let Some(ref filepath) = self.filepath
             && let Err(e) = TableData::write_to_path(filepath, &td)
@@ -109,6 +132,15 @@ impl TableEditorView {
         }
     }
 
+    /// Export the table as header.json / data.json into the export directory.
+    pub fn export(&mut self) {
+        let td = self.table_data();
+        self.export_result = Some(
+            TableDataAccessor::export_json(&td, Path::new(&self.export_dir))
+                .map_err(|e| format!("{:#}", e)),
+        );
+    }
+
     /// isMd5OrSha256Hash - checks if text is a valid md5 or sha256 hash
     /// Java: public static boolean isMd5OrSha256Hash(String text)
     pub fn is_md5_or_sha256_hash(text: &str) -> bool {
@@ -258,11 +290,39 @@ impl TableEditorView {
             ui.text_edit_singleline(&mut self.table_name);
         });
 
+        ui.horizontal(|ui| {
+            ui.label("Symbol:");
+            ui.text_edit_singleline(&mut self.table_tag);
+        });
+
         // Save button
         if ui.button("Save").clicked() {
             self.commit();
         }
 
+        ui.horizontal(|ui| {
+            ui.label("Export Directory:");
+            ui.text_edit_singleline(&mut self.export_dir);
+            if ui
+                .add_enabled(
+                    !self.export_dir.is_empty(),
+                    egui::Button::new("Export header/data JSON"),
+                )
+                .clicked()
+            {
+                self.export();
+            }
+        });
+        match &self.export_result {
+            Some(Ok(())) => {
+                ui.label(format!("Exported to {}", self.export_dir));
+            }
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::RED, format!("Export failed: {}", e));
+            }
+            None => {}
+        }
+
         ui.separator();
 
         // Sub-editor tabs
@@ -598,4 +658,28 @@ mod tests {
         view2.update(tmpfile.path());
         assert_eq!(view2.table_name, "Modified");
     }
+
+    #[test]
+    fn test_export_writes_table_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut view = TableEditorView::new();
+        view.table_name = "Exported".to_string();
+        view.folder_controller.folders = vec![TableFolder {
+            name: Some("1".to_string()),
+            songs: vec![make_song("S1", "abcd1234abcd1234abcd1234abcd1234", "")],
+        }];
+
+        view.export_dir = dir.path().to_string_lossy().to_string();
+        view.export();
+        assert!(
+            matches!(view.export_result, Some(Err(_))),
+            "symbol required"
+        );
+
+        view.table_tag = "E".to_string();
+        view.export();
+        assert_eq!(view.export_result, Some(Ok(())));
+        assert!(dir.path().join("header.json").exists());
+        assert!(dir.path().join("data.json").exists());
+    }
 }
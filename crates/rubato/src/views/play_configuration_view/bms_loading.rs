@@ -144,50 +144,7 @@ impl PlayConfigurationView {
         // Reset any previous result
         self.bms_loading_result = None;
 
-        let listener = Arc::new(SongListener::new());
-        let listener_clone = Arc::clone(&listener);
-
-        let songpath = config.paths.songpath.clone();
-        let bmsroot = config.paths.bmsroot.clone();
-        let use_song_info = config.use_song_info;
-        let songinfopath = config.paths.songinfopath.clone();
-
-        let join_handle = std::thread::spawn(move || -> anyhow::Result<()> {
-            log::info!("song.db update started");
-
-            let songdb = SQLiteSongDatabaseAccessor::new(&songpath, &bmsroot)?;
-
-            let infodb = if use_song_info {
-                match SongInformationAccessor::new(&songinfopath) {
-                    Ok(db) => Some(db),
-                    Err(e) => {
-                        log::warn!("Failed to open song info DB: {}", e);
-                        None
-                    }
-                }
-            } else {
-                None
-            };
-
-            songdb.update_song_datas_with_listener(
-                updatepath.as_deref(),
-                &bmsroot,
-                update_all,
-                false,
-                infodb
-                    .as_ref()
-                    .map(|db| db as &dyn crate::song_information_db::SongInformationDb),
-                &listener_clone,
-            );
-
-            log::info!("song.db update completed");
-            Ok(())
-        });
-
-        self.bms_loading_handle = Some(BmsLoadingHandle {
-            listener,
-            join_handle,
-        });
+        self.bms_loading_handle = Some(BmsLoadingHandle::spawn(&config, updatepath, update_all));
     }
 
     /// Get the current BMS loading state.
@@ -195,11 +152,7 @@ impl PlayConfigurationView {
     /// Call this from the egui update loop to display progress.
     pub fn bms_loading_state(&self) -> BmsLoadingState {
         if let Some(handle) = &self.bms_loading_handle {
-            BmsLoadingState::Loading {
-                bms_files: handle.listener.bms_files_count(),
-                processed_files: handle.listener.processed_bms_files_count(),
-                new_files: handle.listener.new_bms_files_count(),
-            }
+            handle.state()
         } else if let Some(result) = &self.bms_loading_result {
             match result {
                 Ok(()) => BmsLoadingState::Completed,
@@ -219,26 +172,20 @@ impl PlayConfigurationView {
         let finished = self
             .bms_loading_handle
             .as_ref()
-            .is_some_and(|h| h.join_handle.is_finished());
+            .is_some_and(|h| h.is_finished());
 
         if finished {
             let handle = self.bms_loading_handle.take().expect("take");
-            match handle.join_handle.join() {
-                Ok(Ok(())) => {
+            match handle.join() {
+                Ok(()) => {
                     self.song_updated = true;
                     self.bms_loading_result = Some(Ok(()));
                     log::info!("BMS loading completed successfully");
                 }
-                Ok(Err(e)) => {
-                    let msg = format!("{}", e);
+                Err(msg) => {
                     log::error!("BMS loading failed: {}", msg);
                     self.bms_loading_result = Some(Err(msg));
                 }
-                Err(_panic) => {
-                    let msg = "BMS loading thread panicked".to_string();
-                    log::error!("{}", msg);
-                    self.bms_loading_result = Some(Err(msg));
-                }
             }
         }
     }
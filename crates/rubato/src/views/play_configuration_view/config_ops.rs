@@ -194,6 +194,7 @@ impl PlayConfigurationView {
         self.lntype = Some(player.play_settings.lnmode);
 
         self.notesdisplaytiming = player.judge_settings.judgetiming;
+        self.audiooffset = player.judge_settings.audio_offset;
         self.notesdisplaytimingautoadjust = player.judge_settings.notes_display_timing_auto_adjust;
 
         self.bpmguide = player.display_settings.bpmguide;
@@ -201,6 +202,10 @@ impl PlayConfigurationView {
         self.bottomshiftablegauge = Some(player.play_settings.bottom_shiftable_gauge);
 
         self.customjudge = player.judge_settings.custom_judge;
+        self.judgerule = Some(match player.judge_settings.judge_rule {
+            BMSPlayerRule::LR2 => 0,
+            BMSPlayerRule::Beatoraja => 1,
+        });
         self.njudgepg = player.judge_settings.key_judge_window_rate_perfect_great;
         self.njudgegr = player.judge_settings.key_judge_window_rate_great;
         self.njudgegd = player.judge_settings.key_judge_window_rate_good;
@@ -225,9 +230,11 @@ impl PlayConfigurationView {
             self.autosavereplay3 = Some(player.misc_settings.autosavereplay[2]);
             self.autosavereplay4 = Some(player.misc_settings.autosavereplay[3]);
         }
+        self.replay_autosave_policy = Some(player.misc_settings.replay_autosave_policy);
 
         self.target = player.select_settings.targetlist.clone();
         self.target_selected = Some(player.select_settings.targetid.clone());
+        self.target_custom_rate = player.select_settings.target_custom_rate;
         self.showhiddennote = player.display_settings.showhiddennote;
         self.showpastnote = player.display_settings.showpastnote;
 
@@ -338,6 +345,7 @@ impl PlayConfigurationView {
             player.play_settings.gauge = self.gaugeop.unwrap_or(0);
             player.play_settings.lnmode = self.lntype.unwrap_or(0);
             player.judge_settings.judgetiming = self.notesdisplaytiming;
+            player.judge_settings.audio_offset = self.audiooffset;
             player.judge_settings.notes_display_timing_auto_adjust =
                 self.notesdisplaytimingautoadjust;
 
@@ -345,6 +353,10 @@ impl PlayConfigurationView {
             player.play_settings.gauge_auto_shift = self.gaugeautoshift.unwrap_or(0);
             player.play_settings.bottom_shiftable_gauge = self.bottomshiftablegauge.unwrap_or(0);
             player.judge_settings.custom_judge = self.customjudge;
+            player.judge_settings.judge_rule = match self.judgerule {
+                Some(1) => BMSPlayerRule::Beatoraja,
+                _ => BMSPlayerRule::LR2,
+            };
             player.judge_settings.key_judge_window_rate_perfect_great = self.njudgepg;
             player.judge_settings.key_judge_window_rate_great = self.njudgegr;
             player.judge_settings.key_judge_window_rate_good = self.njudgegd;
@@ -368,11 +380,13 @@ impl PlayConfigurationView {
                 self.autosavereplay3.unwrap_or(0),
                 self.autosavereplay4.unwrap_or(0),
             ];
+            player.misc_settings.replay_autosave_policy = self.replay_autosave_policy.unwrap_or(0);
 
             player.display_settings.showjudgearea = self.judgeregion;
             if let Some(ref target) = self.target_selected {
                 player.select_settings.targetid = target.clone();
             }
+            player.select_settings.target_custom_rate = self.target_custom_rate;
 
             player.display_settings.showhiddennote = self.showhiddennote;
             player.display_settings.showpastnote = self.showpastnote;
@@ -408,6 +422,24 @@ impl PlayConfigurationView {
             }
             if let Some(ms_p) = self.music_select_controller.player() {
                 player.select_settings.is_random_select = ms_p.select_settings.is_random_select;
+                player.select_settings.random_select_roulette =
+                    ms_p.select_settings.random_select_roulette;
+                player.select_settings.clear_filter = ms_p.select_settings.clear_filter;
+                player.select_settings.rank_filter = ms_p.select_settings.rank_filter;
+                player.select_settings.level_filter_min = ms_p.select_settings.level_filter_min;
+                player.select_settings.level_filter_max = ms_p.select_settings.level_filter_max;
+                player.select_settings.group_charts = ms_p.select_settings.group_charts;
+                player.select_settings.lock_next_dan = ms_p.select_settings.lock_next_dan;
+                player.select_settings.random_course_stages =
+                    ms_p.select_settings.random_course_stages;
+                player.select_settings.random_course_table =
+                    ms_p.select_settings.random_course_table.clone();
+                player.select_settings.random_course_level_min =
+                    ms_p.select_settings.random_course_level_min;
+                player.select_settings.random_course_level_max =
+                    ms_p.select_settings.random_course_level_max;
+                player.select_settings.random_course_unplayed =
+                    ms_p.select_settings.random_course_unplayed;
                 player.play_settings.chart_replication_mode =
                     ms_p.play_settings.chart_replication_mode.clone();
             }
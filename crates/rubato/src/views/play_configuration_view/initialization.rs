@@ -38,11 +38,13 @@ impl PlayConfigurationView {
             bgmpath: String::new(),
             soundpath: String::new(),
             notesdisplaytiming: 0,
+            audiooffset: 0,
             notesdisplaytimingautoadjust: false,
             bpmguide: false,
             gaugeautoshift: None,
             bottomshiftablegauge: None,
             customjudge: false,
+            judgerule: None,
             njudgepg: 400,
             njudgegr: 400,
             njudgegd: 100,
@@ -68,11 +70,13 @@ impl PlayConfigurationView {
             showpastnote: false,
             target: Vec::new(),
             target_selected: None,
+            target_custom_rate: 90.0,
             judgealgorithm: None,
             autosavereplay1: None,
             autosavereplay2: None,
             autosavereplay3: None,
             autosavereplay4: None,
+            replay_autosave_policy: None,
             usecim: false,
             enable_ipfs: false,
             ipfsurl: String::new(),
@@ -95,7 +99,9 @@ impl PlayConfigurationView {
             scrollmode_labels: Vec::new(),
             longnotemode_labels: Vec::new(),
             judgealgorithm_labels: Vec::new(),
+            judgerule_labels: Vec::new(),
             autosave_labels: Vec::new(),
+            replay_autosave_policy_labels: Vec::new(),
             video_controller: VideoConfigurationView::default(),
             audio_controller: AudioConfigurationView::default(),
             input_controller: InputConfigurationView::default(),
@@ -214,7 +220,9 @@ impl PlayConfigurationView {
         ]);
 
         // These would normally come from resource bundle
-        self.judgealgorithm_labels = Self::init_combo_box_labels(&["LR2", "AC", "BOTTOM PRIORITY"]);
+        self.judgealgorithm_labels =
+            Self::init_combo_box_labels(&["LR2", "AC", "BOTTOM PRIORITY", "SCORE PRIORITY"]);
+        self.judgerule_labels = Self::init_combo_box_labels(&["LR2", "BEATORAJA"]);
 
         self.autosave_labels = Self::init_combo_box_labels(&[
             "NONE",
@@ -229,8 +237,16 @@ impl PlayConfigurationView {
             "BETTER ALL",
             "ALWAYS",
         ]);
+        self.replay_autosave_policy_labels = Self::init_combo_box_labels(&[
+            "PER SLOT",
+            "ALWAYS",
+            "PERSONAL BEST",
+            "BETTER LAMP",
+            "NEVER",
+            "ASK",
+        ]);
 
-        self.http_download_source = DOWNLOAD_SOURCES.keys().cloned().collect();
+        self.http_download_source = DOWNLOAD_SOURCES.names().map(str::to_string).collect();
 
         // Sub-controller init calls: these methods set up internal state
         // (table columns, combo box items, etc.) — actual rendering is egui.
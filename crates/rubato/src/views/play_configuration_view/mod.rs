@@ -9,6 +9,7 @@ pub(crate) use log::{info, warn};
 
 pub(crate) use crate::core::config::Config;
 pub(crate) use crate::core::player_config::PlayerConfig;
+pub(crate) use crate::skin::bms_player_rule::BMSPlayerRule;
 pub(crate) use crate::song::md_processor::http_download_processor::DOWNLOAD_SOURCES;
 pub(crate) use crate::song::song_database_update_listener::SongDatabaseUpdateListener as SongListener;
 pub(crate) use crate::song::song_information_accessor::SongInformationAccessor;
@@ -55,11 +56,84 @@ pub enum BmsLoadingState {
 ///
 /// Holds the shared `SongDatabaseUpdateListener` (atomic counters) and the
 /// `JoinHandle` so the UI can poll progress and detect completion.
-struct BmsLoadingHandle {
+pub struct BmsLoadingHandle {
     listener: Arc<SongListener>,
     join_handle: JoinHandle<anyhow::Result<()>>,
 }
 
+impl BmsLoadingHandle {
+    /// Start updating song.db for `updatepath` (all BMS roots if None) on a
+    /// background thread.
+    pub fn spawn(config: &Config, updatepath: Option<String>, update_all: bool) -> Self {
+        let listener = Arc::new(SongListener::new());
+        let listener_clone = Arc::clone(&listener);
+
+        let songpath = config.paths.songpath.clone();
+        let bmsroot = config.paths.bmsroot.clone();
+        let use_song_info = config.use_song_info;
+        let songinfopath = config.paths.songinfopath.clone();
+
+        let join_handle = std::thread::spawn(move || -> anyhow::Result<()> {
+            log::info!("song.db update started");
+
+            let songdb = SQLiteSongDatabaseAccessor::new(&songpath, &bmsroot)?;
+
+            let infodb = if use_song_info {
+                match SongInformationAccessor::new(&songinfopath) {
+                    Ok(db) => Some(db),
+                    Err(e) => {
+                        log::warn!("Failed to open song info DB: {}", e);
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+
+            songdb.update_song_datas_with_listener(
+                updatepath.as_deref(),
+                &bmsroot,
+                update_all,
+                false,
+                infodb
+                    .as_ref()
+                    .map(|db| db as &dyn crate::song_information_db::SongInformationDb),
+                &listener_clone,
+            );
+
+            log::info!("song.db update completed");
+            Ok(())
+        });
+
+        Self {
+            listener,
+            join_handle,
+        }
+    }
+
+    /// Progress counters of the running update.
+    pub fn state(&self) -> BmsLoadingState {
+        BmsLoadingState::Loading {
+            bms_files: self.listener.bms_files_count(),
+            processed_files: self.listener.processed_bms_files_count(),
+            new_files: self.listener.new_bms_files_count(),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.join_handle.is_finished()
+    }
+
+    /// Wait for the thread and convert its outcome into an error message.
+    pub fn join(self) -> Result<(), String> {
+        match self.join_handle.join() {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(format!("{}", e)),
+            Err(_panic) => Err("BMS loading thread panicked".to_string()),
+        }
+    }
+}
+
 /// PlayMode enum
 /// Translated from PlayConfigurationView.PlayMode
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -203,6 +277,7 @@ pub struct PlayConfigurationView {
 
     // Timing
     pub notesdisplaytiming: i32,
+    pub audiooffset: i32,
     pub notesdisplaytimingautoadjust: bool,
     pub bpmguide: bool,
     pub gaugeautoshift: Option<i32>,
@@ -210,6 +285,7 @@ pub struct PlayConfigurationView {
 
     // Custom judge
     pub customjudge: bool,
+    pub judgerule: Option<i32>,
     pub njudgepg: i32,
     pub njudgegr: i32,
     pub njudgegd: i32,
@@ -239,6 +315,7 @@ pub struct PlayConfigurationView {
     pub showpastnote: bool,
     pub target: Vec<String>,
     pub target_selected: Option<String>,
+    pub target_custom_rate: f32,
 
     // Judge algorithm
     pub judgealgorithm: Option<i32>,
@@ -248,6 +325,7 @@ pub struct PlayConfigurationView {
     pub autosavereplay2: Option<i32>,
     pub autosavereplay3: Option<i32>,
     pub autosavereplay4: Option<i32>,
+    pub replay_autosave_policy: Option<i32>,
 
     // CIM
     pub usecim: bool,
@@ -280,6 +358,8 @@ pub struct PlayConfigurationView {
     pub scrollmode_labels: Vec<String>,
     pub longnotemode_labels: Vec<String>,
     pub judgealgorithm_labels: Vec<String>,
+    pub judgerule_labels: Vec<String>,
+    pub replay_autosave_policy_labels: Vec<String>,
     pub autosave_labels: Vec<String>,
 
     // Sub-controllers
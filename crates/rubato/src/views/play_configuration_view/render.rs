@@ -231,6 +231,10 @@ impl PlayConfigurationView {
                 ui.checkbox(&mut self.notesdisplaytimingautoadjust, "");
                 ui.end_row();
 
+                ui.label("Audio Offset:");
+                ui.add(egui::DragValue::new(&mut self.audiooffset).range(-500..=500));
+                ui.end_row();
+
                 ui.label("BPM Guide:");
                 ui.checkbox(&mut self.bpmguide, "");
                 ui.end_row();
@@ -261,6 +265,15 @@ impl PlayConfigurationView {
                     &self.judgealgorithm_labels,
                 );
                 ui.end_row();
+
+                ui.label("Judge Window Table:");
+                Self::render_combo_i32(
+                    ui,
+                    "pcv_judgerule",
+                    &mut self.judgerule,
+                    &self.judgerule_labels,
+                );
+                ui.end_row();
             });
 
         ui.separator();
@@ -416,6 +429,14 @@ impl PlayConfigurationView {
                         });
                 }
                 ui.end_row();
+
+                ui.label("Custom Target Rate (%):");
+                ui.add(
+                    egui::DragValue::new(&mut self.target_custom_rate)
+                        .range(0.0..=100.0)
+                        .speed(0.1),
+                );
+                ui.end_row();
             });
 
         ui.separator();
@@ -425,6 +446,15 @@ impl PlayConfigurationView {
         egui::Grid::new("pcv_autosave_grid")
             .num_columns(2)
             .show(ui, |ui| {
+                ui.label("Policy:");
+                Self::render_combo_i32(
+                    ui,
+                    "pcv_autosave_policy",
+                    &mut self.replay_autosave_policy,
+                    &self.replay_autosave_policy_labels,
+                );
+                ui.end_row();
+
                 ui.label("Replay 1:");
                 Self::render_combo_i32(
                     ui,
@@ -28,7 +28,8 @@ fn test_initialize_sets_combo_box_labels() {
     assert_eq!(view.minemode_labels.len(), 5);
     assert_eq!(view.scrollmode_labels.len(), 3);
     assert_eq!(view.longnotemode_labels.len(), 6);
-    assert_eq!(view.judgealgorithm_labels.len(), 3);
+    assert_eq!(view.judgealgorithm_labels.len(), 4);
+    assert_eq!(view.judgerule_labels.len(), 2);
     assert_eq!(view.autosave_labels.len(), 11);
 }
 
@@ -1091,6 +1092,8 @@ fn test_commit_player_copies_back_music_select_controller_mutations() {
     let mut ms_player = view.player.as_ref().unwrap().clone();
     ms_player.select_settings.is_random_select = true;
     ms_player.play_settings.chart_replication_mode = "ALWAYS".to_string();
+    ms_player.select_settings.clear_filter = 2;
+    ms_player.select_settings.level_filter_max = 12;
     view.music_select_controller.update_player(&ms_player);
 
     view.commit_player();
@@ -1104,6 +1107,8 @@ fn test_commit_player_copies_back_music_select_controller_mutations() {
         committed.play_settings.chart_replication_mode, "ALWAYS",
         "chart_replication_mode should be copied back from music_select_controller"
     );
+    assert_eq!(committed.select_settings.clear_filter, 2);
+    assert_eq!(committed.select_settings.level_filter_max, 12);
 }
 
 /// Regression test: input controller mutations (play mode configs) must be
@@ -3,15 +3,20 @@
 
 use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::thread::JoinHandle;
 
 use log::error;
 
 use crate::core::config::{AVAILABLE_TABLEURL, Config};
+use crate::core::table_data::TableData;
 use crate::core::table_data_accessor::TableDataAccessor;
+use crate::skin::song_data::SongData;
+use crate::song::sqlite_song_database_accessor::SQLiteSongDatabaseAccessor;
+use crate::song_database_accessor::SongDatabaseAccessor;
 
 use crate::platform::show_directory_chooser;
+use crate::views::editors::table_editor_view::TableEditorView;
 use crate::views::play_configuration_view::PlayConfigurationView;
 
 /// TableInfo - inner data class for table URL entries
@@ -21,6 +26,8 @@ pub struct TableInfo {
     pub url: String,
     pub name_status: String,
     pub comment: String,
+    /// (owned, total) charts of the locally cached table, if known.
+    pub coverage: Option<(usize, usize)>,
 }
 
 impl TableInfo {
@@ -32,12 +39,14 @@ impl TableInfo {
                 url: url.to_string(),
                 name_status: name.clone(),
                 comment: comment.clone(),
+                coverage: None,
             }
         } else {
             TableInfo {
                 url: url.to_string(),
                 name_status: String::new(),
                 comment: String::new(),
+                coverage: None,
             }
         }
     }
@@ -46,6 +55,20 @@ impl TableInfo {
         self.name_status = name_status.to_string();
     }
 
+    /// Coverage column text, e.g. "12 / 340 (3.5%)".
+    pub fn coverage_label(&self) -> String {
+        match self.coverage {
+            Some((_, 0)) => "0 / 0".to_string(),
+            Some((owned, total)) => format!(
+                "{} / {} ({:.1}%)",
+                owned,
+                total,
+                owned as f64 * 100.0 / total as f64
+            ),
+            None => "-".to_string(),
+        }
+    }
+
     /// Translates: TableInfo.toUrlArray(List<TableInfo>)
     pub fn to_url_array(list: &[TableInfo]) -> Vec<String> {
         list.iter().map(|t| t.url.clone()).collect()
@@ -60,6 +83,35 @@ impl TableInfo {
     }
 }
 
+/// Count the distinct charts of `table` and how many of them are in `owned`.
+///
+/// Charts are matched by SHA-256 when the table provides it, otherwise by MD5.
+pub fn table_chart_coverage(table: &TableData, owned: &[SongData]) -> (usize, usize) {
+    let owned_hashes: HashSet<&str> = owned
+        .iter()
+        .flat_map(|song| [song.file.md5.as_str(), song.file.sha256.as_str()])
+        .filter(|hash| !hash.is_empty())
+        .collect();
+    let charts: HashSet<&str> = table
+        .folder
+        .iter()
+        .flat_map(|folder| folder.songs.iter())
+        .map(|song| {
+            if song.file.sha256.is_empty() {
+                song.file.md5.as_str()
+            } else {
+                song.file.sha256.as_str()
+            }
+        })
+        .filter(|hash| !hash.is_empty())
+        .collect();
+    let owned_count = charts
+        .iter()
+        .filter(|hash| owned_hashes.contains(*hash))
+        .count();
+    (owned_count, charts.len())
+}
+
 /// Static table of known table URLs with name and comment.
 /// Translates: ResourceConfigurationView.tableNameComment (Map.ofEntries(...))
 /// Unicode escapes are preserved from Java source.
@@ -422,6 +474,13 @@ pub struct ResourceConfigurationView {
 
     /// Background table loading thread handle
     table_load_handle: Option<JoinHandle<()>>,
+
+    /// Locally created tables (path, name), read with the local table info.
+    custom_tables: Vec<(PathBuf, String)>,
+    /// Name input for a new custom table.
+    custom_table_name: String,
+    /// Editor of the open custom table.
+    custom_table_editor: Option<TableEditorView>,
 }
 
 impl Default for ResourceConfigurationView {
@@ -445,6 +504,9 @@ impl ResourceConfigurationView {
             config: None,
             download_directory: String::new(),
             table_load_handle: None,
+            custom_tables: Vec::new(),
+            custom_table_name: String::new(),
+            custom_table_editor: None,
         }
     }
 
@@ -512,6 +574,11 @@ impl ResourceConfigurationView {
         }
     }
 
+    /// Config as last committed from this view.
+    pub fn config(&self) -> Option<&Config> {
+        self.config.as_ref()
+    }
+
     /// Translates: refreshLocalTableInfo()
     /// JavaFX progress bar and threading → simplified to synchronous call.
     pub fn refresh_local_table_info(&mut self) {
@@ -538,6 +605,92 @@ impl ResourceConfigurationView {
                 }
             }
         }
+        self.refresh_table_coverage();
+        self.refresh_custom_tables();
+    }
+
+    /// Re-read the list of locally created tables.
+    pub fn refresh_custom_tables(&mut self) {
+        if let Some(ref config) = self.config {
+            self.custom_tables = TableDataAccessor::new(&config.paths.tablepath).custom_tables();
+        }
+    }
+
+    /// Open a custom table in the editor. `new_name` names a table that has
+    /// not been saved yet.
+    fn open_custom_table(&mut self, path: &Path, new_name: Option<&str>) {
+        let Some(ref config) = self.config else {
+            return;
+        };
+        let mut editor = TableEditorView::new();
+        if Path::new(&config.paths.songpath).exists() {
+            let open = || {
+                SQLiteSongDatabaseAccessor::new(&config.paths.songpath, &config.paths.bmsroot)
+                    .map_err(|e| error!("failed to open song database: {}", e))
+                    .ok()
+            };
+            if let (Some(course_songdb), Some(folder_songdb)) = (open(), open()) {
+                editor.init(Box::new(course_songdb), Box::new(folder_songdb));
+            }
+        }
+        editor.update(path);
+        if let Some(name) = new_name {
+            editor.set_table_name(name);
+        }
+        self.custom_table_editor = Some(editor);
+    }
+
+    /// Create a custom table named after the name input.
+    pub fn create_custom_table(&mut self) {
+        let name = self.custom_table_name.trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+        let Some(ref config) = self.config else {
+            return;
+        };
+        let _ = fs::create_dir_all(&config.paths.tablepath);
+        let path = TableDataAccessor::new(&config.paths.tablepath).custom_table_path(&name);
+        self.open_custom_table(&path, Some(&name));
+        self.custom_table_name.clear();
+    }
+
+    /// Recompute how many charts of each locally cached table are in the song database.
+    pub fn refresh_table_coverage(&mut self) {
+        let Some(ref config) = self.config else {
+            return;
+        };
+        // Do not create an empty song database just to count charts
+        let songdb = if Path::new(&config.paths.songpath).exists() {
+            SQLiteSongDatabaseAccessor::new(&config.paths.songpath, &config.paths.bmsroot)
+                .map_err(|e| error!("failed to open song database: {}", e))
+                .ok()
+        } else {
+            None
+        };
+        let tda = TableDataAccessor::new(&config.paths.tablepath);
+        for table_info in &mut self.tableurl {
+            table_info.coverage = tda.read_cache(&table_info.url).map(|table| {
+                let hashes: Vec<String> = table
+                    .folder
+                    .iter()
+                    .flat_map(|folder| folder.songs.iter())
+                    .map(|song| {
+                        if song.file.sha256.is_empty() {
+                            song.file.md5.clone()
+                        } else {
+                            song.file.sha256.clone()
+                        }
+                    })
+                    .filter(|hash| !hash.is_empty())
+                    .collect();
+                let owned = songdb
+                    .as_ref()
+                    .map(|db| db.song_datas_by_hashes(&hashes))
+                    .unwrap_or_default();
+                table_chart_coverage(&table, &owned)
+            });
+        }
     }
 
     /// Poll for background table load completion. Call from the egui render
@@ -1009,6 +1162,14 @@ impl ResourceConfigurationView {
         // --- Update Song checkbox ---
         ui.checkbox(&mut self.updatesong, "Update songs on startup");
 
+        self.render_tables(ui);
+    }
+
+    /// Render the difficulty table management section (URL entry, ordering,
+    /// downloads and per-table chart coverage).
+    pub fn render_tables(&mut self, ui: &mut egui::Ui) {
+        self.poll_table_load();
+
         // --- Table URL management ---
         ui.separator();
         ui.label("Table URL:");
@@ -1069,10 +1230,11 @@ impl ResourceConfigurationView {
             .max_height(150.0)
             .show(ui, |ui| {
                 egui::Grid::new("tableurl_grid")
-                    .num_columns(3)
+                    .num_columns(4)
                     .striped(true)
                     .show(ui, |ui| {
                         ui.label("Name/Status");
+                        ui.label("Owned");
                         ui.label("Comment");
                         ui.label("URL");
                         ui.end_row();
@@ -1083,6 +1245,7 @@ impl ResourceConfigurationView {
                             if ui.selectable_label(selected, &info.name_status).clicked() {
                                 clicked_index = Some(i);
                             }
+                            ui.label(info.coverage_label());
                             ui.label(&info.comment);
                             ui.label(&info.url);
                             ui.end_row();
@@ -1149,6 +1312,48 @@ impl ResourceConfigurationView {
                         }
                     });
             });
+
+        self.render_custom_tables(ui);
+    }
+
+    /// Render the list of locally created tables and the editor of the open one.
+    /// Charts are dragged from the song search onto a level (folder); saved
+    /// tables show up in music select like downloaded ones.
+    fn render_custom_tables(&mut self, ui: &mut egui::Ui) {
+        ui.separator();
+        ui.label("Custom Tables:");
+
+        ui.horizontal(|ui| {
+            ui.text_edit_singleline(&mut self.custom_table_name);
+            if ui
+                .add_enabled(
+                    !self.custom_table_name.trim().is_empty(),
+                    egui::Button::new("New Table"),
+                )
+                .clicked()
+            {
+                self.create_custom_table();
+            }
+            if self.custom_table_editor.is_some() && ui.button("Close").clicked() {
+                self.custom_table_editor = None;
+                self.refresh_custom_tables();
+            }
+        });
+
+        let mut open: Option<PathBuf> = None;
+        for (path, name) in &self.custom_tables {
+            if ui.selectable_label(false, name).clicked() {
+                open = Some(path.clone());
+            }
+        }
+        if let Some(path) = open {
+            self.open_custom_table(&path, None);
+        }
+
+        if let Some(ref mut editor) = self.custom_table_editor {
+            ui.separator();
+            editor.render(ui);
+        }
     }
 }
 
@@ -1310,4 +1515,44 @@ mod tests {
         view.poll_table_load();
         assert!(view.table_load_handle.is_none());
     }
+
+    // -- table_chart_coverage --
+
+    fn song(md5: &str, sha256: &str) -> SongData {
+        let mut song = SongData::default();
+        song.file.md5 = md5.to_string();
+        song.file.sha256 = sha256.to_string();
+        song
+    }
+
+    #[test]
+    fn table_chart_coverage_counts_distinct_owned_charts() {
+        let table = TableData {
+            folder: vec![
+                crate::core::table_data::TableFolder {
+                    name: Some("1".to_string()),
+                    songs: vec![song("m1", "s1"), song("m2", "")],
+                },
+                crate::core::table_data::TableFolder {
+                    name: Some("2".to_string()),
+                    // Same chart listed twice and a chart the player does not own
+                    songs: vec![song("m1", "s1"), song("m3", "s3")],
+                },
+            ],
+            ..Default::default()
+        };
+        let owned = vec![song("m1", "s1"), song("m2", "s2")];
+
+        assert_eq!(table_chart_coverage(&table, &owned), (2, 3));
+    }
+
+    #[test]
+    fn table_info_coverage_label() {
+        let mut info = TableInfo::new("http://example.com/table.html");
+        assert_eq!(info.coverage_label(), "-");
+        info.coverage = Some((1, 8));
+        assert_eq!(info.coverage_label(), "1 / 8 (12.5%)");
+        info.coverage = Some((0, 0));
+        assert_eq!(info.coverage_label(), "0 / 0");
+    }
 }
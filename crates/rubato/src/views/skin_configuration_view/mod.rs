@@ -152,6 +152,26 @@ impl SkinConfigurationView {
         self.selected.as_ref()
     }
 
+    /// Preview image shipped next to a skin file, if any.
+    ///
+    /// Looks for `<skin file stem>.png`/`.jpg` first, then a `preview`,
+    /// `thumbnail` or `screenshot` image in the skin's directory.
+    pub fn preview_image_path(header: &SkinHeader) -> Option<PathBuf> {
+        const EXTENSIONS: [&str; 3] = ["png", "jpg", "jpeg"];
+        const NAMES: [&str; 3] = ["preview", "thumbnail", "screenshot"];
+        let path = header.path()?;
+        let dir = path.parent()?;
+        let stem = path.file_stem()?.to_string_lossy().into_owned();
+        std::iter::once(stem.as_str())
+            .chain(NAMES)
+            .flat_map(|name| {
+                EXTENSIONS
+                    .iter()
+                    .map(move |ext| dir.join(format!("{name}.{ext}")))
+            })
+            .find(|candidate| candidate.is_file())
+    }
+
     /// Translates: getProperty()
     /// Reads current UI state and returns a SkinProperty.
     pub fn property(&self) -> SkinProperty {
@@ -475,6 +495,7 @@ mod tests {
     use super::header_loader::convert_lr2_header_data;
     use super::*;
     use crate::skin::skin_header::CustomFile;
+    use crate::skin::skin_header::TYPE_BEATORJASKIN;
     use crate::skin::skin_header::TYPE_LR2SKIN;
 
     /// Helper to get the path to the test skin directory
@@ -817,4 +838,43 @@ mod tests {
             "default selection should resolve to the wildcard-matched file"
         );
     }
+
+    #[test]
+    fn preview_image_prefers_skin_named_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let skin_path = dir.path().join("play7.json");
+        std::fs::write(&skin_path, "{}").unwrap();
+        let mut header = SkinHeader::new();
+        header.set_path(skin_path);
+        assert_eq!(SkinConfigurationView::preview_image_path(&header), None);
+
+        std::fs::write(dir.path().join("preview.png"), b"").unwrap();
+        assert_eq!(
+            SkinConfigurationView::preview_image_path(&header),
+            Some(dir.path().join("preview.png"))
+        );
+        std::fs::write(dir.path().join("play7.jpg"), b"").unwrap();
+        assert_eq!(
+            SkinConfigurationView::preview_image_path(&header),
+            Some(dir.path().join("play7.jpg"))
+        );
+    }
+
+    #[test]
+    fn skin_metadata_lists_name_author_and_resolution() {
+        let mut header = SkinHeader::new();
+        header.set_name("Test Skin".to_string());
+        header.set_author("someone".to_string());
+        header.skin_type_id = TYPE_BEATORJASKIN;
+        let metadata = SkinConfigurationView::skin_metadata(&header);
+        assert!(metadata.contains(&("Name", "Test Skin".to_string())));
+        assert!(metadata.contains(&("Author", "someone".to_string())));
+        assert!(metadata.contains(&("Format", "beatoraja".to_string())));
+        header.resolution = crate::skin::skin_resolution::Resolution {
+            width: 1280.0,
+            height: 720.0,
+        };
+        let metadata = SkinConfigurationView::skin_metadata(&header);
+        assert!(metadata.contains(&("Resolution", "1280x720".to_string())));
+    }
 }
@@ -161,4 +161,41 @@ impl SkinConfigurationView {
             format!("{} (LR2 Skin)", name)
         }
     }
+
+    /// Label/value pairs describing a skin for the launcher's metadata panel.
+    pub fn skin_metadata(
+        header: &crate::skin::skin_header::SkinHeader,
+    ) -> Vec<(&'static str, String)> {
+        let format = if header.toast_type() == TYPE_BEATORJASKIN {
+            "beatoraja"
+        } else {
+            "LR2"
+        };
+        let resolution = header.resolution();
+        vec![
+            ("Name", header.name().unwrap_or("").to_string()),
+            ("Author", header.author().unwrap_or("-").to_string()),
+            ("Format", format.to_string()),
+            (
+                "Resolution",
+                format!("{}x{}", resolution.width, resolution.height),
+            ),
+            (
+                "Customization",
+                format!(
+                    "{} options, {} files, {} offsets",
+                    header.custom_options().len(),
+                    header.custom_files().len(),
+                    header.custom_offsets().len()
+                ),
+            ),
+            (
+                "Path",
+                header
+                    .path()
+                    .map(|p| p.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+            ),
+        ]
+    }
 }
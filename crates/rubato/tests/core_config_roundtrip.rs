@@ -33,6 +33,7 @@ fn make_custom_config() -> Config {
     Config {
         playername: Some("TestPlayer".to_string()),
         last_booted_version: "1.0.0".to_string(),
+        config_version: rubato::skin::config_migration::CONFIG_VERSION,
         audio: Some(audio),
         use_song_info: false,
         updatesong: true,
@@ -45,6 +46,8 @@ fn make_custom_config() -> Config {
             window_height: 1080,
             max_frame_per_second: 120,
             prepare_frame_per_second: 60,
+            input_poll_thread: true,
+            judge_thread: true,
         },
         paths: PathConfig {
             songpath: "custom_song.db".to_string(),
@@ -66,11 +69,13 @@ fn make_custom_config() -> Config {
         render: RenderConfig {
             bga: BGA_OFF,
             bga_expand: BGAEXPAND_FULL,
+            bga_dim: 30,
             frameskip: 2,
             skin_pixmap_gen: 8,
             stagefile_pixmap_gen: 4,
             banner_pixmap_gen: 4,
             song_resource_gen: 2,
+            texture_budget: 512,
         },
         network: NetworkConfig {
             enable_ipfs: false,
@@ -81,6 +86,13 @@ fn make_custom_config() -> Config {
             override_download_url: "https://override.example.com".to_string(),
             download_directory: "custom_downloads".to_string(),
             ir_send_count: 10,
+            max_concurrent_downloads: 2,
+            mirror_fallback: false,
+            download_placement: "{source}/{table}".to_string(),
+            sanitize_download_names: false,
+            download_speed_limit: 512,
+            download_only_when_idle: true,
+            check_updates: false,
         },
         obs: ObsConfig {
             use_obs_ws: true,
@@ -89,22 +101,46 @@ fn make_custom_config() -> Config {
             obs_ws_pass: "obspassword".to_string(),
             obs_ws_rec_stop_wait: 3000,
             obs_ws_rec_mode: 1,
+            obs_ws_rec_on_play: true,
+            obs_ws_replay_on_full_combo: true,
+            obs_ws_replay_on_new_lamp: true,
             obs_scenes,
             obs_actions,
         },
         integration: IntegrationConfig {
             use_discord_rpc: true,
+            discord_hide_song_details: true,
             set_clipboard_screenshot: true,
             monitor_name: "HDMI-1".to_string(),
             webhook_option: 2,
             webhook_name: "MyWebhook".to_string(),
             webhook_avatar: "https://example.com/avatar.png".to_string(),
             webhook_url: vec!["https://hook.example.com/1".to_string()],
+            webhook_only_personal_best: true,
+            webhook_only_clear: true,
+            webhook_min_rank: 5,
+            webhook_template: "webhook/embed.json".to_string(),
+            result_screenshot: true,
+            result_screenshot_dir: "shots/result".to_string(),
+            result_screenshot_share: true,
+            mastodon_instance: "mstdn.example".to_string(),
+            mastodon_access_token: "mastodon-token".to_string(),
+            misskey_instance: "misskey.example".to_string(),
+            misskey_access_token: "misskey-token".to_string(),
+            social_share_text: "{title} {clear}".to_string(),
+            overlay_server: true,
+            overlay_server_port: 8080,
+            state_push_server: true,
+            state_push_port: 8081,
+            now_playing_path: "obs/nowplaying.json".to_string(),
+            metrics_dump_path: "metrics.json".to_string(),
         },
         select: SelectConfig {
             folderlamp: false,
             max_search_bar_count: 20,
             skip_decide_screen: true,
+            decide_min_duration: 500,
+            decide_max_duration: 3000,
             show_no_song_existing_bar: false,
             scrolldurationlow: 500,
             scrolldurationhigh: 100,
@@ -112,6 +148,7 @@ fn make_custom_config() -> Config {
             analog_ticks_per_scroll: 5,
             song_preview: SongPreview::NONE,
             cache_skin_image: true,
+            watch_library: true,
         },
     }
 }
@@ -146,6 +183,11 @@ fn config_serialize_deserialize_roundtrip() {
         restored.display.prepare_frame_per_second,
         config.display.prepare_frame_per_second
     );
+    assert_eq!(
+        restored.display.input_poll_thread,
+        config.display.input_poll_thread
+    );
+    assert_eq!(restored.display.judge_thread, config.display.judge_thread);
     assert_eq!(
         restored.select.max_search_bar_count,
         config.select.max_search_bar_count
@@ -171,6 +213,7 @@ fn config_serialize_deserialize_roundtrip() {
         restored.select.analog_ticks_per_scroll,
         config.select.analog_ticks_per_scroll
     );
+    assert_eq!(restored.select.watch_library, config.select.watch_library);
     assert_eq!(
         restored.select.cache_skin_image,
         config.select.cache_skin_image
@@ -218,6 +261,7 @@ fn config_serialize_deserialize_roundtrip() {
         restored.render.song_resource_gen,
         config.render.song_resource_gen
     );
+    assert_eq!(restored.render.texture_budget, config.render.texture_budget);
     assert_eq!(restored.network.enable_ipfs, config.network.enable_ipfs);
     assert_eq!(restored.network.ipfsurl, config.network.ipfsurl);
     assert_eq!(restored.network.enable_http, config.network.enable_http);
@@ -238,10 +282,39 @@ fn config_serialize_deserialize_roundtrip() {
         config.network.download_directory
     );
     assert_eq!(restored.network.ir_send_count, config.network.ir_send_count);
+    assert_eq!(
+        restored.network.max_concurrent_downloads,
+        config.network.max_concurrent_downloads
+    );
+    assert_eq!(
+        restored.network.mirror_fallback,
+        config.network.mirror_fallback
+    );
+    assert_eq!(
+        restored.network.download_placement,
+        config.network.download_placement
+    );
+    assert_eq!(
+        restored.network.sanitize_download_names,
+        config.network.sanitize_download_names
+    );
+    assert_eq!(
+        restored.network.download_speed_limit,
+        config.network.download_speed_limit
+    );
+    assert_eq!(
+        restored.network.download_only_when_idle,
+        config.network.download_only_when_idle
+    );
+    assert_eq!(restored.network.check_updates, config.network.check_updates);
     assert_eq!(
         restored.integration.use_discord_rpc,
         config.integration.use_discord_rpc
     );
+    assert_eq!(
+        restored.integration.discord_hide_song_details,
+        config.integration.discord_hide_song_details
+    );
     assert_eq!(
         restored.integration.set_clipboard_screenshot,
         config.integration.set_clipboard_screenshot
@@ -264,6 +337,45 @@ fn config_serialize_deserialize_roundtrip() {
         restored.integration.webhook_avatar,
         config.integration.webhook_avatar
     );
+    assert!(restored.integration.webhook_only_personal_best);
+    assert!(restored.integration.webhook_only_clear);
+    assert_eq!(restored.integration.webhook_min_rank, 5);
+    assert_eq!(
+        restored.integration.webhook_template,
+        config.integration.webhook_template
+    );
+    assert!(restored.integration.result_screenshot);
+    assert_eq!(
+        restored.integration.result_screenshot_dir,
+        config.integration.result_screenshot_dir
+    );
+    assert!(restored.integration.result_screenshot_share);
+    assert_eq!(
+        restored.integration.mastodon_instance,
+        config.integration.mastodon_instance
+    );
+    assert_eq!(
+        restored.integration.mastodon_access_token,
+        config.integration.mastodon_access_token
+    );
+    assert_eq!(
+        restored.integration.misskey_instance,
+        config.integration.misskey_instance
+    );
+    assert_eq!(
+        restored.integration.misskey_access_token,
+        config.integration.misskey_access_token
+    );
+    assert_eq!(
+        restored.integration.social_share_text,
+        config.integration.social_share_text
+    );
+    assert!(restored.integration.overlay_server);
+    assert_eq!(restored.integration.overlay_server_port, 8080);
+    assert!(restored.integration.state_push_server);
+    assert_eq!(restored.integration.state_push_port, 8081);
+    assert_eq!(restored.integration.now_playing_path, "obs/nowplaying.json");
+    assert_eq!(restored.integration.metrics_dump_path, "metrics.json");
 
     // Verify OBS fields
     assert_eq!(restored.obs.use_obs_ws, config.obs.use_obs_ws);
@@ -275,6 +387,18 @@ fn config_serialize_deserialize_roundtrip() {
         config.obs.obs_ws_rec_stop_wait
     );
     assert_eq!(restored.obs.obs_ws_rec_mode, config.obs.obs_ws_rec_mode);
+    assert_eq!(
+        restored.obs.obs_ws_rec_on_play,
+        config.obs.obs_ws_rec_on_play
+    );
+    assert_eq!(
+        restored.obs.obs_ws_replay_on_full_combo,
+        config.obs.obs_ws_replay_on_full_combo
+    );
+    assert_eq!(
+        restored.obs.obs_ws_replay_on_new_lamp,
+        config.obs.obs_ws_replay_on_new_lamp
+    );
     assert_eq!(restored.obs.obs_scenes, config.obs.obs_scenes);
     assert_eq!(restored.obs.obs_actions, config.obs.obs_actions);
 
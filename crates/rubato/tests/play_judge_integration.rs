@@ -163,7 +163,7 @@ fn judge_manager_from_config_initializes_correctly() {
         notes: &judge_notes,
         mode: &mode,
         ln_type: model.lntype(),
-        judge_rank: model.judgerank,
+        judge_rank: model.judgerank_exact(),
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -207,7 +207,7 @@ fn judge_manager_autoplay_judges_all_pgreat() {
         notes: &judge_notes,
         mode: &mode,
         ln_type: model.lntype(),
-        judge_rank: model.judgerank,
+        judge_rank: model.judgerank_exact(),
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -360,7 +360,7 @@ fn manual_input_at_note_time_produces_pgreat() {
         notes: &judge_notes,
         mode: &mode,
         ln_type: model.lntype(),
-        judge_rank: model.judgerank,
+        judge_rank: model.judgerank_exact(),
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -421,7 +421,7 @@ fn no_input_produces_all_miss() {
         notes: &judge_notes,
         mode: &mode,
         ln_type: model.lntype(),
-        judge_rank: model.judgerank,
+        judge_rank: model.judgerank_exact(),
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -86,6 +86,8 @@ fn gauge_update_judge_index_oob_is_ignored() {
         border: 0.0,
         death: 0.0,
         guts: vec![],
+        hcn_recovery: 0.5,
+        hcn_drain: 0.5,
     };
     let mut gauge = Gauge::new(&model, element, ClearType::Hard);
     let initial = gauge.value();
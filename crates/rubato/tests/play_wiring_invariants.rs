@@ -57,7 +57,7 @@ fn make_judge_manager(model: &BMSModel) -> (JudgeManager, rubato::play::groove_g
         notes: &judge_notes,
         mode: &mode,
         ln_type: model.lntype(),
-        judge_rank: model.judgerank,
+        judge_rank: model.judgerank_exact(),
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -407,7 +407,7 @@ fn multiple_simultaneous_judgments_produce_all_lanes() {
         notes: &judge_notes,
         mode: &mode,
         ln_type: model.lntype(),
-        judge_rank: model.judgerank,
+        judge_rank: model.judgerank_exact(),
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -0,0 +1,64 @@
+// Exports java.util.Random outputs for golden-master/fixtures/java_random.json.
+//
+// Run with `just golden-master-java-random-gen` (JDK 11+ single-file launch).
+
+import java.util.Random;
+
+public class JavaRandomExport {
+    static final long[] SEEDS = {
+        0L, 1L, -1L, 42L, 12345L, 1234567890123L, -987654321L,
+        Long.MAX_VALUE, Long.MIN_VALUE, 0x5DEECE66DL, 281474976710655L,
+    };
+    static final int[] BOUNDS = {1, 2, 3, 5, 6, 7, 8, 9, 10, 14, 16, 100, 1 << 30, Integer.MAX_VALUE};
+    static final int[] DESCENDING = {5, 7, 9, 14};
+    static final int COUNT = 20;
+
+    public static void main(String[] args) {
+        StringBuilder sb = new StringBuilder();
+        sb.append("{\n  \"test_cases\": [\n");
+        for (int s = 0; s < SEEDS.length; s++) {
+            long seed = SEEDS[s];
+            sb.append("    {\n      \"seed\": ").append(seed).append(",\n");
+
+            sb.append("      \"next_int\": [\n");
+            for (int b = 0; b < BOUNDS.length; b++) {
+                Random rand = new Random(seed);
+                int[] values = new int[COUNT];
+                for (int i = 0; i < COUNT; i++) {
+                    values[i] = rand.nextInt(BOUNDS[b]);
+                }
+                appendInts(sb, BOUNDS[b], values, b + 1 < BOUNDS.length);
+            }
+            sb.append("      ],\n");
+
+            // Shrinking bounds as used when lanes are drawn from a shrinking list
+            sb.append("      \"descending\": [\n");
+            for (int d = 0; d < DESCENDING.length; d++) {
+                Random rand = new Random(seed);
+                int[] values = new int[DESCENDING[d]];
+                for (int i = 0; i < values.length; i++) {
+                    values[i] = rand.nextInt(DESCENDING[d] - i);
+                }
+                appendInts(sb, DESCENDING[d], values, d + 1 < DESCENDING.length);
+            }
+            sb.append("      ],\n");
+
+            Random rand = new Random(seed);
+            sb.append("      \"next_double\": [");
+            for (int i = 0; i < COUNT; i++) {
+                sb.append(i == 0 ? "" : ", ").append(rand.nextDouble());
+            }
+            sb.append("]\n    }").append(s + 1 < SEEDS.length ? ",\n" : "\n");
+        }
+        sb.append("  ]\n}");
+        System.out.println(sb);
+    }
+
+    static void appendInts(StringBuilder sb, int bound, int[] values, boolean more) {
+        sb.append("        { \"bound\": ").append(bound).append(", \"values\": [");
+        for (int i = 0; i < values.length; i++) {
+            sb.append(i == 0 ? "" : ", ").append(values[i]);
+        }
+        sb.append("] }").append(more ? ",\n" : "\n");
+    }
+}
@@ -1,6 +1,7 @@
 // Audio golden master fixture deserialization structures
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// Root fixture for audio test cases
 #[derive(Debug, Deserialize)]
@@ -33,3 +34,133 @@ pub fn load_audio_fixture(path: &std::path::Path) -> anyhow::Result<AudioFixture
     serde_json::from_str(&content)
         .map_err(|e| anyhow::anyhow!("Failed to parse audio fixture: {}: {}", path.display(), e))
 }
+
+// =========================================================================
+// Chart mixdown
+// =========================================================================
+
+/// Frequencies (Hz) whose energy is tracked per fingerprint window
+pub const FINGERPRINT_BANDS: [f64; 6] = [110.0, 220.0, 440.0, 880.0, 1760.0, 3520.0];
+
+/// Root fixture for offline chart mixdowns (BMSRenderer autoplay of every note)
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AudioRenderFixture {
+    pub test_cases: Vec<AudioRenderTestCase>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AudioRenderTestCase {
+    /// Chart path relative to test-bms/
+    pub filename: String,
+    pub sample_rate: i32,
+    pub channels: i32,
+    pub duration_ms: i64,
+    pub fingerprint: AudioFingerprint,
+}
+
+/// Hash and coarse spectrum of interleaved 16-bit PCM.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AudioFingerprint {
+    pub sample_count: usize,
+    /// SHA-256 of the little-endian samples, for bit-exact matches
+    pub sha256: String,
+    pub window_ms: u32,
+    /// Per window: RMS of the mono downmix, normalized to [0, 1]
+    pub rms: Vec<f64>,
+    /// Per window: magnitude at each of `FINGERPRINT_BANDS`, normalized to [0, 1]
+    pub bands: Vec<Vec<f64>>,
+}
+
+impl AudioFingerprint {
+    pub fn new(samples: &[i16], channels: usize, sample_rate: u32, window_ms: u32) -> Self {
+        let mut hasher = Sha256::new();
+        for s in samples {
+            hasher.update(s.to_le_bytes());
+        }
+        let sha256 = hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+
+        let mono: Vec<f64> = samples
+            .chunks(channels.max(1))
+            .map(|frame| {
+                frame.iter().map(|&s| s as f64).sum::<f64>() / frame.len() as f64 / 32768.0
+            })
+            .collect();
+        let window = (sample_rate as usize * window_ms as usize / 1000).max(1);
+        let mut rms = Vec::new();
+        let mut bands = Vec::new();
+        for chunk in mono.chunks(window) {
+            rms.push((chunk.iter().map(|s| s * s).sum::<f64>() / chunk.len() as f64).sqrt());
+            bands.push(
+                FINGERPRINT_BANDS
+                    .iter()
+                    .map(|&freq| goertzel(chunk, freq, sample_rate as f64))
+                    .collect(),
+            );
+        }
+        Self {
+            sample_count: samples.len(),
+            sha256,
+            window_ms,
+            rms,
+            bands,
+        }
+    }
+
+    /// Differences beyond `tolerance` in any window value, or in the window layout.
+    pub fn diff(&self, expected: &AudioFingerprint, tolerance: f64) -> Vec<String> {
+        let mut diffs = Vec::new();
+        if self.sample_count != expected.sample_count || self.window_ms != expected.window_ms {
+            diffs.push(format!(
+                "layout: samples {} vs {}, window {}ms vs {}ms",
+                self.sample_count, expected.sample_count, self.window_ms, expected.window_ms
+            ));
+            return diffs;
+        }
+        for (w, (actual, expected)) in self.rms.iter().zip(&expected.rms).enumerate() {
+            if (actual - expected).abs() > tolerance {
+                diffs.push(format!(
+                    "window[{w}] rms: actual={actual} expected={expected}"
+                ));
+            }
+        }
+        for (w, (actual, expected)) in self.bands.iter().zip(&expected.bands).enumerate() {
+            for (b, (a, e)) in actual.iter().zip(expected).enumerate() {
+                if (a - e).abs() > tolerance {
+                    diffs.push(format!(
+                        "window[{w}] {}Hz: actual={a} expected={e}",
+                        FINGERPRINT_BANDS[b]
+                    ));
+                }
+            }
+        }
+        diffs
+    }
+}
+
+/// Magnitude of `freq` in `samples`, scaled so a full-scale sine reads 1.0.
+fn goertzel(samples: &[f64], freq: f64, sample_rate: f64) -> f64 {
+    let coeff = 2.0 * (2.0 * std::f64::consts::PI * freq / sample_rate).cos();
+    let (mut s1, mut s2) = (0.0, 0.0);
+    for &x in samples {
+        let s0 = x + coeff * s1 - s2;
+        s2 = s1;
+        s1 = s0;
+    }
+    let power = (s1 * s1 + s2 * s2 - coeff * s1 * s2).max(0.0);
+    2.0 * power.sqrt() / samples.len() as f64
+}
+
+impl AudioRenderFixture {
+    pub fn path() -> std::path::PathBuf {
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/audio_render.json")
+    }
+
+    pub fn load() -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(Self::path())?;
+        Ok(serde_json::from_str(&content)?)
+    }
+}
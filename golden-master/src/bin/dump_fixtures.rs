@@ -0,0 +1,132 @@
+// Dump Rust-side golden master fixtures in the Java fixture schema.
+//
+//   cargo run -p golden-master --bin dump-fixtures -- model test-bms/minimal_7k.bms
+//   cargo run -p golden-master --bin dump-fixtures -- judge-windows -o /tmp/judge_windows.json
+//
+// Rule and pattern dumps read their inputs from a fixture (the committed Java
+// one by default) and replace the outputs, so the two files can be diffed
+// directly or a hand-written input list turned into a new fixture.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use bms::model::bms_decoder::BMSDecoder;
+use bms::model::bms_model::{BMSModel, LNTYPE_LONGNOTE};
+use bms::model::bmson_decoder::BMSONDecoder;
+use bms::model::chart_information::ChartInformation;
+use clap::{Parser, Subcommand};
+use golden_master::rust_dump;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "dump-fixtures",
+    about = "Dump brs output in the golden master fixture schema"
+)]
+struct Args {
+    /// Write to this file instead of stdout
+    #[arg(short = 'o', long, global = true)]
+    output: Option<PathBuf>,
+
+    #[command(subcommand)]
+    kind: Kind,
+}
+
+#[derive(Subcommand, Debug)]
+enum Kind {
+    /// Decoded chart (<chart>.json schema)
+    Model {
+        /// BMS, PMS or bmson file
+        chart: PathBuf,
+        /// Selected #RANDOM values, in order of appearance
+        #[arg(long, value_delimiter = ',')]
+        randoms: Option<Vec<i32>>,
+    },
+    /// Judge windows (judge_windows.json schema)
+    JudgeWindows {
+        #[arg(default_value = "judge_windows.json")]
+        input: PathBuf,
+    },
+    /// Gauge properties (gauge_properties.json schema)
+    GaugeProperties {
+        #[arg(default_value = "gauge_properties.json")]
+        input: PathBuf,
+    },
+    /// Gauge values after each judgment (gauge_sequences.json schema)
+    GaugeSequences {
+        #[arg(default_value = "gauge_sequences.json")]
+        input: PathBuf,
+    },
+    /// Lane shuffle mappings (pattern_lane_shuffle.json schema)
+    LaneShuffle {
+        #[arg(default_value = "pattern_lane_shuffle.json")]
+        input: PathBuf,
+    },
+}
+
+/// Bare file names are looked up in golden-master/fixtures.
+fn resolve_input(input: &Path) -> PathBuf {
+    if input.components().count() == 1 && !input.exists() {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures")
+            .join(input)
+    } else {
+        input.to_path_buf()
+    }
+}
+
+fn read_input<T: serde::de::DeserializeOwned>(input: &Path) -> anyhow::Result<T> {
+    let path = resolve_input(input);
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn decode_chart(chart: &Path, randoms: Option<Vec<i32>>) -> anyhow::Result<BMSModel> {
+    let is_bmson = chart
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("bmson"));
+    let model = if is_bmson {
+        BMSONDecoder::new(LNTYPE_LONGNOTE).decode_path(chart)
+    } else {
+        let info = ChartInformation::new(Some(chart.to_path_buf()), LNTYPE_LONGNOTE, randoms);
+        BMSDecoder::new().decode(info)
+    };
+    model.with_context(|| format!("Failed to decode {}", chart.display()))
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let json = match args.kind {
+        Kind::Model { chart, randoms } => serde_json::to_string_pretty(&rust_dump::model_fixture(
+            &decode_chart(&chart, randoms)?,
+        ))?,
+        Kind::JudgeWindows { input } => {
+            let mut fixture = read_input(&input)?;
+            rust_dump::judge_windows(&mut fixture)?;
+            serde_json::to_string_pretty(&fixture)?
+        }
+        Kind::GaugeProperties { input } => {
+            let mut fixture = read_input(&input)?;
+            rust_dump::gauge_properties(&mut fixture)?;
+            serde_json::to_string_pretty(&fixture)?
+        }
+        Kind::GaugeSequences { input } => {
+            let mut fixture = read_input(&input)?;
+            rust_dump::gauge_sequences(&mut fixture)?;
+            serde_json::to_string_pretty(&fixture)?
+        }
+        Kind::LaneShuffle { input } => {
+            let mut fixture = read_input(&input)?;
+            rust_dump::lane_shuffle(&mut fixture)?;
+            serde_json::to_string_pretty(&fixture)?
+        }
+    };
+    match args.output {
+        Some(path) => std::fs::write(&path, json + "\n")
+            .with_context(|| format!("Failed to write {}", path.display())),
+        None => {
+            println!("{json}");
+            Ok(())
+        }
+    }
+}
@@ -67,13 +67,14 @@ pub fn count_normal_notes(notes: &[JudgeNote]) -> usize {
 pub fn run_autoplay_simulation(model: &BMSModel, gauge_type: i32) -> SimulationResult {
     let judge_notes = model.build_judge_notes();
     let mode = model.mode().cloned().unwrap_or(Mode::BEAT_7K);
-    let rule = BMSPlayerRule::for_mode(&mode);
+    // Fixtures were captured with the LR2 rule applied to every mode, PMS included.
+    let rule = BMSPlayerRule::lr2();
 
     let config = JudgeConfig {
         notes: &judge_notes,
         mode: &mode,
         ln_type: model.lntype(),
-        judge_rank: model.judgerank,
+        judge_rank: model.judgerank_exact(),
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -159,13 +160,14 @@ pub fn run_manual_simulation(
 ) -> SimulationResult {
     let judge_notes = model.build_judge_notes();
     let mode = model.mode().cloned().unwrap_or(Mode::BEAT_7K);
-    let rule = BMSPlayerRule::for_mode(&mode);
+    // Fixtures were captured with the LR2 rule applied to every mode, PMS included.
+    let rule = BMSPlayerRule::lr2();
 
     let config = JudgeConfig {
         notes: &judge_notes,
         mode: &mode,
         ln_type: model.lntype(),
-        judge_rank: model.judgerank,
+        judge_rank: model.judgerank_exact(),
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
@@ -266,13 +268,14 @@ pub fn run_course_simulation(models: &[&BMSModel], gauge_type: i32) -> CourseSim
 
         let judge_notes = model.build_judge_notes();
         let mode = model.mode().cloned().unwrap_or(Mode::BEAT_7K);
-        let rule = BMSPlayerRule::for_mode(&mode);
+        // Fixtures were captured with the LR2 rule applied to every mode, PMS included.
+        let rule = BMSPlayerRule::lr2();
 
         let config = JudgeConfig {
             notes: &judge_notes,
             mode: &mode,
             ln_type: model.lntype(),
-            judge_rank: model.judgerank,
+            judge_rank: model.judgerank_exact(),
             judge_window_rate: [100, 100, 100],
             scratch_judge_window_rate: [100, 100, 100],
             algorithm: JudgeAlgorithm::Combo,
@@ -365,13 +368,14 @@ pub fn run_course_simulation_manual(
 
         let judge_notes = model.build_judge_notes();
         let mode = model.mode().cloned().unwrap_or(Mode::BEAT_7K);
-        let rule = BMSPlayerRule::for_mode(&mode);
+        // Fixtures were captured with the LR2 rule applied to every mode, PMS included.
+        let rule = BMSPlayerRule::lr2();
 
         let config = JudgeConfig {
             notes: &judge_notes,
             mode: &mode,
             ln_type: model.lntype(),
-            judge_rank: model.judgerank,
+            judge_rank: model.judgerank_exact(),
             judge_window_rate: [100, 100, 100],
             scratch_judge_window_rate: [100, 100, 100],
             algorithm: JudgeAlgorithm::Combo,
@@ -1,6 +1,6 @@
 // Fixture types for JudgeManager golden master tests
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize)]
 pub struct JudgeFixtures {
@@ -56,6 +56,18 @@ pub struct ExpectedScore {
     pub passnotes: i32,
 }
 
+impl ExpectedScore {
+    /// FAST count as shown in play: GREAT to MISS hit early.
+    pub fn fast(&self) -> i32 {
+        self.egr + self.egd + self.ebd + self.epr + self.ems
+    }
+
+    /// SLOW count as shown in play: GREAT to MISS hit late.
+    pub fn slow(&self) -> i32 {
+        self.lgr + self.lgd + self.lbd + self.lpr + self.lms
+    }
+}
+
 impl JudgeFixtures {
     pub fn load() -> anyhow::Result<Self> {
         let path =
@@ -65,3 +77,44 @@ impl JudgeFixtures {
         Ok(fixtures)
     }
 }
+
+/// Judge fixtures replaying recorded key logs in replay form.
+///
+/// Each case carries the key log as a replay `keyinput` string and the Java
+/// judge results for it. Built from the manual cases of judge_manager.json.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct JudgeKeylogFixtures {
+    pub test_cases: Vec<JudgeKeylogCase>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct JudgeKeylogCase {
+    pub group: String,
+    pub name: String,
+    pub filename: String,
+    pub gauge_type: String,
+    /// Compressed key log as stored in replay files, empty when no key was pressed
+    pub keyinput: String,
+    pub expected: ExpectedJudgeLog,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ExpectedJudgeLog {
+    /// Judge of each note in chart order (0=PG .. 4=POOR/MISS)
+    pub judgments: Vec<usize>,
+    pub fast: i32,
+    pub slow: i32,
+    pub maxcombo: i32,
+}
+
+impl JudgeKeylogFixtures {
+    pub fn path() -> std::path::PathBuf {
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/judge_keylog.json")
+    }
+
+    pub fn load() -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(Self::path())?;
+        let fixtures: JudgeKeylogFixtures = serde_json::from_str(&content)?;
+        Ok(fixtures)
+    }
+}
@@ -13,6 +13,7 @@ pub mod pattern_modifier_detail_fixtures;
 pub mod render_snapshot;
 pub mod replay_e2e_fixtures;
 pub mod rule_fixtures;
+pub mod rust_dump;
 pub mod score_data_property_fixtures;
 pub mod skin_fixtures;
 pub mod song_information_fixtures;
@@ -21,14 +22,14 @@ pub mod state_provider;
 use std::path::Path;
 
 use anyhow::{Context, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use bms::model::bms_model::BMSModel;
 use bms::model::mode::Mode;
 use bms::model::note::Note;
 
 /// Java fixture root structure
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Fixture {
     pub metadata: FixtureMetadata,
     pub hashes: FixtureHashes,
@@ -40,7 +41,7 @@ pub struct Fixture {
     pub stop_events: Vec<FixtureStopEvent>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct FixtureMetadata {
     pub title: String,
     pub subtitle: String,
@@ -60,13 +61,13 @@ pub struct FixtureMetadata {
     pub preview: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct FixtureHashes {
     pub md5: String,
     pub sha256: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct FixtureStatistics {
     pub total_notes: usize,
     pub total_notes_mine: usize,
@@ -75,7 +76,7 @@ pub struct FixtureStatistics {
     pub timeline_count: usize,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct FixtureTimeline {
     pub time_us: i64,
     pub bpm: f64,
@@ -86,7 +87,7 @@ pub struct FixtureTimeline {
     pub hidden_notes: Vec<FixtureNote>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct FixtureNote {
     pub lane: usize,
     pub time_us: i64,
@@ -94,19 +95,22 @@ pub struct FixtureNote {
     pub wav_id: i32,
     #[serde(rename = "type")]
     pub note_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub end_time_us: Option<i64>,
     /// May be -2 for undefined in Java
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub end_wav_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub damage: Option<f64>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct FixtureBpmChange {
     pub time_us: i64,
     pub bpm: f64,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct FixtureStopEvent {
     pub time_us: i64,
     pub duration_us: i64,
@@ -127,17 +131,17 @@ pub fn mode_hint_to_mode(hint: &str) -> Option<Mode> {
 
 /// Collect all playable notes (non-LN-end) from the model as flat list,
 /// sorted by (time_us, lane).
-struct FlatNote {
-    lane: usize,
-    time_us: i64,
-    note_type_str: String,
-    wav_id: i32,
-    end_time_us: i64,
-    end_wav_id: i32,
-    damage: f64,
+pub(crate) struct FlatNote {
+    pub(crate) lane: usize,
+    pub(crate) time_us: i64,
+    pub(crate) note_type_str: String,
+    pub(crate) wav_id: i32,
+    pub(crate) end_time_us: i64,
+    pub(crate) end_wav_id: i32,
+    pub(crate) damage: f64,
 }
 
-fn flatten_notes(model: &BMSModel) -> Vec<FlatNote> {
+pub(crate) fn flatten_notes(model: &BMSModel) -> Vec<FlatNote> {
     let keys = model.mode().map(|m| m.key()).unwrap_or(0);
     let timelines = &model.timelines;
     let mut flat = Vec::new();
@@ -152,16 +156,7 @@ fn flatten_notes(model: &BMSModel) -> Vec<FlatNote> {
                 if note.is_end() {
                     continue;
                 }
-                let note_type_str = match note {
-                    Note::Normal(_) => "Normal".to_string(),
-                    Note::Long { note_type, .. } => match *note_type {
-                        bms::model::note::TYPE_LONGNOTE => "LongNote".to_string(),
-                        bms::model::note::TYPE_CHARGENOTE => "ChargeNote".to_string(),
-                        bms::model::note::TYPE_HELLCHARGENOTE => "HellChargeNote".to_string(),
-                        _ => "LongNoteUndefined".to_string(),
-                    },
-                    Note::Mine { .. } => "Mine".to_string(),
-                };
+                let note_type_str = note_type_name(note).to_string();
 
                 // For LN notes, find the paired end note by scanning forward
                 let (end_time_us, end_wav_id) = if note.is_long() {
@@ -200,9 +195,23 @@ fn flatten_notes(model: &BMSModel) -> Vec<FlatNote> {
     flat
 }
 
+/// Fixture "type" of a playable note (LN ends are named by the caller).
+pub(crate) fn note_type_name(note: &Note) -> &'static str {
+    match note {
+        Note::Normal(_) => "Normal",
+        Note::Long { note_type, .. } => match *note_type {
+            bms::model::note::TYPE_LONGNOTE => "LongNote",
+            bms::model::note::TYPE_CHARGENOTE => "ChargeNote",
+            bms::model::note::TYPE_HELLCHARGENOTE => "HellChargeNote",
+            _ => "LongNoteUndefined",
+        },
+        Note::Mine { .. } => "Mine",
+    }
+}
+
 /// Find the end time and wav of the paired LN-end note for a LN-start note.
 /// Scans forward from the start timeline index on the same lane.
-fn find_ln_end_time(
+pub(crate) fn find_ln_end_time(
     timelines: &[bms::model::time_line::TimeLine],
     start_tl_idx: usize,
     lane: i32,
@@ -220,7 +229,7 @@ fn find_ln_end_time(
 }
 
 /// Count mine notes in the model.
-fn count_mines(model: &BMSModel) -> usize {
+pub(crate) fn count_mines(model: &BMSModel) -> usize {
     let keys = model.mode().map(|m| m.key()).unwrap_or(0);
     let mut count = 0;
     for tl in &model.timelines {
@@ -235,6 +244,30 @@ fn count_mines(model: &BMSModel) -> usize {
     count
 }
 
+/// (time_us, bpm) of every timeline where the BPM changes.
+pub(crate) fn bpm_changes(model: &BMSModel) -> Vec<(i64, f64)> {
+    let mut changes = Vec::new();
+    let mut prev_bpm = model.bpm;
+    for tl in &model.timelines {
+        let bpm = tl.bpm;
+        if (bpm - prev_bpm).abs() > 0.0001 {
+            changes.push((tl.micro_time(), bpm));
+            prev_bpm = bpm;
+        }
+    }
+    changes
+}
+
+/// (time_us, duration_us) of every timeline with a stop.
+pub(crate) fn stop_events(model: &BMSModel) -> Vec<(i64, i64)> {
+    model
+        .timelines
+        .iter()
+        .filter(|tl| tl.micro_stop() > 0)
+        .map(|tl| (tl.micro_time(), tl.micro_stop()))
+        .collect()
+}
+
 /// Compare a Rust BMSModel against a Java fixture.
 /// Returns a list of differences found.
 pub fn compare_model(model: &BMSModel, fixture: &Fixture) -> Vec<String> {
@@ -500,16 +533,7 @@ pub fn compare_model(model: &BMSModel, fixture: &Fixture) -> Vec<String> {
 
     // BPM changes
     {
-        // Extract BPM changes from timelines
-        let mut rust_bpm_changes = Vec::new();
-        let mut prev_bpm = model.bpm;
-        for tl in &model.timelines {
-            let bpm = tl.bpm;
-            if (bpm - prev_bpm).abs() > 0.0001 {
-                rust_bpm_changes.push((tl.micro_time(), bpm));
-                prev_bpm = bpm;
-            }
-        }
+        let rust_bpm_changes = bpm_changes(model);
 
         if rust_bpm_changes.len() != fixture.bpm_changes.len() {
             diffs.push(format!(
@@ -541,14 +565,7 @@ pub fn compare_model(model: &BMSModel, fixture: &Fixture) -> Vec<String> {
 
     // Stop events
     {
-        // Extract stop events from timelines
-        let mut rust_stops = Vec::new();
-        for tl in &model.timelines {
-            let stop = tl.micro_stop();
-            if stop > 0 {
-                rust_stops.push((tl.micro_time(), stop));
-            }
-        }
+        let rust_stops = stop_events(model);
 
         if rust_stops.len() != fixture.stop_events.len() {
             diffs.push(format!(
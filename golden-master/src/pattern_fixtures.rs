@@ -1,17 +1,17 @@
 // Fixture types for Phase 3 pattern shuffle golden master testing
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 // =========================================================================
 // Lane Shuffle Mappings
 // =========================================================================
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct LaneShuffleFixture {
     pub test_cases: Vec<LaneShuffleTestCase>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct LaneShuffleTestCase {
     pub modifier_type: String,
     pub mode: String,
@@ -23,6 +23,32 @@ pub struct LaneShuffleTestCase {
     pub mapping: Vec<usize>,
 }
 
+// =========================================================================
+// java.util.Random
+// =========================================================================
+
+/// Outputs of `java.util.Random`, exported by java/JavaRandomExport.java.
+#[derive(Debug, Deserialize)]
+pub struct JavaRandomFixture {
+    pub test_cases: Vec<JavaRandomTestCase>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JavaRandomTestCase {
+    pub seed: i64,
+    /// `nextInt(bound)` repeated with the same bound from a fresh `Random(seed)`
+    pub next_int: Vec<BoundedInts>,
+    /// `nextInt(bound)`, `nextInt(bound - 1)`, ..., `nextInt(1)` from a fresh `Random(seed)`
+    pub descending: Vec<BoundedInts>,
+    pub next_double: Vec<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BoundedInts {
+    pub bound: i32,
+    pub values: Vec<i32>,
+}
+
 // =========================================================================
 // Playable Random
 // =========================================================================
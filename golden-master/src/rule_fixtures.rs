@@ -1,17 +1,17 @@
 // Fixture types for Phase 2 rule engine golden master testing
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 // =========================================================================
 // Judge Windows
 // =========================================================================
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct JudgeWindowFixture {
     pub test_cases: Vec<JudgeWindowTestCase>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct JudgeWindowTestCase {
     pub mode: String,
     pub note_type: String,
@@ -24,12 +24,12 @@ pub struct JudgeWindowTestCase {
 // Gauge Properties
 // =========================================================================
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct GaugePropertyFixture {
     pub test_cases: Vec<GaugePropertyTestCase>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct GaugePropertyTestCase {
     pub mode: String,
     pub gauge_type: String,
@@ -46,7 +46,7 @@ pub struct GaugePropertyTestCase {
     pub guts: Vec<GutsFixtureEntry>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct GutsFixtureEntry {
     pub threshold: f32,
     pub multiplier: f32,
@@ -56,12 +56,12 @@ pub struct GutsFixtureEntry {
 // Gauge Sequences
 // =========================================================================
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct GaugeSequenceFixture {
     pub test_cases: Vec<GaugeSequenceTestCase>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct GaugeSequenceTestCase {
     pub mode: String,
     pub sequence_name: String,
@@ -71,8 +71,37 @@ pub struct GaugeSequenceTestCase {
     pub values_after_each_step: Vec<Vec<f32>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SequenceStep {
     pub judge: usize,
     pub rate_x100: i32,
 }
+
+// =========================================================================
+// Gauge Deltas
+// =========================================================================
+
+/// Per-judgment gauge deltas of the Java gauge sequences, for every gauge type.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GaugeDeltaFixture {
+    pub test_cases: Vec<GaugeDeltaTestCase>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GaugeDeltaTestCase {
+    pub mode: String,
+    pub sequence_name: String,
+    pub total: f64,
+    pub total_notes: usize,
+    pub sequence: Vec<SequenceStep>,
+    /// Initial value of each gauge type
+    pub init: Vec<f32>,
+    /// [step][gauge type] change caused by that step's judgment
+    pub deltas: Vec<Vec<f32>>,
+}
+
+impl GaugeDeltaFixture {
+    pub fn path() -> std::path::PathBuf {
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/gauge_deltas.json")
+    }
+}
@@ -0,0 +1,323 @@
+// Rust-side fixture dumps in the Java fixture schema.
+//
+// Charts are dumped from scratch. Rule and pattern fixtures keep the inputs
+// of an existing fixture (or a hand-written one with empty outputs) and
+// replace every output with what brs computes, so the result can be diffed
+// against the Java export or committed as a new fixture.
+
+use bms::model::bms_model::BMSModel;
+use bms::model::mode::Mode;
+use bms::model::note::Note;
+use bms::model::time_line::TimeLine;
+use rubato::core::pattern::lane_shuffle_modifier::{
+    LaneCrossShuffleModifier, LaneMirrorShuffleModifier, LaneRandomShuffleModifier,
+    LaneRotateShuffleModifier, PlayerFlipModifier,
+};
+use rubato::play::judge_property::{self, JudgeProperty, NoteType};
+use rubato::skin::clear_type::ClearType;
+use rubato::skin::gauge_property::GaugeProperty;
+use rubato::skin::groove_gauge::Gauge;
+
+use crate::pattern_fixtures::LaneShuffleFixture;
+use crate::rule_fixtures::{
+    GaugePropertyFixture, GaugeSequenceFixture, GutsFixtureEntry, JudgeWindowFixture,
+};
+use crate::{
+    Fixture, FixtureBpmChange, FixtureHashes, FixtureMetadata, FixtureNote, FixtureStatistics,
+    FixtureStopEvent, FixtureTimeline, bpm_changes, count_mines, find_ln_end_time, flatten_notes,
+    note_type_name, stop_events,
+};
+
+// =========================================================================
+// Charts
+// =========================================================================
+
+/// Dump a decoded chart in the schema of the `<chart>.json` fixtures.
+pub fn model_fixture(model: &BMSModel) -> Fixture {
+    let keys = model.mode().map(|m| m.key()).unwrap_or(0);
+    let timelines = model
+        .timelines
+        .iter()
+        .enumerate()
+        .map(|(i, tl)| FixtureTimeline {
+            time_us: tl.micro_time(),
+            bpm: tl.bpm,
+            stop_us: tl.micro_stop(),
+            notes: (0..keys)
+                .filter_map(|lane| {
+                    tl.note(lane)
+                        .map(|note| timeline_note(&model.timelines, i, lane, note))
+                })
+                .collect(),
+            hidden_notes: (0..keys)
+                .filter_map(|lane| {
+                    tl.hidden_note(lane).map(|note| FixtureNote {
+                        lane: lane as usize,
+                        time_us: note.micro_time(),
+                        wav_id: note.wav(),
+                        note_type: "Invisible".to_string(),
+                        end_time_us: None,
+                        end_wav_id: None,
+                        damage: None,
+                    })
+                })
+                .collect(),
+        })
+        .collect();
+
+    let notes = flatten_notes(model)
+        .into_iter()
+        .map(|n| {
+            let is_long = n.note_type_str.contains("Long") || n.note_type_str.contains("Charge");
+            FixtureNote {
+                lane: n.lane,
+                time_us: n.time_us,
+                wav_id: n.wav_id,
+                end_time_us: is_long.then_some(n.end_time_us),
+                end_wav_id: is_long.then_some(n.end_wav_id),
+                damage: (n.note_type_str == "Mine").then_some(n.damage),
+                note_type: n.note_type_str,
+            }
+        })
+        .collect();
+
+    Fixture {
+        metadata: FixtureMetadata {
+            title: model.title.clone(),
+            subtitle: model.sub_title.clone(),
+            artist: model.artist.clone(),
+            sub_artist: model.subartist.clone(),
+            genre: model.genre.clone(),
+            initial_bpm: model.bpm,
+            judge_rank: model.judgerank,
+            total: model.total,
+            player: model.player,
+            mode: model.mode().map(|m| m.hint()).unwrap_or("").to_string(),
+            mode_key_count: keys as usize,
+            ln_type: model.lnmode,
+            banner: model.banner.clone(),
+            stagefile: model.stagefile.clone(),
+            backbmp: model.backbmp.clone(),
+            preview: model.preview.clone(),
+        },
+        hashes: FixtureHashes {
+            md5: model.md5.clone(),
+            sha256: model.sha256.clone(),
+        },
+        statistics: FixtureStatistics {
+            total_notes: model.total_notes() as usize,
+            total_notes_mine: count_mines(model),
+            min_bpm: model.min_bpm(),
+            max_bpm: model.max_bpm(),
+            timeline_count: model.timelines.len(),
+        },
+        timelines,
+        notes,
+        bpm_changes: bpm_changes(model)
+            .into_iter()
+            .map(|(time_us, bpm)| FixtureBpmChange { time_us, bpm })
+            .collect(),
+        stop_events: stop_events(model)
+            .into_iter()
+            .map(|(time_us, duration_us)| FixtureStopEvent {
+                time_us,
+                duration_us,
+            })
+            .collect(),
+    }
+}
+
+/// A note as listed under its timeline, where LN ends are included.
+fn timeline_note(timelines: &[TimeLine], index: usize, lane: i32, note: &Note) -> FixtureNote {
+    let (note_type, end) = if note.is_long() && note.is_end() {
+        ("LongNoteEnd", None)
+    } else if note.is_long() {
+        (
+            note_type_name(note),
+            Some(find_ln_end_time(timelines, index, lane)),
+        )
+    } else {
+        (note_type_name(note), None)
+    };
+    FixtureNote {
+        lane: lane as usize,
+        time_us: note.micro_time(),
+        wav_id: note.wav(),
+        note_type: note_type.to_string(),
+        end_time_us: end.map(|(time, _)| time),
+        end_wav_id: end.map(|(_, wav)| wav),
+        damage: note.is_mine().then(|| note.damage()),
+    }
+}
+
+// =========================================================================
+// Rules
+// =========================================================================
+
+pub fn judge_property_by_name(name: &str) -> Option<JudgeProperty> {
+    Some(match name {
+        "FIVEKEYS" => judge_property::fivekeys(),
+        "SEVENKEYS" => judge_property::sevenkeys(),
+        "PMS" => judge_property::pms(),
+        "KEYBOARD" => judge_property::keyboard(),
+        "LR2" => judge_property::lr2(),
+        _ => return None,
+    })
+}
+
+pub fn gauge_property_by_name(name: &str) -> Option<GaugeProperty> {
+    Some(match name {
+        "FIVEKEYS" => GaugeProperty::FiveKeys,
+        "SEVENKEYS" => GaugeProperty::SevenKeys,
+        "PMS" => GaugeProperty::Pms,
+        "KEYBOARD" => GaugeProperty::Keyboard,
+        "LR2" => GaugeProperty::Lr2,
+        _ => return None,
+    })
+}
+
+pub fn note_type_by_name(name: &str) -> Option<NoteType> {
+    Some(match name {
+        "NOTE" => NoteType::Note,
+        "LONGNOTE_END" => NoteType::LongnoteEnd,
+        "SCRATCH" => NoteType::Scratch,
+        "LONGSCRATCH_END" => NoteType::LongscratchEnd,
+        _ => return None,
+    })
+}
+
+/// A BEAT_7K chart with `total_notes` normal notes, as the Java gauge exporter uses.
+pub fn gauge_model(total: f64, total_notes: usize) -> BMSModel {
+    let mut model = BMSModel::new();
+    model.total = total;
+    model.set_mode(Mode::BEAT_7K);
+    let key_count = Mode::BEAT_7K.key();
+    model.timelines = (0..total_notes)
+        .map(|i| {
+            let mut tl = TimeLine::new(i as f64, (i as i64 + 1) * 100_000, key_count);
+            tl.bpm = 120.0;
+            tl.set_note(0, Some(Note::new_normal(1)));
+            tl
+        })
+        .collect();
+    model
+}
+
+fn gauges_for(mode: &str, total: f64, total_notes: usize) -> anyhow::Result<Vec<Gauge>> {
+    let property = gauge_property_by_name(mode)
+        .ok_or_else(|| anyhow::anyhow!("Unknown gauge mode: {mode}"))?;
+    let model = gauge_model(total, total_notes);
+    Ok(property
+        .element_values()
+        .into_iter()
+        .enumerate()
+        .map(|(i, element)| {
+            let clear = ClearType::clear_type_by_gauge(i as i32).unwrap_or(ClearType::Failed);
+            Gauge::new(&model, element, clear)
+        })
+        .collect())
+}
+
+/// Replace every `windows` entry with the brs judge windows.
+pub fn judge_windows(fixture: &mut JudgeWindowFixture) -> anyhow::Result<()> {
+    for tc in &mut fixture.test_cases {
+        let property = judge_property_by_name(&tc.mode)
+            .ok_or_else(|| anyhow::anyhow!("Unknown judge mode: {}", tc.mode))?;
+        let note_type = note_type_by_name(&tc.note_type)
+            .ok_or_else(|| anyhow::anyhow!("Unknown note type: {}", tc.note_type))?;
+        let rate: [i32; 3] = tc
+            .judge_window_rate
+            .as_slice()
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("judge_window_rate must have 3 entries"))?;
+        tc.windows = property
+            .judge(note_type, tc.judgerank as f64, &rate)
+            .iter()
+            .map(|w| w.to_vec())
+            .collect();
+    }
+    Ok(())
+}
+
+/// Replace every gauge property output with the brs values.
+pub fn gauge_properties(fixture: &mut GaugePropertyFixture) -> anyhow::Result<()> {
+    for tc in &mut fixture.test_cases {
+        let property = gauge_property_by_name(&tc.mode)
+            .ok_or_else(|| anyhow::anyhow!("Unknown gauge mode: {}", tc.mode))?;
+        let element = property
+            .element_values()
+            .into_iter()
+            .nth(tc.gauge_type_index)
+            .ok_or_else(|| anyhow::anyhow!("No gauge type {}", tc.gauge_type_index))?;
+        let model = gauge_model(tc.total, tc.total_notes);
+        tc.min = element.min;
+        tc.max = element.max;
+        tc.border = element.border;
+        tc.death = element.death;
+        tc.base_values = element.value.clone();
+        tc.modified_values = element
+            .value
+            .iter()
+            .map(|&v| match element.modifier {
+                Some(ref modifier) => modifier.modify(v, &model),
+                None => v,
+            })
+            .collect();
+        tc.guts = element
+            .guts
+            .iter()
+            .map(|g| GutsFixtureEntry {
+                threshold: g.first().copied().unwrap_or(0.0),
+                multiplier: g.get(1).copied().unwrap_or(0.0),
+            })
+            .collect();
+        let clear =
+            ClearType::clear_type_by_gauge(tc.gauge_type_index as i32).unwrap_or(ClearType::Failed);
+        tc.init = Gauge::new(&model, element, clear).value();
+    }
+    Ok(())
+}
+
+/// Replace `values_after_each_step` with the brs gauge values.
+pub fn gauge_sequences(fixture: &mut GaugeSequenceFixture) -> anyhow::Result<()> {
+    for tc in &mut fixture.test_cases {
+        let mut gauges = gauges_for(&tc.mode, tc.total, tc.total_notes)?;
+        tc.values_after_each_step = tc
+            .sequence
+            .iter()
+            .map(|step| {
+                for gauge in &mut gauges {
+                    gauge.update(step.judge as i32, step.rate_x100 as f32 / 100.0);
+                }
+                gauges.iter().map(|g| g.value()).collect()
+            })
+            .collect();
+    }
+    Ok(())
+}
+
+// =========================================================================
+// Patterns
+// =========================================================================
+
+/// Replace every lane `mapping` with the brs shuffle for the same seed.
+pub fn lane_shuffle(fixture: &mut LaneShuffleFixture) -> anyhow::Result<()> {
+    for tc in &mut fixture.test_cases {
+        let mode = Mode::from_hint(&tc.mode)
+            .ok_or_else(|| anyhow::anyhow!("Unknown mode: {}", tc.mode))?;
+        let mut model = BMSModel::new();
+        model.set_mode(mode);
+        let keys: Vec<i32> = tc.keys.iter().map(|&k| k as i32).collect();
+        let seed = tc.seed.unwrap_or(0);
+        let mapping = match tc.modifier_type.as_str() {
+            "MIRROR" => LaneMirrorShuffleModifier::make_random(&keys, &model, seed),
+            "ROTATE" => LaneRotateShuffleModifier::make_random(&keys, &model, seed),
+            "RANDOM" => LaneRandomShuffleModifier::make_random(&keys, &model, seed),
+            "CROSS" => LaneCrossShuffleModifier::make_random(&keys, &model, seed),
+            "FLIP" => PlayerFlipModifier::make_random(&keys, &model, seed),
+            other => anyhow::bail!("Unknown modifier type: {other}"),
+        };
+        tc.mapping = mapping.iter().map(|&v| v as usize).collect();
+    }
+    Ok(())
+}
@@ -1,11 +1,17 @@
 // Golden master tests: Java vs Rust audio processing comparison
 //
-// Compares WAV decode, resample, and channel conversion results
+// Compares WAV decode, resample, channel conversion and chart mixdown results
 // between Java (AudioExporter) and Rust (beatoraja-audio) implementations.
 
 use std::path::PathBuf;
 
-use golden_master::audio_fixtures::{AudioTestCase, load_audio_fixture};
+use bms::model::bms_decoder::BMSDecoder;
+use bms::model::bms_model::{BMSModel, LNTYPE_LONGNOTE};
+use bms::model::chart_information::ChartInformation;
+use golden_master::audio_fixtures::{
+    AudioFingerprint, AudioRenderFixture, AudioRenderTestCase, AudioTestCase, load_audio_fixture,
+};
+use rubato::audio::bms_renderer::BMSRenderer;
 
 fn fixture_path() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/audio_fixtures.json")
@@ -202,3 +208,105 @@ fn channel_stereo_to_mono() {
     assert_eq!(converted.channels, tc.target_channels.unwrap());
     compare_samples(&rust_samples, &tc.samples_i16, 1, "channel_stereo_to_mono");
 }
+
+// =========================================================================
+// Chart mixdown
+// =========================================================================
+
+/// Charts and output formats rendered when the fixture is regenerated
+const RENDER_CASES: [(&str, i32, i32); 3] = [
+    ("audio/render_mix.bms", 44100, 2),
+    ("audio/render_mix.bms", 44100, 1),
+    ("audio/render_mix.bms", 48000, 2),
+];
+const RENDER_WINDOW_MS: u32 = 50;
+
+fn load_chart(filename: &str) -> BMSModel {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../test-bms")
+        .join(filename);
+    let info = ChartInformation::new(Some(path), LNTYPE_LONGNOTE, None);
+    BMSDecoder::new()
+        .decode(info)
+        .unwrap_or_else(|| panic!("Failed to parse {filename}"))
+}
+
+/// Render every note of the chart, as autoplay would play it, to 16-bit PCM.
+fn render_chart(filename: &str, sample_rate: i32, channels: i32) -> (i64, AudioFingerprint) {
+    let model = load_chart(filename);
+    let result = BMSRenderer::new(sample_rate, channels)
+        .render_bms(&model)
+        .unwrap_or_else(|| panic!("Failed to render {filename}"));
+    let fingerprint = AudioFingerprint::new(
+        &result.samples(),
+        channels as usize,
+        sample_rate as u32,
+        RENDER_WINDOW_MS,
+    );
+    (result.duration_ms, fingerprint)
+}
+
+fn build_render_fixture() -> AudioRenderFixture {
+    let test_cases = RENDER_CASES
+        .iter()
+        .map(|&(filename, sample_rate, channels)| {
+            let (duration_ms, fingerprint) = render_chart(filename, sample_rate, channels);
+            AudioRenderTestCase {
+                filename: filename.to_string(),
+                sample_rate,
+                channels,
+                duration_ms,
+                fingerprint,
+            }
+        })
+        .collect();
+    AudioRenderFixture { test_cases }
+}
+
+#[test]
+fn render_chart_mixdown() {
+    if std::env::var("UPDATE_AUDIO_RENDER_FIXTURES").is_ok() {
+        let json = serde_json::to_string_pretty(&build_render_fixture()).unwrap();
+        std::fs::write(AudioRenderFixture::path(), json + "\n")
+            .expect("Failed to write audio_render.json");
+    }
+    let fixture = AudioRenderFixture::load().expect("Failed to load audio_render.json");
+    assert!(!fixture.test_cases.is_empty());
+
+    let mut failures: Vec<String> = Vec::new();
+    for tc in &fixture.test_cases {
+        let label = format!("{} @{}Hz/{}ch", tc.filename, tc.sample_rate, tc.channels);
+        let (duration_ms, actual) = render_chart(&tc.filename, tc.sample_rate, tc.channels);
+        if duration_ms != tc.duration_ms {
+            failures.push(format!(
+                "{label}: duration {duration_ms}ms, expected {}ms",
+                tc.duration_ms
+            ));
+            continue;
+        }
+        // A bit-exact mix is a pass; otherwise the spectrum must still agree,
+        // which tolerates float rounding but not misplaced or missing keysounds.
+        if actual.sha256 == tc.fingerprint.sha256 {
+            continue;
+        }
+        let diffs = actual.diff(&tc.fingerprint, 1e-3);
+        if !diffs.is_empty() {
+            failures.push(format!(
+                "{label}: {} fingerprint diffs\n    {}",
+                diffs.len(),
+                diffs
+                    .iter()
+                    .take(10)
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join("\n    ")
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Chart mixdown mismatch:\n{}",
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,291 @@
+// Golden master tests: read and rewrite beatoraja sqlite databases through brs's accessors.
+//
+// Each database is copied, every row of the round-tripped tables is read with
+// the brs accessor and written back with it, and the copy is then compared
+// with the original column by column. Any column brs drops, retypes or
+// rewrites shows up as a diff, which would silently corrupt a user's database.
+//
+// Real databases are picked up from fixtures/databases/ and from the
+// directory in BEATORAJA_DB_DIR (songdata.db, score.db, searched recursively).
+// The player table is not round-tripped: brs, like beatoraja, stamps it with
+// the current date on write.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use rubato::core::score_database_accessor::ScoreDatabaseAccessor;
+use rubato::song::sqlite_song_database_accessor::SQLiteSongDatabaseAccessor;
+use rubato::song_database_accessor::SongDatabaseAccessor;
+use rusqlite::Connection;
+use rusqlite::types::Value;
+
+/// Primary key => column => value
+type Rows = BTreeMap<String, BTreeMap<String, Value>>;
+
+fn fixture_db_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/databases")
+}
+
+fn find_databases(dir: &Path, name: &str, found: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            find_databases(&path, name, found);
+        } else if path.file_name().and_then(|n| n.to_str()) == Some(name) {
+            found.push(path);
+        }
+    }
+}
+
+fn real_databases(name: &str) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    find_databases(&fixture_db_dir(), name, &mut found);
+    if let Ok(dir) = std::env::var("BEATORAJA_DB_DIR") {
+        find_databases(Path::new(&dir), name, &mut found);
+    }
+    found.sort();
+    found
+}
+
+/// Declared columns of `table` as (name, type, pk), or None if it does not exist.
+fn table_columns(conn: &Connection, table: &str) -> Option<Vec<(String, String, i32)>> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info('{table}')"))
+        .unwrap();
+    let columns: Vec<(String, String, i32)> = stmt
+        .query_map([], |row| Ok((row.get(1)?, row.get(2)?, row.get(5)?)))
+        .unwrap()
+        .filter_map(|r| r.ok())
+        .collect();
+    (!columns.is_empty()).then_some(columns)
+}
+
+fn read_rows(conn: &Connection, table: &str) -> Rows {
+    let columns = table_columns(conn, table).unwrap_or_default();
+    let mut keys: Vec<&str> = columns
+        .iter()
+        .filter(|(_, _, pk)| *pk > 0)
+        .map(|(name, _, _)| name.as_str())
+        .collect();
+    if keys.is_empty() {
+        keys = columns.iter().map(|(name, _, _)| name.as_str()).collect();
+    }
+
+    let mut stmt = conn.prepare(&format!("SELECT * FROM [{table}]")).unwrap();
+    let names: Vec<String> = stmt.column_names().iter().map(|s| s.to_string()).collect();
+    let mut rows = Rows::new();
+    let mut query = stmt.query([]).unwrap();
+    while let Some(row) = query.next().unwrap() {
+        let values: BTreeMap<String, Value> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.clone(), row.get::<_, Value>(i).unwrap()))
+            .collect();
+        let key = keys
+            .iter()
+            .map(|k| format!("{:?}", values.get(*k)))
+            .collect::<Vec<_>>()
+            .join("|");
+        rows.insert(key, values);
+    }
+    rows
+}
+
+/// Copy `path` (and any WAL file) into a temp dir so the original is never touched.
+fn copy_database(path: &Path, dir: &Path) -> PathBuf {
+    let name = path.file_name().unwrap();
+    let copy = dir.join(name);
+    std::fs::copy(path, &copy).unwrap();
+    for suffix in ["-wal", "-shm"] {
+        let mut side = path.as_os_str().to_owned();
+        side.push(suffix);
+        let side = PathBuf::from(side);
+        if side.exists() {
+            let mut dest = copy.as_os_str().to_owned();
+            dest.push(suffix);
+            std::fs::copy(&side, PathBuf::from(dest)).unwrap();
+        }
+    }
+    copy
+}
+
+/// Column diffs between the original tables and the round-tripped copy.
+fn compare_tables(original: &Connection, copy: &Connection, tables: &[&str]) -> Vec<String> {
+    let mut diffs = Vec::new();
+    for &table in tables {
+        let Some(columns) = table_columns(original, table) else {
+            continue;
+        };
+        if let Some(copied) = table_columns(copy, table) {
+            for (name, type_name, _) in &columns {
+                match copied.iter().find(|(n, _, _)| n == name) {
+                    None => diffs.push(format!("{table}.{name}: column dropped")),
+                    Some((_, t, _)) if !t.eq_ignore_ascii_case(type_name) => {
+                        diffs.push(format!("{table}.{name}: type {type_name} became {t}"))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let before = read_rows(original, table);
+        let after = read_rows(copy, table);
+        for (key, row) in &before {
+            let Some(copied) = after.get(key) else {
+                diffs.push(format!("{table}[{key}]: row lost"));
+                continue;
+            };
+            for (name, value) in row {
+                let copied_value = copied.get(name).unwrap_or(&Value::Null);
+                if copied_value != value {
+                    diffs.push(format!(
+                        "{table}[{key}].{name}: {value:?} became {copied_value:?}"
+                    ));
+                }
+            }
+        }
+        for key in after.keys().filter(|k| !before.contains_key(*k)) {
+            diffs.push(format!("{table}[{key}]: row added"));
+        }
+    }
+    diffs
+}
+
+fn round_trip_songdata(path: &Path) -> Vec<String> {
+    let dir = tempfile::tempdir().unwrap();
+    let copy = copy_database(path, dir.path());
+    {
+        let accessor = SQLiteSongDatabaseAccessor::new(copy.to_str().unwrap(), &[]).unwrap();
+        let conn = Connection::open(&copy).unwrap();
+        let hashes: Vec<String> = conn
+            .prepare("SELECT DISTINCT sha256 FROM song")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .filter_map(|r| r.ok())
+            .collect();
+        let songs = accessor.song_datas_by_hashes(&hashes);
+        accessor.set_song_datas(&songs).unwrap();
+    }
+    let original = Connection::open(path).unwrap();
+    let copy = Connection::open(&copy).unwrap();
+    compare_tables(&original, &copy, &["song"])
+}
+
+fn round_trip_score(path: &Path) -> Vec<String> {
+    let dir = tempfile::tempdir().unwrap();
+    let copy = copy_database(path, dir.path());
+    {
+        let accessor = ScoreDatabaseAccessor::new(copy.to_str().unwrap()).unwrap();
+        accessor.create_table().unwrap();
+        if let Some(info) = accessor.information() {
+            accessor.set_information(&info);
+        }
+        let scores = accessor.score_datas("1 = 1").unwrap_or_default();
+        let refs: Vec<_> = scores.iter().collect();
+        accessor.set_score_data_batch(&refs);
+    }
+    let original = Connection::open(path).unwrap();
+    let copy = Connection::open(&copy).unwrap();
+    compare_tables(&original, &copy, &["info", "score"])
+}
+
+fn assert_round_trips(label: &str, diffs: Vec<String>) {
+    assert!(
+        diffs.is_empty(),
+        "{label}: {} lossy columns\n  {}",
+        diffs.len(),
+        diffs
+            .iter()
+            .take(30)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n  ")
+    );
+}
+
+// =========================================================================
+// Real databases
+// =========================================================================
+
+#[test]
+fn real_songdata_round_trip() {
+    let files = real_databases("songdata.db");
+    if files.is_empty() {
+        eprintln!("No songdata.db found in fixtures/databases or BEATORAJA_DB_DIR; skipping");
+        return;
+    }
+    for path in files {
+        assert_round_trips(&path.display().to_string(), round_trip_songdata(&path));
+    }
+}
+
+#[test]
+fn real_score_round_trip() {
+    let files = real_databases("score.db");
+    if files.is_empty() {
+        eprintln!("No score.db found in fixtures/databases or BEATORAJA_DB_DIR; skipping");
+        return;
+    }
+    for path in files {
+        assert_round_trips(&path.display().to_string(), round_trip_score(&path));
+    }
+}
+
+// =========================================================================
+// Databases written the way beatoraja writes them
+// =========================================================================
+
+/// A score.db with the table layout beatoraja's validate() creates and
+/// values at the edges of each column type.
+fn write_beatoraja_score_db(path: &Path) {
+    let conn = Connection::open(path).unwrap();
+    conn.execute_batch(&format!(
+        "CREATE TABLE [info] ([id] TEXT NOT NULL,[name] TEXT NOT NULL,[rank] TEXT,PRIMARY KEY(id));
+         CREATE TABLE [player] ([date] INTEGER,[playcount] INTEGER,[clear] INTEGER,[epg] INTEGER,[lpg] INTEGER,[egr] INTEGER,[lgr] INTEGER,[egd] INTEGER,[lgd] INTEGER,[ebd] INTEGER,[lbd] INTEGER,[epr] INTEGER,[lpr] INTEGER,[ems] INTEGER,[lms] INTEGER,[playtime] INTEGER,[maxcombo] INTEGER,PRIMARY KEY(date));
+         CREATE TABLE [score] ([sha256] TEXT NOT NULL,[mode] INTEGER,[clear] INTEGER,[epg] INTEGER,[lpg] INTEGER,[egr] INTEGER,[lgr] INTEGER,[egd] INTEGER,[lgd] INTEGER,[ebd] INTEGER,[lbd] INTEGER,[epr] INTEGER,[lpr] INTEGER,[ems] INTEGER,[lms] INTEGER,[notes] INTEGER,[combo] INTEGER,[minbp] INTEGER,[avgjudge] INTEGER NOT NULL DEFAULT {max},[playcount] INTEGER,[clearcount] INTEGER,[trophy] TEXT,[ghost] TEXT,[option] INTEGER,[seed] INTEGER,[random] INTEGER,[date] INTEGER,[state] INTEGER,[scorehash] TEXT,PRIMARY KEY(sha256,mode));
+         INSERT INTO info VALUES ('0123456789abcdef', 'プレイヤー', '');
+         INSERT INTO score VALUES ('{sha_a}', 0, 10, 1200, 1150, 300, 280, 20, 18, 3, 2, 1, 0, 4, 5, 3000, 2980, 12, 4567, 120, 88, 'gh', 'H4sIAAAAAAAAAGNgYGBgBAA=', 10203, 1234567890123, 2, 1735689600, 0, '035abc');
+         INSERT INTO score VALUES ('{sha_a}', 1, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1000, 0, 2147483647, {max}, 1, 0, '', '', 0, -1, 0, 1, 0, '');
+         INSERT INTO score VALUES ('{sha_b}', 0, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 100, 50, 90, 1500, 2147483647, 1, 'eg', '', 2, -4611686018427387904, 7, 4102444800, 3, 'hash');",
+        max = i32::MAX,
+        sha_a = "a".repeat(64),
+        sha_b = "0f".repeat(32),
+    ))
+    .unwrap();
+}
+
+/// A songdata.db with the table layout beatoraja's validate() creates.
+fn write_beatoraja_songdata_db(path: &Path) {
+    let conn = Connection::open(path).unwrap();
+    conn.execute_batch(&format!(
+        "CREATE TABLE [folder] ([title] TEXT,[subtitle] TEXT,[command] TEXT,[path] TEXT,[banner] TEXT,[parent] TEXT,[type] INTEGER,[date] INTEGER,[adddate] INTEGER,[max] INTEGER,PRIMARY KEY(path));
+         CREATE TABLE [song] ([md5] TEXT NOT NULL,[sha256] TEXT NOT NULL,[title] TEXT,[subtitle] TEXT,[genre] TEXT,[artist] TEXT,[subartist] TEXT,[tag] TEXT,[path] TEXT,[folder] TEXT,[stagefile] TEXT,[banner] TEXT,[backbmp] TEXT,[preview] TEXT,[parent] TEXT,[level] INTEGER,[difficulty] INTEGER,[maxbpm] INTEGER,[minbpm] INTEGER,[length] INTEGER,[mode] INTEGER,[judge] INTEGER,[feature] INTEGER,[content] INTEGER,[date] INTEGER,[favorite] INTEGER,[adddate] INTEGER,[notes] INTEGER,[charthash] TEXT,PRIMARY KEY(path));
+         INSERT INTO song VALUES ('{md5_a}', '{sha_a}', '曲名 [SPA]', '-another-', 'ジャンル', 'アーティスト', 'obj: 譜面', 'tag', 'BMS/song/a.bms', 'f1', 'stage.png', 'banner.png', 'back.png', 'preview.ogg', 'p1', 12, 4, 300, 75, 123456, 7, 100, 1, 3, 1735689600, 1, 1735689601, 2345, '{sha_a}');
+         INSERT INTO song VALUES ('{md5_b}', '{sha_b}', 'Song B', '', '', '', '', '', 'BMS/song/b.bme', 'f1', '', '', '', '', 'p1', 0, 0, 150, 150, 0, 14, 0, 0, 0, 0, 0, 0, 0, '');",
+        md5_a = "c".repeat(32),
+        sha_a = "a".repeat(64),
+        md5_b = "d".repeat(32),
+        sha_b = "0f".repeat(32),
+    ))
+    .unwrap();
+}
+
+#[test]
+fn beatoraja_score_db_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("score.db");
+    write_beatoraja_score_db(&path);
+    assert_round_trips("score.db", round_trip_score(&path));
+}
+
+#[test]
+fn beatoraja_songdata_db_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("songdata.db");
+    write_beatoraja_songdata_db(&path);
+    assert_round_trips("songdata.db", round_trip_songdata(&path));
+}
@@ -2,6 +2,9 @@
 //
 // Compares Rust JudgeManager output against Java JudgeManagerExporter fixtures.
 // Covers: autoplay, manual input, gauge types, LN, and cross-mode tests (26 cases).
+// compare_judge_keylog replays the manual input logs from their replay
+// `keyinput` form (judge_keylog.json) and checks per-note judgments, FAST/SLOW
+// and max combo.
 //
 // Notes:
 // - JudgeManager.prev_time starts at 0, so notes at time_us=0 are skipped on
@@ -16,13 +19,18 @@ use bms::model::bms_decoder::BMSDecoder;
 use bms::model::bms_model::{BMSModel, LNTYPE_LONGNOTE};
 use bms::model::chart_information::ChartInformation;
 use bms::model::mode::Mode;
-use golden_master::judge_fixtures::{JudgeFixtures, JudgeTestCase};
+use golden_master::judge_fixtures::{
+    ExpectedJudgeLog, JudgeFixtures, JudgeKeylogCase, JudgeKeylogFixtures, JudgeTestCase,
+};
 use rubato::core::score_data::ScoreData;
 use rubato::play::bms_player_rule::BMSPlayerRule;
 use rubato::play::judge_algorithm::JudgeAlgorithm;
 use rubato::play::judge_manager::{JudgeConfig, JudgeManager};
 use rubato::play::lane_property::LaneProperty;
+use rubato::skin::KeyInputLog;
 use rubato::skin::groove_gauge::GrooveGauge;
+use rubato::skin::replay_data::ReplayData;
+use rubato::skin::validatable::Validatable;
 
 #[path = "support/random_seeds.rs"]
 mod random_seeds;
@@ -78,22 +86,30 @@ struct SimResult {
     gauge_value: f32,
     gauge_qualified: bool,
     pass_notes: i32,
+    fast: i32,
+    slow: i32,
 }
 
-fn run_simulation(model: &BMSModel, tc: &JudgeTestCase) -> SimResult {
+fn run_simulation(
+    model: &BMSModel,
+    gauge_type: &str,
+    autoplay: bool,
+    input_log: &[KeyInputLog],
+) -> SimResult {
     let judge_notes = model.build_judge_notes();
     let mode = model.mode().cloned().unwrap_or(Mode::BEAT_7K);
-    let rule = BMSPlayerRule::for_mode(&mode);
+    // Fixtures were captured with the LR2 rule applied to every mode, PMS included.
+    let rule = BMSPlayerRule::lr2();
 
     let config = JudgeConfig {
         notes: &judge_notes,
         mode: &mode,
         ln_type: model.lntype(),
-        judge_rank: model.judgerank,
+        judge_rank: model.judgerank_exact(),
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
-        autoplay: tc.autoplay,
+        autoplay,
         judge_property: &rule.judge,
         lane_property: None,
         auto_adjust_enabled: false,
@@ -101,7 +117,7 @@ fn run_simulation(model: &BMSModel, tc: &JudgeTestCase) -> SimResult {
         judgeregion: 1,
     };
 
-    let gauge_type = parse_gauge_type(&tc.gauge_type);
+    let gauge_type = parse_gauge_type(gauge_type);
     let mut jm = JudgeManager::from_config(&config);
     let mut gauge = GrooveGauge::new(model, gauge_type, &rule.gauge);
 
@@ -120,7 +136,7 @@ fn run_simulation(model: &BMSModel, tc: &JudgeTestCase) -> SimResult {
         .unwrap_or(0);
     let end_time = last_note_time + TAIL_TIME;
 
-    if tc.autoplay {
+    if autoplay {
         // Autoplay: run with empty key states
         let key_states = vec![false; physical_key_count];
         let key_times = vec![NOT_SET; physical_key_count];
@@ -131,8 +147,8 @@ fn run_simulation(model: &BMSModel, tc: &JudgeTestCase) -> SimResult {
         }
     } else {
         // Manual: convert input_log to per-frame key states
-        let mut sorted_log: Vec<&_> = tc.input_log.iter().collect();
-        sorted_log.sort_by_key(|e| e.presstime);
+        let mut sorted_log: Vec<&KeyInputLog> = input_log.iter().collect();
+        sorted_log.sort_by_key(|e| e.time);
 
         let mut key_states = vec![false; physical_key_count];
         let mut log_cursor = 0;
@@ -142,12 +158,12 @@ fn run_simulation(model: &BMSModel, tc: &JudgeTestCase) -> SimResult {
             let mut key_changed_times = vec![NOT_SET; physical_key_count];
 
             // Input log uses lane indices (keycodes); map directly to physical key indices.
-            while log_cursor < sorted_log.len() && sorted_log[log_cursor].presstime <= time {
+            while log_cursor < sorted_log.len() && sorted_log[log_cursor].time <= time {
                 let event = &sorted_log[log_cursor];
                 let key = event.keycode as usize;
                 if key < physical_key_count {
                     key_states[key] = event.pressed;
-                    key_changed_times[key] = event.presstime;
+                    key_changed_times[key] = event.time;
                 }
                 log_cursor += 1;
             }
@@ -170,9 +186,22 @@ fn run_simulation(model: &BMSModel, tc: &JudgeTestCase) -> SimResult {
         gauge_value: gauge.value(),
         gauge_qualified: gauge.is_qualified(),
         pass_notes: jm.past_notes(),
+        fast: jm.fast_count(),
+        slow: jm.slow_count(),
     }
 }
 
+fn to_key_log(tc: &JudgeTestCase) -> Vec<KeyInputLog> {
+    tc.input_log
+        .iter()
+        .map(|e| KeyInputLog {
+            time: e.presstime,
+            keycode: e.keycode,
+            pressed: e.pressed,
+        })
+        .collect()
+}
+
 fn compare_score(
     actual: &ScoreData,
     expected: &golden_master::judge_fixtures::ExpectedScore,
@@ -211,7 +240,7 @@ fn compare_judge_manager() {
 
     for tc in &fixtures.test_cases {
         let model = load_bms(&tc.filename);
-        let result = run_simulation(&model, tc);
+        let result = run_simulation(&model, &tc.gauge_type, tc.autoplay, &to_key_log(tc));
 
         let mut diffs: Vec<String> = Vec::new();
 
@@ -303,3 +332,117 @@ fn compare_judge_manager() {
         fixtures.test_cases.len(),
     );
 }
+
+/// Build judge_keylog.json from the manual cases of judge_manager.json.
+///
+/// The key logs are stored compressed as in replay files; the expected
+/// values are the Java results of the source case.
+fn build_keylog_fixtures() -> JudgeKeylogFixtures {
+    let fixtures = JudgeFixtures::load().expect("Failed to load judge_manager.json");
+    let test_cases = fixtures
+        .test_cases
+        .iter()
+        .filter(|tc| !tc.autoplay)
+        .map(|tc| {
+            let mut replay = ReplayData {
+                keylog: to_key_log(tc),
+                ..Default::default()
+            };
+            replay.shrink();
+            JudgeKeylogCase {
+                group: tc.group.clone(),
+                name: tc.name.clone(),
+                filename: tc.filename.clone(),
+                gauge_type: tc.gauge_type.clone(),
+                // An empty log (no key pressed) has no keyinput
+                keyinput: replay.keyinput.unwrap_or_default(),
+                expected: ExpectedJudgeLog {
+                    judgments: tc.expected.ghost.clone(),
+                    fast: tc.expected.score.fast(),
+                    slow: tc.expected.score.slow(),
+                    maxcombo: tc.expected.maxcombo,
+                },
+            }
+        })
+        .collect();
+    JudgeKeylogFixtures { test_cases }
+}
+
+#[test]
+fn compare_judge_keylog() {
+    if std::env::var("UPDATE_JUDGE_KEYLOG_FIXTURES").is_ok() {
+        let json = serde_json::to_string_pretty(&build_keylog_fixtures()).unwrap();
+        std::fs::write(JudgeKeylogFixtures::path(), json + "\n")
+            .expect("Failed to write judge_keylog.json");
+    }
+    let fixtures = JudgeKeylogFixtures::load().expect("Failed to load judge_keylog.json");
+    assert!(!fixtures.test_cases.is_empty());
+
+    let mut failures: Vec<String> = Vec::new();
+    for tc in &fixtures.test_cases {
+        let mut replay = ReplayData {
+            keyinput: Some(tc.keyinput.clone()).filter(|k| !k.is_empty()),
+            ..Default::default()
+        };
+        replay.validate();
+
+        let model = load_bms(&tc.filename);
+        let result = run_simulation(&model, &tc.gauge_type, false, &replay.keylog);
+
+        let mut diffs: Vec<String> = Vec::new();
+        for (name, actual, expected) in [
+            ("fast", result.fast, tc.expected.fast),
+            ("slow", result.slow, tc.expected.slow),
+            ("maxcombo", result.max_combo, tc.expected.maxcombo),
+        ] {
+            if actual != expected {
+                diffs.push(format!("{name}: rust={actual} java={expected}"));
+            }
+        }
+        if result.ghost.len() != tc.expected.judgments.len() {
+            diffs.push(format!(
+                "judgments.len: rust={} java={}",
+                result.ghost.len(),
+                tc.expected.judgments.len()
+            ));
+        } else {
+            for (i, (r, j)) in result
+                .ghost
+                .iter()
+                .zip(tc.expected.judgments.iter())
+                .enumerate()
+            {
+                if r != j {
+                    diffs.push(format!("judgments[{i}]: rust={r} java={j}"));
+                }
+            }
+        }
+
+        if !diffs.is_empty() {
+            failures.push(format!(
+                "[{}/{}] {} differences:\n{}",
+                tc.group,
+                tc.name,
+                diffs.len(),
+                diffs
+                    .iter()
+                    .map(|d| format!("    - {d}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!(
+            "Judge keylog GM test: {} of {} failed:\n\n{}",
+            failures.len(),
+            fixtures.test_cases.len(),
+            failures.join("\n\n")
+        );
+    }
+    println!(
+        "Judge keylog GM test: {} all passed",
+        fixtures.test_cases.len()
+    );
+}
@@ -1,12 +1,14 @@
 // Golden master tests for Phase 3: Pattern Shuffle
 //
-// Compares Rust lane shuffle implementations against Java fixture output.
+// Compares Rust lane shuffle implementations and the java.util.Random port
+// they draw from against Java fixture output.
 
 use std::path::Path;
 
 use bms::model::bms_model::BMSModel;
 use bms::model::mode::Mode;
-use golden_master::pattern_fixtures::LaneShuffleFixture;
+use golden_master::pattern_fixtures::{JavaRandomFixture, LaneShuffleFixture};
+use rubato::core::pattern::java_random::JavaRandom;
 use rubato::core::pattern::lane_shuffle_modifier::{
     LaneCrossShuffleModifier, LaneMirrorShuffleModifier, LaneRandomShuffleModifier,
     LaneRotateShuffleModifier, PlayerFlipModifier,
@@ -90,3 +92,69 @@ fn golden_master_lane_shuffle_mappings() {
     );
     assert_eq!(fail, 0, "{fail} lane shuffle mapping test(s) failed");
 }
+
+// =========================================================================
+// java.util.Random Parity Tests
+// =========================================================================
+
+#[test]
+fn golden_master_java_random() {
+    let content = std::fs::read_to_string(fixture_dir().join("java_random.json"))
+        .expect("Failed to read java_random.json");
+    let fixture: JavaRandomFixture =
+        serde_json::from_str(&content).expect("Failed to parse fixture");
+    assert!(!fixture.test_cases.is_empty());
+
+    let mut failures: Vec<String> = Vec::new();
+    for tc in &fixture.test_cases {
+        for case in &tc.next_int {
+            let mut rand = JavaRandom::new(tc.seed);
+            let actual: Vec<i32> = (0..case.values.len())
+                .map(|_| rand.next_int_bounded(case.bound))
+                .collect();
+            if actual != case.values {
+                failures.push(format!(
+                    "seed={} nextInt({}): expected {:?}, actual {:?}",
+                    tc.seed, case.bound, case.values, actual
+                ));
+            }
+        }
+
+        for case in &tc.descending {
+            let mut rand = JavaRandom::new(tc.seed);
+            let actual: Vec<i32> = (0..case.values.len() as i32)
+                .map(|i| rand.next_int_bounded(case.bound - i))
+                .collect();
+            if actual != case.values {
+                failures.push(format!(
+                    "seed={} descending from {}: expected {:?}, actual {:?}",
+                    tc.seed, case.bound, case.values, actual
+                ));
+            }
+        }
+
+        let mut rand = JavaRandom::new(tc.seed);
+        let actual: Vec<f64> = (0..tc.next_double.len())
+            .map(|_| rand.next_double())
+            .collect();
+        // serde_json's default float parsing can be one ulp off the printed value
+        let matches = actual.len() == tc.next_double.len()
+            && actual
+                .iter()
+                .zip(&tc.next_double)
+                .all(|(a, e)| (a.to_bits() as i64 - e.to_bits() as i64).abs() <= 1);
+        if !matches {
+            failures.push(format!(
+                "seed={} nextDouble: expected {:?}, actual {:?}",
+                tc.seed, tc.next_double, actual
+            ));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "java.util.Random parity failed ({} failures):\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
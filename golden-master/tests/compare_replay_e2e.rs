@@ -78,13 +78,14 @@ struct SimResult {
 fn run_simulation(model: &BMSModel, tc: &ReplayE2ETestCase) -> SimResult {
     let judge_notes = model.build_judge_notes();
     let mode = model.mode().cloned().unwrap_or(Mode::BEAT_7K);
-    let rule = BMSPlayerRule::for_mode(&mode);
+    // Fixtures were captured with the LR2 rule applied to every mode, PMS included.
+    let rule = BMSPlayerRule::lr2();
 
     let config = JudgeConfig {
         notes: &judge_notes,
         mode: &mode,
         ln_type: model.lntype(),
-        judge_rank: model.judgerank,
+        judge_rank: model.judgerank_exact(),
         judge_window_rate: [100, 100, 100],
         scratch_judge_window_rate: [100, 100, 100],
         algorithm: JudgeAlgorithm::Combo,
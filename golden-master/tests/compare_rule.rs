@@ -1,6 +1,6 @@
 // Golden master tests: compare Rust rule engine output against Java fixtures
 //
-// Tests judge window calculation and gauge property/sequence behavior
+// Tests judge window calculation and gauge property/sequence/delta behavior
 // against Java-exported golden master fixtures.
 
 use std::path::Path;
@@ -10,12 +10,13 @@ use bms::model::mode::Mode;
 use bms::model::note::Note;
 use bms::model::time_line::TimeLine;
 use golden_master::rule_fixtures::{
-    GaugePropertyFixture, GaugeSequenceFixture, JudgeWindowFixture,
+    GaugeDeltaFixture, GaugeDeltaTestCase, GaugePropertyFixture, GaugeSequenceFixture,
+    JudgeWindowFixture,
 };
 use rubato::play::judge_property::{self, JudgeProperty, NoteType};
 use rubato::skin::clear_type::ClearType;
 use rubato::skin::gauge_property::GaugeProperty;
-use rubato::skin::groove_gauge::Gauge;
+use rubato::skin::groove_gauge::{Gauge, GrooveGauge};
 
 fn fixtures_dir() -> &'static Path {
     Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -112,7 +113,7 @@ fn golden_master_judge_windows() {
         ];
 
         // get_judge returns Vec<[i64; 2]> in microseconds — matches fixture format
-        let rust_windows = prop.judge(note_type, tc.judgerank, &jwr);
+        let rust_windows = prop.judge(note_type, tc.judgerank as f64, &jwr);
 
         // Compare window count
         if rust_windows.len() != tc.windows.len() {
@@ -361,3 +362,115 @@ fn golden_master_gauge_sequences() {
         fixture.test_cases.len()
     );
 }
+
+// =========================================================================
+// Gauge Delta GM Test
+// =========================================================================
+
+/// Per-step deltas of the Java gauge sequences, starting from the Java init values.
+fn build_gauge_delta_fixture() -> GaugeDeltaFixture {
+    let sequences: GaugeSequenceFixture = load_json("gauge_sequences.json");
+    let properties: GaugePropertyFixture = load_json("gauge_properties.json");
+    let test_cases = sequences
+        .test_cases
+        .into_iter()
+        .map(|tc| {
+            let mut init = vec![0.0_f32; GAUGE_NAMES.len()];
+            for p in properties.test_cases.iter().filter(|p| {
+                p.mode == tc.mode && p.total == tc.total && p.total_notes == tc.total_notes
+            }) {
+                init[p.gauge_type_index] = p.init;
+            }
+            let mut before = init.clone();
+            let deltas = tc
+                .values_after_each_step
+                .iter()
+                .map(|after| {
+                    let delta = after.iter().zip(&before).map(|(a, b)| a - b).collect();
+                    before = after.clone();
+                    delta
+                })
+                .collect();
+            GaugeDeltaTestCase {
+                mode: tc.mode,
+                sequence_name: tc.sequence_name,
+                total: tc.total,
+                total_notes: tc.total_notes,
+                sequence: tc.sequence,
+                init,
+                deltas,
+            }
+        })
+        .collect();
+    GaugeDeltaFixture { test_cases }
+}
+
+#[test]
+fn golden_master_gauge_deltas() {
+    if std::env::var("UPDATE_GAUGE_DELTA_FIXTURES").is_ok() {
+        let json = serde_json::to_string_pretty(&build_gauge_delta_fixture()).unwrap();
+        std::fs::write(GaugeDeltaFixture::path(), json + "\n")
+            .expect("Failed to write gauge_deltas.json");
+    }
+    let fixture: GaugeDeltaFixture = load_json("gauge_deltas.json");
+    let mut failures = Vec::new();
+    let tol = 1e-4_f32; // per-step, so no accumulated error
+
+    for (i, tc) in fixture.test_cases.iter().enumerate() {
+        let prop = mode_to_gauge_property(&tc.mode);
+        let model = make_model_for_gauge(tc.total, tc.total_notes);
+        let label = format!(
+            "[{i}] {}/{}/total={}/notes={}",
+            tc.mode, tc.sequence_name, tc.total, tc.total_notes
+        );
+
+        for gauge_type in 0..GAUGE_NAMES.len() {
+            let gauge_name = GAUGE_NAMES[gauge_type];
+            let mut gauge = GrooveGauge::new(&model, gauge_type as i32, &prop);
+            let init = gauge.value_by_type(gauge_type as i32);
+            if (init - tc.init[gauge_type]).abs() > tol {
+                failures.push(format!(
+                    "{label} gauge[{gauge_name}] init: rust={init} java={}",
+                    tc.init[gauge_type]
+                ));
+                continue;
+            }
+
+            // Replay from the Java value before each step so one drifting
+            // step does not shift every later comparison.
+            let mut before = init;
+            for (step_idx, step) in tc.sequence.iter().enumerate() {
+                gauge.set_value_by_type(gauge_type as i32, before);
+                gauge.update_with_rate(step.judge as i32, step.rate_x100 as f32 / 100.0);
+                let rust_delta = gauge.value_by_type(gauge_type as i32) - before;
+                let java_delta = tc.deltas[step_idx][gauge_type];
+                if (rust_delta - java_delta).abs() > tol {
+                    failures.push(format!(
+                        "{label} step[{step_idx}] judge={} gauge[{gauge_name}]: rust={rust_delta} java={java_delta}",
+                        step.judge
+                    ));
+                }
+                before += java_delta;
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!(
+            "Gauge delta GM mismatch ({} failures out of {} cases):\n{}",
+            failures.len(),
+            fixture.test_cases.len(),
+            failures
+                .iter()
+                .take(30)
+                .map(|f| format!("  - {f}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+
+    println!(
+        "Gauge delta GM: all {} cases passed",
+        fixture.test_cases.len()
+    );
+}
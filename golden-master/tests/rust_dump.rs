@@ -0,0 +1,90 @@
+// Rust-side fixture dumps must read back through the same comparators as the
+// Java fixtures, and match them wherever brs already matches Java.
+
+use std::path::Path;
+
+use bms::model::bms_decoder::BMSDecoder;
+use golden_master::pattern_fixtures::LaneShuffleFixture;
+use golden_master::rule_fixtures::{GaugeSequenceFixture, JudgeWindowFixture};
+use golden_master::{Fixture, compare_model, rust_dump};
+
+fn fixtures_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .leak()
+}
+
+fn load_json<T: serde::de::DeserializeOwned>(name: &str) -> T {
+    let content = std::fs::read_to_string(fixtures_dir().join(name)).expect("Failed to read");
+    serde_json::from_str(&content).expect("Failed to parse")
+}
+
+/// Serialize and parse back, as the CLI output would be read.
+fn reparse<T: serde::Serialize + serde::de::DeserializeOwned>(value: &T) -> T {
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+#[test]
+fn model_dump_matches_its_own_chart_and_the_java_fixture() {
+    let chart = Path::new(env!("CARGO_MANIFEST_DIR")).join("../test-bms/longnote_types.bms");
+    let model = BMSDecoder::new().decode_path(&chart).unwrap();
+    let dump: Fixture = reparse(&rust_dump::model_fixture(&model));
+
+    let diffs = compare_model(&model, &dump);
+    assert!(diffs.is_empty(), "self diff: {diffs:?}");
+
+    let java: Fixture = load_json("longnote_types.bms.json");
+    assert_eq!(dump.notes.len(), java.notes.len());
+    assert_eq!(dump.timelines.len(), java.timelines.len());
+    for (rust, java) in dump.timelines.iter().zip(&java.timelines) {
+        let types = |tl: &golden_master::FixtureTimeline| {
+            tl.notes
+                .iter()
+                .map(|n| (n.lane, n.note_type.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(rust.time_us, java.time_us);
+        assert_eq!(
+            types(rust).len(),
+            types(java).len(),
+            "notes at {}",
+            java.time_us
+        );
+    }
+}
+
+#[test]
+fn rule_and_pattern_dumps_reproduce_java_outputs() {
+    let java: JudgeWindowFixture = load_json("judge_windows.json");
+    let mut dump: JudgeWindowFixture = load_json("judge_windows.json");
+    rust_dump::judge_windows(&mut dump).unwrap();
+    let dump = reparse(&dump);
+    for (rust, java) in dump.test_cases.iter().zip(&java.test_cases) {
+        assert_eq!(
+            rust.windows, java.windows,
+            "{} {}",
+            java.mode, java.note_type
+        );
+    }
+
+    let java: LaneShuffleFixture = load_json("pattern_lane_shuffle.json");
+    let mut dump: LaneShuffleFixture = load_json("pattern_lane_shuffle.json");
+    rust_dump::lane_shuffle(&mut dump).unwrap();
+    for (rust, java) in dump.test_cases.iter().zip(&java.test_cases) {
+        assert_eq!(rust.mapping, java.mapping, "{}", java.modifier_type);
+    }
+
+    let java: GaugeSequenceFixture = load_json("gauge_sequences.json");
+    let mut dump: GaugeSequenceFixture = load_json("gauge_sequences.json");
+    rust_dump::gauge_sequences(&mut dump).unwrap();
+    for (rust, java) in dump.test_cases.iter().zip(&java.test_cases) {
+        for (r, j) in rust
+            .values_after_each_step
+            .iter()
+            .flatten()
+            .zip(java.values_after_each_step.iter().flatten())
+        {
+            assert!((r - j).abs() < 1e-3, "{}: {r} vs {j}", java.sequence_name);
+        }
+    }
+}